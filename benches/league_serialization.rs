@@ -0,0 +1,76 @@
+//! Benchmark comparing MessagePack and JSON serialization of a league.
+//!
+//! Builds a league of 10 complete 16-team seasons, each with a simulated
+//! regular season and playoffs, and times serializing and parsing it with
+//! `League::to_bytes`/`League::from_bytes` and with `serde_json`.
+//!
+//! Run with `cargo bench --bench league_serialization --features binary`.
+use std::hint::black_box;
+use std::time::Instant;
+
+use fbsim_core::league::League;
+use fbsim_core::league::season::{LeagueSeasonPlayoffOptions, LeagueSeasonScheduleOptions};
+use fbsim_core::team::FootballTeam;
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+
+const NUM_TEAMS: usize = 16;
+const NUM_SEASONS: usize = 10;
+const ITERATIONS: u32 = 20;
+
+fn main() {
+    // Create and simulate the league
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut league = League::new();
+    for _ in 0..NUM_TEAMS {
+        league.add_team();
+    }
+    for year in 2026..2026 + NUM_SEASONS {
+        league.add_season_with_year(year).unwrap();
+        for id in 0..NUM_TEAMS {
+            league.add_season_team(id, FootballTeam::new()).unwrap();
+        }
+        league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        league.sim(&mut rng).unwrap();
+        let season = league.current_season_mut().as_mut().unwrap();
+        let options = LeagueSeasonPlayoffOptions::new().num_playoff_teams(8);
+        season.generate_playoffs(options, &mut rng).unwrap();
+        season.sim_playoffs(&mut rng).unwrap();
+    }
+
+    let json = serde_json::to_string(&league).unwrap();
+    let bytes = league.to_bytes().unwrap();
+
+    // Time serializing the league each way
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(serde_json::to_string(black_box(&league)).unwrap());
+    }
+    let json_write = start.elapsed() / ITERATIONS;
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(black_box(&league).to_bytes().unwrap());
+    }
+    let bytes_write = start.elapsed() / ITERATIONS;
+
+    // Time parsing the league each way, including validation
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let _: League = serde_json::from_str(black_box(&json)).unwrap();
+    }
+    let json_read = start.elapsed() / ITERATIONS;
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(League::from_bytes(black_box(&bytes)).unwrap());
+    }
+    let bytes_read = start.elapsed() / ITERATIONS;
+
+    println!(
+        "{} teams, {} seasons: json {} bytes, write {:?}/iter, parse {:?}/iter",
+        NUM_TEAMS, NUM_SEASONS, json.len(), json_write, json_read
+    );
+    println!(
+        "{} teams, {} seasons: binary {} bytes, write {:?}/iter, parse {:?}/iter",
+        NUM_TEAMS, NUM_SEASONS, bytes.len(), bytes_write, bytes_read
+    );
+}
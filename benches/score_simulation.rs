@@ -0,0 +1,115 @@
+//! Benchmark for `PreparedMatchup` and `FinalScoreSimulator::fast_sim` on a
+//! Monte Carlo season projection.
+//!
+//! Simulates every matchup of an 8-team double round robin 10,000 times,
+//! tallying each team's wins, once with `FinalScoreSimulator::sim`, once
+//! with matchups prepared up front by `FinalScoreSimulator::prepare`, once
+//! from the teams' overalls alone with `FinalScoreSimulator::fast_sim`, and
+//! once from the prepared matchups with `PreparedMatchup::fast_sim`.
+//! All runs use the same seed, so their tallies must be identical.
+//!
+//! Run with `cargo bench --bench score_simulation`.
+use std::hint::black_box;
+use std::time::Instant;
+
+use fbsim_core::game::score::FinalScoreSimulator;
+use fbsim_core::team::FootballTeam;
+use fbsim_core::game::score::ScoreSimulatable;
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+
+const NUM_TEAMS: usize = 8;
+const ITERATIONS: usize = 10_000;
+
+fn main() {
+    // Create the teams and every home and away pairing between them
+    let teams: Vec<FootballTeam> = (0..NUM_TEAMS)
+        .map(|id| {
+            let overall = 30 + 5 * id as u32;
+            FootballTeam::from_overalls(&format!("Team {}", id), &format!("T{}", id), overall, 100 - overall).unwrap()
+        })
+        .collect();
+    let matchups: Vec<(usize, usize)> = (0..NUM_TEAMS)
+        .flat_map(|home| (0..NUM_TEAMS).filter(move |away| *away != home).map(move |away| (home, away)))
+        .collect();
+    let simulator = FinalScoreSimulator::new();
+
+    // Project the season, simulating each matchup from the teams
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut wins = vec![0_usize; NUM_TEAMS];
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        for (home, away) in matchups.iter() {
+            let score = simulator.sim(black_box(&teams[*home]), black_box(&teams[*away]), &mut rng).unwrap();
+            tally(&mut wins, *home, *away, score.home_score(), score.away_score());
+        }
+    }
+    let unprepared_elapsed = start.elapsed();
+
+    // Project the season again from prepared matchups
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut prepared_wins = vec![0_usize; NUM_TEAMS];
+    let start = Instant::now();
+    let prepared: Vec<_> = matchups.iter()
+        .map(|(home, away)| simulator.prepare(&teams[*home], &teams[*away]).unwrap())
+        .collect();
+    for _ in 0..ITERATIONS {
+        for ((home, away), matchup) in matchups.iter().zip(prepared.iter()) {
+            let score = black_box(matchup).sim(&mut rng).unwrap();
+            tally(&mut prepared_wins, *home, *away, score.home_score(), score.away_score());
+        }
+    }
+    let prepared_elapsed = start.elapsed();
+    assert_eq!(wins, prepared_wins);
+
+    // Project the season again from the overalls alone
+    let overalls: Vec<(u32, u32)> = teams.iter()
+        .map(|team| (team.offense_overall(), team.defense_overall()))
+        .collect();
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut fast_wins = vec![0_usize; NUM_TEAMS];
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        for (home, away) in matchups.iter() {
+            let (home_offense, home_defense) = black_box(overalls[*home]);
+            let (away_offense, away_defense) = black_box(overalls[*away]);
+            let (home_score, away_score) = simulator.fast_sim(
+                home_offense, home_defense, away_offense, away_defense, &mut rng
+            ).unwrap();
+            tally(&mut fast_wins, *home, *away, home_score as u32, away_score as u32);
+        }
+    }
+    let fast_elapsed = start.elapsed();
+    assert_eq!(wins, fast_wins);
+
+    // Project the season again from the prepared matchups' scores alone
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut prepared_fast_wins = vec![0_usize; NUM_TEAMS];
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        for ((home, away), matchup) in matchups.iter().zip(prepared.iter()) {
+            let (home_score, away_score) = black_box(matchup).fast_sim(&mut rng);
+            tally(&mut prepared_fast_wins, *home, *away, home_score as u32, away_score as u32);
+        }
+    }
+    let prepared_fast_elapsed = start.elapsed();
+    assert_eq!(wins, prepared_fast_wins);
+
+    let speedup = |elapsed: std::time::Duration| unprepared_elapsed.as_secs_f64() / elapsed.as_secs_f64();
+    println!(
+        "{} iterations of {} games: unprepared {:?}, prepared {:?} ({:.2}x), fast {:?} ({:.2}x), prepared fast {:?} ({:.2}x)",
+        ITERATIONS, matchups.len(), unprepared_elapsed,
+        prepared_elapsed, speedup(prepared_elapsed),
+        fast_elapsed, speedup(fast_elapsed),
+        prepared_fast_elapsed, speedup(prepared_fast_elapsed)
+    );
+}
+
+/// Credit the winner of a game, if any
+fn tally(wins: &mut [usize], home: usize, away: usize, home_score: u32, away_score: u32) {
+    if home_score > away_score {
+        wins[home] += 1;
+    } else if away_score > home_score {
+        wins[away] += 1;
+    }
+}
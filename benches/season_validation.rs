@@ -0,0 +1,53 @@
+//! Benchmark for `LeagueSeasonRaw::validate` on a large season.
+//!
+//! Builds a 64-team season with a 64-week schedule (the minimum schedule
+//! length the validation accepts for 64 teams) and times validation of the
+//! raw season, as well as a full deserialize of the season from JSON.
+//!
+//! Run with `cargo bench --bench season_validation`.
+use std::hint::black_box;
+use std::time::Instant;
+
+use fbsim_core::league::season::{LeagueSeason, LeagueSeasonRaw, LeagueSeasonScheduleOptions};
+use fbsim_core::team::FootballTeam;
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+
+const NUM_TEAMS: usize = 64;
+const NUM_WEEKS: usize = 64;
+const ITERATIONS: u32 = 1000;
+
+fn main() {
+    // Create the season and generate its schedule
+    let mut season = LeagueSeason::with_year(2026);
+    for id in 0..NUM_TEAMS {
+        season.add_team(id, FootballTeam::new()).unwrap();
+    }
+    let mut options = LeagueSeasonScheduleOptions::new();
+    options.weeks = Some(NUM_WEEKS);
+    let mut rng = SmallRng::seed_from_u64(0);
+    season.generate_schedule(options, &mut rng).unwrap();
+
+    // Serialize the season and deserialize it into its raw form
+    let json = serde_json::to_string(&season).unwrap();
+    let raw: LeagueSeasonRaw = serde_json::from_str(&json).unwrap();
+
+    // Time validation of the raw season
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(&raw).validate().unwrap();
+    }
+    let validate_elapsed = start.elapsed() / ITERATIONS;
+
+    // Time a full deserialize, including validation
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let _: LeagueSeason = serde_json::from_str(black_box(&json)).unwrap();
+    }
+    let deserialize_elapsed = start.elapsed() / ITERATIONS;
+
+    println!(
+        "{} teams, {} weeks: validate {:?}/iter, deserialize {:?}/iter",
+        NUM_TEAMS, NUM_WEEKS, validate_elapsed, deserialize_elapsed
+    );
+}
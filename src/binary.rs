@@ -0,0 +1,843 @@
+#![doc = include_str!("../docs/binary.md")]
+use serde::de::{self, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, VariantAccess, Visitor};
+use serde::ser::{self, Serialize};
+use serde::Deserialize;
+
+/// The deepest nesting of arrays, maps, and enum variants decoded before
+/// the input is rejected, matching `serde_json`
+const MAX_DEPTH: usize = 128;
+
+/// # `BinaryError` struct
+///
+/// The error raised while encoding or decoding a value, carrying its
+/// message
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct BinaryError(String);
+
+impl std::fmt::Display for BinaryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for BinaryError {}
+
+impl ser::Error for BinaryError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        BinaryError(msg.to_string())
+    }
+}
+
+impl de::Error for BinaryError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        BinaryError(msg.to_string())
+    }
+}
+
+/// Serialize a value to MessagePack bytes
+///
+/// ### Example
+/// ```
+/// use fbsim_core::binary;
+/// use fbsim_core::team::FootballTeam;
+///
+/// let my_team = FootballTeam::new();
+/// let bytes = binary::to_bytes(&my_team).unwrap();
+/// assert!(bytes.len() < serde_json::to_vec(&my_team).unwrap().len());
+/// ```
+pub fn to_bytes<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, String> {
+    let mut serializer = Serializer { output: Vec::new() };
+    value.serialize(&mut serializer).map_err(|e| format!("Failed to encode binary: {}", e))?;
+    Ok(serializer.output)
+}
+
+/// Deserialize a value from MessagePack bytes, through the same validation
+/// as deserializing it from JSON
+///
+/// ### Example
+/// ```
+/// use fbsim_core::binary;
+/// use fbsim_core::team::FootballTeam;
+///
+/// let my_team = FootballTeam::new();
+/// let bytes = binary::to_bytes(&my_team).unwrap();
+/// let decoded: FootballTeam = binary::from_bytes(&bytes).unwrap();
+/// assert_eq!(decoded, my_team);
+///
+/// // Trailing bytes are rejected
+/// let mut extra = bytes.clone();
+/// extra.push(0);
+/// assert!(binary::from_bytes::<FootballTeam>(&extra).is_err());
+///
+/// // So is input nested more than 128 levels deep
+/// let nested = [0x91; 1000];
+/// assert!(binary::from_bytes::<serde_json::Value>(&nested).is_err());
+/// ```
+pub fn from_bytes<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<T, String> {
+    let mut deserializer = Deserializer { input: bytes, pos: 0, depth: 0 };
+    let value = T::deserialize(&mut deserializer)
+        .map_err(|e| format!("Failed to decode binary at byte {}: {}", deserializer.pos, e))?;
+    if deserializer.pos != bytes.len() {
+        return Err(format!(
+            "Failed to decode binary: {} trailing bytes after the value",
+            bytes.len() - deserializer.pos
+        ));
+    }
+    Ok(value)
+}
+
+/// Writes MessagePack into a byte buffer
+struct Serializer {
+    output: Vec<u8>
+}
+
+impl Serializer {
+    fn write_u64(&mut self, v: u64) {
+        if v < 0x80 {
+            self.output.push(v as u8);
+        } else if v <= u8::MAX as u64 {
+            self.output.extend_from_slice(&[0xcc, v as u8]);
+        } else if v <= u16::MAX as u64 {
+            self.output.push(0xcd);
+            self.output.extend_from_slice(&(v as u16).to_be_bytes());
+        } else if v <= u32::MAX as u64 {
+            self.output.push(0xce);
+            self.output.extend_from_slice(&(v as u32).to_be_bytes());
+        } else {
+            self.output.push(0xcf);
+            self.output.extend_from_slice(&v.to_be_bytes());
+        }
+    }
+
+    fn write_i64(&mut self, v: i64) {
+        if v >= 0 {
+            self.write_u64(v as u64);
+        } else if v >= -32 {
+            self.output.push(v as u8);
+        } else if v >= i8::MIN as i64 {
+            self.output.extend_from_slice(&[0xd0, v as u8]);
+        } else if v >= i16::MIN as i64 {
+            self.output.push(0xd1);
+            self.output.extend_from_slice(&(v as i16).to_be_bytes());
+        } else if v >= i32::MIN as i64 {
+            self.output.push(0xd2);
+            self.output.extend_from_slice(&(v as i32).to_be_bytes());
+        } else {
+            self.output.push(0xd3);
+            self.output.extend_from_slice(&v.to_be_bytes());
+        }
+    }
+
+    fn write_str(&mut self, v: &str) {
+        let len = v.len();
+        if len < 32 {
+            self.output.push(0xa0 | len as u8);
+        } else if len <= u8::MAX as usize {
+            self.output.extend_from_slice(&[0xd9, len as u8]);
+        } else if len <= u16::MAX as usize {
+            self.output.push(0xda);
+            self.output.extend_from_slice(&(len as u16).to_be_bytes());
+        } else {
+            self.output.push(0xdb);
+            self.output.extend_from_slice(&(len as u32).to_be_bytes());
+        }
+        self.output.extend_from_slice(v.as_bytes());
+    }
+
+    fn write_bin(&mut self, v: &[u8]) {
+        let len = v.len();
+        if len <= u8::MAX as usize {
+            self.output.extend_from_slice(&[0xc4, len as u8]);
+        } else if len <= u16::MAX as usize {
+            self.output.push(0xc5);
+            self.output.extend_from_slice(&(len as u16).to_be_bytes());
+        } else {
+            self.output.push(0xc6);
+            self.output.extend_from_slice(&(len as u32).to_be_bytes());
+        }
+        self.output.extend_from_slice(v);
+    }
+
+    /// Encode the header of an array or map with a given number of elements
+    fn header(len: usize, map: bool) -> Vec<u8> {
+        let (fix, marker16, marker32) = if map { (0x80, 0xde, 0xdf) } else { (0x90, 0xdc, 0xdd) };
+        if len < 16 {
+            vec![fix | len as u8]
+        } else if len <= u16::MAX as usize {
+            let mut header = vec![marker16];
+            header.extend_from_slice(&(len as u16).to_be_bytes());
+            header
+        } else {
+            let mut header = vec![marker32];
+            header.extend_from_slice(&(len as u32).to_be_bytes());
+            header
+        }
+    }
+
+    /// Begin an array or map. The header is written from the expected
+    /// length if known, and corrected once the elements are written if the
+    /// count differs.
+    fn begin(&mut self, len: Option<usize>, map: bool) -> Compound<'_> {
+        let start = self.output.len();
+        let expected = len.unwrap_or(usize::MAX);
+        if let Some(len) = len {
+            self.output.extend_from_slice(&Serializer::header(len, map));
+        }
+        Compound { ser: self, start, expected, count: 0, map }
+    }
+}
+
+/// An array or map being written
+struct Compound<'a> {
+    ser: &'a mut Serializer,
+    start: usize,
+    expected: usize,
+    count: usize,
+    map: bool
+}
+
+impl Compound<'_> {
+    fn end(self) -> Result<(), BinaryError> {
+        if self.count != self.expected {
+            let written = if self.expected == usize::MAX {
+                0
+            } else {
+                Serializer::header(self.expected, self.map).len()
+            };
+            let header = Serializer::header(self.count, self.map);
+            self.ser.output.splice(self.start..self.start + written, header);
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ser::Serializer for &'a mut Serializer {
+    type Ok = ();
+    type Error = BinaryError;
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    fn serialize_bool(self, v: bool) -> Result<(), BinaryError> {
+        self.output.push(if v { 0xc3 } else { 0xc2 });
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), BinaryError> {
+        self.write_i64(v as i64);
+        Ok(())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), BinaryError> {
+        self.write_i64(v as i64);
+        Ok(())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), BinaryError> {
+        self.write_i64(v as i64);
+        Ok(())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), BinaryError> {
+        self.write_i64(v);
+        Ok(())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<(), BinaryError> {
+        match i64::try_from(v) {
+            Ok(v) => self.write_i64(v),
+            Err(_) => self.write_bin(&v.to_be_bytes())
+        }
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), BinaryError> {
+        self.write_u64(v as u64);
+        Ok(())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), BinaryError> {
+        self.write_u64(v as u64);
+        Ok(())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), BinaryError> {
+        self.write_u64(v as u64);
+        Ok(())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), BinaryError> {
+        self.write_u64(v);
+        Ok(())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<(), BinaryError> {
+        match u64::try_from(v) {
+            Ok(v) => self.write_u64(v),
+            Err(_) => self.write_bin(&v.to_be_bytes())
+        }
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), BinaryError> {
+        self.output.push(0xca);
+        self.output.extend_from_slice(&v.to_be_bytes());
+        Ok(())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), BinaryError> {
+        self.output.push(0xcb);
+        self.output.extend_from_slice(&v.to_be_bytes());
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), BinaryError> {
+        self.write_str(v.encode_utf8(&mut [0; 4]));
+        Ok(())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), BinaryError> {
+        self.write_str(v);
+        Ok(())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), BinaryError> {
+        self.write_bin(v);
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<(), BinaryError> {
+        self.output.push(0xc0);
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), BinaryError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), BinaryError> {
+        self.output.push(0xc0);
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), BinaryError> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> Result<(), BinaryError> {
+        self.write_str(variant);
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<(), BinaryError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(self, _name: &'static str, _index: u32, variant: &'static str, value: &T) -> Result<(), BinaryError> {
+        self.output.push(0x81);
+        self.write_str(variant);
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Compound<'a>, BinaryError> {
+        Ok(self.begin(len, false))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Compound<'a>, BinaryError> {
+        Ok(self.begin(Some(len), false))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<Compound<'a>, BinaryError> {
+        Ok(self.begin(Some(len), false))
+    }
+
+    fn serialize_tuple_variant(self, _name: &'static str, _index: u32, variant: &'static str, len: usize) -> Result<Compound<'a>, BinaryError> {
+        self.output.push(0x81);
+        self.write_str(variant);
+        Ok(self.begin(Some(len), false))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Compound<'a>, BinaryError> {
+        Ok(self.begin(len, true))
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Compound<'a>, BinaryError> {
+        Ok(self.begin(Some(len), true))
+    }
+
+    fn serialize_struct_variant(self, _name: &'static str, _index: u32, variant: &'static str, len: usize) -> Result<Compound<'a>, BinaryError> {
+        self.output.push(0x81);
+        self.write_str(variant);
+        Ok(self.begin(Some(len), true))
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = BinaryError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), BinaryError> {
+        self.count += 1;
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<(), BinaryError> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = BinaryError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), BinaryError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<(), BinaryError> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = BinaryError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), BinaryError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<(), BinaryError> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = BinaryError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), BinaryError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<(), BinaryError> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = BinaryError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), BinaryError> {
+        self.count += 1;
+        key.serialize(&mut *self.ser)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), BinaryError> {
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<(), BinaryError> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = BinaryError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), BinaryError> {
+        self.count += 1;
+        self.ser.write_str(key);
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<(), BinaryError> {
+        Compound::end(self)
+    }
+}
+
+impl ser::SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = BinaryError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), BinaryError> {
+        ser::SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<(), BinaryError> {
+        Compound::end(self)
+    }
+}
+
+/// Reads MessagePack from a byte slice
+struct Deserializer<'de> {
+    input: &'de [u8],
+    pos: usize,
+    /// The number of arrays, maps, and enum variants being read
+    depth: usize
+}
+
+impl<'de> Deserializer<'de> {
+    /// Read the content of an array, map, or enum variant one level deeper,
+    /// failing rather than overflowing the stack on deeply nested input
+    fn nested<T>(&mut self, visit: impl FnOnce(&mut Self) -> Result<T, BinaryError>) -> Result<T, BinaryError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(BinaryError(format!("nesting exceeds the maximum depth of {}", MAX_DEPTH)));
+        }
+        self.depth += 1;
+        let value = visit(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn peek(&self) -> Result<u8, BinaryError> {
+        self.input.get(self.pos).copied()
+            .ok_or_else(|| BinaryError(String::from("unexpected end of input")))
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'de [u8], BinaryError> {
+        let end = self.pos.checked_add(len)
+            .filter(|end| *end <= self.input.len())
+            .ok_or_else(|| BinaryError(String::from("unexpected end of input")))?;
+        let bytes = &self.input[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], BinaryError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    /// Read the length following a marker, in the given number of bytes
+    fn take_len(&mut self, width: usize) -> Result<usize, BinaryError> {
+        Ok(match width {
+            1 => self.take_array::<1>()?[0] as usize,
+            2 => u16::from_be_bytes(self.take_array()?) as usize,
+            _ => u32::from_be_bytes(self.take_array()?) as usize
+        })
+    }
+
+    fn take_str(&mut self, len: usize) -> Result<&'de str, BinaryError> {
+        std::str::from_utf8(self.take(len)?)
+            .map_err(|e| BinaryError(format!("invalid UTF-8 string: {}", e)))
+    }
+
+    /// Read a string if one is next, for enum variant names
+    fn try_str(&mut self) -> Result<Option<&'de str>, BinaryError> {
+        let marker = self.peek()?;
+        let width = match marker {
+            0xa0..=0xbf => 0,
+            0xd9 => 1,
+            0xda => 2,
+            0xdb => 4,
+            _ => return Ok(None)
+        };
+        self.pos += 1;
+        let len = if width == 0 { (marker & 0x1f) as usize } else { self.take_len(width)? };
+        self.take_str(len).map(Some)
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
+    type Error = BinaryError;
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BinaryError> {
+        let marker = self.peek()?;
+        self.pos += 1;
+        match marker {
+            0x00..=0x7f => visitor.visit_u64(marker as u64),
+            0x80..=0x8f => self.nested(|de| visitor.visit_map(Elements { de, remaining: (marker & 0x0f) as usize })),
+            0x90..=0x9f => self.nested(|de| visitor.visit_seq(Elements { de, remaining: (marker & 0x0f) as usize })),
+            0xa0..=0xbf => visitor.visit_borrowed_str(self.take_str((marker & 0x1f) as usize)?),
+            0xc0 => visitor.visit_unit(),
+            0xc2 => visitor.visit_bool(false),
+            0xc3 => visitor.visit_bool(true),
+            0xc4..=0xc6 => {
+                let len = self.take_len(1 << (marker - 0xc4))?;
+                visitor.visit_borrowed_bytes(self.take(len)?)
+            },
+            0xca => visitor.visit_f32(f32::from_be_bytes(self.take_array()?)),
+            0xcb => visitor.visit_f64(f64::from_be_bytes(self.take_array()?)),
+            0xcc => visitor.visit_u64(self.take_array::<1>()?[0] as u64),
+            0xcd => visitor.visit_u64(u16::from_be_bytes(self.take_array()?) as u64),
+            0xce => visitor.visit_u64(u32::from_be_bytes(self.take_array()?) as u64),
+            0xcf => visitor.visit_u64(u64::from_be_bytes(self.take_array()?)),
+            0xd0 => visitor.visit_i64(i8::from_be_bytes(self.take_array()?) as i64),
+            0xd1 => visitor.visit_i64(i16::from_be_bytes(self.take_array()?) as i64),
+            0xd2 => visitor.visit_i64(i32::from_be_bytes(self.take_array()?) as i64),
+            0xd3 => visitor.visit_i64(i64::from_be_bytes(self.take_array()?)),
+            0xd9..=0xdb => {
+                let len = self.take_len(1 << (marker - 0xd9))?;
+                visitor.visit_borrowed_str(self.take_str(len)?)
+            },
+            0xdc | 0xdd => {
+                let remaining = self.take_len(2 << (marker - 0xdc))?;
+                self.nested(|de| visitor.visit_seq(Elements { de, remaining }))
+            },
+            0xde | 0xdf => {
+                let remaining = self.take_len(2 << (marker - 0xde))?;
+                self.nested(|de| visitor.visit_map(Elements { de, remaining }))
+            },
+            0xe0..=0xff => visitor.visit_i64(marker as i8 as i64),
+            _ => Err(BinaryError(format!("unsupported marker 0x{:02x}", marker)))
+        }
+    }
+
+    fn deserialize_i128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BinaryError> {
+        if self.peek()? == 0xc4 && self.input.get(self.pos + 1) == Some(&16) {
+            self.pos += 2;
+            return visitor.visit_i128(i128::from_be_bytes(self.take_array()?));
+        }
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_u128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BinaryError> {
+        if self.peek()? == 0xc4 && self.input.get(self.pos + 1) == Some(&16) {
+            self.pos += 2;
+            return visitor.visit_u128(u128::from_be_bytes(self.take_array()?));
+        }
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BinaryError> {
+        if self.peek()? == 0xc0 {
+            self.pos += 1;
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, BinaryError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str], visitor: V) -> Result<V::Value, BinaryError> {
+        // A unit variant is its name, any other variant a map from its name
+        // to its content
+        if let Some(variant) = self.try_str()? {
+            return visitor.visit_enum(variant.into_deserializer());
+        }
+        if self.peek()? != 0x81 {
+            return Err(BinaryError(String::from("expected an enum variant name or a single-entry map")));
+        }
+        self.pos += 1;
+        self.nested(|de| visitor.visit_enum(Variant { de }))
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BinaryError> {
+        self.deserialize_any(de::IgnoredAny)?;
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes
+        byte_buf unit unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
+
+/// The elements of an array or map being read
+struct Elements<'a, 'de> {
+    de: &'a mut Deserializer<'de>,
+    remaining: usize
+}
+
+impl<'de> SeqAccess<'de> for Elements<'_, 'de> {
+    type Error = BinaryError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, BinaryError> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        // Bound the hint by the bytes left so a corrupt length cannot
+        // reserve an enormous buffer
+        Some(self.remaining.min(self.de.input.len() - self.de.pos))
+    }
+}
+
+impl<'de> MapAccess<'de> for Elements<'_, 'de> {
+    type Error = BinaryError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, BinaryError> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, BinaryError> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining.min(self.de.input.len() - self.de.pos))
+    }
+}
+
+/// An enum variant with content, read from a single-entry map
+struct Variant<'a, 'de> {
+    de: &'a mut Deserializer<'de>
+}
+
+impl<'a, 'de> EnumAccess<'de> for Variant<'a, 'de> {
+    type Error = BinaryError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self), BinaryError> {
+        let variant = seed.deserialize(&mut *self.de)?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for Variant<'_, 'de> {
+    type Error = BinaryError;
+
+    fn unit_variant(self) -> Result<(), BinaryError> {
+        de::Deserialize::deserialize(self.de)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, BinaryError> {
+        seed.deserialize(self.de)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, BinaryError> {
+        de::Deserializer::deserialize_any(self.de, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(self, _fields: &'static [&'static str], visitor: V) -> Result<V::Value, BinaryError> {
+        de::Deserializer::deserialize_any(self.de, visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+    enum Shape {
+        Empty,
+        Square(u32),
+        Rect(u32, u32),
+        Named { name: String, sides: Option<u8> }
+    }
+
+    #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+    #[serde(tag = "type", content = "data")]
+    enum Tagged {
+        Point(i32),
+        Line { length: f64 }
+    }
+
+    #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+    struct Sample {
+        small: u8,
+        wide: u64,
+        negative: i64,
+        huge: u128,
+        ratio: f64,
+        text: String,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        skipped: Vec<u32>,
+        keyed: BTreeMap<usize, Shape>,
+        tagged: Vec<Tagged>,
+        nothing: Option<u32>,
+        unit: ()
+    }
+
+    #[test]
+    fn test_binary_round_trips_every_shape() {
+        let long = "x".repeat(300);
+        let mut keyed = BTreeMap::new();
+        keyed.insert(0, Shape::Empty);
+        keyed.insert(7, Shape::Square(4));
+        keyed.insert(300, Shape::Rect(2, 70_000));
+        keyed.insert(70_000, Shape::Named { name: long.clone(), sides: Some(3) });
+        let sample = Sample {
+            small: 200,
+            wide: u64::MAX,
+            negative: -40_000,
+            huge: u128::MAX - 1,
+            ratio: -0.25,
+            text: String::from("fbsim"),
+            skipped: Vec::new(),
+            keyed,
+            tagged: (0..20).map(|i| if i % 2 == 0 { Tagged::Point(-i) } else { Tagged::Line { length: i as f64 } }).collect(),
+            nothing: None,
+            unit: ()
+        };
+        let bytes = to_bytes(&sample).unwrap();
+        assert_eq!(from_bytes::<Sample>(&bytes).unwrap(), sample);
+
+        // The format is self-describing, so it decodes to JSON values as well
+        let narrow = Sample { huge: 12, keyed: BTreeMap::new(), ..sample.clone() };
+        let value: serde_json::Value = from_bytes(&to_bytes(&narrow).unwrap()).unwrap();
+        assert_eq!(value["text"], "fbsim");
+        assert!(value.get("skipped").is_none());
+        assert_eq!(value["tagged"].as_array().unwrap().len(), 20);
+
+        // Truncated input and unknown markers are errors rather than panics
+        for len in 0..bytes.len() {
+            assert!(from_bytes::<Sample>(&bytes[..len]).is_err());
+        }
+        assert!(from_bytes::<Sample>(&[0xc1]).is_err());
+        assert!(from_bytes::<Vec<u32>>(&[0xdd, 0xff, 0xff, 0xff, 0xff]).is_err());
+    }
+
+    #[test]
+    fn test_binary_rejects_deep_nesting() {
+        use crate::team::FootballTeam;
+
+        // Nesting up to the limit decodes, one level more is an error
+        let mut at_limit = vec![0x91; MAX_DEPTH - 1];
+        at_limit.push(0x90);
+        assert!(from_bytes::<serde_json::Value>(&at_limit).is_ok());
+        let mut past_limit = vec![0x91; MAX_DEPTH];
+        past_limit.push(0x90);
+        let err = from_bytes::<serde_json::Value>(&past_limit).unwrap_err();
+        assert!(err.contains("maximum depth"), "{}", err);
+
+        // Deeply nested arrays as the value of an unknown field are an
+        // error rather than a stack overflow
+        let mut bytes = to_bytes(&FootballTeam::new()).unwrap();
+        let fields = bytes[0] & 0x0f;
+        bytes[0] = 0x80 | (fields + 1);
+        bytes.push(0xa7);
+        bytes.extend_from_slice(b"unknown");
+        bytes.extend(std::iter::repeat_n(0x91, 100_000));
+        bytes.push(0x90);
+        let err = from_bytes::<FootballTeam>(&bytes).unwrap_err();
+        assert!(err.contains("maximum depth"), "{}", err);
+
+        // Deeply nested enum variants are limited as well
+        let mut variants = Vec::new();
+        for _ in 0..100_000 {
+            variants.extend_from_slice(&[0x81, 0xa4]);
+            variants.extend_from_slice(b"Some");
+        }
+        assert!(from_bytes::<serde_json::Value>(&variants).is_err());
+    }
+}
@@ -0,0 +1,41 @@
+#![doc = include_str!("../docs/features.md")]
+
+/// Whether the `binary` feature is enabled, which adds the `binary` module
+/// for serializing to and from MessagePack bytes
+pub const BINARY: bool = cfg!(feature = "binary");
+
+/// Whether the `chrono` feature is enabled, which defaults season years to
+/// the current year
+pub const CHRONO: bool = cfg!(feature = "chrono");
+
+/// Whether the `export` feature is enabled, which adds exporting a season's
+/// schedule to CSV and iCalendar
+pub const EXPORT: bool = cfg!(feature = "export");
+
+/// Whether the `rocket_okapi` feature is enabled, which derives OpenAPI
+/// schemas for the library's types
+pub const ROCKET_OKAPI: bool = cfg!(feature = "rocket_okapi");
+
+/// Whether the `wasm` feature is enabled, which adds the `wasm` module and
+/// TypeScript bindings for the library's types
+pub const WASM: bool = cfg!(feature = "wasm");
+
+/// Get the names of the optional features this build of the library was
+/// compiled with
+///
+/// ### Example
+/// ```
+/// use fbsim_core::features;
+///
+/// let enabled = features::enabled();
+/// assert_eq!(enabled.contains(&"binary"), features::BINARY);
+/// assert_eq!(enabled.contains(&"chrono"), features::CHRONO);
+/// assert_eq!(enabled.contains(&"export"), features::EXPORT);
+/// assert_eq!(enabled.contains(&"wasm"), features::WASM);
+/// ```
+pub fn enabled() -> Vec<&'static str> {
+    [("binary", BINARY), ("chrono", CHRONO), ("export", EXPORT), ("rocket_okapi", ROCKET_OKAPI), ("wasm", WASM)].into_iter()
+        .filter(|(_, on)| *on)
+        .map(|(name, _)| name)
+        .collect()
+}
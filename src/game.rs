@@ -1,5 +1,6 @@
 #![doc = include_str!("../docs/game.md")]
 pub mod context;
+pub mod error;
 pub mod matchup;
 pub mod play;
 pub mod score;
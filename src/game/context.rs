@@ -1,4 +1,9 @@
 #![doc = include_str!("../../docs/game/context.md")]
+pub mod clock;
+pub mod overtime;
+pub mod weather;
+
+use rand::Rng;
 #[cfg(feature = "rocket_okapi")]
 use rocket_okapi::okapi::schemars;
 #[cfg(feature = "rocket_okapi")]
@@ -7,13 +12,56 @@ use serde::{Deserialize, Deserializer, Serialize};
 #[cfg(feature = "wasm")]
 use tsify_next::Tsify;
 
+use crate::game::context::clock::ClockRules;
+use crate::game::context::weather::Weather;
+use crate::game::context::overtime::{OvertimeMode, OvertimeRules};
+use crate::game::error::GameError;
 use crate::game::play::context::PlayContext;
 use crate::game::play::result::{ScoreResult, PlayResult};
 
+/// # `GameAlreadyOver` struct
+///
+/// A `GameAlreadyOver` is the error produced when attempting to simulate a
+/// play, drive, or game from a terminal `GameContext`
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub struct GameAlreadyOver;
+
+impl std::fmt::Display for GameAlreadyOver {
+    /// Format a `GameAlreadyOver` error as a string
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameAlreadyOver;
+    ///
+    /// assert_eq!(GameAlreadyOver.to_string(), "Game is already over");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Game is already over")
+    }
+}
+
+impl std::error::Error for GameAlreadyOver {}
+
+impl From<GameAlreadyOver> for String {
+    /// Convert a `GameAlreadyOver` error into its string message
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameAlreadyOver;
+    ///
+    /// let error: String = GameAlreadyOver.into();
+    /// assert_eq!(error, "Game is already over");
+    /// ```
+    fn from(item: GameAlreadyOver) -> Self {
+        item.to_string()
+    }
+}
+
 /// # `GameContextRaw` struct
 ///
 /// A `GameContextRaw` is a `GameContext` before its properties have been
-/// validated
+/// validated. Boolean properties default to false when absent, matching the
+/// sparse form in which `GameContext` omits them.
 #[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
 pub struct GameContextRaw {
@@ -28,20 +76,91 @@ pub struct GameContextRaw {
     away_score: u32,
     home_timeouts: u32,
     away_timeouts: u32,
+    #[serde(default)]
     home_positive_direction: bool,
+    #[serde(default)]
     home_opening_kickoff: bool,
+    #[serde(default)]
     home_possession: bool,
+    #[serde(default)]
     last_play_turnover: bool,
+    #[serde(default)]
     last_play_incomplete: bool,
+    #[serde(default)]
     last_play_out_of_bounds: bool,
+    #[serde(default)]
     last_play_timeout: bool,
+    #[serde(default)]
     last_play_kickoff: bool,
+    #[serde(default)]
     last_play_punt: bool,
+    #[serde(default)]
     next_play_extra_point: bool,
+    #[serde(default)]
     next_play_kickoff: bool,
+    #[serde(default)]
     neutral_site: bool,
+    #[serde(default)]
     end_of_half: bool,
-    game_over: bool
+    #[serde(default)]
+    game_over: bool,
+    #[serde(default)]
+    overtime_rules: OvertimeRules,
+    #[serde(default)]
+    clock_rules: ClockRules,
+    #[serde(default)]
+    weather: Weather,
+    #[serde(default)]
+    home_overtime_possession: bool,
+    #[serde(default)]
+    away_overtime_possession: bool
+}
+
+impl Default for GameContextRaw {
+    /// Default constructor for the GameContextRaw class, matching the
+    /// default `GameContext`
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextRaw;
+    ///
+    /// let my_context_raw = GameContextRaw::default();
+    /// assert!(my_context_raw.validate().is_ok());
+    /// ```
+    fn default() -> Self {
+        GameContextRaw {
+            home_team_short: String::from("HOME"),
+            away_team_short: String::from("AWAY"),
+            quarter: 1,
+            half_seconds: 1800,
+            down: 0,
+            distance: 10,
+            yard_line: 35,
+            home_score: 0,
+            away_score: 0,
+            home_timeouts: 3,
+            away_timeouts: 3,
+            home_positive_direction: true,
+            home_opening_kickoff: false,
+            home_possession: true,
+            last_play_turnover: false,
+            last_play_incomplete: false,
+            last_play_out_of_bounds: false,
+            last_play_timeout: false,
+            last_play_kickoff: false,
+            last_play_punt: false,
+            next_play_extra_point: false,
+            next_play_kickoff: true,
+            neutral_site: false,
+            end_of_half: false,
+            game_over: false,
+            overtime_rules: OvertimeRules::default(),
+            clock_rules: ClockRules::default(),
+            weather: Weather::default(),
+            home_overtime_possession: false,
+            away_overtime_possession: false
+        }
+    }
 }
 
 impl GameContextRaw {
@@ -64,32 +183,29 @@ impl GameContextRaw {
             )
         }
 
-        // Ensure half seconds is no greater than 1800 (15 mins)
-        if self.half_seconds > 1800 {
-            return Err(
-                format!(
-                    "Half seconds is not in range [0, 1800]: {}",
-                    self.half_seconds
-                )
-            )
-        }
+        // Ensure the clock rules are valid
+        self.clock_rules.validate()?;
+        let rules = &self.clock_rules;
 
-        // Ensure half seconds is not less than 900 if quarter is odd and less than 4
-        if self.half_seconds < 900 && self.quarter % 2 == 1 && self.quarter < 4 {
+        // Ensure half seconds is no greater than the length of a half
+        if self.half_seconds > rules.half_length() {
             return Err(
                 format!(
-                    "Half seconds is not in range [900, 1800] for quarter {}: {}",
-                    self.quarter,
+                    "Half seconds is not in range [0, {}]: {}",
+                    rules.half_length(),
                     self.half_seconds
                 )
             )
         }
 
-        // Ensure half seconds is not greater than 900 if quarter is even or greater than 4
-        if self.half_seconds > 900 && (self.quarter.is_multiple_of(2) || self.quarter > 4) {
+        // Ensure half seconds is within the quarter
+        let (quarter_end, quarter_start) = (rules.quarter_end(self.quarter), rules.quarter_start(self.quarter));
+        if self.half_seconds < quarter_end || self.half_seconds > quarter_start {
             return Err(
                 format!(
-                    "Half seconds is not in range [0, 900] for quarter {}: {}",
+                    "Half seconds is not in range [{}, {}] for quarter {}: {}",
+                    quarter_end,
+                    quarter_start,
                     self.quarter,
                     self.half_seconds
                 )
@@ -179,8 +295,10 @@ impl GameContextRaw {
             )
         }
 
-        // Ensure half is not over if quarter is odd and less than 4
-        if self.end_of_half && (self.quarter == 1 || (self.quarter == 3 && self.half_seconds < 1800)) {
+        // Ensure half is not over before the last quarter of either half,
+        // other than at the start of the second half
+        let second_half_start = self.quarter == rules.quarters_per_half() + 1 && self.half_seconds == rules.half_length();
+        if self.end_of_half && !rules.ends_half(self.quarter) && !second_half_start {
             return Err(
                 format!(
                     "Cannot end half during quarter: {}",
@@ -190,7 +308,7 @@ impl GameContextRaw {
         }
 
         // Ensure half is not over if there is still time left
-        if self.end_of_half && self.half_seconds != 1800 && self.half_seconds != 600 && self.half_seconds > 0 {
+        if self.end_of_half && self.half_seconds != rules.half_length() && self.half_seconds != rules.overtime_seconds && self.half_seconds > 0 {
             return Err(
                 format!(
                     "End of half but nonzero half seconds: {}",
@@ -199,8 +317,8 @@ impl GameContextRaw {
             )
         }
 
-        // Ensure game is not over if quarter is less than 4
-        if self.game_over && self.quarter < 4 {
+        // Ensure game is not over before the last quarter
+        if self.game_over && !rules.final_quarter(self.quarter) {
             return Err(
                 format!(
                     "Cannot end game during quarter: {}",
@@ -209,8 +327,9 @@ impl GameContextRaw {
             )
         }
 
-        // Ensure game is not over if there is still time left
-        if self.game_over && self.half_seconds > 0 {
+        // Ensure game is not over if there is still time left, unless it was
+        // decided by a sudden death overtime score
+        if self.game_over && self.half_seconds > 0 && !(rules.overtime(self.quarter) && self.overtime_rules.sudden_death()) {
             return Err(
                 format!(
                     "End of game but nonzero half seconds: {}",
@@ -218,6 +337,16 @@ impl GameContextRaw {
                 )
             )
         }
+
+        // Ensure overtime possessions are only recorded in overtime
+        if (self.home_overtime_possession || self.away_overtime_possession) && !rules.overtime(self.quarter) {
+            return Err(
+                format!(
+                    "Overtime possession recorded during quarter: {}",
+                    self.quarter
+                )
+            )
+        }
         Ok(())
     }
 }
@@ -226,7 +355,10 @@ impl GameContextRaw {
 ///
 /// A `GameContextUpdateOptions` contains the parameters required to derive
 /// the next game context
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default)]
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
 pub struct GameContextUpdateOptions {
     pub duration: u32,
     pub net_yards: i32,
@@ -242,9 +374,127 @@ pub struct GameContextUpdateOptions {
     pub end_of_game: bool
 }
 
+/// # `PlayOutcome` struct
+///
+/// A `PlayOutcome` gathers every property of a play's result which
+/// determines the next game context. It is the input to
+/// `GameContext::transition`, and can be built from any `PlayResult`.
+/// Penalty enforcement may additionally replay the down or award an
+/// automatic first down, which a `PlayResult` otherwise only does when the
+/// kicking team recovers a punt.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct PlayOutcome {
+    pub duration: u32,
+    pub net_yards: i32,
+    pub off_score: ScoreResult,
+    pub def_score: ScoreResult,
+    pub turnover: bool,
+    pub touchback: bool,
+    pub incomplete: bool,
+    pub out_of_bounds: bool,
+    pub kickoff: bool,
+    pub punt: bool,
+    pub off_timeout: bool,
+    pub def_timeout: bool,
+    pub next_play_extra_point: bool,
+    pub next_play_kickoff: bool,
+    #[serde(default)]
+    pub replay_down: bool,
+    #[serde(default)]
+    pub automatic_first_down: bool
+}
+
+impl PlayOutcome {
+    /// Initialize a new play outcome
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::PlayOutcome;
+    ///
+    /// let my_outcome = PlayOutcome::new();
+    /// assert!(my_outcome.duration == 0);
+    /// ```
+    pub fn new() -> PlayOutcome {
+        PlayOutcome::default()
+    }
+
+    /// Collect the outcome of a play from its result
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::PlayOutcome;
+    /// use fbsim_core::game::play::result::run::RunResultBuilder;
+    ///
+    /// let my_res = RunResultBuilder::new()
+    ///     .yards_gained(4)
+    ///     .play_duration(6)
+    ///     .build()
+    ///     .unwrap();
+    /// let my_outcome = PlayOutcome::from_result(&my_res);
+    /// assert!(my_outcome.net_yards == 4);
+    /// assert!(my_outcome.duration == 6);
+    /// ```
+    pub fn from_result(result: &(impl PlayResult + ?Sized)) -> PlayOutcome {
+        PlayOutcome{
+            duration: result.play_duration(),
+            net_yards: result.net_yards(),
+            off_score: result.offense_score(),
+            def_score: result.defense_score(),
+            turnover: result.turnover(),
+            touchback: result.touchback(),
+            incomplete: result.incomplete(),
+            out_of_bounds: result.out_of_bounds(),
+            kickoff: result.kickoff(),
+            punt: result.punt(),
+            off_timeout: result.offense_timeout(),
+            def_timeout: result.defense_timeout(),
+            next_play_extra_point: result.next_play_extra_point(),
+            next_play_kickoff: result.next_play_kickoff(),
+            replay_down: false,
+            automatic_first_down: false
+        }
+    }
+
+    /// Get the update options passed to the fine-grained `next_*` methods
+    /// of `GameContext`
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::{GameContext, PlayOutcome};
+    ///
+    /// let mut my_outcome = PlayOutcome::new();
+    /// my_outcome.net_yards = 4;
+    /// my_outcome.duration = 6;
+    ///
+    /// let my_context = GameContext::new();
+    /// let update_opts = my_outcome.update_options();
+    /// assert!(my_context.next_half_seconds(&update_opts) == 1794);
+    /// ```
+    pub fn update_options(&self) -> GameContextUpdateOptions {
+        GameContextUpdateOptions{
+            duration: self.duration,
+            net_yards: self.net_yards,
+            off_score: self.off_score,
+            def_score: self.def_score,
+            turnover: self.turnover,
+            touchback: self.touchback,
+            kickoff_oob: self.kickoff && self.out_of_bounds,
+            off_timeout: self.off_timeout,
+            def_timeout: self.def_timeout,
+            next_play_extra_point: self.next_play_extra_point,
+            between_play: false,
+            end_of_game: false
+        }
+    }
+}
+
 /// # `GameContext` struct
 ///
-/// A `GameContext` represents a game scenario
+/// A `GameContext` represents a game scenario. Boolean properties that are
+/// false are omitted when serialized.
 #[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
 #[cfg_attr(feature = "wasm", derive(Tsify))]
 #[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
@@ -261,20 +511,44 @@ pub struct GameContext {
     away_score: u32,
     home_timeouts: u32,
     away_timeouts: u32,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
     home_positive_direction: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
     home_opening_kickoff: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
     home_possession: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
     last_play_turnover: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
     last_play_incomplete: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
     last_play_out_of_bounds: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
     last_play_timeout: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
     last_play_kickoff: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
     last_play_punt: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
     next_play_extra_point: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
     next_play_kickoff: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
     neutral_site: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
     end_of_half: bool,
-    game_over: bool
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    game_over: bool,
+    #[serde(skip_serializing_if = "OvertimeRules::is_default")]
+    overtime_rules: OvertimeRules,
+    #[serde(skip_serializing_if = "ClockRules::is_default")]
+    clock_rules: ClockRules,
+    #[serde(skip_serializing_if = "Weather::is_clear")]
+    weather: Weather,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    home_overtime_possession: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    away_overtime_possession: bool
 }
 
 impl Default for GameContext {
@@ -285,6 +559,10 @@ impl Default for GameContext {
     /// use fbsim_core::game::context::GameContext;
     /// 
     /// let my_context = GameContext::default();
+    ///
+    /// // The default passes validation
+    /// let json = serde_json::to_string(&my_context).unwrap();
+    /// assert!(serde_json::from_str::<GameContext>(&json).is_ok());
     /// ```
     fn default() -> Self {
         GameContext {
@@ -300,7 +578,7 @@ impl Default for GameContext {
             home_timeouts: 3,
             away_timeouts: 3,
             home_positive_direction: true,
-            home_opening_kickoff: true,
+            home_opening_kickoff: false,
             home_possession: true,
             last_play_turnover: false,
             last_play_incomplete: false,
@@ -312,7 +590,12 @@ impl Default for GameContext {
             next_play_kickoff: true,
             neutral_site: false,
             end_of_half: false,
-            game_over: false
+            game_over: false,
+            overtime_rules: OvertimeRules::default(),
+            clock_rules: ClockRules::default(),
+            weather: Weather::default(),
+            home_overtime_possession: false,
+            away_overtime_possession: false
         }
     }
 }
@@ -354,7 +637,12 @@ impl TryFrom<GameContextRaw> for GameContext {
                 next_play_kickoff: item.next_play_kickoff,
                 neutral_site: item.neutral_site,
                 end_of_half: item.end_of_half,
-                game_over: item.game_over
+                game_over: item.game_over,
+                overtime_rules: item.overtime_rules,
+                clock_rules: item.clock_rules,
+                weather: item.weather,
+                home_overtime_possession: item.home_overtime_possession,
+                away_overtime_possession: item.away_overtime_possession
             }
         )
     }
@@ -384,6 +672,46 @@ impl GameContext {
         GameContext::default()
     }
 
+    /// Constructor for the GameContext class at the opening kickoff, given
+    /// the team short names and whether the home team receives.  The
+    /// receiving team's opponent possesses the ball for the kickoff, moving
+    /// in the positive direction, and receives the second half kickoff.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    ///
+    /// let my_context = GameContext::with_opening_kickoff("HOME", "AWAY", true).unwrap();
+    /// assert!(my_context.home_opening_kickoff());
+    /// assert!(!my_context.home_possession());
+    /// assert!(my_context.next_play_kickoff());
+    /// ```
+    pub fn with_opening_kickoff(home_team_short: &str, away_team_short: &str, home_receives: bool) -> Result<GameContext, String> {
+        GameContextBuilder::new()
+            .home_team_short(home_team_short)
+            .away_team_short(away_team_short)
+            .home_possession(!home_receives)
+            .home_positive_direction(!home_receives)
+            .home_opening_kickoff(home_receives)
+            .build()
+    }
+
+    /// Constructor for the GameContext class at the opening kickoff, given
+    /// the team short names, where a coin toss decides which team receives
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let my_context = GameContext::with_coin_toss("HOME", "AWAY", &mut rng).unwrap();
+    /// assert_eq!(my_context.home_possession(), !my_context.home_opening_kickoff());
+    /// ```
+    pub fn with_coin_toss(home_team_short: &str, away_team_short: &str, rng: &mut impl Rng) -> Result<GameContext, String> {
+        let home_receives: bool = rng.gen::<bool>();
+        GameContext::with_opening_kickoff(home_team_short, away_team_short, home_receives)
+    }
+
     /// Borrow the GameContext home team short property
     ///
     /// ### Example
@@ -566,7 +894,8 @@ impl GameContext {
         self.home_positive_direction
     }
 
-    /// Borrow the GameContext home_opening_kickoff property
+    /// Borrow the GameContext home_opening_kickoff property, which is true
+    /// if the home team received the opening kickoff
     ///
     /// ### Example
     /// ```
@@ -574,7 +903,7 @@ impl GameContext {
     /// 
     /// let my_context = GameContext::new();
     /// let home_opening_kickoff = my_context.home_opening_kickoff();
-    /// assert!(home_opening_kickoff);
+    /// assert!(!home_opening_kickoff);
     /// ```
     pub fn home_opening_kickoff(&self) -> bool {
         self.home_opening_kickoff
@@ -734,6 +1063,163 @@ impl GameContext {
         self.game_over
     }
 
+    /// Borrow the GameContext overtime_rules property
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::context::overtime::OvertimeRules;
+    ///
+    /// let my_context = GameContext::new();
+    /// assert_eq!(*my_context.overtime_rules(), OvertimeRules::new());
+    /// ```
+    pub fn overtime_rules(&self) -> &OvertimeRules {
+        &self.overtime_rules
+    }
+
+    /// Borrow the GameContext clock_rules property
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// let my_context = GameContext::new();
+    /// assert_eq!(*my_context.clock_rules(), ClockRules::new());
+    /// ```
+    pub fn clock_rules(&self) -> &ClockRules {
+        &self.clock_rules
+    }
+
+    /// Get the GameContext weather property
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::context::weather::Weather;
+    ///
+    /// let my_context = GameContext::new();
+    /// assert_eq!(my_context.weather(), Weather::Clear);
+    /// ```
+    pub fn weather(&self) -> Weather {
+        self.weather
+    }
+
+    /// Get the GameContext home_overtime_possession property, whether the
+    /// home team has run a play from scrimmage in overtime
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    ///
+    /// let my_context = GameContext::new();
+    /// assert!(!my_context.home_overtime_possession());
+    /// ```
+    pub fn home_overtime_possession(&self) -> bool {
+        self.home_overtime_possession
+    }
+
+    /// Get the GameContext away_overtime_possession property, whether the
+    /// away team has run a play from scrimmage in overtime
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    ///
+    /// let my_context = GameContext::new();
+    /// assert!(!my_context.away_overtime_possession());
+    /// ```
+    pub fn away_overtime_possession(&self) -> bool {
+        self.away_overtime_possession
+    }
+
+    /// Get a copy of the context which follows the given overtime rules
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::context::overtime::OvertimeRules;
+    ///
+    /// let my_context = GameContext::new().with_overtime_rules(OvertimeRules::playoffs());
+    /// assert_eq!(*my_context.overtime_rules(), OvertimeRules::playoffs());
+    /// ```
+    pub fn with_overtime_rules(&self, overtime_rules: OvertimeRules) -> GameContext {
+        let mut context = self.clone();
+        context.overtime_rules = overtime_rules;
+        context
+    }
+
+    /// Get a copy of the context played in the given weather
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::context::weather::Weather;
+    ///
+    /// let my_context = GameContext::new().with_weather(Weather::Rain);
+    /// assert_eq!(my_context.weather(), Weather::Rain);
+    /// ```
+    pub fn with_weather(&self, weather: Weather) -> GameContext {
+        let mut context = self.clone();
+        context.weather = weather;
+        context
+    }
+
+    /// Get a copy of the context which follows the given clock rules, with
+    /// the clock reset to the start of the game. Fails if the game has
+    /// already started or the rules are invalid.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// let my_context = GameContext::new()
+    ///     .with_clock_rules(ClockRules::new().quarter_seconds(600))
+    ///     .unwrap();
+    /// assert_eq!(my_context.half_seconds(), 1200);
+    /// assert!(!my_context.started());
+    /// ```
+    pub fn with_clock_rules(&self, clock_rules: ClockRules) -> Result<GameContext, String> {
+        if self.started() {
+            return Err(String::from("Cannot change the clock rules of a game which has started"));
+        }
+        clock_rules.validate()?;
+        let mut context = self.clone();
+        context.clock_rules = clock_rules;
+        context.half_seconds = clock_rules.half_length();
+        Ok(context)
+    }
+
+    /// Determine whether the context is terminal, meaning no further plays
+    /// may be simulated from it
+    ///
+    /// A context is terminal exactly when its `game_over` flag is set. The
+    /// `end_of_half` flag alone is not terminal, since play resumes with the
+    /// second half or overtime kickoff, and a tied game at the end of
+    /// regulation continues into overtime rather than ending.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::{GameContext, GameContextBuilder};
+    ///
+    /// let my_context = GameContext::new();
+    /// assert!(!my_context.is_terminal());
+    ///
+    /// let final_context = GameContextBuilder::new()
+    ///     .quarter(4)
+    ///     .half_seconds(0)
+    ///     .home_score(21)
+    ///     .away_score(14)
+    ///     .game_over(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(final_context.is_terminal());
+    /// ```
+    pub fn is_terminal(&self) -> bool {
+        self.game_over
+    }
+
     /// Determine whether the game has started
     ///
     /// ### Example
@@ -748,7 +1234,7 @@ impl GameContext {
             || self.home_score > 0
             || self.away_score > 0
             || self.quarter > 1
-            || self.half_seconds < 1800
+            || self.half_seconds < self.clock_rules.half_length()
     }
 
     /// Get the number of timeouts the defense has left
@@ -803,8 +1289,8 @@ impl GameContext {
             self.next_play_kickoff || self.last_play_kickoff || self.last_play_punt || self.last_play_turnover ||
             (
                 self.last_play_out_of_bounds && (
-                    (self.quarter == 2 && self.half_seconds < 120) ||
-                    (self.quarter >= 4 && self.half_seconds < 300)
+                    (self.clock_rules.halftime_quarter(self.quarter) && self.half_seconds < 120) ||
+                    (self.clock_rules.final_quarter(self.quarter) && self.half_seconds < 300)
                 )
             )
         )
@@ -952,9 +1438,11 @@ impl GameContext {
         let next_clock = u32::try_from(self.half_seconds as i32 - update_opts.duration as i32).unwrap_or_default();
         let end_of_half = self.next_end_of_half(update_opts) || (self.end_of_half && update_opts.between_play);
 
-        // If end of quarter, max out at 900 seconds
-        if (self.quarter == 1 || self.quarter == 3) && self.half_seconds > 900 && next_clock <= 900 {
-            return 900;
+        // If end of quarter within the half, stop at the end of the quarter
+        let rules = &self.clock_rules;
+        let quarter_end = rules.quarter_end(self.quarter);
+        if !rules.ends_half(self.quarter) && self.half_seconds > quarter_end && next_clock <= quarter_end {
+            return quarter_end;
         }
 
         // If end of half, return 0 seconds
@@ -962,19 +1450,21 @@ impl GameContext {
             return 0;
         }
 
-        // If start of second half, return to 1800 seconds
-        if (end_of_half && update_opts.between_play && self.quarter < 4) || (self.end_of_half && self.quarter == 2) {
-            return 1800;
+        // If start of second half, return to the length of a half
+        if (end_of_half && update_opts.between_play && !rules.final_quarter(self.quarter)) ||
+            (self.end_of_half && rules.halftime_quarter(self.quarter)) {
+            return rules.half_length();
         }
 
-        // Check if end of game
-        if self.quarter >= 4 && next_clock == 0 {
-            if !self.next_score_tied(update_opts) {
+        // Check if end of game, which waits for the try after a touchdown
+        if rules.final_quarter(self.quarter) && next_clock == 0 {
+            let touchdown = update_opts.off_score == ScoreResult::Touchdown || update_opts.def_score == ScoreResult::Touchdown;
+            if touchdown || !self.next_score_tied(update_opts) || self.overtime_ends_tied() {
                 // If end of game, max out at 0 seconds
                 return 0;
             } else {
-                // If overtime, return to 600 seconds
-                return 600;
+                // If overtime, return to the length of an overtime period
+                return rules.overtime_seconds;
             }
         }
         next_clock
@@ -997,7 +1487,7 @@ impl GameContext {
         let next_clock = u32::try_from(self.half_seconds as i32 - update_opts.duration as i32).unwrap_or_default();
 
         // Check if end of half
-        if next_clock == 0 && (self.quarter == 2 || self.quarter >=4) &&
+        if next_clock == 0 && self.clock_rules.ends_half(self.quarter) &&
             !(update_opts.off_score == ScoreResult::Touchdown || update_opts.def_score == ScoreResult::Touchdown) {
             return true;
         }
@@ -1018,46 +1508,172 @@ impl GameContext {
     /// assert!(!game_over);
     /// ```
     pub fn next_game_over(&self, update_opts: &GameContextUpdateOptions) -> bool {
+        self.next_clock_expired(update_opts) ||
+            self.next_overtime_decided(update_opts, self.next_home_possession(update_opts))
+    }
+
+    /// Determine whether the game ends with the clock running out
+    fn next_clock_expired(&self, update_opts: &GameContextUpdateOptions) -> bool {
         let next_clock = u32::try_from(self.half_seconds as i32 - update_opts.duration as i32).unwrap_or_default();
-        self.quarter >= 4 && next_clock == 0 && !self.next_score_tied(update_opts)
+        self.clock_rules.final_quarter(self.quarter) && next_clock == 0 &&
+            (!self.next_score_tied(update_opts) || self.overtime_ends_tied())
     }
 
-    /// Get the updated quarter
+    /// Determine whether a tie at the end of the current period ends the
+    /// game, which is the case in the first overtime period if the overtime
+    /// rules allow ties
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::game::context::{GameContext, GameContextUpdateOptions};
-    /// use fbsim_core::game::play::result::ScoreResult;
-    /// 
-    /// let mut update_opts = GameContextUpdateOptions::default();
-    /// update_opts.duration = 10;
-    /// let my_context = GameContext::new();
-    /// let quarter = my_context.next_quarter(&update_opts);
-    /// assert!(quarter == 1);
+    /// use fbsim_core::game::context::{GameContext, GameContextBuilder};
+    /// use fbsim_core::game::context::overtime::OvertimeRules;
+    ///
+    /// let my_context = GameContextBuilder::new()
+    ///     .quarter(5)
+    ///     .half_seconds(600)
+    ///     .overtime_rules(OvertimeRules::regular_season())
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_context.overtime_ends_tied());
+    /// assert!(!GameContext::new().overtime_ends_tied());
     /// ```
-    pub fn next_quarter(&self, update_opts: &GameContextUpdateOptions) -> u32 {
-        let next_clock = u32::try_from(self.half_seconds as i32 - update_opts.duration as i32).unwrap_or_default();
-
-        // Don't increment quarter if extra point still needs to be kicked
-        if update_opts.off_score == ScoreResult::Touchdown || update_opts.def_score == ScoreResult::Touchdown {
-            return self.quarter
-        }
-
-        // If end of 1st - 3rd quarter, increment quarter regardless
-        // If end of 4th - OT, increment quarter only if tied
-        if ((self.quarter == 1 || self.quarter == 3) && self.half_seconds >= 900 && next_clock <= 900) ||
-            (self.quarter == 2 && next_clock == 0) ||
-            (self.quarter >= 4 && next_clock == 0 && self.next_score_tied(update_opts)) {
-            return self.quarter + 1;
-        }
-        self.quarter
+    pub fn overtime_ends_tied(&self) -> bool {
+        self.quarter == self.clock_rules.quarters + 1 && self.overtime_rules.allow_ties
     }
 
-    /// Get the updated home team direction
+    /// Determine whether a play ends an overtime game under sudden death
+    /// rules before the period's clock runs out, given which team has
+    /// possession after the play
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::game::context::{GameContext, GameContextUpdateOptions};
+    /// use fbsim_core::game::context::{GameContextBuilder, GameContextUpdateOptions};
+    /// use fbsim_core::game::context::overtime::OvertimeRules;
+    /// use fbsim_core::game::play::result::ScoreResult;
+    ///
+    /// // A field goal on the first possession of overtime does not end it
+    /// let my_context = GameContextBuilder::new()
+    ///     .quarter(5)
+    ///     .half_seconds(400)
+    ///     .down(4)
+    ///     .distance(5)
+    ///     .yard_line(70)
+    ///     .home_score(17)
+    ///     .away_score(17)
+    ///     .next_play_kickoff(false)
+    ///     .overtime_rules(OvertimeRules::playoffs())
+    ///     .home_overtime_possession(true)
+    ///     .build()
+    ///     .unwrap();
+    /// let mut update_opts = GameContextUpdateOptions::default();
+    /// update_opts.off_score = ScoreResult::FieldGoal;
+    /// assert!(!my_context.next_overtime_decided(&update_opts, true));
+    ///
+    /// // A touchdown does
+    /// update_opts.off_score = ScoreResult::Touchdown;
+    /// assert!(my_context.next_overtime_decided(&update_opts, true));
+    /// ```
+    pub fn next_overtime_decided(&self, update_opts: &GameContextUpdateOptions, next_home_possession: bool) -> bool {
+        if !self.clock_rules.overtime(self.quarter) || !self.overtime_rules.sudden_death() || update_opts.between_play {
+            return false;
+        }
+        let next_home_score = self.next_home_score(update_opts);
+        let next_away_score = self.next_away_score(update_opts);
+        if next_home_score == next_away_score {
+            return false;
+        }
+        if self.overtime_rules.mode == OvertimeMode::SuddenDeath {
+            return true;
+        }
+
+        // Under modified sudden death, any score but a field goal decides
+        // the game
+        let scored = next_home_score != self.home_score || next_away_score != self.away_score;
+        if scored && update_opts.off_score != ScoreResult::FieldGoal {
+            return true;
+        }
+
+        // A field goal decides the game once the trailing team has possessed
+        // the ball
+        let home_leads = next_home_score > next_away_score;
+        if scored {
+            let (home_possessed, away_possessed) = self.next_overtime_possessions();
+            return if home_leads { away_possessed } else { home_possessed };
+        }
+
+        // Otherwise the game is decided once the trailing team loses
+        // possession from scrimmage
+        let scrimmage = !(self.next_play_kickoff || self.next_play_extra_point);
+        scrimmage && self.home_possession != home_leads && next_home_possession != self.home_possession
+    }
+
+    /// Get whether the home and away teams have run a play from scrimmage in
+    /// overtime, counting the play about to be run. Possessions are only
+    /// tracked under sudden death overtime rules.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::context::overtime::OvertimeRules;
+    ///
+    /// let my_context = GameContextBuilder::new()
+    ///     .quarter(5)
+    ///     .half_seconds(550)
+    ///     .down(1)
+    ///     .yard_line(25)
+    ///     .next_play_kickoff(false)
+    ///     .overtime_rules(OvertimeRules::playoffs())
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(my_context.next_overtime_possessions(), (true, false));
+    /// ```
+    pub fn next_overtime_possessions(&self) -> (bool, bool) {
+        let scrimmage = self.clock_rules.overtime(self.quarter) && self.overtime_rules.sudden_death() &&
+            !(self.next_play_kickoff || self.next_play_extra_point);
+        (
+            self.home_overtime_possession || (scrimmage && self.home_possession),
+            self.away_overtime_possession || (scrimmage && !self.home_possession)
+        )
+    }
+
+    /// Get the updated quarter
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::{GameContext, GameContextUpdateOptions};
+    /// use fbsim_core::game::play::result::ScoreResult;
+    /// 
+    /// let mut update_opts = GameContextUpdateOptions::default();
+    /// update_opts.duration = 10;
+    /// let my_context = GameContext::new();
+    /// let quarter = my_context.next_quarter(&update_opts);
+    /// assert!(quarter == 1);
+    /// ```
+    pub fn next_quarter(&self, update_opts: &GameContextUpdateOptions) -> u32 {
+        let next_clock = u32::try_from(self.half_seconds as i32 - update_opts.duration as i32).unwrap_or_default();
+
+        // Don't increment quarter if extra point still needs to be kicked
+        if update_opts.off_score == ScoreResult::Touchdown || update_opts.def_score == ScoreResult::Touchdown {
+            return self.quarter
+        }
+
+        // If end of a quarter within a half, increment quarter regardless
+        // If end of 4th - OT, increment quarter only if tied
+        let rules = &self.clock_rules;
+        let quarter_end = rules.quarter_end(self.quarter);
+        if (!rules.ends_half(self.quarter) && self.half_seconds >= quarter_end && next_clock <= quarter_end) ||
+            (rules.halftime_quarter(self.quarter) && next_clock == 0) ||
+            (rules.final_quarter(self.quarter) && next_clock == 0 && self.next_score_tied(update_opts) && !self.overtime_ends_tied()) {
+            return self.quarter + 1;
+        }
+        self.quarter
+    }
+
+    /// Get the updated home team direction
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::{GameContext, GameContextUpdateOptions};
     /// use fbsim_core::game::play::result::ScoreResult;
     /// 
     /// let mut update_opts = GameContextUpdateOptions::default();
@@ -1171,13 +1787,21 @@ impl GameContext {
             return self.home_possession;
         }
 
+        // The team which scored the touchdown kicks off after the try, even
+        // if the defense returned it for two points
+        if self.next_play_extra_point {
+            return self.home_possession;
+        }
+
         // Change possession on successful kickoffs, defensive TDs, turnovers
         if self.next_play_kickoff || update_opts.def_score == ScoreResult::Touchdown || update_opts.turnover {
             return !self.home_possession;
         }
 
-        // Maintain possession on first downs, offensive scores
+        // Maintain possession on first downs, offensive scores, and safeties,
+        // after which the team which conceded the safety kicks off
         if update_opts.net_yards >= self.distance as i32 ||
+            update_opts.def_score == ScoreResult::Safety ||
             update_opts.off_score == ScoreResult::Touchdown ||
             update_opts.off_score == ScoreResult::FieldGoal ||
             update_opts.off_score == ScoreResult::ExtraPoint ||
@@ -1234,31 +1858,18 @@ impl GameContext {
             return eoq_yl;
         }
 
-        // Extra point after touchdowns
+        // Extra point after touchdowns, which is attempted before the quarter
+        // ends so the field is never flipped
         if update_opts.off_score == ScoreResult::Touchdown {
-            let next_yl = if self.home_possession ^ self.home_positive_direction {
-                2
-            } else {
-                98
-            };
-            let eoq_yl = if end_of_quarter {
-                100 - next_yl
-            } else {
-                next_yl
-            };
-            return eoq_yl;
+            if self.home_possession ^ self.home_positive_direction {
+                return 2;
+            }
+            return 98;
         } else if update_opts.def_score == ScoreResult::Touchdown {
-            let next_yl = if self.home_possession ^ self.home_positive_direction {
-                98
-            } else {
-                2
-            };
-            let eoq_yl = if end_of_quarter {
-                100 - next_yl
-            } else {
-                next_yl
-            };
-            return eoq_yl;
+            if self.home_possession ^ self.home_positive_direction {
+                return 98;
+            }
+            return 2;
         }
 
         // Touchbacks and kickoffs out of bounds
@@ -1430,29 +2041,43 @@ impl GameContext {
         away_tos
     }
 
-    /// Get the next context given the results of the previous play
-    pub fn next_context(&self, result: &(impl PlayResult + ?Sized)) -> GameContext {
-        let duration = result.play_duration();
-        let off_score = result.offense_score();
-        let def_score = result.defense_score();
-        let off_timeout = result.offense_timeout();
-        let def_timeout = result.defense_timeout();
-        let next_play_extra_point = result.next_play_extra_point();
-        let turnover = result.turnover();
-        let update_opts = GameContextUpdateOptions{
-            duration,
-            net_yards: result.net_yards(),
-            off_score,
-            def_score,
-            turnover,
-            touchback: result.touchback(),
-            kickoff_oob: result.kickoff() && result.out_of_bounds(),
-            off_timeout,
-            def_timeout,
-            next_play_extra_point,
-            between_play: false,
-            end_of_game: false
-        };
+    /// Get the next context given the outcome of the previous play
+    ///
+    /// This is the single entry point for context transitions. It composes
+    /// the fine-grained `next_*` methods, and returns
+    /// `GameError::GameAlreadyOver` if the context is terminal, or an error
+    /// if the resulting context fails validation.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::{GameContext, GameContextBuilder, PlayOutcome};
+    ///
+    /// // Gain 4 yards on 1st & 10 in 6 seconds
+    /// let my_context = GameContextBuilder::new()
+    ///     .down(1)
+    ///     .distance(10)
+    ///     .yard_line(25)
+    ///     .next_play_kickoff(false)
+    ///     .build()
+    ///     .unwrap();
+    /// let mut my_outcome = PlayOutcome::new();
+    /// my_outcome.net_yards = 4;
+    /// my_outcome.duration = 6;
+    ///
+    /// // Now 2nd & 6 from the 29
+    /// let next_context = my_context.transition(&my_outcome).unwrap();
+    /// assert!(next_context.down() == 2);
+    /// assert!(next_context.distance() == 6);
+    /// assert!(next_context.yard_line() == 29);
+    /// assert!(next_context.half_seconds() == 1794);
+    /// ```
+    pub fn transition(&self, outcome: &PlayOutcome) -> Result<GameContext, GameError> {
+        // No play may be applied once the game is over
+        if self.is_terminal() {
+            return Err(GameError::GameAlreadyOver);
+        }
+        let update_opts = outcome.update_options();
+        let next_play_extra_point = outcome.next_play_extra_point;
         let end_of_half = if self.end_of_half {
             false
         } else {
@@ -1463,7 +2088,7 @@ impl GameContext {
         } else {
             self.next_quarter(&update_opts)
         };
-        let raw = GameContextRaw{
+        let mut raw = GameContextRaw{
             home_team_short: self.home_team_short.clone(),
             away_team_short: self.away_team_short.clone(),
             quarter: next_quarter,
@@ -1478,19 +2103,88 @@ impl GameContext {
             home_positive_direction: self.next_home_positive_direction(&update_opts),
             home_opening_kickoff: self.home_opening_kickoff,
             home_possession: self.next_home_possession(&update_opts),
-            last_play_turnover: turnover,
-            last_play_incomplete: result.incomplete(),
-            last_play_out_of_bounds: result.out_of_bounds(),
-            last_play_timeout: off_timeout || def_timeout,
-            last_play_kickoff: result.kickoff(),
-            last_play_punt: result.punt(),
+            last_play_turnover: outcome.turnover,
+            last_play_incomplete: outcome.incomplete,
+            last_play_out_of_bounds: outcome.out_of_bounds,
+            last_play_timeout: outcome.off_timeout || outcome.def_timeout,
+            last_play_kickoff: outcome.kickoff,
+            last_play_punt: outcome.punt,
             next_play_extra_point,
-            next_play_kickoff: result.next_play_kickoff() || (end_of_half && !next_play_extra_point),
+            next_play_kickoff: outcome.next_play_kickoff || (end_of_half && !next_play_extra_point),
             neutral_site: self.neutral_site,
             end_of_half,
-            game_over: self.next_game_over(&update_opts)
+            game_over: false,
+            overtime_rules: self.overtime_rules,
+            clock_rules: self.clock_rules,
+            weather: self.weather,
+            home_overtime_possession: false,
+            away_overtime_possession: false
         };
-        GameContext::try_from(raw).unwrap()
+        (raw.home_overtime_possession, raw.away_overtime_possession) = self.next_overtime_possessions();
+
+        // Replay the down or award a first down after penalty enforcement,
+        // or after the kicking team recovers a punt, unless the play ended
+        // in a score, a turnover, or the end of a half
+        let automatic_first_down = outcome.automatic_first_down || outcome.punt;
+        if (outcome.replay_down || automatic_first_down) && raw.down != 0 && !outcome.turnover {
+            raw.home_possession = self.home_possession;
+            if outcome.net_yards >= self.distance as i32 {
+                raw.down = 1;
+                raw.distance = self.next_distance(&update_opts);
+            } else if automatic_first_down {
+                let mut first_down = self.clone();
+                first_down.distance = 0;
+                raw.down = 1;
+                raw.distance = first_down.next_distance(&update_opts);
+            } else {
+                raw.down = self.down;
+                raw.distance = (self.distance as i32 - outcome.net_yards) as u32;
+            }
+        }
+
+        // A game decided in sudden death overtime ends immediately, without
+        // an extra point attempt
+        if self.next_overtime_decided(&update_opts, raw.home_possession) {
+            raw.next_play_extra_point = false;
+            raw.next_play_kickoff = false;
+            raw.game_over = true;
+        } else {
+            raw.game_over = self.next_clock_expired(&update_opts);
+        }
+        Ok(GameContext::try_from(raw)?)
+    }
+
+    /// Get the next context given the results of the previous play, or an
+    /// error if the context is terminal or the resulting context fails
+    /// validation
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::{GameContext, GameContextBuilder};
+    /// use fbsim_core::game::play::result::run::RunResultBuilder;
+    ///
+    /// // Gain 4 yards on 1st & 10 in 6 seconds
+    /// let my_context = GameContextBuilder::new()
+    ///     .down(1)
+    ///     .distance(10)
+    ///     .yard_line(25)
+    ///     .next_play_kickoff(false)
+    ///     .build()
+    ///     .unwrap();
+    /// let my_result = RunResultBuilder::new()
+    ///     .yards_gained(4)
+    ///     .play_duration(6)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Now 2nd & 6 from the 29
+    /// let next_context = my_context.next_context(&my_result).unwrap();
+    /// assert!(next_context.down() == 2);
+    /// assert!(next_context.distance() == 6);
+    /// assert!(next_context.yard_line() == 29);
+    /// ```
+    pub fn next_context(&self, result: &(impl PlayResult + ?Sized)) -> Result<GameContext, GameError> {
+        self.transition(&PlayOutcome::from_result(result))
     }
 }
 
@@ -1556,7 +2250,14 @@ pub struct GameContextBuilder {
     next_play_kickoff: bool,
     neutral_site: bool,
     end_of_half: bool,
-    game_over: bool
+    game_over: bool,
+    overtime_rules: OvertimeRules,
+    #[serde(default)]
+    clock_rules: ClockRules,
+    #[serde(default)]
+    weather: Weather,
+    home_overtime_possession: bool,
+    away_overtime_possession: bool
 }
 
 impl Default for GameContextBuilder {
@@ -1582,7 +2283,7 @@ impl Default for GameContextBuilder {
             home_timeouts: 3,
             away_timeouts: 3,
             home_positive_direction: true,
-            home_opening_kickoff: true,
+            home_opening_kickoff: false,
             home_possession: true,
             last_play_turnover: false,
             last_play_incomplete: false,
@@ -1594,7 +2295,12 @@ impl Default for GameContextBuilder {
             next_play_kickoff: true,
             neutral_site: false,
             end_of_half: false,
-            game_over: false
+            game_over: false,
+            overtime_rules: OvertimeRules::default(),
+            clock_rules: ClockRules::default(),
+            weather: Weather::default(),
+            home_overtime_possession: false,
+            away_overtime_possession: false
         }
     }
 }
@@ -1818,17 +2524,18 @@ impl GameContextBuilder {
         self
     }
     
-    /// Set the home opening kickoff property
+    /// Set the home opening kickoff property, which is true if the home
+    /// team received the opening kickoff
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::game::context::{GameContext, GameContextBuilder};
     /// 
     /// let my_context = GameContextBuilder::new()
-    ///     .home_opening_kickoff(false)
+    ///     .home_opening_kickoff(true)
     ///     .build()
     ///     .unwrap();
-    /// assert!(my_context.home_opening_kickoff() == false);
+    /// assert!(my_context.home_opening_kickoff());
     /// ```
     pub fn home_opening_kickoff(mut self, home_opening_kickoff: bool) -> Self {
         self.home_opening_kickoff = home_opening_kickoff;
@@ -2044,6 +2751,100 @@ impl GameContextBuilder {
         self
     }
 
+    /// Set the overtime rules
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::context::overtime::OvertimeRules;
+    ///
+    /// let my_context = GameContextBuilder::new()
+    ///     .overtime_rules(OvertimeRules::regular_season())
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(*my_context.overtime_rules(), OvertimeRules::regular_season());
+    /// ```
+    pub fn overtime_rules(mut self, overtime_rules: OvertimeRules) -> Self {
+        self.overtime_rules = overtime_rules;
+        self
+    }
+
+    /// Set the clock rules. The half seconds are not changed, so set them
+    /// to fit the rules when the half is not the default length.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// let my_context = GameContextBuilder::new()
+    ///     .clock_rules(ClockRules::new().quarter_seconds(600))
+    ///     .half_seconds(1200)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(my_context.clock_rules().quarter_seconds, 600);
+    /// ```
+    pub fn clock_rules(mut self, clock_rules: ClockRules) -> Self {
+        self.clock_rules = clock_rules;
+        self
+    }
+
+    /// Set the weather
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::context::weather::Weather;
+    ///
+    /// let my_context = GameContextBuilder::new()
+    ///     .weather(Weather::Snow)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(my_context.weather(), Weather::Snow);
+    /// ```
+    pub fn weather(mut self, weather: Weather) -> Self {
+        self.weather = weather;
+        self
+    }
+
+    /// Set the home overtime possession property
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    ///
+    /// let my_context = GameContextBuilder::new()
+    ///     .quarter(5)
+    ///     .half_seconds(400)
+    ///     .home_overtime_possession(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_context.home_overtime_possession());
+    /// ```
+    pub fn home_overtime_possession(mut self, home_overtime_possession: bool) -> Self {
+        self.home_overtime_possession = home_overtime_possession;
+        self
+    }
+
+    /// Set the away overtime possession property
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    ///
+    /// let my_context = GameContextBuilder::new()
+    ///     .quarter(5)
+    ///     .half_seconds(400)
+    ///     .away_overtime_possession(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_context.away_overtime_possession());
+    /// ```
+    pub fn away_overtime_possession(mut self, away_overtime_possession: bool) -> Self {
+        self.away_overtime_possession = away_overtime_possession;
+        self
+    }
+
     /// Build the game context
     ///
     /// ### Example
@@ -2101,7 +2902,12 @@ impl GameContextBuilder {
             next_play_kickoff: self.next_play_kickoff,
             neutral_site: self.neutral_site,
             end_of_half: self.end_of_half,
-            game_over: self.game_over
+            game_over: self.game_over,
+            overtime_rules: self.overtime_rules,
+            clock_rules: self.clock_rules,
+            weather: self.weather,
+            home_overtime_possession: self.home_overtime_possession,
+            away_overtime_possession: self.away_overtime_possession
         };
         GameContext::try_from(raw)
     }
@@ -2112,6 +2918,43 @@ mod tests {
     use super::*;
     use crate::game::play::result::betweenplay::{BetweenPlayResult, BetweenPlayResultBuilder};
     use crate::game::play::result::kickoff::{KickoffResult, KickoffResultBuilder};
+    use crate::game::play::result::punt::{PuntResult, PuntResultBuilder};
+
+    #[test]
+    fn test_dense_and_sparse_context_round_trip() {
+        // Serialize a context in the sparse form, omitting false booleans
+        let context = GameContextBuilder::new()
+            .home_possession(false)
+            .last_play_kickoff(true)
+            .build()
+            .unwrap();
+        let sparse = serde_json::to_value(&context).unwrap();
+        assert!(sparse.get("home_possession").is_none());
+        assert!(sparse.get("game_over").is_none());
+        assert_eq!(sparse.get("last_play_kickoff"), Some(&serde_json::Value::Bool(true)));
+
+        // Fill in the omitted booleans to produce the dense form
+        let mut dense = sparse.clone();
+        let fields = [
+            "home_positive_direction", "home_opening_kickoff", "home_possession",
+            "last_play_turnover", "last_play_incomplete", "last_play_out_of_bounds",
+            "last_play_timeout", "last_play_kickoff", "last_play_punt",
+            "next_play_extra_point", "next_play_kickoff", "neutral_site",
+            "end_of_half", "game_over"
+        ];
+        for field in fields {
+            dense.as_object_mut().unwrap()
+                .entry(field)
+                .or_insert(serde_json::Value::Bool(false));
+        }
+        assert!(dense.to_string().len() > sparse.to_string().len());
+
+        // Both forms deserialize into the same context
+        let from_sparse: GameContext = serde_json::from_value(sparse).unwrap();
+        let from_dense: GameContext = serde_json::from_value(dense).unwrap();
+        assert_eq!(from_sparse, context);
+        assert_eq!(from_dense, context);
+    }
 
     #[test]
     fn test_long_kickoff_return_fumble_result() {
@@ -2134,7 +2977,7 @@ mod tests {
             .unwrap();
 
         // Get the next context
-        let next_context: GameContext = kickoff_return.next_context(&context);
+        let next_context: GameContext = kickoff_return.next_context(&context).unwrap();
 
         // Assert the next distance is 10
         assert!(next_context.distance() == 10);
@@ -2162,13 +3005,201 @@ mod tests {
             .unwrap();
 
         // Get the next context
-        let next_context: GameContext = kickoff_return.next_context(&context);
+        let next_context: GameContext = kickoff_return.next_context(&context).unwrap();
 
         // Assert the next distance is 7
         assert!(next_context.distance() == 7);
         assert!(next_context.home_possession());
     }
 
+    /// Build a 4th & 10 context at the home team's own yard line
+    fn fourth_and_ten_at_own(yard_line: u32) -> GameContext {
+        GameContextBuilder::new()
+            .down(4)
+            .distance(10)
+            .yard_line(yard_line)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_punt_return_touchdown_result() {
+        // A punt from the own 30 caught at the receiving 25 and returned
+        // the length of the field
+        let context = fourth_and_ten_at_own(30);
+        let punt: PuntResult = PuntResultBuilder::new()
+            .punt_yards(45)
+            .catch_spot(25)
+            .punt_return_yards(75)
+            .touchdown(true)
+            .play_duration(12)
+            .build()
+            .unwrap();
+        let next_context = punt.next_context(&context).unwrap();
+
+        // The receiving team scores and attempts the extra point
+        assert_eq!(next_context.away_score(), 6);
+        assert!(!next_context.home_possession());
+        assert!(next_context.next_play_extra_point());
+    }
+
+    #[test]
+    fn test_muffed_punt_kicking_team_recovery_result() {
+        // A punt from the own 30 muffed at the receiving 30 and recovered
+        // by the kicking team
+        let context = fourth_and_ten_at_own(30);
+        let punt: PuntResult = PuntResultBuilder::new()
+            .punt_yards(40)
+            .catch_spot(30)
+            .muffed(true)
+            .fumble(true)
+            .play_duration(8)
+            .build()
+            .unwrap();
+        assert!(punt.kicking_team_recovery());
+        let next_context = punt.next_context(&context).unwrap();
+
+        // The kicking team keeps the ball with a first down at the spot
+        assert!(next_context.home_possession());
+        assert_eq!(next_context.down(), 1);
+        assert_eq!(next_context.distance(), 10);
+        assert_eq!(next_context.yard_line(), 70);
+    }
+
+    #[test]
+    fn test_short_muffed_punt_kicking_team_recovery_result() {
+        // A shanked punt short of the line to gain, muffed and recovered by
+        // the kicking team, is still a first down rather than a turnover on
+        // downs
+        let context = fourth_and_ten_at_own(30);
+        let punt: PuntResult = PuntResultBuilder::new()
+            .punt_yards(6)
+            .catch_spot(64)
+            .muffed(true)
+            .fumble(true)
+            .play_duration(5)
+            .build()
+            .unwrap();
+        let next_context = punt.next_context(&context).unwrap();
+        assert!(next_context.home_possession());
+        assert_eq!(next_context.down(), 1);
+        assert_eq!(next_context.distance(), 10);
+        assert_eq!(next_context.yard_line(), 36);
+    }
+
+    #[test]
+    fn test_muffed_punt_receiving_team_recovery_result() {
+        // A punt from the own 30 muffed at the receiving 30 and recovered
+        // by the receiving team
+        let context = fourth_and_ten_at_own(30);
+        let punt: PuntResult = PuntResultBuilder::new()
+            .punt_yards(40)
+            .catch_spot(30)
+            .muffed(true)
+            .fumble(true)
+            .receiving_recovery(true)
+            .play_duration(8)
+            .build()
+            .unwrap();
+        assert!(!punt.kicking_team_recovery());
+        let next_context = punt.next_context(&context).unwrap();
+
+        // The receiving team takes over at the spot of the recovery
+        assert!(!next_context.home_possession());
+        assert_eq!(next_context.down(), 1);
+        assert_eq!(next_context.distance(), 10);
+        assert_eq!(next_context.yard_line(), 70);
+    }
+
+    #[test]
+    fn test_punt_downed_inside_5_result() {
+        // A punt from the own 30 let bounce and downed at the receiving 3
+        let context = fourth_and_ten_at_own(30);
+        let punt: PuntResult = PuntResultBuilder::new()
+            .punt_yards(67)
+            .catch_spot(3)
+            .downed(true)
+            .hang_time(44)
+            .play_duration(9)
+            .build()
+            .unwrap();
+        assert!(punt.downed_inside_5());
+        assert_eq!(punt.start_yard_line(), 3);
+        let next_context = punt.next_context(&context).unwrap();
+
+        // The receiving team takes over pinned at its own 3
+        assert!(!next_context.home_possession());
+        assert_eq!(next_context.down(), 1);
+        assert_eq!(next_context.yard_line(), 97);
+        assert_eq!(next_context.yards_to_touchdown(), 97);
+    }
+
+    #[test]
+    fn test_blocked_punt_safety_result() {
+        // A punt from the own 5 blocked and recovered by the kicking team
+        // in its own end zone
+        let context = fourth_and_ten_at_own(5);
+        let punt: PuntResult = PuntResultBuilder::new()
+            .punt_yards(-5)
+            .catch_spot(100)
+            .blocked(true)
+            .safety(true)
+            .play_duration(5)
+            .build()
+            .unwrap();
+        let next_context = punt.next_context(&context).unwrap();
+
+        // The receiving team scores 2 and the kicking team free kicks from
+        // its own 35
+        assert_eq!(next_context.away_score(), 2);
+        assert_eq!(next_context.home_score(), 0);
+        assert!(next_context.home_possession());
+        assert!(next_context.next_play_kickoff());
+        assert_eq!(next_context.down(), 0);
+        assert_eq!(next_context.yard_line(), 35);
+    }
+
+    #[test]
+    fn test_blocked_punt_touchdown_result() {
+        // A punt from the own 5 blocked and recovered by the receiving team
+        // in the kicking team's end zone
+        let context = fourth_and_ten_at_own(5);
+        let punt: PuntResult = PuntResultBuilder::new()
+            .punt_yards(-5)
+            .catch_spot(100)
+            .blocked(true)
+            .touchdown(true)
+            .play_duration(5)
+            .build()
+            .unwrap();
+        let next_context = punt.next_context(&context).unwrap();
+        assert_eq!(next_context.away_score(), 6);
+        assert!(!next_context.home_possession());
+        assert!(next_context.next_play_extra_point());
+    }
+
+    #[test]
+    fn test_blocked_punt_receiving_team_recovery_result() {
+        // A punt from the own 30 blocked and recovered by the receiving
+        // team 7 yards behind the line
+        let context = fourth_and_ten_at_own(30);
+        let punt: PuntResult = PuntResultBuilder::new()
+            .punt_yards(-7)
+            .catch_spot(77)
+            .blocked(true)
+            .play_duration(4)
+            .build()
+            .unwrap();
+        assert_eq!(punt.start_yard_line(), 77);
+        let next_context = punt.next_context(&context).unwrap();
+
+        // The receiving team takes over at the kicking team's 23
+        assert!(!next_context.home_possession());
+        assert_eq!(next_context.down(), 1);
+        assert_eq!(next_context.distance(), 10);
+        assert_eq!(next_context.yard_line(), 23);
+    }
+
     #[test]
     fn test_end_of_game_next_yl_1() {
         // Create a context
@@ -2195,7 +3226,7 @@ mod tests {
             .unwrap();
         
         // Get the next context
-        let next_context: GameContext = between_play.next_context(&context);
+        let next_context: GameContext = between_play.next_context(&context).unwrap();
 
         // Assert the correct context is derived
         assert!(next_context.home_possession());
@@ -2230,7 +3261,7 @@ mod tests {
             .unwrap();
 
         // Get the next context
-        let next_context: GameContext = between_play.next_context(&context);
+        let next_context: GameContext = between_play.next_context(&context).unwrap();
 
         // Assert the correct context is derived
         assert_eq!(next_context.down(), 0);
@@ -2239,4 +3270,742 @@ mod tests {
         assert!(next_context.end_of_half());
         assert_eq!(next_context.yard_line(), 65);
     }
+
+    #[test]
+    fn test_sim_play_after_game_over() {
+        use crate::game::play::{Drive, DriveSimulator, Game, GameSimulator};
+        use crate::team::FootballTeam;
+
+        // Simulate a game to completion
+        let home = FootballTeam::new();
+        let away = FootballTeam::new();
+        let mut game = Game::new();
+        let simulator = GameSimulator::new();
+        let mut rng = rand::thread_rng();
+        let final_context = simulator.sim_game(
+            &home, &away, GameContext::new(), &mut game, &mut rng
+        ).unwrap();
+        assert!(final_context.is_terminal());
+
+        // Feed one extra play into the finished game
+        let num_drives = game.drives().len();
+        let res = simulator.sim_play(&home, &away, final_context.clone(), &mut game, &mut rng);
+        assert_eq!(res, Err(GameError::GameAlreadyOver));
+        assert_eq!(game.drives().len(), num_drives);
+
+        // The drive simulator also refuses to simulate from a terminal context
+        let mut drive = Drive::new();
+        let res = DriveSimulator::new().sim_play(&home, &away, final_context.clone(), &mut drive, &mut rng);
+        assert_eq!(res, Err(GameError::GameAlreadyOver));
+        assert!(drive.plays().is_empty());
+
+        // No play outcome may be applied to a terminal context either
+        let last_play = game.drives().last().unwrap().plays().last().unwrap();
+        let outcome = PlayOutcome::from_result(last_play.result());
+        assert_eq!(final_context.transition(&outcome), Err(GameError::GameAlreadyOver));
+        assert_eq!(final_context.next_context(last_play.result()), Err(GameError::GameAlreadyOver));
+    }
+
+    /// The next context as computed before `GameContext::transition`, by
+    /// reading each property straight from the play result
+    fn legacy_next_context(context: &GameContext, result: &(impl PlayResult + ?Sized)) -> GameContext {
+        let next_play_extra_point = result.next_play_extra_point();
+        let update_opts = GameContextUpdateOptions{
+            duration: result.play_duration(),
+            net_yards: result.net_yards(),
+            off_score: result.offense_score(),
+            def_score: result.defense_score(),
+            turnover: result.turnover(),
+            touchback: result.touchback(),
+            kickoff_oob: result.kickoff() && result.out_of_bounds(),
+            off_timeout: result.offense_timeout(),
+            def_timeout: result.defense_timeout(),
+            next_play_extra_point,
+            between_play: false,
+            end_of_game: false
+        };
+        let end_of_half = if context.end_of_half {
+            false
+        } else {
+            context.next_end_of_half(&update_opts) && !next_play_extra_point
+        };
+        let next_quarter = if end_of_half {
+            context.quarter()
+        } else {
+            context.next_quarter(&update_opts)
+        };
+        let raw = GameContextRaw{
+            home_team_short: context.home_team_short.clone(),
+            away_team_short: context.away_team_short.clone(),
+            quarter: next_quarter,
+            half_seconds: context.next_half_seconds(&update_opts),
+            down: context.next_down(&update_opts),
+            distance: context.next_distance(&update_opts),
+            yard_line: context.next_yard_line(&update_opts),
+            home_score: context.next_home_score(&update_opts),
+            away_score: context.next_away_score(&update_opts),
+            home_timeouts: context.next_home_timeouts(&update_opts),
+            away_timeouts: context.next_away_timeouts(&update_opts),
+            home_positive_direction: context.next_home_positive_direction(&update_opts),
+            home_opening_kickoff: context.home_opening_kickoff,
+            home_possession: context.next_home_possession(&update_opts),
+            last_play_turnover: result.turnover(),
+            last_play_incomplete: result.incomplete(),
+            last_play_out_of_bounds: result.out_of_bounds(),
+            last_play_timeout: result.offense_timeout() || result.defense_timeout(),
+            last_play_kickoff: result.kickoff(),
+            last_play_punt: result.punt(),
+            next_play_extra_point,
+            next_play_kickoff: result.next_play_kickoff() || (end_of_half && !next_play_extra_point),
+            neutral_site: context.neutral_site,
+            end_of_half,
+            game_over: context.next_game_over(&update_opts),
+            overtime_rules: context.overtime_rules,
+            clock_rules: context.clock_rules,
+            weather: context.weather,
+            home_overtime_possession: context.home_overtime_possession,
+            away_overtime_possession: context.away_overtime_possession
+        };
+        GameContext::try_from(raw).unwrap()
+    }
+
+    #[test]
+    fn test_transition_matches_legacy_next_context() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::game::play::result::PlayTypeResult;
+        use crate::game::play::{Game, GameSimulator};
+        use crate::team::FootballTeam;
+
+        // Build a corpus of plays from simulated games
+        let home = FootballTeam::new();
+        let away = FootballTeam::new();
+        let simulator = GameSimulator::new();
+        let mut rng = SmallRng::seed_from_u64(1451);
+        let mut num_plays = 0;
+        for _ in 0..20 {
+            let mut game = Game::new();
+            simulator.sim_game(&home, &away, GameContext::new(), &mut game, &mut rng).unwrap();
+            for drive in game.drives() {
+                for play in drive.plays() {
+                    // Between-play results compute their own next context
+                    if matches!(play.result(), PlayTypeResult::BetweenPlay(_)) {
+                        continue;
+                    }
+
+                    // The old and new paths produce identical contexts
+                    let context = play.context();
+                    let outcome = PlayOutcome::from_result(play.result());
+                    let expected = legacy_next_context(context, play.result());
+                    assert_eq!(context.transition(&outcome).unwrap(), expected);
+                    assert_eq!(context.next_context(play.result()).unwrap(), expected);
+
+                    // The fine-grained methods agree with the transition
+                    let update_opts = outcome.update_options();
+                    assert_eq!(context.next_down(&update_opts), expected.down());
+                    assert_eq!(context.next_yard_line(&update_opts), expected.yard_line());
+                    num_plays += 1;
+                }
+            }
+        }
+        assert!(num_plays > 1000);
+    }
+
+    fn first_and_ten_at_own_25() -> GameContext {
+        GameContextBuilder::new()
+            .down(1)
+            .distance(10)
+            .yard_line(25)
+            .next_play_kickoff(false)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_defensive_offside_declined_on_long_completion() {
+        use crate::game::play::penalty::{resolve_penalties, Penalty, PenaltyTeam, PenaltyDecision, ExpectedPointsPenaltyStrategy};
+
+        // A 40 yard completion from 1st & 10 at the offense's own 25
+        let context = first_and_ten_at_own_25();
+        let completion = PlayOutcome {
+            duration: 7,
+            net_yards: 40,
+            ..Default::default()
+        };
+        let offside = vec![Penalty::new(PenaltyTeam::Defense, 5)];
+        let resolution = resolve_penalties(&context, &completion, &offside, &ExpectedPointsPenaltyStrategy).unwrap();
+
+        // The offense declines the flag and keeps the completion
+        assert_eq!(resolution.decision(), PenaltyDecision::Declined);
+        assert_eq!(resolution.effective(), &completion);
+        let next = &resolution.chosen().context;
+        assert_eq!(next.down(), 1);
+        assert_eq!(next.yard_line(), 65);
+
+        // Both alternatives are recorded
+        assert_eq!(resolution.accepted().context.down(), 1);
+        assert_eq!(resolution.accepted().context.distance(), 5);
+        assert_eq!(resolution.accepted().context.yard_line(), 30);
+    }
+
+    #[test]
+    fn test_defensive_offside_accepted_on_incompletion() {
+        use crate::game::play::penalty::{resolve_penalties, Penalty, PenaltyTeam, PenaltyDecision, ExpectedPointsPenaltyStrategy};
+
+        // An incompletion from 1st & 10 at the offense's own 25
+        let context = first_and_ten_at_own_25();
+        let incompletion = PlayOutcome {
+            duration: 5,
+            incomplete: true,
+            ..Default::default()
+        };
+        let offside = vec![Penalty::new(PenaltyTeam::Defense, 5)];
+        let resolution = resolve_penalties(&context, &incompletion, &offside, &ExpectedPointsPenaltyStrategy).unwrap();
+
+        // The offense accepts the flag and replays 1st & 5 rather than 2nd & 10
+        assert_eq!(resolution.decision(), PenaltyDecision::Accepted);
+        assert_eq!(resolution.declined().context.down(), 2);
+        let next = &resolution.chosen().context;
+        assert_eq!(next.down(), 1);
+        assert_eq!(next.distance(), 5);
+        assert_eq!(next.yard_line(), 30);
+        assert_eq!(next.home_possession(), context.home_possession());
+    }
+
+    #[test]
+    fn test_offsetting_penalties_replay_down() {
+        use crate::game::play::penalty::{resolve_penalties, Penalty, PenaltyTeam, PenaltyDecision, ExpectedPointsPenaltyStrategy};
+
+        // Offsetting flags on a 40 yard completion
+        let context = first_and_ten_at_own_25();
+        let completion = PlayOutcome {
+            duration: 7,
+            net_yards: 40,
+            ..Default::default()
+        };
+        let penalties = vec![
+            Penalty::new(PenaltyTeam::Offense, 10),
+            Penalty::new(PenaltyTeam::Defense, 15).automatic_first_down(true)
+        ];
+        let resolution = resolve_penalties(&context, &completion, &penalties, &ExpectedPointsPenaltyStrategy).unwrap();
+
+        // The down is replayed with no yardage
+        assert_eq!(resolution.decision(), PenaltyDecision::Offsetting);
+        assert_eq!(resolution.effective().net_yards, 0);
+        let next = &resolution.chosen().context;
+        assert_eq!(next.down(), 1);
+        assert_eq!(next.distance(), 10);
+        assert_eq!(next.yard_line(), 25);
+    }
+
+    #[test]
+    fn test_penalty_strategy_override() {
+        use crate::game::play::penalty::{resolve_penalties, Penalty, PenaltyTeam, PenaltyDecision, PenaltyAlternative, PenaltyStrategy};
+
+        // A strategy which always accepts a penalty
+        struct AlwaysAccept;
+        impl PenaltyStrategy for AlwaysAccept {
+            fn accept(&self, _: &GameContext, _: &Penalty, _: &PenaltyAlternative, _: &PenaltyAlternative) -> bool {
+                true
+            }
+        }
+
+        // The strategy overrides the default heuristic
+        let context = first_and_ten_at_own_25();
+        let completion = PlayOutcome {
+            duration: 7,
+            net_yards: 40,
+            ..Default::default()
+        };
+        let offside = vec![Penalty::new(PenaltyTeam::Defense, 5)];
+        let resolution = resolve_penalties(&context, &completion, &offside, &AlwaysAccept).unwrap();
+        assert_eq!(resolution.decision(), PenaltyDecision::Accepted);
+        assert_eq!(resolution.chosen().context.yard_line(), 30);
+    }
+
+    #[test]
+    fn test_offensive_penalty_half_the_distance() {
+        use crate::game::play::penalty::{resolve_penalties, Penalty, PenaltyTeam, PenaltyDecision, ExpectedPointsPenaltyStrategy};
+
+        // Offensive holding inside the offense's own 10 on a 3 yard run
+        let context = GameContextBuilder::new()
+            .down(2)
+            .distance(10)
+            .yard_line(8)
+            .next_play_kickoff(false)
+            .build()
+            .unwrap();
+        let run = PlayOutcome {
+            duration: 6,
+            net_yards: 3,
+            ..Default::default()
+        };
+        let holding = vec![Penalty::new(PenaltyTeam::Offense, 10)];
+        let resolution = resolve_penalties(&context, &run, &holding, &ExpectedPointsPenaltyStrategy).unwrap();
+
+        // The defense accepts and the ball moves half the distance to the goal
+        assert_eq!(resolution.decision(), PenaltyDecision::Accepted);
+        let next = &resolution.chosen().context;
+        assert_eq!(next.down(), 2);
+        assert_eq!(next.distance(), 14);
+        assert_eq!(next.yard_line(), 4);
+    }
+
+    #[test]
+    fn test_game_simulation_is_deterministic() {
+        use crate::game::play::{Drive, DriveSimulator, GameSimulator, PlaySimulator};
+        use crate::game::score::FinalScoreSimulator;
+        use crate::team::FootballTeam;
+
+        // Each simulator produces identical output for identical seeds
+        let home = FootballTeam::new();
+        let away = FootballTeam::new();
+        let context = GameContext::new();
+        assert_deterministic!(context, |c, rng| {
+            PlaySimulator::new().sim(&home, &away, c.clone(), &mut rng)
+        });
+        assert_deterministic!(context, |c, rng| {
+            Ok::<_, String>(DriveSimulator::new().sim(&home, &away, c.clone(), &mut rng))
+        });
+        assert_deterministic!(context, |c, rng| {
+            let mut drive = Drive::new();
+            DriveSimulator::new().sim_play(&home, &away, c.clone(), &mut drive, &mut rng)
+                .map(|c| (drive, c))
+        });
+        assert_deterministic!(context, |c, rng| GameSimulator::new().sim(&home, &away, c.clone(), &mut rng));
+        assert_deterministic!(context, |_c, rng| FinalScoreSimulator::new().sim(&home, &away, &mut rng));
+    }
+
+    #[test]
+    fn test_overtime_rules() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::game::play::{DriveResult, GameSimulator};
+        use crate::team::FootballTeam;
+
+        // A first possession field goal continues modified sudden death, and
+        // a touchdown ends it without an extra point
+        let first_possession = GameContextBuilder::new()
+            .quarter(5)
+            .half_seconds(400)
+            .down(1)
+            .yard_line(90)
+            .home_score(20)
+            .away_score(20)
+            .next_play_kickoff(false)
+            .overtime_rules(OvertimeRules::playoffs())
+            .build()
+            .unwrap();
+        let mut outcome = PlayOutcome::new();
+        outcome.duration = 5;
+        outcome.off_score = ScoreResult::FieldGoal;
+        outcome.next_play_kickoff = true;
+        let field_goal = first_possession.transition(&outcome).unwrap();
+        assert!(!field_goal.game_over());
+        assert!(field_goal.home_overtime_possession());
+        let mut outcome = PlayOutcome::new();
+        outcome.duration = 5;
+        outcome.net_yards = 10;
+        outcome.off_score = ScoreResult::Touchdown;
+        outcome.next_play_extra_point = true;
+        let touchdown = first_possession.transition(&outcome).unwrap();
+        assert!(touchdown.game_over());
+        assert!(!touchdown.next_play_extra_point());
+        assert_eq!((touchdown.home_score(), touchdown.half_seconds()), (26, 395));
+
+        // A game over with time left is only valid in sudden death overtime
+        let builder = GameContextBuilder::new().quarter(5).half_seconds(300).home_score(3).game_over(true);
+        assert!(builder.clone().build().is_err());
+        assert!(builder.overtime_rules(OvertimeRules::regular_season()).build().is_ok());
+        assert!(GameContextBuilder::new().home_overtime_possession(true).build().is_err());
+
+        // Simulate overtime under each set of rules
+        let home = FootballTeam::new();
+        let away = FootballTeam::new();
+        let mut rng = SmallRng::seed_from_u64(1502);
+        let modes = [OvertimeMode::FullPeriods, OvertimeMode::SuddenDeath, OvertimeMode::ModifiedSuddenDeath];
+        let mut ties = 0;
+        for mode in modes {
+            for allow_ties in [false, true] {
+                let rules = OvertimeRules::new().mode(mode).allow_ties(allow_ties);
+                for _ in 0..20 {
+                    let context = GameContextBuilder::new()
+                        .quarter(5)
+                        .half_seconds(600)
+                        .home_score(17)
+                        .away_score(17)
+                        .overtime_rules(rules)
+                        .build()
+                        .unwrap();
+                    let (game, end) = GameSimulator::new().sim(&home, &away, context, &mut rng).unwrap();
+                    let margin = end.home_score().abs_diff(end.away_score());
+                    assert!(end.game_over());
+
+                    // Only the first period may end tied, and only if allowed
+                    if margin == 0 {
+                        assert!(allow_ties && end.quarter() == 5 && end.half_seconds() == 0);
+                        ties += 1;
+                    }
+                    if allow_ties {
+                        assert_eq!(end.quarter(), 5);
+                    }
+
+                    // Sudden death ends on the first score, and modified
+                    // sudden death only ends on a field goal once both teams
+                    // have possessed the ball
+                    if end.half_seconds() > 0 {
+                        assert_ne!(mode, OvertimeMode::FullPeriods);
+                        assert!(margin > 0);
+                        let last_drive = game.drives().last().unwrap();
+                        assert_ne!(*last_drive.result(), DriveResult::EndOfHalf);
+                    }
+                    if mode == OvertimeMode::SuddenDeath && margin > 0 {
+                        assert!([2, 3, 6].contains(&margin), "{:?}", end);
+                    }
+                    if mode == OvertimeMode::ModifiedSuddenDeath && margin == 3 && end.half_seconds() > 0 {
+                        assert!(end.home_overtime_possession() && end.away_overtime_possession());
+                    }
+                }
+            }
+        }
+        assert!(ties > 0);
+    }
+
+    #[test]
+    fn test_opening_kickoff_alternates_halves() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::game::play::GameSimulator;
+        use crate::team::FootballTeam;
+
+        let home = FootballTeam::new();
+        let away = FootballTeam::new();
+        for home_receives in [true, false] {
+            let context = GameContext::with_opening_kickoff("HOME", "AWAY", home_receives).unwrap();
+            let mut rng = SmallRng::seed_from_u64(1508);
+            let (game, _) = GameSimulator::new().sim(&home, &away, context, &mut rng).unwrap();
+
+            // Find the kickoffs opening each half
+            let plays: Vec<&GameContext> = game.drives().iter()
+                .flat_map(|drive| drive.plays().iter().map(|play| play.context()))
+                .collect();
+            let opening = plays[0];
+            let second_half = plays.iter()
+                .find(|context| context.quarter() == 3 && context.next_play_kickoff())
+                .unwrap();
+
+            // The receiving team's opponent kicks off from its own 35 to
+            // open the game, and receives the second half kickoff
+            for (kickoff, home_kicks) in [(opening, !home_receives), (*second_half, home_receives)] {
+                assert!(kickoff.next_play_kickoff());
+                assert_eq!(kickoff.home_possession(), home_kicks);
+                assert_eq!(kickoff.home_opening_kickoff(), home_receives);
+                let remaining_yards = if kickoff.home_possession() ^ kickoff.home_positive_direction() {
+                    kickoff.yard_line()
+                } else {
+                    100 - kickoff.yard_line()
+                };
+                assert_eq!(remaining_yards, 65);
+            }
+        }
+
+        // The coin toss lands both ways
+        let mut rng = SmallRng::seed_from_u64(1508);
+        let tosses: Vec<bool> = (0..32)
+            .map(|_| GameContext::with_coin_toss("HOME", "AWAY", &mut rng).unwrap().home_opening_kickoff())
+            .collect();
+        assert!(tosses.contains(&true) && tosses.contains(&false));
+        assert!(GameContext::with_coin_toss("HAWKS", "AWAY", &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_sim_result_summarizes_game() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::game::play::GameSimulator;
+        use crate::team::FootballTeam;
+
+        let home = FootballTeam::from_overalls("HOME", "HOME", 70, 60).unwrap();
+        let away = FootballTeam::from_overalls("AWAY", "AWAY", 60, 70).unwrap();
+        let mut rng = SmallRng::seed_from_u64(1513);
+        let simulator = GameSimulator::new();
+        for _ in 0..200 {
+            let context = GameContext::with_coin_toss("HOME", "AWAY", &mut rng).unwrap();
+            let result = simulator.sim_result(&home, &away, context, &mut rng).unwrap();
+            assert!(result.context.game_over());
+
+            // The plays run in order, each starting from its predecessor's end
+            assert!(!result.plays.is_empty());
+            assert!(result.plays.len() < simulator.max_plays());
+            assert!(result.plays.windows(2).all(|pair| pair[0].context() != pair[1].context()));
+
+            // Scoring by period adds up to the final score
+            let periods = result.period_points();
+            assert!(periods.len() >= 4);
+            let home_points: u32 = periods.iter().map(|(home, _)| home).sum();
+            let away_points: u32 = periods.iter().map(|(_, away)| away).sum();
+            assert_eq!(home_points, result.context.home_score());
+            assert_eq!(away_points, result.context.away_score());
+            assert_eq!(result.box_score.home().passing_yards(), result.home_stats.passing().yards());
+        }
+
+        // A game cut off by the play limit fails rather than running on
+        let context = GameContext::with_coin_toss("HOME", "AWAY", &mut rng).unwrap();
+        let err = GameSimulator::with_max_plays(50).sim_result(&home, &away, context, &mut rng).unwrap_err();
+        assert_eq!(err.to_string(), "Game did not end within the maximum of 50 plays");
+        assert_eq!(err.code(), "GAME_INVALID");
+    }
+
+    #[test]
+    fn test_two_point_conversion_chart_and_returns() {
+        use crate::game::play::context::PlayContext;
+        use crate::game::play::result::{PlayResult, PlayTypeResult, ScoreResult, TwoPointAttemptResult};
+        use crate::game::play::result::pass::PassResultBuilder;
+        use crate::game::play::result::run::RunResultBuilder;
+
+        // The home team lines up for the try at the away 2 after a touchdown
+        let try_context = |quarter: u32, home_score: u32, away_score: u32| GameContextBuilder::new()
+            .quarter(quarter)
+            .half_seconds(if quarter == 4 { 90 } else { 990 })
+            .down(0)
+            .distance(2)
+            .yard_line(98)
+            .home_score(home_score)
+            .away_score(away_score)
+            .home_possession(true)
+            .home_positive_direction(true)
+            .next_play_kickoff(false)
+            .next_play_extra_point(true)
+            .build()
+            .unwrap();
+
+        // Late in the 4th quarter the chart decides, scores including the touchdown
+        let goes_for_two = |quarter: u32, home_score: u32, away_score: u32| {
+            PlayContext::from(&try_context(quarter, home_score, away_score)).two_point_conversion()
+        };
+        assert!(goes_for_two(4, 20, 22));  // Down 8 before the touchdown
+        assert!(!goes_for_two(4, 21, 22)); // Down 7 kicks to tie
+        assert!(!goes_for_two(4, 21, 21)); // Down 6 kicks to lead
+        assert!(goes_for_two(4, 22, 21));  // Down 5 goes up 3
+        assert!(goes_for_two(4, 10, 30));  // Down 26 needs every point
+        assert!(!goes_for_two(3, 20, 22)); // Too early to chase points
+
+        // A successful try runs no clock, and the scoring team kicks off
+        let context = try_context(4, 20, 22);
+        let good = RunResultBuilder::new()
+            .play_duration(4)
+            .yards_gained(2)
+            .touchdown(true)
+            .two_point_conversion(true)
+            .build()
+            .unwrap();
+        assert_eq!(PlayTypeResult::Run(good).two_point_attempt(), Some(TwoPointAttemptResult::Good));
+        let next = good.next_context(&context).unwrap();
+        assert_eq!((next.home_score(), next.away_score()), (22, 22));
+        assert_eq!(next.half_seconds(), 90);
+        assert!(next.next_play_kickoff() && next.home_possession());
+
+        // A failed try scores nothing
+        let no_good = PassResultBuilder::new().play_duration(5).two_point_conversion(true).build().unwrap();
+        assert_eq!(PlayTypeResult::Pass(no_good).two_point_attempt(), Some(TwoPointAttemptResult::NoGood));
+        let next = no_good.next_context(&context).unwrap();
+        assert_eq!((next.home_score(), next.away_score(), next.half_seconds()), (20, 22, 90));
+
+        // An interception returned the other way scores two for the defense,
+        // and the team which scored the touchdown still kicks off from its 35
+        let returned = PassResultBuilder::new()
+            .play_duration(5)
+            .pass_dist(3)
+            .interception(true)
+            .return_yards(100)
+            .touchdown(true)
+            .two_point_conversion(true)
+            .build()
+            .unwrap();
+        assert_eq!(returned.offense_score(), ScoreResult::None);
+        assert_eq!(returned.defense_score(), ScoreResult::TwoPointConversion);
+        assert_eq!(PlayTypeResult::Pass(returned).two_point_attempt(), Some(TwoPointAttemptResult::DefensiveReturn));
+        assert!(returned.to_string().ends_with("Returned for a defensive two point conversion!"));
+        let next = returned.next_context(&context).unwrap();
+        assert_eq!((next.home_score(), next.away_score()), (20, 24));
+        assert_eq!(next.half_seconds(), 90);
+        assert!(next.next_play_kickoff() && !next.next_play_extra_point());
+        assert!(next.home_possession());
+        assert_eq!(next.yard_line(), 35);
+
+        // Neither kind of play is a two-point attempt outside of a try
+        let run = RunResultBuilder::new().yards_gained(2).build().unwrap();
+        assert_eq!(PlayTypeResult::Run(run).two_point_attempt(), None);
+    }
+
+    #[test]
+    fn test_pick_six_on_last_play_of_quarter() {
+        use crate::game::play::result::betweenplay::BetweenPlayResult;
+        use crate::game::play::result::fieldgoal::FieldGoalResultBuilder;
+        use crate::game::play::result::pass::PassResultBuilder;
+
+        for (quarter, home_possession, home_positive_direction) in [
+            (1, true, true), (1, false, true), (3, true, false), (3, false, false)
+        ] {
+            // An interception returned 60 yards for a touchdown as time
+            // expires in the quarter
+            let context = GameContextBuilder::new()
+                .quarter(quarter)
+                .half_seconds(905)
+                .down(2)
+                .distance(10)
+                .yard_line(if home_possession ^ home_positive_direction { 40 } else { 60 })
+                .home_possession(home_possession)
+                .home_positive_direction(home_positive_direction)
+                .next_play_kickoff(false)
+                .build()
+                .unwrap();
+            let pick_six = PassResultBuilder::new()
+                .play_duration(8)
+                .pass_dist(12)
+                .interception(true)
+                .return_yards(60)
+                .touchdown(true)
+                .build()
+                .unwrap();
+
+            // The defense tries the extra point from the 2 before the quarter
+            // ends, without flipping the field
+            let next = pick_six.next_context(&context).unwrap();
+            let next = BetweenPlayResult::new().next_context(&next).unwrap();
+            assert_eq!(next.quarter(), quarter);
+            assert_eq!(next.half_seconds(), 900);
+            assert_eq!(next.home_possession(), !home_possession);
+            assert_eq!(next.home_positive_direction(), home_positive_direction);
+            assert!(next.next_play_extra_point());
+            assert_eq!((next.down(), next.distance()), (0, 2));
+            let expected_yard_line = if next.home_possession() ^ home_positive_direction { 2 } else { 98 };
+            assert_eq!(next.yard_line(), expected_yard_line);
+
+            // After the try the quarter ends, the field flips, and the
+            // scoring team kicks off from its 35
+            let extra_point = FieldGoalResultBuilder::new()
+                .field_goal_distance(20)
+                .made(true)
+                .extra_point(true)
+                .build()
+                .unwrap();
+            let kickoff = extra_point.next_context(&next).unwrap();
+            assert_eq!(kickoff.quarter(), quarter + 1);
+            assert_eq!(kickoff.half_seconds(), 900);
+            assert_eq!(kickoff.home_positive_direction(), !home_positive_direction);
+            assert_eq!(kickoff.home_possession(), !home_possession);
+            assert!(kickoff.next_play_kickoff());
+            let expected_yard_line = if kickoff.home_possession() ^ kickoff.home_positive_direction() { 65 } else { 35 };
+            assert_eq!(kickoff.yard_line(), expected_yard_line);
+        }
+    }
+
+    #[test]
+    fn test_clock_rules() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::game::play::GameSimulator;
+        use crate::team::FootballTeam;
+
+        // Clock rules apply only before the game starts, and must be valid
+        let rules = ClockRules::new().quarter_seconds(600);
+        let context = GameContext::new().with_clock_rules(rules).unwrap();
+        assert_eq!(context.half_seconds(), 1200);
+        assert!(GameContext::new().with_clock_rules(ClockRules::new().quarters(3)).is_err());
+        let started = GameContextBuilder::new().quarter(2).half_seconds(900).build().unwrap();
+        assert!(started.with_clock_rules(rules).is_err());
+        assert!(GameContextBuilder::new().clock_rules(rules).half_seconds(1500).build().is_err());
+        assert!(GameContextBuilder::new().clock_rules(rules).half_seconds(1200).build().is_ok());
+
+        // Default rules are omitted when serialized, and a context saved
+        // without clock rules loads with the defaults
+        let default_json = serde_json::to_value(GameContext::new()).unwrap();
+        assert!(default_json.get("clock_rules").is_none());
+        let loaded: GameContext = serde_json::from_value(default_json).unwrap();
+        assert!(loaded.clock_rules().is_default());
+        let custom_json = serde_json::to_string(&context).unwrap();
+        let loaded: GameContext = serde_json::from_str(&custom_json).unwrap();
+        assert_eq!(loaded, context);
+
+        // Games with 10 minute quarters last 40 minutes of regulation
+        let home = FootballTeam::new();
+        let away = FootballTeam::new();
+        let mut rng = SmallRng::seed_from_u64(1525);
+        for _ in 0..10 {
+            let (game, end) = GameSimulator::new().sim(&home, &away, context.clone(), &mut rng).unwrap();
+            assert!(end.game_over());
+            assert!(end.quarter() >= 4);
+            let overtime = match end.quarter() {
+                q if q > 4 => 600 * (q - 4) - end.half_seconds(),
+                _ => 0
+            };
+            let box_score = game.box_score(&end);
+            assert_eq!(
+                box_score.home().time_of_possession() + box_score.away().time_of_possession(),
+                2400 + overtime
+            );
+            assert_eq!(box_score.home().period_points().len(), end.quarter().max(4) as usize);
+        }
+    }
+
+    #[test]
+    fn test_weather() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::game::context::weather::WeatherDistribution;
+        use crate::game::play::result::{PlayResultSimulator, PlayTypeResult};
+        use crate::game::play::result::fieldgoal::FieldGoalResultSimulator;
+        use crate::game::play::result::pass::PassResultSimulator;
+        use crate::team::FootballTeam;
+
+        // Clear weather is omitted when serialized, and a context saved
+        // without weather loads as clear
+        let clear_json = serde_json::to_value(GameContext::new()).unwrap();
+        assert!(clear_json.get("weather").is_none());
+        let loaded: GameContext = serde_json::from_value(clear_json).unwrap();
+        assert_eq!(loaded.weather(), Weather::Clear);
+        let snow = GameContext::new().with_weather(Weather::Snow);
+        let snow_json = serde_json::to_string(&snow).unwrap();
+        let loaded: GameContext = serde_json::from_str(&snow_json).unwrap();
+        assert_eq!(loaded, snow);
+
+        // Passes are completed less often and gain fewer yards in the snow
+        let offense = FootballTeam::new();
+        let defense = FootballTeam::new();
+        let mut rng = SmallRng::seed_from_u64(1528);
+        let own_30 = GameContextBuilder::new().down(3).distance(10).yard_line(30).build().unwrap();
+        let completion_rate = |context: &GameContext, rng: &mut SmallRng| {
+            let completions = (0..4000).filter(|_| matches!(
+                PassResultSimulator::new().sim(&offense, &defense, context, rng),
+                PlayTypeResult::Pass(result) if result.complete()
+            )).count();
+            completions as f64 / 4000_f64
+        };
+        let clear_rate = completion_rate(&own_30, &mut rng);
+        let snow_rate = completion_rate(&own_30.with_weather(Weather::Snow), &mut rng);
+        assert!(snow_rate < clear_rate);
+        let clear_passes = PassResultSimulator::new().distribution_sample(4000, &offense, &defense, &own_30, &mut rng);
+        let snow_passes = PassResultSimulator::new().distribution_sample(
+            4000, &offense, &defense, &own_30.with_weather(Weather::Snow), &mut rng
+        );
+        assert!(snow_passes.mean_net_yards() < clear_passes.mean_net_yards());
+
+        // Long field goals are missed more often in the snow
+        let opp_35 = GameContextBuilder::new().down(4).distance(10).yard_line(65).build().unwrap();
+        let clear_fgs = FieldGoalResultSimulator::new().distribution_sample(4000, &offense, &defense, &opp_35, &mut rng);
+        let snow_fgs = FieldGoalResultSimulator::new().distribution_sample(
+            4000, &offense, &defense, &opp_35.with_weather(Weather::Snow), &mut rng
+        );
+        assert!(snow_fgs.offense_score_rate() < clear_fgs.offense_score_rate());
+
+        // Weather is generated in proportion to the distribution
+        let distribution = WeatherDistribution::new().clear(0.5).rain(0.5).snow(0.0).wind(0.0).dome(0.0);
+        let samples: Vec<Weather> = (0..2000).map(|_| distribution.sample(&mut rng)).collect();
+        let rain = samples.iter().filter(|w| **w == Weather::Rain).count();
+        assert!(samples.iter().all(|w| *w == Weather::Clear || *w == Weather::Rain));
+        assert!(rain > 900 && rain < 1100);
+        let invalid = WeatherDistribution::new().clear(-1.0);
+        assert_eq!(invalid.sample(&mut rng), Weather::Clear);
+    }
 }
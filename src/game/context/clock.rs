@@ -0,0 +1,289 @@
+#![doc = include_str!("../../../docs/game/context/clock.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+/// # `ClockRules` struct
+///
+/// The length and number of the periods a game is played in
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClockRules {
+    /// Length of each regulation quarter in seconds
+    pub quarter_seconds: u32,
+    /// Number of regulation quarters, split evenly between two halves
+    pub quarters: u32,
+    /// Length of each overtime period in seconds
+    pub overtime_seconds: u32
+}
+
+impl Default for ClockRules {
+    /// Default constructor for the `ClockRules` struct, four 15 minute
+    /// quarters and 10 minute overtime periods
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// let my_rules = ClockRules::default();
+    /// assert_eq!(my_rules.quarter_seconds, 900);
+    /// assert_eq!(my_rules.quarters, 4);
+    /// assert_eq!(my_rules.overtime_seconds, 600);
+    /// ```
+    fn default() -> Self {
+        ClockRules {
+            quarter_seconds: 900,
+            quarters: 4,
+            overtime_seconds: 600
+        }
+    }
+}
+
+impl ClockRules {
+    /// Constructor for the `ClockRules` struct, four 15 minute quarters and
+    /// 10 minute overtime periods
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// let my_rules = ClockRules::new();
+    /// assert!(my_rules.is_default());
+    /// ```
+    pub fn new() -> ClockRules {
+        ClockRules::default()
+    }
+
+    /// Set the length of each regulation quarter in seconds
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// let my_rules = ClockRules::new().quarter_seconds(600);
+    /// assert_eq!(my_rules.quarter_seconds, 600);
+    /// ```
+    pub fn quarter_seconds(mut self, quarter_seconds: u32) -> Self {
+        self.quarter_seconds = quarter_seconds;
+        self
+    }
+
+    /// Set the number of regulation quarters
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// let my_rules = ClockRules::new().quarters(2);
+    /// assert_eq!(my_rules.quarters, 2);
+    /// ```
+    pub fn quarters(mut self, quarters: u32) -> Self {
+        self.quarters = quarters;
+        self
+    }
+
+    /// Set the length of each overtime period in seconds
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// let my_rules = ClockRules::new().overtime_seconds(300);
+    /// assert_eq!(my_rules.overtime_seconds, 300);
+    /// ```
+    pub fn overtime_seconds(mut self, overtime_seconds: u32) -> Self {
+        self.overtime_seconds = overtime_seconds;
+        self
+    }
+
+    /// Ensure every period has time on its clock and the quarters split
+    /// evenly between two halves
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// assert!(ClockRules::new().validate().is_ok());
+    /// assert!(ClockRules::new().quarters(3).validate().is_err());
+    /// assert!(ClockRules::new().quarter_seconds(0).validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        if self.quarter_seconds == 0 {
+            return Err(String::from("Quarter seconds must be at least 1"));
+        }
+        if self.overtime_seconds == 0 {
+            return Err(String::from("Overtime seconds must be at least 1"));
+        }
+        if self.quarters == 0 || !self.quarters.is_multiple_of(2) {
+            return Err(
+                format!(
+                    "Number of quarters must be a positive even number: {}",
+                    self.quarters
+                )
+            );
+        }
+        Ok(())
+    }
+
+    /// Whether the rules are the default four 15 minute quarters and 10
+    /// minute overtime periods
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// assert!(ClockRules::new().is_default());
+    /// assert!(!ClockRules::new().quarter_seconds(600).is_default());
+    /// ```
+    pub fn is_default(&self) -> bool {
+        *self == ClockRules::default()
+    }
+
+    /// Get the number of quarters in each half
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// assert_eq!(ClockRules::new().quarters_per_half(), 2);
+    /// ```
+    pub fn quarters_per_half(&self) -> u32 {
+        (self.quarters / 2).max(1)
+    }
+
+    /// Get the length of each half in seconds
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// assert_eq!(ClockRules::new().half_length(), 1800);
+    /// assert_eq!(ClockRules::new().quarter_seconds(600).half_length(), 1200);
+    /// ```
+    pub fn half_length(&self) -> u32 {
+        self.quarter_seconds * self.quarters_per_half()
+    }
+
+    /// Get the length of regulation in seconds
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// assert_eq!(ClockRules::new().regulation_seconds(), 3600);
+    /// assert_eq!(ClockRules::new().quarter_seconds(600).regulation_seconds(), 2400);
+    /// ```
+    pub fn regulation_seconds(&self) -> u32 {
+        self.half_length() * 2
+    }
+
+    /// Whether a quarter is the last of the first half
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// assert!(ClockRules::new().halftime_quarter(2));
+    /// assert!(!ClockRules::new().halftime_quarter(4));
+    /// ```
+    pub fn halftime_quarter(&self, quarter: u32) -> bool {
+        quarter == self.quarters_per_half()
+    }
+
+    /// Whether a quarter is in the second half or overtime
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// assert!(!ClockRules::new().second_half(2));
+    /// assert!(ClockRules::new().second_half(3));
+    /// ```
+    pub fn second_half(&self, quarter: u32) -> bool {
+        quarter > self.quarters_per_half()
+    }
+
+    /// Whether a quarter is the last of regulation or in overtime
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// assert!(!ClockRules::new().final_quarter(3));
+    /// assert!(ClockRules::new().final_quarter(4));
+    /// assert!(ClockRules::new().final_quarter(5));
+    /// ```
+    pub fn final_quarter(&self, quarter: u32) -> bool {
+        quarter >= self.quarters
+    }
+
+    /// Whether a quarter is an overtime period
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// assert!(!ClockRules::new().overtime(4));
+    /// assert!(ClockRules::new().overtime(5));
+    /// ```
+    pub fn overtime(&self, quarter: u32) -> bool {
+        quarter > self.quarters
+    }
+
+    /// Whether the half ends with a quarter: the last quarter of the first
+    /// half, the last quarter of regulation, or any overtime period
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// assert!(!ClockRules::new().ends_half(1));
+    /// assert!(ClockRules::new().ends_half(2));
+    /// assert!(ClockRules::new().ends_half(5));
+    /// ```
+    pub fn ends_half(&self, quarter: u32) -> bool {
+        self.halftime_quarter(quarter) || self.final_quarter(quarter)
+    }
+
+    /// Get the half seconds at which a quarter starts
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// let my_rules = ClockRules::new();
+    /// assert_eq!(my_rules.quarter_start(3), 1800);
+    /// assert_eq!(my_rules.quarter_start(4), 900);
+    /// assert_eq!(my_rules.quarter_start(5), 600);
+    /// ```
+    pub fn quarter_start(&self, quarter: u32) -> u32 {
+        if self.overtime(quarter) {
+            return self.overtime_seconds;
+        }
+        let position = quarter.saturating_sub(1) % self.quarters_per_half();
+        self.half_length() - position * self.quarter_seconds
+    }
+
+    /// Get the half seconds at which a quarter ends
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::clock::ClockRules;
+    ///
+    /// let my_rules = ClockRules::new();
+    /// assert_eq!(my_rules.quarter_end(1), 900);
+    /// assert_eq!(my_rules.quarter_end(2), 0);
+    /// assert_eq!(my_rules.quarter_end(5), 0);
+    /// ```
+    pub fn quarter_end(&self, quarter: u32) -> u32 {
+        if self.overtime(quarter) {
+            return 0;
+        }
+        self.quarter_start(quarter).saturating_sub(self.quarter_seconds)
+    }
+}
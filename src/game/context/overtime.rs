@@ -0,0 +1,146 @@
+#![doc = include_str!("../../../docs/game/context/overtime.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+/// # `OvertimeMode` enum
+///
+/// How an overtime period is decided
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub enum OvertimeMode {
+    /// Each overtime period is played to the end of its clock, and the game
+    /// ends once a period ends with the score untied
+    #[default]
+    FullPeriods,
+    /// The first score of overtime ends the game
+    SuddenDeath,
+    /// Both teams possess the ball unless the first possession ends in a
+    /// touchdown or the defense scores, after which the next score ends the
+    /// game
+    ModifiedSuddenDeath
+}
+
+/// # `OvertimeRules` struct
+///
+/// The rules a game follows when it is tied at the end of regulation
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct OvertimeRules {
+    /// How each overtime period is decided
+    pub mode: OvertimeMode,
+    /// Whether the game ends in a tie if the first overtime period ends
+    /// tied, rather than continuing into another period
+    pub allow_ties: bool
+}
+
+impl OvertimeRules {
+    /// Constructor for the `OvertimeRules` struct, playing full overtime
+    /// periods until one ends with the score untied
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::overtime::{OvertimeMode, OvertimeRules};
+    ///
+    /// let my_rules = OvertimeRules::new();
+    /// assert_eq!(my_rules.mode, OvertimeMode::FullPeriods);
+    /// assert!(!my_rules.allow_ties);
+    /// ```
+    pub fn new() -> OvertimeRules {
+        OvertimeRules::default()
+    }
+
+    /// Overtime rules for a regular season game: modified sudden death,
+    /// ending in a tie if the first overtime period ends tied
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::overtime::{OvertimeMode, OvertimeRules};
+    ///
+    /// let my_rules = OvertimeRules::regular_season();
+    /// assert_eq!(my_rules.mode, OvertimeMode::ModifiedSuddenDeath);
+    /// assert!(my_rules.allow_ties);
+    /// ```
+    pub fn regular_season() -> OvertimeRules {
+        OvertimeRules::new()
+            .mode(OvertimeMode::ModifiedSuddenDeath)
+            .allow_ties(true)
+    }
+
+    /// Overtime rules for a playoff game: modified sudden death, playing
+    /// further overtime periods until the game is decided
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::overtime::{OvertimeMode, OvertimeRules};
+    ///
+    /// let my_rules = OvertimeRules::playoffs();
+    /// assert_eq!(my_rules.mode, OvertimeMode::ModifiedSuddenDeath);
+    /// assert!(!my_rules.allow_ties);
+    /// ```
+    pub fn playoffs() -> OvertimeRules {
+        OvertimeRules::new()
+            .mode(OvertimeMode::ModifiedSuddenDeath)
+    }
+
+    /// Set how each overtime period is decided
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::overtime::{OvertimeMode, OvertimeRules};
+    ///
+    /// let my_rules = OvertimeRules::new().mode(OvertimeMode::SuddenDeath);
+    /// assert_eq!(my_rules.mode, OvertimeMode::SuddenDeath);
+    /// ```
+    pub fn mode(mut self, mode: OvertimeMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set whether the game ends in a tie if the first overtime period ends
+    /// tied
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::overtime::OvertimeRules;
+    ///
+    /// let my_rules = OvertimeRules::new().allow_ties(true);
+    /// assert!(my_rules.allow_ties);
+    /// ```
+    pub fn allow_ties(mut self, allow_ties: bool) -> Self {
+        self.allow_ties = allow_ties;
+        self
+    }
+
+    /// Whether the rules are the default full overtime periods without ties
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::overtime::OvertimeRules;
+    ///
+    /// assert!(OvertimeRules::new().is_default());
+    /// assert!(!OvertimeRules::playoffs().is_default());
+    /// ```
+    pub fn is_default(&self) -> bool {
+        *self == OvertimeRules::default()
+    }
+
+    /// Whether overtime periods may end before their clock runs out
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::overtime::OvertimeRules;
+    ///
+    /// assert!(!OvertimeRules::new().sudden_death());
+    /// assert!(OvertimeRules::regular_season().sudden_death());
+    /// ```
+    pub fn sudden_death(&self) -> bool {
+        self.mode != OvertimeMode::FullPeriods
+    }
+}
@@ -0,0 +1,372 @@
+#![doc = include_str!("../../../docs/game/context/weather.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+use std::cmp::Ordering;
+
+/// # `Weather` enum
+///
+/// The conditions a game is played in
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub enum Weather {
+    /// Clear skies, which do not affect play
+    #[default]
+    Clear,
+    /// Rain, which shortens and spoils passes and loosens the ball
+    Rain,
+    /// Snow, which affects passing, ball security, and kicking the most
+    Snow,
+    /// Wind, which pushes kicks off line and holds up long passes
+    Wind,
+    /// A domed stadium, sheltered from the weather
+    Dome
+}
+
+impl Weather {
+    /// Whether the weather is clear, the default
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::weather::Weather;
+    ///
+    /// assert!(Weather::Clear.is_clear());
+    /// assert!(!Weather::Snow.is_clear());
+    /// ```
+    pub fn is_clear(&self) -> bool {
+        *self == Weather::Clear
+    }
+
+    /// Get the modifiers the weather applies to play outcomes
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::weather::{Weather, WeatherModifiers};
+    ///
+    /// assert_eq!(Weather::Dome.modifiers(), WeatherModifiers::default());
+    /// assert!(Weather::Snow.modifiers().completion < 1.0);
+    /// assert!(Weather::Wind.modifiers().field_goal < 1.0);
+    /// ```
+    pub fn modifiers(&self) -> WeatherModifiers {
+        match self {
+            Weather::Clear | Weather::Dome => WeatherModifiers::default(),
+            Weather::Rain => WeatherModifiers {
+                pass_distance: 0.92,
+                completion: 0.93,
+                fumble: 1.4,
+                field_goal: 0.97,
+                kick_distance: 0.97
+            },
+            Weather::Snow => WeatherModifiers {
+                pass_distance: 0.85,
+                completion: 0.86,
+                fumble: 1.6,
+                field_goal: 0.92,
+                kick_distance: 0.92
+            },
+            Weather::Wind => WeatherModifiers {
+                pass_distance: 0.93,
+                completion: 0.95,
+                fumble: 1.0,
+                field_goal: 0.88,
+                kick_distance: 0.9
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Weather {
+    /// Format the weather as its name
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::weather::Weather;
+    ///
+    /// assert_eq!(Weather::Snow.to_string(), "Snow");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Weather::Clear => "Clear",
+            Weather::Rain => "Rain",
+            Weather::Snow => "Snow",
+            Weather::Wind => "Wind",
+            Weather::Dome => "Dome"
+        };
+        f.write_str(name)
+    }
+}
+
+/// # `WeatherModifiers` struct
+///
+/// Multipliers the weather applies to the play result simulators. Each is 1
+/// in clear weather and in a dome, leaving play unchanged.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct WeatherModifiers {
+    /// Multiplier on the mean distance of a pass
+    pub pass_distance: f64,
+    /// Multiplier on the probability a pass is completed
+    pub completion: f64,
+    /// Multiplier on the probability the ball carrier fumbles on a run or
+    /// after a catch
+    pub fumble: f64,
+    /// Multiplier on the probability a field goal or extra point is made
+    pub field_goal: f64,
+    /// Multiplier on the distance of a kickoff or punt
+    pub kick_distance: f64
+}
+
+impl Default for WeatherModifiers {
+    /// Default constructor for the `WeatherModifiers` struct, which leaves
+    /// play unchanged
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::weather::WeatherModifiers;
+    ///
+    /// let my_modifiers = WeatherModifiers::default();
+    /// assert_eq!(my_modifiers.fumble, 1.0);
+    /// ```
+    fn default() -> Self {
+        WeatherModifiers {
+            pass_distance: 1.0,
+            completion: 1.0,
+            fumble: 1.0,
+            field_goal: 1.0,
+            kick_distance: 1.0
+        }
+    }
+}
+
+/// # `WeatherDistribution` struct
+///
+/// The relative likelihood of each kind of weather, used to generate the
+/// weather for a game at random
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WeatherDistribution {
+    /// Relative likelihood of clear weather
+    pub clear: f64,
+    /// Relative likelihood of rain
+    pub rain: f64,
+    /// Relative likelihood of snow
+    pub snow: f64,
+    /// Relative likelihood of wind
+    pub wind: f64,
+    /// Relative likelihood of a domed stadium
+    pub dome: f64
+}
+
+impl Default for WeatherDistribution {
+    /// Default constructor for the `WeatherDistribution` struct, mostly
+    /// clear with occasional rain, wind, and snow, and some games in domes
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::weather::WeatherDistribution;
+    ///
+    /// let my_distribution = WeatherDistribution::default();
+    /// assert!(my_distribution.clear > my_distribution.snow);
+    /// ```
+    fn default() -> Self {
+        WeatherDistribution {
+            clear: 0.55,
+            rain: 0.12,
+            snow: 0.05,
+            wind: 0.1,
+            dome: 0.18
+        }
+    }
+}
+
+impl PartialEq for WeatherDistribution {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for WeatherDistribution {}
+
+impl PartialOrd for WeatherDistribution {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for WeatherDistribution {
+    /// Compare each likelihood in turn by its total order, so that a
+    /// distribution can be held by types which are `Eq` and `Ord`
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.weights().iter().zip(other.weights().iter())
+            .map(|((_, a), (_, b))| a.total_cmp(b))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl WeatherDistribution {
+    /// Constructor for the `WeatherDistribution` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::weather::WeatherDistribution;
+    ///
+    /// let my_distribution = WeatherDistribution::new();
+    /// assert!(my_distribution.validate().is_ok());
+    /// ```
+    pub fn new() -> WeatherDistribution {
+        WeatherDistribution::default()
+    }
+
+    /// Set the relative likelihood of clear weather
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::weather::WeatherDistribution;
+    ///
+    /// let my_distribution = WeatherDistribution::new().clear(1.0);
+    /// assert_eq!(my_distribution.clear, 1.0);
+    /// ```
+    pub fn clear(mut self, clear: f64) -> Self {
+        self.clear = clear;
+        self
+    }
+
+    /// Set the relative likelihood of rain
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::weather::WeatherDistribution;
+    ///
+    /// let my_distribution = WeatherDistribution::new().rain(0.3);
+    /// assert_eq!(my_distribution.rain, 0.3);
+    /// ```
+    pub fn rain(mut self, rain: f64) -> Self {
+        self.rain = rain;
+        self
+    }
+
+    /// Set the relative likelihood of snow
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::weather::WeatherDistribution;
+    ///
+    /// let my_distribution = WeatherDistribution::new().snow(0.2);
+    /// assert_eq!(my_distribution.snow, 0.2);
+    /// ```
+    pub fn snow(mut self, snow: f64) -> Self {
+        self.snow = snow;
+        self
+    }
+
+    /// Set the relative likelihood of wind
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::weather::WeatherDistribution;
+    ///
+    /// let my_distribution = WeatherDistribution::new().wind(0.2);
+    /// assert_eq!(my_distribution.wind, 0.2);
+    /// ```
+    pub fn wind(mut self, wind: f64) -> Self {
+        self.wind = wind;
+        self
+    }
+
+    /// Set the relative likelihood of a domed stadium
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::weather::WeatherDistribution;
+    ///
+    /// let my_distribution = WeatherDistribution::new().dome(0.0);
+    /// assert_eq!(my_distribution.dome, 0.0);
+    /// ```
+    pub fn dome(mut self, dome: f64) -> Self {
+        self.dome = dome;
+        self
+    }
+
+    /// Get each kind of weather with its relative likelihood
+    fn weights(&self) -> [(Weather, f64); 5] {
+        [
+            (Weather::Clear, self.clear),
+            (Weather::Rain, self.rain),
+            (Weather::Snow, self.snow),
+            (Weather::Wind, self.wind),
+            (Weather::Dome, self.dome)
+        ]
+    }
+
+    /// Ensure every likelihood is finite and non-negative, and that at least
+    /// one is positive
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::weather::WeatherDistribution;
+    ///
+    /// assert!(WeatherDistribution::new().validate().is_ok());
+    /// assert!(WeatherDistribution::new().rain(-0.1).validate().is_err());
+    /// let none = WeatherDistribution::new().clear(0.0).rain(0.0).snow(0.0).wind(0.0).dome(0.0);
+    /// assert!(none.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        for (weather, weight) in self.weights() {
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(
+                    format!(
+                        "Likelihood of {} weather must be a non-negative number: {}",
+                        weather, weight
+                    )
+                );
+            }
+        }
+        if self.weights().iter().all(|(_, weight)| *weight == 0.0) {
+            return Err(String::from("At least one kind of weather must have a positive likelihood"));
+        }
+        Ok(())
+    }
+
+    /// Generate the weather for a game at random. Falls back to clear
+    /// weather if the distribution is invalid.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::context::weather::{Weather, WeatherDistribution};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let my_distribution = WeatherDistribution::new().clear(0.0).rain(0.0).wind(0.0).dome(0.0);
+    /// let my_context = GameContext::new().with_weather(my_distribution.sample(&mut rng));
+    /// assert_eq!(my_context.weather(), Weather::Snow);
+    /// ```
+    pub fn sample(&self, rng: &mut impl Rng) -> Weather {
+        if self.validate().is_err() {
+            return Weather::Clear;
+        }
+        let total: f64 = self.weights().iter().map(|(_, weight)| weight).sum();
+        let mut draw = rng.gen::<f64>() * total;
+        for (weather, weight) in self.weights() {
+            if weight > 0.0 && draw < weight {
+                return weather;
+            }
+            draw -= weight;
+        }
+        self.weights().iter().rev()
+            .find(|(_, weight)| *weight > 0.0)
+            .map(|(weather, _)| *weather)
+            .unwrap_or_default()
+    }
+}
+
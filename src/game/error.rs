@@ -0,0 +1,100 @@
+#![doc = include_str!("../../docs/game/error.md")]
+use std::fmt;
+
+use crate::game::context::GameAlreadyOver;
+
+/// # `GameError` enum
+///
+/// An error from simulating or advancing a game, each with a stable code
+/// for hosts to match on.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum GameError {
+    /// The game context is terminal, so no more plays may be simulated or
+    /// applied from it
+    GameAlreadyOver,
+    /// Any other invalid input or state
+    Invalid { reason: String }
+}
+
+impl GameError {
+    /// Get the stable code of the error
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::error::GameError;
+    ///
+    /// assert_eq!(GameError::GameAlreadyOver.code(), "GAME_ALREADY_OVER");
+    /// ```
+    pub fn code(&self) -> &'static str {
+        match self {
+            GameError::GameAlreadyOver => "GAME_ALREADY_OVER",
+            GameError::Invalid { .. } => "GAME_INVALID"
+        }
+    }
+}
+
+impl fmt::Display for GameError {
+    /// Format a `GameError` as its description
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::error::GameError;
+    ///
+    /// assert_eq!(GameError::GameAlreadyOver.to_string(), "Game is already over");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::GameAlreadyOver => write!(f, "{}", GameAlreadyOver),
+            GameError::Invalid { reason } => f.write_str(reason)
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+impl From<GameAlreadyOver> for GameError {
+    /// Convert a `GameAlreadyOver` error into its `GameError` variant
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameAlreadyOver;
+    /// use fbsim_core::game::error::GameError;
+    ///
+    /// assert_eq!(GameError::from(GameAlreadyOver), GameError::GameAlreadyOver);
+    /// ```
+    fn from(_: GameAlreadyOver) -> Self {
+        GameError::GameAlreadyOver
+    }
+}
+
+impl From<String> for GameError {
+    /// Convert an error message into an `Invalid` error, for messages from
+    /// validation
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::error::GameError;
+    ///
+    /// let my_error = GameError::from(String::from("Down not in range [0, 4]: 5"));
+    /// assert_eq!(my_error.code(), "GAME_INVALID");
+    /// ```
+    fn from(reason: String) -> Self {
+        GameError::Invalid { reason }
+    }
+}
+
+impl From<GameError> for String {
+    /// Convert a `GameError` into its description, for callers which still
+    /// handle errors as strings
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::error::GameError;
+    ///
+    /// let my_error: String = GameError::GameAlreadyOver.into();
+    /// assert_eq!(my_error, "Game is already over");
+    /// ```
+    fn from(error: GameError) -> Self {
+        error.to_string()
+    }
+}
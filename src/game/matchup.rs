@@ -10,6 +10,10 @@ use crate::team::FootballTeam;
 /// # `FootballMatchupResult` enum
 ///
 /// Represents a result (win, loss, tie) of a football game
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
 pub enum FootballMatchupResult {
     Win,
     Loss,
@@ -1,6 +1,9 @@
 #![doc = include_str!("../../docs/game/play.md")]
 pub mod call;
 pub mod context;
+pub mod decision;
+pub mod narration;
+pub mod penalty;
 pub mod result;
 
 #[cfg(feature = "rocket_okapi")]
@@ -13,15 +16,18 @@ use rand::Rng;
 use tsify_next::Tsify;
 
 use crate::game::context::GameContext;
+use crate::game::error::GameError;
 use crate::game::play::call::{PlayCallSimulator, PlayCall};
+use crate::game::play::decision::{Decision, DecisionLog};
+use crate::game::play::penalty::PenaltyResolution;
 use crate::game::play::result::{PlayResultSimulator, PlayResult, PlayTypeResult, ScoreResult};
 use crate::game::play::result::betweenplay::BetweenPlayResultSimulator;
 use crate::game::play::result::fieldgoal::FieldGoalResultSimulator;
 use crate::game::play::result::kickoff::KickoffResultSimulator;
 use crate::game::play::result::punt::PuntResultSimulator;
-use crate::game::play::result::pass::PassResultSimulator;
-use crate::game::play::result::run::RunResultSimulator;
-use crate::game::stat::{PassingStats, RushingStats, ReceivingStats, OffensiveStats};
+use crate::game::play::result::pass::{PassResult, PassResultSimulator};
+use crate::game::play::result::run::{RunResult, RunResultSimulator};
+use crate::game::stat::{PassingStats, RushingStats, ReceivingStats, OffensiveStats, GameStats};
 use crate::team::FootballTeam;
 use crate::team::coach::FootballTeamCoach;
 use crate::team::defense::FootballTeamDefense;
@@ -41,7 +47,9 @@ pub trait PlaySimulatable {
 pub struct Play {
     context: GameContext,
     result: PlayTypeResult,
-    post_play: PlayTypeResult
+    post_play: PlayTypeResult,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    penalty: Option<PenaltyResolution>
 }
 
 impl Play {
@@ -68,7 +76,8 @@ impl Play {
         Play{
             context,
             result,
-            post_play
+            post_play,
+            penalty: None
         }
     }
 
@@ -146,6 +155,68 @@ impl Play {
     pub fn context(&self) -> &GameContext {
         &self.context
     }
+
+    /// Borrow the resolution of the penalties called on the play, if any
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::Play;
+    /// use fbsim_core::game::play::result::PlayTypeResult;
+    /// use fbsim_core::game::play::result::betweenplay::BetweenPlayResult;
+    /// use fbsim_core::game::play::result::pass::PassResult;
+    /// use fbsim_core::game::context::GameContext;
+    ///
+    /// // Initialize a game context
+    /// let my_context = GameContext::new();
+    ///
+    /// // Initialize a play type result
+    /// let my_res = PlayTypeResult::Pass(PassResult::new());
+    /// let my_between = PlayTypeResult::BetweenPlay(BetweenPlayResult::new());
+    ///
+    /// // Initialize a play and borrow its penalty resolution
+    /// let my_play = Play::new(my_context, my_res, my_between);
+    /// assert!(my_play.penalty().is_none());
+    /// ```
+    pub fn penalty(&self) -> Option<&PenaltyResolution> {
+        self.penalty.as_ref()
+    }
+
+    /// Record the resolution of the penalties called on the play
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::Play;
+    /// use fbsim_core::game::play::penalty::{resolve_penalties, Penalty, PenaltyTeam, ExpectedPointsPenaltyStrategy};
+    /// use fbsim_core::game::play::result::PlayTypeResult;
+    /// use fbsim_core::game::play::result::betweenplay::BetweenPlayResult;
+    /// use fbsim_core::game::play::result::pass::PassResult;
+    /// use fbsim_core::game::context::{GameContextBuilder, PlayOutcome};
+    ///
+    /// // Initialize a game context
+    /// let my_context = GameContextBuilder::new()
+    ///     .down(1)
+    ///     .distance(10)
+    ///     .yard_line(25)
+    ///     .next_play_kickoff(false)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Resolve a penalty on the play
+    /// let penalties = vec![Penalty::new(PenaltyTeam::Defense, 5)];
+    /// let resolution = resolve_penalties(
+    ///     &my_context, &PlayOutcome::new(), &penalties, &ExpectedPointsPenaltyStrategy
+    /// ).unwrap();
+    ///
+    /// // Initialize a play and record the penalty resolution
+    /// let my_res = PlayTypeResult::Pass(PassResult::new());
+    /// let my_between = PlayTypeResult::BetweenPlay(BetweenPlayResult::new());
+    /// let my_play = Play::new(my_context, my_res, my_between).with_penalty(resolution);
+    /// assert!(my_play.penalty().is_some());
+    /// ```
+    pub fn with_penalty(mut self, penalty: PenaltyResolution) -> Self {
+        self.penalty = Some(penalty);
+        self
+    }
 }
 
 impl std::fmt::Display for Play {
@@ -249,17 +320,44 @@ impl PlaySimulator {
     /// // Initialize a play simulator and simulate a play
     /// let my_sim = PlaySimulator::new();
     /// let mut rng = rand::thread_rng();
-    /// let (play, new_context) = my_sim.sim(&my_home, &my_away, my_context, &mut rng);
+    /// let (play, new_context) = my_sim.sim(&my_home, &my_away, my_context, &mut rng).unwrap();
     /// ```
-    pub fn sim(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, rng: &mut impl Rng) -> (Play, GameContext) {
+    pub fn sim(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, rng: &mut impl Rng) -> Result<(Play, GameContext), GameError> {
+        let (play, new_context, _) = self.sim_with_decisions(home, away, context, rng)?;
+        Ok((play, new_context))
+    }
+
+    /// Simulate a play, along with the coaching decisions made before and
+    /// after it
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::PlaySimulator;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// // Initialize home & away teams
+    /// let my_home = FootballTeam::new();
+    /// let my_away = FootballTeam::new();
+    ///
+    /// // Initialize a game context
+    /// let my_context = GameContext::new();
+    ///
+    /// // Initialize a play simulator and simulate a play
+    /// let my_sim = PlaySimulator::new();
+    /// let mut rng = rand::thread_rng();
+    /// let (play, new_context, decisions) = my_sim.sim_with_decisions(&my_home, &my_away, my_context, &mut rng).unwrap();
+    /// ```
+    pub fn sim_with_decisions(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, rng: &mut impl Rng) -> Result<(Play, GameContext, Vec<Decision>), GameError> {
         // Determine the play call
-        let play_call = if context.next_play_kickoff() {
-            PlayCall::Kickoff
+        let (play_call, decision) = if context.next_play_kickoff() {
+            (PlayCall::Kickoff, None)
         } else if context.home_possession() {
-            self.playcall.sim(home, &context, rng)
+            self.playcall.sim_with_decision(home, &context, rng)
         } else {
-            self.playcall.sim(away, &context, rng)
+            self.playcall.sim_with_decision(away, &context, rng)
         };
+        let mut decisions: Vec<Decision> = decision.into_iter().collect();
 
         // Simulate the play
         let result = if context.home_possession() {
@@ -270,8 +368,8 @@ impl PlaySimulator {
                 PlayCall::Punt => self.punt.sim(home, away, &context, rng),
                 PlayCall::Kickoff => self.kickoff.sim(home, away, &context, rng),
                 PlayCall::ExtraPoint => self.fieldgoal.sim(home, away, &context, rng),
-                PlayCall::QbKneel => self.run.sim(home, away, &context, rng),
-                PlayCall::QbSpike => self.pass.sim(home, away, &context, rng)
+                PlayCall::QbKneel => PlayTypeResult::QbKneel(RunResult::kneel()),
+                PlayCall::QbSpike => PlayTypeResult::QbSpike(PassResult::spike())
             }
         } else {
             match play_call {
@@ -281,20 +379,23 @@ impl PlaySimulator {
                 PlayCall::Punt => self.punt.sim(away, home, &context, rng),
                 PlayCall::Kickoff => self.kickoff.sim(away, home, &context, rng),
                 PlayCall::ExtraPoint => self.fieldgoal.sim(away, home, &context, rng),
-                PlayCall::QbKneel => self.run.sim(away, home, &context, rng),
-                PlayCall::QbSpike => self.pass.sim(away, home, &context, rng)
+                PlayCall::QbKneel => PlayTypeResult::QbKneel(RunResult::kneel()),
+                PlayCall::QbSpike => PlayTypeResult::QbSpike(PassResult::spike())
             }
         };
-        let next_context = result.next_context(&context);
+        let next_context = result.next_context(&context)
+            .map_err(|e| format!("Invalid context after {:?} play: {}", play_call, e))?;
 
         // Simulate between plays
-        let between_res = if context.home_possession() {
-            self.betweenplay.sim(home, away, &next_context, rng)
+        let (between_res, between_decisions) = if context.home_possession() {
+            self.betweenplay.sim_with_decisions(home, away, &next_context, rng)
         } else {
-            self.betweenplay.sim(away, home, &next_context, rng)
+            self.betweenplay.sim_with_decisions(away, home, &next_context, rng)
         };
-        let new_context = between_res.next_context(&next_context);
-        (Play::new(context, result, between_res), new_context)
+        decisions.extend(between_decisions);
+        let new_context = between_res.next_context(&next_context)
+            .map_err(|e| format!("Invalid context between plays: {}", e))?;
+        Ok((Play::new(context, result, between_res), new_context, decisions))
     }
 }
 
@@ -601,6 +702,17 @@ impl std::fmt::Display for Drive {
     }
 }
 
+/// Prefix the description of an invalid simulation error, leaving
+/// `GameAlreadyOver` as is so callers can still match on it
+fn prefix_error(prefix: &str, error: GameError) -> GameError {
+    match error {
+        GameError::Invalid { reason } => GameError::Invalid {
+            reason: format!("{}: {}", prefix, reason)
+        },
+        other => other
+    }
+}
+
 /// # `DriveSimulator` struct
 ///
 /// A `DriveSimulator` can simulate a drive given a context, returning an
@@ -659,7 +771,19 @@ impl DriveSimulator {
     /// let mut rng = rand::thread_rng();
     /// my_context = my_sim.sim_play(&my_home, &my_away, my_context, &mut drive, &mut rng).unwrap();
     /// ```
-    pub fn sim_play(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, drive: &mut Drive, rng: &mut impl Rng) -> Result<GameContext, String> {
+    pub fn sim_play(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, drive: &mut Drive, rng: &mut impl Rng) -> Result<GameContext, GameError> {
+        self.sim_play_with_decisions(home, away, context, drive, rng)
+            .map(|(new_context, _)| new_context)
+    }
+
+    /// Simulate the next play of a drive, along with the coaching decisions
+    /// made before and after it
+    fn sim_play_with_decisions(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, drive: &mut Drive, rng: &mut impl Rng) -> Result<(GameContext, Vec<Decision>), GameError> {
+        // Error if the game is over
+        if context.is_terminal() {
+            return Err(GameError::GameAlreadyOver)
+        }
+
         // Ensure the result is none, unless last play was a touchdown
         let drive_res = *drive.result();
         let result_was_none = drive_res == DriveResult::None;
@@ -671,7 +795,7 @@ impl DriveSimulator {
                 format!(
                     "Cannot simulate play, result was not None ({}) and last play was not TD",
                     drive.result()
-                )
+                ).into()
             )
         }
 
@@ -679,7 +803,7 @@ impl DriveSimulator {
         let mut complete = false;
         let mut result = DriveResult::None;
         let prev_context = context.clone();
-        let (play, next_context) = self.play.sim(home, away, prev_context, rng);
+        let (play, next_context, decisions) = self.play.sim_with_decisions(home, away, prev_context, rng)?;
         let play_result = play.result();
         let new_context = next_context;
 
@@ -750,7 +874,7 @@ impl DriveSimulator {
                     PlayTypeResult::Run(res) => res.fumble(),
                     PlayTypeResult::Pass(res) => res.fumble(),
                     PlayTypeResult::FieldGoal(res) => res.blocked(),
-                    PlayTypeResult::Punt(res) => res.fumble(),
+                    PlayTypeResult::Punt(res) => res.kicking_team_recovery(),
                     PlayTypeResult::Kickoff(res) => res.fumble(),
                     PlayTypeResult::QbKneel(res) => res.fumble(),
                     PlayTypeResult::QbSpike(res) => res.fumble(),
@@ -779,11 +903,15 @@ impl DriveSimulator {
                 complete = true;
             }
 
-            // End of half
-            let end_of_half = ((prev_context.quarter() == 2 || prev_context.quarter() >= 4) &&
+            // End of half, unless the play decided a sudden death overtime,
+            // in which case the drive keeps the play's result
+            let decided_overtime = new_context.game_over() && new_context.half_seconds() > 0;
+            let end_of_half = (prev_context.clock_rules().ends_half(prev_context.quarter()) &&
                 (prev_context.quarter() != new_context.quarter())) || new_context.game_over();
-            if end_of_half {
+            if end_of_half && !decided_overtime {
                 result = DriveResult::EndOfHalf;
+            }
+            if end_of_half {
                 complete = true;
             }
         } else if result_was_touchdown {
@@ -798,7 +926,7 @@ impl DriveSimulator {
         *drive_res = result;
         let drive_complete = drive.complete_mut();
         *drive_complete = complete;
-        Ok(new_context)
+        Ok((new_context, decisions))
     }
 
     /// Simulate the remaining plays of a drive
@@ -822,15 +950,27 @@ impl DriveSimulator {
     /// let mut rng = rand::thread_rng();
     /// let next_context = my_sim.sim_drive(&my_home, &my_away, my_context, &mut my_drive, &mut rng).unwrap();
     /// ```
-    pub fn sim_drive(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, drive: &mut Drive, rng: &mut impl Rng) -> Result<GameContext, String> {
+    pub fn sim_drive(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, drive: &mut Drive, rng: &mut impl Rng) -> Result<GameContext, GameError> {
+        self.sim_drive_with_decisions(home, away, context, drive, rng)
+            .map(|(next_context, _)| next_context)
+    }
+
+    /// Simulate the remaining plays of a drive, along with the coaching
+    /// decisions made, each paired with the index of its play in the drive
+    fn sim_drive_with_decisions(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, drive: &mut Drive, rng: &mut impl Rng) -> Result<(GameContext, Vec<(usize, Decision)>), GameError> {
         let mut extra_point_complete: bool = false;
         let mut prev_context = context.clone();
+        let mut decisions: Vec<(usize, Decision)> = Vec::new();
         while !drive.complete() {
             // Simulate a play
             let prev_result = *drive.result();
-            let next_context = match self.sim_play(home, away, prev_context, drive, rng) {
-                Ok(c) => c,
-                Err(e) => return Err(format!("Error simulating the next play of drive: {}", e))
+            let next_context = match self.sim_play_with_decisions(home, away, prev_context, drive, rng) {
+                Ok((c, play_decisions)) => {
+                    let play_index = drive.plays().len() - 1;
+                    decisions.extend(play_decisions.into_iter().map(|d| (play_index, d)));
+                    c
+                },
+                Err(e) => return Err(prefix_error("Error simulating the next play of drive", e))
             };
             let result = *drive.result();
 
@@ -842,13 +982,13 @@ impl DriveSimulator {
             }
             
             // Break the loop if necessary
-            if (result == DriveResult::None && prev_result == DriveResult::None) || !extra_point_complete {
+            if !next_context.game_over() && ((result == DriveResult::None && prev_result == DriveResult::None) || !extra_point_complete) {
                 prev_context = next_context
             } else {
-                return Ok(next_context)
+                return Ok((next_context, decisions))
             }
         }
-        Err(String::from("Drive was already complete"))
+        Err(String::from("Drive was already complete").into())
     }
 
     /// Simulate a new drive
@@ -889,7 +1029,7 @@ impl DriveSimulator {
             }
             
             // Break the loop if necessary
-            if (result == DriveResult::None && prev_result == DriveResult::None) || !extra_point_complete {
+            if !next_context.game_over() && ((result == DriveResult::None && prev_result == DriveResult::None) || !extra_point_complete) {
                 prev_context = next_context
             } else {
                 return (drive, next_context)
@@ -978,6 +1118,31 @@ impl Game {
         &mut self.drives
     }
 
+    /// Get the largest number of points by which the home or away team
+    /// trailed at the start of any play of the game
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::Game;
+    ///
+    /// let game = Game::new();
+    /// assert_eq!(game.largest_deficit(true), 0);
+    /// ```
+    pub fn largest_deficit(&self, home: bool) -> u32 {
+        self.drives.iter()
+            .flat_map(|drive| drive.plays().iter())
+            .map(|play| {
+                let context = play.context();
+                if home {
+                    context.away_score().saturating_sub(context.home_score())
+                } else {
+                    context.home_score().saturating_sub(context.away_score())
+                }
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
     /// Get the rushing stats for either team
     ///
     /// ### Example
@@ -1173,6 +1338,94 @@ impl Game {
             self.receiving_stats(false)
         )
     }
+
+    /// Get the box score of the game. The context the game ended in, or
+    /// the game's current context if it is in progress, gives the clock
+    /// after its last play for tallying time of possession.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::GameSimulator;
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let team = FootballTeam::new();
+    /// let (game, context) = GameSimulator::new()
+    ///     .sim(&team, &team, GameContext::new(), &mut rng)
+    ///     .unwrap();
+    /// let box_score = game.box_score(&context);
+    /// assert!(box_score.home().time_of_possession() + box_score.away().time_of_possession() >= 3600);
+    /// ```
+    pub fn box_score(&self, context: &GameContext) -> GameStats {
+        let mut stats = GameStats::new();
+        for home in [true, false] {
+            let team = if home { stats.home_mut() } else { stats.away_mut() };
+            let offense = if home { self.home_stats() } else { self.away_stats() };
+            team.increment_passing_yards(offense.passing().yards());
+            team.increment_rushing_yards(offense.rushing().yards());
+            team.increment_turnovers(offense.turnovers());
+        }
+
+        // Record each period through the one the game is in, or ended in
+        let periods = context.quarter().max(context.clock_rules().quarters) as usize;
+        stats.home_mut().increment_period_points(periods, 0);
+        stats.away_mut().increment_period_points(periods, 0);
+
+        let plays: Vec<&Play> = self.drives.iter().flat_map(|drive| drive.plays().iter()).collect();
+        for (i, play) in plays.iter().enumerate() {
+            let play_context = play.context();
+            let next_context = plays.get(i + 1).map(|next| next.context()).unwrap_or(context);
+
+            // Credit the points scored on the play to the period it began in
+            let period = play_context.quarter() as usize;
+            let home_points = next_context.home_score().saturating_sub(play_context.home_score());
+            let away_points = next_context.away_score().saturating_sub(play_context.away_score());
+            stats.home_mut().increment_period_points(period, home_points);
+            stats.away_mut().increment_period_points(period, away_points);
+            let home = play_context.home_possession();
+            let same_possession = next_context.home_possession() == home &&
+                !(next_context.next_play_kickoff() || next_context.next_play_extra_point());
+
+            // Credit the clock run off to the team in possession, or to the
+            // receiving team on a kickoff. The clock resets between periods,
+            // in which case the period ran out.
+            let elapsed = if next_context.half_seconds() <= play_context.half_seconds() {
+                play_context.half_seconds() - next_context.half_seconds()
+            } else {
+                play_context.half_seconds()
+            };
+            let kickoff = matches!(play.result(), PlayTypeResult::Kickoff(_));
+            let possessing = if kickoff { !home } else { home };
+            let team = if possessing { stats.home_mut() } else { stats.away_mut() };
+            team.increment_time_of_possession(elapsed);
+
+            // Credit sacks to the defense
+            let (scrimmage, two_point_conversion, sack) = match play.result() {
+                PlayTypeResult::Run(res) | PlayTypeResult::QbKneel(res) => (true, res.two_point_conversion(), false),
+                PlayTypeResult::Pass(res) | PlayTypeResult::QbSpike(res) => (true, res.two_point_conversion(), res.sack()),
+                _ => (false, false, false)
+            };
+            if sack && !two_point_conversion {
+                let team = if home { stats.away_mut() } else { stats.home_mut() };
+                team.increment_sacks(1);
+            }
+
+            // Tally third downs, skipping those replayed after a penalty
+            if !scrimmage || two_point_conversion || play_context.down() != 3 ||
+                (same_possession && next_context.down() == 3) {
+                continue;
+            }
+            let converted = play.result().offense_score() == ScoreResult::Touchdown ||
+                (same_possession && next_context.down() == 1);
+            let team = if home { stats.home_mut() } else { stats.away_mut() };
+            team.increment_third_down_attempts(1);
+            if converted {
+                team.increment_third_down_conversions(1);
+            }
+        }
+        stats
+    }
 }
 
 impl std::fmt::Display for Game {
@@ -1195,12 +1448,80 @@ impl std::fmt::Display for Game {
     }
 }
 
+/// The default number of plays after which a `GameSimulator` gives up on a
+/// game which has not ended
+pub const DEFAULT_MAX_PLAYS: usize = 1000;
+
+/// # `GameResult` struct
+///
+/// A `GameResult` is the outcome of a full play-by-play game simulated by
+/// `GameSimulator::sim_result`
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct GameResult {
+    /// The context the game ended in
+    pub context: GameContext,
+    /// Every play of the game, in order
+    pub plays: Vec<Play>,
+    /// The box score of the game, including each team's points by period
+    pub box_score: GameStats,
+    /// The home team's offensive stats
+    pub home_stats: OffensiveStats,
+    /// The away team's offensive stats
+    pub away_stats: OffensiveStats
+}
+
+impl GameResult {
+    /// Summarize a game given the context it ended in
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::{Game, GameResult};
+    ///
+    /// let my_result = GameResult::from_game(&Game::new(), GameContext::new());
+    /// assert!(my_result.plays.is_empty());
+    /// ```
+    pub fn from_game(game: &Game, context: GameContext) -> GameResult {
+        GameResult {
+            plays: game.drives().iter().flat_map(|drive| drive.plays().iter().cloned()).collect(),
+            box_score: game.box_score(&context),
+            home_stats: game.home_stats(),
+            away_stats: game.away_stats(),
+            context
+        }
+    }
+
+    /// Get the points each team scored in each period, the regulation
+    /// quarters followed by any overtime periods, as `(home, away)` pairs
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::GameSimulator;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let team = FootballTeam::new();
+    /// let mut rng = rand::thread_rng();
+    /// let my_result = GameSimulator::new().sim_result(&team, &team, GameContext::new(), &mut rng).unwrap();
+    /// let home: u32 = my_result.period_points().iter().map(|(home, _)| home).sum();
+    /// assert_eq!(home, my_result.context.home_score());
+    /// ```
+    pub fn period_points(&self) -> Vec<(u32, u32)> {
+        self.box_score.home().period_points().iter()
+            .zip(self.box_score.away().period_points().iter())
+            .map(|(home, away)| (*home, *away))
+            .collect()
+    }
+}
+
 /// # `GameSimulator` struct
 ///
 /// A `GameSimulator` can simulate a game given a context, returning an
 /// updated context and a drive
 pub struct GameSimulator {
-    drive: DriveSimulator
+    drive: DriveSimulator,
+    max_plays: usize
 }
 
 impl Default for GameSimulator {
@@ -1214,7 +1535,8 @@ impl Default for GameSimulator {
     /// ```
     fn default() -> Self {
         GameSimulator {
-            drive: DriveSimulator::new()
+            drive: DriveSimulator::new(),
+            max_plays: DEFAULT_MAX_PLAYS
         }
     }
 }
@@ -1232,6 +1554,50 @@ impl GameSimulator {
         GameSimulator::default()
     }
 
+    /// Constructor for the `GameSimulator` struct which fails a game once it
+    /// has run for more than a given number of plays without ending
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::GameSimulator;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let team = FootballTeam::new();
+    /// let mut rng = rand::thread_rng();
+    /// let my_sim = GameSimulator::with_max_plays(10);
+    /// assert!(my_sim.sim_result(&team, &team, GameContext::new(), &mut rng).is_err());
+    /// ```
+    pub fn with_max_plays(max_plays: usize) -> GameSimulator {
+        GameSimulator {
+            max_plays,
+            ..GameSimulator::default()
+        }
+    }
+
+    /// Get the number of plays after which the simulator gives up on a
+    /// game which has not ended
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::{GameSimulator, DEFAULT_MAX_PLAYS};
+    ///
+    /// let my_sim = GameSimulator::new();
+    /// assert_eq!(my_sim.max_plays(), DEFAULT_MAX_PLAYS);
+    /// ```
+    pub fn max_plays(&self) -> usize {
+        self.max_plays
+    }
+
+    /// Record the decisions made on the plays of a game's latest drive,
+    /// given the index of each decision's play in the drive
+    fn record_decisions(&self, drives: &[Drive], decisions: Vec<(usize, Decision)>, log: &mut DecisionLog) {
+        let offset: usize = drives.iter().rev().skip(1).map(|d| d.plays().len()).sum();
+        for (play_index, decision) in decisions {
+            log.record(offset + play_index, decision);
+        }
+    }
+
     /// Simulate the next play of a game
     ///
     /// ### Example
@@ -1253,43 +1619,64 @@ impl GameSimulator {
     /// let mut rng = rand::thread_rng();
     /// let next_context = my_sim.sim_play(&my_home, &my_away, my_context, &mut my_game, &mut rng).unwrap();
     /// ```
-    pub fn sim_play(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, game: &mut Game, rng: &mut impl Rng) -> Result<GameContext, String> {
+    pub fn sim_play(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, game: &mut Game, rng: &mut impl Rng) -> Result<GameContext, GameError> {
+        self.sim_play_with_decisions(home, away, context, game, &mut DecisionLog::new(), rng)
+    }
+
+    /// Simulate the next play of a game, recording the coaching decisions
+    /// made into a decision log
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::{GameSimulator, Game};
+    /// use fbsim_core::game::play::decision::DecisionLog;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// // Initialize home & away teams
+    /// let my_home = FootballTeam::new();
+    /// let my_away = FootballTeam::new();
+    ///
+    /// // Initialize a game context
+    /// let my_context = GameContext::new();
+    ///
+    /// // Initialize a game simulator & simulate a play
+    /// let mut my_game = Game::new();
+    /// let mut my_log = DecisionLog::new();
+    /// let my_sim = GameSimulator::new();
+    /// let mut rng = rand::thread_rng();
+    /// let next_context = my_sim.sim_play_with_decisions(&my_home, &my_away, my_context, &mut my_game, &mut my_log, &mut rng).unwrap();
+    /// ```
+    pub fn sim_play_with_decisions(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, game: &mut Game, log: &mut DecisionLog, rng: &mut impl Rng) -> Result<GameContext, GameError> {
         // Error if the game is over
-        if context.game_over() {
-            return Err(String::from("Game is already over, cannot simulate next play"))
+        if context.is_terminal() {
+            return Err(GameError::GameAlreadyOver)
         }
 
         // Get the latest drive to sim or create new one if latest is complete
         let drives = game.drives_mut();
-        let new_context = match drives.last_mut() {
-            Some(d) => {
-                if !d.complete() {
-                    match self.drive.sim_play(home, away, context, d, rng) {
-                        Ok(c) => c,
-                        Err(e) => return Err(format!("Error simulating next play of game: {}", e))
-                    }
-                } else {
-                    let mut new_drive = Drive::new();
-                    let new_context = match self.drive.sim_play(home, away, context, &mut new_drive, rng) {
-                        Ok(c) => c,
-                        Err(e) => return Err(format!("Error simulating the next play of game: {}", e))
-                    };
-                    drives.push(new_drive);
-                    new_context
+        let (new_context, decisions) = match drives.last_mut() {
+            Some(d) if !d.complete() => {
+                match self.drive.sim_play_with_decisions(home, away, context, d, rng) {
+                    Ok(res) => res,
+                    Err(e) => return Err(prefix_error("Error simulating next play of game", e))
                 }
             },
-            None => {
+            _ => {
                 let mut new_drive = Drive::new();
-                let new_context = match self.drive.sim_play(home, away, context, &mut new_drive, rng) {
-                    Ok(c) => c,
-                    Err(e) => return Err(format!("Error simulating the next play of game: {}", e))
+                let res = match self.drive.sim_play_with_decisions(home, away, context, &mut new_drive, rng) {
+                    Ok(res) => res,
+                    Err(e) => return Err(prefix_error("Error simulating the next play of game", e))
                 };
                 drives.push(new_drive);
-                new_context
+                res
             }
         };
 
-        // Return the new context
+        // Record the decisions and return the new context
+        let play_index = drives.last().map(|d| d.plays().len()).unwrap_or_default().saturating_sub(1);
+        let decisions = decisions.into_iter().map(|d| (play_index, d)).collect();
+        self.record_decisions(drives, decisions, log);
         Ok(new_context)
     }
 
@@ -1314,43 +1701,62 @@ impl GameSimulator {
     /// let mut rng = rand::thread_rng();
     /// let next_context = my_sim.sim_drive(&my_home, &my_away, my_context, &mut my_game, &mut rng).unwrap();
     /// ```
-    pub fn sim_drive(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, game: &mut Game, rng: &mut impl Rng) -> Result<GameContext, String> {
+    pub fn sim_drive(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, game: &mut Game, rng: &mut impl Rng) -> Result<GameContext, GameError> {
+        self.sim_drive_with_decisions(home, away, context, game, &mut DecisionLog::new(), rng)
+    }
+
+    /// Simulate the next drive of a game, recording the coaching decisions
+    /// made into a decision log
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::{GameSimulator, Game};
+    /// use fbsim_core::game::play::decision::DecisionLog;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// // Initialize home & away teams
+    /// let my_home = FootballTeam::new();
+    /// let my_away = FootballTeam::new();
+    ///
+    /// // Initialize a game context
+    /// let my_context = GameContext::new();
+    ///
+    /// // Initialize a game simulator & simulate a drive
+    /// let mut my_game = Game::new();
+    /// let mut my_log = DecisionLog::new();
+    /// let my_sim = GameSimulator::new();
+    /// let mut rng = rand::thread_rng();
+    /// let next_context = my_sim.sim_drive_with_decisions(&my_home, &my_away, my_context, &mut my_game, &mut my_log, &mut rng).unwrap();
+    /// ```
+    pub fn sim_drive_with_decisions(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, game: &mut Game, log: &mut DecisionLog, rng: &mut impl Rng) -> Result<GameContext, GameError> {
         // Error if the game is over
-        if context.game_over() {
-            return Err(String::from("Game is already over, cannot simulate next drive"))
+        if context.is_terminal() {
+            return Err(GameError::GameAlreadyOver)
         }
 
         // Get the latest drive to sim or create new one if latest is complete
         let drives = game.drives_mut();
-        let new_context = match drives.last_mut() {
-            Some(d) => {
-                if !d.complete() {
-                    match self.drive.sim_drive(home, away, context, d, rng) {
-                        Ok(c) => c,
-                        Err(e) => return Err(format!("Error simulating the next drive of game: {}", e))
-                    }
-                } else {
-                    let mut new_drive = Drive::new();
-                    let new_context = match self.drive.sim_drive(home, away, context, &mut new_drive, rng) {
-                        Ok(c) => c,
-                        Err(e) => return Err(format!("Error simulating the next drive of game: {}", e))
-                    };
-                    drives.push(new_drive);
-                    new_context
+        let (new_context, decisions) = match drives.last_mut() {
+            Some(d) if !d.complete() => {
+                match self.drive.sim_drive_with_decisions(home, away, context, d, rng) {
+                    Ok(res) => res,
+                    Err(e) => return Err(prefix_error("Error simulating the next drive of game", e))
                 }
             },
-            None => {
+            _ => {
                 let mut new_drive = Drive::new();
-                let new_context = match self.drive.sim_drive(home, away, context, &mut new_drive, rng) {
-                    Ok(c) => c,
-                    Err(e) => return Err(format!("Error simulating the next drive of game: {}", e))
+                let res = match self.drive.sim_drive_with_decisions(home, away, context, &mut new_drive, rng) {
+                    Ok(res) => res,
+                    Err(e) => return Err(prefix_error("Error simulating the next drive of game", e))
                 };
                 drives.push(new_drive);
-                new_context
+                res
             }
         };
 
-        // Return the new context
+        // Record the decisions and return the new context
+        self.record_decisions(drives, decisions, log);
         Ok(new_context)
     }
 
@@ -1375,45 +1781,59 @@ impl GameSimulator {
     /// let mut rng = rand::thread_rng();
     /// let next_context = my_sim.sim_game(&my_home, &my_away, my_context, &mut my_game, &mut rng).unwrap();
     /// ```
-    pub fn sim_game(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, game: &mut Game, rng: &mut impl Rng) -> Result<GameContext, String> {
+    pub fn sim_game(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, game: &mut Game, rng: &mut impl Rng) -> Result<GameContext, GameError> {
+        self.sim_game_with_decisions(home, away, context, game, &mut DecisionLog::new(), rng)
+    }
+
+    /// Simulate the remainder of a game, recording the coaching decisions
+    /// made into a decision log
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::{GameSimulator, Game};
+    /// use fbsim_core::game::play::decision::DecisionLog;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// // Initialize home & away teams
+    /// let my_home = FootballTeam::new();
+    /// let my_away = FootballTeam::new();
+    ///
+    /// // Initialize a game context
+    /// let my_context = GameContext::new();
+    ///
+    /// // Initialize a game simulator and game, simulate the game
+    /// let mut my_game = Game::new();
+    /// let mut my_log = DecisionLog::new();
+    /// let my_sim = GameSimulator::new();
+    /// let mut rng = rand::thread_rng();
+    /// let next_context = my_sim.sim_game_with_decisions(&my_home, &my_away, my_context, &mut my_game, &mut my_log, &mut rng).unwrap();
+    /// assert!(!my_log.entries().is_empty());
+    /// ```
+    pub fn sim_game_with_decisions(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, game: &mut Game, log: &mut DecisionLog, rng: &mut impl Rng) -> Result<GameContext, GameError> {
         // Error if the game is over
-        if context.game_over() {
-            return Err(String::from("Game is already over, cannot simulate remainder of game"))
+        if context.is_terminal() {
+            return Err(GameError::GameAlreadyOver)
         }
+        self.sim_remaining(home, away, context, game, log, rng)
+    }
 
-        // Get the latest drive to sim or create new one if latest is complete
-        let drives = game.drives_mut();
-        let mut next_context = context.clone();
-        let mut game_over = next_context.game_over();
-        while !game_over {
-            let new_context = match drives.last_mut() {
-                Some(d) => {
-                    if !d.complete() {
-                        match self.drive.sim_drive(home, away, next_context, d, rng) {
-                            Ok(c) => c,
-                            Err(e) => return Err(format!("Error simulating the next drive of game: {}", e))
-                        }
-                    } else {
-                        let mut new_drive = Drive::new();
-                        let new_context = match self.drive.sim_drive(home, away, next_context, &mut new_drive, rng) {
-                            Ok(c) => c,
-                            Err(e) => return Err(format!("Error simulating the next drive of game: {}", e))
-                        };
-                        drives.push(new_drive);
-                        new_context
-                    }
-                },
-                None => {
-                    let mut new_drive = Drive::new();
-                    let new_context = match self.drive.sim_drive(home, away, next_context, &mut new_drive, rng) {
-                        Ok(c) => c,
-                        Err(e) => return Err(format!("Error simulating the next drive of game: {}", e))
-                    };
-                    drives.push(new_drive);
-                    new_context
-                }
-            };
-            game_over = new_context.game_over();
+    /// Simulate plays until the game is over, recording the coaching
+    /// decisions made into a decision log. Fails if a play leaves the
+    /// context unchanged, or if the game runs past the maximum number of
+    /// plays, rather than looping forever.
+    fn sim_remaining(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, game: &mut Game, log: &mut DecisionLog, rng: &mut impl Rng) -> Result<GameContext, GameError> {
+        let mut num_plays: usize = game.drives().iter().map(|d| d.plays().len()).sum();
+        let mut next_context = context;
+        while !next_context.game_over() {
+            if num_plays >= self.max_plays {
+                return Err(format!("Game did not end within the maximum of {} plays", self.max_plays).into());
+            }
+            let new_context = self.sim_play_with_decisions(home, away, next_context.clone(), game, log, rng)?;
+            num_plays += 1;
+            if new_context == next_context {
+                return Err(format!("Game stopped progressing: play {} left the context unchanged", num_plays).into());
+            }
             next_context = new_context;
         }
 
@@ -1441,45 +1861,63 @@ impl GameSimulator {
     /// let mut rng = rand::thread_rng();
     /// let (game, final_context) = my_sim.sim(&my_home, &my_away, my_context, &mut rng).unwrap();
     /// ```
-    pub fn sim(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, rng: &mut impl Rng) -> Result<(Game, GameContext), String> {
-        // Get the latest drive to sim or create new one if latest is complete
+    pub fn sim(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, rng: &mut impl Rng) -> Result<(Game, GameContext), GameError> {
         let mut game = Game::new();
-        let drives = game.drives_mut();
-        let mut next_context = context.clone();
-        let mut game_over = next_context.game_over();
-        while !game_over {
-            let new_context = match drives.last_mut() {
-                Some(d) => {
-                    if !d.complete() {
-                        match self.drive.sim_drive(home, away, next_context, d, rng) {
-                            Ok(c) => c,
-                            Err(e) => return Err(format!("Error simulating the next drive of game: {}", e))
-                        }
-                    } else {
-                        let mut new_drive = Drive::new();
-                        let new_context = match self.drive.sim_drive(home, away, next_context, &mut new_drive, rng) {
-                            Ok(c) => c,
-                            Err(e) => return Err(format!("Error simulating the next drive of game: {}", e))
-                        };
-                        drives.push(new_drive);
-                        new_context
-                    }
-                },
-                None => {
-                    let mut new_drive = Drive::new();
-                    let new_context = match self.drive.sim_drive(home, away, next_context, &mut new_drive, rng) {
-                        Ok(c) => c,
-                        Err(e) => return Err(format!("Error simulating the next drive of game: {}", e))
-                    };
-                    drives.push(new_drive);
-                    new_context
-                }
-            };
-            game_over = new_context.game_over();
-            next_context = new_context;
-        }
-
-        // Return the final context
+        let next_context = self.sim_remaining(home, away, context, &mut game, &mut DecisionLog::new(), rng)?;
         Ok((game, next_context))
     }
+
+    /// Simulate a new game, along with the log of the coaching decisions made
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::GameSimulator;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// // Initialize home & away teams
+    /// let my_home = FootballTeam::new();
+    /// let my_away = FootballTeam::new();
+    ///
+    /// // Initialize a game context
+    /// let my_context = GameContext::new();
+    ///
+    /// // Initialize a game simulator & simulate a game
+    /// let my_sim = GameSimulator::new();
+    /// let mut rng = rand::thread_rng();
+    /// let (game, final_context, decisions) = my_sim.sim_with_decisions(&my_home, &my_away, my_context, &mut rng).unwrap();
+    /// assert!(!decisions.entries().is_empty());
+    /// ```
+    pub fn sim_with_decisions(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, rng: &mut impl Rng) -> Result<(Game, GameContext, DecisionLog), GameError> {
+        let mut game = Game::new();
+        let mut log = DecisionLog::new();
+        let next_context = self.sim_remaining(home, away, context, &mut game, &mut log, rng)?;
+        Ok((game, next_context, log))
+    }
+
+    /// Simulate a new game play by play, returning its final context, every
+    /// play in order, its scoring by period, and each team's stats
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::GameSimulator;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// // Initialize home & away teams
+    /// let my_home = FootballTeam::new();
+    /// let my_away = FootballTeam::new();
+    ///
+    /// // Simulate a game from the opening kickoff
+    /// let my_context = GameContext::with_opening_kickoff("HOME", "AWAY", false).unwrap();
+    /// let my_sim = GameSimulator::new();
+    /// let mut rng = rand::thread_rng();
+    /// let my_result = my_sim.sim_result(&my_home, &my_away, my_context, &mut rng).unwrap();
+    /// assert!(my_result.context.game_over());
+    /// assert!(my_result.period_points().len() >= 4);
+    /// ```
+    pub fn sim_result(&self, home: &FootballTeam, away: &FootballTeam, context: GameContext, rng: &mut impl Rng) -> Result<GameResult, GameError> {
+        let (game, final_context) = self.sim(home, away, context, rng)?;
+        Ok(GameResult::from_game(&game, final_context))
+    }
 }
@@ -9,6 +9,7 @@ use serde::{Serialize, Deserialize};
 use crate::game::context::GameContext;
 use crate::game::play::PlaySimulatable;
 use crate::game::play::context::PlayContext;
+use crate::game::play::decision::{Decision, DecisionChoice, DecisionKind};
 
 // Run probabilities on 1st-3rd down clock management scenarios
 const P_RUN_CLOCK_MANAGEMENT: f64 = 0.15_f64;
@@ -30,6 +31,16 @@ const P_RUN_THIRD_DOWN_COEF: f64 = 0.22902729;
 const P_RUN_DIST_INTR: f64 = 0.30634251685198927_f64;
 const P_RUN_DIST_COEF: f64 = -0.00318081_f64;
 
+// Game script run probability shift on 1st-3rd down. The shift is
+// P_RUN_GAME_SCRIPT_MAX * game_script * tanh(score_diff / GAME_SCRIPT_SCORE_SCALE)
+// * (GAME_SCRIPT_EARLY_WEIGHT + (1 - GAME_SCRIPT_EARLY_WEIGHT) * game_progress),
+// so a leading offense runs more and a trailing offense passes more, most of
+// all late in the game. The shift is antisymmetric in the score differential,
+// so it leaves the league-wide run-pass balance near the coach's baseline.
+const P_RUN_GAME_SCRIPT_MAX: f64 = 0.4_f64;
+const GAME_SCRIPT_SCORE_SCALE: f64 = 14_f64;
+const GAME_SCRIPT_EARLY_WEIGHT: f64 = 0.25_f64;
+
 // Field goal risk-taking-based probability regression on 4th down
 const P_FIELD_GOAL_RISK_INTR: f64 = 0.7886141537295228_f64;
 const P_FIELD_GOAL_RISK_COEF: f64 = -0.26532936_f64;
@@ -50,7 +61,10 @@ const P_RUN_FOURTH_DOWN_COEF: f64 = 0.05793641_f64;
 /// # `PlayCall` enum
 ///
 /// Defines the various types of plays that can be run in football
-#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum PlayCall {
     Run,
     Pass,
@@ -83,16 +97,21 @@ impl PlayCallSimulator {
         PlayCallSimulator{}
     }
 
+    /// Compute the probability of a field goal on the last play
+    fn last_play_field_goal_probability(&self, context: &PlayContext) -> f64 {
+        let yard_line = context.yard_line();
+        1_f64.min(0_f64.max(
+            0.2_f64 + P_FIELD_GOAL_YARD_LINE_INTR + (P_FIELD_GOAL_YARD_LINE_COEF_1 * yard_line as f64) +
+                (P_FIELD_GOAL_YARD_LINE_COEF_2 * yard_line.pow(2) as f64)
+        )) // Adjust by +0.2 to incentivize going for field goals if a field goal is all that is needed
+    }
+
     /// Generate the play call for the last play
     fn last_play_playcall(&self, context: &PlayContext, rng: &mut impl Rng) -> PlayCall {
         if context.last_play_need_td() {
             return PlayCall::Pass;
         }
-        let yard_line = context.yard_line();
-        let p_field_goal_yardline: f64 = 1_f64.min(0_f64.max(
-            0.2_f64 + P_FIELD_GOAL_YARD_LINE_INTR + (P_FIELD_GOAL_YARD_LINE_COEF_1 * yard_line as f64) +
-                (P_FIELD_GOAL_YARD_LINE_COEF_2 * yard_line.pow(2) as f64)
-        )); // Adjust by +0.2 to incentivize going for field goals if a field goal is all that is needed
+        let p_field_goal_yardline: f64 = self.last_play_field_goal_probability(context);
         if rng.gen::<f64>() < p_field_goal_yardline {
             return PlayCall::FieldGoal;
         }
@@ -112,8 +131,17 @@ impl PlayCallSimulator {
         PlayCall::Pass
     }
 
+    /// Compute the game script shift in run probability given the score
+    /// differential, the elapsed game time, and the coach's normalized game
+    /// script tendency
+    fn game_script_shift(&self, context: &PlayContext, game_script: f64) -> f64 {
+        let score_weight = (context.score_diff() as f64 / GAME_SCRIPT_SCORE_SCALE).tanh();
+        let time_weight = GAME_SCRIPT_EARLY_WEIGHT + ((1_f64 - GAME_SCRIPT_EARLY_WEIGHT) * context.game_progress());
+        P_RUN_GAME_SCRIPT_MAX * game_script * score_weight * time_weight
+    }
+
     /// Generate the play call for a non-clock management scenario on 1st-3rd
-    fn normal_play_call(&self, context: &PlayContext, run_pass: f64, rng: &mut impl Rng) -> PlayCall {
+    fn normal_play_call(&self, context: &PlayContext, run_pass: f64, game_script: f64, rng: &mut impl Rng) -> PlayCall {
         let down = context.down();
         let distance = context.distance();
         let p_run_call: f64 = match down {
@@ -125,7 +153,8 @@ impl PlayCallSimulator {
         };
         let p_run_dist: f64 = P_RUN_DIST_INTR + (P_RUN_DIST_COEF * distance as f64);
         let p_run: f64 = 1_f64.min(0_f64.max(
-            (p_run_dist * 0.3_f64) + (p_run_call * 0.7_f64)
+            (p_run_dist * 0.3_f64) + (p_run_call * 0.7_f64) +
+                self.game_script_shift(context, game_script)
         ));
         if rng.gen::<f64>() < p_run {
             return PlayCall::Run;
@@ -133,15 +162,9 @@ impl PlayCallSimulator {
         PlayCall::Pass
     }
 
-    /// Generate the play call for fourth down
-    fn fourth_down_play_call(&self, context: &PlayContext, risk_taking: f64, run_pass: f64, rng: &mut impl Rng) -> PlayCall {
-        let in_field_goal_range: bool = context.in_field_goal_range();
-        let go_for_it_scenario: bool = context.can_go_for_it();
-        if !(in_field_goal_range || go_for_it_scenario) {
-            return PlayCall::Punt;
-        }
-
-        // Calculate go for it & field goal probabilities
+    /// Compute the go for it & field goal probabilities on fourth down in a
+    /// go for it scenario
+    fn fourth_down_probabilities(&self, context: &PlayContext, risk_taking: f64) -> (f64, f64) {
         let yard_line = context.yard_line();
         let p_go_for_it: f64 = 1_f64.min(0_f64.max(
             P_GO_FOR_IT_INTR + (P_GO_FOR_IT_COEF * risk_taking)
@@ -156,6 +179,20 @@ impl PlayCallSimulator {
                 ).max(0.0001).ln() * 0.8
             ) * 1.6 + 0.0001
         );
+        (p_go_for_it, p_field_goal)
+    }
+
+    /// Generate the play call for fourth down
+    fn fourth_down_play_call(&self, context: &PlayContext, risk_taking: f64, run_pass: f64, game_script: f64, rng: &mut impl Rng) -> PlayCall {
+        let in_field_goal_range: bool = context.in_field_goal_range();
+        let go_for_it_scenario: bool = context.can_go_for_it();
+        if !(in_field_goal_range || go_for_it_scenario) {
+            return PlayCall::Punt;
+        }
+
+        // Calculate go for it & field goal probabilities
+        let yard_line = context.yard_line();
+        let (p_go_for_it, p_field_goal) = self.fourth_down_probabilities(context, risk_taking);
 
         // Go for it scenario
         if go_for_it_scenario {
@@ -163,7 +200,7 @@ impl PlayCallSimulator {
                 return PlayCall::FieldGoal;
             }
             if yard_line <= 20 || rng.gen::<f64>() < p_go_for_it {
-                return self.normal_play_call(context, run_pass, rng);
+                return self.normal_play_call(context, run_pass, game_script, rng);
             }
         }
 
@@ -175,6 +212,76 @@ impl PlayCallSimulator {
         }
     }
 
+    /// Record the fourth down decision behind a play call, scoring each
+    /// option by its probability of being called
+    fn fourth_down_decision(&self, context: &GameContext, play_context: &PlayContext, risk_taking: f64, play_call: PlayCall) -> Decision {
+        let chosen = DecisionChoice::from_fourth_down_call(play_call).unwrap_or(DecisionChoice::GoForIt);
+        let decision = Decision::new(DecisionKind::FourthDown, chosen, context);
+
+        // Last play scenarios
+        if play_context.must_score() {
+            if play_context.last_play_need_td() {
+                return decision
+                    .option(DecisionChoice::GoForIt, 1_f64)
+                    .reason("Must score, and only a touchdown will do");
+            }
+            let p_field_goal = self.last_play_field_goal_probability(play_context);
+            return decision
+                .option(DecisionChoice::GoForIt, 1_f64 - p_field_goal)
+                .option(DecisionChoice::FieldGoal, p_field_goal)
+                .reason("Must score on this possession");
+        }
+
+        // Punt or field goal outside go for it scenarios
+        let in_field_goal_range: bool = play_context.in_field_goal_range();
+        if !play_context.can_go_for_it() {
+            if in_field_goal_range {
+                return decision
+                    .option(DecisionChoice::FieldGoal, 1_f64)
+                    .reason("In field goal range, too far to go for it");
+            }
+            return decision
+                .option(DecisionChoice::Punt, 1_f64)
+                .reason("Out of field goal range, too far to go for it");
+        }
+
+        // Go for it scenario
+        let (p_go_for_it, p_field_goal) = self.fourth_down_probabilities(play_context, risk_taking);
+        let p_field_goal = if in_field_goal_range { p_field_goal } else { 0_f64 };
+        let p_go_for_it = if play_context.yard_line() <= 20 { 1_f64 } else { p_go_for_it };
+        let p_go_for_it = (1_f64 - p_field_goal) * p_go_for_it;
+        let p_kick = 1_f64 - p_go_for_it;
+        if in_field_goal_range {
+            decision
+                .option(DecisionChoice::GoForIt, p_go_for_it)
+                .option(DecisionChoice::FieldGoal, p_kick)
+                .reason("Short yardage in field goal range")
+        } else {
+            decision
+                .option(DecisionChoice::GoForIt, p_go_for_it)
+                .option(DecisionChoice::Punt, p_kick)
+                .reason("Short yardage near midfield")
+        }
+    }
+
+    /// Record the extra point or two-point conversion decision behind a play
+    /// call, from the two-point chart
+    fn conversion_decision(&self, context: &GameContext, play_context: &PlayContext) -> Decision {
+        if play_context.two_point_conversion() {
+            Decision::new(DecisionKind::Conversion, DecisionChoice::TwoPointConversion, context)
+                .option(DecisionChoice::TwoPointConversion, 1_f64)
+                .reason("Two-point chart calls for going for two")
+        } else if play_context.quarter() == play_context.clock_rules().quarters {
+            Decision::new(DecisionKind::Conversion, DecisionChoice::ExtraPoint, context)
+                .option(DecisionChoice::ExtraPoint, 1_f64)
+                .reason("Two-point chart calls for the extra point")
+        } else {
+            Decision::new(DecisionKind::Conversion, DecisionChoice::ExtraPoint, context)
+                .option(DecisionChoice::ExtraPoint, 1_f64)
+                .reason("Two-point chart only applies in the 4th quarter")
+        }
+    }
+
     /// Generate a play call
     ///
     /// ### Example
@@ -195,37 +302,244 @@ impl PlayCallSimulator {
     /// let my_play_call = my_sim.sim(&my_off, &my_context, &mut rng);
     /// ```
     pub fn sim(&self, offense: &impl PlaySimulatable, context: &GameContext, rng: &mut impl Rng) -> PlayCall {
+        let (play_call, _) = self.sim_with_decision(offense, context, rng);
+        play_call
+    }
+
+    /// Generate a play call, along with the coaching decision behind it on
+    /// 4th down or a conversion attempt
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::play::call::PlayCallSimulator;
+    /// use fbsim_core::game::play::decision::DecisionKind;
+    ///
+    /// // Initialize offensive team
+    /// let my_off = FootballTeam::new();
+    ///
+    /// // Initialize a 4th down game context
+    /// let my_context = GameContextBuilder::new()
+    ///     .down(4)
+    ///     .distance(2)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Generate a play call and its decision
+    /// let my_sim = PlayCallSimulator::new();
+    /// let mut rng = rand::thread_rng();
+    /// let (_, decision) = my_sim.sim_with_decision(&my_off, &my_context, &mut rng);
+    /// assert_eq!(decision.unwrap().kind, DecisionKind::FourthDown);
+    /// ```
+    pub fn sim_with_decision(&self, offense: &impl PlaySimulatable, context: &GameContext, rng: &mut impl Rng) -> (PlayCall, Option<Decision>) {
         // Compute normalized skill levels and context
         let norm_risk_taking: f64 = offense.coach().risk_taking() as f64 / 100_f64;
         let norm_run_pass: f64 = offense.coach().run_pass() as f64 / 100_f64;
+        let norm_game_script: f64 = offense.coach().game_script() as f64 / 100_f64;
         let extra_point = context.next_play_extra_point();
         let play_context = PlayContext::from(context);
 
         // Extra point playcalling
         if extra_point {
-            if play_context.two_point_conversion() {
-                return self.normal_play_call(&play_context, norm_run_pass, rng);
+            let decision = self.conversion_decision(context, &play_context);
+            let play_call = if play_context.two_point_conversion() {
+                self.normal_play_call(&play_context, norm_run_pass, 0_f64, rng)
             } else {
-                return PlayCall::ExtraPoint;
-            }
+                PlayCall::ExtraPoint
+            };
+            return (play_call, Some(decision));
+        }
+
+        // Victory formation when kneeling runs out the clock
+        if play_context.can_kneel() {
+            return (PlayCall::QbKneel, None);
         }
 
         // Fourth down playcalling
         if play_context.down() == 4 {
-            if play_context.must_score() {
-                return self.last_play_playcall(&play_context, rng);
-            }
-            return self.fourth_down_play_call(&play_context, norm_risk_taking, norm_run_pass, rng);
+            let play_call = if play_context.must_score() {
+                self.last_play_playcall(&play_context, rng)
+            } else {
+                self.fourth_down_play_call(&play_context, norm_risk_taking, norm_run_pass, norm_game_script, rng)
+            };
+            let decision = self.fourth_down_decision(context, &play_context, norm_risk_taking, play_call);
+            return (play_call, Some(decision));
         }
 
-        // Clock management situation playcalling
+        // Clock management situation playcalling, which takes precedence over
+        // the game script so a trailing offense's hurry-up calls are unchanged
         if play_context.offense_conserve_clock() {
             if play_context.last_play() {
-                return self.last_play_playcall(&play_context, rng);
+                return (self.last_play_playcall(&play_context, rng), None);
+            }
+            if play_context.spike() {
+                return (PlayCall::QbSpike, None);
+            }
+            return (self.conserve_clock_playcall(&play_context, rng), None);
+        }
+
+        (self.normal_play_call(&play_context, norm_run_pass, norm_game_script, rng), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use crate::game::play::{Game, GameSimulator};
+    use crate::game::play::result::PlayTypeResult;
+    use crate::team::FootballTeam;
+    use crate::team::coach::FootballTeamCoachBuilder;
+    use crate::team::defense::FootballTeamDefense;
+    use crate::team::offense::FootballTeamOffense;
+
+    /// Tally of run and pass calls
+    #[derive(Default)]
+    struct Tally {
+        runs: usize,
+        passes: usize
+    }
+
+    impl Tally {
+        fn pass_share(&self) -> f64 {
+            self.passes as f64 / (self.runs + self.passes) as f64
+        }
+    }
+
+    /// Tally the run and pass calls in a game overall, by a 4th quarter
+    /// offense leading by 14 or more, and by one trailing by 14 or more
+    fn tally(game: &Game, overall: &mut Tally, leading: &mut Tally, trailing: &mut Tally) {
+        for play in game.drives().iter().flat_map(|d| d.plays().iter()) {
+            let pass = match play.result() {
+                PlayTypeResult::Run(_) => false,
+                PlayTypeResult::Pass(_) => true,
+                _ => continue
+            };
+            let context = play.context();
+            let score_diff = if context.home_possession() {
+                context.home_score() as i32 - context.away_score() as i32
+            } else {
+                context.away_score() as i32 - context.home_score() as i32
+            };
+            let mut tallies = vec![&mut *overall];
+            let final_quarter = context.quarter() == context.clock_rules().quarters;
+            if final_quarter && score_diff >= 14 {
+                tallies.push(&mut *leading);
+            } else if final_quarter && score_diff <= -14 {
+                tallies.push(&mut *trailing);
+            }
+            for tally in tallies {
+                if pass {
+                    tally.passes += 1;
+                } else {
+                    tally.runs += 1;
+                }
+            }
+        }
+    }
+
+    /// Simulate games between mismatched teams whose coaches share a game
+    /// script tendency, returning the overall, leading, and trailing tallies
+    fn sim_games(game_script: u32, games: usize) -> (Tally, Tally, Tally) {
+        let coach = FootballTeamCoachBuilder::new()
+            .game_script(game_script)
+            .build()
+            .unwrap();
+        let team = |name: &str, short_name: &str, overall: u32| FootballTeam::from_properties(
+            name, short_name, coach.clone(),
+            FootballTeamOffense::from_overall(overall).unwrap(),
+            FootballTeamDefense::from_overall(overall).unwrap()
+        );
+        let home = team("Hawks", "HWK", 80);
+        let away = team("Eagles", "EGL", 40);
+        let mut rng = SmallRng::seed_from_u64(1480);
+        let (mut overall, mut leading, mut trailing) = (Tally::default(), Tally::default(), Tally::default());
+        for _ in 0..games {
+            let (game, _) = GameSimulator::new().sim(&home, &away, Default::default(), &mut rng).unwrap();
+            tally(&game, &mut overall, &mut leading, &mut trailing);
+        }
+        (overall, leading, trailing)
+    }
+
+    #[test]
+    fn test_game_script_trailing_offense_passes_more() {
+        // A 4th quarter offense trailing by 14 passes clearly more often
+        // than one leading by 14
+        let (overall, leading, trailing) = sim_games(50, 300);
+        assert!(leading.runs + leading.passes > 500);
+        assert!(trailing.runs + trailing.passes > 500);
+        assert!(
+            trailing.pass_share() > leading.pass_share() + 0.2,
+            "trailing {} vs. leading {}", trailing.pass_share(), leading.pass_share()
+        );
+
+        // Without a game script the shares are much closer together
+        let (baseline, flat_leading, flat_trailing) = sim_games(0, 300);
+        assert!(
+            flat_trailing.pass_share() - flat_leading.pass_share() <
+                trailing.pass_share() - leading.pass_share()
+        );
+
+        // The overall run-pass balance stays near the baseline
+        assert!(
+            (overall.pass_share() - baseline.pass_share()).abs() < 0.03,
+            "overall {} vs. baseline {}", overall.pass_share(), baseline.pass_share()
+        );
+    }
+
+    #[test]
+    fn test_leading_offense_kneels_out_the_clock() {
+        use crate::game::context::GameContextBuilder;
+        use crate::game::play::call::{PlayCall, PlayCallSimulator};
+
+        // Leading by 4 with 1:50 left against a defense with no timeouts
+        let context = GameContextBuilder::new()
+            .quarter(4)
+            .half_seconds(110)
+            .down(1)
+            .distance(10)
+            .yard_line(25)
+            .home_score(21)
+            .away_score(17)
+            .away_timeouts(0)
+            .next_play_kickoff(false)
+            .build()
+            .unwrap();
+        let team = FootballTeam::new();
+        let mut rng = SmallRng::seed_from_u64(1521);
+        for _ in 0..100 {
+            // The offense kneels until the game ends, and the opponent never
+            // gets the ball back
+            let (game, final_context) = GameSimulator::new().sim(&team, &team, context.clone(), &mut rng).unwrap();
+            assert!(final_context.game_over());
+            assert_eq!((final_context.home_score(), final_context.away_score()), (21, 17));
+            let plays: Vec<_> = game.drives().iter().flat_map(|d| d.plays().iter()).collect();
+            assert_eq!(plays.len(), 3);
+            for play in plays {
+                assert!(play.context().home_possession());
+                match play.result() {
+                    PlayTypeResult::QbKneel(res) => assert_eq!(res.yards_gained(), -1),
+                    result => panic!("Expected a kneel, got {:?}", result)
+                }
             }
-            return self.conserve_clock_playcall(&play_context, rng);
         }
 
-        self.normal_play_call(&play_context, norm_run_pass, rng)
+        // A trailing offense with no timeouts spikes the ball with the clock
+        // running, but not with a timeout left
+        let trailing = |home_timeouts: u32| GameContextBuilder::new()
+            .quarter(4)
+            .half_seconds(20)
+            .down(1)
+            .yard_line(70)
+            .home_score(17)
+            .away_score(20)
+            .home_timeouts(home_timeouts)
+            .next_play_kickoff(false)
+            .build()
+            .unwrap();
+        let simulator = PlayCallSimulator::new();
+        assert_eq!(simulator.sim(&team, &trailing(0), &mut rng), PlayCall::QbSpike);
+        assert_ne!(simulator.sim(&team, &trailing(1), &mut rng), PlayCall::QbSpike);
     }
 }
@@ -6,11 +6,22 @@ use rocket_okapi::okapi::schemars::JsonSchema;
 use serde::{Serialize, Deserialize};
 
 use crate::game::context::GameContext;
+use crate::game::context::clock::ClockRules;
+
+// Duration of a kneel, and the clock run off by each kneel including the
+// play clock run down before the next snap
+pub(crate) const KNEEL_DURATION: u32 = 2;
+const KNEEL_RUNOFF_SECONDS: u32 = 42;
+
+// Seconds left in the half at which a trailing offense spikes the ball
+const SPIKE_SECONDS: u32 = 30;
 
 /// # `PlayContext` struct
 ///
 /// A `PlayContext` represents a play scenario
 #[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
 pub struct PlayContext {
     quarter: u32,
@@ -21,7 +32,9 @@ pub struct PlayContext {
     score_diff: i32,
     off_timeouts: u32,
     def_timeouts: u32,
-    clock_running: bool
+    clock_running: bool,
+    #[serde(default)]
+    clock_rules: ClockRules
 }
 
 impl From<&GameContext> for PlayContext {
@@ -70,7 +83,8 @@ impl From<&GameContext> for PlayContext {
             score_diff,
             off_timeouts,
             def_timeouts,
-            clock_running: item.clock_running()
+            clock_running: item.clock_running(),
+            clock_rules: *item.clock_rules()
         }
     }
 }
@@ -121,7 +135,7 @@ impl PlayContext {
     /// assert!(!two_point_conversion);
     /// ```
     pub fn two_point_conversion(&self) -> bool {
-        self.quarter == 4 && (
+        self.quarter == self.clock_rules.quarters && (
             matches!(
                 self.score_diff,
                 25 | 22 | 19 | 5 | 4 | 1 | -2 | -5 | -10 | -12 | -13
@@ -241,6 +255,83 @@ impl PlayContext {
         self.quarter
     }
 
+    /// Gets the clock rules the game is played under
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::context::PlayContext;
+    /// 
+    /// let game_context = GameContext::new();
+    /// let play_context = PlayContext::from(&game_context);
+    /// let clock_rules = play_context.clock_rules();
+    /// assert!(clock_rules.is_default());
+    /// ```
+    pub fn clock_rules(&self) -> &ClockRules {
+        &self.clock_rules
+    }
+
+    /// Gets the seconds remaining in the half
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::context::PlayContext;
+    ///
+    /// let game_context = GameContext::new();
+    /// let play_context = PlayContext::from(&game_context);
+    /// let half_seconds = play_context.half_seconds();
+    /// assert!(half_seconds == 1800);
+    /// ```
+    pub fn half_seconds(&self) -> u32 {
+        self.half_seconds
+    }
+
+    /// Gets the offense's score minus the defense's score
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::context::PlayContext;
+    ///
+    /// let game_context = GameContext::new();
+    /// let play_context = PlayContext::from(&game_context);
+    /// let score_diff = play_context.score_diff();
+    /// assert!(score_diff == 0);
+    /// ```
+    pub fn score_diff(&self) -> i32 {
+        self.score_diff
+    }
+
+    /// Gets the fraction of regulation which has elapsed, from 0 at the
+    /// opening kickoff to 1 at the end of the 4th quarter and in overtime
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::play::context::PlayContext;
+    ///
+    /// let game_context = GameContextBuilder::new()
+    ///     .quarter(3)
+    ///     .half_seconds(1800)
+    ///     .build()
+    ///     .unwrap();
+    /// let play_context = PlayContext::from(&game_context);
+    /// assert!(play_context.game_progress() == 0.5);
+    /// ```
+    pub fn game_progress(&self) -> f64 {
+        let rules = &self.clock_rules;
+        let regulation = rules.regulation_seconds();
+        let elapsed: u32 = if rules.overtime(self.quarter) {
+            regulation
+        } else if rules.second_half(self.quarter) {
+            regulation.saturating_sub(self.half_seconds)
+        } else {
+            rules.half_length().saturating_sub(self.half_seconds)
+        };
+        elapsed as f64 / regulation as f64
+    }
+
     /// Whether this is a drain-clock scenario for the offense
     ///
     /// ### Example
@@ -260,7 +351,7 @@ impl PlayContext {
         let scores_up_by: f32 = self.score_diff as f32 / 8_f32;
         let drain_threshold_sig: i32 = (scores_up_by * 4_f32 * 60_f32) as i32;
         let drain_threshold: u32 = u32::try_from(drain_threshold_sig).unwrap_or_default();
-        if self.quarter >= 4 && self.half_seconds < drain_threshold {
+        if self.clock_rules.final_quarter(self.quarter) && self.half_seconds < drain_threshold {
             return true
         }
         false
@@ -279,7 +370,7 @@ impl PlayContext {
     /// assert!(!up_tempo);
     /// ```
     pub fn up_tempo(&self) -> bool {
-        (self.quarter == 2 || self.quarter >= 4) && self.half_seconds <= 180 &&
+        self.clock_rules.ends_half(self.quarter) && self.half_seconds <= 180 &&
         self.score_diff < 0 && self.score_diff >= -17
     }
 
@@ -313,7 +404,7 @@ impl PlayContext {
     /// assert!(!conserve_clock);
     /// ```
     pub fn offense_conserve_clock(&self) -> bool {
-        (self.quarter == 2 || self.quarter >= 4) && self.half_seconds <= 180 &&
+        self.clock_rules.ends_half(self.quarter) && self.half_seconds <= 180 &&
         self.score_diff < 0 && self.score_diff > -18
     }
 
@@ -330,7 +421,7 @@ impl PlayContext {
     /// assert!(!conserve_clock);
     /// ```
     pub fn defense_conserve_clock(&self) -> bool {
-        self.quarter >= 4 && self.half_seconds <= 180 &&
+        self.clock_rules.final_quarter(self.quarter) && self.half_seconds <= 180 &&
         self.score_diff > 0 && self.score_diff < 18
     }
 
@@ -395,27 +486,117 @@ impl PlayContext {
     /// assert!(!onside_kick);
     /// ```
     pub fn onside_kick(&self) -> bool {
-        self.score_diff < 0 && self.quarter >= 4 && self.must_score()
+        self.score_diff < 0 && self.clock_rules.final_quarter(self.quarter) && self.must_score()
     }
 
-    /// Whether the offense can kneel to end the game
+    /// Whether the offense can kneel to end the game: it leads in the 4th
+    /// quarter or overtime, and kneeling on each remaining down before 4th
+    /// runs out the clock despite the defense's timeouts without backing up
+    /// into its own end zone
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::context::{GameContext, GameContextBuilder};
     /// use fbsim_core::game::play::context::PlayContext;
     /// 
     /// let game_context = GameContext::new();
     /// let play_context = PlayContext::from(&game_context);
     /// let can_kneel = play_context.can_kneel();
     /// assert!(!can_kneel);
+    ///
+    /// // Leading with 1:50 left against a defense with no timeouts
+    /// let game_context = GameContextBuilder::new()
+    ///     .quarter(4)
+    ///     .half_seconds(110)
+    ///     .down(1)
+    ///     .home_score(7)
+    ///     .away_score(3)
+    ///     .away_timeouts(0)
+    ///     .next_play_kickoff(false)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(PlayContext::from(&game_context).can_kneel());
     /// ```
     pub fn can_kneel(&self) -> bool {
+        if self.score_diff <= 0 || !self.clock_rules.final_quarter(self.quarter) || self.down == 0 || self.down > 3 {
+            return false
+        }
         let downs_remaining = 4 - self.down;
-        let runoff_seconds = 42 * downs_remaining.checked_sub(self.def_timeouts).unwrap_or_default();
+        if self.yard_line <= downs_remaining {
+            return false
+        }
+        let runoff_seconds = KNEEL_RUNOFF_SECONDS * downs_remaining.saturating_sub(self.def_timeouts);
         runoff_seconds >= self.half_seconds
     }
 
+    /// Whether the offense could have knelt out the clock before the down
+    /// just played, in which case it lets the play clock run down before
+    /// the next snap
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::play::context::PlayContext;
+    ///
+    /// // 2nd down after a kneel on 1st down with 1:50 left
+    /// let game_context = GameContextBuilder::new()
+    ///     .quarter(4)
+    ///     .half_seconds(108)
+    ///     .down(2)
+    ///     .distance(11)
+    ///     .home_score(7)
+    ///     .away_score(3)
+    ///     .away_timeouts(0)
+    ///     .next_play_kickoff(false)
+    ///     .build()
+    ///     .unwrap();
+    /// let play_context = PlayContext::from(&game_context);
+    /// assert!(play_context.victory_formation());
+    /// assert!(!play_context.can_kneel());
+    /// ```
+    pub fn victory_formation(&self) -> bool {
+        if self.score_diff <= 0 || !self.clock_rules.final_quarter(self.quarter) || self.down < 2 || self.down > 4 {
+            return false
+        }
+        let downs_remaining = 5 - self.down;
+        let runoff_seconds = KNEEL_RUNOFF_SECONDS * downs_remaining.saturating_sub(self.def_timeouts);
+        runoff_seconds >= self.half_seconds + KNEEL_DURATION
+    }
+
+    /// Whether the trailing offense should spike the ball to stop the clock:
+    /// the clock is running inside the last 30 seconds of the half, the
+    /// offense has no timeouts left, and it can spare a down, which on 3rd
+    /// down means a field goal on 4th would be enough
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::play::context::PlayContext;
+    ///
+    /// // Trailing by 3 with the clock running and no timeouts
+    /// let game_context = GameContextBuilder::new()
+    ///     .quarter(4)
+    ///     .half_seconds(20)
+    ///     .home_score(0)
+    ///     .away_score(3)
+    ///     .down(1)
+    ///     .home_timeouts(0)
+    ///     .next_play_kickoff(false)
+    ///     .build()
+    ///     .unwrap();
+    /// let play_context = PlayContext::from(&game_context);
+    /// assert!(play_context.spike());
+    /// ```
+    pub fn spike(&self) -> bool {
+        if !(self.offense_conserve_clock() && self.clock_running) || self.off_timeouts > 0 {
+            return false
+        }
+        if self.half_seconds > SPIKE_SECONDS || self.last_play() {
+            return false
+        }
+        self.down < 3 || (self.down == 3 && self.score_diff >= -3 && self.in_field_goal_range())
+    }
+
     /// Whether this is a must-score scenario for 4th-down playcalling
     ///
     /// ### Example
@@ -438,7 +619,7 @@ impl PlayContext {
         }
         let non_timeout_drive_time = (42 * 3) + 8;
         let timeout_drives_remaining: u32 = 1;
-        let non_timeout_drive_time_remaining = self.half_seconds.checked_sub(timeout_drive_time).unwrap_or_default();
+        let non_timeout_drive_time_remaining = self.half_seconds.saturating_sub(timeout_drive_time);
         let non_timeout_drives_remaining = (
             non_timeout_drive_time_remaining as f32 / non_timeout_drive_time as f32
         ).ceil() as u32;
@@ -499,12 +680,7 @@ impl std::fmt::Display for PlayContext {
     /// ```
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // Format the clock
-        let clock_total = if self.half_seconds < 900 || 
-            (self.half_seconds == 900 && self.quarter.is_multiple_of(2) && self.quarter <= 4) {
-            self.half_seconds
-        } else {
-            self.half_seconds - 900
-        };
+        let clock_total = self.half_seconds.saturating_sub(self.clock_rules.quarter_end(self.quarter));
         let clock_mins = clock_total / 60;
         let clock_secs = clock_total - (clock_mins * 60);
         let clock_secs_str = if clock_secs < 10 {
@@ -515,10 +691,10 @@ impl std::fmt::Display for PlayContext {
         let clock_str = format!("{}:{}", clock_mins, &clock_secs_str);
 
         // Format the quarter
-        let quarter_str = if self.quarter <= 4 {
+        let quarter_str = if !self.clock_rules.overtime(self.quarter) {
             format!("{}Q", self.quarter)
         } else {
-            let num_ot = self.quarter - 4;
+            let num_ot = self.quarter - self.clock_rules.quarters;
             format!("{}OT", num_ot)
         };
 
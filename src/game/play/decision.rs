@@ -0,0 +1,360 @@
+#![doc = include_str!("../../../docs/game/play/decision.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+use crate::game::context::GameContext;
+use crate::game::play::call::PlayCall;
+use crate::game::play::context::PlayContext;
+
+/// # `DecisionKind` enum
+///
+/// The kinds of coaching decisions recorded in a `DecisionLog`
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum DecisionKind {
+    /// Whether to go for it, kick a field goal, or punt on 4th down
+    FourthDown,
+    /// Whether to kick the extra point or go for two after a touchdown
+    Conversion,
+    /// Whether to call a timeout between plays
+    Timeout
+}
+
+/// # `DecisionChoice` enum
+///
+/// The options a coach chooses between
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum DecisionChoice {
+    GoForIt,
+    FieldGoal,
+    Punt,
+    ExtraPoint,
+    TwoPointConversion,
+    OffenseTimeout,
+    DefenseTimeout,
+    NoTimeout
+}
+
+impl DecisionChoice {
+    /// The 4th down choice a play call represents, or `None` if the play
+    /// call is not made on 4th down
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::call::PlayCall;
+    /// use fbsim_core::game::play::decision::DecisionChoice;
+    ///
+    /// assert_eq!(DecisionChoice::from_fourth_down_call(PlayCall::Run), Some(DecisionChoice::GoForIt));
+    /// assert_eq!(DecisionChoice::from_fourth_down_call(PlayCall::Punt), Some(DecisionChoice::Punt));
+    /// assert_eq!(DecisionChoice::from_fourth_down_call(PlayCall::Kickoff), None);
+    /// ```
+    pub fn from_fourth_down_call(play_call: PlayCall) -> Option<DecisionChoice> {
+        match play_call {
+            PlayCall::Run | PlayCall::Pass => Some(DecisionChoice::GoForIt),
+            PlayCall::FieldGoal => Some(DecisionChoice::FieldGoal),
+            PlayCall::Punt => Some(DecisionChoice::Punt),
+            _ => None
+        }
+    }
+}
+
+/// # `DecisionOption` struct
+///
+/// An option a coach considered, scored by the probability the coach's
+/// tendencies give it of being chosen
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DecisionOption {
+    /// The option considered
+    pub choice: DecisionChoice,
+    /// The probability of choosing the option, in [0, 1]
+    pub score: f64
+}
+
+/// # `Decision` struct
+///
+/// A coaching decision: the situation it was made in, the options
+/// considered, the option chosen, and why
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Decision {
+    /// The kind of decision
+    pub kind: DecisionKind,
+    /// The game clock, down and distance, yard line and score differential
+    /// from the offense's point of view
+    pub situation: PlayContext,
+    /// Whether the home team had possession
+    pub home_possession: bool,
+    /// The options considered
+    pub options: Vec<DecisionOption>,
+    /// The option chosen
+    pub chosen: DecisionChoice,
+    /// Why the options were considered
+    pub reason: String
+}
+
+impl Decision {
+    /// Constructor for the `Decision` struct, with no options considered
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::decision::{Decision, DecisionChoice, DecisionKind};
+    ///
+    /// let my_decision = Decision::new(DecisionKind::Timeout, DecisionChoice::NoTimeout, &GameContext::new());
+    /// assert!(my_decision.options.is_empty());
+    /// assert_eq!(my_decision.situation.quarter(), 1);
+    /// ```
+    pub fn new(kind: DecisionKind, chosen: DecisionChoice, context: &GameContext) -> Decision {
+        Decision {
+            kind,
+            situation: PlayContext::from(context),
+            home_possession: context.home_possession(),
+            options: Vec::new(),
+            chosen,
+            reason: String::new()
+        }
+    }
+
+    /// Add an option the coach considered, with its probability of being
+    /// chosen. Options which could not have been chosen are left out.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::decision::{Decision, DecisionChoice, DecisionKind};
+    ///
+    /// let my_decision = Decision::new(DecisionKind::FourthDown, DecisionChoice::Punt, &GameContext::new())
+    ///     .option(DecisionChoice::Punt, 0.75)
+    ///     .option(DecisionChoice::GoForIt, 0.25)
+    ///     .option(DecisionChoice::FieldGoal, 0.0);
+    /// assert_eq!(my_decision.options.len(), 2);
+    /// ```
+    pub fn option(mut self, choice: DecisionChoice, score: f64) -> Self {
+        if score > 0_f64 {
+            self.options.push(DecisionOption { choice, score });
+        }
+        self
+    }
+
+    /// Set why the options were considered
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::decision::{Decision, DecisionChoice, DecisionKind};
+    ///
+    /// let my_decision = Decision::new(DecisionKind::Timeout, DecisionChoice::OffenseTimeout, &GameContext::new())
+    ///     .reason("Offense conserving the clock");
+    /// assert_eq!(my_decision.reason, "Offense conserving the clock");
+    /// ```
+    pub fn reason(mut self, reason: &str) -> Self {
+        self.reason = String::from(reason);
+        self
+    }
+
+    /// The score of the chosen option
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::decision::{Decision, DecisionChoice, DecisionKind};
+    ///
+    /// let my_decision = Decision::new(DecisionKind::FourthDown, DecisionChoice::Punt, &GameContext::new())
+    ///     .option(DecisionChoice::Punt, 0.75)
+    ///     .option(DecisionChoice::GoForIt, 0.25);
+    /// assert_eq!(my_decision.chosen_score(), Some(0.75));
+    /// ```
+    pub fn chosen_score(&self) -> Option<f64> {
+        self.options.iter()
+            .find(|option| option.choice == self.chosen)
+            .map(|option| option.score)
+    }
+}
+
+/// # `DecisionEntry` struct
+///
+/// A coaching decision, linked to the play of the game it was made on
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DecisionEntry {
+    /// Index of the play among all the plays of the game's drives. 4th down
+    /// and conversion decisions are made before the play, and timeout
+    /// decisions after it.
+    pub play_index: usize,
+    /// The decision
+    pub decision: Decision
+}
+
+/// # `DecisionLog` struct
+///
+/// The coaching decisions made during a game, in the order they were made
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct DecisionLog {
+    entries: Vec<DecisionEntry>
+}
+
+impl DecisionLog {
+    /// Constructor for an empty `DecisionLog`
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::decision::DecisionLog;
+    ///
+    /// let my_log = DecisionLog::new();
+    /// assert!(my_log.entries().is_empty());
+    /// ```
+    pub fn new() -> DecisionLog {
+        DecisionLog::default()
+    }
+
+    /// Borrow the logged decisions
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::decision::DecisionLog;
+    ///
+    /// let my_log = DecisionLog::new();
+    /// let entries = my_log.entries();
+    /// ```
+    pub fn entries(&self) -> &Vec<DecisionEntry> {
+        &self.entries
+    }
+
+    /// Record a decision made on a play
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::decision::{Decision, DecisionChoice, DecisionKind, DecisionLog};
+    ///
+    /// let mut my_log = DecisionLog::new();
+    /// let my_decision = Decision::new(DecisionKind::Conversion, DecisionChoice::ExtraPoint, &GameContext::new());
+    /// my_log.record(12, my_decision);
+    /// assert_eq!(my_log.entries()[0].play_index, 12);
+    /// ```
+    pub fn record(&mut self, play_index: usize, decision: Decision) {
+        self.entries.push(DecisionEntry { play_index, decision });
+    }
+
+    /// The decisions made on a play
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::decision::{Decision, DecisionChoice, DecisionKind, DecisionLog};
+    ///
+    /// let mut my_log = DecisionLog::new();
+    /// let my_decision = Decision::new(DecisionKind::Conversion, DecisionChoice::ExtraPoint, &GameContext::new());
+    /// my_log.record(12, my_decision);
+    /// assert_eq!(my_log.for_play(12).len(), 1);
+    /// assert!(my_log.for_play(13).is_empty());
+    /// ```
+    pub fn for_play(&self, play_index: usize) -> Vec<&Decision> {
+        self.entries.iter()
+            .filter(|entry| entry.play_index == play_index)
+            .map(|entry| &entry.decision)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use crate::game::play::GameSimulator;
+    use crate::game::play::result::PlayTypeResult;
+    use crate::team::FootballTeam;
+
+    #[test]
+    fn test_decision_log_matches_game() {
+        let home = FootballTeam::from_overalls("HM", "HM", 70, 60).unwrap();
+        let away = FootballTeam::from_overalls("AW", "AW", 60, 70).unwrap();
+        let simulator = GameSimulator::new();
+        let mut fourth_downs = 0;
+        let mut timeouts = 0;
+        for seed in 0..20 {
+            // Logging decisions does not change the simulated game
+            let mut rng = SmallRng::seed_from_u64(1503 + seed);
+            let (game, _, log) = simulator.sim_with_decisions(&home, &away, GameContext::new(), &mut rng).unwrap();
+            let mut rng = SmallRng::seed_from_u64(1503 + seed);
+            let (unlogged, _) = simulator.sim(&home, &away, GameContext::new(), &mut rng).unwrap();
+            assert_eq!(game, unlogged);
+
+            let plays: Vec<_> = game.drives().iter().flat_map(|d| d.plays().iter()).collect();
+            assert!(log.entries().iter().all(|entry| entry.play_index < plays.len()));
+            for (play_index, play) in plays.iter().enumerate() {
+                let context = play.context();
+                let decisions = log.for_play(play_index);
+                let of_kind = |kind: DecisionKind| -> Vec<&Decision> {
+                    decisions.iter().copied().filter(|d| d.kind == kind).collect()
+                };
+
+                // Every 4th down snap has one decision, consistent with the
+                // play which was run
+                let snap = !context.next_play_kickoff() && !context.next_play_extra_point();
+                let fourth_down = of_kind(DecisionKind::FourthDown);
+                if snap && context.down() == 4 {
+                    fourth_downs += 1;
+                    assert_eq!(fourth_down.len(), 1);
+                    let expected = match play.result() {
+                        PlayTypeResult::Run(_) | PlayTypeResult::Pass(_) |
+                            PlayTypeResult::QbKneel(_) | PlayTypeResult::QbSpike(_) => DecisionChoice::GoForIt,
+                        PlayTypeResult::FieldGoal(_) => DecisionChoice::FieldGoal,
+                        PlayTypeResult::Punt(_) => DecisionChoice::Punt,
+                        other => panic!("Unexpected 4th down play: {}", other)
+                    };
+                    let decision = fourth_down[0];
+                    assert_eq!(decision.chosen, expected);
+                    assert_eq!(decision.situation, PlayContext::from(context));
+                    assert!(decision.chosen_score().unwrap() > 0_f64);
+                    let total: f64 = decision.options.iter().map(|o| o.score).sum();
+                    assert!((total - 1_f64).abs() < 1e-9, "{:?}", decision);
+                } else {
+                    assert!(fourth_down.is_empty());
+                }
+
+                // Conversion decisions match the conversion attempted
+                let conversion = of_kind(DecisionKind::Conversion);
+                if context.next_play_extra_point() {
+                    assert_eq!(conversion.len(), 1);
+                    let two_point = matches!(play.result(), PlayTypeResult::Run(_) | PlayTypeResult::Pass(_));
+                    assert_eq!(conversion[0].chosen == DecisionChoice::TwoPointConversion, two_point);
+                } else {
+                    assert!(conversion.is_empty());
+                }
+
+                // Every timeout called has a decision
+                let (offense_timeout, defense_timeout) = match play.post_play() {
+                    PlayTypeResult::BetweenPlay(res) => (res.offense_timeout(), res.defense_timeout()),
+                    _ => (false, false)
+                };
+                let timeout = of_kind(DecisionKind::Timeout);
+                let chosen = |choice: DecisionChoice| timeout.iter().any(|d| d.chosen == choice);
+                assert_eq!(chosen(DecisionChoice::OffenseTimeout), offense_timeout);
+                assert_eq!(chosen(DecisionChoice::DefenseTimeout), defense_timeout);
+                timeouts += (offense_timeout || defense_timeout) as usize;
+            }
+        }
+        assert!(fourth_downs > 0);
+        assert!(timeouts > 0);
+    }
+}
@@ -0,0 +1,850 @@
+#![doc = include_str!("../../../docs/game/play/narration.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+#[cfg(feature = "wasm")]
+use tsify_next::Tsify;
+
+use crate::game::context::GameContext;
+use crate::game::play::{Game, Play};
+use crate::game::play::penalty::{PenaltyDecision, PenaltyTeam};
+use crate::game::play::result::{PlayResult, PlayTypeResult, ScoreResult};
+use crate::game::play::result::betweenplay::BetweenPlayResult;
+use crate::game::play::result::fieldgoal::FieldGoalResult;
+use crate::game::play::result::kickoff::KickoffResult;
+use crate::game::play::result::pass::PassResult;
+use crate::game::play::result::punt::PuntResult;
+use crate::game::play::result::run::RunResult;
+
+/// # `NarrationTemplates` struct
+///
+/// The templates used to narrate plays. Each template is a sentence whose
+/// `{placeholder}` tokens are replaced when narrating, so the narration can
+/// be localized by overriding them. An empty template is left out of the
+/// narration entirely.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NarrationTemplates {
+    /// Ordinal names of the downs and quarters, starting from the first
+    pub ordinals: Vec<String>,
+    /// A yard line on one team's side of the field: `{team}`, `{yard}`
+    pub yard_line: String,
+    /// The 50 yard line
+    pub midfield: String,
+    /// A distance of one yard, substituted for `{yards}`: `{n}`
+    pub yard: String,
+    /// A distance of any other number of yards, substituted for `{yards}`:
+    /// `{n}`
+    pub yards: String,
+    /// The situation before a scrimmage play: `{down}`, `{distance}`, `{spot}`
+    pub down_and_distance: String,
+    /// The situation before a scrimmage play in a goal-to-go situation:
+    /// `{down}`, `{spot}`
+    pub down_and_goal: String,
+    /// A two-point conversion attempt: `{offense}`
+    pub two_point_attempt: String,
+    /// A run gaining yards: `{offense}`, `{yards}`
+    pub run: String,
+    /// A run losing yards: `{offense}`, `{yards}`
+    pub run_loss: String,
+    /// A quarterback kneel: `{offense}`
+    pub qb_kneel: String,
+    /// A quarterback spike: `{offense}`
+    pub qb_spike: String,
+    /// A completed pass: `{offense}`, `{distance}`, `{yards}`
+    pub pass_complete: String,
+    /// An incomplete pass: `{offense}`
+    pub pass_incomplete: String,
+    /// A sack: `{offense}`, `{yards}`
+    pub sack: String,
+    /// A quarterback scramble: `{offense}`, `{yards}`
+    pub scramble: String,
+    /// An interception: `{offense}`, `{defense}`
+    pub interception: String,
+    /// A fumble
+    pub fumble: String,
+    /// A recovered fumble or onside kick: `{team}`
+    pub recovery: String,
+    /// A return of a kick or turnover: `{yards}`
+    pub return_yards: String,
+    /// A kickoff: `{offense}`, `{yards}`, `{spot}`
+    pub kickoff: String,
+    /// An onside kick: `{offense}`, `{yards}`, `{spot}`
+    pub onside_kick: String,
+    /// A punt: `{offense}`, `{yards}`, `{spot}`
+    pub punt: String,
+    /// A blocked punt: `{offense}`
+    pub punt_blocked: String,
+    /// A muffed punt: `{team}`
+    pub muffed: String,
+    /// A fair catch: `{team}`
+    pub fair_catch: String,
+    /// A punt downed by the kicking team: `{team}`
+    pub downed: String,
+    /// A kick downed in the end zone
+    pub touchback: String,
+    /// A kick out of bounds
+    pub out_of_bounds: String,
+    /// A made field goal: `{offense}`, `{distance}`
+    pub field_goal_good: String,
+    /// A missed field goal: `{offense}`, `{distance}`
+    pub field_goal_no_good: String,
+    /// A blocked field goal: `{offense}`, `{distance}`
+    pub field_goal_blocked: String,
+    /// A made extra point: `{offense}`
+    pub extra_point_good: String,
+    /// A missed extra point: `{offense}`
+    pub extra_point_no_good: String,
+    /// A blocked extra point: `{offense}`
+    pub extra_point_blocked: String,
+    /// A successful two-point conversion: `{team}`
+    pub two_point_good: String,
+    /// A failed two-point conversion
+    pub two_point_no_good: String,
+    /// A touchdown: `{team}`
+    pub touchdown: String,
+    /// A safety: `{team}`
+    pub safety: String,
+    /// The score after a scoring play: `{home}`, `{home_score}`, `{away}`,
+    /// `{away_score}`
+    pub score: String,
+    /// A penalty: `{team}`, `{yards}`
+    pub penalty: String,
+    /// An accepted penalty
+    pub penalty_accepted: String,
+    /// A declined penalty
+    pub penalty_declined: String,
+    /// Offsetting penalties
+    pub penalty_offsetting: String,
+    /// A timeout: `{team}`
+    pub timeout: String,
+    /// The end of a quarter: `{quarter}`
+    pub end_of_quarter: String,
+    /// The end of the first half
+    pub end_of_half: String,
+    /// The end of an overtime period
+    pub end_of_overtime: String,
+    /// The end of the game: `{home}`, `{home_score}`, `{away}`,
+    /// `{away_score}`
+    pub end_of_game: String
+}
+
+impl Default for NarrationTemplates {
+    /// Default constructor for the `NarrationTemplates` struct, narrating
+    /// in English
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::narration::NarrationTemplates;
+    ///
+    /// let my_templates = NarrationTemplates::default();
+    /// assert_eq!(my_templates.midfield, "midfield");
+    /// ```
+    fn default() -> Self {
+        NarrationTemplates {
+            ordinals: ["1st", "2nd", "3rd", "4th"].iter().map(|s| String::from(*s)).collect(),
+            yard_line: String::from("{team} {yard}"),
+            midfield: String::from("midfield"),
+            yard: String::from("{n} yard"),
+            yards: String::from("{n} yards"),
+            down_and_distance: String::from("{down} & {distance} at {spot}:"),
+            down_and_goal: String::from("{down} & goal at {spot}:"),
+            two_point_attempt: String::from("{offense} goes for two:"),
+            run: String::from("{offense} runs for {yards}."),
+            run_loss: String::from("{offense} runs for a loss of {yards}."),
+            qb_kneel: String::from("{offense} takes a knee."),
+            qb_spike: String::from("{offense} spikes the ball."),
+            pass_complete: String::from("{offense} completes a pass for {yards}."),
+            pass_incomplete: String::from("{offense} pass is incomplete."),
+            sack: String::from("{offense} is sacked for a loss of {yards}."),
+            scramble: String::from("{offense} scrambles for {yards}."),
+            interception: String::from("{offense} pass is intercepted by {defense}!"),
+            fumble: String::from("Fumble!"),
+            recovery: String::from("Recovered by {team}."),
+            return_yards: String::from("Returned {yards}."),
+            kickoff: String::from("{offense} kicks off {yards} from {spot}."),
+            onside_kick: String::from("{offense} attempts an onside kick of {yards} from {spot}."),
+            punt: String::from("{offense} punts {yards} from {spot}."),
+            punt_blocked: String::from("{offense} punt is blocked!"),
+            muffed: String::from("Muffed by {team}!"),
+            fair_catch: String::from("Fair catch by {team}."),
+            downed: String::from("Downed by {team}."),
+            touchback: String::from("Touchback."),
+            out_of_bounds: String::from("Out of bounds."),
+            field_goal_good: String::from("{offense} {distance}-yard field goal is good."),
+            field_goal_no_good: String::from("{offense} {distance}-yard field goal is no good."),
+            field_goal_blocked: String::from("{offense} {distance}-yard field goal is blocked!"),
+            extra_point_good: String::from("{offense} extra point is good."),
+            extra_point_no_good: String::from("{offense} extra point is no good."),
+            extra_point_blocked: String::from("{offense} extra point is blocked!"),
+            two_point_good: String::from("Two-point conversion, {team}!"),
+            two_point_no_good: String::from("The two-point conversion fails."),
+            touchdown: String::from("Touchdown, {team}!"),
+            safety: String::from("Safety, {team}!"),
+            score: String::from("{home} {home_score}, {away} {away_score}."),
+            penalty: String::from("Penalty on {team}, {yards}."),
+            penalty_accepted: String::from("The penalty is accepted."),
+            penalty_declined: String::from("The penalty is declined."),
+            penalty_offsetting: String::from("Offsetting penalties."),
+            timeout: String::from("Timeout, {team}."),
+            end_of_quarter: String::from("End of the {quarter} quarter."),
+            end_of_half: String::from("End of the half."),
+            end_of_overtime: String::from("End of overtime."),
+            end_of_game: String::from("Final: {home} {home_score}, {away} {away_score}.")
+        }
+    }
+}
+
+/// Substitute each `{name}` placeholder of a template with its value
+fn fill(template: &str, values: &[(&str, String)]) -> String {
+    let mut filled = String::from(template);
+    for (name, value) in values.iter() {
+        filled = filled.replace(&format!("{{{}}}", name), value);
+    }
+    filled
+}
+
+/// The short names of the teams on offense and defense in a context
+fn offense_defense(context: &GameContext) -> (String, String) {
+    let home = String::from(context.home_team_short());
+    let away = String::from(context.away_team_short());
+    if context.home_possession() {
+        (home, away)
+    } else {
+        (away, home)
+    }
+}
+
+impl NarrationTemplates {
+    /// Constructor for the `NarrationTemplates` struct, narrating in English
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::narration::NarrationTemplates;
+    ///
+    /// let my_templates = NarrationTemplates::new();
+    /// assert_eq!(my_templates, NarrationTemplates::default());
+    /// ```
+    pub fn new() -> NarrationTemplates {
+        NarrationTemplates::default()
+    }
+
+    /// Get the ordinal name of a down or quarter, counting from 1
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::narration::NarrationTemplates;
+    ///
+    /// let my_templates = NarrationTemplates::new();
+    /// assert_eq!(my_templates.ordinal(3), "3rd");
+    /// assert_eq!(my_templates.ordinal(5), "5th");
+    /// ```
+    pub fn ordinal(&self, n: u32) -> String {
+        match self.ordinals.get((n as usize).wrapping_sub(1)) {
+            Some(ordinal) => ordinal.clone(),
+            None => format!("{}th", n)
+        }
+    }
+
+    /// Name a distance in yards
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::narration::NarrationTemplates;
+    ///
+    /// let my_templates = NarrationTemplates::new();
+    /// assert_eq!(my_templates.yards(1), "1 yard");
+    /// assert_eq!(my_templates.yards(-3), "-3 yards");
+    /// ```
+    pub fn yards(&self, n: i32) -> String {
+        let template = if n == 1 { &self.yard } else { &self.yards };
+        fill(template, &[("n", n.to_string())])
+    }
+
+    /// Name a yard line of a context's field by the team whose side of the
+    /// field it is on, for example "HOME 38" or "AWAY 12"
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::play::narration::NarrationTemplates;
+    ///
+    /// let my_context = GameContextBuilder::new()
+    ///     .home_positive_direction(true)
+    ///     .build()
+    ///     .unwrap();
+    /// let my_templates = NarrationTemplates::new();
+    /// assert_eq!(my_templates.spot(&my_context, 38), "HOME 38");
+    /// assert_eq!(my_templates.spot(&my_context, 88), "AWAY 12");
+    /// assert_eq!(my_templates.spot(&my_context, 50), "midfield");
+    /// ```
+    pub fn spot(&self, context: &GameContext, yard_line: u32) -> String {
+        if yard_line == 50 {
+            return self.midfield.clone();
+        }
+
+        // The home team defends the goal at yard line 0 while moving in the
+        // positive direction
+        let home_side = (yard_line < 50) == context.home_positive_direction();
+        let team = if home_side {
+            context.home_team_short()
+        } else {
+            context.away_team_short()
+        };
+        let yard = if yard_line < 50 { yard_line } else { 100 - yard_line.min(100) };
+        fill(
+            &self.yard_line,
+            &[("team", String::from(team)), ("yard", yard.to_string())]
+        )
+    }
+
+    /// Narrate the down, distance, and line of scrimmage of a context
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::play::narration::NarrationTemplates;
+    ///
+    /// let my_context = GameContextBuilder::new()
+    ///     .home_positive_direction(true)
+    ///     .down(3)
+    ///     .distance(7)
+    ///     .yard_line(38)
+    ///     .next_play_kickoff(false)
+    ///     .build()
+    ///     .unwrap();
+    /// let my_templates = NarrationTemplates::new();
+    /// assert_eq!(my_templates.situation(&my_context), "3rd & 7 at HOME 38:");
+    /// ```
+    pub fn situation(&self, context: &GameContext) -> String {
+        let spot = self.spot(context, context.yard_line());
+        let down = self.ordinal(context.down());
+        if context.distance() as i32 >= context.yards_to_touchdown() {
+            fill(&self.down_and_goal, &[("down", down), ("spot", spot)])
+        } else {
+            fill(
+                &self.down_and_distance,
+                &[("down", down), ("distance", context.distance().to_string()), ("spot", spot)]
+            )
+        }
+    }
+
+    /// Narrate the result of a play given the context it was run from
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::play::narration::NarrationTemplates;
+    /// use fbsim_core::game::play::result::PlayTypeResult;
+    /// use fbsim_core::game::play::result::run::RunResultBuilder;
+    ///
+    /// let my_context = GameContextBuilder::new()
+    ///     .home_positive_direction(true)
+    ///     .down(1)
+    ///     .distance(10)
+    ///     .yard_line(25)
+    ///     .next_play_kickoff(false)
+    ///     .build()
+    ///     .unwrap();
+    /// let my_result = PlayTypeResult::Run(
+    ///     RunResultBuilder::new().yards_gained(4).build().unwrap()
+    /// );
+    /// let my_templates = NarrationTemplates::new();
+    /// assert_eq!(
+    ///     my_templates.narrate(&my_context, &my_result).unwrap(),
+    ///     "1st & 10 at HOME 25: HOME runs for 4 yards."
+    /// );
+    /// ```
+    pub fn narrate(&self, context: &GameContext, result: &PlayTypeResult) -> Result<String, String> {
+        let next = result.next_context(context)?;
+        Ok(self.narrate_result(context, result, &next).join(" "))
+    }
+
+    /// Narrate a play, including its result, any penalty, the timeouts taken
+    /// after it, and the end of a quarter, half, or game it brought about
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::play::Play;
+    /// use fbsim_core::game::play::narration::NarrationTemplates;
+    /// use fbsim_core::game::play::result::PlayTypeResult;
+    /// use fbsim_core::game::play::result::betweenplay::BetweenPlayResultBuilder;
+    /// use fbsim_core::game::play::result::run::RunResultBuilder;
+    ///
+    /// let my_context = GameContextBuilder::new()
+    ///     .home_positive_direction(true)
+    ///     .down(1)
+    ///     .distance(10)
+    ///     .yard_line(25)
+    ///     .next_play_kickoff(false)
+    ///     .build()
+    ///     .unwrap();
+    /// let my_result = PlayTypeResult::Run(
+    ///     RunResultBuilder::new().yards_gained(4).build().unwrap()
+    /// );
+    /// let my_between = PlayTypeResult::BetweenPlay(
+    ///     BetweenPlayResultBuilder::new().defense_timeout(true).build().unwrap()
+    /// );
+    /// let my_play = Play::new(my_context, my_result, my_between);
+    /// let my_templates = NarrationTemplates::new();
+    /// assert_eq!(
+    ///     my_templates.narrate_play(&my_play).unwrap(),
+    ///     "1st & 10 at HOME 25: HOME runs for 4 yards. Timeout, AWAY."
+    /// );
+    /// ```
+    pub fn narrate_play(&self, play: &Play) -> Result<String, String> {
+        let context = play.context();
+        let after = match play.penalty() {
+            Some(penalty) => penalty.chosen().context.clone(),
+            None => play.result().next_context(context)?
+        };
+        let mut parts = self.narrate_result(context, play.result(), &after);
+
+        // Narrate the penalty and its enforcement
+        if let Some(penalty) = play.penalty() {
+            let (offense, defense) = offense_defense(context);
+            for flag in penalty.penalties().iter() {
+                let team = match flag.team {
+                    PenaltyTeam::Offense => offense.clone(),
+                    PenaltyTeam::Defense => defense.clone()
+                };
+                parts.push(
+                    fill(&self.penalty, &[("team", team), ("yards", self.yards(flag.enforced_yards(context).abs()))])
+                );
+            }
+            parts.push(
+                match penalty.decision() {
+                    PenaltyDecision::Accepted => self.penalty_accepted.clone(),
+                    PenaltyDecision::Declined => self.penalty_declined.clone(),
+                    PenaltyDecision::Offsetting => self.penalty_offsetting.clone()
+                }
+            );
+        }
+
+        // Narrate the timeouts taken after the play
+        let final_context = play.post_play().next_context(&after)?;
+        if let PlayTypeResult::BetweenPlay(res) = play.post_play() {
+            parts.extend(self.narrate_timeouts(&after, res));
+        }
+
+        // Narrate the end of the quarter, half, or game
+        let end_of_period = final_context.quarter() != context.quarter() ||
+            (after.end_of_half() && !context.end_of_half());
+        if final_context.game_over() && !context.game_over() {
+            parts.push(self.final_score(&self.end_of_game, &final_context));
+        } else if end_of_period {
+            let rules = context.clock_rules();
+            parts.push(
+                match context.quarter() {
+                    q if rules.halftime_quarter(q) => self.end_of_half.clone(),
+                    q if rules.overtime(q) => self.end_of_overtime.clone(),
+                    q => fill(&self.end_of_quarter, &[("quarter", self.ordinal(q))])
+                }
+            );
+        }
+        parts.retain(|p| !p.is_empty());
+        Ok(parts.join(" "))
+    }
+
+    /// Narrate every play of a game in order, one line per play
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::GameSimulator;
+    /// use fbsim_core::game::play::narration::NarrationTemplates;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let home = FootballTeam::new();
+    /// let away = FootballTeam::new();
+    /// let mut rng = rand::thread_rng();
+    /// let (game, _) = GameSimulator::new().sim(&home, &away, GameContext::new(), &mut rng).unwrap();
+    /// let lines = NarrationTemplates::new().narrate_game(&game).unwrap();
+    /// assert!(lines.last().unwrap().contains("Final"));
+    /// ```
+    pub fn narrate_game(&self, game: &Game) -> Result<Vec<String>, String> {
+        game.drives().iter()
+            .flat_map(|drive| drive.plays().iter())
+            .map(|play| self.narrate_play(play))
+            .collect()
+    }
+
+    /// Fill a template with the team names and scores of a context
+    fn final_score(&self, template: &str, context: &GameContext) -> String {
+        fill(
+            template,
+            &[
+                ("home_score", context.home_score().to_string()),
+                ("away_score", context.away_score().to_string()),
+                ("home", String::from(context.home_team_short())),
+                ("away", String::from(context.away_team_short()))
+            ]
+        )
+    }
+
+    /// Narrate the timeouts of a between-play result
+    fn narrate_timeouts(&self, context: &GameContext, result: &BetweenPlayResult) -> Vec<String> {
+        let (offense, defense) = offense_defense(context);
+        let mut parts = Vec::new();
+        if result.offense_timeout() {
+            parts.push(fill(&self.timeout, &[("team", offense)]));
+        }
+        if result.defense_timeout() {
+            parts.push(fill(&self.timeout, &[("team", defense)]));
+        }
+        parts
+    }
+
+    /// Narrate the result of a play as a list of sentences, given the
+    /// contexts before and after it
+    fn narrate_result(&self, context: &GameContext, result: &PlayTypeResult, next: &GameContext) -> Vec<String> {
+        let (offense, defense) = offense_defense(context);
+        let mut parts = Vec::new();
+        match result {
+            PlayTypeResult::BetweenPlay(res) => {
+                parts.extend(self.narrate_timeouts(context, res));
+            },
+            PlayTypeResult::Run(res) | PlayTypeResult::QbKneel(res) => {
+                parts.push(self.scrimmage_situation(context, &offense));
+                parts.extend(self.narrate_run(result, res, &offense, &defense));
+            },
+            PlayTypeResult::Pass(res) | PlayTypeResult::QbSpike(res) => {
+                parts.push(self.scrimmage_situation(context, &offense));
+                parts.extend(self.narrate_pass(result, res, &offense, &defense));
+            },
+            PlayTypeResult::FieldGoal(res) | PlayTypeResult::ExtraPoint(res) => {
+                if !res.extra_point() {
+                    parts.push(self.situation(context));
+                }
+                parts.extend(self.narrate_field_goal(res, &offense, &defense));
+            },
+            PlayTypeResult::Punt(res) => {
+                parts.push(self.situation(context));
+                parts.extend(self.narrate_punt(context, res, &offense, &defense));
+            },
+            PlayTypeResult::Kickoff(res) => {
+                parts.extend(self.narrate_kickoff(context, res, &offense, &defense));
+            }
+        }
+
+        // Narrate a score and the updated scoreboard
+        let offense_score = result.offense_score();
+        let defense_score = result.defense_score();
+        for (score, team) in [(offense_score, &offense), (defense_score, &defense)] {
+            let sentence = match score {
+                ScoreResult::Touchdown => fill(&self.touchdown, &[("team", team.clone())]),
+                ScoreResult::Safety => fill(&self.safety, &[("team", team.clone())]),
+                ScoreResult::TwoPointConversion => fill(&self.two_point_good, &[("team", team.clone())]),
+                _ => String::new()
+            };
+            parts.push(sentence);
+        }
+        let two_point_attempt = context.next_play_extra_point() &&
+            matches!(result, PlayTypeResult::Run(_) | PlayTypeResult::Pass(_));
+        if two_point_attempt && offense_score == ScoreResult::None && defense_score == ScoreResult::None {
+            parts.push(self.two_point_no_good.clone());
+        }
+        if offense_score != ScoreResult::None || defense_score != ScoreResult::None {
+            parts.push(self.final_score(&self.score, next));
+        }
+        parts.retain(|p| !p.is_empty());
+        parts
+    }
+
+    /// Narrate the situation before a run or pass, which is a two-point
+    /// attempt if it follows a touchdown
+    fn scrimmage_situation(&self, context: &GameContext, offense: &str) -> String {
+        if context.next_play_extra_point() {
+            fill(&self.two_point_attempt, &[("offense", String::from(offense))])
+        } else {
+            self.situation(context)
+        }
+    }
+
+    /// Narrate a run or kneel
+    fn narrate_run(&self, result: &PlayTypeResult, res: &RunResult, offense: &str, defense: &str) -> Vec<String> {
+        let mut parts = Vec::new();
+        if matches!(result, PlayTypeResult::QbKneel(_)) {
+            parts.push(fill(&self.qb_kneel, &[("offense", String::from(offense))]));
+        } else if res.yards_gained() < 0 {
+            parts.push(fill(&self.run_loss, &[("offense", String::from(offense)), ("yards", self.yards(-res.yards_gained()))]));
+        } else {
+            parts.push(fill(&self.run, &[("offense", String::from(offense)), ("yards", self.yards(res.yards_gained()))]));
+        }
+        if res.fumble() {
+            parts.push(self.fumble.clone());
+            parts.push(fill(&self.recovery, &[("team", String::from(defense))]));
+            if res.return_yards() != 0 {
+                parts.push(fill(&self.return_yards, &[("yards", self.yards(res.return_yards()))]));
+            }
+        }
+        parts
+    }
+
+    /// Narrate a pass, sack, scramble, or spike
+    fn narrate_pass(&self, result: &PlayTypeResult, res: &PassResult, offense: &str, defense: &str) -> Vec<String> {
+        let mut parts = Vec::new();
+        if matches!(result, PlayTypeResult::QbSpike(_)) {
+            parts.push(fill(&self.qb_spike, &[("offense", String::from(offense))]));
+        } else if res.sack() {
+            parts.push(fill(&self.sack, &[("offense", String::from(offense)), ("yards", self.yards(res.sack_yards_lost()))]));
+        } else if res.scramble() {
+            parts.push(fill(&self.scramble, &[("offense", String::from(offense)), ("yards", self.yards(res.scramble_yards()))]));
+        } else if res.complete() {
+            parts.push(
+                fill(
+                    &self.pass_complete,
+                    &[
+                        ("offense", String::from(offense)),
+                        ("distance", res.pass_dist().to_string()),
+                        ("yards", self.yards(res.pass_dist() + res.yards_after_catch()))
+                    ]
+                )
+            );
+        } else if !res.interception() {
+            parts.push(fill(&self.pass_incomplete, &[("offense", String::from(offense))]));
+        }
+        if res.interception() {
+            parts.push(fill(&self.interception, &[("offense", String::from(offense)), ("defense", String::from(defense))]));
+        } else if res.fumble() {
+            parts.push(self.fumble.clone());
+            parts.push(fill(&self.recovery, &[("team", String::from(defense))]));
+        }
+        if (res.interception() || res.fumble()) && res.return_yards() != 0 {
+            parts.push(fill(&self.return_yards, &[("yards", self.yards(res.return_yards()))]));
+        }
+        parts
+    }
+
+    /// Narrate a field goal or extra point
+    fn narrate_field_goal(&self, res: &FieldGoalResult, offense: &str, defense: &str) -> Vec<String> {
+        let values = [
+            ("offense", String::from(offense)),
+            ("distance", res.field_goal_distance().to_string())
+        ];
+        let template = match (res.extra_point(), res.blocked(), res.made()) {
+            (true, true, _) => &self.extra_point_blocked,
+            (true, false, true) => &self.extra_point_good,
+            (true, false, false) => &self.extra_point_no_good,
+            (false, true, _) => &self.field_goal_blocked,
+            (false, false, true) => &self.field_goal_good,
+            (false, false, false) => &self.field_goal_no_good
+        };
+        let mut parts = vec![fill(template, &values)];
+        if res.blocked() {
+            parts.push(fill(&self.recovery, &[("team", String::from(defense))]));
+            if res.return_yards() != 0 {
+                parts.push(fill(&self.return_yards, &[("yards", self.yards(res.return_yards()))]));
+            }
+        }
+        parts
+    }
+
+    /// Narrate a punt
+    fn narrate_punt(&self, context: &GameContext, res: &PuntResult, offense: &str, defense: &str) -> Vec<String> {
+        if res.blocked() {
+            return vec![fill(&self.punt_blocked, &[("offense", String::from(offense))])];
+        }
+        let mut parts = vec![
+            fill(
+                &self.punt,
+                &[
+                    ("offense", String::from(offense)),
+                    ("yards", self.yards(res.punt_yards())),
+                    ("spot", self.spot(context, context.yard_line()))
+                ]
+            )
+        ];
+        if res.touchback() {
+            parts.push(self.touchback.clone());
+        } else if res.out_of_bounds() {
+            parts.push(self.out_of_bounds.clone());
+        } else if res.downed() {
+            parts.push(fill(&self.downed, &[("team", String::from(offense))]));
+        } else if res.muffed() {
+            parts.push(fill(&self.muffed, &[("team", String::from(defense))]));
+        } else if res.fair_catch() {
+            parts.push(fill(&self.fair_catch, &[("team", String::from(defense))]));
+        } else if res.punt_return_yards() != 0 || !res.fumble() {
+            parts.push(fill(&self.return_yards, &[("yards", self.yards(res.punt_return_yards()))]));
+        }
+        if res.fumble() {
+            if !res.muffed() {
+                parts.push(self.fumble.clone());
+            }
+            let team = if res.receiving_recovery() { defense } else { offense };
+            parts.push(fill(&self.recovery, &[("team", String::from(team))]));
+        }
+        parts
+    }
+
+    /// Narrate a kickoff or onside kick
+    fn narrate_kickoff(&self, context: &GameContext, res: &KickoffResult, offense: &str, defense: &str) -> Vec<String> {
+        let template = if res.onside_kick() { &self.onside_kick } else { &self.kickoff };
+        let mut parts = vec![
+            fill(
+                template,
+                &[
+                    ("offense", String::from(offense)),
+                    ("yards", self.yards(res.kickoff_yards())),
+                    ("spot", self.spot(context, context.yard_line()))
+                ]
+            )
+        ];
+        if res.touchback() {
+            parts.push(self.touchback.clone());
+        } else if res.out_of_bounds() {
+            parts.push(self.out_of_bounds.clone());
+        } else if res.fair_catch() && !res.fumble() {
+            parts.push(fill(&self.fair_catch, &[("team", String::from(defense))]));
+        } else if res.onside_kick() && !res.fumble() {
+            parts.push(fill(&self.recovery, &[("team", String::from(defense))]));
+        } else if res.kick_return_yards() != 0 || !res.fumble() {
+            parts.push(fill(&self.return_yards, &[("yards", self.yards(res.kick_return_yards()))]));
+        }
+        if res.fumble() {
+            if !res.onside_kick() {
+                parts.push(self.fumble.clone());
+            }
+            parts.push(fill(&self.recovery, &[("team", String::from(offense))]));
+        }
+        parts
+    }
+}
+
+/// Narrate the result of a play given the context it was run from, using
+/// the default English templates
+///
+/// ### Example
+/// ```
+/// use fbsim_core::game::context::GameContextBuilder;
+/// use fbsim_core::game::play::narration::narrate;
+/// use fbsim_core::game::play::result::PlayTypeResult;
+/// use fbsim_core::game::play::result::pass::PassResultBuilder;
+///
+/// let my_context = GameContextBuilder::new()
+///     .home_positive_direction(true)
+///     .home_possession(false)
+///     .down(4)
+///     .distance(12)
+///     .yard_line(12)
+///     .next_play_kickoff(false)
+///     .build()
+///     .unwrap();
+/// let my_result = PlayTypeResult::Pass(
+///     PassResultBuilder::new().complete(true).pass_dist(12).touchdown(true).build().unwrap()
+/// );
+/// assert_eq!(
+///     narrate(&my_context, &my_result).unwrap(),
+///     "4th & goal at HOME 12: AWAY completes a pass for 12 yards. Touchdown, AWAY! HOME 0, AWAY 6."
+/// );
+/// ```
+pub fn narrate(context: &GameContext, result: &PlayTypeResult) -> Result<String, String> {
+    NarrationTemplates::default().narrate(context, result)
+}
+
+/// Narrate a play using the default English templates
+///
+/// ### Example
+/// ```
+/// use fbsim_core::game::context::GameContext;
+/// use fbsim_core::game::play::Play;
+/// use fbsim_core::game::play::narration::narrate_play;
+/// use fbsim_core::game::play::result::PlayTypeResult;
+/// use fbsim_core::game::play::result::betweenplay::BetweenPlayResult;
+/// use fbsim_core::game::play::result::kickoff::KickoffResultBuilder;
+///
+/// let my_result = PlayTypeResult::Kickoff(
+///     KickoffResultBuilder::new().kickoff_yards(65).touchback(true).build().unwrap()
+/// );
+/// let my_between = PlayTypeResult::BetweenPlay(BetweenPlayResult::new());
+/// let my_play = Play::new(GameContext::new(), my_result, my_between);
+/// assert_eq!(narrate_play(&my_play).unwrap(), "HOME kicks off 65 yards from HOME 35. Touchback.");
+/// ```
+pub fn narrate_play(play: &Play) -> Result<String, String> {
+    NarrationTemplates::default().narrate_play(play)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use crate::game::context::{GameContextBuilder, PlayOutcome};
+    use crate::game::play::GameSimulator;
+    use crate::game::play::penalty::{resolve_penalties, ExpectedPointsPenaltyStrategy, Penalty};
+    use crate::game::play::result::pass::PassResultBuilder;
+    use crate::game::play::result::run::RunResultBuilder;
+    use crate::team::FootballTeam;
+
+    #[test]
+    fn test_narrate_seeded_game_matches_golden() {
+        let home = FootballTeam::from_overalls("Hawks", "HWK", 70, 60).unwrap();
+        let away = FootballTeam::from_overalls("Eagles", "EGL", 60, 70).unwrap();
+        let context = GameContextBuilder::new()
+            .home_team_short("HWK")
+            .away_team_short("EGL")
+            .build()
+            .unwrap();
+        let mut rng = SmallRng::seed_from_u64(1468);
+        let (game, _) = GameSimulator::new().sim(&home, &away, context, &mut rng).unwrap();
+
+        // The full narration matches the golden file
+        let narration = NarrationTemplates::new().narrate_game(&game).unwrap().join("\n") + "\n";
+        if std::env::var("FBSIM_UPDATE_GOLDEN").is_ok() {
+            std::fs::write("tests/golden/game_narration.txt", &narration).unwrap();
+        }
+        assert_eq!(narration, include_str!("../../../tests/golden/game_narration.txt"));
+
+        // The game narrates kicks, scores, timeouts, and period ends
+        for expected in [
+            "kicks off", "punts", "field goal is", "extra point is", "Touchdown",
+            "Timeout", "End of the 1st quarter.", "End of the half.", "End of the 3rd quarter.", "Final: "
+        ] {
+            assert!(narration.contains(expected), "narration is missing {:?}", expected);
+        }
+    }
+
+    #[test]
+    fn test_narrate_penalties_kneels_and_spikes() {
+        let context = GameContextBuilder::new()
+            .home_positive_direction(true)
+            .down(2)
+            .distance(6)
+            .yard_line(60)
+            .next_play_kickoff(false)
+            .build()
+            .unwrap();
+        let between = PlayTypeResult::BetweenPlay(BetweenPlayResult::new());
+
+        // A kneel and a spike are narrated with the situation
+        let kneel = PlayTypeResult::QbKneel(RunResultBuilder::new().yards_gained(-1).build().unwrap());
+        assert_eq!(narrate(&context, &kneel).unwrap(), "2nd & 6 at AWAY 40: HOME takes a knee.");
+        let spike = PlayTypeResult::QbSpike(PassResultBuilder::new().build().unwrap());
+        assert_eq!(narrate(&context, &spike).unwrap(), "2nd & 6 at AWAY 40: HOME spikes the ball.");
+
+        // An accepted penalty is narrated after the play it was flagged on
+        let run = PlayTypeResult::Run(RunResultBuilder::new().yards_gained(8).build().unwrap());
+        let penalty = Penalty::new(PenaltyTeam::Offense, 10);
+        let outcome = PlayOutcome::from_result(&run);
+        let resolution = resolve_penalties(&context, &outcome, &[penalty], &ExpectedPointsPenaltyStrategy).unwrap();
+        assert_eq!(resolution.decision(), PenaltyDecision::Accepted);
+        let play = Play::new(context.clone(), run, between).with_penalty(resolution);
+        assert_eq!(
+            narrate_play(&play).unwrap(),
+            "2nd & 6 at AWAY 40: HOME runs for 8 yards. Penalty on HOME, 10 yards. The penalty is accepted."
+        );
+
+        // Templates can be overridden
+        let mut templates = NarrationTemplates::new();
+        templates.qb_kneel = String::from("{offense} pose un genou au sol.");
+        templates.down_and_distance = String::new();
+        assert_eq!(templates.narrate(&context, &kneel).unwrap(), "HOME pose un genou au sol.");
+    }
+}
@@ -0,0 +1,493 @@
+#![doc = include_str!("../../../docs/game/play/penalty.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+#[cfg(feature = "wasm")]
+use tsify_next::Tsify;
+
+use crate::game::context::{GameContext, PlayOutcome};
+
+/// # `PenaltyTeam` enum
+///
+/// Identifies which side of the ball committed a penalty, relative to the
+/// team in possession at the snap
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(PartialEq, Clone, Copy, Eq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub enum PenaltyTeam {
+    #[default] Offense,
+    Defense
+}
+
+/// # `Penalty` struct
+///
+/// A `Penalty` represents a flag thrown during a scrimmage play
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(PartialEq, Clone, Copy, Eq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct Penalty {
+    /// The side of the ball which committed the penalty
+    pub team: PenaltyTeam,
+    /// The yardage of the penalty before the half-the-distance rule
+    pub yards: u32,
+    /// Whether a defensive penalty awards an automatic first down
+    pub automatic_first_down: bool,
+    /// Whether an offensive penalty also costs the offense the down
+    pub loss_of_down: bool
+}
+
+impl Penalty {
+    /// Constructor for the `Penalty` struct with no automatic first down or
+    /// loss of down
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::penalty::{Penalty, PenaltyTeam};
+    ///
+    /// let offside = Penalty::new(PenaltyTeam::Defense, 5);
+    /// assert!(offside.yards == 5);
+    /// ```
+    pub fn new(team: PenaltyTeam, yards: u32) -> Penalty {
+        Penalty {
+            team,
+            yards,
+            automatic_first_down: false,
+            loss_of_down: false
+        }
+    }
+
+    /// Set whether the penalty awards an automatic first down
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::penalty::{Penalty, PenaltyTeam};
+    ///
+    /// let holding = Penalty::new(PenaltyTeam::Defense, 5).automatic_first_down(true);
+    /// assert!(holding.automatic_first_down);
+    /// ```
+    pub fn automatic_first_down(mut self, automatic_first_down: bool) -> Self {
+        self.automatic_first_down = automatic_first_down;
+        self
+    }
+
+    /// Set whether the penalty costs the offense the down
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::penalty::{Penalty, PenaltyTeam};
+    ///
+    /// let grounding = Penalty::new(PenaltyTeam::Offense, 10).loss_of_down(true);
+    /// assert!(grounding.loss_of_down);
+    /// ```
+    pub fn loss_of_down(mut self, loss_of_down: bool) -> Self {
+        self.loss_of_down = loss_of_down;
+        self
+    }
+
+    /// Get the net yards the penalty moves the offense from the line of
+    /// scrimmage. A penalty cannot move the ball more than half the distance
+    /// to the goal line of the team it penalizes.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::play::penalty::{Penalty, PenaltyTeam};
+    ///
+    /// let my_context = GameContextBuilder::new()
+    ///     .down(1)
+    ///     .distance(4)
+    ///     .yard_line(96)
+    ///     .next_play_kickoff(false)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // A 15 yard penalty at the 4 yard line moves the ball to the 2
+    /// let penalty = Penalty::new(PenaltyTeam::Defense, 15);
+    /// assert_eq!(penalty.enforced_yards(&my_context), 2);
+    /// ```
+    pub fn enforced_yards(&self, context: &GameContext) -> i32 {
+        let (yards_to_goal, sign) = match self.team {
+            PenaltyTeam::Defense => (context.yards_to_touchdown(), 1),
+            PenaltyTeam::Offense => (-context.yards_to_safety(), -1)
+        };
+        let yards = self.yards as i32;
+        if yards * 2 > yards_to_goal {
+            sign * (yards_to_goal / 2)
+        } else {
+            sign * yards
+        }
+    }
+
+    /// Get the outcome of the play if the penalty is enforced. The result of
+    /// the play is discarded, the clock runs for the duration of the play,
+    /// and the down is replayed unless the penalty awards an automatic first
+    /// down or a loss of down.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::{GameContext, PlayOutcome};
+    /// use fbsim_core::game::play::penalty::{Penalty, PenaltyTeam};
+    ///
+    /// let mut my_play = PlayOutcome::new();
+    /// my_play.net_yards = 40;
+    /// my_play.duration = 7;
+    ///
+    /// let penalty = Penalty::new(PenaltyTeam::Defense, 5);
+    /// let enforced = penalty.enforce(&GameContext::new(), &my_play);
+    /// assert_eq!(enforced.net_yards, 5);
+    /// assert_eq!(enforced.duration, 7);
+    /// assert!(enforced.replay_down);
+    /// ```
+    pub fn enforce(&self, context: &GameContext, play: &PlayOutcome) -> PlayOutcome {
+        let automatic_first_down = self.team == PenaltyTeam::Defense && self.automatic_first_down;
+        let loss_of_down = self.team == PenaltyTeam::Offense && self.loss_of_down;
+        PlayOutcome {
+            duration: play.duration,
+            net_yards: self.enforced_yards(context),
+            replay_down: !automatic_first_down && !loss_of_down,
+            automatic_first_down,
+            ..Default::default()
+        }
+    }
+}
+
+/// # `PenaltyDecision` enum
+///
+/// The decision made on the penalties of a play
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(PartialEq, Clone, Copy, Eq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub enum PenaltyDecision {
+    /// The offended team enforced the penalty
+    Accepted,
+    /// The offended team let the result of the play stand
+    #[default] Declined,
+    /// Both teams were penalized and the down is replayed
+    Offsetting
+}
+
+/// # `PenaltyAlternative` struct
+///
+/// One of the two outcomes the offended team chooses between, along with
+/// the game context it would produce
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct PenaltyAlternative {
+    pub outcome: PlayOutcome,
+    pub context: GameContext
+}
+
+impl PenaltyAlternative {
+    /// Derive an alternative by transitioning the game context at the snap
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::{GameContextBuilder, PlayOutcome};
+    /// use fbsim_core::game::play::penalty::PenaltyAlternative;
+    ///
+    /// let my_context = GameContextBuilder::new()
+    ///     .down(1)
+    ///     .distance(10)
+    ///     .yard_line(25)
+    ///     .next_play_kickoff(false)
+    ///     .build()
+    ///     .unwrap();
+    /// let mut my_play = PlayOutcome::new();
+    /// my_play.net_yards = 4;
+    ///
+    /// let alternative = PenaltyAlternative::from_outcome(&my_context, my_play).unwrap();
+    /// assert_eq!(alternative.context.down(), 2);
+    /// ```
+    pub fn from_outcome(context: &GameContext, outcome: PlayOutcome) -> Result<PenaltyAlternative, String> {
+        let next = context.transition(&outcome)?;
+        Ok(
+            PenaltyAlternative {
+                outcome,
+                context: next
+            }
+        )
+    }
+}
+
+/// # `PenaltyResolution` struct
+///
+/// A `PenaltyResolution` records the penalties on a play, the decision made
+/// on them, and both alternatives which were considered
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct PenaltyResolution {
+    penalties: Vec<Penalty>,
+    decision: PenaltyDecision,
+    declined: PenaltyAlternative,
+    accepted: PenaltyAlternative
+}
+
+impl PenaltyResolution {
+    /// Borrow the penalties called on the play
+    pub fn penalties(&self) -> &Vec<Penalty> {
+        &self.penalties
+    }
+
+    /// Get the decision made on the penalties
+    pub fn decision(&self) -> PenaltyDecision {
+        self.decision
+    }
+
+    /// Borrow the alternative in which the result of the play stands
+    pub fn declined(&self) -> &PenaltyAlternative {
+        &self.declined
+    }
+
+    /// Borrow the alternative in which the penalty is enforced, or the down
+    /// is replayed for offsetting penalties
+    pub fn accepted(&self) -> &PenaltyAlternative {
+        &self.accepted
+    }
+
+    /// Borrow the alternative which was chosen
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::{GameContextBuilder, PlayOutcome};
+    /// use fbsim_core::game::play::penalty::{resolve_penalties, Penalty, PenaltyTeam, ExpectedPointsPenaltyStrategy};
+    ///
+    /// let my_context = GameContextBuilder::new()
+    ///     .down(1)
+    ///     .distance(10)
+    ///     .yard_line(25)
+    ///     .next_play_kickoff(false)
+    ///     .build()
+    ///     .unwrap();
+    /// let penalties = vec![
+    ///     Penalty::new(PenaltyTeam::Offense, 10),
+    ///     Penalty::new(PenaltyTeam::Defense, 5)
+    /// ];
+    /// let resolution = resolve_penalties(
+    ///     &my_context, &PlayOutcome::new(), &penalties, &ExpectedPointsPenaltyStrategy
+    /// ).unwrap();
+    ///
+    /// // Offsetting penalties replay the down with no yardage
+    /// assert_eq!(resolution.chosen().context.down(), 1);
+    /// assert_eq!(resolution.chosen().context.yard_line(), 25);
+    /// ```
+    pub fn chosen(&self) -> &PenaltyAlternative {
+        match self.decision {
+            PenaltyDecision::Declined => &self.declined,
+            _ => &self.accepted
+        }
+    }
+
+    /// Get the effective outcome of the play
+    pub fn effective(&self) -> &PlayOutcome {
+        &self.chosen().outcome
+    }
+}
+
+/// # `PenaltyStrategy` trait
+///
+/// A `PenaltyStrategy` decides whether the offended team accepts a penalty.
+/// Implement it to override the default heuristic.
+pub trait PenaltyStrategy {
+    /// Return whether the offended team accepts the penalty given the game
+    /// context at the snap and both alternatives
+    fn accept(&self, context: &GameContext, penalty: &Penalty, declined: &PenaltyAlternative, accepted: &PenaltyAlternative) -> bool;
+}
+
+/// Estimate the expected points of a game context for the given team,
+/// positive when the team is expected to score next. The estimate is based on
+/// the field position of the team in possession along with the down and
+/// distance.
+///
+/// ### Example
+/// ```
+/// use fbsim_core::game::context::GameContextBuilder;
+/// use fbsim_core::game::play::penalty::expected_points;
+///
+/// let first_and_ten = GameContextBuilder::new()
+///     .down(1)
+///     .distance(10)
+///     .yard_line(25)
+///     .next_play_kickoff(false)
+///     .build()
+///     .unwrap();
+/// let third_and_ten = GameContextBuilder::new()
+///     .down(3)
+///     .distance(10)
+///     .yard_line(25)
+///     .next_play_kickoff(false)
+///     .build()
+///     .unwrap();
+/// let home = first_and_ten.home_possession();
+/// assert!(expected_points(&first_and_ten, home) > expected_points(&third_and_ten, home));
+/// assert!(expected_points(&first_and_ten, !home) < 0.0);
+/// ```
+pub fn expected_points(context: &GameContext, home: bool) -> f64 {
+    let sign = if context.home_possession() == home { 1.0 } else { -1.0 };
+    if context.next_play_extra_point() {
+        return sign * 0.95;
+    }
+    if context.next_play_kickoff() || context.down() == 0 {
+        return 0.0;
+    }
+    let ep = 6.0 -
+        0.07 * context.yards_to_touchdown() as f64 -
+        0.45 * (context.down() as f64 - 1.0) -
+        0.05 * context.distance() as f64;
+    sign * ep
+}
+
+/// # `ExpectedPointsPenaltyStrategy` struct
+///
+/// The default `PenaltyStrategy`. The offended team compares the two
+/// alternatives in order by
+/// 1. Points scored for it minus points scored against it
+/// 2. Whether it has possession afterward
+/// 3. For the offense, whether it earns a new set of downs, and for the
+///    defense, whether it prevents one
+/// 4. Its expected points afterward
+///
+/// and accepts the penalty only if the enforced alternative is strictly
+/// better, so ties let the play stand.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ExpectedPointsPenaltyStrategy;
+
+impl ExpectedPointsPenaltyStrategy {
+    /// Compare an alternative for the offended team by the documented keys
+    fn value(context: &GameContext, alternative: &PenaltyAlternative, offended_home: bool, offense_offended: bool) -> (i64, bool, bool, f64) {
+        let next = &alternative.context;
+        let (for_points, against_points) = if offended_home {
+            (next.home_score() as i64 - context.home_score() as i64, next.away_score() as i64 - context.away_score() as i64)
+        } else {
+            (next.away_score() as i64 - context.away_score() as i64, next.home_score() as i64 - context.home_score() as i64)
+        };
+        let outcome = &alternative.outcome;
+        let first_down = !outcome.turnover &&
+            (outcome.automatic_first_down || outcome.net_yards >= context.distance() as i32);
+        (
+            for_points - against_points,
+            next.home_possession() == offended_home,
+            first_down == offense_offended,
+            expected_points(next, offended_home)
+        )
+    }
+}
+
+impl PenaltyStrategy for ExpectedPointsPenaltyStrategy {
+    /// Accept the penalty if the enforced alternative is strictly better for
+    /// the offended team
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::{GameContextBuilder, PlayOutcome};
+    /// use fbsim_core::game::play::penalty::{Penalty, PenaltyTeam, PenaltyAlternative, PenaltyStrategy, ExpectedPointsPenaltyStrategy};
+    ///
+    /// let my_context = GameContextBuilder::new()
+    ///     .down(1)
+    ///     .distance(10)
+    ///     .yard_line(25)
+    ///     .next_play_kickoff(false)
+    ///     .build()
+    ///     .unwrap();
+    /// let mut incompletion = PlayOutcome::new();
+    /// incompletion.incomplete = true;
+    ///
+    /// let penalty = Penalty::new(PenaltyTeam::Defense, 5);
+    /// let declined = PenaltyAlternative::from_outcome(&my_context, incompletion.clone()).unwrap();
+    /// let accepted = PenaltyAlternative::from_outcome(&my_context, penalty.enforce(&my_context, &incompletion)).unwrap();
+    /// assert!(ExpectedPointsPenaltyStrategy.accept(&my_context, &penalty, &declined, &accepted));
+    /// ```
+    fn accept(&self, context: &GameContext, penalty: &Penalty, declined: &PenaltyAlternative, accepted: &PenaltyAlternative) -> bool {
+        let offense_offended = penalty.team == PenaltyTeam::Defense;
+        let offended_home = context.home_possession() == offense_offended;
+        let declined_value = Self::value(context, declined, offended_home, offense_offended);
+        let accepted_value = Self::value(context, accepted, offended_home, offense_offended);
+        accepted_value > declined_value
+    }
+}
+
+/// Resolve the penalties called on a scrimmage play, producing the effective
+/// outcome of the play. If both teams were penalized, the penalties offset
+/// and the down is replayed with no yardage. Otherwise the offended team
+/// considers the largest enforceable penalty against the result of the play
+/// and the strategy decides whether it is accepted.
+///
+/// ### Example
+/// ```
+/// use fbsim_core::game::context::{GameContextBuilder, PlayOutcome};
+/// use fbsim_core::game::play::penalty::{resolve_penalties, Penalty, PenaltyTeam, PenaltyDecision, ExpectedPointsPenaltyStrategy};
+///
+/// let my_context = GameContextBuilder::new()
+///     .down(1)
+///     .distance(10)
+///     .yard_line(25)
+///     .next_play_kickoff(false)
+///     .build()
+///     .unwrap();
+/// let mut completion = PlayOutcome::new();
+/// completion.net_yards = 40;
+/// completion.duration = 7;
+///
+/// // Defensive offside on a 40 yard completion is declined
+/// let penalties = vec![Penalty::new(PenaltyTeam::Defense, 5)];
+/// let resolution = resolve_penalties(
+///     &my_context, &completion, &penalties, &ExpectedPointsPenaltyStrategy
+/// ).unwrap();
+/// assert_eq!(resolution.decision(), PenaltyDecision::Declined);
+/// assert_eq!(resolution.effective().net_yards, 40);
+/// ```
+pub fn resolve_penalties(context: &GameContext, play: &PlayOutcome, penalties: &[Penalty], strategy: &impl PenaltyStrategy) -> Result<PenaltyResolution, String> {
+    if penalties.is_empty() {
+        return Err(String::from("Cannot resolve penalties: No penalties given"));
+    }
+    if context.next_play_kickoff() || context.next_play_extra_point() {
+        return Err(String::from("Cannot resolve penalties: Penalties are only supported on scrimmage plays"));
+    }
+    let declined = PenaltyAlternative::from_outcome(context, play.clone())?;
+
+    // Offsetting penalties replay the down with no yardage
+    let offense = penalties.iter().any(|p| p.team == PenaltyTeam::Offense);
+    let defense = penalties.iter().any(|p| p.team == PenaltyTeam::Defense);
+    if offense && defense {
+        let replay = PlayOutcome {
+            duration: play.duration,
+            replay_down: true,
+            ..Default::default()
+        };
+        return Ok(
+            PenaltyResolution {
+                penalties: penalties.to_vec(),
+                decision: PenaltyDecision::Offsetting,
+                declined,
+                accepted: PenaltyAlternative::from_outcome(context, replay)?
+            }
+        );
+    }
+
+    // Otherwise weigh the largest enforceable penalty against the play
+    let penalty = penalties.iter()
+        .max_by_key(|p| p.enforced_yards(context).abs())
+        .unwrap();
+    let accepted = PenaltyAlternative::from_outcome(context, penalty.enforce(context, play))?;
+    let decision = if strategy.accept(context, penalty, &declined, &accepted) {
+        PenaltyDecision::Accepted
+    } else {
+        PenaltyDecision::Declined
+    };
+    Ok(
+        PenaltyResolution {
+            penalties: penalties.to_vec(),
+            decision,
+            declined,
+            accepted
+        }
+    )
+}
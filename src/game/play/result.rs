@@ -16,6 +16,7 @@ pub mod run;
 
 use rand::Rng;
 
+use crate::game::error::GameError;
 use crate::game::context::GameContext;
 use crate::game::play::PlaySimulatable;
 use crate::game::play::result::betweenplay::BetweenPlayResult;
@@ -30,7 +31,7 @@ use crate::game::play::result::run::RunResult;
 /// The `PlayResult` trait defines the necessary methods in order to
 /// update a `GameContext` after a play is complete
 pub trait PlayResult {
-    fn next_context(&self, context: &GameContext) -> GameContext where Self: Sized { context.next_context(self) }
+    fn next_context(&self, context: &GameContext) -> Result<GameContext, GameError> where Self: Sized { context.next_context(self) }
     fn play_duration(&self) -> u32 { 0 }
     fn net_yards(&self) -> i32 { 0 }
     fn turnover(&self) -> bool { false }
@@ -96,7 +97,7 @@ impl std::fmt::Display for PlayTypeResult {
 }
 
 impl PlayResult for PlayTypeResult {
-    fn next_context(&self, context: &GameContext) -> GameContext where Self: Sized {
+    fn next_context(&self, context: &GameContext) -> Result<GameContext, GameError> where Self: Sized {
         match self {
             PlayTypeResult::BetweenPlay(res) => res.next_context(context),
             PlayTypeResult::Run(res) => res.next_context(context),
@@ -314,11 +315,245 @@ impl PlayResult for PlayTypeResult {
 /// `GameContext` after a play is simulated.
 pub trait PlayResultSimulator {
     fn sim(&self, offense: &impl PlaySimulatable, defense: &impl PlaySimulatable, context: &GameContext, rng: &mut impl Rng) -> PlayTypeResult;
+
+    /// Simulate the same play `n` times from one context and summarize the
+    /// distribution of its results
+    ///
+    /// ### Example
+    /// ```
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::play::result::PlayResultSimulator;
+    /// use fbsim_core::game::play::result::punt::PuntResultSimulator;
+    ///
+    /// // Punt on 4th & 10 from the offense's own 30 yard line
+    /// let my_context = GameContextBuilder::new()
+    ///     .down(4)
+    ///     .distance(10)
+    ///     .yard_line(30)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Sample 10k punts
+    /// let my_off = FootballTeam::new();
+    /// let my_def = FootballTeam::new();
+    /// let mut rng = SmallRng::seed_from_u64(0);
+    /// let my_sample = PuntResultSimulator::new().distribution_sample(10000, &my_off, &my_def, &my_context, &mut rng);
+    /// assert!(my_sample.plays() == 10000);
+    /// assert!(my_sample.mean_net_yards() > 0_f64);
+    /// assert!(my_sample.min_net_yards() <= my_sample.max_net_yards());
+    /// ```
+    fn distribution_sample(&self, n: usize, offense: &impl PlaySimulatable, defense: &impl PlaySimulatable, context: &GameContext, rng: &mut impl Rng) -> PlayResultSample where Self: Sized {
+        let results: Vec<PlayTypeResult> = (0..n).map(
+            |_| self.sim(offense, defense, context, rng)
+        ).collect();
+        PlayResultSample::new(&results)
+    }
+}
+
+/// # `PlayResultSample` struct
+///
+/// A `PlayResultSample` summarizes the distribution of a collection of play
+/// results, typically produced by `PlayResultSimulator::distribution_sample`
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlayResultSample {
+    plays: usize,
+    mean_net_yards: f64,
+    std_net_yards: f64,
+    min_net_yards: i32,
+    max_net_yards: i32,
+    mean_play_duration: f64,
+    turnover_rate: f64,
+    offense_score_rate: f64,
+    defense_score_rate: f64,
+    touchback_rate: f64
+}
+
+impl PlayResultSample {
+    /// Summarize a collection of play results.  An empty collection produces
+    /// a sample whose statistics are all zero.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::{PlayResultSample, PlayTypeResult};
+    /// use fbsim_core::game::play::result::run::RunResultBuilder;
+    ///
+    /// let my_results = vec![
+    ///     PlayTypeResult::Run(RunResultBuilder::new().yards_gained(2).build().unwrap()),
+    ///     PlayTypeResult::Run(RunResultBuilder::new().yards_gained(6).build().unwrap())
+    /// ];
+    /// let my_sample = PlayResultSample::new(&my_results);
+    /// assert!(my_sample.plays() == 2);
+    /// assert!(my_sample.mean_net_yards() == 4_f64);
+    /// assert!(my_sample.std_net_yards() == 2_f64);
+    /// ```
+    pub fn new(results: &[PlayTypeResult]) -> PlayResultSample {
+        if results.is_empty() {
+            return PlayResultSample::default();
+        }
+        let n = results.len() as f64;
+        let rate = |f: &dyn Fn(&PlayTypeResult) -> bool| {
+            results.iter().filter(|r| f(r)).count() as f64 / n
+        };
+        let net_yards: Vec<i32> = results.iter().map(|r| r.net_yards()).collect();
+        let mean_net_yards = net_yards.iter().map(|y| *y as f64).sum::<f64>() / n;
+        let var_net_yards = net_yards.iter().map(
+            |y| (*y as f64 - mean_net_yards).powi(2)
+        ).sum::<f64>() / n;
+        PlayResultSample{
+            plays: results.len(),
+            mean_net_yards,
+            std_net_yards: var_net_yards.sqrt(),
+            min_net_yards: net_yards.iter().copied().min().unwrap_or_default(),
+            max_net_yards: net_yards.iter().copied().max().unwrap_or_default(),
+            mean_play_duration: results.iter().map(|r| r.play_duration() as f64).sum::<f64>() / n,
+            turnover_rate: rate(&|r| r.turnover()),
+            offense_score_rate: rate(&|r| r.offense_score() != ScoreResult::None),
+            defense_score_rate: rate(&|r| r.defense_score() != ScoreResult::None),
+            touchback_rate: rate(&|r| r.touchback())
+        }
+    }
+
+    /// Get the number of plays in the sample
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::PlayResultSample;
+    ///
+    /// let my_sample = PlayResultSample::new(&[]);
+    /// assert!(my_sample.plays() == 0);
+    /// ```
+    pub fn plays(&self) -> usize {
+        self.plays
+    }
+
+    /// Get the mean net yards across the sample
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::PlayResultSample;
+    ///
+    /// let my_sample = PlayResultSample::new(&[]);
+    /// assert!(my_sample.mean_net_yards() == 0_f64);
+    /// ```
+    pub fn mean_net_yards(&self) -> f64 {
+        self.mean_net_yards
+    }
+
+    /// Get the (population) standard deviation of net yards across the sample
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::PlayResultSample;
+    ///
+    /// let my_sample = PlayResultSample::new(&[]);
+    /// assert!(my_sample.std_net_yards() == 0_f64);
+    /// ```
+    pub fn std_net_yards(&self) -> f64 {
+        self.std_net_yards
+    }
+
+    /// Get the fewest net yards of any play in the sample
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::PlayResultSample;
+    ///
+    /// let my_sample = PlayResultSample::new(&[]);
+    /// assert!(my_sample.min_net_yards() == 0);
+    /// ```
+    pub fn min_net_yards(&self) -> i32 {
+        self.min_net_yards
+    }
+
+    /// Get the most net yards of any play in the sample
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::PlayResultSample;
+    ///
+    /// let my_sample = PlayResultSample::new(&[]);
+    /// assert!(my_sample.max_net_yards() == 0);
+    /// ```
+    pub fn max_net_yards(&self) -> i32 {
+        self.max_net_yards
+    }
+
+    /// Get the mean play duration in seconds across the sample
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::PlayResultSample;
+    ///
+    /// let my_sample = PlayResultSample::new(&[]);
+    /// assert!(my_sample.mean_play_duration() == 0_f64);
+    /// ```
+    pub fn mean_play_duration(&self) -> f64 {
+        self.mean_play_duration
+    }
+
+    /// Get the fraction of plays in the sample which resulted in a turnover
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::PlayResultSample;
+    ///
+    /// let my_sample = PlayResultSample::new(&[]);
+    /// assert!(my_sample.turnover_rate() == 0_f64);
+    /// ```
+    pub fn turnover_rate(&self) -> f64 {
+        self.turnover_rate
+    }
+
+    /// Get the fraction of plays in the sample on which the offense scored
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::PlayResultSample;
+    ///
+    /// let my_sample = PlayResultSample::new(&[]);
+    /// assert!(my_sample.offense_score_rate() == 0_f64);
+    /// ```
+    pub fn offense_score_rate(&self) -> f64 {
+        self.offense_score_rate
+    }
+
+    /// Get the fraction of plays in the sample on which the defense scored
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::PlayResultSample;
+    ///
+    /// let my_sample = PlayResultSample::new(&[]);
+    /// assert!(my_sample.defense_score_rate() == 0_f64);
+    /// ```
+    pub fn defense_score_rate(&self) -> f64 {
+        self.defense_score_rate
+    }
+
+    /// Get the fraction of plays in the sample which resulted in a touchback
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::PlayResultSample;
+    ///
+    /// let my_sample = PlayResultSample::new(&[]);
+    /// assert!(my_sample.touchback_rate() == 0_f64);
+    /// ```
+    pub fn touchback_rate(&self) -> f64 {
+        self.touchback_rate
+    }
 }
 
 /// `ScoreResult` enum
 ///
 /// Enumerates the various ways a team can score points in football
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
 #[cfg_attr(feature = "wasm", derive(Tsify))]
 #[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
 #[derive(PartialEq, Clone, Copy, Eq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
@@ -352,3 +587,229 @@ impl ScoreResult {
         }
     }
 }
+
+/// `TwoPointAttemptResult` enum
+///
+/// Enumerates the outcomes of a two-point conversion attempt
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(PartialEq, Clone, Copy, Eq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum TwoPointAttemptResult {
+    /// The offense converted for two points
+    Good,
+    /// The attempt failed
+    NoGood,
+    /// The defense took the ball away and returned it for two points
+    DefensiveReturn
+}
+
+impl PlayTypeResult {
+    /// Get the outcome of a run or pass which was a two-point conversion
+    /// attempt, or `None` for any other play
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::{PlayTypeResult, TwoPointAttemptResult};
+    /// use fbsim_core::game::play::result::pass::PassResultBuilder;
+    ///
+    /// let my_result = PassResultBuilder::new()
+    ///     .interception(true)
+    ///     .return_yards(100)
+    ///     .touchdown(true)
+    ///     .two_point_conversion(true)
+    ///     .build()
+    ///     .unwrap();
+    /// let my_play = PlayTypeResult::Pass(my_result);
+    /// assert_eq!(my_play.two_point_attempt(), Some(TwoPointAttemptResult::DefensiveReturn));
+    /// ```
+    pub fn two_point_attempt(&self) -> Option<TwoPointAttemptResult> {
+        let attempt = match self {
+            PlayTypeResult::Run(res) => res.two_point_conversion(),
+            PlayTypeResult::Pass(res) => res.two_point_conversion(),
+            _ => false
+        };
+        if !attempt {
+            return None;
+        }
+        if self.offense_score() == ScoreResult::TwoPointConversion {
+            Some(TwoPointAttemptResult::Good)
+        } else if self.defense_score() == ScoreResult::TwoPointConversion {
+            Some(TwoPointAttemptResult::DefensiveReturn)
+        } else {
+            Some(TwoPointAttemptResult::NoGood)
+        }
+    }
+}
+
+/// `ReturnDecision` enum
+///
+/// Enumerates the decisions a returner can make when receiving a kickoff or
+/// a punt
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(PartialEq, Clone, Copy, Eq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub enum ReturnDecision {
+    /// The returner fielded the kick and returned it
+    #[default] Return,
+    /// The returner called for a fair catch
+    FairCatch,
+    /// The returner downed the kick in the end zone
+    Touchback,
+    /// The returner let the kick bounce without fielding it
+    LetBounce
+}
+
+impl ReturnDecision {
+    /// Infer the return decision from the outcome flags of a kick, used when
+    /// deserializing results recorded before the decision was tracked
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::ReturnDecision;
+    ///
+    /// let my_decision = ReturnDecision::from_flags(false, false, true, false);
+    /// assert!(my_decision == ReturnDecision::FairCatch);
+    /// ```
+    pub fn from_flags(touchback: bool, out_of_bounds: bool, fair_catch: bool, blocked: bool) -> ReturnDecision {
+        if touchback {
+            ReturnDecision::Touchback
+        } else if fair_catch {
+            ReturnDecision::FairCatch
+        } else if out_of_bounds || blocked {
+            ReturnDecision::LetBounce
+        } else {
+            ReturnDecision::Return
+        }
+    }
+
+    /// Describe a spot on the field measured in yards from the receiving
+    /// team's goal line, where negative values are in the end zone
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::ReturnDecision;
+    ///
+    /// assert!(ReturnDecision::spot_str(-3) == "3 yards deep in the end zone");
+    /// assert!(ReturnDecision::spot_str(20) == "at the receiving 20");
+    /// assert!(ReturnDecision::spot_str(60) == "at the kicking 40");
+    /// ```
+    pub fn spot_str(spot: i32) -> String {
+        match spot {
+            s if s < -1 => format!("{} yards deep in the end zone", -s),
+            -1 => String::from("1 yard deep in the end zone"),
+            0 => String::from("at the goal line"),
+            50 => String::from("at midfield"),
+            s if s < 50 => format!("at the receiving {}", s),
+            s if s < 100 => format!("at the kicking {}", 100 - s),
+            _ => String::from("at the kicking goal line")
+        }
+    }
+}
+
+impl std::fmt::Display for ReturnDecision {
+    /// Format a `ReturnDecision` as a string.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use fbsim_core::game::play::result::ReturnDecision;
+    ///
+    /// let my_decision = ReturnDecision::FairCatch;
+    /// assert!(my_decision.to_string() == "Fair catch");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let decision_str = match self {
+            ReturnDecision::Return => "Caught",
+            ReturnDecision::FairCatch => "Fair catch",
+            ReturnDecision::Touchback => "Downed",
+            ReturnDecision::LetBounce => "Bounced"
+        };
+        f.write_str(decision_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use crate::game::context::GameContextBuilder;
+    use crate::game::play::result::fieldgoal::FieldGoalResultSimulator;
+    use crate::game::play::result::kickoff::KickoffResultSimulator;
+    use crate::game::play::result::pass::PassResultSimulator;
+    use crate::game::play::result::punt::PuntResultSimulator;
+    use crate::game::play::result::run::RunResultSimulator;
+    use crate::team::FootballTeam;
+
+    #[test]
+    fn test_distribution_sample_per_play_type() {
+        let offense = FootballTeam::new();
+        let defense = FootballTeam::new();
+        let mut rng = SmallRng::seed_from_u64(1494);
+
+        // 4th & 10 from the offense's own 30 yard line
+        let own_30 = GameContextBuilder::new().down(4).distance(10).yard_line(30).build().unwrap();
+        assert_eq!(own_30.yards_to_touchdown(), 70);
+
+        // Punts change possession and travel roughly 30 to 50 net yards
+        let punts = PuntResultSimulator::new().distribution_sample(2000, &offense, &defense, &own_30, &mut rng);
+        assert_eq!(punts.plays(), 2000);
+        assert!(punts.turnover_rate() > 0.9);
+        assert!(punts.mean_net_yards() > 30_f64 && punts.mean_net_yards() < 50_f64);
+
+        // Runs and passes gain a few yards on average, and rarely turn it over
+        let runs = RunResultSimulator::new().distribution_sample(2000, &offense, &defense, &own_30, &mut rng);
+        assert!(runs.mean_net_yards() > 0_f64 && runs.mean_net_yards() < 10_f64);
+        assert!(runs.turnover_rate() < 0.05);
+        let passes = PassResultSimulator::new().distribution_sample(2000, &offense, &defense, &own_30, &mut rng);
+        assert!(passes.mean_net_yards() > 0_f64 && passes.mean_net_yards() < 15_f64);
+        assert!(passes.min_net_yards() < 0);
+
+        // Kickoffs are touchbacks a meaningful fraction of the time
+        let kickoffs = KickoffResultSimulator::new().distribution_sample(2000, &offense, &defense, &own_30, &mut rng);
+        assert!(kickoffs.touchback_rate() > 0.25 && kickoffs.touchback_rate() < 0.9);
+
+        // Short field goals are made far more often than long ones
+        let opp_20 = GameContextBuilder::new().down(4).distance(10).yard_line(80).build().unwrap();
+        assert_eq!(opp_20.yards_to_touchdown(), 20);
+        let short_fgs = FieldGoalResultSimulator::new().distribution_sample(2000, &offense, &defense, &opp_20, &mut rng);
+        let long_fgs = FieldGoalResultSimulator::new().distribution_sample(2000, &offense, &defense, &own_30, &mut rng);
+        assert!(short_fgs.offense_score_rate() > 0.8);
+        assert!(short_fgs.offense_score_rate() > long_fgs.offense_score_rate());
+    }
+
+    #[test]
+    fn test_punts_backed_up_to_own_goal_line() {
+        let offense = FootballTeam::new();
+        let defense = FootballTeam::new();
+        let mut rng = SmallRng::seed_from_u64(1532);
+
+        // 4th & 10 from the offense's own 3 yard line, where a blocked punt
+        // recovered behind the line may end in the end zone
+        let own_3 = GameContextBuilder::new().down(4).distance(10).yard_line(3).build().unwrap();
+        let sim = PuntResultSimulator::new();
+        let mut safeties = 0;
+        for _ in 0..4000 {
+            let res = match sim.sim(&offense, &defense, &own_3, &mut rng) {
+                PlayTypeResult::Punt(res) => res,
+                _ => unreachable!()
+            };
+
+            // Safeties are only conceded on blocked punts, after which the
+            // kicking team keeps possession to free kick
+            if res.safety() {
+                safeties += 1;
+                assert!(res.blocked());
+                assert_eq!(res.defense_score(), ScoreResult::Safety);
+                assert!(!res.turnover());
+                assert!(res.next_play_kickoff());
+            }
+
+            // Every punt produces a valid next context
+            assert!(res.next_context(&own_3).is_ok());
+        }
+        assert!(safeties > 0);
+    }
+}
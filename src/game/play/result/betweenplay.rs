@@ -9,9 +9,11 @@ use serde::{Deserialize, Deserializer, Serialize};
 use tsify_next::Tsify;
 use rand_distr::{SkewNormal, Normal, Distribution};
 
+use crate::game::error::GameError;
 use crate::game::context::{GameContext, GameContextBuilder, GameContextUpdateOptions};
 use crate::game::play::PlaySimulatable;
 use crate::game::play::context::PlayContext;
+use crate::game::play::decision::{Decision, DecisionChoice, DecisionKind};
 use crate::game::play::result::{PlayResult, PlayTypeResult, PlayResultSimulator};
 
 // Up-tempo probability regression
@@ -134,6 +136,10 @@ impl Default for BetweenPlayResult {
     /// use fbsim_core::game::play::result::betweenplay::BetweenPlayResult;
     /// 
     /// let my_result = BetweenPlayResult::default();
+    ///
+    /// // The default passes validation
+    /// let json = serde_json::to_string(&my_result).unwrap();
+    /// assert!(serde_json::from_str::<BetweenPlayResult>(&json).is_ok());
     /// ```
     fn default() -> Self {
         BetweenPlayResult{
@@ -183,9 +189,11 @@ impl std::fmt::Display for BetweenPlayResult {
 }
 
 impl PlayResult for BetweenPlayResult {
-    fn next_context(&self, context: &GameContext) -> GameContext {
-        if context.next_play_extra_point() {
-            return context.clone();
+    fn next_context(&self, context: &GameContext) -> Result<GameContext, GameError> {
+        // Nothing happens between plays before an extra point, or after a
+        // game decided in sudden death overtime
+        if context.next_play_extra_point() || (context.game_over() && context.half_seconds() > 0) {
+            return Ok(context.clone());
         }
         let default_update_opts = GameContextUpdateOptions{
             between_play: true,
@@ -254,8 +262,13 @@ impl PlayResult for BetweenPlayResult {
             .next_play_kickoff(context.next_play_kickoff() || (end_of_half && !next_play_extra_point))
             .end_of_half(end_of_half)
             .game_over(context.next_game_over(&between_update_opts))
+            .overtime_rules(*context.overtime_rules())
+            .clock_rules(*context.clock_rules())
+            .weather(context.weather())
+            .home_overtime_possession(context.home_overtime_possession())
+            .away_overtime_possession(context.away_overtime_possession())
             .build()
-            .unwrap()
+            .map_err(GameError::from)
     }
 }
 
@@ -570,15 +583,23 @@ impl BetweenPlayResultSimulator {
         rng.gen::<f64>() < p_not_set
     }
 
+    /// Computes the probability the defense calls timeout to get set, or
+    /// `None` if the defense cannot call one
+    fn defense_get_set_timeout_probability(&self, context: &PlayContext, norm_risk_taking: f64) -> Option<f64> {
+        if (context.defense_timeouts() == 0) || context.clock_rules().second_half(context.quarter()) {
+            return None;
+        }
+        Some(1_f64.min(0_f64.max(
+            P_GET_SET_TIMEOUT_INTR + (P_GET_SET_TIMEOUT_COEF * norm_risk_taking)
+        )))
+    }
+
     /// Generates whether the defense calls timeout due to the defense not being set
     fn defense_get_set_timeout(&self, context: &PlayContext, norm_risk_taking: f64, rng: &mut impl Rng) -> bool {
-        if (context.defense_timeouts() == 0) || (context.quarter() > 2) {
-            return false;
+        match self.defense_get_set_timeout_probability(context, norm_risk_taking) {
+            Some(p_timeout) => rng.gen::<f64>() < p_timeout,
+            None => false
         }
-        let p_timeout: f64 = 1_f64.min(0_f64.max(
-            P_GET_SET_TIMEOUT_INTR + (P_GET_SET_TIMEOUT_COEF * norm_risk_taking)
-        ));
-        rng.gen::<f64>() < p_timeout
     }
 
     /// Generates whether the offense calls timeout to conserve clock
@@ -609,7 +630,7 @@ impl BetweenPlayResultSimulator {
 
     /// Generates the clock seconds which pass in-between plays
     fn duration(&self, context: &PlayContext, up_tempo: bool, rng: &mut impl Rng) -> u32 {
-        if context.drain_clock() {
+        if context.drain_clock() || context.victory_formation() {
             return 40;
         }
         let duration = if up_tempo {
@@ -619,16 +640,14 @@ impl BetweenPlayResultSimulator {
         };
         u32::try_from(duration as i32).unwrap_or_default()
     }
-}
 
-impl PlayResultSimulator for BetweenPlayResultSimulator {
-    /// Simulate the events between plays
+    /// Simulate the events between plays, along with the timeout decisions
+    /// made
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::game::context::GameContext;
-    /// use fbsim_core::game::play::result::PlayResultSimulator;
     /// use fbsim_core::game::play::result::betweenplay::BetweenPlayResultSimulator;
     ///
     /// // Initialize home & away teams
@@ -641,9 +660,9 @@ impl PlayResultSimulator for BetweenPlayResultSimulator {
     /// // Initialize a between-play simulator and simulate
     /// let my_sim = BetweenPlayResultSimulator::new();
     /// let mut rng = rand::thread_rng();
-    /// let my_res = my_sim.sim(&my_off, &my_def, &my_context, &mut rng);
+    /// let (my_res, decisions) = my_sim.sim_with_decisions(&my_off, &my_def, &my_context, &mut rng);
     /// ```
-    fn sim(&self, offense: &impl PlaySimulatable, defense: &impl PlaySimulatable, context: &GameContext, rng: &mut impl Rng) -> PlayTypeResult {
+    pub fn sim_with_decisions(&self, offense: &impl PlaySimulatable, defense: &impl PlaySimulatable, context: &GameContext, rng: &mut impl Rng) -> (PlayTypeResult, Vec<Decision>) {
         // Calculate normalized skill diffs, skill levels, context values
         let norm_defense_risk_taking: f64 = defense.coach().risk_taking() as f64 / 100_f64;
         let norm_offense_up_tempo: f64 = offense.coach().up_tempo() as f64 / 100_f64;
@@ -668,11 +687,31 @@ impl PlayResultSimulator for BetweenPlayResultSimulator {
         let critical_down: bool = play_context.critical_down();
 
         // Generate whether the defense calls timeout
+        let mut decisions: Vec<Decision> = Vec::new();
         let defense_timeout: bool = if !(last_play_turnover || last_play_kickoff) {
             if defense_not_set || critical_down {
-                self.defense_get_set_timeout(&play_context, norm_defense_risk_taking, rng)
+                let timeout = self.defense_get_set_timeout(&play_context, norm_defense_risk_taking, rng);
+                if let Some(p_timeout) = self.defense_get_set_timeout_probability(&play_context, norm_defense_risk_taking) {
+                    let chosen = if timeout { DecisionChoice::DefenseTimeout } else { DecisionChoice::NoTimeout };
+                    let reason = if defense_not_set { "Defense not set" } else { "Defense getting set for a critical down" };
+                    decisions.push(
+                        Decision::new(DecisionKind::Timeout, chosen, context)
+                            .option(DecisionChoice::DefenseTimeout, p_timeout)
+                            .option(DecisionChoice::NoTimeout, 1_f64 - p_timeout)
+                            .reason(reason)
+                    );
+                }
+                timeout
             } else {
-                self.defense_conserve_clock_timeout(&play_context)
+                let timeout = self.defense_conserve_clock_timeout(&play_context);
+                if timeout {
+                    decisions.push(
+                        Decision::new(DecisionKind::Timeout, DecisionChoice::DefenseTimeout, context)
+                            .option(DecisionChoice::DefenseTimeout, 1_f64)
+                            .reason("Defense conserving the clock")
+                    );
+                }
+                timeout
             }
         } else {
             false
@@ -684,6 +723,13 @@ impl PlayResultSimulator for BetweenPlayResultSimulator {
         } else {
             false
         };
+        if offense_timeout {
+            decisions.push(
+                Decision::new(DecisionKind::Timeout, DecisionChoice::OffenseTimeout, context)
+                    .option(DecisionChoice::OffenseTimeout, 1_f64)
+                    .reason("Offense conserving the clock")
+            );
+        }
 
         // Generate the between-play duration
         let between_play_duration: u32 = if !(offense_timeout || defense_timeout) && clock_running {
@@ -700,6 +746,35 @@ impl PlayResultSimulator for BetweenPlayResultSimulator {
             critical_down
         };
         let between_res = BetweenPlayResult::try_from(raw).unwrap();
-        PlayTypeResult::BetweenPlay(between_res)
+        (PlayTypeResult::BetweenPlay(between_res), decisions)
+    }
+}
+
+impl PlayResultSimulator for BetweenPlayResultSimulator {
+    /// Simulate the events between plays
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::game::context::GameContext;
+    /// use fbsim_core::game::play::result::PlayResultSimulator;
+    /// use fbsim_core::game::play::result::betweenplay::BetweenPlayResultSimulator;
+    ///
+    /// // Initialize home & away teams
+    /// let my_off = FootballTeam::new();
+    /// let my_def = FootballTeam::new();
+    ///
+    /// // Initialize a game context
+    /// let my_context = GameContext::new();
+    ///
+    /// // Initialize a between-play simulator and simulate
+    /// let my_sim = BetweenPlayResultSimulator::new();
+    /// let mut rng = rand::thread_rng();
+    /// let my_res = my_sim.sim(&my_off, &my_def, &my_context, &mut rng);
+    /// ```
+    fn sim(&self, offense: &impl PlaySimulatable, defense: &impl PlaySimulatable, context: &GameContext, rng: &mut impl Rng) -> PlayTypeResult {
+        let (result, _) = self.sim_with_decisions(offense, defense, context, rng);
+        result
     }
 }
+
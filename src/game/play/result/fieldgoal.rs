@@ -9,7 +9,9 @@ use serde::{Deserialize, Deserializer, Serialize};
 use tsify_next::Tsify;
 use rand_distr::{Distribution, Exp, SkewNormal};
 
+use crate::game::error::GameError;
 use crate::game::context::GameContext;
+use crate::game::context::weather::WeatherModifiers;
 use crate::game::play::PlaySimulatable;
 use crate::game::play::result::{PlayResult, PlayTypeResult, PlayResultSimulator, ScoreResult};
 
@@ -183,6 +185,10 @@ impl Default for FieldGoalResult {
     /// use fbsim_core::game::play::result::fieldgoal::FieldGoalResult;
     /// 
     /// let my_result = FieldGoalResult::default();
+    ///
+    /// // The default passes validation
+    /// let json = serde_json::to_string(&my_result).unwrap();
+    /// assert!(serde_json::from_str::<FieldGoalResult>(&json).is_ok());
     /// ```
     fn default() -> Self {
         FieldGoalResult{
@@ -244,7 +250,7 @@ impl std::fmt::Display for FieldGoalResult {
 }
 
 impl PlayResult for FieldGoalResult {
-    fn next_context(&self, context: &GameContext) -> GameContext {
+    fn next_context(&self, context: &GameContext) -> Result<GameContext, GameError> {
         context.next_context(self)
     }
 
@@ -662,7 +668,7 @@ impl FieldGoalResultSimulator {
     }
 
     /// Generate whether the field goal was made
-    fn made(&self, norm_kicking: f64, yard_line: i32, rng: &mut impl Rng) -> bool {
+    fn made(&self, norm_kicking: f64, yard_line: i32, modifiers: &WeatherModifiers, rng: &mut impl Rng) -> bool {
         let p_made_skill: f64 = P_FIELD_GOAL_MADE_SKILL_INTR + (P_FIELD_GOAL_MADE_SKILL_COEF * norm_kicking);
         let p_made_yardline: f64 = P_FIELD_GOAL_MADE_YARD_LINE_INTR + (P_FIELD_GOAL_MADE_YARD_LINE_COEF_1 * yard_line as f64) +
             (P_FIELD_GOAL_MADE_YARD_LINE_COEF_2 * yard_line.pow(2) as f64);
@@ -673,7 +679,7 @@ impl FieldGoalResultSimulator {
                 ).max(0.0001).ln() + 1.0
             )
         );
-        rng.gen::<f64>() < p_made
+        rng.gen::<f64>() < p_made * modifiers.field_goal
     }
 
     /// Generate the duration of the field goal play
@@ -735,7 +741,7 @@ impl PlayResultSimulator for FieldGoalResultSimulator {
 
         // Generate whether the field goal was made
         let made: bool = if !blocked {
-            self.made(norm_kicking, td_yards, rng)
+            self.made(norm_kicking, td_yards, &context.weather().modifiers(), rng)
         } else {
             false
         };
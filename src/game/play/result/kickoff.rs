@@ -9,10 +9,12 @@ use serde::{Deserialize, Deserializer, Serialize};
 use tsify_next::Tsify;
 use rand_distr::{Normal, Distribution, Exp, SkewNormal};
 
+use crate::game::error::GameError;
 use crate::game::context::GameContext;
+use crate::game::context::weather::WeatherModifiers;
 use crate::game::play::PlaySimulatable;
 use crate::game::play::context::PlayContext;
-use crate::game::play::result::{PlayResult, PlayTypeResult, PlayResultSimulator, ScoreResult};
+use crate::game::play::result::{PlayResult, PlayTypeResult, PlayResultSimulator, ReturnDecision, ScoreResult};
 
 // Touchback probability regression
 const P_TOUCHBACK_INTR: f64 = 0.2528877428268531_f64;
@@ -70,6 +72,14 @@ const P_KICKOFF_RETURN_FUMBLE: f64 = 0.007_f64;
 const KICKOFF_RETURN_PLAY_DURATION_INTR: f64 = 0.11217103_f64;
 const KICKOFF_RETURN_PLAY_DURATION_COEF: f64 = 1.20326252_f64;
 
+// Kickoff hang time regression (seconds)
+const KICKOFF_HANG_TIME_INTR: f64 = 1.5_f64;
+const KICKOFF_HANG_TIME_COEF: f64 = 0.04_f64;
+
+// Receiving team yard line after a touchback or kickoff out of bounds
+const KICKOFF_TOUCHBACK_YARD_LINE: u32 = 25;
+const KICKOFF_OOB_YARD_LINE: u32 = 35;
+
 /// # `KickoffResultRaw` struct
 ///
 /// A `KickoffResultRaw` represents a result of a kickoff
@@ -85,7 +95,17 @@ pub struct KickoffResultRaw {
     fair_catch: bool,
     fumble: bool,
     touchdown: bool,
-    onside_kick: bool
+    onside_kick: bool,
+    /// Yards from the receiving team's goal line where the kick was caught
+    /// or landed, negative in the end zone (default: 0)
+    #[serde(default)]
+    catch_spot: i32,
+    /// The returner's decision (default: inferred from the outcome flags)
+    #[serde(default)]
+    decision: Option<ReturnDecision>,
+    /// Hang time of the kick in tenths of a second (default: 0)
+    #[serde(default)]
+    hang_time: u32
 }
 
 impl KickoffResultRaw {
@@ -150,6 +170,69 @@ impl KickoffResultRaw {
                 )
             )
         }
+
+        // Ensure the catch spot is between the back of the end zone and the
+        // kicking team's goal line
+        if self.catch_spot < -10 || self.catch_spot > 100 {
+            return Err(
+                format!(
+                    "Catch spot is not in range [-10, 100]: {}",
+                    self.catch_spot
+                )
+            )
+        }
+
+        // Ensure hang time is no more than 10 seconds
+        if self.hang_time > 100 {
+            return Err(
+                format!(
+                    "Hang time is not in range [0, 100]: {}",
+                    self.hang_time
+                )
+            )
+        }
+
+        // Ensure a fair catch was not returned
+        if self.fair_catch && self.kick_return_yards != 0 {
+            return Err(
+                format!(
+                    "Fair catch was called but kick return yards were nonzero: {}",
+                    self.kick_return_yards
+                )
+            )
+        }
+
+        // Ensure a touchback was caught in the end zone
+        if self.touchback && self.catch_spot > 0 {
+            return Err(
+                format!(
+                    "Touchback but catch spot is not in the end zone: {}",
+                    self.catch_spot
+                )
+            )
+        }
+
+        // Ensure the return decision agrees with the outcome flags
+        if let Some(decision) = self.decision {
+            if (decision == ReturnDecision::Touchback) != self.touchback ||
+                (decision == ReturnDecision::FairCatch) != self.fair_catch ||
+                (decision == ReturnDecision::Return && self.out_of_bounds) {
+                return Err(
+                    format!(
+                        "Return decision {:?} is inconsistent with touchback ({}), out of bounds ({}), and fair catch ({})",
+                        decision, self.touchback, self.out_of_bounds, self.fair_catch
+                    )
+                )
+            }
+            if decision == ReturnDecision::LetBounce && self.kick_return_yards != 0 {
+                return Err(
+                    format!(
+                        "Kick was allowed to bounce but kick return yards were nonzero: {}",
+                        self.kick_return_yards
+                    )
+                )
+            }
+        }
         Ok(())
     }
 }
@@ -171,7 +254,10 @@ pub struct KickoffResult {
     fair_catch: bool,
     fumble: bool,
     touchdown: bool,
-    onside_kick: bool
+    onside_kick: bool,
+    catch_spot: i32,
+    decision: ReturnDecision,
+    hang_time: u32
 }
 
 impl TryFrom<KickoffResultRaw> for KickoffResult {
@@ -196,7 +282,12 @@ impl TryFrom<KickoffResultRaw> for KickoffResult {
                 fair_catch: item.fair_catch,
                 fumble: item.fumble,
                 touchdown: item.touchdown,
-                onside_kick: item.onside_kick
+                onside_kick: item.onside_kick,
+                catch_spot: item.catch_spot,
+                decision: item.decision.unwrap_or(
+                    ReturnDecision::from_flags(item.touchback, item.out_of_bounds, item.fair_catch, false)
+                ),
+                hang_time: item.hang_time
             }
         )
     }
@@ -221,6 +312,10 @@ impl Default for KickoffResult {
     /// use fbsim_core::game::play::result::kickoff::KickoffResult;
     /// 
     /// let my_result = KickoffResult::default();
+    ///
+    /// // The default passes validation
+    /// let json = serde_json::to_string(&my_result).unwrap();
+    /// assert!(serde_json::from_str::<KickoffResult>(&json).is_ok());
     /// ```
     fn default() -> Self {
         KickoffResult{
@@ -233,7 +328,10 @@ impl Default for KickoffResult {
             fair_catch: false,
             fumble: false,
             touchdown: false,
-            onside_kick: false
+            onside_kick: false,
+            catch_spot: 0,
+            decision: ReturnDecision::Touchback,
+            hang_time: 41
         }
     }
 }
@@ -244,10 +342,26 @@ impl std::fmt::Display for KickoffResult {
     /// ### Example
     ///
     /// ```
-    /// use fbsim_core::game::play::result::kickoff::KickoffResult;
+    /// use fbsim_core::game::play::result::kickoff::{KickoffResult, KickoffResultBuilder};
     /// 
     /// let my_result = KickoffResult::default();
-    /// println!("{}", my_result);
+    /// assert!(
+    ///     my_result.to_string() == "Kickoff 65 yards for a touchback. Downed at the goal \
+    ///         line with 4.1s of hang time. Ball spotted at the receiving 25."
+    /// );
+    ///
+    /// // A kickoff returned out of the end zone
+    /// let my_result = KickoffResultBuilder::new()
+    ///     .touchback(false)
+    ///     .catch_spot(-2)
+    ///     .kick_return_yards(27)
+    ///     .hang_time(42)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(
+    ///     my_result.to_string() == "Kickoff 65 yards fielded. Caught 2 yards deep in the \
+    ///         end zone with 4.2s of hang time. Returned 27 yards. Ball spotted at the receiving 25."
+    /// );
     /// ```
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let distance_str = if self.onside_kick {
@@ -266,6 +380,16 @@ impl std::fmt::Display for KickoffResult {
         } else {
             " fielded."
         };
+        let decision_str = if !self.onside_kick {
+            format!(
+                " {} {} with {:.1}s of hang time.",
+                self.decision,
+                ReturnDecision::spot_str(self.catch_spot),
+                self.hang_time as f64 / 10_f64
+            )
+        } else {
+            String::from("")
+        };
         let kick_return_str = if !(
             self.touchback || self.out_of_bounds ||
                 (self.fair_catch && !self.fumble) ||
@@ -287,20 +411,27 @@ impl std::fmt::Display for KickoffResult {
         } else {
             ""
         };
+        let start_str = if !self.touchdown {
+            format!(" Ball spotted {}.", ReturnDecision::spot_str(self.start_yard_line() as i32))
+        } else {
+            String::from("")
+        };
         let kickoff_str = format!(
-            "{}{}{}{}{}",
+            "{}{}{}{}{}{}{}",
             &distance_str,
             landing_suffix,
+            &decision_str,
             &kick_return_str,
             &fumble_str,
-            &touchdown_str
+            &touchdown_str,
+            &start_str
         );
         f.write_str(kickoff_str.trim())
     }
 }
 
 impl PlayResult for KickoffResult {
-    fn next_context(&self, context: &GameContext) -> GameContext {
+    fn next_context(&self, context: &GameContext) -> Result<GameContext, GameError> {
         context.next_context(self)
     }
 
@@ -507,6 +638,72 @@ impl KickoffResult {
     pub fn onside_kick(&self) -> bool {
         self.onside_kick
     }
+
+    /// Get a kickoff result's catch_spot property, the yards from the
+    /// receiving team's goal line where the kick was caught or landed
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::kickoff::KickoffResult;
+    /// 
+    /// let my_res = KickoffResult::new();
+    /// let catch_spot = my_res.catch_spot();
+    /// assert!(catch_spot == 0);
+    /// ```
+    pub fn catch_spot(&self) -> i32 {
+        self.catch_spot
+    }
+
+    /// Get a kickoff result's decision property
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::ReturnDecision;
+    /// use fbsim_core::game::play::result::kickoff::KickoffResult;
+    /// 
+    /// let my_res = KickoffResult::new();
+    /// let decision = my_res.decision();
+    /// assert!(decision == ReturnDecision::Touchback);
+    /// ```
+    pub fn decision(&self) -> ReturnDecision {
+        self.decision
+    }
+
+    /// Get a kickoff result's hang_time property in tenths of a second
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::kickoff::KickoffResult;
+    /// 
+    /// let my_res = KickoffResult::new();
+    /// let hang_time = my_res.hang_time();
+    /// assert!(hang_time == 41);
+    /// ```
+    pub fn hang_time(&self) -> u32 {
+        self.hang_time
+    }
+
+    /// Get the yards from the receiving team's goal line at which the ball
+    /// is spotted after the kickoff
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::kickoff::KickoffResult;
+    /// 
+    /// let my_res = KickoffResult::new();
+    /// let start_yard_line = my_res.start_yard_line();
+    /// assert!(start_yard_line == 25);
+    /// ```
+    pub fn start_yard_line(&self) -> u32 {
+        if self.touchback {
+            return KICKOFF_TOUCHBACK_YARD_LINE;
+        }
+        if self.out_of_bounds {
+            return KICKOFF_OOB_YARD_LINE;
+        }
+        let spot = self.catch_spot + self.kick_return_yards - self.fumble_return_yards;
+        u32::try_from(spot.clamp(0, 100)).unwrap_or_default()
+    }
 }
 
 /// # `KickoffResultBuilder` struct
@@ -525,7 +722,10 @@ pub struct KickoffResultBuilder {
     fair_catch: bool,
     fumble: bool,
     touchdown: bool,
-    onside_kick: bool
+    onside_kick: bool,
+    catch_spot: i32,
+    decision: Option<ReturnDecision>,
+    hang_time: u32
 }
 
 impl Default for KickoffResultBuilder {
@@ -548,7 +748,10 @@ impl Default for KickoffResultBuilder {
             fair_catch: false,
             fumble: false,
             touchdown: false,
-            onside_kick: false
+            onside_kick: false,
+            catch_spot: 0,
+            decision: None,
+            hang_time: 41
         }
     }
 }
@@ -739,6 +942,70 @@ impl KickoffResultBuilder {
         self
     }
 
+    /// Set the catch_spot property
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::kickoff::KickoffResultBuilder;
+    /// 
+    /// let my_result = KickoffResultBuilder::new()
+    ///     .catch_spot(-4)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_result.catch_spot() == -4);
+    /// ```
+    pub fn catch_spot(mut self, catch_spot: i32) -> Self {
+        self.catch_spot = catch_spot;
+        self
+    }
+
+    /// Set the decision property, inferred from the touchback, out of
+    /// bounds and fair catch properties if not set
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::ReturnDecision;
+    /// use fbsim_core::game::play::result::kickoff::KickoffResultBuilder;
+    /// 
+    /// let my_result = KickoffResultBuilder::new()
+    ///     .touchback(false)
+    ///     .fair_catch(true)
+    ///     .catch_spot(8)
+    ///     .decision(ReturnDecision::FairCatch)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_result.decision() == ReturnDecision::FairCatch);
+    ///
+    /// // A fair catch may not be returned
+    /// let my_result = KickoffResultBuilder::new()
+    ///     .touchback(false)
+    ///     .fair_catch(true)
+    ///     .kick_return_yards(12)
+    ///     .build();
+    /// assert!(my_result.is_err());
+    /// ```
+    pub fn decision(mut self, decision: ReturnDecision) -> Self {
+        self.decision = Some(decision);
+        self
+    }
+
+    /// Set the hang_time property in tenths of a second
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::kickoff::KickoffResultBuilder;
+    /// 
+    /// let my_result = KickoffResultBuilder::new()
+    ///     .hang_time(38)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_result.hang_time() == 38);
+    /// ```
+    pub fn hang_time(mut self, hang_time: u32) -> Self {
+        self.hang_time = hang_time;
+        self
+    }
+
     /// Build the KickoffResult
     ///
     /// ### Example
@@ -760,7 +1027,10 @@ impl KickoffResultBuilder {
             fair_catch: self.fair_catch,
             fumble: self.fumble,
             touchdown: self.touchdown,
-            onside_kick: self.onside_kick
+            onside_kick: self.onside_kick,
+            catch_spot: self.catch_spot,
+            decision: self.decision,
+            hang_time: self.hang_time
         };
         KickoffResult::try_from(raw)
     }
@@ -808,7 +1078,7 @@ impl KickoffResultSimulator {
     }
 
     /// Generates the distance of the kickoff
-    fn distance(&self, norm_kicking: f64, inside_20: bool, rng: &mut impl Rng) -> i32 {
+    fn distance(&self, norm_kicking: f64, inside_20: bool, modifiers: &WeatherModifiers, rng: &mut impl Rng) -> i32 {
         let mean_dist: f64 = if inside_20 {
             MEAN_KICKOFF_INSIDE_20_DIST
         } else {
@@ -824,7 +1094,7 @@ impl KickoffResultSimulator {
         } else {
             SKEW_KICKOFF_OUTSIDE_20_DIST
         };
-        let dist_dist = SkewNormal::new(mean_dist, std_dist, skew_dist).unwrap();
+        let dist_dist = SkewNormal::new(mean_dist * modifiers.kick_distance, std_dist, skew_dist).unwrap();
         dist_dist.sample(rng).round() as i32
     }
 
@@ -861,6 +1131,12 @@ impl KickoffResultSimulator {
         Exp::new(1_f64).unwrap().sample(rng).round() as i32
     }
 
+    /// Computes the hang time of the kickoff in tenths of a second
+    fn hang_time(&self, kickoff_distance: i32) -> u32 {
+        let hang_time: f64 = KICKOFF_HANG_TIME_INTR + (KICKOFF_HANG_TIME_COEF * kickoff_distance.max(0) as f64);
+        (hang_time * 10_f64).round() as u32
+    }
+
     /// Generates the duration of the kickoff play in seconds
     fn play_duration(&self, total_yards: u32, rng: &mut impl Rng) -> u32 {
         let mean_duration: f64 = KICKOFF_RETURN_PLAY_DURATION_INTR + (KICKOFF_RETURN_PLAY_DURATION_COEF * total_yards as f64);
@@ -935,7 +1211,7 @@ impl PlayResultSimulator for KickoffResultSimulator {
             // TODO: Make this more dynamic
             10
         } else if !touchback {
-            td_yards.min(self.distance(norm_kicking, inside_20, rng))
+            td_yards.min(self.distance(norm_kicking, inside_20, &context.weather().modifiers(), rng))
         } else {
             td_yards
         };
@@ -997,7 +1273,10 @@ impl PlayResultSimulator for KickoffResultSimulator {
             fair_catch,
             fumble,
             touchdown,
-            onside_kick
+            onside_kick,
+            catch_spot: (td_yards - kickoff_distance).clamp(-10, 100),
+            decision: Some(ReturnDecision::from_flags(touchback, out_of_bounds, fair_catch, false)),
+            hang_time: self.hang_time(kickoff_distance)
         };
         let kickoff_res = KickoffResult::try_from(raw).unwrap();
         PlayTypeResult::Kickoff(kickoff_res)
@@ -9,7 +9,9 @@ use serde::{Deserialize, Deserializer, Serialize};
 use tsify_next::Tsify;
 use rand_distr::{Normal, Distribution, Exp, SkewNormal};
 
+use crate::game::error::GameError;
 use crate::game::context::GameContext;
+use crate::game::context::weather::WeatherModifiers;
 use crate::game::play::PlaySimulatable;
 use crate::game::play::result::{PlayResult, PlayTypeResult, PlayResultSimulator, ScoreResult};
 
@@ -131,6 +133,9 @@ const MEAN_PLAY_DURATION_INTR: f64 = 8.32135821_f64; // Adjusted + 3
 const MEAN_PLAY_DURATION_COEF_1: f64 = 0.11343699_f64;
 const MEAN_PLAY_DURATION_COEF_2: f64 = -0.00056798_f64;
 
+// Duration of a spike from the snap until the ball hits the ground
+const SPIKE_DURATION: u32 = 1;
+
 /// # `PassResultRaw` struct
 ///
 /// A `PassResultRaw` is a `PassResult` before its properties have been
@@ -391,6 +396,10 @@ impl Default for PassResult {
     /// use fbsim_core::game::play::result::pass::PassResult;
     /// 
     /// let my_result = PassResult::default();
+    ///
+    /// // The default passes validation
+    /// let json = serde_json::to_string(&my_result).unwrap();
+    /// assert!(serde_json::from_str::<PassResult>(&json).is_ok());
     /// ```
     fn default() -> Self {
         PassResult{
@@ -458,7 +467,9 @@ impl std::fmt::Display for PassResult {
             String::from("")
         };
         let score_str = if self.touchdown {
-            if self.two_point_conversion {
+            if self.two_point_conversion && (self.fumble || self.interception) {
+                " Returned for a defensive two point conversion!"
+            } else if self.two_point_conversion {
                 " Two point conversion is GOOD!"
             } else {
                 " TOUCHDOWN!"
@@ -483,12 +494,16 @@ impl std::fmt::Display for PassResult {
 }
 
 impl PlayResult for PassResult {
-    fn next_context(&self, context: &GameContext) -> GameContext {
+    fn next_context(&self, context: &GameContext) -> Result<GameContext, GameError> {
         context.next_context(self)
     }
 
     fn play_duration(&self) -> u32 {
-        self.play_duration
+        if self.two_point_conversion {
+            0
+        } else {
+            self.play_duration
+        }
     }
 
     fn net_yards(&self) -> i32 {
@@ -561,6 +576,24 @@ impl PassResult {
         PassResult::default()
     }
 
+    /// Initialize the result of a quarterback spike, an incomplete pass
+    /// which stops the clock at the cost of a down
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::pass::PassResult;
+    ///
+    /// let my_res = PassResult::spike();
+    /// assert!(!my_res.complete());
+    /// assert!(my_res.play_duration() == 1);
+    /// ```
+    pub fn spike() -> PassResult {
+        PassResult {
+            play_duration: SPIKE_DURATION,
+            ..PassResult::default()
+        }
+    }
+
     /// Get a pass result's play_duration property
     ///
     /// ### Example
@@ -1190,16 +1223,16 @@ impl PassResultSimulator {
     }
 
     /// Generates the distance of a short pass
-    fn short_pass_distance(&self, yard_line: u32, rng: &mut impl Rng) -> i32 {
-        let mean_short_pass_dist: f64 = MEAN_SHORT_PASS_DIST_INTR + (MEAN_SHORT_PASS_DIST_COEF_1 * yard_line as f64) + (MEAN_SHORT_PASS_DIST_COEF_2 * yard_line.pow(2) as f64) + (MEAN_SHORT_PASS_DIST_COEF_3 * yard_line.pow(3) as f64);
+    fn short_pass_distance(&self, yard_line: u32, modifiers: &WeatherModifiers, rng: &mut impl Rng) -> i32 {
+        let mean_short_pass_dist: f64 = modifiers.pass_distance * (MEAN_SHORT_PASS_DIST_INTR + (MEAN_SHORT_PASS_DIST_COEF_1 * yard_line as f64) + (MEAN_SHORT_PASS_DIST_COEF_2 * yard_line.pow(2) as f64) + (MEAN_SHORT_PASS_DIST_COEF_3 * yard_line.pow(3) as f64));
         let std_short_pass_dist: f64 = STD_SHORT_PASS_DIST_INTR + (STD_SHORT_PASS_DIST_COEF_1 * yard_line as f64) + (STD_SHORT_PASS_DIST_COEF_2 * yard_line.pow(2) as f64) + (STD_SHORT_PASS_DIST_COEF_3 * yard_line.pow(3) as f64);
         let short_pass_dist = Normal::new(mean_short_pass_dist, std_short_pass_dist).unwrap();
         (short_pass_dist.sample(rng).round() as i32).max(-2)
     }
 
     /// Generates the distance of a deep pass
-    fn deep_pass_distance(&self, yard_line: u32, rng: &mut impl Rng) -> i32 {
-        let mean_deep_pass_dist: f64 = MEAN_DEEP_PASS_DIST_INTR + (MEAN_DEEP_PASS_DIST_COEF_1 * yard_line as f64) + (MEAN_DEEP_PASS_DIST_COEF_2 * yard_line.pow(2) as f64) + (MEAN_DEEP_PASS_DIST_COEF_3 * yard_line.pow(3) as f64);
+    fn deep_pass_distance(&self, yard_line: u32, modifiers: &WeatherModifiers, rng: &mut impl Rng) -> i32 {
+        let mean_deep_pass_dist: f64 = modifiers.pass_distance * (MEAN_DEEP_PASS_DIST_INTR + (MEAN_DEEP_PASS_DIST_COEF_1 * yard_line as f64) + (MEAN_DEEP_PASS_DIST_COEF_2 * yard_line.pow(2) as f64) + (MEAN_DEEP_PASS_DIST_COEF_3 * yard_line.pow(3) as f64));
         let std_deep_pass_dist: f64 = STD_DEEP_PASS_DIST_INTR + (STD_DEEP_PASS_DIST_COEF_1 * yard_line as f64) + (STD_DEEP_PASS_DIST_COEF_2 * yard_line.pow(2) as f64) + (STD_DEEP_PASS_DIST_COEF_3 * yard_line.pow(3) as f64);
         let deep_pass_dist = Normal::new(mean_deep_pass_dist, std_deep_pass_dist).unwrap();
         deep_pass_dist.sample(rng).round() as i32
@@ -1224,7 +1257,7 @@ impl PassResultSimulator {
     }
 
     /// Generates whether the quarterback threw a complete pass
-    fn complete(&self, norm_diff_passing: f64, pass_dist: i32, rng: &mut impl Rng) -> bool {
+    fn complete(&self, norm_diff_passing: f64, pass_dist: i32, modifiers: &WeatherModifiers, rng: &mut impl Rng) -> bool {
         let p_complete_skill: f64 = P_COMPLETE_INTR + (P_COMPLETE_COEF * norm_diff_passing);
         let p_complete_yl: f64 = P_COMPLETE_DIST_INTR + (P_COMPLETE_DIST_COEF * pass_dist as f64);
         let p_complete: f64 = 0.8_f64.min((
@@ -1234,7 +1267,7 @@ impl PassResultSimulator {
                 ).max(0.01).ln() + 1.0
             ).max(0.01).ln() + 1.23
         ).max(0.01));
-        rng.gen::<f64>() < p_complete
+        rng.gen::<f64>() < p_complete * modifiers.completion
     }
 
     /// Generates whether the wide receiver had zero yards after catch
@@ -1253,8 +1286,8 @@ impl PassResultSimulator {
     }
 
     /// Generates whether a fumble occurred
-    fn fumble(&self, norm_diff_turnovers: f64, rng: &mut impl Rng) -> bool {
-        let p_fumble: f64 = 0.001_f64.max(P_FUMBLE_INTR + (P_FUMBLE_COEF * norm_diff_turnovers));
+    fn fumble(&self, norm_diff_turnovers: f64, modifiers: &WeatherModifiers, rng: &mut impl Rng) -> bool {
+        let p_fumble: f64 = 0.001_f64.max(P_FUMBLE_INTR + (P_FUMBLE_COEF * norm_diff_turnovers)) * modifiers.fumble;
         rng.gen::<f64>() < p_fumble
     }
 
@@ -1328,6 +1361,7 @@ impl PlayResultSimulator for PassResultSimulator {
             ) / 200_f64
         );
         let norm_scrambling: f64 = offense.offense().scrambling() as f64 / 100_f64;
+        let modifiers = context.weather().modifiers();
         let td_yards = context.yards_to_touchdown();
         let yard_line = u32::try_from(td_yards).unwrap_or_default();
         let oob_yards = td_yards + 10;
@@ -1385,9 +1419,9 @@ impl PlayResultSimulator for PassResultSimulator {
         // Generate pass distance
         let pass_distance: i32 = if pass {
             if short_pass {
-                self.short_pass_distance(yard_line, rng)
+                self.short_pass_distance(yard_line, &modifiers, rng)
             } else {
-                self.deep_pass_distance(yard_line, rng)
+                self.deep_pass_distance(yard_line, &modifiers, rng)
             }
         } else {
             0
@@ -1416,7 +1450,7 @@ impl PlayResultSimulator for PassResultSimulator {
 
         // Generate whether the pass was complete
         let complete: bool = if pass && !interception {
-            self.complete(norm_diff_passing, pass_distance, rng)
+            self.complete(norm_diff_passing, pass_distance, &modifiers, rng)
         } else {
             false
         };
@@ -1457,7 +1491,7 @@ impl PlayResultSimulator for PassResultSimulator {
 
         // Generate whether a fumble occurred
         let fumble: bool = if (scramble || complete) && !touchdown {
-            self.fumble(norm_diff_turnovers, rng)
+            self.fumble(norm_diff_turnovers, &modifiers, rng)
         } else {
             false
         };
@@ -9,9 +9,11 @@ use serde::{Deserialize, Deserializer, Serialize};
 use tsify_next::Tsify;
 use rand_distr::{Normal, Distribution, Exp, SkewNormal};
 
+use crate::game::error::GameError;
 use crate::game::context::GameContext;
+use crate::game::context::weather::WeatherModifiers;
 use crate::game::play::PlaySimulatable;
-use crate::game::play::result::{PlayResult, PlayTypeResult, PlayResultSimulator, ScoreResult};
+use crate::game::play::result::{PlayResult, PlayTypeResult, PlayResultSimulator, ReturnDecision, ScoreResult};
 
 // Punt block probability regression
 const P_BLOCK_INTR: f64 = -0.0010160286505995551_f64;
@@ -91,6 +93,28 @@ const P_FUMBLE_COEF: f64 = -0.08389777_f64; // Adjusted - 0.04
 const PUNT_PLAY_DURATION_INTR: f64 = 8.2792296_f64; // Adjusted + 3
 const PUNT_PLAY_DURATION_COEF: f64 = 0.09291598_f64;
 
+// Punt hang time regression (seconds)
+const PUNT_HANG_TIME_INTR: f64 = 1.0_f64;
+const PUNT_HANG_TIME_COEF: f64 = 0.07_f64;
+
+// Receiving team yard line after a touchback
+const PUNT_TOUCHBACK_YARD_LINE: u32 = 25;
+
+// Probability the receiving team recovers its own muffed punt
+const P_MUFF_RECEIVING_RECOVERY: f64 = 0.5_f64;
+
+// Punts landing within this many yards of the goal line may be let bounce
+// and downed by the kicking team, with the given probability
+const PUNT_DOWNED_MAX_SPOT: i32 = 10;
+const P_PUNT_DOWNED: f64 = 0.6_f64;
+
+// Mean yards behind the line of scrimmage a blocked punt is recovered
+const BLOCKED_PUNT_MEAN_RECOVERY_YARDS: f64 = 8_f64;
+
+// Probability the receiving team recovers a blocked punt in the kicking
+// team's end zone for a touchdown, rather than conceding a safety
+const P_BLOCKED_PUNT_TOUCHDOWN: f64 = 0.5_f64;
+
 /// # `PuntResultRaw` struct
 ///
 /// A `PuntResultRaw` is a `PuntResult` before its properties have been
@@ -108,7 +132,29 @@ pub struct PuntResultRaw {
     fair_catch: bool,
     muffed: bool,
     fumble: bool,
-    touchdown: bool
+    touchdown: bool,
+    /// Yards from the receiving team's goal line where the punt was caught
+    /// or landed, negative in the end zone (default: 0)
+    #[serde(default)]
+    catch_spot: i32,
+    /// The returner's decision (default: inferred from the outcome flags)
+    #[serde(default)]
+    decision: Option<ReturnDecision>,
+    /// Hang time of the punt in tenths of a second (default: 0)
+    #[serde(default)]
+    hang_time: u32,
+    /// Whether the receiving team recovered its own muff or fumble, rather
+    /// than the kicking team (default: false)
+    #[serde(default)]
+    receiving_recovery: bool,
+    /// Whether the punt was let bounce and downed by the kicking team
+    /// (default: false)
+    #[serde(default)]
+    downed: bool,
+    /// Whether a blocked punt went out of or was recovered by the kicking
+    /// team in its own end zone for a safety (default: false)
+    #[serde(default)]
+    safety: bool
 }
 
 impl PuntResultRaw {
@@ -164,7 +210,7 @@ impl PuntResultRaw {
         }
 
         // Ensure punt return yards are zero if punt was not returned
-        if (self.blocked || self.out_of_bounds || self.touchback || self.fair_catch || self.muffed) && self.punt_return_yards != 0 {
+        if (self.blocked || self.out_of_bounds || self.touchback || self.fair_catch || self.muffed || self.downed) && self.punt_return_yards != 0 {
             return Err(
                 format!(
                     "Punt was not returned but punt return yards were nonzero: {}",
@@ -180,6 +226,44 @@ impl PuntResultRaw {
             )
         }
 
+        // Ensure the receiving team only recovers a fumble, which it does
+        // not return
+        if self.receiving_recovery && !self.fumble {
+            return Err(
+                String::from("Cannot have a receiving team recovery without a fumble")
+            )
+        }
+        if self.receiving_recovery && self.fumble_return_yards != 0 {
+            return Err(
+                format!(
+                    "Receiving team recovered but fumble return yards are nonzero: {}",
+                    self.fumble_return_yards
+                )
+            )
+        }
+
+        // Ensure a safety only follows a blocked punt, and is not also a
+        // touchdown
+        if self.safety && (!self.blocked || self.touchdown) {
+            return Err(
+                format!(
+                    "Cannot have a safety without a blocked punt ({}) or with a touchdown ({})",
+                    self.blocked, self.touchdown
+                )
+            )
+        }
+
+        // Ensure if downed, the punt was neither blocked, out of bounds, a
+        // touchback, fair caught, muffed, fumbled, nor returned for a TD
+        if self.downed && (self.blocked || self.out_of_bounds || self.touchback || self.fair_catch || self.muffed || self.fumble || self.touchdown) {
+            return Err(
+                format!(
+                    "Cannot have both downed punt and blocked ({}), out of bounds ({}), touchback ({}), fair catch ({}), muffed ({}), fumble ({}), or touchdown ({})",
+                    self.blocked, self.out_of_bounds, self.touchback, self.fair_catch, self.muffed, self.fumble, self.touchdown
+                )
+            )
+        }
+
         // Ensure if blocked, not also oob, touchback, fair catch, or muffed
         if self.blocked && (self.out_of_bounds || self.touchback || self.fair_catch || self.muffed) {
             return Err(
@@ -209,6 +293,79 @@ impl PuntResultRaw {
                 )
             )
         }
+
+        // Ensure the catch spot is between the back of the end zone and the
+        // kicking team's goal line
+        if self.catch_spot < -10 || self.catch_spot > 100 {
+            return Err(
+                format!(
+                    "Catch spot is not in range [-10, 100]: {}",
+                    self.catch_spot
+                )
+            )
+        }
+
+        // Ensure hang time is no more than 10 seconds
+        if self.hang_time > 100 {
+            return Err(
+                format!(
+                    "Hang time is not in range [0, 100]: {}",
+                    self.hang_time
+                )
+            )
+        }
+
+        // Ensure a downed punt was downed in the field of play
+        if self.downed && self.catch_spot <= 0 {
+            return Err(
+                format!(
+                    "Punt was downed but catch spot is not in the field of play: {}",
+                    self.catch_spot
+                )
+            )
+        }
+
+        // Ensure a safety was recovered in the kicking team's end zone
+        if self.safety && self.catch_spot < 100 {
+            return Err(
+                format!(
+                    "Safety but catch spot is not in the kicking team's end zone: {}",
+                    self.catch_spot
+                )
+            )
+        }
+
+        // Ensure a touchback was caught in the end zone
+        if self.touchback && self.catch_spot > 0 {
+            return Err(
+                format!(
+                    "Touchback but catch spot is not in the end zone: {}",
+                    self.catch_spot
+                )
+            )
+        }
+
+        // Ensure the return decision agrees with the outcome flags
+        if let Some(decision) = self.decision {
+            if (decision == ReturnDecision::Touchback) != self.touchback ||
+                (decision == ReturnDecision::FairCatch) != self.fair_catch ||
+                (decision == ReturnDecision::Return && (self.out_of_bounds || self.blocked || self.downed)) {
+                return Err(
+                    format!(
+                        "Return decision {:?} is inconsistent with touchback ({}), out of bounds ({}), fair catch ({}), blocked ({}), and downed ({})",
+                        decision, self.touchback, self.out_of_bounds, self.fair_catch, self.blocked, self.downed
+                    )
+                )
+            }
+            if decision == ReturnDecision::LetBounce && self.punt_return_yards != 0 {
+                return Err(
+                    format!(
+                        "Punt was allowed to bounce but punt return yards were nonzero: {}",
+                        self.punt_return_yards
+                    )
+                )
+            }
+        }
         Ok(())
     }
 }
@@ -231,7 +388,13 @@ pub struct PuntResult {
     fair_catch: bool,
     muffed: bool,
     fumble: bool,
-    touchdown: bool
+    touchdown: bool,
+    catch_spot: i32,
+    decision: ReturnDecision,
+    hang_time: u32,
+    receiving_recovery: bool,
+    downed: bool,
+    safety: bool
 }
 
 impl TryFrom<PuntResultRaw> for PuntResult {
@@ -257,7 +420,15 @@ impl TryFrom<PuntResultRaw> for PuntResult {
                 fair_catch: item.fair_catch,
                 muffed: item.muffed,
                 fumble: item.fumble,
-                touchdown: item.touchdown
+                touchdown: item.touchdown,
+                catch_spot: item.catch_spot,
+                decision: item.decision.unwrap_or(
+                    ReturnDecision::from_flags(item.touchback, item.out_of_bounds || item.downed, item.fair_catch, item.blocked)
+                ),
+                hang_time: item.hang_time,
+                receiving_recovery: item.receiving_recovery,
+                downed: item.downed,
+                safety: item.safety
             }
         )
     }
@@ -282,6 +453,10 @@ impl Default for PuntResult {
     /// use fbsim_core::game::play::result::punt::PuntResult;
     /// 
     /// let my_result = PuntResult::default();
+    ///
+    /// // The default passes validation
+    /// let json = serde_json::to_string(&my_result).unwrap();
+    /// assert!(serde_json::from_str::<PuntResult>(&json).is_ok());
     /// ```
     fn default() -> Self {
         PuntResult{
@@ -295,7 +470,13 @@ impl Default for PuntResult {
             fair_catch: false,
             muffed: false,
             fumble: false,
-            touchdown: false
+            touchdown: false,
+            catch_spot: 0,
+            decision: ReturnDecision::Return,
+            hang_time: 0,
+            receiving_recovery: false,
+            downed: false,
+            safety: false
         }
     }
 }
@@ -306,14 +487,59 @@ impl std::fmt::Display for PuntResult {
     /// ### Example
     ///
     /// ```
-    /// use fbsim_core::game::play::result::punt::PuntResult;
+    /// use fbsim_core::game::play::result::punt::{PuntResult, PuntResultBuilder};
     /// 
     /// let my_result = PuntResult::default();
-    /// println!("{}", my_result);
+    /// assert!(
+    ///     my_result.to_string() == "Punt 0 yards fielded. Caught at the goal line with \
+    ///         0.0s of hang time. Punt returned 0 yards. Ball spotted at the goal line."
+    /// );
+    ///
+    /// // A punt fair caught inside the 20
+    /// let my_result = PuntResultBuilder::new()
+    ///     .punt_yards(44)
+    ///     .fair_catch(true)
+    ///     .catch_spot(11)
+    ///     .hang_time(41)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(
+    ///     my_result.to_string() == "Punt 44 yards for a fair catch. Fair catch at the receiving \
+    ///         11 with 4.1s of hang time. Ball spotted at the receiving 11."
+    /// );
+    ///
+    /// // A punt downed inside the 5
+    /// let my_result = PuntResultBuilder::new()
+    ///     .punt_yards(38)
+    ///     .downed(true)
+    ///     .catch_spot(3)
+    ///     .hang_time(37)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(
+    ///     my_result.to_string() == "Punt 38 yards downed. Bounced at the receiving 3 with \
+    ///         3.7s of hang time. Ball spotted at the receiving 3."
+    /// );
+    ///
+    /// // A blocked punt recovered in the end zone for a safety
+    /// let my_result = PuntResultBuilder::new()
+    ///     .punt_yards(-12)
+    ///     .blocked(true)
+    ///     .safety(true)
+    ///     .catch_spot(100)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_result.to_string() == "Punt BLOCKED, recovered in the end zone for a SAFETY.");
     /// ```
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let blocked_prefix = if self.blocked {
-            format!("Punt BLOCKED, returned {} yards.", self.fumble_return_yards)
+            if self.safety {
+                String::from("Punt BLOCKED, recovered in the end zone for a SAFETY.")
+            } else if self.touchdown {
+                String::from("Punt BLOCKED, recovered in the end zone for a TOUCHDOWN.")
+            } else {
+                format!("Punt BLOCKED, recovered {} yards behind the line.", -self.punt_yards)
+            }
         } else {
             String::from("")
         };
@@ -322,10 +548,14 @@ impl std::fmt::Display for PuntResult {
         } else {
             String::from("")
         };
-        let catch_str = if self.touchback {
+        let catch_str = if self.blocked {
+            String::from("")
+        } else if self.touchback {
             String::from(" for a touchback.")
         } else if self.out_of_bounds {
             String::from(" out of bounds.")
+        } else if self.downed {
+            String::from(" downed.")
         } else if self.fair_catch {
             if self.muffed {
                 String::from(" fair catch MUFFED.")
@@ -335,30 +565,49 @@ impl std::fmt::Display for PuntResult {
         } else {
             String::from(" fielded.")
         };
-        let return_str = if !(self.touchback || self.out_of_bounds || (self.fair_catch && !self.muffed)) {
+        let decision_str = if !self.blocked {
+            format!(
+                " {} {} with {:.1}s of hang time.",
+                self.decision,
+                ReturnDecision::spot_str(self.catch_spot),
+                self.hang_time as f64 / 10_f64
+            )
+        } else {
+            String::from("")
+        };
+        let return_str = if !(self.blocked || self.touchback || self.out_of_bounds || self.downed || (self.fair_catch && !self.muffed)) {
             format!(" Punt returned {} yards.", self.punt_return_yards)
         } else {
             String::from("")
         };
-        let fumble_str = if self.fumble {
-            format!(" FUMBLE recovered by the kicking team, returned {} yards", self.fumble_return_yards)
+        let fumble_str = if self.fumble && self.receiving_recovery {
+            String::from(" FUMBLE recovered by the receiving team.")
+        } else if self.fumble {
+            format!(" FUMBLE recovered by the kicking team, returned {} yards.", self.fumble_return_yards)
+        } else {
+            String::from("")
+        };
+        let start_str = if !(self.touchdown || self.safety) {
+            format!(" Ball spotted {}.", ReturnDecision::spot_str(self.start_yard_line() as i32))
         } else {
             String::from("")
         };
         let punt_str = format!(
-            "{}{}{}{}{}",
+            "{}{}{}{}{}{}{}",
             &blocked_prefix,
             &punt_distance_str,
             &catch_str,
+            &decision_str,
             &return_str,
-            &fumble_str
+            &fumble_str,
+            &start_str
         );
         f.write_str(punt_str.trim())
     }
 }
 
 impl PlayResult for PuntResult {
-    fn next_context(&self, context: &GameContext) -> GameContext {
+    fn next_context(&self, context: &GameContext) -> Result<GameContext, GameError> {
         context.next_context(self)
     }
 
@@ -371,22 +620,25 @@ impl PlayResult for PuntResult {
     }
 
     fn turnover(&self) -> bool {
-        // In this case, turnover means change of possession
-        // Usually fumble means turnover but in this case fumble means no change of possession
-        !self.fumble
+        // In this case, turnover means change of possession, which happens
+        // unless the kicking team recovers a fumble or concedes a safety
+        !(self.kicking_team_recovery() || self.safety)
     }
 
     fn offense_score(&self) -> ScoreResult {
-        if self.touchdown && self.fumble {
+        if self.touchdown && self.kicking_team_recovery() {
             return ScoreResult::Touchdown;
         }
         ScoreResult::None
     }
 
     fn defense_score(&self) -> ScoreResult {
-        if self.touchdown && !self.fumble {
+        if self.touchdown && !self.kicking_team_recovery() {
             return ScoreResult::Touchdown;
         }
+        if self.safety {
+            return ScoreResult::Safety;
+        }
         ScoreResult::None
     }
 
@@ -408,7 +660,9 @@ impl PlayResult for PuntResult {
 
     fn punt(&self) -> bool { true }
 
-    fn next_play_kickoff(&self) -> bool { false }
+    fn next_play_kickoff(&self) -> bool {
+        self.safety
+    }
 
     fn next_play_extra_point(&self) -> bool {
         self.touchdown
@@ -581,6 +835,158 @@ impl PuntResult {
     pub fn touchdown(&self) -> bool {
         self.touchdown
     }
+
+    /// Get a punt result's catch_spot property, the yards from the receiving
+    /// team's goal line where the punt was caught or landed
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::punt::PuntResult;
+    /// 
+    /// let my_res = PuntResult::new();
+    /// let catch_spot = my_res.catch_spot();
+    /// assert!(catch_spot == 0);
+    /// ```
+    pub fn catch_spot(&self) -> i32 {
+        self.catch_spot
+    }
+
+    /// Get a punt result's decision property
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::ReturnDecision;
+    /// use fbsim_core::game::play::result::punt::PuntResult;
+    /// 
+    /// let my_res = PuntResult::new();
+    /// let decision = my_res.decision();
+    /// assert!(decision == ReturnDecision::Return);
+    /// ```
+    pub fn decision(&self) -> ReturnDecision {
+        self.decision
+    }
+
+    /// Get a punt result's hang_time property in tenths of a second
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::punt::PuntResult;
+    /// 
+    /// let my_res = PuntResult::new();
+    /// let hang_time = my_res.hang_time();
+    /// assert!(hang_time == 0);
+    /// ```
+    pub fn hang_time(&self) -> u32 {
+        self.hang_time
+    }
+
+    /// Get a punt result's receiving_recovery property, whether the
+    /// receiving team recovered its own muff or fumble
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::punt::PuntResult;
+    /// 
+    /// let my_res = PuntResult::new();
+    /// let receiving_recovery = my_res.receiving_recovery();
+    /// assert!(!receiving_recovery);
+    /// ```
+    pub fn receiving_recovery(&self) -> bool {
+        self.receiving_recovery
+    }
+
+    /// Get whether the kicking team recovered a muff or fumble, keeping
+    /// possession
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::punt::PuntResultBuilder;
+    /// 
+    /// let my_res = PuntResultBuilder::new()
+    ///     .punt_yards(40)
+    ///     .fair_catch(true)
+    ///     .muffed(true)
+    ///     .fumble(true)
+    ///     .catch_spot(20)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_res.kicking_team_recovery());
+    /// ```
+    pub fn kicking_team_recovery(&self) -> bool {
+        self.fumble && !self.receiving_recovery
+    }
+
+    /// Get a punt result's downed property, whether the punt was let bounce
+    /// and downed by the kicking team
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::punt::PuntResult;
+    /// 
+    /// let my_res = PuntResult::new();
+    /// let downed = my_res.downed();
+    /// assert!(!downed);
+    /// ```
+    pub fn downed(&self) -> bool {
+        self.downed
+    }
+
+    /// Get whether the punt was downed inside the receiving team's 5 yard
+    /// line
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::punt::PuntResultBuilder;
+    /// 
+    /// let my_res = PuntResultBuilder::new()
+    ///     .punt_yards(42)
+    ///     .downed(true)
+    ///     .catch_spot(4)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_res.downed_inside_5());
+    /// ```
+    pub fn downed_inside_5(&self) -> bool {
+        self.downed && self.catch_spot <= 5
+    }
+
+    /// Get a punt result's safety property, whether a blocked punt was
+    /// recovered by the kicking team in its own end zone
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::punt::PuntResult;
+    /// 
+    /// let my_res = PuntResult::new();
+    /// let safety = my_res.safety();
+    /// assert!(!safety);
+    /// ```
+    pub fn safety(&self) -> bool {
+        self.safety
+    }
+
+    /// Get the yards from the receiving team's goal line at which the ball
+    /// is spotted after the punt
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::punt::PuntResultBuilder;
+    /// 
+    /// let my_res = PuntResultBuilder::new()
+    ///     .punt_yards(45)
+    ///     .punt_return_yards(8)
+    ///     .catch_spot(22)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_res.start_yard_line() == 30);
+    /// ```
+    pub fn start_yard_line(&self) -> u32 {
+        if self.touchback {
+            return PUNT_TOUCHBACK_YARD_LINE;
+        }
+        let spot = self.catch_spot + self.punt_return_yards - self.fumble_return_yards;
+        u32::try_from(spot.clamp(0, 100)).unwrap_or_default()
+    }
 }
 
 /// # `PuntResultBuilder` struct
@@ -600,7 +1006,13 @@ pub struct PuntResultBuilder {
     fair_catch: bool,
     muffed: bool,
     fumble: bool,
-    touchdown: bool
+    touchdown: bool,
+    catch_spot: i32,
+    decision: Option<ReturnDecision>,
+    hang_time: u32,
+    receiving_recovery: bool,
+    downed: bool,
+    safety: bool
 }
 
 impl Default for PuntResultBuilder {
@@ -624,7 +1036,13 @@ impl Default for PuntResultBuilder {
             fair_catch: false,
             muffed: false,
             fumble: false,
-            touchdown: false
+            touchdown: false,
+            catch_spot: 0,
+            decision: None,
+            hang_time: 0,
+            receiving_recovery: false,
+            downed: false,
+            safety: false
         }
     }
 }
@@ -831,6 +1249,129 @@ impl PuntResultBuilder {
         self
     }
 
+    /// Set the catch_spot property
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::punt::PuntResultBuilder;
+    /// 
+    /// let my_result = PuntResultBuilder::new()
+    ///     .catch_spot(12)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_result.catch_spot() == 12);
+    /// ```
+    pub fn catch_spot(mut self, catch_spot: i32) -> Self {
+        self.catch_spot = catch_spot;
+        self
+    }
+
+    /// Set the decision property, inferred from the touchback, out of
+    /// bounds, fair catch and blocked properties if not set
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::ReturnDecision;
+    /// use fbsim_core::game::play::result::punt::PuntResultBuilder;
+    /// 
+    /// let my_result = PuntResultBuilder::new()
+    ///     .touchback(true)
+    ///     .catch_spot(-2)
+    ///     .decision(ReturnDecision::Touchback)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_result.decision() == ReturnDecision::Touchback);
+    ///
+    /// // A touchback must be caught in the end zone
+    /// let my_result = PuntResultBuilder::new()
+    ///     .touchback(true)
+    ///     .catch_spot(5)
+    ///     .build();
+    /// assert!(my_result.is_err());
+    /// ```
+    pub fn decision(mut self, decision: ReturnDecision) -> Self {
+        self.decision = Some(decision);
+        self
+    }
+
+    /// Set the hang_time property in tenths of a second
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::punt::PuntResultBuilder;
+    /// 
+    /// let my_result = PuntResultBuilder::new()
+    ///     .hang_time(44)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_result.hang_time() == 44);
+    /// ```
+    pub fn hang_time(mut self, hang_time: u32) -> Self {
+        self.hang_time = hang_time;
+        self
+    }
+
+    /// Set the receiving_recovery property
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::punt::PuntResultBuilder;
+    /// 
+    /// let my_result = PuntResultBuilder::new()
+    ///     .punt_yards(40)
+    ///     .fair_catch(true)
+    ///     .muffed(true)
+    ///     .fumble(true)
+    ///     .receiving_recovery(true)
+    ///     .catch_spot(20)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_result.receiving_recovery());
+    /// ```
+    pub fn receiving_recovery(mut self, receiving_recovery: bool) -> Self {
+        self.receiving_recovery = receiving_recovery;
+        self
+    }
+
+    /// Set the downed property
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::punt::PuntResultBuilder;
+    /// 
+    /// let my_result = PuntResultBuilder::new()
+    ///     .punt_yards(40)
+    ///     .downed(true)
+    ///     .catch_spot(8)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_result.downed());
+    /// ```
+    pub fn downed(mut self, downed: bool) -> Self {
+        self.downed = downed;
+        self
+    }
+
+    /// Set the safety property
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::punt::PuntResultBuilder;
+    /// 
+    /// let my_result = PuntResultBuilder::new()
+    ///     .punt_yards(-6)
+    ///     .blocked(true)
+    ///     .safety(true)
+    ///     .catch_spot(100)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_result.safety());
+    /// ```
+    pub fn safety(mut self, safety: bool) -> Self {
+        self.safety = safety;
+        self
+    }
+
     /// Build the PuntResult
     ///
     /// ### Example
@@ -853,7 +1394,13 @@ impl PuntResultBuilder {
             fair_catch: self.fair_catch,
             muffed: self.muffed,
             fumble: self.fumble,
-            touchdown: self.touchdown
+            touchdown: self.touchdown,
+            catch_spot: self.catch_spot,
+            decision: self.decision,
+            hang_time: self.hang_time,
+            receiving_recovery: self.receiving_recovery,
+            downed: self.downed,
+            safety: self.safety
         };
         PuntResult::try_from(raw)
     }
@@ -902,7 +1449,7 @@ impl PuntResultSimulator {
     }
 
     /// Generates the distance of the punt
-    fn distance(&self, yard_line: i32, punt_inside_20: bool, rng: &mut impl Rng) -> i32 {
+    fn distance(&self, yard_line: i32, punt_inside_20: bool, modifiers: &WeatherModifiers, rng: &mut impl Rng) -> i32 {
         let mean_rel_dist: f64 = if punt_inside_20 {
             PUNT_INSIDE_20_MEAN_REL_DIST_INTR + (PUNT_INSIDE_20_MEAN_REL_DIST_COEF * yard_line as f64)
         } else {
@@ -926,7 +1473,7 @@ impl PuntResultSimulator {
         let rel_dist: f64 = rel_dist_dist.sample(rng);
         let new_yard_line: f64 = yard_line as f64 * rel_dist;
         let punt_distance: i32 = yard_line - new_yard_line as i32;
-        punt_distance
+        (punt_distance as f64 * modifiers.kick_distance).round() as i32
     }
 
     /// Generates whether the punt went out of bounds
@@ -954,6 +1501,28 @@ impl PuntResultSimulator {
         rng.gen::<f64>() < p_muffed_punt
     }
 
+    /// Generates whether the kicking team let the punt bounce and downed it
+    fn downed(&self, rng: &mut impl Rng) -> bool {
+        rng.gen::<f64>() < P_PUNT_DOWNED
+    }
+
+    /// Generates whether the receiving team recovered its own muff or fumble
+    fn receiving_recovery(&self, rng: &mut impl Rng) -> bool {
+        rng.gen::<f64>() < P_MUFF_RECEIVING_RECOVERY
+    }
+
+    /// Generates the yards behind the line of scrimmage a blocked punt is
+    /// recovered
+    fn blocked_recovery_yards(&self, rng: &mut impl Rng) -> i32 {
+        Exp::new(1_f64 / BLOCKED_PUNT_MEAN_RECOVERY_YARDS).unwrap().sample(rng).round() as i32
+    }
+
+    /// Generates whether a blocked punt recovered in the kicking team's end
+    /// zone was recovered by the receiving team for a touchdown
+    fn blocked_touchdown(&self, rng: &mut impl Rng) -> bool {
+        rng.gen::<f64>() < P_BLOCKED_PUNT_TOUCHDOWN
+    }
+
     /// Generates the punt return yards
     fn return_yards(&self, landing_yard_line: i32, norm_diff_returning: f64, rng: &mut impl Rng) -> i32 {
         let mean_rel_return_yards: f64 = MEAN_REL_RETURN_YARDS_INTR + (MEAN_REL_RETURN_YARDS_COEF_1 * norm_diff_returning) +
@@ -981,6 +1550,12 @@ impl PuntResultSimulator {
         Exp::new(1_f64).unwrap().sample(rng).round() as i32
     }
 
+    /// Computes the hang time of the punt in tenths of a second
+    fn hang_time(&self, punt_distance: i32) -> u32 {
+        let hang_time: f64 = PUNT_HANG_TIME_INTR + (PUNT_HANG_TIME_COEF * punt_distance.max(0) as f64);
+        (hang_time * 10_f64).round() as u32
+    }
+
     /// Generates the duration of the punt play
     fn play_duration(&self, total_yards: u32, rng: &mut impl Rng) -> u32 {
         let mean_duration: f64 = PUNT_PLAY_DURATION_INTR + (PUNT_PLAY_DURATION_COEF * total_yards as f64);
@@ -1040,14 +1615,21 @@ impl PlayResultSimulator for PuntResultSimulator {
             false
         };
 
-        // Generate the distance of the punt
+        // Generate the distance of the punt, or how far behind the line a
+        // blocked punt was recovered
         let punt_distance: i32 = if !blocked {
-            self.distance(td_yards, inside_20, rng)
+            self.distance(td_yards, inside_20, &context.weather().modifiers(), rng)
         } else {
-            0
+            -self.blocked_recovery_yards(rng).min(100 - td_yards)
         };
         let punt_landing: i32 = 100.min(0.max(td_yards - punt_distance));
-        let touchback: bool = punt_landing <= 0;
+        let touchback: bool = !blocked && punt_landing <= 0;
+
+        // Determine whether a blocked punt was recovered in the kicking
+        // team's end zone, for either a safety or a touchdown
+        let blocked_in_end_zone: bool = blocked && punt_landing >= 100;
+        let blocked_touchdown: bool = blocked_in_end_zone && self.blocked_touchdown(rng);
+        let safety: bool = blocked_in_end_zone && !blocked_touchdown;
 
         // Generate whether the punt went out of bounds
         let out_of_bounds: bool = if !(blocked || touchback) {
@@ -1063,45 +1645,60 @@ impl PlayResultSimulator for PuntResultSimulator {
             false
         };
 
+        // Generate whether the kicking team let a short punt bounce and
+        // downed it
+        let downed: bool = if !(blocked || out_of_bounds || touchback || fair_catch) && punt_landing <= PUNT_DOWNED_MAX_SPOT {
+            self.downed(rng)
+        } else {
+            false
+        };
+
         // Generate whether the punt was muffed
-        let punt_muffed: bool = if !(blocked || out_of_bounds || touchback) {
+        let punt_muffed: bool = if !(blocked || out_of_bounds || touchback || downed) {
             self.muffed(norm_diff_returning, rng)
         } else {
             false
         };
 
         // Generate the punt return yards
-        let punt_return_yards: i32 = if !(blocked || fair_catch || out_of_bounds || touchback || punt_muffed) {
+        let punt_return_yards: i32 = if !(blocked || fair_catch || out_of_bounds || touchback || punt_muffed || downed) {
             (100 - punt_landing).min(self.return_yards(100 - punt_landing, norm_diff_returning, rng))
         } else {
             0
         };
 
         // Determine if a punt return touchdown occurred
-        let mut touchdown: bool = if !(blocked || out_of_bounds || touchback || punt_muffed) {
+        let mut touchdown: bool = if !(blocked || out_of_bounds || touchback || punt_muffed || downed) {
             (punt_landing + punt_return_yards) >= 100
         } else {
-            false
+            blocked_touchdown
         };
 
         // Generate whether a fumble occurred
         let fumble: bool = if punt_muffed {
             true
-        } else if !(blocked || out_of_bounds || touchback || touchdown) {
+        } else if !(blocked || out_of_bounds || touchback || touchdown || downed) {
             self.fumble(norm_diff_returning, rng)
         } else {
             false
         };
 
+        // Generate which team recovered the fumble
+        let receiving_recovery: bool = if fumble {
+            self.receiving_recovery(rng)
+        } else {
+            false
+        };
+
         // Generate the fumble return yards
-        let fumble_return_yards: i32 = if fumble {
+        let fumble_return_yards: i32 = if fumble && !receiving_recovery {
             self.fumble_return_yards(rng)
         } else {
             0
         };
 
         // Determine if a fumble recovery touchdown occurred
-        touchdown = if fumble {
+        touchdown = if fumble && !receiving_recovery {
             punt_landing + punt_return_yards - fumble_return_yards <= 0
         } else {
             touchdown
@@ -1121,7 +1718,17 @@ impl PlayResultSimulator for PuntResultSimulator {
             fair_catch,
             muffed: punt_muffed,
             fumble,
-            touchdown
+            touchdown,
+            catch_spot: (td_yards - punt_distance).clamp(-10, 100),
+            decision: Some(ReturnDecision::from_flags(touchback, out_of_bounds || downed, fair_catch, blocked)),
+            hang_time: if blocked {
+                0
+            } else {
+                self.hang_time(punt_distance)
+            },
+            receiving_recovery,
+            downed,
+            safety
         };
         let punt_res = PuntResult::try_from(raw).unwrap();
         PlayTypeResult::Punt(punt_res)
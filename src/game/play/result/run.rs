@@ -9,8 +9,11 @@ use serde::{Deserialize, Deserializer, Serialize};
 use tsify_next::Tsify;
 use rand_distr::{Normal, Distribution, Exp};
 
+use crate::game::error::GameError;
 use crate::game::context::GameContext;
+use crate::game::context::weather::WeatherModifiers;
 use crate::game::play::PlaySimulatable;
+use crate::game::play::context::KNEEL_DURATION;
 use crate::game::play::result::{PlayResult, PlayTypeResult, PlayResultSimulator, ScoreResult};
 
 // Mean & std regression for standard rushing play
@@ -192,6 +195,10 @@ impl Default for RunResult {
     /// use fbsim_core::game::play::result::run::RunResult;
     /// 
     /// let my_result = RunResult::default();
+    ///
+    /// // The default passes validation
+    /// let json = serde_json::to_string(&my_result).unwrap();
+    /// assert!(serde_json::from_str::<RunResult>(&json).is_ok());
     /// ```
     fn default() -> Self {
         RunResult{
@@ -226,7 +233,9 @@ impl std::fmt::Display for RunResult {
             String::from("")
         };
         let result_str = if self.touchdown {
-            if self.two_point_conversion {
+            if self.two_point_conversion && self.fumble {
+                " Returned for a defensive two point conversion!"
+            } else if self.two_point_conversion {
                 " Two point conversion is GOOD!"
             } else {
                 " TOUCHDOWN!"
@@ -249,12 +258,16 @@ impl std::fmt::Display for RunResult {
 }
 
 impl PlayResult for RunResult {
-    fn next_context(&self, context: &GameContext) -> GameContext {
+    fn next_context(&self, context: &GameContext) -> Result<GameContext, GameError> {
         context.next_context(self)
     }
 
     fn play_duration(&self) -> u32 {
-        self.play_duration
+        if self.two_point_conversion {
+            0
+        } else {
+            self.play_duration
+        }
     }
 
     fn net_yards(&self) -> i32 {
@@ -321,6 +334,25 @@ impl RunResult {
         RunResult::default()
     }
 
+    /// Initialize the result of a quarterback kneel, a one yard loss after
+    /// which the clock keeps running
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::result::run::RunResult;
+    ///
+    /// let my_res = RunResult::kneel();
+    /// assert!(my_res.yards_gained() == -1);
+    /// assert!(!my_res.out_of_bounds());
+    /// ```
+    pub fn kneel() -> RunResult {
+        RunResult {
+            yards_gained: -1,
+            play_duration: KNEEL_DURATION,
+            ..RunResult::default()
+        }
+    }
+
     /// Get a run result's play_duration property
     ///
     /// ### Example
@@ -705,8 +737,8 @@ impl RunResultSimulator {
     }
 
     /// Generates whether a fumble occurred on the play
-    fn fumble(&self, norm_diff_turnovers: f64, rng: &mut impl Rng) -> bool {
-        let p_fumble: f64 = 1_f64.min(0.001_f64.max(P_FUMBLE_INTR + (P_FUMBLE_COEF * norm_diff_turnovers)));
+    fn fumble(&self, norm_diff_turnovers: f64, modifiers: &WeatherModifiers, rng: &mut impl Rng) -> bool {
+        let p_fumble: f64 = 1_f64.min(0.001_f64.max(P_FUMBLE_INTR + (P_FUMBLE_COEF * norm_diff_turnovers)) * modifiers.fumble);
         rng.gen::<f64>() < p_fumble
     }
 
@@ -774,7 +806,7 @@ impl PlayResultSimulator for RunResultSimulator {
 
         // If neither a touchdown or safety occurred, determine if a fumble occurred
         let fumble: bool = if !(touchdown || safety) {
-            self.fumble(norm_diff_turnovers, rng)
+            self.fumble(norm_diff_turnovers, &context.weather().modifiers(), rng)
         } else {
             false
         };
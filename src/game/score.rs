@@ -1,7 +1,6 @@
 #![doc = include_str!("../../docs/game/score.md")]
 pub mod freq;
 
-use lazy_static::lazy_static;
 use rand::Rng;
 use rand_distr::{Normal, Distribution, Bernoulli};
 #[cfg(feature = "rocket_okapi")]
@@ -9,9 +8,9 @@ use rocket_okapi::okapi::schemars;
 #[cfg(feature = "rocket_okapi")]
 use rocket_okapi::okapi::schemars::JsonSchema;
 use serde::{Serialize, Deserialize, Deserializer};
-use statrs::distribution::Categorical;
+use statrs::function::erf::erf;
 
-use crate::game::score::freq::ScoreFrequencyLookup;
+use crate::game::score::freq::{SCORE_FREQUENCIES, DEFAULT_SCORE_FREQUENCY};
 use crate::team::{DEFAULT_TEAM_NAME};
 
 // Home score simulator model weights
@@ -33,14 +32,9 @@ const P_TIE_COEF: f64 = -0.00752297_f64;
 const P_TIE_INTERCEPT: f64 = 0.01055039_f64;
 const P_TIE_BASE: f64 = 0.036_f64;
 
-// Score frequency distribution
-lazy_static!{
-    static ref SCORE_FREQ_LUT: ScoreFrequencyLookup = {
-        let mut tmp_lut = ScoreFrequencyLookup::new();
-        tmp_lut.create();
-        tmp_lut
-    };
-}
+// Maximum number of times a final score is resampled to fall within the
+// simulator's score bounds before it is clamped
+const MAX_SCORE_BOUNDS_RESAMPLES: usize = 100;
 
 /// # `ScoreSimulatable` trait
 ///
@@ -141,6 +135,10 @@ impl Default for FinalScore {
     /// use fbsim_core::game::score::FinalScore;
     ///
     /// let my_score = FinalScore::default();
+    ///
+    /// // The default passes validation
+    /// let json = serde_json::to_string(&my_score).unwrap();
+    /// assert!(serde_json::from_str::<FinalScore>(&json).is_ok());
     /// ```
     fn default() -> Self {
         FinalScore {
@@ -355,17 +353,129 @@ impl FinalScoreBuilder {
     }
 }
 
+/// # `ScoreBounds` struct
+///
+/// Optional sanity bounds on a final score: a maximum score for either team
+/// and a maximum margin of victory.  Each bound is off unless set.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScoreBounds {
+    /// Maximum score for either team, or `None` for no maximum
+    pub max_score: Option<u32>,
+    /// Maximum margin of victory, or `None` for no maximum
+    pub max_margin: Option<u32>
+}
+
+impl ScoreBounds {
+    /// Constructor for the `ScoreBounds` struct, with every bound off
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::score::ScoreBounds;
+    ///
+    /// let my_bounds = ScoreBounds::new();
+    /// assert!(my_bounds.contains(99, 0));
+    /// ```
+    pub fn new() -> ScoreBounds {
+        ScoreBounds::default()
+    }
+
+    /// Set the maximum score for either team
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::score::ScoreBounds;
+    ///
+    /// let my_bounds = ScoreBounds::new().max_score(60);
+    /// assert_eq!(my_bounds.max_score, Some(60));
+    /// ```
+    pub fn max_score(mut self, max_score: u32) -> Self {
+        self.max_score = Some(max_score);
+        self
+    }
+
+    /// Set the maximum margin of victory
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::score::ScoreBounds;
+    ///
+    /// let my_bounds = ScoreBounds::new().max_margin(35);
+    /// assert_eq!(my_bounds.max_margin, Some(35));
+    /// ```
+    pub fn max_margin(mut self, max_margin: u32) -> Self {
+        self.max_margin = Some(max_margin);
+        self
+    }
+
+    /// Whether the given home and away scores are within the bounds
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::score::ScoreBounds;
+    ///
+    /// let my_bounds = ScoreBounds::new().max_score(60).max_margin(35);
+    /// assert!(my_bounds.contains(45, 10));
+    /// assert!(!my_bounds.contains(61, 40));
+    /// assert!(!my_bounds.contains(7, 45));
+    /// ```
+    pub fn contains(&self, home_score: u32, away_score: u32) -> bool {
+        if let Some(max) = self.max_score {
+            if home_score > max || away_score > max {
+                return false;
+            }
+        }
+        if let Some(max) = self.max_margin {
+            if home_score.abs_diff(away_score) > max {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Clamp the given home and away scores to the bounds.  Each score is
+    /// first capped at the maximum score, then the winning score is lowered
+    /// until the margin is within the maximum margin.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::score::ScoreBounds;
+    ///
+    /// let my_bounds = ScoreBounds::new().max_score(60).max_margin(35);
+    /// assert_eq!(my_bounds.clamp_score(72, 14), (49, 14));
+    /// assert_eq!(my_bounds.clamp_score(70, 65), (60, 60));
+    /// ```
+    pub fn clamp_score(&self, home_score: u32, away_score: u32) -> (u32, u32) {
+        let (mut home, mut away) = match self.max_score {
+            Some(max) => (home_score.min(max), away_score.min(max)),
+            None => (home_score, away_score)
+        };
+        if let Some(max) = self.max_margin {
+            home = home.min(away.saturating_add(max));
+            away = away.min(home.saturating_add(max));
+        }
+        (home, away)
+    }
+}
+
 /// # `FinalScoreSimulator` struct
 ///
 /// A `FinalScoreSimulator` generates an american football final score
 /// given the normalized skill differential (in range [0, 1]) of the
 /// home offense and the away defense, and vice versa, the away
-/// offense and the home defense.
+/// offense and the home defense.  Scores may optionally be kept within
+/// `ScoreBounds`.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default)]
-pub struct FinalScoreSimulator;
+pub struct FinalScoreSimulator {
+    bounds: ScoreBounds
+}
 
 impl FinalScoreSimulator {
-    /// Constructor for the `FinalScoreSimulator` struct
+    /// Constructor for the `FinalScoreSimulator` struct, with no score
+    /// bounds
     ///
     /// ### Example
     /// ```
@@ -374,7 +484,44 @@ impl FinalScoreSimulator {
     /// let my_sim = FinalScoreSimulator::new();
     /// ```
     pub fn new() -> FinalScoreSimulator {
-        FinalScoreSimulator{}
+        FinalScoreSimulator::default()
+    }
+
+    /// Constructor for the `FinalScoreSimulator` struct which keeps its
+    /// final scores within the given bounds
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::score::{FinalScoreSimulator, ScoreBounds};
+    ///
+    /// let my_sim = FinalScoreSimulator::with_bounds(ScoreBounds::new().max_score(60));
+    /// assert_eq!(my_sim.bounds().max_score, Some(60));
+    /// ```
+    pub fn with_bounds(bounds: ScoreBounds) -> FinalScoreSimulator {
+        FinalScoreSimulator{
+            bounds
+        }
+    }
+
+    /// Borrow the score bounds of the simulator
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::score::FinalScoreSimulator;
+    ///
+    /// let my_sim = FinalScoreSimulator::new();
+    /// assert!(my_sim.bounds().max_score.is_none());
+    /// ```
+    pub fn bounds(&self) -> &ScoreBounds {
+        &self.bounds
+    }
+
+    /// Gets the normalized skill differentials of the home offense and away
+    /// defense, and of the away offense and home defense
+    fn get_norm_diffs(&self, home_team: &impl ScoreSimulatable, away_team: &impl ScoreSimulatable) -> (f64, f64) {
+        let ha_norm_diff: f64 = (home_team.offense_overall() as i32 - away_team.defense_overall() as i32 + 100_i32) as f64 / 200_f64;
+        let ah_norm_diff: f64 = (away_team.offense_overall() as i32 - home_team.defense_overall() as i32 + 100_i32) as f64 / 200_f64;
+        (ha_norm_diff, ah_norm_diff)
     }
 
     /// Gets the mean score parameter for the score generation
@@ -415,54 +562,6 @@ impl FinalScoreSimulator {
         (p_tie - P_TIE_BASE) / (P_TIE_BASE.powi(2) - P_TIE_BASE)
     }
 
-    /// Generates the away score only
-    fn gen_away_score(&self, norm_diff: f64, rng: &mut impl Rng) -> u32 {
-        // Create and sample a normal distribution for the score
-        let (mean, std): (f64, f64) = self.get_normal_params(norm_diff, false);
-        let away_dist = Normal::new(mean, std).unwrap();
-        let away_score_float = away_dist.sample(rng);
-
-        // Round to nearest integer and return
-        u32::try_from(away_score_float.round() as i32).unwrap_or_default()
-    }
-
-    /// Generates the home score only
-    fn gen_home_score(&self, norm_diff: f64, rng: &mut impl Rng) -> u32 {
-        // Create and sample a normal distribution for the score
-        let (mean, std) = self.get_normal_params(norm_diff, true);
-        let home_dist = Normal::new(mean, std).unwrap();
-        let home_score_float = home_dist.sample(rng);
-
-        // Round to nearest integer, ensure positive and return
-        u32::try_from(home_score_float.round() as i32).unwrap_or_default()
-    }
-
-    /// Generates the home and away scores, returns as a 2-tuple
-    /// in which the first value is the home score, and the second
-    /// value is the away score
-    fn gen_score(&self, ha_norm_diff: f64, ah_norm_diff: f64, rng: &mut impl Rng) -> Result<(u32, u32), String> {
-         // Ensure normalized differentials are in range [0, 1]
-         if !(0.0_f64..=1.0_f64).contains(&ha_norm_diff) {
-            return Err(
-                format!(
-                    "Home offense / away defense normalized skill differential not in range [0, 1]: {}",
-                    ha_norm_diff
-                )
-            )
-        }
-        if !(0.0_f64..=1.0_f64).contains(&ah_norm_diff) {
-           return Err(
-               format!(
-                   "Away offense / home defense normalized skill differential not in range [0, 1]: {}",
-                   ah_norm_diff
-               )
-           )
-        }
-
-        // Generate the home and away scores
-        Ok((self.gen_home_score(ha_norm_diff, rng), self.gen_away_score(ah_norm_diff, rng)))
-    }
-
     /// Filters the final score by score frequency.  The score's nearest
     /// neighbors and their frequency are retrieved to construct a probability
     /// mass function for a categorical distribution.  That distribution is
@@ -474,20 +573,25 @@ impl FinalScoreSimulator {
         }
 
         // Get the nearest neighbors of the score
-        let low = SCORE_FREQ_LUT.frequency(score - 1).unwrap();
-        let mid = SCORE_FREQ_LUT.frequency(score).unwrap();
-        let high = SCORE_FREQ_LUT.frequency(score + 1).unwrap();
-        
-        // Construct a categorical distribution
-        let dist = Categorical::new(&[low as f64, mid as f64, high as f64]).unwrap();
-        let score_adjustment_r: f64 = dist.sample(rng);
-        let score_adjustment = (score_adjustment_r as i32) - 1_i32;
+        let low = score_frequency(score - 1);
+        let mid = score_frequency(score);
+        let high = score_frequency(score + 1);
+
+        // Sample the categorical distribution of the neighbors by inverting
+        // its cumulative mass, without allocating or branching
+        let draw: f64 = rng.gen::<f64>() * (low + mid + high);
+        let score_adjustment = (draw > low) as i32 + (draw > low + mid) as i32 - 1_i32;
         let adj_score = score as i32 + score_adjustment;
         u32::try_from(adj_score).unwrap_or_default()
     }
 
     /// Simulates a game by generating a final score result
     ///
+    /// If the simulator has score bounds, a final score outside of them is
+    /// resampled, so scores within the bounds keep their relative
+    /// likelihood.  If the score is still out of bounds after
+    /// 100 resamples, it is clamped to the bounds.
+    ///
     /// ### Example
     /// ```
     /// use fbsim_core::game::score::{FinalScore, FinalScoreSimulator};
@@ -501,67 +605,518 @@ impl FinalScoreSimulator {
     /// println!("{}", score);
     /// ```
     pub fn sim(&self, home_team: &impl ScoreSimulatable, away_team: &impl ScoreSimulatable, rng: &mut impl Rng) -> Result<FinalScore, String> {
+        // Resample until the final score is within the bounds
+        let mut final_score = self.sim_unbounded(home_team, away_team, rng)?;
+        let mut resamples: usize = 0;
+        while !self.bounds.contains(final_score.home_score(), final_score.away_score()) {
+            if resamples >= MAX_SCORE_BOUNDS_RESAMPLES {
+                // Clamp the final score if resampling fails
+                let (home_score, away_score) = self.bounds.clamp_score(
+                    final_score.home_score(), final_score.away_score()
+                );
+                return FinalScoreBuilder::new()
+                    .home_team(home_team.name())
+                    .home_score(home_score)
+                    .away_team(away_team.name())
+                    .away_score(away_score)
+                    .build();
+            }
+            final_score = self.sim_unbounded(home_team, away_team, rng)?;
+            resamples += 1;
+        }
+        Ok(final_score)
+    }
+
+    /// Get the probabilities of a home win, an away win, and a tie without
+    /// simulating, by approximating each team's score with the normal
+    /// distribution it would be sampled from. Score frequency filtering and
+    /// score bounds are not taken into account.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::score::FinalScoreSimulator;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let strong = FootballTeam::from_overalls("Strong", "STR", 90, 90).unwrap();
+    /// let weak = FootballTeam::from_overalls("Weak", "WEAK", 10, 10).unwrap();
+    /// let sim = FinalScoreSimulator::new();
+    /// let (home, away, tie) = sim.outcome_probabilities(&strong, &weak).unwrap();
+    /// assert!(home > away);
+    /// assert!((home + away + tie - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn outcome_probabilities(&self, home_team: &impl ScoreSimulatable, away_team: &impl ScoreSimulatable) -> Result<(f64, f64, f64), String> {
         // Calculate the normalized skill differentials
-        let ha_norm_diff: f64 = (home_team.offense_overall() as i32 - away_team.defense_overall() as i32 + 100_i32) as f64 / 200_f64;
-        let ah_norm_diff: f64 = (away_team.offense_overall() as i32 - home_team.defense_overall() as i32 + 100_i32) as f64 / 200_f64;
+        let (ha_norm_diff, ah_norm_diff) = self.get_norm_diffs(home_team, away_team);
+        if !(0.0_f64..=1.0_f64).contains(&ha_norm_diff) || !(0.0_f64..=1.0_f64).contains(&ah_norm_diff) {
+            return Err(
+                format!(
+                    "Normalized skill differentials not in range [0, 1]: {}, {}",
+                    ha_norm_diff, ah_norm_diff
+                )
+            )
+        }
 
-        // Generate the final score, return error if error is encountered
-        let (home_score, away_score): (u32, u32) = self.gen_score(ha_norm_diff, ah_norm_diff, rng)?;
+        // The margin of victory is the difference of two normal scores
+        let (home_mean, home_std) = self.get_normal_params(ha_norm_diff, true);
+        let (away_mean, away_std) = self.get_normal_params(ah_norm_diff, false);
+        let margin_std = (home_std.powi(2) + away_std.powi(2)).sqrt();
+        let p_home_margin = 0.5 * (1.0 + erf((home_mean - away_mean) / (margin_std * 2_f64.sqrt())));
 
-        // Filter the final score by score frequency
-        let adj_home_score = self.filter_score(home_score, rng);
-        let adj_away_score = self.filter_score(away_score, rng);
+        // Split the non-tie probability by the margin's distribution
+        let p_tie = self.get_p_tie((ha_norm_diff + ah_norm_diff) / 2_f64).clamp(0.0, 1.0);
+        Ok(((1.0 - p_tie) * p_home_margin, (1.0 - p_tie) * (1.0 - p_home_margin), p_tie))
+    }
 
-        // Instantiate as a FinalScore
-        let final_score: FinalScore = FinalScoreBuilder::new()
+    /// Prepare a matchup for repeated simulation.  The skill differentials,
+    /// score distributions, and tie re-sim probability of the matchup are
+    /// computed once, so each call to `PreparedMatchup::sim` only samples
+    /// them.  Given the same RNG state, a prepared matchup produces exactly
+    /// the same final scores as `FinalScoreSimulator::sim`.
+    ///
+    /// ### Example
+    /// ```
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    /// use fbsim_core::game::score::FinalScoreSimulator;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let home = FootballTeam::from_overalls("Home", "HOME", 70, 60).unwrap();
+    /// let away = FootballTeam::from_overalls("Away", "AWAY", 55, 65).unwrap();
+    /// let sim = FinalScoreSimulator::new();
+    /// let prepared = sim.prepare(&home, &away).unwrap();
+    ///
+    /// let mut rng_a = SmallRng::seed_from_u64(0);
+    /// let mut rng_b = SmallRng::seed_from_u64(0);
+    /// for _ in 0..100 {
+    ///     assert_eq!(
+    ///         prepared.sim(&mut rng_a).unwrap(),
+    ///         sim.sim(&home, &away, &mut rng_b).unwrap()
+    ///     );
+    /// }
+    /// ```
+    pub fn prepare(&self, home_team: &impl ScoreSimulatable, away_team: &impl ScoreSimulatable) -> Result<PreparedMatchup, String> {
+        // Validate the team names and skill differentials
+        FinalScoreRaw {
+            home_team: String::from(home_team.name()),
+            home_score: 0,
+            away_team: String::from(away_team.name()),
+            away_score: 0
+        }.validate()?;
+        let (ha_norm_diff, ah_norm_diff) = self.get_norm_diffs(home_team, away_team);
+        Ok(
+            PreparedMatchup {
+                simulator: *self,
+                home_team: String::from(home_team.name()),
+                away_team: String::from(away_team.name()),
+                model: self.score_model(ha_norm_diff, ah_norm_diff)?
+            }
+        )
+    }
+
+    /// Simulates a game from the skill levels of each side of the ball
+    /// alone, returning the home and away scores.  Nothing is allocated and
+    /// no `FinalScore` is built, which suits Monte Carlo studies of millions
+    /// of games.  Given the same RNG state, the scores are exactly those
+    /// `FinalScoreSimulator::sim` produces for teams with these overalls.
+    ///
+    /// ### Example
+    /// ```
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    /// use fbsim_core::game::score::FinalScoreSimulator;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let home = FootballTeam::from_overalls("Home", "HOME", 70, 60).unwrap();
+    /// let away = FootballTeam::from_overalls("Away", "AWAY", 55, 65).unwrap();
+    /// let sim = FinalScoreSimulator::new();
+    ///
+    /// let mut rng_a = SmallRng::seed_from_u64(0);
+    /// let mut rng_b = SmallRng::seed_from_u64(0);
+    /// let (home_score, away_score) = sim.fast_sim(70, 60, 55, 65, &mut rng_a).unwrap();
+    /// let score = sim.sim(&home, &away, &mut rng_b).unwrap();
+    /// assert_eq!((home_score as u32, away_score as u32), (score.home_score(), score.away_score()));
+    /// ```
+    pub fn fast_sim(&self, home_offense: u32, home_defense: u32, away_offense: u32, away_defense: u32, rng: &mut impl Rng) -> Result<(u16, u16), String> {
+        let ha_norm_diff: f64 = (home_offense as i64 - away_defense as i64 + 100_i64) as f64 / 200_f64;
+        let ah_norm_diff: f64 = (away_offense as i64 - home_defense as i64 + 100_i64) as f64 / 200_f64;
+        let (home_score, away_score) = self.score_model(ha_norm_diff, ah_norm_diff)?.sim(self, rng);
+        Ok(narrow_scores(home_score, away_score))
+    }
+
+    /// Constructs the score distributions and the tie re-sim distribution
+    /// for a pair of normalized skill differentials
+    fn score_model(&self, ha_norm_diff: f64, ah_norm_diff: f64) -> Result<ScoreModel, String> {
+        if !(0.0_f64..=1.0_f64).contains(&ha_norm_diff) {
+            return Err(
+                format!(
+                    "Home offense / away defense normalized skill differential not in range [0, 1]: {}",
+                    ha_norm_diff
+                )
+            )
+        }
+        if !(0.0_f64..=1.0_f64).contains(&ah_norm_diff) {
+            return Err(
+                format!(
+                    "Away offense / home defense normalized skill differential not in range [0, 1]: {}",
+                    ah_norm_diff
+                )
+            )
+        }
+        let (home_mean, home_std) = self.get_normal_params(ha_norm_diff, true);
+        let (away_mean, away_std) = self.get_normal_params(ah_norm_diff, false);
+        let p_res: f64 = self.get_p_resim(self.get_p_tie((ha_norm_diff + ah_norm_diff) / 2_f64));
+        Ok(
+            ScoreModel {
+                home_dist: Normal::new(home_mean, home_std).map_err(|e| e.to_string())?,
+                away_dist: Normal::new(away_mean, away_std).map_err(|e| e.to_string())?,
+                resim_dist: Bernoulli::new(p_res).map_err(|e| e.to_string())?
+            }
+        )
+    }
+
+    /// Simulates a game by generating a final score result without regard
+    /// for the score bounds
+    fn sim_unbounded(&self, home_team: &impl ScoreSimulatable, away_team: &impl ScoreSimulatable, rng: &mut impl Rng) -> Result<FinalScore, String> {
+        // Sample the matchup's score model once, without the score bounds
+        let (ha_norm_diff, ah_norm_diff) = self.get_norm_diffs(home_team, away_team);
+        let (home_score, away_score) = self.score_model(ha_norm_diff, ah_norm_diff)?.sim_unbounded(self, rng);
+        FinalScoreBuilder::new()
             .home_team(home_team.name())
-            .home_score(adj_home_score)
+            .home_score(home_score)
             .away_team(away_team.name())
-            .away_score(adj_away_score)
+            .away_score(away_score)
             .build()
-            .unwrap();
+    }
+}
+
+/// Gets the observed frequency of a score
+fn score_frequency(score: u32) -> f64 {
+    SCORE_FREQUENCIES.get(score as usize).copied().unwrap_or(DEFAULT_SCORE_FREQUENCY) as f64
+}
+
+/// Narrows a pair of scores to `u16`, saturating any score too large to fit
+fn narrow_scores(home_score: u32, away_score: u32) -> (u16, u16) {
+    (
+        u16::try_from(home_score).unwrap_or(u16::MAX),
+        u16::try_from(away_score).unwrap_or(u16::MAX)
+    )
+}
+
+/// Samples a score from a normal distribution, rounding it to the nearest
+/// non-negative integer
+fn sample_score(dist: &Normal<f64>, rng: &mut impl Rng) -> u32 {
+    u32::try_from(dist.sample(rng).round() as i32).unwrap_or_default()
+}
+
+/// # `ScoreModel` struct
+///
+/// A `ScoreModel` holds the score distributions and tie re-sim distribution
+/// of a matchup, sampled by `FinalScoreSimulator::sim`,
+/// `FinalScoreSimulator::fast_sim`, and `PreparedMatchup`
+#[derive(Copy, Clone, Debug)]
+struct ScoreModel {
+    home_dist: Normal<f64>,
+    away_dist: Normal<f64>,
+    resim_dist: Bernoulli
+}
+
+impl ScoreModel {
+    /// Simulates the home and away scores, resampling until they are within
+    /// the simulator's bounds as `FinalScoreSimulator::sim` does
+    fn sim(&self, simulator: &FinalScoreSimulator, rng: &mut impl Rng) -> (u32, u32) {
+        // Resample until the final score is within the bounds
+        let bounds = simulator.bounds();
+        let (mut home_score, mut away_score) = self.sim_unbounded(simulator, rng);
+        let mut resamples: usize = 0;
+        while !bounds.contains(home_score, away_score) {
+            if resamples >= MAX_SCORE_BOUNDS_RESAMPLES {
+                // Clamp the final score if resampling fails
+                return bounds.clamp_score(home_score, away_score);
+            }
+            (home_score, away_score) = self.sim_unbounded(simulator, rng);
+            resamples += 1;
+        }
+        (home_score, away_score)
+    }
 
-        // If not a tie, then return as-is
+    /// Simulates the home and away scores without regard for the score
+    /// bounds
+    fn sim_unbounded(&self, simulator: &FinalScoreSimulator, rng: &mut impl Rng) -> (u32, u32) {
+        // Generate and filter the final score
+        let home_score = sample_score(&self.home_dist, rng);
+        let away_score = sample_score(&self.away_dist, rng);
+        let adj_home_score = simulator.filter_score(home_score, rng);
+        let adj_away_score = simulator.filter_score(away_score, rng);
         if adj_home_score != adj_away_score {
-            return Ok(final_score)
+            return (adj_home_score, adj_away_score)
         }
 
-        // If a tie is achieved after filtering, re-sim based on the skill
-        // differentials and their associated tie probability.  Start by
-        // calculating the average of the two skill differentials
-        let avg_norm_diff: f64 = (ha_norm_diff + ah_norm_diff) / 2_f64;
-
-        // Get the probability of a tie for the average skill differential.
-        // Use it to get the required probability of a re-sim to achieve the
-        // observed tie probability in the end
-        let p_tie: f64 = self.get_p_tie(avg_norm_diff);
-        let p_res: f64 = self.get_p_resim(p_tie);
-
-        // Sample a bernoulli distribution of p_res to determine whether
-        // to re-sim or not
-        let dst_res: Bernoulli = Bernoulli::new(p_res).unwrap();
-        let res: bool = dst_res.sample(rng);
-
-        // Re-sim and re-filter if needed
-        if res {
-            // Generate the final score, return error if error is encountered
-            let (home_score_2, away_score_2): (u32, u32) = self.gen_score(ha_norm_diff, ah_norm_diff, rng)?;
-
-            // Filter the final score by score frequency
-            let adj_home_score_2 = self.filter_score(home_score_2, rng);
-            let adj_away_score_2 = self.filter_score(away_score_2, rng);
-
-            // Instantiate as a FinalScore and return
-            let final_score_2: FinalScore = FinalScoreBuilder::new()
-                .home_team(home_team.name())
-                .home_score(adj_home_score_2)
-                .away_team(away_team.name())
-                .away_score(adj_away_score_2)
-                .build()
-                .unwrap();
-            return Ok(final_score_2)
+        // If a tie is achieved after filtering, re-sim with the probability
+        // required to achieve the model's tie probability
+        if self.resim_dist.sample(rng) {
+            let home_score_2 = sample_score(&self.home_dist, rng);
+            let away_score_2 = sample_score(&self.away_dist, rng);
+            return (
+                simulator.filter_score(home_score_2, rng),
+                simulator.filter_score(away_score_2, rng)
+            )
         }
+        (adj_home_score, adj_away_score)
+    }
+}
 
-        Ok(final_score)
+/// # `PreparedMatchup` struct
+///
+/// A `PreparedMatchup` is a matchup prepared by
+/// `FinalScoreSimulator::prepare` for repeated simulation, such as in a
+/// Monte Carlo projection.  It holds the matchup's score distributions so
+/// that simulating it only samples them.
+#[derive(Clone, Debug)]
+pub struct PreparedMatchup {
+    simulator: FinalScoreSimulator,
+    home_team: String,
+    away_team: String,
+    model: ScoreModel
+}
+
+impl PreparedMatchup {
+    /// Borrow the simulator which prepared the matchup
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::score::{FinalScoreSimulator, ScoreBounds};
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let sim = FinalScoreSimulator::with_bounds(ScoreBounds::new().max_score(60));
+    /// let prepared = sim.prepare(&FootballTeam::new(), &FootballTeam::new()).unwrap();
+    /// assert_eq!(prepared.simulator().bounds().max_score, Some(60));
+    /// ```
+    pub fn simulator(&self) -> &FinalScoreSimulator {
+        &self.simulator
+    }
+
+    /// Simulates the matchup by generating a final score result, exactly as
+    /// `FinalScoreSimulator::sim` would for the same teams and RNG state
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::score::FinalScoreSimulator;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let home = FootballTeam::new();
+    /// let away = FootballTeam::new();
+    /// let prepared = FinalScoreSimulator::new().prepare(&home, &away).unwrap();
+    /// let mut rng = rand::thread_rng();
+    /// for _ in 0..10 {
+    ///     let score = prepared.sim(&mut rng).unwrap();
+    ///     println!("{}", score);
+    /// }
+    /// ```
+    pub fn sim(&self, rng: &mut impl Rng) -> Result<FinalScore, String> {
+        let (home_score, away_score) = self.model.sim(&self.simulator, rng);
+
+        // The team names were validated when the matchup was prepared
+        Ok(
+            FinalScore {
+                home_team: self.home_team.clone(),
+                home_score,
+                away_team: self.away_team.clone(),
+                away_score
+            }
+        )
+    }
+
+    /// Simulates the matchup's home and away scores without building a
+    /// `FinalScore`, as `FinalScoreSimulator::fast_sim` does for the teams'
+    /// overalls.  Nothing is allocated, and the scores are exactly those
+    /// `PreparedMatchup::sim` produces for the same RNG state.
+    ///
+    /// ### Example
+    /// ```
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    /// use fbsim_core::game::score::FinalScoreSimulator;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let home = FootballTeam::from_overalls("Home", "HOME", 70, 60).unwrap();
+    /// let away = FootballTeam::from_overalls("Away", "AWAY", 55, 65).unwrap();
+    /// let prepared = FinalScoreSimulator::new().prepare(&home, &away).unwrap();
+    ///
+    /// let mut rng_a = SmallRng::seed_from_u64(0);
+    /// let mut rng_b = SmallRng::seed_from_u64(0);
+    /// let (home_score, away_score) = prepared.fast_sim(&mut rng_a);
+    /// let score = prepared.sim(&mut rng_b).unwrap();
+    /// assert_eq!((home_score as u32, away_score as u32), (score.home_score(), score.away_score()));
+    /// ```
+    pub fn fast_sim(&self, rng: &mut impl Rng) -> (u16, u16) {
+        let (home_score, away_score) = self.model.sim(&self.simulator, rng);
+        narrow_scores(home_score, away_score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    /// A team with a fixed offense and defense overall
+    struct FixedTeam {
+        offense: u32,
+        defense: u32
+    }
+
+    impl ScoreSimulatable for FixedTeam {
+        fn defense_overall(&self) -> u32 { self.defense }
+        fn offense_overall(&self) -> u32 { self.offense }
+    }
+
+    #[test]
+    fn test_score_bounds_cap() {
+        const SIMS: usize = 100_000;
+        const CAP: u32 = 60;
+        let home = FixedTeam { offense: 100, defense: 0 };
+        let away = FixedTeam { offense: 100, defense: 0 };
+
+        // Histogram the home scores with and without a 60 point cap
+        let bounded = FinalScoreSimulator::with_bounds(ScoreBounds::new().max_score(CAP));
+        let unbounded = FinalScoreSimulator::new();
+        let mut bounded_rng = SmallRng::seed_from_u64(1467);
+        let mut unbounded_rng = SmallRng::seed_from_u64(1468);
+        let mut bounded_freq = vec![0_usize; CAP as usize + 1];
+        let mut unbounded_freq = vec![0_usize; CAP as usize + 1];
+        let mut exceeded = 0_usize;
+        for _ in 0..SIMS {
+            let score = bounded.sim(&home, &away, &mut bounded_rng).unwrap();
+            assert!(score.home_score() <= CAP && score.away_score() <= CAP);
+            bounded_freq[score.home_score() as usize] += 1;
+
+            let score = unbounded.sim(&home, &away, &mut unbounded_rng).unwrap();
+            if !ScoreBounds::new().max_score(CAP).contains(score.home_score(), score.away_score()) {
+                exceeded += 1;
+                continue;
+            }
+            unbounded_freq[score.home_score() as usize] += 1;
+        }
+        assert!(exceeded > 0);
+
+        // Below the cap, the bounded distribution matches the unbounded
+        // distribution conditioned on being in bounds
+        let bounded_total: usize = bounded_freq.iter().sum();
+        let unbounded_total: usize = unbounded_freq.iter().sum();
+        let distance: f64 = bounded_freq.iter().zip(unbounded_freq.iter())
+            .map(|(b, u)| (*b as f64 / bounded_total as f64 - *u as f64 / unbounded_total as f64).abs())
+            .sum::<f64>() / 2.0;
+        assert!(distance < 0.02, "total variation distance {}", distance);
+    }
+
+    #[test]
+    fn test_score_bounds_off_by_default() {
+        // Without bounds the simulator draws exactly as before
+        let home = FixedTeam { offense: 80, defense: 40 };
+        let away = FixedTeam { offense: 60, defense: 70 };
+        let mut rng_a = SmallRng::seed_from_u64(1467);
+        let mut rng_b = SmallRng::seed_from_u64(1467);
+        for _ in 0..1000 {
+            assert_eq!(
+                FinalScoreSimulator::new().sim(&home, &away, &mut rng_a).unwrap(),
+                FinalScoreSimulator::new().sim_unbounded(&home, &away, &mut rng_b).unwrap()
+            );
+        }
+
+        // A margin that can never be met is clamped after resampling
+        let tight = FinalScoreSimulator::with_bounds(ScoreBounds::new().max_score(0).max_margin(0));
+        let score = tight.sim(&home, &away, &mut rng_a).unwrap();
+        assert_eq!((score.home_score(), score.away_score()), (0, 0));
+    }
+
+    #[test]
+    fn test_prepared_matchup_equivalence() {
+        // Across skill levels and score bounds, a prepared matchup draws
+        // exactly the same final scores as the unprepared simulator
+        let overalls = [0_u32, 25, 50, 75, 100];
+        let simulators = [
+            FinalScoreSimulator::new(),
+            FinalScoreSimulator::with_bounds(ScoreBounds::new().max_score(35).max_margin(14)),
+            FinalScoreSimulator::with_bounds(ScoreBounds::new().max_score(0).max_margin(0))
+        ];
+        for simulator in simulators.iter() {
+            for (i, home_offense) in overalls.iter().enumerate() {
+                let home = FixedTeam { offense: *home_offense, defense: overalls[(i + 2) % overalls.len()] };
+                for (j, away_offense) in overalls.iter().enumerate() {
+                    let away = FixedTeam { offense: *away_offense, defense: overalls[(j + 3) % overalls.len()] };
+                    let prepared = simulator.prepare(&home, &away).unwrap();
+                    let mut rng_a = SmallRng::seed_from_u64(1490);
+                    let mut rng_b = SmallRng::seed_from_u64(1490);
+                    for _ in 0..200 {
+                        assert_eq!(
+                            prepared.sim(&mut rng_a).unwrap(),
+                            simulator.sim(&home, &away, &mut rng_b).unwrap()
+                        );
+                    }
+                }
+            }
+        }
+
+        // Invalid team names are rejected when the matchup is prepared
+        struct LongName;
+        impl ScoreSimulatable for LongName {
+            fn name(&self) -> &str { "This team name is much longer than the sixty-four character limit" }
+        }
+        assert!(FinalScoreSimulator::new().prepare(&LongName, &FixedTeam { offense: 50, defense: 50 }).is_err());
+    }
+
+    #[test]
+    fn test_fast_sim_equivalence() {
+        // Across skill levels and score bounds, the fast path draws exactly
+        // the same final scores as the standard simulator
+        let overalls = [0_u32, 25, 50, 75, 100];
+        let simulators = [
+            FinalScoreSimulator::new(),
+            FinalScoreSimulator::with_bounds(ScoreBounds::new().max_score(35).max_margin(14)),
+            FinalScoreSimulator::with_bounds(ScoreBounds::new().max_score(0).max_margin(0))
+        ];
+        for simulator in simulators.iter() {
+            for (i, home_offense) in overalls.iter().enumerate() {
+                let home = FixedTeam { offense: *home_offense, defense: overalls[(i + 2) % overalls.len()] };
+                for (j, away_offense) in overalls.iter().enumerate() {
+                    let away = FixedTeam { offense: *away_offense, defense: overalls[(j + 3) % overalls.len()] };
+                    let prepared = simulator.prepare(&home, &away).unwrap();
+                    let mut rng_a = SmallRng::seed_from_u64(1507);
+                    let mut rng_b = SmallRng::seed_from_u64(1507);
+                    let mut rng_c = SmallRng::seed_from_u64(1507);
+                    for _ in 0..200 {
+                        let (home_score, away_score) = simulator.fast_sim(
+                            home.offense, home.defense, away.offense, away.defense, &mut rng_a
+                        ).unwrap();
+                        let score = simulator.sim(&home, &away, &mut rng_b).unwrap();
+                        assert_eq!((home_score as u32, away_score as u32), (score.home_score(), score.away_score()));
+                        assert_eq!(prepared.fast_sim(&mut rng_c), (home_score, away_score));
+                    }
+                }
+            }
+        }
+
+        // Over many games from the same seed, the score distributions match
+        const SIMS: usize = 100_000;
+        let home = FixedTeam { offense: 65, defense: 45 };
+        let away = FixedTeam { offense: 55, defense: 70 };
+        let mut rng_a = SmallRng::seed_from_u64(1507);
+        let mut rng_b = SmallRng::seed_from_u64(1507);
+        let mut fast_freq = vec![[0_usize; 2]; 128];
+        let mut standard_freq = vec![[0_usize; 2]; 128];
+        for _ in 0..SIMS {
+            let (home_score, away_score) = FinalScoreSimulator::new().fast_sim(65, 45, 55, 70, &mut rng_a).unwrap();
+            fast_freq[home_score as usize][0] += 1;
+            fast_freq[away_score as usize][1] += 1;
+            let score = FinalScoreSimulator::new().sim(&home, &away, &mut rng_b).unwrap();
+            standard_freq[score.home_score() as usize][0] += 1;
+            standard_freq[score.away_score() as usize][1] += 1;
+        }
+        assert_eq!(fast_freq, standard_freq);
+
+        // Skill levels out of range are rejected
+        assert!(FinalScoreSimulator::new().fast_sim(250, 0, 50, 50, &mut rng_a).is_err());
     }
 }
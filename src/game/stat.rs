@@ -777,6 +777,22 @@ impl OffensiveStats {
         self.receiving.increment_fumbles(receiving.fumbles());
         self.receiving.increment_yards(receiving.yards());
     }
+
+    /// Get the number of turnovers committed by the offense, its
+    /// interceptions thrown plus fumbles lost by its rushers and receivers
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::OffensiveStats;
+    ///
+    /// let mut my_stats = OffensiveStats::new();
+    /// my_stats.passing_mut().increment_interceptions(2);
+    /// my_stats.rushing_mut().increment_fumbles(1);
+    /// assert!(my_stats.turnovers() == 3);
+    /// ```
+    pub fn turnovers(&self) -> u32 {
+        self.passing.interceptions() + self.rushing.fumbles() + self.receiving.fumbles()
+    }
 }
 
 impl std::fmt::Display for OffensiveStats {
@@ -799,3 +815,437 @@ impl std::fmt::Display for OffensiveStats {
         f.write_str(&stat_str)
     }
 }
+
+/// # `TeamGameStats` struct
+///
+/// A `TeamGameStats` represents one team's line in a game's box score
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct TeamGameStats {
+    passing_yards: i32,
+    rushing_yards: i32,
+    turnovers: u32,
+    time_of_possession: u32,
+    third_down_attempts: u32,
+    third_down_conversions: u32,
+    sacks: u32,
+    /// Points scored in each period, the 4 quarters followed by any
+    /// overtime periods (default: empty, for box scores archived before
+    /// scoring by period was recorded)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    period_points: Vec<u32>
+}
+
+impl TeamGameStats {
+    /// Constructor for the TeamGameStats struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let my_stats = TeamGameStats::new();
+    /// ```
+    pub fn new() -> TeamGameStats {
+        TeamGameStats::default()
+    }
+
+    /// Get the total yards gained by the team's offense, its passing yards
+    /// plus its rushing yards
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let mut my_stats = TeamGameStats::new();
+    /// my_stats.increment_passing_yards(210);
+    /// my_stats.increment_rushing_yards(95);
+    /// assert!(my_stats.total_yards() == 305);
+    /// ```
+    pub fn total_yards(&self) -> i32 {
+        self.passing_yards + self.rushing_yards
+    }
+
+    /// Get the net passing yards from the TeamGameStats struct, less the
+    /// yards lost to sacks
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let my_stats = TeamGameStats::new();
+    /// let passing_yards = my_stats.passing_yards();
+    /// assert!(passing_yards == 0);
+    /// ```
+    pub fn passing_yards(&self) -> i32 {
+        self.passing_yards
+    }
+
+    /// Increment the passing yards in the TeamGameStats struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let mut my_stats = TeamGameStats::new();
+    /// my_stats.increment_passing_yards(12);
+    /// assert!(my_stats.passing_yards() == 12);
+    /// ```
+    pub fn increment_passing_yards(&mut self, yards: i32) {
+        self.passing_yards += yards;
+    }
+
+    /// Get the rushing yards from the TeamGameStats struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let my_stats = TeamGameStats::new();
+    /// let rushing_yards = my_stats.rushing_yards();
+    /// assert!(rushing_yards == 0);
+    /// ```
+    pub fn rushing_yards(&self) -> i32 {
+        self.rushing_yards
+    }
+
+    /// Increment the rushing yards in the TeamGameStats struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let mut my_stats = TeamGameStats::new();
+    /// my_stats.increment_rushing_yards(7);
+    /// assert!(my_stats.rushing_yards() == 7);
+    /// ```
+    pub fn increment_rushing_yards(&mut self, yards: i32) {
+        self.rushing_yards += yards;
+    }
+
+    /// Get the number of turnovers committed by the team's offense
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let my_stats = TeamGameStats::new();
+    /// let turnovers = my_stats.turnovers();
+    /// assert!(turnovers == 0);
+    /// ```
+    pub fn turnovers(&self) -> u32 {
+        self.turnovers
+    }
+
+    /// Increment the turnovers in the TeamGameStats struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let mut my_stats = TeamGameStats::new();
+    /// my_stats.increment_turnovers(1);
+    /// assert!(my_stats.turnovers() == 1);
+    /// ```
+    pub fn increment_turnovers(&mut self, turnovers: u32) {
+        self.turnovers += turnovers;
+    }
+
+    /// Get the team's time of possession in seconds
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let my_stats = TeamGameStats::new();
+    /// let time_of_possession = my_stats.time_of_possession();
+    /// assert!(time_of_possession == 0);
+    /// ```
+    pub fn time_of_possession(&self) -> u32 {
+        self.time_of_possession
+    }
+
+    /// Increment the time of possession in the TeamGameStats struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let mut my_stats = TeamGameStats::new();
+    /// my_stats.increment_time_of_possession(35);
+    /// assert!(my_stats.time_of_possession() == 35);
+    /// ```
+    pub fn increment_time_of_possession(&mut self, seconds: u32) {
+        self.time_of_possession += seconds;
+    }
+
+    /// Get the number of third downs the team's offense ran a play on
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let my_stats = TeamGameStats::new();
+    /// let third_down_attempts = my_stats.third_down_attempts();
+    /// assert!(third_down_attempts == 0);
+    /// ```
+    pub fn third_down_attempts(&self) -> u32 {
+        self.third_down_attempts
+    }
+
+    /// Increment the third down attempts in the TeamGameStats struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let mut my_stats = TeamGameStats::new();
+    /// my_stats.increment_third_down_attempts(1);
+    /// assert!(my_stats.third_down_attempts() == 1);
+    /// ```
+    pub fn increment_third_down_attempts(&mut self, attempts: u32) {
+        self.third_down_attempts += attempts;
+    }
+
+    /// Get the number of third downs the team's offense converted into a
+    /// first down or touchdown
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let my_stats = TeamGameStats::new();
+    /// let third_down_conversions = my_stats.third_down_conversions();
+    /// assert!(third_down_conversions == 0);
+    /// ```
+    pub fn third_down_conversions(&self) -> u32 {
+        self.third_down_conversions
+    }
+
+    /// Increment the third down conversions in the TeamGameStats struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let mut my_stats = TeamGameStats::new();
+    /// my_stats.increment_third_down_conversions(1);
+    /// assert!(my_stats.third_down_conversions() == 1);
+    /// ```
+    pub fn increment_third_down_conversions(&mut self, conversions: u32) {
+        self.third_down_conversions += conversions;
+    }
+
+    /// Get the number of sacks made by the team's defense
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let my_stats = TeamGameStats::new();
+    /// let sacks = my_stats.sacks();
+    /// assert!(sacks == 0);
+    /// ```
+    pub fn sacks(&self) -> u32 {
+        self.sacks
+    }
+
+    /// Increment the sacks in the TeamGameStats struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let mut my_stats = TeamGameStats::new();
+    /// my_stats.increment_sacks(1);
+    /// assert!(my_stats.sacks() == 1);
+    /// ```
+    pub fn increment_sacks(&mut self, sacks: u32) {
+        self.sacks += sacks;
+    }
+
+    /// Borrow the points scored in each period, the 4 quarters followed by
+    /// any overtime periods
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let my_stats = TeamGameStats::new();
+    /// assert!(my_stats.period_points().is_empty());
+    /// ```
+    pub fn period_points(&self) -> &Vec<u32> {
+        &self.period_points
+    }
+
+    /// Increment the points scored in a period, counting the 1st quarter as
+    /// period 1. Any earlier periods not yet recorded are recorded as
+    /// scoreless.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    ///
+    /// let mut my_stats = TeamGameStats::new();
+    /// my_stats.increment_period_points(2, 7);
+    /// assert_eq!(my_stats.period_points(), &vec![0, 7]);
+    /// ```
+    pub fn increment_period_points(&mut self, period: usize, points: u32) {
+        if period == 0 {
+            return;
+        }
+        if self.period_points.len() < period {
+            self.period_points.resize(period, 0);
+        }
+        self.period_points[period - 1] += points;
+    }
+}
+
+impl std::fmt::Display for TeamGameStats {
+    /// Display a team's box score line as a human readable string
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::TeamGameStats;
+    /// 
+    /// let my_stats = TeamGameStats::new();
+    /// println!("{}", my_stats);
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let stat_str = format!(
+            "{} yards ({} pass, {} rush), {} TO, {}-{} 3rd down, {} sacks, {}:{:02} TOP",
+            self.total_yards(),
+            self.passing_yards,
+            self.rushing_yards,
+            self.turnovers,
+            self.third_down_conversions,
+            self.third_down_attempts,
+            self.sacks,
+            self.time_of_possession / 60,
+            self.time_of_possession % 60
+        );
+        f.write_str(&stat_str)
+    }
+}
+
+/// # `GameStats` struct
+///
+/// A `GameStats` represents the box score of a game, one `TeamGameStats`
+/// for each team
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct GameStats {
+    home: TeamGameStats,
+    away: TeamGameStats
+}
+
+impl GameStats {
+    /// Initialize a new GameStats instance
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::GameStats;
+    ///
+    /// let my_stats = GameStats::new();
+    /// ```
+    pub fn new() -> GameStats {
+        GameStats::default()
+    }
+
+    /// Initialize a new GameStats instance given each team's stats
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::{GameStats, TeamGameStats};
+    ///
+    /// let my_stats = GameStats::from_properties(
+    ///     TeamGameStats::new(),
+    ///     TeamGameStats::new()
+    /// );
+    /// ```
+    pub fn from_properties(home: TeamGameStats, away: TeamGameStats) -> GameStats {
+        GameStats {
+            home,
+            away
+        }
+    }
+
+    /// Borrow the home team's stats
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::GameStats;
+    ///
+    /// let my_stats = GameStats::new();
+    /// let home_stats = my_stats.home();
+    /// assert!(home_stats.total_yards() == 0);
+    /// ```
+    pub fn home(&self) -> &TeamGameStats {
+        &self.home
+    }
+
+    /// Mutably borrow the home team's stats
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::GameStats;
+    ///
+    /// let mut my_stats = GameStats::new();
+    /// my_stats.home_mut().increment_sacks(1);
+    /// assert!(my_stats.home().sacks() == 1);
+    /// ```
+    pub fn home_mut(&mut self) -> &mut TeamGameStats {
+        &mut self.home
+    }
+
+    /// Borrow the away team's stats
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::GameStats;
+    ///
+    /// let my_stats = GameStats::new();
+    /// let away_stats = my_stats.away();
+    /// assert!(away_stats.total_yards() == 0);
+    /// ```
+    pub fn away(&self) -> &TeamGameStats {
+        &self.away
+    }
+
+    /// Mutably borrow the away team's stats
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::GameStats;
+    ///
+    /// let mut my_stats = GameStats::new();
+    /// my_stats.away_mut().increment_sacks(1);
+    /// assert!(my_stats.away().sacks() == 1);
+    /// ```
+    pub fn away_mut(&mut self) -> &mut TeamGameStats {
+        &mut self.away
+    }
+}
+
+impl std::fmt::Display for GameStats {
+    /// Display a box score as a human readable string
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::stat::GameStats;
+    /// 
+    /// let my_stats = GameStats::new();
+    /// println!("{}", my_stats);
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let stat_str = format!(
+            "Home: {}\nAway: {}",
+            &self.home,
+            &self.away
+        );
+        f.write_str(&stat_str)
+    }
+}
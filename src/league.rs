@@ -1,15 +1,40 @@
 #![doc = include_str!("../docs/league.md")]
+pub mod chunk;
+pub mod era;
+pub mod error;
+mod fingerprint;
+pub mod franchise;
+pub mod history;
+pub mod integrity;
 pub mod matchup;
+pub mod provenance;
 pub mod season;
+pub mod skills;
 pub mod team;
+pub mod tier;
+pub mod world;
 
+use crate::league::error::LeagueError;
+use crate::rng::SeededRng;
 use crate::team::FootballTeam;
 use crate::game::play::Game;
 use crate::league::matchup::{LeagueMatchups, LeagueTeamRecord};
+use crate::league::chunk::{join_chunks, split_chunks, ChunkError};
+use crate::league::era::EraSummary;
+use crate::league::franchise::FranchiseSeasonEntry;
+use crate::league::history::{LeagueHeadToHead, LeagueSeasonResult};
+use crate::league::fingerprint::Fingerprinter;
+use crate::league::integrity::{check_league, ValidationIssue};
+use crate::league::provenance::{LeagueForkPoint, LeagueProvenance};
 use crate::league::team::LeagueTeam;
 use crate::league::season::{LeagueSeason, LeagueSeasonScheduleOptions};
+use crate::league::season::annotation::{AnnotationTarget, LeagueSeasonAnnotation};
+use crate::league::season::honors::SeasonHonors;
+use crate::league::season::import::TeamDefinitions;
 use crate::league::season::matchup::{LeagueSeasonMatchup, LeagueSeasonMatchups};
-use crate::league::season::week::{LeagueSeasonWeek};
+use crate::league::season::week::{LeagueSeasonWeek, WeekSimReport};
+use crate::league::season::trade::LeagueSeasonTrade;
+use crate::league::tier::{LeagueTier, LeagueTierExchange, LeagueTierLink};
 
 use std::collections::BTreeMap;
 
@@ -17,44 +42,52 @@ use std::collections::BTreeMap;
 use rocket_okapi::okapi::schemars;
 #[cfg(feature = "rocket_okapi")]
 use rocket_okapi::okapi::schemars::JsonSchema;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand::rngs::SmallRng;
 use serde::{Serialize, Deserialize, Deserializer};
 
 /// # `LeagueRaw` struct
 ///
 /// A `LeagueRaw` represents a league that is freshly deserialized from JSON
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
 pub struct LeagueRaw {
     pub teams: BTreeMap<usize, LeagueTeam>,
     pub current_season: Option<LeagueSeason>,
-    pub seasons: Vec<LeagueSeason>
+    pub seasons: Vec<LeagueSeason>,
+    /// Lineage of the league if it was forked (default: not forked)
+    #[serde(default)]
+    pub provenance: Option<LeagueProvenance>,
+    /// Honors of each past season keyed by year (default: computed from
+    /// the past seasons)
+    #[serde(default)]
+    pub honors: BTreeMap<usize, SeasonHonors>,
+    /// State of the RNG saved with the league (default: none)
+    #[serde(default)]
+    pub rng: Option<SeededRng>,
+    /// Teams exchanged with other tiers, in the order the exchanges were
+    /// applied (default: none)
+    #[serde(default)]
+    pub tier_exchanges: Vec<LeagueTierExchange>
 }
 
 impl LeagueRaw {
-    pub fn validate(&self) -> Result<(), String> {
-        // Ensure the IDs in the current season map to matching league team IDs
-        if let Some(season) = &self.current_season {
-            for (id, team) in season.teams().iter() {
-                if !self.teams.contains_key(id) {
-                    return Err(
-                        format!(
-                            "Season {} contains team {} with nonexistent ID: {}",
-                            season.year(), team.name(), id
-                        )
-                    )
-                }
-            }
+    pub fn validate(&self) -> Result<(), LeagueError> {
+        // Ensure the season years are unique and every season and playoff
+        // team ID maps to a matching team
+        let issues = check_league(&self.teams, &self.seasons, self.current_season.as_ref());
+        if let Some(issue) = issues.into_iter().find(|issue| issue.kind.fatal()) {
+            return Err(issue.to_string().into());
         }
 
-        // Ensure the IDs in all past seasons map to matching league team IDs
-        for season in self.seasons.iter() {
-            for (id, team) in season.teams().iter() {
+        // Ensure every team exchanged with another tier maps to a team
+        for exchange in self.tier_exchanges.iter() {
+            for id in exchange.promoted.iter().chain(exchange.relegated.iter()) {
                 if !self.teams.contains_key(id) {
                     return Err(
                         format!(
-                            "Season {} contains team {} with nonexistent ID: {}",
-                            season.year(), team.name(), id
-                        )
+                            "Tier exchange {} contains nonexistent team ID: {}",
+                            exchange.year, id
+                        ).into()
                     )
                 }
             }
@@ -73,25 +106,42 @@ impl LeagueRaw {
 pub struct League {
     teams: BTreeMap<usize, LeagueTeam>,
     current_season: Option<LeagueSeason>,
-    seasons: Vec<LeagueSeason>
+    seasons: Vec<LeagueSeason>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    provenance: Option<LeagueProvenance>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    honors: BTreeMap<usize, SeasonHonors>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rng: Option<SeededRng>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tier_exchanges: Vec<LeagueTierExchange>
 }
 
 impl TryFrom<LeagueRaw> for League {
-    type Error = String;
+    type Error = LeagueError;
 
-    fn try_from(item: LeagueRaw) -> Result<Self, Self::Error> {
+    fn try_from(mut item: LeagueRaw) -> Result<Self, Self::Error> {
         // Validate the raw league
         match item.validate() {
             Ok(()) => (),
             Err(error) => return Err(error),
         };
 
+        // Compute the honors of past seasons saved without them
+        for season in item.seasons.iter() {
+            item.honors.entry(*season.year()).or_insert_with(|| season.honors());
+        }
+
         // If valid, then convert
         Ok(
             League{
                 teams: item.teams,
                 current_season: item.current_season,
-                seasons: item.seasons
+                seasons: item.seasons,
+                provenance: item.provenance,
+                honors: item.honors,
+                rng: item.rng,
+                tier_exchanges: item.tier_exchanges
             }
         )
     }
@@ -116,12 +166,20 @@ impl Default for League {
     /// use fbsim_core::league::League;
     /// 
     /// let my_league = League::default();
+    ///
+    /// // The default passes validation
+    /// let json = serde_json::to_string(&my_league).unwrap();
+    /// assert!(serde_json::from_str::<League>(&json).is_ok());
     /// ```
     fn default() -> Self {
         League{
             teams: BTreeMap::new(),
             current_season: None,
-            seasons: Vec::new()
+            seasons: Vec::new(),
+            provenance: None,
+            honors: BTreeMap::new(),
+            rng: None,
+            tier_exchanges: Vec::new()
         }
     }
 }
@@ -160,6 +218,25 @@ impl League {
         }
     }
 
+    /// Adds a `LeagueTeam` to a `League` with the given team ID, for leagues
+    /// whose team IDs are assigned externally, such as by a `World`
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    ///
+    /// let mut my_league = League::new();
+    /// assert!(my_league.add_team_with_id(7).is_ok());
+    /// assert!(my_league.add_team_with_id(7).is_err());
+    /// ```
+    pub fn add_team_with_id(&mut self, id: usize) -> Result<(), LeagueError> {
+        if self.teams.contains_key(&id) {
+            return Err(LeagueError::DuplicateTeam { id });
+        }
+        self.teams.insert(id, LeagueTeam::new());
+        Ok(())
+    }
+
     /// Borrows the BTreeMap of teams immutably
     ///
     /// ### Example
@@ -328,6 +405,202 @@ impl League {
         &mut self.current_season
     }
 
+    /// Fork the league into an independent branch, for simulating an
+    /// alternate future without affecting the original
+    ///
+    /// The fork is a deep clone carrying a `LeagueProvenance` which records
+    /// the label, the state of this league at the fork, this league's own
+    /// provenance, and the seed of the fork's RNG. The original league is
+    /// not modified.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    ///
+    /// let mut my_league = League::new();
+    /// my_league.add_team();
+    /// my_league.add_season();
+    ///
+    /// // Fork the league, then fork the fork
+    /// let my_fork = my_league.fork("alternate", 42);
+    /// let my_fork_fork = my_fork.fork("alternate of alternate", 43);
+    /// assert!(my_league.provenance().is_none());
+    /// assert_eq!(my_fork.provenance().unwrap().seed(), 42);
+    /// assert_eq!(
+    ///     my_fork_fork.provenance().unwrap().lineage(),
+    ///     vec!["alternate", "alternate of alternate"]
+    /// );
+    /// ```
+    pub fn fork(&self, label: &str, seed: u64) -> League {
+        let fork_point = LeagueForkPoint {
+            seasons: self.seasons.len(),
+            year: self.current_season.as_ref().map(|s| *s.year()),
+            week: self.current_season.as_ref()
+                .and_then(|s| s.weeks().iter().position(|w| !w.complete()))
+        };
+        let mut fork = self.clone();
+        fork.provenance = Some(
+            LeagueProvenance::new(label, self.provenance.clone(), fork_point, seed)
+        );
+        fork
+    }
+
+    /// Borrow the lineage of the league, or `None` if it was never forked
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    ///
+    /// let my_league = League::new();
+    /// assert!(my_league.provenance().is_none());
+    /// assert_eq!(my_league.fork("alternate", 42).provenance().unwrap().label(), "alternate");
+    /// ```
+    pub fn provenance(&self) -> Option<&LeagueProvenance> {
+        self.provenance.as_ref()
+    }
+
+    /// Draw the next RNG from a forked league's seed for simulating its
+    /// future, or `None` if the league was never forked
+    ///
+    /// Each draw advances the state recorded in the league's provenance, so
+    /// successive draws differ, while forks with the same seed and history
+    /// draw the same RNGs.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    ///
+    /// let mut my_league = League::new();
+    /// assert!(my_league.fork_rng().is_none());
+    ///
+    /// let mut my_fork = my_league.fork("alternate", 42);
+    /// assert!(my_fork.fork_rng().is_some());
+    /// assert_eq!(my_fork.provenance().unwrap().rng_draws(), 1);
+    /// ```
+    pub fn fork_rng(&mut self) -> Option<SmallRng> {
+        self.provenance.as_mut()
+            .map(|p| SmallRng::seed_from_u64(p.next_rng_seed()))
+    }
+
+    /// Borrow the RNG saved with the league, if any
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::rng::SeededRng;
+    ///
+    /// let mut my_league = League::new();
+    /// assert!(my_league.rng().is_none());
+    ///
+    /// my_league.set_rng(Some(SeededRng::from_seed(42)));
+    /// assert_eq!(my_league.rng().unwrap().seed(), 42);
+    /// ```
+    pub fn rng(&self) -> Option<&SeededRng> {
+        self.rng.as_ref()
+    }
+
+    /// Save an RNG with the league, so its state is serialized along with
+    /// the league and simulation can resume deterministically once loaded
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::rng::SeededRng;
+    ///
+    /// let mut my_league = League::new();
+    /// my_league.set_rng(Some(SeededRng::from_seed(42)));
+    ///
+    /// let json = serde_json::to_string(&my_league).unwrap();
+    /// let loaded: League = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(loaded.rng(), my_league.rng());
+    /// ```
+    pub fn set_rng(&mut self, rng: Option<SeededRng>) {
+        self.rng = rng;
+    }
+
+    /// Take the RNG saved with the league, leaving none in its place, so it
+    /// can be passed to the league's simulation methods
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::rng::SeededRng;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let mut my_league = League::new();
+    /// my_league.add_season().unwrap();
+    /// for id in 0..4 {
+    ///     my_league.add_team();
+    ///     my_league.add_season_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// my_league.set_rng(Some(SeededRng::from_seed(42)));
+    ///
+    /// // Simulate with the saved RNG, then save its advanced state
+    /// let mut rng = my_league.take_rng().unwrap();
+    /// my_league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league.sim(&mut rng).unwrap();
+    /// my_league.set_rng(Some(rng));
+    /// assert!(my_league.rng().unwrap().word_pos() > 0);
+    /// ```
+    pub fn take_rng(&mut self) -> Option<SeededRng> {
+        self.rng.take()
+    }
+
+    /// Get a stable 64-bit fingerprint of the league, which changes if and
+    /// only if its serialized content changes. The league's teams and
+    /// provenance are hashed on each call, while each season contributes
+    /// its own cached `LeagueSeason::fingerprint`.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    ///
+    /// let mut my_league = League::new();
+    /// let empty = my_league.fingerprint();
+    /// assert_eq!(my_league.fingerprint(), empty);
+    ///
+    /// my_league.add_team();
+    /// assert_ne!(my_league.fingerprint(), empty);
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        let mut fingerprinter = Fingerprinter::default();
+        fingerprinter.write_value(&self.teams);
+        fingerprinter.write_value(&self.provenance);
+        fingerprinter.write_value(&self.rng);
+        if !self.tier_exchanges.is_empty() {
+            fingerprinter.write_value(&self.tier_exchanges);
+        }
+        match &self.current_season {
+            Some(season) => fingerprinter.write_fingerprint(season.fingerprint()),
+            None => fingerprinter.write_value(&self.current_season)
+        }
+        for season in self.seasons.iter() {
+            fingerprinter.write_fingerprint(season.fingerprint());
+        }
+        fingerprinter.finish()
+    }
+
+    /// Check the invariants spanning the league's seasons: unique and
+    /// increasing season years, season and playoff team IDs which map to
+    /// matching teams, archived seasons which are complete, and title
+    /// playoffs between exactly the teams tied for the best record. Issues
+    /// which would prevent the league from deserializing are also checked
+    /// by its validation.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    ///
+    /// let mut my_league = League::new();
+    /// my_league.add_team();
+    /// my_league.add_season_with_year(2027).unwrap();
+    /// assert!(my_league.integrity_check().is_empty());
+    /// ```
+    pub fn integrity_check(&self) -> Vec<ValidationIssue> {
+        check_league(&self.teams, &self.seasons, self.current_season.as_ref())
+    }
+
     /// Gets the most recent year among the completed seasons
     fn most_recent_year(&self) -> usize {
         let mut most_recent_year = 0_usize;
@@ -342,7 +615,10 @@ impl League {
     }
 
     /// Creates a new `LeagueSeason` and archives the current `LeagueSeason`
-    /// if the current `LeagueSeason` is complete
+    /// if the current `LeagueSeason` is complete. The new season follows
+    /// the year of the current or most recent season. The first season of
+    /// a league takes the current year, which requires the `chrono` feature;
+    /// without it, use `add_season_with_year`.
     ///
     /// ### Example
     /// ```
@@ -355,48 +631,147 @@ impl League {
     /// // Create a new season for the new League
     /// let res = my_league.add_season();
     /// ```
-    pub fn add_season(&mut self) -> Result<(), String> {
-        // Check if the current season exists
-        if let Some(season) = &mut self.current_season {
-            // If so, then check if the season is complete
-            if season.complete() {
-                // If the season is complete, archive and create new season
-                let most_recent_year = season.year();
-                let mut new_season = LeagueSeason::new();
-                let new_year = new_season.year_mut();
-                *new_year = most_recent_year + 1;
-                let old_season = season.clone();
-                *season = new_season;
-                self.seasons.push(old_season);
-                return Ok(());
-            }
-
-            // If the season is not complete, then error
+    pub fn add_season(&mut self) -> Result<(), LeagueError> {
+        let year = match &self.current_season {
+            Some(season) => season.year() + 1,
+            None if !self.seasons.is_empty() => self.most_recent_year() + 1,
+            None => Self::first_season_year()?
+        };
+        self.add_season_with_year(year)
+    }
+
+    /// Borrow the honors of each past season, keyed by year. A season's
+    /// honors are recorded when it is archived.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::season::{LeagueSeasonPlayoffOptions, LeagueSeasonScheduleOptions};
+    ///
+    /// // Simulate a season and playoffs of a 4 team league
+    /// let mut my_league = League::new();
+    /// my_league.add_season_with_year(2026).unwrap();
+    /// for id in 0..4 {
+    ///     my_league.add_team();
+    ///     my_league.add_season_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league.sim(&mut rng).unwrap();
+    /// let season = my_league.current_season_mut().as_mut().unwrap();
+    /// season.generate_playoffs(LeagueSeasonPlayoffOptions::new(), &mut rng).unwrap();
+    /// season.sim_playoffs(&mut rng).unwrap();
+    /// assert!(my_league.honors_history().is_empty());
+    ///
+    /// // Archiving the season records its honors
+    /// my_league.add_season().unwrap();
+    /// let honors = my_league.honors_history().get(&2026).unwrap();
+    /// assert_eq!(honors.league_champion, my_league.season(2026).unwrap().champion());
+    /// ```
+    pub fn honors_history(&self) -> &BTreeMap<usize, SeasonHonors> {
+        &self.honors
+    }
+
+    /// Set the year of the current season. Fails if there is no current
+    /// season, if it has started, or if the year is not later than that of
+    /// every archived season, which includes any year the league already
+    /// contains.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    ///
+    /// let mut my_league = League::new();
+    /// my_league.add_season_with_year(2026).unwrap();
+    /// my_league.set_current_season_year(2030).unwrap();
+    /// assert_eq!(*my_league.current_season().as_ref().unwrap().year(), 2030);
+    /// ```
+    pub fn set_current_season_year(&mut self, year: usize) -> Result<(), LeagueError> {
+        // Ensure the year is later than every archived season
+        if let Some(season) = self.seasons.iter().find(|season| *season.year() == year) {
             return Err(
                 format!(
-                    "Cannot create new season: {}",
-                    "Current season still in progress"
-                )
+                    "Cannot set current season year: league already contains season {}",
+                    season.year()
+                ).into()
             );
         }
+        if let Some(latest) = self.seasons.iter().map(|season| *season.year()).max() {
+            if year < latest {
+                return Err(
+                    format!(
+                        "Cannot set current season year: year {} is not later than season {}",
+                        year, latest
+                    ).into()
+                );
+            }
+        }
 
-        // Create a new league season
-        let mut new_season = LeagueSeason::new();
+        // Set the year, which fails if the season has started
+        match &mut self.current_season {
+            Some(season) => season.set_year(year),
+            None => Err(LeagueError::NoCurrentSeason)
+        }
+    }
 
-        // If the past seasons list is empty then stick with the default year
-        if self.seasons.is_empty() {
-            self.current_season = Some(new_season);
-            return Ok(());
+    /// Creates a new `LeagueSeason` with the given year and archives the
+    /// current `LeagueSeason` if the current `LeagueSeason` is complete. The
+    /// year must be later than that of every existing season.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    ///
+    /// let mut my_league = League::new();
+    /// my_league.add_season_with_year(2030).unwrap();
+    /// assert_eq!(*my_league.current_season().as_ref().unwrap().year(), 2030);
+    /// ```
+    pub fn add_season_with_year(&mut self, year: usize) -> Result<(), LeagueError> {
+        // Ensure the season is later than every existing season
+        let latest = self.current_season.iter()
+            .chain(self.seasons.iter())
+            .map(|season| *season.year())
+            .max();
+        if let Some(latest) = latest {
+            if year <= latest {
+                return Err(
+                    format!(
+                        "Cannot create new season: year {} is not later than season {}",
+                        year, latest
+                    ).into()
+                );
+            }
         }
 
-        // If the past seasons list is nonempty then update the year
-        let most_recent_year = self.most_recent_year();
-        let new_year = new_season.year_mut();
-        *new_year = most_recent_year + 1;
-        self.current_season = Some(new_season);
+        // Archive the current season if it is complete, otherwise error
+        if let Some(season) = &mut self.current_season {
+            if !season.complete() {
+                return Err(LeagueError::SeasonInProgress);
+            }
+            let old_season = std::mem::replace(season, LeagueSeason::with_year(year));
+            self.honors.insert(*old_season.year(), old_season.honors());
+            self.seasons.push(old_season);
+            return Ok(());
+        }
+        self.current_season = Some(LeagueSeason::with_year(year));
         Ok(())
     }
 
+    /// Gets the year of a league's first season, the current year
+    #[cfg(feature = "chrono")]
+    fn first_season_year() -> Result<usize, LeagueError> {
+        Ok(*LeagueSeason::new().year())
+    }
+
+    /// Gets the year of a league's first season, which requires a clock
+    #[cfg(not(feature = "chrono"))]
+    fn first_season_year() -> Result<usize, LeagueError> {
+        Err(String::from(
+            "Cannot create new season: the first season needs a year without the chrono feature, use add_season_with_year"
+        ).into())
+    }
+
     /// Adds a `FootballTeam` to a `LeagueSeason`, and corresponds the
     /// `FootballTeam` to the `LeagueTeam` with the given team ID
     ///
@@ -417,10 +792,10 @@ impl League {
     /// // Add a new team to the new season corresponding to the new team
     /// my_league.add_season_team(0, FootballTeam::new());
     /// ```
-    pub fn add_season_team(&mut self, id: usize, team: FootballTeam) -> Result<(), String> {
+    pub fn add_season_team(&mut self, id: usize, team: FootballTeam) -> Result<(), LeagueError> {
         // Ensure the given team ID exists in the league
         if !self.teams.contains_key(&id) {
-            return Err(format!("No team with ID: {}", id));
+            return Err(LeagueError::TeamNotFound { id });
         }
         
         // Add the team to the current season
@@ -428,10 +803,146 @@ impl League {
         // must have already completed in order to be archived in that list
         match &mut self.current_season {
             Some(ref mut season) => season.add_team(id, team),
-            None => Err("No current season to which to add a new team".to_string()),
+            None => Err(LeagueError::NoCurrentSeason),
+        }
+    }
+
+    /// Import a batch of teams from a JSON document of `TeamDefinitions` into
+    /// the current season, adding a `LeagueTeam` for any team ID the league
+    /// does not have yet. Nothing is added if any team is invalid.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// // Export the teams of a season
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new()).unwrap();
+    /// let mut json: Vec<u8> = Vec::new();
+    /// my_league_season.export_teams(&mut json).unwrap();
+    ///
+    /// // Import them into a new league
+    /// let mut my_league = League::new();
+    /// my_league.add_season().unwrap();
+    /// my_league.import_teams(json.as_slice()).unwrap();
+    /// assert!(my_league.team(0).is_some());
+    /// ```
+    pub fn import_teams(&mut self, reader: impl std::io::Read) -> Result<(), LeagueError> {
+        let season = match &mut self.current_season {
+            Some(ref mut season) => season,
+            None => return Err(LeagueError::NoCurrentSeason),
+        };
+        let definitions: TeamDefinitions = serde_json::from_reader(reader)
+            .map_err(|e| format!("Failed to read team definitions: {}", e))?;
+        let ids: Vec<usize> = definitions.teams.iter().map(|d| d.id).collect();
+        season.import_team_definitions(definitions)?;
+        for id in ids {
+            self.teams.entry(id).or_default();
+        }
+        Ok(())
+    }
+
+    /// Export the teams of the current season as a JSON document of
+    /// `TeamDefinitions`
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::League;
+    ///
+    /// let mut my_league = League::new();
+    /// my_league.add_team();
+    /// my_league.add_season().unwrap();
+    /// my_league.add_season_team(0, FootballTeam::new()).unwrap();
+    ///
+    /// let mut json: Vec<u8> = Vec::new();
+    /// assert!(my_league.export_teams(&mut json).is_ok());
+    /// ```
+    pub fn export_teams(&self, writer: impl std::io::Write) -> Result<(), LeagueError> {
+        match &self.current_season {
+            Some(season) => season.export_teams(writer),
+            None => Err(LeagueError::NoCurrentSeason),
         }
     }
 
+    /// Serialize the league as JSON split into a manifest followed by chunks
+    /// of at most `max_chunk_bytes` bytes each, for hosts which limit the
+    /// size of a single stored string
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    ///
+    /// let mut my_league = League::new();
+    /// my_league.add_team();
+    ///
+    /// let chunks = my_league.save_chunks(64).unwrap();
+    /// assert!(chunks.iter().all(|c| c.len() <= 64));
+    /// assert_eq!(League::load_chunks(&chunks).unwrap(), my_league);
+    /// ```
+    pub fn save_chunks(&self, max_chunk_bytes: usize) -> Result<Vec<String>, ChunkError> {
+        let json = serde_json::to_string(self)
+            .map_err(|e| ChunkError::InvalidPayload(e.to_string()))?;
+        split_chunks(&json, max_chunk_bytes)
+    }
+
+    /// Deserialize a league from the chunks produced by `save_chunks`, given
+    /// in any order
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    ///
+    /// let my_league = League::new();
+    /// let mut chunks = my_league.save_chunks(64).unwrap();
+    ///
+    /// // A chunk whose data was altered fails its checksum
+    /// chunks[1] = chunks[1].replace("teams", "tames");
+    /// assert_eq!(League::load_chunks(&chunks).unwrap_err().code(), "CHUNK_CORRUPT");
+    /// ```
+    pub fn load_chunks(chunks: &[impl AsRef<str>]) -> Result<League, ChunkError> {
+        let json = join_chunks(chunks)?;
+        serde_json::from_str(&json).map_err(|e| ChunkError::InvalidPayload(e.to_string()))
+    }
+
+    /// Serialize the league to compact MessagePack bytes
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    ///
+    /// let mut my_league = League::new();
+    /// my_league.add_team();
+    ///
+    /// let bytes = my_league.to_bytes().unwrap();
+    /// assert!(bytes.len() < serde_json::to_vec(&my_league).unwrap().len());
+    /// ```
+    #[cfg(feature = "binary")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, LeagueError> {
+        crate::binary::to_bytes(self).map_err(LeagueError::from)
+    }
+
+    /// Deserialize a league from the bytes produced by `to_bytes`, validating
+    /// it as when deserializing it from JSON
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    ///
+    /// let mut my_league = League::new();
+    /// my_league.add_team();
+    ///
+    /// let bytes = my_league.to_bytes().unwrap();
+    /// assert_eq!(League::from_bytes(&bytes).unwrap(), my_league);
+    /// assert!(League::from_bytes(&bytes[1..]).is_err());
+    /// ```
+    #[cfg(feature = "binary")]
+    pub fn from_bytes(bytes: &[u8]) -> Result<League, LeagueError> {
+        crate::binary::from_bytes(bytes).map_err(LeagueError::from)
+    }
+
     /// Generate a schedule for the current season
     ///
     /// ### Example
@@ -462,11 +973,11 @@ impl League {
     /// let mut rng = rand::thread_rng();
     /// my_league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
     /// ```
-    pub fn generate_schedule(&mut self, options: LeagueSeasonScheduleOptions, rng: &mut impl Rng) -> Result<(), String> {
+    pub fn generate_schedule(&mut self, options: LeagueSeasonScheduleOptions, rng: &mut impl Rng) -> Result<(), LeagueError> {
         // Generate a schedule for the current season if it exists
         match &mut self.current_season {
             Some(ref mut season) => season.generate_schedule(options, rng), // Return the result
-            None => Err("No current season to simulate".to_string()),
+            None => Err(LeagueError::NoCurrentSeason),
         }
     }
 
@@ -503,11 +1014,11 @@ impl League {
     /// // Simulate the season
     /// my_league.sim(&mut rng);
     /// ```
-    pub fn sim(&mut self, rng: &mut impl Rng) -> Result<(), String> {
+    pub fn sim(&mut self, rng: &mut impl Rng) -> Result<(), LeagueError> {
         // Simulate the current season if it exists, return the result
         match &mut self.current_season {
             Some(ref mut season) => season.sim(rng),
-            None => Err("No current season to simulate".to_string()),
+            None => Err(LeagueError::NoCurrentSeason),
         }
     }
 
@@ -544,11 +1055,11 @@ impl League {
     /// // Simulate the first week of the season
     /// my_league.sim_week(0, &mut rng);
     /// ```
-    pub fn sim_week(&mut self, week: usize, rng: &mut impl Rng) -> Result<(), String> {
+    pub fn sim_week(&mut self, week: usize, rng: &mut impl Rng) -> Result<WeekSimReport, LeagueError> {
         // Simulate a week of the current season if it exists, return the result
         match &mut self.current_season {
             Some(ref mut season) => season.sim_week(week, rng),
-            None => Err("No current season to simulate".to_string()),
+            None => Err(LeagueError::NoCurrentSeason),
         }
     }
 
@@ -585,11 +1096,11 @@ impl League {
     /// // Simulate the first week of the season
     /// my_league.sim_matchup(0, 0, &mut rng);
     /// ```
-    pub fn sim_matchup(&mut self, week: usize, matchup: usize, rng: &mut impl Rng) -> Result<Game, String> {
+    pub fn sim_matchup(&mut self, week: usize, matchup: usize, rng: &mut impl Rng) -> Result<Game, LeagueError> {
         // Simulate a matchup from the current season if it exists, return the result
         match &mut self.current_season {
             Some(ref mut season) => season.sim_matchup(week, matchup, rng),
-            None => Err("No current season to simulate".to_string()),
+            None => Err(LeagueError::NoCurrentSeason),
         }
     }
 
@@ -626,60 +1137,225 @@ impl League {
     /// // Simulate the first week of the season
     /// my_league.sim_play(0, 0, &mut rng);
     /// ```
-    pub fn sim_play(&mut self, week: usize, matchup: usize, rng: &mut impl Rng) -> Result<Option<Game>, String> {
+    pub fn sim_play(&mut self, week: usize, matchup: usize, rng: &mut impl Rng) -> Result<Option<Game>, LeagueError> {
         // Simulate a matchup from the current season if it exists, return the result
         match &mut self.current_season {
             Some(ref mut season) => season.sim_play(week, matchup, rng),
-            None => Err("No current season to simulate".to_string()),
+            None => Err(LeagueError::NoCurrentSeason),
         }
     }
 
-    /// Get all matchups involving a team for a given season
+    /// Attach a note to part of a current or archived season identified by
+    /// its year
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::league::League;
-    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchups;
+    /// use fbsim_core::league::season::annotation::AnnotationTarget;
     ///
-    /// // Instantiate a new League
     /// let mut my_league = League::new();
+    /// my_league.add_season_with_year(2026).unwrap();
+    /// my_league.annotate(2026, AnnotationTarget::Season, "The inaugural season", None).unwrap();
+    /// assert_eq!(my_league.annotations_for(2026, &AnnotationTarget::Season).unwrap().len(), 1);
+    /// assert!(my_league.annotate(2027, AnnotationTarget::Season, "Not yet", None).is_err());
+    /// ```
+    pub fn annotate(&mut self, year: usize, target: AnnotationTarget, text: &str, timestamp: Option<u64>) -> Result<(), LeagueError> {
+        let season = self.current_season.iter_mut()
+            .chain(self.seasons.iter_mut())
+            .find(|season| *season.year() == year)
+            .ok_or(format!("No such season: {}", year))?;
+        season.annotate(target, text, timestamp)
+    }
+
+    /// Borrow the notes attached to part of a current or archived season
+    /// identified by its year
     ///
-    /// // Add 4 new teams to the new league
-    /// my_league.add_team();
-    /// my_league.add_team();
-    /// my_league.add_team();
-    /// my_league.add_team();
-    ///
-    /// // Create a new season for the new League
-    /// let res = my_league.add_season();
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::season::annotation::AnnotationTarget;
     ///
-    /// // Add 4 new season teams to the new season
-    /// my_league.add_season_team(0, FootballTeam::new());
-    /// my_league.add_season_team(1, FootballTeam::new());
-    /// my_league.add_season_team(2, FootballTeam::new());
-    /// my_league.add_season_team(3, FootballTeam::new());
+    /// let mut my_league = League::new();
+    /// my_league.add_season_with_year(2026).unwrap();
+    /// assert!(my_league.annotations_for(2026, &AnnotationTarget::Season).unwrap().is_empty());
+    /// assert!(my_league.annotations_for(2027, &AnnotationTarget::Season).is_err());
+    /// ```
+    pub fn annotations_for(&self, year: usize, target: &AnnotationTarget) -> Result<Vec<&LeagueSeasonAnnotation>, LeagueError> {
+        let season = self.season(year).ok_or(format!("No such season: {}", year))?;
+        Ok(season.annotations_for(target))
+    }
+
+    /// Execute a trade in the current season, identified by its year,
+    /// before the next week of the season to be played
     ///
-    /// // Generate the season schedule
-    /// let mut rng = rand::thread_rng();
-    /// my_league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// The trade is applied with `LeagueSeason::apply_trade()`, so it is
+    /// validated, rejected past the season's trade deadline, and recorded in
+    /// the season's trade ledger, and the teams' skills are updated for
+    /// every later game. Archived seasons may not be traded in, and a trade
+    /// is rejected while the next week is partially simulated or once the
+    /// regular season is complete.
     ///
-    /// // Simulate the season
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::trade::LeagueSeasonTrade;
+    ///
+    /// let mut my_league = League::new();
+    /// my_league.add_season_with_year(2026).unwrap();
+    /// for id in 0..4 {
+    ///     my_league.add_team();
+    ///     my_league.add_season_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    ///
+    /// // Team 0 gains defense from team 2 before week 0
+    /// my_league.execute_trade(2026, LeagueSeasonTrade::new(0, 2, 0, 5)).unwrap();
+    /// assert_eq!(my_league.season(2026).unwrap().trades()[0].week(), 0);
+    /// assert!(my_league.execute_trade(2027, LeagueSeasonTrade::new(0, 2, 0, 5)).is_err());
+    /// ```
+    pub fn execute_trade(&mut self, year: usize, trade: LeagueSeasonTrade) -> Result<(), LeagueError> {
+        if self.seasons.iter().any(|season| *season.year() == year) {
+            return Err(LeagueError::SeasonArchived { year });
+        }
+        let season = match self.current_season.as_mut() {
+            Some(season) if *season.year() == year => season,
+            _ => return Err(LeagueError::SeasonNotFound { year })
+        };
+        if season.weeks().is_empty() {
+            return Err(LeagueError::NoSchedule);
+        }
+        let week = season.weeks().iter()
+            .position(|week| !week.complete())
+            .ok_or(LeagueError::SeasonComplete)?;
+        if season.weeks()[week].started() {
+            return Err(LeagueError::WeekAlreadyStarted { week });
+        }
+        season.apply_trade(week, trade)
+    }
+
+    /// Exchange teams between this league, the upper tier, and a lower
+    /// tier league once the current seasons of both are complete. The
+    /// bottom teams of this league's standings swap with the top teams of
+    /// the lower tier's, with one more swap if the lower tier team wins the
+    /// promotion playoff. Each league gains a `LeagueTeam` for every team
+    /// joining it and records the exchange. Team IDs must identify the same
+    /// franchises in both leagues, as they do in a `World`.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::season::{LeagueSeasonPlayoffOptions, LeagueSeasonScheduleOptions};
+    /// use fbsim_core::league::tier::LeagueTierLink;
+    ///
+    /// // Simulate a season and playoffs of two 4 team tiers
+    /// let mut rng = rand::thread_rng();
+    /// let mut upper = League::new();
+    /// let mut lower = League::new();
+    /// for (league, ids) in [(&mut upper, 0..4), (&mut lower, 4..8)] {
+    ///     league.add_season_with_year(2026).unwrap();
+    ///     for id in ids {
+    ///         league.add_team_with_id(id).unwrap();
+    ///         league.add_season_team(id, FootballTeam::new()).unwrap();
+    ///     }
+    ///     league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    ///     league.sim(&mut rng).unwrap();
+    ///     let season = league.current_season_mut().as_mut().unwrap();
+    ///     season.generate_playoffs(LeagueSeasonPlayoffOptions::new(), &mut rng).unwrap();
+    ///     season.sim_playoffs(&mut rng).unwrap();
+    /// }
+    ///
+    /// // Swap the bottom team of the upper tier with the top of the lower
+    /// let link = LeagueTierLink::new().num_teams(1);
+    /// upper.apply_promotion_relegation(&mut lower, link, &mut rng).unwrap();
+    /// let promoted = upper.tier_exchanges()[0].promoted[0];
+    /// assert!(upper.team(promoted).is_some());
+    /// assert_eq!(lower.tier_exchanges()[0].promoted, vec![promoted]);
+    /// ```
+    pub fn apply_promotion_relegation(&mut self, lower: &mut League, link: LeagueTierLink, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        let (upper_season, lower_season) = match (&self.current_season, &lower.current_season) {
+            (Some(upper_season), Some(lower_season)) => (upper_season, lower_season),
+            _ => return Err(LeagueError::NoCurrentSeason)
+        };
+        let year = *upper_season.year();
+        let applied = |league: &League, tier: LeagueTier| league.tier_exchanges.iter()
+            .any(|exchange| exchange.year == year && exchange.tier == tier);
+        if applied(self, LeagueTier::Upper) || applied(lower, LeagueTier::Lower) {
+            return Err(LeagueError::TierExchangeAlreadyApplied { year });
+        }
+        let exchange = LeagueTierExchange::from_seasons(upper_season, lower_season, &link, rng)?;
+
+        // Each league gains the teams joining it and records the exchange
+        let lower_exchange = LeagueTierExchange { tier: LeagueTier::Lower, ..exchange.clone() };
+        for (league, exchange) in [(&mut *self, exchange), (lower, lower_exchange)] {
+            for id in exchange.arriving().iter() {
+                league.teams.entry(*id).or_insert_with(LeagueTeam::new);
+            }
+            league.tier_exchanges.push(exchange);
+        }
+        Ok(())
+    }
+
+    /// Borrow the teams exchanged with other tiers, in the order the
+    /// exchanges were applied
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    ///
+    /// let my_league = League::new();
+    /// assert!(my_league.tier_exchanges().is_empty());
+    /// ```
+    pub fn tier_exchanges(&self) -> &Vec<LeagueTierExchange> {
+        &self.tier_exchanges
+    }
+
+    /// Get all matchups involving a team for a given season
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchups;
+    ///
+    /// // Instantiate a new League
+    /// let mut my_league = League::new();
+    ///
+    /// // Add 4 new teams to the new league
+    /// my_league.add_team();
+    /// my_league.add_team();
+    /// my_league.add_team();
+    /// my_league.add_team();
+    ///
+    /// // Create a new season for the new League
+    /// let res = my_league.add_season();
+    ///
+    /// // Add 4 new season teams to the new season
+    /// my_league.add_season_team(0, FootballTeam::new());
+    /// my_league.add_season_team(1, FootballTeam::new());
+    /// my_league.add_season_team(2, FootballTeam::new());
+    /// my_league.add_season_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Simulate the season
     /// my_league.sim(&mut rng);
     ///
     /// // Get the season matchups for team 0
     /// let matchups: LeagueSeasonMatchups = my_league.team_season_matchups(0, 2026).unwrap();
     /// ```
-    pub fn team_season_matchups(&self, id: usize, year: usize) -> Result<LeagueSeasonMatchups, String> {
+    pub fn team_season_matchups(&self, id: usize, year: usize) -> Result<LeagueSeasonMatchups, LeagueError> {
         // Ensure the team ID exists
         let _team = match self.team(id) {
             Some(t) => t,
             None => return Err(
-                format!(
-                    "No team found with id {}",
-                    id
-                )
+                LeagueError::TeamNotFound { id }
             )
         };
 
@@ -687,10 +1363,7 @@ impl League {
         let season = match self.season(year) {
             Some(s) => s,
             None => return Err(
-                format!(
-                    "No season found with year {}",
-                    year
-                )
+                LeagueError::SeasonNotFound { year }
             )
         };
 
@@ -714,10 +1387,10 @@ impl League {
     /// assert!(record.is_ok());
     /// assert!(record.unwrap() == LeagueTeamRecord::new());
     /// ```
-    pub fn team_playoff_record(&self, id: usize) -> Result<LeagueTeamRecord, String> {
+    pub fn team_playoff_record(&self, id: usize) -> Result<LeagueTeamRecord, LeagueError> {
         // Ensure the team ID exists in the league
         if !self.teams.contains_key(&id) {
-            return Err(format!("No team with ID: {}", id));
+            return Err(LeagueError::TeamNotFound { id });
         }
         let mut record = LeagueTeamRecord::new();
 
@@ -754,10 +1427,10 @@ impl League {
     /// assert!(appearances.is_ok());
     /// assert!(appearances.unwrap() == 0);
     /// ```
-    pub fn team_championship_appearances(&self, id: usize) -> Result<usize, String> {
+    pub fn team_championship_appearances(&self, id: usize) -> Result<usize, LeagueError> {
         // Ensure the team ID exists in the league
         if !self.teams.contains_key(&id) {
-            return Err(format!("No team with ID: {}", id));
+            return Err(LeagueError::TeamNotFound { id });
         }
         let mut appearances = 0;
 
@@ -792,10 +1465,10 @@ impl League {
     /// assert!(wins.is_ok());
     /// assert!(wins.unwrap() == 0);
     /// ```
-    pub fn team_championship_wins(&self, id: usize) -> Result<usize, String> {
+    pub fn team_championship_wins(&self, id: usize) -> Result<usize, LeagueError> {
         // Ensure the team ID exists in the league
         if !self.teams.contains_key(&id) {
-            return Err(format!("No team with ID: {}", id));
+            return Err(LeagueError::TeamNotFound { id });
         }
         let mut wins = 0;
 
@@ -815,6 +1488,287 @@ impl League {
         Ok(wins)
     }
 
+    /// Borrow every season of the league, archived and current, ordered by
+    /// year
+    fn all_seasons(&self) -> impl Iterator<Item = &LeagueSeason> {
+        self.seasons.iter().chain(self.current_season.iter())
+    }
+
+    /// Get a team's cumulative regular season record across every complete
+    /// season, skipping seasons in which the team did not play
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::season::{LeagueSeasonPlayoffOptions, LeagueSeasonScheduleOptions};
+    ///
+    /// // Simulate a season and playoffs of a 4 team league
+    /// let mut my_league = League::new();
+    /// my_league.add_season_with_year(2026).unwrap();
+    /// for id in 0..4 {
+    ///     my_league.add_team();
+    ///     my_league.add_season_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league.sim(&mut rng).unwrap();
+    /// let season = my_league.current_season_mut().as_mut().unwrap();
+    /// season.generate_playoffs(LeagueSeasonPlayoffOptions::new(), &mut rng).unwrap();
+    /// season.sim_playoffs(&mut rng).unwrap();
+    ///
+    /// // Sum up team 0's regular season games
+    /// let record = my_league.all_time_record(0).unwrap();
+    /// let games = record.wins() + record.losses() + record.ties();
+    /// assert_eq!(games, my_league.season(2026).unwrap().weeks().len());
+    /// ```
+    pub fn all_time_record(&self, id: usize) -> Result<LeagueTeamRecord, LeagueError> {
+        // Ensure the team ID exists in the league
+        if !self.teams.contains_key(&id) {
+            return Err(LeagueError::TeamNotFound { id });
+        }
+        let mut record = LeagueTeamRecord::new();
+        for season in self.all_seasons().filter(|season| season.complete()) {
+            if let Some(result) = LeagueSeasonResult::from_season(season, id)? {
+                record.increment_wins(*result.record.wins());
+                record.increment_losses(*result.record.losses());
+                record.increment_ties(*result.record.ties());
+            }
+        }
+        Ok(record)
+    }
+
+    /// Count the championships a team has won, skipping seasons in which
+    /// the team did not play
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::League;
+    ///
+    /// // A team which has not played a season has won no championships
+    /// let mut my_league = League::new();
+    /// my_league.add_team();
+    /// my_league.add_team();
+    /// my_league.add_season_with_year(2026).unwrap();
+    /// my_league.add_season_team(0, FootballTeam::new()).unwrap();
+    /// assert_eq!(my_league.championships(1).unwrap(), 0);
+    /// assert!(my_league.championships(2).is_err());
+    /// ```
+    pub fn championships(&self, id: usize) -> Result<usize, LeagueError> {
+        // Ensure the team ID exists in the league
+        if !self.teams.contains_key(&id) {
+            return Err(LeagueError::TeamNotFound { id });
+        }
+        Ok(self.all_seasons().filter(|season| season.champion() == Some(id)).count())
+    }
+
+    /// Sum up every completed meeting between two teams across all seasons,
+    /// regular season and playoffs, from the first team's point of view
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// // Simulate a season of a 4 team league
+    /// let mut my_league = League::new();
+    /// my_league.add_season_with_year(2026).unwrap();
+    /// for id in 0..4 {
+    ///     my_league.add_team();
+    ///     my_league.add_season_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league.sim(&mut rng).unwrap();
+    ///
+    /// // Each team's record against the other mirrors the other's
+    /// let series = my_league.head_to_head(0, 1).unwrap();
+    /// let reverse = my_league.head_to_head(1, 0).unwrap();
+    /// assert_eq!(series.games.len(), reverse.games.len());
+    /// assert_eq!(series.record().wins(), reverse.record().losses());
+    /// assert!(my_league.head_to_head(0, 0).is_err());
+    /// ```
+    pub fn head_to_head(&self, team: usize, opponent: usize) -> Result<LeagueHeadToHead, LeagueError> {
+        // Ensure both team IDs exist in the league
+        for id in [team, opponent] {
+            if !self.teams.contains_key(&id) {
+                return Err(LeagueError::TeamNotFound { id });
+            }
+        }
+        if team == opponent {
+            return Err(LeagueError::SameTeam { id: team });
+        }
+        let games = self.all_seasons()
+            .flat_map(|season| LeagueHeadToHead::season_games(season, team, opponent))
+            .collect();
+        Ok(LeagueHeadToHead::new(team, opponent, games))
+    }
+
+    /// Get a team's finish in each season it played, ordered by year,
+    /// including the current season so far
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::history::PlayoffResult;
+    /// use fbsim_core::league::season::{LeagueSeasonPlayoffOptions, LeagueSeasonScheduleOptions};
+    ///
+    /// // Simulate a season and playoffs of a 4 team league
+    /// let mut my_league = League::new();
+    /// my_league.add_season_with_year(2026).unwrap();
+    /// for id in 0..4 {
+    ///     my_league.add_team();
+    ///     my_league.add_season_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league.sim(&mut rng).unwrap();
+    /// let season = my_league.current_season_mut().as_mut().unwrap();
+    /// season.generate_playoffs(LeagueSeasonPlayoffOptions::new(), &mut rng).unwrap();
+    /// season.sim_playoffs(&mut rng).unwrap();
+    ///
+    /// // The champion's finish records its title
+    /// let champion = my_league.season(2026).unwrap().champion().unwrap();
+    /// let results = my_league.season_results(champion).unwrap();
+    /// assert_eq!(results.len(), 1);
+    /// assert_eq!(results[0].playoff_result, Some(PlayoffResult::Champion));
+    /// ```
+    pub fn season_results(&self, id: usize) -> Result<Vec<LeagueSeasonResult>, LeagueError> {
+        // Ensure the team ID exists in the league
+        if !self.teams.contains_key(&id) {
+            return Err(LeagueError::TeamNotFound { id });
+        }
+        let mut results = Vec::new();
+        for season in self.all_seasons() {
+            if let Some(result) = LeagueSeasonResult::from_season(season, id)? {
+                results.push(result);
+            }
+        }
+        results.sort_by_key(|result| result.year);
+        Ok(results)
+    }
+
+    /// Get a team's season-by-season history, ordered by year. Seasons in
+    /// which the team did not play are omitted, and each entry records the
+    /// name the team played under that season.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::season::{LeagueSeasonPlayoffOptions, LeagueSeasonScheduleOptions};
+    ///
+    /// // Simulate a season and playoffs of a 4 team league
+    /// let mut my_league = League::new();
+    /// my_league.add_season_with_year(2026).unwrap();
+    /// for id in 0..4 {
+    ///     my_league.add_team();
+    ///     my_league.add_season_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league.sim(&mut rng).unwrap();
+    /// let season = my_league.current_season_mut().as_mut().unwrap();
+    /// season.generate_playoffs(LeagueSeasonPlayoffOptions::new(), &mut rng).unwrap();
+    /// season.sim_playoffs(&mut rng).unwrap();
+    ///
+    /// // Summarize team 0's history
+    /// let timeline = my_league.franchise_timeline(0).unwrap();
+    /// assert_eq!(timeline.len(), 1);
+    /// assert_eq!(timeline[0].year, 2026);
+    /// assert!(timeline[0].complete);
+    /// ```
+    pub fn franchise_timeline(&self, id: usize) -> Result<Vec<FranchiseSeasonEntry>, LeagueError> {
+        // Ensure the team ID exists in the league
+        if !self.teams.contains_key(&id) {
+            return Err(LeagueError::TeamNotFound { id });
+        }
+        let mut timeline: Vec<FranchiseSeasonEntry> = Vec::new();
+
+        // Archived seasons use the honors recorded when they were archived
+        for season in self.seasons().iter() {
+            let honors = match self.honors.get(season.year()) {
+                Some(honors) => honors.clone(),
+                None => season.honors()
+            };
+            if let Some(entry) = FranchiseSeasonEntry::from_season(season, id, &honors)? {
+                timeline.push(entry);
+            }
+        }
+
+        // The current season's honors are computed from its progress so far
+        if let Some(season) = self.current_season() {
+            if let Some(entry) = FranchiseSeasonEntry::from_season(season, id, &season.honors())? {
+                timeline.push(entry);
+            }
+        }
+        timeline.sort_by_key(|entry| entry.year);
+        Ok(timeline)
+    }
+
+    /// Sum up the complete seasons between two years, inclusive. Years
+    /// without a complete season are skipped, and each team is summed up
+    /// over the seasons it played, so that teams which joined or left the
+    /// league partway through the era can be compared by their per-season
+    /// averages.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::season::{LeagueSeasonPlayoffOptions, LeagueSeasonScheduleOptions};
+    ///
+    /// // Simulate a season and playoffs of a 4 team league
+    /// let mut my_league = League::new();
+    /// my_league.add_season_with_year(2026).unwrap();
+    /// for id in 0..4 {
+    ///     my_league.add_team();
+    ///     my_league.add_season_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league.sim(&mut rng).unwrap();
+    /// let season = my_league.current_season_mut().as_mut().unwrap();
+    /// season.generate_playoffs(LeagueSeasonPlayoffOptions::new(), &mut rng).unwrap();
+    /// season.sim_playoffs(&mut rng).unwrap();
+    ///
+    /// // Sum up the decade
+    /// let summary = my_league.era_summary(2025, 2034).unwrap();
+    /// assert_eq!(summary.years, vec![2026]);
+    /// assert_eq!(summary.teams.len(), 4);
+    /// assert!(summary.leaders.most_championships.is_some());
+    /// ```
+    pub fn era_summary(&self, start_year: usize, end_year: usize) -> Result<EraSummary, LeagueError> {
+        if start_year > end_year {
+            return Err(
+                format!(
+                    "Era start year {} is after its end year {}",
+                    start_year, end_year
+                ).into()
+            );
+        }
+        let in_era = |year: usize| start_year <= year && year <= end_year;
+
+        // Collect each team's complete seasons within the era
+        let mut entries: Vec<(usize, Vec<FranchiseSeasonEntry>)> = Vec::new();
+        for id in self.teams.keys() {
+            let timeline = self.franchise_timeline(*id)?.into_iter()
+                .filter(|entry| entry.complete && in_era(entry.year))
+                .collect();
+            entries.push((*id, timeline));
+        }
+        let mut years: Vec<usize> = self.seasons.iter()
+            .chain(self.current_season.iter())
+            .filter(|season| season.complete() && in_era(*season.year()))
+            .map(|season| *season.year())
+            .collect();
+        years.sort();
+        Ok(EraSummary::from_entries(start_year, end_year, years, entries))
+    }
+
     /// Get all matchups involving a team over all seasons
     ///
     /// ### Example
@@ -853,10 +1807,10 @@ impl League {
     /// let matchups = my_league.team_matchups(0);
     /// assert!(matchups.is_ok());
     /// ```
-    pub fn team_matchups(&self, id: usize) -> Result<LeagueMatchups, String> {
+    pub fn team_matchups(&self, id: usize) -> Result<LeagueMatchups, LeagueError> {
         // Ensure the team ID exists in the league
         if !self.teams.contains_key(&id) {
-            return Err(format!("No team with ID: {}", id));
+            return Err(LeagueError::TeamNotFound { id });
         }
         let mut matchups: BTreeMap<usize, LeagueSeasonMatchups> = BTreeMap::new();
 
@@ -880,3 +1834,991 @@ impl League {
         Ok(LeagueMatchups::new(matchups))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Create a league with a 4 team season whose first week is complete
+    fn league_after_first_week() -> League {
+        let mut rng = SmallRng::seed_from_u64(1466);
+        let mut league = League::new();
+        for _ in 0..4 {
+            league.add_team();
+        }
+        league.add_season_with_year(2026).unwrap();
+        for id in 0..4 {
+            league.add_season_team(id, FootballTeam::new()).unwrap();
+        }
+        league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        league.sim_week(0, &mut rng).unwrap();
+        league
+    }
+
+    #[test]
+    fn test_fork_branches_diverge_by_seed() {
+        let parent = league_after_first_week();
+        let before = parent.clone();
+
+        // Forks record where they were taken from
+        let mut a = parent.fork("a", 1);
+        let mut b = parent.fork("b", 2);
+        let mut c = parent.fork("c", 1);
+        let provenance = a.provenance().unwrap();
+        assert_eq!(provenance.fork_point().seasons, 0);
+        assert_eq!(provenance.fork_point().year, Some(*parent.current_season().as_ref().unwrap().year()));
+        assert_eq!(provenance.fork_point().week, Some(1));
+        assert!(provenance.parent().is_none());
+
+        // Simulate the rest of each branch with its own RNG
+        for fork in [&mut a, &mut b, &mut c] {
+            let mut rng = fork.fork_rng().unwrap();
+            fork.sim(&mut rng).unwrap();
+        }
+
+        // The parent is untouched, and only forks with the same seed match
+        assert_eq!(parent, before);
+        assert_ne!(a.current_season(), parent.current_season());
+        assert_ne!(a.current_season(), b.current_season());
+        assert_eq!(a.current_season(), c.current_season());
+
+        // Successive draws differ, and the draw count survives serialization
+        let first: u64 = a.fork_rng().unwrap().gen();
+        let second: u64 = a.fork_rng().unwrap().gen();
+        assert_ne!(first, second);
+        let json = serde_json::to_string(&a).unwrap();
+        let mut restored: League = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, a);
+        assert_eq!(restored.fork_rng().unwrap().gen::<u64>(), a.fork_rng().unwrap().gen::<u64>());
+
+        // A fork of a fork keeps its lineage
+        let grandchild = b.fork("b2", 3);
+        assert_eq!(grandchild.provenance().unwrap().lineage(), vec!["b", "b2"]);
+        assert_eq!(grandchild.provenance().unwrap().parent(), b.provenance());
+
+        // Leagues that were never forked serialize without a provenance
+        let json = serde_json::to_string(&parent).unwrap();
+        assert!(!json.contains("provenance"));
+    }
+    #[test]
+    fn test_chunked_save_round_trips_multi_season_league() {
+        use crate::league::season::ChampionshipMode;
+
+        let mut rng = SmallRng::seed_from_u64(1477);
+        let mut league = League::new();
+        for _ in 0..6 {
+            league.add_team();
+        }
+        for year in 2026..2029 {
+            league.add_season_with_year(year).unwrap();
+            for id in 0..6 {
+                league.add_season_team(id, FootballTeam::new()).unwrap();
+            }
+            let season = league.current_season_mut().as_mut().unwrap();
+            season.set_championship_mode(ChampionshipMode::BestRecord).unwrap();
+            league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+            league.sim(&mut rng).unwrap();
+            let season = league.current_season_mut().as_mut().unwrap();
+            if season.best_record_teams().len() > 1 {
+                season.generate_title_playoff(&mut rng).unwrap();
+                season.sim_playoffs(&mut rng).unwrap();
+            }
+        }
+        league.add_season().unwrap();
+        assert_eq!(league.seasons().len(), 3);
+        assert_eq!(*league.current_season().as_ref().unwrap().year(), 2029);
+
+        // Every chunk fits, and the chunks are deterministic
+        let max_chunk_bytes = 16 * 1024;
+        let chunks = league.save_chunks(max_chunk_bytes).unwrap();
+        assert!(chunks.len() > 2);
+        assert!(chunks.iter().all(|c| c.len() <= max_chunk_bytes));
+        assert_eq!(chunks, league.save_chunks(max_chunk_bytes).unwrap());
+
+        // The chunks reassemble in any order
+        let mut shuffled = chunks.clone();
+        shuffled.rotate_left(2);
+        assert_eq!(League::load_chunks(&shuffled).unwrap(), league);
+        assert_eq!(League::load_chunks(&chunks).unwrap().fingerprint(), league.fingerprint());
+
+        // Missing, duplicated, and corrupt chunks are each reported
+        let mut missing = chunks.clone();
+        missing.remove(2);
+        assert_eq!(League::load_chunks(&missing), Err(ChunkError::MissingChunk { index: 1 }));
+        let mut duplicated = chunks.clone();
+        duplicated.push(chunks[1].clone());
+        assert_eq!(League::load_chunks(&duplicated), Err(ChunkError::DuplicateChunk { index: 0 }));
+        let mut corrupt = chunks.clone();
+        corrupt[1].pop();
+        assert_eq!(League::load_chunks(&corrupt), Err(ChunkError::CorruptChunk { index: Some(0) }));
+        assert_eq!(League::load_chunks(&chunks[1..]), Err(ChunkError::MissingManifest));
+        assert_eq!(
+            league.save_chunks(16).unwrap_err(),
+            ChunkError::ChunkSizeTooSmall { max_chunk_bytes: 16 }
+        );
+
+        // A manifest declaring the wrong length is reported rather than
+        // trusted, however large the length
+        for length in [String::from("0"), usize::MAX.to_string()] {
+            let mut fields: Vec<String> = chunks[0].split(':').map(String::from).collect();
+            fields[3] = length;
+            let mut wrong_length = chunks.clone();
+            wrong_length[0] = fields.join(":");
+            assert_eq!(League::load_chunks(&wrong_length), Err(ChunkError::PayloadMismatch));
+        }
+        assert_eq!(join_chunks(&["fbsim-chunks:1:0:18446744073709551615:0"]), Err(ChunkError::PayloadMismatch));
+        assert_eq!(
+            join_chunks(&[format!("fbsim-chunks:1:{}:0:0", usize::MAX)]),
+            Err(ChunkError::MissingChunk { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_add_season_with_explicit_years() {
+        use crate::league::season::ChampionshipMode;
+
+        let mut league = League::new();
+        league.add_season_with_year(2031).unwrap();
+        assert_eq!(*league.current_season().as_ref().unwrap().year(), 2031);
+
+        // An incomplete season cannot be archived
+        assert!(league.add_season_with_year(2032).is_err());
+
+        // Complete the current season by crowning the best record
+        let season = league.current_season_mut().as_mut().unwrap();
+        season.set_championship_mode(ChampionshipMode::BestRecord).unwrap();
+        let mut rng = SmallRng::seed_from_u64(1478);
+        for id in 0..4 {
+            season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        season.sim(&mut rng).unwrap();
+        if season.best_record_teams().len() > 1 {
+            season.generate_title_playoff(&mut rng).unwrap();
+            season.sim_playoffs(&mut rng).unwrap();
+        }
+
+        // A new season must be later than every existing season
+        assert!(league.add_season_with_year(2031).is_err());
+        league.add_season_with_year(2035).unwrap();
+        assert_eq!(league.seasons()[0].year(), &2031);
+        assert_eq!(*league.current_season().as_ref().unwrap().year(), 2035);
+    }
+    #[test]
+    fn test_set_current_season_year_guards() {
+        use crate::league::season::ChampionshipMode;
+
+        // Archive a complete 2026 season and start a 2027 season
+        let mut rng = SmallRng::seed_from_u64(1488);
+        let mut league = League::new();
+        league.add_season_with_year(2026).unwrap();
+        for id in 0..4 {
+            league.add_team();
+            league.add_season_team(id, FootballTeam::new()).unwrap();
+        }
+        let season = league.current_season_mut().as_mut().unwrap();
+        season.set_championship_mode(ChampionshipMode::BestRecord).unwrap();
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        season.sim(&mut rng).unwrap();
+        if season.best_record_teams().len() > 1 {
+            season.generate_title_playoff(&mut rng).unwrap();
+            season.sim_playoffs(&mut rng).unwrap();
+        }
+        assert!(league.current_season_mut().as_mut().unwrap().set_year(2020).is_err());
+        league.add_season_with_year(2027).unwrap();
+        for id in 0..4 {
+            league.add_season_team(id, FootballTeam::new()).unwrap();
+        }
+        league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+
+        // The year may not collide with or precede an archived season
+        let error = league.set_current_season_year(2026).unwrap_err().to_string();
+        assert!(error.contains("already contains season 2026"), "{}", error);
+        assert!(league.set_current_season_year(2025).is_err());
+        league.set_current_season_year(2028).unwrap();
+        assert_eq!(*league.current_season().as_ref().unwrap().year(), 2028);
+
+        // The year is fixed once the season has started
+        league.sim_week(0, &mut rng).unwrap();
+        let error = league.set_current_season_year(2029).unwrap_err().to_string();
+        assert!(error.contains("already started"), "{}", error);
+        assert_eq!(*league.current_season().as_ref().unwrap().year(), 2028);
+
+        // A league with a duplicate year is rejected on load
+        let mut value = serde_json::to_value(&league).unwrap();
+        value["current_season"]["year"] = serde_json::json!(2026);
+        let error = serde_json::from_value::<League>(value).unwrap_err();
+        assert!(error.to_string().contains("seasons/2026"), "{}", error);
+        assert!(League::new().set_current_season_year(2026).is_err());
+    }
+
+    #[test]
+    fn test_integrity_check_reports_each_issue() {
+        use crate::league::integrity::ValidationIssueKind;
+        use crate::league::season::ChampionshipMode;
+        use crate::league::season::playoffs::LeagueSeasonPlayoffs;
+
+        // Create a league of 4 teams with a season complete by best record
+        let mut rng = SmallRng::seed_from_u64(1479);
+        let mut league = League::new();
+        for _ in 0..4 {
+            league.add_team();
+        }
+        league.add_season_with_year(2026).unwrap();
+        for id in 0..4 {
+            league.add_season_team(id, FootballTeam::new()).unwrap();
+        }
+        let season = league.current_season_mut().as_mut().unwrap();
+        season.set_championship_mode(ChampionshipMode::BestRecord).unwrap();
+        league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        league.sim(&mut rng).unwrap();
+        let season = league.current_season_mut().as_mut().unwrap();
+        if season.best_record_teams().len() > 1 {
+            season.generate_title_playoff(&mut rng).unwrap();
+            season.sim_playoffs(&mut rng).unwrap();
+        }
+        let complete = league.current_season().clone().unwrap();
+        league.add_season_with_year(2027).unwrap();
+        assert!(league.integrity_check().is_empty());
+
+        // Check a broken league's issues and whether it still deserializes
+        let check = |league: &League, kind: ValidationIssueKind, path: &str| {
+            let issues = league.integrity_check();
+            assert_eq!(issues.len(), 1, "{:?}", issues);
+            assert_eq!(issues[0].kind, kind);
+            assert_eq!(issues[0].path, path);
+            let json = serde_json::to_string(league).unwrap();
+            let result = serde_json::from_str::<League>(&json);
+            assert_eq!(result.is_err(), kind.fatal());
+            if let Err(error) = result {
+                assert!(error.to_string().contains(path));
+            }
+        };
+
+        // Duplicate season years
+        let mut duplicate = league.clone();
+        *duplicate.current_season_mut().as_mut().unwrap().year_mut() = 2026;
+        check(&duplicate, ValidationIssueKind::DuplicateSeasonYear, "seasons/2026");
+
+        // Season years out of order
+        let mut out_of_order = league.clone();
+        *out_of_order.current_season_mut().as_mut().unwrap().year_mut() = 2025;
+        check(&out_of_order, ValidationIssueKind::SeasonOutOfOrder, "seasons/2025");
+
+        // A season team which is not a league team
+        let mut dangling = league.clone();
+        dangling.current_season_mut().as_mut().unwrap().add_team(9, FootballTeam::new()).unwrap();
+        check(&dangling, ValidationIssueKind::DanglingTeam, "seasons/2027/teams/9");
+
+        // A playoff team which is not a season team
+        let mut dangling_playoffs = league.clone();
+        let season = dangling_playoffs.current_season_mut().as_mut().unwrap();
+        season.playoffs_mut().add_team(3, "D", None).unwrap();
+        season.teams_mut().remove(&3);
+        check(&dangling_playoffs, ValidationIssueKind::DanglingPlayoffTeam, "seasons/2027/playoffs/teams/3");
+
+        // An archived season which is incomplete
+        let mut incomplete = league.clone();
+        incomplete.seasons[0] = LeagueSeason::with_year(2026);
+        check(&incomplete, ValidationIssueKind::IncompleteArchivedSeason, "seasons/2026");
+
+        // A title playoff including a team not tied for the best record
+        let mut mismatch = league.clone();
+        let mut season = complete.clone();
+        let tied = season.best_record_teams();
+        let mut playoffs = LeagueSeasonPlayoffs::new();
+        let extra = (0..4).find(|id| !tied.contains(id)).unwrap();
+        for id in tied.iter().chain([extra].iter()) {
+            playoffs.add_team(*id, "T", None).unwrap();
+        }
+        playoffs.gen_next_playoff_round(&mut rng).unwrap();
+        *season.playoffs_mut() = playoffs;
+        season.sim_playoffs(&mut rng).unwrap();
+        mismatch.seasons[0] = season;
+        check(&mismatch, ValidationIssueKind::ChampionshipMismatch, "seasons/2026/playoffs");
+    }
+
+    #[test]
+    fn test_shrinking_league_keeps_past_seasons_valid() {
+        use crate::league::integrity::ValidationIssueKind;
+        use crate::league::season::LeagueSeasonPlayoffOptions;
+
+        // Complete a season of 6 teams, then a season of only 4 of them
+        let mut rng = SmallRng::seed_from_u64(1497);
+        let mut league = League::new();
+        for _ in 0..6 {
+            league.add_team();
+        }
+        for (year, num_teams) in [(2026, 6), (2027, 4)] {
+            league.add_season_with_year(year).unwrap();
+            for id in 0..num_teams {
+                league.add_season_team(id, FootballTeam::new()).unwrap();
+            }
+            league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+            league.sim(&mut rng).unwrap();
+            let season = league.current_season_mut().as_mut().unwrap();
+            season.generate_playoffs(LeagueSeasonPlayoffOptions::new().num_playoff_teams(2), &mut rng).unwrap();
+            season.sim_playoffs(&mut rng).unwrap();
+        }
+        league.add_season_with_year(2028).unwrap();
+        assert!(league.integrity_check().is_empty());
+
+        // The league round trips with seasons of different sizes
+        let json = serde_json::to_string(&league).unwrap();
+        let loaded: League = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.season(2026).unwrap().teams().len(), 6);
+        assert_eq!(loaded.season(2027).unwrap().teams().len(), 4);
+        assert_eq!(loaded, league);
+
+        // Removing the dropped teams from the league only warns about the
+        // archived season which still has them
+        league.teams.remove(&4);
+        league.teams.remove(&5);
+        let issues = league.integrity_check();
+        assert_eq!(issues.len(), 2);
+        assert!(issues.iter().all(|issue| issue.kind == ValidationIssueKind::RetiredTeam));
+        assert_eq!(issues[0].path, "seasons/2026/teams/4");
+        let json = serde_json::to_string(&league).unwrap();
+        let loaded: League = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.season(2026).unwrap().teams().len(), 6);
+    }
+
+    #[test]
+    fn test_honors_history_matches_recomputation() {
+        use crate::league::season::LeagueSeasonPlayoffOptions;
+
+        // Simulate a season with conferences and divisions, then archive it
+        let mut rng = SmallRng::seed_from_u64(1483);
+        let mut league = League::new();
+        league.add_season_with_year(2026).unwrap();
+        league.import_teams(include_str!("../docs/league/season/teams.json").as_bytes()).unwrap();
+        let schedule = LeagueSeasonScheduleOptions::new()
+            .conference_games(2)
+            .cross_conference_games(4);
+        league.generate_schedule(schedule, &mut rng).unwrap();
+        let season = league.current_season_mut().as_mut().unwrap();
+        season.sim_regular_season(&mut rng).unwrap();
+        let options = LeagueSeasonPlayoffOptions::new()
+            .use_conference_brackets(true)
+            .playoff_teams_per_conference(2)
+            .division_winners_guaranteed(true);
+        season.generate_playoffs(options, &mut rng).unwrap();
+        season.sim_playoffs(&mut rng).unwrap();
+        league.add_season().unwrap();
+
+        // The persisted honors match recomputation from the archived season
+        let season = league.season(2026).unwrap();
+        let honors = league.honors_history().get(&2026).unwrap();
+        assert_eq!(*honors, season.honors());
+        assert_eq!(honors.year, 2026);
+        assert_eq!(honors.league_champion, season.champion());
+        assert!(honors.league_champion.is_some());
+        assert_eq!(honors.top_seed, season.standings().first().map(|(id, _)| *id));
+        for (conf_index, conference) in season.conferences().iter().enumerate() {
+            assert_eq!(
+                honors.conference_champion(conf_index),
+                season.conference_regular_season_champion(conf_index).unwrap()
+            );
+            assert_eq!(
+                honors.conference_playoff_champions.get(&conf_index).copied(),
+                season.playoffs().conference_champion(conf_index)
+            );
+            for div_id in 0..conference.divisions().len() {
+                let champion = honors.division_champion(conf_index, div_id);
+                assert!(champion.is_some());
+                assert_eq!(champion, season.division_champion(conf_index, div_id).unwrap());
+            }
+        }
+
+        // The honors survive serialization, and are recomputed for leagues
+        // saved without them
+        let json = serde_json::to_string(&league).unwrap();
+        assert_eq!(serde_json::from_str::<League>(&json).unwrap(), league);
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value.as_object_mut().unwrap().remove("honors");
+        assert_eq!(serde_json::from_value::<League>(value).unwrap(), league);
+    }
+
+    #[test]
+    fn test_franchise_timeline_across_rebrand_and_expansion() {
+        use crate::league::franchise::FranchiseHonor;
+        use crate::league::season::LeagueSeasonPlayoffOptions;
+
+        // Simulate three seasons, renaming team 0 after the first and adding
+        // teams 4 and 5 in the third
+        let mut rng = SmallRng::seed_from_u64(1485);
+        let mut league = League::new();
+        for _ in 0..6 {
+            league.add_team();
+        }
+        for (year, num_teams) in [(2026, 4), (2027, 4), (2028, 6)] {
+            league.add_season_with_year(year).unwrap();
+            for id in 0..num_teams {
+                let name = if id == 0 && year > 2026 { "Renamed Team" } else { "Original Team" };
+                let team = FootballTeam::from_overalls(name, "TM", 50, 50).unwrap();
+                league.add_season_team(id, team).unwrap();
+            }
+            league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+            league.sim(&mut rng).unwrap();
+            let season = league.current_season_mut().as_mut().unwrap();
+            let options = LeagueSeasonPlayoffOptions::new().num_playoff_teams(2);
+            season.generate_playoffs(options, &mut rng).unwrap();
+            season.sim_playoffs(&mut rng).unwrap();
+        }
+
+        // The rebranded team shows the name it used each year
+        let timeline = league.franchise_timeline(0).unwrap();
+        let years: Vec<usize> = timeline.iter().map(|entry| entry.year).collect();
+        assert_eq!(years, vec![2026, 2027, 2028]);
+        assert_eq!(timeline[0].name, "Original Team");
+        assert_eq!(timeline[1].name, "Renamed Team");
+        assert_eq!(timeline[2].name, "Renamed Team");
+
+        // Expansion teams only appear in the seasons they played
+        let timeline = league.franchise_timeline(4).unwrap();
+        assert_eq!(timeline.len(), 1);
+        assert_eq!(timeline[0].year, 2028);
+
+        // Every entry agrees with its season
+        let mut missed_playoffs = 0;
+        for id in 0..6 {
+            for entry in league.franchise_timeline(id).unwrap() {
+                let season = match league.season(entry.year) {
+                    Some(season) => season,
+                    None => league.current_season().as_ref().unwrap()
+                };
+                let (rank, (_, record)) = season.standings().into_iter()
+                    .enumerate()
+                    .find(|(_, (team, _))| *team == id)
+                    .unwrap();
+                assert_eq!(entry.standings_rank, rank + 1);
+                assert_eq!(entry.record, record);
+                assert_eq!(entry.point_differential, entry.points_for as i64 - entry.points_against as i64);
+                assert_eq!(entry.made_playoffs(), season.team_in_playoffs(id).unwrap());
+                assert_eq!(entry.playoff_record.is_some(), entry.made_playoffs());
+                assert_eq!(entry.champion, season.champion() == Some(id));
+                assert_eq!(
+                    entry.honors.contains(&FranchiseHonor::LeagueChampion),
+                    entry.champion
+                );
+                assert!(entry.complete);
+                if !entry.made_playoffs() {
+                    missed_playoffs += 1;
+                    assert!(!entry.championship_appearance);
+                }
+            }
+        }
+        assert!(missed_playoffs > 0);
+
+        // The timeline survives serialization
+        let timeline = league.franchise_timeline(0).unwrap();
+        let json = serde_json::to_string(&timeline).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<FranchiseSeasonEntry>>(&json).unwrap(), timeline);
+
+        // Unknown teams are rejected
+        assert!(league.franchise_timeline(6).is_err());
+    }
+
+    #[test]
+    fn test_era_summary_prorates_expansion_team() {
+        use crate::league::era::ERA_ALL_STAR_TEAMS;
+        use crate::league::season::LeagueSeasonPlayoffOptions;
+
+        // Simulate five seasons with no season in 2028, replacing team 5
+        // with expansion team 6 in 2027, and leave a sixth season in progress
+        let mut rng = SmallRng::seed_from_u64(1504);
+        let mut league = League::new();
+        for _ in 0..7 {
+            league.add_team();
+        }
+        for year in [2025, 2026, 2027, 2029, 2030, 2031] {
+            league.add_season_with_year(year).unwrap();
+            let ids = if year < 2027 { [0, 1, 2, 3, 4, 5] } else { [0, 1, 2, 3, 4, 6] };
+            for id in ids {
+                let team = FootballTeam::from_overalls("Team", "TM", 40 + 10 * id as u32, 60).unwrap();
+                league.add_season_team(id, team).unwrap();
+            }
+            league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+            if year == 2031 {
+                league.sim_week(0, &mut rng).unwrap();
+                continue;
+            }
+            league.sim(&mut rng).unwrap();
+            let season = league.current_season_mut().as_mut().unwrap();
+            let options = LeagueSeasonPlayoffOptions::new().num_playoff_teams(2);
+            season.generate_playoffs(options, &mut rng).unwrap();
+            season.sim_playoffs(&mut rng).unwrap();
+        }
+
+        // Missing and incomplete seasons are skipped
+        let summary = league.era_summary(2024, 2034).unwrap();
+        assert_eq!(summary.years, vec![2025, 2026, 2027, 2029, 2030]);
+        assert_eq!(summary.teams.len(), 7);
+        assert_eq!(summary.team(0).unwrap().seasons, 5);
+        assert_eq!(summary.team(5).unwrap().seasons, 2);
+        assert_eq!(summary.team(6).unwrap().seasons, 3);
+
+        // Each team's totals and averages agree with its timeline
+        let mut championships = 0;
+        for team in summary.teams.iter() {
+            let timeline: Vec<FranchiseSeasonEntry> = league.franchise_timeline(team.team_id).unwrap()
+                .into_iter()
+                .filter(|entry| entry.complete)
+                .collect();
+            assert_eq!(team.seasons, timeline.len());
+            let wins: usize = timeline.iter().map(|entry| *entry.record.wins()).sum();
+            let differential: i64 = timeline.iter().map(|entry| entry.point_differential).sum();
+            assert_eq!(*team.record.wins(), wins);
+            assert_eq!(team.point_differential, differential);
+            assert_eq!(team.wins_per_season, wins as f64 / team.seasons as f64);
+            assert_eq!(team.point_differential_per_season, differential as f64 / team.seasons as f64);
+            championships += team.championships;
+        }
+        assert_eq!(championships, 5);
+
+        // Leaders by totals and by per-season averages
+        for (leaders, per_season) in [(&summary.leaders, false), (&summary.per_season_leaders, true)] {
+            let wins = |id: usize| {
+                let team = summary.team(id).unwrap();
+                if per_season { team.wins_per_season } else { *team.record.wins() as f64 }
+            };
+            assert_eq!(leaders.all_stars.len(), ERA_ALL_STAR_TEAMS);
+            assert!(leaders.all_stars.windows(2).all(|pair| wins(pair[0]) >= wins(pair[1])));
+            let champion = summary.team(leaders.most_championships.unwrap()).unwrap();
+            assert!(summary.teams.iter().all(|team| if per_season {
+                team.championships_per_season <= champion.championships_per_season
+            } else {
+                team.championships <= champion.championships
+            }));
+            let best = summary.team(leaders.best_record.unwrap()).unwrap();
+            assert!(summary.teams.iter().all(|team| team.record.cmp_win_pct(&best.record).is_le()));
+        }
+        assert_eq!(summary.leaders.best_record, summary.per_season_leaders.best_record);
+
+        // A narrower era leaves out the expansion team
+        let summary = league.era_summary(2025, 2026).unwrap();
+        assert_eq!(summary.years, vec![2025, 2026]);
+        assert!(summary.team(6).is_none());
+        assert_eq!(summary.team(0).unwrap().seasons, 2);
+
+        // The summary survives serialization, and inverted ranges fail
+        let json = serde_json::to_string(&summary).unwrap();
+        assert_eq!(serde_json::from_str::<EraSummary>(&json).unwrap(), summary);
+        assert!(league.era_summary(2030, 2025).is_err());
+        assert!(league.era_summary(2000, 2010).unwrap().teams.is_empty());
+    }
+
+    #[test]
+    fn test_annotations_attach_survive_and_dangle() {
+        use crate::league::integrity::ValidationIssueKind;
+        use crate::league::season::LeagueSeasonPlayoffOptions;
+        use crate::league::season::annotation::{AnnotationTarget, ANNOTATION_MAX_LEN};
+        use crate::league::season::dashboard::SeasonDashboardOptions;
+        use crate::league::season::report::SeasonReportOptions;
+
+        // Create a league of 4 teams with a 6 week schedule
+        let mut rng = SmallRng::seed_from_u64(1508);
+        let mut league = League::new();
+        for _ in 0..4 {
+            league.add_team();
+        }
+        league.add_season_with_year(2026).unwrap();
+        for id in 0..4 {
+            league.add_season_team(id, FootballTeam::new()).unwrap();
+        }
+        league.generate_schedule(LeagueSeasonScheduleOptions::new().weeks(6), &mut rng).unwrap();
+
+        // Attach a note at each level of the season
+        league.annotate(2026, AnnotationTarget::Season, "The inaugural season", None).unwrap();
+        league.annotate(2026, AnnotationTarget::Week { week: 5 }, "The snow week", Some(1_790_000_000)).unwrap();
+        let game = AnnotationTarget::Matchup { week: 0, matchup: 1 };
+        league.annotate(2026, game, "Decided by a blown call", None).unwrap();
+        league.annotate(2026, game, "Upheld on review", None).unwrap();
+        league.annotate(2026, AnnotationTarget::Team { team_id: 2 }, "Moved mid-season", None).unwrap();
+        let notes = league.annotations_for(2026, &game).unwrap();
+        assert_eq!(notes.len(), 2);
+        assert_eq!(notes[1].text, "Upheld on review");
+        assert_eq!(league.annotations_for(2026, &AnnotationTarget::Week { week: 5 }).unwrap()[0].timestamp, Some(1_790_000_000));
+
+        // Missing targets and invalid text are rejected
+        assert!(league.annotate(2026, AnnotationTarget::Week { week: 6 }, "Too late", None).is_err());
+        assert!(league.annotate(2026, AnnotationTarget::Matchup { week: 0, matchup: 2 }, "No game", None).is_err());
+        assert!(league.annotate(2026, AnnotationTarget::Team { team_id: 9 }, "No team", None).is_err());
+        let final_round = AnnotationTarget::PlayoffRound { conference: Some(0), round: 0 };
+        assert!(league.annotate(2026, final_round, "Not yet", None).is_err());
+        assert!(league.annotate(2026, AnnotationTarget::Season, " \n", None).is_err());
+        let long = "x".repeat(ANNOTATION_MAX_LEN + 1);
+        assert!(league.annotate(2026, AnnotationTarget::Season, &long, None).is_err());
+        assert!(league.annotate(2030, AnnotationTarget::Season, "No season", None).is_err());
+        assert_eq!(league.current_season().as_ref().unwrap().annotations().len(), 5);
+
+        // Notes survive a shorter schedule, flagged by the integrity check
+        assert!(league.integrity_check().is_empty());
+        league.generate_schedule(LeagueSeasonScheduleOptions::new().weeks(4), &mut rng).unwrap();
+        let issues = league.integrity_check();
+        assert_eq!(issues.len(), 1, "{:?}", issues);
+        assert_eq!(issues[0].kind, ValidationIssueKind::DanglingAnnotation);
+        assert_eq!(issues[0].path, "seasons/2026/annotations/1");
+        assert!(!issues[0].kind.fatal());
+
+        // Notes round-trip through serialization, dangling or not
+        let json = serde_json::to_string(&league).unwrap();
+        let loaded: League = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, league);
+        assert_eq!(loaded.current_season().as_ref().unwrap().annotations().len(), 5);
+        let season = league.current_season_mut().as_mut().unwrap();
+        assert_eq!(season.remove_annotation(1).unwrap().text, "The snow week");
+        assert!(league.integrity_check().is_empty());
+
+        // Playoff rounds can be annotated once generated, archived seasons by
+        // year
+        league.sim(&mut rng).unwrap();
+        let season = league.current_season_mut().as_mut().unwrap();
+        season.generate_playoffs(LeagueSeasonPlayoffOptions::new().num_playoff_teams(2), &mut rng).unwrap();
+        season.sim_playoffs(&mut rng).unwrap();
+        league.add_season_with_year(2027).unwrap();
+        league.annotate(2026, final_round, "Played in overtime", None).unwrap();
+        assert!(league.integrity_check().is_empty());
+
+        // The report and dashboard surface the notes unless turned off
+        let season = league.season(2026).unwrap();
+        let report = season.report_markdown(SeasonReportOptions::new());
+        assert!(report.contains("## Notes"), "{}", report);
+        assert!(report.lines().any(|line| line.starts_with("| Round 1 ") && line.contains("Played in overtime")), "{}", report);
+        assert!(report.contains("2026 Season"), "{}", report);
+        let report = season.report_markdown(SeasonReportOptions::new().annotations(false));
+        assert!(!report.contains("## Notes"));
+        let dashboard = season.dashboard(SeasonDashboardOptions::new()).unwrap();
+        assert_eq!(dashboard.annotations.unwrap(), *season.annotations());
+        let dashboard = season.dashboard(SeasonDashboardOptions::new().annotations(false)).unwrap();
+        assert!(dashboard.annotations.is_none());
+    }
+
+    #[test]
+    fn test_history_spans_seasons_and_late_joiners() {
+        use crate::league::history::{LeagueHeadToHead, LeagueSeasonResult, PlayoffResult};
+        use crate::league::season::LeagueSeasonPlayoffOptions;
+
+        // A 4 team league whose 2027 season adds teams 4 and 5
+        let mut rng = SmallRng::seed_from_u64(1509);
+        let mut league = League::new();
+        for year in [2026, 2027] {
+            league.add_season_with_year(year).unwrap();
+            let num_teams = if year == 2026 { 4 } else { 6 };
+            for id in 0..num_teams {
+                if league.team(id).is_none() {
+                    league.add_team();
+                }
+                let team = FootballTeam::from_overalls("TM", "TM", 45 + 5 * id as u32, 60).unwrap();
+                league.add_season_team(id, team).unwrap();
+            }
+            league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+            league.sim(&mut rng).unwrap();
+            let season = league.current_season_mut().as_mut().unwrap();
+            season.generate_playoffs(LeagueSeasonPlayoffOptions::new().num_playoff_teams(4), &mut rng).unwrap();
+
+            // Before the playoffs are played every playoff team is alive
+            for id in league.current_season().as_ref().unwrap().playoffs().teams().iter() {
+                let results = league.season_results(id).unwrap();
+                assert_eq!(results.last().unwrap().playoff_result, Some(PlayoffResult::InProgress));
+            }
+            let season = league.current_season_mut().as_mut().unwrap();
+            season.sim_playoffs(&mut rng).unwrap();
+        }
+
+        // Teams which joined later have only the seasons they played
+        let results = league.season_results(4).unwrap();
+        assert_eq!(results.iter().map(|r| r.year).collect::<Vec<usize>>(), vec![2027]);
+        assert_eq!(league.season_results(0).unwrap().len(), 2);
+        assert!(league.season_results(6).is_err());
+        assert_eq!(league.all_time_record(4).unwrap(), results[0].record);
+
+        // The all-time record sums each complete season's record
+        for id in 0..6 {
+            let mut expected = LeagueTeamRecord::new();
+            for result in league.season_results(id).unwrap() {
+                assert!(result.complete);
+                expected.increment_wins(*result.record.wins());
+                expected.increment_losses(*result.record.losses());
+                expected.increment_ties(*result.record.ties());
+            }
+            assert_eq!(league.all_time_record(id).unwrap(), expected);
+        }
+
+        // Each season has one champion and one runner-up, and every other
+        // playoff team was eliminated
+        let titles: usize = (0..6).map(|id| league.championships(id).unwrap()).sum();
+        assert_eq!(titles, 2);
+        for year in [2026, 2027] {
+            let season = league.season(year).unwrap();
+            let champion = season.champion().unwrap();
+            assert!(league.championships(champion).unwrap() > 0);
+            let mut finishes = Vec::new();
+            for id in season.teams().keys() {
+                let result = LeagueSeasonResult::from_season(season, *id).unwrap().unwrap();
+                assert_eq!(result.made_playoffs, result.playoff_record.is_some());
+                finishes.push(result.playoff_result);
+            }
+            let count = |finish: Option<PlayoffResult>| finishes.iter().filter(|f| **f == finish).count();
+            assert_eq!(count(Some(PlayoffResult::Champion)), 1);
+            assert_eq!(count(Some(PlayoffResult::RunnerUp)), 1);
+            assert_eq!(count(Some(PlayoffResult::Eliminated)), 2);
+            assert_eq!(count(None), season.teams().len() - 4);
+        }
+
+        // Head-to-head meetings sum the regular season and playoffs of
+        // every season both teams played
+        for (team, opponent) in [(0, 1), (2, 3), (0, 5), (4, 5)] {
+            let series = league.head_to_head(team, opponent).unwrap();
+            let reverse = league.head_to_head(opponent, team).unwrap();
+            assert_eq!(series.games, reverse.games);
+            assert_eq!(series.record().wins(), reverse.record().losses());
+            assert_eq!(series.points_for, reverse.points_against);
+            let mut regular_season = 0;
+            for season in league.seasons().iter().chain(league.current_season().iter()) {
+                if let Ok(season_series) = season.head_to_head(team, opponent) {
+                    regular_season += season_series.games.len();
+                }
+            }
+            let regular_season_games = series.games.iter().filter(|game| !game.playoffs).count();
+            assert_eq!(regular_season_games, regular_season);
+            let record = &series.regular_season;
+            assert_eq!(record.wins() + record.losses() + record.ties(), regular_season);
+            assert!(series.games.windows(2).all(|pair| pair[0].year <= pair[1].year));
+            if team >= 4 || opponent >= 4 {
+                assert!(series.games.iter().all(|game| game.year == 2027));
+            }
+            let json = serde_json::to_string(&series).unwrap();
+            assert_eq!(serde_json::from_str::<LeagueHeadToHead>(&json).unwrap(), series);
+        }
+        assert!(league.head_to_head(0, 0).is_err());
+        assert!(league.head_to_head(0, 6).is_err());
+
+        // Season results round-trip through serialization
+        let results = league.season_results(0).unwrap();
+        let json = serde_json::to_string(&results).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<LeagueSeasonResult>>(&json).unwrap(), results);
+    }
+
+    #[test]
+    fn test_execute_trade_before_next_week() {
+        use crate::game::score::ScoreSimulatable;
+        use crate::league::season::LeagueSeasonPlayoffOptions;
+
+        let mut rng = SmallRng::seed_from_u64(1517);
+        let mut league = League::new();
+        league.add_season_with_year(2026).unwrap();
+        for id in 0..4 {
+            league.add_team();
+            league.add_season_team(id, FootballTeam::new()).unwrap();
+        }
+        league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        league.current_season_mut().as_mut().unwrap().set_trade_deadline(Some(2)).unwrap();
+        league.sim_week(0, &mut rng).unwrap();
+
+        // The trade lands before week 1 and moves skill between the teams
+        league.execute_trade(2026, LeagueSeasonTrade::new(1, 3, 10, -10)).unwrap();
+        let season = league.season(2026).unwrap();
+        assert_eq!(season.trades().len(), 1);
+        assert_eq!(season.trades()[0].week(), 1);
+        assert_eq!(season.team(1).unwrap().offense_overall(), 60);
+        assert_eq!(season.team(3).unwrap().defense_overall(), 60);
+
+        // Trades are rejected while the next week is partially simulated
+        league.current_season_mut().as_mut().unwrap().sim_matchups(1, &[0], false, &mut rng).unwrap();
+        let trade = LeagueSeasonTrade::new(1, 3, 1, 0);
+        assert_eq!(league.execute_trade(2026, trade), Err(LeagueError::WeekAlreadyStarted { week: 1 }));
+
+        // Trades are rejected past the deadline and once the season is over
+        league.sim_week(1, &mut rng).unwrap();
+        assert_eq!(
+            league.execute_trade(2026, trade),
+            Err(LeagueError::TradeDeadlinePassed { week: 2, deadline: 2 })
+        );
+        league.sim(&mut rng).unwrap();
+        assert_eq!(league.execute_trade(2026, trade), Err(LeagueError::SeasonComplete));
+        assert_eq!(league.season(2026).unwrap().trades().len(), 1);
+
+        // Archived seasons may not be traded in
+        let season = league.current_season_mut().as_mut().unwrap();
+        season.generate_playoffs(LeagueSeasonPlayoffOptions::new(), &mut rng).unwrap();
+        season.sim_playoffs(&mut rng).unwrap();
+        league.add_season_with_year(2027).unwrap();
+        assert_eq!(league.execute_trade(2026, trade), Err(LeagueError::SeasonArchived { year: 2026 }));
+        assert_eq!(league.season(2026).unwrap().trades().len(), 1);
+    }
+
+    #[cfg(feature = "binary")]
+    #[test]
+    fn test_binary_round_trips_league() {
+        use crate::league::season::LeagueSeasonPlayoffOptions;
+        use crate::league::season::annotation::AnnotationTarget;
+        use crate::rng::SeededRng;
+
+        // A complete season with conferences, then a season whose playoffs
+        // stop partway through a game
+        let mut rng = SmallRng::seed_from_u64(1515);
+        let mut league = League::new();
+        for year in [2026, 2027] {
+            league.add_season_with_year(year).unwrap();
+            league.import_teams(include_str!("../docs/league/season/teams.json").as_bytes()).unwrap();
+            let schedule = LeagueSeasonScheduleOptions::new()
+                .conference_games(2)
+                .cross_conference_games(4);
+            league.generate_schedule(schedule, &mut rng).unwrap();
+            let season = league.current_season_mut().as_mut().unwrap();
+            season.sim_regular_season(&mut rng).unwrap();
+            let options = LeagueSeasonPlayoffOptions::new()
+                .use_conference_brackets(true)
+                .playoff_teams_per_conference(2);
+            season.generate_playoffs(options, &mut rng).unwrap();
+            if year == 2026 {
+                season.sim_playoffs(&mut rng).unwrap();
+            } else {
+                for _ in 0..5 {
+                    season.sim_playoff_play(0, 0, 0, &mut rng).unwrap();
+                }
+            }
+        }
+        league.annotate(2027, AnnotationTarget::Season, "Saved mid-game", Some(1_800_000_000)).unwrap();
+        let mut seeded = SeededRng::from_seed(1515);
+        let _: u64 = seeded.gen();
+        league.set_rng(Some(seeded));
+        assert!(!league.current_season().as_ref().unwrap().playoffs().complete());
+
+        // The bytes decode to the same league, and are smaller than JSON
+        let bytes = league.to_bytes().unwrap();
+        let json = serde_json::to_vec(&league).unwrap();
+        assert!(bytes.len() < json.len());
+        let decoded = League::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, league);
+        assert_eq!(decoded.to_bytes().unwrap(), bytes);
+
+        // Invalid data is rejected with the same error as from JSON
+        let text = "Saved mid-game";
+        let blank = " ".repeat(text.len());
+        let json_err = serde_json::from_str::<League>(
+            &String::from_utf8(json).unwrap().replace(text, &blank)
+        ).unwrap_err().to_string();
+        assert!(json_err.contains("Annotation text is blank"));
+        let start = bytes.windows(text.len()).position(|w| w == text.as_bytes()).unwrap();
+        let mut corrupted = bytes.clone();
+        corrupted[start..start + text.len()].copy_from_slice(blank.as_bytes());
+        let bytes_err = League::from_bytes(&corrupted).unwrap_err().to_string();
+        assert!(bytes_err.contains("Annotation text is blank"), "{}", bytes_err);
+    }
+
+    #[test]
+    fn test_promotion_relegation_exchanges_teams() {
+        use crate::league::season::LeagueSeasonPlayoffOptions;
+        use crate::league::tier::{LeagueTier, LeagueTierLink, LeagueTierMove};
+
+        // Two complete 6 team tiers sharing one set of team IDs
+        let mut rng = SmallRng::seed_from_u64(1520);
+        let mut upper = League::new();
+        let mut lower = League::new();
+        for (league, ids) in [(&mut upper, 0..6), (&mut lower, 6..12)] {
+            league.add_season_with_year(2026).unwrap();
+            for id in ids {
+                league.add_team_with_id(id).unwrap();
+                league.add_season_team(id, FootballTeam::new()).unwrap();
+            }
+            league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+            league.sim(&mut rng).unwrap();
+        }
+
+        // Both seasons must be complete and large enough
+        let link = LeagueTierLink::new().num_teams(2).promotion_playoff(true);
+        let err = upper.apply_promotion_relegation(&mut lower, link.clone(), &mut rng).unwrap_err().to_string();
+        assert!(err.contains("not complete"), "{}", err);
+        for league in [&mut upper, &mut lower] {
+            let season = league.current_season_mut().as_mut().unwrap();
+            season.generate_playoffs(LeagueSeasonPlayoffOptions::new(), &mut rng).unwrap();
+            season.sim_playoffs(&mut rng).unwrap();
+        }
+        let too_many = LeagueTierLink::new().num_teams(6).promotion_playoff(true);
+        let err = upper.apply_promotion_relegation(&mut lower, too_many, &mut rng).unwrap_err().to_string();
+        assert!(err.contains("7 needed"), "{}", err);
+        let empty = LeagueTierLink::new().num_teams(0);
+        let err = upper.apply_promotion_relegation(&mut lower, empty, &mut rng).unwrap_err();
+        assert_eq!(err, LeagueError::EmptyTierLink);
+        assert!(upper.tier_exchanges().is_empty());
+
+        // The bottom 2 and top 2 swap, and the playoff decides one more
+        let upper_standings: Vec<usize> = upper.season(2026).unwrap().standings().into_iter().map(|(id, _)| id).collect();
+        let lower_standings: Vec<usize> = lower.season(2026).unwrap().standings().into_iter().map(|(id, _)| id).collect();
+        upper.apply_promotion_relegation(&mut lower, link.clone(), &mut rng).unwrap();
+        let exchange = upper.tier_exchanges()[0].clone();
+        assert_eq!(exchange.tier, LeagueTier::Upper);
+        assert_eq!(exchange.promoted[..2], lower_standings[..2]);
+        assert_eq!(exchange.relegated[exchange.relegated.len() - 2..], upper_standings[4..]);
+        let playoff = exchange.playoff.as_ref().unwrap();
+        assert_eq!(*playoff.home_team(), upper_standings[3]);
+        assert_eq!(*playoff.away_team(), lower_standings[2]);
+        assert!(playoff.context().game_over());
+        let upset = playoff.winner() == Some(lower_standings[2]);
+        assert_eq!(exchange.promoted.len(), if upset { 3 } else { 2 });
+        assert_eq!(exchange.relegated.len(), exchange.promoted.len());
+        assert_eq!(exchange.moved(upper_standings[3]).is_some(), upset);
+
+        // Both leagues record the exchange and know the arriving teams
+        assert_eq!(lower.tier_exchanges()[0].tier, LeagueTier::Lower);
+        assert_eq!(lower.tier_exchanges()[0].promoted, exchange.promoted);
+        for id in exchange.promoted.iter() {
+            assert!(upper.team(*id).is_some());
+            assert_eq!(lower.tier_exchanges()[0].moved(*id), Some(LeagueTierMove::Promoted));
+        }
+        for id in exchange.relegated.iter() {
+            assert!(lower.team(*id).is_some());
+        }
+        let err = upper.apply_promotion_relegation(&mut lower, link, &mut rng).unwrap_err();
+        assert_eq!(err, LeagueError::TierExchangeAlreadyApplied { year: 2026 });
+
+        // Promoted teams play the next season in the upper tier
+        upper.add_season_with_year(2027).unwrap();
+        for id in exchange.promoted.iter() {
+            upper.add_season_team(*id, FootballTeam::new()).unwrap();
+        }
+
+        // The exchanges survive serialization and reference known teams
+        let json = serde_json::to_string(&upper).unwrap();
+        let parsed: League = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.tier_exchanges(), upper.tier_exchanges());
+        assert!(!serde_json::to_string(&League::new()).unwrap().contains("tier_exchanges"));
+        let mut raw: LeagueRaw = serde_json::from_str(&json).unwrap();
+        raw.tier_exchanges[0].promoted.push(99);
+        let err = League::try_from(raw).unwrap_err().to_string();
+        assert!(err.contains("nonexistent team ID: 99"), "{}", err);
+    }
+
+    #[test]
+    fn test_league_errors() {
+        let mut rng = SmallRng::seed_from_u64(1529);
+
+        // Errors name the kind of failure so callers can match on them
+        let mut league = League::new();
+        assert_eq!(league.add_season_team(0, FootballTeam::new()), Err(LeagueError::TeamNotFound { id: 0 }));
+        assert_eq!(league.sim(&mut rng), Err(LeagueError::NoCurrentSeason));
+        league.add_season_with_year(2026).unwrap();
+        for id in 0..4 {
+            league.add_team_with_id(id).unwrap();
+            league.add_season_team(id, FootballTeam::new()).unwrap();
+        }
+        assert_eq!(league.add_team_with_id(2), Err(LeagueError::DuplicateTeam { id: 2 }));
+        league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        assert_eq!(league.sim_week(40, &mut rng).unwrap_err(), LeagueError::WeekOutOfRange { week: 40 });
+        league.sim_week(0, &mut rng).unwrap();
+        let error = league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap_err();
+        assert_eq!(error, LeagueError::SeasonAlreadyStarted);
+        assert_eq!(error.code(), "LEAGUE_SEASON_ALREADY_STARTED");
+
+        // Each error converts to its description for string-based callers
+        let message: String = error.into();
+        assert_eq!(message, "Season has already started");
+
+        // Validation errors still read clearly when deserializing
+        let raw = LeagueRaw { current_season: league.current_season().clone(), ..Default::default() };
+        let error = serde_json::from_value::<League>(serde_json::to_value(&raw).unwrap()).unwrap_err();
+        assert!(error.to_string().contains("nonexistent"), "{}", error);
+    }
+}
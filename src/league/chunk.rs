@@ -0,0 +1,230 @@
+#![doc = include_str!("../../docs/league/chunk.md")]
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::Write;
+
+use crate::league::fingerprint::Fingerprinter;
+
+/// Prefix identifying the manifest chunk and the framing version
+const MANIFEST_PREFIX: &str = "fbsim-chunks:1:";
+
+/// Smallest maximum chunk size accepted, leaving room for each chunk's
+/// header alongside its data
+pub const MIN_CHUNK_BYTES: usize = 64;
+
+/// # `ChunkError` enum
+///
+/// An error splitting a payload into chunks or reassembling it, each with a
+/// stable code for hosts to match on.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ChunkError {
+    /// The maximum chunk size is too small to hold a chunk header
+    ChunkSizeTooSmall { max_chunk_bytes: usize },
+    /// No chunk is the manifest
+    MissingManifest,
+    /// More than one chunk is a manifest
+    DuplicateManifest,
+    /// The manifest could not be parsed
+    InvalidManifest,
+    /// A data chunk listed by the manifest is absent
+    MissingChunk { index: usize },
+    /// A data chunk appears more than once
+    DuplicateChunk { index: usize },
+    /// A data chunk's header could not be parsed, or its data does not
+    /// match its checksum
+    CorruptChunk { index: Option<usize> },
+    /// A data chunk's index is beyond the number listed by the manifest
+    UnexpectedChunk { index: usize },
+    /// The reassembled payload does not match the manifest's length or
+    /// checksum
+    PayloadMismatch,
+    /// The payload could not be serialized or deserialized
+    InvalidPayload(String)
+}
+
+impl ChunkError {
+    /// Get the stable code of the error
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::chunk::ChunkError;
+    ///
+    /// let my_error = ChunkError::MissingChunk { index: 2 };
+    /// assert_eq!(my_error.code(), "CHUNK_MISSING");
+    /// ```
+    pub fn code(&self) -> &'static str {
+        match self {
+            ChunkError::ChunkSizeTooSmall { .. } => "CHUNK_SIZE_TOO_SMALL",
+            ChunkError::MissingManifest => "CHUNK_MANIFEST_MISSING",
+            ChunkError::DuplicateManifest => "CHUNK_MANIFEST_DUPLICATE",
+            ChunkError::InvalidManifest => "CHUNK_MANIFEST_INVALID",
+            ChunkError::MissingChunk { .. } => "CHUNK_MISSING",
+            ChunkError::DuplicateChunk { .. } => "CHUNK_DUPLICATE",
+            ChunkError::CorruptChunk { .. } => "CHUNK_CORRUPT",
+            ChunkError::UnexpectedChunk { .. } => "CHUNK_UNEXPECTED",
+            ChunkError::PayloadMismatch => "CHUNK_PAYLOAD_MISMATCH",
+            ChunkError::InvalidPayload(_) => "CHUNK_PAYLOAD_INVALID"
+        }
+    }
+}
+
+impl fmt::Display for ChunkError {
+    /// Format a `ChunkError` as its code followed by a description
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::chunk::ChunkError;
+    ///
+    /// let my_error = ChunkError::MissingChunk { index: 2 };
+    /// assert_eq!(my_error.to_string(), "CHUNK_MISSING: Chunk 2 is missing");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            ChunkError::ChunkSizeTooSmall { max_chunk_bytes } => format!(
+                "Maximum chunk size must be at least {} bytes: {} given",
+                MIN_CHUNK_BYTES, max_chunk_bytes
+            ),
+            ChunkError::MissingManifest => String::from("No manifest chunk found"),
+            ChunkError::DuplicateManifest => String::from("More than one manifest chunk found"),
+            ChunkError::InvalidManifest => String::from("Manifest chunk could not be parsed"),
+            ChunkError::MissingChunk { index } => format!("Chunk {} is missing", index),
+            ChunkError::DuplicateChunk { index } => format!("Chunk {} appears more than once", index),
+            ChunkError::CorruptChunk { index: Some(index) } => format!("Chunk {} is corrupt", index),
+            ChunkError::CorruptChunk { index: None } => String::from("A chunk header could not be parsed"),
+            ChunkError::UnexpectedChunk { index } => format!("Chunk {} is not listed by the manifest", index),
+            ChunkError::PayloadMismatch => String::from("Reassembled payload does not match the manifest"),
+            ChunkError::InvalidPayload(error) => format!("Invalid payload: {}", error)
+        };
+        write!(f, "{}: {}", self.code(), description)
+    }
+}
+
+impl std::error::Error for ChunkError {}
+
+/// Compute the checksum of a string's bytes
+fn checksum(data: &str) -> u64 {
+    let mut fingerprinter = Fingerprinter::default();
+    let _ = fingerprinter.write_all(data.as_bytes());
+    fingerprinter.finish()
+}
+
+/// Split a payload into a manifest chunk followed by data chunks, each at
+/// most `max_chunk_bytes` bytes long. The same payload and size always
+/// produce the same chunks.
+///
+/// ### Example
+/// ```
+/// use fbsim_core::league::chunk::{join_chunks, split_chunks};
+///
+/// let payload = "a".repeat(1000);
+/// let chunks = split_chunks(&payload, 100).unwrap();
+/// assert!(chunks.iter().all(|c| c.len() <= 100));
+/// assert_eq!(join_chunks(&chunks).unwrap(), payload);
+/// ```
+pub fn split_chunks(payload: &str, max_chunk_bytes: usize) -> Result<Vec<String>, ChunkError> {
+    if max_chunk_bytes < MIN_CHUNK_BYTES {
+        return Err(ChunkError::ChunkSizeTooSmall { max_chunk_bytes });
+    }
+
+    // Split the payload on character boundaries, leaving room for the
+    // widest possible header of each chunk
+    let header_bytes = format!("{}:{:016x}:", usize::MAX, u64::MAX).len();
+    let data_bytes = max_chunk_bytes - header_bytes;
+    let mut chunks: Vec<String> = Vec::new();
+    let mut start = 0;
+    while start < payload.len() {
+        let mut end = (start + data_bytes).min(payload.len());
+        while !payload.is_char_boundary(end) {
+            end -= 1;
+        }
+        let data = &payload[start..end];
+        chunks.push(format!("{}:{:016x}:{}", chunks.len(), checksum(data), data));
+        start = end;
+    }
+
+    // Prepend the manifest
+    let manifest = format!(
+        "{}{}:{}:{:016x}",
+        MANIFEST_PREFIX, chunks.len(), payload.len(), checksum(payload)
+    );
+    chunks.insert(0, manifest);
+    Ok(chunks)
+}
+
+/// Reassemble a payload from the chunks produced by `split_chunks`, given
+/// in any order, verifying each chunk's checksum and the payload's length
+/// and checksum against the manifest
+///
+/// ### Example
+/// ```
+/// use fbsim_core::league::chunk::{join_chunks, split_chunks, ChunkError};
+///
+/// let mut chunks = split_chunks(&"a".repeat(1000), 100).unwrap();
+/// chunks.reverse();
+/// assert!(join_chunks(&chunks).is_ok());
+///
+/// // A missing chunk is reported by its index
+/// chunks.remove(0);
+/// assert_eq!(join_chunks(&chunks).unwrap_err().code(), "CHUNK_MISSING");
+/// ```
+pub fn join_chunks(chunks: &[impl AsRef<str>]) -> Result<String, ChunkError> {
+    // Separate the manifest from the data chunks
+    let mut manifest: Option<&str> = None;
+    let mut data: BTreeMap<usize, &str> = BTreeMap::new();
+    for chunk in chunks.iter().map(|c| c.as_ref()) {
+        if let Some(rest) = chunk.strip_prefix(MANIFEST_PREFIX) {
+            if manifest.replace(rest).is_some() {
+                return Err(ChunkError::DuplicateManifest);
+            }
+            continue;
+        }
+        let mut parts = chunk.splitn(3, ':');
+        let (index, sum, contents) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(index), Some(sum), Some(contents)) => (index, sum, contents),
+            _ => return Err(ChunkError::CorruptChunk { index: None })
+        };
+        let index: usize = index.parse().map_err(|_| ChunkError::CorruptChunk { index: None })?;
+        let sum = u64::from_str_radix(sum, 16).map_err(|_| ChunkError::CorruptChunk { index: Some(index) })?;
+        if checksum(contents) != sum {
+            return Err(ChunkError::CorruptChunk { index: Some(index) });
+        }
+        if data.insert(index, contents).is_some() {
+            return Err(ChunkError::DuplicateChunk { index });
+        }
+    }
+
+    // Parse the manifest
+    let manifest = manifest.ok_or(ChunkError::MissingManifest)?;
+    let fields: Vec<&str> = manifest.split(':').collect();
+    let (count, length, sum) = match fields.as_slice() {
+        [count, length, sum] => (
+            count.parse::<usize>().map_err(|_| ChunkError::InvalidManifest)?,
+            length.parse::<usize>().map_err(|_| ChunkError::InvalidManifest)?,
+            u64::from_str_radix(sum, 16).map_err(|_| ChunkError::InvalidManifest)?
+        ),
+        _ => return Err(ChunkError::InvalidManifest)
+    };
+
+    // Ensure exactly the manifest's chunks are present, and check the
+    // declared length before allocating the payload
+    if let Some((&index, _)) = data.range(count..).next() {
+        return Err(ChunkError::UnexpectedChunk { index });
+    }
+    if let Some(index) = (0..count).find(|index| !data.contains_key(index)) {
+        return Err(ChunkError::MissingChunk { index });
+    }
+    let total: usize = data.values().map(|contents| contents.len()).sum();
+    if total != length {
+        return Err(ChunkError::PayloadMismatch);
+    }
+
+    // Reassemble the data chunks in order
+    let mut payload = String::with_capacity(total);
+    for contents in data.values() {
+        payload.push_str(contents);
+    }
+    if checksum(&payload) != sum {
+        return Err(ChunkError::PayloadMismatch);
+    }
+    Ok(payload)
+}
@@ -0,0 +1,225 @@
+#![doc = include_str!("../../docs/league/era.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+use crate::league::franchise::FranchiseSeasonEntry;
+use crate::league::matchup::LeagueTeamRecord;
+
+/// The number of teams named to an era's all-star list
+pub const ERA_ALL_STAR_TEAMS: usize = 5;
+
+/// # `EraTeamSummary` struct
+///
+/// An `EraTeamSummary` sums up one team's seasons within an era, both in
+/// total and averaged over the seasons it played
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct EraTeamSummary {
+    /// The ID of the team
+    pub team_id: usize,
+    /// The team's name in its latest season of the era
+    pub name: String,
+    /// The team's short name in its latest season of the era
+    pub short_name: String,
+    /// The number of seasons the team played in the era
+    pub seasons: usize,
+    /// The team's combined regular season record
+    pub record: LeagueTeamRecord,
+    /// The points the team scored in regular season games
+    pub points_for: u32,
+    /// The points the team allowed in regular season games
+    pub points_against: u32,
+    /// The points scored minus the points allowed
+    pub point_differential: i64,
+    /// The number of seasons the team made the playoffs
+    pub playoff_appearances: usize,
+    /// The number of seasons the team reached the championship
+    pub championship_appearances: usize,
+    /// The number of championships the team won
+    pub championships: usize,
+    /// The team's regular season wins per season played
+    pub wins_per_season: f64,
+    /// The team's point differential per season played
+    pub point_differential_per_season: f64,
+    /// The team's championships per season played
+    pub championships_per_season: f64
+}
+
+impl EraTeamSummary {
+    /// Sum up a team's seasons, or return `None` if it played none
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::era::EraTeamSummary;
+    ///
+    /// assert!(EraTeamSummary::from_entries(0, &[]).is_none());
+    /// ```
+    pub fn from_entries(team_id: usize, entries: &[FranchiseSeasonEntry]) -> Option<EraTeamSummary> {
+        let latest = entries.iter().max_by_key(|entry| entry.year)?;
+        let mut record = LeagueTeamRecord::new();
+        let mut points_for: u32 = 0;
+        let mut points_against: u32 = 0;
+        for entry in entries.iter() {
+            record.increment_wins(*entry.record.wins());
+            record.increment_losses(*entry.record.losses());
+            record.increment_ties(*entry.record.ties());
+            points_for += entry.points_for;
+            points_against += entry.points_against;
+        }
+        let seasons = entries.len();
+        let point_differential = points_for as i64 - points_against as i64;
+        let championships = entries.iter().filter(|entry| entry.champion).count();
+        Some(EraTeamSummary {
+            team_id,
+            name: latest.name.clone(),
+            short_name: latest.short_name.clone(),
+            seasons,
+            wins_per_season: *record.wins() as f64 / seasons as f64,
+            record,
+            points_for,
+            points_against,
+            point_differential,
+            playoff_appearances: entries.iter().filter(|entry| entry.made_playoffs()).count(),
+            championship_appearances: entries.iter().filter(|entry| entry.championship_appearance).count(),
+            championships,
+            point_differential_per_season: point_differential as f64 / seasons as f64,
+            championships_per_season: championships as f64 / seasons as f64
+        })
+    }
+}
+
+/// # `EraLeaders` struct
+///
+/// The teams leading an era in each category, ties going to the lowest
+/// team ID
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct EraLeaders {
+    /// The team with the best combined regular season win percentage
+    pub best_record: Option<usize>,
+    /// The team with the most championships, if any were won
+    pub most_championships: Option<usize>,
+    /// The team with the best point differential
+    pub best_point_differential: Option<usize>,
+    /// The teams with the most regular season wins, best first
+    pub all_stars: Vec<usize>
+}
+
+impl EraLeaders {
+    /// Rank teams by a total over their seasons, either as is or divided by
+    /// the number of seasons each played. Per-season values are compared as
+    /// exact fractions.
+    fn rank_by<F>(teams: &[EraTeamSummary], per_season: bool, total: F) -> Vec<usize>
+    where
+        F: Fn(&EraTeamSummary) -> i64
+    {
+        let mut ranked: Vec<&EraTeamSummary> = teams.iter().collect();
+        let seasons = |team: &EraTeamSummary| if per_season { team.seasons as i128 } else { 1 };
+        ranked.sort_by(|a, b| {
+            (total(b) as i128 * seasons(a)).cmp(&(total(a) as i128 * seasons(b)))
+                .then(a.team_id.cmp(&b.team_id))
+        });
+        ranked.iter().map(|team| team.team_id).collect()
+    }
+
+    /// Find the leaders among a set of team summaries, from their totals or
+    /// from their per-season averages
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::era::EraLeaders;
+    ///
+    /// let leaders = EraLeaders::from_teams(&[], true);
+    /// assert!(leaders.best_record.is_none());
+    /// assert!(leaders.all_stars.is_empty());
+    /// ```
+    pub fn from_teams(teams: &[EraTeamSummary], per_season: bool) -> EraLeaders {
+        let best_record = teams.iter()
+            .min_by(|a, b| b.record.cmp_win_pct(&a.record).then(a.team_id.cmp(&b.team_id)))
+            .map(|team| team.team_id);
+        let most_championships = EraLeaders::rank_by(teams, per_season, |team| team.championships as i64)
+            .into_iter()
+            .next()
+            .filter(|id| teams.iter().any(|team| team.team_id == *id && team.championships > 0));
+        let best_point_differential = EraLeaders::rank_by(teams, per_season, |team| team.point_differential)
+            .into_iter()
+            .next();
+        let mut all_stars = EraLeaders::rank_by(teams, per_season, |team| *team.record.wins() as i64);
+        all_stars.truncate(ERA_ALL_STAR_TEAMS);
+        EraLeaders {
+            best_record,
+            most_championships,
+            best_point_differential,
+            all_stars
+        }
+    }
+}
+
+/// # `EraSummary` struct
+///
+/// An `EraSummary` sums up the complete seasons of a league within a range
+/// of years, along with the teams leading the era
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct EraSummary {
+    /// The first year of the era
+    pub start_year: usize,
+    /// The last year of the era
+    pub end_year: usize,
+    /// The years of the era with a complete season, in order
+    pub years: Vec<usize>,
+    /// The summary of each team which played in the era, ordered by ID
+    pub teams: Vec<EraTeamSummary>,
+    /// The leaders of the era by their totals
+    pub leaders: EraLeaders,
+    /// The leaders of the era by their per-season averages
+    pub per_season_leaders: EraLeaders
+}
+
+impl EraSummary {
+    /// Sum up an era from the timeline entries of each team within it
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::era::EraSummary;
+    ///
+    /// let summary = EraSummary::from_entries(2025, 2034, vec![2025], Vec::new());
+    /// assert!(summary.teams.is_empty());
+    /// ```
+    pub fn from_entries(start_year: usize, end_year: usize, years: Vec<usize>, entries: Vec<(usize, Vec<FranchiseSeasonEntry>)>) -> EraSummary {
+        let mut teams: Vec<EraTeamSummary> = entries.iter()
+            .filter_map(|(id, entries)| EraTeamSummary::from_entries(*id, entries))
+            .collect();
+        teams.sort_by_key(|team| team.team_id);
+        EraSummary {
+            start_year,
+            end_year,
+            years,
+            leaders: EraLeaders::from_teams(&teams, false),
+            per_season_leaders: EraLeaders::from_teams(&teams, true),
+            teams
+        }
+    }
+
+    /// Borrow the summary of a team, if it played in the era
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::era::EraSummary;
+    ///
+    /// let summary = EraSummary::from_entries(2025, 2034, Vec::new(), Vec::new());
+    /// assert!(summary.team(0).is_none());
+    /// ```
+    pub fn team(&self, team_id: usize) -> Option<&EraTeamSummary> {
+        self.teams.iter().find(|team| team.team_id == team_id)
+    }
+}
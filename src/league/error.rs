@@ -0,0 +1,341 @@
+#![doc = include_str!("../../docs/league/error.md")]
+use std::fmt;
+
+use crate::league::season::annotation::AnnotationTarget;
+
+/// # `LeagueError` enum
+///
+/// An error from a fallible operation on a league, world, or season, each
+/// with a stable code for hosts to match on.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum LeagueError {
+    /// No league has the given name
+    LeagueNotFound { name: String },
+    /// A league with the given name already exists
+    DuplicateLeague { name: String },
+    /// The season has started, so the operation may no longer be applied
+    SeasonAlreadyStarted,
+    /// The current season has not been played in full
+    SeasonInProgress,
+    /// Every week of the regular season has been played, so the operation
+    /// may no longer be applied
+    SeasonComplete,
+    /// No season has the given year
+    SeasonNotFound { year: usize },
+    /// The season with the given year is archived, so the operation may
+    /// only be applied to the current season
+    SeasonArchived { year: usize },
+    /// The league has no current season
+    NoCurrentSeason,
+    /// The season has no simulation seed
+    NoSimulationSeed,
+    /// The season has no schedule
+    NoSchedule,
+    /// The season has no conferences
+    NoConferences,
+    /// No team has the given ID
+    TeamNotFound { id: usize },
+    /// A team with the given ID already exists
+    DuplicateTeam { id: usize },
+    /// The team with the given ID is already active in another league in
+    /// the given year
+    TeamAlreadyActive { id: usize, league: String, year: usize },
+    /// The operation needs two different teams, but was given the team with
+    /// the given ID twice
+    SameTeam { id: usize },
+    /// The season has no week at the given index
+    WeekOutOfRange { week: usize },
+    /// The week has already started
+    WeekAlreadyStarted { week: usize },
+    /// The week has already been played
+    WeekAlreadyComplete { week: usize },
+    /// The week has not been played in full
+    WeekNotComplete { week: usize },
+    /// The week is after the trade deadline
+    TradeDeadlinePassed { week: usize, deadline: usize },
+    /// The week has no matchup at the given index
+    MatchupNotFound { week: usize, matchup: usize },
+    /// The matchup has already been played
+    MatchupAlreadyComplete { week: usize, matchup: usize },
+    /// The matchup has not been played
+    MatchupNotComplete { week: usize, matchup: usize },
+    /// The matchup was given more than once
+    DuplicateMatchup { week: usize, matchup: usize },
+    /// The matchup's game, context, and archived stats disagree
+    InconsistentMatchup { week: usize, matchup: usize, reason: String },
+    /// A schedule could not be generated or applied
+    InvalidSchedule { reason: String },
+    /// The target distribution for normalizing team skills is invalid
+    InvalidSkillTarget { reason: String },
+    /// The regular season has not been played in full
+    RegularSeasonNotComplete,
+    /// The number of playoff teams is outside of the allowed range
+    PlayoffTeamsOutOfRange { num_playoff_teams: usize, min: usize, max: usize },
+    /// The champion is decided by the best record, so there are no playoffs
+    ChampionDecidedByBestRecord,
+    /// The champion is decided by the playoffs, so there is no title playoff
+    ChampionDecidedByPlayoffs,
+    /// No teams are tied for the best record, so there is no title playoff
+    NoTieForBestRecord,
+    /// The playoffs have not been generated
+    PlayoffsNotGenerated,
+    /// The playoffs have started, so the operation may no longer be applied
+    PlayoffsAlreadyStarted,
+    /// The playoffs have been played in full
+    PlayoffsAlreadyComplete,
+    /// The playoffs have not been played in full
+    PlayoffsNotComplete,
+    /// The playoffs are not seeded by conference, so have no winners bracket
+    NotConferencePlayoffs,
+    /// The playoffs have no round at the given index
+    PlayoffRoundNotFound { round: usize },
+    /// The playoff round has not been played in full
+    PlayoffRoundNotComplete { round: usize },
+    /// The playoff round has no matchup at the given index
+    PlayoffMatchupNotFound { round: usize, matchup: usize },
+    /// The playoff matchup has already been played
+    PlayoffMatchupAlreadyComplete { round: usize, matchup: usize },
+    /// The winners bracket has no round at the given index
+    WinnersBracketRoundNotFound { round: usize },
+    /// The winners bracket round has not been played in full
+    WinnersBracketRoundNotComplete { round: usize },
+    /// The winners bracket round has no matchup at the given index
+    WinnersBracketMatchupNotFound { round: usize, matchup: usize },
+    /// The winners bracket matchup has already been played
+    WinnersBracketMatchupAlreadyComplete { round: usize, matchup: usize },
+    /// The team with the given ID is not in the playoffs
+    TeamNotInPlayoffs { id: usize },
+    /// A playoff picture failed validation
+    InvalidPlayoffPicture { reason: String },
+    /// A projection or simulation was asked to run zero trials
+    ZeroTrials,
+    /// The annotation target does not exist in the season
+    AnnotationTargetNotFound { target: AnnotationTarget },
+    /// The season has no annotation at the given index
+    AnnotationNotFound { index: usize },
+    /// The tier link exchanges no teams
+    EmptyTierLink,
+    /// Teams have already been exchanged between the tiers in the given year
+    TierExchangeAlreadyApplied { year: usize },
+    /// A game failed to simulate
+    Simulation { reason: String },
+    /// Any other invalid input or state
+    Invalid { reason: String }
+}
+
+impl LeagueError {
+    /// Get the stable code of the error
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::error::LeagueError;
+    ///
+    /// let my_error = LeagueError::TeamNotFound { id: 2 };
+    /// assert_eq!(my_error.code(), "LEAGUE_TEAM_NOT_FOUND");
+    /// ```
+    pub fn code(&self) -> &'static str {
+        match self {
+            LeagueError::LeagueNotFound { .. } => "LEAGUE_LEAGUE_NOT_FOUND",
+            LeagueError::DuplicateLeague { .. } => "LEAGUE_DUPLICATE_LEAGUE",
+            LeagueError::SeasonAlreadyStarted => "LEAGUE_SEASON_ALREADY_STARTED",
+            LeagueError::SeasonInProgress => "LEAGUE_SEASON_IN_PROGRESS",
+            LeagueError::SeasonComplete => "LEAGUE_SEASON_COMPLETE",
+            LeagueError::SeasonNotFound { .. } => "LEAGUE_SEASON_NOT_FOUND",
+            LeagueError::SeasonArchived { .. } => "LEAGUE_SEASON_ARCHIVED",
+            LeagueError::NoCurrentSeason => "LEAGUE_NO_CURRENT_SEASON",
+            LeagueError::NoSimulationSeed => "LEAGUE_NO_SIMULATION_SEED",
+            LeagueError::NoSchedule => "LEAGUE_NO_SCHEDULE",
+            LeagueError::NoConferences => "LEAGUE_NO_CONFERENCES",
+            LeagueError::TeamNotFound { .. } => "LEAGUE_TEAM_NOT_FOUND",
+            LeagueError::DuplicateTeam { .. } => "LEAGUE_DUPLICATE_TEAM",
+            LeagueError::TeamAlreadyActive { .. } => "LEAGUE_TEAM_ALREADY_ACTIVE",
+            LeagueError::SameTeam { .. } => "LEAGUE_SAME_TEAM",
+            LeagueError::WeekOutOfRange { .. } => "LEAGUE_WEEK_OUT_OF_RANGE",
+            LeagueError::WeekAlreadyStarted { .. } => "LEAGUE_WEEK_ALREADY_STARTED",
+            LeagueError::WeekAlreadyComplete { .. } => "LEAGUE_WEEK_ALREADY_COMPLETE",
+            LeagueError::WeekNotComplete { .. } => "LEAGUE_WEEK_NOT_COMPLETE",
+            LeagueError::TradeDeadlinePassed { .. } => "LEAGUE_TRADE_DEADLINE_PASSED",
+            LeagueError::MatchupNotFound { .. } => "LEAGUE_MATCHUP_NOT_FOUND",
+            LeagueError::MatchupAlreadyComplete { .. } => "LEAGUE_MATCHUP_ALREADY_COMPLETE",
+            LeagueError::MatchupNotComplete { .. } => "LEAGUE_MATCHUP_NOT_COMPLETE",
+            LeagueError::DuplicateMatchup { .. } => "LEAGUE_DUPLICATE_MATCHUP",
+            LeagueError::InconsistentMatchup { .. } => "LEAGUE_INCONSISTENT_MATCHUP",
+            LeagueError::InvalidSchedule { .. } => "LEAGUE_INVALID_SCHEDULE",
+            LeagueError::InvalidSkillTarget { .. } => "LEAGUE_INVALID_SKILL_TARGET",
+            LeagueError::RegularSeasonNotComplete => "LEAGUE_REGULAR_SEASON_NOT_COMPLETE",
+            LeagueError::PlayoffTeamsOutOfRange { .. } => "LEAGUE_PLAYOFF_TEAMS_OUT_OF_RANGE",
+            LeagueError::ChampionDecidedByBestRecord => "LEAGUE_CHAMPION_DECIDED_BY_BEST_RECORD",
+            LeagueError::ChampionDecidedByPlayoffs => "LEAGUE_CHAMPION_DECIDED_BY_PLAYOFFS",
+            LeagueError::NoTieForBestRecord => "LEAGUE_NO_TIE_FOR_BEST_RECORD",
+            LeagueError::PlayoffsNotGenerated => "LEAGUE_PLAYOFFS_NOT_GENERATED",
+            LeagueError::PlayoffsAlreadyStarted => "LEAGUE_PLAYOFFS_ALREADY_STARTED",
+            LeagueError::PlayoffsAlreadyComplete => "LEAGUE_PLAYOFFS_ALREADY_COMPLETE",
+            LeagueError::PlayoffsNotComplete => "LEAGUE_PLAYOFFS_NOT_COMPLETE",
+            LeagueError::NotConferencePlayoffs => "LEAGUE_NOT_CONFERENCE_PLAYOFFS",
+            LeagueError::PlayoffRoundNotFound { .. } => "LEAGUE_PLAYOFF_ROUND_NOT_FOUND",
+            LeagueError::PlayoffRoundNotComplete { .. } => "LEAGUE_PLAYOFF_ROUND_NOT_COMPLETE",
+            LeagueError::PlayoffMatchupNotFound { .. } => "LEAGUE_PLAYOFF_MATCHUP_NOT_FOUND",
+            LeagueError::PlayoffMatchupAlreadyComplete { .. } => "LEAGUE_PLAYOFF_MATCHUP_ALREADY_COMPLETE",
+            LeagueError::WinnersBracketRoundNotFound { .. } => "LEAGUE_WINNERS_BRACKET_ROUND_NOT_FOUND",
+            LeagueError::WinnersBracketRoundNotComplete { .. } => "LEAGUE_WINNERS_BRACKET_ROUND_NOT_COMPLETE",
+            LeagueError::WinnersBracketMatchupNotFound { .. } => "LEAGUE_WINNERS_BRACKET_MATCHUP_NOT_FOUND",
+            LeagueError::WinnersBracketMatchupAlreadyComplete { .. } => "LEAGUE_WINNERS_BRACKET_MATCHUP_ALREADY_COMPLETE",
+            LeagueError::TeamNotInPlayoffs { .. } => "LEAGUE_TEAM_NOT_IN_PLAYOFFS",
+            LeagueError::InvalidPlayoffPicture { .. } => "LEAGUE_INVALID_PLAYOFF_PICTURE",
+            LeagueError::ZeroTrials => "LEAGUE_ZERO_TRIALS",
+            LeagueError::AnnotationTargetNotFound { .. } => "LEAGUE_ANNOTATION_TARGET_NOT_FOUND",
+            LeagueError::AnnotationNotFound { .. } => "LEAGUE_ANNOTATION_NOT_FOUND",
+            LeagueError::EmptyTierLink => "LEAGUE_EMPTY_TIER_LINK",
+            LeagueError::TierExchangeAlreadyApplied { .. } => "LEAGUE_TIER_EXCHANGE_ALREADY_APPLIED",
+            LeagueError::Simulation { .. } => "LEAGUE_SIMULATION",
+            LeagueError::Invalid { .. } => "LEAGUE_INVALID"
+        }
+    }
+}
+
+impl fmt::Display for LeagueError {
+    /// Format a `LeagueError` as a readable description
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::error::LeagueError;
+    ///
+    /// let my_error = LeagueError::WeekOutOfRange { week: 20 };
+    /// assert_eq!(my_error.to_string(), "No such week: 20");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LeagueError::LeagueNotFound { name } => write!(f, "No such league: {}", name),
+            LeagueError::DuplicateLeague { name } => write!(f, "League {} already exists", name),
+            LeagueError::SeasonAlreadyStarted => write!(f, "Season has already started"),
+            LeagueError::SeasonInProgress => write!(f, "Current season still in progress"),
+            LeagueError::SeasonComplete => write!(f, "Regular season is already complete"),
+            LeagueError::SeasonNotFound { year } => write!(f, "No such season: {}", year),
+            LeagueError::SeasonArchived { year } => write!(f, "Season {} is archived", year),
+            LeagueError::NoCurrentSeason => write!(f, "League has no current season"),
+            LeagueError::NoSimulationSeed => write!(f, "Season has no simulation seed"),
+            LeagueError::NoSchedule => write!(f, "Season has no schedule"),
+            LeagueError::NoConferences => write!(f, "Season has no conferences defined"),
+            LeagueError::TeamNotFound { id } => write!(f, "No team with ID: {}", id),
+            LeagueError::DuplicateTeam { id } => write!(f, "Team with ID {} already exists", id),
+            LeagueError::TeamAlreadyActive { id, league, year } => write!(
+                f, "Team {} is already active in league {} in {}", id, league, year
+            ),
+            LeagueError::SameTeam { id } => write!(f, "Team {} cannot be paired with itself", id),
+            LeagueError::WeekOutOfRange { week } => write!(f, "No such week: {}", week),
+            LeagueError::WeekAlreadyStarted { week } => write!(f, "Week {} has already started", week),
+            LeagueError::WeekAlreadyComplete { week } => write!(f, "Week {} is already complete", week),
+            LeagueError::WeekNotComplete { week } => write!(f, "Week {} is not complete", week),
+            LeagueError::TradeDeadlinePassed { week, deadline } => write!(
+                f, "Week {} is not before the trade deadline week {}", week, deadline
+            ),
+            LeagueError::MatchupNotFound { week, matchup } => write!(
+                f, "No such matchup in week {}: {}", week, matchup
+            ),
+            LeagueError::MatchupAlreadyComplete { week, matchup } => write!(
+                f, "Week {} matchup {} is already complete", week, matchup
+            ),
+            LeagueError::MatchupNotComplete { week, matchup } => write!(
+                f, "Week {} matchup {} is not complete", week, matchup
+            ),
+            LeagueError::DuplicateMatchup { week, matchup } => write!(
+                f, "Week {} matchup {} given more than once", week, matchup
+            ),
+            LeagueError::InconsistentMatchup { week, matchup, reason } => write!(
+                f, "Week {} matchup {} is inconsistent: {}", week, matchup, reason
+            ),
+            LeagueError::InvalidSchedule { reason } => write!(f, "Invalid schedule: {}", reason),
+            LeagueError::InvalidSkillTarget { reason } => write!(f, "Invalid skill target: {}", reason),
+            LeagueError::RegularSeasonNotComplete => write!(f, "Regular season is not complete"),
+            LeagueError::PlayoffTeamsOutOfRange { num_playoff_teams, min, max } => write!(
+                f, "Number of playoff teams is not in range [{}, {}]: {}", min, max, num_playoff_teams
+            ),
+            LeagueError::ChampionDecidedByBestRecord => write!(f, "Champion is decided by best record"),
+            LeagueError::ChampionDecidedByPlayoffs => write!(f, "Champion is decided by the playoffs"),
+            LeagueError::NoTieForBestRecord => write!(f, "No teams are tied for the best record"),
+            LeagueError::PlayoffsNotGenerated => write!(f, "Playoffs have not been generated"),
+            LeagueError::PlayoffsAlreadyStarted => write!(f, "Playoffs have already started"),
+            LeagueError::PlayoffsAlreadyComplete => write!(f, "Playoffs are already complete"),
+            LeagueError::PlayoffsNotComplete => write!(f, "Playoffs are not complete"),
+            LeagueError::NotConferencePlayoffs => write!(f, "Playoffs are not seeded by conference"),
+            LeagueError::PlayoffRoundNotFound { round } => write!(f, "No such playoff round: {}", round),
+            LeagueError::PlayoffRoundNotComplete { round } => write!(f, "Playoff round {} is not complete", round),
+            LeagueError::PlayoffMatchupNotFound { round, matchup } => write!(
+                f, "No such matchup in playoff round {}: {}", round, matchup
+            ),
+            LeagueError::PlayoffMatchupAlreadyComplete { round, matchup } => write!(
+                f, "Playoff round {} matchup {} is already complete", round, matchup
+            ),
+            LeagueError::WinnersBracketRoundNotFound { round } => write!(f, "No such winners bracket round: {}", round),
+            LeagueError::WinnersBracketRoundNotComplete { round } => write!(
+                f, "Winners bracket round {} is not complete", round
+            ),
+            LeagueError::WinnersBracketMatchupNotFound { round, matchup } => write!(
+                f, "No such matchup in winners bracket round {}: {}", round, matchup
+            ),
+            LeagueError::WinnersBracketMatchupAlreadyComplete { round, matchup } => write!(
+                f, "Winners bracket round {} matchup {} is already complete", round, matchup
+            ),
+            LeagueError::TeamNotInPlayoffs { id } => write!(f, "Team {} is not in the playoffs", id),
+            LeagueError::InvalidPlayoffPicture { reason } => write!(f, "Invalid playoff picture: {}", reason),
+            LeagueError::ZeroTrials => write!(f, "Number of trials must be at least 1"),
+            LeagueError::AnnotationTargetNotFound { target } => write!(f, "No such annotation target: {:?}", target),
+            LeagueError::AnnotationNotFound { index } => write!(f, "No such annotation: {}", index),
+            LeagueError::EmptyTierLink => write!(f, "Tier link exchanges no teams"),
+            LeagueError::TierExchangeAlreadyApplied { year } => write!(
+                f, "Teams already exchanged between these tiers in {}", year
+            ),
+            LeagueError::Simulation { reason } => write!(f, "Error while simulating: {}", reason),
+            LeagueError::Invalid { reason } => f.write_str(reason)
+        }
+    }
+}
+
+impl std::error::Error for LeagueError {}
+
+impl From<String> for LeagueError {
+    /// Convert an error message into an `Invalid` error, for messages from
+    /// validation and from outside the league module
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::error::LeagueError;
+    ///
+    /// let my_error = LeagueError::from(String::from("Days between weeks must be positive"));
+    /// assert_eq!(my_error.code(), "LEAGUE_INVALID");
+    /// ```
+    fn from(reason: String) -> Self {
+        LeagueError::Invalid { reason }
+    }
+}
+
+impl From<&str> for LeagueError {
+    /// Convert an error message into an `Invalid` error
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::error::LeagueError;
+    ///
+    /// let my_error = LeagueError::from("Trials must be nonzero");
+    /// assert_eq!(my_error.to_string(), "Trials must be nonzero");
+    /// ```
+    fn from(reason: &str) -> Self {
+        LeagueError::Invalid { reason: reason.to_string() }
+    }
+}
+
+impl From<LeagueError> for String {
+    /// Convert a `LeagueError` into its description, for callers which
+    /// still handle errors as strings
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::error::LeagueError;
+    ///
+    /// let my_error: String = LeagueError::SeasonAlreadyStarted.into();
+    /// assert_eq!(my_error, "Season has already started");
+    /// ```
+    fn from(error: LeagueError) -> Self {
+        error.to_string()
+    }
+}
@@ -0,0 +1,100 @@
+//! Stable fingerprints over the serialized form of leagues and seasons
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::sync::OnceLock;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// # `Fingerprinter` struct
+///
+/// A 64-bit FNV-1a hasher that consumes serialized bytes as they are
+/// written, so a value may be fingerprinted without allocating its JSON.
+/// Unlike `std::collections::hash_map::DefaultHasher`, its output is stable
+/// across Rust versions and platforms.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Fingerprinter(u64);
+
+impl Default for Fingerprinter {
+    fn default() -> Self {
+        Fingerprinter(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Fingerprinter {
+    /// Feed a serializable value into the fingerprint as JSON
+    pub(crate) fn write_value<T: Serialize>(&mut self, value: &T) {
+        // Writing into the fingerprinter never fails, and the league types
+        // always serialize successfully
+        let _ = serde_json::to_writer(&mut *self, value);
+    }
+
+    /// Feed a previously computed fingerprint into this one
+    pub(crate) fn write_fingerprint(&mut self, fingerprint: u64) {
+        self.write_bytes(&fingerprint.to_le_bytes());
+    }
+
+    /// Get the fingerprint of everything written so far
+    pub(crate) fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+}
+
+impl std::io::Write for Fingerprinter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.write_bytes(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// # `FingerprintCache` struct
+///
+/// A lazily computed fingerprint that its owner clears from each of its
+/// mutating methods. It is never serialized and never affects equality or
+/// ordering, so two values with the same content compare equal whether or
+/// not either has computed its fingerprint yet.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct FingerprintCache(OnceLock<u64>);
+
+impl FingerprintCache {
+    /// Get the cached fingerprint, computing it first if it was cleared
+    pub(crate) fn get_or_compute(&self, compute: impl FnOnce() -> u64) -> u64 {
+        *self.0.get_or_init(compute)
+    }
+
+    /// Clear the cached fingerprint after its owner is mutated
+    pub(crate) fn invalidate(&mut self) {
+        self.0.take();
+    }
+}
+
+impl PartialEq for FingerprintCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for FingerprintCache {}
+
+impl PartialOrd for FingerprintCache {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FingerprintCache {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
@@ -0,0 +1,216 @@
+#![doc = include_str!("../../docs/league/franchise.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+use crate::league::error::LeagueError;
+use crate::game::play::PlaySimulatable;
+use crate::league::matchup::LeagueTeamRecord;
+use crate::league::season::LeagueSeason;
+use crate::league::season::honors::SeasonHonors;
+use crate::team::coach::FootballTeamCoach;
+
+/// # `FranchiseHonor` enum
+///
+/// A title won by a franchise during a season
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum FranchiseHonor {
+    /// Finished first in a division's standings
+    DivisionChampion {
+        conference: usize,
+        division: usize
+    },
+    /// Finished first in a conference's standings
+    ConferenceChampion {
+        conference: usize
+    },
+    /// Won a conference's playoff bracket
+    ConferencePlayoffChampion {
+        conference: usize
+    },
+    /// Finished with the best regular season record in the league
+    TopSeed,
+    /// Won the league championship
+    LeagueChampion
+}
+
+impl FranchiseHonor {
+    /// Collect the titles won by a team from a season's honors
+    ///
+    /// ### Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use fbsim_core::league::franchise::FranchiseHonor;
+    /// use fbsim_core::league::season::honors::SeasonHonors;
+    ///
+    /// let mut my_honors = SeasonHonors::default();
+    /// my_honors.division_champions.insert(0, BTreeMap::from([(1, 5)]));
+    /// my_honors.league_champion = Some(5);
+    /// let titles = FranchiseHonor::from_season_honors(&my_honors, 5);
+    /// assert_eq!(
+    ///     titles,
+    ///     vec![
+    ///         FranchiseHonor::DivisionChampion { conference: 0, division: 1 },
+    ///         FranchiseHonor::LeagueChampion
+    ///     ]
+    /// );
+    /// ```
+    pub fn from_season_honors(honors: &SeasonHonors, team_id: usize) -> Vec<FranchiseHonor> {
+        let mut titles = Vec::new();
+        for (conference, divisions) in honors.division_champions.iter() {
+            for (division, champion) in divisions.iter() {
+                if *champion == team_id {
+                    titles.push(FranchiseHonor::DivisionChampion {
+                        conference: *conference,
+                        division: *division
+                    });
+                }
+            }
+        }
+        for (conference, champion) in honors.conference_champions.iter() {
+            if *champion == team_id {
+                titles.push(FranchiseHonor::ConferenceChampion { conference: *conference });
+            }
+        }
+        for (conference, champion) in honors.conference_playoff_champions.iter() {
+            if *champion == team_id {
+                titles.push(FranchiseHonor::ConferencePlayoffChampion { conference: *conference });
+            }
+        }
+        if honors.top_seed == Some(team_id) {
+            titles.push(FranchiseHonor::TopSeed);
+        }
+        if honors.league_champion == Some(team_id) {
+            titles.push(FranchiseHonor::LeagueChampion);
+        }
+        titles
+    }
+}
+
+/// # `FranchiseSeasonEntry` struct
+///
+/// A `FranchiseSeasonEntry` summarizes one team's season: the name it
+/// played under, its coach, record, finish, points, and playoff result,
+/// and the titles it won.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct FranchiseSeasonEntry {
+    /// The year of the season
+    pub year: usize,
+    /// The team's name that season
+    pub name: String,
+    /// The team's short name that season
+    pub short_name: String,
+    /// The team's coach that season
+    pub coach: FootballTeamCoach,
+    /// The team's regular season record
+    pub record: LeagueTeamRecord,
+    /// The team's one-based rank in the league standings
+    pub standings_rank: usize,
+    /// The points the team scored in completed regular season games
+    pub points_for: u32,
+    /// The points the team allowed in completed regular season games
+    pub points_against: u32,
+    /// The points scored minus the points allowed
+    pub point_differential: i64,
+    /// The team's playoff seed, if it made the playoffs
+    pub playoff_seed: Option<usize>,
+    /// The team's playoff record, if it made the playoffs
+    pub playoff_record: Option<LeagueTeamRecord>,
+    /// Whether the team reached the championship
+    pub championship_appearance: bool,
+    /// Whether the team won the championship
+    pub champion: bool,
+    /// The titles the team won that season
+    pub honors: Vec<FranchiseHonor>,
+    /// Whether the season is complete
+    pub complete: bool
+}
+
+impl FranchiseSeasonEntry {
+    /// Summarize a team's season, or return `None` if the team did not
+    /// play in the season
+    pub(crate) fn from_season(season: &LeagueSeason, team_id: usize, honors: &SeasonHonors) -> Result<Option<FranchiseSeasonEntry>, LeagueError> {
+        let team = match season.team(team_id) {
+            Some(team) => team,
+            None => return Ok(None)
+        };
+
+        // Sum the scores of the team's completed regular season games
+        let mut points_for: u32 = 0;
+        let mut points_against: u32 = 0;
+        for matchup in season.team_matchups(team_id)?.matchups().iter().flatten() {
+            let context = matchup.context();
+            if !context.game_over() {
+                continue;
+            }
+            let (scored, allowed) = if matchup.is_home_team(team_id) {
+                (context.home_score(), context.away_score())
+            } else {
+                (context.away_score(), context.home_score())
+            };
+            points_for += scored;
+            points_against += allowed;
+        }
+
+        // Find the team's record and finish in the standings
+        let standings = season.standings();
+        let (rank, record) = standings.iter()
+            .enumerate()
+            .find(|(_, (id, _))| *id == team_id)
+            .map(|(index, (_, record))| (index + 1, record.clone()))
+            .ok_or_else(|| format!("Team {} missing from season {} standings", team_id, season.year()))?;
+
+        // Summarize the team's postseason
+        let (playoff_seed, playoff_record) = if season.team_in_playoffs(team_id)? {
+            (
+                Some(season.playoffs().team_seed(team_id)?),
+                Some(season.playoff_record(team_id)?)
+            )
+        } else {
+            (None, None)
+        };
+        Ok(Some(FranchiseSeasonEntry {
+            year: *season.year(),
+            name: team.name().to_string(),
+            short_name: team.short_name().to_string(),
+            coach: team.coach().clone(),
+            record,
+            standings_rank: rank,
+            points_for,
+            points_against,
+            point_differential: points_for as i64 - points_against as i64,
+            playoff_seed,
+            playoff_record,
+            championship_appearance: season.team_in_championship(team_id)?,
+            champion: season.team_won_championship(team_id)?,
+            honors: FranchiseHonor::from_season_honors(honors, team_id),
+            complete: season.complete()
+        }))
+    }
+
+    /// Whether the team made the playoffs
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let mut my_league = League::new();
+    /// my_league.add_team();
+    /// my_league.add_season_with_year(2026).unwrap();
+    /// my_league.add_season_team(0, FootballTeam::new()).unwrap();
+    /// let timeline = my_league.franchise_timeline(0).unwrap();
+    /// assert!(!timeline[0].made_playoffs());
+    /// ```
+    pub fn made_playoffs(&self) -> bool {
+        self.playoff_seed.is_some()
+    }
+}
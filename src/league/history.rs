@@ -0,0 +1,283 @@
+#![doc = include_str!("../../docs/league/history.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+use crate::league::error::LeagueError;
+use crate::league::matchup::LeagueTeamRecord;
+use crate::league::season::LeagueSeason;
+use crate::league::season::week::LeagueSeasonWeek;
+
+/// # `PlayoffResult` enum
+///
+/// How far a team went in a season's playoffs
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum PlayoffResult {
+    /// The team has not yet been eliminated
+    InProgress,
+    /// The team lost before the championship
+    Eliminated,
+    /// The team lost the championship
+    RunnerUp,
+    /// The team won the championship
+    Champion
+}
+
+/// # `LeagueSeasonResult` struct
+///
+/// A `LeagueSeasonResult` is one team's finish in one season: its regular
+/// season record and rank, and how far it went in the playoffs
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct LeagueSeasonResult {
+    /// The year of the season
+    pub year: usize,
+    /// The team's regular season record
+    pub record: LeagueTeamRecord,
+    /// The team's one-based rank in the league standings
+    pub standings_rank: usize,
+    /// Whether the team made the playoffs
+    pub made_playoffs: bool,
+    /// The team's playoff record, if it made the playoffs
+    pub playoff_record: Option<LeagueTeamRecord>,
+    /// How far the team went in the playoffs, if it made them or won the
+    /// title on its record alone
+    pub playoff_result: Option<PlayoffResult>,
+    /// Whether the season is complete
+    pub complete: bool
+}
+
+impl LeagueSeasonResult {
+    /// Summarize a team's finish in a season, or return `None` if the team
+    /// did not play in the season
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::history::LeagueSeasonResult;
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new()).unwrap();
+    /// let result = LeagueSeasonResult::from_season(&my_league_season, 0).unwrap().unwrap();
+    /// assert!(!result.made_playoffs);
+    /// assert!(LeagueSeasonResult::from_season(&my_league_season, 1).unwrap().is_none());
+    /// ```
+    pub fn from_season(season: &LeagueSeason, team_id: usize) -> Result<Option<LeagueSeasonResult>, LeagueError> {
+        if season.team(team_id).is_none() {
+            return Ok(None);
+        }
+
+        // Find the team's record and finish in the standings
+        let standings = season.standings();
+        let (standings_rank, record) = standings.iter()
+            .enumerate()
+            .find(|(_, (id, _))| *id == team_id)
+            .map(|(index, (_, record))| (index + 1, record.clone()))
+            .ok_or_else(|| format!("Team {} missing from season {} standings", team_id, season.year()))?;
+
+        // Summarize the team's postseason
+        let made_playoffs = season.team_in_playoffs(team_id)?;
+        let playoff_record = if made_playoffs {
+            Some(season.playoff_record(team_id)?)
+        } else {
+            None
+        };
+        let playoff_result = if season.team_won_championship(team_id)? {
+            Some(PlayoffResult::Champion)
+        } else if !made_playoffs {
+            None
+        } else if season.team_in_championship(team_id)? &&
+            season.playoffs().championship().is_some_and(|game| game.context().game_over())
+        {
+            Some(PlayoffResult::RunnerUp)
+        } else if playoff_record.as_ref().is_some_and(|record| *record.losses() > 0) {
+            Some(PlayoffResult::Eliminated)
+        } else {
+            Some(PlayoffResult::InProgress)
+        };
+        Ok(Some(LeagueSeasonResult {
+            year: *season.year(),
+            record,
+            standings_rank,
+            made_playoffs,
+            playoff_record,
+            playoff_result,
+            complete: season.complete()
+        }))
+    }
+}
+
+/// # `LeagueHeadToHeadGame` struct
+///
+/// A completed meeting between two teams in any season of a league
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct LeagueHeadToHeadGame {
+    /// The year of the season
+    pub year: usize,
+    /// Whether the meeting was a playoff game
+    pub playoffs: bool,
+    /// Index of the regular season week, or of the round within its playoff
+    /// bracket
+    pub week: usize,
+    /// The home team's ID
+    pub home_team: usize,
+    /// The away team's ID
+    pub away_team: usize,
+    /// The home team's final score
+    pub home_score: u32,
+    /// The away team's final score
+    pub away_score: u32
+}
+
+/// # `LeagueHeadToHead` struct
+///
+/// A `LeagueHeadToHead` sums up every completed meeting between two teams
+/// across a league's seasons, from the first team's point of view
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct LeagueHeadToHead {
+    /// The first team's ID
+    pub team: usize,
+    /// The opponent's ID
+    pub opponent: usize,
+    /// The first team's record in regular season meetings
+    pub regular_season: LeagueTeamRecord,
+    /// The first team's record in playoff meetings
+    pub playoffs: LeagueTeamRecord,
+    /// The points the first team scored in the meetings
+    pub points_for: u32,
+    /// The points the first team allowed in the meetings
+    pub points_against: u32,
+    /// The completed meetings, ordered by year, with each season's regular
+    /// season games before its playoff games
+    pub games: Vec<LeagueHeadToHeadGame>
+}
+
+impl LeagueHeadToHead {
+    /// Sum up the completed meetings between two teams from the first
+    /// team's point of view
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::history::{LeagueHeadToHead, LeagueHeadToHeadGame};
+    ///
+    /// let games = vec![
+    ///     LeagueHeadToHeadGame { year: 2026, playoffs: false, week: 3, home_team: 0, away_team: 1, home_score: 24, away_score: 17 },
+    ///     LeagueHeadToHeadGame { year: 2026, playoffs: true, week: 0, home_team: 1, away_team: 0, home_score: 21, away_score: 14 }
+    /// ];
+    /// let series = LeagueHeadToHead::new(0, 1, games);
+    /// assert_eq!(series.regular_season.to_string(), "1-0-0");
+    /// assert_eq!(series.playoffs.to_string(), "0-1-0");
+    /// assert_eq!(series.record().to_string(), "1-1-0");
+    /// ```
+    pub fn new(team: usize, opponent: usize, games: Vec<LeagueHeadToHeadGame>) -> LeagueHeadToHead {
+        let mut regular_season = LeagueTeamRecord::new();
+        let mut playoffs = LeagueTeamRecord::new();
+        let mut points_for: u32 = 0;
+        let mut points_against: u32 = 0;
+        for game in games.iter() {
+            let (scored, allowed) = if game.home_team == team {
+                (game.home_score, game.away_score)
+            } else {
+                (game.away_score, game.home_score)
+            };
+            let record = if game.playoffs { &mut playoffs } else { &mut regular_season };
+            if scored > allowed {
+                record.increment_wins(1);
+            } else if scored < allowed {
+                record.increment_losses(1);
+            } else {
+                record.increment_ties(1);
+            }
+            points_for += scored;
+            points_against += allowed;
+        }
+        LeagueHeadToHead {
+            team,
+            opponent,
+            regular_season,
+            playoffs,
+            points_for,
+            points_against,
+            games
+        }
+    }
+
+    /// Collect the completed meetings between two teams in a season, regular
+    /// season games first, then playoff games by bracket and round
+    pub(crate) fn season_games(season: &LeagueSeason, team: usize, opponent: usize) -> Vec<LeagueHeadToHeadGame> {
+        let year = *season.year();
+        let meetings = |playoffs: bool, weeks: &[LeagueSeasonWeek]| -> Vec<LeagueHeadToHeadGame> {
+            let mut games = Vec::new();
+            for (week_index, week) in weeks.iter().enumerate() {
+                for matchup in week.matchups().iter() {
+                    let context = matchup.context();
+                    if context.game_over() && matchup.participated(team) && matchup.participated(opponent) {
+                        games.push(LeagueHeadToHeadGame {
+                            year,
+                            playoffs,
+                            week: week_index,
+                            home_team: *matchup.home_team(),
+                            away_team: *matchup.away_team(),
+                            home_score: context.home_score(),
+                            away_score: context.away_score()
+                        });
+                    }
+                }
+            }
+            games
+        };
+        let mut games = meetings(false, season.weeks());
+        for bracket in season.playoffs().conference_brackets().values() {
+            games.extend(meetings(true, bracket));
+        }
+        games.extend(meetings(true, season.playoffs().winners_bracket()));
+        games
+    }
+
+    /// The first team's combined regular season and playoff record
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::history::LeagueHeadToHead;
+    ///
+    /// let series = LeagueHeadToHead::new(0, 1, Vec::new());
+    /// assert_eq!(series.record().to_string(), "0-0-0");
+    /// ```
+    pub fn record(&self) -> LeagueTeamRecord {
+        let mut record = self.regular_season.clone();
+        record.increment_wins(*self.playoffs.wins());
+        record.increment_losses(*self.playoffs.losses());
+        record.increment_ties(*self.playoffs.ties());
+        record
+    }
+
+    /// The points the first team scored minus the points it allowed
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::history::{LeagueHeadToHead, LeagueHeadToHeadGame};
+    ///
+    /// let games = vec![
+    ///     LeagueHeadToHeadGame { year: 2027, playoffs: false, week: 2, home_team: 3, away_team: 4, home_score: 10, away_score: 27 }
+    /// ];
+    /// let series = LeagueHeadToHead::new(3, 4, games);
+    /// assert_eq!(series.point_differential(), -17);
+    /// ```
+    pub fn point_differential(&self) -> i64 {
+        self.points_for as i64 - self.points_against as i64
+    }
+}
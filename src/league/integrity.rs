@@ -0,0 +1,239 @@
+#![doc = include_str!("../../docs/league/integrity.md")]
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+use crate::league::season::{ChampionshipMode, LeagueSeason};
+use crate::league::team::LeagueTeam;
+
+/// # `ValidationIssueKind` enum
+///
+/// The class of invariant broken by a `ValidationIssue`
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum ValidationIssueKind {
+    /// Two seasons share a year
+    DuplicateSeasonYear,
+    /// A season's year is not later than the season before it
+    SeasonOutOfOrder,
+    /// A season team is not a team in the league
+    DanglingTeam,
+    /// An archived season team is no longer a team in the league
+    RetiredTeam,
+    /// A playoff team is not a team in its season
+    DanglingPlayoffTeam,
+    /// A past season was archived before it completed
+    IncompleteArchivedSeason,
+    /// A title playoff does not involve exactly the teams tied for the best
+    /// record
+    ChampionshipMismatch,
+    /// A season annotation's target no longer exists in its season
+    DanglingAnnotation,
+}
+
+impl ValidationIssueKind {
+    /// Whether an issue of this kind prevents a league from deserializing
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::integrity::ValidationIssueKind;
+    ///
+    /// assert!(ValidationIssueKind::DuplicateSeasonYear.fatal());
+    /// assert!(!ValidationIssueKind::IncompleteArchivedSeason.fatal());
+    /// assert!(!ValidationIssueKind::RetiredTeam.fatal());
+    /// ```
+    pub fn fatal(&self) -> bool {
+        matches!(
+            self,
+            ValidationIssueKind::DuplicateSeasonYear |
+            ValidationIssueKind::DanglingTeam |
+            ValidationIssueKind::DanglingPlayoffTeam
+        )
+    }
+}
+
+/// # `ValidationIssue` struct
+///
+/// A broken invariant found by `League::integrity_check`, located by a
+/// path such as `seasons/2027/playoffs`
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    /// The class of invariant broken
+    pub kind: ValidationIssueKind,
+    /// The location of the issue within the league
+    pub path: String,
+    /// A description of the issue
+    pub message: String
+}
+
+impl ValidationIssue {
+    /// Constructor for the `ValidationIssue` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::integrity::{ValidationIssue, ValidationIssueKind};
+    ///
+    /// let my_issue = ValidationIssue::new(
+    ///     ValidationIssueKind::DuplicateSeasonYear,
+    ///     "seasons/2027",
+    ///     "More than one season in 2027"
+    /// );
+    /// assert_eq!(my_issue.path, "seasons/2027");
+    /// ```
+    pub fn new(kind: ValidationIssueKind, path: &str, message: &str) -> ValidationIssue {
+        ValidationIssue {
+            kind,
+            path: String::from(path),
+            message: String::from(message)
+        }
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    /// Display a `ValidationIssue` as its path followed by its message
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::integrity::{ValidationIssue, ValidationIssueKind};
+    ///
+    /// let my_issue = ValidationIssue::new(
+    ///     ValidationIssueKind::DuplicateSeasonYear,
+    ///     "seasons/2027",
+    ///     "More than one season in 2027"
+    /// );
+    /// assert_eq!(my_issue.to_string(), "seasons/2027: More than one season in 2027");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Check the invariants spanning a league's seasons, given its teams, its
+/// archived seasons in order, and its current season
+pub(crate) fn check_league(
+    teams: &BTreeMap<usize, LeagueTeam>,
+    seasons: &[LeagueSeason],
+    current_season: Option<&LeagueSeason>
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    // Ensure the season years are unique and increasing
+    let mut years: BTreeSet<usize> = BTreeSet::new();
+    let mut previous: Option<usize> = None;
+    for season in seasons.iter().chain(current_season) {
+        let year = *season.year();
+        let path = format!("seasons/{}", year);
+        if !years.insert(year) {
+            issues.push(ValidationIssue::new(
+                ValidationIssueKind::DuplicateSeasonYear,
+                &path,
+                &format!("More than one season in {}", year)
+            ));
+        } else if let Some(previous) = previous.filter(|p| *p > year) {
+            issues.push(ValidationIssue::new(
+                ValidationIssueKind::SeasonOutOfOrder,
+                &path,
+                &format!("Season {} follows season {}", year, previous)
+            ));
+        }
+        previous = Some(year);
+    }
+
+    // Check each season's teams, playoffs, and champion
+    for season in seasons.iter() {
+        check_season(teams, season, true, &mut issues);
+        if !season.complete() {
+            issues.push(ValidationIssue::new(
+                ValidationIssueKind::IncompleteArchivedSeason,
+                &format!("seasons/{}", season.year()),
+                &format!("Season {} was archived before it completed", season.year())
+            ));
+        }
+    }
+    if let Some(season) = current_season {
+        check_season(teams, season, false, &mut issues);
+    }
+    issues
+}
+
+/// Check a single season against its league's teams. An archived season is
+/// validated against its own teams, so a team since removed from the league
+/// is only reported rather than preventing the league from loading.
+fn check_season(teams: &BTreeMap<usize, LeagueTeam>, season: &LeagueSeason, archived: bool, issues: &mut Vec<ValidationIssue>) {
+    let year = season.year();
+
+    // Ensure the season teams are league teams
+    for (id, team) in season.teams().iter() {
+        if teams.contains_key(id) {
+            continue;
+        }
+        if archived {
+            issues.push(ValidationIssue::new(
+                ValidationIssueKind::RetiredTeam,
+                &format!("seasons/{}/teams/{}", year, id),
+                &format!("Season {} contains team {} which is no longer in the league: {}", year, team.name(), id)
+            ));
+        } else {
+            issues.push(ValidationIssue::new(
+                ValidationIssueKind::DanglingTeam,
+                &format!("seasons/{}/teams/{}", year, id),
+                &format!("Season {} contains team {} with nonexistent ID: {}", year, team.name(), id)
+            ));
+        }
+    }
+
+    // Ensure the playoff teams are season teams
+    let playoffs = season.playoffs();
+    for id in playoffs.teams().iter() {
+        if !season.teams().contains_key(&id) {
+            issues.push(ValidationIssue::new(
+                ValidationIssueKind::DanglingPlayoffTeam,
+                &format!("seasons/{}/playoffs/teams/{}", year, id),
+                &format!("Season {} playoffs contain nonexistent season team ID: {}", year, id)
+            ));
+        }
+    }
+
+    // Ensure a title playoff is between the teams tied for the best record
+    if *season.championship_mode() == ChampionshipMode::BestRecord &&
+        !playoffs.conference_brackets().is_empty() &&
+        season.regular_season_complete()
+    {
+        let tied: BTreeSet<usize> = season.best_record_teams().into_iter().collect();
+        let entrants: BTreeSet<usize> = playoffs.teams().iter().collect();
+        if tied != entrants {
+            issues.push(ValidationIssue::new(
+                ValidationIssueKind::ChampionshipMismatch,
+                &format!("seasons/{}/playoffs", year),
+                &format!(
+                    "Season {} title playoff teams {:?} differ from the teams tied for the best record {:?}",
+                    year, entrants, tied
+                )
+            ));
+        }
+    }
+
+    // Ensure each annotation's target still exists
+    for (index, annotation) in season.annotations().iter().enumerate() {
+        if !annotation.target.exists_in(season) {
+            issues.push(ValidationIssue::new(
+                ValidationIssueKind::DanglingAnnotation,
+                &format!("seasons/{}/annotations/{}", year, index),
+                &format!(
+                    "Season {} annotation is attached to a nonexistent target: {:?}",
+                    year, annotation.target
+                )
+            ));
+        }
+    }
+}
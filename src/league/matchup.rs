@@ -146,12 +146,261 @@ impl LeagueTeamRecord {
     pub fn increment_ties(&mut self, n: usize) {
         self.ties += n
     }
+
+    /// Compare the win percentage of this record to another, counting a tie
+    /// as half a win. The comparison is exact, cross-multiplying the integer
+    /// counts rather than dividing them, and a record with no games played
+    /// has a win percentage of zero.
+    ///
+    /// ### Example
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use fbsim_core::league::matchup::LeagueTeamRecord;
+    ///
+    /// // 1-2 and 2-4 are the same win percentage
+    /// let mut a = LeagueTeamRecord::new();
+    /// a.increment_wins(1);
+    /// a.increment_losses(2);
+    /// let mut b = LeagueTeamRecord::new();
+    /// b.increment_wins(2);
+    /// b.increment_losses(4);
+    /// assert_eq!(a.cmp_win_pct(&b), Ordering::Equal);
+    ///
+    /// // 1-1-1 is better than 1-2
+    /// let mut c = LeagueTeamRecord::new();
+    /// c.increment_wins(1);
+    /// c.increment_losses(1);
+    /// c.increment_ties(1);
+    /// assert_eq!(c.cmp_win_pct(&a), Ordering::Greater);
+    /// ```
+    pub fn cmp_win_pct(&self, other: &LeagueTeamRecord) -> std::cmp::Ordering {
+        // Win percentage as the fraction (2 * wins + ties) / (2 * games)
+        let fraction = |record: &LeagueTeamRecord| {
+            let games = record.wins + record.losses + record.ties;
+            if games > 0 {
+                ((2 * record.wins + record.ties) as u128, games as u128)
+            } else {
+                (0, 1)
+            }
+        };
+        let (num_a, den_a) = fraction(self);
+        let (num_b, den_b) = fraction(other);
+        (num_a * den_b).cmp(&(num_b * den_a))
+    }
+
+    /// Format the record as wins-losses-ties
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::matchup::LeagueTeamRecord;
+    ///
+    /// let mut my_record = LeagueTeamRecord::new();
+    /// my_record.increment_wins(10);
+    /// my_record.increment_losses(5);
+    /// my_record.increment_ties(1);
+    /// assert_eq!(my_record.record_string(), "10-5-1");
+    /// ```
+    pub fn record_string(&self) -> String {
+        format!("{}-{}-{}", self.wins, self.losses, self.ties)
+    }
+
+    /// Format the record's win percentage in the conventional three-decimal
+    /// form, counting each tie as half a win.  The percentage is rounded to
+    /// the nearest thousandth with exact halves rounded up.  A record with no
+    /// games is ".000", and only a record without any losses or ties is
+    /// "1.000", so a nearly perfect record is ".999" rather than rounding up
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::matchup::LeagueTeamRecord;
+    ///
+    /// let mut my_record = LeagueTeamRecord::new();
+    /// assert_eq!(my_record.win_pct_string(), ".000");
+    /// my_record.increment_wins(2);
+    /// my_record.increment_losses(1);
+    /// assert_eq!(my_record.win_pct_string(), ".667");
+    /// my_record.increment_ties(1);
+    /// assert_eq!(my_record.win_pct_string(), ".625");
+    /// ```
+    pub fn win_pct_string(&self) -> String {
+        let games = (self.wins + self.losses + self.ties) as u128;
+        if games == 0 {
+            return String::from(".000");
+        }
+        if self.losses == 0 && self.ties == 0 {
+            return String::from("1.000");
+        }
+
+        // Round (2 * wins + ties) / (2 * games) to thousandths in integer
+        // arithmetic so that exact halves always round up
+        let half_wins = (2 * self.wins + self.ties) as u128;
+        let thousandths = (half_wins * 1000 + games) / (2 * games);
+        format!(".{:03}", thousandths.min(999))
+    }
+
+    /// Get the number of games the record trails a leader's record by.  Ties
+    /// count as half a win and half a loss, so they cancel out.  The result
+    /// is negative if the record is ahead of the leader
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::matchup::LeagueTeamRecord;
+    ///
+    /// let mut leader = LeagueTeamRecord::new();
+    /// leader.increment_wins(10);
+    /// leader.increment_losses(4);
+    /// let mut my_record = LeagueTeamRecord::new();
+    /// my_record.increment_wins(8);
+    /// my_record.increment_losses(5);
+    /// my_record.increment_ties(1);
+    /// assert_eq!(my_record.games_back(&leader), 1.5);
+    /// ```
+    pub fn games_back(&self, leader: &LeagueTeamRecord) -> f64 {
+        let behind = (leader.wins + self.losses) as f64;
+        let ahead = (self.wins + leader.losses) as f64;
+        (behind - ahead) / 2.0
+    }
+
+    /// Format the number of games the record trails a leader's record by,
+    /// using an em dash for the leader and any record level with it
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::matchup::LeagueTeamRecord;
+    ///
+    /// let mut leader = LeagueTeamRecord::new();
+    /// leader.increment_wins(10);
+    /// leader.increment_losses(4);
+    /// let mut my_record = LeagueTeamRecord::new();
+    /// my_record.increment_wins(7);
+    /// my_record.increment_losses(6);
+    /// assert_eq!(leader.games_back_string(&leader), "\u{2014}");
+    /// assert_eq!(my_record.games_back_string(&leader), "2.5");
+    /// ```
+    pub fn games_back_string(&self, leader: &LeagueTeamRecord) -> String {
+        format_games_back(self.games_back(leader))
+    }
 }
 
 impl fmt::Display for LeagueTeamRecord {
     /// Display a LeagueTeamRecord as a string
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}-{}-{}", self.wins, self.losses, self.ties)
+        f.write_str(&self.record_string())
+    }
+}
+
+/// Format a number of games back to one decimal place, using an em dash for
+/// zero or fewer games back
+///
+/// ### Example
+/// ```
+/// use fbsim_core::league::matchup::format_games_back;
+///
+/// assert_eq!(format_games_back(0.0), "\u{2014}");
+/// assert_eq!(format_games_back(3.0), "3.0");
+/// assert_eq!(format_games_back(2.5), "2.5");
+/// ```
+pub fn format_games_back(games_back: f64) -> String {
+    if games_back <= 0.0 {
+        String::from("\u{2014}")
+    } else {
+        format!("{:.1}", games_back)
+    }
+}
+
+/// # `LeagueTeamTurnovers` struct
+///
+/// The number of turnovers a team gave away and took away.  May be for a
+/// season or for many seasons.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct LeagueTeamTurnovers {
+    giveaways: usize,
+    takeaways: usize
+}
+
+impl LeagueTeamTurnovers {
+    /// Constructor for the LeagueTeamTurnovers type
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::matchup::LeagueTeamTurnovers;
+    ///
+    /// let my_turnovers = LeagueTeamTurnovers::new();
+    /// ```
+    pub fn new() -> LeagueTeamTurnovers {
+        LeagueTeamTurnovers::default()
+    }
+
+    /// Get the number of turnovers the team committed
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::matchup::LeagueTeamTurnovers;
+    ///
+    /// let my_turnovers = LeagueTeamTurnovers::new();
+    /// assert!(*my_turnovers.giveaways() == 0);
+    /// ```
+    pub fn giveaways(&self) -> &usize {
+        &self.giveaways
+    }
+
+    /// Increment the number of turnovers the team committed
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::matchup::LeagueTeamTurnovers;
+    ///
+    /// let mut my_turnovers = LeagueTeamTurnovers::new();
+    /// my_turnovers.increment_giveaways(2);
+    /// assert!(*my_turnovers.giveaways() == 2);
+    /// ```
+    pub fn increment_giveaways(&mut self, n: usize) {
+        self.giveaways += n
+    }
+
+    /// Get the number of turnovers the team forced
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::matchup::LeagueTeamTurnovers;
+    ///
+    /// let my_turnovers = LeagueTeamTurnovers::new();
+    /// assert!(*my_turnovers.takeaways() == 0);
+    /// ```
+    pub fn takeaways(&self) -> &usize {
+        &self.takeaways
+    }
+
+    /// Increment the number of turnovers the team forced
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::matchup::LeagueTeamTurnovers;
+    ///
+    /// let mut my_turnovers = LeagueTeamTurnovers::new();
+    /// my_turnovers.increment_takeaways(3);
+    /// assert!(*my_turnovers.takeaways() == 3);
+    /// ```
+    pub fn increment_takeaways(&mut self, n: usize) {
+        self.takeaways += n
+    }
+
+    /// Get the turnover margin, the takeaways less the giveaways
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::matchup::LeagueTeamTurnovers;
+    ///
+    /// let mut my_turnovers = LeagueTeamTurnovers::new();
+    /// my_turnovers.increment_giveaways(3);
+    /// my_turnovers.increment_takeaways(1);
+    /// assert!(my_turnovers.margin() == -2);
+    /// ```
+    pub fn margin(&self) -> i64 {
+        self.takeaways as i64 - self.giveaways as i64
     }
 }
 
@@ -244,3 +493,66 @@ impl LeagueMatchups {
         stats
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(wins: usize, losses: usize, ties: usize) -> LeagueTeamRecord {
+        let mut record = LeagueTeamRecord::new();
+        record.increment_wins(wins);
+        record.increment_losses(losses);
+        record.increment_ties(ties);
+        record
+    }
+
+    #[test]
+    fn test_record_formatting() {
+        // Records with no games, all ties, and mixed results
+        let cases = [
+            ((0, 0, 0), "0-0-0", ".000"),
+            ((0, 0, 3), "0-0-3", ".500"),
+            ((3, 0, 0), "3-0-0", "1.000"),
+            ((0, 3, 0), "0-3-0", ".000"),
+            ((2, 1, 0), "2-1-0", ".667"),
+            ((1, 2, 0), "1-2-0", ".333"),
+            ((10, 5, 1), "10-5-1", ".656"),
+            ((7, 0, 1), "7-0-1", ".938"),
+            ((0, 1, 1), "0-1-1", ".250"),
+            ((0, 15, 2), "0-15-2", ".059"),
+            // 0.0625 rounds half up to .063
+            ((1, 15, 0), "1-15-0", ".063"),
+            // 1999/2000 would round to 1.000 without a perfect record
+            ((1999, 1, 0), "1999-1-0", ".999")
+        ];
+        for ((wins, losses, ties), expected_record, expected_pct) in cases {
+            let record = record(wins, losses, ties);
+            assert_eq!(record.record_string(), expected_record);
+            assert_eq!(record.to_string(), expected_record);
+            assert_eq!(record.win_pct_string(), expected_pct, "{}", expected_record);
+        }
+    }
+
+    #[test]
+    fn test_games_back_formatting() {
+        let leader = record(10, 4, 0);
+        let cases = [
+            (record(10, 4, 0), 0.0, "\u{2014}"),
+            (record(11, 3, 0), -1.0, "\u{2014}"),
+            (record(10, 5, 0), 0.5, "0.5"),
+            (record(9, 5, 0), 1.0, "1.0"),
+            (record(8, 5, 1), 1.5, "1.5"),
+            (record(7, 6, 1), 2.5, "2.5"),
+            (record(0, 0, 14), 3.0, "3.0"),
+            (record(0, 0, 0), 3.0, "3.0")
+        ];
+        for (record, expected_back, expected_string) in cases {
+            assert_eq!(record.games_back(&leader), expected_back, "{}", record);
+            assert_eq!(record.games_back_string(&leader), expected_string, "{}", record);
+        }
+
+        // Between two teams without games
+        let empty = LeagueTeamRecord::new();
+        assert_eq!(empty.games_back_string(&empty), "\u{2014}");
+    }
+}
@@ -0,0 +1,160 @@
+#![doc = include_str!("../../docs/league/provenance.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+/// # `LeagueForkPoint` struct
+///
+/// The state of a league at the moment it was forked.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct LeagueForkPoint {
+    /// Number of archived seasons
+    pub seasons: usize,
+    /// Year of the current season, if any
+    pub year: Option<usize>,
+    /// Index of the first incomplete regular season week, if any
+    pub week: Option<usize>
+}
+
+/// # `LeagueProvenance` struct
+///
+/// The lineage of a forked league: its label, where it was forked, the
+/// provenance of the league it was forked from, and the state of the RNG
+/// used for its future simulations.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct LeagueProvenance {
+    label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    parent: Option<Box<LeagueProvenance>>,
+    fork_point: LeagueForkPoint,
+    seed: u64,
+    #[serde(default)]
+    rng_draws: u64
+}
+
+impl LeagueProvenance {
+    /// Constructor for the `LeagueProvenance` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::provenance::{LeagueForkPoint, LeagueProvenance};
+    ///
+    /// let my_provenance = LeagueProvenance::new("alt", None, LeagueForkPoint::default(), 7);
+    /// assert_eq!(my_provenance.label(), "alt");
+    /// ```
+    pub fn new(label: &str, parent: Option<LeagueProvenance>, fork_point: LeagueForkPoint, seed: u64) -> LeagueProvenance {
+        LeagueProvenance {
+            label: String::from(label),
+            parent: parent.map(Box::new),
+            fork_point,
+            seed,
+            rng_draws: 0
+        }
+    }
+
+    /// Get the label given to the fork
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::provenance::{LeagueForkPoint, LeagueProvenance};
+    ///
+    /// let my_provenance = LeagueProvenance::new("alt", None, LeagueForkPoint::default(), 7);
+    /// assert_eq!(my_provenance.label(), "alt");
+    /// ```
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Get the provenance of the league this one was forked from, or `None`
+    /// if the parent was not itself a fork
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::provenance::{LeagueForkPoint, LeagueProvenance};
+    ///
+    /// let root = LeagueProvenance::new("a", None, LeagueForkPoint::default(), 1);
+    /// let child = LeagueProvenance::new("b", Some(root.clone()), LeagueForkPoint::default(), 2);
+    /// assert!(root.parent().is_none());
+    /// assert_eq!(child.parent(), Some(&root));
+    /// ```
+    pub fn parent(&self) -> Option<&LeagueProvenance> {
+        self.parent.as_deref()
+    }
+
+    /// Get the state of the parent league when it was forked
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::provenance::{LeagueForkPoint, LeagueProvenance};
+    ///
+    /// let my_provenance = LeagueProvenance::new("alt", None, LeagueForkPoint::default(), 7);
+    /// assert!(my_provenance.fork_point().year.is_none());
+    /// ```
+    pub fn fork_point(&self) -> &LeagueForkPoint {
+        &self.fork_point
+    }
+
+    /// Get the seed of the fork's RNG
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::provenance::{LeagueForkPoint, LeagueProvenance};
+    ///
+    /// let my_provenance = LeagueProvenance::new("alt", None, LeagueForkPoint::default(), 7);
+    /// assert_eq!(my_provenance.seed(), 7);
+    /// ```
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Get the number of RNGs drawn from the fork's seed so far
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::provenance::{LeagueForkPoint, LeagueProvenance};
+    ///
+    /// let my_provenance = LeagueProvenance::new("alt", None, LeagueForkPoint::default(), 7);
+    /// assert_eq!(my_provenance.rng_draws(), 0);
+    /// ```
+    pub fn rng_draws(&self) -> u64 {
+        self.rng_draws
+    }
+
+    /// Get the labels of the fork's lineage, from the oldest ancestor fork
+    /// to this one
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::provenance::{LeagueForkPoint, LeagueProvenance};
+    ///
+    /// let root = LeagueProvenance::new("a", None, LeagueForkPoint::default(), 1);
+    /// let child = LeagueProvenance::new("b", Some(root), LeagueForkPoint::default(), 2);
+    /// assert_eq!(child.lineage(), vec!["a", "b"]);
+    /// ```
+    pub fn lineage(&self) -> Vec<&str> {
+        let mut lineage: Vec<&str> = Vec::new();
+        let mut provenance = Some(self);
+        while let Some(p) = provenance {
+            lineage.push(p.label());
+            provenance = p.parent();
+        }
+        lineage.reverse();
+        lineage
+    }
+
+    /// Derive the seed of the next RNG drawn from the fork's seed, advancing
+    /// the draw count so that successive draws differ
+    pub(crate) fn next_rng_seed(&mut self) -> u64 {
+        let seed = self.seed ^ self.rng_draws.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        self.rng_draws += 1;
+        seed
+    }
+}
@@ -1,30 +1,139 @@
 #![doc = include_str!("../../docs/league/season.md")]
+pub mod annotation;
+pub mod attendance;
 pub mod conference;
+pub mod dashboard;
+pub mod edit;
+#[cfg(feature = "export")]
+pub mod export;
+pub mod head_to_head;
+pub mod history;
+pub mod honors;
+pub mod import;
+pub mod lint;
 pub mod matchup;
+pub mod notification;
 pub mod playoffs;
+pub mod prediction;
+pub mod proposal;
+pub mod report;
+pub mod scoreboard;
+pub mod stats;
+pub mod tiebreaker;
+pub mod trade;
+pub mod venue;
 pub mod week;
 
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
 
+use crate::league::error::LeagueError;
 use crate::team::FootballTeam;
-use crate::league::matchup::LeagueTeamRecord;
+use crate::league::matchup::{LeagueTeamRecord, LeagueTeamTurnovers};
+use crate::league::season::annotation::{AnnotationTarget, LeagueSeasonAnnotation};
+use crate::league::season::attendance::AttendanceModel;
 use crate::league::season::conference::{LeagueConference, LeagueDivision};
-use crate::league::season::week::LeagueSeasonWeek;
-use crate::league::season::matchup::{LeagueSeasonMatchup, LeagueSeasonMatchups};
-use crate::league::season::playoffs::LeagueSeasonPlayoffs;
-use crate::league::season::playoffs::picture::PlayoffPicture;
+use crate::league::season::dashboard::{SeasonDashboard, SeasonDashboardOptions};
+use crate::league::season::head_to_head::{HeadToHeadGame, HeadToHeadSeries};
+use crate::league::season::history::{StandingsHistory, StandingsHistoryOptions};
+use crate::league::season::honors::SeasonHonors;
+use crate::league::season::import::{TeamDefinition, TeamDefinitions};
+use crate::league::season::lint::SeasonLint;
+use crate::league::season::notification::{Notification, NotificationGame, StandingsRanks, playoff_changes};
+use crate::league::season::week::{LeagueSeasonWeek, MatchupSimOutcome, MatchupSimReport, WeekSimOptions, WeekSimReport};
+use crate::league::fingerprint::{FingerprintCache, Fingerprinter};
+use crate::league::season::matchup::{LeagueSeasonMatchup, LeagueSeasonMatchupBuilder, LeagueSeasonMatchups, ScoreAnomaly};
+use crate::league::season::playoffs::{LeagueSeasonPlayoffs, SeedingRule};
+use crate::league::season::playoffs::picture::{PlayoffPicture, PlayoffPictureOptions, RemainingGames};
+use crate::league::season::prediction::{PredictedResult, PredictionAccuracy};
+use crate::league::season::proposal::{ScheduleBalance, ScheduleProposal};
+use crate::league::season::trade::{LeagueSeasonTrade, LeagueSeasonTradeRecord};
+use crate::league::season::venue::{HomeAwayRebalance, LeagueSeasonVenueSwap};
+use crate::league::season::edit::{LeagueSeasonResultEdit, ResultEditAction};
+use crate::league::season::tiebreaker::{records_tied, TiebreakerExplanation, TiebreakerRule, Tiebreakers};
+use crate::league::season::report::{season_report, SeasonReportFormat, SeasonReportOptions};
+use crate::league::season::stats::LeagueSeasonTeamStats;
+use crate::game::context::GameContext;
+use crate::game::context::overtime::OvertimeRules;
+use crate::game::context::weather::WeatherDistribution;
 use crate::game::matchup::FootballMatchupResult;
 use crate::game::play::{Game, GameSimulator};
+use crate::game::score::{ScoreBounds, ScoreSimulatable};
 
 #[cfg(feature = "rocket_okapi")]
 use rocket_okapi::okapi::schemars;
 #[cfg(feature = "rocket_okapi")]
 use rocket_okapi::okapi::schemars::JsonSchema;
+#[cfg(feature = "chrono")]
 use chrono::Datelike;
-use rand::Rng;
+use rand::{Rng, RngCore, SeedableRng};
+use rand::rngs::SmallRng;
 use rand::seq::SliceRandom;
 use serde::{Serialize, Deserialize, Deserializer};
 
+/// Sort standings by win percentage (descending), then by wins
+/// (descending), then by team ID (ascending)
+///
+/// Win percentages are compared exactly with
+/// `LeagueTeamRecord::cmp_win_pct`, and the team ID makes the order total,
+/// so equal records always sort the same way regardless of input order.
+pub(crate) fn sort_standings(standings: &mut [(usize, LeagueTeamRecord)]) {
+    standings.sort_by(|(id_a, rec_a), (id_b, rec_b)| {
+        rec_b.cmp_win_pct(rec_a)
+            .then_with(|| rec_b.wins().cmp(rec_a.wins()))
+            .then_with(|| id_a.cmp(id_b))
+    });
+}
+
+/// Compute the standings of a season's teams from the first `num_weeks`
+/// regular season weeks, ordered by the tiebreaker chain
+fn standings_from_parts(
+    teams: &BTreeMap<usize, FootballTeam>,
+    conferences: &[LeagueConference],
+    weeks: &[LeagueSeasonWeek],
+    num_weeks: usize,
+) -> Vec<(usize, LeagueTeamRecord)> {
+    let mut standings: Vec<(usize, LeagueTeamRecord)> = Vec::new();
+    for id in teams.keys() {
+        let matchups = weeks.iter()
+            .take(num_weeks)
+            .map(|week| week.team_matchup(*id))
+            .collect();
+        standings.push((*id, LeagueSeasonMatchups::new(*id, matchups).record()));
+    }
+    sort_standings(&mut standings);
+    Tiebreakers::from_parts(conferences, weeks, num_weeks).break_ties(&mut standings);
+    standings
+}
+
+/// Collect the teams tied for the best record in standings ordered by the
+/// tiebreaker chain, those the chain cannot separate from the leader without
+/// falling back on team ID
+pub(crate) fn best_record_teams(standings: &[(usize, LeagueTeamRecord)], tiebreakers: &Tiebreakers) -> Vec<usize> {
+    let (leader, leader_record) = match standings.first() {
+        Some((id, record)) => (*id, record),
+        None => return Vec::new(),
+    };
+    let group: Vec<usize> = standings.iter()
+        .take_while(|(_, record)| records_tied(record, leader_record))
+        .map(|(id, _)| *id)
+        .collect();
+    tiebreakers.still_tied(group, leader)
+}
+
+/// Get the number of slots in a round robin of the given number of teams,
+/// which with an odd number of teams includes an empty slot for the bye
+fn schedule_slots(num_teams: usize) -> usize {
+    num_teams + num_teams % 2
+}
+
+/// Check if conference-aware (structured) scheduling is needed for a set of
+/// conferences: if multiple conferences exist, or any conference has
+/// multiple divisions
+fn needs_structured_scheduling(conferences: &[LeagueConference]) -> bool {
+    conferences.len() > 1
+        || conferences.iter().any(|c| c.divisions().len() > 1)
+}
+
 /// # `LeagueSeasonRaw` struct
 ///
 /// A `LeagueSeasonRaw` represents a freshly deserialized `LeagueSeason` prior
@@ -33,14 +142,59 @@ use serde::{Serialize, Deserialize, Deserializer};
 pub struct LeagueSeasonRaw {
     pub year: usize,
     pub teams: BTreeMap<usize, FootballTeam>,
+    /// Conferences in the season (default: no conferences)
     #[serde(default)]
     pub conferences: Vec<LeagueConference>,
     pub weeks: Vec<LeagueSeasonWeek>,
-    pub playoffs: LeagueSeasonPlayoffs
+    pub playoffs: LeagueSeasonPlayoffs,
+    /// Format the schedule was generated with (default: round robin)
+    #[serde(default)]
+    pub schedule_format: ScheduleFormat,
+    /// How the season champion is decided (default: playoffs)
+    #[serde(default)]
+    pub championship_mode: ChampionshipMode,
+    /// Week before which trades must be applied (default: no deadline)
+    #[serde(default)]
+    pub trade_deadline: Option<usize>,
+    /// Trades applied during the season (default: no trades)
+    #[serde(default)]
+    pub trades: Vec<LeagueSeasonTradeRecord>,
+    /// Model used to record per-game attendance (default: no attendance)
+    #[serde(default)]
+    pub attendance_model: Option<AttendanceModel>,
+    /// Distribution each game's weather is drawn from (default: every game
+    /// is played in clear weather)
+    #[serde(default)]
+    pub weather_distribution: Option<WeatherDistribution>,
+    /// Matchups whose home and away teams were swapped (default: no swaps)
+    #[serde(default)]
+    pub venue_swaps: Vec<LeagueSeasonVenueSwap>,
+    /// Whether completed results must be reopened before being changed
+    /// (default: not strict)
+    #[serde(default)]
+    pub strict: bool,
+    /// Matchup results entered or reopened by hand (default: no edits)
+    #[serde(default)]
+    pub result_edits: Vec<LeagueSeasonResultEdit>,
+    /// Seed from which each regular season game's RNG is derived (default:
+    /// games use the caller's RNG)
+    #[serde(default)]
+    pub sim_seed: Option<u64>,
+    /// Notes attached to parts of the season (default: no notes)
+    #[serde(default)]
+    pub annotations: Vec<LeagueSeasonAnnotation>
 }
 
+/// Get the current year from the system clock
+#[cfg(feature = "chrono")]
+fn current_year() -> usize {
+    chrono::Utc::now().year() as usize
+}
+
+#[cfg(feature = "chrono")]
 impl Default for LeagueSeasonRaw {
-    /// Default constructor for the `LeagueSeasonRaw` struct
+    /// Default constructor for the `LeagueSeasonRaw` struct, with the year
+    /// defaulting to the current year. Requires the `chrono` feature.
     ///
     /// ### Example
     /// ```
@@ -49,18 +203,43 @@ impl Default for LeagueSeasonRaw {
     /// let raw_league_season = LeagueSeasonRaw::default();
     /// ```
     fn default() -> Self {
+        LeagueSeasonRaw::with_year(current_year())
+    }
+}
+
+impl LeagueSeasonRaw {
+    /// Constructor for the `LeagueSeasonRaw` struct with the given year
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeasonRaw;
+    ///
+    /// let raw_league_season = LeagueSeasonRaw::with_year(2026);
+    /// assert_eq!(raw_league_season.year, 2026);
+    /// ```
+    pub fn with_year(year: usize) -> LeagueSeasonRaw {
         LeagueSeasonRaw{
-            year: chrono::Utc::now().year() as usize,
+            year,
             teams: BTreeMap::new(),
             conferences: Vec::new(),
             weeks: Vec::new(),
-            playoffs: LeagueSeasonPlayoffs::new()
+            playoffs: LeagueSeasonPlayoffs::new(),
+            schedule_format: ScheduleFormat::default(),
+            championship_mode: ChampionshipMode::default(),
+            trade_deadline: None,
+            trades: Vec::new(),
+            attendance_model: None,
+            weather_distribution: None,
+            venue_swaps: Vec::new(),
+            strict: false,
+            result_edits: Vec::new(),
+            sim_seed: None,
+            annotations: Vec::new()
         }
     }
-}
 
-impl LeagueSeasonRaw {
-    /// Constructor for the `LeagueSeasonRaw` struct
+    /// Constructor for the `LeagueSeasonRaw` struct, with the year
+    /// defaulting to the current year. Requires the `chrono` feature.
     ///
     /// ### Example
     /// ```
@@ -68,6 +247,7 @@ impl LeagueSeasonRaw {
     ///
     /// let raw_league_season = LeagueSeasonRaw::new();
     /// ```
+    #[cfg(feature = "chrono")]
     pub fn new() -> LeagueSeasonRaw {
         LeagueSeasonRaw::default()
     }
@@ -122,6 +302,11 @@ impl LeagueSeasonRaw {
 
     /// Determine whether the season is complete
     ///
+    /// With `ChampionshipMode::BestRecord`, the raw season is complete once
+    /// the regular season is complete and no teams remain tied for the best
+    /// record, or once the title playoff between the tied teams is complete,
+    /// exactly as for `LeagueSeason::complete`.
+    ///
     /// ### Example
     /// ```
     /// use fbsim_core::league::season::LeagueSeasonRaw;
@@ -132,6 +317,11 @@ impl LeagueSeasonRaw {
     pub fn complete(&self) -> bool {
         if !self.regular_season_complete() {
             false
+        } else if self.championship_mode == ChampionshipMode::BestRecord && self.playoffs.conference_brackets().is_empty() {
+            let num_weeks = self.weeks.len();
+            let standings = standings_from_parts(&self.teams, &self.conferences, &self.weeks, num_weeks);
+            let tiebreakers = Tiebreakers::from_parts(&self.conferences, &self.weeks, num_weeks);
+            best_record_teams(&standings, &tiebreakers).len() < 2
         } else {
             self.playoffs.complete()
         }
@@ -146,34 +336,57 @@ impl LeagueSeasonRaw {
     /// let raw_league_season = LeagueSeasonRaw::new();
     /// let valid_res = raw_league_season.validate();
     /// ```
-    pub fn validate(&self) -> Result<(), String> {
-        // If a schedule has been generated
+    pub fn validate(&self) -> Result<(), LeagueError> {
+        // If a round robin schedule has been generated over a single
+        // division. Conferences or divisions are scheduled by their division,
+        // conference, and cross-conference games instead.
         let num_weeks = self.weeks.len();
-        if num_weeks > 0 {
+        let structured = needs_structured_scheduling(&self.conferences);
+        if num_weeks > 0 && self.schedule_format == ScheduleFormat::RoundRobin && !structured {
             let num_teams = self.teams.len();
             
-            // Check whether the number of games is between the prescribed min and max
-            let max_num_weeks = (num_teams - 1) * 3;
+            // Check whether the number of games is between the prescribed min
+            // and max, counting the bye as a team if the number of teams is odd
+            let max_num_weeks = (schedule_slots(num_teams) - 1) * 3;
             if num_weeks > max_num_weeks {
                 return Err(
-                    format!(
-                        "Schedule can involve teams playing each other team at most 3 times ({} games): {} given",
-                        max_num_weeks, num_weeks
-                    )
+                    LeagueError::InvalidSchedule {
+                        reason: format!(
+                            "Schedule can involve teams playing each other team at most 3 times ({} games): {} given",
+                            max_num_weeks, num_weeks
+                        )
+                    }
                 )
             }
             if num_weeks < num_teams {
                 return Err(
-                    format!(
-                        "Schedule must involve teams playing each other team at least 1 time ({} games): {} given",
-                        num_teams, num_weeks
-                    )
+                    LeagueError::InvalidSchedule {
+                        reason: format!(
+                            "Schedule must involve teams playing each other team at least 1 time ({} games): {} given",
+                            num_teams, num_weeks
+                        )
+                    }
+                )
+            }
+        }
+
+        // If a partial schedule has been generated, ensure it spans enough weeks
+        if let ScheduleFormat::Partial { games_per_team } = self.schedule_format {
+            if num_weeks > 0 && num_weeks < games_per_team {
+                return Err(
+                    LeagueError::InvalidSchedule {
+                        reason: format!(
+                            "Partial schedule of {} games per team must span at least {} weeks: {} given",
+                            games_per_team, games_per_team, num_weeks
+                        )
+                    }
                 )
             }
         }
 
-        // Ensure if the season is started or complete that there are an even
-        // number of teams greater than 4
+        // Ensure if the season is started or complete that there are at
+        // least 4 teams. An odd number of teams is allowed, with one team on
+        // a bye each week.
         if self.started() {
             let num_teams = self.teams.len();
             if num_teams < 4 {
@@ -182,16 +395,7 @@ impl LeagueSeasonRaw {
                         "Season {} has started, but has fewer than 4 team(s): {}",
                         self.year,
                         num_teams
-                    )
-                );
-            }
-            if !num_teams.is_multiple_of(2) {
-                return Err(
-                    format!(
-                        "Season {} has started, but has an odd number of teams: {}",
-                        self.year,
-                        num_teams
-                    )
+                    ).into()
                 );
             }
         }
@@ -205,23 +409,25 @@ impl LeagueSeasonRaw {
                     return Err(format!(
                         "Conference {} ('{}') references nonexistent team ID: {}",
                         conf_index, conference.name(), team_id
-                    ));
+                    ).into());
                 }
                 // Ensure no duplicate team IDs across conferences
                 if !seen_conference_teams.insert(team_id) {
                     return Err(format!(
                         "Duplicate team ID {} found across conferences (detected in conference {} '{}')",
                         team_id, conf_index, conference.name()
-                    ));
+                    ).into());
                 }
             }
         }
 
         // Validate the season weeks
+        let team_ids: HashSet<usize> = self.teams.keys().copied().collect();
+        let mut found_ids: HashSet<usize> = HashSet::new();
         let mut prev_started: bool = false;
         let mut prev_completed: bool = false;
         for (i, week) in self.weeks.iter().enumerate() {
-            let mut found_ids: Vec<usize> = Vec::new();
+            found_ids.clear();
 
             // Ensure later weeks are not simulated before earlier weeks
             let week_started = week.started();
@@ -231,7 +437,7 @@ impl LeagueSeasonRaw {
                     format!(
                         "Season {} week {} is started but a previous week is not",
                         self.year, i
-                    )
+                    ).into()
                 );
             }
             if i > 0 && !prev_completed && week_complete {
@@ -239,7 +445,7 @@ impl LeagueSeasonRaw {
                     format!(
                         "Season {} week {} is complete but a previous week is not",
                         self.year, i
-                    )
+                    ).into()
                 );
             }
             prev_started = week_started;
@@ -250,24 +456,24 @@ impl LeagueSeasonRaw {
                 let away_id = matchup.away_team();
 
                 // Ensure all matchups map to valid season team IDs
-                if !self.teams.contains_key(home_id) {
+                if !team_ids.contains(home_id) {
                     return Err(
                         format!(
                             "Season {} week {} matchup {} contains nonexistent home team ID: {}",
                             self.year,
                             i, j,
                             home_id
-                        )
+                        ).into()
                     );
                 }
-                if !self.teams.contains_key(away_id) {
+                if !team_ids.contains(away_id) {
                     return Err(
                         format!(
                             "Season {} week {} matchup {} contains nonexistent away team ID: {}",
                             self.year,
                             i, j,
                             away_id
-                        )
+                        ).into()
                     )
                 }
 
@@ -279,7 +485,7 @@ impl LeagueSeasonRaw {
                             home_id,
                             self.year,
                             i, j
-                        )
+                        ).into()
                     )
                 }
                 if found_ids.contains(away_id) {
@@ -289,17 +495,199 @@ impl LeagueSeasonRaw {
                             away_id,
                             self.year,
                             i, j
-                        )
+                        ).into()
                     )
                 }
-                found_ids.push(*home_id);
-                found_ids.push(*away_id);
+                found_ids.insert(*home_id);
+                found_ids.insert(*away_id);
+            }
+        }
+
+        // Validate the trade ledger
+        for record in self.trades.iter() {
+            let trade = record.trade();
+            trade.validate()?;
+            for id in [trade.team_a, trade.team_b] {
+                if !team_ids.contains(&id) {
+                    return Err(
+                        format!(
+                            "Season {} week {} trade references nonexistent team ID: {}",
+                            self.year, record.week(), id
+                        ).into()
+                    );
+                }
+            }
+            if record.week() >= num_weeks {
+                return Err(
+                    format!(
+                        "Season {} trade references nonexistent week: {}",
+                        self.year, record.week()
+                    ).into()
+                );
+            }
+            if let Some(deadline) = self.trade_deadline {
+                if record.week() >= deadline {
+                    return Err(
+                        format!(
+                            "Season {} week {} trade is not before the trade deadline week: {}",
+                            self.year, record.week(), deadline
+                        ).into()
+                    );
+                }
+            }
+        }
+
+        // Validate the venue swap ledger
+        for swap in self.venue_swaps.iter() {
+            for id in [swap.home_team, swap.away_team] {
+                if !team_ids.contains(&id) {
+                    return Err(
+                        format!(
+                            "Season {} week {} venue swap references nonexistent team ID: {}",
+                            self.year, swap.week, id
+                        ).into()
+                    );
+                }
+            }
+            let in_range = self.weeks.get(swap.week)
+                .is_some_and(|week| swap.matchup < week.matchups().len());
+            if !in_range {
+                return Err(
+                    format!(
+                        "Season {} venue swap references nonexistent week {} matchup {}",
+                        self.year, swap.week, swap.matchup
+                    ).into()
+                );
+            }
+        }
+
+        // Validate the result edit ledger
+        for edit in self.result_edits.iter() {
+            let in_range = self.weeks.get(edit.week)
+                .is_some_and(|week| edit.matchup < week.matchups().len());
+            if !in_range {
+                return Err(
+                    format!(
+                        "Season {} result edit references nonexistent week {} matchup {}",
+                        self.year, edit.week, edit.matchup
+                    ).into()
+                );
+            }
+        }
+
+        // Validate the annotation text. Annotations whose targets no longer
+        // exist are kept, and reported by the league's integrity check
+        for annotation in self.annotations.iter() {
+            annotation.validate().map_err(|e| format!("Season {} annotation: {}", self.year, e))?;
+        }
+
+        // Validate the weather distribution
+        if let Some(distribution) = &self.weather_distribution {
+            distribution.validate().map_err(|e| format!("Season {} weather: {}", self.year, e))?;
+        }
+
+        // Validate the attendance model and recorded attendance
+        if let Some(model) = &self.attendance_model {
+            model.validate()?;
+        }
+        let playoff_weeks = self.playoffs.conference_brackets().values()
+            .flatten()
+            .chain(self.playoffs.winners_bracket().iter());
+        for week in self.weeks.iter().chain(playoff_weeks) {
+            for matchup in week.matchups().iter() {
+                let attendance = match matchup.attendance() {
+                    Some(a) => a,
+                    None => continue
+                };
+                let capacity = match self.teams.get(matchup.home_team()) {
+                    Some(team) => team.stadium_capacity(),
+                    None => continue
+                };
+                if attendance > capacity {
+                    return Err(
+                        format!(
+                            "Season {} matchup {} vs. {} has attendance {} exceeding stadium capacity {}",
+                            self.year,
+                            matchup.away_team(),
+                            matchup.home_team(),
+                            attendance,
+                            capacity
+                        ).into()
+                    );
+                }
             }
         }
         Ok(())
     }
 }
 
+/// # `ScheduleFormat` enum
+///
+/// A `ScheduleFormat` describes how many opponents each team plays in a
+/// generated season schedule
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub enum ScheduleFormat {
+    /// Each team plays each other team at least once (default)
+    #[default]
+    RoundRobin,
+    /// Each team plays a fixed number of games, one per week, against as
+    /// few repeat opponents as possible
+    Partial {
+        games_per_team: usize
+    }
+}
+
+impl ScheduleFormat {
+    /// Whether the schedule format is the default round robin format
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::ScheduleFormat;
+    ///
+    /// let my_format = ScheduleFormat::default();
+    /// assert!(my_format.is_round_robin());
+    /// ```
+    pub fn is_round_robin(&self) -> bool {
+        matches!(self, ScheduleFormat::RoundRobin)
+    }
+}
+
+/// # `ChampionshipMode` enum
+///
+/// A `ChampionshipMode` describes how the champion of a season is decided
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub enum ChampionshipMode {
+    /// The champion is the winner of the playoffs, whose bracket is
+    /// configured by the `LeagueSeasonPlayoffOptions` (default)
+    #[default]
+    Playoffs,
+    /// The champion is the team with the best regular season record, with a
+    /// title playoff between any teams still tied after every tiebreaker but
+    /// team ID
+    BestRecord
+}
+
+impl ChampionshipMode {
+    /// Whether the championship mode is the default playoffs mode
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::ChampionshipMode;
+    ///
+    /// let my_mode = ChampionshipMode::default();
+    /// assert!(my_mode.is_playoffs());
+    /// ```
+    pub fn is_playoffs(&self) -> bool {
+        matches!(self, ChampionshipMode::Playoffs)
+    }
+}
+
 /// # `LeagueSeasonScheduleOptions` struct
 ///
 /// A `LeagueSeasonScheduleOptions` represents a collection of options used
@@ -317,6 +705,21 @@ pub struct LeagueSeasonScheduleOptions {
     pub conference_games: Option<usize>,
     /// Total number of cross-conference games per team (default: 0)
     pub cross_conference_games: Option<usize>,
+    /// Exact number of games each team plays against the other of two
+    /// conferences, each against a different opponent, with home and away
+    /// games balanced (default: none)
+    pub interconference_games: Option<usize>,
+    /// Schedule format (default: round robin)
+    #[serde(default)]
+    pub format: ScheduleFormat,
+    /// Maximum number of meetings between any pair of teams (default: no
+    /// limit beyond the format's own)
+    pub max_meetings_per_opponent: Option<usize>,
+    /// Exact number of meetings between every pair of teams (default: none)
+    pub exact_meetings: Option<usize>,
+    /// Whether a season with an odd number of teams may be scheduled with
+    /// one team on a bye each week (default: true)
+    pub allow_byes: Option<bool>,
 }
 
 impl Default for LeagueSeasonScheduleOptions {
@@ -336,6 +739,11 @@ impl Default for LeagueSeasonScheduleOptions {
             division_games: None,
             conference_games: None,
             cross_conference_games: None,
+            interconference_games: None,
+            format: ScheduleFormat::RoundRobin,
+            max_meetings_per_opponent: None,
+            exact_meetings: None,
+            allow_byes: None,
         }
     }
 }
@@ -352,1374 +760,6284 @@ impl LeagueSeasonScheduleOptions {
     pub fn new() -> LeagueSeasonScheduleOptions {
         LeagueSeasonScheduleOptions::default()
     }
-}
-
-/// # `LeagueSeasonPlayoffOptions` struct
-///
-/// Options for generating playoffs. Supports both single-bracket and
-/// multi-conference bracket modes.
-#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
-#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
-pub struct LeagueSeasonPlayoffOptions {
-    /// Total number of playoff teams (used when not using conference brackets)
-    pub num_playoff_teams: usize,
-    /// If true and the league has multiple conferences, use separate conference
-    /// brackets plus a winners bracket for conference champions
-    pub use_conference_brackets: bool,
-    /// Number of playoff teams per conference (only used when
-    /// `use_conference_brackets` is true and the league has multiple conferences)
-    pub playoff_teams_per_conference: usize,
-    /// If true, division winners are guaranteed playoff spots regardless of
-    /// record (only used when `use_conference_brackets` is true)
-    pub division_winners_guaranteed: bool,
-}
-
-impl Default for LeagueSeasonPlayoffOptions {
-    fn default() -> Self {
-        LeagueSeasonPlayoffOptions {
-            num_playoff_teams: 2,
-            use_conference_brackets: false,
-            playoff_teams_per_conference: 2,
-            division_winners_guaranteed: false,
-        }
-    }
-}
 
-impl LeagueSeasonPlayoffOptions {
-    /// Constructor for the `LeagueSeasonPlayoffOptions` struct
+    /// Set the number of weeks to schedule
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
     ///
-    /// let my_playoff_options = LeagueSeasonPlayoffOptions::new();
+    /// let my_options = LeagueSeasonScheduleOptions::new()
+    ///     .weeks(16);
+    /// assert!(my_options.weeks == Some(16));
     /// ```
-    pub fn new() -> LeagueSeasonPlayoffOptions {
-        LeagueSeasonPlayoffOptions::default()
-    }
-}
-
-/// # `LeagueSeason` struct
-///
-/// A `LeagueSeason` represents a season of a football league.
-#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize)]
-pub struct LeagueSeason {
-    year: usize,
-    teams: BTreeMap<usize, FootballTeam>,
-    conferences: Vec<LeagueConference>,
-    weeks: Vec<LeagueSeasonWeek>,
-    playoffs: LeagueSeasonPlayoffs
-}
-
-impl TryFrom<LeagueSeasonRaw> for LeagueSeason {
-    type Error = String;
-
-    fn try_from(item: LeagueSeasonRaw) -> Result<Self, Self::Error> {
-        // Validate the raw season
-        match item.validate() {
-            Ok(()) => (),
-            Err(error) => return Err(error),
-        };
-
-        // If valid, then convert
-        Ok(
-            LeagueSeason{
-                year: item.year,
-                teams: item.teams,
-                conferences: item.conferences,
-                weeks: item.weeks,
-                playoffs: item.playoffs
-            }
-        )
-    }
-}
-
-impl<'de> Deserialize<'de> for LeagueSeason {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        // Only deserialize if the conversion from raw succeeds
-        let raw = LeagueSeasonRaw::deserialize(deserializer)?;
-        LeagueSeason::try_from(raw).map_err(serde::de::Error::custom)
+    pub fn weeks(mut self, weeks: usize) -> Self {
+        self.weeks = Some(weeks);
+        self
     }
-}
 
-impl Default for LeagueSeason {
-    /// Default constructor for the `LeagueSeason` struct, with the year
-    /// defaulting to the current year
+    /// Set the number of weeks to shift the schedule by
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
     ///
-    /// let my_league_season = LeagueSeason::default();
+    /// let my_options = LeagueSeasonScheduleOptions::new()
+    ///     .shift(2);
+    /// assert!(my_options.shift == Some(2));
     /// ```
-    fn default() -> Self {
-        LeagueSeason{
-            year: chrono::Utc::now().year() as usize,
-            teams: BTreeMap::new(),
-            conferences: Vec::new(),
-            weeks: Vec::new(),
-            playoffs: LeagueSeasonPlayoffs::new()
-        }
+    pub fn shift(mut self, shift: usize) -> Self {
+        self.shift = Some(shift);
+        self
     }
-}
 
-impl LeagueSeason {
-    /// Constructor for the `LeagueSeason` struct, with the year
-    /// defaulting to the current year
+    /// Set the whether to permute the schedule
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
     ///
-    /// let my_league_season = LeagueSeason::new();
+    /// let my_options = LeagueSeasonScheduleOptions::new()
+    ///     .permute(true);
+    /// assert!(my_options.permute == Some(true));
     /// ```
-    pub fn new() -> LeagueSeason {
-        LeagueSeason::default()
+    pub fn permute(mut self, permute: bool) -> Self {
+        self.permute = Some(permute);
+        self
     }
 
-    /// Borrow the year the season took place
+    /// Set the number of games per division opponent
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
     ///
-    /// let my_league_season = LeagueSeason::new();
-    /// let my_season_year = my_league_season.year();
+    /// let my_options = LeagueSeasonScheduleOptions::new()
+    ///     .division_games(2);
+    /// assert!(my_options.division_games == Some(2));
     /// ```
-    pub fn year(&self) -> &usize {
-        &self.year
+    pub fn division_games(mut self, division_games: usize) -> Self {
+        self.division_games = Some(division_games);
+        self
     }
 
-    /// Mutably borrow the year the season took place
+    /// Set the number of games per non-division conference opponent
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
     ///
-    /// let mut my_league_season = LeagueSeason::new();
-    /// let mut my_season_year = my_league_season.year_mut();
+    /// let my_options = LeagueSeasonScheduleOptions::new()
+    ///     .conference_games(1);
+    /// assert!(my_options.conference_games == Some(1));
     /// ```
-    pub fn year_mut(&mut self) -> &mut usize {
-        &mut self.year
+    pub fn conference_games(mut self, conference_games: usize) -> Self {
+        self.conference_games = Some(conference_games);
+        self
     }
 
-    /// Borrow the teams which competed in the season
+    /// Set the total number of cross-conference games per team
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
     ///
-    /// let my_league_season = LeagueSeason::new();
-    /// let my_season_teams = my_league_season.teams();
+    /// let my_options = LeagueSeasonScheduleOptions::new()
+    ///     .cross_conference_games(4);
+    /// assert!(my_options.cross_conference_games == Some(4));
     /// ```
-    pub fn teams(&self) -> &BTreeMap<usize, FootballTeam> {
-        &self.teams
+    pub fn cross_conference_games(mut self, cross_conference_games: usize) -> Self {
+        self.cross_conference_games = Some(cross_conference_games);
+        self
     }
 
-    /// Mutably borrow the year the season took place
+    /// Set the exact number of games each team plays against the other
+    /// conference
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
     ///
-    /// let mut my_league_season = LeagueSeason::new();
-    /// let mut my_season_teams = my_league_season.teams_mut();
+    /// let my_options = LeagueSeasonScheduleOptions::new()
+    ///     .interconference_games(2);
+    /// assert!(my_options.interconference_games == Some(2));
     /// ```
-    pub fn teams_mut(&mut self) -> &mut BTreeMap<usize, FootballTeam> {
-        &mut self.teams
+    pub fn interconference_games(mut self, interconference_games: usize) -> Self {
+        self.interconference_games = Some(interconference_games);
+        self
     }
 
-    /// Add a team to the season
+    /// Set the schedule format
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::team::FootballTeam;
-    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::{LeagueSeasonScheduleOptions, ScheduleFormat};
     ///
-    /// let mut my_league_season = LeagueSeason::new();
-    /// let my_season_team = FootballTeam::new();
-    /// my_league_season.add_team(0, my_season_team);
+    /// let my_options = LeagueSeasonScheduleOptions::new()
+    ///     .format(ScheduleFormat::Partial { games_per_team: 10 });
+    /// assert!(my_options.format == ScheduleFormat::Partial { games_per_team: 10 });
     /// ```
-    pub fn add_team(&mut self, id: usize, team: FootballTeam) -> Result<(), String> {
-        // Ensure the season has not already started
-        if self.started() {
-            return Err("Season has already started, cannot add new team".to_string());
-        }
-
-        // Ensure the given ID is unique
-        if self.teams.contains_key(&id) {
-            return Err(format!("Team with ID {} already exists", id));
-        }
-
-        // Add the team
-        self.teams.insert(id, team);
-        Ok(())
+    pub fn format(mut self, format: ScheduleFormat) -> Self {
+        self.format = format;
+        self
     }
 
-    /// Check if a team exists in the season
+    /// Set the maximum number of times any two teams may meet
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::team::FootballTeam;
-    /// use fbsim_core::league::season::LeagueSeason;
-    ///
-    /// // Create a new season
-    /// let mut my_league_season = LeagueSeason::new();
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
     ///
-    /// // Team 0 should not exist at this point
-    /// assert!(!my_league_season.team_exists(0));
+    /// let my_options = LeagueSeasonScheduleOptions::new().max_meetings_per_opponent(2);
+    /// assert!(my_options.max_meetings_per_opponent == Some(2));
+    /// ```
+    pub fn max_meetings_per_opponent(mut self, max_meetings: usize) -> Self {
+        self.max_meetings_per_opponent = Some(max_meetings);
+        self
+    }
+
+    /// Require every two teams to meet exactly the given number of times.
+    /// A round robin schedule without a number of weeks then spans exactly
+    /// enough weeks for that many meetings.
     ///
-    /// // Add team 0 to the season
-    /// let my_season_team = FootballTeam::new();
-    /// my_league_season.add_team(0, my_season_team);
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
     ///
-    /// // Team 0 should now exist
-    /// assert!(my_league_season.team_exists(0));
+    /// let my_options = LeagueSeasonScheduleOptions::new().exact_meetings(2);
+    /// assert!(my_options.exact_meetings == Some(2));
     /// ```
-    pub fn team_exists(&self, id: usize) -> bool {
-        self.teams.contains_key(&id)
+    pub fn exact_meetings(mut self, meetings: usize) -> Self {
+        self.exact_meetings = Some(meetings);
+        self
     }
 
-    /// Borrows an immutable `FootballTeam` from a `LeagueSeason` given
-    /// the team ID
+    /// Set whether a season with an odd number of teams may be scheduled
+    /// with one team on a bye each week
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::team::FootballTeam;
-    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
     ///
-    /// // Instantiate a new LeagueSeason
-    /// let mut my_league_season = LeagueSeason::new();
+    /// // 5 teams are scheduled with byes unless byes are disallowed
+    /// assert!(LeagueSeasonScheduleOptions::new().validate(5).is_ok());
+    /// let my_options = LeagueSeasonScheduleOptions::new().allow_byes(false);
+    /// assert!(my_options.allow_byes == Some(false));
+    /// assert!(my_options.validate(5).is_err());
+    /// ```
+    pub fn allow_byes(mut self, allow_byes: bool) -> Self {
+        self.allow_byes = Some(allow_byes);
+        self
+    }
+
+    /// Get the number of weeks of a round robin schedule of the given number
+    /// of teams, defaulting to a double round robin, or to exactly enough
+    /// weeks for the required number of meetings
+    fn round_robin_weeks(&self, num_teams: usize) -> usize {
+        let meetings = self.exact_meetings.unwrap_or(2);
+        self.weeks.unwrap_or((schedule_slots(num_teams) - 1) * meetings)
+    }
+
+    /// Check that a schedule in which each team plays the given number of
+    /// games, spread as evenly as possible over its opponents, satisfies the
+    /// meeting constraints
+    fn validate_meetings(&self, num_teams: usize, num_games: usize) -> Result<(), LeagueError> {
+        let opponents = num_teams - 1;
+        if let (Some(max), Some(exact)) = (self.max_meetings_per_opponent, self.exact_meetings) {
+            if exact > max {
+                return Err(
+                    LeagueError::InvalidSchedule {
+                        reason: format!(
+                            "Exact meetings per opponent ({}) exceed the maximum meetings per opponent ({})",
+                            exact, max
+                        )
+                    }
+                )
+            }
+        }
+        if let Some(max) = self.max_meetings_per_opponent {
+            if num_games > max * opponents {
+                return Err(
+                    LeagueError::InvalidSchedule {
+                        reason: format!(
+                            "Schedule of {} games per team must involve some pair of teams meeting more than {} times",
+                            num_games, max
+                        )
+                    }
+                )
+            }
+        }
+        if let Some(exact) = self.exact_meetings {
+            if num_games != exact * opponents {
+                return Err(
+                    LeagueError::InvalidSchedule {
+                        reason: format!(
+                            "Schedule of {} games per team cannot involve every pair of teams meeting exactly {} times ({} games)",
+                            num_games, exact, exact * opponents
+                        )
+                    }
+                )
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate the schedule options for a season with the given number of
+    /// teams, without generating a schedule. This performs the same checks
+    /// as `LeagueSeason::generate_schedule` for round robin and partial
+    /// schedules. A season divided into multiple conferences or divisions
+    /// derives its number of weeks from the per-opponent game counts, so for
+    /// those seasons the shift is checked once the weeks are generated.
     ///
-    /// // Instantiate a new FootballTeam
-    /// let my_season_team = FootballTeam::new();
+    /// A shift must be less than the number of weeks, and cannot be combined
+    /// with permuting the weeks, since the permutation would discard it. The
+    /// number of weeks must also allow the maximum or exact meetings per
+    /// opponent, given that each team's games are spread as evenly as
+    /// possible over its opponents.
     ///
-    /// // Add the team with ID 2
-    /// let my_season_teams = my_league_season.teams_mut();
-    /// my_season_teams.insert(2, my_season_team);
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
     ///
-    /// // Get the LeagueTeam with ID 2
-    /// let my_season_team = my_league_season.team(2);
+    /// // A double round robin of 4 teams spans 6 weeks
+    /// assert!(LeagueSeasonScheduleOptions::new().shift(5).validate(4).is_ok());
+    /// assert!(LeagueSeasonScheduleOptions::new().shift(6).validate(4).is_err());
+    ///
+    /// // A shifted schedule cannot also be permuted
+    /// let my_options = LeagueSeasonScheduleOptions::new()
+    ///     .shift(2)
+    ///     .permute(true);
+    /// assert!(my_options.validate(4).is_err());
+    ///
+    /// // 7 weeks of 4 teams must involve some pair meeting 3 times
+    /// let my_options = LeagueSeasonScheduleOptions::new().weeks(7);
+    /// assert!(my_options.clone().max_meetings_per_opponent(3).validate(4).is_ok());
+    /// assert!(my_options.clone().max_meetings_per_opponent(2).validate(4).is_err());
+    /// assert!(my_options.exact_meetings(2).validate(4).is_err());
     /// ```
-    pub fn team(&self, id: usize) -> Option<&FootballTeam> {
-        self.teams.get(&id)
+    pub fn validate(&self, num_teams: usize) -> Result<(), LeagueError> {
+        self.validate_common(num_teams)?;
+
+        // Check the team count and number of weeks for the schedule format.
+        // With an odd number of teams, the bye is scheduled as an extra
+        // team, so each week stands in for one game against it or another.
+        let num_slots = schedule_slots(num_teams);
+        let max_num_weeks = (num_slots - 1) * 3;
+        let num_weeks = match self.format {
+            ScheduleFormat::Partial { .. } if num_slots != num_teams => {
+                return Err(
+                    LeagueError::InvalidSchedule {
+                        reason: format!(
+                            "Partial schedule requires an even number of teams so that each team plays every week: {}",
+                            num_teams
+                        )
+                    }
+                )
+            },
+            ScheduleFormat::Partial { games_per_team } => {
+                if games_per_team > max_num_weeks {
+                    return Err(
+                        LeagueError::InvalidSchedule {
+                            reason: format!(
+                                "Schedule can involve teams playing each other team at most 3 times ({} games): {} given",
+                                max_num_weeks, games_per_team
+                            )
+                        }
+                    )
+                }
+                if games_per_team == 0 {
+                    return Err(LeagueError::InvalidSchedule { reason: String::from("Partial schedule must involve at least 1 game per team") })
+                }
+                if let Some(weeks) = self.weeks {
+                    if weeks != games_per_team {
+                        return Err(
+                            LeagueError::InvalidSchedule {
+                                reason: format!(
+                                    "Partial schedule of {} games per team requires {} weeks: {} given",
+                                    games_per_team, games_per_team, weeks
+                                )
+                            }
+                        )
+                    }
+                }
+                games_per_team
+            },
+            ScheduleFormat::RoundRobin => {
+                let num_weeks = self.round_robin_weeks(num_teams);
+                if num_weeks > max_num_weeks {
+                    return Err(
+                        LeagueError::InvalidSchedule {
+                            reason: format!(
+                                "Schedule can involve teams playing each other team at most 3 times ({} games): {} given",
+                                max_num_weeks, num_weeks
+                            )
+                        }
+                    )
+                }
+                if num_weeks < (num_slots - 1) {
+                    return Err(
+                        LeagueError::InvalidSchedule {
+                            reason: format!(
+                                "Schedule must involve teams playing each other team at least 1 time ({} games): {} given",
+                                num_slots - 1, num_weeks
+                            )
+                        }
+                    )
+                }
+                num_weeks
+            }
+        };
+        self.validate_meetings(num_slots, num_weeks)?;
+        self.validate_shift(num_weeks)
     }
 
-    /// Borrows a mutable `FootballTeam` from a `LeagueSeason` given the
-    /// team ID
+    /// Validate the options which do not depend on the number of weeks
+    fn validate_common(&self, num_teams: usize) -> Result<(), LeagueError> {
+        if num_teams < 4 {
+            return Err(
+                LeagueError::InvalidSchedule {
+                    reason: format!(
+                        "Less than 4 teams, not enough teams to generate a schedule: {}",
+                        num_teams
+                    )
+                }
+            );
+        }
+        if !num_teams.is_multiple_of(2) && self.allow_byes == Some(false) {
+            return Err(
+                LeagueError::InvalidSchedule {
+                    reason: format!(
+                        "Odd number of teams, cannot generate a schedule: {}",
+                        num_teams
+                    )
+                }
+            )
+        }
+        if let (Some(shift), Some(true)) = (self.shift, self.permute) {
+            if shift > 0 {
+                return Err(
+                    LeagueError::InvalidSchedule {
+                        reason: format!(
+                            "Cannot shift ({}) a permuted schedule, the permutation would discard the shift",
+                            shift
+                        )
+                    }
+                )
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate the shift against the number of weeks in the schedule
+    fn validate_shift(&self, num_weeks: usize) -> Result<(), LeagueError> {
+        if let Some(shift) = self.shift {
+            if shift >= num_weeks {
+                return Err(
+                    LeagueError::InvalidSchedule {
+                        reason: format!(
+                            "Shift ({}) must be less than the number of weeks ({})",
+                            shift, num_weeks
+                        )
+                    }
+                )
+            }
+        }
+        Ok(())
+    }
+}
+
+/// # `LeagueSeasonPlayoffOptions` struct
+///
+/// Options for generating playoffs. Supports both single-bracket and
+/// multi-conference bracket modes.
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LeagueSeasonPlayoffOptions {
+    /// Total number of playoff teams (used when not using conference brackets)
+    pub num_playoff_teams: usize,
+    /// If true and the league has multiple conferences, use separate conference
+    /// brackets plus a winners bracket for conference champions
+    pub use_conference_brackets: bool,
+    /// Number of playoff teams per conference (only used when
+    /// `use_conference_brackets` is true and the league has multiple conferences)
+    pub playoff_teams_per_conference: usize,
+    /// If true, division winners are guaranteed playoff spots regardless of
+    /// record (only used when `use_conference_brackets` is true)
+    pub division_winners_guaranteed: bool,
+    /// How division winners are seeded relative to the wild cards (only
+    /// used when `division_winners_guaranteed` is true)
+    pub seeding_rule: SeedingRule,
+    /// Extra weeks of rest after each playoff round, in order (rounds
+    /// beyond the end of the list get no extra rest)
+    pub rest_weeks_between_rounds: Vec<usize>,
+    /// Amount added to each skill level of a team in a playoff matchup
+    /// when it has had more rest than its opponent
+    pub rest_advantage: u32,
+    /// If true, the surviving teams of each conference bracket are reseeded
+    /// after every round, the best remaining seed hosting the worst
+    pub reseed: bool,
+}
+
+impl Default for LeagueSeasonPlayoffOptions {
+    /// Default constructor for the `LeagueSeasonPlayoffOptions` struct, a
+    /// single bracket of 2 playoff teams
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::team::FootballTeam;
-    /// use fbsim_core::league::season::LeagueSeason;
-    ///
-    /// // Instantiate a new LeagueSeason
-    /// let mut my_league_season = LeagueSeason::new();
-    ///
-    /// // Instantiate a new FootballTeam
-    /// let mut my_season_team = FootballTeam::new();
-    ///
-    /// // Add the team with ID 2
-    /// let my_season_teams = my_league_season.teams_mut();
-    /// my_season_teams.insert(2, my_season_team);
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
     ///
-    /// // Get the LeagueTeam with ID 2
-    /// let mut my_season_team = my_league_season.team_mut(2);
+    /// let my_playoff_options = LeagueSeasonPlayoffOptions::default();
+    /// assert!(my_playoff_options.num_playoff_teams == 2);
     /// ```
-    pub fn team_mut(&mut self, id: usize) -> Option<&mut FootballTeam> {
-        self.teams.get_mut(&id)
+    fn default() -> Self {
+        LeagueSeasonPlayoffOptions {
+            num_playoff_teams: 2,
+            use_conference_brackets: false,
+            playoff_teams_per_conference: 2,
+            division_winners_guaranteed: false,
+            seeding_rule: SeedingRule::default(),
+            rest_weeks_between_rounds: Vec::new(),
+            rest_advantage: 0,
+            reseed: false,
+        }
     }
+}
 
-    /// Borrow the conferences in the season
+impl LeagueSeasonPlayoffOptions {
+    /// Constructor for the `LeagueSeasonPlayoffOptions` struct
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
     ///
-    /// let season = LeagueSeason::new();
-    /// let conferences = season.conferences();
+    /// let my_playoff_options = LeagueSeasonPlayoffOptions::new();
     /// ```
-    pub fn conferences(&self) -> &Vec<LeagueConference> {
-        &self.conferences
+    pub fn new() -> LeagueSeasonPlayoffOptions {
+        LeagueSeasonPlayoffOptions::default()
     }
 
-    /// Mutably borrow the conferences in the season
+    /// Set the total number of playoff teams
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
     ///
-    /// let mut season = LeagueSeason::new();
-    /// let conferences = season.conferences_mut();
+    /// let my_options = LeagueSeasonPlayoffOptions::new()
+    ///     .num_playoff_teams(6);
+    /// assert!(my_options.num_playoff_teams == 6);
     /// ```
-    pub fn conferences_mut(&mut self) -> &mut Vec<LeagueConference> {
-        &mut self.conferences
+    pub fn num_playoff_teams(mut self, num_playoff_teams: usize) -> Self {
+        self.num_playoff_teams = num_playoff_teams;
+        self
     }
 
-    /// Add a conference to the season
+    /// Set the whether to use separate conference brackets
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::conference::LeagueConference;
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
     ///
-    /// let mut season = LeagueSeason::new();
-    /// let conference = LeagueConference::with_name("AFC");
-    /// season.add_conference(conference);
+    /// let my_options = LeagueSeasonPlayoffOptions::new()
+    ///     .use_conference_brackets(true);
+    /// assert!(my_options.use_conference_brackets);
     /// ```
-    pub fn add_conference(&mut self, conference: LeagueConference) -> Result<(), String> {
-        let existing_teams: Vec<usize> = self
-            .conferences
-            .iter()
-            .flat_map(|c| c.all_teams())
-            .collect();
-        for team_id in conference.all_teams() {
-            if existing_teams.contains(&team_id) {
-                return Err(format!(
-                    "Team with ID {} already exists in another conference",
-                    team_id
-                ));
-            }
-        }
-        self.conferences.push(conference);
-        Ok(())
+    pub fn use_conference_brackets(mut self, use_conference_brackets: bool) -> Self {
+        self.use_conference_brackets = use_conference_brackets;
+        self
     }
 
-    /// Get a conference by index
+    /// Set the number of playoff teams per conference
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::conference::LeagueConference;
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
     ///
-    /// let mut season = LeagueSeason::new();
-    /// season.add_conference(LeagueConference::with_name("AFC"));
-    /// let conference = season.conference(0);
-    /// assert!(conference.is_some());
+    /// let my_options = LeagueSeasonPlayoffOptions::new()
+    ///     .playoff_teams_per_conference(4);
+    /// assert!(my_options.playoff_teams_per_conference == 4);
     /// ```
-    pub fn conference(&self, index: usize) -> Option<&LeagueConference> {
-        self.conferences.get(index)
+    pub fn playoff_teams_per_conference(mut self, playoff_teams_per_conference: usize) -> Self {
+        self.playoff_teams_per_conference = playoff_teams_per_conference;
+        self
     }
 
-    /// Get a mutable conference by index
+    /// Set the whether division winners are guaranteed playoff spots
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::conference::LeagueConference;
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
     ///
-    /// let mut season = LeagueSeason::new();
-    /// season.add_conference(LeagueConference::with_name("AFC"));
-    /// let conference = season.conference_mut(0);
-    /// assert!(conference.is_some());
+    /// let my_options = LeagueSeasonPlayoffOptions::new()
+    ///     .division_winners_guaranteed(true);
+    /// assert!(my_options.division_winners_guaranteed);
     /// ```
-    pub fn conference_mut(&mut self, index: usize) -> Option<&mut LeagueConference> {
-        self.conferences.get_mut(index)
+    pub fn division_winners_guaranteed(mut self, division_winners_guaranteed: bool) -> Self {
+        self.division_winners_guaranteed = division_winners_guaranteed;
+        self
     }
 
-    /// Find which conference a team belongs to (returns conference index)
+    /// Set how division winners are seeded relative to the wild cards
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
+    /// use fbsim_core::league::season::playoffs::SeedingRule;
     ///
-    /// let mut season = LeagueSeason::new();
-    /// let mut conf = LeagueConference::with_name("AFC");
-    /// let mut div = LeagueDivision::with_name("East");
-    /// div.add_team(0);
-    /// conf.add_division(div);
-    /// season.add_conference(conf);
+    /// let my_options = LeagueSeasonPlayoffOptions::new()
+    ///     .seeding_rule(SeedingRule::ByRecord);
+    /// assert!(my_options.seeding_rule == SeedingRule::ByRecord);
+    /// ```
+    pub fn seeding_rule(mut self, seeding_rule: SeedingRule) -> Self {
+        self.seeding_rule = seeding_rule;
+        self
+    }
+
+    /// Set the extra weeks of rest after each playoff round
     ///
-    /// assert_eq!(season.team_conference(0), Some(0));
-    /// assert_eq!(season.team_conference(99), None);
+    /// ### Example
     /// ```
-    pub fn team_conference(&self, team_id: usize) -> Option<usize> {
-        for (conf_index, conference) in self.conferences.iter().enumerate() {
-            if conference.contains_team(team_id) {
-                return Some(conf_index);
-            }
-        }
-        None
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
+    ///
+    /// let my_options = LeagueSeasonPlayoffOptions::new()
+    ///     .rest_weeks_between_rounds(vec![0, 0, 1]);
+    /// assert!(my_options.rest_weeks_between_rounds == vec![0, 0, 1]);
+    /// ```
+    pub fn rest_weeks_between_rounds(mut self, rest_weeks_between_rounds: Vec<usize>) -> Self {
+        self.rest_weeks_between_rounds = rest_weeks_between_rounds;
+        self
     }
 
-    /// Find which division a team belongs to (returns conference index and division id)
+    /// Set the skill bonus of a playoff team with more rest than its opponent
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
     ///
-    /// let mut season = LeagueSeason::new();
-    /// let mut conf = LeagueConference::with_name("AFC");
-    /// let mut div = LeagueDivision::with_name("East");
-    /// div.add_team(0);
-    /// conf.add_division(div);
-    /// season.add_conference(conf);
+    /// let my_options = LeagueSeasonPlayoffOptions::new()
+    ///     .rest_advantage(3);
+    /// assert!(my_options.rest_advantage == 3);
+    /// ```
+    pub fn rest_advantage(mut self, rest_advantage: u32) -> Self {
+        self.rest_advantage = rest_advantage;
+        self
+    }
+
+    /// Set whether conference brackets are reseeded after every round
     ///
-    /// assert_eq!(season.team_division(0), Some((0, 0)));
-    /// assert_eq!(season.team_division(99), None);
+    /// ### Example
     /// ```
-    pub fn team_division(&self, team_id: usize) -> Option<(usize, usize)> {
-        for (conf_index, conference) in self.conferences.iter().enumerate() {
-            if let Some(div_id) = conference.team_division(team_id) {
-                return Some((conf_index, div_id));
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
+    ///
+    /// let my_options = LeagueSeasonPlayoffOptions::new()
+    ///     .reseed(true);
+    /// assert!(my_options.reseed);
+    /// ```
+    pub fn reseed(mut self, reseed: bool) -> Self {
+        self.reseed = reseed;
+        self
+    }
+}
+
+/// Derive the seed of a single regular season game's RNG from a season's
+/// simulation seed, its year, and the game's week and matchup indices
+///
+/// The inputs are combined as `seed ^ (year * 0x9E3779B97F4A7C15) ^ (week <<
+/// 32) ^ matchup`, with wrapping multiplication, and then mixed with the
+/// SplitMix64 finalizer so that neighbouring games get unrelated seeds. The
+/// game is simulated with `SmallRng::seed_from_u64` of the result.
+///
+/// ### Example
+/// ```
+/// use fbsim_core::league::season::derive_matchup_seed;
+///
+/// let seed = derive_matchup_seed(42, 2026, 3, 1);
+/// assert_eq!(seed, derive_matchup_seed(42, 2026, 3, 1));
+/// assert_ne!(seed, derive_matchup_seed(42, 2026, 3, 2));
+/// assert_ne!(seed, derive_matchup_seed(42, 2027, 3, 1));
+/// ```
+pub fn derive_matchup_seed(seed: u64, year: usize, week: usize, matchup: usize) -> u64 {
+    let mut z = seed
+        ^ (year as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ ((week as u64) << 32)
+        ^ (matchup as u64);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Derive the seed for a single regular season game's attendance from the
+/// attendance model's seed and the game's position in the season
+fn attendance_seed(seed: u64, year: usize, week: usize, matchup: usize) -> u64 {
+    seed ^ ((year as u64) << 32) ^ ((week as u64) << 16) ^ (matchup as u64)
+}
+
+/// Find the shortest chain of unplayed matchups, given as (week, matchup,
+/// home, away), whose venues may be swapped to move two home games from the
+/// start team to a team with more away games than home games, or two away
+/// games from the start team to a team with more home games than away games
+fn venue_swap_path(games: &[(usize, usize, usize, usize)], surplus: &BTreeMap<usize, i64>, start: usize, too_many_home: bool) -> Option<Vec<(usize, usize)>> {
+    let mut previous: BTreeMap<usize, (usize, usize, usize)> = BTreeMap::new();
+    let mut queue: VecDeque<usize> = VecDeque::from([start]);
+    while let Some(team) = queue.pop_front() {
+        for (week, matchup, home, away) in games.iter() {
+            // Follow games hosted by the team if it has too many home games,
+            // otherwise games in which it is the visitor
+            let next = match (too_many_home, *home == team, *away == team) {
+                (true, true, _) => *away,
+                (false, _, true) => *home,
+                _ => continue
+            };
+            if next == start || previous.contains_key(&next) {
+                continue;
+            }
+            previous.insert(next, (team, *week, *matchup));
+            let next_surplus = surplus.get(&next).copied().unwrap_or(0);
+            if (too_many_home && next_surplus < 0) || (!too_many_home && next_surplus > 0) {
+                let mut path = Vec::new();
+                let mut current = next;
+                while current != start {
+                    let (prev, week, matchup) = previous[&current];
+                    path.push((week, matchup));
+                    current = prev;
+                }
+                path.reverse();
+                return Some(path);
             }
+            queue.push_back(next);
         }
-        None
     }
+    None
+}
 
-    /// Check if two teams are in the same division
+/// # `LeagueSeason` struct
+///
+/// A `LeagueSeason` represents a season of a football league.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize)]
+pub struct LeagueSeason {
+    year: usize,
+    teams: BTreeMap<usize, FootballTeam>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    conferences: Vec<LeagueConference>,
+    weeks: Vec<LeagueSeasonWeek>,
+    playoffs: LeagueSeasonPlayoffs,
+    #[serde(skip_serializing_if = "ScheduleFormat::is_round_robin")]
+    schedule_format: ScheduleFormat,
+    #[serde(skip_serializing_if = "ChampionshipMode::is_playoffs")]
+    championship_mode: ChampionshipMode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trade_deadline: Option<usize>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    trades: Vec<LeagueSeasonTradeRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attendance_model: Option<AttendanceModel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weather_distribution: Option<WeatherDistribution>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    venue_swaps: Vec<LeagueSeasonVenueSwap>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    strict: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    result_edits: Vec<LeagueSeasonResultEdit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sim_seed: Option<u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    annotations: Vec<LeagueSeasonAnnotation>,
+    #[serde(skip)]
+    fingerprint: FingerprintCache
+}
+
+impl TryFrom<LeagueSeasonRaw> for LeagueSeason {
+    type Error = LeagueError;
+
+    fn try_from(item: LeagueSeasonRaw) -> Result<Self, Self::Error> {
+        // Validate the raw season
+        match item.validate() {
+            Ok(()) => (),
+            Err(error) => return Err(error),
+        };
+
+        // If valid, then convert
+        Ok(
+            LeagueSeason{
+                year: item.year,
+                teams: item.teams,
+                conferences: item.conferences,
+                weeks: item.weeks,
+                playoffs: item.playoffs,
+                schedule_format: item.schedule_format,
+                championship_mode: item.championship_mode,
+                trade_deadline: item.trade_deadline,
+                trades: item.trades,
+                attendance_model: item.attendance_model,
+                weather_distribution: item.weather_distribution,
+                venue_swaps: item.venue_swaps,
+                strict: item.strict,
+                result_edits: item.result_edits,
+                sim_seed: item.sim_seed,
+                annotations: item.annotations,
+                fingerprint: FingerprintCache::default()
+            }
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for LeagueSeason {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Only deserialize if the conversion from raw succeeds
+        let raw = LeagueSeasonRaw::deserialize(deserializer)?;
+        LeagueSeason::try_from(raw).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Default for LeagueSeason {
+    /// Default constructor for the `LeagueSeason` struct, with the year
+    /// defaulting to the current year. Requires the `chrono` feature.
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
     ///
-    /// let mut season = LeagueSeason::new();
-    /// let mut conf = LeagueConference::new();
-    /// let mut div1 = LeagueDivision::new();
-    /// div1.add_team(0);
-    /// div1.add_team(1);
-    /// let mut div2 = LeagueDivision::new();
-    /// div2.add_team(2);
-    /// div2.add_team(3);
-    /// conf.add_division(div1);
-    /// conf.add_division(div2);
-    /// season.add_conference(conf);
+    /// let my_league_season = LeagueSeason::default();
     ///
-    /// assert!(season.same_division(0, 1));
-    /// assert!(!season.same_division(0, 2));
+    /// // The default passes validation
+    /// let json = serde_json::to_string(&my_league_season).unwrap();
+    /// assert!(serde_json::from_str::<LeagueSeason>(&json).is_ok());
     /// ```
-    pub fn same_division(&self, team1: usize, team2: usize) -> bool {
-        match (self.team_division(team1), self.team_division(team2)) {
-            (Some((conf1, div1)), Some((conf2, div2))) => conf1 == conf2 && div1 == div2,
-            _ => false,
-        }
+    fn default() -> Self {
+        LeagueSeason::with_year(current_year())
     }
+}
 
-    /// Check if two teams are in the same conference
+impl LeagueSeason {
+    /// Constructor for the `LeagueSeason` struct with the given year
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
-    ///
-    /// let mut season = LeagueSeason::new();
-    ///
-    /// // AFC with two divisions
-    /// let mut afc = LeagueConference::with_name("AFC");
-    /// let mut afc_east = LeagueDivision::with_name("East");
-    /// afc_east.add_team(0);
-    /// afc_east.add_team(1);
-    /// let mut afc_west = LeagueDivision::with_name("West");
-    /// afc_west.add_team(2);
-    /// afc_west.add_team(3);
-    /// afc.add_division(afc_east);
-    /// afc.add_division(afc_west);
-    ///
-    /// // NFC with one division
-    /// let mut nfc = LeagueConference::with_name("NFC");
-    /// let mut nfc_east = LeagueDivision::with_name("East");
-    /// nfc_east.add_team(4);
-    /// nfc_east.add_team(5);
-    /// nfc.add_division(nfc_east);
-    ///
-    /// season.add_conference(afc);
-    /// season.add_conference(nfc);
     ///
-    /// // Teams 0 and 2 are in same conference (AFC) but different divisions
-    /// assert!(season.same_conference(0, 2));
-    /// // Teams 0 and 4 are in different conferences
-    /// assert!(!season.same_conference(0, 4));
+    /// let my_league_season = LeagueSeason::with_year(2026);
+    /// assert_eq!(*my_league_season.year(), 2026);
     /// ```
-    pub fn same_conference(&self, team1: usize, team2: usize) -> bool {
-        match (self.team_conference(team1), self.team_conference(team2)) {
-            (Some(conf1), Some(conf2)) => conf1 == conf2,
-            _ => false,
+    pub fn with_year(year: usize) -> LeagueSeason {
+        LeagueSeason{
+            year,
+            teams: BTreeMap::new(),
+            conferences: Vec::new(),
+            weeks: Vec::new(),
+            playoffs: LeagueSeasonPlayoffs::new(),
+            schedule_format: ScheduleFormat::default(),
+            championship_mode: ChampionshipMode::default(),
+            trade_deadline: None,
+            trades: Vec::new(),
+            attendance_model: None,
+            weather_distribution: None,
+            venue_swaps: Vec::new(),
+            strict: false,
+            result_edits: Vec::new(),
+            sim_seed: None,
+            annotations: Vec::new(),
+            fingerprint: FingerprintCache::default()
         }
     }
 
-    /// Borrow the weeks of the season
+    /// Constructor for the `LeagueSeason` struct, with the year
+    /// defaulting to the current year. Requires the `chrono` feature.
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::league::season::LeagueSeason;
     ///
     /// let my_league_season = LeagueSeason::new();
-    /// let my_season_weeks = my_league_season.weeks();
     /// ```
-    pub fn weeks(&self) -> &Vec<LeagueSeasonWeek> {
-        &self.weeks
+    #[cfg(feature = "chrono")]
+    pub fn new() -> LeagueSeason {
+        LeagueSeason::default()
     }
 
-    /// Mutably borrow the weeks of the season
+    /// Borrow the year the season took place
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::league::season::LeagueSeason;
     ///
-    /// let mut my_league_season = LeagueSeason::new();
-    /// let my_season_weeks = my_league_season.weeks_mut();
+    /// let my_league_season = LeagueSeason::new();
+    /// let my_season_year = my_league_season.year();
     /// ```
-    pub fn weeks_mut(&mut self) -> &mut Vec<LeagueSeasonWeek> {
-        &mut self.weeks
+    pub fn year(&self) -> &usize {
+        &self.year
     }
 
-    /// Borrow the playoffs from the season
+    /// Set the year the season takes place. Fails if the season has started.
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::{LeagueSeason, LeagueSeasonScheduleOptions};
     ///
-    /// let my_league_season = LeagueSeason::new();
-    /// let my_season_weeks = my_league_season.weeks();
+    /// let mut my_league_season = LeagueSeason::with_year(2026);
+    /// my_league_season.set_year(2027).unwrap();
+    /// assert_eq!(*my_league_season.year(), 2027);
+    ///
+    /// // The year is fixed once a game has been played
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league_season.sim_week(0, &mut rng).unwrap();
+    /// assert!(my_league_season.set_year(2028).is_err());
     /// ```
-    pub fn playoffs(&self) -> &LeagueSeasonPlayoffs {
-        &self.playoffs
+    pub fn set_year(&mut self, year: usize) -> Result<(), LeagueError> {
+        if self.started() {
+            return Err(
+                LeagueError::SeasonAlreadyStarted
+            );
+        }
+        self.fingerprint.invalidate();
+        self.year = year;
+        Ok(())
     }
 
-    /// Mutably borrow the playoffs from the season
+    /// Mutably borrow the year the season took place
+    ///
+    /// Deprecated: prefer `set_year`, which refuses to change the year of a
+    /// season once it has started. Changing the year of a season in a
+    /// `League` may also collide with another season's year, so prefer
+    /// `League::set_current_season_year` there.
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::league::season::LeagueSeason;
     ///
     /// let mut my_league_season = LeagueSeason::new();
-    /// let my_season_weeks = my_league_season.weeks_mut();
+    /// let mut my_season_year = my_league_season.year_mut();
     /// ```
-    pub fn playoffs_mut(&mut self) -> &mut LeagueSeasonPlayoffs {
-        &mut self.playoffs
+    pub fn year_mut(&mut self) -> &mut usize {
+        self.fingerprint.invalidate();
+        &mut self.year
     }
 
-    /// Determine based on the matchups whether the season has started
+    /// Borrow the teams which competed in the season
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::league::season::LeagueSeason;
     ///
     /// let my_league_season = LeagueSeason::new();
-    /// let started = my_league_season.started();
+    /// let my_season_teams = my_league_season.teams();
     /// ```
-    pub fn started(&self) -> bool {
-        // If no season weeks, then the season hasn't started
-        if self.weeks.is_empty() {
-            return false;
-        }
-
-        // Loop through the season weeks and check if any are started
-        for week in self.weeks.iter() {
-            if week.started() {
-                return true;
-            }
-        }
-        false
+    pub fn teams(&self) -> &BTreeMap<usize, FootballTeam> {
+        &self.teams
     }
 
-    /// Determine whether the regular season is complete
+    /// Mutably borrow the year the season took place
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::league::season::LeagueSeasonRaw;
+    /// use fbsim_core::league::season::LeagueSeason;
     ///
-    /// let raw_league_season = LeagueSeasonRaw::new();
-    /// let complete = raw_league_season.regular_season_complete();
+    /// let mut my_league_season = LeagueSeason::new();
+    /// let mut my_season_teams = my_league_season.teams_mut();
     /// ```
-    pub fn regular_season_complete(&self) -> bool {
-        // If no season weeks, then the season isn't complete
-        if self.weeks.is_empty() {
-            return false;
-        }
-
-        // Loop through the season weeks and check if any are not complete
-        for week in self.weeks.iter() {
-            if !week.complete() {
-                return false;
-            }
-        }
-        true
+    pub fn teams_mut(&mut self) -> &mut BTreeMap<usize, FootballTeam> {
+        self.fingerprint.invalidate();
+        &mut self.teams
     }
 
-    /// Determine whether the season is complete
+    /// Add a team to the season
     ///
     /// ### Example
     /// ```
-    /// use fbsim_core::league::season::LeagueSeasonRaw;
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
     ///
-    /// let raw_league_season = LeagueSeasonRaw::new();
-    /// let complete = raw_league_season.complete();
+    /// let mut my_league_season = LeagueSeason::new();
+    /// let my_season_team = FootballTeam::new();
+    /// my_league_season.add_team(0, my_season_team);
     /// ```
-    pub fn complete(&self) -> bool {
-        if !self.regular_season_complete() {
-            false
-        } else {
-            self.playoffs.complete()
+    pub fn add_team(&mut self, id: usize, team: FootballTeam) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        // Ensure the season has not already started
+        if self.started() {
+            return Err(LeagueError::SeasonAlreadyStarted);
         }
-    }
 
-    /// Create a default conference structure with a single conference and division
-    /// containing all teams. Used when no conferences are defined but schedule
-    /// generation is requested.
-    fn create_default_conference(&mut self) {
-        let mut division = LeagueDivision::with_name("Default");
-        for team_id in self.teams.keys() {
-            division.add_team(*team_id).expect("default conference has unique team IDs");
+        // Ensure the given ID is unique
+        if self.teams.contains_key(&id) {
+            return Err(LeagueError::DuplicateTeam { id });
         }
 
-        let mut conference = LeagueConference::with_name("Default");
-        conference.add_division(division).expect("default conference has a single division");
-
-        self.conferences.clear();
-        self.conferences.push(conference);
+        // Add the team
+        self.teams.insert(id, team);
+        Ok(())
     }
 
-    /// Check if conference-aware (structured) scheduling is needed
-    fn needs_structured_scheduling(&self) -> bool {
-        // Need structured scheduling if:
-        // 1. Multiple conferences exist
-        // 2. Any conference has multiple divisions
-        self.conferences.len() > 1
-            || self.conferences.iter().any(|c| c.divisions().len() > 1)
+    /// Import a batch of teams from a JSON document of `TeamDefinitions`,
+    /// adding each team and its conference and division assignment to the
+    /// season. The whole batch is validated first, and nothing is added if
+    /// any team is invalid.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let json = r#"{"teams": [
+    ///     {"id": 0, "conference": "AFC", "division": "East", "name": "Aces", "short_name": "ACE",
+    ///      "coach": {"risk_taking": 50, "run_pass": 50, "up_tempo": 50},
+    ///      "offense": {"passing": 50, "blocking": 50, "rushing": 50, "receiving": 50, "scrambling": 50,
+    ///                  "turnovers": 50, "field_goals": 50, "punting": 50, "kickoffs": 50, "kick_return_defense": 50},
+    ///      "defense": {"blitzing": 50, "rush_defense": 50, "pass_defense": 50, "coverage": 50,
+    ///                  "turnovers": 50, "kick_returning": 50}}
+    /// ]}"#;
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.import_teams(json.as_bytes()).unwrap();
+    /// assert_eq!(my_league_season.team(0).unwrap().short_name(), "ACE");
+    /// assert_eq!(my_league_season.conference(0).unwrap().name(), "AFC");
+    /// ```
+    pub fn import_teams(&mut self, reader: impl std::io::Read) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        let definitions: TeamDefinitions = serde_json::from_reader(reader)
+            .map_err(|e| format!("Failed to read team definitions: {}", e))?;
+        self.import_team_definitions(definitions)
     }
 
-    /// Generate a schedule for the season.  The generated schedule is a round
-    /// robin schedule in which each team plays an equal number of home and
-    /// away games, and in which each team plays each other twice.
+    /// Import a batch of already parsed team definitions, adding each team
+    /// and its conference and division assignment to the season. The whole
+    /// batch is validated first, and nothing is added if any team is invalid.
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::import::{TeamDefinition, TeamDefinitions};
     ///
-    /// // Create a new season
     /// let mut my_league_season = LeagueSeason::new();
-    ///
-    /// // Add 4 teams to the season
-    /// my_league_season.add_team(0, FootballTeam::new());
-    /// my_league_season.add_team(1, FootballTeam::new());
-    /// my_league_season.add_team(2, FootballTeam::new());
-    /// my_league_season.add_team(3, FootballTeam::new());
-    ///
-    /// // Generate the season schedule
-    /// let mut rng = rand::thread_rng();
-    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// my_league_season.add_team(0, FootballTeam::new()).unwrap();
+    ///
+    /// // Team 1 is valid, but team 0 already exists, so neither is added
+    /// let definitions = TeamDefinitions {
+    ///     teams: vec![
+    ///         TeamDefinition { id: 1, team: FootballTeam::from_overalls("One", "ONE", 50, 50).unwrap(), ..Default::default() },
+    ///         TeamDefinition { id: 0, team: FootballTeam::from_overalls("Zero", "ZERO", 50, 50).unwrap(), ..Default::default() },
+    ///     ]
+    /// };
+    /// assert!(my_league_season.import_team_definitions(definitions).is_err());
+    /// assert!(!my_league_season.team_exists(1));
     /// ```
-    pub fn generate_schedule(&mut self, options: LeagueSeasonScheduleOptions, rng: &mut impl Rng) -> Result<(), String> {
-        // If no conferences defined, create default single conference with all teams
-        if self.conferences.is_empty() {
-            self.create_default_conference();
+    pub fn import_team_definitions(&mut self, definitions: TeamDefinitions) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        // Ensure the season has not already started
+        if self.started() {
+            return Err(LeagueError::SeasonAlreadyStarted);
         }
 
-        // Validate that divisions within each conference are of equal size
-        for conference in &self.conferences {
-            let div_sizes: Vec<usize> = conference.divisions().iter()
-                .map(|d| d.num_teams())
-                .collect();
-            if let Some(&first) = div_sizes.first() {
-                if div_sizes.iter().any(|&s| s != first) {
-                    return Err(format!(
-                        "Conference '{}' has divisions of unequal size: {:?}",
-                        conference.name(), div_sizes
-                    ));
-                }
+        // Validate the batch, then validate it against the season's teams
+        definitions.validate()?;
+        for definition in &definitions.teams {
+            if self.teams.contains_key(&definition.id) {
+                return Err(LeagueError::DuplicateTeam { id: definition.id });
+            }
+            if self.teams.values().any(|t| t.short_name() == definition.team.short_name()) {
+                return Err(format!(
+                    "Team with short name {} already exists",
+                    definition.team.short_name()
+                ).into());
+            }
+            if self.conferences.iter().any(|c| c.contains_team(definition.id)) {
+                return Err(format!(
+                    "Team with ID {} is already assigned to a conference",
+                    definition.id
+                ).into());
             }
         }
 
-        // Route to appropriate schedule generation method
-        if self.needs_structured_scheduling() {
-            self.generate_structured_schedule(options, rng)
-        } else {
-            self.generate_round_robin_schedule(options, rng)
-        }
-    }
-
-    /// Generate a simple round-robin schedule (existing algorithm)
-    fn generate_round_robin_schedule(&mut self, options: LeagueSeasonScheduleOptions, rng: &mut impl Rng) -> Result<(), String> {
-        // Check whether there are at least 4 teams, an even number of teams
-        let num_teams = self.teams.len();
-        if num_teams < 4 {
-            return Err(
-                format!(
-                    "Less than 4 teams, not enough teams to generate a schedule: {}",
-                    num_teams
-                )
-            );
-        }
-        if !num_teams.is_multiple_of(2) {
-            return Err(
-                format!(
-                    "Odd number of teams, cannot generate a schedule: {}",
-                    num_teams
-                )
-            )
-        }
-
-        // Check whether the number of weeks is between the prescribed min and max
-        let num_weeks = match options.weeks {
-            Some(weeks) => weeks,
-            None => (num_teams - 1) * 2,
-        };
-        let max_num_weeks = (num_teams - 1) * 3;
-        if num_weeks > max_num_weeks {
-            return Err(
-                format!(
-                    "Schedule can involve teams playing each other team at most 3 times ({} games): {} given",
-                    max_num_weeks, num_weeks
-                )
-            )
-        }
-        if num_weeks < (num_teams - 1) {
-            return Err(
-                format!(
-                    "Schedule must involve teams playing each other team at least 1 time ({} games): {} given",
-                    num_teams, num_weeks
-                )
-            )
+        // Ensure the season's structure stays consistent: either every team
+        // is assigned to a division or the season has no conferences
+        let assigned = definitions.teams.iter().any(|d| d.conference.is_some());
+        if !definitions.teams.is_empty() && !self.teams.is_empty() && assigned == self.conferences.is_empty() {
+            return Err(if assigned {
+                LeagueError::from("Cannot import teams with conferences into a season whose teams have none")
+            } else {
+                LeagueError::from("Cannot import teams without conferences into a season with conferences")
+            });
         }
 
-        // Get the shift option value, error if it is invalid
-        let shift = match options.shift {
-            Some(s) => {
-                if s > num_weeks {
-                    return Err(
-                        format!(
-                            "Shift ({}) must be less than the number of weeks ({})",
-                            s, num_weeks
-                        )
-                    )
-                } else {
-                    s
+        // Build the new conference structure before modifying the season
+        let mut conferences = self.conferences.clone();
+        for definition in &definitions.teams {
+            let (Some(conference_name), Some(division_name)) = (&definition.conference, &definition.division) else {
+                continue;
+            };
+            let conference_index = match conferences.iter().position(|c| c.name() == conference_name) {
+                Some(index) => index,
+                None => {
+                    conferences.push(LeagueConference::with_name(conference_name));
+                    conferences.len() - 1
                 }
-            },
-            None => 0
-        };
-
-        // Check to make sure the season has not already started
-        if self.started() {
-            return Err(
-                "Season has already started, cannot re-generate schedule".to_string()
-            )
-        }
-
-        // If the schedule is already non-empty then empty it before re-gen
-        if !self.weeks.is_empty() {
-            self.weeks.clear()
+            };
+            let conference = &mut conferences[conference_index];
+            let division_index = match conference.divisions().iter().position(|d| d.name() == division_name) {
+                Some(index) => index,
+                None => {
+                    conference.add_division(LeagueDivision::with_name(division_name))?;
+                    conference.num_divisions() - 1
+                }
+            };
+            conference.division_mut(division_index)
+                .ok_or_else(|| format!("Failed to get division {} of conference {}", division_name, conference_name))?
+                .add_team(definition.id)?;
         }
 
-        // Generate the vec of team IDs and randomly permute it for a unique schedule
-        let mut team_ids: Vec<usize> = self.teams.keys().cloned().collect();
-        team_ids.shuffle(rng); // Generate a random permutation of the season team IDs
-
-        // Generate the round-robin schedule using the circle method:
-        // - Keep the team at index 0 fixed
-        // - Rotate the teams at indices 1 to n-1
-        // - Pair: index 0 with index n-1, index 1 with index n-2, etc.
-        // This guarantees each pair plays exactly once per cycle of (n-1) rounds
-        for week_index in 0..num_weeks {
-            // Create a new league season week
-            let mut week = LeagueSeasonWeek::new();
-
-            // TODO: Implement the ability to have bye weeks
-            let num_matchups = num_teams / 2;
-
-            // Determine which round within the cycle and which cycle we're in
-            // Each cycle is (n-1) rounds; in a double round-robin we have 2 cycles
-            let round_in_cycle = week_index % (num_teams - 1);
-            let cycle = week_index / (num_teams - 1);
-
-            // Build the arrangement for this round using the circle method
-            // Team at index 0 stays fixed, others rotate
-            let mut arrangement: Vec<usize> = Vec::with_capacity(num_teams);
-            arrangement.push(team_ids[0]); // Fixed team
-            for i in 0..(num_teams - 1) {
-                // Rotate the other teams: for round r, shift by r positions
-                let rotated_index = (i + (num_teams - 1) - round_in_cycle) % (num_teams - 1);
-                arrangement.push(team_ids[1 + rotated_index]);
-            }
-
-            // Create matchups for each pair of teams
-            // Pair first with last, second with second-to-last, etc.
-            for matchup_index in 0..num_matchups {
-                let team1_index = matchup_index;
-                let team2_index = num_teams - 1 - matchup_index;
-
-                let team1_id = arrangement[team1_index];
-                let team2_id = arrangement[team2_index];
-
-                // Determine home/away based on cycle to balance home games
-                // In cycle 0, team1 (lower index) is home; in cycle 1, team2 is home
-                // This ensures each pair plays once with each team as home
-                let (home_id, away_id) = if cycle.is_multiple_of(2) {
-                    (team1_id, team2_id)
-                } else {
-                    (team2_id, team1_id)
-                };
-
-                // Get the home & away short names
-                let home_short_name = self.teams.get(&home_id).unwrap().short_name();
-                let away_short_name = self.teams.get(&away_id).unwrap().short_name();
-
-                // Create the matchup and add to the week
-                let matchup = LeagueSeasonMatchup::new(home_id, away_id, home_short_name, away_short_name, rng);
-                week.matchups_mut().push(matchup);
-            }
-
-            // Add the week to the season
-            self.weeks.push(week);
+        // Add the teams
+        self.conferences = conferences;
+        for definition in definitions.teams {
+            self.teams.insert(definition.id, definition.team);
         }
+        Ok(())
+    }
 
-        // If desired, shift the weeks of the season
-        if shift > 0 {
-            self.weeks.rotate_right(shift);
-        }
+    /// Export the season's teams and their conference and division
+    /// assignments as a JSON document of `TeamDefinitions`, which
+    /// `LeagueSeason::import_teams` can read back
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new()).unwrap();
+    ///
+    /// let mut json: Vec<u8> = Vec::new();
+    /// my_league_season.export_teams(&mut json).unwrap();
+    ///
+    /// let mut my_copy = LeagueSeason::new();
+    /// my_copy.import_teams(json.as_slice()).unwrap();
+    /// assert_eq!(my_copy.teams(), my_league_season.teams());
+    /// ```
+    pub fn export_teams(&self, writer: impl std::io::Write) -> Result<(), LeagueError> {
+        serde_json::to_writer_pretty(writer, &self.team_definitions())
+            .map_err(|e| format!("Failed to write team definitions: {}", e).into())
+    }
 
-        // If desired, randomly permute the weeks of the season
-        if let Some(permute) = options.permute {
-            if permute {
-                self.weeks.shuffle(rng);
+    /// Get the season's teams and their conference and division assignments
+    /// as `TeamDefinitions`
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new()).unwrap();
+    /// let definitions = my_league_season.team_definitions();
+    /// assert_eq!(definitions.teams[0].id, 0);
+    /// assert!(definitions.teams[0].conference.is_none());
+    /// ```
+    pub fn team_definitions(&self) -> TeamDefinitions {
+        let teams = self.teams.iter().map(|(id, team)| {
+            let assignment = self.conferences.iter().find_map(|c| {
+                let division = c.division(c.team_division(*id)?)?;
+                Some((String::from(c.name()), String::from(division.name())))
+            });
+            let (conference, division) = match assignment {
+                Some((c, d)) => (Some(c), Some(d)),
+                None => (None, None)
+            };
+            TeamDefinition {
+                id: *id,
+                conference,
+                division,
+                team: team.clone()
             }
-        }
-        Ok(())
+        }).collect();
+        TeamDefinitions { teams }
     }
 
-    /// Generate a conference-aware structured schedule
-    fn generate_structured_schedule(&mut self, options: LeagueSeasonScheduleOptions, rng: &mut impl Rng) -> Result<(), String> {
-        // Validate basic requirements
-        let num_teams = self.teams.len();
-        if num_teams < 4 {
-            return Err(format!("Less than 4 teams, not enough teams to generate a schedule: {}", num_teams));
-        }
+    /// Check if a team exists in the season
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// // Create a new season
+    /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Team 0 should not exist at this point
+    /// assert!(!my_league_season.team_exists(0));
+    ///
+    /// // Add team 0 to the season
+    /// let my_season_team = FootballTeam::new();
+    /// my_league_season.add_team(0, my_season_team);
+    ///
+    /// // Team 0 should now exist
+    /// assert!(my_league_season.team_exists(0));
+    /// ```
+    pub fn team_exists(&self, id: usize) -> bool {
+        self.teams.contains_key(&id)
+    }
 
-        // Check to make sure the season has not already started
-        if self.started() {
-            return Err("Season has already started, cannot re-generate schedule".to_string());
-        }
+    /// Borrows an immutable `FootballTeam` from a `LeagueSeason` given
+    /// the team ID
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// // Instantiate a new LeagueSeason
+    /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Instantiate a new FootballTeam
+    /// let my_season_team = FootballTeam::new();
+    ///
+    /// // Add the team with ID 2
+    /// let my_season_teams = my_league_season.teams_mut();
+    /// my_season_teams.insert(2, my_season_team);
+    ///
+    /// // Get the LeagueTeam with ID 2
+    /// let my_season_team = my_league_season.team(2);
+    /// ```
+    pub fn team(&self, id: usize) -> Option<&FootballTeam> {
+        self.teams.get(&id)
+    }
+
+    /// Borrows a mutable `FootballTeam` from a `LeagueSeason` given the
+    /// team ID
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// // Instantiate a new LeagueSeason
+    /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Instantiate a new FootballTeam
+    /// let mut my_season_team = FootballTeam::new();
+    ///
+    /// // Add the team with ID 2
+    /// let my_season_teams = my_league_season.teams_mut();
+    /// my_season_teams.insert(2, my_season_team);
+    ///
+    /// // Get the LeagueTeam with ID 2
+    /// let mut my_season_team = my_league_season.team_mut(2);
+    /// ```
+    pub fn team_mut(&mut self, id: usize) -> Option<&mut FootballTeam> {
+        self.fingerprint.invalidate();
+        self.teams.get_mut(&id)
+    }
+
+    /// Borrow the conferences in the season
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let season = LeagueSeason::new();
+    /// let conferences = season.conferences();
+    /// ```
+    pub fn conferences(&self) -> &Vec<LeagueConference> {
+        &self.conferences
+    }
+
+    /// Mutably borrow the conferences in the season
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let mut season = LeagueSeason::new();
+    /// let conferences = season.conferences_mut();
+    /// ```
+    pub fn conferences_mut(&mut self) -> &mut Vec<LeagueConference> {
+        self.fingerprint.invalidate();
+        &mut self.conferences
+    }
+
+    /// Add a conference to the season
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::conference::LeagueConference;
+    ///
+    /// let mut season = LeagueSeason::new();
+    /// let conference = LeagueConference::with_name("AFC");
+    /// season.add_conference(conference);
+    /// ```
+    pub fn add_conference(&mut self, conference: LeagueConference) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        let existing_teams: Vec<usize> = self
+            .conferences
+            .iter()
+            .flat_map(|c| c.all_teams())
+            .collect();
+        for team_id in conference.all_teams() {
+            if existing_teams.contains(&team_id) {
+                return Err(LeagueError::DuplicateTeam { id: team_id });
+            }
+        }
+        self.conferences.push(conference);
+        Ok(())
+    }
+
+    /// Get the indices of the conferences which have at least one team.
+    /// Empty conferences are ignored by the playoff picture and by playoff
+    /// seeding, and `LeagueSeason::lint` reports them as warnings.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    ///
+    /// let mut season = LeagueSeason::new();
+    /// let mut afc = LeagueConference::with_name("AFC");
+    /// let mut east = LeagueDivision::with_name("East");
+    /// east.add_team(0).unwrap();
+    /// afc.add_division(east).unwrap();
+    /// season.add_conference(afc).unwrap();
+    /// season.add_conference(LeagueConference::with_name("NFC")).unwrap();
+    /// assert_eq!(season.populated_conferences(), vec![0]);
+    /// ```
+    pub fn populated_conferences(&self) -> Vec<usize> {
+        self.conferences.iter()
+            .enumerate()
+            .filter(|(_, c)| c.num_teams() > 0)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Get a conference by index
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::conference::LeagueConference;
+    ///
+    /// let mut season = LeagueSeason::new();
+    /// season.add_conference(LeagueConference::with_name("AFC"));
+    /// let conference = season.conference(0);
+    /// assert!(conference.is_some());
+    /// ```
+    pub fn conference(&self, index: usize) -> Option<&LeagueConference> {
+        self.conferences.get(index)
+    }
+
+    /// Get a mutable conference by index
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::conference::LeagueConference;
+    ///
+    /// let mut season = LeagueSeason::new();
+    /// season.add_conference(LeagueConference::with_name("AFC"));
+    /// let conference = season.conference_mut(0);
+    /// assert!(conference.is_some());
+    /// ```
+    pub fn conference_mut(&mut self, index: usize) -> Option<&mut LeagueConference> {
+        self.fingerprint.invalidate();
+        self.conferences.get_mut(index)
+    }
+
+    /// Find which conference a team belongs to (returns conference index)
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    ///
+    /// let mut season = LeagueSeason::new();
+    /// let mut conf = LeagueConference::with_name("AFC");
+    /// let mut div = LeagueDivision::with_name("East");
+    /// div.add_team(0);
+    /// conf.add_division(div);
+    /// season.add_conference(conf);
+    ///
+    /// assert_eq!(season.team_conference(0), Some(0));
+    /// assert_eq!(season.team_conference(99), None);
+    /// ```
+    pub fn team_conference(&self, team_id: usize) -> Option<usize> {
+        for (conf_index, conference) in self.conferences.iter().enumerate() {
+            if conference.contains_team(team_id) {
+                return Some(conf_index);
+            }
+        }
+        None
+    }
+
+    /// Find which division a team belongs to (returns conference index and division id)
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    ///
+    /// let mut season = LeagueSeason::new();
+    /// let mut conf = LeagueConference::with_name("AFC");
+    /// let mut div = LeagueDivision::with_name("East");
+    /// div.add_team(0);
+    /// conf.add_division(div);
+    /// season.add_conference(conf);
+    ///
+    /// assert_eq!(season.team_division(0), Some((0, 0)));
+    /// assert_eq!(season.team_division(99), None);
+    /// ```
+    pub fn team_division(&self, team_id: usize) -> Option<(usize, usize)> {
+        for (conf_index, conference) in self.conferences.iter().enumerate() {
+            if let Some(div_id) = conference.team_division(team_id) {
+                return Some((conf_index, div_id));
+            }
+        }
+        None
+    }
+
+    /// Check if two teams are in the same division
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    ///
+    /// let mut season = LeagueSeason::new();
+    /// let mut conf = LeagueConference::new();
+    /// let mut div1 = LeagueDivision::new();
+    /// div1.add_team(0);
+    /// div1.add_team(1);
+    /// let mut div2 = LeagueDivision::new();
+    /// div2.add_team(2);
+    /// div2.add_team(3);
+    /// conf.add_division(div1);
+    /// conf.add_division(div2);
+    /// season.add_conference(conf);
+    ///
+    /// assert!(season.same_division(0, 1));
+    /// assert!(!season.same_division(0, 2));
+    /// ```
+    pub fn same_division(&self, team1: usize, team2: usize) -> bool {
+        match (self.team_division(team1), self.team_division(team2)) {
+            (Some((conf1, div1)), Some((conf2, div2))) => conf1 == conf2 && div1 == div2,
+            _ => false,
+        }
+    }
+
+    /// Check if two teams are in the same conference
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    ///
+    /// let mut season = LeagueSeason::new();
+    ///
+    /// // AFC with two divisions
+    /// let mut afc = LeagueConference::with_name("AFC");
+    /// let mut afc_east = LeagueDivision::with_name("East");
+    /// afc_east.add_team(0);
+    /// afc_east.add_team(1);
+    /// let mut afc_west = LeagueDivision::with_name("West");
+    /// afc_west.add_team(2);
+    /// afc_west.add_team(3);
+    /// afc.add_division(afc_east);
+    /// afc.add_division(afc_west);
+    ///
+    /// // NFC with one division
+    /// let mut nfc = LeagueConference::with_name("NFC");
+    /// let mut nfc_east = LeagueDivision::with_name("East");
+    /// nfc_east.add_team(4);
+    /// nfc_east.add_team(5);
+    /// nfc.add_division(nfc_east);
+    ///
+    /// season.add_conference(afc);
+    /// season.add_conference(nfc);
+    ///
+    /// // Teams 0 and 2 are in same conference (AFC) but different divisions
+    /// assert!(season.same_conference(0, 2));
+    /// // Teams 0 and 4 are in different conferences
+    /// assert!(!season.same_conference(0, 4));
+    /// ```
+    pub fn same_conference(&self, team1: usize, team2: usize) -> bool {
+        match (self.team_conference(team1), self.team_conference(team2)) {
+            (Some(conf1), Some(conf2)) => conf1 == conf2,
+            _ => false,
+        }
+    }
+
+    /// Borrow the weeks of the season
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let my_league_season = LeagueSeason::new();
+    /// let my_season_weeks = my_league_season.weeks();
+    /// ```
+    pub fn weeks(&self) -> &Vec<LeagueSeasonWeek> {
+        &self.weeks
+    }
+
+    /// Mutably borrow the weeks of the season
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// let my_season_weeks = my_league_season.weeks_mut();
+    /// ```
+    pub fn weeks_mut(&mut self) -> &mut Vec<LeagueSeasonWeek> {
+        self.fingerprint.invalidate();
+        &mut self.weeks
+    }
+
+    /// Borrow the format of the season schedule
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::{LeagueSeason, ScheduleFormat};
+    ///
+    /// let my_league_season = LeagueSeason::new();
+    /// assert_eq!(*my_league_season.schedule_format(), ScheduleFormat::RoundRobin);
+    /// ```
+    pub fn schedule_format(&self) -> &ScheduleFormat {
+        &self.schedule_format
+    }
+
+    /// Count the regular season meetings scheduled between each pair of
+    /// teams, by team ID and then opponent ID. Every other team is listed
+    /// as an opponent, including those the team never meets.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // In a double round robin every pair of teams meets twice
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// let meetings = my_league_season.opponent_meetings();
+    /// assert_eq!(meetings[&0][&3], 2);
+    /// assert!(!meetings[&0].contains_key(&0));
+    /// ```
+    pub fn opponent_meetings(&self) -> BTreeMap<usize, BTreeMap<usize, usize>> {
+        let mut meetings: BTreeMap<usize, BTreeMap<usize, usize>> = self.teams.keys()
+            .map(|id| {
+                let opponents = self.teams.keys()
+                    .filter(|opponent| *opponent != id)
+                    .map(|opponent| (*opponent, 0))
+                    .collect();
+                (*id, opponents)
+            })
+            .collect();
+        for week in self.weeks.iter() {
+            for matchup in week.matchups().iter() {
+                let (home, away) = (*matchup.home_team(), *matchup.away_team());
+                for (team, opponent) in [(home, away), (away, home)] {
+                    if let Some(count) = meetings.get_mut(&team).and_then(|o| o.get_mut(&opponent)) {
+                        *count += 1;
+                    }
+                }
+            }
+        }
+        meetings
+    }
+
+    /// Get the series between two teams in the completed regular season
+    /// games, from the first team's point of view
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league_season.sim_regular_season(&mut rng).unwrap();
+    ///
+    /// // Each pair of teams meets twice in a 4-team round robin
+    /// let series = my_league_season.head_to_head(0, 1).unwrap();
+    /// assert_eq!(series.games.len(), 2);
+    /// let reverse = my_league_season.head_to_head(1, 0).unwrap();
+    /// assert_eq!(series.record.wins(), reverse.record.losses());
+    /// assert_eq!(series.points_for, reverse.points_against);
+    /// assert!(my_league_season.head_to_head(0, 4).is_err());
+    /// ```
+    pub fn head_to_head(&self, team: usize, opponent: usize) -> Result<HeadToHeadSeries, LeagueError> {
+        for id in [team, opponent] {
+            if !self.teams.contains_key(&id) {
+                return Err(LeagueError::TeamNotFound { id });
+            }
+        }
+        if team == opponent {
+            return Err(LeagueError::SameTeam { id: team });
+        }
+        let mut games = Vec::new();
+        for (week_index, week) in self.weeks.iter().enumerate() {
+            for matchup in week.matchups().iter() {
+                let context = matchup.context();
+                if context.game_over() && matchup.participated(team) && matchup.participated(opponent) {
+                    games.push(HeadToHeadGame {
+                        week: week_index,
+                        home_team: *matchup.home_team(),
+                        away_team: *matchup.away_team(),
+                        home_score: context.home_score(),
+                        away_score: context.away_score()
+                    });
+                }
+            }
+        }
+        Ok(HeadToHeadSeries::new(team, opponent, games))
+    }
+
+    /// Explain which of two teams ranks ahead in the standings and the rule
+    /// which decided it, so the reason can be shown next to a seed
+    ///
+    /// Teams sharing a division are compared in the division standings,
+    /// teams sharing only a conference in the conference standings, and
+    /// other teams in the league standings, since a tie among more teams can
+    /// be broken differently.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::tiebreaker::TiebreakerRule;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    ///
+    /// // Before any games, every tiebreaker is level except the team ID
+    /// let explanation = my_league_season.tiebreaker_explanation(2, 1).unwrap();
+    /// assert_eq!((explanation.winner, explanation.loser), (1, 2));
+    /// assert_eq!(explanation.rule, TiebreakerRule::TeamId);
+    /// assert!(my_league_season.tiebreaker_explanation(1, 1).is_err());
+    /// ```
+    pub fn tiebreaker_explanation(&self, team_a: usize, team_b: usize) -> Result<TiebreakerExplanation, LeagueError> {
+        for id in [team_a, team_b] {
+            if !self.teams.contains_key(&id) {
+                return Err(LeagueError::TeamNotFound { id });
+            }
+        }
+        if team_a == team_b {
+            return Err(LeagueError::SameTeam { id: team_a });
+        }
+
+        // Compare the teams in the narrowest standings containing both
+        let all_standings = self.standings();
+        let mut standings = all_standings.clone();
+        for (conf_index, conference) in self.conferences.iter().enumerate() {
+            if !conference.contains_team(team_a) || !conference.contains_team(team_b) {
+                continue;
+            }
+            standings = match (conference.team_division(team_a), conference.team_division(team_b)) {
+                (Some(div_a), Some(div_b)) if div_a == div_b => {
+                    self.filter_division_standings(&all_standings, conf_index, div_a)?
+                },
+                _ => self.filter_conference_standings(&all_standings, conf_index)?
+            };
+        }
+        let position = |id: usize| standings.iter().position(|(team, _)| *team == id);
+        let (pos_a, pos_b) = match (position(team_a), position(team_b)) {
+            (Some(a), Some(b)) => (a, b),
+            (None, _) => return Err(LeagueError::TeamNotFound { id: team_a }),
+            (_, None) => return Err(LeagueError::TeamNotFound { id: team_b })
+        };
+        let (winner, loser) = if pos_a < pos_b { (team_a, team_b) } else { (team_b, team_a) };
+
+        // Teams with different records need no tiebreaker
+        let (record_a, record_b) = (&standings[pos_a].1, &standings[pos_b].1);
+        if !records_tied(record_a, record_b) {
+            return Ok(TiebreakerExplanation { winner, loser, rule: TiebreakerRule::Record });
+        }
+
+        // Replay the tiebreaker chain over the teams tied with them
+        let group: Vec<usize> = standings.iter()
+            .filter(|(_, record)| records_tied(record, record_a))
+            .map(|(id, _)| *id)
+            .collect();
+        let mut rule = None;
+        Tiebreakers::new(self).resolve(group, Some((team_a, team_b)), &mut rule);
+        Ok(TiebreakerExplanation { winner, loser, rule: rule.unwrap_or(TiebreakerRule::TeamId) })
+    }
+
+    /// Summarize how evenly the season's regular season schedule spreads
+    /// each team's home and away games and its meetings with each opponent
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// let balance = my_league_season.schedule_balance();
+    /// assert_eq!(balance.home_games[&0] + balance.away_games[&0], 6);
+    /// ```
+    pub fn schedule_balance(&self) -> ScheduleBalance {
+        let mut balance = ScheduleBalance {
+            home_games: self.teams.keys().map(|id| (*id, 0)).collect(),
+            away_games: self.teams.keys().map(|id| (*id, 0)).collect(),
+            meetings: self.opponent_meetings()
+        };
+        for matchup in self.weeks.iter().flat_map(|w| w.matchups().iter()) {
+            *balance.home_games.entry(*matchup.home_team()).or_default() += 1;
+            *balance.away_games.entry(*matchup.away_team()).or_default() += 1;
+        }
+        balance
+    }
+
+    /// Borrow how the season champion is decided
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::{LeagueSeason, ChampionshipMode};
+    ///
+    /// let my_league_season = LeagueSeason::new();
+    /// assert_eq!(*my_league_season.championship_mode(), ChampionshipMode::Playoffs);
+    /// ```
+    pub fn championship_mode(&self) -> &ChampionshipMode {
+        &self.championship_mode
+    }
+
+    /// Set how the season champion is decided
+    ///
+    /// Fails if the playoffs have already been generated, since the bracket
+    /// would no longer match the new mode.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::{LeagueSeason, ChampionshipMode};
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// let res = my_league_season.set_championship_mode(ChampionshipMode::BestRecord);
+    /// assert!(res.is_ok());
+    /// assert_eq!(*my_league_season.championship_mode(), ChampionshipMode::BestRecord);
+    /// ```
+    pub fn set_championship_mode(&mut self, mode: ChampionshipMode) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        if !self.playoffs.conference_brackets().is_empty() {
+            return Err(LeagueError::PlayoffsAlreadyStarted);
+        }
+        self.championship_mode = mode;
+        Ok(())
+    }
+
+    /// Get the week before which trades must be applied, if any
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let my_league_season = LeagueSeason::new();
+    /// assert!(my_league_season.trade_deadline().is_none());
+    /// ```
+    pub fn trade_deadline(&self) -> Option<usize> {
+        self.trade_deadline
+    }
+
+    /// Set the week before which trades must be applied, or `None` to allow
+    /// trades before any week of the regular season
+    ///
+    /// Fails if a trade has already been applied on or after the new deadline.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// assert!(my_league_season.set_trade_deadline(Some(8)).is_ok());
+    /// assert_eq!(my_league_season.trade_deadline(), Some(8));
+    /// ```
+    pub fn set_trade_deadline(&mut self, deadline: Option<usize>) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        if let Some(d) = deadline {
+            if let Some(record) = self.trades.iter().find(|r| r.week() >= d) {
+                return Err(
+                    format!(
+                        "Cannot set trade deadline to week {}: a trade was applied in week {}",
+                        d, record.week()
+                    ).into()
+                );
+            }
+        }
+        self.trade_deadline = deadline;
+        Ok(())
+    }
+
+    /// Borrow the ledger of trades applied during the season
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let my_league_season = LeagueSeason::new();
+    /// assert!(my_league_season.trades().is_empty());
+    /// ```
+    pub fn trades(&self) -> &Vec<LeagueSeasonTradeRecord> {
+        &self.trades
+    }
+
+    /// Apply a trade before the given week, transferring skill between the
+    /// two teams and recording the trade in the ledger
+    ///
+    /// The week must be the next week to be played and must come before the
+    /// trade deadline, if any. Fails if the trade would push either team's
+    /// offensive or defensive overall out of the range [0, 100]. Individual
+    /// skills are clamped to the same range.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::score::ScoreSimulatable;
+    /// use fbsim_core::league::season::{LeagueSeason, LeagueSeasonScheduleOptions};
+    /// use fbsim_core::league::season::trade::LeagueSeasonTrade;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    ///
+    /// // Team 0 gains offense from team 1 before week 0
+    /// let res = my_league_season.apply_trade(0, LeagueSeasonTrade::new(0, 1, 10, 0));
+    /// assert!(res.is_ok());
+    /// assert_eq!(my_league_season.teams().get(&0).unwrap().offense_overall(), 60);
+    /// assert_eq!(my_league_season.teams().get(&1).unwrap().offense_overall(), 40);
+    /// assert_eq!(my_league_season.trades().len(), 1);
+    /// ```
+    pub fn apply_trade(&mut self, week: usize, trade: LeagueSeasonTrade) -> Result<(), LeagueError> {
+        trade.validate()?;
+
+        // Ensure the week is the next to be played and before the deadline
+        if week >= self.weeks.len() {
+            return Err(LeagueError::WeekOutOfRange { week });
+        }
+        if let Some(deadline) = self.trade_deadline {
+            if week >= deadline {
+                return Err(LeagueError::TradeDeadlinePassed { week, deadline });
+            }
+        }
+        if self.weeks[week].started() {
+            return Err(LeagueError::WeekAlreadyStarted { week });
+        }
+        if let Some(previous) = self.weeks[..week].iter().position(|w| !w.complete()) {
+            return Err(LeagueError::WeekNotComplete { week: previous });
+        }
+
+        // Ensure both teams exist and remain within range after the trade
+        for (id, sign) in [(trade.team_a, 1), (trade.team_b, -1)] {
+            let team = match self.teams.get(&id) {
+                Some(t) => t,
+                None => return Err(LeagueError::TeamNotFound { id })
+            };
+            let offense = team.offense_overall() as i32 + sign * trade.offense_delta;
+            let defense = team.defense_overall() as i32 + sign * trade.defense_delta;
+            if !(0..=100).contains(&offense) {
+                return Err(
+                    format!(
+                        "Trade would push team {} offense overall out of range [0, 100]: {}",
+                        id, offense
+                    ).into()
+                );
+            }
+            if !(0..=100).contains(&defense) {
+                return Err(
+                    format!(
+                        "Trade would push team {} defense overall out of range [0, 100]: {}",
+                        id, defense
+                    ).into()
+                );
+            }
+        }
+
+        // Apply the trade and record it in the ledger
+        self.fingerprint.invalidate();
+        for (id, sign) in [(trade.team_a, 1), (trade.team_b, -1)] {
+            let team = self.teams.get_mut(&id).unwrap();
+            team.offense_mut().adjust(sign * trade.offense_delta);
+            team.defense_mut().adjust(sign * trade.defense_delta);
+        }
+        self.trades.push(LeagueSeasonTradeRecord::new(week, trade));
+        Ok(())
+    }
+
+    /// Borrow the model used to record per-game attendance, if any
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let my_league_season = LeagueSeason::new();
+    /// assert!(my_league_season.attendance_model().is_none());
+    /// ```
+    pub fn attendance_model(&self) -> &Option<AttendanceModel> {
+        &self.attendance_model
+    }
+
+    /// Set the model used to record per-game attendance, or `None` to stop
+    /// recording attendance. Attendance is immediately recorded for any
+    /// completed matchups which have none.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::attendance::AttendanceModel;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// let res = my_league_season.set_attendance_model(Some(AttendanceModel::new()));
+    /// assert!(res.is_ok());
+    /// assert!(my_league_season.attendance_model().is_some());
+    ///
+    /// // Invalid models are rejected
+    /// let res = my_league_season.set_attendance_model(Some(AttendanceModel::new().variance(200)));
+    /// assert!(res.is_err());
+    /// ```
+    pub fn set_attendance_model(&mut self, model: Option<AttendanceModel>) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        if let Some(m) = &model {
+            m.validate()?;
+        }
+        self.attendance_model = model;
+        self.record_attendance();
+        Ok(())
+    }
+
+    /// Borrow the distribution each game's weather is drawn from, if any
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let my_league_season = LeagueSeason::new();
+    /// assert!(my_league_season.weather_distribution().is_none());
+    /// ```
+    pub fn weather_distribution(&self) -> &Option<WeatherDistribution> {
+        &self.weather_distribution
+    }
+
+    /// Set the distribution each game's weather is drawn from when it is
+    /// simulated, or `None` to play every game in clear weather. Games which
+    /// have already started keep their weather.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::weather::WeatherDistribution;
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// let res = my_league_season.set_weather_distribution(Some(WeatherDistribution::new()));
+    /// assert!(res.is_ok());
+    /// assert!(my_league_season.weather_distribution().is_some());
+    ///
+    /// // Invalid distributions are rejected
+    /// let res = my_league_season.set_weather_distribution(Some(WeatherDistribution::new().rain(-1.0)));
+    /// assert!(res.is_err());
+    /// ```
+    pub fn set_weather_distribution(&mut self, distribution: Option<WeatherDistribution>) -> Result<(), LeagueError> {
+        if let Some(d) = &distribution {
+            d.validate()?;
+        }
+        self.fingerprint.invalidate();
+        self.weather_distribution = distribution;
+        Ok(())
+    }
+
+    /// Record attendance for each completed matchup which has none, if the
+    /// season has an attendance model. Regular season attendance is based on
+    /// each team's record entering the week of the matchup, and each game's
+    /// attendance is generated from the model's seed, the season year, the
+    /// week, and the matchup, so it does not depend on the order in which
+    /// games are simulated.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.record_attendance();
+    /// ```
+    pub fn record_attendance(&mut self) {
+        self.fingerprint.invalidate();
+        let model = match &self.attendance_model {
+            Some(m) => m.clone(),
+            None => return
+        };
+        let num_weeks = self.weeks.len();
+
+        // Record regular season attendance, tracking records week by week
+        let mut records: BTreeMap<usize, LeagueTeamRecord> = BTreeMap::new();
+        for (week_index, week) in self.weeks.iter_mut().enumerate() {
+            let mut results: Vec<(usize, FootballMatchupResult)> = Vec::new();
+            for (matchup_index, matchup) in week.matchups_mut().iter_mut().enumerate() {
+                if !matchup.context().game_over() {
+                    continue;
+                }
+                let home_id = *matchup.home_team();
+                let away_id = *matchup.away_team();
+                if matchup.attendance().is_none() {
+                    if let Some(home_team) = self.teams.get(&home_id) {
+                        let home_record = records.get(&home_id).cloned().unwrap_or_default();
+                        let away_record = records.get(&away_id).cloned().unwrap_or_default();
+                        let mut rng = SmallRng::seed_from_u64(
+                            attendance_seed(model.seed, self.year, week_index, matchup_index)
+                        );
+                        *matchup.attendance_mut() = Some(
+                            model.attendance(
+                                home_team.stadium_capacity(), &home_record, &away_record,
+                                week_index, num_weeks, false, &mut rng
+                            )
+                        );
+                    }
+                }
+                for id in [home_id, away_id] {
+                    if let Some(result) = matchup.result(id) {
+                        results.push((id, result));
+                    }
+                }
+            }
+
+            // Apply the week's results once all of its matchups are recorded
+            for (id, result) in results {
+                let record = records.entry(id).or_default();
+                match result {
+                    FootballMatchupResult::Win => record.increment_wins(1),
+                    FootballMatchupResult::Loss => record.increment_losses(1),
+                    FootballMatchupResult::Tie => record.increment_ties(1)
+                }
+            }
+        }
+
+        // Record playoff attendance
+        let mut rng = SmallRng::seed_from_u64(model.seed);
+        let teams = &self.teams;
+        let mut record_playoff_week = |week: &mut LeagueSeasonWeek| {
+            for matchup in week.matchups_mut().iter_mut() {
+                if !matchup.context().game_over() || matchup.attendance().is_some() {
+                    continue;
+                }
+                if let Some(home_team) = teams.get(matchup.home_team()) {
+                    let record = records.get(matchup.home_team()).cloned().unwrap_or_default();
+                    *matchup.attendance_mut() = Some(
+                        model.attendance(
+                            home_team.stadium_capacity(), &record, &record,
+                            num_weeks, num_weeks, true, &mut rng
+                        )
+                    );
+                }
+            }
+        };
+        for week in self.playoffs.conference_brackets_mut().values_mut().flatten() {
+            record_playoff_week(week);
+        }
+        for week in self.playoffs.winners_bracket_mut().iter_mut() {
+            record_playoff_week(week);
+        }
+    }
+
+    /// Compute a team's average regular season home attendance, or `None` if
+    /// no attendance has been recorded for any of its home games
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new()).unwrap();
+    /// let average = my_league_season.average_attendance(0).unwrap();
+    /// assert!(average.is_none());
+    /// ```
+    pub fn average_attendance(&self, team_id: usize) -> Result<Option<f64>, LeagueError> {
+        if !self.teams.contains_key(&team_id) {
+            return Err(LeagueError::TeamNotFound { id: team_id });
+        }
+        let attendances: Vec<u32> = self.weeks.iter()
+            .flat_map(|week| week.matchups().iter())
+            .filter(|matchup| *matchup.home_team() == team_id)
+            .filter_map(|matchup| matchup.attendance())
+            .collect();
+        if attendances.is_empty() {
+            return Ok(None);
+        }
+        let total: u64 = attendances.iter().map(|a| *a as u64).sum();
+        Ok(Some(total as f64 / attendances.len() as f64))
+    }
+
+    /// Borrow the playoffs from the season
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let my_league_season = LeagueSeason::new();
+    /// let my_season_weeks = my_league_season.weeks();
+    /// ```
+    pub fn playoffs(&self) -> &LeagueSeasonPlayoffs {
+        &self.playoffs
+    }
+
+    /// Get the zero-based index of the season week in which a playoff round
+    /// is played, counting from the first regular season week and including
+    /// any rest weeks between playoff rounds
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::{LeagueSeason, LeagueSeasonPlayoffOptions, LeagueSeasonScheduleOptions};
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new());
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league_season.sim_regular_season(&mut rng).unwrap();
+    ///
+    /// // One week off between the semifinals and the final
+    /// let options = LeagueSeasonPlayoffOptions::new()
+    ///     .num_playoff_teams(4)
+    ///     .rest_weeks_between_rounds(vec![1]);
+    /// my_league_season.generate_playoffs(options, &mut rng).unwrap();
+    /// let weeks = my_league_season.weeks().len();
+    /// assert_eq!(my_league_season.playoff_round_week(false, 0), weeks);
+    /// assert_eq!(my_league_season.playoff_round_week(false, 1), weeks + 2);
+    /// ```
+    pub fn playoff_round_week(&self, winners_bracket: bool, round: usize) -> usize {
+        self.weeks.len() + self.playoffs.round_week(winners_bracket, round)
+    }
+
+    /// Mutably borrow the playoffs from the season
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// let my_season_weeks = my_league_season.weeks_mut();
+    /// ```
+    pub fn playoffs_mut(&mut self) -> &mut LeagueSeasonPlayoffs {
+        self.fingerprint.invalidate();
+        &mut self.playoffs
+    }
+
+    /// Determine based on the matchups whether the season has started
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let my_league_season = LeagueSeason::new();
+    /// let started = my_league_season.started();
+    /// ```
+    pub fn started(&self) -> bool {
+        // If no season weeks, then the season hasn't started
+        if self.weeks.is_empty() {
+            return false;
+        }
+
+        // Loop through the season weeks and check if any are started
+        for week in self.weeks.iter() {
+            if week.started() {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Determine whether the regular season is complete
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeasonRaw;
+    ///
+    /// let raw_league_season = LeagueSeasonRaw::new();
+    /// let complete = raw_league_season.regular_season_complete();
+    /// ```
+    pub fn regular_season_complete(&self) -> bool {
+        // If no season weeks, then the season isn't complete
+        if self.weeks.is_empty() {
+            return false;
+        }
+
+        // Loop through the season weeks and check if any are not complete
+        for week in self.weeks.iter() {
+            if !week.complete() {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Determine whether the season is complete
+    ///
+    /// With `ChampionshipMode::BestRecord`, the season is complete once the
+    /// regular season is complete and no teams remain tied for the best
+    /// record, or once the title playoff between the tied teams is complete.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let my_league_season = LeagueSeason::new();
+    /// assert!(!my_league_season.complete());
+    /// ```
+    pub fn complete(&self) -> bool {
+        if !self.regular_season_complete() {
+            false
+        } else if self.championship_mode == ChampionshipMode::BestRecord && self.playoffs.conference_brackets().is_empty() {
+            self.best_record_teams().len() < 2
+        } else {
+            self.playoffs.complete()
+        }
+    }
+
+    /// Create a default conference structure with a single conference and division
+    /// containing all teams. Used when no conferences are defined but schedule
+    /// generation is requested.
+    fn create_default_conference(&mut self) {
+        let mut division = LeagueDivision::with_name("Default");
+        for team_id in self.teams.keys() {
+            division.add_team(*team_id).expect("default conference has unique team IDs");
+        }
+
+        let mut conference = LeagueConference::with_name("Default");
+        conference.add_division(division).expect("default conference has a single division");
+
+        self.conferences.clear();
+        self.conferences.push(conference);
+    }
+
+    /// Check if conference-aware (structured) scheduling is needed
+    fn needs_structured_scheduling(&self) -> bool {
+        needs_structured_scheduling(&self.conferences)
+    }
+
+    /// Generate a schedule for the season.  The generated schedule is a round
+    /// robin schedule in which each team plays an equal number of home and
+    /// away games, and in which each team plays each other twice.  With the
+    /// `ScheduleFormat::Partial` format, each team instead plays a fixed
+    /// number of games, favoring division and conference opponents.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// // Create a new season
+    /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Add 4 teams to the season
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// ```
+    pub fn generate_schedule(&mut self, options: LeagueSeasonScheduleOptions, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        // If no conferences defined, create default single conference with all teams
+        if self.conferences.is_empty() {
+            self.create_default_conference();
+        }
+
+        // Validate that divisions within each conference are of equal size
+        for conference in &self.conferences {
+            let div_sizes: Vec<usize> = conference.divisions().iter()
+                .map(|d| d.num_teams())
+                .collect();
+            if let Some(&first) = div_sizes.first() {
+                if div_sizes.iter().any(|&s| s != first) {
+                    return Err(LeagueError::InvalidSchedule {
+                        reason: format!(
+                            "Conference '{}' has divisions of unequal size: {:?}",
+                            conference.name(), div_sizes
+                        )
+                    });
+                }
+            }
+        }
+
+        // Validate the options, then route to the appropriate schedule
+        // generation method
+        if let Some(interconference_games) = options.interconference_games {
+            self.validate_interconference_games(interconference_games, &options)?;
+        }
+        let format = options.format;
+        let structured = format.is_round_robin() && self.needs_structured_scheduling();
+        if structured {
+            options.validate_common(self.teams.len())?;
+            self.validate_structured_meetings(&options)?;
+        } else {
+            options.validate(self.teams.len())?;
+        }
+        let (max_meetings, exact_meetings) = (options.max_meetings_per_opponent, options.exact_meetings);
+        let res = if let ScheduleFormat::Partial { games_per_team } = format {
+            self.generate_partial_schedule(games_per_team, options, rng)
+        } else if structured {
+            self.generate_structured_schedule(options, rng)
+        } else {
+            self.generate_round_robin_schedule(options, rng)
+        };
+        res?;
+
+        // Ensure the generated schedule honors the meeting constraints
+        for (team, opponents) in self.opponent_meetings().iter() {
+            for (opponent, meetings) in opponents.iter() {
+                let exceeds_max = max_meetings.is_some_and(|max| *meetings > max);
+                let not_exact = exact_meetings.is_some_and(|exact| *meetings != exact);
+                if exceeds_max || not_exact {
+                    self.weeks.clear();
+                    return Err(
+                        LeagueError::InvalidSchedule {
+                            reason: format!(
+                                "Generated schedule for season {} has teams {} and {} meeting {} times",
+                                self.year, team, opponent, meetings
+                            )
+                        }
+                    )
+                }
+            }
+        }
+
+        // Record the format of the generated schedule, whose matchups have
+        // not had their venues swapped
+        self.schedule_format = format;
+        self.venue_swaps.clear();
+        self.result_edits.clear();
+        Ok(())
+    }
+
+    /// Generate a schedule for the season without modifying the season,
+    /// returning it as a proposal which may be installed with
+    /// `apply_schedule`. The proposal is the schedule `generate_schedule`
+    /// would generate given the same options and RNG.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    ///
+    /// // Preview a schedule, leaving the season untouched
+    /// let mut rng = rand::thread_rng();
+    /// let proposal = my_league_season.preview_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// assert!(my_league_season.weeks().is_empty());
+    ///
+    /// // Accept the proposal
+    /// my_league_season.apply_schedule(proposal).unwrap();
+    /// assert_eq!(my_league_season.weeks().len(), 6);
+    /// ```
+    pub fn preview_schedule(&self, options: LeagueSeasonScheduleOptions, rng: &mut impl Rng) -> Result<ScheduleProposal, LeagueError> {
+        let mut season = self.clone();
+        season.generate_schedule(options, rng)?;
+        Ok(ScheduleProposal::from_season(&season))
+    }
+
+    /// Install a schedule previewed by `preview_schedule`. Fails if the
+    /// season has started, or if its teams, their short names, or its
+    /// conferences have changed since the schedule was previewed.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// // Create a new season with 4 teams and preview a schedule
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// let proposal = my_league_season.preview_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    ///
+    /// // A team added after the preview is not in the proposed schedule
+    /// my_league_season.add_team(4, FootballTeam::new()).unwrap();
+    /// my_league_season.add_team(5, FootballTeam::new()).unwrap();
+    /// assert!(my_league_season.apply_schedule(proposal).is_err());
+    /// assert!(my_league_season.weeks().is_empty());
+    /// ```
+    pub fn apply_schedule(&mut self, proposal: ScheduleProposal) -> Result<(), LeagueError> {
+        // Ensure the season has not already started
+        if self.started() {
+            return Err(LeagueError::SeasonAlreadyStarted);
+        }
+
+        // Ensure the teams have not changed since the preview
+        for id in self.teams.keys() {
+            if !proposal.teams().contains_key(id) {
+                return Err(
+                    LeagueError::InvalidSchedule {
+                        reason: format!(
+                            "Cannot apply schedule: team {} was added to season {} after the schedule was previewed",
+                            id, self.year
+                        )
+                    }
+                );
+            }
+        }
+        for (id, short_name) in proposal.teams().iter() {
+            let team = match self.teams.get(id) {
+                Some(t) => t,
+                None => return Err(
+                    LeagueError::InvalidSchedule {
+                        reason: format!(
+                            "Cannot apply schedule: team {} was removed from season {} after the schedule was previewed",
+                            id, self.year
+                        )
+                    }
+                )
+            };
+            if team.short_name() != short_name {
+                return Err(
+                    LeagueError::InvalidSchedule {
+                        reason: format!(
+                            "Cannot apply schedule: team {} was renamed from {} to {} after the schedule was previewed",
+                            id, short_name, team.short_name()
+                        )
+                    }
+                );
+            }
+        }
+
+        // Ensure the conferences have not changed since the preview, unless
+        // the preview created the default conference for the season
+        if !self.conferences.is_empty() && self.conferences != *proposal.conferences() {
+            return Err(
+                LeagueError::InvalidSchedule {
+                    reason: format!(
+                        "Cannot apply schedule: conferences of season {} changed after the schedule was previewed",
+                        self.year
+                    )
+                }
+            );
+        }
+
+        let conference_teams: BTreeSet<usize> = proposal.conferences().iter()
+            .flat_map(|c| c.all_teams())
+            .collect();
+        if !conference_teams.iter().eq(self.teams.keys()) {
+            return Err(
+                LeagueError::InvalidSchedule {
+                    reason: format!(
+                        "Cannot apply schedule: proposed conferences do not contain exactly the teams of season {}",
+                        self.year
+                    )
+                }
+            );
+        }
+
+        // Ensure every matchup is between two of the season's teams
+        for (i, week) in proposal.weeks().iter().enumerate() {
+            for (j, matchup) in week.matchups().iter().enumerate() {
+                if !self.teams.contains_key(matchup.home_team()) || !self.teams.contains_key(matchup.away_team()) {
+                    return Err(
+                        LeagueError::InvalidSchedule {
+                            reason: format!(
+                                "Cannot apply schedule: week {} matchup {} is not between two teams of season {}",
+                                i, j, self.year
+                            )
+                        }
+                    );
+                }
+            }
+        }
+
+        // Install the schedule
+        self.fingerprint.invalidate();
+        let (weeks, format, conferences) = proposal.into_schedule();
+        self.weeks = weeks;
+        self.schedule_format = format;
+        self.conferences = conferences;
+        self.venue_swaps.clear();
+        self.result_edits.clear();
+        Ok(())
+    }
+
+    /// Whether the season is in strict mode, in which a completed matchup
+    /// must be reopened before its score can be changed
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let my_league_season = LeagueSeason::new();
+    /// assert!(!my_league_season.strict());
+    /// ```
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Turn strict mode on or off
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.set_strict(true);
+    /// assert!(my_league_season.strict());
+    /// ```
+    pub fn set_strict(&mut self, strict: bool) {
+        self.fingerprint.invalidate();
+        self.strict = strict;
+    }
+
+    /// Get the seed from which each regular season game's RNG is derived,
+    /// or `None` if games use the caller's RNG
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let my_league_season = LeagueSeason::new();
+    /// assert!(my_league_season.sim_seed().is_none());
+    /// ```
+    pub fn sim_seed(&self) -> Option<u64> {
+        self.sim_seed
+    }
+
+    /// Set the seed from which each regular season game's RNG is derived.
+    /// While set, each game is simulated with the RNG seeded by
+    /// `LeagueSeason::matchup_seed` rather than the caller's RNG, so any
+    /// game can be replayed on its own.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.set_sim_seed(Some(42));
+    /// assert_eq!(my_league_season.sim_seed(), Some(42));
+    /// ```
+    pub fn set_sim_seed(&mut self, sim_seed: Option<u64>) {
+        self.fingerprint.invalidate();
+        self.sim_seed = sim_seed;
+    }
+
+    /// Get the seed of a regular season game's RNG, derived with
+    /// `derive_matchup_seed` from the season's simulation seed, its year, and
+    /// the game's week and matchup indices
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::{derive_matchup_seed, LeagueSeason};
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// let mut my_league_season = LeagueSeason::with_year(2026);
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    ///
+    /// // An unseeded season has no per-game seeds
+    /// assert!(my_league_season.matchup_seed(0, 1).is_err());
+    /// my_league_season.set_sim_seed(Some(42));
+    /// assert_eq!(my_league_season.matchup_seed(0, 1).unwrap(), derive_matchup_seed(42, 2026, 0, 1));
+    /// assert!(my_league_season.matchup_seed(0, 2).is_err());
+    /// ```
+    pub fn matchup_seed(&self, week: usize, matchup: usize) -> Result<u64, LeagueError> {
+        let seed = match self.sim_seed {
+            Some(seed) => seed,
+            None => return Err(LeagueError::NoSimulationSeed)
+        };
+        if self.weeks.get(week).and_then(|w| w.matchups().get(matchup)).is_none() {
+            return Err(LeagueError::MatchupNotFound { week, matchup });
+        }
+        Ok(derive_matchup_seed(seed, self.year, week, matchup))
+    }
+
+    /// Replay a regular season game from kickoff with the RNG it was
+    /// simulated with, returning the replayed game's play-by-play and final
+    /// context without modifying the season
+    ///
+    /// The replay matches the recorded game if the season had its current
+    /// simulation seed when the game was simulated, the game was simulated
+    /// from kickoff in one call, and neither team has changed since.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league_season.set_sim_seed(Some(42));
+    /// my_league_season.sim_week(0, &mut rng).unwrap();
+    ///
+    /// // The replay reproduces the recorded score
+    /// let (game, context) = my_league_season.resimulate_matchup_replay(0, 1).unwrap();
+    /// let recorded = my_league_season.weeks()[0].matchups()[1].context();
+    /// assert_eq!(context.home_score(), recorded.home_score());
+    /// assert_eq!(context.away_score(), recorded.away_score());
+    /// assert!(!game.drives().is_empty());
+    /// ```
+    pub fn resimulate_matchup_replay(&self, week: usize, matchup: usize) -> Result<(Game, GameContext), LeagueError> {
+        let seed = self.matchup_seed(week, matchup)?;
+        let mut replay = self.weeks[week].matchups()[matchup].clone();
+        if !replay.context().game_over() {
+            return Err(LeagueError::MatchupNotComplete { week, matchup });
+        }
+        replay.reopen()?;
+        let mut teams = Vec::new();
+        for id in [*replay.home_team(), *replay.away_team()] {
+            match self.teams.get(&id) {
+                Some(team) => teams.push(team),
+                None => return Err(LeagueError::TeamNotFound { id })
+            }
+        }
+        let mut rng = SmallRng::seed_from_u64(seed);
+        let mut game = Game::new();
+        let context = GameSimulator::new()
+            .sim_game(teams[0], teams[1], replay.sim_context_with_weather(OvertimeRules::regular_season(), self.weather_distribution.as_ref(), &mut rng), &mut game, &mut rng)
+            .map_err(|e| LeagueError::Simulation { reason: e.to_string() })?;
+        Ok((game, context))
+    }
+
+    /// Borrow the ledger of matchup results entered or reopened by hand
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let my_league_season = LeagueSeason::new();
+    /// assert!(my_league_season.result_edits().is_empty());
+    /// ```
+    pub fn result_edits(&self) -> &Vec<LeagueSeasonResultEdit> {
+        &self.result_edits
+    }
+
+    /// Enter the final score of a regular season matchup by hand and record
+    /// it in the result edit ledger. In strict mode, a completed matchup must
+    /// be reopened with `reopen_matchup` before a new score can be entered.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    ///
+    /// // A strict season refuses to overwrite a completed result
+    /// my_league_season.set_strict(true);
+    /// my_league_season.set_score(0, 0, 21, 14).unwrap();
+    /// assert!(my_league_season.set_score(0, 0, 14, 21).is_err());
+    /// assert_eq!(my_league_season.weeks()[0].matchups()[0].context().home_score(), 21);
+    /// ```
+    pub fn set_score(&mut self, week: usize, matchup: usize, home_score: u32, away_score: u32) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        let strict = self.strict;
+        let week_ref = match self.weeks.get_mut(week) {
+            Some(w) => w,
+            None => return Err(LeagueError::WeekOutOfRange { week })
+        };
+        let matchup_ref = match week_ref.matchups_mut().get_mut(matchup) {
+            Some(m) => m,
+            None => return Err(LeagueError::MatchupNotFound { week, matchup })
+        };
+        if strict && matchup_ref.context().game_over() {
+            return Err(
+                LeagueError::MatchupAlreadyComplete { week, matchup }
+            );
+        }
+        matchup_ref.enter_score(home_score, away_score)?;
+        self.result_edits.push(
+            LeagueSeasonResultEdit::new(week, matchup, ResultEditAction::Entered, home_score, away_score)
+        );
+        self.record_attendance();
+        Ok(())
+    }
+
+    /// Clear the result of a completed regular season matchup so that it can
+    /// be simulated or entered again, and record the cleared score in the
+    /// result edit ledger. A matchup cannot be reopened once the playoffs
+    /// have been generated, since they were seeded from the regular season
+    /// results.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::edit::ResultEditAction;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league_season.set_strict(true);
+    ///
+    /// // Reopen a completed matchup to correct its score
+    /// my_league_season.sim_matchup(0, 0, &mut rng).unwrap();
+    /// my_league_season.reopen_matchup(0, 0).unwrap();
+    /// assert!(!my_league_season.weeks()[0].matchups()[0].context().game_over());
+    /// my_league_season.set_score(0, 0, 10, 7).unwrap();
+    /// assert_eq!(my_league_season.result_edits()[0].action, ResultEditAction::Reopened);
+    /// assert_eq!(my_league_season.result_edits()[1].action, ResultEditAction::Entered);
+    /// ```
+    pub fn reopen_matchup(&mut self, week: usize, matchup: usize) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        if self.playoffs.num_teams() > 0 {
+            return Err(
+                LeagueError::PlayoffsAlreadyStarted
+            );
+        }
+        let week_ref = match self.weeks.get_mut(week) {
+            Some(w) => w,
+            None => return Err(LeagueError::WeekOutOfRange { week })
+        };
+        let matchup_ref = match week_ref.matchups_mut().get_mut(matchup) {
+            Some(m) => m,
+            None => return Err(LeagueError::MatchupNotFound { week, matchup })
+        };
+        if !matchup_ref.context().game_over() {
+            return Err(
+                LeagueError::MatchupNotComplete { week, matchup }
+            );
+        }
+        let home_score = matchup_ref.context().home_score();
+        let away_score = matchup_ref.context().away_score();
+        matchup_ref.reopen()?;
+        self.result_edits.push(
+            LeagueSeasonResultEdit::new(week, matchup, ResultEditAction::Reopened, home_score, away_score)
+        );
+        Ok(())
+    }
+
+    /// Helper method which checks that the result of a completed regular
+    /// season matchup can be corrected: the playoffs have not been seeded
+    /// from the regular season results, and no later week has started
+    fn check_result_correction(&self, week: usize, matchup: usize) -> Result<(), LeagueError> {
+        if self.playoffs.num_teams() > 0 {
+            return Err(
+                LeagueError::PlayoffsAlreadyStarted
+            );
+        }
+        let matchup_ref = match self.weeks.get(week) {
+            Some(w) => match w.matchups().get(matchup) {
+                Some(m) => m,
+                None => return Err(LeagueError::MatchupNotFound { week, matchup })
+            },
+            None => return Err(LeagueError::WeekOutOfRange { week })
+        };
+        if !matchup_ref.context().game_over() {
+            return Err(
+                LeagueError::MatchupNotComplete { week, matchup }
+            );
+        }
+        if let Some(later) = self.weeks.iter().skip(week + 1).position(|w| w.started()) {
+            return Err(LeagueError::WeekAlreadyStarted { week: week + 1 + later });
+        }
+        Ok(())
+    }
+
+    /// Correct the final score of a completed regular season matchup. The
+    /// correction is recorded in the result edit ledger as a reopen of the
+    /// previous score followed by the entry of the new one, so it is allowed
+    /// in strict mode. A result cannot be corrected once a later week has
+    /// started or the playoffs have been seeded, since they depend on it.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    ///
+    /// // Correct a score entered by mistake
+    /// my_league_season.set_score(0, 0, 21, 41).unwrap();
+    /// my_league_season.override_result(0, 0, 21, 14).unwrap();
+    /// assert_eq!(my_league_season.weeks()[0].matchups()[0].context().away_score(), 14);
+    ///
+    /// // Unplayed matchups have no result to correct
+    /// assert!(my_league_season.override_result(1, 0, 21, 14).is_err());
+    /// ```
+    pub fn override_result(&mut self, week: usize, matchup: usize, home_score: u32, away_score: u32) -> Result<(), LeagueError> {
+        self.check_result_correction(week, matchup)?;
+        self.fingerprint.invalidate();
+        let matchup_ref = &mut self.weeks[week].matchups_mut()[matchup];
+        let previous = (matchup_ref.context().home_score(), matchup_ref.context().away_score());
+        matchup_ref.enter_score(home_score, away_score)?;
+        self.result_edits.push(
+            LeagueSeasonResultEdit::new(week, matchup, ResultEditAction::Reopened, previous.0, previous.1)
+        );
+        self.result_edits.push(
+            LeagueSeasonResultEdit::new(week, matchup, ResultEditAction::Entered, home_score, away_score)
+        );
+        self.record_attendance();
+        Ok(())
+    }
+
+    /// Clear the result of a completed regular season matchup so that it can
+    /// be simulated again, recording the cleared score in the result edit
+    /// ledger. Unlike `reopen_matchup`, a result cannot be invalidated once a
+    /// later week has started, so the season's weeks are always completed in
+    /// order.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league_season.sim_week(0, &mut rng).unwrap();
+    /// my_league_season.sim_week(1, &mut rng).unwrap();
+    ///
+    /// // Week 0 cannot be invalidated once week 1 has been played
+    /// assert!(my_league_season.invalidate_result(0, 0).is_err());
+    /// my_league_season.invalidate_result(1, 0).unwrap();
+    /// assert!(!my_league_season.weeks()[1].matchups()[0].context().game_over());
+    /// ```
+    pub fn invalidate_result(&mut self, week: usize, matchup: usize) -> Result<(), LeagueError> {
+        self.check_result_correction(week, matchup)?;
+        self.reopen_matchup(week, matchup)
+    }
+
+    /// Borrow the notes attached to parts of the season
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let my_league_season = LeagueSeason::new();
+    /// assert!(my_league_season.annotations().is_empty());
+    /// ```
+    pub fn annotations(&self) -> &Vec<LeagueSeasonAnnotation> {
+        &self.annotations
+    }
+
+    /// Attach a note to part of the season, with an optional timestamp in
+    /// seconds since the Unix epoch. Fails if the target does not exist in
+    /// the season, or if the text is blank or longer than
+    /// `ANNOTATION_MAX_LEN` characters.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::annotation::AnnotationTarget;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    ///
+    /// let target = AnnotationTarget::Matchup { week: 0, matchup: 1 };
+    /// my_league_season.annotate(target, "Decided by a blown call", None).unwrap();
+    /// assert_eq!(my_league_season.annotations_for(&target)[0].text, "Decided by a blown call");
+    ///
+    /// // The target must exist
+    /// let missing = AnnotationTarget::Week { week: 99 };
+    /// assert!(my_league_season.annotate(missing, "The snow week", None).is_err());
+    /// ```
+    pub fn annotate(&mut self, target: AnnotationTarget, text: &str, timestamp: Option<u64>) -> Result<(), LeagueError> {
+        if !target.exists_in(self) {
+            return Err(LeagueError::AnnotationTargetNotFound { target });
+        }
+        let annotation = LeagueSeasonAnnotation::new(target, text, timestamp);
+        annotation.validate()?;
+        self.fingerprint.invalidate();
+        self.annotations.push(annotation);
+        Ok(())
+    }
+
+    /// Borrow the notes attached to a part of the season, in the order they
+    /// were attached
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::annotation::AnnotationTarget;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.annotate(AnnotationTarget::Season, "The expansion season", None).unwrap();
+    /// assert_eq!(my_league_season.annotations_for(&AnnotationTarget::Season).len(), 1);
+    /// assert!(my_league_season.annotations_for(&AnnotationTarget::Team { team_id: 0 }).is_empty());
+    /// ```
+    pub fn annotations_for(&self, target: &AnnotationTarget) -> Vec<&LeagueSeasonAnnotation> {
+        self.annotations.iter().filter(|annotation| annotation.target == *target).collect()
+    }
+
+    /// Remove a note from the season by its index among the season's notes
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::annotation::AnnotationTarget;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.annotate(AnnotationTarget::Season, "The expansion season", None).unwrap();
+    /// let removed = my_league_season.remove_annotation(0).unwrap();
+    /// assert_eq!(removed.text, "The expansion season");
+    /// assert!(my_league_season.remove_annotation(0).is_err());
+    /// ```
+    pub fn remove_annotation(&mut self, index: usize) -> Result<LeagueSeasonAnnotation, LeagueError> {
+        if index >= self.annotations.len() {
+            return Err(LeagueError::AnnotationNotFound { index });
+        }
+        self.fingerprint.invalidate();
+        Ok(self.annotations.remove(index))
+    }
+
+    /// Borrow the ledger of matchups whose home and away teams were swapped
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let my_league_season = LeagueSeason::new();
+    /// assert!(my_league_season.venue_swaps().is_empty());
+    /// ```
+    pub fn venue_swaps(&self) -> &Vec<LeagueSeasonVenueSwap> {
+        &self.venue_swaps
+    }
+
+    /// Swap the home and away teams of an unplayed regular season matchup
+    /// and record the swap in the venue swap ledger
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    ///
+    /// // Move the first game of week 0 to the away team's stadium
+    /// let away = *my_league_season.weeks()[0].matchups()[0].away_team();
+    /// my_league_season.swap_home_away(0, 0).unwrap();
+    /// assert_eq!(*my_league_season.weeks()[0].matchups()[0].home_team(), away);
+    /// assert_eq!(my_league_season.venue_swaps().len(), 1);
+    /// ```
+    pub fn swap_home_away(&mut self, week: usize, matchup: usize) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        let week_ref = match self.weeks.get_mut(week) {
+            Some(w) => w,
+            None => return Err(LeagueError::WeekOutOfRange { week })
+        };
+        let matchup_ref = match week_ref.matchups_mut().get_mut(matchup) {
+            Some(m) => m,
+            None => return Err(LeagueError::MatchupNotFound { week, matchup })
+        };
+        matchup_ref.swap_home_away()?;
+        let swap = LeagueSeasonVenueSwap::new(
+            week, matchup, *matchup_ref.home_team(), *matchup_ref.away_team()
+        );
+        self.venue_swaps.push(swap);
+        Ok(())
+    }
+
+    /// Swap the home and away teams of unplayed regular season matchups
+    /// until every team's home and away games differ by at most one, or no
+    /// further swap can help. Each step swaps the shortest chain of unplayed
+    /// matchups leading from a team with too many home games to one with more
+    /// away games than home games, or the reverse, so that only the teams at
+    /// either end of the chain change balance. Returns the schedule's balance
+    /// before and after along with the swaps made, each of which is also
+    /// recorded in the venue swap ledger.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    ///
+    /// // Even out the home and away games of each team
+    /// let rebalance = my_league_season.rebalance_home_away().unwrap();
+    /// assert!(rebalance.balanced());
+    /// assert_eq!(rebalance.after, my_league_season.schedule_balance());
+    /// ```
+    pub fn rebalance_home_away(&mut self) -> Result<HomeAwayRebalance, LeagueError> {
+        let before = self.schedule_balance();
+        let mut swaps: Vec<LeagueSeasonVenueSwap> = Vec::new();
+        loop {
+            // Count each team's home games in excess of its away games
+            let balance = self.schedule_balance();
+            let surplus: BTreeMap<usize, i64> = balance.home_games.iter()
+                .map(|(id, home)| {
+                    let away = balance.away_games.get(id).copied().unwrap_or(0);
+                    (*id, *home as i64 - away as i64)
+                })
+                .collect();
+
+            // Collect the unplayed matchups which may be swapped
+            let mut games: Vec<(usize, usize, usize, usize)> = Vec::new();
+            for (i, week) in self.weeks.iter().enumerate() {
+                for (j, matchup) in week.matchups().iter().enumerate() {
+                    let context = matchup.context();
+                    if matchup.game().is_none() && !context.started() && !context.game_over() {
+                        games.push((i, j, *matchup.home_team(), *matchup.away_team()));
+                    }
+                }
+            }
+
+            // Swap along a chain from the first unbalanced team with one
+            let path = surplus.iter()
+                .filter(|(_, s)| s.abs() >= 2)
+                .find_map(|(id, s)| venue_swap_path(&games, &surplus, *id, *s > 0));
+            let path = match path {
+                Some(p) => p,
+                None => break
+            };
+            for (week, matchup) in path {
+                self.swap_home_away(week, matchup)?;
+                swaps.extend(self.venue_swaps.last().copied());
+            }
+        }
+        Ok(
+            HomeAwayRebalance {
+                before,
+                after: self.schedule_balance(),
+                swaps
+            }
+        )
+    }
+
+    /// Check that the per-opponent game counts of a structured schedule
+    /// satisfy the meeting constraints, given that division and conference
+    /// opponents meet a fixed number of times and cross-conference opponents
+    /// meet at most once
+    fn validate_structured_meetings(&self, options: &LeagueSeasonScheduleOptions) -> Result<(), LeagueError> {
+        let division_games = options.division_games.unwrap_or(2);
+        let conference_games = options.conference_games.unwrap_or(1);
+        let cross_conference_games = options.interconference_games
+            .or(options.cross_conference_games)
+            .unwrap_or(0);
+        let has_division_opponents = self.conferences.iter()
+            .flat_map(|c| c.divisions().iter())
+            .any(|d| d.num_teams() > 1);
+        let has_conference_opponents = self.conferences.iter()
+            .any(|c| c.divisions().iter().filter(|d| d.num_teams() > 0).count() > 1);
+        let teams_in_conferences: usize = self.conferences.iter().map(|c| c.num_teams()).sum();
+
+        // Each kind of opponent must meet within the maximum
+        if let Some(max) = options.max_meetings_per_opponent {
+            let counts = [
+                (has_division_opponents, division_games, "Division"),
+                (has_conference_opponents, conference_games, "Conference"),
+                (cross_conference_games > 0, 1, "Cross-conference")
+            ];
+            for (applies, meetings, kind) in counts {
+                if applies && meetings > max {
+                    return Err(
+                        LeagueError::InvalidSchedule {
+                            reason: format!(
+                                "{} opponents meeting {} times exceed the maximum meetings per opponent ({})",
+                                kind, meetings, max
+                            )
+                        }
+                    )
+                }
+            }
+            if let Some(exact) = options.exact_meetings {
+                if exact > max {
+                    return Err(
+                        LeagueError::InvalidSchedule {
+                            reason: format!(
+                                "Exact meetings per opponent ({}) exceed the maximum meetings per opponent ({})",
+                                exact, max
+                            )
+                        }
+                    )
+                }
+            }
+        }
+
+        // Every kind of opponent must meet exactly the required number of
+        // times, so every cross-conference pair must meet once
+        if let Some(exact) = options.exact_meetings {
+            if has_division_opponents && division_games != exact {
+                return Err(
+                    LeagueError::InvalidSchedule {
+                        reason: format!(
+                            "Division opponents meeting {} times cannot meet exactly {} times",
+                            division_games, exact
+                        )
+                    }
+                )
+            }
+            if has_conference_opponents && conference_games != exact {
+                return Err(
+                    LeagueError::InvalidSchedule {
+                        reason: format!(
+                            "Conference opponents meeting {} times cannot meet exactly {} times",
+                            conference_games, exact
+                        )
+                    }
+                )
+            }
+            for conference in self.conferences.iter() {
+                let cross_conference_opponents = teams_in_conferences - conference.num_teams();
+                if conference.num_teams() == 0 || cross_conference_opponents == 0 {
+                    continue;
+                }
+                if exact != 1 {
+                    return Err(
+                        LeagueError::InvalidSchedule {
+                            reason: format!(
+                                "Cross-conference opponents meet at most once, so they cannot meet exactly {} times",
+                                exact
+                            )
+                        }
+                    )
+                }
+                if cross_conference_games != cross_conference_opponents {
+                    return Err(
+                        LeagueError::InvalidSchedule {
+                            reason: format!(
+                                "Conference '{}' teams must play {} cross-conference games to meet each opponent exactly once: {} given",
+                                conference.name(), cross_conference_opponents, cross_conference_games
+                            )
+                        }
+                    )
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that every team can play exactly the given number of games
+    /// against the other conference, each against a different opponent
+    fn validate_interconference_games(&self, interconference_games: usize, options: &LeagueSeasonScheduleOptions) -> Result<(), LeagueError> {
+        if options.cross_conference_games.is_some() {
+            return Err(
+                LeagueError::InvalidSchedule {
+                    reason: String::from("Cannot set both cross-conference games and inter-conference games")
+                }
+            )
+        }
+        if !options.format.is_round_robin() {
+            return Err(
+                LeagueError::InvalidSchedule {
+                    reason: String::from("Inter-conference games require the round robin schedule format")
+                }
+            )
+        }
+        let conferences: Vec<&LeagueConference> = self.conferences.iter()
+            .filter(|c| c.num_teams() > 0)
+            .collect();
+        if conferences.len() != 2 {
+            return Err(
+                LeagueError::InvalidSchedule {
+                    reason: format!(
+                        "Inter-conference games require exactly 2 conferences with teams: {} given",
+                        conferences.len()
+                    )
+                }
+            )
+        }
+        if interconference_games == 0 {
+            return Ok(());
+        }
+
+        // Each inter-conference game is played by one team of each
+        // conference, so both conferences play the same number of them
+        let (first, second) = (conferences[0], conferences[1]);
+        if first.num_teams() != second.num_teams() {
+            return Err(
+                LeagueError::InvalidSchedule {
+                    reason: format!(
+                        "Conferences '{}' ({} teams) and '{}' ({} teams) cannot each play {} inter-conference games per team: \
+                            they would play {} and {} games against each other",
+                        first.name(), first.num_teams(), second.name(), second.num_teams(), interconference_games,
+                        first.num_teams() * interconference_games, second.num_teams() * interconference_games
+                    )
+                }
+            )
+        }
+        if interconference_games > second.num_teams() {
+            return Err(
+                LeagueError::InvalidSchedule {
+                    reason: format!(
+                        "Teams can play at most {} inter-conference games, one against each team of the other conference: {} given",
+                        second.num_teams(), interconference_games
+                    )
+                }
+            )
+        }
+        Ok(())
+    }
+
+    /// Generate a partial schedule in which each team plays a fixed number
+    /// of games, one per week
+    fn generate_partial_schedule(&mut self, games_per_team: usize, options: LeagueSeasonScheduleOptions, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        // The options have been validated by generate_schedule
+        let num_teams = self.teams.len();
+        let num_weeks = games_per_team;
+        let shift = options.shift.unwrap_or(0);
+
+        // Check to make sure the season has not already started
+        if self.started() {
+            return Err(
+                LeagueError::SeasonAlreadyStarted
+            )
+        }
+
+        // If the schedule is already non-empty then empty it before re-gen
+        if !self.weeks.is_empty() {
+            self.weeks.clear()
+        }
+
+        // Generate the rounds of a single round robin, in which each team
+        // plays each other team exactly once
+        let mut team_ids: Vec<usize> = self.teams.keys().cloned().collect();
+        team_ids.shuffle(rng);
+        let mut rounds: Vec<Vec<(usize, usize)>> = Vec::with_capacity(num_teams - 1);
+        for round in 0..(num_teams - 1) {
+            let mut arrangement: Vec<usize> = Vec::with_capacity(num_teams);
+            arrangement.push(team_ids[0]);
+            for i in 0..(num_teams - 1) {
+                let rotated_index = (i + (num_teams - 1) - round) % (num_teams - 1);
+                arrangement.push(team_ids[1 + rotated_index]);
+            }
+            let pairs: Vec<(usize, usize)> = (0..num_teams / 2)
+                .map(|m| (arrangement[m], arrangement[num_teams - 1 - m]))
+                .collect();
+            rounds.push(pairs);
+        }
+
+        // Prioritize the rounds containing the most division and conference
+        // opponents, so that partial cycles favor structured opponents
+        let round_priority = |pairs: &Vec<(usize, usize)>| -> usize {
+            pairs.iter().map(|(a, b)| {
+                if self.same_division(*a, *b) {
+                    2
+                } else if self.same_conference(*a, *b) {
+                    1
+                } else {
+                    0
+                }
+            }).sum()
+        };
+        let mut prioritized: Vec<&Vec<(usize, usize)>> = rounds.iter().collect();
+        prioritized.sort_by_key(|pairs| std::cmp::Reverse(round_priority(pairs)));
+
+        // Take full round robin cycles, then the highest priority rounds, so
+        // that no pair repeats unless necessary
+        let full_cycles = games_per_team / (num_teams - 1);
+        let remainder = games_per_team % (num_teams - 1);
+        let mut selected: Vec<Vec<(usize, usize)>> = Vec::with_capacity(num_weeks);
+        for _ in 0..full_cycles {
+            selected.extend(rounds.iter().cloned());
+        }
+        selected.extend(prioritized.into_iter().take(remainder).cloned());
+
+        // Assign home and away teams so each team's split is as even as possible
+        let oriented = Self::balance_home_away(&team_ids, selected);
+
+        // Create the weeks of the season from the selected rounds
+        for round in oriented {
+            let mut week = LeagueSeasonWeek::new();
+            for (home_id, away_id) in round {
+                let home_short_name = self.teams.get(&home_id).unwrap().short_name();
+                let away_short_name = self.teams.get(&away_id).unwrap().short_name();
+                let matchup = LeagueSeasonMatchupBuilder::new()
+                    .home_team(home_id)
+                    .away_team(away_id)
+                    .home_short_name(home_short_name)
+                    .away_short_name(away_short_name)
+                    .coin_toss(rng)
+                    .build()?;
+                week.matchups_mut().push(matchup);
+            }
+            self.weeks.push(week);
+        }
+
+        // If desired, shift the weeks of the season
+        if shift > 0 {
+            self.weeks.rotate_right(shift);
+        }
+
+        // If desired, randomly permute the weeks of the season
+        if let Some(true) = options.permute {
+            self.weeks.shuffle(rng);
+        }
+        Ok(())
+    }
+
+    /// Orient the given rounds of pairings such that each team's number of
+    /// home and away games differ by at most one
+    ///
+    /// Each team with an odd number of games is joined to a placeholder, so
+    /// that every team has an even number of games. The games are then
+    /// walked as closed trails, hosting each game at the team the trail
+    /// departs from, so every team departs as often as it arrives.
+    fn balance_home_away(team_ids: &[usize], rounds: Vec<Vec<(usize, usize)>>) -> Vec<Vec<(usize, usize)>> {
+        // Map team IDs to vertex indices, with the placeholder last
+        let index: BTreeMap<usize, usize> = team_ids.iter().enumerate()
+            .map(|(i, id)| (*id, i))
+            .collect();
+        let placeholder = team_ids.len();
+
+        // Collect the games as edges, followed by placeholder edges
+        let mut edges: Vec<(usize, usize)> = rounds.iter().flatten()
+            .map(|(a, b)| (index[a], index[b]))
+            .collect();
+        let num_games = edges.len();
+        let mut degrees: Vec<usize> = vec![0; placeholder + 1];
+        for (a, b) in edges.iter() {
+            degrees[*a] += 1;
+            degrees[*b] += 1;
+        }
+        for (vertex, degree) in degrees.iter().enumerate().take(placeholder) {
+            if !degree.is_multiple_of(2) {
+                edges.push((vertex, placeholder));
+            }
+        }
+
+        // Build the adjacency lists
+        let mut adjacent: Vec<Vec<usize>> = vec![Vec::new(); placeholder + 1];
+        for (e, (a, b)) in edges.iter().enumerate() {
+            adjacent[*a].push(e);
+            adjacent[*b].push(e);
+        }
+
+        // Walk closed trails until every edge is used, orienting each edge
+        // in the direction it is walked
+        let mut used: Vec<bool> = vec![false; edges.len()];
+        let mut next: Vec<usize> = vec![0; placeholder + 1];
+        let mut flipped: Vec<bool> = vec![false; edges.len()];
+        for start in 0..=placeholder {
+            let mut vertex = start;
+            loop {
+                // Find the next unused edge from this vertex
+                while next[vertex] < adjacent[vertex].len() && used[adjacent[vertex][next[vertex]]] {
+                    next[vertex] += 1;
+                }
+                if next[vertex] == adjacent[vertex].len() {
+                    break;
+                }
+                let e = adjacent[vertex][next[vertex]];
+                used[e] = true;
+                let (a, b) = edges[e];
+                flipped[e] = a != vertex;
+                vertex = if a == vertex { b } else { a };
+            }
+        }
+
+        // Rebuild the rounds with the home team first
+        let mut e = 0;
+        let mut oriented: Vec<Vec<(usize, usize)>> = Vec::with_capacity(rounds.len());
+        for round in rounds {
+            let mut pairs = Vec::with_capacity(round.len());
+            for (a, b) in round {
+                pairs.push(if flipped[e] { (b, a) } else { (a, b) });
+                e += 1;
+            }
+            oriented.push(pairs);
+        }
+        debug_assert_eq!(e, num_games);
+        oriented
+    }
+
+    /// Generate a simple round-robin schedule (existing algorithm)
+    fn generate_round_robin_schedule(&mut self, options: LeagueSeasonScheduleOptions, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        // The options have been validated by generate_schedule
+        let num_teams = self.teams.len();
+        let num_weeks = options.round_robin_weeks(num_teams);
+        let shift = options.shift.unwrap_or(0);
+
+        // Check to make sure the season has not already started
+        if self.started() {
+            return Err(
+                LeagueError::SeasonAlreadyStarted
+            )
+        }
+
+        // If the schedule is already non-empty then empty it before re-gen
+        if !self.weeks.is_empty() {
+            self.weeks.clear()
+        }
+
+        // Generate the vec of team IDs and randomly permute it for a unique schedule
+        let mut team_ids: Vec<usize> = self.teams.keys().cloned().collect();
+        team_ids.shuffle(rng); // Generate a random permutation of the season team IDs
+
+        // With an odd number of teams, add an empty slot for the bye. The
+        // team paired with it each week sits out.
+        let mut slots: Vec<Option<usize>> = team_ids.into_iter().map(Some).collect();
+        if !num_teams.is_multiple_of(2) {
+            slots.push(None);
+        }
+        let num_slots = slots.len();
+
+        // Generate the round-robin schedule using the circle method:
+        // - Keep the team at index 0 fixed
+        // - Rotate the teams at indices 1 to n-1
+        // - Pair: index 0 with index n-1, index 1 with index n-2, etc.
+        // This guarantees each pair plays exactly once per cycle of (n-1) rounds
+        for week_index in 0..num_weeks {
+            // Create a new league season week
+            let mut week = LeagueSeasonWeek::new();
+            let num_matchups = num_slots / 2;
+
+            // Determine which round within the cycle and which cycle we're in
+            // Each cycle is (n-1) rounds; in a double round-robin we have 2 cycles
+            let round_in_cycle = week_index % (num_slots - 1);
+            let cycle = week_index / (num_slots - 1);
+
+            // Build the arrangement for this round using the circle method
+            // Team at index 0 stays fixed, others rotate
+            let mut arrangement: Vec<Option<usize>> = Vec::with_capacity(num_slots);
+            arrangement.push(slots[0]); // Fixed team
+            for i in 0..(num_slots - 1) {
+                // Rotate the other teams: for round r, shift by r positions
+                let rotated_index = (i + (num_slots - 1) - round_in_cycle) % (num_slots - 1);
+                arrangement.push(slots[1 + rotated_index]);
+            }
+
+            // Create matchups for each pair of teams
+            // Pair first with last, second with second-to-last, etc.
+            for matchup_index in 0..num_matchups {
+                let team1_index = matchup_index;
+                let team2_index = num_slots - 1 - matchup_index;
+
+                // Skip the pairing with the bye
+                let (team1_id, team2_id) = match (arrangement[team1_index], arrangement[team2_index]) {
+                    (Some(team1_id), Some(team2_id)) => (team1_id, team2_id),
+                    _ => continue
+                };
+
+                // Determine home/away based on cycle to balance home games
+                // In cycle 0, team1 (lower index) is home; in cycle 1, team2 is home
+                // This ensures each pair plays once with each team as home
+                let (home_id, away_id) = if cycle.is_multiple_of(2) {
+                    (team1_id, team2_id)
+                } else {
+                    (team2_id, team1_id)
+                };
+
+                // Get the home & away short names
+                let home_short_name = self.teams.get(&home_id).unwrap().short_name();
+                let away_short_name = self.teams.get(&away_id).unwrap().short_name();
+
+                // Create the matchup and add to the week
+                let matchup = LeagueSeasonMatchupBuilder::new()
+                    .home_team(home_id)
+                    .away_team(away_id)
+                    .home_short_name(home_short_name)
+                    .away_short_name(away_short_name)
+                    .coin_toss(rng)
+                    .build()?;
+                week.matchups_mut().push(matchup);
+            }
+
+            // Add the week to the season
+            self.weeks.push(week);
+        }
+
+        // If desired, shift the weeks of the season
+        if shift > 0 {
+            self.weeks.rotate_right(shift);
+        }
+
+        // If desired, randomly permute the weeks of the season
+        if let Some(permute) = options.permute {
+            if permute {
+                self.weeks.shuffle(rng);
+            }
+        }
+        Ok(())
+    }
+
+    /// Generate a conference-aware structured schedule
+    fn generate_structured_schedule(&mut self, options: LeagueSeasonScheduleOptions, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        // Check to make sure the season has not already started
+        if self.started() {
+            return Err(LeagueError::SeasonAlreadyStarted);
+        }
+
+        // Clear existing schedule
+        if !self.weeks.is_empty() {
+            self.weeks.clear();
+        }
+
+        // Get scheduling parameters with defaults
+        let division_games = options.division_games.unwrap_or(2);
+        let conference_games = options.conference_games.unwrap_or(1);
+        let cross_conference_games = options.cross_conference_games.unwrap_or(0);
+
+        // Generate all matchups as (home_id, away_id) tuples
+        let mut all_matchups: Vec<(usize, usize)> = Vec::new();
+
+        // Phase 1: Division matchups
+        let division_matchups = self.generate_division_matchups(division_games, rng)?;
+        all_matchups.extend(division_matchups);
+
+        // Phase 2: Conference (non-division) matchups
+        let conference_matchups = self.generate_conference_matchups(conference_games, rng)?;
+        all_matchups.extend(conference_matchups);
+
+        // Phase 3: Cross-conference matchups
+        if let Some(interconference_games) = options.interconference_games {
+            let interconference_matchups = self.generate_interconference_matchups(interconference_games, rng);
+            all_matchups.extend(interconference_matchups);
+        } else if cross_conference_games > 0 {
+            let cross_conf_matchups = self.generate_cross_conference_matchups(cross_conference_games, rng)?;
+            all_matchups.extend(cross_conf_matchups);
+        }
+
+        // Phase 4: Interleave matchups into weeks
+        self.interleave_matchups(all_matchups, rng)?;
+
+        // Validate the shift now that the number of weeks is known
+        if let Err(e) = options.validate_shift(self.weeks.len()) {
+            self.weeks.clear();
+            return Err(e);
+        }
+        let shift = options.shift.unwrap_or(0);
+        if shift > 0 {
+            self.weeks.rotate_right(shift);
+        }
+
+        // If desired, randomly permute the weeks of the season
+        if let Some(true) = options.permute {
+            self.weeks.shuffle(rng);
+        }
+
+        Ok(())
+    }
+
+    /// Generate intra-division matchups using circle method within each division
+    fn generate_division_matchups(&self, games_per_opponent: usize, rng: &mut impl Rng) -> Result<Vec<(usize, usize)>, LeagueError> {
+        let mut matchups = Vec::new();
+
+        for conference in &self.conferences {
+            for division in conference.divisions().iter() {
+                let teams: Vec<usize> = division.teams().clone();
+                let n = teams.len();
+
+                if n < 2 {
+                    continue; // Skip divisions with less than 2 teams
+                }
+
+                // Generate round-robin matchups within division
+                let div_matchups = self.circle_method_matchups(&teams, games_per_opponent, rng)?;
+                matchups.extend(div_matchups);
+            }
+        }
+
+        Ok(matchups)
+    }
+
+    /// Generate intra-conference (cross-division) matchups
+    fn generate_conference_matchups(&self, games_per_opponent: usize, rng: &mut impl Rng) -> Result<Vec<(usize, usize)>, LeagueError> {
+        let mut matchups = Vec::new();
+
+        if games_per_opponent == 0 {
+            return Ok(matchups);
+        }
+
+        for conference in &self.conferences {
+            // Get all teams in this conference
+            let all_teams = conference.all_teams();
+
+            // For each pair of teams in the same conference but different divisions
+            for i in 0..all_teams.len() {
+                for j in (i + 1)..all_teams.len() {
+                    let team1 = all_teams[i];
+                    let team2 = all_teams[j];
+
+                    // Skip if same division (already handled in division matchups)
+                    if self.same_division(team1, team2) {
+                        continue;
+                    }
+
+                    // Generate the specified number of games
+                    for cycle in 0..games_per_opponent {
+                        // Alternate home/away
+                        if cycle % 2 == 0 {
+                            matchups.push((team1, team2));
+                        } else {
+                            matchups.push((team2, team1));
+                        }
+                    }
+                }
+            }
+        }
+
+        // Shuffle to add variety
+        matchups.shuffle(rng);
+        Ok(matchups)
+    }
+
+    /// Generate cross-conference matchups
+    fn generate_cross_conference_matchups(&self, total_games_per_team: usize, rng: &mut impl Rng) -> Result<Vec<(usize, usize)>, LeagueError> {
+        let mut matchups = Vec::new();
+
+        if self.conferences.len() < 2 || total_games_per_team == 0 {
+            return Ok(matchups);
+        }
+
+        // Track how many cross-conference games each team has scheduled
+        let mut team_cross_conf_games: BTreeMap<usize, usize> = BTreeMap::new();
+        for team_id in self.teams.keys() {
+            team_cross_conf_games.insert(*team_id, 0);
+        }
+
+        // Get all possible cross-conference pairs
+        let mut possible_pairs: Vec<(usize, usize)> = Vec::new();
+        for (i, conf1) in self.conferences.iter().enumerate() {
+            for conf2 in self.conferences.iter().skip(i + 1) {
+                let teams1 = conf1.all_teams();
+                let teams2 = conf2.all_teams();
+
+                for t1 in &teams1 {
+                    for t2 in &teams2 {
+                        possible_pairs.push((*t1, *t2));
+                    }
+                }
+            }
+        }
+
+        // Shuffle pairs for randomness
+        possible_pairs.shuffle(rng);
+
+        // Greedily assign cross-conference games
+        for (team1, team2) in possible_pairs {
+            let games1 = *team_cross_conf_games.get(&team1).unwrap_or(&0);
+            let games2 = *team_cross_conf_games.get(&team2).unwrap_or(&0);
+
+            if games1 < total_games_per_team && games2 < total_games_per_team {
+                // Randomly decide home/away
+                if rng.gen::<bool>() {
+                    matchups.push((team1, team2));
+                } else {
+                    matchups.push((team2, team1));
+                }
+                *team_cross_conf_games.get_mut(&team1).unwrap() += 1;
+                *team_cross_conf_games.get_mut(&team2).unwrap() += 1;
+            }
+        }
+
+        Ok(matchups)
+    }
+
+    /// Generate exactly the given number of games for each team against the
+    /// other of two equally sized conferences. In each round every team of
+    /// the first conference plays the next team of the second, and the
+    /// conferences alternate hosting rounds so each team's home and away
+    /// games differ by at most one.
+    fn generate_interconference_matchups(&self, games_per_team: usize, rng: &mut impl Rng) -> Vec<(usize, usize)> {
+        let mut matchups = Vec::new();
+        let conferences: Vec<&LeagueConference> = self.conferences.iter()
+            .filter(|c| c.num_teams() > 0)
+            .collect();
+        if conferences.len() != 2 || games_per_team == 0 {
+            return matchups;
+        }
+
+        // Shuffle both conferences for variety
+        let mut first = conferences[0].all_teams();
+        let mut second = conferences[1].all_teams();
+        first.shuffle(rng);
+        second.shuffle(rng);
+
+        // Randomly decide which conference hosts the odd game, if any
+        let first_hosts_even_rounds = rng.gen::<bool>();
+        let n = second.len();
+        for round in 0..games_per_team {
+            let first_home = (round % 2 == 0) == first_hosts_even_rounds;
+            for (i, team1) in first.iter().enumerate() {
+                let team2 = second[(i + round) % n];
+                if first_home {
+                    matchups.push((*team1, team2));
+                } else {
+                    matchups.push((team2, *team1));
+                }
+            }
+        }
+        matchups
+    }
+
+    /// Apply circle method to generate round-robin matchups for a set of teams
+    fn circle_method_matchups(&self, teams: &[usize], games_per_opponent: usize, rng: &mut impl Rng) -> Result<Vec<(usize, usize)>, LeagueError> {
+        let mut matchups = Vec::new();
+        let n = teams.len();
+
+        if n < 2 {
+            return Ok(matchups);
+        }
+
+        // Shuffle teams for variety
+        let mut team_ids = teams.to_vec();
+        team_ids.shuffle(rng);
+
+        let num_rounds = n - 1;
+        let num_matchups_per_round = n / 2;
+
+        // Generate games_per_opponent cycles of round-robin
+        for cycle in 0..games_per_opponent {
+            for round in 0..num_rounds {
+                // Build arrangement using circle method
+                let mut arrangement: Vec<usize> = Vec::with_capacity(n);
+                arrangement.push(team_ids[0]); // Fixed team
+                for i in 0..(n - 1) {
+                    let rotated_index = (i + (n - 1) - round) % (n - 1);
+                    arrangement.push(team_ids[1 + rotated_index]);
+                }
+
+                // Create matchups
+                for m in 0..num_matchups_per_round {
+                    let team1 = arrangement[m];
+                    let team2 = arrangement[n - 1 - m];
+
+                    // Alternate home/away based on cycle
+                    if cycle % 2 == 0 {
+                        matchups.push((team1, team2));
+                    } else {
+                        matchups.push((team2, team1));
+                    }
+                }
+            }
+        }
+
+        Ok(matchups)
+    }
+
+    /// Interleave matchups into weeks, avoiding long road trips
+    fn interleave_matchups(&mut self, matchups: Vec<(usize, usize)>, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        if matchups.is_empty() {
+            return Ok(());
+        }
+
+        let num_teams = self.teams.len();
+        let _matchups_per_week = num_teams / 2;
+
+        // Track home/away streaks and which matchups are scheduled
+        let mut remaining_matchups: Vec<(usize, usize)> = matchups;
+        remaining_matchups.shuffle(rng); // Shuffle for variety
+
+        let mut team_away_streak: BTreeMap<usize, usize> = BTreeMap::new();
+        let mut team_home_streak: BTreeMap<usize, usize> = BTreeMap::new();
+        for id in self.teams.keys() {
+            team_away_streak.insert(*id, 0);
+            team_home_streak.insert(*id, 0);
+        }
+
+        while !remaining_matchups.is_empty() {
+            let mut week = LeagueSeasonWeek::new();
+            let mut teams_scheduled_this_week: BTreeSet<usize> = BTreeSet::new();
+            let mut matchups_this_week: Vec<usize> = Vec::new(); // indices into remaining_matchups
+
+            // Greedily select matchups for this week
+            for (index, (home_id, away_id)) in remaining_matchups.iter().enumerate() {
+                // Skip if either team already scheduled this week
+                if teams_scheduled_this_week.contains(home_id) || teams_scheduled_this_week.contains(away_id) {
+                    continue;
+                }
+
+                // Check road trip constraint (no more than 2 consecutive away games)
+                let away_streak = *team_away_streak.get(away_id).unwrap_or(&0);
+                if away_streak >= 2 {
+                    // Check if there's an alternative where this team is home
+                    // For now, we'll allow it but prefer not to
+                    // This could be improved with backtracking
+                }
+
+                // Schedule this matchup
+                teams_scheduled_this_week.insert(*home_id);
+                teams_scheduled_this_week.insert(*away_id);
+                matchups_this_week.push(index);
+
+                if teams_scheduled_this_week.len() >= num_teams {
+                    break; // Week is full
+                }
+            }
+
+            // If we couldn't schedule any matchups but there are still remaining, force one
+            if matchups_this_week.is_empty() && !remaining_matchups.is_empty() {
+                matchups_this_week.push(0);
+                let (home_id, away_id) = remaining_matchups[0];
+                teams_scheduled_this_week.insert(home_id);
+                teams_scheduled_this_week.insert(away_id);
+            }
+
+            // Create matchups and update streaks
+            // Sort indices in reverse so we can remove from end first
+            matchups_this_week.sort_by(|a, b| b.cmp(a));
+            for index in matchups_this_week {
+                let (home_id, away_id) = remaining_matchups.remove(index);
+
+                // Get team short names
+                let home_short_name = self.teams.get(&home_id).unwrap().short_name();
+                let away_short_name = self.teams.get(&away_id).unwrap().short_name();
+
+                // Create matchup
+                let matchup = LeagueSeasonMatchupBuilder::new()
+                    .home_team(home_id)
+                    .away_team(away_id)
+                    .home_short_name(home_short_name)
+                    .away_short_name(away_short_name)
+                    .coin_toss(rng)
+                    .build()?;
+                week.matchups_mut().push(matchup);
+
+                // Update streaks
+                *team_home_streak.get_mut(&home_id).unwrap() += 1;
+                *team_away_streak.get_mut(&home_id).unwrap() = 0;
+                *team_away_streak.get_mut(&away_id).unwrap() += 1;
+                *team_home_streak.get_mut(&away_id).unwrap() = 0;
+            }
+
+            self.weeks.push(week);
+
+            // Safety: prevent infinite loop
+            if self.weeks.len() > num_teams * 10 {
+                return Err(LeagueError::InvalidSchedule {
+                    reason: "Failed to generate valid schedule - too many weeks".to_string()
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Computes the season standings sorted in descending order, mapping
+    /// team IDs to their season record
+    ///
+    /// Teams are ordered by win percentage (descending), counting a tie as
+    /// half a win, then by wins (descending). Teams with equal records are
+    /// ordered by the tiebreaker chain: head-to-head record, division
+    /// record, conference record, point differential, and team ID. Win
+    /// percentages are compared exactly, so equal records always sort the
+    /// same way.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// // Create a new season
+    /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Add 4 teams to the season
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Simulate the entire regular season
+    /// my_league_season.sim_regular_season(&mut rng);
+    ///
+    /// // Compute the standings
+    /// let standings = my_league_season.standings();
+    /// ```
+    pub fn standings(&self) -> Vec<(usize, LeagueTeamRecord)> {
+        self.standings_through(self.weeks.len())
+    }
+
+    /// Computes the standings as they stood after a regular season week,
+    /// counting only the completed games of weeks `0..=week` and breaking
+    /// ties with those games alone. The season itself is not modified.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule and simulate the regular season
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// my_league_season.sim_regular_season(&mut rng).unwrap();
+    ///
+    /// // After the first week every team has played one game
+    /// let standings = my_league_season.standings_through_week(0).unwrap();
+    /// assert!(standings.iter().all(|(_, r)| r.wins() + r.losses() + r.ties() == 1));
+    ///
+    /// // Through the last week the standings are the final standings
+    /// let last_week = my_league_season.weeks().len() - 1;
+    /// assert_eq!(my_league_season.standings_through_week(last_week).unwrap(), my_league_season.standings());
+    /// assert!(my_league_season.standings_through_week(last_week + 1).is_err());
+    /// ```
+    pub fn standings_through_week(&self, week: usize) -> Result<Vec<(usize, LeagueTeamRecord)>, LeagueError> {
+        if week >= self.weeks.len() {
+            return Err(LeagueError::WeekOutOfRange { week });
+        }
+        Ok(self.standings_through(week + 1))
+    }
+
+    /// Computes the standings from the first `num_weeks` regular season
+    /// weeks
+    fn standings_through(&self, num_weeks: usize) -> Vec<(usize, LeagueTeamRecord)> {
+        standings_from_parts(&self.teams, &self.conferences, &self.weeks, num_weeks)
+    }
+
+    /// Get the teams tied for the best record in the standings
+    ///
+    /// Teams are tied when they share the leader's record and the tiebreaker
+    /// chain cannot separate them without falling back on team ID, which is
+    /// not a genuine tiebreaker. Returns the leader alone when no team is
+    /// tied with it, and the tied teams in standings order otherwise.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Before any games are played, every team is tied
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// assert_eq!(my_league_season.best_record_teams(), vec![0, 1, 2, 3]);
+    /// ```
+    pub fn best_record_teams(&self) -> Vec<usize> {
+        best_record_teams(&self.standings(), &Tiebreakers::new(self))
+    }
+
+    /// Computes the division standings for a specific division
+    ///
+    /// ### Arguments
+    /// * `conf_index` - The conference index (position in conferences Vec)
+    /// * `div_id` - The division ID (key in conference's divisions BTreeMap)
+    ///
+    /// ### Returns
+    /// * `Ok(Vec<(usize, LeagueTeamRecord)>)` - Division standings sorted in the same order as `standings()`
+    /// * `Err(LeagueError)` - If conference or division doesn't exist
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    ///
+    /// // Create a new season with 2 conferences
+    /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Add teams
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Create conference structure
+    /// let mut afc = LeagueConference::with_name("AFC");
+    /// let mut afc_div = LeagueDivision::with_name("East");
+    /// afc_div.add_team(0);
+    /// afc_div.add_team(1);
+    /// afc.add_division(afc_div);
+    /// my_league_season.add_conference(afc);
+    ///
+    /// let mut nfc = LeagueConference::with_name("NFC");
+    /// let mut nfc_div = LeagueDivision::with_name("East");
+    /// nfc_div.add_team(2);
+    /// nfc_div.add_team(3);
+    /// nfc.add_division(nfc_div);
+    /// my_league_season.add_conference(nfc);
+    ///
+    /// // Compute the division standings
+    /// let afc_east_standings = my_league_season.division_standings(0, 0);
+    /// if let Err(ref e) = afc_east_standings {
+    ///     println!("{}", e);
+    /// }
+    /// assert!(afc_east_standings.is_ok());
+    /// ```
+    pub fn division_standings(&self, conf_index: usize, div_id: usize) -> Result<Vec<(usize, LeagueTeamRecord)>, LeagueError> {
+        self.filter_division_standings(&self.standings(), conf_index, div_id)
+    }
+
+    /// Computes the standings of a specific division as they stood after a
+    /// regular season week, in the same order as `standings_through_week()`
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    ///
+    /// // Create a new season with 2 conferences of 2 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for (name, teams) in [("AFC", [0, 1]), ("NFC", [2, 3])] {
+    ///     let mut conference = LeagueConference::with_name(name);
+    ///     let mut division = LeagueDivision::with_name("East");
+    ///     for id in teams {
+    ///         my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    ///         division.add_team(id);
+    ///     }
+    ///     conference.add_division(division);
+    ///     my_league_season.add_conference(conference);
+    /// }
+    ///
+    /// // Generate the season schedule and simulate the first week
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league_season.sim_week(0, &mut rng).unwrap();
+    ///
+    /// // Compute the first division's standings after the first week
+    /// let standings = my_league_season.division_standings_through_week(0, 0, 0).unwrap();
+    /// assert_eq!(standings.len(), 2);
+    /// ```
+    pub fn division_standings_through_week(&self, conf_index: usize, div_id: usize, week: usize) -> Result<Vec<(usize, LeagueTeamRecord)>, LeagueError> {
+        let standings = self.standings_through_week(week)?;
+        self.filter_division_standings_through(&standings, conf_index, div_id, week + 1)
+    }
+
+    /// Filter sorted standings to the teams of a specific division
+    pub(crate) fn filter_division_standings(&self, all_standings: &[(usize, LeagueTeamRecord)], conf_index: usize, div_id: usize) -> Result<Vec<(usize, LeagueTeamRecord)>, LeagueError> {
+        self.filter_division_standings_through(all_standings, conf_index, div_id, self.weeks.len())
+    }
+
+    /// Filter sorted standings to the teams of a specific division, breaking
+    /// ties with the games of the first `num_weeks` weeks
+    fn filter_division_standings_through(&self, all_standings: &[(usize, LeagueTeamRecord)], conf_index: usize, div_id: usize, num_weeks: usize) -> Result<Vec<(usize, LeagueTeamRecord)>, LeagueError> {
+        // Get the conference
+        let conference = self.conferences.get(conf_index)
+            .ok_or_else(|| format!("Conference index {} does not exist", conf_index))?;
+
+        // Get the division
+        let division = conference.division(div_id)
+            .ok_or_else(|| format!("Division {} does not exist in conference {}", div_id, conf_index))?;
+
+        // Filter the standings to this division's teams
+        let division_teams: HashSet<usize> = division.teams().iter().cloned().collect();
+        let mut standings: Vec<(usize, LeagueTeamRecord)> = all_standings
+            .iter()
+            .filter(|(id, _)| division_teams.contains(id))
+            .cloned()
+            .collect();
+
+        // Break ties again among the teams which remain
+        Tiebreakers::through_week(self, num_weeks).break_ties(&mut standings);
+        Ok(standings)
+    }
+
+    /// Computes the conference standings for a specific conference
+    ///
+    /// ### Arguments
+    /// * `conf_index` - The conference index (position in conferences Vec)
+    ///
+    /// ### Returns
+    /// * `Ok(Vec<(usize, LeagueTeamRecord)>)` - Conference standings sorted in the same order as `standings()`
+    /// * `Err(LeagueError)` - If conference doesn't exist
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    ///
+    /// // Create a new season with 2 conferences
+    /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Add teams
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Create conference structure
+    /// let mut afc = LeagueConference::with_name("AFC");
+    /// let mut afc_div = LeagueDivision::with_name("East");
+    /// afc_div.add_team(0);
+    /// afc_div.add_team(1);
+    /// afc.add_division(afc_div);
+    /// my_league_season.add_conference(afc);
+    ///
+    /// let mut nfc = LeagueConference::with_name("NFC");
+    /// let mut nfc_div = LeagueDivision::with_name("East");
+    /// nfc_div.add_team(2);
+    /// nfc_div.add_team(3);
+    /// nfc.add_division(nfc_div);
+    /// my_league_season.add_conference(nfc);
+    ///
+    /// // Compute the conference standings
+    /// let nfc_standings = my_league_season.conference_standings(1);
+    /// assert!(nfc_standings.is_ok());
+    /// ```
+    pub fn conference_standings(&self, conf_index: usize) -> Result<Vec<(usize, LeagueTeamRecord)>, LeagueError> {
+        self.filter_conference_standings(&self.standings(), conf_index)
+    }
+
+    /// Computes the standings of a specific conference as they stood after
+    /// a regular season week, in the same order as `standings_through_week()`
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    ///
+    /// // Create a new season with 2 conferences of 2 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for (name, teams) in [("AFC", [0, 1]), ("NFC", [2, 3])] {
+    ///     let mut conference = LeagueConference::with_name(name);
+    ///     let mut division = LeagueDivision::with_name("East");
+    ///     for id in teams {
+    ///         my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    ///         division.add_team(id);
+    ///     }
+    ///     conference.add_division(division);
+    ///     my_league_season.add_conference(conference);
+    /// }
+    ///
+    /// // Generate the season schedule and simulate the regular season
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league_season.sim_regular_season(&mut rng).unwrap();
+    ///
+    /// // Through the last week the conference standings are final
+    /// let last_week = my_league_season.weeks().len() - 1;
+    /// assert_eq!(
+    ///     my_league_season.conference_standings_through_week(1, last_week).unwrap(),
+    ///     my_league_season.conference_standings(1).unwrap()
+    /// );
+    /// ```
+    pub fn conference_standings_through_week(&self, conf_index: usize, week: usize) -> Result<Vec<(usize, LeagueTeamRecord)>, LeagueError> {
+        let standings = self.standings_through_week(week)?;
+        self.filter_conference_standings_through(&standings, conf_index, week + 1)
+    }
+
+    /// Filter sorted standings to the teams of a specific conference
+    pub(crate) fn filter_conference_standings(&self, all_standings: &[(usize, LeagueTeamRecord)], conf_index: usize) -> Result<Vec<(usize, LeagueTeamRecord)>, LeagueError> {
+        self.filter_conference_standings_through(all_standings, conf_index, self.weeks.len())
+    }
+
+    /// Filter sorted standings to the teams of a specific conference,
+    /// breaking ties with the games of the first `num_weeks` weeks
+    fn filter_conference_standings_through(&self, all_standings: &[(usize, LeagueTeamRecord)], conf_index: usize, num_weeks: usize) -> Result<Vec<(usize, LeagueTeamRecord)>, LeagueError> {
+        // Get the conference
+        let conference = self.conferences.get(conf_index)
+            .ok_or_else(|| format!("Conference index {} does not exist", conf_index))?;
+
+        // Get all teams in the conference
+        let conference_teams: HashSet<usize> = conference.all_teams().into_iter().collect();
+
+        // Filter the standings to this conference's teams
+        let mut standings: Vec<(usize, LeagueTeamRecord)> = all_standings
+            .iter()
+            .filter(|(id, _)| conference_teams.contains(id))
+            .cloned()
+            .collect();
+
+        // Break ties again among the teams which remain
+        Tiebreakers::through_week(self, num_weeks).break_ties(&mut standings);
+        Ok(standings)
+    }
+
+    /// Get the champion of a division, the team leading the division
+    /// standings once the regular season is complete
+    ///
+    /// ### Arguments
+    /// * `conf_index` - The conference index (position in conferences Vec)
+    /// * `div_id` - The division ID (key in conference's divisions BTreeMap)
+    ///
+    /// ### Returns
+    /// * `Ok(Some(usize))` - The division champion's team ID
+    /// * `Ok(None)` - If the regular season is not complete
+    /// * `Err(LeagueError)` - If conference or division doesn't exist
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    ///
+    /// // Create a season with two single-division conferences
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// for (name, teams) in [("AFC", [0, 1]), ("NFC", [2, 3])] {
+    ///     let mut conference = LeagueConference::with_name(name);
+    ///     let mut division = LeagueDivision::with_name("East");
+    ///     division.add_team(teams[0]).unwrap();
+    ///     division.add_team(teams[1]).unwrap();
+    ///     conference.add_division(division).unwrap();
+    ///     my_league_season.add_conference(conference).unwrap();
+    /// }
+    ///
+    /// // No champion until the regular season is complete
+    /// assert_eq!(my_league_season.division_champion(0, 0), Ok(None));
+    /// assert!(my_league_season.division_champion(0, 1).is_err());
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league_season.sim_regular_season(&mut rng).unwrap();
+    /// let champion = my_league_season.division_champion(0, 0).unwrap().unwrap();
+    /// assert!(champion == 0 || champion == 1);
+    /// ```
+    pub fn division_champion(&self, conf_index: usize, div_id: usize) -> Result<Option<usize>, LeagueError> {
+        let standings = self.division_standings(conf_index, div_id)?;
+        if !self.regular_season_complete() {
+            return Ok(None);
+        }
+        Ok(standings.first().map(|(id, _)| *id))
+    }
+
+    /// Get the regular season champion of a conference, the team leading
+    /// the conference standings once the regular season is complete
+    ///
+    /// ### Arguments
+    /// * `conf_index` - The conference index (position in conferences Vec)
+    ///
+    /// ### Returns
+    /// * `Ok(Some(usize))` - The conference champion's team ID
+    /// * `Ok(None)` - If the regular season is not complete
+    /// * `Err(LeagueError)` - If conference doesn't exist
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    ///
+    /// // Create a season with two single-division conferences
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// for (name, teams) in [("AFC", [0, 1]), ("NFC", [2, 3])] {
+    ///     let mut conference = LeagueConference::with_name(name);
+    ///     let mut division = LeagueDivision::with_name("East");
+    ///     division.add_team(teams[0]).unwrap();
+    ///     division.add_team(teams[1]).unwrap();
+    ///     conference.add_division(division).unwrap();
+    ///     my_league_season.add_conference(conference).unwrap();
+    /// }
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league_season.sim_regular_season(&mut rng).unwrap();
+    /// let champion = my_league_season.conference_regular_season_champion(1).unwrap().unwrap();
+    /// assert!(champion == 2 || champion == 3);
+    /// ```
+    pub fn conference_regular_season_champion(&self, conf_index: usize) -> Result<Option<usize>, LeagueError> {
+        let standings = self.conference_standings(conf_index)?;
+        if !self.regular_season_complete() {
+            return Ok(None);
+        }
+        Ok(standings.first().map(|(id, _)| *id))
+    }
+
+    /// Summarize the titles won during the season. Titles which have not
+    /// been decided yet are omitted.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    ///
+    /// // Create a season with two single-division conferences
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// for (name, teams) in [("AFC", [0, 1]), ("NFC", [2, 3])] {
+    ///     let mut conference = LeagueConference::with_name(name);
+    ///     let mut division = LeagueDivision::with_name("East");
+    ///     division.add_team(teams[0]).unwrap();
+    ///     division.add_team(teams[1]).unwrap();
+    ///     conference.add_division(division).unwrap();
+    ///     my_league_season.add_conference(conference).unwrap();
+    /// }
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league_season.sim_regular_season(&mut rng).unwrap();
+    /// let honors = my_league_season.honors();
+    /// assert_eq!(honors.conference_champions.len(), 2);
+    /// assert_eq!(honors.division_champion(0, 0), honors.conference_champion(0));
+    /// assert!(honors.league_champion.is_none());
+    /// ```
+    pub fn honors(&self) -> SeasonHonors {
+        let mut honors = SeasonHonors {
+            year: self.year,
+            league_champion: self.champion(),
+            ..Default::default()
+        };
+        if self.regular_season_complete() {
+            // Crown the leaders of the league, conference, and division
+            // standings, computing the standings only once
+            let standings = self.standings();
+            honors.top_seed = standings.first().map(|(id, _)| *id);
+            for (conf_index, conference) in self.conferences.iter().enumerate() {
+                let conf_standings = self.filter_conference_standings(&standings, conf_index)
+                    .unwrap_or_default();
+                if let Some((id, _)) = conf_standings.first() {
+                    honors.conference_champions.insert(conf_index, *id);
+                }
+                for div_id in 0..conference.divisions().len() {
+                    let div_standings = self.filter_division_standings(&standings, conf_index, div_id)
+                        .unwrap_or_default();
+                    if let Some((id, _)) = div_standings.first() {
+                        honors.division_champions.entry(conf_index).or_default().insert(div_id, *id);
+                    }
+                }
+            }
+        }
+        for conference in self.playoffs.conference_brackets().keys() {
+            if let Some(champion) = self.playoffs.conference_champion(*conference) {
+                honors.conference_playoff_champions.insert(*conference, champion);
+            }
+        }
+        honors
+    }
+
+    /// Get a stable 64-bit fingerprint of the season, which changes if and
+    /// only if its serialized content changes. The fingerprint is cached
+    /// and cleared by each mutating method, so repeated calls between
+    /// mutations do not re-hash the season.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// let empty = my_league_season.fingerprint();
+    /// assert_eq!(my_league_season.fingerprint(), empty);
+    ///
+    /// my_league_season.add_team(0, FootballTeam::new()).unwrap();
+    /// assert_ne!(my_league_season.fingerprint(), empty);
+    ///
+    /// // Seasons with the same content have the same fingerprint
+    /// let json = serde_json::to_string(&my_league_season).unwrap();
+    /// let copy: LeagueSeason = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(copy.fingerprint(), my_league_season.fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint.get_or_compute(|| {
+            let mut fingerprinter = Fingerprinter::default();
+            fingerprinter.write_value(self);
+            fingerprinter.finish()
+        })
+    }
+
+    /// Scan the season for problems which are likely mistakes, such as
+    /// conferences without any teams, or regular season matchups edited in
+    /// place into a state which `LeagueSeasonWeek::reconcile` would repair
+    /// or reject
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::conference::LeagueConference;
+    /// use fbsim_core::league::season::lint::SeasonLint;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// assert!(my_league_season.lint().is_empty());
+    ///
+    /// my_league_season.add_conference(LeagueConference::with_name("AFC")).unwrap();
+    /// assert_eq!(
+    ///     my_league_season.lint(),
+    ///     vec![SeasonLint::EmptyConference { conference: 0, name: String::from("AFC") }]
+    /// );
+    /// ```
+    pub fn lint(&self) -> Vec<SeasonLint> {
+        let mut lints = Vec::new();
+        for (index, conference) in self.conferences.iter().enumerate() {
+            if conference.num_teams() == 0 {
+                lints.push(SeasonLint::EmptyConference {
+                    conference: index,
+                    name: String::from(conference.name())
+                });
+            }
+        }
+        for (week_index, week) in self.weeks.iter().enumerate() {
+            for (matchup_index, matchup) in week.matchups().iter().enumerate() {
+                match matchup.clone().reconcile() {
+                    Ok(false) => (),
+                    Ok(true) => lints.push(SeasonLint::UnreconciledMatchup {
+                        week: week_index,
+                        matchup: matchup_index
+                    }),
+                    Err(reason) => lints.push(SeasonLint::InconsistentMatchup {
+                        week: week_index,
+                        matchup: matchup_index,
+                        reason: reason.to_string()
+                    })
+                }
+            }
+        }
+        lints
+    }
+
+    /// Scan the completed regular season matchups for final scores outside
+    /// of the given bounds, such as absurd results in imported data
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::score::ScoreBounds;
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule and simulate the regular season
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// my_league_season.sim_regular_season(&mut rng);
+    ///
+    /// // No score is anomalous without bounds
+    /// assert!(my_league_season.anomalies(&ScoreBounds::new()).is_empty());
+    ///
+    /// // Every score is anomalous when no points are allowed
+    /// let anomalies = my_league_season.anomalies(&ScoreBounds::new().max_score(0));
+    /// assert!(anomalies.iter().all(|a| a.home_score > 0 || a.away_score > 0));
+    /// ```
+    pub fn anomalies(&self, bounds: &ScoreBounds) -> Vec<ScoreAnomaly> {
+        let mut anomalies: Vec<ScoreAnomaly> = Vec::new();
+        for (week_index, week) in self.weeks.iter().enumerate() {
+            for (matchup_index, matchup) in week.matchups().iter().enumerate() {
+                let context = matchup.context();
+                if !context.game_over() || bounds.contains(context.home_score(), context.away_score()) {
+                    continue;
+                }
+                anomalies.push(
+                    ScoreAnomaly {
+                        week: week_index,
+                        matchup: matchup_index,
+                        home_team: *matchup.home_team(),
+                        away_team: *matchup.away_team(),
+                        home_score: context.home_score(),
+                        away_score: context.away_score()
+                    }
+                );
+            }
+        }
+        anomalies
+    }
+
+    /// Computes a team's stats over its completed regular season games:
+    /// its points for and against, home, away, and neutral site splits,
+    /// margin of victory, and current streak
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league_season.sim_regular_season(&mut rng).unwrap();
+    ///
+    /// // Each team plays 6 games, half of them at home
+    /// let stats = my_league_season.team_stats(0).unwrap();
+    /// assert_eq!(stats.overall().games_played(), 6);
+    /// assert_eq!(stats.home().games_played(), 3);
+    /// assert!(stats.streak().is_some());
+    /// assert!(my_league_season.team_stats(4).is_err());
+    /// ```
+    pub fn team_stats(&self, team_id: usize) -> Result<LeagueSeasonTeamStats, LeagueError> {
+        if !self.teams.contains_key(&team_id) {
+            return Err(LeagueError::TeamNotFound { id: team_id });
+        }
+        let mut stats = LeagueSeasonTeamStats::new(team_id);
+        for week in self.weeks.iter() {
+            for matchup in week.matchups().iter() {
+                stats.add_matchup(matchup);
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Computes every team's stats over its completed regular season games
+    /// in a single pass over the season, keyed by team ID
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league_season.sim_regular_season(&mut rng).unwrap();
+    ///
+    /// // Every point scored is a point allowed by another team
+    /// let all_stats = my_league_season.all_team_stats();
+    /// let differential: i64 = all_stats.values().map(|s| s.point_differential()).sum();
+    /// assert_eq!(all_stats.len(), 4);
+    /// assert_eq!(differential, 0);
+    /// ```
+    pub fn all_team_stats(&self) -> BTreeMap<usize, LeagueSeasonTeamStats> {
+        let mut all_stats: BTreeMap<usize, LeagueSeasonTeamStats> = self.teams.keys()
+            .map(|id| (*id, LeagueSeasonTeamStats::new(*id)))
+            .collect();
+        for week in self.weeks.iter() {
+            for matchup in week.matchups().iter() {
+                for id in [matchup.home_team(), matchup.away_team()] {
+                    if let Some(stats) = all_stats.get_mut(id) {
+                        stats.add_matchup(matchup);
+                    }
+                }
+            }
+        }
+        all_stats
+    }
+
+    /// Computes the turnovers a team gave away and took away over its
+    /// completed regular season games. Returns `None` if any of those games
+    /// was completed without detailed stats, since the totals would be
+    /// incomplete.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule and simulate the regular season
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// my_league_season.sim_regular_season(&mut rng);
+    ///
+    /// // Get team 0's turnovers
+    /// let turnovers = my_league_season.team_turnovers(0).unwrap().unwrap();
+    /// assert_eq!(
+    ///     turnovers.margin(),
+    ///     *turnovers.takeaways() as i64 - *turnovers.giveaways() as i64
+    /// );
+    /// ```
+    pub fn team_turnovers(&self, team_id: usize) -> Result<Option<LeagueTeamTurnovers>, LeagueError> {
+        if !self.teams.contains_key(&team_id) {
+            return Err(LeagueError::TeamNotFound { id: team_id });
+        }
+        let mut turnovers = LeagueTeamTurnovers::new();
+        for week in self.weeks.iter() {
+            for matchup in week.matchups().iter() {
+                if !(matchup.context().game_over() && matchup.participated(team_id)) {
+                    continue;
+                }
+                match matchup.turnovers(team_id) {
+                    Some(game) => {
+                        turnovers.increment_giveaways(*game.giveaways());
+                        turnovers.increment_takeaways(*game.takeaways());
+                    },
+                    None => return Ok(None)
+                }
+            }
+        }
+        Ok(Some(turnovers))
+    }
+
+    /// Computes a team's turnover margin over its completed regular season
+    /// games, its takeaways less its giveaways. Returns `None` if any of
+    /// those games was completed without detailed stats.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Before any games are played the margin is zero
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// assert_eq!(my_league_season.turnover_margin(0).unwrap(), Some(0));
+    /// ```
+    pub fn turnover_margin(&self, team_id: usize) -> Result<Option<i64>, LeagueError> {
+        Ok(self.team_turnovers(team_id)?.map(|t| t.margin()))
+    }
+
+    /// Computes a team's record against division opponents only
+    ///
+    /// ### Arguments
+    /// * `team_id` - The team ID to compute division record for
+    ///
+    /// ### Returns
+    /// * `Ok(LeagueTeamRecord)` - The team's record against division opponents
+    /// * `Err(LeagueError)` - If team doesn't exist or has no division assignment
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    ///
+    /// // Create a new season with 2 conferences
+    /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Add teams
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Create conference structure
+    /// let mut afc = LeagueConference::with_name("AFC");
+    /// let mut afc_div = LeagueDivision::with_name("East");
+    /// afc_div.add_team(0);
+    /// afc_div.add_team(1);
+    /// afc.add_division(afc_div);
+    /// my_league_season.add_conference(afc);
+    ///
+    /// let mut nfc = LeagueConference::with_name("NFC");
+    /// let mut nfc_div = LeagueDivision::with_name("East");
+    /// nfc_div.add_team(2);
+    /// nfc_div.add_team(3);
+    /// nfc.add_division(nfc_div);
+    /// my_league_season.add_conference(nfc);
+    ///
+    /// // Generate the season schedule and simulate the season
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// my_league_season.sim_regular_season(&mut rng);
+    ///
+    /// // Calculate team 2's division record
+    /// let record = my_league_season.division_record(2);
+    /// assert!(record.is_ok());
+    /// ```
+    pub fn division_record(&self, team_id: usize) -> Result<LeagueTeamRecord, LeagueError> {
+        // Find team's division
+        let (conf_index, div_id) = self.team_division(team_id)
+            .ok_or_else(|| format!("Team {} has no division assignment", team_id))?;
+
+        // Get division teams
+        let division = self.conferences.get(conf_index)
+            .and_then(|c| c.division(div_id))
+            .ok_or_else(|| format!("Division not found for team {}", team_id))?;
+
+        let division_teams: HashSet<usize> = division.teams().iter().cloned().collect();
+
+        // Compute record against division opponents
+        let mut record = LeagueTeamRecord::new();
+
+        for week in &self.weeks {
+            for matchup in week.matchups() {
+                if !matchup.context().game_over() {
+                    continue;
+                }
+
+                let home = *matchup.home_team();
+                let away = *matchup.away_team();
+
+                // Check if this is a division game involving our team
+                let is_our_game = home == team_id || away == team_id;
+                let opponent = if home == team_id { away } else { home };
+                let is_division_game = division_teams.contains(&opponent);
+
+                if is_our_game && is_division_game {
+                    match matchup.result(team_id) {
+                        Some(FootballMatchupResult::Win) => {
+                            record.increment_wins(1);
+                        }
+                        Some(FootballMatchupResult::Loss) => {
+                            record.increment_losses(1);
+                        }
+                        Some(FootballMatchupResult::Tie) => {
+                            record.increment_ties(1);
+                        }
+                        None => {}
+                    }
+                }
+            }
+        }
+
+        Ok(record)
+    }
+
+    /// Computes a team's record against conference opponents only
+    ///
+    /// ### Arguments
+    /// * `team_id` - The team ID to compute conference record for
+    ///
+    /// ### Returns
+    /// * `Ok(LeagueTeamRecord)` - The team's record against conference opponents
+    /// * `Err(LeagueError)` - If team doesn't exist or has no conference assignment
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    ///
+    /// // Create a new season with 2 conferences
+    /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Add teams
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Create conference structure
+    /// let mut afc = LeagueConference::with_name("AFC");
+    /// let mut afc_div = LeagueDivision::with_name("East");
+    /// afc_div.add_team(0);
+    /// afc_div.add_team(1);
+    /// afc.add_division(afc_div);
+    /// my_league_season.add_conference(afc);
+    ///
+    /// let mut nfc = LeagueConference::with_name("NFC");
+    /// let mut nfc_div = LeagueDivision::with_name("East");
+    /// nfc_div.add_team(2);
+    /// nfc_div.add_team(3);
+    /// nfc.add_division(nfc_div);
+    /// my_league_season.add_conference(nfc);
+    ///
+    /// // Generate the season schedule and simulate the season
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// my_league_season.sim_regular_season(&mut rng);
+    ///
+    /// // Calculate team 1's conference record
+    /// let record = my_league_season.conference_record(1);
+    /// assert!(record.is_ok());
+    /// ```
+    pub fn conference_record(&self, team_id: usize) -> Result<LeagueTeamRecord, LeagueError> {
+        // Find team's conference
+        let conf_index = self.team_conference(team_id)
+            .ok_or_else(|| format!("Team {} has no conference assignment", team_id))?;
+
+        // Get conference teams
+        let conference = self.conferences.get(conf_index)
+            .ok_or_else(|| format!("Conference not found for team {}", team_id))?;
+
+        let conference_teams: HashSet<usize> = conference.all_teams().into_iter().collect();
+
+        // Compute record against conference opponents
+        Ok(self.record_against(team_id, |opponent| conference_teams.contains(&opponent)))
+    }
+
+    /// Computes a team's record against teams outside its conference
+    ///
+    /// ### Arguments
+    /// * `team_id` - The team ID to compute the inter-conference record for
+    ///
+    /// ### Returns
+    /// * `Ok(LeagueTeamRecord)` - The team's record against other conferences
+    /// * `Err(LeagueError)` - If team doesn't exist or has no conference assignment
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    ///
+    /// // Create a new season with 2 conferences of 2 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for (name, teams) in [("AFC", [0, 1]), ("NFC", [2, 3])] {
+    ///     let mut conference = LeagueConference::with_name(name);
+    ///     let mut division = LeagueDivision::with_name("East");
+    ///     for id in teams {
+    ///         my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    ///         division.add_team(id).unwrap();
+    ///     }
+    ///     conference.add_division(division).unwrap();
+    ///     my_league_season.add_conference(conference).unwrap();
+    /// }
+    ///
+    /// // Each team plays one game against the other conference
+    /// let mut rng = rand::thread_rng();
+    /// let my_options = LeagueSeasonScheduleOptions::new().interconference_games(1);
+    /// my_league_season.generate_schedule(my_options, &mut rng).unwrap();
+    /// my_league_season.sim_regular_season(&mut rng).unwrap();
+    ///
+    /// // Calculate team 1's inter-conference record
+    /// let record = my_league_season.interconference_record(1).unwrap();
+    /// assert_eq!(record.wins() + record.losses() + record.ties(), 1);
+    /// ```
+    pub fn interconference_record(&self, team_id: usize) -> Result<LeagueTeamRecord, LeagueError> {
+        // Find team's conference
+        let conf_index = self.team_conference(team_id)
+            .ok_or_else(|| format!("Team {} has no conference assignment", team_id))?;
+
+        // Get conference teams
+        let conference = self.conferences.get(conf_index)
+            .ok_or_else(|| format!("Conference not found for team {}", team_id))?;
+
+        let conference_teams: HashSet<usize> = conference.all_teams().into_iter().collect();
+
+        // Compute record against opponents outside the conference
+        Ok(self.record_against(team_id, |opponent| !conference_teams.contains(&opponent)))
+    }
+
+    /// Computes a team's record in its completed games against the
+    /// opponents matching a predicate
+    fn record_against(&self, team_id: usize, is_opponent: impl Fn(usize) -> bool) -> LeagueTeamRecord {
+        let mut record = LeagueTeamRecord::new();
+
+        for week in &self.weeks {
+            for matchup in week.matchups() {
+                if !matchup.context().game_over() {
+                    continue;
+                }
+
+                let home = *matchup.home_team();
+                let away = *matchup.away_team();
+
+                // Check if this is a game involving our team and a matching
+                // opponent
+                let is_our_game = home == team_id || away == team_id;
+                let opponent = if home == team_id { away } else { home };
+
+                if is_our_game && is_opponent(opponent) {
+                    match matchup.result(team_id) {
+                        Some(FootballMatchupResult::Win) => {
+                            record.increment_wins(1);
+                        }
+                        Some(FootballMatchupResult::Loss) => {
+                            record.increment_losses(1);
+                        }
+                        Some(FootballMatchupResult::Tie) => {
+                            record.increment_ties(1);
+                        }
+                        None => {}
+                    }
+                }
+            }
+        }
+
+        record
+    }
+
+    /// Generate the current playoff picture for the season
+    ///
+    /// ### Arguments
+    /// * `num_playoff_teams` - Number of teams that will make the playoffs
+    ///
+    /// ### Returns
+    /// * `Ok(PlayoffPicture)` - The current playoff picture
+    /// * `Err(LeagueError)` - If the season hasn't started or parameters are invalid
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Get the playoff picture for a 2-team playoff
+    /// let picture = my_league_season.playoff_picture(2);
+    /// assert!(picture.is_ok());
+    /// ```
+    pub fn playoff_picture(&self, num_playoff_teams: usize) -> Result<playoffs::picture::PlayoffPicture, LeagueError> {
+        self.playoff_picture_from_standings(
+            &self.standings(),
+            &PlayoffPicture::remaining_games(self),
+            num_playoff_teams
+        )
+    }
+
+    /// Compute the playoff picture given the season's sorted standings and
+    /// remaining games
+    pub(crate) fn playoff_picture_from_standings(&self, standings: &[(usize, LeagueTeamRecord)], remaining_games: &RemainingGames, num_playoff_teams: usize) -> Result<PlayoffPicture, LeagueError> {
+        // When crowning the best record, the picture is a race for first place
+        if self.championship_mode == ChampionshipMode::BestRecord {
+            let options = PlayoffPictureOptions::new().by_conference(false);
+            return PlayoffPicture::from_standings(self, standings, remaining_games, 1, Some(options));
+        }
+        PlayoffPicture::from_standings(self, standings, remaining_games, num_playoff_teams, None)
+    }
+
+    /// Compute a dashboard of the season's standings, division and conference
+    /// standings, leaders, current week, and playoff picture from a single
+    /// pass over the season's matchups
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::dashboard::SeasonDashboardOptions;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule and simulate the regular season
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// my_league_season.sim_regular_season(&mut rng);
+    ///
+    /// // Compute the dashboard without the leaders
+    /// let options = SeasonDashboardOptions::new().leaders(false).playoff_picture(2);
+    /// let dashboard = my_league_season.dashboard(options).unwrap();
+    /// assert!(dashboard.leaders.is_none());
+    /// assert!(dashboard.week.is_none());
+    /// assert_eq!(dashboard.playoff_picture.unwrap(), my_league_season.playoff_picture(2).unwrap());
+    /// ```
+    pub fn dashboard(&self, options: SeasonDashboardOptions) -> Result<SeasonDashboard, LeagueError> {
+        SeasonDashboard::from_season(self, options)
+    }
+
+    /// Get every team's 1-based rank in the overall standings after each
+    /// completed week, by team ID, computed in a single pass over the
+    /// season. Partial seasons are covered up to the first week which has
+    /// not completed.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule and simulate the first week
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// my_league_season.sim_week(0, &mut rng);
+    ///
+    /// // Each team has one rank, matching the current standings
+    /// let history = my_league_season.rank_history();
+    /// let (leader, _) = my_league_season.standings()[0];
+    /// assert_eq!(history[&leader], vec![1]);
+    /// ```
+    pub fn rank_history(&self) -> BTreeMap<usize, Vec<usize>> {
+        StandingsHistory::from_season(self, StandingsHistoryOptions::new()).ranks
+    }
+
+    /// Compute the standings history of the season, every team's rank and
+    /// optionally its games back from first after each completed week
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::history::StandingsHistoryOptions;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Before any week completes, the history is empty
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// let options = StandingsHistoryOptions::new().games_back(true);
+    /// let history = my_league_season.standings_history(options);
+    /// assert_eq!(history.weeks, 0);
+    /// assert!(history.ranks[&0].is_empty());
+    /// ```
+    pub fn standings_history(&self, options: StandingsHistoryOptions) -> StandingsHistory {
+        StandingsHistory::from_season(self, options)
+    }
+
+    /// Generate a markdown report of the season, including the champion,
+    /// final standings, playoff bracket results and weekly scores. Sections
+    /// may be omitted via the `SeasonReportOptions`. Team names are escaped
+    /// and table columns are padded so the source reads cleanly as well.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::report::SeasonReportOptions;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule and simulate the regular season
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// my_league_season.sim_regular_season(&mut rng).unwrap();
+    ///
+    /// // Generate a report without the weekly scores
+    /// let options = SeasonReportOptions::new().weekly_scores(false);
+    /// let report = my_league_season.report_markdown(options);
+    /// assert!(report.contains("## Standings"));
+    /// assert!(!report.contains("## Weekly Scores"));
+    /// ```
+    pub fn report_markdown(&self, options: SeasonReportOptions) -> String {
+        season_report(self, &options, SeasonReportFormat::Markdown)
+    }
+
+    /// Generate a plain-text report of the season for display in a terminal,
+    /// with the same sections as `report_markdown`
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::report::SeasonReportOptions;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Generate a report of only the standings
+    /// let options = SeasonReportOptions::new()
+    ///     .champion(false)
+    ///     .playoffs(false)
+    ///     .weekly_scores(false);
+    /// let report = my_league_season.report_text(options);
+    /// assert!(report.contains("Standings\n---------"));
+    /// ```
+    pub fn report_text(&self, options: SeasonReportOptions) -> String {
+        season_report(self, &options, SeasonReportFormat::PlainText)
+    }
+
+    /// Export the season's schedule as CSV, with one row per matchup giving
+    /// its week, playoff round, teams, and scores once it is complete
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// // Create a new season with 4 teams and generate its schedule
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new());
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// let csv = my_league_season.to_csv_schedule();
+    /// assert!(csv.starts_with("week,round,home_team,away_team,home_score,away_score\n"));
+    /// let matchups: usize = my_league_season.weeks().iter().map(|w| w.matchups().len()).sum();
+    /// assert_eq!(csv.lines().count(), matchups + 1);
+    /// ```
+    #[cfg(feature = "export")]
+    pub fn to_csv_schedule(&self) -> String {
+        export::csv_schedule(self)
+    }
+
+    /// Export the season's schedule as an iCalendar, with an all-day event
+    /// per matchup, the first week played on a date in the season year and
+    /// each later week a number of days after the one before
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::export::CalendarDate;
+    ///
+    /// // Create a new season with 4 teams and generate its schedule
+    /// let mut my_league_season = LeagueSeason::with_year(2026);
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new());
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Play the first week on September 10th and every week after
+    /// let ical = my_league_season.to_ical(CalendarDate::new(9, 10), 7).unwrap();
+    /// assert!(ical.contains("DTSTART;VALUE=DATE:20260910\r\n"));
+    /// assert!(ical.contains("DTSTART;VALUE=DATE:20260917\r\n"));
+    /// let matchups: usize = my_league_season.weeks().iter().map(|w| w.matchups().len()).sum();
+    /// assert_eq!(ical.matches("BEGIN:VEVENT").count(), matchups);
+    /// ```
+    #[cfg(feature = "export")]
+    pub fn to_ical(&self, start_date: export::CalendarDate, days_between_weeks: u32) -> Result<String, LeagueError> {
+        export::ical_schedule(self, start_date, days_between_weeks)
+    }
 
-        // Clear existing schedule
-        if !self.weeks.is_empty() {
-            self.weeks.clear();
+    /// Determine of a team participated in the playoffs
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// // Create a season and add a team
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    ///
+    /// // Check if that team is in the playoffs
+    /// let in_playoffs = my_league_season.team_in_playoffs(0);
+    /// assert!(in_playoffs.is_ok());
+    /// assert!(!in_playoffs.unwrap());
+    /// ```
+    pub fn team_in_playoffs(&self, team_id: usize) -> Result<bool, LeagueError> {
+        if !self.team_exists(team_id) {
+            return Err(LeagueError::TeamNotFound { id: team_id });
         }
+        Ok(self.playoffs.team_in_playoffs(team_id))
+    }
 
-        // Get scheduling parameters with defaults
-        let division_games = options.division_games.unwrap_or(2);
-        let conference_games = options.conference_games.unwrap_or(1);
-        let cross_conference_games = options.cross_conference_games.unwrap_or(0);
+    /// Compute a team's playoff record
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
+    /// use fbsim_core::league::matchup::LeagueTeamRecord;
+    ///
+    /// // Create a new season
+    /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Add 4 teams to the season
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Simulate the entire regular season
+    /// my_league_season.sim_regular_season(&mut rng);
+    ///
+    /// // Generate playoffs with 4 teams
+    /// let mut playoff_options = LeagueSeasonPlayoffOptions::new();
+    /// playoff_options.num_playoff_teams = 4;
+    /// my_league_season.generate_playoffs(playoff_options, &mut rng);
+    ///
+    /// // Simulate the playoffs
+    /// my_league_season.sim_playoffs(&mut rng);
+    ///
+    /// // Compute a team's playoff record
+    /// let record = my_league_season.playoff_record(0);
+    /// assert!(record.is_ok());
+    /// ```
+    pub fn playoff_record(&self, team_id: usize) -> Result<LeagueTeamRecord, LeagueError> {
+        if self.team_in_playoffs(team_id)? {
+            self.playoffs.record(team_id)
+        } else {
+            // Technically should be unreachable
+            Ok(LeagueTeamRecord::new())
+        }
+    }
 
-        // Generate all matchups as (home_id, away_id) tuples
-        let mut all_matchups: Vec<(usize, usize)> = Vec::new();
+    /// Check if a team made it to the championship game this season
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// // Create a season and add a team
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    ///
+    /// // Determine if that team is in the shampionship
+    /// let in_championship = my_league_season.team_in_championship(0);
+    /// assert!(in_championship.is_ok());
+    /// assert!(!in_championship.unwrap());
+    /// ```
+    pub fn team_in_championship(&self, team_id: usize) -> Result<bool, LeagueError> {
+        if self.team_in_playoffs(team_id)? {
+            self.playoffs.in_championship(team_id)
+        } else if self.championship_mode == ChampionshipMode::BestRecord {
+            // Without a title playoff the champion is the only contender
+            Ok(self.champion() == Some(team_id))
+        } else {
+            Ok(false)
+        }
+    }
 
-        // Phase 1: Division matchups
-        let division_matchups = self.generate_division_matchups(division_games, rng)?;
-        all_matchups.extend(division_matchups);
+    /// Check if a team won the championship this season
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    ///
+    /// // Create a season and add a team
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    ///
+    /// // Determine if that team won the shampionship
+    /// let won_championship = my_league_season.team_won_championship(0);
+    /// assert!(won_championship.is_ok());
+    /// assert!(!won_championship.unwrap());
+    /// ```
+    pub fn team_won_championship(&self, team_id: usize) -> Result<bool, LeagueError> {
+        if !self.team_exists(team_id) {
+            return Err(LeagueError::TeamNotFound { id: team_id });
+        }
+        Ok(self.champion() == Some(team_id))
+    }
+
+    /// Get the season champion if it has been decided
+    ///
+    /// With `ChampionshipMode::Playoffs` this is the winner of the playoffs.
+    /// With `ChampionshipMode::BestRecord` this is the team with the best
+    /// record once the regular season is complete, or the winner of the title
+    /// playoff if teams were tied for the best record.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::{LeagueSeason, ChampionshipMode};
+    ///
+    /// // Create a season which crowns the best record
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.set_championship_mode(ChampionshipMode::BestRecord).unwrap();
+    ///
+    /// // No champion before the regular season completes
+    /// assert!(my_league_season.champion().is_none());
+    /// ```
+    pub fn champion(&self) -> Option<usize> {
+        match self.championship_mode {
+            ChampionshipMode::Playoffs => self.playoffs.champion(),
+            ChampionshipMode::BestRecord => {
+                if !self.regular_season_complete() {
+                    return None;
+                }
+                if !self.playoffs.conference_brackets().is_empty() {
+                    return self.playoffs.champion();
+                }
+                match self.best_record_teams().as_slice() {
+                    [champion] => Some(*champion),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    /// Generate the playoffs
+    ///
+    /// ### Example (single bracket)
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
+    ///
+    /// // Create a new season
+    /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Add 4 teams to the season
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Simulate the entire regular season
+    /// my_league_season.sim_regular_season(&mut rng);
+    ///
+    /// // Generate playoffs with 4 teams
+    /// let mut options = LeagueSeasonPlayoffOptions::new();
+    /// options.num_playoff_teams = 4;
+    /// let res = my_league_season.generate_playoffs(options, &mut rng);
+    /// assert!(res.is_ok());
+    /// ```
+    ///
+    /// ### Example (conference brackets)
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    ///
+    /// // Create a new season with 2 conferences
+    /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Add teams
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Create conference structure
+    /// let mut afc = LeagueConference::with_name("AFC");
+    /// let mut afc_div = LeagueDivision::with_name("East");
+    /// afc_div.add_team(0);
+    /// afc_div.add_team(1);
+    /// afc.add_division(afc_div);
+    /// my_league_season.add_conference(afc);
+    ///
+    /// let mut nfc = LeagueConference::with_name("NFC");
+    /// let mut nfc_div = LeagueDivision::with_name("East");
+    /// nfc_div.add_team(2);
+    /// nfc_div.add_team(3);
+    /// nfc.add_division(nfc_div);
+    /// my_league_season.add_conference(nfc);
+    ///
+    /// // Generate schedule and simulate
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// my_league_season.sim_regular_season(&mut rng);
+    ///
+    /// // Generate conference playoffs (2 teams per conference)
+    /// let mut options = LeagueSeasonPlayoffOptions::new();
+    /// options.use_conference_brackets = true;
+    /// options.playoff_teams_per_conference = 2;
+    /// options.division_winners_guaranteed = true;
+    /// let res = my_league_season.generate_playoffs(options, &mut rng);
+    /// assert!(res.is_ok());
+    /// ```
+    pub fn generate_playoffs(&mut self, options: LeagueSeasonPlayoffOptions, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        // Ensure the regular season is complete
+        if !self.regular_season_complete() {
+            return Err(LeagueError::RegularSeasonNotComplete);
+        }
+
+        // Ensure the playoffs have not already started
+        if self.playoffs.started() {
+            return Err(LeagueError::PlayoffsAlreadyStarted);
+        }
+
+        // Ensure the champion is decided by the playoffs
+        if self.championship_mode == ChampionshipMode::BestRecord {
+            return Err(LeagueError::ChampionDecidedByBestRecord);
+        }
+
+        // Seed the playoff teams and generate the first round
+        let mut playoffs = self.seed_playoffs(&options)?;
+        playoffs.gen_next_playoff_round(rng)?;
+        self.playoffs = playoffs;
+        Ok(())
+    }
+
+    /// Seed the playoff teams from the current standings without generating
+    /// any rounds. Shared by playoff generation and the playoff preview so
+    /// that both always seed identically.
+    pub(crate) fn seed_playoffs(&self, options: &LeagueSeasonPlayoffOptions) -> Result<LeagueSeasonPlayoffs, LeagueError> {
+        // Determine whether to use conference brackets
+        let populated = self.populated_conferences();
+        let use_conferences = options.use_conference_brackets && populated.len() > 1;
+
+        // Build the playoffs from scratch
+        let mut playoffs = LeagueSeasonPlayoffs::new();
+        playoffs.set_rest(options.rest_weeks_between_rounds.clone(), options.rest_advantage);
+        playoffs.set_reseed(options.reseed)?;
+
+        if use_conferences {
+            // Multi-conference path
+            let playoff_teams_per_conference = options.playoff_teams_per_conference;
+            let division_winners_guaranteed = options.division_winners_guaranteed;
+
+            // Validate teams per conference
+            // Validate that each conference has an equal number of teams,
+            // ignoring empty conferences
+            let conf_sizes: Vec<usize> = populated.iter()
+                .map(|c| self.conferences[*c].num_teams())
+                .collect();
+            if playoff_teams_per_conference < 1 {
+                return Err(LeagueError::PlayoffTeamsOutOfRange {
+                    num_playoff_teams: playoff_teams_per_conference,
+                    min: 1,
+                    max: conf_sizes.iter().copied().min().unwrap_or(0)
+                });
+            }
+            if let Some(&first) = conf_sizes.first() {
+                if conf_sizes.iter().any(|&s| s != first) {
+                    return Err(format!(
+                        "Conferences have unequal number of teams: {:?}",
+                        conf_sizes
+                    ).into());
+                }
+            }
 
-        // Phase 2: Conference (non-division) matchups
-        let conference_matchups = self.generate_conference_matchups(conference_games, rng)?;
-        all_matchups.extend(conference_matchups);
+            // Process each conference
+            for conf_index in populated {
+                let conference = &self.conferences[conf_index];
 
-        // Phase 3: Cross-conference matchups
-        if cross_conference_games > 0 {
-            let cross_conf_matchups = self.generate_cross_conference_matchups(cross_conference_games, rng)?;
-            all_matchups.extend(cross_conf_matchups);
-        }
+                // Validate that conference has enough teams
+                let conf_team_count = conference.num_teams();
+                if playoff_teams_per_conference > conf_team_count {
+                    return Err(LeagueError::PlayoffTeamsOutOfRange {
+                        num_playoff_teams: playoff_teams_per_conference,
+                        min: 1,
+                        max: conf_team_count
+                    });
+                }
 
-        // Phase 4: Interleave matchups into weeks
-        self.interleave_matchups(all_matchups, rng)?;
+                // Get conference standings
+                let conf_standings = self.conference_standings(conf_index)?;
 
-        // Get the shift option value
-        let shift = options.shift.unwrap_or(0);
-        if shift > 0 && shift <= self.weeks.len() {
-            self.weeks.rotate_right(shift);
-        }
+                // Determine division winners if guaranteed spots
+                let mut division_winners: Vec<usize> = Vec::new();
+                if division_winners_guaranteed {
+                    for (div_id, _) in conference.divisions().iter().enumerate() {
+                        let div_standings = self.division_standings(conf_index, div_id)?;
+                        if let Some((winner_id, _)) = div_standings.first() {
+                            division_winners.push(*winner_id);
+                        }
+                    }
+                }
 
-        // If desired, randomly permute the weeks of the season
-        if let Some(true) = options.permute {
-            self.weeks.shuffle(rng);
-        }
+                // Select and seed the playoff teams for this conference
+                let conf_playoff_teams = options.seeding_rule.seed(
+                    &conf_standings,
+                    &division_winners,
+                    playoff_teams_per_conference
+                );
 
-        Ok(())
-    }
+                // Add teams to conference bracket
+                for team_id in conf_playoff_teams {
+                    let team = self.teams.get(&team_id)
+                        .ok_or_else(|| format!("Team {} not found", team_id))?;
+                    let short_name = team.short_name();
+                    playoffs.add_team(team_id, short_name, Some(conf_index))?;
+                }
+            }
+        } else {
+            // Single-bracket path
+            let num_playoff_teams = options.num_playoff_teams;
 
-    /// Generate intra-division matchups using circle method within each division
-    fn generate_division_matchups(&self, games_per_opponent: usize, rng: &mut impl Rng) -> Result<Vec<(usize, usize)>, String> {
-        let mut matchups = Vec::new();
+            // Validate the number of playoff teams
+            if num_playoff_teams < 2 {
+                return Err(LeagueError::PlayoffTeamsOutOfRange {
+                    num_playoff_teams,
+                    min: 2,
+                    max: self.teams.len()
+                });
+            }
+            if num_playoff_teams > self.teams.len() {
+                return Err(LeagueError::PlayoffTeamsOutOfRange {
+                    num_playoff_teams,
+                    min: 2,
+                    max: self.teams.len()
+                });
+            }
 
-        for conference in &self.conferences {
-            for division in conference.divisions().iter() {
-                let teams: Vec<usize> = division.teams().clone();
-                let n = teams.len();
+            // Get the standings and select the top teams
+            let standings = self.standings();
 
-                if n < 2 {
-                    continue; // Skip divisions with less than 2 teams
+            // Add the top teams to the playoffs in seed order
+            for (i, (team_id, _)) in standings.iter().enumerate() {
+                if i >= num_playoff_teams {
+                    break;
                 }
 
-                // Generate round-robin matchups within division
-                let div_matchups = self.circle_method_matchups(&teams, games_per_opponent, rng)?;
-                matchups.extend(div_matchups);
+                // Get the team's short name for the playoff bracket
+                let team = match self.teams.get(team_id) {
+                    Some(t) => t,
+                    None => return Err(LeagueError::TeamNotFound { id: *team_id })
+                };
+                let short_name = team.short_name();
+
+                // Add the team to the playoffs
+                playoffs.add_team(*team_id, short_name, None)?;
             }
         }
 
-        Ok(matchups)
+        Ok(playoffs)
     }
 
-    /// Generate intra-conference (cross-division) matchups
-    fn generate_conference_matchups(&self, games_per_opponent: usize, rng: &mut impl Rng) -> Result<Vec<(usize, usize)>, String> {
-        let mut matchups = Vec::new();
-
-        if games_per_opponent == 0 {
-            return Ok(matchups);
+    /// Generate a title playoff between the teams tied for the best record
+    ///
+    /// Only used with `ChampionshipMode::BestRecord`, when teams remain tied
+    /// for the best record after every tiebreaker but team ID, as reported
+    /// by `best_record_teams`. The tied teams are seeded in
+    /// standings order into a single bracket, which is then simulated like
+    /// any other playoffs.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::{LeagueSeason, ChampionshipMode};
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// // Create a season which crowns the best record
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    /// my_league_season.set_championship_mode(ChampionshipMode::BestRecord).unwrap();
+    ///
+    /// // Simulate the regular season
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// my_league_season.sim_regular_season(&mut rng);
+    ///
+    /// // Settle any tie for the best record with a title playoff
+    /// if my_league_season.best_record_teams().len() > 1 {
+    ///     my_league_season.generate_title_playoff(&mut rng).unwrap();
+    ///     my_league_season.sim_playoffs(&mut rng).unwrap();
+    /// }
+    /// assert!(my_league_season.complete());
+    /// assert!(my_league_season.champion().is_some());
+    /// ```
+    pub fn generate_title_playoff(&mut self, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        // Ensure the champion is decided by best record
+        if self.championship_mode != ChampionshipMode::BestRecord {
+            return Err(LeagueError::ChampionDecidedByPlayoffs);
         }
 
-        for conference in &self.conferences {
-            // Get all teams in this conference
-            let all_teams = conference.all_teams();
+        // Ensure the regular season is complete
+        if !self.regular_season_complete() {
+            return Err(LeagueError::RegularSeasonNotComplete);
+        }
 
-            // For each pair of teams in the same conference but different divisions
-            for i in 0..all_teams.len() {
-                for j in (i + 1)..all_teams.len() {
-                    let team1 = all_teams[i];
-                    let team2 = all_teams[j];
+        // Ensure the title playoff has not already started
+        if self.playoffs.started() {
+            return Err(LeagueError::PlayoffsAlreadyStarted);
+        }
 
-                    // Skip if same division (already handled in division matchups)
-                    if self.same_division(team1, team2) {
-                        continue;
-                    }
+        // Ensure teams are tied for the best record
+        let tied_teams = self.best_record_teams();
+        if tied_teams.len() < 2 {
+            return Err(LeagueError::NoTieForBestRecord);
+        }
 
-                    // Generate the specified number of games
-                    for cycle in 0..games_per_opponent {
-                        // Alternate home/away
-                        if cycle % 2 == 0 {
-                            matchups.push((team1, team2));
-                        } else {
-                            matchups.push((team2, team1));
-                        }
-                    }
-                }
-            }
+        // Seed the tied teams in standings order
+        self.playoffs = LeagueSeasonPlayoffs::new();
+        for team_id in tied_teams.iter() {
+            let team = match self.teams.get(team_id) {
+                Some(t) => t,
+                None => return Err(LeagueError::TeamNotFound { id: *team_id })
+            };
+            let short_name = team.short_name();
+            self.playoffs.add_team(*team_id, short_name, None)?;
         }
 
-        // Shuffle to add variety
-        matchups.shuffle(rng);
-        Ok(matchups)
+        // Generate the first round
+        self.playoffs.gen_next_playoff_round(rng)?;
+        Ok(())
     }
 
-    /// Generate cross-conference matchups
-    fn generate_cross_conference_matchups(&self, total_games_per_team: usize, rng: &mut impl Rng) -> Result<Vec<(usize, usize)>, String> {
-        let mut matchups = Vec::new();
-
-        if self.conferences.len() < 2 || total_games_per_team == 0 {
-            return Ok(matchups);
+    /// Generate the next playoff round
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
+    ///
+    /// // Create a new season
+    /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Add 4 teams to the season
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Simulate the entire regular season
+    /// my_league_season.sim_regular_season(&mut rng);
+    ///
+    /// // Generate playoffs with 4 teams, simulate the first round
+    /// let mut playoff_options = LeagueSeasonPlayoffOptions::new();
+    /// playoff_options.num_playoff_teams = 4;
+    /// let _ = my_league_season.generate_playoffs(playoff_options, &mut rng);
+    /// let _ = my_league_season.sim_playoff_round(0, &mut rng);
+    ///
+    /// // Generate the next round of the playoffs
+    /// let res = my_league_season.generate_next_playoff_round(&mut rng);
+    /// assert!(res.is_ok());
+    /// ```
+    pub fn generate_next_playoff_round(&mut self, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        // Ensure the playoffs have started, are set up, are not complete
+        if !self.regular_season_complete() {
+            return Err(LeagueError::RegularSeasonNotComplete);
         }
-
-        // Track how many cross-conference games each team has scheduled
-        let mut team_cross_conf_games: HashMap<usize, usize> = HashMap::new();
-        for team_id in self.teams.keys() {
-            team_cross_conf_games.insert(*team_id, 0);
+        if self.playoffs.num_teams() < 2 {
+            return Err(LeagueError::PlayoffsNotGenerated);
+        }
+        if self.playoffs.complete() {
+            return Err(LeagueError::PlayoffsAlreadyComplete);
         }
 
-        // Get all possible cross-conference pairs
-        let mut possible_pairs: Vec<(usize, usize)> = Vec::new();
-        for (i, conf1) in self.conferences.iter().enumerate() {
-            for conf2 in self.conferences.iter().skip(i + 1) {
-                let teams1 = conf1.all_teams();
-                let teams2 = conf2.all_teams();
+        // Ensure all current rounds across all conference brackets are complete
+        for conference_rounds in self.playoffs.conference_brackets().values() {
+            if let Some(current_round) = conference_rounds.last() {
+                if !current_round.complete() {
+                    return Err(LeagueError::PlayoffRoundNotComplete { round: conference_rounds.len() - 1 });
+                }
+            }
+        }
 
-                for t1 in &teams1 {
-                    for t2 in &teams2 {
-                        possible_pairs.push((*t1, *t2));
-                    }
+        // Ensure the current winners bracket round is complete
+        if self.playoffs.is_conference_playoff() && self.playoffs.conference_brackets_complete() {
+            if let Some(round) = self.playoffs.winners_bracket().last() {
+                if !round.complete() {
+                    return Err(LeagueError::WinnersBracketRoundNotComplete {
+                        round: self.playoffs.winners_bracket().len() - 1
+                    });
                 }
             }
         }
 
-        // Shuffle pairs for randomness
-        possible_pairs.shuffle(rng);
+        // Generate the next round
+        self.playoffs.gen_next_playoff_round(rng)
+    }
 
-        // Greedily assign cross-conference games
-        for (team1, team2) in possible_pairs {
-            let games1 = *team_cross_conf_games.get(&team1).unwrap_or(&0);
-            let games2 = *team_cross_conf_games.get(&team2).unwrap_or(&0);
+    /// Simulate a playoff matchup in a specific conference bracket
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
+    ///
+    /// // Create a new season
+    /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Add 4 teams to the season
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Simulate the entire regular season
+    /// my_league_season.sim_regular_season(&mut rng);
+    ///
+    /// // Generate playoffs with 4 teams
+    /// let mut playoff_options = LeagueSeasonPlayoffOptions::new();
+    /// playoff_options.num_playoff_teams = 4;
+    /// my_league_season.generate_playoffs(playoff_options, &mut rng);
+    ///
+    /// // Simulate the first playoff matchup in conference bracket 0
+    /// let res = my_league_season.sim_playoff_matchup(0, 0, 0, &mut rng);
+    /// assert!(res.is_ok());
+    /// ```
+    pub fn sim_playoff_matchup(&mut self, conference: usize, round: usize, matchup: usize, rng: &mut impl Rng) -> Result<Game, LeagueError> {
+        self.fingerprint.invalidate();
+        // Ensure the regular season is complete
+        if !self.regular_season_complete() {
+            return Err(LeagueError::RegularSeasonNotComplete);
+        }
 
-            if games1 < total_games_per_team && games2 < total_games_per_team {
-                // Randomly decide home/away
-                if rng.gen::<bool>() {
-                    matchups.push((team1, team2));
-                } else {
-                    matchups.push((team2, team1));
-                }
-                *team_cross_conf_games.get_mut(&team1).unwrap() += 1;
-                *team_cross_conf_games.get_mut(&team2).unwrap() += 1;
+        // Ensure the playoffs have started
+        let bracket = self.playoffs.conference_bracket(conference)
+            .ok_or_else(|| format!("Cannot simulate playoff matchup: Conference bracket {} not found", conference))?;
+        if bracket.is_empty() {
+            return Err(LeagueError::PlayoffsNotGenerated);
+        }
+
+        // Check if the prior round is complete (if not the first round)
+        if round > 0 {
+            let prev_round = match bracket.get(round - 1) {
+                Some(r) => r,
+                None => return Err(LeagueError::PlayoffRoundNotFound { round: round - 1 })
+            };
+            if !prev_round.complete() {
+                return Err(LeagueError::PlayoffRoundNotComplete { round: round - 1 });
             }
         }
 
-        Ok(matchups)
-    }
+        // Get the playoff round
+        let playoff_round = match self.playoffs.conference_bracket_mut(conference)
+            .and_then(|b| b.get_mut(round)) {
+            Some(r) => r,
+            None => return Err(LeagueError::PlayoffRoundNotFound { round })
+        };
 
-    /// Apply circle method to generate round-robin matchups for a set of teams
-    fn circle_method_matchups(&self, teams: &[usize], games_per_opponent: usize, rng: &mut impl Rng) -> Result<Vec<(usize, usize)>, String> {
-        let mut matchups = Vec::new();
-        let n = teams.len();
+        // Get the matchup
+        let playoff_matchup = match playoff_round.matchups_mut().get_mut(matchup) {
+            Some(m) => m,
+            None => return Err(LeagueError::PlayoffMatchupNotFound { round, matchup })
+        };
 
-        if n < 2 {
-            return Ok(matchups);
+        // Ensure the matchup is not already complete
+        if playoff_matchup.context().is_terminal() {
+            return Err(LeagueError::PlayoffMatchupAlreadyComplete { round, matchup });
         }
 
-        // Shuffle teams for variety
-        let mut team_ids = teams.to_vec();
-        team_ids.shuffle(rng);
-
-        let num_rounds = n - 1;
-        let num_matchups_per_round = n / 2;
+        // Get the home and away teams
+        let home_id = *playoff_matchup.home_team();
+        let away_id = *playoff_matchup.away_team();
 
-        // Generate games_per_opponent cycles of round-robin
-        for cycle in 0..games_per_opponent {
-            for round in 0..num_rounds {
-                // Build arrangement using circle method
-                let mut arrangement: Vec<usize> = Vec::with_capacity(n);
-                arrangement.push(team_ids[0]); // Fixed team
-                for i in 0..(n - 1) {
-                    let rotated_index = (i + (n - 1) - round) % (n - 1);
-                    arrangement.push(team_ids[1 + rotated_index]);
-                }
+        let home_team = match self.teams.get(&home_id) {
+            Some(t) => t,
+            None => return Err(LeagueError::TeamNotFound { id: home_id })
+        };
+        let away_team = match self.teams.get(&away_id) {
+            Some(t) => t,
+            None => return Err(LeagueError::TeamNotFound { id: away_id })
+        };
 
-                // Create matchups
-                for m in 0..num_matchups_per_round {
-                    let team1 = arrangement[m];
-                    let team2 = arrangement[n - 1 - m];
+        // Apply any rest advantage to the rested team
+        let home_team = playoff_matchup.rested_team(home_id, home_team);
+        let away_team = playoff_matchup.rested_team(away_id, away_team);
 
-                    // Alternate home/away based on cycle
-                    if cycle % 2 == 0 {
-                        matchups.push((team1, team2));
-                    } else {
-                        matchups.push((team2, team1));
-                    }
-                }
-            }
-        }
+        // Simulate the matchup
+        let mut game = Game::new();
+        let simulator = GameSimulator::new();
+        let context = match simulator.sim_game(
+            &home_team, &away_team,
+            playoff_matchup.sim_context_with_weather(OvertimeRules::playoffs(), self.weather_distribution.as_ref(), rng),
+            &mut game, rng
+        ) {
+            Ok(c) => c,
+            Err(e) => return Err(LeagueError::Simulation { reason: e.to_string() })
+        };
 
-        Ok(matchups)
+        // Update the matchup context and stats
+        playoff_matchup.finalize(context, &game)?;
+        self.record_attendance();
+        Ok(game)
     }
 
-    /// Interleave matchups into weeks, avoiding long road trips
-    fn interleave_matchups(&mut self, matchups: Vec<(usize, usize)>, rng: &mut impl Rng) -> Result<(), String> {
-        if matchups.is_empty() {
-            return Ok(());
+    /// Simulate a winners bracket matchup
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    ///
+    /// // Create a new season with 2 conferences
+    /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Add teams
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Create conference structure
+    /// let mut afc = LeagueConference::with_name("AFC");
+    /// let mut afc_div = LeagueDivision::with_name("East");
+    /// afc_div.add_team(0);
+    /// afc_div.add_team(1);
+    /// afc.add_division(afc_div);
+    /// my_league_season.add_conference(afc);
+    ///
+    /// let mut nfc = LeagueConference::with_name("NFC");
+    /// let mut nfc_div = LeagueDivision::with_name("East");
+    /// nfc_div.add_team(2);
+    /// nfc_div.add_team(3);
+    /// nfc.add_division(nfc_div);
+    /// my_league_season.add_conference(nfc);
+    ///
+    /// // Generate schedule and simulate regular season
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// my_league_season.sim_regular_season(&mut rng);
+    ///
+    /// // Generate conference playoffs and simulate all conference brackets
+    /// let mut options = LeagueSeasonPlayoffOptions::new();
+    /// options.use_conference_brackets = true;
+    /// options.playoff_teams_per_conference = 2;
+    /// options.division_winners_guaranteed = true;
+    /// let _ = my_league_season.generate_playoffs(options, &mut rng);
+    /// let _ = my_league_season.sim_playoff_round(0, &mut rng);
+    ///
+    /// // Generate the winners bracket
+    /// let _ = my_league_season.generate_next_playoff_round(&mut rng);
+    ///
+    /// // Simulate the first winners bracket matchup
+    /// let res = my_league_season.sim_winners_bracket_matchup(0, 0, &mut rng);
+    /// assert!(res.is_ok());
+    /// ```
+    pub fn sim_winners_bracket_matchup(&mut self, round: usize, matchup: usize, rng: &mut impl Rng) -> Result<Game, LeagueError> {
+        self.fingerprint.invalidate();
+        // Ensure the regular season is complete
+        if !self.regular_season_complete() {
+            return Err(LeagueError::RegularSeasonNotComplete);
         }
 
-        let num_teams = self.teams.len();
-        let _matchups_per_week = num_teams / 2;
-
-        // Track home/away streaks and which matchups are scheduled
-        let mut remaining_matchups: Vec<(usize, usize)> = matchups;
-        remaining_matchups.shuffle(rng); // Shuffle for variety
-
-        let mut team_away_streak: HashMap<usize, usize> = HashMap::new();
-        let mut team_home_streak: HashMap<usize, usize> = HashMap::new();
-        for id in self.teams.keys() {
-            team_away_streak.insert(*id, 0);
-            team_home_streak.insert(*id, 0);
+        // Ensure this is a conference playoff with a winners bracket
+        if !self.playoffs.is_conference_playoff() {
+            return Err(LeagueError::NotConferencePlayoffs);
         }
 
-        while !remaining_matchups.is_empty() {
-            let mut week = LeagueSeasonWeek::new();
-            let mut teams_scheduled_this_week: HashSet<usize> = HashSet::new();
-            let mut matchups_this_week: Vec<usize> = Vec::new(); // indices into remaining_matchups
-
-            // Greedily select matchups for this week
-            for (index, (home_id, away_id)) in remaining_matchups.iter().enumerate() {
-                // Skip if either team already scheduled this week
-                if teams_scheduled_this_week.contains(home_id) || teams_scheduled_this_week.contains(away_id) {
-                    continue;
-                }
-
-                // Check road trip constraint (no more than 2 consecutive away games)
-                let away_streak = *team_away_streak.get(away_id).unwrap_or(&0);
-                if away_streak >= 2 {
-                    // Check if there's an alternative where this team is home
-                    // For now, we'll allow it but prefer not to
-                    // This could be improved with backtracking
-                }
-
-                // Schedule this matchup
-                teams_scheduled_this_week.insert(*home_id);
-                teams_scheduled_this_week.insert(*away_id);
-                matchups_this_week.push(index);
+        // Ensure the winners bracket has started
+        if self.playoffs.winners_bracket().is_empty() {
+            return Err(LeagueError::PlayoffsNotGenerated);
+        }
 
-                if teams_scheduled_this_week.len() >= num_teams {
-                    break; // Week is full
-                }
+        // Check if the prior round is complete (if not the first round)
+        if round > 0 {
+            let prev_round = match self.playoffs.winners_bracket().get(round - 1) {
+                Some(r) => r,
+                None => return Err(LeagueError::WinnersBracketRoundNotFound { round: round - 1 })
+            };
+            if !prev_round.complete() {
+                return Err(LeagueError::WinnersBracketRoundNotComplete { round: round - 1 });
             }
+        }
 
-            // If we couldn't schedule any matchups but there are still remaining, force one
-            if matchups_this_week.is_empty() && !remaining_matchups.is_empty() {
-                matchups_this_week.push(0);
-                let (home_id, away_id) = remaining_matchups[0];
-                teams_scheduled_this_week.insert(home_id);
-                teams_scheduled_this_week.insert(away_id);
-            }
+        // Get the round
+        let playoff_round = match self.playoffs.winners_bracket_mut().get_mut(round) {
+            Some(r) => r,
+            None => return Err(LeagueError::PlayoffRoundNotFound { round })
+        };
 
-            // Create matchups and update streaks
-            // Sort indices in reverse so we can remove from end first
-            matchups_this_week.sort_by(|a, b| b.cmp(a));
-            for index in matchups_this_week {
-                let (home_id, away_id) = remaining_matchups.remove(index);
+        // Get the matchup
+        let playoff_matchup = match playoff_round.matchups_mut().get_mut(matchup) {
+            Some(m) => m,
+            None => return Err(LeagueError::WinnersBracketMatchupNotFound { round, matchup })
+        };
 
-                // Get team short names
-                let home_short_name = self.teams.get(&home_id).unwrap().short_name();
-                let away_short_name = self.teams.get(&away_id).unwrap().short_name();
+        // Ensure the matchup is not already complete
+        if playoff_matchup.context().is_terminal() {
+            return Err(LeagueError::WinnersBracketMatchupAlreadyComplete { round, matchup });
+        }
 
-                // Create matchup
-                let matchup = LeagueSeasonMatchup::new(home_id, away_id, home_short_name, away_short_name, rng);
-                week.matchups_mut().push(matchup);
+        // Get the home and away teams
+        let home_id = *playoff_matchup.home_team();
+        let away_id = *playoff_matchup.away_team();
 
-                // Update streaks
-                *team_home_streak.get_mut(&home_id).unwrap() += 1;
-                *team_away_streak.get_mut(&home_id).unwrap() = 0;
-                *team_away_streak.get_mut(&away_id).unwrap() += 1;
-                *team_home_streak.get_mut(&away_id).unwrap() = 0;
-            }
+        let home_team = match self.teams.get(&home_id) {
+            Some(t) => t,
+            None => return Err(LeagueError::TeamNotFound { id: home_id })
+        };
+        let away_team = match self.teams.get(&away_id) {
+            Some(t) => t,
+            None => return Err(LeagueError::TeamNotFound { id: away_id })
+        };
 
-            self.weeks.push(week);
+        // Apply any rest advantage to the rested team
+        let home_team = playoff_matchup.rested_team(home_id, home_team);
+        let away_team = playoff_matchup.rested_team(away_id, away_team);
 
-            // Safety: prevent infinite loop
-            if self.weeks.len() > num_teams * 10 {
-                return Err("Failed to generate valid schedule - too many weeks".to_string());
-            }
-        }
+        // Simulate the matchup
+        let mut game = Game::new();
+        let simulator = GameSimulator::new();
+        let context = match simulator.sim_game(
+            &home_team, &away_team,
+            playoff_matchup.sim_context_with_weather(OvertimeRules::playoffs(), self.weather_distribution.as_ref(), rng),
+            &mut game, rng
+        ) {
+            Ok(c) => c,
+            Err(e) => return Err(LeagueError::Simulation { reason: e.to_string() })
+        };
 
-        Ok(())
+        // Update the matchup context and stats
+        playoff_matchup.finalize(context, &game)?;
+        self.record_attendance();
+        Ok(game)
     }
 
-    /// Computes the season standings sorted in descending order, mapping
-    /// team IDs to their season record
+    /// Simulate a single play of a winners bracket playoff matchup
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::league::season::LeagueSeason;
     /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
+    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
     ///
-    /// // Create a new season
+    /// // Create a new season with 2 conferences
     /// let mut my_league_season = LeagueSeason::new();
     ///
-    /// // Add 4 teams to the season
+    /// // Add teams
     /// my_league_season.add_team(0, FootballTeam::new());
     /// my_league_season.add_team(1, FootballTeam::new());
     /// my_league_season.add_team(2, FootballTeam::new());
     /// my_league_season.add_team(3, FootballTeam::new());
     ///
-    /// // Generate the season schedule
+    /// // Create conference structure
+    /// let mut afc = LeagueConference::with_name("AFC");
+    /// let mut afc_div = LeagueDivision::with_name("East");
+    /// afc_div.add_team(0);
+    /// afc_div.add_team(1);
+    /// afc.add_division(afc_div);
+    /// my_league_season.add_conference(afc);
+    ///
+    /// let mut nfc = LeagueConference::with_name("NFC");
+    /// let mut nfc_div = LeagueDivision::with_name("East");
+    /// nfc_div.add_team(2);
+    /// nfc_div.add_team(3);
+    /// nfc.add_division(nfc_div);
+    /// my_league_season.add_conference(nfc);
+    ///
+    /// // Generate schedule and simulate regular season
     /// let mut rng = rand::thread_rng();
     /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
-    ///
-    /// // Simulate the entire regular season
     /// my_league_season.sim_regular_season(&mut rng);
     ///
-    /// // Compute the standings
-    /// let standings = my_league_season.standings();
+    /// // Generate conference playoffs and simulate all conference brackets
+    /// let mut options = LeagueSeasonPlayoffOptions::new();
+    /// options.use_conference_brackets = true;
+    /// options.playoff_teams_per_conference = 2;
+    /// options.division_winners_guaranteed = true;
+    /// let _ = my_league_season.generate_playoffs(options, &mut rng);
+    /// let _ = my_league_season.sim_playoff_round(0, &mut rng);
+    ///
+    /// // Generate the winners bracket
+    /// let _ = my_league_season.generate_next_playoff_round(&mut rng);
+    ///
+    /// // Simulate a single play of the first winners bracket matchup
+    /// let res = my_league_season.sim_winners_bracket_play(0, 0, &mut rng);
+    /// assert!(res.is_ok());
     /// ```
-    pub fn standings(&self) -> Vec<(usize, LeagueTeamRecord)> {
-        // Compute each team's record
-        let mut standings: Vec<(usize, LeagueTeamRecord)> = Vec::new();
-        for (id, _) in self.teams.iter() {
-            let matchups = match self.team_matchups(*id) {
-                Ok(m) => m,
-                Err(_) => continue
-            };
-            standings.push((*id, matchups.record()));
+    pub fn sim_winners_bracket_play(&mut self, round: usize, matchup: usize, rng: &mut impl Rng) -> Result<Option<Game>, LeagueError> {
+        self.fingerprint.invalidate();
+        // Ensure the regular season is complete
+        if !self.regular_season_complete() {
+            return Err(LeagueError::RegularSeasonNotComplete);
         }
 
-        // Sort by win percentage (descending), then by wins (descending), then by team ID
-        standings.sort_by(|a, b| {
-            let (id_a, rec_a) = a;
-            let (id_b, rec_b) = b;
+        // Ensure this is a conference playoff with a winners bracket
+        if !self.playoffs.is_conference_playoff() {
+            return Err(LeagueError::NotConferencePlayoffs);
+        }
 
-            // Calculate win percentage (wins + 0.5*ties) / total games
-            let games_a = rec_a.wins() + rec_a.losses() + rec_a.ties();
-            let games_b = rec_b.wins() + rec_b.losses() + rec_b.ties();
+        // Ensure the winners bracket has started
+        if self.playoffs.winners_bracket().is_empty() {
+            return Err(LeagueError::PlayoffsNotGenerated);
+        }
 
-            // Avoid division by zero
-            let pct_a = if games_a > 0 {
-                (*rec_a.wins() as f64 + 0.5 * *rec_a.ties() as f64) / games_a as f64
-            } else {
-                0.0
-            };
-            let pct_b = if games_b > 0 {
-                (*rec_b.wins() as f64 + 0.5 * *rec_b.ties() as f64) / games_b as f64
-            } else {
-                0.0
+        // Check if the prior round is complete (if not the first round)
+        if round > 0 {
+            let prev_round = match self.playoffs.winners_bracket().get(round - 1) {
+                Some(r) => r,
+                None => return Err(LeagueError::WinnersBracketRoundNotFound { round: round - 1 })
             };
-
-            // Sort by win percentage (descending)
-            match pct_b.partial_cmp(&pct_a) {
-                Some(std::cmp::Ordering::Equal) | None => {}
-                Some(ord) => return ord,
+            if !prev_round.complete() {
+                return Err(LeagueError::WinnersBracketRoundNotComplete { round: round - 1 });
             }
+        }
 
-            // Tiebreaker: wins (descending)
-            match rec_b.wins().cmp(rec_a.wins()) {
-                std::cmp::Ordering::Equal => {}
-                ord => return ord,
-            }
+        // Get the round
+        let playoff_round = match self.playoffs.winners_bracket_mut().get_mut(round) {
+            Some(r) => r,
+            None => return Err(LeagueError::PlayoffRoundNotFound { round })
+        };
 
-            // Final tiebreaker: team ID (ascending for consistency)
-            id_a.cmp(id_b)
-        });
+        // Get the matchup
+        let playoff_matchup = match playoff_round.matchups_mut().get_mut(matchup) {
+            Some(m) => m,
+            None => return Err(LeagueError::WinnersBracketMatchupNotFound { round, matchup })
+        };
+
+        // Ensure the matchup is not already complete
+        if playoff_matchup.context().is_terminal() {
+            return Err(LeagueError::WinnersBracketMatchupAlreadyComplete { round, matchup });
+        }
+
+        // Get the home and away teams
+        let home_id = *playoff_matchup.home_team();
+        let away_id = *playoff_matchup.away_team();
+
+        let home_team = match self.teams.get(&home_id) {
+            Some(t) => t,
+            None => return Err(LeagueError::TeamNotFound { id: home_id })
+        };
+        let away_team = match self.teams.get(&away_id) {
+            Some(t) => t,
+            None => return Err(LeagueError::TeamNotFound { id: away_id })
+        };
+
+        // Apply any rest advantage to the rested team
+        let home_team = playoff_matchup.rested_team(home_id, home_team);
+        let away_team = playoff_matchup.rested_team(away_id, away_team);
+
+        // Create a new game if game has not started, or get existing game
+        if playoff_matchup.game().is_none() {
+            *playoff_matchup.game_mut() = Some(Game::new());
+        }
+
+        // Simulate the next play
+        let simulator = GameSimulator::new();
+        let context = match simulator.sim_play(
+            &home_team, &away_team,
+            playoff_matchup.sim_context_with_weather(OvertimeRules::playoffs(), self.weather_distribution.as_ref(), rng),
+            playoff_matchup.game_mut().as_mut().unwrap(),
+            rng
+        ) {
+            Ok(c) => c,
+            Err(e) => return Err(LeagueError::Simulation { reason: e.to_string() })
+        };
 
-        standings
+        // If game is over, archive game stats, clear game, update context
+        if context.game_over() {
+            let game = playoff_matchup.take_game().ok_or(
+                "Failed to archive stats for winners bracket game"
+            )?;
+            playoff_matchup.finalize(context, &game)?;
+            self.record_attendance();
+            return Ok(Some(game));
+        }
+        *playoff_matchup.context_mut() = context;
+        Ok(None)
     }
 
-    /// Computes the division standings for a specific division
-    ///
-    /// ### Arguments
-    /// * `conf_index` - The conference index (position in conferences Vec)
-    /// * `div_id` - The division ID (key in conference's divisions BTreeMap)
-    ///
-    /// ### Returns
-    /// * `Ok(Vec<(usize, LeagueTeamRecord)>)` - Division standings sorted by record
-    /// * `Err(String)` - If conference or division doesn't exist
+    /// Simulate a single play of a playoff matchup in a specific conference bracket
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::league::season::LeagueSeason;
     /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
     ///
-    /// // Create a new season with 2 conferences
+    /// // Create a new season
     /// let mut my_league_season = LeagueSeason::new();
     ///
-    /// // Add teams
+    /// // Add 4 teams to the season
     /// my_league_season.add_team(0, FootballTeam::new());
     /// my_league_season.add_team(1, FootballTeam::new());
     /// my_league_season.add_team(2, FootballTeam::new());
     /// my_league_season.add_team(3, FootballTeam::new());
     ///
-    /// // Create conference structure
-    /// let mut afc = LeagueConference::with_name("AFC");
-    /// let mut afc_div = LeagueDivision::with_name("East");
-    /// afc_div.add_team(0);
-    /// afc_div.add_team(1);
-    /// afc.add_division(afc_div);
-    /// my_league_season.add_conference(afc);
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
     ///
-    /// let mut nfc = LeagueConference::with_name("NFC");
-    /// let mut nfc_div = LeagueDivision::with_name("East");
-    /// nfc_div.add_team(2);
-    /// nfc_div.add_team(3);
-    /// nfc.add_division(nfc_div);
-    /// my_league_season.add_conference(nfc);
+    /// // Simulate the entire regular season
+    /// my_league_season.sim_regular_season(&mut rng);
     ///
-    /// // Compute the division standings
-    /// let afc_east_standings = my_league_season.division_standings(0, 0);
-    /// if let Err(ref e) = afc_east_standings {
-    ///     println!("{}", e);
-    /// }
-    /// assert!(afc_east_standings.is_ok());
+    /// // Generate playoffs with 4 teams
+    /// let mut playoff_options = LeagueSeasonPlayoffOptions::new();
+    /// playoff_options.num_playoff_teams = 4;
+    /// my_league_season.generate_playoffs(playoff_options, &mut rng);
+    ///
+    /// // Simulate a single play of the first playoff matchup
+    /// let res = my_league_season.sim_playoff_play(0, 0, 0, &mut rng);
+    /// assert!(res.is_ok());
     /// ```
-    pub fn division_standings(&self, conf_index: usize, div_id: usize) -> Result<Vec<(usize, LeagueTeamRecord)>, String> {
-        // Get the conference
-        let conference = self.conferences.get(conf_index)
-            .ok_or_else(|| format!("Conference index {} does not exist", conf_index))?;
+    pub fn sim_playoff_play(&mut self, conference: usize, round: usize, matchup: usize, rng: &mut impl Rng) -> Result<Option<Game>, LeagueError> {
+        self.fingerprint.invalidate();
+        // Ensure the regular season is complete
+        if !self.regular_season_complete() {
+            return Err(LeagueError::RegularSeasonNotComplete);
+        }
 
-        // Get the division
-        let division = conference.division(div_id)
-            .ok_or_else(|| format!("Division {} does not exist in conference {}", div_id, conf_index))?;
+        // Ensure the playoffs have started
+        let bracket = self.playoffs.conference_bracket(conference)
+            .ok_or_else(|| format!("Cannot simulate playoff play: Conference bracket {} not found", conference))?;
+        if bracket.is_empty() {
+            return Err(LeagueError::PlayoffsNotGenerated);
+        }
 
-        // Get all standings and filter to this division's teams
-        let all_standings = self.standings();
-        let division_teams: HashSet<usize> = division.teams().iter().cloned().collect();
+        // Check if the prior round is complete (if not the first round)
+        if round > 0 {
+            let prev_round = match bracket.get(round - 1) {
+                Some(r) => r,
+                None => return Err(LeagueError::PlayoffRoundNotFound { round: round - 1 })
+            };
+            if !prev_round.complete() {
+                return Err(LeagueError::PlayoffRoundNotComplete { round: round - 1 });
+            }
+        }
 
-        let standings: Vec<(usize, LeagueTeamRecord)> = all_standings
-            .into_iter()
-            .filter(|(id, _)| division_teams.contains(id))
-            .collect();
+        // Get the playoff round
+        let playoff_round = match self.playoffs.conference_bracket_mut(conference)
+            .and_then(|b| b.get_mut(round)) {
+            Some(r) => r,
+            None => return Err(LeagueError::PlayoffRoundNotFound { round })
+        };
 
-        Ok(standings)
+        // Get the matchup
+        let playoff_matchup = match playoff_round.matchups_mut().get_mut(matchup) {
+            Some(m) => m,
+            None => return Err(LeagueError::PlayoffMatchupNotFound { round, matchup })
+        };
+
+        // Ensure the matchup is not already complete
+        if playoff_matchup.context().is_terminal() {
+            return Err(LeagueError::PlayoffMatchupAlreadyComplete { round, matchup });
+        }
+
+        // Get the home and away teams
+        let home_id = *playoff_matchup.home_team();
+        let away_id = *playoff_matchup.away_team();
+
+        let home_team = match self.teams.get(&home_id) {
+            Some(t) => t,
+            None => return Err(LeagueError::TeamNotFound { id: home_id })
+        };
+        let away_team = match self.teams.get(&away_id) {
+            Some(t) => t,
+            None => return Err(LeagueError::TeamNotFound { id: away_id })
+        };
+
+        // Apply any rest advantage to the rested team
+        let home_team = playoff_matchup.rested_team(home_id, home_team);
+        let away_team = playoff_matchup.rested_team(away_id, away_team);
+
+        // Create a new game if game has not started, or get existing game
+        if playoff_matchup.game().is_none() {
+            *playoff_matchup.game_mut() = Some(Game::new());
+        }
+
+        // Simulate the next play
+        let simulator = GameSimulator::new();
+        let context = match simulator.sim_play(
+            &home_team, &away_team,
+            playoff_matchup.sim_context_with_weather(OvertimeRules::playoffs(), self.weather_distribution.as_ref(), rng),
+            playoff_matchup.game_mut().as_mut().unwrap(),
+            rng
+        ) {
+            Ok(c) => c,
+            Err(e) => return Err(LeagueError::Simulation { reason: e.to_string() })
+        };
+
+        // If game is over, archive game stats, clear game, update context
+        if context.game_over() {
+            let game = playoff_matchup.take_game().ok_or(
+                "Failed to archive stats for playoff game"
+            )?;
+            playoff_matchup.finalize(context, &game)?;
+            self.record_attendance();
+            return Ok(Some(game));
+        }
+        *playoff_matchup.context_mut() = context;
+        Ok(None)
     }
 
-    /// Computes the conference standings for a specific conference
-    ///
-    /// ### Arguments
-    /// * `conf_index` - The conference index (position in conferences Vec)
-    ///
-    /// ### Returns
-    /// * `Ok(Vec<(usize, LeagueTeamRecord)>)` - Conference standings sorted by record
-    /// * `Err(String)` - If conference doesn't exist
+    /// Simulate all matchups in a specific conference bracket round
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::league::season::LeagueSeason;
     /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
     ///
-    /// // Create a new season with 2 conferences
+    /// // Create a new season
     /// let mut my_league_season = LeagueSeason::new();
     ///
-    /// // Add teams
+    /// // Add 4 teams to the season
     /// my_league_season.add_team(0, FootballTeam::new());
     /// my_league_season.add_team(1, FootballTeam::new());
     /// my_league_season.add_team(2, FootballTeam::new());
     /// my_league_season.add_team(3, FootballTeam::new());
     ///
-    /// // Create conference structure
-    /// let mut afc = LeagueConference::with_name("AFC");
-    /// let mut afc_div = LeagueDivision::with_name("East");
-    /// afc_div.add_team(0);
-    /// afc_div.add_team(1);
-    /// afc.add_division(afc_div);
-    /// my_league_season.add_conference(afc);
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
     ///
-    /// let mut nfc = LeagueConference::with_name("NFC");
-    /// let mut nfc_div = LeagueDivision::with_name("East");
-    /// nfc_div.add_team(2);
-    /// nfc_div.add_team(3);
-    /// nfc.add_division(nfc_div);
-    /// my_league_season.add_conference(nfc);
+    /// // Simulate the entire regular season
+    /// my_league_season.sim_regular_season(&mut rng);
+    ///
+    /// // Generate playoffs with 4 teams
+    /// let mut playoff_options = LeagueSeasonPlayoffOptions::new();
+    /// playoff_options.num_playoff_teams = 4;
+    /// my_league_season.generate_playoffs(playoff_options, &mut rng);
     ///
-    /// // Compute the conference standings
-    /// let nfc_standings = my_league_season.conference_standings(1);
-    /// assert!(nfc_standings.is_ok());
+    /// // Simulate the first round of conference bracket 0
+    /// let res = my_league_season.sim_playoff_conference_round(0, 0, &mut rng);
+    /// assert!(res.is_ok());
     /// ```
-    pub fn conference_standings(&self, conf_index: usize) -> Result<Vec<(usize, LeagueTeamRecord)>, String> {
-        // Get the conference
-        let conference = self.conferences.get(conf_index)
-            .ok_or_else(|| format!("Conference index {} does not exist", conf_index))?;
+    pub fn sim_playoff_conference_round(&mut self, conference: usize, round: usize, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        // Ensure the regular season is complete
+        if !self.regular_season_complete() {
+            return Err(LeagueError::RegularSeasonNotComplete);
+        }
 
-        // Get all teams in the conference
-        let conference_teams: HashSet<usize> = conference.all_teams().into_iter().collect();
+        // Ensure the bracket exists
+        let bracket = self.playoffs.conference_bracket(conference)
+            .ok_or_else(|| format!("Cannot simulate playoff round: Conference bracket {} not found", conference))?;
+        if bracket.is_empty() {
+            return Err(LeagueError::PlayoffsNotGenerated);
+        }
 
-        // Get all standings and filter to this conference's teams
-        let all_standings = self.standings();
+        // Check if the prior round is complete (if not the first round)
+        if round > 0 {
+            let prev_round = match bracket.get(round - 1) {
+                Some(r) => r,
+                None => return Err(LeagueError::PlayoffRoundNotFound { round: round - 1 })
+            };
+            if !prev_round.complete() {
+                return Err(LeagueError::PlayoffRoundNotComplete { round: round - 1 });
+            }
+        }
 
-        let standings: Vec<(usize, LeagueTeamRecord)> = all_standings
-            .into_iter()
-            .filter(|(id, _)| conference_teams.contains(id))
-            .collect();
+        // Get the number of matchups in this round
+        let num_matchups = match bracket.get(round) {
+            Some(r) => r.matchups().len(),
+            None => return Err(LeagueError::PlayoffRoundNotFound { round })
+        };
 
-        Ok(standings)
+        // Simulate each matchup in the round
+        for i in 0..num_matchups {
+            // Skip matchups that have already been completed
+            let is_complete = match self.playoffs.conference_bracket(conference)
+                .and_then(|b| b.get(round)) {
+                Some(r) => match r.matchups().get(i) {
+                    Some(m) => m.context().game_over(),
+                    None => continue
+                },
+                None => continue
+            };
+            if is_complete {
+                continue;
+            }
+
+            // Simulate the matchup
+            self.sim_playoff_matchup(conference, round, i, rng)?;
+        }
+        Ok(())
     }
 
-    /// Computes a team's record against division opponents only
-    ///
-    /// ### Arguments
-    /// * `team_id` - The team ID to compute division record for
-    ///
-    /// ### Returns
-    /// * `Ok(LeagueTeamRecord)` - The team's record against division opponents
-    /// * `Err(String)` - If team doesn't exist or has no division assignment
+    /// Simulate a winners bracket round
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::league::season::LeagueSeason;
     /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
     /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
     ///
     /// // Create a new season with 2 conferences
@@ -1746,178 +7064,162 @@ impl LeagueSeason {
     /// nfc.add_division(nfc_div);
     /// my_league_season.add_conference(nfc);
     ///
-    /// // Generate the season schedule and simulate the season
+    /// // Generate schedule and simulate regular season
     /// let mut rng = rand::thread_rng();
     /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
     /// my_league_season.sim_regular_season(&mut rng);
     ///
-    /// // Calculate team 2's division record
-    /// let record = my_league_season.division_record(2);
-    /// assert!(record.is_ok());
+    /// // Generate conference playoffs and simulate all conference brackets
+    /// let mut options = LeagueSeasonPlayoffOptions::new();
+    /// options.use_conference_brackets = true;
+    /// options.playoff_teams_per_conference = 2;
+    /// options.division_winners_guaranteed = true;
+    /// let _ = my_league_season.generate_playoffs(options, &mut rng);
+    /// let _ = my_league_season.sim_playoff_round(0, &mut rng);
+    ///
+    /// // Generate the winners bracket
+    /// let _ = my_league_season.generate_next_playoff_round(&mut rng);
+    ///
+    /// // Simulate the first winners bracket round
+    /// let res = my_league_season.sim_winners_bracket_round(0, &mut rng);
+    /// assert!(res.is_ok());
     /// ```
-    pub fn division_record(&self, team_id: usize) -> Result<LeagueTeamRecord, String> {
-        // Find team's division
-        let (conf_index, div_id) = self.team_division(team_id)
-            .ok_or_else(|| format!("Team {} has no division assignment", team_id))?;
-
-        // Get division teams
-        let division = self.conferences.get(conf_index)
-            .and_then(|c| c.division(div_id))
-            .ok_or_else(|| format!("Division not found for team {}", team_id))?;
-
-        let division_teams: HashSet<usize> = division.teams().iter().cloned().collect();
+    pub fn sim_winners_bracket_round(&mut self, round: usize, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        // Ensure the regular season is complete
+        if !self.regular_season_complete() {
+            return Err(LeagueError::RegularSeasonNotComplete);
+        }
 
-        // Compute record against division opponents
-        let mut record = LeagueTeamRecord::new();
+        // Ensure this is a conference playoff
+        if !self.playoffs.is_conference_playoff() {
+            return Err(LeagueError::NotConferencePlayoffs);
+        }
 
-        for week in &self.weeks {
-            for matchup in week.matchups() {
-                if !matchup.context().game_over() {
-                    continue;
-                }
+        // Ensure the winners bracket has started
+        if self.playoffs.winners_bracket().is_empty() {
+            return Err(LeagueError::PlayoffsNotGenerated);
+        }
 
-                let home = *matchup.home_team();
-                let away = *matchup.away_team();
+        // Check if the prior round is complete (if not the first round)
+        if round > 0 {
+            let prev_round = match self.playoffs.winners_bracket().get(round - 1) {
+                Some(r) => r,
+                None => return Err(LeagueError::WinnersBracketRoundNotFound { round: round - 1 })
+            };
+            if !prev_round.complete() {
+                return Err(LeagueError::WinnersBracketRoundNotComplete { round: round - 1 });
+            }
+        }
 
-                // Check if this is a division game involving our team
-                let is_our_game = home == team_id || away == team_id;
-                let opponent = if home == team_id { away } else { home };
-                let is_division_game = division_teams.contains(&opponent);
+        // Get the number of matchups in this round
+        let num_matchups = match self.playoffs.winners_bracket().get(round) {
+            Some(r) => r.matchups().len(),
+            None => return Err(LeagueError::PlayoffRoundNotFound { round })
+        };
 
-                if is_our_game && is_division_game {
-                    match matchup.result(team_id) {
-                        Some(FootballMatchupResult::Win) => {
-                            record.increment_wins(1);
-                        }
-                        Some(FootballMatchupResult::Loss) => {
-                            record.increment_losses(1);
-                        }
-                        Some(FootballMatchupResult::Tie) => {
-                            record.increment_ties(1);
-                        }
-                        None => {}
-                    }
-                }
+        // Simulate each matchup in the round
+        for i in 0..num_matchups {
+            // Skip matchups that have already been completed
+            let is_complete = match self.playoffs.winners_bracket().get(round) {
+                Some(r) => match r.matchups().get(i) {
+                    Some(m) => m.context().game_over(),
+                    None => continue
+                },
+                None => continue
+            };
+            if is_complete {
+                continue;
             }
-        }
 
-        Ok(record)
+            // Simulate the matchup
+            self.sim_winners_bracket_matchup(round, i, rng)?;
+        }
+        Ok(())
     }
 
-    /// Computes a team's record against conference opponents only
-    ///
-    /// ### Arguments
-    /// * `team_id` - The team ID to compute conference record for
-    ///
-    /// ### Returns
-    /// * `Ok(LeagueTeamRecord)` - The team's record against conference opponents
-    /// * `Err(String)` - If team doesn't exist or has no conference assignment
+    /// Simulate a full playoff round across all conference brackets
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::league::season::LeagueSeason;
     /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
     ///
-    /// // Create a new season with 2 conferences
+    /// // Create a new season
     /// let mut my_league_season = LeagueSeason::new();
     ///
-    /// // Add teams
+    /// // Add 4 teams to the season
     /// my_league_season.add_team(0, FootballTeam::new());
     /// my_league_season.add_team(1, FootballTeam::new());
     /// my_league_season.add_team(2, FootballTeam::new());
     /// my_league_season.add_team(3, FootballTeam::new());
     ///
-    /// // Create conference structure
-    /// let mut afc = LeagueConference::with_name("AFC");
-    /// let mut afc_div = LeagueDivision::with_name("East");
-    /// afc_div.add_team(0);
-    /// afc_div.add_team(1);
-    /// afc.add_division(afc_div);
-    /// my_league_season.add_conference(afc);
-    ///
-    /// let mut nfc = LeagueConference::with_name("NFC");
-    /// let mut nfc_div = LeagueDivision::with_name("East");
-    /// nfc_div.add_team(2);
-    /// nfc_div.add_team(3);
-    /// nfc.add_division(nfc_div);
-    /// my_league_season.add_conference(nfc);
-    ///
-    /// // Generate the season schedule and simulate the season
+    /// // Generate the season schedule
     /// let mut rng = rand::thread_rng();
     /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Simulate the entire regular season
     /// my_league_season.sim_regular_season(&mut rng);
     ///
-    /// // Calculate team 1's conference record
-    /// let record = my_league_season.conference_record(1);
-    /// assert!(record.is_ok());
+    /// // Generate playoffs with 4 teams
+    /// let mut playoff_options = LeagueSeasonPlayoffOptions::new();
+    /// playoff_options.num_playoff_teams = 4;
+    /// my_league_season.generate_playoffs(playoff_options, &mut rng);
+    ///
+    /// // Simulate the first playoff round
+    /// let res = my_league_season.sim_playoff_round(0, &mut rng);
+    /// assert!(res.is_ok());
     /// ```
-    pub fn conference_record(&self, team_id: usize) -> Result<LeagueTeamRecord, String> {
-        // Find team's conference
-        let conf_index = self.team_conference(team_id)
-            .ok_or_else(|| format!("Team {} has no conference assignment", team_id))?;
-
-        // Get conference teams
-        let conference = self.conferences.get(conf_index)
-            .ok_or_else(|| format!("Conference not found for team {}", team_id))?;
-
-        let conference_teams: HashSet<usize> = conference.all_teams().into_iter().collect();
-
-        // Compute record against conference opponents
-        let mut record = LeagueTeamRecord::new();
-
-        for week in &self.weeks {
-            for matchup in week.matchups() {
-                if !matchup.context().game_over() {
-                    continue;
-                }
+    pub fn sim_playoff_round(&mut self, round: usize, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        // Ensure the regular season is complete
+        if !self.regular_season_complete() {
+            return Err(LeagueError::RegularSeasonNotComplete);
+        }
 
-                let home = *matchup.home_team();
-                let away = *matchup.away_team();
+        // Ensure the playoffs have started
+        if self.playoffs.conference_brackets().is_empty() {
+            return Err(LeagueError::PlayoffsNotGenerated);
+        }
 
-                // Check if this is a conference game involving our team
-                let is_our_game = home == team_id || away == team_id;
-                let opponent = if home == team_id { away } else { home };
-                let is_conference_game = conference_teams.contains(&opponent);
+        // Simulate the round in each conference bracket
+        let conference_ids: Vec<usize> = self.playoffs.conference_brackets().keys().copied().collect();
+        for conf_id in conference_ids {
+            // Skip conferences that don't have this round
+            let has_round = self.playoffs.conference_bracket(conf_id)
+                .is_some_and(|b| b.len() > round);
+            if !has_round {
+                continue;
+            }
 
-                if is_our_game && is_conference_game {
-                    match matchup.result(team_id) {
-                        Some(FootballMatchupResult::Win) => {
-                            record.increment_wins(1);
-                        }
-                        Some(FootballMatchupResult::Loss) => {
-                            record.increment_losses(1);
-                        }
-                        Some(FootballMatchupResult::Tie) => {
-                            record.increment_ties(1);
-                        }
-                        None => {}
-                    }
-                }
+            // Skip conferences whose round is already complete
+            let round_complete = self.playoffs.conference_bracket(conf_id)
+                .and_then(|b| b.get(round))
+                .is_some_and(|r| r.complete());
+            if round_complete {
+                continue;
             }
-        }
 
-        Ok(record)
+            self.sim_playoff_conference_round(conf_id, round, rng)?;
+        }
+        Ok(())
     }
 
-    /// Generate the current playoff picture for the season
-    ///
-    /// ### Arguments
-    /// * `num_playoff_teams` - Number of teams that will make the playoffs
-    ///
-    /// ### Returns
-    /// * `Ok(PlayoffPicture)` - The current playoff picture
-    /// * `Err(String)` - If the season hasn't started or parameters are invalid
+    /// Simulate all remaining playoffs
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::league::season::LeagueSeason;
     /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
     ///
-    /// // Create a new season with 4 teams
+    /// // Create a new season
     /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Add 4 teams to the season
     /// my_league_season.add_team(0, FootballTeam::new());
     /// my_league_season.add_team(1, FootballTeam::new());
     /// my_league_season.add_team(2, FootballTeam::new());
@@ -1927,46 +7229,69 @@ impl LeagueSeason {
     /// let mut rng = rand::thread_rng();
     /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
     ///
-    /// // Get the playoff picture for a 2-team playoff
-    /// let picture = my_league_season.playoff_picture(2);
-    /// assert!(picture.is_ok());
-    /// ```
-    pub fn playoff_picture(&self, num_playoff_teams: usize) -> Result<playoffs::picture::PlayoffPicture, String> {
-        PlayoffPicture::from_season(self, num_playoff_teams, None)
-    }
-
-    /// Determine of a team participated in the playoffs
-    ///
-    /// ### Example
-    /// ```
-    /// use fbsim_core::team::FootballTeam;
-    /// use fbsim_core::league::season::LeagueSeason;
+    /// // Simulate the entire regular season
+    /// my_league_season.sim_regular_season(&mut rng);
     ///
-    /// // Create a season and add a team
-    /// let mut my_league_season = LeagueSeason::new();
-    /// my_league_season.add_team(0, FootballTeam::new());
+    /// // Generate playoffs with 4 teams
+    /// let mut playoff_options = LeagueSeasonPlayoffOptions::new();
+    /// playoff_options.num_playoff_teams = 4;
+    /// my_league_season.generate_playoffs(playoff_options, &mut rng);
     ///
-    /// // Check if that team is in the playoffs
-    /// let in_playoffs = my_league_season.team_in_playoffs(0);
-    /// assert!(in_playoffs.is_ok());
-    /// assert!(!in_playoffs.unwrap());
+    /// // Simulate all playoffs
+    /// let res = my_league_season.sim_playoffs(&mut rng);
+    /// assert!(res.is_ok());
     /// ```
-    pub fn team_in_playoffs(&self, team_id: usize) -> Result<bool, String> {
-        if !self.team_exists(team_id) {
-            return Err(format!("No season team with ID: {}", team_id));
+    pub fn sim_playoffs(&mut self, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        // Ensure the regular season is complete
+        if !self.regular_season_complete() {
+            return Err(LeagueError::RegularSeasonNotComplete);
         }
-        Ok(self.playoffs.team_in_playoffs(team_id))
+
+        // Ensure the playoffs have started
+        if self.playoffs.conference_brackets().is_empty() {
+            return Err(LeagueError::PlayoffsNotGenerated);
+        }
+
+        // Simulate rounds until playoffs are complete
+        while !self.playoffs.complete() {
+            // If conference brackets are not yet complete, simulate them
+            if !self.playoffs.conference_brackets_complete() {
+                // Get the current round index (minimum across all brackets)
+                let current_round = self.playoffs
+                    .conference_brackets().values().map(|b| b.len().saturating_sub(1))
+                    .min()
+                    .unwrap_or_default();
+
+                // Simulate the current round across all conferences
+                self.sim_playoff_round(current_round, rng)?;
+
+                // Generate the next round if needed and playoffs aren't done
+                if !self.playoffs.complete() {
+                    self.generate_next_playoff_round(rng)?;
+                }
+            } else if self.playoffs.is_conference_playoff() {
+                // Conference brackets complete, simulate winners bracket
+                let current_round = self.playoffs.winners_bracket().len().saturating_sub(1);
+
+                self.sim_winners_bracket_round(current_round, rng)?;
+
+                // Generate the next winners bracket round if needed
+                if !self.playoffs.complete() {
+                    self.generate_next_playoff_round(rng)?;
+                }
+            }
+        }
+        Ok(())
     }
 
-    /// Compute a team's playoff record
+    /// Simulate the next play of a season matchup
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::league::season::LeagueSeason;
     /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
-    /// use fbsim_core::league::matchup::LeagueTeamRecord;
     ///
     /// // Create a new season
     /// let mut my_league_season = LeagueSeason::new();
@@ -1981,87 +7306,290 @@ impl LeagueSeason {
     /// let mut rng = rand::thread_rng();
     /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
     ///
-    /// // Simulate the entire regular season
-    /// my_league_season.sim_regular_season(&mut rng);
-    ///
-    /// // Generate playoffs with 4 teams
-    /// let mut playoff_options = LeagueSeasonPlayoffOptions::new();
-    /// playoff_options.num_playoff_teams = 4;
-    /// my_league_season.generate_playoffs(playoff_options, &mut rng);
-    ///
-    /// // Simulate the playoffs
-    /// my_league_season.sim_playoffs(&mut rng);
-    ///
-    /// // Compute a team's playoff record
-    /// let record = my_league_season.playoff_record(0);
-    /// assert!(record.is_ok());
+    /// // Simulate the first game of the first week
+    /// let res = my_league_season.sim_play(0, 0, &mut rng);
+    /// assert!(res.is_ok());
     /// ```
-    pub fn playoff_record(&self, team_id: usize) -> Result<LeagueTeamRecord, String> {
-        if self.team_in_playoffs(team_id)? {
-            self.playoffs.record(team_id)
-        } else {
-            // Technically should be unreachable
-            Ok(LeagueTeamRecord::new())
+    pub fn sim_play(&mut self, week: usize, matchup: usize, rng: &mut impl Rng) -> Result<Option<Game>, LeagueError> {
+        self.fingerprint.invalidate();
+        // Check if the prior week is not complete
+        if week > 0 {
+            let prev_week = match self.weeks.get(week - 1) {
+                Some(w) => w,
+                None => return Err(LeagueError::WeekOutOfRange { week })
+            };
+            if !prev_week.complete() {
+                return Err(LeagueError::WeekNotComplete { week: week - 1 });
+            }
+        }
+
+        // Try to get the given week
+        let mut _week_to_sim = match self.weeks.get_mut(week) {
+            Some(w) => w,
+            None => return Err(LeagueError::WeekOutOfRange { week }),
+        };
+
+        // Try to get the given matchup
+        let mut _matchup_to_sim = match _week_to_sim.matchups_mut().get_mut(matchup) {
+            Some(m) => m,
+            None => return Err(LeagueError::MatchupNotFound { week, matchup }),
+        };
+
+        // Ensure the matchup is not already complete
+        if _matchup_to_sim.context().is_terminal() {
+            return Err(LeagueError::MatchupAlreadyComplete { week, matchup });
+        }
+
+        // Try to get the home team for the matchup
+        let home_id = _matchup_to_sim.home_team();
+        let home_team = match self.teams.get(home_id) {
+            Some(t) => t,
+            None => return Err(LeagueError::TeamNotFound { id: *home_id })
+        };
+
+        // Try to get the away team for the matchup
+        let away_id = _matchup_to_sim.away_team();
+        let away_team = match self.teams.get(away_id) {
+            Some(t) => t,
+            None => return Err(LeagueError::TeamNotFound { id: *away_id })
+        };
+
+        // Create a new game if game has not started, or get existing game
+        if _matchup_to_sim.game().is_none() {
+            *_matchup_to_sim.game_mut() = Some(Game::new());
+        }
+
+        // Simulate the next play
+        let simulator = GameSimulator::new();
+        let context = match simulator.sim_play(
+            home_team, away_team,
+            _matchup_to_sim.sim_context_with_weather(OvertimeRules::regular_season(), self.weather_distribution.as_ref(), rng),
+            _matchup_to_sim.game_mut().as_mut().unwrap(),
+            rng
+        ) {
+            Ok(c) => c,
+            Err(e) => return Err(LeagueError::Simulation { reason: e.to_string() })
+        };
+
+        // If game is over, archive game stats, clear game, update context
+        if context.game_over() {
+            let game = _matchup_to_sim.take_game().ok_or(
+                "Failed to archive stats for game"
+            )?;
+            _matchup_to_sim.finalize(context, &game)?;
+            self.record_attendance();
+            return Ok(Some(game));
         }
+        *_matchup_to_sim.context_mut() = context;
+        Ok(None)
     }
 
-    /// Check if a team made it to the championship game this season
+    /// Simulate a season matchup play by play, returning the `Game` with
+    /// each of its drives and plays
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
     ///
-    /// // Create a season and add a team
+    /// // Create a new season
     /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Add 4 teams to the season
     /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
     ///
-    /// // Determine if that team is in the shampionship
-    /// let in_championship = my_league_season.team_in_championship(0);
-    /// assert!(in_championship.is_ok());
-    /// assert!(!in_championship.unwrap());
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Simulate the first game of the first week
+    /// let game = my_league_season.sim_matchup(0, 0, &mut rng).unwrap();
+    /// assert!(!game.drives().is_empty());
     /// ```
-    pub fn team_in_championship(&self, team_id: usize) -> Result<bool, String> {
-        if self.team_in_playoffs(team_id)? {
-            self.playoffs.in_championship(team_id)
-        } else {
-            Ok(false)
+    pub fn sim_matchup(&mut self, week: usize, matchup: usize, rng: &mut impl Rng) -> Result<Game, LeagueError> {
+        self.fingerprint.invalidate();
+        // Check if the prior week is not complete
+        if week > 0 {
+            let prev_week = match self.weeks.get(week - 1) {
+                Some(w) => w,
+                None => return Err(LeagueError::WeekOutOfRange { week })
+            };
+            if !prev_week.complete() {
+                return Err(LeagueError::WeekNotComplete { week: week - 1 });
+            }
+        }
+        
+        // Try to get the given week
+        let _week_to_sim = match self.weeks.get(week) {
+            Some(w) => w,
+            None => return Err(LeagueError::WeekOutOfRange { week }),
+        };
+
+        // Try to get the given matchup
+        let _matchup_to_sim = match _week_to_sim.matchups().get(matchup) {
+            Some(m) => m,
+            None => return Err(LeagueError::MatchupNotFound { week, matchup }),
+        };
+
+        // Ensure the matchup is not already complete
+        if _matchup_to_sim.context().is_terminal() {
+            return Err(LeagueError::MatchupAlreadyComplete { week, matchup });
         }
+
+        // Simulate the matchup and record its attendance
+        let game = self.sim_scheduled_matchup(week, matchup, rng)?;
+        self.record_attendance();
+        Ok(game)
     }
 
-    /// Check if a team won the championship this season
+    /// Simulate a season matchup to completion without checking whether it
+    /// may be simulated, then archive its stats and update its context
+    fn sim_scheduled_matchup(&mut self, week: usize, matchup: usize, rng: &mut impl Rng) -> Result<Game, LeagueError> {
+        // A seeded season simulates each game with its own derived RNG
+        let mut seeded_rng = self.sim_seed
+            .map(|seed| SmallRng::seed_from_u64(derive_matchup_seed(seed, self.year, week, matchup)));
+        let mut rng: &mut dyn RngCore = match seeded_rng.as_mut() {
+            Some(seeded) => seeded,
+            None => rng
+        };
+
+        // Try to get the given matchup
+        let _matchup_to_sim = match self.weeks.get_mut(week).and_then(|w| w.matchups_mut().get_mut(matchup)) {
+            Some(m) => m,
+            None => return Err(LeagueError::MatchupNotFound { week, matchup }),
+        };
+
+        // Try to get the home team for the matchup
+        let home_id = _matchup_to_sim.home_team();
+        let home_team = match self.teams.get(home_id) {
+            Some(t) => t,
+            None => return Err(LeagueError::TeamNotFound { id: *home_id })
+        };
+
+        // Try to get the away team for the matchup
+        let away_id = _matchup_to_sim.away_team();
+        let away_team = match self.teams.get(away_id) {
+            Some(t) => t,
+            None => return Err(LeagueError::TeamNotFound { id: *away_id })
+        };
+
+        // Simulate the matchup
+        let mut game = Game::new();
+        let simulator = GameSimulator::new();
+        let context = match simulator.sim_game(
+            home_team, away_team,
+            _matchup_to_sim.sim_context_with_weather(OvertimeRules::regular_season(), self.weather_distribution.as_ref(), &mut rng),
+            &mut game, &mut rng
+        ) {
+            Ok(c) => c,
+            Err(e) => return Err(LeagueError::Simulation { reason: e.to_string() })
+        };
+
+        // Archive the game stats, clear the game, update the context
+        _matchup_to_sim.finalize(context, &game)?;
+        Ok(game)
+    }
+
+    /// Simulate a subset of a week's matchups in the given order
+    ///
+    /// Each matchup is simulated with its own RNG, so its result does not
+    /// depend on the order of the matchups or on how a week is split across
+    /// calls. A seeded season uses the RNG of `matchup_seed`, exactly as
+    /// `sim_week` does, so simulating a week in parts reproduces it.
+    ///
+    /// ### Arguments
+    /// * `week` - The index of the week containing the matchups
+    /// * `matchups` - The matchup indices to simulate, in simulation order
+    /// * `skip_complete` - Whether to skip already-complete matchups rather than error
+    /// * `rng` - The random number generator each matchup's seed is drawn from, if the season has no simulation seed
+    ///
+    /// ### Returns
+    /// One entry per given index: the simulated game, or `None` if the
+    /// matchup was already complete and skipped
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
     ///
-    /// // Create a season and add a team
+    /// // Create a new season
     /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Add 4 teams to the season
     /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
     ///
-    /// // Determine if that team won the shampionship
-    /// let won_championship = my_league_season.team_won_championship(0);
-    /// assert!(won_championship.is_ok());
-    /// assert!(!won_championship.unwrap());
+    /// // Simulate the second game of the first week, then the first
+    /// let games = my_league_season.sim_matchups(0, &[1, 0], false, &mut rng).unwrap();
+    /// assert_eq!(games.len(), 2);
+    /// assert!(my_league_season.weeks()[0].complete());
     /// ```
-    pub fn team_won_championship(&self, team_id: usize) -> Result<bool, String> {
-        if self.team_in_playoffs(team_id)? {
-            if let Some(champion_id) = self.playoffs.champion() {
-                return Ok(champion_id == team_id);
+    pub fn sim_matchups(&mut self, week: usize, matchups: &[usize], skip_complete: bool, rng: &mut impl Rng) -> Result<Vec<Option<Game>>, LeagueError> {
+        self.fingerprint.invalidate();
+        // Try to get the given week
+        let week_to_sim = match self.weeks.get(week) {
+            Some(w) => w,
+            None => return Err(LeagueError::WeekOutOfRange { week }),
+        };
+
+        // Validate all matchup indices before simulating any of them
+        let mut seen: HashSet<usize> = HashSet::new();
+        for matchup in matchups.iter() {
+            let matchup_to_sim = match week_to_sim.matchups().get(*matchup) {
+                Some(m) => m,
+                None => return Err(LeagueError::MatchupNotFound { week, matchup: *matchup }),
+            };
+            if !seen.insert(*matchup) {
+                return Err(LeagueError::DuplicateMatchup { week, matchup: *matchup });
+            }
+            if !skip_complete && matchup_to_sim.context().is_terminal() {
+                return Err(LeagueError::MatchupAlreadyComplete { week, matchup: *matchup });
+            }
+        }
+
+        // Simulate each matchup in the given order with its own RNG, derived
+        // from a seed drawn once from the caller's RNG if the season has no
+        // simulation seed of its own
+        let unseeded_base: Option<u64> = match self.sim_seed {
+            Some(_) => None,
+            None => Some(rng.gen())
+        };
+        let mut games: Vec<Option<Game>> = Vec::new();
+        for matchup in matchups.iter() {
+            if self.weeks[week].matchups()[*matchup].context().is_terminal() {
+                games.push(None);
+                continue;
             }
+            let seed = match unseeded_base {
+                Some(base) => derive_matchup_seed(base, self.year, week, *matchup),
+                None => self.matchup_seed(week, *matchup)?
+            };
+            let mut matchup_rng = SmallRng::seed_from_u64(seed);
+            games.push(Some(self.sim_matchup(week, *matchup, &mut matchup_rng)?));
         }
-        Ok(false)
+        Ok(games)
     }
 
-    /// Generate the playoffs
+    /// Simulate a full week of season matchups, attempting every matchup
+    /// even if some fail. Returns a report of each attempted matchup's
+    /// outcome so the failures can be retried with `sim_matchups`.
     ///
-    /// ### Example (single bracket)
+    /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::league::season::LeagueSeason;
     /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
     ///
     /// // Create a new season
     /// let mut my_league_season = LeagueSeason::new();
@@ -2076,216 +7604,343 @@ impl LeagueSeason {
     /// let mut rng = rand::thread_rng();
     /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
     ///
-    /// // Simulate the entire regular season
-    /// my_league_season.sim_regular_season(&mut rng);
-    ///
-    /// // Generate playoffs with 4 teams
-    /// let mut options = LeagueSeasonPlayoffOptions::new();
-    /// options.num_playoff_teams = 4;
-    /// let res = my_league_season.generate_playoffs(options, &mut rng);
-    /// assert!(res.is_ok());
+    /// // Simulate the first week of the season
+    /// let report = my_league_season.sim_week(0, &mut rng).unwrap();
+    /// assert!(report.succeeded());
+    /// assert_eq!(report.matchups.len(), 2);
     /// ```
+    pub fn sim_week(&mut self, week: usize, rng: &mut impl Rng) -> Result<WeekSimReport, LeagueError> {
+        self.sim_week_with_options(week, WeekSimOptions::new(), rng)
+    }
+
+    /// Simulate a full week of season matchups with the given options. If
+    /// the options abort on error, the first matchup which fails to simulate
+    /// is returned as an error, leaving the matchups simulated before it
+    /// complete.
     ///
-    /// ### Example (conference brackets)
+    /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::league::season::LeagueSeason;
     /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
-    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    /// use fbsim_core::league::season::week::WeekSimOptions;
     ///
-    /// // Create a new season with 2 conferences
+    /// // Create a new season
     /// let mut my_league_season = LeagueSeason::new();
     ///
-    /// // Add teams
+    /// // Add 4 teams to the season
     /// my_league_season.add_team(0, FootballTeam::new());
     /// my_league_season.add_team(1, FootballTeam::new());
     /// my_league_season.add_team(2, FootballTeam::new());
     /// my_league_season.add_team(3, FootballTeam::new());
     ///
-    /// // Create conference structure
-    /// let mut afc = LeagueConference::with_name("AFC");
-    /// let mut afc_div = LeagueDivision::with_name("East");
-    /// afc_div.add_team(0);
-    /// afc_div.add_team(1);
-    /// afc.add_division(afc_div);
-    /// my_league_season.add_conference(afc);
-    ///
-    /// let mut nfc = LeagueConference::with_name("NFC");
-    /// let mut nfc_div = LeagueDivision::with_name("East");
-    /// nfc_div.add_team(2);
-    /// nfc_div.add_team(3);
-    /// nfc.add_division(nfc_div);
-    /// my_league_season.add_conference(nfc);
-    ///
-    /// // Generate schedule and simulate
+    /// // Generate the season schedule
     /// let mut rng = rand::thread_rng();
     /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
-    /// my_league_season.sim_regular_season(&mut rng);
     ///
-    /// // Generate conference playoffs (2 teams per conference)
-    /// let mut options = LeagueSeasonPlayoffOptions::new();
-    /// options.use_conference_brackets = true;
-    /// options.playoff_teams_per_conference = 2;
-    /// options.division_winners_guaranteed = true;
-    /// let res = my_league_season.generate_playoffs(options, &mut rng);
-    /// assert!(res.is_ok());
+    /// // Simulate the first week of the season, stopping at any failure
+    /// let options = WeekSimOptions::new().abort_on_error(true);
+    /// let report = my_league_season.sim_week_with_options(0, options, &mut rng).unwrap();
+    /// assert!(report.succeeded());
     /// ```
-    pub fn generate_playoffs(&mut self, options: LeagueSeasonPlayoffOptions, rng: &mut impl Rng) -> Result<(), String> {
-        // Ensure the regular season is complete
-        if !self.regular_season_complete() {
-            return Err(String::from("Cannot generate playoffs: Regular season is not complete"));
-        }
-
-        // Ensure the playoffs have not already started
-        if self.playoffs.started() {
-            return Err(String::from("Cannot generate playoffs: Playoffs have already started"));
+    pub fn sim_week_with_options(&mut self, week: usize, options: WeekSimOptions, rng: &mut impl Rng) -> Result<WeekSimReport, LeagueError> {
+        self.fingerprint.invalidate();
+        // Check if the prior week is not complete
+        if week > 0 {
+            let prev_week = match self.weeks.get(week - 1) {
+                Some(w) => w,
+                None => return Err(LeagueError::WeekOutOfRange { week })
+            };
+            if !prev_week.complete() {
+                return Err(LeagueError::WeekNotComplete { week: week - 1 });
+            }
         }
 
-        // Determine whether to use conference brackets
-        let use_conferences = options.use_conference_brackets && self.conferences.len() > 1;
-
-        // Reset the playoffs
-        self.playoffs = LeagueSeasonPlayoffs::new();
+        // Try to get the given week
+        let _week_to_sim = match self.weeks.get(week) {
+            Some(w) => w,
+            None => return Err(LeagueError::WeekOutOfRange { week }),
+        };
 
-        if use_conferences {
-            // Multi-conference path
-            let playoff_teams_per_conference = options.playoff_teams_per_conference;
-            let division_winners_guaranteed = options.division_winners_guaranteed;
+        // Check if the current week is complete
+        if _week_to_sim.complete() {
+            return Err(LeagueError::WeekAlreadyComplete { week });
+        }
 
-            // Validate teams per conference
-            if playoff_teams_per_conference < 1 {
-                return Err("Playoffs must have at least 1 team per conference".to_string());
-            }
+        // Snapshot the standings and playoff picture entering the week, only
+        // if the notification rules need them
+        let rules = options.notifications.unwrap_or_default();
+        let ranks = if rules.needs_standings() {
+            StandingsRanks::new(&self.standings())
+        } else {
+            StandingsRanks::default()
+        };
+        let picture_before = match rules.playoff_teams {
+            Some(n) => Some(self.playoff_picture(n)?),
+            None => None
+        };
 
-            // Validate that each conference has an equal number of teams
-            let conf_sizes: Vec<usize> = self.conferences.iter()
-                .map(|c| c.num_teams())
-                .collect();
-            if let Some(&first) = conf_sizes.first() {
-                if conf_sizes.iter().any(|&s| s != first) {
-                    return Err(format!(
-                        "Conferences have unequal number of teams: {:?}",
-                        conf_sizes
-                    ));
-                }
+        // Attempt each of the week's incomplete matchups
+        let mut report = WeekSimReport {
+            week,
+            matchups: Vec::new(),
+            notifications: Vec::new(),
+            play_logs: BTreeMap::new()
+        };
+        for i in 0..self.weeks[week].matchups().len() {
+            // Skip matchups that have already been completed
+            let matchup = &self.weeks[week].matchups()[i];
+            if matchup.context().is_terminal() {
+                continue
             }
-
-            // Process each conference
-            for (conf_index, conference) in self.conferences.iter().enumerate() {
-                // Validate that conference has enough teams
-                let conf_team_count = conference.num_teams();
-                if playoff_teams_per_conference > conf_team_count {
-                    return Err(format!(
-                        "Cannot have {} playoff teams in conference {} which only has {} teams",
-                        playoff_teams_per_conference,
-                        conference.name(),
-                        conf_team_count
-                    ));
-                }
-
-                // Get conference standings
-                let conf_standings = self.conference_standings(conf_index)?;
-
-                // Determine division winners if guaranteed spots
-                let mut division_winners: Vec<usize> = Vec::new();
-                if division_winners_guaranteed {
-                    for (div_id, _) in conference.divisions().iter().enumerate() {
-                        let div_standings = self.division_standings(conf_index, div_id)?;
-                        if let Some((winner_id, _)) = div_standings.first() {
-                            division_winners.push(*winner_id);
-                        }
-                    }
-                }
-
-                // Build playoff teams for this conference
-                let mut conf_playoff_teams: Vec<usize> = Vec::new();
-
-                // First, add division winners (in order of their conference standing)
-                let mut sorted_div_winners: Vec<(usize, usize)> = division_winners
-                    .iter()
-                    .filter_map(|&winner_id| {
-                        conf_standings
-                            .iter()
-                            .position(|(id, _)| *id == winner_id)
-                            .map(|pos| (pos, winner_id))
-                    })
-                    .collect();
-                sorted_div_winners.sort_by_key(|(pos, _)| *pos);
-
-                for (_, winner_id) in sorted_div_winners {
-                    if conf_playoff_teams.len() < playoff_teams_per_conference {
-                        conf_playoff_teams.push(winner_id);
+            let home_team = *matchup.home_team();
+            let away_team = *matchup.away_team();
+
+            // Simulate the matchup, recording its score or failure
+            let outcome = match self.sim_scheduled_matchup(week, i, rng) {
+                Ok(game) => {
+                    let context = self.weeks[week].matchups()[i].context();
+                    let home_score = context.home_score();
+                    let away_score = context.away_score();
+                    let notification_game = NotificationGame {
+                        home_team,
+                        away_team,
+                        home_score,
+                        away_score,
+                        winner_deficit: Some(game.largest_deficit(home_score > away_score))
+                    };
+                    for kind in rules.evaluate_game(&notification_game, &ranks) {
+                        report.notifications.push(Notification { week, matchup: Some(i), kind });
                     }
-                }
-
-                // Fill remaining spots with wild cards (best records not already in)
-                for (team_id, _record) in conf_standings.iter() {
-                    if conf_playoff_teams.len() >= playoff_teams_per_conference {
-                        break;
+                    if options.play_logs {
+                        report.play_logs.insert(i, game);
                     }
-                    if !conf_playoff_teams.contains(team_id) {
-                        conf_playoff_teams.push(*team_id);
+                    MatchupSimOutcome::Completed { home_score, away_score }
+                },
+                Err(error) => {
+                    if options.abort_on_error {
+                        self.record_attendance();
+                        return Err(error);
                     }
+                    MatchupSimOutcome::Failed { reason: error.to_string() }
                 }
-
-                // Add teams to conference bracket
-                for team_id in conf_playoff_teams {
-                    let team = self.teams.get(&team_id)
-                        .ok_or_else(|| format!("Team {} not found", team_id))?;
-                    let short_name = team.short_name();
-                    self.playoffs.add_team(team_id, short_name, Some(conf_index))?;
+            };
+            report.matchups.push(
+                MatchupSimReport {
+                    matchup: i,
+                    home_team,
+                    away_team,
+                    outcome
                 }
+            );
+        }
+
+        // Repair any matchup edited in place before the week was simulated
+        for (index, matchup) in self.weeks[week].matchups_mut().iter_mut().enumerate() {
+            if let Err(e) = matchup.reconcile() {
+                return Err(LeagueError::InconsistentMatchup { week, matchup: index, reason: e.to_string() });
             }
-        } else {
-            // Single-bracket path
-            let num_playoff_teams = options.num_playoff_teams;
+        }
+        self.record_attendance();
 
-            // Validate the number of playoff teams
-            if num_playoff_teams < 2 {
-                return Err(format!("Playoffs must have at least 2 teams, got {}", num_playoff_teams));
+        // Report playoff clinches and eliminations decided this week
+        if let (Some(before), Some(n)) = (picture_before, rules.playoff_teams) {
+            let after = self.playoff_picture(n)?;
+            for kind in playoff_changes(&before, &after) {
+                report.notifications.push(Notification { week, matchup: None, kind });
             }
-            if num_playoff_teams > self.teams.len() {
-                return Err(format!(
-                    "Cannot have more playoff teams ({}) than season teams ({})",
-                    num_playoff_teams, self.teams.len()
-                ));
+        }
+        Ok(report)
+    }
+
+    /// Predict the final scores of a week's unplayed matchups without
+    /// modifying the season
+    ///
+    /// ### Arguments
+    /// * `week` - The index of the week to predict
+    /// * `force` - Whether to predict the week even if the previous week is not complete
+    /// * `rng` - The random number generator used to simulate the matchups
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// // Create a new season
+    /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Add 4 teams to the season
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Predict the first week of the season
+    /// let predictions = my_league_season.predict_week(0, false, &mut rng).unwrap();
+    /// assert_eq!(predictions.len(), 2);
+    /// assert!(!my_league_season.weeks()[0].complete());
+    ///
+    /// // Predicting the second week requires forcing it
+    /// assert!(my_league_season.predict_week(1, false, &mut rng).is_err());
+    /// assert!(my_league_season.predict_week(1, true, &mut rng).is_ok());
+    /// ```
+    pub fn predict_week(&self, week: usize, force: bool, rng: &mut impl Rng) -> Result<Vec<PredictedResult>, LeagueError> {
+        // Check if the prior week is not complete
+        if week > 0 && !force {
+            let prev_week = match self.weeks.get(week - 1) {
+                Some(w) => w,
+                None => return Err(LeagueError::WeekOutOfRange { week })
+            };
+            if !prev_week.complete() {
+                return Err(LeagueError::WeekNotComplete { week: week - 1 });
             }
+        }
 
-            // Get the standings and select the top teams
-            let standings = self.standings();
+        // Try to get the given week
+        let week_to_predict = match self.weeks.get(week) {
+            Some(w) => w,
+            None => return Err(LeagueError::WeekOutOfRange { week }),
+        };
 
-            // Add the top teams to the playoffs in seed order
-            for (i, (team_id, _)) in standings.iter().enumerate() {
-                if i >= num_playoff_teams {
-                    break;
+        // Simulate each unplayed matchup from a copy of its context
+        let simulator = GameSimulator::new();
+        let mut predictions: Vec<PredictedResult> = Vec::new();
+        for (i, matchup) in week_to_predict.matchups().iter().enumerate() {
+            if matchup.context().is_terminal() {
+                continue
+            }
+
+            // Try to get the home team for the matchup
+            let home_id = matchup.home_team();
+            let home_team = match self.teams.get(home_id) {
+                Some(t) => t,
+                None => return Err(LeagueError::TeamNotFound { id: *home_id })
+            };
+
+            // Try to get the away team for the matchup
+            let away_id = matchup.away_team();
+            let away_team = match self.teams.get(away_id) {
+                Some(t) => t,
+                None => return Err(LeagueError::TeamNotFound { id: *away_id })
+            };
+
+            // Simulate the matchup
+            let mut game = Game::new();
+            let context = match simulator.sim_game(
+                home_team, away_team,
+                matchup.sim_context_with_weather(OvertimeRules::regular_season(), self.weather_distribution.as_ref(), rng),
+                &mut game, rng
+            ) {
+                Ok(c) => c,
+                Err(e) => return Err(LeagueError::Simulation { reason: e.to_string() })
+            };
+            predictions.push(
+                PredictedResult {
+                    week,
+                    matchup: i,
+                    home_team: *home_id,
+                    away_team: *away_id,
+                    home_score: context.home_score(),
+                    away_score: context.away_score()
                 }
+            );
+        }
+        Ok(predictions)
+    }
 
-                // Get the team's short name for the playoff bracket
-                let team = match self.teams.get(team_id) {
-                    Some(t) => t,
-                    None => return Err(format!("Team {} not found in season", team_id))
-                };
-                let short_name = team.short_name();
+    /// Score predictions of a week against its completed matchups
+    ///
+    /// ### Arguments
+    /// * `week` - The index of the week the predictions are for
+    /// * `predictions` - The predictions to score, each of a completed matchup in the week
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// // Create a new season
+    /// let mut my_league_season = LeagueSeason::new();
+    ///
+    /// // Add 4 teams to the season
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Predict the first week, then simulate it and score the predictions
+    /// let predictions = my_league_season.predict_week(0, false, &mut rng).unwrap();
+    /// my_league_season.sim_week(0, &mut rng);
+    /// let accuracy = my_league_season.score_predictions(0, &predictions).unwrap();
+    /// assert_eq!(accuracy.predictions, 2);
+    /// assert!(accuracy.correct_winners <= 2);
+    /// ```
+    pub fn score_predictions(&self, week: usize, predictions: &[PredictedResult]) -> Result<PredictionAccuracy, LeagueError> {
+        // Try to get the given week
+        let week_to_score = match self.weeks.get(week) {
+            Some(w) => w,
+            None => return Err(LeagueError::WeekOutOfRange { week }),
+        };
 
-                // Add the team to the playoffs
-                self.playoffs.add_team(*team_id, short_name, None)?;
+        // Compare each prediction to its completed matchup
+        let mut accuracy = PredictionAccuracy::default();
+        let mut total_error: u64 = 0;
+        for prediction in predictions.iter() {
+            if prediction.week != week {
+                return Err(
+                    format!(
+                        "Prediction for week {} cannot be scored against season {} week {}",
+                        prediction.week, self.year, week
+                    ).into()
+                );
+            }
+            let matchup = match week_to_score.matchups().get(prediction.matchup) {
+                Some(m) => m,
+                None => return Err(LeagueError::MatchupNotFound { week, matchup: prediction.matchup }),
+            };
+            if *matchup.home_team() != prediction.home_team || *matchup.away_team() != prediction.away_team {
+                return Err(
+                    format!(
+                        "Prediction of {} at {} does not match season {} week {} matchup {}",
+                        prediction.away_team, prediction.home_team, self.year, week, prediction.matchup
+                    ).into()
+                );
+            }
+            if !matchup.context().game_over() {
+                return Err(LeagueError::MatchupNotComplete { week, matchup: prediction.matchup });
             }
-        }
 
-        // Generate the first round
-        self.playoffs.gen_next_playoff_round(rng)?;
-        Ok(())
+            // Accumulate the correct winners and score errors
+            if matchup.winner() == prediction.winner() {
+                accuracy.correct_winners += 1;
+            }
+            total_error += matchup.context().home_score().abs_diff(prediction.home_score) as u64;
+            total_error += matchup.context().away_score().abs_diff(prediction.away_score) as u64;
+            accuracy.predictions += 1;
+        }
+        if accuracy.predictions > 0 {
+            accuracy.average_score_error = total_error as f64 / (2 * accuracy.predictions) as f64;
+        }
+        Ok(accuracy)
     }
 
-    /// Generate the next playoff round
+    /// Simulate the regular season
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::league::season::LeagueSeason;
     /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
     ///
     /// // Create a new season
     /// let mut my_league_season = LeagueSeason::new();
@@ -2300,65 +7955,52 @@ impl LeagueSeason {
     /// let mut rng = rand::thread_rng();
     /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
     ///
-    /// // Simulate the entire regular season
+    /// // Simulate the entire season
     /// my_league_season.sim_regular_season(&mut rng);
-    ///
-    /// // Generate playoffs with 4 teams, simulate the first round
-    /// let mut playoff_options = LeagueSeasonPlayoffOptions::new();
-    /// playoff_options.num_playoff_teams = 4;
-    /// let _ = my_league_season.generate_playoffs(playoff_options, &mut rng);
-    /// let _ = my_league_season.sim_playoff_round(0, &mut rng);
-    ///
-    /// // Generate the next round of the playoffs
-    /// let res = my_league_season.generate_next_playoff_round(&mut rng);
-    /// assert!(res.is_ok());
     /// ```
-    pub fn generate_next_playoff_round(&mut self, rng: &mut impl Rng) -> Result<(), String> {
-        // Ensure the playoffs have started, are set up, are not complete
-        if !self.regular_season_complete() {
-            return Err(String::from("Cannot generate playoff round: Regular season is not complete"));
-        }
-        if self.playoffs.num_teams() < 2 {
-            return Err(String::from("Cannot generate playoff round: Playoffs have not been initialized"));
-        }
-        if self.playoffs.complete() {
-            return Err(String::from("Cannot generate playoff round: Playoffs are already complete"));
-        }
-
-        // Ensure all current rounds across all conference brackets are complete
-        for conference_rounds in self.playoffs.conference_brackets().values() {
-            if let Some(current_round) = conference_rounds.last() {
-                if !current_round.complete() {
-                    return Err(String::from("Cannot generate next round: Current round is not complete"));
-                }
+    pub fn sim_regular_season(&mut self, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        for i in 0..self.weeks.len() {
+            // Skip weeks which have already completed
+            let week = match self.weeks.get(i) {
+                Some(w) => w,
+                None => return Err(
+                    LeagueError::WeekOutOfRange { week: i }
+                ),
+            };
+            if week.complete() {
+                continue;
             }
-        }
 
-        // Ensure the current winners bracket round is complete
-        if self.playoffs.is_conference_playoff() && self.playoffs.conference_brackets_complete() {
-            if let Some(round) = self.playoffs.winners_bracket().last() {
-                if !round.complete() {
-                    return Err(
-                        String::from(
-                            "Cannot generate next round: Current winners bracket round is not complete"
-                        )
-                    )
-                }
+            // Simulate the week, failing if any of its matchups failed
+            let report = self.sim_week(i, rng)?;
+            if let Some(error) = report.error() {
+                return Err(
+                    LeagueError::Simulation {
+                        reason: format!("Season {} week {}: {}", self.year, i, error)
+                    }
+                );
             }
         }
-
-        // Generate the next round
-        self.playoffs.gen_next_playoff_round(rng)
+        Ok(())
     }
 
-    /// Simulate a playoff matchup in a specific conference bracket
+    /// Simulate the regular season weeks from `from` up to but not including
+    /// `to`, skipping weeks which have already completed, so that a caller
+    /// may simulate the season in chunks. Each week is simulated in full
+    /// before the next begins, so stopping between chunks leaves the season
+    /// as it would be partway through `sim_regular_season`.
+    ///
+    /// ### Returns
+    /// * `Ok(Vec<WeekSimReport>)` - The report of each week simulated
+    /// * `Err(LeagueError)` - If the range is invalid, an earlier week is
+    ///   incomplete, or a matchup failed to simulate
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::league::season::LeagueSeason;
     /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
     ///
     /// // Create a new season
     /// let mut my_league_season = LeagueSeason::new();
@@ -2373,1391 +8015,3343 @@ impl LeagueSeason {
     /// let mut rng = rand::thread_rng();
     /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
     ///
-    /// // Simulate the entire regular season
-    /// my_league_season.sim_regular_season(&mut rng);
-    ///
-    /// // Generate playoffs with 4 teams
-    /// let mut playoff_options = LeagueSeasonPlayoffOptions::new();
-    /// playoff_options.num_playoff_teams = 4;
-    /// my_league_season.generate_playoffs(playoff_options, &mut rng);
-    ///
-    /// // Simulate the first playoff matchup in conference bracket 0
-    /// let res = my_league_season.sim_playoff_matchup(0, 0, 0, &mut rng);
-    /// assert!(res.is_ok());
+    /// // Simulate the first two weeks of the season
+    /// let reports = my_league_season.sim_weeks(0, 2, &mut rng).unwrap();
+    /// assert_eq!(reports.len(), 2);
+    /// assert!(my_league_season.weeks()[1].complete());
+    /// assert!(!my_league_season.weeks()[2].complete());
     /// ```
-    pub fn sim_playoff_matchup(&mut self, conference: usize, round: usize, matchup: usize, rng: &mut impl Rng) -> Result<Game, String> {
-        // Ensure the regular season is complete
-        if !self.regular_season_complete() {
-            return Err(String::from("Cannot simulate playoff matchup: Regular season is not complete"));
+    pub fn sim_weeks(&mut self, from: usize, to: usize, rng: &mut impl Rng) -> Result<Vec<WeekSimReport>, LeagueError> {
+        if from > to {
+            return Err(
+                LeagueError::Invalid {
+                    reason: format!("First week {} is after last week {}", from, to)
+                }
+            );
         }
-
-        // Ensure the playoffs have started
-        let bracket = self.playoffs.conference_bracket(conference)
-            .ok_or_else(|| format!("Cannot simulate playoff matchup: Conference bracket {} not found", conference))?;
-        if bracket.is_empty() {
-            return Err(String::from("Cannot simulate playoff matchup: Playoffs have not been generated"));
+        if to > self.weeks.len() {
+            return Err(LeagueError::WeekOutOfRange { week: to - 1 });
         }
-
-        // Check if the prior round is complete (if not the first round)
-        if round > 0 {
-            let prev_round = match bracket.get(round - 1) {
-                Some(r) => r,
-                None => return Err(format!("Failed to get previous playoff round {}", round - 1))
-            };
-            if !prev_round.complete() {
-                return Err(format!(
-                    "Cannot simulate playoff round {}: Previous round {} is not complete",
-                    round, round - 1
-                ));
+        self.fingerprint.invalidate();
+        let mut reports = Vec::new();
+        for i in from..to {
+            // Skip weeks which have already completed
+            if self.weeks[i].complete() {
+                continue;
             }
-        }
-
-        // Get the playoff round
-        let playoff_round = match self.playoffs.conference_bracket_mut(conference)
-            .and_then(|b| b.get_mut(round)) {
-            Some(r) => r,
-            None => return Err(format!("No such playoff round: {}", round))
-        };
-
-        // Get the matchup
-        let playoff_matchup = match playoff_round.matchups_mut().get_mut(matchup) {
-            Some(m) => m,
-            None => return Err(format!("No such matchup {} in playoff round {}", matchup, round))
-        };
 
-        // Ensure the matchup is not already complete
-        if playoff_matchup.context().game_over() {
-            return Err(format!("Playoff round {} matchup {} is already complete", round, matchup));
+            // Simulate the week, failing if any of its matchups failed
+            let report = self.sim_week(i, rng)?;
+            if let Some(error) = report.error() {
+                return Err(
+                    LeagueError::Simulation {
+                        reason: format!("Season {} week {}: {}", self.year, i, error)
+                    }
+                );
+            }
+            reports.push(report);
         }
-
-        // Get the home and away teams
-        let home_id = *playoff_matchup.home_team();
-        let away_id = *playoff_matchup.away_team();
-
-        let home_team = match self.teams.get(&home_id) {
-            Some(t) => t,
-            None => return Err(format!("Playoff matchup references nonexistent home team ID: {}", home_id))
-        };
-        let away_team = match self.teams.get(&away_id) {
-            Some(t) => t,
-            None => return Err(format!("Playoff matchup references nonexistent away team ID: {}", away_id))
-        };
-
-        // Simulate the matchup
-        let mut game = Game::new();
-        let simulator = GameSimulator::new();
-        let context = match simulator.sim_game(
-            home_team, away_team,
-            playoff_matchup.context().clone(),
-            &mut game, rng
-        ) {
-            Ok(c) => c,
-            Err(e) => return Err(format!("Error while simulating playoff matchup: {}", e))
-        };
-
-        // Update the matchup context and stats
-        *playoff_matchup.context_mut() = context;
-        *playoff_matchup.home_stats_mut() = Some(game.home_stats());
-        *playoff_matchup.away_stats_mut() = Some(game.away_stats());
-        Ok(game)
+        Ok(reports)
     }
 
-    /// Simulate a winners bracket matchup
+    /// Simulate a full season of matchups
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::league::season::LeagueSeason;
     /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
-    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
     ///
-    /// // Create a new season with 2 conferences
+    /// // Create a new season
     /// let mut my_league_season = LeagueSeason::new();
     ///
-    /// // Add teams
+    /// // Add 4 teams to the season
     /// my_league_season.add_team(0, FootballTeam::new());
     /// my_league_season.add_team(1, FootballTeam::new());
     /// my_league_season.add_team(2, FootballTeam::new());
     /// my_league_season.add_team(3, FootballTeam::new());
     ///
-    /// // Create conference structure
-    /// let mut afc = LeagueConference::with_name("AFC");
-    /// let mut afc_div = LeagueDivision::with_name("East");
-    /// afc_div.add_team(0);
-    /// afc_div.add_team(1);
-    /// afc.add_division(afc_div);
-    /// my_league_season.add_conference(afc);
-    ///
-    /// let mut nfc = LeagueConference::with_name("NFC");
-    /// let mut nfc_div = LeagueDivision::with_name("East");
-    /// nfc_div.add_team(2);
-    /// nfc_div.add_team(3);
-    /// nfc.add_division(nfc_div);
-    /// my_league_season.add_conference(nfc);
-    ///
-    /// // Generate schedule and simulate regular season
+    /// // Generate the season schedule
     /// let mut rng = rand::thread_rng();
     /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
-    /// my_league_season.sim_regular_season(&mut rng);
     ///
-    /// // Generate conference playoffs and simulate all conference brackets
-    /// let mut options = LeagueSeasonPlayoffOptions::new();
-    /// options.use_conference_brackets = true;
-    /// options.playoff_teams_per_conference = 2;
-    /// options.division_winners_guaranteed = true;
-    /// let _ = my_league_season.generate_playoffs(options, &mut rng);
-    /// let _ = my_league_season.sim_playoff_round(0, &mut rng);
-    ///
-    /// // Generate the winners bracket
-    /// let _ = my_league_season.generate_next_playoff_round(&mut rng);
-    ///
-    /// // Simulate the first winners bracket matchup
-    /// let res = my_league_season.sim_winners_bracket_matchup(0, 0, &mut rng);
-    /// assert!(res.is_ok());
+    /// // Simulate the entire season
+    /// my_league_season.sim(&mut rng);
     /// ```
-    pub fn sim_winners_bracket_matchup(&mut self, round: usize, matchup: usize, rng: &mut impl Rng) -> Result<Game, String> {
-        // Ensure the regular season is complete
-        if !self.regular_season_complete() {
-            return Err(String::from("Cannot simulate winners bracket matchup: Regular season is not complete"));
-        }
-
-        // Ensure this is a conference playoff with a winners bracket
-        if !self.playoffs.is_conference_playoff() {
-            return Err(String::from("Cannot simulate winners bracket: Not a conference playoff"));
-        }
-
-        // Ensure the winners bracket has started
-        if self.playoffs.winners_bracket().is_empty() {
-            return Err(String::from("Cannot simulate winners bracket matchup: Winners bracket has not been generated"));
-        }
-
-        // Check if the prior round is complete (if not the first round)
-        if round > 0 {
-            let prev_round = match self.playoffs.winners_bracket().get(round - 1) {
-                Some(r) => r,
-                None => return Err(format!("Failed to get previous winners bracket round {}", round - 1))
+    pub fn sim(&mut self, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        self.fingerprint.invalidate();
+        for i in 0..self.weeks.len() {
+            // Skip weeks which have already completed
+            let week = match self.weeks.get(i) {
+                Some(w) => w,
+                None => return Err(LeagueError::WeekOutOfRange { week: i }),
             };
-            if !prev_round.complete() {
-                return Err(format!(
-                    "Cannot simulate winners bracket round {}: Previous round {} is not complete",
-                    round, round - 1
-                ));
-            }
-        }
-
-        // Get the round
-        let playoff_round = match self.playoffs.winners_bracket_mut().get_mut(round) {
-            Some(r) => r,
-            None => return Err(format!("No such winners bracket round: {}", round))
-        };
-
-        // Get the matchup
-        let playoff_matchup = match playoff_round.matchups_mut().get_mut(matchup) {
-            Some(m) => m,
-            None => return Err(format!("No such matchup {} in winners bracket round {}", matchup, round))
-        };
-
-        // Ensure the matchup is not already complete
-        if playoff_matchup.context().game_over() {
-            return Err(format!("Winners bracket round {} matchup {} is already complete", round, matchup));
-        }
-
-        // Get the home and away teams
-        let home_id = *playoff_matchup.home_team();
-        let away_id = *playoff_matchup.away_team();
-
-        let home_team = match self.teams.get(&home_id) {
-            Some(t) => t,
-            None => return Err(format!("Winners bracket matchup references nonexistent home team ID: {}", home_id))
-        };
-        let away_team = match self.teams.get(&away_id) {
-            Some(t) => t,
-            None => return Err(format!("Winners bracket matchup references nonexistent away team ID: {}", away_id))
-        };
-
-        // Simulate the matchup
-        let mut game = Game::new();
-        let simulator = GameSimulator::new();
-        let context = match simulator.sim_game(
-            home_team, away_team,
-            playoff_matchup.context().clone(),
-            &mut game, rng
-        ) {
-            Ok(c) => c,
-            Err(e) => return Err(format!("Error while simulating winners bracket matchup: {}", e))
-        };
-
-        // Update the matchup context and stats
-        *playoff_matchup.context_mut() = context;
-        *playoff_matchup.home_stats_mut() = Some(game.home_stats());
-        *playoff_matchup.away_stats_mut() = Some(game.away_stats());
-        Ok(game)
+            if week.complete() {
+                continue;
+            }
+
+            // Simulate the week, failing if any of its matchups failed
+            let report = self.sim_week(i, rng)?;
+            if let Some(error) = report.error() {
+                return Err(
+                    LeagueError::Simulation {
+                        reason: format!("Season {} week {}: {}", self.year, i, error)
+                    }
+                );
+            }
+        }
+        Ok(())
     }
 
-    /// Simulate a single play of a winners bracket playoff matchup
+    /// Get all season matchups involving a team
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     /// use fbsim_core::league::season::LeagueSeason;
     /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
-    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchups;
     ///
-    /// // Create a new season with 2 conferences
+    /// // Create a new season
     /// let mut my_league_season = LeagueSeason::new();
     ///
-    /// // Add teams
+    /// // Add 4 teams to the season
     /// my_league_season.add_team(0, FootballTeam::new());
     /// my_league_season.add_team(1, FootballTeam::new());
     /// my_league_season.add_team(2, FootballTeam::new());
     /// my_league_season.add_team(3, FootballTeam::new());
     ///
-    /// // Create conference structure
-    /// let mut afc = LeagueConference::with_name("AFC");
-    /// let mut afc_div = LeagueDivision::with_name("East");
-    /// afc_div.add_team(0);
-    /// afc_div.add_team(1);
-    /// afc.add_division(afc_div);
-    /// my_league_season.add_conference(afc);
-    ///
-    /// let mut nfc = LeagueConference::with_name("NFC");
-    /// let mut nfc_div = LeagueDivision::with_name("East");
-    /// nfc_div.add_team(2);
-    /// nfc_div.add_team(3);
-    /// nfc.add_division(nfc_div);
-    /// my_league_season.add_conference(nfc);
-    ///
-    /// // Generate schedule and simulate regular season
+    /// // Generate the season schedule
     /// let mut rng = rand::thread_rng();
     /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
-    /// my_league_season.sim_regular_season(&mut rng);
-    ///
-    /// // Generate conference playoffs and simulate all conference brackets
-    /// let mut options = LeagueSeasonPlayoffOptions::new();
-    /// options.use_conference_brackets = true;
-    /// options.playoff_teams_per_conference = 2;
-    /// options.division_winners_guaranteed = true;
-    /// let _ = my_league_season.generate_playoffs(options, &mut rng);
-    /// let _ = my_league_season.sim_playoff_round(0, &mut rng);
     ///
-    /// // Generate the winners bracket
-    /// let _ = my_league_season.generate_next_playoff_round(&mut rng);
+    /// // Simulate the entire season
+    /// my_league_season.sim(&mut rng);
     ///
-    /// // Simulate a single play of the first winners bracket matchup
-    /// let res = my_league_season.sim_winners_bracket_play(0, 0, &mut rng);
-    /// assert!(res.is_ok());
+    /// // Get the mathups for team 0
+    /// let matchups: LeagueSeasonMatchups = my_league_season.team_matchups(0).unwrap();
     /// ```
-    pub fn sim_winners_bracket_play(&mut self, round: usize, matchup: usize, rng: &mut impl Rng) -> Result<Option<Game>, String> {
-        // Ensure the regular season is complete
-        if !self.regular_season_complete() {
-            return Err(String::from("Cannot simulate winners bracket play: Regular season is not complete"));
+    pub fn team_matchups(&self, id: usize) -> Result<LeagueSeasonMatchups, LeagueError> {
+        // Ensure the given team ID exists
+        let _team = match self.team(id) {
+            Some(t) => t,
+            None => return Err(
+                LeagueError::TeamNotFound { id }
+            )
+        };
+
+        // Construct the matchups vector
+        let mut matchups: Vec<Option<LeagueSeasonMatchup>> = Vec::new();
+        for week in self.weeks.iter() {
+            matchups.push(week.team_matchup(id));
         }
+        Ok(LeagueSeasonMatchups::new(id, matchups))
+    }
+}
 
-        // Ensure this is a conference playoff with a winners bracket
-        if !self.playoffs.is_conference_playoff() {
-            return Err(String::from("Cannot simulate winners bracket: Not a conference playoff"));
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schedule_gen() {
+        // Create a new season
+        let mut my_league_season = LeagueSeason::with_year(2026);
+    
+        // Add some teams to the season
+        let _ = my_league_season.add_team(0, FootballTeam::new());
+        let _ = my_league_season.add_team(1, FootballTeam::new());
+        let _ = my_league_season.add_team(2, FootballTeam::new());
+        let _ = my_league_season.add_team(3, FootballTeam::new());
+        let _ = my_league_season.add_team(4, FootballTeam::new());
+        let _ = my_league_season.add_team(5, FootballTeam::new());
+        
+        // Generate the season schedule
+        let mut rng = rand::thread_rng();
+        let _ = my_league_season.generate_schedule(
+            LeagueSeasonScheduleOptions::new(),
+            &mut rng
+        );
+
+        // Validate the schedule
+        // Map team IDs to a tuple of usizes which represent:
+        // Home games, away games, consecutive away games
+        let mut home_away: BTreeMap<usize, (usize, usize, usize)> = BTreeMap::new();
+        for (id, _) in my_league_season.teams().iter() {
+            home_away.insert(*id, (0, 0, 0));
         }
+        for week in my_league_season.weeks() {
+            // Loop through the week's matchups
+            for matchup in week.matchups().iter() {
+                let home_id = matchup.home_team();
+                let away_id = matchup.away_team();
 
-        // Ensure the winners bracket has started
-        if self.playoffs.winners_bracket().is_empty() {
-            return Err(String::from("Cannot simulate winners bracket play: Winners bracket has not been generated"));
+                // Tally home games, away games, consecutive away games
+                home_away.entry(*home_id)
+                    .and_modify(|(home, _, cons_away)| {
+                        *home += 1;
+                        *cons_away = 0;
+                    });
+                home_away.entry(*away_id)
+                    .and_modify(|(_, away, cons_away)| {
+                        *away += 1;
+                        *cons_away = 0;
+                    });
+
+                // Assert that no team plays three away games in a row
+                if let Some(entry) = home_away.get(away_id) {
+                    let (_, _, cons_away) = entry;
+                    assert!(*cons_away < 3);
+                }
+            }
         }
 
-        // Check if the prior round is complete (if not the first round)
-        if round > 0 {
-            let prev_round = match self.playoffs.winners_bracket().get(round - 1) {
-                Some(r) => r,
-                None => return Err(format!("Failed to get previous winners bracket round {}", round - 1))
-            };
-            if !prev_round.complete() {
-                return Err(format!(
-                    "Cannot simulate winners bracket round {}: Previous round {} is not complete",
-                    round, round - 1
-                ));
+        // Assert that each team plays an equal number of home and away games
+        for (_, (home, away, _)) in home_away.iter() {
+            assert!(home == away);
+        }
+    }
+
+    #[test]
+    fn test_sim_matchups_partial_week() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        // Create a season with a generated schedule
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            let _ = my_league_season.add_team(id, FootballTeam::new());
+        }
+        let mut rng = SmallRng::seed_from_u64(0);
+        let _ = my_league_season.generate_schedule(
+            LeagueSeasonScheduleOptions::new(),
+            &mut rng
+        );
+        my_league_season.set_sim_seed(Some(1440));
+        let mut full_week_season = my_league_season.clone();
+
+        // Simulate the first half of the week, then the rest
+        let mut partial_rng = SmallRng::seed_from_u64(1);
+        let num_matchups = my_league_season.weeks()[0].matchups().len();
+        let first_half: Vec<usize> = (0..num_matchups / 2).collect();
+        let second_half: Vec<usize> = (num_matchups / 2..num_matchups).collect();
+        let games = my_league_season.sim_matchups(0, &first_half, false, &mut partial_rng).unwrap();
+        assert_eq!(games.len(), first_half.len());
+        assert!(!my_league_season.weeks()[0].complete());
+        let played: usize = my_league_season.standings().iter()
+            .map(|(_, r)| r.wins() + r.losses() + r.ties())
+            .sum();
+        assert_eq!(played, first_half.len() * 2);
+
+        // Completed matchups error unless skipped
+        assert!(my_league_season.sim_matchups(0, &first_half, false, &mut partial_rng).is_err());
+        let mut all: Vec<usize> = first_half.clone();
+        all.extend(second_half.iter());
+        let games = my_league_season.sim_matchups(0, &all, true, &mut partial_rng).unwrap();
+        assert!(games[..first_half.len()].iter().all(|g| g.is_none()));
+        assert!(games[first_half.len()..].iter().all(|g| g.is_some()));
+        assert!(my_league_season.weeks()[0].complete());
+
+        // Compare against a full week simulated with the same matchup seeds
+        let mut full_rng = SmallRng::seed_from_u64(1);
+        let _ = full_week_season.sim_week(0, &mut full_rng);
+        assert_eq!(my_league_season.weeks()[0], full_week_season.weeks()[0]);
+        assert_eq!(my_league_season.standings(), full_week_season.standings());
+
+        // Invalid and duplicate indices are rejected
+        assert!(my_league_season.sim_matchups(1, &[num_matchups], false, &mut partial_rng).is_err());
+        assert_eq!(
+            my_league_season.sim_matchups(1, &[0, 0], false, &mut partial_rng).unwrap_err(),
+            LeagueError::DuplicateMatchup { week: 1, matchup: 0 }
+        );
+    }
+
+    #[test]
+    fn test_sim_matchups_out_of_order_matches_sim_week() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..8 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        let mut rng = SmallRng::seed_from_u64(1440);
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        my_league_season.set_sim_seed(Some(1440));
+        let mut full_week_season = my_league_season.clone();
+
+        // Simulate the week in two parts, each out of order, drawing from
+        // unrelated RNGs
+        let mut split_rng = SmallRng::seed_from_u64(1);
+        my_league_season.sim_matchups(0, &[3, 1], false, &mut split_rng).unwrap();
+        let mut split_rng = SmallRng::seed_from_u64(2);
+        my_league_season.sim_matchups(0, &[2, 0], false, &mut split_rng).unwrap();
+
+        // Each game matches the same game of the week simulated at once
+        let mut full_rng = SmallRng::seed_from_u64(3);
+        full_week_season.sim_week(0, &mut full_rng).unwrap();
+        for (split, full) in my_league_season.weeks()[0].matchups().iter()
+            .zip(full_week_season.weeks()[0].matchups().iter()) {
+            assert_eq!(split.context().home_score(), full.context().home_score());
+            assert_eq!(split.context().away_score(), full.context().away_score());
+        }
+        assert_eq!(my_league_season.standings(), full_week_season.standings());
+
+        // Without a simulation seed, a game's result within a call does not
+        // depend on the order the matchups are given in
+        let mut unseeded = full_week_season.clone();
+        unseeded.set_sim_seed(None);
+        let mut reversed = unseeded.clone();
+        unseeded.sim_matchups(1, &[0, 1, 2, 3], false, &mut SmallRng::seed_from_u64(4)).unwrap();
+        reversed.sim_matchups(1, &[3, 2, 1, 0], false, &mut SmallRng::seed_from_u64(4)).unwrap();
+        assert_eq!(unseeded.weeks()[1], reversed.weeks()[1]);
+    }
+
+    #[test]
+    fn test_partial_schedule_gen() {
+        // Create a season with 20 teams in two conferences of two divisions
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..20 {
+            let _ = my_league_season.add_team(id, FootballTeam::new());
+        }
+        for c in 0..2 {
+            let mut conference = LeagueConference::new();
+            for d in 0..2 {
+                let mut division = LeagueDivision::new();
+                for t in 0..5 {
+                    let _ = division.add_team(c * 10 + d * 5 + t);
+                }
+                let _ = conference.add_division(division);
             }
+            let _ = my_league_season.add_conference(conference);
         }
 
-        // Get the round
-        let playoff_round = match self.playoffs.winners_bracket_mut().get_mut(round) {
-            Some(r) => r,
-            None => return Err(format!("No such winners bracket round: {}", round))
-        };
+        // Generate a 10 game partial schedule
+        let mut options = LeagueSeasonScheduleOptions::new();
+        options.format = ScheduleFormat::Partial { games_per_team: 10 };
+        let mut rng = rand::thread_rng();
+        let res = my_league_season.generate_schedule(options, &mut rng);
+        assert!(res.is_ok(), "{:?}", res);
+        assert_eq!(my_league_season.weeks().len(), 10);
+        assert_eq!(
+            *my_league_season.schedule_format(),
+            ScheduleFormat::Partial { games_per_team: 10 }
+        );
 
-        // Get the matchup
-        let playoff_matchup = match playoff_round.matchups_mut().get_mut(matchup) {
-            Some(m) => m,
-            None => return Err(format!("No such matchup {} in winners bracket round {}", matchup, round))
-        };
+        // Tally games, home games, and opponents per team
+        let mut games: BTreeMap<usize, (usize, usize)> = BTreeMap::new();
+        let mut pairs: HashSet<(usize, usize)> = HashSet::new();
+        for week in my_league_season.weeks() {
+            let mut scheduled: HashSet<usize> = HashSet::new();
+            for matchup in week.matchups() {
+                let home_id = *matchup.home_team();
+                let away_id = *matchup.away_team();
 
-        // Ensure the matchup is not already complete
-        if playoff_matchup.context().game_over() {
-            return Err(format!("Winners bracket round {} matchup {} is already complete", round, matchup));
+                // Assert no team is double-booked within a week
+                assert!(scheduled.insert(home_id));
+                assert!(scheduled.insert(away_id));
+
+                // Assert no pair repeats
+                assert!(pairs.insert((home_id.min(away_id), home_id.max(away_id))));
+                games.entry(home_id).or_default().0 += 1;
+                games.entry(away_id).or_default().1 += 1;
+            }
         }
 
-        // Get the home and away teams
-        let home_id = *playoff_matchup.home_team();
-        let away_id = *playoff_matchup.away_team();
+        // Assert each team plays 10 games, 5 at home and 5 away
+        assert_eq!(games.len(), 20);
+        for (home, away) in games.values() {
+            assert_eq!(*home, 5);
+            assert_eq!(*away, 5);
+        }
 
-        let home_team = match self.teams.get(&home_id) {
-            Some(t) => t,
-            None => return Err(format!("Winners bracket matchup references nonexistent home team ID: {}", home_id))
-        };
-        let away_team = match self.teams.get(&away_id) {
-            Some(t) => t,
-            None => return Err(format!("Winners bracket matchup references nonexistent away team ID: {}", away_id))
-        };
+        // Assert the partial schedule survives validation
+        let json = serde_json::to_string(&my_league_season).unwrap();
+        let deserialized: Result<LeagueSeason, _> = serde_json::from_str(&json);
+        assert!(deserialized.is_ok());
+    }
 
-        // Create a new game if game has not started, or get existing game
-        if playoff_matchup.game().is_none() {
-            *playoff_matchup.game_mut() = Some(Game::new());
+    /// Fill in the fields omitted from the sparse form of a serialized season
+    fn densify(value: &mut serde_json::Value, key: &str) {
+        match value {
+            serde_json::Value::Object(map) => {
+                // Game contexts omit false booleans
+                if map.contains_key("home_team_short") {
+                    for field in [
+                        "home_positive_direction", "home_opening_kickoff", "home_possession",
+                        "last_play_turnover", "last_play_incomplete", "last_play_out_of_bounds",
+                        "last_play_timeout", "last_play_kickoff", "last_play_punt",
+                        "next_play_extra_point", "next_play_kickoff", "neutral_site",
+                        "end_of_half", "game_over"
+                    ] {
+                        map.entry(field).or_insert(serde_json::Value::Bool(false));
+                    }
+                }
+
+                // Matchups omit unplayed games, stats, and attendance
+                if map.contains_key("home_team") && map.contains_key("context") {
+                    for field in ["game", "home_stats", "away_stats", "attendance"] {
+                        map.entry(field).or_insert(serde_json::Value::Null);
+                    }
+                }
+
+                // Seasons omit empty conferences, the default schedule
+                // format and championship mode, an unset trade deadline,
+                // trade ledger, attendance model, weather distribution, venue
+                // swap ledger, result edit ledger, and simulation seed, and a
+                // season which is not strict
+                if map.contains_key("weeks") && map.contains_key("playoffs") {
+                    map.entry("conferences").or_insert(serde_json::json!([]));
+                    map.entry("schedule_format").or_insert(serde_json::json!("RoundRobin"));
+                    map.entry("championship_mode").or_insert(serde_json::json!("Playoffs"));
+                    map.entry("trade_deadline").or_insert(serde_json::Value::Null);
+                    map.entry("trades").or_insert(serde_json::json!([]));
+                    map.entry("attendance_model").or_insert(serde_json::Value::Null);
+                    map.entry("weather_distribution").or_insert(serde_json::Value::Null);
+                    map.entry("venue_swaps").or_insert(serde_json::json!([]));
+                    map.entry("strict").or_insert(serde_json::json!(false));
+                    map.entry("result_edits").or_insert(serde_json::json!([]));
+                    map.entry("sim_seed").or_insert(serde_json::Value::Null);
+                }
+
+                // Playoffs omit empty brackets
+                if key == "playoffs" {
+                    map.entry("conference_brackets").or_insert(serde_json::json!({}));
+                    map.entry("winners_bracket").or_insert(serde_json::json!([]));
+                }
+
+                for (k, v) in map.iter_mut() {
+                    densify(v, k);
+                }
+            },
+            serde_json::Value::Array(values) => {
+                for v in values.iter_mut() {
+                    densify(v, key);
+                }
+            },
+            _ => ()
         }
+    }
 
-        // Simulate the next play
-        let simulator = GameSimulator::new();
-        let context = match simulator.sim_play(
-            home_team, away_team,
-            playoff_matchup.context().clone(),
-            playoff_matchup.game_mut().as_mut().unwrap(),
-            rng
-        ) {
-            Ok(c) => c,
-            Err(e) => return Err(format!("Error while simulating winners bracket play: {}", e))
-        };
+    #[test]
+    fn test_dense_and_sparse_season_round_trip() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        // Create and simulate a season with playoffs
+        let mut rng = SmallRng::seed_from_u64(1448);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        my_league_season.sim_week(0, &mut rng).unwrap();
+
+        // Round trip both the sparse and dense forms of a partial season
+        let sparse = serde_json::to_value(&my_league_season).unwrap();
+        let mut dense = sparse.clone();
+        densify(&mut dense, "");
+        assert!(dense.to_string().len() > sparse.to_string().len());
+        let from_sparse: LeagueSeason = serde_json::from_value(sparse).unwrap();
+        let from_dense: LeagueSeason = serde_json::from_value(dense).unwrap();
+        assert_eq!(from_sparse, my_league_season);
+        assert_eq!(from_dense, my_league_season);
+
+        // Round trip both forms of a completed season
+        my_league_season.sim_regular_season(&mut rng).unwrap();
+        let mut playoff_options = LeagueSeasonPlayoffOptions::new();
+        playoff_options.num_playoff_teams = 4;
+        my_league_season.generate_playoffs(playoff_options, &mut rng).unwrap();
+        my_league_season.sim_playoffs(&mut rng).unwrap();
+        let sparse = serde_json::to_value(&my_league_season).unwrap();
+        let mut dense = sparse.clone();
+        densify(&mut dense, "");
+        let from_sparse: LeagueSeason = serde_json::from_value(sparse).unwrap();
+        let from_dense: LeagueSeason = serde_json::from_value(dense).unwrap();
+        assert_eq!(from_sparse, my_league_season);
+        assert_eq!(from_dense, my_league_season);
+    }
 
-        // If game is over, archive game stats, clear game, update context
-        if context.game_over() {
-            *playoff_matchup.home_stats_mut() = Some(
-                playoff_matchup.game().as_ref().ok_or(
-                    "Failed to archive home stats for winners bracket game"
-                )?.home_stats()
-            );
-            *playoff_matchup.away_stats_mut() = Some(
-                playoff_matchup.game().as_ref().ok_or(
-                    "Failed to archive away stats for winners bracket game"
-                )?.away_stats()
-            );
-            *playoff_matchup.context_mut() = context;
-            return Ok(playoff_matchup.take_game());
+    /// Complete every regular season matchup, deciding winners by `home_wins`
+    fn finish_regular_season(season: &mut LeagueSeason, home_wins: impl Fn(usize, usize) -> bool) {
+        use crate::game::context::GameContextBuilder;
+
+        for week in season.weeks_mut().iter_mut() {
+            for matchup in week.matchups_mut().iter_mut() {
+                let home_won = home_wins(*matchup.home_team(), *matchup.away_team());
+                *matchup.context_mut() = GameContextBuilder::new()
+                    .quarter(4)
+                    .half_seconds(0)
+                    .home_score(if home_won { 1 } else { 0 })
+                    .away_score(if home_won { 0 } else { 1 })
+                    .game_over(true)
+                    .build()
+                    .unwrap();
+            }
         }
-        *playoff_matchup.context_mut() = context;
-        Ok(None)
     }
 
-    /// Simulate a single play of a playoff matchup in a specific conference bracket
-    ///
-    /// ### Example
-    /// ```
-    /// use fbsim_core::team::FootballTeam;
-    /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
-    ///
-    /// // Create a new season
-    /// let mut my_league_season = LeagueSeason::new();
-    ///
-    /// // Add 4 teams to the season
-    /// my_league_season.add_team(0, FootballTeam::new());
-    /// my_league_season.add_team(1, FootballTeam::new());
-    /// my_league_season.add_team(2, FootballTeam::new());
-    /// my_league_season.add_team(3, FootballTeam::new());
-    ///
-    /// // Generate the season schedule
-    /// let mut rng = rand::thread_rng();
-    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
-    ///
-    /// // Simulate the entire regular season
-    /// my_league_season.sim_regular_season(&mut rng);
-    ///
-    /// // Generate playoffs with 4 teams
-    /// let mut playoff_options = LeagueSeasonPlayoffOptions::new();
-    /// playoff_options.num_playoff_teams = 4;
-    /// my_league_season.generate_playoffs(playoff_options, &mut rng);
-    ///
-    /// // Simulate a single play of the first playoff matchup
-    /// let res = my_league_season.sim_playoff_play(0, 0, 0, &mut rng);
-    /// assert!(res.is_ok());
-    /// ```
-    pub fn sim_playoff_play(&mut self, conference: usize, round: usize, matchup: usize, rng: &mut impl Rng) -> Result<Option<Game>, String> {
-        // Ensure the regular season is complete
-        if !self.regular_season_complete() {
-            return Err(String::from("Cannot simulate playoff play: Regular season is not complete"));
+    #[test]
+    fn test_best_record_title_playoff() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut rng = SmallRng::seed_from_u64(1450);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
         }
+        my_league_season.set_championship_mode(ChampionshipMode::BestRecord).unwrap();
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+
+        // Teams 0 and 1 split their games and beat everyone else, so they
+        // finish tied for the best record
+        finish_regular_season(&mut my_league_season, |home, away| {
+            if home < 2 && away < 2 {
+                true
+            } else if home < 2 || away < 2 {
+                home < 2
+            } else {
+                true
+            }
+        });
+        assert!(my_league_season.regular_season_complete());
+        assert_eq!(my_league_season.best_record_teams(), vec![0, 1]);
+
+        // The tie leaves the season incomplete and without a champion, and
+        // the raw season agrees
+        assert!(!my_league_season.complete());
+        assert!(my_league_season.champion().is_none());
+        let raw: LeagueSeasonRaw = serde_json::from_str(&serde_json::to_string(&my_league_season).unwrap()).unwrap();
+        assert!(!raw.complete());
+        let picture = my_league_season.playoff_picture(4).unwrap();
+        assert_eq!(picture.num_playoff_teams(), 1);
+
+        // Regular playoffs are not allowed when crowning the best record
+        assert!(my_league_season.generate_playoffs(LeagueSeasonPlayoffOptions::new(), &mut rng).is_err());
+
+        // Generate and simulate the title playoff between the tied teams
+        my_league_season.generate_title_playoff(&mut rng).unwrap();
+        assert_eq!(my_league_season.playoffs().num_teams(), 2);
+        assert!(my_league_season.set_championship_mode(ChampionshipMode::Playoffs).is_err());
+        my_league_season.sim_playoffs(&mut rng).unwrap();
+
+        // The title playoff winner is the champion
+        assert!(my_league_season.complete());
+        let champion = my_league_season.champion().unwrap();
+        assert!(champion < 2);
+        assert!(my_league_season.team_won_championship(champion).unwrap());
+        assert!(my_league_season.team_in_championship(1 - champion).unwrap());
+        assert!(!my_league_season.team_in_championship(2).unwrap());
+
+        // The mode survives a round trip
+        let json = serde_json::to_string(&my_league_season).unwrap();
+        let deserialized: LeagueSeason = serde_json::from_str(&json).unwrap();
+        assert_eq!(*deserialized.championship_mode(), ChampionshipMode::BestRecord);
+        assert_eq!(deserialized.champion(), Some(champion));
+    }
 
-        // Ensure the playoffs have started
-        let bracket = self.playoffs.conference_bracket(conference)
-            .ok_or_else(|| format!("Cannot simulate playoff play: Conference bracket {} not found", conference))?;
-        if bracket.is_empty() {
-            return Err(String::from("Cannot simulate playoff play: Playoffs have not been generated"));
+    #[test]
+    fn test_best_record_outright_champion() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut rng = SmallRng::seed_from_u64(1450);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
         }
+        my_league_season.set_championship_mode(ChampionshipMode::BestRecord).unwrap();
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+
+        // The lower team ID wins every game, so team 0 finishes alone on top
+        finish_regular_season(&mut my_league_season, |home, away| home < away);
+        assert_eq!(my_league_season.best_record_teams(), vec![0]);
+
+        // The season is complete without any title playoff
+        assert!(my_league_season.complete());
+        assert_eq!(my_league_season.champion(), Some(0));
+        assert!(my_league_season.team_won_championship(0).unwrap());
+        assert!(my_league_season.team_in_championship(0).unwrap());
+        assert!(!my_league_season.team_in_championship(1).unwrap());
+        assert!(my_league_season.generate_title_playoff(&mut rng).is_err());
+    }
 
-        // Check if the prior round is complete (if not the first round)
-        if round > 0 {
-            let prev_round = match bracket.get(round - 1) {
-                Some(r) => r,
-                None => return Err(format!("Failed to get previous playoff round {}", round - 1))
-            };
-            if !prev_round.complete() {
-                return Err(format!(
-                    "Cannot simulate playoff round {}: Previous round {} is not complete",
-                    round, round - 1
-                ));
+    #[test]
+    fn test_best_record_tie_broken_by_tiebreakers() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::game::context::GameContextBuilder;
+
+        let mut rng = SmallRng::seed_from_u64(1450);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        my_league_season.set_championship_mode(ChampionshipMode::BestRecord).unwrap();
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+
+        // Teams 0 and 1 split their games and beat everyone else, but team 1
+        // wins by more, so it leads on point differential
+        for week in my_league_season.weeks_mut().iter_mut() {
+            for matchup in week.matchups_mut().iter_mut() {
+                let (home, away) = (*matchup.home_team(), *matchup.away_team());
+                let (home_score, away_score) = if home < 2 && away < 2 {
+                    (1, 0)
+                } else if home < 2 || away < 2 {
+                    let margin = if home.min(away) == 1 { 10 } else { 1 };
+                    if home < 2 { (margin, 0) } else { (0, margin) }
+                } else {
+                    (1, 0)
+                };
+                *matchup.context_mut() = GameContextBuilder::new()
+                    .quarter(4)
+                    .half_seconds(0)
+                    .home_score(home_score)
+                    .away_score(away_score)
+                    .game_over(true)
+                    .build()
+                    .unwrap();
             }
         }
 
-        // Get the playoff round
-        let playoff_round = match self.playoffs.conference_bracket_mut(conference)
-            .and_then(|b| b.get_mut(round)) {
-            Some(r) => r,
-            None => return Err(format!("No such playoff round: {}", round))
-        };
+        // The tie in record is broken short of team ID, so team 1 is the
+        // outright champion and no title playoff is needed
+        let standings = my_league_season.standings();
+        assert_eq!(standings[0].1.wins(), standings[1].1.wins());
+        assert_eq!(my_league_season.best_record_teams(), vec![1]);
+        assert!(my_league_season.complete());
+        assert_eq!(my_league_season.champion(), Some(1));
+        assert!(my_league_season.generate_title_playoff(&mut rng).is_err());
+        let raw: LeagueSeasonRaw = serde_json::from_str(&serde_json::to_string(&my_league_season).unwrap()).unwrap();
+        assert!(raw.complete());
+    }
 
-        // Get the matchup
-        let playoff_matchup = match playoff_round.matchups_mut().get_mut(matchup) {
-            Some(m) => m,
-            None => return Err(format!("No such matchup {} in playoff round {}", matchup, round))
-        };
+    #[test]
+    fn test_attendance_within_capacity() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::league::season::attendance::AttendanceModel;
+
+        let mut rng = SmallRng::seed_from_u64(1452);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for (id, capacity) in [0, 20000, 65000, 250000, 40000, 90000].into_iter().enumerate() {
+            let mut team = FootballTeam::new();
+            team.set_stadium_capacity(capacity).unwrap();
+            my_league_season.add_team(id, team).unwrap();
+        }
+        my_league_season.set_attendance_model(Some(AttendanceModel::new().seed(7))).unwrap();
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        my_league_season.sim_regular_season(&mut rng).unwrap();
+        my_league_season.generate_playoffs(LeagueSeasonPlayoffOptions::new().num_playoff_teams(4), &mut rng).unwrap();
+        my_league_season.sim_playoffs(&mut rng).unwrap();
+
+        // Every regular season game records attendance within capacity
+        for week in my_league_season.weeks().iter() {
+            for matchup in week.matchups().iter() {
+                let capacity = my_league_season.teams().get(matchup.home_team()).unwrap().stadium_capacity();
+                let attendance = matchup.attendance().unwrap();
+                assert!(attendance <= capacity);
+            }
+            assert!(week.total_attendance().is_some());
+        }
 
-        // Ensure the matchup is not already complete
-        if playoff_matchup.context().game_over() {
-            return Err(format!("Playoff round {} matchup {} is already complete", round, matchup));
+        // Every playoff game is a sellout
+        for week in my_league_season.playoffs().conference_brackets().values().flatten() {
+            for matchup in week.matchups().iter() {
+                let capacity = my_league_season.teams().get(matchup.home_team()).unwrap().stadium_capacity();
+                assert_eq!(matchup.attendance(), Some(capacity));
+            }
         }
 
-        // Get the home and away teams
-        let home_id = *playoff_matchup.home_team();
-        let away_id = *playoff_matchup.away_team();
+        // Attendance is reproducible from the model's seed
+        let mut rerecorded = my_league_season.clone();
+        for week in rerecorded.weeks_mut().iter_mut() {
+            for matchup in week.matchups_mut().iter_mut() {
+                *matchup.attendance_mut() = None;
+            }
+        }
+        rerecorded.record_attendance();
+        assert_eq!(rerecorded, my_league_season);
+
+        // Attendance beyond a stadium's capacity fails validation
+        let mut value = serde_json::to_value(&my_league_season).unwrap();
+        let matchup = &mut value["weeks"][0]["matchups"][0];
+        let home_id = matchup["home_team"].as_u64().unwrap() as usize;
+        let capacity = my_league_season.teams().get(&home_id).unwrap().stadium_capacity();
+        matchup["attendance"] = serde_json::json!(capacity + 1);
+        assert!(serde_json::from_value::<LeagueSeason>(value).is_err());
+    }
 
-        let home_team = match self.teams.get(&home_id) {
-            Some(t) => t,
-            None => return Err(format!("Playoff matchup references nonexistent home team ID: {}", home_id))
-        };
-        let away_team = match self.teams.get(&away_id) {
-            Some(t) => t,
-            None => return Err(format!("Playoff matchup references nonexistent away team ID: {}", away_id))
-        };
+    #[test]
+    fn test_attendance_follows_winning() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::league::season::attendance::AttendanceModel;
+
+        let mut rng = SmallRng::seed_from_u64(1452);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..8 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+
+        // The lower team ID wins every game, so team 0 goes unbeaten and team
+        // 7 goes winless
+        finish_regular_season(&mut my_league_season, |home, away| home < away);
+        my_league_season.set_attendance_model(Some(AttendanceModel::new())).unwrap();
+
+        let winning = my_league_season.average_attendance(0).unwrap().unwrap();
+        let losing = my_league_season.average_attendance(7).unwrap().unwrap();
+        assert!(winning > losing);
+        assert!(winning <= FootballTeam::new().stadium_capacity() as f64);
+        assert!(my_league_season.average_attendance(8).is_err());
+    }
 
-        // Create a new game if game has not started, or get existing game
-        if playoff_matchup.game().is_none() {
-            *playoff_matchup.game_mut() = Some(Game::new());
+    #[test]
+    fn test_apply_trade() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::league::season::trade::LeagueSeasonTrade;
+        use crate::game::play::PlaySimulatable;
+        use crate::team::offense::{FootballTeamOffense, FootballTeamOffenseBuilder};
+
+        let mut rng = SmallRng::seed_from_u64(1453);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
         }
+        *my_league_season.teams.get_mut(&0).unwrap().offense_mut() = FootballTeamOffense::from_overall(95).unwrap();
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        my_league_season.set_trade_deadline(Some(2)).unwrap();
+
+        // A trade which would push an overall out of range is rejected
+        let too_large = LeagueSeasonTrade::new(0, 1, 10, 0);
+        assert!(my_league_season.apply_trade(0, too_large).is_err());
+        assert!(my_league_season.trades().is_empty());
+
+        // Trades only apply before the next week to be played
+        let trade = LeagueSeasonTrade::new(1, 0, 5, -5);
+        assert!(my_league_season.apply_trade(1, trade).is_err());
+        assert!(my_league_season.apply_trade(0, trade).is_ok());
+        assert_eq!(my_league_season.teams().get(&1).unwrap().offense_overall(), 55);
+        assert_eq!(my_league_season.teams().get(&1).unwrap().defense_overall(), 45);
+        assert_eq!(my_league_season.teams().get(&0).unwrap().offense_overall(), 90);
+        assert_eq!(my_league_season.teams().get(&0).unwrap().defense_overall(), 55);
+
+        // Skills are clamped to range when the overall stays in range, and
+        // past results are untouched
+        my_league_season.sim_week(0, &mut rng).unwrap();
+        let week_zero = my_league_season.weeks()[0].clone();
+        *my_league_season.teams.get_mut(&2).unwrap().offense_mut() = FootballTeamOffenseBuilder::new()
+            .passing(95)
+            .build()
+            .unwrap();
+        assert!(my_league_season.apply_trade(1, LeagueSeasonTrade::new(2, 3, 10, 0)).is_ok());
+        let offense = my_league_season.teams().get(&2).unwrap().offense().clone();
+        assert_eq!(offense.passing(), 100);
+        assert_eq!(offense.blocking(), 60);
+        assert_eq!(my_league_season.weeks()[0], week_zero);
+
+        // Trades on or after the deadline are rejected
+        my_league_season.sim_week(1, &mut rng).unwrap();
+        assert!(my_league_season.apply_trade(2, LeagueSeasonTrade::new(0, 1, 1, 1)).is_err());
+        assert!(my_league_season.set_trade_deadline(Some(1)).is_err());
+
+        // The ledger records each applied trade and survives serialization
+        assert_eq!(my_league_season.trades().len(), 2);
+        assert_eq!(my_league_season.trades()[0].week(), 0);
+        assert_eq!(*my_league_season.trades()[1].trade(), LeagueSeasonTrade::new(2, 3, 10, 0));
+        let json = serde_json::to_string(&my_league_season).unwrap();
+        let deserialized: LeagueSeason = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.trades(), my_league_season.trades());
+    }
 
-        // Simulate the next play
-        let simulator = GameSimulator::new();
-        let context = match simulator.sim_play(
-            home_team, away_team,
-            playoff_matchup.context().clone(),
-            playoff_matchup.game_mut().as_mut().unwrap(),
-            rng
-        ) {
-            Ok(c) => c,
-            Err(e) => return Err(format!("Error while simulating playoff play: {}", e))
-        };
+    /// Collect the first-round pairings of generated playoffs in the same
+    /// shape as a playoff preview
+    fn generated_first_round(season: &LeagueSeason) -> Vec<(usize, usize, usize)> {
+        let mut pairings = Vec::new();
+        for (conference, bracket) in season.playoffs().conference_brackets().iter() {
+            for matchup in bracket[0].matchups().iter() {
+                pairings.push((*conference, *matchup.home_team(), *matchup.away_team()));
+            }
+        }
+        pairings
+    }
 
-        // If game is over, archive game stats, clear game, update context
-        if context.game_over() {
-            *playoff_matchup.home_stats_mut() = Some(
-                playoff_matchup.game().as_ref().ok_or(
-                    "Failed to archive home stats for playoff game"
-                )?.home_stats()
-            );
-            *playoff_matchup.away_stats_mut() = Some(
-                playoff_matchup.game().as_ref().ok_or(
-                    "Failed to archive away stats for playoff game"
-                )?.away_stats()
-            );
-            *playoff_matchup.context_mut() = context;
-            return Ok(playoff_matchup.take_game());
+    #[test]
+    fn test_playoff_preview_matches_generated_playoffs() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::league::season::conference::{LeagueConference, LeagueDivision};
+        use crate::league::season::playoffs::LeagueSeasonPlayoffs;
+
+        let mut rng = SmallRng::seed_from_u64(1454);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..12 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        for conf_index in 0..2 {
+            let mut conf = LeagueConference::new();
+            for div_index in 0..2 {
+                let mut div = LeagueDivision::new();
+                for offset in 0..3 {
+                    div.add_team(conf_index * 6 + div_index * 3 + offset).unwrap();
+                }
+                conf.add_division(div).unwrap();
+            }
+            my_league_season.add_conference(conf).unwrap();
+        }
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+
+        // A mid-season preview leaves the season untouched
+        my_league_season.sim_week(0, &mut rng).unwrap();
+        let before = my_league_season.clone();
+        let options = LeagueSeasonPlayoffOptions::new().num_playoff_teams(6);
+        assert!(LeagueSeasonPlayoffs::preview_from_standings(&my_league_season, options).is_ok());
+        assert_eq!(my_league_season, before);
+        my_league_season.sim_regular_season(&mut rng).unwrap();
+
+        // The preview agrees with the generated bracket for each format
+        let all_options = [
+            LeagueSeasonPlayoffOptions::new().num_playoff_teams(6),
+            LeagueSeasonPlayoffOptions::new().num_playoff_teams(8),
+            LeagueSeasonPlayoffOptions::new()
+                .use_conference_brackets(true)
+                .playoff_teams_per_conference(3),
+            LeagueSeasonPlayoffOptions::new()
+                .use_conference_brackets(true)
+                .playoff_teams_per_conference(4)
+                .division_winners_guaranteed(true),
+        ];
+        for options in all_options {
+            let view = LeagueSeasonPlayoffs::preview_from_standings(&my_league_season, options.clone()).unwrap();
+            my_league_season.generate_playoffs(options, &mut rng).unwrap();
+            let preview: Vec<(usize, usize, usize)> = view.first_round.iter()
+                .map(|m| (m.conference, m.home_team, m.away_team))
+                .collect();
+            assert_eq!(preview, generated_first_round(&my_league_season));
+            for team in view.teams.iter() {
+                let playoffs = my_league_season.playoffs();
+                assert_eq!(playoffs.team_seed(team.team_id).unwrap(), team.seed);
+                assert_eq!(playoffs.team_conference(team.team_id).unwrap(), team.conference);
+            }
+            assert_eq!(view.teams.len(), my_league_season.playoffs().num_teams());
         }
-        *playoff_matchup.context_mut() = context;
-        Ok(None)
     }
 
-    /// Simulate all matchups in a specific conference bracket round
-    ///
-    /// ### Example
-    /// ```
-    /// use fbsim_core::team::FootballTeam;
-    /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
-    ///
-    /// // Create a new season
-    /// let mut my_league_season = LeagueSeason::new();
-    ///
-    /// // Add 4 teams to the season
-    /// my_league_season.add_team(0, FootballTeam::new());
-    /// my_league_season.add_team(1, FootballTeam::new());
-    /// my_league_season.add_team(2, FootballTeam::new());
-    /// my_league_season.add_team(3, FootballTeam::new());
-    ///
-    /// // Generate the season schedule
-    /// let mut rng = rand::thread_rng();
-    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
-    ///
-    /// // Simulate the entire regular season
-    /// my_league_season.sim_regular_season(&mut rng);
-    ///
-    /// // Generate playoffs with 4 teams
-    /// let mut playoff_options = LeagueSeasonPlayoffOptions::new();
-    /// playoff_options.num_playoff_teams = 4;
-    /// my_league_season.generate_playoffs(playoff_options, &mut rng);
-    ///
-    /// // Simulate the first round of conference bracket 0
-    /// let res = my_league_season.sim_playoff_conference_round(0, 0, &mut rng);
-    /// assert!(res.is_ok());
-    /// ```
-    pub fn sim_playoff_conference_round(&mut self, conference: usize, round: usize, rng: &mut impl Rng) -> Result<(), String> {
-        // Ensure the regular season is complete
-        if !self.regular_season_complete() {
-            return Err(String::from("Cannot simulate playoff round: Regular season is not complete"));
+    #[test]
+    fn test_seeding_rule_division_winner_below_wild_card() {
+        use crate::game::context::GameContextBuilder;
+        use crate::league::season::conference::{LeagueConference, LeagueDivision};
+        use crate::league::season::matchup::LeagueSeasonMatchup;
+        use crate::league::season::playoffs::SeedingRule;
+        use crate::league::season::playoffs::picture::{PlayoffPictureOptions, PlayoffStatus};
+        use crate::league::season::week::LeagueSeasonWeek;
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut rng = SmallRng::seed_from_u64(1491);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..12 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        for conf_index in 0..2 {
+            let mut conf = LeagueConference::new();
+            for div_index in 0..2 {
+                let mut div = LeagueDivision::new();
+                for offset in 0..3 {
+                    div.add_team(conf_index * 6 + div_index * 3 + offset).unwrap();
+                }
+                conf.add_division(div).unwrap();
+            }
+            my_league_season.add_conference(conf).unwrap();
         }
 
-        // Ensure the bracket exists
-        let bracket = self.playoffs.conference_bracket(conference)
-            .ok_or_else(|| format!("Cannot simulate playoff round: Conference bracket {} not found", conference))?;
-        if bracket.is_empty() {
-            return Err(String::from("Cannot simulate playoff round: Playoffs have not been generated"));
+        // Each week team i hosts team 6 + i, winning the given number of its
+        // 16 games.  In the first conference team 3 wins its division at 9-7
+        // behind wild cards 1 (12-4) and 2 (11-5), and in the second team 8
+        // wins its division at 5-11 behind wild card 10 (9-7)
+        let home_wins = [13, 12, 11, 9, 7, 3];
+        for week_index in 0..16 {
+            let mut week = LeagueSeasonWeek::new();
+            for (home, wins) in home_wins.iter().enumerate() {
+                let home_won = week_index < *wins;
+                let mut matchup = LeagueSeasonMatchup::new(home, home + 6, "HOME", "AWAY", &mut rng);
+                *matchup.context_mut() = GameContextBuilder::new()
+                    .quarter(4)
+                    .half_seconds(0)
+                    .home_score(if home_won { 1 } else { 0 })
+                    .away_score(if home_won { 0 } else { 1 })
+                    .game_over(true)
+                    .build()
+                    .unwrap();
+                week.matchups_mut().push(matchup);
+            }
+            my_league_season.weeks_mut().push(week);
         }
 
-        // Check if the prior round is complete (if not the first round)
-        if round > 0 {
-            let prev_round = match bracket.get(round - 1) {
-                Some(r) => r,
-                None => return Err(format!("Failed to get previous playoff round {}", round - 1))
-            };
-            if !prev_round.complete() {
-                return Err(format!(
-                    "Cannot simulate playoff round {}: Previous round {} is not complete",
-                    round, round - 1
-                ));
+        let cases = [
+            (SeedingRule::DivisionWinnersFirst, [vec![0, 3, 1], vec![11, 8, 10]]),
+            (SeedingRule::ByRecord, [vec![0, 1, 3], vec![11, 10, 8]])
+        ];
+        for (rule, expected) in cases {
+            // The generated playoffs seed each conference by the rule
+            let options = LeagueSeasonPlayoffOptions::new()
+                .use_conference_brackets(true)
+                .playoff_teams_per_conference(3)
+                .division_winners_guaranteed(true)
+                .seeding_rule(rule);
+            let mut season = my_league_season.clone();
+            season.generate_playoffs(options, &mut rng).unwrap();
+            for seeds in expected.iter() {
+                for (index, team_id) in seeds.iter().enumerate() {
+                    assert_eq!(season.playoffs().team_seed(*team_id).unwrap(), index + 1);
+                }
+            }
+            assert_eq!(season.playoffs().num_teams(), 6);
+
+            // The playoff picture agrees with the generated seeds
+            let options = PlayoffPictureOptions::new()
+                .by_conference(true)
+                .division_winners_guaranteed(true)
+                .seeding_rule(rule);
+            let picture = PlayoffPicture::from_season(&my_league_season, 3, Some(options)).unwrap();
+            for seeds in expected.iter() {
+                for (index, team_id) in seeds.iter().enumerate() {
+                    let seed = match picture.team_status(*team_id).unwrap().status() {
+                        PlayoffStatus::ClinchedTopSeed => 1,
+                        PlayoffStatus::ClinchedPlayoffs { current_seed }
+                        | PlayoffStatus::InPlayoffPosition { current_seed } => *current_seed,
+                        status => panic!("Team {} has status {:?}", team_id, status)
+                    };
+                    assert_eq!(seed, index + 1, "{:?} team {}", rule, team_id);
+                }
             }
+
+            // The wild card displaced by the division winner is out
+            assert_eq!(*picture.team_status(2).unwrap().status(), PlayoffStatus::Eliminated);
+            assert_eq!(*picture.team_status(9).unwrap().status(), PlayoffStatus::Eliminated);
         }
 
-        // Get the number of matchups in this round
-        let num_matchups = match bracket.get(round) {
-            Some(r) => r.matchups().len(),
-            None => return Err(format!("No such playoff round: {}", round))
+        // Without the guarantee, both wild cards make the playoffs
+        let picture = PlayoffPicture::from_season(
+            &my_league_season, 3, Some(PlayoffPictureOptions::new().by_conference(true))
+        ).unwrap();
+        assert!(matches!(
+            picture.team_status(2).unwrap().status(),
+            PlayoffStatus::ClinchedPlayoffs { current_seed: 3 }
+        ));
+        assert_eq!(*picture.team_status(3).unwrap().status(), PlayoffStatus::Eliminated);
+    }
+
+    #[test]
+    fn test_head_to_head_breaks_seeding_tie() {
+        use crate::game::context::GameContextBuilder;
+        use crate::league::season::matchup::LeagueSeasonMatchup;
+        use crate::league::season::week::LeagueSeasonWeek;
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut rng = SmallRng::seed_from_u64(1493);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        let mut add_week = |season: &mut LeagueSeason, games: Vec<(usize, usize, u32, u32)>| {
+            let mut week = LeagueSeasonWeek::new();
+            for (home, away, home_score, away_score) in games {
+                let mut matchup = LeagueSeasonMatchup::new(home, away, "HOME", "AWAY", &mut rng);
+                *matchup.context_mut() = GameContextBuilder::new()
+                    .quarter(4)
+                    .half_seconds(0)
+                    .home_score(home_score)
+                    .away_score(away_score)
+                    .game_over(true)
+                    .build()
+                    .unwrap();
+                week.matchups_mut().push(matchup);
+            }
+            season.weeks_mut().push(week);
         };
 
-        // Simulate each matchup in the round
-        for i in 0..num_matchups {
-            // Skip matchups that have already been completed
-            let is_complete = match self.playoffs.conference_bracket(conference)
-                .and_then(|b| b.get(round)) {
-                Some(r) => match r.matchups().get(i) {
-                    Some(m) => m.context().game_over(),
-                    None => continue
+        // Team 2 sweeps team 1 by three points each
+        add_week(&mut my_league_season, vec![(2, 1, 10, 7), (0, 3, 1, 0), (4, 5, 1, 0)]);
+        add_week(&mut my_league_season, vec![(1, 2, 7, 10), (0, 4, 1, 0), (3, 5, 1, 0)]);
+
+        // Both finish 11-5, team 1 with lopsided wins and close losses and
+        // team 2 with close wins and lopsided losses, so team 1 has the
+        // better point differential.  Team 0 finishes 13-3
+        let score = |won: bool, margin: u32| if won { (margin, 0) } else { (0, margin) };
+        for week in 0..14 {
+            let (h1, a1) = score(week < 11, if week < 11 { 40 } else { 1 });
+            let (h2, a2) = score(week < 9, if week < 9 { 1 } else { 40 });
+            let (h0, a0) = score(week < 11, 1);
+            add_week(&mut my_league_season, vec![(1, 3, h1, a1), (2, 4, h2, a2), (0, 5, h0, a0)]);
+        }
+
+        // The standings order the tied teams by their season series
+        let standings = my_league_season.standings();
+        let ids: Vec<usize> = standings.iter().take(3).map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![0, 2, 1]);
+        assert_eq!(standings[1].1.record_string(), "11-5-0");
+        assert_eq!(standings[2].1.record_string(), "11-5-0");
+
+        // The season series accessor reports the sweep
+        let series = my_league_season.head_to_head(2, 1).unwrap();
+        assert_eq!(series.record.record_string(), "2-0-0");
+        assert_eq!((series.points_for, series.points_against), (20, 14));
+        assert_eq!(series.games.iter().map(|g| g.week).collect::<Vec<_>>(), vec![0, 1]);
+        assert!(my_league_season.head_to_head(1, 1).is_err());
+
+        // The sweep gives team 2 the 2 seed ahead of team 1, both in the
+        // generated playoffs and in the preview
+        let options = LeagueSeasonPlayoffOptions::new().num_playoff_teams(4);
+        let view = LeagueSeasonPlayoffs::preview_from_standings(&my_league_season, options.clone()).unwrap();
+        my_league_season.generate_playoffs(options, &mut rng).unwrap();
+        let playoffs = my_league_season.playoffs();
+        assert_eq!(playoffs.team_seed(0).unwrap(), 1);
+        assert_eq!(playoffs.team_seed(2).unwrap(), 2);
+        assert_eq!(playoffs.team_seed(1).unwrap(), 3);
+        for team in view.teams.iter() {
+            assert_eq!(playoffs.team_seed(team.team_id).unwrap(), team.seed);
+        }
+    }
+
+    #[test]
+    fn test_dashboard_matches_individual_results() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::league::season::attendance::AttendanceModel;
+        use crate::league::season::conference::{LeagueConference, LeagueDivision};
+        use crate::league::season::dashboard::SeasonDashboardOptions;
+
+        let mut rng = SmallRng::seed_from_u64(1456);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..12 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        for conf_index in 0..2 {
+            let mut conf = LeagueConference::new();
+            for div_index in 0..2 {
+                let mut div = LeagueDivision::new();
+                for offset in 0..3 {
+                    div.add_team(conf_index * 6 + div_index * 3 + offset).unwrap();
+                }
+                conf.add_division(div).unwrap();
+            }
+            my_league_season.add_conference(conf).unwrap();
+        }
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        my_league_season.set_attendance_model(Some(AttendanceModel::new())).unwrap();
+
+        // Compare the dashboard before, during, and after the regular season
+        let num_weeks = my_league_season.weeks().len();
+        for week in 0..=num_weeks {
+            if week > 0 {
+                my_league_season.sim_week(week - 1, &mut rng).unwrap();
+            }
+            let options = SeasonDashboardOptions::new().playoff_picture(3);
+            let dashboard = my_league_season.dashboard(options).unwrap();
+            assert_eq!(dashboard.standings, my_league_season.standings());
+            assert_eq!(dashboard.leaders.unwrap(), my_league_season.best_record_teams());
+            assert_eq!(dashboard.playoff_picture.unwrap(), my_league_season.playoff_picture(3).unwrap());
+            let conferences = dashboard.conference_standings.unwrap();
+            assert_eq!(conferences.len(), 2);
+            for (conf_index, standings) in conferences.iter().enumerate() {
+                assert_eq!(*standings, my_league_season.conference_standings(conf_index).unwrap());
+            }
+            let divisions = dashboard.division_standings.unwrap();
+            assert_eq!(divisions.len(), 4);
+            for division in divisions.iter() {
+                assert_eq!(
+                    division.standings,
+                    my_league_season.division_standings(division.conference, division.division).unwrap()
+                );
+            }
+
+            let turnovers = dashboard.turnovers.unwrap();
+            for (id, team_turnovers) in turnovers.iter() {
+                assert_eq!(*team_turnovers, my_league_season.team_turnovers(*id).unwrap());
+            }
+
+            // The current week is the next week to be played
+            match dashboard.week {
+                Some(summary) => {
+                    assert_eq!(summary.week, week);
+                    assert_eq!(summary.completed, 0);
+                    assert_eq!(summary.matchups, my_league_season.weeks()[week].matchups().len());
+                    assert_eq!(summary.attendance, my_league_season.weeks()[week].total_attendance());
                 },
-                None => continue
-            };
-            if is_complete {
-                continue;
+                None => assert_eq!(week, num_weeks)
             }
+        }
 
-            // Simulate the matchup
-            self.sim_playoff_matchup(conference, round, i, rng)?;
+        // Omitted sections are not computed
+        let options = SeasonDashboardOptions::new()
+            .conference_standings(false)
+            .division_standings(false)
+            .leaders(false)
+            .week(false)
+            .turnovers(false);
+        let dashboard = my_league_season.dashboard(options).unwrap();
+        assert!(dashboard.turnovers.is_none());
+        assert!(dashboard.conference_standings.is_none());
+        assert!(dashboard.division_standings.is_none());
+        assert!(dashboard.leaders.is_none());
+        assert!(dashboard.week.is_none());
+        assert!(dashboard.playoff_picture.is_none());
+        assert_eq!(dashboard.standings, my_league_season.standings());
+    }
+
+    #[test]
+    fn test_report_markdown_golden() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        // Simulate a seeded season with names requiring markdown escapes
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        *my_league_season.year_mut() = 2024;
+        let names = ["Bay City Bears", "St. Louis Stars", "Hawks | Eagles", "*Red* Raiders"];
+        for (id, name) in names.iter().enumerate() {
+            let mut team = FootballTeam::new();
+            *team.name_mut() = String::from(*name);
+            let _ = my_league_season.add_team(id, team);
         }
-        Ok(())
+        let mut rng = SmallRng::seed_from_u64(1458);
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        my_league_season.sim_regular_season(&mut rng).unwrap();
+        let mut playoff_options = LeagueSeasonPlayoffOptions::new();
+        playoff_options.num_playoff_teams = 4;
+        my_league_season.generate_playoffs(playoff_options, &mut rng).unwrap();
+        my_league_season.sim_playoffs(&mut rng).unwrap();
+
+        // The full markdown report matches the golden file
+        let report = my_league_season.report_markdown(SeasonReportOptions::new());
+        if std::env::var("FBSIM_UPDATE_GOLDEN").is_ok() {
+            std::fs::write("tests/golden/season_report.md", &report).unwrap();
+        }
+        assert_eq!(report, include_str!("../../tests/golden/season_report.md"));
+
+        // The plain text report contains the same sections without markup
+        let text = my_league_season.report_text(SeasonReportOptions::new());
+        assert!(text.starts_with("2024 Season Report\n==================\n"));
+        assert!(text.contains("Hawks | Eagles"));
+        assert!(!text.contains('\\'));
+        assert_eq!(text, my_league_season.report_text(SeasonReportOptions::new()));
     }
 
-    /// Simulate a winners bracket round
-    ///
-    /// ### Example
-    /// ```
-    /// use fbsim_core::team::FootballTeam;
-    /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
-    /// use fbsim_core::league::season::conference::{LeagueConference, LeagueDivision};
-    ///
-    /// // Create a new season with 2 conferences
-    /// let mut my_league_season = LeagueSeason::new();
-    ///
-    /// // Add teams
-    /// my_league_season.add_team(0, FootballTeam::new());
-    /// my_league_season.add_team(1, FootballTeam::new());
-    /// my_league_season.add_team(2, FootballTeam::new());
-    /// my_league_season.add_team(3, FootballTeam::new());
-    ///
-    /// // Create conference structure
-    /// let mut afc = LeagueConference::with_name("AFC");
-    /// let mut afc_div = LeagueDivision::with_name("East");
-    /// afc_div.add_team(0);
-    /// afc_div.add_team(1);
-    /// afc.add_division(afc_div);
-    /// my_league_season.add_conference(afc);
-    ///
-    /// let mut nfc = LeagueConference::with_name("NFC");
-    /// let mut nfc_div = LeagueDivision::with_name("East");
-    /// nfc_div.add_team(2);
-    /// nfc_div.add_team(3);
-    /// nfc.add_division(nfc_div);
-    /// my_league_season.add_conference(nfc);
-    ///
-    /// // Generate schedule and simulate regular season
-    /// let mut rng = rand::thread_rng();
-    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
-    /// my_league_season.sim_regular_season(&mut rng);
-    ///
-    /// // Generate conference playoffs and simulate all conference brackets
-    /// let mut options = LeagueSeasonPlayoffOptions::new();
-    /// options.use_conference_brackets = true;
-    /// options.playoff_teams_per_conference = 2;
-    /// options.division_winners_guaranteed = true;
-    /// let _ = my_league_season.generate_playoffs(options, &mut rng);
-    /// let _ = my_league_season.sim_playoff_round(0, &mut rng);
-    ///
-    /// // Generate the winners bracket
-    /// let _ = my_league_season.generate_next_playoff_round(&mut rng);
-    ///
-    /// // Simulate the first winners bracket round
-    /// let res = my_league_season.sim_winners_bracket_round(0, &mut rng);
-    /// assert!(res.is_ok());
-    /// ```
-    pub fn sim_winners_bracket_round(&mut self, round: usize, rng: &mut impl Rng) -> Result<(), String> {
-        // Ensure the regular season is complete
-        if !self.regular_season_complete() {
-            return Err(String::from("Cannot simulate winners bracket round: Regular season is not complete"));
+    #[test]
+    fn test_schedule_options_validate_shift() {
+        // A shift equal to the number of weeks is rejected
+        let options = LeagueSeasonScheduleOptions::new().shift(6);
+        assert!(options.validate(4).is_err());
+        assert!(LeagueSeasonScheduleOptions::new().shift(5).validate(4).is_ok());
+        let options = LeagueSeasonScheduleOptions::new().weeks(4).shift(4);
+        assert!(options.validate(4).is_err());
+        let options = LeagueSeasonScheduleOptions::new()
+            .format(ScheduleFormat::Partial { games_per_team: 5 })
+            .shift(5);
+        assert!(options.validate(6).is_err());
+
+        // Generation rejects the same options without modifying the season
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            let _ = my_league_season.add_team(id, FootballTeam::new());
+        }
+        let mut rng = rand::thread_rng();
+        let res = my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new().shift(6), &mut rng);
+        assert!(res.is_err());
+        assert!(my_league_season.weeks().is_empty());
+    }
+
+    #[test]
+    fn test_schedule_options_validate_shift_and_permute() {
+        // A shift cannot be combined with a permutation
+        let options = LeagueSeasonScheduleOptions::new().shift(2).permute(true);
+        assert!(options.validate(4).is_err());
+        let options = LeagueSeasonScheduleOptions::new()
+            .format(ScheduleFormat::Partial { games_per_team: 3 })
+            .shift(1)
+            .permute(true);
+        assert!(options.validate(4).is_err());
+
+        // Either alone, or a zero shift, is accepted
+        assert!(LeagueSeasonScheduleOptions::new().shift(2).permute(false).validate(4).is_ok());
+        assert!(LeagueSeasonScheduleOptions::new().permute(true).validate(4).is_ok());
+        assert!(LeagueSeasonScheduleOptions::new().shift(0).permute(true).validate(4).is_ok());
+    }
+
+    #[test]
+    fn test_schedule_options_validate_team_count() {
+        // Too few teams is rejected, and an odd number of teams only when
+        // byes are disallowed or every team must play every week
+        assert!(LeagueSeasonScheduleOptions::new().validate(2).is_err());
+        assert!(LeagueSeasonScheduleOptions::new().validate(5).is_ok());
+        assert!(LeagueSeasonScheduleOptions::new().allow_byes(false).validate(5).is_err());
+        let options = LeagueSeasonScheduleOptions::new()
+            .format(ScheduleFormat::Partial { games_per_team: 2 });
+        assert!(options.validate(5).is_err());
+
+        // The number of weeks must be within the round robin bounds
+        assert!(LeagueSeasonScheduleOptions::new().weeks(2).validate(4).is_err());
+        assert!(LeagueSeasonScheduleOptions::new().weeks(10).validate(4).is_err());
+        assert!(LeagueSeasonScheduleOptions::new().weeks(9).validate(4).is_ok());
+        let options = LeagueSeasonScheduleOptions::new()
+            .format(ScheduleFormat::Partial { games_per_team: 4 })
+            .weeks(5);
+        assert!(options.validate(6).is_err());
+    }
+
+    #[test]
+    fn test_structured_schedule_rejects_shift_and_permute() {
+        // Create a season with two conferences
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..8 {
+            let _ = my_league_season.add_team(id, FootballTeam::new());
         }
+        for (name, teams) in [("East", 0..4), ("West", 4..8)] {
+            let mut division = LeagueDivision::with_name(name);
+            for id in teams {
+                division.add_team(id).unwrap();
+            }
+            let mut conference = LeagueConference::with_name(name);
+            conference.add_division(division).unwrap();
+            my_league_season.add_conference(conference).unwrap();
+        }
+        let mut rng = rand::thread_rng();
 
-        // Ensure this is a conference playoff
-        if !self.playoffs.is_conference_playoff() {
-            return Err(String::from("Cannot simulate winners bracket: Not a conference playoff"));
+        // A permuted shift is rejected before generating the schedule
+        let options = LeagueSeasonScheduleOptions::new().shift(1).permute(true);
+        assert!(my_league_season.generate_schedule(options, &mut rng).is_err());
+        assert!(my_league_season.weeks().is_empty());
+
+        // A shift of at least the number of weeks is rejected once generated
+        let options = LeagueSeasonScheduleOptions::new().shift(1000);
+        assert!(my_league_season.generate_schedule(options, &mut rng).is_err());
+        assert!(my_league_season.weeks().is_empty());
+        let options = LeagueSeasonScheduleOptions::new().shift(1);
+        assert!(my_league_season.generate_schedule(options, &mut rng).is_ok());
+    }
+
+    #[test]
+    fn test_generation_is_deterministic() {
+        // Create a flat season and a season with two conferences
+        let mut flat_season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            flat_season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        let mut structured_season = LeagueSeason::with_year(2026);
+        for id in 0..8 {
+            structured_season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        for (name, teams) in [("East", [0, 1, 2, 3]), ("West", [4, 5, 6, 7])] {
+            let mut conference = LeagueConference::with_name(name);
+            for pair in teams.chunks(2) {
+                let mut division = LeagueDivision::with_name(name);
+                for id in pair {
+                    division.add_team(*id).unwrap();
+                }
+                conference.add_division(division).unwrap();
+            }
+            structured_season.add_conference(conference).unwrap();
         }
 
-        // Ensure the winners bracket has started
-        if self.playoffs.winners_bracket().is_empty() {
-            return Err(String::from("Cannot simulate winners bracket round: Winners bracket has not been generated"));
+        // Schedule generation
+        let partial = LeagueSeasonScheduleOptions::new()
+            .format(ScheduleFormat::Partial { games_per_team: 4 });
+        let cross_conference = LeagueSeasonScheduleOptions::new().cross_conference_games(2);
+        assert_deterministic!(flat_season, |s, rng| s.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng));
+        assert_deterministic!(flat_season, |s, rng| s.generate_schedule(partial.clone(), &mut rng));
+        assert_deterministic!(structured_season, |s, rng| s.generate_schedule(cross_conference.clone(), &mut rng));
+
+        // Regular season simulation
+        let mut rng = rand::thread_rng();
+        flat_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        structured_season.generate_schedule(cross_conference.clone(), &mut rng).unwrap();
+        assert_deterministic!(flat_season, |s, rng| s.sim_play(0, 0, &mut rng));
+        assert_deterministic!(flat_season, |s, rng| s.sim_matchup(0, 0, &mut rng));
+        assert_deterministic!(flat_season, |s, rng| s.sim_matchups(0, &[2, 0], false, &mut rng));
+        assert_deterministic!(flat_season, |s, rng| s.sim_week(0, &mut rng));
+        assert_deterministic!(flat_season, |s, rng| s.sim_regular_season(&mut rng));
+        assert_deterministic!(flat_season, |s, rng| s.sim_weeks(0, 2, &mut rng));
+        assert_deterministic!(flat_season, |s, rng| s.sim(&mut rng));
+
+        // Playoff generation and simulation
+        flat_season.sim_regular_season(&mut rng).unwrap();
+        structured_season.sim_regular_season(&mut rng).unwrap();
+        let mut options = LeagueSeasonPlayoffOptions::new();
+        options.num_playoff_teams = 4;
+        let conference_options = LeagueSeasonPlayoffOptions::new()
+            .use_conference_brackets(true)
+            .playoff_teams_per_conference(2);
+        assert_deterministic!(flat_season, |s, rng| s.generate_playoffs(options.clone(), &mut rng));
+        assert_deterministic!(structured_season, |s, rng| s.generate_playoffs(conference_options.clone(), &mut rng));
+        flat_season.generate_playoffs(options.clone(), &mut rng).unwrap();
+        structured_season.generate_playoffs(conference_options.clone(), &mut rng).unwrap();
+        assert_deterministic!(flat_season, |s, rng| s.sim_playoff_matchup(0, 0, 0, &mut rng));
+        assert_deterministic!(flat_season, |s, rng| s.sim_playoff_play(0, 0, 0, &mut rng));
+        assert_deterministic!(flat_season, |s, rng| s.sim_playoff_conference_round(0, 0, &mut rng));
+        assert_deterministic!(flat_season, |s, rng| {
+            s.sim_playoff_round(0, &mut rng).and_then(|_| s.generate_next_playoff_round(&mut rng))
+        });
+        assert_deterministic!(flat_season, |s, rng| s.sim_playoffs(&mut rng));
+        assert_deterministic!(structured_season, |s, rng| s.sim_playoffs(&mut rng));
+        assert_deterministic!(structured_season, |s, rng| {
+            s.sim_playoff_conference_round(0, 0, &mut rng)
+                .and_then(|_| s.sim_playoff_conference_round(1, 0, &mut rng))
+                .and_then(|_| s.generate_next_playoff_round(&mut rng))
+                .and_then(|_| s.sim_winners_bracket_round(0, &mut rng))
+        });
+
+        // Title playoff generation
+        let mut title_season = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            title_season.add_team(id, FootballTeam::new()).unwrap();
         }
+        title_season.set_championship_mode(ChampionshipMode::BestRecord).unwrap();
+        title_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        finish_regular_season(&mut title_season, |home, away| home < 2 || away >= 2);
+        assert_deterministic!(title_season, |s, rng| s.generate_title_playoff(&mut rng));
+    }
 
-        // Check if the prior round is complete (if not the first round)
-        if round > 0 {
-            let prev_round = match self.playoffs.winners_bracket().get(round - 1) {
-                Some(r) => r,
-                None => return Err(format!("Failed to get previous winners bracket round {}", round - 1))
-            };
-            if !prev_round.complete() {
-                return Err(format!(
-                    "Cannot simulate winners bracket round {}: Previous round {} is not complete",
-                    round, round - 1
-                ));
+    #[test]
+    fn test_turnovers_balance_across_league() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        // Simulate a season play by play
+        let mut rng = SmallRng::seed_from_u64(1463);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        my_league_season.sim_regular_season(&mut rng).unwrap();
+
+        // League-wide giveaways equal takeaways, and margins sum to zero
+        let mut giveaways = 0;
+        let mut takeaways = 0;
+        let mut margin = 0;
+        for id in 0..6 {
+            let turnovers = my_league_season.team_turnovers(id).unwrap().unwrap();
+            giveaways += turnovers.giveaways();
+            takeaways += turnovers.takeaways();
+            margin += my_league_season.turnover_margin(id).unwrap().unwrap();
+        }
+        assert_eq!(giveaways, takeaways);
+        assert!(giveaways > 0);
+        assert_eq!(margin, 0);
+
+        // A game completed without detailed stats leaves the totals unknown
+        *my_league_season.weeks_mut()[0].matchups_mut()[0].home_stats_mut() = None;
+        let home = *my_league_season.weeks()[0].matchups()[0].home_team();
+        let away = *my_league_season.weeks()[0].matchups()[0].away_team();
+        assert!(my_league_season.turnover_margin(home).unwrap().is_none());
+        assert!(my_league_season.turnover_margin(away).unwrap().is_none());
+        let other = (0..6).find(|id| *id != home && *id != away).unwrap();
+        assert!(my_league_season.turnover_margin(other).unwrap().is_some());
+        assert!(my_league_season.turnover_margin(6).is_err());
+    }
+
+    #[test]
+    fn test_predict_week_does_not_modify_season() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut rng = SmallRng::seed_from_u64(1464);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+
+        // Predicting the current and a forced future week leaves the season
+        // unchanged
+        let before = my_league_season.clone();
+        let predictions = my_league_season.predict_week(0, false, &mut rng).unwrap();
+        assert_eq!(predictions.len(), my_league_season.weeks()[0].matchups().len());
+        assert!(my_league_season.predict_week(1, false, &mut rng).is_err());
+        let future = my_league_season.predict_week(1, true, &mut rng).unwrap();
+        assert!(future.iter().all(|p| p.week == 1));
+        assert_eq!(my_league_season, before);
+        assert!(my_league_season.weeks().iter().all(|w| !w.complete()));
+
+        // The same seed gives the same predictions
+        let mut rng_a = SmallRng::seed_from_u64(7);
+        let mut rng_b = SmallRng::seed_from_u64(7);
+        assert_eq!(
+            my_league_season.predict_week(0, false, &mut rng_a).unwrap(),
+            my_league_season.predict_week(0, false, &mut rng_b).unwrap()
+        );
+
+        // Completed matchups are not predicted
+        my_league_season.sim_matchup(0, 0, &mut rng).unwrap();
+        let remaining = my_league_season.predict_week(0, false, &mut rng).unwrap();
+        assert_eq!(remaining.len(), predictions.len() - 1);
+        assert!(remaining.iter().all(|p| p.matchup != 0));
+        assert!(my_league_season.predict_week(99, true, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_score_predictions() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut rng = SmallRng::seed_from_u64(1464);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        let predictions = my_league_season.predict_week(0, false, &mut rng).unwrap();
+
+        // Predictions cannot be scored before the week completes
+        assert!(my_league_season.score_predictions(0, &predictions).is_err());
+        my_league_season.sim_week(0, &mut rng).unwrap();
+
+        // The actual results score as perfect predictions
+        let actual: Vec<PredictedResult> = predictions.iter()
+            .map(|p| {
+                let context = my_league_season.weeks()[0].matchups()[p.matchup].context();
+                PredictedResult {
+                    home_score: context.home_score(),
+                    away_score: context.away_score(),
+                    ..p.clone()
+                }
+            })
+            .collect();
+        let accuracy = my_league_season.score_predictions(0, &actual).unwrap();
+        assert_eq!(accuracy.predictions, 2);
+        assert_eq!(accuracy.correct_winners, 2);
+        assert_eq!(accuracy.average_score_error, 0.0);
+
+        // Scores off by 3 points each give an average error of 3
+        let off: Vec<PredictedResult> = actual.iter()
+            .map(|p| PredictedResult { home_score: p.home_score + 3, away_score: p.away_score + 3, ..p.clone() })
+            .collect();
+        let accuracy = my_league_season.score_predictions(0, &off).unwrap();
+        assert_eq!(accuracy.correct_winners, 2);
+        assert_eq!(accuracy.average_score_error, 3.0);
+
+        // The original predictions score within bounds
+        let accuracy = my_league_season.score_predictions(0, &predictions).unwrap();
+        assert_eq!(accuracy.predictions, 2);
+        assert!(accuracy.correct_winners <= 2);
+
+        // Predictions for another week or matchup are rejected
+        assert!(my_league_season.score_predictions(1, &predictions).is_err());
+        let mismatched = vec![PredictedResult { home_team: 99, ..predictions[0].clone() }];
+        assert!(my_league_season.score_predictions(0, &mismatched).is_err());
+    }
+
+    /// Complete every regular season matchup as a tie
+    fn tie_regular_season(season: &mut LeagueSeason) {
+        use crate::game::context::GameContextBuilder;
+
+        for week in season.weeks_mut().iter_mut() {
+            for matchup in week.matchups_mut().iter_mut() {
+                *matchup.context_mut() = GameContextBuilder::new()
+                    .quarter(4)
+                    .half_seconds(0)
+                    .home_score(7)
+                    .away_score(7)
+                    .game_over(true)
+                    .build()
+                    .unwrap();
             }
         }
+    }
 
-        // Get the number of matchups in this round
-        let num_matchups = match self.playoffs.winners_bracket().get(round) {
-            Some(r) => r.matchups().len(),
-            None => return Err(format!("No such winners bracket round: {}", round))
+    #[test]
+    fn test_tied_records_give_pinned_brackets() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::league::season::conference::{LeagueConference, LeagueDivision};
+
+        let build = || {
+            let mut rng = SmallRng::seed_from_u64(1465);
+            let mut season = LeagueSeason::with_year(2026);
+            for id in [5, 2, 7, 0, 6, 3, 1, 4] {
+                season.add_team(id, FootballTeam::new()).unwrap();
+            }
+            for conf_index in 0..2 {
+                let mut conf = LeagueConference::new();
+                for div_index in 0..2 {
+                    let mut div = LeagueDivision::new();
+                    div.add_team(conf_index * 4 + div_index * 2 + 1).unwrap();
+                    div.add_team(conf_index * 4 + div_index * 2).unwrap();
+                    conf.add_division(div).unwrap();
+                }
+                season.add_conference(conf).unwrap();
+            }
+            season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+            tie_regular_season(&mut season);
+            season
         };
 
-        // Simulate each matchup in the round
-        for i in 0..num_matchups {
-            // Skip matchups that have already been completed
-            let is_complete = match self.playoffs.winners_bracket().get(round) {
-                Some(r) => match r.matchups().get(i) {
-                    Some(m) => m.context().game_over(),
-                    None => continue
-                },
-                None => continue
-            };
-            if is_complete {
-                continue;
+        // Equal records sort by team ID
+        let season = build();
+        let ids: Vec<usize> = season.standings().iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, (0..8).collect::<Vec<usize>>());
+        assert_eq!(season.best_record_teams(), (0..8).collect::<Vec<usize>>());
+
+        // Each format seeds equal records by team ID, pinning its bracket
+        let cases = [
+            (
+                LeagueSeasonPlayoffOptions::new().num_playoff_teams(4),
+                vec![(0, 1, 0), (0, 2, 1), (0, 3, 2), (0, 4, 3)],
+                vec![(0, 0, 3), (0, 1, 2)]
+            ),
+            (
+                LeagueSeasonPlayoffOptions::new()
+                    .use_conference_brackets(true)
+                    .playoff_teams_per_conference(2),
+                vec![(0, 1, 0), (0, 2, 1), (1, 1, 4), (1, 2, 5)],
+                vec![(0, 0, 1), (1, 4, 5)]
+            ),
+            (
+                LeagueSeasonPlayoffOptions::new()
+                    .use_conference_brackets(true)
+                    .playoff_teams_per_conference(3)
+                    .division_winners_guaranteed(true),
+                vec![(0, 1, 0), (0, 2, 2), (0, 3, 1), (1, 1, 4), (1, 2, 6), (1, 3, 5)],
+                vec![(0, 2, 1), (1, 6, 5)]
+            ),
+        ];
+        for (options, expected_seeds, expected_first_round) in cases {
+            let mut season = build();
+            let mut rng = SmallRng::seed_from_u64(1465);
+            season.generate_playoffs(options, &mut rng).unwrap();
+            let mut seeds: Vec<(usize, usize, usize)> = Vec::new();
+            for conference in season.playoffs().teams().conferences().copied() {
+                for (id, team) in season.playoffs().teams().conference_teams_by_seed(conference) {
+                    seeds.push((conference, team.seed(), id));
+                }
             }
+            assert_eq!(seeds, expected_seeds);
+            assert_eq!(generated_first_round(&season), expected_first_round);
+        }
+    }
 
-            // Simulate the matchup
-            self.sim_winners_bracket_matchup(round, i, rng)?;
+    #[test]
+    fn test_malformed_playoff_seeds_are_rejected() {
+        use crate::league::season::playoffs::PlayoffTeams;
+
+        let parse = |conference: serde_json::Value| {
+            serde_json::from_value::<PlayoffTeams>(serde_json::json!({
+                "teams": { "0": { "5": { "seed": 1, "short_name": "E" } }, "1": conference }
+            })).map_err(|e| e.to_string())
+        };
+
+        // Seeds running from 1 to the number of teams are accepted in any order
+        let teams = parse(serde_json::json!({
+            "9": { "seed": 2, "short_name": "I" },
+            "2": { "seed": 1, "short_name": "B" },
+            "7": { "seed": 3, "short_name": "G" }
+        })).unwrap();
+        let by_seed: Vec<(usize, usize)> = teams.conference_teams_by_seed(1).iter()
+            .map(|(id, team)| (team.seed(), *id))
+            .collect();
+        assert_eq!(by_seed, vec![(1, 2), (2, 9), (3, 7)]);
+
+        // Two teams sharing a seed
+        let err = parse(serde_json::json!({
+            "9": { "seed": 1, "short_name": "I" },
+            "4": { "seed": 2, "short_name": "D" },
+            "2": { "seed": 1, "short_name": "B" }
+        })).unwrap_err();
+        assert!(err.contains("Duplicate seed 1 in playoff conference 1 (teams 2 and 9)"), "{}", err);
+
+        // A gap in the seeds
+        let err = parse(serde_json::json!({
+            "2": { "seed": 1, "short_name": "B" },
+            "4": { "seed": 3, "short_name": "D" }
+        })).unwrap_err();
+        assert!(err.contains("Seed 3 in playoff conference 1 is out of range: seeds must run from 1 to 2 (seed 2 is missing)"), "{}", err);
+
+        // A zero seed
+        let err = parse(serde_json::json!({
+            "2": { "seed": 0, "short_name": "B" }
+        })).unwrap_err();
+        assert!(err.contains("Seed 0 in playoff conference 1 is out of range: seeds must run from 1 to 1 (seed 1 is missing)"), "{}", err);
+
+        // The errors surface when loading a whole season
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..2 {
+            season.add_team(id, FootballTeam::new()).unwrap();
         }
-        Ok(())
+        season.playoffs_mut().add_team(0, "A", None).unwrap();
+        season.playoffs_mut().add_team(1, "B", None).unwrap();
+        let json = serde_json::to_string(&season).unwrap()
+            .replace(r#"{"seed":2,"short_name":"B"}"#, r#"{"seed":1,"short_name":"B"}"#);
+        let err = serde_json::from_str::<LeagueSeason>(&json).unwrap_err().to_string();
+        assert!(err.contains("Duplicate seed 1 in playoff conference 0 (teams 0 and 1)"), "{}", err);
     }
 
-    /// Simulate a full playoff round across all conference brackets
-    ///
-    /// ### Example
-    /// ```
-    /// use fbsim_core::team::FootballTeam;
-    /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
-    ///
-    /// // Create a new season
-    /// let mut my_league_season = LeagueSeason::new();
-    ///
-    /// // Add 4 teams to the season
-    /// my_league_season.add_team(0, FootballTeam::new());
-    /// my_league_season.add_team(1, FootballTeam::new());
-    /// my_league_season.add_team(2, FootballTeam::new());
-    /// my_league_season.add_team(3, FootballTeam::new());
-    ///
-    /// // Generate the season schedule
-    /// let mut rng = rand::thread_rng();
-    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
-    ///
-    /// // Simulate the entire regular season
-    /// my_league_season.sim_regular_season(&mut rng);
-    ///
-    /// // Generate playoffs with 4 teams
-    /// let mut playoff_options = LeagueSeasonPlayoffOptions::new();
-    /// playoff_options.num_playoff_teams = 4;
-    /// my_league_season.generate_playoffs(playoff_options, &mut rng);
-    ///
-    /// // Simulate the first playoff round
-    /// let res = my_league_season.sim_playoff_round(0, &mut rng);
-    /// assert!(res.is_ok());
-    /// ```
-    pub fn sim_playoff_round(&mut self, round: usize, rng: &mut impl Rng) -> Result<(), String> {
-        // Ensure the regular season is complete
-        if !self.regular_season_complete() {
-            return Err(String::from("Cannot simulate playoff round: Regular season is not complete"));
+    #[test]
+    fn test_anomalies_flag_out_of_bounds_scores() {
+        use crate::game::context::GameContextBuilder;
+
+        let mut rng = SmallRng::seed_from_u64(1467);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
         }
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        finish_regular_season(&mut my_league_season, |home, _| home < 2);
+
+        // Import an absurd result and a blowout
+        let set_score = |season: &mut LeagueSeason, week: usize, home_score: u32, away_score: u32| {
+            *season.weeks_mut()[week].matchups_mut()[0].context_mut() = GameContextBuilder::new()
+                .quarter(4)
+                .half_seconds(0)
+                .home_score(home_score)
+                .away_score(away_score)
+                .game_over(true)
+                .build()
+                .unwrap();
+        };
+        set_score(&mut my_league_season, 0, 91, 3);
+        set_score(&mut my_league_season, 1, 49, 7);
+
+        // Bounds are off by default
+        assert!(my_league_season.anomalies(&ScoreBounds::new()).is_empty());
+
+        // Each bound flags the results outside of it
+        let capped = my_league_season.anomalies(&ScoreBounds::new().max_score(60));
+        assert_eq!(capped.len(), 1);
+        assert_eq!((capped[0].week, capped[0].matchup, capped[0].home_score, capped[0].away_score), (0, 0, 91, 3));
+        let blowouts = my_league_season.anomalies(&ScoreBounds::new().max_margin(35));
+        let weeks: Vec<usize> = blowouts.iter().map(|a| a.week).collect();
+        assert_eq!(weeks, vec![0, 1]);
+
+        // Unplayed matchups are never flagged
+        *my_league_season.weeks_mut()[0].matchups_mut()[0].context_mut() = GameContextBuilder::new()
+            .home_score(91)
+            .build()
+            .unwrap();
+        assert!(my_league_season.anomalies(&ScoreBounds::new().max_score(60)).is_empty());
+    }
 
-        // Ensure the playoffs have started
-        if self.playoffs.conference_brackets().is_empty() {
-            return Err(String::from("Cannot simulate playoff round: Playoffs have not been generated"));
+    #[test]
+    fn test_inconsistent_matchups_are_repaired_or_rejected() {
+        let mut rng = SmallRng::seed_from_u64(1469);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
         }
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        my_league_season.sim_week(0, &mut rng).unwrap();
+        let saved = serde_json::to_value(&my_league_season).unwrap();
+
+        // A completed matchup saved with its game still in progress is
+        // repaired by dropping the game and keeping the archived stats
+        let mut in_progress = saved.clone();
+        in_progress["weeks"][0]["matchups"][0]["game"] = serde_json::to_value(Game::new()).unwrap();
+        let repaired: LeagueSeason = serde_json::from_value(in_progress.clone()).unwrap();
+        assert_eq!(repaired, my_league_season);
+        assert_eq!(serde_json::from_value::<LeagueSeason>(in_progress).unwrap(), repaired);
+
+        // Missing stats are archived from the game being dropped
+        let mut missing_stats = saved.clone();
+        let matchup = missing_stats["weeks"][0]["matchups"][0].as_object_mut().unwrap();
+        matchup.remove("home_stats");
+        matchup.remove("away_stats");
+        matchup.insert(String::from("game"), serde_json::to_value(Game::new()).unwrap());
+        let repaired: LeagueSeason = serde_json::from_value(missing_stats).unwrap();
+        let matchup = &repaired.weeks()[0].matchups()[0];
+        assert!(matchup.game().is_none());
+        assert_eq!(*matchup.home_stats(), Some(Game::new().home_stats()));
+
+        // A matchup whose stats say it was played but whose context says it
+        // was not is rejected rather than counted by only some views
+        let mut unplayed = saved.clone();
+        unplayed["weeks"][0]["matchups"][0]["context"]
+            .as_object_mut()
+            .unwrap()
+            .remove("game_over");
+        let error = serde_json::from_value::<LeagueSeason>(unplayed).unwrap_err();
+        assert!(error.to_string().contains("context is not over"));
+
+        // So is an unplayed matchup given stats
+        let mut stats = saved.clone();
+        stats["weeks"][1]["matchups"][0]["home_stats"] = saved["weeks"][0]["matchups"][0]["home_stats"].clone();
+        assert!(serde_json::from_value::<LeagueSeason>(stats).is_err());
+    }
 
-        // Simulate the round in each conference bracket
-        let conference_ids: Vec<usize> = self.playoffs.conference_brackets().keys().copied().collect();
-        for conf_id in conference_ids {
-            // Skip conferences that don't have this round
-            let has_round = self.playoffs.conference_bracket(conf_id)
-                .is_some_and(|b| b.len() > round);
-            if !has_round {
-                continue;
+    #[test]
+    fn test_rest_weeks_and_bye_rest_advantage() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::game::play::PlaySimulatable;
+        use crate::league::season::matchup::MatchupRestAdvantage;
+
+        let build = |options: LeagueSeasonPlayoffOptions| {
+            let mut rng = SmallRng::seed_from_u64(1470);
+            let mut season = LeagueSeason::with_year(2026);
+            for id in 0..6 {
+                season.add_team(id, FootballTeam::new()).unwrap();
             }
+            season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+            finish_regular_season(&mut season, |home, away| home < away);
+            season.generate_playoffs(options, &mut rng).unwrap();
+            season.sim_playoff_round(0, &mut rng).unwrap();
+            season.generate_next_playoff_round(&mut rng).unwrap();
+            season
+        };
 
-            // Skip conferences whose round is already complete
-            let round_complete = self.playoffs.conference_bracket(conf_id)
-                .and_then(|b| b.get(round))
-                .is_some_and(|r| r.complete());
-            if round_complete {
-                continue;
+        // Without options the schedule is unchanged and nobody is rested
+        let season = build(LeagueSeasonPlayoffOptions::new().num_playoff_teams(6));
+        let weeks = season.weeks().len();
+        assert_eq!(season.playoff_round_week(false, 1), weeks + 1);
+        let bracket = season.playoffs().conference_bracket(0).unwrap();
+        assert!(bracket.iter().flat_map(|w| w.matchups()).all(|m| m.rest_advantage().is_none()));
+
+        // A rest week after the wild card round delays every later round
+        let season = build(
+            LeagueSeasonPlayoffOptions::new()
+                .num_playoff_teams(6)
+                .rest_weeks_between_rounds(vec![1])
+                .rest_advantage(5)
+        );
+        assert_eq!(season.playoff_round_week(false, 0), weeks);
+        assert_eq!(season.playoff_round_week(false, 1), weeks + 2);
+        assert_eq!(season.playoff_round_week(false, 2), weeks + 3);
+
+        // Wild card teams are equally rested, bye teams are rested one week more
+        let bracket = season.playoffs().conference_bracket(0).unwrap();
+        assert!(bracket[0].matchups().iter().all(|m| m.rest_advantage().is_none()));
+        let byes = [0, 1];
+        for matchup in bracket[1].matchups() {
+            let (home, away) = (*matchup.home_team(), *matchup.away_team());
+            let rested = if byes.contains(&home) { home } else { away };
+            let other = if rested == home { away } else { home };
+            assert!(byes.contains(&rested) && !byes.contains(&other));
+            assert_eq!(
+                matchup.rest_advantage(),
+                Some(&MatchupRestAdvantage { team: rested, rest_weeks: 1, bonus: 5 })
+            );
+
+            // Only the rested side's effective skills are raised
+            let rested_team = season.team(rested).unwrap();
+            let other_team = season.team(other).unwrap();
+            assert_eq!(
+                matchup.rested_team(rested, rested_team).offense().overall(),
+                rested_team.offense().overall() + 5
+            );
+            assert_eq!(matchup.rested_team(other, other_team).as_ref(), other_team);
+        }
+    }
+
+    #[test]
+    fn test_import_example_teams_and_sim_week() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let example = include_str!("../../docs/league/season/teams.json");
+        let mut season = LeagueSeason::with_year(2026);
+        season.import_teams(example.as_bytes()).unwrap();
+        assert_eq!(season.teams().len(), 8);
+        assert_eq!(season.team(4).unwrap().short_name(), "GATR");
+
+        // The conference structure is created in order of appearance
+        let names: Vec<&str> = season.conferences().iter().map(|c| c.name()).collect();
+        assert_eq!(names, vec!["North", "South"]);
+        assert_eq!(season.conferences()[1].divisions()[1].name(), "Mesa");
+        assert_eq!(season.conferences()[1].divisions()[1].teams(), &vec![6, 7]);
+
+        // Exporting and re-importing reproduces the season's teams
+        let mut json: Vec<u8> = Vec::new();
+        season.export_teams(&mut json).unwrap();
+        let mut copy = LeagueSeason::with_year(2026);
+        copy.import_teams(json.as_slice()).unwrap();
+        assert_eq!(copy.teams(), season.teams());
+        assert_eq!(copy.conferences(), season.conferences());
+
+        // The imported league can be scheduled and simulated
+        let mut rng = SmallRng::seed_from_u64(1471);
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        season.sim_week(0, &mut rng).unwrap();
+        assert!(season.weeks()[0].complete());
+    }
+
+    #[test]
+    fn test_import_teams_is_atomic() {
+        let example = include_str!("../../docs/league/season/teams.json");
+        let definitions: serde_json::Value = serde_json::from_str(example).unwrap();
+        let mut season = LeagueSeason::with_year(2026);
+        season.add_team(10, FootballTeam::from_overalls("Existing", "EXST", 50, 50).unwrap()).unwrap();
+        let before = season.clone();
+
+        // Each invalid batch is rejected without adding any of its teams
+        let cases: [fn(&mut serde_json::Value); 8] = [
+            (|d| d["teams"][7]["id"] = serde_json::json!(0)),
+            (|d| d["teams"][7]["short_name"] = serde_json::json!("HWK")),
+            (|d| d["teams"][7]["short_name"] = serde_json::json!("EXST")),
+            (|d| d["teams"][7]["id"] = serde_json::json!(10)),
+            (|d| d["teams"][7]["offense"]["passing"] = serde_json::json!(101)),
+            (|d| { d["teams"][7].as_object_mut().unwrap().remove("division"); }),
+            (|d| {
+                for team in d["teams"].as_array_mut().unwrap() {
+                    let team = team.as_object_mut().unwrap();
+                    team.remove("conference");
+                    team.remove("division");
+                }
+                d["teams"][0]["conference"] = serde_json::json!("North");
+                d["teams"][0]["division"] = serde_json::json!("Harbor");
+            }),
+            // The existing team has no conference, so a structured batch is inconsistent
+            (|_| ()),
+        ];
+        for modify in cases {
+            let mut batch = definitions.clone();
+            modify(&mut batch);
+            let json = serde_json::to_vec(&batch).unwrap();
+            assert!(season.import_teams(json.as_slice()).is_err());
+            assert_eq!(season, before);
+        }
+    }
+
+    #[test]
+    fn test_empty_conferences_are_ignored_and_linted() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::league::season::conference::{LeagueConference, LeagueDivision};
+        use crate::league::season::lint::SeasonLint;
+        use crate::league::season::playoffs::PlayoffTeams;
+
+        // Two conferences of 4 teams, generated before an empty conference
+        // is inserted between them
+        let mut rng = SmallRng::seed_from_u64(1473);
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..8 {
+            season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        for conf_index in 0..2 {
+            let mut conf = LeagueConference::new();
+            for div_index in 0..2 {
+                let mut div = LeagueDivision::new();
+                div.add_team(conf_index * 4 + div_index * 2).unwrap();
+                div.add_team(conf_index * 4 + div_index * 2 + 1).unwrap();
+                conf.add_division(div).unwrap();
             }
+            season.add_conference(conf).unwrap();
+        }
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        season.conferences_mut().insert(1, LeagueConference::with_name("Empty"));
+        assert_eq!(
+            season.lint(),
+            vec![SeasonLint::EmptyConference { conference: 1, name: String::from("Empty") }]
+        );
+        assert_eq!(season.populated_conferences(), vec![0, 2]);
+
+        // Standings: the empty conference has no standings
+        finish_regular_season(&mut season, |home, away| home < away);
+        assert!(season.conference_standings(1).unwrap().is_empty());
+        assert_eq!(season.conference_standings(2).unwrap().len(), 4);
+
+        // Playoff picture: spots are only given to populated conferences
+        let picture = PlayoffPicture::from_season(&season, 2, None).unwrap();
+        assert_eq!(picture.num_playoff_teams(), 4);
+        assert_eq!(picture.playoff_teams().len(), 4);
+
+        // Playoffs: the bracket has one conference per populated conference
+        let options = LeagueSeasonPlayoffOptions::new()
+            .use_conference_brackets(true)
+            .playoff_teams_per_conference(2);
+        season.generate_playoffs(options, &mut rng).unwrap();
+        assert_eq!(season.playoffs().num_conferences(), 2);
+        assert_eq!(season.playoffs().teams().conferences().copied().collect::<Vec<usize>>(), vec![0, 2]);
+        season.sim_playoffs(&mut rng).unwrap();
+        assert!(season.champion().is_some());
+
+        // Playoff teams prune a conference with its last team, and on load
+        let mut teams = PlayoffTeams::new();
+        teams.add(0, "A", 0).unwrap();
+        teams.add(1, "B", 1).unwrap();
+        teams.remove(1).unwrap();
+        assert_eq!(teams.num_conferences(), 1);
+        let loaded: PlayoffTeams = serde_json::from_value(serde_json::json!({
+            "teams": {"0": {"0": {"seed": 1, "short_name": "A"}}, "1": {}}
+        })).unwrap();
+        assert_eq!(loaded, teams);
+    }
+    #[test]
+    fn test_week_reconcile_after_each_result_entry_path() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::game::context::GameContextBuilder;
+        use crate::game::stat::OffensiveStats;
+        use crate::league::season::lint::{SeasonLint, SeasonLintLevel};
+        use crate::league::season::matchup::MatchupRestAdvantage;
+
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        let mut rng = SmallRng::seed_from_u64(1495);
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        let final_context = GameContextBuilder::new()
+            .quarter(4)
+            .half_seconds(0)
+            .home_score(17)
+            .away_score(10)
+            .game_over(true)
+            .build()
+            .unwrap();
+
+        // Play-by-play and full matchup simulation leave the week consistent
+        while season.sim_play(0, 0, &mut rng).unwrap().is_none() {}
+        season.sim_matchup(0, 1, &mut rng).unwrap();
+        assert!(season.weeks()[0].complete());
+        assert_eq!(season.weeks_mut()[0].reconcile(), Ok(false));
+        assert!(season.lint().is_empty());
+
+        // A manually entered result over a game in progress is linted, then
+        // archived by the week simulation
+        let matchup = &mut season.weeks_mut()[1].matchups_mut()[0];
+        *matchup.game_mut() = Some(Game::new());
+        *matchup.context_mut() = final_context.clone();
+        assert_eq!(season.lint(), vec![SeasonLint::UnreconciledMatchup { week: 1, matchup: 0 }]);
+        assert!(season.sim_week(1, &mut rng).unwrap().succeeded());
+        let week = &season.weeks()[1];
+        assert!(week.complete());
+        assert!(week.matchups()[0].game().is_none());
+        assert!(week.matchups()[0].home_stats().is_some());
+        assert_eq!(week.matchups()[0].context().home_score(), 17);
+        assert!(season.lint().is_empty());
+
+        // Archived stats on an unplayed matchup conflict, and are rejected by
+        // reconciliation, the lint, and on load
+        *season.weeks_mut()[2].matchups_mut()[1].home_stats_mut() = Some(OffensiveStats::new());
+        assert!(season.weeks_mut()[2].reconcile().unwrap_err().to_string().starts_with("Matchup 1 is inconsistent"));
+        let lints = season.lint();
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].level(), SeasonLintLevel::Error);
+        assert!(matches!(lints[0], SeasonLint::InconsistentMatchup { week: 2, matchup: 1, .. }));
+        let save = serde_json::to_value(&season).unwrap();
+        assert!(serde_json::from_value::<LeagueSeason>(save).is_err());
+
+        // A completed matchup which fails reconciliation after the rest of
+        // its week is simulated is reported by week and matchup
+        let mut conflicted = season.clone();
+        let matchup = &mut conflicted.weeks_mut()[2].matchups_mut()[1];
+        matchup.enter_score(24, 17).unwrap();
+        *matchup.rest_advantage_mut() = Some(MatchupRestAdvantage { team: 99, rest_weeks: 1, bonus: 5 });
+        assert!(matches!(
+            conflicted.sim_week(2, &mut rng),
+            Err(LeagueError::InconsistentMatchup { week: 2, matchup: 1, .. })
+        ));
+
+        // A hand-crafted save with a finished matchup still in progress is
+        // repaired on load
+        *season.weeks_mut()[2].matchups_mut()[1].home_stats_mut() = None;
+        let mut save = serde_json::to_value(&season).unwrap();
+        save["weeks"][2]["matchups"][0]["context"] = serde_json::to_value(&final_context).unwrap();
+        save["weeks"][2]["matchups"][0]["game"] = serde_json::to_value(Game::new()).unwrap();
+        let loaded: LeagueSeason = serde_json::from_value(save).unwrap();
+        assert!(loaded.weeks()[2].matchups()[0].game().is_none());
+        assert!(loaded.weeks()[2].matchups()[0].home_stats().is_some());
+        assert!(loaded.lint().is_empty());
+    }
 
-            self.sim_playoff_conference_round(conf_id, round, rng)?;
+    #[test]
+    fn test_strict_mode_score_edits() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::league::season::edit::{LeagueSeasonResultEdit, ResultEditAction};
+
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            season.add_team(id, FootballTeam::new()).unwrap();
         }
-        Ok(())
+        let mut rng = SmallRng::seed_from_u64(1496);
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        let home = *season.weeks()[0].matchups()[0].home_team();
+        let away = *season.weeks()[0].matchups()[0].away_team();
+        let record = |season: &LeagueSeason, id: usize| {
+            season.standings().into_iter().find(|(t, _)| *t == id).unwrap().1
+        };
+
+        // Outside of strict mode a completed score is overwritten directly
+        season.set_score(0, 0, 21, 14).unwrap();
+        season.set_score(0, 0, 14, 21).unwrap();
+        assert_eq!(*record(&season, away).wins(), 1);
+
+        // In strict mode a completed score cannot be overwritten
+        season.set_strict(true);
+        let fingerprint = season.fingerprint();
+        let err = season.set_score(0, 0, 35, 0).unwrap_err();
+        assert_eq!(err, LeagueError::MatchupAlreadyComplete { week: 0, matchup: 0 });
+        assert_eq!(season.weeks()[0].matchups()[0].context().away_score(), 21);
+        assert_eq!(*record(&season, away).wins(), 1);
+
+        // Reopening clears the result from the standings, then a new score
+        // is entered
+        season.reopen_matchup(0, 0).unwrap();
+        assert_ne!(season.fingerprint(), fingerprint);
+        assert_eq!(*record(&season, home).losses(), 0);
+        assert_eq!(*record(&season, away).wins(), 0);
+        assert!(season.reopen_matchup(0, 0).is_err());
+        season.set_score(0, 0, 35, 0).unwrap();
+        assert_eq!(*record(&season, home).wins(), 1);
+        assert_eq!(*record(&season, away).losses(), 1);
+        assert_eq!(
+            season.result_edits().to_vec(),
+            vec![
+                LeagueSeasonResultEdit::new(0, 0, ResultEditAction::Entered, 21, 14),
+                LeagueSeasonResultEdit::new(0, 0, ResultEditAction::Entered, 14, 21),
+                LeagueSeasonResultEdit::new(0, 0, ResultEditAction::Reopened, 14, 21),
+                LeagueSeasonResultEdit::new(0, 0, ResultEditAction::Entered, 35, 0)
+            ]
+        );
+
+        // Strict mode and the ledger survive a round trip
+        let loaded: LeagueSeason = serde_json::from_value(serde_json::to_value(&season).unwrap()).unwrap();
+        assert!(loaded.strict());
+        assert_eq!(loaded.result_edits(), season.result_edits());
+
+        // Results cannot be reopened once the playoffs have been generated
+        season.sim_regular_season(&mut rng).unwrap();
+        season.generate_playoffs(LeagueSeasonPlayoffOptions::new().num_playoff_teams(2), &mut rng).unwrap();
+        assert_eq!(season.reopen_matchup(0, 0).unwrap_err(), LeagueError::PlayoffsAlreadyStarted);
     }
 
-    /// Simulate all remaining playoffs
-    ///
-    /// ### Example
-    /// ```
-    /// use fbsim_core::team::FootballTeam;
-    /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    /// use fbsim_core::league::season::LeagueSeasonPlayoffOptions;
-    ///
-    /// // Create a new season
-    /// let mut my_league_season = LeagueSeason::new();
-    ///
-    /// // Add 4 teams to the season
-    /// my_league_season.add_team(0, FootballTeam::new());
-    /// my_league_season.add_team(1, FootballTeam::new());
-    /// my_league_season.add_team(2, FootballTeam::new());
-    /// my_league_season.add_team(3, FootballTeam::new());
-    ///
-    /// // Generate the season schedule
-    /// let mut rng = rand::thread_rng();
-    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
-    ///
-    /// // Simulate the entire regular season
-    /// my_league_season.sim_regular_season(&mut rng);
-    ///
-    /// // Generate playoffs with 4 teams
-    /// let mut playoff_options = LeagueSeasonPlayoffOptions::new();
-    /// playoff_options.num_playoff_teams = 4;
-    /// my_league_season.generate_playoffs(playoff_options, &mut rng);
-    ///
-    /// // Simulate all playoffs
-    /// let res = my_league_season.sim_playoffs(&mut rng);
-    /// assert!(res.is_ok());
-    /// ```
-    pub fn sim_playoffs(&mut self, rng: &mut impl Rng) -> Result<(), String> {
-        // Ensure the regular season is complete
-        if !self.regular_season_complete() {
-            return Err(String::from("Cannot simulate playoffs: regular season is not complete"));
+    #[test]
+    fn test_result_corrections() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::league::season::edit::{LeagueSeasonResultEdit, ResultEditAction};
+
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            season.add_team(id, FootballTeam::new()).unwrap();
         }
+        let mut rng = SmallRng::seed_from_u64(1527);
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        season.set_strict(true);
+        let home = *season.weeks()[0].matchups()[0].home_team();
+        let record = |season: &LeagueSeason, id: usize| {
+            season.standings().into_iter().find(|(t, _)| *t == id).unwrap().1
+        };
 
-        // Ensure the playoffs have started
-        if self.playoffs.conference_brackets().is_empty() {
-            return Err(String::from("Cannot simulate playoffs: playoffs have not been generated"));
+        // Only completed matchups which exist can be corrected
+        let not_complete = LeagueError::MatchupNotComplete { week: 0, matchup: 0 };
+        assert_eq!(season.override_result(0, 0, 21, 14).unwrap_err(), not_complete);
+        assert_eq!(season.invalidate_result(0, 0).unwrap_err(), not_complete);
+        assert_eq!(season.override_result(0, 9, 21, 14).unwrap_err(), LeagueError::MatchupNotFound { week: 0, matchup: 9 });
+        assert_eq!(season.invalidate_result(99, 0).unwrap_err(), LeagueError::WeekOutOfRange { week: 99 });
+
+        // A correction is allowed in strict mode, and recorded as a reopen
+        // of the previous score followed by the new one
+        season.set_score(0, 0, 10, 17).unwrap();
+        let fingerprint = season.fingerprint();
+        season.override_result(0, 0, 17, 10).unwrap();
+        assert_ne!(season.fingerprint(), fingerprint);
+        assert_eq!(*record(&season, home).wins(), 1);
+        assert_eq!(
+            season.result_edits().to_vec(),
+            vec![
+                LeagueSeasonResultEdit::new(0, 0, ResultEditAction::Entered, 10, 17),
+                LeagueSeasonResultEdit::new(0, 0, ResultEditAction::Reopened, 10, 17),
+                LeagueSeasonResultEdit::new(0, 0, ResultEditAction::Entered, 17, 10)
+            ]
+        );
+
+        // An invalidated result can be simulated again
+        season.invalidate_result(0, 0).unwrap();
+        assert!(!season.weeks()[0].matchups()[0].context().started());
+        assert_eq!(*record(&season, home).wins(), 0);
+        assert_eq!(season.result_edits().last().unwrap().action, ResultEditAction::Reopened);
+        season.sim_week(0, &mut rng).unwrap();
+
+        // Results cannot change once a later week has started
+        season.sim_matchup(1, 0, &mut rng).unwrap();
+        let err = season.override_result(0, 0, 3, 0).unwrap_err().to_string();
+        assert!(err.contains("Week 1 has already started"), "{}", err);
+        assert!(season.invalidate_result(0, 1).unwrap_err().to_string().contains("Week 1 has already started"));
+        season.override_result(1, 0, 3, 0).unwrap();
+        season.invalidate_result(1, 0).unwrap();
+        season.override_result(0, 1, 3, 0).unwrap();
+
+        // Nor once the playoffs have been seeded
+        season.sim_regular_season(&mut rng).unwrap();
+        season.generate_playoffs(LeagueSeasonPlayoffOptions::new().num_playoff_teams(2), &mut rng).unwrap();
+        let last = season.weeks().len() - 1;
+        let edits = season.result_edits().len();
+        assert_eq!(season.override_result(last, 0, 3, 0).unwrap_err(), LeagueError::PlayoffsAlreadyStarted);
+        assert_eq!(season.invalidate_result(last, 0).unwrap_err(), LeagueError::PlayoffsAlreadyStarted);
+        assert_eq!(season.result_edits().len(), edits);
+    }
+
+    #[test]
+    fn test_fingerprint_tracks_serialized_content() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            season.add_team(id, FootballTeam::new()).unwrap();
         }
+        let mut rng = SmallRng::seed_from_u64(1474);
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        let scheduled = season.fingerprint();
+
+        // Read-only calls leave the fingerprint unchanged
+        let _ = season.standings();
+        let _ = season.playoff_picture(4);
+        let _ = season.lint();
+        assert_eq!(season.fingerprint(), scheduled);
+
+        // Simulating a matchup changes it
+        season.sim_matchup(0, 0, &mut rng).unwrap();
+        let simulated = season.fingerprint();
+        assert_ne!(simulated, scheduled);
+
+        // Mutable access clears the cached fingerprint even when it is
+        // only used to restore the original content
+        let year = *season.year();
+        *season.year_mut() = year + 1;
+        assert_ne!(season.fingerprint(), simulated);
+        *season.year_mut() = year;
+        assert_eq!(season.fingerprint(), simulated);
+
+        // The fingerprint survives a serialization round trip, and is not
+        // part of the serialized content or of equality
+        let json = serde_json::to_string(&season).unwrap();
+        assert!(!json.contains("fingerprint"));
+        let restored: LeagueSeason = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.fingerprint(), simulated);
+        assert_eq!(restored, season);
+    }
+    #[test]
+    fn test_standings_history_matches_per_week_standings() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
 
-        // Simulate rounds until playoffs are complete
-        while !self.playoffs.complete() {
-            // If conference brackets are not yet complete, simulate them
-            if !self.playoffs.conference_brackets_complete() {
-                // Get the current round index (minimum across all brackets)
-                let current_round = self.playoffs
-                    .conference_brackets().values().map(|b| b.len().saturating_sub(1))
-                    .min()
-                    .unwrap_or_default();
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        let mut rng = SmallRng::seed_from_u64(1475);
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        for week in 0..5 {
+            season.sim_week(week, &mut rng).unwrap();
+        }
 
-                // Simulate the current round across all conferences
-                self.sim_playoff_round(current_round, rng)?;
+        // A partially completed week does not extend the history
+        season.sim_matchup(5, 0, &mut rng).unwrap();
+        let options = StandingsHistoryOptions::new().games_back(true);
+        let history = season.standings_history(options);
+        assert_eq!(history.weeks, 5);
+        assert_eq!(history.ranks, season.rank_history());
+
+        // Each week matches the standings of the season truncated after it
+        let mut ranks: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        let mut games_back: BTreeMap<usize, Vec<f64>> = BTreeMap::new();
+        for week in 0..history.weeks {
+            let mut truncated = season.clone();
+            truncated.weeks_mut().truncate(week + 1);
+            let standings = truncated.standings();
+            let (_, leader) = &standings[0];
+            for (position, (id, record)) in standings.iter().enumerate() {
+                ranks.entry(*id).or_default().push(position + 1);
+                games_back.entry(*id).or_default().push(
+                    ((*leader.wins() as f64 - *record.wins() as f64)
+                        + (*record.losses() as f64 - *leader.losses() as f64)) / 2.0
+                );
+            }
+        }
+        assert_eq!(history.ranks, ranks);
+        assert_eq!(history.games_back, Some(games_back));
 
-                // Generate the next round if needed and playoffs aren't done
-                if !self.playoffs.complete() {
-                    self.generate_next_playoff_round(rng)?;
+        // Games back are omitted unless requested
+        let json = serde_json::to_string(&season.standings_history(StandingsHistoryOptions::new())).unwrap();
+        assert!(!json.contains("games_back"));
+    }
+
+    #[test]
+    fn test_standings_through_week_matches_truncated_season() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut season = LeagueSeason::with_year(2026);
+        season.import_teams(include_str!("../../docs/league/season/teams.json").as_bytes()).unwrap();
+        let mut rng = SmallRng::seed_from_u64(1516);
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        for week in 0..2 {
+            season.sim_week(week, &mut rng).unwrap();
+        }
+        season.sim_matchup(2, 0, &mut rng).unwrap();
+        let before = season.clone();
+
+        // Each week, even the partial one, matches the truncated season
+        for week in 0..4 {
+            let mut truncated = season.clone();
+            truncated.weeks_mut().truncate(week + 1);
+            assert_eq!(season.standings_through_week(week).unwrap(), truncated.standings());
+            for (conf_index, conference) in season.conferences().iter().enumerate() {
+                assert_eq!(
+                    season.conference_standings_through_week(conf_index, week).unwrap(),
+                    truncated.conference_standings(conf_index).unwrap()
+                );
+                for div_id in 0..conference.divisions().len() {
+                    assert_eq!(
+                        season.division_standings_through_week(conf_index, div_id, week).unwrap(),
+                        truncated.division_standings(conf_index, div_id).unwrap()
+                    );
                 }
-            } else if self.playoffs.is_conference_playoff() {
-                // Conference brackets complete, simulate winners bracket
-                let current_round = self.playoffs.winners_bracket().len().saturating_sub(1);
+            }
+        }
+        assert!(season.standings_through_week(season.weeks().len()).is_err());
+        assert!(season.conference_standings_through_week(season.conferences().len(), 0).is_err());
+
+        // The history tables cover only the completed weeks
+        let history = season.standings_history(StandingsHistoryOptions::new().tables(true));
+        let tables = history.tables.unwrap();
+        assert_eq!(tables.len(), 2);
+        for (week, table) in tables.iter().enumerate() {
+            assert_eq!(*table, season.standings_through_week(week).unwrap());
+        }
+        assert_eq!(season, before);
+    }
+    #[test]
+    fn test_schedule_meeting_constraints_hold_for_every_week_count() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut rng = SmallRng::seed_from_u64(1476);
+        for num_teams in (4..=16).step_by(2) {
+            let mut empty = LeagueSeason::with_year(2026);
+            for id in 0..num_teams {
+                empty.add_team(id, FootballTeam::new()).unwrap();
+            }
+            let opponents = num_teams - 1;
+            for round_robin in [true, false] {
+                let (min_games, max_games) = if round_robin { (opponents, opponents * 3) } else { (1, opponents * 3) };
+                for games in min_games..=max_games {
+                    let base = if round_robin {
+                        LeagueSeasonScheduleOptions::new().weeks(games)
+                    } else {
+                        LeagueSeasonScheduleOptions::new().format(ScheduleFormat::Partial { games_per_team: games })
+                    };
+                    for limit in 1..=3 {
+                        // A maximum is feasible whenever the games fit
+                        let mut season = empty.clone();
+                        let res = season.generate_schedule(base.clone().max_meetings_per_opponent(limit), &mut rng);
+                        if games <= limit * opponents {
+                            res.unwrap();
+                            let meetings = season.opponent_meetings();
+                            assert!(meetings.values().flat_map(|o| o.values()).all(|m| *m <= limit));
+                        } else {
+                            assert!(res.is_err());
+                            assert!(season.weeks().is_empty());
+                        }
+
+                        // An exact count is feasible only for whole cycles
+                        let mut season = empty.clone();
+                        let res = season.generate_schedule(base.clone().exact_meetings(limit), &mut rng);
+                        if games == limit * opponents {
+                            res.unwrap();
+                            let meetings = season.opponent_meetings();
+                            assert!(meetings.values().flat_map(|o| o.values()).all(|m| *m == limit));
+                        } else {
+                            assert!(res.is_err());
+                            assert!(season.weeks().is_empty());
+                        }
+                    }
+                }
+            }
 
-                self.sim_winners_bracket_round(current_round, rng)?;
+            // Without a number of weeks, an exact count sets the cycles
+            let mut season = empty.clone();
+            season.generate_schedule(LeagueSeasonScheduleOptions::new().exact_meetings(3), &mut rng).unwrap();
+            assert_eq!(season.weeks().len(), opponents * 3);
+        }
+    }
 
-                // Generate the next winners bracket round if needed
-                if !self.playoffs.complete() {
-                    self.generate_next_playoff_round(rng)?;
+    #[test]
+    fn test_structured_schedule_meeting_constraints() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let example = include_str!("../../docs/league/season/teams.json");
+        let mut empty = LeagueSeason::with_year(2026);
+        empty.import_teams(example.as_bytes()).unwrap();
+        let mut rng = SmallRng::seed_from_u64(1476);
+
+        // The default of two division games exceeds a maximum of one
+        let mut season = empty.clone();
+        let options = LeagueSeasonScheduleOptions::new().max_meetings_per_opponent(1);
+        assert!(season.generate_schedule(options, &mut rng).is_err());
+        assert!(season.weeks().is_empty());
+
+        // Meeting every opponent exactly once requires every cross-conference game
+        let options = LeagueSeasonScheduleOptions::new()
+            .division_games(1)
+            .conference_games(1)
+            .exact_meetings(1);
+        assert!(season.generate_schedule(options.clone().cross_conference_games(3), &mut rng).is_err());
+        assert!(season.generate_schedule(options.clone().cross_conference_games(4).exact_meetings(2), &mut rng).is_err());
+        season.generate_schedule(options.cross_conference_games(4), &mut rng).unwrap();
+        let meetings = season.opponent_meetings();
+        assert!(meetings.values().flat_map(|o| o.values()).all(|m| *m == 1));
+
+        // The report lists the meetings only when requested
+        assert!(!season.report_markdown(SeasonReportOptions::new()).contains("## Meetings"));
+        let report = season.report_markdown(SeasonReportOptions::new().meetings(true));
+        assert!(report.contains("## Meetings"));
+        assert!(report.contains("| Team "));
+    }
+
+    #[test]
+    fn test_interconference_games() {
+        let example = include_str!("../../docs/league/season/teams.json");
+        let mut season = LeagueSeason::with_year(2026);
+        season.import_teams(example.as_bytes()).unwrap();
+        let mut rng = SmallRng::seed_from_u64(1533);
+
+        // Each team plays exactly 3 distinct inter-conference opponents,
+        // hosting 1 or 2 of them, and one conference hosts the odd game
+        let options = LeagueSeasonScheduleOptions::new().interconference_games(3);
+        season.generate_schedule(options, &mut rng).unwrap();
+        let mut home_games: BTreeMap<usize, usize> = BTreeMap::new();
+        let mut opponents: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
+        for week in season.weeks() {
+            for matchup in week.matchups() {
+                let (home, away) = (*matchup.home_team(), *matchup.away_team());
+                if season.team_conference(home) == season.team_conference(away) {
+                    continue;
                 }
+                *home_games.entry(home).or_default() += 1;
+                opponents.entry(home).or_default().insert(away);
+                opponents.entry(away).or_default().insert(home);
             }
         }
-        Ok(())
-    }
+        assert_eq!(opponents.len(), 8);
+        assert!(opponents.values().all(|o| o.len() == 3));
+        for team in opponents.keys() {
+            let home = home_games.get(team).copied().unwrap_or(0);
+            assert!(home == 1 || home == 2);
+        }
+        assert_eq!(home_games.values().sum::<usize>(), 12);
+
+        // Conference and inter-conference records make up the overall record
+        season.sim_regular_season(&mut rng).unwrap();
+        for (team, record) in season.standings() {
+            let conference = season.conference_record(team).unwrap();
+            let interconference = season.interconference_record(team).unwrap();
+            assert_eq!(interconference.wins() + interconference.losses() + interconference.ties(), 3);
+            assert_eq!(conference.wins() + interconference.wins(), *record.wins());
+            assert_eq!(conference.losses() + interconference.losses(), *record.losses());
+            assert_eq!(conference.ties() + interconference.ties(), *record.ties());
+        }
 
-    /// Simulate the next play of a season matchup
-    ///
-    /// ### Example
-    /// ```
-    /// use fbsim_core::team::FootballTeam;
-    /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    ///
-    /// // Create a new season
-    /// let mut my_league_season = LeagueSeason::new();
-    ///
-    /// // Add 4 teams to the season
-    /// my_league_season.add_team(0, FootballTeam::new());
-    /// my_league_season.add_team(1, FootballTeam::new());
-    /// my_league_season.add_team(2, FootballTeam::new());
-    /// my_league_season.add_team(3, FootballTeam::new());
-    ///
-    /// // Generate the season schedule
-    /// let mut rng = rand::thread_rng();
-    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
-    ///
-    /// // Simulate the first game of the first week
-    /// let res = my_league_season.sim_play(0, 0, &mut rng);
-    /// assert!(res.is_ok());
-    /// ```
-    pub fn sim_play(&mut self, week: usize, matchup: usize, rng: &mut impl Rng) -> Result<Option<Game>, String> {
-        // Check if the prior week is not complete
-        if week > 0 {
-            let prev_week = match self.weeks.get(week - 1) {
-                Some(w) => w,
-                None => return Err(format!("Failed to get previous week {} from season {}", week-1, self.year))
-            };
-            if !prev_week.complete() {
-                return Err(
-                    format!(
-                        "Cannot simulate week {} for season {}: previous week {} not complete",
-                        week, self.year, week-1
-                    )
-                );
+        // Impossible configurations are rejected with a reason
+        let mut season = LeagueSeason::with_year(2026);
+        season.import_teams(example.as_bytes()).unwrap();
+        let too_many = LeagueSeasonScheduleOptions::new().interconference_games(5);
+        assert!(season.generate_schedule(too_many, &mut rng).is_err());
+        let both = LeagueSeasonScheduleOptions::new().interconference_games(2).cross_conference_games(2);
+        assert!(season.generate_schedule(both, &mut rng).is_err());
+        let partial = LeagueSeasonScheduleOptions::new()
+            .interconference_games(2)
+            .format(ScheduleFormat::Partial { games_per_team: 6 });
+        assert!(season.generate_schedule(partial, &mut rng).is_err());
+        assert!(season.weeks().is_empty());
+
+        // Conferences of different sizes cannot play the same number of
+        // inter-conference games
+        let mut uneven = LeagueSeason::with_year(2026);
+        for (name, teams) in [("AFC", vec![0, 1, 2, 3]), ("NFC", vec![4, 5])] {
+            let mut conference = LeagueConference::with_name(name);
+            let mut division = LeagueDivision::with_name("East");
+            for id in teams {
+                uneven.add_team(id, FootballTeam::new()).unwrap();
+                division.add_team(id).unwrap();
             }
+            conference.add_division(division).unwrap();
+            uneven.add_conference(conference).unwrap();
         }
+        let err = uneven.generate_schedule(LeagueSeasonScheduleOptions::new().interconference_games(1), &mut rng).unwrap_err();
+        assert_eq!(err.code(), "LEAGUE_INVALID_SCHEDULE");
+        assert!(err.to_string().contains("'AFC' (4 teams) and 'NFC' (2 teams)"));
+        uneven.generate_schedule(LeagueSeasonScheduleOptions::new().interconference_games(0), &mut rng).unwrap();
+        assert!(uneven.weeks().iter().flat_map(|w| w.matchups()).all(|m| {
+            uneven.team_conference(*m.home_team()) == uneven.team_conference(*m.away_team())
+        }));
+    }
 
-        // Try to get the given week
-        let mut _week_to_sim = match self.weeks.get_mut(week) {
-            Some(w) => w,
-            None => return Err(format!("No such week for season {}: {}", self.year, week)),
-        };
+    #[test]
+    fn test_divisional_schedule_round_trips() {
+        let example = include_str!("../../docs/league/season/teams.json");
+        let mut season = LeagueSeason::with_year(2026);
+        season.import_teams(example.as_bytes()).unwrap();
+        let mut rng = SmallRng::seed_from_u64(1511);
+        let options = LeagueSeasonScheduleOptions::new()
+            .division_games(2)
+            .conference_games(1)
+            .cross_conference_games(1);
+        season.generate_schedule(options, &mut rng).unwrap();
+
+        // Division rivals meet twice, every other opponent at most once
+        for (team, opponents) in season.opponent_meetings().iter() {
+            for (opponent, meetings) in opponents.iter() {
+                if season.same_division(*team, *opponent) {
+                    assert_eq!(*meetings, 2);
+                } else {
+                    assert!(*meetings <= 1);
+                }
+            }
+        }
 
-        // Try to get the given matchup
-        let mut _matchup_to_sim = match _week_to_sim.matchups_mut().get_mut(matchup) {
-            Some(m) => m,
-            None => return Err(format!("No such matchup in season {} week {}: {}", self.year, week, matchup)),
-        };
+        // The divisional schedule survives a save and reload
+        let json = serde_json::to_string(&season).unwrap();
+        let mut loaded: LeagueSeason = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, season);
+
+        // Division standings are available once the season is played
+        loaded.sim_regular_season(&mut rng).unwrap();
+        for (conf_index, conference) in loaded.conferences().iter().enumerate() {
+            for (div_id, division) in conference.divisions().iter().enumerate() {
+                let standings = loaded.division_standings(conf_index, div_id).unwrap();
+                assert_eq!(standings.len(), division.teams().len());
+            }
+        }
+    }
 
-        // Ensure the matchup is not already complete
-        if _matchup_to_sim.context().game_over() {
-            return Err(format!("Season {} week {} matchup {} is already complete", self.year, week, matchup));
+    #[test]
+    fn test_sim_week_returns_play_logs() {
+        let mut rng = SmallRng::seed_from_u64(1510);
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            season.add_team(id, FootballTeam::from_overalls("TM", "TM", 50 + 5 * id as u32, 60).unwrap()).unwrap();
         }
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        season.set_sim_seed(Some(1510));
+
+        // Scores only by default
+        let mut scores_only = season.clone();
+        let report = scores_only.sim_week(0, &mut rng).unwrap();
+        assert!(report.play_logs.is_empty());
+        assert!(!serde_json::to_string(&report).unwrap().contains("play_logs"));
+
+        // With play logs, each completed game is returned with its drives,
+        // and the results are unchanged
+        let options = WeekSimOptions::new().play_logs(true);
+        let logged = season.sim_week_with_options(0, options, &mut rng).unwrap();
+        assert_eq!(logged.matchups, report.matchups);
+        assert_eq!(season.weeks()[0], scores_only.weeks()[0]);
+        assert_eq!(logged.play_logs.keys().copied().collect::<Vec<usize>>(), vec![0, 1, 2]);
+        for (index, game) in logged.play_logs.iter() {
+            let matchup = &season.weeks()[0].matchups()[*index];
+            assert!(!game.drives().is_empty());
+            assert_eq!(Some(game.home_stats()), *matchup.home_stats());
+            assert_eq!(Some(game.away_stats()), *matchup.away_stats());
+        }
+        let json = serde_json::to_string(&logged).unwrap();
+        assert_eq!(serde_json::from_str::<WeekSimReport>(&json).unwrap(), logged);
+    }
 
-        // Try to get the home team for the matchup
-        let home_id = _matchup_to_sim.home_team();
-        let home_team = match self.teams.get(home_id) {
-            Some(t) => t,
-            None => return Err(
-                format!(
-                    "Season {} week {} matchup {} references nonexistent home team ID: {}",
-                    self.year, week, matchup, home_id
-                )
-            )
-        };
+    #[test]
+    fn test_sim_week_reports_failed_matchups() {
+        let mut rng = SmallRng::seed_from_u64(1482);
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+
+        // Inject a matchup referencing a missing team in the middle of the week
+        let bad = LeagueSeasonMatchup::new(0, 99, "HOME", "AWAY", &mut rng);
+        season.weeks_mut()[0].matchups_mut().insert(1, bad.clone());
+
+        // Strict simulation stops at the bad matchup, after the first
+        let mut strict = season.clone();
+        let options = WeekSimOptions::new().abort_on_error(true);
+        let error = strict.sim_week_with_options(0, options, &mut rng).unwrap_err();
+        assert_eq!(error, LeagueError::TeamNotFound { id: 99 });
+        let matchups = strict.weeks()[0].matchups();
+        assert!(matchups[0].context().game_over());
+        assert!(matchups[1..].iter().all(|m| !m.context().game_over()));
+
+        // Tolerant simulation completes every other matchup and reports the bad one
+        let report = season.sim_week(0, &mut rng).unwrap();
+        assert_eq!(report.matchups.len(), 4);
+        assert_eq!(report.failed_matchups(), vec![1]);
+        assert_eq!((report.failures()[0].home_team, report.failures()[0].away_team), (0, 99));
+        let completed = report.matchups.iter()
+            .filter(|m| matches!(m.outcome, MatchupSimOutcome::Completed { .. }))
+            .count();
+        assert_eq!(completed, 3);
+        for (i, matchup) in season.weeks()[0].matchups().iter().enumerate() {
+            assert_eq!(matchup.context().game_over(), i != 1);
+        }
+        assert_eq!(season.weeks()[0].matchups()[1], bad);
+
+        // Simulating the season names the failure
+        let error = season.clone().sim(&mut rng).unwrap_err();
+        assert_eq!(error.code(), "LEAGUE_SIMULATION");
+        assert!(error.to_string().contains("week 0") && error.to_string().contains("99"));
+
+        // Once the missing team exists only the failed matchup needs to be
+        // retried
+        let mut retried = season.clone();
+        retried.teams.insert(99, FootballTeam::new());
+        let games = retried.sim_matchups(0, &report.failed_matchups(), false, &mut rng).unwrap();
+        assert_eq!(games.len(), 1);
+        assert!(retried.weeks()[0].complete());
+
+        // Without the bad matchup the partially simulated season is valid
+        season.weeks_mut()[0].matchups_mut().remove(1);
+        assert!(season.weeks()[0].complete());
+        let json = serde_json::to_string(&season).unwrap();
+        let _: LeagueSeason = serde_json::from_str(&json).unwrap();
+    }
 
-        // Try to get the away team for the matchup
-        let away_id = _matchup_to_sim.away_team();
-        let away_team = match self.teams.get(away_id) {
-            Some(t) => t,
-            None => return Err(
-                format!(
-                    "Season {} week {} matchup {} references nonexistent away team ID: {}",
-                    self.year, week, matchup, away_id
-                )
-            )
-        };
+    #[test]
+    fn test_preview_then_apply_schedule() {
+        let example = include_str!("../../docs/league/season/teams.json");
+        let mut season = LeagueSeason::with_year(2026);
+        season.import_teams(example.as_bytes()).unwrap();
+        let options = LeagueSeasonScheduleOptions::new()
+            .conference_games(2)
+            .cross_conference_games(4);
+
+        // Previewing leaves the season untouched
+        let before = season.clone();
+        let mut rng = SmallRng::seed_from_u64(1484);
+        let proposal = season.preview_schedule(options.clone(), &mut rng).unwrap();
+        assert_eq!(season, before);
+        assert!(!proposal.weeks().is_empty());
+        assert_eq!(proposal.balance().meetings, {
+            let mut generated = season.clone();
+            generated.generate_schedule(options.clone(), &mut SmallRng::seed_from_u64(1484)).unwrap();
+            generated.opponent_meetings()
+        });
+        let games: usize = proposal.weeks().iter().map(|w| w.matchups().len()).sum();
+        assert_eq!(proposal.balance().home_games.values().sum::<usize>(), games);
+        assert_eq!(proposal.balance().away_games.values().sum::<usize>(), games);
+
+        // The proposal survives serialization, and applying it unchanged
+        // matches generating the schedule directly with the same seed
+        let json = serde_json::to_string(&proposal).unwrap();
+        let restored: ScheduleProposal = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, proposal);
+        let mut direct = season.clone();
+        direct.generate_schedule(options.clone(), &mut SmallRng::seed_from_u64(1484)).unwrap();
+        let mut applied = season.clone();
+        applied.apply_schedule(restored).unwrap();
+        assert_eq!(applied, direct);
+        assert_eq!(applied.fingerprint(), direct.fingerprint());
+
+        // Proposals are rejected once the team set changes
+        let mut added = season.clone();
+        added.add_team(8, FootballTeam::new()).unwrap();
+        let error = added.apply_schedule(proposal.clone()).unwrap_err().to_string();
+        assert!(error.contains("team 8 was added"), "{}", error);
+        assert!(added.weeks().is_empty());
+        let mut renamed = season.clone();
+        *renamed.teams.get_mut(&3).unwrap().short_name_mut() = String::from("NEW");
+        let error = renamed.apply_schedule(proposal.clone()).unwrap_err().to_string();
+        assert!(error.contains("team 3 was renamed"), "{}", error);
+
+        // Proposals are rejected once the season has started
+        let mut started = season.clone();
+        started.apply_schedule(proposal.clone()).unwrap();
+        started.sim_week(0, &mut rng).unwrap();
+        assert!(started.apply_schedule(proposal).is_err());
+    }
 
-        // Create a new game if game has not started, or get existing game
-        if _matchup_to_sim.game().is_none() {
-            *_matchup_to_sim.game_mut() = Some(Game::new());
+    #[test]
+    fn test_swap_and_rebalance_home_away() {
+        let mut rng = SmallRng::seed_from_u64(1486);
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            let short_name = format!("T{}", id);
+            season.add_team(id, FootballTeam::from_overalls("Team", &short_name, 50, 50).unwrap()).unwrap();
         }
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
 
-        // Simulate the next play
-        let simulator = GameSimulator::new();
-        let context = match simulator.sim_play(
-            home_team, away_team,
-            _matchup_to_sim.context().clone(),
-            _matchup_to_sim.game_mut().as_mut().unwrap(),
-            rng
-        ) {
-            Ok(c) => c,
-            Err(e) => return Err(format!("Error while simulating matchup: {}", e))
+        // Swapping a game moves it to the former away team's stadium
+        let (home, away) = {
+            let matchup = &season.weeks()[0].matchups()[0];
+            (*matchup.home_team(), *matchup.away_team())
         };
-
-        // If game is over, archive game stats, clear game, update context
-        if context.game_over() {
-            *_matchup_to_sim.home_stats_mut() = Some(
-                _matchup_to_sim.game().as_ref().ok_or(
-                    "Failed to archive home stats for game"
-                )?.home_stats()
-            );
-            *_matchup_to_sim.away_stats_mut() = Some(
-                _matchup_to_sim.game().as_ref().ok_or(
-                    "Failed to archive away stats for game"
-                )?.away_stats()
-            );
-            *_matchup_to_sim.context_mut() = context;
-            return Ok(_matchup_to_sim.take_game());
+        season.swap_home_away(0, 0).unwrap();
+        assert_eq!(season.venue_swaps().to_vec(), vec![LeagueSeasonVenueSwap::new(0, 0, away, home)]);
+        season.sim_week(0, &mut rng).unwrap();
+        let matchup = &season.weeks()[0].matchups()[0];
+        assert_eq!(*matchup.home_team(), away);
+        assert_eq!(matchup.context().home_team_short(), format!("T{}", away));
+        assert_eq!(matchup.context().away_team_short(), format!("T{}", home));
+        assert!(!matchup.context().neutral_site());
+        let new_home_points: u32 = season.team_matchups(away).unwrap().matchups().iter()
+            .flatten()
+            .filter(|m| m.context().game_over())
+            .map(|m| if m.is_home_team(away) { m.context().home_score() } else { m.context().away_score() })
+            .sum();
+        assert_eq!(new_home_points, matchup.context().home_score());
+
+        // Played games cannot be swapped, and the ledger survives
+        // serialization
+        assert!(season.swap_home_away(0, 0).is_err());
+        assert!(season.swap_home_away(99, 0).is_err());
+        let json = serde_json::to_string(&season).unwrap();
+        assert_eq!(serde_json::from_str::<LeagueSeason>(&json).unwrap(), season);
+
+        // Skew the remaining schedule so that team 0 hosts every unplayed game
+        for week in 1..season.weeks().len() {
+            for matchup in 0..season.weeks()[week].matchups().len() {
+                if *season.weeks()[week].matchups()[matchup].away_team() == 0 {
+                    season.swap_home_away(week, matchup).unwrap();
+                }
+            }
         }
-        *_matchup_to_sim.context_mut() = context;
-        Ok(None)
+        let skewed = season.schedule_balance();
+        assert!(skewed.max_home_away_imbalance() >= 4);
+
+        // Rebalancing brings every team within one game of even
+        let swaps_before = season.venue_swaps().len();
+        let rebalance = season.rebalance_home_away().unwrap();
+        assert_eq!(rebalance.before, skewed);
+        assert_eq!(rebalance.after, season.schedule_balance());
+        assert!(rebalance.balanced());
+        assert!(!rebalance.swaps.is_empty());
+        assert!(rebalance.swaps.len() <= skewed.max_home_away_imbalance());
+        assert_eq!(season.venue_swaps()[swaps_before..], rebalance.swaps[..]);
+        assert_eq!(rebalance.after.meetings, skewed.meetings);
+
+        // A balanced schedule needs no swaps
+        assert!(season.rebalance_home_away().unwrap().swaps.is_empty());
     }
 
-    /// Simulate a season matchup
-    ///
-    /// ### Example
-    /// ```
-    /// use fbsim_core::team::FootballTeam;
-    /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    ///
-    /// // Create a new season
-    /// let mut my_league_season = LeagueSeason::new();
-    ///
-    /// // Add 4 teams to the season
-    /// my_league_season.add_team(0, FootballTeam::new());
-    /// my_league_season.add_team(1, FootballTeam::new());
-    /// my_league_season.add_team(2, FootballTeam::new());
-    /// my_league_season.add_team(3, FootballTeam::new());
-    ///
-    /// // Generate the season schedule
-    /// let mut rng = rand::thread_rng();
-    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
-    ///
-    /// // Simulate the first game of the first week
-    /// my_league_season.sim_matchup(0, 0, &mut rng);
-    /// ```
-    pub fn sim_matchup(&mut self, week: usize, matchup: usize, rng: &mut impl Rng) -> Result<Game, String> {
-        // Check if the prior week is not complete
-        if week > 0 {
-            let prev_week = match self.weeks.get(week - 1) {
-                Some(w) => w,
-                None => return Err(format!("Failed to get previous week {} from season {}", week-1, self.year))
-            };
-            if !prev_week.complete() {
-                return Err(
-                    format!(
-                        "Cannot simulate week {} for season {}: previous week {} not complete",
-                        week, self.year, week-1
-                    )
-                );
+    #[test]
+    fn test_week_notifications_fire_once() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::league::season::notification::{NotificationKind, NotificationRules, StandingsRanks};
+
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        let mut rng = SmallRng::seed_from_u64(1498);
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        let rules = NotificationRules::new()
+            .upset_rank_gap(2)
+            .top_team_losses(1)
+            .comeback_points(10)
+            .shutouts(true)
+            .playoff_teams(2);
+        let options = WeekSimOptions::new().notifications(rules);
+
+        // Simulate the season, checking each week's game notifications
+        // against the standings entering the week
+        let mut notifications = Vec::new();
+        for week in 0..season.weeks().len() {
+            let ranks = StandingsRanks::new(&season.standings());
+            let report = season.sim_week_with_options(week, options, &mut rng).unwrap();
+            assert!(report.succeeded());
+            for (i, matchup) in season.weeks()[week].matchups().iter().enumerate() {
+                let context = matchup.context();
+                let (home_score, away_score) = (context.home_score(), context.away_score());
+                let (winner, loser) = match home_score.cmp(&away_score) {
+                    std::cmp::Ordering::Greater => (*matchup.home_team(), *matchup.away_team()),
+                    std::cmp::Ordering::Less => (*matchup.away_team(), *matchup.home_team()),
+                    std::cmp::Ordering::Equal => (usize::MAX, usize::MAX)
+                };
+                let fired: Vec<&NotificationKind> = report.notifications.iter()
+                    .filter(|n| n.week == week && n.matchup == Some(i))
+                    .map(|n| &n.kind)
+                    .collect();
+                let count = |pred: fn(&NotificationKind) -> bool| fired.iter().filter(|k| pred(k)).count();
+                let upset = match (ranks.rank(winner), ranks.rank(loser)) {
+                    (Some(w), Some(l)) => w >= l + 2,
+                    _ => false
+                };
+                let top_loss = ranks.rank(loser) == Some(1);
+                let shutout = winner != usize::MAX && home_score.min(away_score) == 0;
+                assert_eq!(count(|k| matches!(k, NotificationKind::Upset { .. })), upset as usize);
+                assert_eq!(count(|k| matches!(k, NotificationKind::TopTeamLoss { .. })), top_loss as usize);
+                assert_eq!(count(|k| matches!(k, NotificationKind::Shutout { .. })), shutout as usize);
+                assert!(count(|k| matches!(k, NotificationKind::Comeback { .. })) <= 1);
+                for kind in fired.iter() {
+                    if let NotificationKind::Comeback { winner: w, deficit, .. } = kind {
+                        assert_eq!(*w, winner);
+                        assert!(*deficit >= 10);
+                    }
+                }
             }
+            notifications.extend(report.notifications);
         }
-        
-        // Try to get the given week
-        let mut _week_to_sim = match self.weeks.get_mut(week) {
-            Some(w) => w,
-            None => return Err(format!("No such week for season {}: {}", self.year, week)),
-        };
+        assert!(notifications.iter().any(|n| n.matchup.is_some()));
+
+        // Each clinch and elimination fires exactly once, matching the
+        // final playoff picture
+        let picture = season.playoff_picture(2).unwrap();
+        for entry in picture.entries().iter() {
+            let team = entry.team_id();
+            let clinched = notifications.iter()
+                .filter(|n| n.kind == NotificationKind::ClinchedPlayoffs { team })
+                .count();
+            let eliminated = notifications.iter()
+                .filter(|n| n.kind == NotificationKind::Eliminated { team })
+                .count();
+            assert_eq!(clinched, entry.is_clinched() as usize);
+            assert_eq!(eliminated, entry.is_eliminated() as usize);
+        }
+        assert!(notifications.iter().any(|n| matches!(n.kind, NotificationKind::Eliminated { .. })));
 
-        // Try to get the given matchup
-        let mut _matchup_to_sim = match _week_to_sim.matchups_mut().get_mut(matchup) {
-            Some(m) => m,
-            None => return Err(format!("No such matchup in season {} week {}: {}", self.year, week, matchup)),
-        };
+        // Without rules no notifications are reported
+        let mut quiet = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            quiet.add_team(id, FootballTeam::new()).unwrap();
+        }
+        quiet.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        let report = quiet.sim_week(0, &mut rng).unwrap();
+        assert!(report.notifications.is_empty());
+    }
 
-        // Ensure the matchup is not already complete
-        if _matchup_to_sim.context().game_over() {
-            return Err(format!("Season {} week {} matchup {} is already complete", self.year, week, matchup));
+    #[test]
+    fn test_add_playoff_teams_batch_is_atomic() {
+        use crate::league::season::playoffs::PlayoffEntry;
+
+        let mut playoffs = LeagueSeasonPlayoffs::new();
+        playoffs.add_team(0, "A", Some(0)).unwrap();
+        let before = playoffs.clone();
+
+        // Each invalid entry fails the batch, naming the entry and leaving
+        // the playoffs unchanged
+        let batches = [
+            (vec![PlayoffEntry::new(1, "B", Some(0)), PlayoffEntry::new(0, "C", Some(1))], "entry 1 (team 0)"),
+            (vec![PlayoffEntry::new(1, "B", Some(0)), PlayoffEntry::new(2, "C", Some(1)), PlayoffEntry::new(1, "D", Some(1))], "entry 2 (team 1)"),
+            (vec![PlayoffEntry::new(1, "LONGER", None)], "entry 0 (team 1)"),
+        ];
+        for (entries, expected) in batches {
+            let err = playoffs.add_teams(entries).unwrap_err().to_string();
+            assert!(err.contains(expected), "{}", err);
+            assert_eq!(playoffs, before);
         }
 
-        // Try to get the home team for the matchup
-        let home_id = _matchup_to_sim.home_team();
-        let home_team = match self.teams.get(home_id) {
-            Some(t) => t,
-            None => return Err(
-                format!(
-                    "Season {} week {} matchup {} references nonexistent home team ID: {}",
-                    self.year, week, matchup, home_id
-                )
-            )
-        };
+        // A valid batch is seeded in order after the existing teams
+        playoffs.add_teams(vec![
+            PlayoffEntry::new(1, "B", Some(0)),
+            PlayoffEntry::new(2, "C", Some(1)),
+            PlayoffEntry::new(3, "D", Some(1))
+        ]).unwrap();
+        assert_eq!(playoffs.teams().get(1).unwrap().seed(), 2);
+        assert_eq!(playoffs.teams().get(3).unwrap().seed(), 2);
+        assert_eq!(playoffs.num_conferences(), 2);
+    }
 
-        // Try to get the away team for the matchup
-        let away_id = _matchup_to_sim.away_team();
-        let away_team = match self.teams.get(away_id) {
-            Some(t) => t,
-            None => return Err(
-                format!(
-                    "Season {} week {} matchup {} references nonexistent away team ID: {}",
-                    self.year, week, matchup, away_id
-                )
-            )
+    #[test]
+    fn test_standings_tiebreaker_chain() {
+        use crate::game::context::GameContextBuilder;
+        use crate::league::season::conference::{LeagueConference, LeagueDivision};
+        use crate::league::season::matchup::LeagueSeasonMatchup;
+        use crate::league::season::week::LeagueSeasonWeek;
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut rng = SmallRng::seed_from_u64(1501);
+        let mut add_week = |season: &mut LeagueSeason, games: Vec<(usize, usize, u32, u32)>| {
+            let mut week = LeagueSeasonWeek::new();
+            for (home, away, home_score, away_score) in games {
+                let mut matchup = LeagueSeasonMatchup::new(home, away, "HOME", "AWAY", &mut rng);
+                *matchup.context_mut() = GameContextBuilder::new()
+                    .quarter(4)
+                    .half_seconds(0)
+                    .home_score(home_score)
+                    .away_score(away_score)
+                    .game_over(true)
+                    .build()
+                    .unwrap();
+                week.matchups_mut().push(matchup);
+            }
+            season.weeks_mut().push(week);
+        };
+        let ids = |standings: &[(usize, LeagueTeamRecord)]| -> Vec<usize> {
+            standings.iter().map(|(id, _)| *id).collect()
         };
 
-        // Simulate the matchup
-        let mut game = Game::new();
-        let simulator = GameSimulator::new();
-        let context = match simulator.sim_game(
-            home_team, away_team,
-            _matchup_to_sim.context().clone(),
-            &mut game, rng
-        ) {
-            Ok(c) => c,
-            Err(e) => return Err(format!("Error while simulating matchup: {}", e))
+        // Teams 0, 1, and 2 beat each other in a circle, while teams 3 and 4
+        // never meet, leaving five teams at 1-1 behind team 5 at 2-2
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        add_week(&mut season, vec![(0, 1, 10, 0), (3, 5, 1, 0)]);
+        add_week(&mut season, vec![(1, 2, 7, 0), (4, 5, 20, 0)]);
+        add_week(&mut season, vec![(2, 0, 4, 0), (5, 3, 1, 0)]);
+        add_week(&mut season, vec![(5, 4, 1, 0)]);
+
+        // Head-to-head is level in the circle and does not apply to teams
+        // who never met, so point differential orders the group, and the
+        // chain restarts for teams 1 and 2, still level at -3
+        assert_eq!(ids(&season.standings()), vec![5, 4, 0, 3, 1, 2]);
+        let explain = |season: &LeagueSeason, a: usize, b: usize| {
+            let explanation = season.tiebreaker_explanation(a, b).unwrap();
+            (explanation.winner, explanation.loser, explanation.rule)
         };
+        assert_eq!(explain(&season, 0, 5), (5, 0, TiebreakerRule::Record));
+        assert_eq!(explain(&season, 3, 4), (4, 3, TiebreakerRule::PointDifferential));
+        assert_eq!(explain(&season, 1, 0), (0, 1, TiebreakerRule::PointDifferential));
+        assert_eq!(explain(&season, 2, 1), (1, 2, TiebreakerRule::HeadToHead));
+        assert_eq!(
+            season.tiebreaker_explanation(2, 1).unwrap().to_string(),
+            "Team 1 ranks ahead of team 2: won head-to-head"
+        );
 
-        // Archive the game stats, clear the game, update the context
-        *_matchup_to_sim.home_stats_mut() = Some(game.home_stats());
-        *_matchup_to_sim.away_stats_mut() = Some(game.away_stats());
-        *_matchup_to_sim.context_mut() = context;
-        Ok(game)
+        // Teams 0 and 1 split their series and finish 2-2, team 1 with the
+        // better point differential but team 0 with the better division
+        // record
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        let mut conference = LeagueConference::with_name("Conference");
+        let mut north = LeagueDivision::with_name("North");
+        for id in 0..3 {
+            north.add_team(id).unwrap();
+        }
+        let mut south = LeagueDivision::with_name("South");
+        south.add_team(3).unwrap();
+        conference.add_division(north).unwrap();
+        conference.add_division(south).unwrap();
+        season.add_conference(conference).unwrap();
+        add_week(&mut season, vec![(0, 1, 3, 0)]);
+        add_week(&mut season, vec![(1, 0, 30, 0)]);
+        add_week(&mut season, vec![(0, 2, 3, 0), (1, 3, 1, 0)]);
+        add_week(&mut season, vec![(2, 1, 3, 0), (3, 0, 3, 0)]);
+        assert_eq!(ids(&season.standings()), vec![0, 1, 3, 2]);
+        assert_eq!(ids(&season.division_standings(0, 0).unwrap()), vec![0, 1, 2]);
+        assert_eq!(ids(&season.conference_standings(0).unwrap()), vec![0, 1, 3, 2]);
+        assert_eq!(explain(&season, 1, 0), (0, 1, TiebreakerRule::DivisionRecord));
+
+        // Teams 2 and 3 are in different divisions and never met, and their
+        // conference records are level, so point differential decides
+        assert_eq!(explain(&season, 2, 3), (3, 2, TiebreakerRule::PointDifferential));
     }
 
-    /// Simulate a full week of season matchups
-    ///
-    /// ### Example
-    /// ```
-    /// use fbsim_core::team::FootballTeam;
-    /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    ///
-    /// // Create a new season
-    /// let mut my_league_season = LeagueSeason::new();
-    ///
-    /// // Add 4 teams to the season
-    /// my_league_season.add_team(0, FootballTeam::new());
-    /// my_league_season.add_team(1, FootballTeam::new());
-    /// my_league_season.add_team(2, FootballTeam::new());
-    /// my_league_season.add_team(3, FootballTeam::new());
-    ///
-    /// // Generate the season schedule
-    /// let mut rng = rand::thread_rng();
-    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
-    ///
-    /// // Simulate the first week of the season
-    /// my_league_season.sim_week(0, &mut rng);
-    /// ```
-    pub fn sim_week(&mut self, week: usize, rng: &mut impl Rng) -> Result<(), String> {
-        // Check if the prior week is not complete
-        if week > 0 {
-            let prev_week = match self.weeks.get(week - 1) {
-                Some(w) => w,
-                None => return Err(format!("Failed to get previous week {} from season {}", week-1, self.year))
-            };
-            if !prev_week.complete() {
-                return Err(
-                    format!(
-                        "Cannot simulate week {} for season {}: previous week {} not complete",
-                        week, self.year, week-1
-                    )
-                );
-            }
+    #[test]
+    fn test_seeded_matchup_replays_independently() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            let team = FootballTeam::from_overalls("Team", "TM", 40 + 8 * id as u32, 80 - 6 * id as u32).unwrap();
+            season.add_team(id, team).unwrap();
         }
-
-        // Try to get the given week
-        let mut _week_to_sim = match self.weeks.get_mut(week) {
-            Some(w) => w,
-            None => return Err(format!("No such week for season {}: {}", self.year, week)),
+        let mut rng = SmallRng::seed_from_u64(1501);
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        season.set_sim_seed(Some(0xF00D));
+        let scheduled = season.clone();
+
+        // Each game's result depends only on the season seed, not on the
+        // caller's RNG or the order the games are simulated in
+        season.sim_regular_season(&mut rng).unwrap();
+        let mut reordered = scheduled.clone();
+        let mut other_rng = SmallRng::seed_from_u64(2);
+        for week in 0..reordered.weeks().len() {
+            let order: Vec<usize> = (0..reordered.weeks()[week].matchups().len()).rev().collect();
+            reordered.sim_matchups(week, &order, false, &mut other_rng).unwrap();
+        }
+        let scores = |season: &LeagueSeason| -> Vec<(u32, u32)> {
+            season.weeks().iter()
+                .flat_map(|w| w.matchups().iter())
+                .map(|m| (m.context().home_score(), m.context().away_score()))
+                .collect()
         };
+        assert_eq!(scores(&season), scores(&reordered));
+
+        // A game picked from the saved season replays to the recorded score
+        let saved = serde_json::to_string(&season).unwrap();
+        let loaded: LeagueSeason = serde_json::from_str(&saved).unwrap();
+        assert_eq!(loaded.sim_seed(), Some(0xF00D));
+        let (week, matchup) = (3, 2);
+        let recorded = season.weeks()[week].matchups()[matchup].context();
+        let (game, context) = loaded.resimulate_matchup_replay(week, matchup).unwrap();
+        assert_eq!(context.home_score(), recorded.home_score());
+        assert_eq!(context.away_score(), recorded.away_score());
+        assert!(context.game_over());
+        assert!(!game.drives().is_empty());
+        assert_eq!(
+            loaded.matchup_seed(week, matchup).unwrap(),
+            derive_matchup_seed(0xF00D, 2026, week, matchup)
+        );
 
-        // Check if the current week is complete
-        if _week_to_sim.complete() {
-            return Err(format!("Season {} week {} already completed", self.year, week));
+        // Unplayed games and unseeded seasons cannot be replayed
+        assert!(scheduled.resimulate_matchup_replay(0, 0).is_err());
+        let mut unseeded = season.clone();
+        unseeded.set_sim_seed(None);
+        assert!(unseeded.resimulate_matchup_replay(week, matchup).is_err());
+    }
+
+    #[test]
+    fn test_box_scores_archived_for_every_game() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        // Simulate a season and its playoffs play by play
+        let mut rng = SmallRng::seed_from_u64(1504);
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            let team = FootballTeam::from_overalls("Team", "TM", 40 + 8 * id as u32, 80 - 6 * id as u32).unwrap();
+            season.add_team(id, team).unwrap();
+        }
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        season.sim_regular_season(&mut rng).unwrap();
+        season.generate_playoffs(LeagueSeasonPlayoffOptions::new().num_playoff_teams(4), &mut rng).unwrap();
+        season.sim_playoffs(&mut rng).unwrap();
+
+        // Every game has a box score which agrees with its archived stats
+        // and accounts for the whole game clock
+        let matchups: Vec<&LeagueSeasonMatchup> = season.weeks().iter()
+            .chain(season.playoffs().winners_bracket().iter())
+            .flat_map(|week| week.matchups().iter())
+            .collect();
+        assert_eq!(matchups.len(), 30);
+        let mut third_down_attempts = 0;
+        let mut sacks = 0;
+        for matchup in matchups.iter() {
+            let context = matchup.context();
+            let box_score = matchup.box_score().unwrap();
+            for (team, offense) in [
+                (matchup.home_box_score().unwrap(), matchup.home_stats().as_ref().unwrap()),
+                (matchup.away_box_score().unwrap(), matchup.away_stats().as_ref().unwrap())
+            ] {
+                assert_eq!(team.passing_yards(), offense.passing().yards());
+                assert_eq!(team.rushing_yards(), offense.rushing().yards());
+                assert_eq!(team.total_yards(), team.passing_yards() + team.rushing_yards());
+                assert_eq!(team.turnovers(), offense.turnovers());
+                assert!(team.third_down_conversions() <= team.third_down_attempts());
+                third_down_attempts += team.third_down_attempts();
+                sacks += team.sacks();
+            }
+            let overtime = match context.quarter() {
+                q if q > 4 => 600 * (q - 4) - context.half_seconds(),
+                _ => 0
+            };
+            assert_eq!(
+                box_score.home().time_of_possession() + box_score.away().time_of_possession(),
+                3600 + overtime
+            );
+        }
+        assert!(third_down_attempts > 0);
+        assert!(sacks > 0);
+
+        // Saves from before box scores were recorded still load
+        let mut save = serde_json::to_value(&season).unwrap();
+        for week in save["weeks"].as_array_mut().unwrap() {
+            for matchup in week["matchups"].as_array_mut().unwrap() {
+                assert!(matchup.as_object_mut().unwrap().remove("box_score").is_some());
+            }
         }
+        let loaded: LeagueSeason = serde_json::from_value(save).unwrap();
+        assert!(loaded.weeks()[0].matchups()[0].box_score().is_none());
+        assert!(loaded.weeks()[0].matchups()[0].home_stats().is_some());
+        assert!(loaded.lint().is_empty());
+
+        // Entering a score by hand clears the box score
+        let mut edited = season.clone();
+        edited.weeks_mut()[0].matchups_mut()[0].enter_score(21, 14).unwrap();
+        assert!(edited.weeks()[0].matchups()[0].box_score().is_none());
+    }
+    #[test]
+    fn test_odd_team_counts_schedule_byes() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut rng = SmallRng::seed_from_u64(1505);
+        for num_teams in [5, 7] {
+            let mut season = LeagueSeason::with_year(2026);
+            for id in 0..num_teams {
+                let team = FootballTeam::from_overalls("Team", "TM", 40 + 6 * id as u32, 70).unwrap();
+                season.add_team(id, team).unwrap();
+            }
+            season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+
+            // Each week one team has a bye, and each team has one bye per
+            // round robin
+            assert_eq!(season.weeks().len(), 2 * num_teams);
+            let mut byes: BTreeMap<usize, usize> = BTreeMap::new();
+            let mut meetings: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+            for week in season.weeks().iter() {
+                assert_eq!(week.matchups().len(), (num_teams - 1) / 2);
+                let idle: Vec<usize> = (0..num_teams).filter(|id| week.team_matchup(*id).is_none()).collect();
+                assert_eq!(idle.len(), 1);
+                *byes.entry(idle[0]).or_default() += 1;
+                for matchup in week.matchups().iter() {
+                    *meetings.entry((*matchup.home_team(), *matchup.away_team())).or_default() += 1;
+                }
+            }
+            assert!((0..num_teams).all(|id| byes.get(&id) == Some(&2)));
+            for home in 0..num_teams {
+                for away in (0..num_teams).filter(|away| *away != home) {
+                    assert_eq!(meetings.get(&(home, away)), Some(&1));
+                }
+            }
 
-        // Loop through the week's matchups mutably
-        for (i, matchup) in _week_to_sim.matchups_mut().iter_mut().enumerate() {
-            // Skip matchups that have already been completed
-            if matchup.context().game_over() {
-                continue
+            // A team's matchups are empty exactly on its bye weeks
+            for id in 0..num_teams {
+                let matchups = season.team_matchups(id).unwrap();
+                for (week, matchup) in season.weeks().iter().zip(matchups.matchups().iter()) {
+                    assert_eq!(matchup.is_none(), week.team_matchup(id).is_none());
+                }
             }
 
-            // Try to get the home team for the matchup
-            let home_id = matchup.home_team();
-            let home_team = match self.teams.get(home_id) {
-                Some(t) => t,
-                None => return Err(
-                    format!(
-                        "Season {} week {} matchup {} references nonexistent home team ID: {}",
-                        self.year, week, i, home_id
-                    )
-                )
-            };
+            // Mid-season, remaining games count only the unplayed matchups
+            season.sim_week(0, &mut rng).unwrap();
+            season.sim_week(1, &mut rng).unwrap();
+            let picture = season.playoff_picture(2).unwrap();
+            for entry in picture.entries().iter() {
+                let unplayed = season.weeks().iter()
+                    .filter_map(|week| week.team_matchup(entry.team_id()))
+                    .filter(|matchup| !matchup.context().game_over())
+                    .count();
+                assert_eq!(entry.remaining_games(), unplayed);
+            }
 
-            // Try to get the away team for the matchup
-            let away_id = matchup.away_team();
-            let away_team = match self.teams.get(away_id) {
-                Some(t) => t,
-                None => return Err(
-                    format!(
-                        "Season {} week {} matchup {} references nonexistent away team ID: {}",
-                        self.year, week, i, away_id
-                    )
-                )
-            };
+            // Byes count as neither wins, losses nor ties
+            season.sim_regular_season(&mut rng).unwrap();
+            for (_, record) in season.standings().iter() {
+                assert_eq!(record.wins() + record.losses() + record.ties(), 2 * (num_teams - 1));
+            }
 
-            // Simulate the matchup
-            let mut game = Game::new();
-            let simulator = GameSimulator::new();
-            let context = match simulator.sim_game(
-                home_team, away_team,
-                matchup.context().clone(),
-                &mut game, rng
-            ) {
-                Ok(c) => c,
-                Err(e) => return Err(format!("Error while simulating matchup: {}", e))
-            };
+            // The season round-trips through serialization
+            let json = serde_json::to_string(&season).unwrap();
+            let loaded: LeagueSeason = serde_json::from_str(&json).unwrap();
+            assert_eq!(loaded, season);
+        }
 
-            // Update the matchup context and stats
-            *matchup.context_mut() = context;
-            *matchup.away_stats_mut() = Some(game.away_stats());
-            *matchup.home_stats_mut() = Some(game.home_stats());
+        // Byes may be disallowed for odd team counts
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..5 {
+            season.add_team(id, FootballTeam::new()).unwrap();
         }
-        Ok(())
+        let options = LeagueSeasonScheduleOptions::new().allow_byes(false);
+        assert!(season.generate_schedule(options, &mut rng).is_err());
     }
 
-    /// Simulate the regular season
-    ///
-    /// ### Example
-    /// ```
-    /// use fbsim_core::team::FootballTeam;
-    /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    ///
-    /// // Create a new season
-    /// let mut my_league_season = LeagueSeason::new();
-    ///
-    /// // Add 4 teams to the season
-    /// my_league_season.add_team(0, FootballTeam::new());
-    /// my_league_season.add_team(1, FootballTeam::new());
-    /// my_league_season.add_team(2, FootballTeam::new());
-    /// my_league_season.add_team(3, FootballTeam::new());
-    ///
-    /// // Generate the season schedule
-    /// let mut rng = rand::thread_rng();
-    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
-    ///
-    /// // Simulate the entire season
-    /// my_league_season.sim_regular_season(&mut rng);
-    /// ```
-    pub fn sim_regular_season(&mut self, rng: &mut impl Rng) -> Result<(), String> {
-        for i in 0..self.weeks.len() {
-            // Skip weeks which have already completed
-            let week = match self.weeks.get(i) {
-                Some(w) => w,
-                None => return Err(
-                    format!(
-                        "Failed to simulate season {} week {}: No such week",
-                        self.year, i
-                    )
-                ),
-            };
-            if week.complete() {
-                continue;
+    #[test]
+    fn test_reseeded_playoffs_pair_best_with_worst() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::league::season::playoffs::LeagueSeasonPlayoffs;
+
+        // A 6-team conference whose 5 and 6 seeds win their wild card games
+        let mut rng = SmallRng::seed_from_u64(1506);
+        let mut wild_card_upsets = |reseed: bool| {
+            let mut playoffs = LeagueSeasonPlayoffs::new();
+            for seed in 1..=6 {
+                playoffs.add_team(seed - 1, "TM", None).unwrap();
             }
-
-            // Simulate the week
-            match self.sim_week(i, rng) {
-                Ok(()) => (),
-                Err(error) => return Err(
-                    format!(
-                        "Failed to simulate season {} week {}: {}",
-                        self.year,
-                        i, error
-                    )
-                ),
+            playoffs.set_reseed(reseed).unwrap();
+            playoffs.gen_next_playoff_round(&mut rng).unwrap();
+            for matchup in playoffs.conference_bracket_mut(0).unwrap()[0].matchups_mut().iter_mut() {
+                matchup.enter_score(14, 21).unwrap();
             }
-        }
-        Ok(())
+            assert!(playoffs.set_reseed(!reseed).is_err());
+            playoffs.gen_next_playoff_round(&mut rng).unwrap();
+            playoffs
+        };
+        let seeds = |playoffs: &LeagueSeasonPlayoffs, round: usize| -> Vec<(usize, usize)> {
+            playoffs.conference_bracket(0).unwrap()[round].matchups().iter()
+                .map(|m| (*m.home_team() + 1, *m.away_team() + 1))
+                .collect()
+        };
+
+        // By default the 1 seed meets the winner of the 4-5 game
+        let fixed = wild_card_upsets(false);
+        assert_eq!(seeds(&fixed, 0), vec![(3, 6), (4, 5)]);
+        assert_eq!(seeds(&fixed, 1), vec![(1, 5), (2, 6)]);
+
+        // Reseeded, the 1 seed meets the worst surviving seed
+        let reseeded = wild_card_upsets(true);
+        assert_eq!(seeds(&reseeded, 0), vec![(3, 6), (4, 5)]);
+        assert_eq!(seeds(&reseeded, 1), vec![(1, 6), (2, 5)]);
+
+        // Reseeding is serialized only when enabled
+        assert!(!serde_json::to_string(&fixed).unwrap().contains("reseed"));
+        let json = serde_json::to_string(&reseeded).unwrap();
+        let loaded: LeagueSeasonPlayoffs = serde_json::from_str(&json).unwrap();
+        assert!(loaded.reseed());
+        assert_eq!(loaded, reseeded);
     }
 
-    /// Simulate a full season of matchups
-    ///
-    /// ### Example
-    /// ```
-    /// use fbsim_core::team::FootballTeam;
-    /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    ///
-    /// // Create a new season
-    /// let mut my_league_season = LeagueSeason::new();
-    ///
-    /// // Add 4 teams to the season
-    /// my_league_season.add_team(0, FootballTeam::new());
-    /// my_league_season.add_team(1, FootballTeam::new());
-    /// my_league_season.add_team(2, FootballTeam::new());
-    /// my_league_season.add_team(3, FootballTeam::new());
-    ///
-    /// // Generate the season schedule
-    /// let mut rng = rand::thread_rng();
-    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
-    ///
-    /// // Simulate the entire season
-    /// my_league_season.sim(&mut rng);
-    /// ```
-    pub fn sim(&mut self, rng: &mut impl Rng) -> Result<(), String> {
-        for i in 0..self.weeks.len() {
-            // Skip weeks which have already completed
-            let week = match self.weeks.get(i) {
-                Some(w) => w,
-                None => return Err(
-                    format!(
-                        "Failed to simulate season {} week {}: {}",
-                        self.year, i, "No such week"
-                    )
-                ),
-            };
-            if week.complete() {
-                continue;
+    #[test]
+    fn test_bracket_tree_links_every_round() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::league::season::playoffs::{LeagueSeasonPlayoffs, PlayoffBracketGame, PlayoffBracketTree};
+
+        // Three 3-team conferences, so both kinds of bracket have byes
+        let mut rng = SmallRng::seed_from_u64(1518);
+        let mut playoffs = LeagueSeasonPlayoffs::new();
+        for id in 0..9 {
+            playoffs.add_team(id, "TM", Some(id / 3)).unwrap();
+        }
+        let winner = |game: &PlayoffBracketGame| match game {
+            PlayoffBracketGame::Matchup(matchup) => matchup.winner(),
+            PlayoffBracketGame::Bye { team_id, .. } => Some(*team_id),
+            PlayoffBracketGame::Pending => None
+        };
+        let check = |playoffs: &LeagueSeasonPlayoffs, tree: &PlayoffBracketTree| {
+            let root = tree.root.unwrap();
+            let mut parents = vec![0; tree.nodes.len()];
+            for (index, node) in tree.nodes.iter().enumerate() {
+                for child in node.children.iter() {
+                    assert!(*child < index);
+                    parents[*child] += 1;
+                }
+                let first_round = node.conference.is_some() && node.round == 0;
+                let expected = match (&node.game, first_round) {
+                    (_, true) => 0,
+                    (PlayoffBracketGame::Bye { .. }, false) => 1,
+                    (_, false) => 2
+                };
+                assert_eq!(node.children.len(), expected, "node {}", index);
+                if let PlayoffBracketGame::Matchup(matchup) = &node.game {
+                    for child in node.children.iter() {
+                        if let Some(team) = winner(&tree.nodes[*child].game) {
+                            assert!(matchup.participated(team), "node {} child {}", index, child);
+                        }
+                    }
+                }
             }
-
-            // Simulate the week
-            match self.sim_week(i, rng) {
-                Ok(()) => (),
-                Err(error) => return Err(
-                    format!(
-                        "Failed to simulate season {} week {}: {}",
-                        self.year,
-                        i, error
-                    )
-                ),
+            for (index, count) in parents.iter().enumerate() {
+                assert_eq!(*count, if index == root { 0 } else { 1 }, "node {}", index);
             }
+            let generated: usize = playoffs.conference_brackets().values()
+                .chain(std::iter::once(playoffs.winners_bracket()))
+                .flat_map(|bracket| bracket.iter())
+                .map(|round| round.matchups().len())
+                .sum();
+            let matchups = tree.nodes.iter()
+                .filter(|node| matches!(node.game, PlayoffBracketGame::Matchup(_)))
+                .count();
+            assert_eq!(matchups, generated);
+        };
+
+        // Before any round is generated, every game is pending
+        let tree = playoffs.bracket_tree().unwrap();
+        check(&playoffs, &tree);
+        assert_eq!(tree.nodes.len(), 12);
+        assert_eq!(tree.round(None, 0).len(), 2);
+        assert_eq!(tree.nodes[tree.root.unwrap()].game, PlayoffBracketGame::Pending);
+
+        // Each round links to the last as it is generated, with the away
+        // team winning every game
+        while !playoffs.complete() {
+            playoffs.gen_next_playoff_round(&mut rng).unwrap();
+            check(&playoffs, &playoffs.bracket_tree().unwrap());
+            let away_wins = |bracket: &mut Vec<LeagueSeasonWeek>| {
+                for matchup in bracket.iter_mut().flat_map(|round| round.matchups_mut().iter_mut()) {
+                    if !matchup.context().game_over() {
+                        matchup.enter_score(14, 21).unwrap();
+                    }
+                }
+            };
+            playoffs.conference_brackets_mut().values_mut().for_each(away_wins);
+            away_wins(playoffs.winners_bracket_mut());
+            check(&playoffs, &playoffs.bracket_tree().unwrap());
         }
-        Ok(())
+
+        // Once complete the root is the championship game
+        let tree = playoffs.bracket_tree().unwrap();
+        assert_eq!(tree.nodes.len(), 12);
+        assert!(tree.nodes.iter().all(|node| node.game != PlayoffBracketGame::Pending));
+        let championship = playoffs.winners_bracket().last().unwrap().matchups()[0].clone();
+        assert_eq!(tree.nodes[tree.root.unwrap()].game, PlayoffBracketGame::Matchup(Box::new(championship)));
+        let json = serde_json::to_string(&tree).unwrap();
+        assert_eq!(serde_json::from_str::<PlayoffBracketTree>(&json).unwrap(), tree);
     }
 
-    /// Get all season matchups involving a team
-    ///
-    /// ### Example
-    /// ```
-    /// use fbsim_core::team::FootballTeam;
-    /// use fbsim_core::league::season::LeagueSeason;
-    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
-    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchups;
-    ///
-    /// // Create a new season
-    /// let mut my_league_season = LeagueSeason::new();
-    ///
-    /// // Add 4 teams to the season
-    /// my_league_season.add_team(0, FootballTeam::new());
-    /// my_league_season.add_team(1, FootballTeam::new());
-    /// my_league_season.add_team(2, FootballTeam::new());
-    /// my_league_season.add_team(3, FootballTeam::new());
-    ///
-    /// // Generate the season schedule
-    /// let mut rng = rand::thread_rng();
-    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
-    ///
-    /// // Simulate the entire season
-    /// my_league_season.sim(&mut rng);
-    ///
-    /// // Get the mathups for team 0
-    /// let matchups: LeagueSeasonMatchups = my_league_season.team_matchups(0).unwrap();
-    /// ```
-    pub fn team_matchups(&self, id: usize) -> Result<LeagueSeasonMatchups, String> {
-        // Ensure the given team ID exists
-        let _team = match self.team(id) {
-            Some(t) => t,
-            None => return Err(
-                format!(
-                    "No team found with ID {} in season {}",
-                    id, self.year()
-                )
-            )
-        };
+    #[test]
+    fn test_team_stats() {
+        use crate::game::context::GameContextBuilder;
+        use crate::league::season::matchup::LeagueSeasonMatchup;
+        use crate::league::season::week::LeagueSeasonWeek;
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut rng = SmallRng::seed_from_u64(1526);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
+        }
 
-        // Construct the matchups vector
-        let mut matchups: Vec<Option<LeagueSeasonMatchup>> = Vec::new();
-        for week in self.weeks.iter() {
-            matchups.push(week.team_matchup(id));
+        // Weeks of (home, away, home score, away score, neutral site), with
+        // a score of None for a game not yet played
+        let weeks = vec![
+            vec![(0, 1, Some((24, 17)), false), (2, 3, Some((10, 13)), false)],
+            vec![(1, 0, Some((3, 20)), false), (3, 2, Some((14, 14)), false)],
+            vec![(0, 2, None, false), (1, 3, Some((27, 7)), false)],
+            vec![(3, 0, Some((21, 31)), true), (2, 1, Some((9, 6)), false)]
+        ];
+        for games in weeks {
+            let mut week = LeagueSeasonWeek::new();
+            for (home, away, score, neutral_site) in games {
+                let mut matchup = LeagueSeasonMatchup::new(home, away, "HOME", "AWAY", &mut rng);
+                if let Some((home_score, away_score)) = score {
+                    *matchup.context_mut() = GameContextBuilder::new()
+                        .quarter(4)
+                        .half_seconds(0)
+                        .home_score(home_score)
+                        .away_score(away_score)
+                        .neutral_site(neutral_site)
+                        .game_over(true)
+                        .build()
+                        .unwrap();
+                }
+                week.matchups_mut().push(matchup);
+            }
+            my_league_season.weeks_mut().push(week);
         }
-        Ok(LeagueSeasonMatchups::new(id, matchups))
-    }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        // Team 0 won its three games, skipping the unplayed one, and its
+        // neutral site game counts in neither its home nor its away split
+        let stats = my_league_season.team_stats(0).unwrap();
+        assert_eq!(stats.overall().record().to_string(), "3-0-0");
+        assert_eq!((stats.points_for(), stats.points_against()), (75, 41));
+        assert_eq!(stats.point_differential(), 34);
+        assert_eq!(stats.home().record().to_string(), "1-0-0");
+        assert_eq!(stats.away().record().to_string(), "1-0-0");
+        assert_eq!(stats.neutral().record().to_string(), "1-0-0");
+        assert_eq!(stats.average_margin_of_victory(), Some(34.0 / 3.0));
+        assert_eq!(stats.streak().unwrap().to_string(), "W3");
+
+        // Streaks end with the most recent result, including ties
+        let streaks: Vec<String> = (1..4)
+            .map(|id| my_league_season.team_stats(id).unwrap().streak().unwrap().to_string())
+            .collect();
+        assert_eq!(streaks, vec!["L1", "W1", "L2"]);
+        let stats = my_league_season.team_stats(3).unwrap();
+        assert_eq!(stats.overall().record().to_string(), "1-2-1");
+        assert!(stats.average_margin_of_victory() == Some(3.0));
+        assert!(my_league_season.team_stats(4).is_err());
+
+        // The stats of every team agree with each team's stats and the
+        // standings after a simulated season
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        my_league_season.sim_regular_season(&mut rng).unwrap();
+        let all_stats = my_league_season.all_team_stats();
+        for (id, record) in my_league_season.standings() {
+            let stats = &all_stats[&id];
+            assert_eq!(*stats, my_league_season.team_stats(id).unwrap());
+            assert_eq!(*stats.overall().record(), record);
+            assert_eq!(
+                stats.home().games_played() + stats.away().games_played() + stats.neutral().games_played(),
+                stats.overall().games_played()
+            );
+        }
+        let points_for: u32 = all_stats.values().map(|s| s.points_for()).sum();
+        let points_against: u32 = all_stats.values().map(|s| s.points_against()).sum();
+        assert_eq!(points_for, points_against);
+        let json = serde_json::to_string(&all_stats[&0]).unwrap();
+        assert_eq!(serde_json::from_str::<LeagueSeasonTeamStats>(&json).unwrap(), all_stats[&0]);
+    }
 
     #[test]
-    fn test_schedule_gen() {
-        // Create a new season
-        let mut my_league_season = LeagueSeason::new();
-    
-        // Add some teams to the season
-        let _ = my_league_season.add_team(0, FootballTeam::new());
-        let _ = my_league_season.add_team(1, FootballTeam::new());
-        let _ = my_league_season.add_team(2, FootballTeam::new());
-        let _ = my_league_season.add_team(3, FootballTeam::new());
-        let _ = my_league_season.add_team(4, FootballTeam::new());
-        let _ = my_league_season.add_team(5, FootballTeam::new());
-        
-        // Generate the season schedule
-        let mut rng = rand::thread_rng();
-        let _ = my_league_season.generate_schedule(
-            LeagueSeasonScheduleOptions::new(),
-            &mut rng
+    fn test_sim_weeks_in_chunks() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let mut rng = SmallRng::seed_from_u64(1531);
+        let mut my_league_season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            my_league_season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        let num_weeks = my_league_season.weeks().len();
+        let mut chunked = my_league_season.clone();
+
+        // Invalid ranges and skipped weeks fail without simulating anything
+        assert!(matches!(chunked.sim_weeks(2, 1, &mut rng), Err(LeagueError::Invalid { .. })));
+        assert_eq!(
+            chunked.sim_weeks(0, num_weeks + 1, &mut rng),
+            Err(LeagueError::WeekOutOfRange { week: num_weeks })
         );
-
-        // Validate the schedule
-        // Map team IDs to a tuple of usizes which represent:
-        // Home games, away games, consecutive away games
-        let mut home_away: BTreeMap<usize, (usize, usize, usize)> = BTreeMap::new();
-        for (id, _) in my_league_season.teams().iter() {
-            home_away.insert(*id, (0, 0, 0));
+        assert!(chunked.sim_weeks(1, 2, &mut rng).is_err());
+        assert!(!chunked.started());
+
+        // Simulating in chunks matches simulating the regular season at once
+        let mut rng = SmallRng::seed_from_u64(1531);
+        my_league_season.sim_regular_season(&mut rng).unwrap();
+        let mut rng = SmallRng::seed_from_u64(1531);
+        let mut from = 0;
+        while from < num_weeks {
+            let to = (from + 3).min(num_weeks);
+            let reports = chunked.sim_weeks(from, to, &mut rng).unwrap();
+            assert_eq!(reports.len(), to - from);
+            assert!(chunked.weeks()[to - 1].complete());
+            if to < num_weeks {
+                assert!(!chunked.weeks()[to].started());
+            }
+            from = to;
         }
-        for week in my_league_season.weeks() {
-            // Loop through the week's matchups
-            for matchup in week.matchups().iter() {
-                let home_id = matchup.home_team();
-                let away_id = matchup.away_team();
+        assert_eq!(chunked.weeks(), my_league_season.weeks());
 
-                // Tally home games, away games, consecutive away games
-                home_away.entry(*home_id)
-                    .and_modify(|(home, _, cons_away)| {
-                        *home += 1;
-                        *cons_away = 0;
-                    });
-                home_away.entry(*away_id)
-                    .and_modify(|(_, away, cons_away)| {
-                        *away += 1;
-                        *cons_away = 0;
-                    });
+        // Completed weeks are skipped
+        assert!(chunked.sim_weeks(0, num_weeks, &mut rng).unwrap().is_empty());
+    }
 
-                // Assert that no team plays three away games in a row
-                if let Some(entry) = home_away.get(away_id) {
-                    let (_, _, cons_away) = entry;
-                    assert!(*cons_away < 3);
-                }
-            }
+    #[test]
+    fn test_weather_drawn_from_distribution() {
+        use std::collections::BTreeSet;
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::game::context::weather::Weather;
+
+        let mut rng = SmallRng::seed_from_u64(1528);
+        let mut clear = LeagueSeason::with_year(2026);
+        for id in 0..8 {
+            clear.add_team(id, FootballTeam::new()).unwrap();
         }
+        clear.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        clear.set_sim_seed(Some(0xBEEF));
+        let mut varied = clear.clone();
+        assert!(varied.set_weather_distribution(Some(WeatherDistribution::new().clear(-1.0))).is_err());
+        varied.set_weather_distribution(Some(WeatherDistribution::new())).unwrap();
+        let weathers = |season: &LeagueSeason| -> Vec<Weather> {
+            let playoff_weeks = season.playoffs().conference_brackets().values()
+                .flatten()
+                .chain(season.playoffs().winners_bracket().iter());
+            season.weeks().iter().chain(playoff_weeks)
+                .flat_map(|w| w.matchups().iter())
+                .map(|m| m.context().weather())
+                .collect()
+        };
 
-        // Assert that each team plays an equal number of home and away games
-        for (_, (home, away, _)) in home_away.iter() {
-            assert!(home == away);
+        for season in [&mut clear, &mut varied] {
+            season.sim_regular_season(&mut rng).unwrap();
+            season.generate_playoffs(LeagueSeasonPlayoffOptions::new().num_playoff_teams(4), &mut rng).unwrap();
+            season.sim_playoffs(&mut rng).unwrap();
         }
+
+        // Without a distribution every game is played in clear weather, and
+        // with one the weather varies from game to game
+        assert!(weathers(&clear).iter().all(|w| *w == Weather::Clear));
+        let drawn: BTreeSet<Weather> = weathers(&varied).into_iter().collect();
+        assert!(drawn.len() > 2, "{:?}", drawn);
+
+        // The weather is drawn from each game's own RNG, so a replay matches
+        let (week, matchup) = (2, 1);
+        let recorded = varied.weeks()[week].matchups()[matchup].context().clone();
+        let (_, context) = varied.resimulate_matchup_replay(week, matchup).unwrap();
+        assert_eq!(context.weather(), recorded.weather());
+        assert_eq!(context.home_score(), recorded.home_score());
+
+        // The distribution is saved with the season
+        let loaded: LeagueSeason = serde_json::from_str(&serde_json::to_string(&varied).unwrap()).unwrap();
+        assert_eq!(loaded.weather_distribution(), &Some(WeatherDistribution::new()));
+        assert!(!serde_json::to_string(&clear).unwrap().contains("weather_distribution"));
     }
 }
@@ -0,0 +1,182 @@
+#![doc = include_str!("../../../docs/league/season/annotation.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+use crate::league::error::LeagueError;
+use crate::league::season::LeagueSeason;
+use crate::league::season::report::{bracket_title, round_name, team_name};
+
+/// The maximum number of characters in an annotation's text
+pub const ANNOTATION_MAX_LEN: usize = 280;
+
+/// # `AnnotationTarget` enum
+///
+/// The part of a season a `LeagueSeasonAnnotation` is attached to
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum AnnotationTarget {
+    /// The season as a whole
+    Season,
+    /// A regular season week
+    Week { week: usize },
+    /// A regular season matchup, by week and index within the week
+    Matchup { week: usize, matchup: usize },
+    /// A team in the season
+    Team { team_id: usize },
+    /// A playoff round of a conference bracket, or of the winners bracket
+    /// if no conference is given
+    PlayoffRound { conference: Option<usize>, round: usize }
+}
+
+impl AnnotationTarget {
+    /// Whether the target exists in a season
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::annotation::AnnotationTarget;
+    ///
+    /// let my_league_season = LeagueSeason::new();
+    /// assert!(AnnotationTarget::Season.exists_in(&my_league_season));
+    /// assert!(!AnnotationTarget::Week { week: 0 }.exists_in(&my_league_season));
+    /// ```
+    pub fn exists_in(&self, season: &LeagueSeason) -> bool {
+        match self {
+            AnnotationTarget::Season => true,
+            AnnotationTarget::Week { week } => *week < season.weeks().len(),
+            AnnotationTarget::Matchup { week, matchup } => season.weeks().get(*week)
+                .is_some_and(|w| *matchup < w.matchups().len()),
+            AnnotationTarget::Team { team_id } => season.teams().contains_key(team_id),
+            AnnotationTarget::PlayoffRound { conference, round } => {
+                let bracket = match conference {
+                    Some(conference) => season.playoffs().conference_bracket(*conference),
+                    None => Some(season.playoffs().winners_bracket())
+                };
+                bracket.is_some_and(|b| *round < b.len())
+            }
+        }
+    }
+
+    /// Describe the target as it appears in a season, such as `Week 3` or
+    /// the name of a team, marking targets which no longer exist
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::annotation::AnnotationTarget;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new()).unwrap();
+    /// let name = my_league_season.team(0).unwrap().name().to_string();
+    /// assert_eq!(AnnotationTarget::Team { team_id: 0 }.describe(&my_league_season), name);
+    /// assert_eq!(AnnotationTarget::Week { week: 2 }.describe(&my_league_season), "Week 3 (missing)");
+    /// ```
+    pub fn describe(&self, season: &LeagueSeason) -> String {
+        let description = match self {
+            AnnotationTarget::Season => format!("{} Season", season.year()),
+            AnnotationTarget::Week { week } => format!("Week {}", week + 1),
+            AnnotationTarget::Matchup { week, matchup } => {
+                let game = season.weeks().get(*week).and_then(|w| w.matchups().get(*matchup));
+                match game {
+                    Some(game) => format!(
+                        "Week {}: {} at {}",
+                        week + 1,
+                        team_name(season, *game.away_team()),
+                        team_name(season, *game.home_team())
+                    ),
+                    None => format!("Week {}, Game {}", week + 1, matchup + 1)
+                }
+            },
+            AnnotationTarget::Team { team_id } => team_name(season, *team_id),
+            AnnotationTarget::PlayoffRound { conference, round } => {
+                let (title, bracket) = match conference {
+                    Some(conference) => (
+                        bracket_title(season, *conference),
+                        season.playoffs().conference_bracket(*conference).map(|b| b.as_slice()).unwrap_or_default()
+                    ),
+                    None => (Some(String::from("Championship")), season.playoffs().winners_bracket().as_slice())
+                };
+                round_name(title.as_deref(), bracket, *round)
+            }
+        };
+        if self.exists_in(season) {
+            description
+        } else {
+            format!("{} (missing)", description)
+        }
+    }
+}
+
+/// # `LeagueSeasonAnnotation` struct
+///
+/// A `LeagueSeasonAnnotation` is a short note attached to part of a season,
+/// such as a week or a matchup, with an optional timestamp
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct LeagueSeasonAnnotation {
+    /// The part of the season the note is attached to
+    pub target: AnnotationTarget,
+    /// The text of the note
+    pub text: String,
+    /// When the note was written, in seconds since the Unix epoch (default:
+    /// none)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>
+}
+
+impl LeagueSeasonAnnotation {
+    /// Constructor for the `LeagueSeasonAnnotation` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::annotation::{AnnotationTarget, LeagueSeasonAnnotation};
+    ///
+    /// let my_annotation = LeagueSeasonAnnotation::new(
+    ///     AnnotationTarget::Week { week: 11 },
+    ///     "The snow week",
+    ///     None
+    /// );
+    /// assert!(my_annotation.validate().is_ok());
+    /// ```
+    pub fn new(target: AnnotationTarget, text: &str, timestamp: Option<u64>) -> LeagueSeasonAnnotation {
+        LeagueSeasonAnnotation {
+            target,
+            text: String::from(text),
+            timestamp
+        }
+    }
+
+    /// Ensure the text of the note is neither blank nor longer than
+    /// `ANNOTATION_MAX_LEN` characters
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::annotation::{AnnotationTarget, LeagueSeasonAnnotation};
+    ///
+    /// let my_annotation = LeagueSeasonAnnotation::new(AnnotationTarget::Season, "  ", None);
+    /// assert!(my_annotation.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), LeagueError> {
+        if self.text.trim().is_empty() {
+            return Err(String::from("Annotation text is blank").into());
+        }
+        let len = self.text.chars().count();
+        if len > ANNOTATION_MAX_LEN {
+            return Err(
+                format!(
+                    "Annotation text is longer than {} characters: {}",
+                    ANNOTATION_MAX_LEN, len
+                ).into()
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,251 @@
+#![doc = include_str!("../../../docs/league/season/attendance.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use crate::league::error::LeagueError;
+use crate::league::matchup::LeagueTeamRecord;
+
+/// Compute a record's win percentage, treating ties as half a win and
+/// defaulting to .500 before any games have been played
+fn win_pct(record: &LeagueTeamRecord) -> f64 {
+    let games = record.wins() + record.losses() + record.ties();
+    if games == 0 {
+        return 0.5;
+    }
+    (*record.wins() as f64 + *record.ties() as f64 / 2.0) / games as f64
+}
+
+/// # `AttendanceModel` struct
+///
+/// An `AttendanceModel` generates cosmetic per-game attendance figures from
+/// a home stadium's capacity, the records of both teams, the point in the
+/// season at which the game is played, and whether the game is a playoff
+/// game. All weights are expressed as percentages of stadium capacity.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AttendanceModel {
+    /// Seed from which each game's attendance is deterministically generated
+    pub seed: u64,
+    /// Percentage of capacity filled for a .500 team facing a .500 opponent
+    pub base_fill: u32,
+    /// Swing in percentage of capacity between a winless and unbeaten home team
+    pub record_weight: u32,
+    /// Swing in percentage of capacity between a winless and unbeaten opponent
+    pub opponent_weight: u32,
+    /// Additional swing applied to the home team's record by the final week
+    pub contention_weight: u32,
+    /// Maximum random variation in percentage of capacity from game to game
+    pub variance: u32
+}
+
+impl Default for AttendanceModel {
+    /// Default constructor for the `AttendanceModel` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::attendance::AttendanceModel;
+    ///
+    /// let my_model = AttendanceModel::default();
+    /// assert!(my_model.base_fill == 75);
+    /// ```
+    fn default() -> Self {
+        AttendanceModel {
+            seed: 0,
+            base_fill: 75,
+            record_weight: 40,
+            opponent_weight: 10,
+            contention_weight: 10,
+            variance: 5
+        }
+    }
+}
+
+impl AttendanceModel {
+    /// Constructor for the `AttendanceModel` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::attendance::AttendanceModel;
+    ///
+    /// let my_model = AttendanceModel::new();
+    /// ```
+    pub fn new() -> AttendanceModel {
+        AttendanceModel::default()
+    }
+
+    /// Set the seed from which attendance is generated
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::attendance::AttendanceModel;
+    ///
+    /// let my_model = AttendanceModel::new().seed(42);
+    /// assert!(my_model.seed == 42);
+    /// ```
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Set the base percentage of capacity filled
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::attendance::AttendanceModel;
+    ///
+    /// let my_model = AttendanceModel::new().base_fill(60);
+    /// assert!(my_model.base_fill == 60);
+    /// ```
+    pub fn base_fill(mut self, base_fill: u32) -> Self {
+        self.base_fill = base_fill;
+        self
+    }
+
+    /// Set the weight of the home team's record
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::attendance::AttendanceModel;
+    ///
+    /// let my_model = AttendanceModel::new().record_weight(50);
+    /// assert!(my_model.record_weight == 50);
+    /// ```
+    pub fn record_weight(mut self, record_weight: u32) -> Self {
+        self.record_weight = record_weight;
+        self
+    }
+
+    /// Set the weight of the opponent's record
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::attendance::AttendanceModel;
+    ///
+    /// let my_model = AttendanceModel::new().opponent_weight(20);
+    /// assert!(my_model.opponent_weight == 20);
+    /// ```
+    pub fn opponent_weight(mut self, opponent_weight: u32) -> Self {
+        self.opponent_weight = opponent_weight;
+        self
+    }
+
+    /// Set the late-season weight of the home team's record
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::attendance::AttendanceModel;
+    ///
+    /// let my_model = AttendanceModel::new().contention_weight(0);
+    /// assert!(my_model.contention_weight == 0);
+    /// ```
+    pub fn contention_weight(mut self, contention_weight: u32) -> Self {
+        self.contention_weight = contention_weight;
+        self
+    }
+
+    /// Set the maximum random variation from game to game
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::attendance::AttendanceModel;
+    ///
+    /// let my_model = AttendanceModel::new().variance(0);
+    /// assert!(my_model.variance == 0);
+    /// ```
+    pub fn variance(mut self, variance: u32) -> Self {
+        self.variance = variance;
+        self
+    }
+
+    /// Validate the attendance model, ensuring every percentage is within
+    /// the range [0, 100]
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::attendance::AttendanceModel;
+    ///
+    /// assert!(AttendanceModel::new().validate().is_ok());
+    /// assert!(AttendanceModel::new().base_fill(101).validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), LeagueError> {
+        let percentages = [
+            ("Base fill", self.base_fill),
+            ("Record weight", self.record_weight),
+            ("Opponent weight", self.opponent_weight),
+            ("Contention weight", self.contention_weight),
+            ("Variance", self.variance)
+        ];
+        for (name, value) in percentages.iter() {
+            if *value > 100 {
+                return Err(
+                    format!(
+                        "{} is out of range [0, 100]: {}",
+                        name, value
+                    ).into()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Generate the attendance for a game given the home stadium's capacity,
+    /// the records of both teams entering the game, the week of the game and
+    /// the number of weeks in the season, and whether the game is a playoff
+    /// game. Playoff games are always sellouts, and the result never exceeds
+    /// the stadium's capacity.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::matchup::LeagueTeamRecord;
+    /// use fbsim_core::league::season::attendance::AttendanceModel;
+    ///
+    /// let my_model = AttendanceModel::new();
+    /// let record = LeagueTeamRecord::new();
+    /// let mut rng = rand::thread_rng();
+    ///
+    /// // Regular season games never exceed capacity
+    /// let attendance = my_model.attendance(70000, &record, &record, 0, 10, false, &mut rng);
+    /// assert!(attendance <= 70000);
+    ///
+    /// // Playoff games sell out
+    /// let attendance = my_model.attendance(70000, &record, &record, 0, 10, true, &mut rng);
+    /// assert_eq!(attendance, 70000);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn attendance(&self, capacity: u32, home_record: &LeagueTeamRecord, away_record: &LeagueTeamRecord, week: usize, num_weeks: usize, playoffs: bool, rng: &mut impl Rng) -> u32 {
+        // Playoff games are always sellouts
+        if playoffs {
+            return capacity;
+        }
+
+        // Determine how far into the season the game is played
+        let progress = if num_weeks > 1 {
+            (week as f64 / (num_weeks - 1) as f64).min(1.0)
+        } else {
+            0.0
+        };
+
+        // Combine the base fill with the record and progress adjustments
+        let home_pct = win_pct(home_record) - 0.5;
+        let away_pct = win_pct(away_record) - 0.5;
+        let mut fill = self.base_fill as f64 +
+            self.record_weight as f64 * home_pct +
+            self.opponent_weight as f64 * away_pct +
+            self.contention_weight as f64 * progress * home_pct;
+
+        // Apply random variation and restrict the fill to the stadium
+        if self.variance > 0 {
+            let variance = self.variance as f64;
+            fill += rng.gen_range(-variance..=variance);
+        }
+        let fill = fill.clamp(0.0, 100.0) / 100.0;
+        ((capacity as f64 * fill).round() as u32).min(capacity)
+    }
+}
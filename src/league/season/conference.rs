@@ -1,4 +1,5 @@
 #![doc = include_str!("../../../docs/league/season/conference.md")]
+use crate::league::error::LeagueError;
 #[cfg(feature = "rocket_okapi")]
 use rocket_okapi::okapi::schemars;
 #[cfg(feature = "rocket_okapi")]
@@ -15,7 +16,7 @@ const MAX_CONFERENCE_NAME_LEN: usize = 64;
 /// # `LeagueDivisionRaw` struct
 ///
 /// A freshly deserialized `LeagueDivision` prior to validation.
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
 pub struct LeagueDivisionRaw {
     pub name: String,
     pub teams: Vec<usize>,
@@ -31,13 +32,13 @@ impl LeagueDivisionRaw {
     /// let raw = LeagueDivisionRaw { name: "East".to_string(), teams: vec![0, 1] };
     /// assert!(raw.validate().is_ok());
     /// ```
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self) -> Result<(), LeagueError> {
         // Validate name length
         if self.name.len() > MAX_DIVISION_NAME_LEN {
             return Err(format!(
                 "Division name '{}' exceeds maximum length of {} characters",
                 self.name, MAX_DIVISION_NAME_LEN
-            ));
+            ).into());
         }
 
         // Check for duplicate team IDs
@@ -47,7 +48,7 @@ impl LeagueDivisionRaw {
                 return Err(format!(
                     "Duplicate team ID {} in division '{}'",
                     team_id, self.name
-                ));
+                ).into());
             }
         }
 
@@ -56,7 +57,7 @@ impl LeagueDivisionRaw {
 }
 
 impl TryFrom<LeagueDivisionRaw> for LeagueDivision {
-    type Error = String;
+    type Error = LeagueError;
 
     fn try_from(raw: LeagueDivisionRaw) -> Result<Self, Self::Error> {
         raw.validate()?;
@@ -70,7 +71,7 @@ impl TryFrom<LeagueDivisionRaw> for LeagueDivision {
 /// # `LeagueConferenceRaw` struct
 ///
 /// A freshly deserialized `LeagueConference` prior to validation.
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
 pub struct LeagueConferenceRaw {
     pub name: String,
     pub divisions: Vec<LeagueDivision>,
@@ -92,13 +93,13 @@ impl LeagueConferenceRaw {
     /// };
     /// assert!(raw.validate().is_ok());
     /// ```
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self) -> Result<(), LeagueError> {
         // Validate name length
         if self.name.len() > MAX_CONFERENCE_NAME_LEN {
             return Err(format!(
                 "Conference name '{}' exceeds maximum length of {} characters",
                 self.name, MAX_CONFERENCE_NAME_LEN
-            ));
+            ).into());
         }
 
         // Check for duplicate team IDs across divisions
@@ -109,7 +110,7 @@ impl LeagueConferenceRaw {
                     return Err(format!(
                         "Duplicate team ID {} across divisions in conference '{}'",
                         team_id, self.name
-                    ));
+                    ).into());
                 }
             }
         }
@@ -119,7 +120,7 @@ impl LeagueConferenceRaw {
 }
 
 impl TryFrom<LeagueConferenceRaw> for LeagueConference {
-    type Error = String;
+    type Error = LeagueError;
 
     fn try_from(raw: LeagueConferenceRaw) -> Result<Self, Self::Error> {
         raw.validate()?;
@@ -150,6 +151,10 @@ impl Default for LeagueDivision {
     /// use fbsim_core::league::season::conference::LeagueDivision;
     ///
     /// let division = LeagueDivision::default();
+    ///
+    /// // The default passes validation
+    /// let json = serde_json::to_string(&division).unwrap();
+    /// assert!(serde_json::from_str::<LeagueDivision>(&json).is_ok());
     /// ```
     fn default() -> Self {
         LeagueDivision {
@@ -250,12 +255,9 @@ impl LeagueDivision {
     /// division.add_team(1).unwrap();
     /// assert_eq!(division.teams().len(), 2);
     /// ```
-    pub fn add_team(&mut self, team_id: usize) -> Result<(), String> {
+    pub fn add_team(&mut self, team_id: usize) -> Result<(), LeagueError> {
         if self.teams.contains(&team_id) {
-            return Err(format!(
-                "Team with ID {} already exists in division '{}'",
-                team_id, self.name
-            ));
+            return Err(LeagueError::DuplicateTeam { id: team_id });
         }
         self.teams.push(team_id);
         Ok(())
@@ -322,6 +324,10 @@ impl Default for LeagueConference {
     /// use fbsim_core::league::season::conference::LeagueConference;
     ///
     /// let conference = LeagueConference::default();
+    ///
+    /// // The default passes validation
+    /// let json = serde_json::to_string(&conference).unwrap();
+    /// assert!(serde_json::from_str::<LeagueConference>(&json).is_ok());
     /// ```
     fn default() -> Self {
         LeagueConference {
@@ -421,14 +427,11 @@ impl LeagueConference {
     /// let division = LeagueDivision::with_name("East");
     /// conference.add_division(division);
     /// ```
-    pub fn add_division(&mut self, division: LeagueDivision) -> Result<(), String> {
+    pub fn add_division(&mut self, division: LeagueDivision) -> Result<(), LeagueError> {
         let existing_teams = self.all_teams();
         for team_id in division.teams() {
             if existing_teams.contains(team_id) {
-                return Err(format!(
-                    "Team with ID {} already exists in conference '{}'",
-                    team_id, self.name
-                ));
+                return Err(LeagueError::DuplicateTeam { id: *team_id });
             }
         }
         self.divisions.push(division);
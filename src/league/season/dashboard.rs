@@ -0,0 +1,407 @@
+#![doc = include_str!("../../../docs/league/season/dashboard.md")]
+use std::collections::BTreeMap;
+
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+use crate::league::error::LeagueError;
+use crate::game::matchup::FootballMatchupResult;
+use crate::league::matchup::{LeagueTeamRecord, LeagueTeamTurnovers};
+use crate::league::season::annotation::LeagueSeasonAnnotation;
+use crate::league::season::{best_record_teams, sort_standings, LeagueSeason};
+use crate::league::season::playoffs::picture::{PlayoffPicture, RemainingGames};
+use crate::league::season::tiebreaker::Tiebreakers;
+
+/// # `SeasonDashboardOptions` struct
+///
+/// Options for computing a `SeasonDashboard`, selecting which sections to
+/// include. The overall standings are always included.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SeasonDashboardOptions {
+    /// Whether to include the standings of each conference
+    pub conference_standings: bool,
+    /// Whether to include the standings of each division
+    pub division_standings: bool,
+    /// Whether to include the teams tied for the best record
+    pub leaders: bool,
+    /// Whether to include a summary of the current week
+    pub week: bool,
+    /// Whether to include each team's turnovers
+    pub turnovers: bool,
+    /// Number of playoff teams for the playoff picture, or `None` to omit it
+    pub num_playoff_teams: Option<usize>,
+    /// Whether to include the notes attached to parts of the season
+    pub annotations: bool
+}
+
+impl Default for SeasonDashboardOptions {
+    /// Default constructor for the `SeasonDashboardOptions` struct, which
+    /// includes every section except the playoff picture
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::dashboard::SeasonDashboardOptions;
+    ///
+    /// let my_options = SeasonDashboardOptions::default();
+    /// assert!(my_options.division_standings);
+    /// assert!(my_options.num_playoff_teams.is_none());
+    /// ```
+    fn default() -> Self {
+        SeasonDashboardOptions {
+            conference_standings: true,
+            division_standings: true,
+            leaders: true,
+            week: true,
+            turnovers: true,
+            num_playoff_teams: None,
+            annotations: true
+        }
+    }
+}
+
+impl SeasonDashboardOptions {
+    /// Constructor for the `SeasonDashboardOptions` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::dashboard::SeasonDashboardOptions;
+    ///
+    /// let my_options = SeasonDashboardOptions::new();
+    /// ```
+    pub fn new() -> SeasonDashboardOptions {
+        SeasonDashboardOptions::default()
+    }
+
+    /// Set whether to include the conference standings
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::dashboard::SeasonDashboardOptions;
+    ///
+    /// let my_options = SeasonDashboardOptions::new().conference_standings(false);
+    /// assert!(!my_options.conference_standings);
+    /// ```
+    pub fn conference_standings(mut self, conference_standings: bool) -> Self {
+        self.conference_standings = conference_standings;
+        self
+    }
+
+    /// Set whether to include the division standings
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::dashboard::SeasonDashboardOptions;
+    ///
+    /// let my_options = SeasonDashboardOptions::new().division_standings(false);
+    /// assert!(!my_options.division_standings);
+    /// ```
+    pub fn division_standings(mut self, division_standings: bool) -> Self {
+        self.division_standings = division_standings;
+        self
+    }
+
+    /// Set whether to include the teams tied for the best record
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::dashboard::SeasonDashboardOptions;
+    ///
+    /// let my_options = SeasonDashboardOptions::new().leaders(false);
+    /// assert!(!my_options.leaders);
+    /// ```
+    pub fn leaders(mut self, leaders: bool) -> Self {
+        self.leaders = leaders;
+        self
+    }
+
+    /// Set whether to include a summary of the current week
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::dashboard::SeasonDashboardOptions;
+    ///
+    /// let my_options = SeasonDashboardOptions::new().week(false);
+    /// assert!(!my_options.week);
+    /// ```
+    pub fn week(mut self, week: bool) -> Self {
+        self.week = week;
+        self
+    }
+
+    /// Set whether to include each team's turnovers
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::dashboard::SeasonDashboardOptions;
+    ///
+    /// let my_options = SeasonDashboardOptions::new().turnovers(false);
+    /// assert!(!my_options.turnovers);
+    /// ```
+    pub fn turnovers(mut self, turnovers: bool) -> Self {
+        self.turnovers = turnovers;
+        self
+    }
+
+    /// Include the playoff picture for the given number of playoff teams
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::dashboard::SeasonDashboardOptions;
+    ///
+    /// let my_options = SeasonDashboardOptions::new().playoff_picture(4);
+    /// assert!(my_options.num_playoff_teams == Some(4));
+    /// ```
+    pub fn playoff_picture(mut self, num_playoff_teams: usize) -> Self {
+        self.num_playoff_teams = Some(num_playoff_teams);
+        self
+    }
+
+    /// Set whether to include the notes attached to parts of the season
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::dashboard::SeasonDashboardOptions;
+    ///
+    /// let my_options = SeasonDashboardOptions::new().annotations(false);
+    /// assert!(!my_options.annotations);
+    /// ```
+    pub fn annotations(mut self, annotations: bool) -> Self {
+        self.annotations = annotations;
+        self
+    }
+}
+
+/// # `SeasonDashboardDivision` struct
+///
+/// The standings of a single division in a `SeasonDashboard`
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct SeasonDashboardDivision {
+    /// Index of the division's conference
+    pub conference: usize,
+    /// Index of the division within its conference
+    pub division: usize,
+    /// The division standings sorted by record
+    pub standings: Vec<(usize, LeagueTeamRecord)>
+}
+
+/// # `SeasonDashboardWeek` struct
+///
+/// A summary of the current week of a season, the first week which has not
+/// yet completed
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct SeasonDashboardWeek {
+    /// Index of the week
+    pub week: usize,
+    /// Number of matchups in the week
+    pub matchups: usize,
+    /// Number of the week's matchups which have completed
+    pub completed: usize,
+    /// Total attendance recorded for the week, if any
+    pub attendance: Option<u64>
+}
+
+/// # `SeasonDashboard` struct
+///
+/// A `SeasonDashboard` aggregates the standings, leaders, current week and
+/// playoff picture of a season, computed from a single pass over its weeks.
+/// Sections omitted by the `SeasonDashboardOptions` are `None`.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct SeasonDashboard {
+    /// The overall standings sorted by record
+    pub standings: Vec<(usize, LeagueTeamRecord)>,
+    /// The standings of each conference, by conference index
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conference_standings: Option<Vec<Vec<(usize, LeagueTeamRecord)>>>,
+    /// The standings of each division
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub division_standings: Option<Vec<SeasonDashboardDivision>>,
+    /// The teams tied for the best record
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leaders: Option<Vec<usize>>,
+    /// A summary of the current week, absent once every week has completed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub week: Option<SeasonDashboardWeek>,
+    /// Each team's turnovers by team ID, or `None` for a team with a game
+    /// completed without detailed stats
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub turnovers: Option<BTreeMap<usize, Option<LeagueTeamTurnovers>>>,
+    /// The playoff picture
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub playoff_picture: Option<PlayoffPicture>,
+    /// The notes attached to parts of the season
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<Vec<LeagueSeasonAnnotation>>
+}
+
+impl SeasonDashboard {
+    /// Compute a dashboard for a season. The records of every team, the
+    /// remaining games, and the current week are accumulated in a single pass
+    /// over the season's matchups and shared between the sections.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::dashboard::{SeasonDashboard, SeasonDashboardOptions};
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Compute the dashboard
+    /// let options = SeasonDashboardOptions::new().playoff_picture(2);
+    /// let dashboard = SeasonDashboard::from_season(&my_league_season, options).unwrap();
+    /// assert_eq!(dashboard.standings, my_league_season.standings());
+    /// assert_eq!(dashboard.week.unwrap().week, 0);
+    /// ```
+    pub fn from_season(season: &LeagueSeason, options: SeasonDashboardOptions) -> Result<SeasonDashboard, LeagueError> {
+        // Accumulate records, turnovers, remaining games, and the current
+        // week in one pass
+        let mut records: BTreeMap<usize, LeagueTeamRecord> = season.teams().keys()
+            .map(|id| (*id, LeagueTeamRecord::new()))
+            .collect();
+        let mut turnovers: BTreeMap<usize, Option<LeagueTeamTurnovers>> = season.teams().keys()
+            .map(|id| (*id, Some(LeagueTeamTurnovers::new())))
+            .collect();
+        let mut remaining_games = RemainingGames::default();
+        let mut current_week: Option<SeasonDashboardWeek> = None;
+        for (week_index, week) in season.weeks().iter().enumerate() {
+            let mut completed = 0;
+            let mut attendance: Option<u64> = None;
+            for matchup in week.matchups().iter() {
+                if let Some(count) = matchup.attendance() {
+                    *attendance.get_or_insert(0) += count as u64;
+                }
+                if !matchup.context().game_over() {
+                    remaining_games.add(*matchup.home_team(), *matchup.away_team());
+                    continue;
+                }
+                completed += 1;
+                for id in [*matchup.home_team(), *matchup.away_team()] {
+                    if let (Some(record), Some(result)) = (records.get_mut(&id), matchup.result(id)) {
+                        match result {
+                            FootballMatchupResult::Win => record.increment_wins(1),
+                            FootballMatchupResult::Loss => record.increment_losses(1),
+                            FootballMatchupResult::Tie => record.increment_ties(1)
+                        }
+                    }
+                    if let Some(total) = turnovers.get_mut(&id) {
+                        *total = match (total.take(), matchup.turnovers(id)) {
+                            (Some(mut total), Some(game)) => {
+                                total.increment_giveaways(*game.giveaways());
+                                total.increment_takeaways(*game.takeaways());
+                                Some(total)
+                            },
+                            _ => None
+                        };
+                    }
+                }
+            }
+
+            // The current week is the first which has not completed
+            let complete = !week.matchups().is_empty() && completed == week.matchups().len();
+            if current_week.is_none() && !complete {
+                current_week = Some(
+                    SeasonDashboardWeek {
+                        week: week_index,
+                        matchups: week.matchups().len(),
+                        completed,
+                        attendance
+                    }
+                );
+            }
+        }
+        let mut standings: Vec<(usize, LeagueTeamRecord)> = records.into_iter().collect();
+        sort_standings(&mut standings);
+        let tiebreakers = Tiebreakers::new(season);
+        tiebreakers.break_ties(&mut standings);
+
+        // Derive each requested section from the shared standings
+        let conference_standings = if options.conference_standings {
+            let mut conferences = Vec::new();
+            for conf_index in 0..season.conferences().len() {
+                conferences.push(season.filter_conference_standings(&standings, conf_index)?);
+            }
+            Some(conferences)
+        } else {
+            None
+        };
+        let division_standings = if options.division_standings {
+            let mut divisions = Vec::new();
+            for (conf_index, conference) in season.conferences().iter().enumerate() {
+                for div_index in 0..conference.divisions().len() {
+                    divisions.push(
+                        SeasonDashboardDivision {
+                            conference: conf_index,
+                            division: div_index,
+                            standings: season.filter_division_standings(&standings, conf_index, div_index)?
+                        }
+                    );
+                }
+            }
+            Some(divisions)
+        } else {
+            None
+        };
+        let leaders = if options.leaders {
+            Some(best_record_teams(&standings, &tiebreakers))
+        } else {
+            None
+        };
+        let week = if options.week {
+            current_week
+        } else {
+            None
+        };
+        let turnovers = if options.turnovers {
+            Some(turnovers)
+        } else {
+            None
+        };
+        let playoff_picture = match options.num_playoff_teams {
+            Some(n) => Some(season.playoff_picture_from_standings(&standings, &remaining_games, n)?),
+            None => None
+        };
+        let annotations = if options.annotations {
+            Some(season.annotations().clone())
+        } else {
+            None
+        };
+        Ok(
+            SeasonDashboard {
+                standings,
+                conference_standings,
+                division_standings,
+                leaders,
+                week,
+                turnovers,
+                playoff_picture,
+                annotations
+            }
+        )
+    }
+}
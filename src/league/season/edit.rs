@@ -0,0 +1,63 @@
+#![doc = include_str!("../../../docs/league/season/edit.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+/// # `ResultEditAction` enum
+///
+/// How a `LeagueSeasonResultEdit` changed a matchup's result
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum ResultEditAction {
+    /// A final score was entered for the matchup
+    Entered,
+    /// The matchup's final score was cleared, leaving it unplayed
+    Reopened
+}
+
+/// # `LeagueSeasonResultEdit` struct
+///
+/// A `LeagueSeasonResultEdit` is an entry in a season's result edit ledger,
+/// recording a regular season matchup whose result was entered or reopened by
+/// hand rather than simulated
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct LeagueSeasonResultEdit {
+    /// Index of the week containing the matchup
+    pub week: usize,
+    /// Index of the matchup within its week
+    pub matchup: usize,
+    /// Whether the result was entered or reopened
+    pub action: ResultEditAction,
+    /// Home score entered, or the home score before the matchup was reopened
+    pub home_score: u32,
+    /// Away score entered, or the away score before the matchup was reopened
+    pub away_score: u32
+}
+
+impl LeagueSeasonResultEdit {
+    /// Constructor for the `LeagueSeasonResultEdit` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::edit::{LeagueSeasonResultEdit, ResultEditAction};
+    ///
+    /// let my_edit = LeagueSeasonResultEdit::new(3, 1, ResultEditAction::Entered, 24, 17);
+    /// assert_eq!(my_edit.home_score, 24);
+    /// ```
+    pub fn new(week: usize, matchup: usize, action: ResultEditAction, home_score: u32, away_score: u32) -> LeagueSeasonResultEdit {
+        LeagueSeasonResultEdit {
+            week,
+            matchup,
+            action,
+            home_score,
+            away_score
+        }
+    }
+}
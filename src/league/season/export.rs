@@ -0,0 +1,459 @@
+#![doc = include_str!("../../../docs/league/season/export.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+use crate::league::error::LeagueError;
+use crate::league::season::LeagueSeason;
+use crate::league::season::report::{bracket_title, round_name, team_name};
+use crate::league::season::week::LeagueSeasonWeek;
+
+/// The columns of an exported CSV schedule
+pub const CSV_SCHEDULE_COLUMNS: [&str; 6] = ["week", "round", "home_team", "away_team", "home_score", "away_score"];
+
+/// The maximum length in octets of an iCalendar content line, beyond which
+/// the line is folded
+const ICAL_LINE_OCTETS: usize = 75;
+
+/// # `CalendarDate` struct
+///
+/// A month and day of the season year, on which the first week of a season
+/// is played
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct CalendarDate {
+    /// The month, from 1 for January to 12 for December
+    pub month: u32,
+    /// The day of the month, from 1
+    pub day: u32
+}
+
+impl CalendarDate {
+    /// Constructor for the `CalendarDate` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::export::CalendarDate;
+    ///
+    /// let my_date = CalendarDate::new(9, 10);
+    /// assert!(my_date.month == 9);
+    /// assert!(my_date.day == 10);
+    /// ```
+    pub fn new(month: u32, day: u32) -> CalendarDate {
+        CalendarDate { month, day }
+    }
+}
+
+/// Determine whether a year is a leap year in the Gregorian calendar
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Get the number of days in a month of a year
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31
+    }
+}
+
+/// Count the days from 1970-01-01 to a date
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Get the date some number of days after 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Format a date some number of days after 1970-01-01 as an iCalendar date
+fn ical_date(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}{:02}{:02}", year, month, day)
+}
+
+/// One matchup of an exported schedule
+struct ScheduleRow {
+    week: usize,
+    round: String,
+    home_team: String,
+    away_team: String,
+    score: Option<(u32, u32)>
+}
+
+/// Build a row of an exported schedule for each matchup in some weeks
+fn week_rows(season: &LeagueSeason, week: usize, round: &str, matchups: &LeagueSeasonWeek) -> Vec<ScheduleRow> {
+    matchups.matchups().iter()
+        .map(|matchup| {
+            let context = matchup.context();
+            ScheduleRow {
+                week: week + 1,
+                round: round.to_string(),
+                home_team: team_name(season, *matchup.home_team()),
+                away_team: team_name(season, *matchup.away_team()),
+                score: if context.game_over() {
+                    Some((context.home_score(), context.away_score()))
+                } else {
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+/// Collect the rows of a season's schedule in week order: the regular
+/// season weeks, then the rounds of each playoff bracket
+fn schedule_rows(season: &LeagueSeason) -> Vec<ScheduleRow> {
+    let mut rows: Vec<ScheduleRow> = Vec::new();
+    for (week, matchups) in season.weeks().iter().enumerate() {
+        rows.extend(week_rows(season, week, "", matchups));
+    }
+
+    // Playoff rounds, which are stably sorted so that the conference
+    // brackets' rounds in the same week stay in conference order
+    let playoffs = season.playoffs();
+    let mut playoff_rows: Vec<ScheduleRow> = Vec::new();
+    for (conf_index, bracket) in playoffs.conference_brackets().iter() {
+        let title = bracket_title(season, *conf_index);
+        for (round, matchups) in bracket.iter().enumerate() {
+            let name = round_name(title.as_deref(), bracket, round);
+            playoff_rows.extend(week_rows(season, season.playoff_round_week(false, round), &name, matchups));
+        }
+    }
+    let bracket = playoffs.winners_bracket();
+    let title = if playoffs.is_conference_playoff() { Some("Championship") } else { None };
+    for (round, matchups) in bracket.iter().enumerate() {
+        let name = round_name(title, bracket, round);
+        playoff_rows.extend(week_rows(season, season.playoff_round_week(true, round), &name, matchups));
+    }
+    playoff_rows.sort_by_key(|row| row.week);
+    rows.extend(playoff_rows);
+    rows
+}
+
+/// Quote a CSV field if it contains a comma, quote, or line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Export a season's schedule as CSV, with a header row and one row per
+/// matchup
+pub(crate) fn csv_schedule(season: &LeagueSeason) -> String {
+    let mut out = CSV_SCHEDULE_COLUMNS.join(",");
+    out.push('\n');
+    for row in schedule_rows(season) {
+        let (home_score, away_score) = match row.score {
+            Some((home, away)) => (home.to_string(), away.to_string()),
+            None => (String::new(), String::new())
+        };
+        let fields = [
+            row.week.to_string(),
+            csv_field(&row.round),
+            csv_field(&row.home_team),
+            csv_field(&row.away_team),
+            home_score,
+            away_score
+        ];
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Escape the characters with special meaning in an iCalendar text value
+fn ical_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            },
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {},
+            _ => escaped.push(c)
+        }
+    }
+    escaped
+}
+
+/// Write an iCalendar content line, folding it onto continuation lines
+/// so that no line exceeds `ICAL_LINE_OCTETS` octets
+fn ical_line(out: &mut String, line: &str) {
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > ICAL_LINE_OCTETS {
+            out.push_str("\r\n ");
+            octets = 1;
+        }
+        out.push(c);
+        octets += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+/// Export a season's schedule as an iCalendar, with an all-day event per
+/// matchup
+pub(crate) fn ical_schedule(season: &LeagueSeason, start_date: CalendarDate, days_between_weeks: u32) -> Result<String, LeagueError> {
+    let year = *season.year() as i64;
+    if !(1..=12).contains(&start_date.month) || start_date.day == 0 || start_date.day > days_in_month(year, start_date.month) {
+        return Err(
+            format!(
+                "Invalid start date for {}: month {} day {}",
+                year, start_date.month, start_date.day
+            ).into()
+        );
+    }
+    if days_between_weeks == 0 {
+        return Err(String::from("Days between weeks must be positive").into());
+    }
+
+    // Write the calendar, stamping each event with the start date so the
+    // output depends only on the season
+    let start = days_from_civil(year, start_date.month, start_date.day);
+    let mut out = String::new();
+    ical_line(&mut out, "BEGIN:VCALENDAR");
+    ical_line(&mut out, "VERSION:2.0");
+    ical_line(&mut out, "PRODID:-//fbsim-core//Season Schedule//EN");
+    ical_line(&mut out, "CALSCALE:GREGORIAN");
+    ical_line(&mut out, &format!("X-WR-CALNAME:{} Season", year));
+    let mut previous_week = 0;
+    let mut index = 0;
+    for row in schedule_rows(season) {
+        if row.week != previous_week {
+            previous_week = row.week;
+            index = 0;
+        }
+        let day = start + (row.week as i64 - 1) * days_between_weeks as i64;
+        let summary = match row.score {
+            Some((home, away)) => format!("{} {} at {} {}", row.away_team, away, row.home_team, home),
+            None => format!("{} at {}", row.away_team, row.home_team)
+        };
+        let description = if row.round.is_empty() {
+            format!("Week {}", row.week)
+        } else {
+            format!("Week {}, {}", row.week, row.round)
+        };
+        ical_line(&mut out, "BEGIN:VEVENT");
+        ical_line(&mut out, &format!("UID:{}-week-{}-game-{}@fbsim-core", year, row.week, index));
+        ical_line(&mut out, &format!("DTSTAMP:{}T000000Z", ical_date(start)));
+        ical_line(&mut out, &format!("DTSTART;VALUE=DATE:{}", ical_date(day)));
+        ical_line(&mut out, &format!("DTEND;VALUE=DATE:{}", ical_date(day + 1)));
+        ical_line(&mut out, &format!("SUMMARY:{}", ical_text(&summary)));
+        ical_line(&mut out, &format!("DESCRIPTION:{}", ical_text(&description)));
+        ical_line(&mut out, "END:VEVENT");
+        index += 1;
+    }
+    ical_line(&mut out, "END:VCALENDAR");
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::league::season::{LeagueSeasonPlayoffOptions, LeagueSeasonScheduleOptions};
+    use crate::team::FootballTeam;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    /// A season of 4 teams, one with a comma in its name, simulated through
+    /// the first playoff round, with the final scheduled
+    fn season(rng: &mut SmallRng) -> LeagueSeason {
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..4 {
+            let mut team = FootballTeam::new();
+            *team.name_mut() = format!("Team {}", id);
+            season.add_team(id, team).unwrap();
+        }
+        *season.team_mut(3).unwrap().name_mut() = String::from("Springfield, Atoms");
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), rng).unwrap();
+        season.sim_regular_season(rng).unwrap();
+        season.generate_playoffs(LeagueSeasonPlayoffOptions::new().num_playoff_teams(4), rng).unwrap();
+        season.sim_playoff_round(0, rng).unwrap();
+        season.generate_next_playoff_round(rng).unwrap();
+        season
+    }
+
+    /// Split a CSV line into fields, unquoting quoted fields
+    fn csv_fields(line: &str) -> Vec<String> {
+        let mut fields = vec![String::new()];
+        let mut quoted = false;
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if quoted && chars.peek() == Some(&'"') => {
+                    chars.next();
+                    fields.last_mut().unwrap().push('"');
+                },
+                '"' => quoted = !quoted,
+                ',' if !quoted => fields.push(String::new()),
+                _ => fields.last_mut().unwrap().push(c)
+            }
+        }
+        fields
+    }
+
+    /// Check that a string is a well-formed iCalendar, returning the
+    /// properties of each event
+    fn parse_ical(ical: &str) -> Result<Vec<Vec<(String, String)>>, LeagueError> {
+        if !ical.ends_with("\r\n") {
+            return Err(String::from("Calendar does not end with CRLF").into());
+        }
+
+        // Unfold the content lines
+        let mut lines: Vec<String> = Vec::new();
+        for line in ical[..ical.len() - 2].split("\r\n") {
+            if line.len() > ICAL_LINE_OCTETS {
+                return Err(format!("Line longer than {} octets: {}", ICAL_LINE_OCTETS, line).into());
+            }
+            if line.contains('\n') || line.contains('\r') {
+                return Err(format!("Bare line break in line: {}", line).into());
+            }
+            match line.strip_prefix(' ') {
+                Some(continuation) => match lines.last_mut() {
+                    Some(last) => last.push_str(continuation),
+                    None => return Err(String::from("Calendar starts with a continuation line").into())
+                },
+                None => lines.push(line.to_string())
+            }
+        }
+
+        // Check the nesting of the components and collect the events
+        let mut stack: Vec<String> = Vec::new();
+        let mut events: Vec<Vec<(String, String)>> = Vec::new();
+        for line in lines.iter() {
+            let (name, value) = line.split_once(':').ok_or(format!("Line has no value: {}", line))?;
+            let name = name.split(';').next().unwrap();
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+                return Err(format!("Invalid property name: {}", name).into());
+            }
+            match name {
+                "BEGIN" => {
+                    if value == "VEVENT" {
+                        events.push(Vec::new());
+                    }
+                    stack.push(value.to_string());
+                },
+                "END" => {
+                    if stack.pop().as_deref() != Some(value) {
+                        return Err(format!("Unmatched END:{}", value).into());
+                    }
+                },
+                _ => match stack.last().map(|s| s.as_str()) {
+                    Some("VEVENT") => events.last_mut().unwrap().push((name.to_string(), value.to_string())),
+                    Some("VCALENDAR") => {},
+                    _ => return Err(format!("Property outside a component: {}", name).into())
+                }
+            }
+        }
+        if !stack.is_empty() || lines.first().map(|l| l.as_str()) != Some("BEGIN:VCALENDAR") {
+            return Err(String::from("Calendar is not a single VCALENDAR").into());
+        }
+        if !lines.iter().any(|l| l == "VERSION:2.0") || !lines.iter().any(|l| l.starts_with("PRODID:")) {
+            return Err(String::from("Calendar is missing VERSION or PRODID").into());
+        }
+        for event in events.iter() {
+            for required in ["UID", "DTSTAMP", "DTSTART"] {
+                if !event.iter().any(|(name, _)| name == required) {
+                    return Err(format!("Event is missing {}", required).into());
+                }
+            }
+        }
+        Ok(events)
+    }
+
+    #[test]
+    fn test_csv_schedule_columns() {
+        let mut rng = SmallRng::seed_from_u64(1522);
+        let season = season(&mut rng);
+        let csv = season.to_csv_schedule();
+        let rows: Vec<Vec<String>> = csv.lines().map(csv_fields).collect();
+
+        // A header, a row per regular season matchup, and a row per playoff
+        // matchup, each with every column
+        assert_eq!(rows[0], CSV_SCHEDULE_COLUMNS.to_vec());
+        assert!(rows.iter().all(|row| row.len() == CSV_SCHEDULE_COLUMNS.len()));
+        let regular: usize = season.weeks().iter().map(|w| w.matchups().len()).sum();
+        assert_eq!(rows.len(), 1 + regular + 3);
+        assert!(rows.iter().any(|row| row[2] == "Springfield, Atoms" || row[3] == "Springfield, Atoms"));
+
+        // Weeks are in order, and only completed matchups have scores
+        let weeks: Vec<usize> = rows[1..].iter().map(|row| row[0].parse().unwrap()).collect();
+        assert!(weeks.windows(2).all(|w| w[0] <= w[1]));
+        let semifinals = &rows[1 + regular..rows.len() - 1];
+        assert!(semifinals.iter().all(|row| row[1] == "Round 1" && !row[4].is_empty() && !row[5].is_empty()));
+        let last = rows.last().unwrap();
+        assert_eq!(last[0], (season.weeks().len() + 2).to_string());
+        assert_eq!(last[1], "Final");
+        assert!(last[4].is_empty() && last[5].is_empty());
+    }
+
+    #[test]
+    fn test_ical_schedule_parses() {
+        let mut rng = SmallRng::seed_from_u64(1522);
+        let season = season(&mut rng);
+        let ical = season.to_ical(CalendarDate::new(12, 24), 7).unwrap();
+        let events = parse_ical(&ical).unwrap();
+        let regular: usize = season.weeks().iter().map(|w| w.matchups().len()).sum();
+        assert_eq!(events.len(), regular + 3);
+
+        // Weekly events roll over into the next year, and UIDs are unique
+        let value = |event: &Vec<(String, String)>, name: &str| {
+            event.iter().find(|(n, _)| n == name).unwrap().1.clone()
+        };
+        assert_eq!(value(&events[0], "DTSTART"), "20261224");
+        assert_eq!(value(&events[0], "DTEND"), "20261225");
+        assert_eq!(value(&events[regular], "DTSTART"), ical_date(days_from_civil(2026, 12, 24) + 7 * season.weeks().len() as i64));
+        assert!(value(events.last().unwrap(), "DTSTART").starts_with("2027"));
+        let uids: std::collections::BTreeSet<String> = events.iter().map(|e| value(e, "UID")).collect();
+        assert_eq!(uids.len(), events.len());
+        assert!(events.iter().any(|e| value(e, "SUMMARY").contains("Springfield\\, Atoms")));
+
+        // Invalid dates and cadences are rejected
+        assert!(season.to_ical(CalendarDate::new(2, 29), 7).is_err());
+        assert!(season.to_ical(CalendarDate::new(13, 1), 7).is_err());
+        assert!(season.to_ical(CalendarDate::new(9, 10), 0).is_err());
+    }
+
+    #[test]
+    fn test_ical_folds_long_lines() {
+        let mut out = String::new();
+        let line = format!("SUMMARY:{}", "\u{e9}".repeat(60));
+        ical_line(&mut out, &line);
+        assert!(out.split("\r\n").all(|l| l.len() <= ICAL_LINE_OCTETS));
+        assert_eq!(out.replace("\r\n ", ""), format!("{}\r\n", line));
+    }
+
+    #[test]
+    fn test_civil_dates_round_trip() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(civil_from_days(days_from_civil(2024, 2, 29)), (2024, 2, 29));
+        assert_eq!(civil_from_days(days_from_civil(2026, 12, 31) + 1), (2027, 1, 1));
+    }
+}
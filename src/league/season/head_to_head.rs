@@ -0,0 +1,115 @@
+#![doc = include_str!("../../../docs/league/season/head_to_head.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+use crate::league::matchup::LeagueTeamRecord;
+
+/// # `HeadToHeadGame` struct
+///
+/// A completed regular season meeting between two teams
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct HeadToHeadGame {
+    /// Index of the regular season week
+    pub week: usize,
+    /// The home team's ID
+    pub home_team: usize,
+    /// The away team's ID
+    pub away_team: usize,
+    /// The home team's final score
+    pub home_score: u32,
+    /// The away team's final score
+    pub away_score: u32
+}
+
+/// # `HeadToHeadSeries` struct
+///
+/// A `HeadToHeadSeries` summarizes the completed regular season meetings
+/// between two teams from the first team's point of view
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct HeadToHeadSeries {
+    /// The first team's ID
+    pub team: usize,
+    /// The opponent's ID
+    pub opponent: usize,
+    /// The first team's record in the series
+    pub record: LeagueTeamRecord,
+    /// The points the first team scored in the series
+    pub points_for: u32,
+    /// The points the first team allowed in the series
+    pub points_against: u32,
+    /// The completed meetings, in the order they were played
+    pub games: Vec<HeadToHeadGame>
+}
+
+impl HeadToHeadSeries {
+    /// Summarize the completed meetings between two teams from the first
+    /// team's point of view
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::head_to_head::{HeadToHeadGame, HeadToHeadSeries};
+    ///
+    /// let games = vec![
+    ///     HeadToHeadGame { week: 0, home_team: 0, away_team: 1, home_score: 24, away_score: 17 },
+    ///     HeadToHeadGame { week: 5, home_team: 1, away_team: 0, home_score: 21, away_score: 14 }
+    /// ];
+    /// let series = HeadToHeadSeries::new(0, 1, games);
+    /// assert_eq!(series.record.to_string(), "1-1-0");
+    /// assert_eq!((series.points_for, series.points_against), (38, 38));
+    /// assert_eq!(series.point_differential(), 0);
+    /// ```
+    pub fn new(team: usize, opponent: usize, games: Vec<HeadToHeadGame>) -> HeadToHeadSeries {
+        let mut record = LeagueTeamRecord::new();
+        let mut points_for: u32 = 0;
+        let mut points_against: u32 = 0;
+        for game in games.iter() {
+            let (scored, allowed) = if game.home_team == team {
+                (game.home_score, game.away_score)
+            } else {
+                (game.away_score, game.home_score)
+            };
+            if scored > allowed {
+                record.increment_wins(1);
+            } else if scored < allowed {
+                record.increment_losses(1);
+            } else {
+                record.increment_ties(1);
+            }
+            points_for += scored;
+            points_against += allowed;
+        }
+        HeadToHeadSeries {
+            team,
+            opponent,
+            record,
+            points_for,
+            points_against,
+            games
+        }
+    }
+
+    /// The points the first team scored minus the points it allowed
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::head_to_head::{HeadToHeadGame, HeadToHeadSeries};
+    ///
+    /// let games = vec![
+    ///     HeadToHeadGame { week: 2, home_team: 3, away_team: 4, home_score: 10, away_score: 27 }
+    /// ];
+    /// let series = HeadToHeadSeries::new(3, 4, games);
+    /// assert_eq!(series.point_differential(), -17);
+    /// ```
+    pub fn point_differential(&self) -> i64 {
+        self.points_for as i64 - self.points_against as i64
+    }
+}
@@ -0,0 +1,193 @@
+#![doc = include_str!("../../../docs/league/season/history.md")]
+use std::collections::BTreeMap;
+
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+use crate::game::matchup::FootballMatchupResult;
+use crate::league::matchup::LeagueTeamRecord;
+use crate::league::season::{sort_standings, LeagueSeason};
+use crate::league::season::tiebreaker::Tiebreakers;
+
+/// # `StandingsHistoryOptions` struct
+///
+/// Options for computing a `StandingsHistory`, selecting which outputs to
+/// include alongside the ranks.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StandingsHistoryOptions {
+    /// Whether to include each team's games back from first after each week
+    pub games_back: bool,
+    /// Whether to include the full standings table after each week
+    pub tables: bool
+}
+
+impl StandingsHistoryOptions {
+    /// Constructor for the `StandingsHistoryOptions` struct, which includes
+    /// only the ranks
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::history::StandingsHistoryOptions;
+    ///
+    /// let my_options = StandingsHistoryOptions::new();
+    /// assert!(!my_options.games_back);
+    /// assert!(!my_options.tables);
+    /// ```
+    pub fn new() -> StandingsHistoryOptions {
+        StandingsHistoryOptions::default()
+    }
+
+    /// Set whether to include each team's games back from first
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::history::StandingsHistoryOptions;
+    ///
+    /// let my_options = StandingsHistoryOptions::new().games_back(true);
+    /// assert!(my_options.games_back);
+    /// ```
+    pub fn games_back(mut self, games_back: bool) -> Self {
+        self.games_back = games_back;
+        self
+    }
+
+    /// Set whether to include the full standings table after each week
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::history::StandingsHistoryOptions;
+    ///
+    /// let my_options = StandingsHistoryOptions::new().tables(true);
+    /// assert!(my_options.tables);
+    /// ```
+    pub fn tables(mut self, tables: bool) -> Self {
+        self.tables = tables;
+        self
+    }
+}
+
+/// # `StandingsHistory` struct
+///
+/// A `StandingsHistory` records where every team stood in the overall
+/// standings after each completed week of a season. Entry `i` of a team's
+/// history is its standing after week `i`.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct StandingsHistory {
+    /// Number of weeks covered, the leading run of completed weeks
+    pub weeks: usize,
+    /// Each team's 1-based rank after each week, by team ID
+    pub ranks: BTreeMap<usize, Vec<usize>>,
+    /// Each team's games back from the first-ranked team after each week,
+    /// by team ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub games_back: Option<BTreeMap<usize, Vec<f64>>>,
+    /// The standings after each week, each sorted in the same order as
+    /// `LeagueSeason::standings_through_week()`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tables: Option<Vec<Vec<(usize, LeagueTeamRecord)>>>
+}
+
+impl StandingsHistory {
+    /// Compute the standings history of a season in a single forward pass,
+    /// updating each team's record week by week and ranking the teams after
+    /// each week in the same order as `LeagueSeason::standings()`. The
+    /// history stops at the first week which has not completed.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::history::{StandingsHistory, StandingsHistoryOptions};
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule and simulate two weeks
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// my_league_season.sim_week(0, &mut rng);
+    /// my_league_season.sim_week(1, &mut rng);
+    ///
+    /// // Compute the history, including games back and standings tables
+    /// let options = StandingsHistoryOptions::new().games_back(true).tables(true);
+    /// let history = StandingsHistory::from_season(&my_league_season, options);
+    /// assert_eq!(history.weeks, 2);
+    /// assert_eq!(history.ranks[&0].len(), 2);
+    /// assert_eq!(history.games_back.unwrap()[&0].len(), 2);
+    /// assert_eq!(history.tables.unwrap()[1], my_league_season.standings_through_week(1).unwrap());
+    /// ```
+    pub fn from_season(season: &LeagueSeason, options: StandingsHistoryOptions) -> StandingsHistory {
+        let mut records: BTreeMap<usize, LeagueTeamRecord> = season.teams().keys()
+            .map(|id| (*id, LeagueTeamRecord::new()))
+            .collect();
+        let mut ranks: BTreeMap<usize, Vec<usize>> = season.teams().keys()
+            .map(|id| (*id, Vec::new()))
+            .collect();
+        let mut games_back: BTreeMap<usize, Vec<f64>> = BTreeMap::new();
+        if options.games_back {
+            games_back = season.teams().keys()
+                .map(|id| (*id, Vec::new()))
+                .collect();
+        }
+
+        let mut tables: Vec<Vec<(usize, LeagueTeamRecord)>> = Vec::new();
+
+        // Update the records with each completed week, then snapshot
+        let mut weeks = 0;
+        for week in season.weeks().iter() {
+            if !week.complete() {
+                break;
+            }
+            for matchup in week.matchups().iter() {
+                for id in [*matchup.home_team(), *matchup.away_team()] {
+                    if let (Some(record), Some(result)) = (records.get_mut(&id), matchup.result(id)) {
+                        match result {
+                            FootballMatchupResult::Win => record.increment_wins(1),
+                            FootballMatchupResult::Loss => record.increment_losses(1),
+                            FootballMatchupResult::Tie => record.increment_ties(1)
+                        }
+                    }
+                }
+            }
+            weeks += 1;
+
+            let mut standings: Vec<(usize, LeagueTeamRecord)> = records.iter()
+                .map(|(id, record)| (*id, record.clone()))
+                .collect();
+            sort_standings(&mut standings);
+            Tiebreakers::through_week(season, weeks).break_ties(&mut standings);
+            for (position, (id, record)) in standings.iter().enumerate() {
+                if let Some(history) = ranks.get_mut(id) {
+                    history.push(position + 1);
+                }
+                if let (Some(history), Some((_, leader))) = (games_back.get_mut(id), standings.first()) {
+                    history.push(record.games_back(leader));
+                }
+            }
+            if options.tables {
+                tables.push(standings);
+            }
+        }
+        StandingsHistory {
+            weeks,
+            ranks,
+            games_back: if options.games_back { Some(games_back) } else { None },
+            tables: if options.tables { Some(tables) } else { None }
+        }
+    }
+}
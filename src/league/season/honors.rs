@@ -0,0 +1,70 @@
+#![doc = include_str!("../../../docs/league/season/honors.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+
+/// # `SeasonHonors` struct
+///
+/// A `SeasonHonors` summarizes the titles won during a season: each
+/// division and conference's regular season champion, each conference's
+/// playoff champion, the league champion, and the top seed.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SeasonHonors {
+    /// The year of the season
+    pub year: usize,
+    /// The ID of each division's champion, keyed by conference index and
+    /// then by division ID
+    pub division_champions: BTreeMap<usize, BTreeMap<usize, usize>>,
+    /// The ID of each conference's regular season champion, keyed by
+    /// conference index
+    pub conference_champions: BTreeMap<usize, usize>,
+    /// The ID of each conference's playoff bracket champion, keyed by
+    /// conference index
+    pub conference_playoff_champions: BTreeMap<usize, usize>,
+    /// The ID of the league champion, if one was decided
+    pub league_champion: Option<usize>,
+    /// The ID of the team with the best regular season record
+    pub top_seed: Option<usize>
+}
+
+impl SeasonHonors {
+    /// Get the champion of a division, if the division exists
+    ///
+    /// ### Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use fbsim_core::league::season::honors::SeasonHonors;
+    ///
+    /// let mut my_honors = SeasonHonors::default();
+    /// my_honors.division_champions.insert(1, BTreeMap::from([(0, 5)]));
+    /// assert_eq!(my_honors.division_champion(1, 0), Some(5));
+    /// assert_eq!(my_honors.division_champion(0, 0), None);
+    /// ```
+    pub fn division_champion(&self, conference: usize, division: usize) -> Option<usize> {
+        self.division_champions.get(&conference)
+            .and_then(|divisions| divisions.get(&division))
+            .copied()
+    }
+
+    /// Get the regular season champion of a conference, if the conference
+    /// exists
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::honors::SeasonHonors;
+    ///
+    /// let mut my_honors = SeasonHonors::default();
+    /// my_honors.conference_champions.insert(1, 5);
+    /// assert_eq!(my_honors.conference_champion(1), Some(5));
+    /// ```
+    pub fn conference_champion(&self, conference: usize) -> Option<usize> {
+        self.conference_champions.get(&conference).copied()
+    }
+}
@@ -0,0 +1,111 @@
+#![doc = include_str!("../../../docs/league/season/import.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+use std::collections::HashSet;
+
+use crate::league::error::LeagueError;
+use crate::league::season::conference::{LeagueConferenceRaw, LeagueDivisionRaw};
+use crate::team::FootballTeam;
+
+/// # `TeamDefinition` struct
+///
+/// A `TeamDefinition` is a single team in a bulk team import or export: its
+/// ID, its `FootballTeam` properties, and optionally the names of the
+/// conference and division to which it belongs.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct TeamDefinition {
+    /// The team's ID
+    pub id: usize,
+    /// The name of the team's conference
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conference: Option<String>,
+    /// The name of the team's division within its conference
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub division: Option<String>,
+    /// The team's name, short name, coach, and skills
+    #[serde(flatten)]
+    pub team: FootballTeam
+}
+
+/// # `TeamDefinitions` struct
+///
+/// A `TeamDefinitions` is a batch of team definitions, as read by
+/// `LeagueSeason::import_teams` and written by `LeagueSeason::export_teams`.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct TeamDefinitions {
+    /// The teams in the batch
+    pub teams: Vec<TeamDefinition>
+}
+
+impl TeamDefinitions {
+    /// Validate the batch on its own: team IDs and short names must be
+    /// unique, and each team must name both a conference and a division or
+    /// neither, the same as every other team in the batch
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::import::{TeamDefinition, TeamDefinitions};
+    ///
+    /// let mut definitions = TeamDefinitions::default();
+    /// for (id, short_name) in ["AAA", "BBB"].iter().enumerate() {
+    ///     definitions.teams.push(TeamDefinition {
+    ///         id,
+    ///         team: FootballTeam::from_overalls("Team", short_name, 50, 50).unwrap(),
+    ///         ..Default::default()
+    ///     });
+    /// }
+    /// assert!(definitions.validate().is_ok());
+    ///
+    /// // Short names must be unique
+    /// definitions.teams[1].team = FootballTeam::from_overalls("Team", "AAA", 50, 50).unwrap();
+    /// assert!(definitions.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), LeagueError> {
+        let mut ids = HashSet::new();
+        let mut short_names = HashSet::new();
+        for definition in &self.teams {
+            // Ensure the team ID and short name are unique
+            if !ids.insert(definition.id) {
+                return Err(LeagueError::DuplicateTeam { id: definition.id });
+            }
+            if !short_names.insert(definition.team.short_name()) {
+                return Err(format!(
+                    "Duplicate team short name in import: {}",
+                    definition.team.short_name()
+                ).into());
+            }
+
+            // Ensure the team is fully assigned to a division or not at all
+            match (&definition.conference, &definition.division) {
+                (Some(conference), Some(division)) => {
+                    LeagueConferenceRaw { name: conference.clone(), divisions: Vec::new() }.validate()?;
+                    LeagueDivisionRaw { name: division.clone(), teams: Vec::new() }.validate()?;
+                },
+                (None, None) => (),
+                _ => return Err(format!(
+                    "Team {} must name both a conference and a division, or neither",
+                    definition.id
+                ).into())
+            }
+        }
+
+        // Ensure either every team or no team is assigned to a division
+        let assigned = self.teams.iter().filter(|d| d.conference.is_some()).count();
+        if assigned != 0 && assigned != self.teams.len() {
+            return Err(String::from(
+                "Either every imported team or no imported team must name a conference"
+            ).into());
+        }
+        Ok(())
+    }
+}
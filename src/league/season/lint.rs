@@ -0,0 +1,76 @@
+#![doc = include_str!("../../../docs/league/season/lint.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+/// # `SeasonLintLevel` enum
+///
+/// How serious a `SeasonLint` is
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum SeasonLintLevel {
+    /// The season is valid, but part of it is likely a mistake
+    Warning,
+    /// The season would fail validation if it were saved and loaded
+    Error,
+}
+
+/// # `SeasonLint` enum
+///
+/// A problem found in a season by `LeagueSeason::lint`
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum SeasonLint {
+    /// A conference has no teams, so it is ignored by standings, the
+    /// playoff picture, and playoff seeding
+    EmptyConference { conference: usize, name: String },
+    /// A matchup is over but still has a game in progress, which
+    /// `LeagueSeasonWeek::reconcile` would archive
+    UnreconciledMatchup { week: usize, matchup: usize },
+    /// A matchup's game context, game, and archived stats conflict, such as
+    /// an unplayed matchup with archived stats
+    InconsistentMatchup { week: usize, matchup: usize, reason: String },
+}
+
+impl SeasonLint {
+    /// Get the lint's level
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::lint::{SeasonLint, SeasonLintLevel};
+    ///
+    /// let my_lint = SeasonLint::EmptyConference { conference: 1, name: String::from("NFC") };
+    /// assert_eq!(my_lint.level(), SeasonLintLevel::Warning);
+    /// ```
+    pub fn level(&self) -> SeasonLintLevel {
+        match self {
+            SeasonLint::EmptyConference { .. } => SeasonLintLevel::Warning,
+            SeasonLint::UnreconciledMatchup { .. } => SeasonLintLevel::Warning,
+            SeasonLint::InconsistentMatchup { .. } => SeasonLintLevel::Error,
+        }
+    }
+}
+
+impl fmt::Display for SeasonLint {
+    /// Display a SeasonLint as a message
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SeasonLint::EmptyConference { conference, name } => write!(
+                f, "Conference {} '{}' has no teams and is ignored", conference, name
+            ),
+            SeasonLint::UnreconciledMatchup { week, matchup } => write!(
+                f, "Week {} matchup {} is over but still has a game in progress", week, matchup
+            ),
+            SeasonLint::InconsistentMatchup { week, matchup, reason } => write!(
+                f, "Week {} matchup {} is inconsistent: {}", week, matchup, reason
+            ),
+        }
+    }
+}
@@ -4,13 +4,170 @@ use rocket_okapi::okapi::schemars;
 #[cfg(feature = "rocket_okapi")]
 use rocket_okapi::okapi::schemars::JsonSchema;
 use rand::Rng;
-use serde::{Serialize, Deserialize};
+use serde::{Serialize, Deserialize, Deserializer};
+use std::borrow::Cow;
 
+use crate::league::error::LeagueError;
 use crate::game::context::{GameContext, GameContextBuilder};
+use crate::game::context::overtime::OvertimeRules;
+use crate::game::context::weather::WeatherDistribution;
 use crate::game::play::Game;
-use crate::game::stat::OffensiveStats;
+use crate::game::stat::{GameStats, OffensiveStats, TeamGameStats};
 use crate::game::matchup::FootballMatchupResult;
-use crate::league::matchup::LeagueTeamRecord;
+use crate::league::matchup::{LeagueTeamRecord, LeagueTeamTurnovers};
+use crate::team::FootballTeam;
+
+/// # `LeagueSeasonMatchupRaw` struct
+///
+/// A `LeagueSeasonMatchupRaw` is a `LeagueSeasonMatchup` before its game
+/// context, in-progress game, and archived stats have been checked against
+/// each other
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct LeagueSeasonMatchupRaw {
+    pub home_team: usize,
+    pub away_team: usize,
+    pub context: GameContext,
+    #[serde(default)]
+    pub game: Option<Game>,
+    #[serde(default)]
+    pub home_stats: Option<OffensiveStats>,
+    #[serde(default)]
+    pub away_stats: Option<OffensiveStats>,
+    #[serde(default)]
+    pub box_score: Option<GameStats>,
+    #[serde(default)]
+    pub attendance: Option<u32>,
+    #[serde(default)]
+    pub rest_advantage: Option<MatchupRestAdvantage>
+}
+
+impl LeagueSeasonMatchupRaw {
+    /// Repair a matchup saved with its completed game still in progress by
+    /// archiving the game's stats and clearing the game. The game context is
+    /// the source of truth for the score and completion of a matchup, so the
+    /// game is only cleared if the context is over. Returns whether the
+    /// matchup was repaired.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::play::Game;
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchupRaw;
+    ///
+    /// let mut my_raw = LeagueSeasonMatchupRaw {
+    ///     home_team: 0,
+    ///     away_team: 1,
+    ///     context: GameContextBuilder::new()
+    ///         .quarter(4)
+    ///         .half_seconds(0)
+    ///         .home_score(7)
+    ///         .game_over(true)
+    ///         .build()
+    ///         .unwrap(),
+    ///     game: Some(Game::new()),
+    ///     home_stats: None,
+    ///     away_stats: None,
+    ///     box_score: None,
+    ///     attendance: None,
+    ///     rest_advantage: None
+    /// };
+    /// assert!(my_raw.reconcile());
+    /// assert!(my_raw.game.is_none());
+    /// assert!(my_raw.home_stats.is_some());
+    /// assert!(my_raw.box_score.is_some());
+    /// assert!(!my_raw.reconcile());
+    /// ```
+    pub fn reconcile(&mut self) -> bool {
+        if !self.context.game_over() {
+            return false;
+        }
+        let game = match self.game.take() {
+            Some(game) => game,
+            None => return false
+        };
+        if self.home_stats.is_none() {
+            self.home_stats = Some(game.home_stats());
+        }
+        if self.away_stats.is_none() {
+            self.away_stats = Some(game.away_stats());
+        }
+        if self.box_score.is_none() {
+            self.box_score = Some(game.box_score(&self.context));
+        }
+        true
+    }
+
+    /// Ensure the matchup's game context, in-progress game, and archived
+    /// stats agree on whether the matchup has been played
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::stat::OffensiveStats;
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchupRaw;
+    ///
+    /// // An unplayed matchup cannot have archived stats
+    /// let my_raw = LeagueSeasonMatchupRaw {
+    ///     home_team: 0,
+    ///     away_team: 1,
+    ///     context: GameContextBuilder::new().build().unwrap(),
+    ///     game: None,
+    ///     home_stats: Some(OffensiveStats::new()),
+    ///     away_stats: None,
+    ///     box_score: None,
+    ///     attendance: None,
+    ///     rest_advantage: None
+    /// };
+    /// assert!(my_raw.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), LeagueError> {
+        // Ensure a rest advantage goes to one of the matchup's teams
+        if let Some(rest) = &self.rest_advantage {
+            if rest.team != self.home_team && rest.team != self.away_team {
+                return Err(
+                    format!(
+                        "Matchup between teams {} and {} gives a rest advantage to team {}",
+                        self.home_team, self.away_team, rest.team
+                    ).into()
+                );
+            }
+        }
+
+        // Ensure a completed matchup is not still in progress
+        if self.context.game_over() && self.game.is_some() {
+            return Err(
+                format!(
+                    "Matchup between teams {} and {} is over but still has a game in progress",
+                    self.home_team, self.away_team
+                ).into()
+            );
+        }
+
+        // Ensure an incomplete matchup has no completed game, stats, or
+        // attendance
+        if !self.context.game_over() {
+            if self.game.as_ref().is_some_and(|g| g.complete()) {
+                return Err(
+                    format!(
+                        "Matchup between teams {} and {} has a completed game but its context is not over",
+                        self.home_team, self.away_team
+                    ).into()
+                );
+            }
+            if self.home_stats.is_some() || self.away_stats.is_some() || self.box_score.is_some() ||
+                self.attendance.is_some() {
+                return Err(
+                    format!(
+                        "Matchup between teams {} and {} has stats or attendance but its context is not over",
+                        self.home_team, self.away_team
+                    ).into()
+                );
+            }
+        }
+        Ok(())
+    }
+}
 
 /// # `LeagueSeasonMatchup` struct
 ///
@@ -18,19 +175,82 @@ use crate::league::matchup::LeagueTeamRecord;
 #[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
 #[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
 #[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize)]
 pub struct LeagueSeasonMatchup {
     home_team: usize,
     away_team: usize,
     context: GameContext,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     game: Option<Game>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     home_stats: Option<OffensiveStats>,
-    away_stats: Option<OffensiveStats>
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    away_stats: Option<OffensiveStats>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    box_score: Option<GameStats>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    attendance: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rest_advantage: Option<MatchupRestAdvantage>
+}
+
+impl TryFrom<LeagueSeasonMatchupRaw> for LeagueSeasonMatchup {
+    type Error = LeagueError;
+
+    fn try_from(mut item: LeagueSeasonMatchupRaw) -> Result<Self, Self::Error> {
+        // Repair the raw matchup if possible, then validate it
+        item.reconcile();
+        item.validate()?;
+
+        // If valid, then convert
+        Ok(
+            LeagueSeasonMatchup{
+                home_team: item.home_team,
+                away_team: item.away_team,
+                context: item.context,
+                game: item.game,
+                home_stats: item.home_stats,
+                away_stats: item.away_stats,
+                box_score: item.box_score,
+                attendance: item.attendance,
+                rest_advantage: item.rest_advantage
+            }
+        )
+    }
+}
+
+impl From<LeagueSeasonMatchup> for LeagueSeasonMatchupRaw {
+    fn from(item: LeagueSeasonMatchup) -> Self {
+        LeagueSeasonMatchupRaw{
+            home_team: item.home_team,
+            away_team: item.away_team,
+            context: item.context,
+            game: item.game,
+            home_stats: item.home_stats,
+            away_stats: item.away_stats,
+            box_score: item.box_score,
+            attendance: item.attendance,
+            rest_advantage: item.rest_advantage
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for LeagueSeasonMatchup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Only deserialize if the conversion from raw succeeds
+        let raw = LeagueSeasonMatchupRaw::deserialize(deserializer)?;
+        LeagueSeasonMatchup::try_from(raw).map_err(serde::de::Error::custom)
+    }
 }
 
 impl LeagueSeasonMatchup {
     /// Constructor for the LeagueSeasonMatchup struct in which the home and
-    /// away team IDs are given, and the score & completion status is zeroed
+    /// away team IDs are given, and the score & completion status is zeroed.
+    /// Panics if the matchup is invalid; use `LeagueSeasonMatchupBuilder` to
+    /// handle the error instead
     ///
     /// ### Example
     /// ```
@@ -40,26 +260,14 @@ impl LeagueSeasonMatchup {
     /// let my_matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
     /// ```
     pub fn new(home_team: usize, away_team: usize, home_short_name: &str, away_short_name: &str, rng: &mut impl Rng) -> LeagueSeasonMatchup {
-        // Generate a GameContext
-        let home_opening_kickoff: bool = rng.gen::<bool>();
-        let context: GameContext = GameContextBuilder::new()
-            .home_team_short(home_short_name)
-            .away_team_short(away_short_name)
-            .home_possession(!home_opening_kickoff)
-            .home_positive_direction(!home_opening_kickoff)
-            .home_opening_kickoff(home_opening_kickoff)
+        LeagueSeasonMatchupBuilder::new()
+            .home_team(home_team)
+            .away_team(away_team)
+            .home_short_name(home_short_name)
+            .away_short_name(away_short_name)
+            .coin_toss(rng)
             .build()
-            .unwrap();
-        
-        // Instantiate and return a LeagueSeasonMatchup
-        LeagueSeasonMatchup {
-            home_team,
-            away_team,
-            context,
-            game: None,
-            home_stats: None,
-            away_stats: None
-        }
+            .unwrap()
     }
 
     /// Borrow the home team ID
@@ -118,6 +326,54 @@ impl LeagueSeasonMatchup {
         &mut self.context
     }
 
+    /// Get the context to simulate the matchup's game from, following the
+    /// given overtime rules unless the game has already started
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::overtime::OvertimeRules;
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let my_matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// let context = my_matchup.sim_context(OvertimeRules::playoffs());
+    /// assert_eq!(*context.overtime_rules(), OvertimeRules::playoffs());
+    /// ```
+    pub fn sim_context(&self, overtime_rules: OvertimeRules) -> GameContext {
+        if self.context.started() {
+            return self.context.clone();
+        }
+        self.context.with_overtime_rules(overtime_rules)
+    }
+
+    /// Get the context to simulate the matchup's game from, as with
+    /// `sim_context`, drawing the weather of a game which has not started
+    /// from the given distribution. Without a distribution the matchup keeps
+    /// its own weather, which is clear unless set by hand.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::overtime::OvertimeRules;
+    /// use fbsim_core::game::context::weather::{Weather, WeatherDistribution};
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let my_matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// let snow = WeatherDistribution::new().clear(0.0).rain(0.0).wind(0.0).dome(0.0);
+    /// let context = my_matchup.sim_context_with_weather(OvertimeRules::regular_season(), Some(&snow), &mut rng);
+    /// assert_eq!(context.weather(), Weather::Snow);
+    ///
+    /// let context = my_matchup.sim_context_with_weather(OvertimeRules::regular_season(), None, &mut rng);
+    /// assert_eq!(context.weather(), Weather::Clear);
+    /// ```
+    pub fn sim_context_with_weather(&self, overtime_rules: OvertimeRules, weather: Option<&WeatherDistribution>, rng: &mut impl Rng) -> GameContext {
+        let context = self.sim_context(overtime_rules);
+        match weather {
+            Some(distribution) if !context.started() => context.with_weather(distribution.sample(rng)),
+            _ => context
+        }
+    }
+
     /// Borrow the matchup's Game
     ///
     /// ### Example
@@ -160,6 +416,153 @@ impl LeagueSeasonMatchup {
         self.game.take()
     }
 
+    /// Complete the matchup with the final context of its game, archiving
+    /// the game's stats and clearing any game in progress. This is the only
+    /// way the simulation completes a matchup, so its context, game, and
+    /// stats always agree.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::GameSimulator;
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut my_matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// let team = FootballTeam::new();
+    /// let (game, context) = GameSimulator::new()
+    ///     .sim(&team, &team, my_matchup.context().clone(), &mut rng)
+    ///     .unwrap();
+    /// my_matchup.finalize(context, &game).unwrap();
+    /// assert!(my_matchup.context().game_over());
+    /// assert!(my_matchup.home_stats().is_some());
+    /// assert!(my_matchup.home_box_score().is_some());
+    /// ```
+    pub fn finalize(&mut self, context: GameContext, game: &Game) -> Result<(), LeagueError> {
+        if !context.game_over() {
+            return Err(
+                format!(
+                    "Cannot finalize matchup between teams {} and {}: Game is not over",
+                    self.home_team, self.away_team
+                ).into()
+            );
+        }
+        self.context = context;
+        self.home_stats = Some(game.home_stats());
+        self.away_stats = Some(game.away_stats());
+        self.box_score = Some(game.box_score(&self.context));
+        self.game = None;
+        Ok(())
+    }
+
+    /// Ensure the matchup's game context, in-progress game, and archived
+    /// stats agree after the matchup has been edited in place. A matchup
+    /// whose context is over but which still has a game in progress is
+    /// repaired as it would be on deserialize, and the matchup is left
+    /// unchanged if its state conflicts. Returns whether the matchup was
+    /// repaired.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::play::Game;
+    /// use fbsim_core::game::stat::OffensiveStats;
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    ///
+    /// // Manually end a matchup whose game is in progress
+    /// let mut rng = rand::thread_rng();
+    /// let mut my_matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// *my_matchup.game_mut() = Some(Game::new());
+    /// *my_matchup.context_mut() = GameContextBuilder::new()
+    ///     .quarter(4)
+    ///     .half_seconds(0)
+    ///     .home_score(10)
+    ///     .game_over(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(my_matchup.reconcile(), Ok(true));
+    /// assert!(my_matchup.game().is_none());
+    /// assert!(my_matchup.home_stats().is_some());
+    ///
+    /// // An unplayed matchup cannot have archived stats
+    /// let mut my_unplayed = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// *my_unplayed.home_stats_mut() = Some(OffensiveStats::new());
+    /// assert!(my_unplayed.reconcile().is_err());
+    /// ```
+    pub fn reconcile(&mut self) -> Result<bool, LeagueError> {
+        let mut raw = LeagueSeasonMatchupRaw::from(self.clone());
+        let repaired = raw.reconcile();
+        raw.validate()?;
+        if repaired {
+            *self = LeagueSeasonMatchup::try_from(raw)?;
+        }
+        Ok(repaired)
+    }
+
+    /// Enter a final score for the matchup by hand, overwriting any result
+    /// it already has. Any game in progress and any archived stats are
+    /// cleared, since they would not agree with the entered score.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut my_matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// my_matchup.enter_score(24, 17).unwrap();
+    /// assert!(my_matchup.context().game_over());
+    /// assert_eq!(my_matchup.winner(), Some(0));
+    /// ```
+    pub fn enter_score(&mut self, home_score: u32, away_score: u32) -> Result<(), LeagueError> {
+        self.context = GameContextBuilder::new()
+            .home_team_short(self.context.home_team_short())
+            .away_team_short(self.context.away_team_short())
+            .home_opening_kickoff(self.context.home_opening_kickoff())
+            .clock_rules(*self.context.clock_rules())
+            .weather(self.context.weather())
+            .quarter(self.context.clock_rules().quarters)
+            .half_seconds(0)
+            .home_score(home_score)
+            .away_score(away_score)
+            .game_over(true)
+            .build()?;
+        self.game = None;
+        self.home_stats = None;
+        self.away_stats = None;
+        self.box_score = None;
+        Ok(())
+    }
+
+    /// Clear the matchup's result, returning it to the state it was
+    /// scheduled in. The team which receives the opening kickoff and any rest
+    /// advantage are kept.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let scheduled = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// let mut my_matchup = scheduled.clone();
+    /// my_matchup.enter_score(24, 17).unwrap();
+    /// *my_matchup.attendance_mut() = Some(50000);
+    /// my_matchup.reopen().unwrap();
+    /// assert_eq!(my_matchup, scheduled);
+    /// ```
+    pub fn reopen(&mut self) -> Result<(), LeagueError> {
+        self.context = GameContext::with_opening_kickoff(
+            self.context.home_team_short(),
+            self.context.away_team_short(),
+            self.context.home_opening_kickoff()
+        )?.with_clock_rules(*self.context.clock_rules())?.with_weather(self.context.weather());
+        self.game = None;
+        self.home_stats = None;
+        self.away_stats = None;
+        self.box_score = None;
+        self.attendance = None;
+        Ok(())
+    }
+
     /// Borrow the matchup's home stats
     ///
     /// ### Example
@@ -216,6 +619,191 @@ impl LeagueSeasonMatchup {
         &mut self.away_stats
     }
 
+    /// Borrow the matchup's box score, archived from its game once the
+    /// matchup is played. Matchups whose score was entered by hand, or which
+    /// were played before box scores were recorded, have none.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let my_matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// assert!(my_matchup.box_score().is_none());
+    /// ```
+    pub fn box_score(&self) -> Option<&GameStats> {
+        self.box_score.as_ref()
+    }
+
+    /// Mutably borrow the matchup's box score
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut my_matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// let box_score = my_matchup.box_score_mut();
+    /// ```
+    pub fn box_score_mut(&mut self) -> &mut Option<GameStats> {
+        &mut self.box_score
+    }
+
+    /// Borrow the home team's line in the matchup's box score
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let my_matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// assert!(my_matchup.home_box_score().is_none());
+    /// ```
+    pub fn home_box_score(&self) -> Option<&TeamGameStats> {
+        self.box_score.as_ref().map(|stats| stats.home())
+    }
+
+    /// Borrow the away team's line in the matchup's box score
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let my_matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// assert!(my_matchup.away_box_score().is_none());
+    /// ```
+    pub fn away_box_score(&self) -> Option<&TeamGameStats> {
+        self.box_score.as_ref().map(|stats| stats.away())
+    }
+
+    /// Get the recorded attendance for the matchup, if any
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let my_matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// assert!(my_matchup.attendance().is_none());
+    /// ```
+    pub fn attendance(&self) -> Option<u32> {
+        self.attendance
+    }
+
+    /// Mutably borrow the recorded attendance for the matchup
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut my_matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// *my_matchup.attendance_mut() = Some(65000);
+    /// assert_eq!(my_matchup.attendance(), Some(65000));
+    /// ```
+    pub fn attendance_mut(&mut self) -> &mut Option<u32> {
+        &mut self.attendance
+    }
+
+    /// Get the rest advantage given to one of the matchup's teams, if any
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let my_matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// assert!(my_matchup.rest_advantage().is_none());
+    /// ```
+    pub fn rest_advantage(&self) -> Option<&MatchupRestAdvantage> {
+        self.rest_advantage.as_ref()
+    }
+
+    /// Mutably borrow the rest advantage given to one of the matchup's teams
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::{LeagueSeasonMatchup, MatchupRestAdvantage};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut my_matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// *my_matchup.rest_advantage_mut() = Some(MatchupRestAdvantage { team: 1, rest_weeks: 1, bonus: 3 });
+    /// assert_eq!(my_matchup.rest_advantage().unwrap().team, 1);
+    /// ```
+    pub fn rest_advantage_mut(&mut self) -> &mut Option<MatchupRestAdvantage> {
+        &mut self.rest_advantage
+    }
+
+    /// Swap the home and away teams of an unplayed matchup, along with their
+    /// short names. The team which receives the opening kickoff is unchanged.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchupBuilder;
+    ///
+    /// let mut my_matchup = LeagueSeasonMatchupBuilder::new()
+    ///     .home_team(0)
+    ///     .away_team(1)
+    ///     .home_short_name("ME")
+    ///     .away_short_name("YOU")
+    ///     .build()
+    ///     .unwrap();
+    /// my_matchup.swap_home_away().unwrap();
+    /// assert_eq!(*my_matchup.home_team(), 1);
+    /// assert_eq!(my_matchup.context().home_team_short(), "YOU");
+    /// assert!(my_matchup.context().home_opening_kickoff());
+    /// ```
+    pub fn swap_home_away(&mut self) -> Result<(), LeagueError> {
+        if self.game.is_some() || self.context.started() || self.context.game_over() {
+            return Err(
+                format!(
+                    "Cannot swap home and away teams of matchup between {} and {}: game has already started",
+                    self.home_team, self.away_team
+                ).into()
+            );
+        }
+        let swapped = LeagueSeasonMatchupBuilder::new()
+            .home_team(self.away_team)
+            .away_team(self.home_team)
+            .home_short_name(self.context.away_team_short())
+            .away_short_name(self.context.home_team_short())
+            .home_opening_kickoff(!self.context.home_opening_kickoff())
+            .build()?;
+        self.home_team = swapped.home_team;
+        self.away_team = swapped.away_team;
+        self.context = swapped.context;
+        Ok(())
+    }
+
+    /// Get a team as it plays in the matchup, with its skill levels raised
+    /// by the matchup's rest advantage if it is the rested team
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::play::PlaySimulatable;
+    /// use fbsim_core::league::season::matchup::{LeagueSeasonMatchup, MatchupRestAdvantage};
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut my_matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// *my_matchup.rest_advantage_mut() = Some(MatchupRestAdvantage { team: 1, rest_weeks: 1, bonus: 3 });
+    /// let team = FootballTeam::new();
+    /// assert_eq!(my_matchup.rested_team(0, &team).offense().overall(), 50);
+    /// assert_eq!(my_matchup.rested_team(1, &team).offense().overall(), 53);
+    /// ```
+    pub fn rested_team<'a>(&self, id: usize, team: &'a FootballTeam) -> Cow<'a, FootballTeam> {
+        match &self.rest_advantage {
+            Some(rest) if rest.team == id && rest.bonus > 0 => {
+                let mut rested = team.clone();
+                rested.offense_mut().adjust(rest.bonus as i32);
+                rested.defense_mut().adjust(rest.bonus as i32);
+                Cow::Owned(rested)
+            },
+            _ => Cow::Borrowed(team)
+        }
+    }
+
     /// Determine whether the given team participated in the matchup
     ///
     /// ### Example
@@ -288,6 +876,37 @@ impl LeagueSeasonMatchup {
         }
     }
 
+    /// Get the turnovers the given team gave away and took away in the
+    /// matchup. Returns `None` if the team did not participate, the game is
+    /// not complete, or the game was completed without detailed stats.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let my_matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// assert!(my_matchup.turnovers(0).is_none());
+    /// ```
+    pub fn turnovers(&self, id: usize) -> Option<LeagueTeamTurnovers> {
+        if !(self.context.game_over() && self.participated(id)) {
+            return None;
+        }
+        let (home, away) = match (&self.home_stats, &self.away_stats) {
+            (Some(home), Some(away)) => (home.turnovers() as usize, away.turnovers() as usize),
+            _ => return None
+        };
+        let (giveaways, takeaways) = if self.is_home_team(id) {
+            (home, away)
+        } else {
+            (away, home)
+        };
+        let mut turnovers = LeagueTeamTurnovers::new();
+        turnovers.increment_giveaways(giveaways);
+        turnovers.increment_takeaways(takeaways);
+        Some(turnovers)
+    }
+
     /// Returns the winner of the matchup if the matchup is complete
     ///
     /// ### Example
@@ -347,6 +966,284 @@ impl std::fmt::Display for LeagueSeasonMatchup {
     }
 }
 
+/// # `LeagueSeasonMatchupBuilder` struct
+///
+/// A `LeagueSeasonMatchupBuilder` implements the builder pattern for the
+/// `LeagueSeasonMatchup` struct
+///
+/// ### Example
+/// ```
+/// use fbsim_core::league::season::matchup::LeagueSeasonMatchupBuilder;
+/// use fbsim_core::league::season::week::LeagueSeasonWeek;
+///
+/// // Build a matchup by hand and insert it into a week
+/// let mut rng = rand::thread_rng();
+/// let my_matchup = LeagueSeasonMatchupBuilder::new()
+///     .home_team(0)
+///     .away_team(1)
+///     .home_short_name("HWK")
+///     .away_short_name("EGL")
+///     .coin_toss(&mut rng)
+///     .build()
+///     .unwrap();
+/// let mut my_week = LeagueSeasonWeek::new();
+/// my_week.matchups_mut().push(my_matchup);
+/// assert!(my_week.team_matchup(1).is_some());
+/// ```
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct LeagueSeasonMatchupBuilder {
+    home_team: Option<usize>,
+    away_team: Option<usize>,
+    home_short_name: String,
+    away_short_name: String,
+    home_opening_kickoff: bool
+}
+
+impl Default for LeagueSeasonMatchupBuilder {
+    /// Default constructor for the `LeagueSeasonMatchupBuilder` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchupBuilder;
+    ///
+    /// let my_builder = LeagueSeasonMatchupBuilder::default();
+    /// ```
+    fn default() -> Self {
+        LeagueSeasonMatchupBuilder {
+            home_team: None,
+            away_team: None,
+            home_short_name: String::from("HOME"),
+            away_short_name: String::from("AWAY"),
+            home_opening_kickoff: false
+        }
+    }
+}
+
+impl LeagueSeasonMatchupBuilder {
+    /// Initialize a new matchup builder
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchupBuilder;
+    ///
+    /// let my_builder = LeagueSeasonMatchupBuilder::new();
+    /// ```
+    pub fn new() -> LeagueSeasonMatchupBuilder {
+        LeagueSeasonMatchupBuilder::default()
+    }
+
+    /// Set the home team ID
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchupBuilder;
+    ///
+    /// let my_matchup = LeagueSeasonMatchupBuilder::new()
+    ///     .home_team(3)
+    ///     .away_team(1)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(*my_matchup.home_team() == 3);
+    /// ```
+    pub fn home_team(mut self, home_team: usize) -> Self {
+        self.home_team = Some(home_team);
+        self
+    }
+
+    /// Set the away team ID
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchupBuilder;
+    ///
+    /// let my_matchup = LeagueSeasonMatchupBuilder::new()
+    ///     .home_team(0)
+    ///     .away_team(3)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(*my_matchup.away_team() == 3);
+    /// ```
+    pub fn away_team(mut self, away_team: usize) -> Self {
+        self.away_team = Some(away_team);
+        self
+    }
+
+    /// Set the home team short name (default: `HOME`)
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchupBuilder;
+    ///
+    /// let my_matchup = LeagueSeasonMatchupBuilder::new()
+    ///     .home_team(0)
+    ///     .away_team(1)
+    ///     .home_short_name("HWK")
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_matchup.context().home_team_short() == "HWK");
+    /// ```
+    pub fn home_short_name(mut self, home_short_name: &str) -> Self {
+        self.home_short_name = String::from(home_short_name);
+        self
+    }
+
+    /// Set the away team short name (default: `AWAY`)
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchupBuilder;
+    ///
+    /// let my_matchup = LeagueSeasonMatchupBuilder::new()
+    ///     .home_team(0)
+    ///     .away_team(1)
+    ///     .away_short_name("EGL")
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_matchup.context().away_team_short() == "EGL");
+    /// ```
+    pub fn away_short_name(mut self, away_short_name: &str) -> Self {
+        self.away_short_name = String::from(away_short_name);
+        self
+    }
+
+    /// Set whether the home team receives the opening kickoff (default: false)
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchupBuilder;
+    ///
+    /// let my_matchup = LeagueSeasonMatchupBuilder::new()
+    ///     .home_team(0)
+    ///     .away_team(1)
+    ///     .home_opening_kickoff(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_matchup.context().home_opening_kickoff());
+    /// ```
+    pub fn home_opening_kickoff(mut self, home_opening_kickoff: bool) -> Self {
+        self.home_opening_kickoff = home_opening_kickoff;
+        self
+    }
+
+    /// Decide which team receives the opening kickoff with a coin toss
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchupBuilder;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let my_matchup = LeagueSeasonMatchupBuilder::new()
+    ///     .home_team(0)
+    ///     .away_team(1)
+    ///     .coin_toss(&mut rng)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn coin_toss(self, rng: &mut impl Rng) -> Self {
+        let home_opening_kickoff: bool = rng.gen::<bool>();
+        self.home_opening_kickoff(home_opening_kickoff)
+    }
+
+    /// Build the matchup, ensuring both team IDs are given and distinct and
+    /// both short names are at most 4 characters
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchupBuilder;
+    ///
+    /// // A team cannot play itself
+    /// let my_matchup = LeagueSeasonMatchupBuilder::new()
+    ///     .home_team(1)
+    ///     .away_team(1)
+    ///     .build();
+    /// assert!(my_matchup.is_err());
+    ///
+    /// // Short names are at most 4 characters
+    /// let my_matchup = LeagueSeasonMatchupBuilder::new()
+    ///     .home_team(0)
+    ///     .away_team(1)
+    ///     .home_short_name("HAWKS")
+    ///     .build();
+    /// assert!(my_matchup.is_err());
+    /// ```
+    pub fn build(self) -> Result<LeagueSeasonMatchup, LeagueError> {
+        // Ensure both teams are given and distinct
+        let home_team = self.home_team.ok_or("Matchup home team was not given")?;
+        let away_team = self.away_team.ok_or("Matchup away team was not given")?;
+        if home_team == away_team {
+            return Err(
+                format!(
+                    "Matchup home and away teams are the same team: {}",
+                    home_team
+                ).into()
+            );
+        }
+
+        // Generate the game context, which validates the short names
+        let context: GameContext = GameContext::with_opening_kickoff(
+            &self.home_short_name,
+            &self.away_short_name,
+            self.home_opening_kickoff
+        )?;
+
+        // Instantiate and return a LeagueSeasonMatchup
+        Ok(
+            LeagueSeasonMatchup {
+                home_team,
+                away_team,
+                context,
+                game: None,
+                home_stats: None,
+                away_stats: None,
+                box_score: None,
+                attendance: None,
+                rest_advantage: None
+            }
+        )
+    }
+}
+
+/// # `MatchupRestAdvantage` struct
+///
+/// The extra rest one team of a playoff matchup had over its opponent, and
+/// the bonus to its skill levels it received for the matchup
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct MatchupRestAdvantage {
+    /// ID of the rested team
+    pub team: usize,
+    /// Number of weeks of rest the team had beyond its opponent's
+    pub rest_weeks: usize,
+    /// Amount added to each of the team's skill levels
+    pub bonus: u32
+}
+
+/// # `ScoreAnomaly` struct
+///
+/// A completed season matchup whose final score falls outside of a set of
+/// `ScoreBounds`
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct ScoreAnomaly {
+    /// Index of the week containing the matchup
+    pub week: usize,
+    /// Index of the matchup within its week
+    pub matchup: usize,
+    /// ID of the home team
+    pub home_team: usize,
+    /// ID of the away team
+    pub away_team: usize,
+    /// Final score of the home team
+    pub home_score: u32,
+    /// Final score of the away team
+    pub away_score: u32
+}
+
 /// # `LeagueSeasonMatchups` struct
 ///
 /// Represents a list of matchups for a given team during a given season
@@ -0,0 +1,347 @@
+#![doc = include_str!("../../../docs/league/season/notification.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::league::matchup::LeagueTeamRecord;
+use crate::league::season::playoffs::picture::{PlayoffPicture, PlayoffStatus};
+
+/// # `NotificationRules` struct
+///
+/// Which notable events a week simulation reports as `Notification`s, and
+/// the thresholds at which they are reported. Every rule is off by default.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationRules {
+    /// Report a win over a team ranked at least this many places higher in
+    /// the standings entering the week
+    pub upset_rank_gap: Option<usize>,
+    /// Report a loss by a team ranked within this many places of the top of
+    /// the standings entering the week
+    pub top_team_losses: Option<usize>,
+    /// Report a win after trailing by at least this many points
+    pub comeback_points: Option<u32>,
+    /// Report a win in which the loser did not score
+    pub shutouts: bool,
+    /// Report teams which clinch a berth or the top seed, or are eliminated,
+    /// in a playoff picture of this many teams
+    pub playoff_teams: Option<usize>
+}
+
+impl NotificationRules {
+    /// Constructor for the `NotificationRules` struct, with every rule off
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::notification::NotificationRules;
+    ///
+    /// let my_rules = NotificationRules::new();
+    /// assert!(my_rules.upset_rank_gap.is_none());
+    /// assert!(!my_rules.shutouts);
+    /// ```
+    pub fn new() -> NotificationRules {
+        NotificationRules::default()
+    }
+
+    /// Report wins over teams ranked at least the given number of places
+    /// higher in the standings entering the week
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::notification::NotificationRules;
+    ///
+    /// let my_rules = NotificationRules::new().upset_rank_gap(4);
+    /// assert_eq!(my_rules.upset_rank_gap, Some(4));
+    /// ```
+    pub fn upset_rank_gap(mut self, upset_rank_gap: usize) -> Self {
+        self.upset_rank_gap = Some(upset_rank_gap);
+        self
+    }
+
+    /// Report losses by teams ranked within the given number of places of
+    /// the top of the standings entering the week
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::notification::NotificationRules;
+    ///
+    /// let my_rules = NotificationRules::new().top_team_losses(2);
+    /// assert_eq!(my_rules.top_team_losses, Some(2));
+    /// ```
+    pub fn top_team_losses(mut self, top_team_losses: usize) -> Self {
+        self.top_team_losses = Some(top_team_losses);
+        self
+    }
+
+    /// Report wins after trailing by at least the given number of points
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::notification::NotificationRules;
+    ///
+    /// let my_rules = NotificationRules::new().comeback_points(20);
+    /// assert_eq!(my_rules.comeback_points, Some(20));
+    /// ```
+    pub fn comeback_points(mut self, comeback_points: u32) -> Self {
+        self.comeback_points = Some(comeback_points);
+        self
+    }
+
+    /// Set whether to report shutouts
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::notification::NotificationRules;
+    ///
+    /// let my_rules = NotificationRules::new().shutouts(true);
+    /// assert!(my_rules.shutouts);
+    /// ```
+    pub fn shutouts(mut self, shutouts: bool) -> Self {
+        self.shutouts = shutouts;
+        self
+    }
+
+    /// Report clinches and eliminations in a playoff picture of the given
+    /// number of teams
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::notification::NotificationRules;
+    ///
+    /// let my_rules = NotificationRules::new().playoff_teams(4);
+    /// assert_eq!(my_rules.playoff_teams, Some(4));
+    /// ```
+    pub fn playoff_teams(mut self, playoff_teams: usize) -> Self {
+        self.playoff_teams = Some(playoff_teams);
+        self
+    }
+
+    /// Whether any rule depends on the standings entering the week
+    pub(crate) fn needs_standings(&self) -> bool {
+        self.upset_rank_gap.is_some() || self.top_team_losses.is_some()
+    }
+
+    /// Evaluate the game rules against a completed matchup, given the
+    /// standings ranks entering the week and the winner's largest deficit
+    pub(crate) fn evaluate_game(&self, game: &NotificationGame, ranks: &StandingsRanks) -> Vec<NotificationKind> {
+        let mut kinds = Vec::new();
+        let (winner, loser, winner_score, loser_score) = match game.result() {
+            Some(result) => result,
+            None => return kinds
+        };
+        let winner_rank = ranks.rank(winner);
+        let loser_rank = ranks.rank(loser);
+        if let (Some(gap), Some(winner_rank), Some(loser_rank)) = (self.upset_rank_gap, winner_rank, loser_rank) {
+            if winner_rank >= loser_rank + gap.max(1) {
+                kinds.push(NotificationKind::Upset {
+                    winner, loser, winner_rank, loser_rank, winner_score, loser_score
+                });
+            }
+        }
+        if let (Some(top), Some(loser_rank)) = (self.top_team_losses, loser_rank) {
+            if loser_rank <= top {
+                kinds.push(NotificationKind::TopTeamLoss {
+                    team: loser, rank: loser_rank, opponent: winner,
+                    score: loser_score, opponent_score: winner_score
+                });
+            }
+        }
+        if let (Some(points), Some(deficit)) = (self.comeback_points, game.winner_deficit) {
+            if deficit >= points.max(1) {
+                kinds.push(NotificationKind::Comeback {
+                    winner, loser, deficit, winner_score, loser_score
+                });
+            }
+        }
+        if self.shutouts && loser_score == 0 {
+            kinds.push(NotificationKind::Shutout { winner, loser, winner_score });
+        }
+        kinds
+    }
+}
+
+/// A completed matchup as seen by the notification rules
+pub(crate) struct NotificationGame {
+    pub home_team: usize,
+    pub away_team: usize,
+    pub home_score: u32,
+    pub away_score: u32,
+    /// Largest deficit the winner overcame, if the game was played by play
+    pub winner_deficit: Option<u32>
+}
+
+impl NotificationGame {
+    /// The winner, loser, and their scores, or `None` for a tie
+    fn result(&self) -> Option<(usize, usize, u32, u32)> {
+        match self.home_score.cmp(&self.away_score) {
+            std::cmp::Ordering::Greater => Some((self.home_team, self.away_team, self.home_score, self.away_score)),
+            std::cmp::Ordering::Less => Some((self.away_team, self.home_team, self.away_score, self.home_score)),
+            std::cmp::Ordering::Equal => None
+        }
+    }
+}
+
+/// Each team's rank in the standings entering a week, where teams with the
+/// same win percentage share a rank and teams yet to play are unranked
+#[derive(Default)]
+pub(crate) struct StandingsRanks(BTreeMap<usize, usize>);
+
+impl StandingsRanks {
+    /// Rank sorted standings, one plus the number of teams with a strictly
+    /// better win percentage
+    pub fn new(standings: &[(usize, LeagueTeamRecord)]) -> StandingsRanks {
+        let mut ranks = BTreeMap::new();
+        for (id, record) in standings.iter() {
+            if record.wins() + record.losses() + record.ties() == 0 {
+                continue;
+            }
+            let better = standings.iter()
+                .filter(|(_, other)| other.cmp_win_pct(record) == std::cmp::Ordering::Greater)
+                .count();
+            ranks.insert(*id, better + 1);
+        }
+        StandingsRanks(ranks)
+    }
+
+    pub fn rank(&self, id: usize) -> Option<usize> {
+        self.0.get(&id).copied()
+    }
+}
+
+/// Compare the playoff pictures before and after a week, reporting each team
+/// which newly clinched a berth or the top seed, or was newly eliminated
+pub(crate) fn playoff_changes(before: &PlayoffPicture, after: &PlayoffPicture) -> Vec<NotificationKind> {
+    let mut kinds = Vec::new();
+    for entry in after.entries().iter() {
+        let team = entry.team_id();
+        let previous = before.team_status(team);
+        let was_clinched = previous.is_some_and(|e| e.is_clinched());
+        let was_top_seed = previous.is_some_and(|e| *e.status() == PlayoffStatus::ClinchedTopSeed);
+        let was_eliminated = previous.is_some_and(|e| e.is_eliminated());
+        if entry.is_clinched() && !was_clinched {
+            kinds.push(NotificationKind::ClinchedPlayoffs { team });
+        }
+        if *entry.status() == PlayoffStatus::ClinchedTopSeed && !was_top_seed {
+            kinds.push(NotificationKind::ClinchedTopSeed { team });
+        }
+        if entry.is_eliminated() && !was_eliminated {
+            kinds.push(NotificationKind::Eliminated { team });
+        }
+    }
+    kinds
+}
+
+/// # `NotificationKind` enum
+///
+/// The rule a `Notification` triggered, with the teams and scores involved
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum NotificationKind {
+    /// A team beat a team ranked well above it entering the week
+    Upset {
+        winner: usize,
+        loser: usize,
+        winner_rank: usize,
+        loser_rank: usize,
+        winner_score: u32,
+        loser_score: u32
+    },
+    /// A team near the top of the standings lost
+    TopTeamLoss {
+        team: usize,
+        rank: usize,
+        opponent: usize,
+        score: u32,
+        opponent_score: u32
+    },
+    /// A team won after trailing by the given deficit
+    Comeback {
+        winner: usize,
+        loser: usize,
+        deficit: u32,
+        winner_score: u32,
+        loser_score: u32
+    },
+    /// A team won without allowing any points
+    Shutout {
+        winner: usize,
+        loser: usize,
+        winner_score: u32
+    },
+    /// A team clinched a playoff berth
+    ClinchedPlayoffs { team: usize },
+    /// A team clinched the top seed
+    ClinchedTopSeed { team: usize },
+    /// A team was eliminated from playoff contention
+    Eliminated { team: usize }
+}
+
+/// # `Notification` struct
+///
+/// A notable event found by a week simulation with `NotificationRules`
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct Notification {
+    /// Index of the week in which the event occurred
+    pub week: usize,
+    /// Index of the matchup within its week, if the event was a game
+    pub matchup: Option<usize>,
+    /// The rule triggered, with the teams and scores involved
+    pub kind: NotificationKind
+}
+
+impl fmt::Display for Notification {
+    /// Display a `Notification` as a message
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::notification::{Notification, NotificationKind};
+    ///
+    /// let my_notification = Notification {
+    ///     week: 3,
+    ///     matchup: Some(1),
+    ///     kind: NotificationKind::Shutout { winner: 2, loser: 5, winner_score: 24 }
+    /// };
+    /// assert_eq!(my_notification.to_string(), "Week 3: Team 2 shut out team 5, 24-0");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Week {}: ", self.week)?;
+        match self.kind {
+            NotificationKind::Upset { winner, loser, winner_rank, loser_rank, winner_score, loser_score } => write!(
+                f, "Team {} (ranked {}) upset team {} (ranked {}), {}-{}",
+                winner, winner_rank, loser, loser_rank, winner_score, loser_score
+            ),
+            NotificationKind::TopTeamLoss { team, rank, opponent, score, opponent_score } => write!(
+                f, "Team {} (ranked {}) lost to team {}, {}-{}",
+                team, rank, opponent, opponent_score, score
+            ),
+            NotificationKind::Comeback { winner, loser, deficit, winner_score, loser_score } => write!(
+                f, "Team {} came back from {} points down to beat team {}, {}-{}",
+                winner, deficit, loser, winner_score, loser_score
+            ),
+            NotificationKind::Shutout { winner, loser, winner_score } => write!(
+                f, "Team {} shut out team {}, {}-0", winner, loser, winner_score
+            ),
+            NotificationKind::ClinchedPlayoffs { team } => write!(
+                f, "Team {} clinched a playoff berth", team
+            ),
+            NotificationKind::ClinchedTopSeed { team } => write!(
+                f, "Team {} clinched the top seed", team
+            ),
+            NotificationKind::Eliminated { team } => write!(
+                f, "Team {} was eliminated from playoff contention", team
+            ),
+        }
+    }
+}
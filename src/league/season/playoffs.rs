@@ -1,18 +1,24 @@
 #![doc = include_str!("../../../docs/league/season/playoffs.md")]
+pub mod odds;
 pub mod picture;
+pub mod preview;
 
 #[cfg(feature = "rocket_okapi")]
 use rocket_okapi::okapi::schemars;
 #[cfg(feature = "rocket_okapi")]
 use rocket_okapi::okapi::schemars::JsonSchema;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand::rngs::SmallRng;
 use serde::{Serialize, Deserialize, Deserializer};
 use std::collections::{BTreeMap, HashSet};
 
+use crate::league::error::LeagueError;
+use crate::game::context::GameContextBuilder;
 use crate::game::matchup::FootballMatchupResult;
 use crate::league::matchup::LeagueTeamRecord;
+use crate::league::season::{ChampionshipMode, LeagueSeason, LeagueSeasonPlayoffOptions};
 use crate::league::season::week::LeagueSeasonWeek;
-use crate::league::season::matchup::LeagueSeasonMatchup;
+use crate::league::season::matchup::{LeagueSeasonMatchup, LeagueSeasonMatchupBuilder, MatchupRestAdvantage};
 
 /// Maximum allowed length for a playoff team short name
 const MAX_PLAYOFF_TEAM_SHORT_NAME_LEN: usize = 4;
@@ -36,19 +42,19 @@ impl PlayoffTeamRaw {
     /// let raw = PlayoffTeamRaw { seed: 1, short_name: "TM".to_string() };
     /// assert!(raw.validate().is_ok());
     /// ```
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self) -> Result<(), LeagueError> {
         if self.short_name.len() > MAX_PLAYOFF_TEAM_SHORT_NAME_LEN {
             return Err(format!(
                 "Playoff team short name '{}' exceeds maximum length of {} characters",
                 self.short_name, MAX_PLAYOFF_TEAM_SHORT_NAME_LEN
-            ));
+            ).into());
         }
         Ok(())
     }
 }
 
 impl TryFrom<PlayoffTeamRaw> for PlayoffTeam {
-    type Error = String;
+    type Error = LeagueError;
 
     fn try_from(raw: PlayoffTeamRaw) -> Result<Self, Self::Error> {
         raw.validate()?;
@@ -78,7 +84,7 @@ impl PlayoffTeamsRaw {
     /// let raw = PlayoffTeamsRaw { teams: BTreeMap::new() };
     /// assert!(raw.validate().is_ok());
     /// ```
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self) -> Result<(), LeagueError> {
         // Check for duplicate team IDs across conferences
         let mut seen = HashSet::new();
         for (conf_id, conference_teams) in &self.teams {
@@ -87,22 +93,46 @@ impl PlayoffTeamsRaw {
                     return Err(format!(
                         "Duplicate team ID {} found across conferences in playoffs (detected in conference {})",
                         team_id, conf_id
-                    ));
+                    ).into());
                 }
             }
         }
+
+        // Check that each conference's seeds are unique and run from 1 to
+        // the number of teams in the conference
+        for (conf_id, conference_teams) in &self.teams {
+            let mut seeds: BTreeMap<usize, usize> = BTreeMap::new();
+            for (&team_id, team) in conference_teams {
+                if let Some(other_id) = seeds.insert(team.seed, team_id) {
+                    return Err(format!(
+                        "Duplicate seed {} in playoff conference {} (teams {} and {})",
+                        team.seed, conf_id, other_id, team_id
+                    ).into());
+                }
+            }
+            let num_teams = conference_teams.len();
+            if let Some(seed) = seeds.keys().find(|&&seed| seed == 0 || seed > num_teams) {
+                let missing = (1..=num_teams).find(|s| !seeds.contains_key(s)).unwrap_or(num_teams);
+                return Err(format!(
+                    "Seed {} in playoff conference {} is out of range: seeds must run from 1 to {} (seed {} is missing)",
+                    seed, conf_id, num_teams, missing
+                ).into());
+            }
+        }
         Ok(())
     }
 }
 
 impl TryFrom<PlayoffTeamsRaw> for PlayoffTeams {
-    type Error = String;
+    type Error = LeagueError;
 
     fn try_from(raw: PlayoffTeamsRaw) -> Result<Self, Self::Error> {
         raw.validate()?;
-        Ok(PlayoffTeams {
-            teams: raw.teams,
-        })
+
+        // Prune any empty conferences
+        let mut teams = raw.teams;
+        teams.retain(|_, conference_teams| !conference_teams.is_empty());
+        Ok(PlayoffTeams { teams })
     }
 }
 
@@ -112,9 +142,22 @@ impl TryFrom<PlayoffTeamsRaw> for PlayoffTeams {
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Default, Debug, Serialize, Deserialize)]
 pub struct LeagueSeasonPlayoffsRaw {
     pub teams: PlayoffTeams,
+    /// Rounds for each conference bracket (default: empty)
+    #[serde(default)]
     pub conference_brackets: BTreeMap<usize, Vec<LeagueSeasonWeek>>,
+    /// Winners bracket between conference champions (default: empty)
     #[serde(default)]
     pub winners_bracket: Vec<LeagueSeasonWeek>,
+    /// Weeks of rest after each round (default: empty)
+    #[serde(default)]
+    pub rest_weeks_between_rounds: Vec<usize>,
+    /// Skill bonus for a team with more rest than its opponent (default: 0)
+    #[serde(default)]
+    pub rest_advantage: u32,
+    /// Whether conference brackets are reseeded after each round (default:
+    /// false, a fixed bracket)
+    #[serde(default)]
+    pub reseed: bool,
 }
 
 impl LeagueSeasonPlayoffsRaw {
@@ -130,10 +173,13 @@ impl LeagueSeasonPlayoffsRaw {
     ///     teams: PlayoffTeams::new(),
     ///     conference_brackets: BTreeMap::new(),
     ///     winners_bracket: Vec::new(),
+    ///     rest_weeks_between_rounds: Vec::new(),
+    ///     rest_advantage: 0,
+    ///     reseed: false,
     /// };
     /// assert!(raw.validate().is_ok());
     /// ```
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self) -> Result<(), LeagueError> {
         // Collect all valid team IDs from the playoff teams
         let valid_team_ids: HashSet<usize> = self.teams.iter().collect();
 
@@ -147,13 +193,13 @@ impl LeagueSeasonPlayoffsRaw {
                         return Err(format!(
                             "Conference {} bracket round {} matchup {} references nonexistent home team ID: {}",
                             conf_id, round_index, matchup_index, home_id
-                        ));
+                        ).into());
                     }
                     if !valid_team_ids.contains(&away_id) {
                         return Err(format!(
                             "Conference {} bracket round {} matchup {} references nonexistent away team ID: {}",
                             conf_id, round_index, matchup_index, away_id
-                        ));
+                        ).into());
                     }
                 }
             }
@@ -168,23 +214,39 @@ impl LeagueSeasonPlayoffsRaw {
                     return Err(format!(
                         "Winners bracket round {} matchup {} references nonexistent home team ID: {}",
                         round_index, matchup_index, home_id
-                    ));
+                    ).into());
                 }
                 if !valid_team_ids.contains(&away_id) {
                     return Err(format!(
                         "Winners bracket round {} matchup {} references nonexistent away team ID: {}",
                         round_index, matchup_index, away_id
-                    ));
+                    ).into());
                 }
             }
         }
 
+        // Validate the winners bracket is no deeper than the conference
+        // champions allow
+        let conferences = self.teams.num_conferences();
+        let max_rounds = if conferences < 2 {
+            0
+        } else {
+            let first_round_teams = LeagueSeasonPlayoffs::num_first_round_teams(conferences)?;
+            LeagueSeasonPlayoffs::num_bracket_rounds(conferences, first_round_teams)
+        };
+        if self.winners_bracket.len() > max_rounds {
+            return Err(format!(
+                "Winners bracket has {} rounds but {} conferences allow at most {}",
+                self.winners_bracket.len(), conferences, max_rounds
+            ).into());
+        }
+
         Ok(())
     }
 }
 
 impl TryFrom<LeagueSeasonPlayoffsRaw> for LeagueSeasonPlayoffs {
-    type Error = String;
+    type Error = LeagueError;
 
     fn try_from(raw: LeagueSeasonPlayoffsRaw) -> Result<Self, Self::Error> {
         raw.validate()?;
@@ -192,6 +254,9 @@ impl TryFrom<LeagueSeasonPlayoffsRaw> for LeagueSeasonPlayoffs {
             teams: raw.teams,
             conference_brackets: raw.conference_brackets,
             winners_bracket: raw.winners_bracket,
+            rest_weeks_between_rounds: raw.rest_weeks_between_rounds,
+            rest_advantage: raw.rest_advantage,
+            reseed: raw.reseed,
         })
     }
 }
@@ -230,6 +295,44 @@ impl PlayoffTeam {
     }
 }
 
+/// # `PlayoffEntry` struct
+///
+/// A team to add to the playoffs in a batch with `PlayoffTeams::add_teams` or
+/// `LeagueSeasonPlayoffs::add_teams`. Entries are seeded in order within each
+/// conference, after any teams already in it.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Default, Debug, Serialize, Deserialize)]
+pub struct PlayoffEntry {
+    /// ID of the team
+    pub team_id: usize,
+    /// Short name displayed in the bracket
+    pub short_name: String,
+    /// Conference of the team, or `None` for the default conference (0)
+    #[serde(default)]
+    pub conference: Option<usize>
+}
+
+impl PlayoffEntry {
+    /// Constructor for the `PlayoffEntry` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::PlayoffEntry;
+    ///
+    /// let my_entry = PlayoffEntry::new(3, "TM", Some(1));
+    /// assert_eq!(my_entry.team_id, 3);
+    /// ```
+    pub fn new(team_id: usize, short_name: &str, conference: Option<usize>) -> PlayoffEntry {
+        PlayoffEntry {
+            team_id,
+            short_name: short_name.to_string(),
+            conference
+        }
+    }
+}
+
 /// # `PlayoffTeams` struct
 ///
 /// A collection of teams participating in the playoffs, organized by conference.
@@ -273,10 +376,10 @@ impl PlayoffTeams {
     /// );
     /// assert!(res.is_ok());
     /// ```
-    pub fn add(&mut self, team_id: usize, short_name: &str, conference: usize) -> Result<(), String> {
+    pub fn add(&mut self, team_id: usize, short_name: &str, conference: usize) -> Result<(), LeagueError> {
         let conference_teams = self.teams.entry(conference).or_default();
         if conference_teams.contains_key(&team_id) {
-            return Err(format!("Team {} is already in conference {}", team_id, conference));
+            return Err(LeagueError::DuplicateTeam { id: team_id });
         }
 
         let seed = conference_teams.len() + 1;
@@ -287,6 +390,92 @@ impl PlayoffTeams {
         Ok(())
     }
 
+    /// Add a batch of teams to the playoffs, seeding them in order within
+    /// each conference. The whole batch is validated before any team is
+    /// added, so an invalid entry leaves the playoffs unchanged.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::{PlayoffEntry, PlayoffTeams};
+    ///
+    /// let mut my_teams = PlayoffTeams::new();
+    /// let res = my_teams.add_teams(vec![
+    ///     PlayoffEntry::new(0, "A", None),
+    ///     PlayoffEntry::new(1, "B", None)
+    /// ]);
+    /// assert!(res.is_ok());
+    /// assert_eq!(my_teams.get(1).unwrap().seed(), 2);
+    ///
+    /// // A batch repeating a team ID adds none of its teams
+    /// let res = my_teams.add_teams(vec![
+    ///     PlayoffEntry::new(2, "C", None),
+    ///     PlayoffEntry::new(0, "D", None)
+    /// ]);
+    /// assert!(res.is_err());
+    /// assert_eq!(my_teams.len(), 2);
+    /// ```
+    pub fn add_teams(&mut self, entries: Vec<PlayoffEntry>) -> Result<(), LeagueError> {
+        // Validate every entry before adding any of them
+        let mut batch_ids = HashSet::new();
+        for (i, entry) in entries.iter().enumerate() {
+            let invalid = |reason: LeagueError| LeagueError::Invalid {
+                reason: format!("Playoff entry {} (team {}) is invalid: {}", i, entry.team_id, reason)
+            };
+            let raw = PlayoffTeamRaw { seed: 0, short_name: entry.short_name.clone() };
+            raw.validate().map_err(invalid)?;
+            if self.contains(entry.team_id) {
+                return Err(invalid(LeagueError::from("team is already in the playoffs")));
+            }
+            if !batch_ids.insert(entry.team_id) {
+                return Err(invalid(LeagueError::from("team appears more than once in the batch")));
+            }
+        }
+
+        // Add the validated entries in order
+        for entry in entries.into_iter() {
+            self.add(entry.team_id, &entry.short_name, entry.conference.unwrap_or(0))?;
+        }
+        Ok(())
+    }
+
+    /// Remove a team by ID, moving each lower seed in its conference up by
+    /// one. A conference is removed along with its last team.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::PlayoffTeams;
+    ///
+    /// let mut my_teams = PlayoffTeams::new();
+    /// let _ = my_teams.add(0, "A", 0);
+    /// let _ = my_teams.add(1, "B", 0);
+    /// let _ = my_teams.add(2, "C", 1);
+    ///
+    /// // Team 1 moves up to the top seed
+    /// assert!(my_teams.remove(0).is_some());
+    /// assert_eq!(my_teams.get(1).unwrap().seed(), 1);
+    ///
+    /// // Removing conference 1's only team removes the conference
+    /// assert!(my_teams.remove(2).is_some());
+    /// assert_eq!(my_teams.num_conferences(), 1);
+    /// assert!(my_teams.get_conference(1).is_none());
+    /// ```
+    pub fn remove(&mut self, team_id: usize) -> Option<PlayoffTeam> {
+        let conference = *self.teams.iter()
+            .find(|(_, conference_teams)| conference_teams.contains_key(&team_id))?
+            .0;
+        let conference_teams = self.teams.get_mut(&conference)?;
+        let removed = conference_teams.remove(&team_id)?;
+        for team in conference_teams.values_mut() {
+            if team.seed > removed.seed {
+                team.seed -= 1;
+            }
+        }
+        if conference_teams.is_empty() {
+            self.teams.remove(&conference);
+        }
+        Some(removed)
+    }
+
     /// Get a team by ID (searches all conferences)
     ///
     /// ### Example
@@ -395,7 +584,7 @@ impl PlayoffTeams {
     /// assert!(my_teams.num_conferences() == 2);
     /// ```
     pub fn num_conferences(&self) -> usize {
-        self.teams.len()
+        self.teams.values().filter(|c| !c.is_empty()).count()
     }
 
     /// Iterate over conference IDs
@@ -437,7 +626,8 @@ impl PlayoffTeams {
 
     /// Get teams in a conference sorted by seed
     ///
-    /// Returns a vector of `(team_id, &PlayoffTeam)` pairs sorted by seed.
+    /// Returns a vector of `(team_id, &PlayoffTeam)` pairs sorted by seed
+    /// (ascending).
     ///
     /// ### Example
     /// ```
@@ -457,7 +647,7 @@ impl PlayoffTeams {
                 .iter()
                 .map(|(&team_id, team)| (team_id, team))
                 .collect();
-            teams.sort_by_key(|(_, team)| team.seed);
+            teams.sort_by_key(|(team_id, team)| (team.seed, *team_id));
             teams
         } else {
             Vec::new()
@@ -506,6 +696,320 @@ impl<'de> Deserialize<'de> for PlayoffTeams {
     }
 }
 
+/// # `SeedingRule` enum
+///
+/// A `SeedingRule` describes how a conference's playoff teams are seeded
+/// when division winners are guaranteed a playoff spot
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub enum SeedingRule {
+    /// Division winners take the top seeds, followed by the wild cards,
+    /// each group ordered by the standings (default)
+    #[default]
+    DivisionWinnersFirst,
+    /// Every playoff team is seeded by its place in the standings
+    ByRecord
+}
+
+impl SeedingRule {
+    /// Select and seed a conference's playoff teams, given its sorted
+    /// standings and its division winners.  The division winners always
+    /// qualify, up to the number of playoff teams, and the remaining spots
+    /// go to the best of the other teams.  Teams are returned in seed order,
+    /// with the standings' tiebreakers applied within each group.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::matchup::LeagueTeamRecord;
+    /// use fbsim_core::league::season::playoffs::SeedingRule;
+    ///
+    /// // Team 2 won its division at 9-7 behind two 12-4 wild cards
+    /// let record = |wins: usize, losses: usize| {
+    ///     let mut record = LeagueTeamRecord::new();
+    ///     record.increment_wins(wins);
+    ///     record.increment_losses(losses);
+    ///     record
+    /// };
+    /// let standings = vec![
+    ///     (0, record(13, 3)),
+    ///     (1, record(12, 4)),
+    ///     (3, record(12, 4)),
+    ///     (2, record(9, 7))
+    /// ];
+    /// let division_winners = vec![0, 2];
+    /// assert_eq!(
+    ///     SeedingRule::DivisionWinnersFirst.seed(&standings, &division_winners, 3),
+    ///     vec![0, 2, 1]
+    /// );
+    /// assert_eq!(
+    ///     SeedingRule::ByRecord.seed(&standings, &division_winners, 3),
+    ///     vec![0, 1, 2]
+    /// );
+    /// ```
+    pub fn seed(&self, standings: &[(usize, LeagueTeamRecord)], division_winners: &[usize], num_playoff_teams: usize) -> Vec<usize> {
+        // Division winners qualify first, in standings order
+        let mut qualified: Vec<usize> = standings.iter()
+            .map(|(team_id, _)| *team_id)
+            .filter(|team_id| division_winners.contains(team_id))
+            .take(num_playoff_teams)
+            .collect();
+        let num_division_winners = qualified.len();
+
+        // Fill the remaining spots with wild cards
+        for (team_id, _) in standings.iter() {
+            if qualified.len() >= num_playoff_teams {
+                break;
+            }
+            if !qualified.contains(team_id) {
+                qualified.push(*team_id);
+            }
+        }
+
+        // Seed every qualified team by the standings if seeding by record
+        if *self == SeedingRule::ByRecord && num_division_winners > 0 {
+            qualified.sort_by_key(|team_id| standings.iter().position(|(id, _)| id == team_id));
+        }
+        qualified
+    }
+}
+
+/// # `PlayoffPathOpponent` enum
+///
+/// Represents a team's opponent in a single round of its path to the championship
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum PlayoffPathOpponent {
+    /// The team has a bye through this round
+    Bye,
+    /// The opponent is set by a matchup which already exists in the bracket
+    Known { team_id: usize, seed: usize },
+    /// The round has not been generated, so the opponent is the team
+    /// favored by seed to fill the slot
+    Projected { team_id: usize, seed: usize },
+}
+
+/// # `PlayoffPreviewTeam` struct
+///
+/// A team which would qualify for the playoffs if they started today
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct PlayoffPreviewTeam {
+    /// Conference bracket the team would be seeded into (0 for
+    /// non-conference playoffs)
+    pub conference: usize,
+    /// The team's seed within its conference bracket
+    pub seed: usize,
+    /// The team's ID
+    pub team_id: usize,
+    /// The team's short name
+    pub short_name: String,
+    /// Whether the team would have a first-round bye
+    pub bye: bool,
+}
+
+/// # `PlayoffPreviewMatchup` struct
+///
+/// A first-round pairing which would be played if the playoffs started today
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct PlayoffPreviewMatchup {
+    /// Conference bracket the matchup belongs to (0 for non-conference
+    /// playoffs)
+    pub conference: usize,
+    /// The home team's ID
+    pub home_team: usize,
+    /// The home team's seed
+    pub home_seed: usize,
+    /// The away team's ID
+    pub away_team: usize,
+    /// The away team's seed
+    pub away_seed: usize,
+}
+
+/// # `PlayoffBracketView` struct
+///
+/// The playoff bracket which would be generated if the playoffs started
+/// today, with each qualifying team's seed and the first-round pairings
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct PlayoffBracketView {
+    /// Qualifying teams ordered by conference and then by seed
+    pub teams: Vec<PlayoffPreviewTeam>,
+    /// First-round pairings ordered by conference and then as generated
+    pub first_round: Vec<PlayoffPreviewMatchup>,
+}
+
+impl PlayoffBracketView {
+    /// Get the IDs of the teams which would have a first-round bye
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::PlayoffBracketView;
+    ///
+    /// let my_view = PlayoffBracketView::default();
+    /// assert!(my_view.byes().is_empty());
+    /// ```
+    pub fn byes(&self) -> Vec<usize> {
+        self.teams.iter()
+            .filter(|t| t.bye)
+            .map(|t| t.team_id)
+            .collect()
+    }
+}
+
+/// # `PlayoffPathRound` struct
+///
+/// A single round of a team's path to the championship
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct PlayoffPathRound {
+    /// Whether the round belongs to the winners bracket rather than the
+    /// team's conference bracket
+    winners_bracket: bool,
+    /// Index of the round within its bracket
+    round: usize,
+    /// The team's opponent in the round
+    opponent: PlayoffPathOpponent,
+}
+
+impl PlayoffPathRound {
+    /// Whether the round belongs to the winners bracket
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::LeagueSeasonPlayoffs;
+    ///
+    /// let mut my_playoffs = LeagueSeasonPlayoffs::new();
+    /// let _ = my_playoffs.add_team(0, "A", None);
+    /// let _ = my_playoffs.add_team(1, "B", None);
+    ///
+    /// let path = my_playoffs.path_to_championship(0).unwrap();
+    /// assert!(!path[0].winners_bracket());
+    /// ```
+    pub fn winners_bracket(&self) -> bool {
+        self.winners_bracket
+    }
+
+    /// Index of the round within its bracket
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::LeagueSeasonPlayoffs;
+    ///
+    /// let mut my_playoffs = LeagueSeasonPlayoffs::new();
+    /// let _ = my_playoffs.add_team(0, "A", None);
+    /// let _ = my_playoffs.add_team(1, "B", None);
+    ///
+    /// let path = my_playoffs.path_to_championship(0).unwrap();
+    /// assert!(path[0].round() == 0);
+    /// ```
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    /// The team's opponent in the round
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::{LeagueSeasonPlayoffs, PlayoffPathOpponent};
+    ///
+    /// let mut my_playoffs = LeagueSeasonPlayoffs::new();
+    /// let _ = my_playoffs.add_team(0, "A", None);
+    /// let _ = my_playoffs.add_team(1, "B", None);
+    ///
+    /// let path = my_playoffs.path_to_championship(0).unwrap();
+    /// assert!(*path[0].opponent() == PlayoffPathOpponent::Projected { team_id: 1, seed: 2 });
+    /// ```
+    pub fn opponent(&self) -> &PlayoffPathOpponent {
+        &self.opponent
+    }
+}
+
+/// # `PlayoffBracketGame` enum
+///
+/// What is played at a node of a `PlayoffBracketTree`
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum PlayoffBracketGame {
+    /// A matchup in a round which has been generated
+    Matchup(Box<LeagueSeasonMatchup>),
+    /// A matchup in a round not yet generated, between the winners of the
+    /// node's children
+    Pending,
+    /// A team advancing through the round without playing
+    Bye { team_id: usize, seed: usize },
+}
+
+/// # `PlayoffBracketNode` struct
+///
+/// A single game or bye in a `PlayoffBracketTree`
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct PlayoffBracketNode {
+    /// Conference bracket the node belongs to, or `None` for the winners
+    /// bracket
+    pub conference: Option<usize>,
+    /// Index of the round within its bracket
+    pub round: usize,
+    /// Index of the matchup within its round, or `None` for a bye
+    pub index: Option<usize>,
+    /// The matchup, pending matchup or bye at the node
+    pub game: PlayoffBracketGame,
+    /// Indices of the nodes whose winners feed into this node, home side
+    /// first, empty for the first round of a conference bracket
+    pub children: Vec<usize>,
+}
+
+/// # `PlayoffBracketTree` struct
+///
+/// The playoff bracket as a tree of nodes, from the first round of each
+/// conference bracket up to the championship at the root
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct PlayoffBracketTree {
+    /// Nodes ordered by conference bracket, then the winners bracket, then
+    /// by round, with each round's matchups before its byes
+    pub nodes: Vec<PlayoffBracketNode>,
+    /// Index of the championship node, or `None` if there are no playoffs
+    pub root: Option<usize>,
+}
+
+impl PlayoffBracketTree {
+    /// Get the nodes of a round of a bracket, which is a conference index or
+    /// `None` for the winners bracket
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::PlayoffBracketTree;
+    ///
+    /// let my_tree = PlayoffBracketTree::default();
+    /// assert!(my_tree.round(Some(0), 0).is_empty());
+    /// ```
+    pub fn round(&self, conference: Option<usize>, round: usize) -> Vec<&PlayoffBracketNode> {
+        self.nodes.iter()
+            .filter(|node| node.conference == conference && node.round == round)
+            .collect()
+    }
+}
+
 /// # `LeagueSeasonPlayoffs` struct
 ///
 /// A `LeagueSeasonPlayoffs` represents football season playoffs.
@@ -521,12 +1025,34 @@ pub struct LeagueSeasonPlayoffs {
     /// Teams participating in the playoffs
     teams: PlayoffTeams,
     /// Conference bracket ID -> rounds for that bracket.
-    /// Single-conference playoffs use bracket ID 0.
+    /// Single-conference playoffs use bracket ID 0. Omitted when empty.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
     conference_brackets: BTreeMap<usize, Vec<LeagueSeasonWeek>>,
     /// Winners bracket for championship game(s) between conference champions.
-    /// Only used in multi-conference playoffs.
-    #[serde(default)]
+    /// Only used in multi-conference playoffs (default: empty).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     winners_bracket: Vec<LeagueSeasonWeek>,
+    /// Weeks of rest after each round, beyond the week the next round
+    /// would otherwise be played in (default: empty, no extra rest)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    rest_weeks_between_rounds: Vec<usize>,
+    /// Amount added to each skill level of a team with more rest than its
+    /// opponent (default: 0)
+    #[serde(default, skip_serializing_if = "is_zero")]
+    rest_advantage: u32,
+    /// Whether the surviving teams of each conference bracket are reseeded
+    /// after every round, rather than following a fixed bracket (default:
+    /// false)
+    #[serde(default, skip_serializing_if = "is_false")]
+    reseed: bool,
+}
+
+fn is_zero(n: &u32) -> bool {
+    *n == 0
+}
+
+fn is_false(b: &bool) -> bool {
+    !*b
 }
 
 impl<'de> Deserialize<'de> for LeagueSeasonPlayoffs {
@@ -552,6 +1078,20 @@ impl LeagueSeasonPlayoffs {
         LeagueSeasonPlayoffs::default()
     }
 
+    /// Borrow the teams participating in the playoffs
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::LeagueSeasonPlayoffs;
+    ///
+    /// let mut my_playoffs = LeagueSeasonPlayoffs::new();
+    /// let _ = my_playoffs.add_team(0, "ME", None);
+    /// assert!(my_playoffs.teams().contains(0));
+    /// ```
+    pub fn teams(&self) -> &PlayoffTeams {
+        &self.teams
+    }
+
     /// Borrow the winners bracket
     ///
     /// ### Example
@@ -673,11 +1213,11 @@ impl LeagueSeasonPlayoffs {
     /// assert!(seed.is_ok());
     /// assert!(seed.unwrap() == 1);
     /// ```
-    pub fn team_seed(&self, team_id: usize) -> Result<usize, String> {
+    pub fn team_seed(&self, team_id: usize) -> Result<usize, LeagueError> {
         self.teams
             .get(team_id)
             .map(|team| team.seed())
-            .ok_or_else(|| format!("Team {} not in playoffs", team_id))
+            .ok_or(LeagueError::TeamNotInPlayoffs { id: team_id })
     }
 
     /// Find the conference for a given team ID
@@ -695,13 +1235,10 @@ impl LeagueSeasonPlayoffs {
     /// assert!(seed.is_ok());
     /// assert!(seed.unwrap() == 0);
     /// ```
-    pub fn team_conference(&self, team_id: usize) -> Result<usize, String> {
+    pub fn team_conference(&self, team_id: usize) -> Result<usize, LeagueError> {
         if !self.teams.contains(team_id) {
             return Err(
-                format!(
-                    "Team {} does not exist in playoffs",
-                    team_id
-                )
+                LeagueError::TeamNotInPlayoffs { id: team_id }
             )
         }
         for conference in self.teams.conferences() {
@@ -717,7 +1254,7 @@ impl LeagueSeasonPlayoffs {
             format!(
                 "Team {} exists but was not found in any conference",
                 team_id
-            )
+            ).into()
         )
     }
 
@@ -913,6 +1450,14 @@ impl LeagueSeasonPlayoffs {
         None
     }
 
+    /// Get the champion of the conference at a position in the order of
+    /// conference IDs. The winners bracket pairs conferences by position so
+    /// that conference IDs need not be contiguous.
+    fn conference_champion_at(&self, position: usize) -> Option<usize> {
+        let conference = *self.teams.conferences().nth(position)?;
+        self.conference_champion(conference)
+    }
+
     /// Determine whether the winners bracket has started
     ///
     /// ### Example
@@ -948,21 +1493,18 @@ impl LeagueSeasonPlayoffs {
             return false;
         }
 
-        // Get the final round of the winners bracket
-        if let Some(final_round) = self.winners_bracket.last() {
-            // If more than one matchup, it hasn't completed
-            let matchups = final_round.matchups().len();
-            if matchups > 1 {
-                return false;
-            }
+        // It has not completed until every round through the championship
+        // has been generated
+        match self.winners_bracket_rounds() {
+            Ok(rounds) if self.winners_bracket.len() == rounds => (),
+            _ => return false
+        }
 
-            // If one matchup and round, but more than 2 conferences, it hasn't completed
-            let conferences = self.num_conferences();
-            if self.winners_bracket.len() == 1 && matchups == 1 && conferences > 2 {
+        // The one matchup in the final round is the championship
+        if let Some(final_round) = self.winners_bracket.last() {
+            if final_round.matchups().len() != 1 {
                 return false;
             }
-
-            // If we reach this point, the one matchup left is the championship
             if let Some(final_matchup) = final_round.matchups().first() {
                 return final_matchup.context().game_over();
             }
@@ -1027,24 +1569,72 @@ impl LeagueSeasonPlayoffs {
     /// let _ = conf_playoffs.add_team(0, "YOU", Some(0));
     /// let _ = conf_playoffs.add_team(1, "THEM", Some(1));
     /// ```
-    pub fn add_team(&mut self, team_id: usize, name: &str, conference: Option<usize>) -> Result<(), String> {
+    pub fn add_team(&mut self, team_id: usize, name: &str, conference: Option<usize>) -> Result<(), LeagueError> {
         // Ensure the playoffs have not already started
         if self.started() {
-            return Err(String::from("Playoffs have already started, cannot add new team"));
+            return Err(LeagueError::PlayoffsAlreadyStarted);
         }
 
         let conf = conference.unwrap_or(0);
         self.teams.add(team_id, name, conf)
     }
 
+    /// Add a batch of teams to the playoffs before they have started. The
+    /// whole batch is validated before any team is added, so an invalid
+    /// entry leaves the playoffs unchanged and the error names the entry.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::{LeagueSeasonPlayoffs, PlayoffEntry};
+    ///
+    /// let mut my_playoffs = LeagueSeasonPlayoffs::new();
+    /// let res = my_playoffs.add_teams(vec![
+    ///     PlayoffEntry::new(0, "A", Some(0)),
+    ///     PlayoffEntry::new(1, "B", Some(1))
+    /// ]);
+    /// assert!(res.is_ok());
+    /// assert_eq!(my_playoffs.num_conferences(), 2);
+    /// ```
+    pub fn add_teams(&mut self, entries: Vec<PlayoffEntry>) -> Result<(), LeagueError> {
+        // Ensure the playoffs have not already started
+        if self.started() {
+            return Err(LeagueError::PlayoffsAlreadyStarted);
+        }
+        self.teams.add_teams(entries)
+    }
+
+    /// Remove a team from the playoffs before they have started
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::LeagueSeasonPlayoffs;
+    ///
+    /// let mut my_playoffs = LeagueSeasonPlayoffs::new();
+    /// let _ = my_playoffs.add_team(0, "A", Some(1));
+    /// assert!(my_playoffs.remove_team(0).is_ok());
+    /// assert_eq!(my_playoffs.num_conferences(), 0);
+    /// assert!(my_playoffs.remove_team(0).is_err());
+    /// ```
+    pub fn remove_team(&mut self, team_id: usize) -> Result<(), LeagueError> {
+        // Ensure the playoffs have not already started
+        if self.started() {
+            return Err(LeagueError::PlayoffsAlreadyStarted);
+        }
+
+        match self.teams.remove(team_id) {
+            Some(_) => Ok(()),
+            None => Err(LeagueError::TeamNotInPlayoffs { id: team_id })
+        }
+    }
+
     /// Helper method to calculate the number of first round teams
-    fn num_first_round_teams(&self, num_teams: usize) -> Result<usize, String> {
+    fn num_first_round_teams(num_teams: usize) -> Result<usize, LeagueError> {
         if num_teams < 2 {
             return Err(
                 format!(
                     "Playoffs must contain at least 2 teams per conference, got {}",
                     num_teams
-                )
+                ).into()
             )
         }
         let next_power = num_teams.checked_next_power_of_two().ok_or(
@@ -1054,7 +1644,7 @@ impl LeagueSeasonPlayoffs {
             Ok(next_power)
         } else {
             next_power.checked_div(2).ok_or(
-                String::from("Failed to calculate first round conference matchups")
+                LeagueError::from("Failed to calculate first round conference matchups")
             )
         }
     }
@@ -1077,10 +1667,10 @@ impl LeagueSeasonPlayoffs {
     /// assert!(first_round_teams.is_ok());
     /// assert!(first_round_teams.unwrap() == 2);
     /// ```
-    pub fn first_round_teams(&self, conference: Option<usize>) -> Result<usize, String> {
+    pub fn first_round_teams(&self, conference: Option<usize>) -> Result<usize, LeagueError> {
         let conf = conference.unwrap_or_default();
         let teams_per_conference = self.conference_teams(conf).len();
-        self.num_first_round_teams(teams_per_conference)
+        Self::num_first_round_teams(teams_per_conference)
     }
 
     /// Get the number of teams that will appear in the first round of the
@@ -1104,17 +1694,17 @@ impl LeagueSeasonPlayoffs {
     /// assert!(first_round_teams.is_ok());
     /// assert!(first_round_teams.unwrap() == 2);
     /// ```
-    pub fn first_round_winners(&self) -> Result<usize, String> {
+    pub fn first_round_winners(&self) -> Result<usize, LeagueError> {
         let conferences = self.num_conferences();
         match conferences {
-            0 => Err(String::from("No conferences yet to determine winners bracket")),
+            0 => Err(String::from("No conferences yet to determine winners bracket").into()),
             1 => Ok(0),
-            _ => self.num_first_round_teams(conferences)
+            _ => Self::num_first_round_teams(conferences)
         }
     }
 
     /// Helper method to calculate the number of wild card teams
-    fn num_wild_card_teams(&self, num_teams: usize, first_round_teams: usize) -> Result<usize, String> {
+    fn num_wild_card_teams(&self, num_teams: usize, first_round_teams: usize) -> Result<usize, LeagueError> {
         if num_teams == 1 && first_round_teams == 0 {
             return Ok(0);
         }
@@ -1142,7 +1732,7 @@ impl LeagueSeasonPlayoffs {
     /// assert!(wild_cards.is_ok());
     /// assert!(wild_cards.unwrap() == 2);
     /// ```
-    pub fn wild_cards(&self, conference: Option<usize>) -> Result<usize, String> {
+    pub fn wild_cards(&self, conference: Option<usize>) -> Result<usize, LeagueError> {
         let conf = conference.unwrap_or_default();
         let num_teams = self.conference_teams(conf).len();
         let first_round_teams = self.first_round_teams(conference)?;
@@ -1170,14 +1760,14 @@ impl LeagueSeasonPlayoffs {
     /// assert!(wild_card_teams.is_ok());
     /// assert!(wild_card_teams.unwrap() == 2);
     /// ```
-    pub fn wild_card_winners(&self) -> Result<usize, String> {
+    pub fn wild_card_winners(&self) -> Result<usize, LeagueError> {
         let num_teams = self.num_conferences();
         let first_round_teams = self.first_round_winners()?;
         self.num_wild_card_teams(num_teams, first_round_teams)
     }
 
     /// Helper method to calculate the number of teams that will have a bye
-    fn num_bye_teams(&self, num_teams: usize, first_round_teams: usize) -> Result<usize, String> {
+    fn num_bye_teams(&self, num_teams: usize, first_round_teams: usize) -> Result<usize, LeagueError> {
         if num_teams == 0 && first_round_teams == 1 {
             return Ok(0);
         }
@@ -1208,7 +1798,7 @@ impl LeagueSeasonPlayoffs {
     /// assert!(byes.is_ok());
     /// assert!(byes.unwrap() == 1);
     /// ```
-    pub fn byes(&self, conference: Option<usize>) -> Result<usize, String> {
+    pub fn byes(&self, conference: Option<usize>) -> Result<usize, LeagueError> {
         let conf = conference.unwrap_or_default();
         let num_teams = self.conference_teams(conf).len();
         let first_round_teams = self.first_round_teams(conference)?;
@@ -1235,14 +1825,78 @@ impl LeagueSeasonPlayoffs {
     /// assert!(winners_bracket_byes.is_ok());
     /// assert!(winners_bracket_byes.unwrap() == 1);
     /// ```
-    pub fn winners_bracket_byes(&self) -> Result<usize, String> {
+    pub fn winners_bracket_byes(&self) -> Result<usize, LeagueError> {
         let num_teams = self.num_conferences();
         let first_round_teams = self.first_round_winners()?;
         self.num_bye_teams(num_teams, first_round_teams)
     }
 
+    /// Helper method to calculate the number of rounds in a bracket: the
+    /// rounds from the first round down to the final, plus the wild card
+    /// round if the number of teams is not a power of 2
+    fn num_bracket_rounds(num_teams: usize, first_round_teams: usize) -> usize {
+        if first_round_teams < 2 {
+            return 0;
+        }
+        let rounds = first_round_teams.trailing_zeros() as usize;
+        if first_round_teams == num_teams {
+            rounds
+        } else {
+            rounds + 1
+        }
+    }
+
+    /// Get the number of rounds in a given conference bracket, from the
+    /// wild card round if there is one through the conference final
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::LeagueSeasonPlayoffs;
+    ///
+    /// // Instantiate playoffs and add teams
+    /// let mut my_playoffs = LeagueSeasonPlayoffs::new();
+    /// let _ = my_playoffs.add_team(0, "ME", None);
+    /// let _ = my_playoffs.add_team(1, "YOU", None);
+    /// let _ = my_playoffs.add_team(2, "THEM", None);
+    ///
+    /// // A wild card round followed by the final
+    /// let rounds = my_playoffs.conference_bracket_rounds(None);
+    /// assert_eq!(rounds.unwrap(), 2);
+    /// ```
+    pub fn conference_bracket_rounds(&self, conference: Option<usize>) -> Result<usize, LeagueError> {
+        let conf = conference.unwrap_or_default();
+        let num_teams = self.conference_teams(conf).len();
+        let first_round_teams = self.first_round_teams(conference)?;
+        Ok(Self::num_bracket_rounds(num_teams, first_round_teams))
+    }
+
+    /// Get the number of rounds in the winners bracket, from the wild card
+    /// round if there is one through the championship. A single conference
+    /// playoff has no winners bracket.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::LeagueSeasonPlayoffs;
+    ///
+    /// // Instantiate playoffs and add teams
+    /// let mut my_playoffs = LeagueSeasonPlayoffs::new();
+    /// for conference in 0..5 {
+    ///     let _ = my_playoffs.add_team(2 * conference, "A", Some(conference));
+    ///     let _ = my_playoffs.add_team(2 * conference + 1, "B", Some(conference));
+    /// }
+    ///
+    /// // A wild card round, then the semifinals and the championship
+    /// let rounds = my_playoffs.winners_bracket_rounds();
+    /// assert_eq!(rounds.unwrap(), 3);
+    /// ```
+    pub fn winners_bracket_rounds(&self) -> Result<usize, LeagueError> {
+        let num_teams = self.num_conferences();
+        let first_round_teams = self.first_round_winners()?;
+        Ok(Self::num_bracket_rounds(num_teams, first_round_teams))
+    }
+
     /// Helper method for generating a conference's wild card matchups
-    fn gen_conference_wild_card_round(&mut self, conference: usize, rng: &mut impl Rng) -> Result<(), String> {
+    fn gen_conference_wild_card_round(&mut self, conference: usize, rng: &mut impl Rng) -> Result<(), LeagueError> {
         // Ensure conditions are valid to generate conference wild card round
         let num_teams = self.conference_teams(conference).len();
         if num_teams < 2 {
@@ -1251,7 +1905,7 @@ impl LeagueSeasonPlayoffs {
                     "Conference {} bracket must contain at least 2 teams, got {}",
                     conference,
                     num_teams
-                )
+                ).into()
             )
         }
         if num_teams.is_power_of_two() {
@@ -1260,14 +1914,12 @@ impl LeagueSeasonPlayoffs {
                     "No wild card round for conference {} bracket with a power of 2 teams: {}",
                     conference,
                     num_teams
-                )
+                ).into()
             )
         }
         if self.conference_brackets_started() {
             return Err(
-                String::from(
-                    "Cannot re-generate wild card rounds, conference playoffs already started"
-                )
+                LeagueError::PlayoffsAlreadyStarted
             )
         }
 
@@ -1301,13 +1953,13 @@ impl LeagueSeasonPlayoffs {
                 ))?;
 
             // Create the matchup and add to the week
-            let matchup = LeagueSeasonMatchup::new(
-                home_team_id,
-                away_team_id,
-                home_team.short_name(),
-                away_team.short_name(),
-                rng
-            );
+            let matchup = LeagueSeasonMatchupBuilder::new()
+                .home_team(home_team_id)
+                .away_team(away_team_id)
+                .home_short_name(home_team.short_name())
+                .away_short_name(away_team.short_name())
+                .coin_toss(rng)
+                .build()?;
             week.matchups_mut().push(matchup);
         }
 
@@ -1317,7 +1969,7 @@ impl LeagueSeasonPlayoffs {
     }
 
     /// Helper method for generating the winners bracket's wild card matchups
-    fn gen_winners_wild_card_round(&mut self, rng: &mut impl Rng) -> Result<(), String> {
+    fn gen_winners_wild_card_round(&mut self, rng: &mut impl Rng) -> Result<(), LeagueError> {
         // Ensure conditions are valid to generate conference wild card round
         let num_teams = self.num_conferences();
         if num_teams < 2 {
@@ -1325,7 +1977,7 @@ impl LeagueSeasonPlayoffs {
                 format!(
                     "Must contain at least 2 conferences for a winners bracket, got {}",
                     num_teams
-                )
+                ).into()
             )
         }
         if num_teams.is_power_of_two() {
@@ -1333,21 +1985,17 @@ impl LeagueSeasonPlayoffs {
                 format!(
                     "No wild card round for winners bracket with a power of 2 teams: {}",
                     num_teams
-                )
+                ).into()
             )
         }
         if !self.conference_brackets_complete() {
             return Err(
-                String::from(
-                    "Cannot generate winners bracket, conference playoffs not complete"
-                )
+                LeagueError::PlayoffsNotComplete
             )
         }
         if self.winners_bracket_started() {
             return Err(
-                String::from(
-                    "Cannot re-generate winners bracket, already started"
-                )
+                LeagueError::PlayoffsAlreadyStarted
             )
         }
 
@@ -1373,13 +2021,13 @@ impl LeagueSeasonPlayoffs {
             // Get the conference champions by conference ID
             let home_conf_id = byes + i;
             let away_conf_id = num_teams - (i + 1);
-            let home_team_id = self.conference_champion(home_conf_id)
+            let home_team_id = self.conference_champion_at(home_conf_id)
                 .ok_or_else(|| format!(
                     "No conference champion found for conference {}",
                     home_conf_id
                 ))?;
             let home_team = self.teams.get(home_team_id).unwrap();
-            let away_team_id = self.conference_champion(away_conf_id)
+            let away_team_id = self.conference_champion_at(away_conf_id)
                 .ok_or_else(|| format!(
                     "No conference champion found for conference {}",
                     away_conf_id
@@ -1387,13 +2035,13 @@ impl LeagueSeasonPlayoffs {
             let away_team = self.teams.get(away_team_id).unwrap();
 
             // Create the matchup and add to the week
-            let matchup = LeagueSeasonMatchup::new(
-                home_team_id,
-                away_team_id,
-                home_team.short_name(),
-                away_team.short_name(),
-                rng
-            );
+            let matchup = LeagueSeasonMatchupBuilder::new()
+                .home_team(home_team_id)
+                .away_team(away_team_id)
+                .home_short_name(home_team.short_name())
+                .away_short_name(away_team.short_name())
+                .coin_toss(rng)
+                .build()?;
             week.matchups_mut().push(matchup);
         }
 
@@ -1403,7 +2051,7 @@ impl LeagueSeasonPlayoffs {
     }
 
     /// Helper method for generating a conference's first round matchups
-    fn gen_conference_first_round(&mut self, conference: usize, rng: &mut impl Rng) -> Result<(), String> {
+    fn gen_conference_first_round(&mut self, conference: usize, rng: &mut impl Rng) -> Result<(), LeagueError> {
         // Ensure there are enough teams (at least 2)
         let num_teams = self.conference_teams(conference).len();
         if num_teams < 2 {
@@ -1412,7 +2060,7 @@ impl LeagueSeasonPlayoffs {
                     "Conference {} bracket must contain at least 2 teams, got {}",
                     conference,
                     num_teams
-                )
+                ).into()
             )
         }
 
@@ -1421,10 +2069,7 @@ impl LeagueSeasonPlayoffs {
             // Ensure the playoffs have not yet started
             if self.conference_brackets_started() {
                 return Err(
-                    format!(
-                        "Cannot re-generate first round, conference {} playoffs already started",
-                        conference
-                    )
+                    LeagueError::PlayoffsAlreadyStarted
                 )
             }
 
@@ -1454,13 +2099,13 @@ impl LeagueSeasonPlayoffs {
                     ))?;
 
                 // Create the matchup and add to the week
-                let matchup = LeagueSeasonMatchup::new(
-                    home_team_id,
-                    away_team_id,
-                    home_team.short_name(),
-                    away_team.short_name(),
-                    rng
-                );
+                let matchup = LeagueSeasonMatchupBuilder::new()
+                    .home_team(home_team_id)
+                    .away_team(away_team_id)
+                    .home_short_name(home_team.short_name())
+                    .away_short_name(away_team.short_name())
+                    .coin_toss(rng)
+                    .build()?;
                 week.matchups_mut().push(matchup);
             }
 
@@ -1477,14 +2122,14 @@ impl LeagueSeasonPlayoffs {
                         "Expected only 1 round for conference {}, found {}",
                         conference,
                         rounds
-                    )
+                    ).into()
                 );
             }
 
             // Get the seeds of the wild card winners from the conference bracket
             let round = match self.conference_brackets.get(&conference).and_then(|b| b.last()) {
                 Some(r) => r,
-                None => return Err(String::from("Wild card round not found"))
+                None => return Err(String::from("Wild card round not found").into())
             };
             let winner_seeds: Vec<usize> = round.matchups().iter().map(
                 |x| self.team_seed(x.winner().unwrap()).unwrap()
@@ -1492,6 +2137,13 @@ impl LeagueSeasonPlayoffs {
             let num_winners = winner_seeds.len();
             let byes = self.byes(Some(conference))?;
 
+            // When reseeding, pair the bye teams and winners by seed
+            if self.reseed {
+                let mut seeds: Vec<usize> = (1..=byes).collect();
+                seeds.extend(winner_seeds);
+                return self.gen_reseeded_conference_round(conference, seeds, rng);
+            }
+
             // Populate the round with matchups
             let mut week = LeagueSeasonWeek::new();
             if num_winners >= byes {
@@ -1501,7 +2153,7 @@ impl LeagueSeasonPlayoffs {
                     let winner_index = num_winners - bye_seed;
                     let winner_seed = match winner_seeds.get(winner_index) {
                         Some(s) => *s,
-                        None => return Err(format!("No winner found at index {}", winner_index))
+                        None => return Err(format!("No winner found at index {}", winner_index).into())
                     };
                     let (home_team_id, home_team) = self.teams.get_by_seed(conference, bye_seed)
                         .ok_or_else(|| format!(
@@ -1517,13 +2169,13 @@ impl LeagueSeasonPlayoffs {
                         ))?;
 
                     // Create the matchup and add to the week
-                    let matchup = LeagueSeasonMatchup::new(
-                        home_team_id,
-                        away_team_id,
-                        home_team.short_name(),
-                        away_team.short_name(),
-                        rng
-                    );
+                    let matchup = LeagueSeasonMatchupBuilder::new()
+                        .home_team(home_team_id)
+                        .away_team(away_team_id)
+                        .home_short_name(home_team.short_name())
+                        .away_short_name(away_team.short_name())
+                        .coin_toss(rng)
+                        .build()?;
                     week.matchups_mut().push(matchup);
                 }
 
@@ -1535,12 +2187,12 @@ impl LeagueSeasonPlayoffs {
                 for i in 0..diff_winner_matchups {
                     let t1_seed = match winner_seeds.get(i) {
                         Some(s) => *s,
-                        None => return Err(format!("No winner found at index {}", i))
+                        None => return Err(format!("No winner found at index {}", i).into())
                     };
                     let t2_index = diff_winners - i + 1;
                     let t2_seed = match winner_seeds.get(diff_winners - i + 1) {
                         Some(s) => *s,
-                        None => return Err(format!("No winner found at index {}", t2_index))
+                        None => return Err(format!("No winner found at index {}", t2_index).into())
                     };
                     // Lower seed gets home field advantage
                     let (home_seed, away_seed) = if t1_seed < t2_seed {
@@ -1562,13 +2214,13 @@ impl LeagueSeasonPlayoffs {
                         ))?;
 
                     // Create the matchup and add to the week
-                    let matchup = LeagueSeasonMatchup::new(
-                        home_team_id,
-                        away_team_id,
-                        home_team.short_name(),
-                        away_team.short_name(),
-                        rng
-                    );
+                    let matchup = LeagueSeasonMatchupBuilder::new()
+                        .home_team(home_team_id)
+                        .away_team(away_team_id)
+                        .home_short_name(home_team.short_name())
+                        .away_short_name(away_team.short_name())
+                        .coin_toss(rng)
+                        .build()?;
                     week.matchups_mut().push(matchup);
                 }
             } else {
@@ -1578,7 +2230,7 @@ impl LeagueSeasonPlayoffs {
                     let winner_index = num_winners - bye_seed;
                     let winner_seed = match winner_seeds.get(winner_index) {
                         Some(s) => *s,
-                        None => return Err(format!("No winner found at index {}", winner_index))
+                        None => return Err(format!("No winner found at index {}", winner_index).into())
                     };
                     let (home_team_id, home_team) = self.teams.get_by_seed(conference, bye_seed)
                         .ok_or_else(|| format!(
@@ -1594,13 +2246,13 @@ impl LeagueSeasonPlayoffs {
                         ))?;
 
                     // Create the matchup and add to the week
-                    let matchup = LeagueSeasonMatchup::new(
-                        home_team_id,
-                        away_team_id,
-                        home_team.short_name(),
-                        away_team.short_name(),
-                        rng
-                    );
+                    let matchup = LeagueSeasonMatchupBuilder::new()
+                        .home_team(home_team_id)
+                        .away_team(away_team_id)
+                        .home_short_name(home_team.short_name())
+                        .away_short_name(away_team.short_name())
+                        .coin_toss(rng)
+                        .build()?;
                     week.matchups_mut().push(matchup);
                 }
 
@@ -1626,13 +2278,13 @@ impl LeagueSeasonPlayoffs {
                         ))?;
 
                     // Create the matchup and add to the week
-                    let matchup = LeagueSeasonMatchup::new(
-                        home_team_id,
-                        away_team_id,
-                        home_team.short_name(),
-                        away_team.short_name(),
-                        rng
-                    );
+                    let matchup = LeagueSeasonMatchupBuilder::new()
+                        .home_team(home_team_id)
+                        .away_team(away_team_id)
+                        .home_short_name(home_team.short_name())
+                        .away_short_name(away_team.short_name())
+                        .coin_toss(rng)
+                        .build()?;
                     week.matchups_mut().push(matchup);
                 }
             }
@@ -1642,7 +2294,7 @@ impl LeagueSeasonPlayoffs {
     }
 
     /// Helper method for generating the winners' bracket first round matchups
-    fn gen_winners_first_round(&mut self, rng: &mut impl Rng) -> Result<(), String> {
+    fn gen_winners_first_round(&mut self, rng: &mut impl Rng) -> Result<(), LeagueError> {
         // Ensure conditions are valid to generate conference wild card round
         let num_teams = self.num_conferences();
         if num_teams < 2 {
@@ -1650,14 +2302,12 @@ impl LeagueSeasonPlayoffs {
                 format!(
                     "Must contain at least 2 conferences for a winners bracket, got {}",
                     num_teams
-                )
+                ).into()
             )
         }
         if !self.conference_brackets_complete() {
             return Err(
-                String::from(
-                    "Cannot generate winners bracket, conference playoffs not complete"
-                )
+                LeagueError::PlayoffsNotComplete
             )
         }
 
@@ -1666,9 +2316,7 @@ impl LeagueSeasonPlayoffs {
             // Ensure the winners bracket has not yet started
             if self.winners_bracket_started() {
                 return Err(
-                    String::from(
-                        "Cannot re-generate first round, winners bracket already started"
-                    )
+                    LeagueError::PlayoffsAlreadyStarted
                 )
             }
 
@@ -1684,13 +2332,13 @@ impl LeagueSeasonPlayoffs {
                 // Get the home and away teams by conference ID
                 let home_conf_id = i;
                 let away_conf_id = num_teams - (i + 1);
-                let home_team_id = self.conference_champion(home_conf_id)
+                let home_team_id = self.conference_champion_at(home_conf_id)
                     .ok_or_else(|| format!(
                         "No conference champion found for conference {}",
                         home_conf_id
                     ))?;
                 let home_team = self.teams.get(home_team_id).unwrap();
-                let away_team_id = self.conference_champion(away_conf_id)
+                let away_team_id = self.conference_champion_at(away_conf_id)
                     .ok_or_else(|| format!(
                         "No conference champion found for conference {}",
                         away_conf_id
@@ -1698,13 +2346,13 @@ impl LeagueSeasonPlayoffs {
                 let away_team = self.teams.get(away_team_id).unwrap();
 
                 // Create the matchup and add to the week
-                let matchup = LeagueSeasonMatchup::new(
-                    home_team_id,
-                    away_team_id,
-                    home_team.short_name(),
-                    away_team.short_name(),
-                    rng
-                );
+                let matchup = LeagueSeasonMatchupBuilder::new()
+                    .home_team(home_team_id)
+                    .away_team(away_team_id)
+                    .home_short_name(home_team.short_name())
+                    .away_short_name(away_team.short_name())
+                    .coin_toss(rng)
+                    .build()?;
                 week.matchups_mut().push(matchup);
             }
 
@@ -1720,14 +2368,14 @@ impl LeagueSeasonPlayoffs {
                     format!(
                         "Expected only 1 round in winners bracket, found {}",
                         rounds
-                    )
+                    ).into()
                 );
             }
 
             // Get the seeds of the wild card winners from the conference bracket
             let round = match self.winners_bracket.last() {
                 Some(r) => r,
-                None => return Err(String::from("Wild card round not found"))
+                None => return Err(String::from("Wild card round not found").into())
             };
             let winner_ids: Vec<usize> = round.matchups().iter().map(
                 |x| x.winner().unwrap()
@@ -1744,9 +2392,9 @@ impl LeagueSeasonPlayoffs {
                     let winner_index = num_winners - (i + 1);
                     let away_team_id = match winner_ids.get(winner_index) {
                         Some(s) => *s,
-                        None => return Err(format!("No winner found at index {}", winner_index))
+                        None => return Err(format!("No winner found at index {}", winner_index).into())
                     };
-                    let home_team_id = self.conference_champion(bye_conf_id)
+                    let home_team_id = self.conference_champion_at(bye_conf_id)
                         .ok_or_else(|| format!(
                             "No conference champion found for conference {}",
                             bye_conf_id
@@ -1759,13 +2407,13 @@ impl LeagueSeasonPlayoffs {
                         ))?;
 
                     // Create the matchup and add to the week
-                    let matchup = LeagueSeasonMatchup::new(
-                        home_team_id,
-                        away_team_id,
-                        home_team.short_name(),
-                        away_team.short_name(),
-                        rng
-                    );
+                    let matchup = LeagueSeasonMatchupBuilder::new()
+                        .home_team(home_team_id)
+                        .away_team(away_team_id)
+                        .home_short_name(home_team.short_name())
+                        .away_short_name(away_team.short_name())
+                        .coin_toss(rng)
+                        .build()?;
                     week.matchups_mut().push(matchup);
                 }
 
@@ -1777,14 +2425,14 @@ impl LeagueSeasonPlayoffs {
                 for i in 0..diff_winner_matchups {
                     let t1_id = match winner_ids.get(i) {
                         Some(s) => *s,
-                        None => return Err(format!("No winner found at index {}", i))
+                        None => return Err(format!("No winner found at index {}", i).into())
                     };
                     let t1_seed = self.team_seed(t1_id)?;
                     let t1_conference = self.team_conference(t1_id)?;
                     let t2_index = diff_winners - (i + 1);
                     let t2_id = match winner_ids.get(t2_index) {
                         Some(s) => *s,
-                        None => return Err(format!("No winner found at index {}", t2_index))
+                        None => return Err(format!("No winner found at index {}", t2_index).into())
                     };
                     let t2_seed = self.team_seed(t2_id)?;
                     let t2_conference = self.team_conference(t2_id)?;
@@ -1808,13 +2456,13 @@ impl LeagueSeasonPlayoffs {
                         ))?;
 
                     // Create the matchup and add to the week
-                    let matchup = LeagueSeasonMatchup::new(
-                        home_team_id,
-                        away_team_id,
-                        home_team.short_name(),
-                        away_team.short_name(),
-                        rng
-                    );
+                    let matchup = LeagueSeasonMatchupBuilder::new()
+                        .home_team(home_team_id)
+                        .away_team(away_team_id)
+                        .home_short_name(home_team.short_name())
+                        .away_short_name(away_team.short_name())
+                        .coin_toss(rng)
+                        .build()?;
                     week.matchups_mut().push(matchup);
                 }
             } else {
@@ -1824,9 +2472,9 @@ impl LeagueSeasonPlayoffs {
                     let winner_index = num_winners - (i + 1);
                     let away_team_id = match winner_ids.get(winner_index) {
                         Some(s) => *s,
-                        None => return Err(format!("No winner found at index {}", winner_index))
+                        None => return Err(format!("No winner found at index {}", winner_index).into())
                     };
-                    let home_team_id = self.conference_champion(bye_conf_id)
+                    let home_team_id = self.conference_champion_at(bye_conf_id)
                         .ok_or_else(|| format!(
                             "No conference champion found for conference {}",
                             bye_conf_id
@@ -1839,13 +2487,13 @@ impl LeagueSeasonPlayoffs {
                         ))?;
 
                     // Create the matchup and add to the week
-                    let matchup = LeagueSeasonMatchup::new(
-                        home_team_id,
-                        away_team_id,
-                        home_team.short_name(),
-                        away_team.short_name(),
-                        rng
-                    );
+                    let matchup = LeagueSeasonMatchupBuilder::new()
+                        .home_team(home_team_id)
+                        .away_team(away_team_id)
+                        .home_short_name(home_team.short_name())
+                        .away_short_name(away_team.short_name())
+                        .coin_toss(rng)
+                        .build()?;
                     week.matchups_mut().push(matchup);
                 }
 
@@ -1856,14 +2504,14 @@ impl LeagueSeasonPlayoffs {
                 )?;
                 for i in 0..diff_winner_matchups {
                     let t1_conference = num_winners + i;
-                    let t1_id = self.conference_champion(t1_conference)
+                    let t1_id = self.conference_champion_at(t1_conference)
                         .ok_or_else(|| format!(
                             "No conference champion found for conference {}",
                             t1_conference
                         ))?;
                     let t1_seed = self.team_seed(t1_id)?;
                     let t2_conference = byes - (i + 1);
-                    let t2_id = self.conference_champion(t2_conference)
+                    let t2_id = self.conference_champion_at(t2_conference)
                         .ok_or_else(|| format!(
                             "No conference champion found for conference {}",
                             t2_conference
@@ -1889,13 +2537,13 @@ impl LeagueSeasonPlayoffs {
                         ))?;
 
                     // Create the matchup and add to the week
-                    let matchup = LeagueSeasonMatchup::new(
-                        home_team_id,
-                        away_team_id,
-                        home_team.short_name(),
-                        away_team.short_name(),
-                        rng
-                    );
+                    let matchup = LeagueSeasonMatchupBuilder::new()
+                        .home_team(home_team_id)
+                        .away_team(away_team_id)
+                        .home_short_name(home_team.short_name())
+                        .away_short_name(away_team.short_name())
+                        .coin_toss(rng)
+                        .build()?;
                     week.matchups_mut().push(matchup);
                 }
             }
@@ -1904,12 +2552,47 @@ impl LeagueSeasonPlayoffs {
         }
     }
 
+    /// Helper method for generating a reseeded conference round, pairing the
+    /// best surviving seed with the worst and the better seed at home
+    fn gen_reseeded_conference_round(&mut self, conference: usize, mut seeds: Vec<usize>, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        seeds.sort_unstable();
+        let mut week = LeagueSeasonWeek::new();
+        for i in 0..seeds.len() / 2 {
+            let home_seed = seeds[i];
+            let away_seed = seeds[seeds.len() - (i + 1)];
+            let (home_team_id, home_team) = self.teams.get_by_seed(conference, home_seed)
+                .ok_or_else(|| format!(
+                    "No team found in conference {} with seed {}",
+                    conference,
+                    home_seed
+                ))?;
+            let (away_team_id, away_team) = self.teams.get_by_seed(conference, away_seed)
+                .ok_or_else(|| format!(
+                    "No team found in conference {} with seed {}",
+                    conference,
+                    away_seed
+                ))?;
+
+            // Create the matchup and add to the week
+            let matchup = LeagueSeasonMatchupBuilder::new()
+                .home_team(home_team_id)
+                .away_team(away_team_id)
+                .home_short_name(home_team.short_name())
+                .away_short_name(away_team.short_name())
+                .coin_toss(rng)
+                .build()?;
+            week.matchups_mut().push(matchup);
+        }
+        self.conference_brackets.entry(conference).or_default().push(week);
+        Ok(())
+    }
+
     /// Helper method for generating the next round of the conference playoffs
-    fn gen_next_conference_round(&mut self, conference: usize, rng: &mut impl Rng) -> Result<(), String> {
+    fn gen_next_conference_round(&mut self, conference: usize, rng: &mut impl Rng) -> Result<(), LeagueError> {
         // Ensure there are enough teams in the conference (at least 2)
         let num_teams = self.conference_teams(conference).len();
         if num_teams < 2 {
-            return Err(format!("Playoffs must contain at least 2 teams, got {}", num_teams))
+            return Err(format!("Playoffs must contain at least 2 teams, got {}", num_teams).into())
         }
         let first_round_teams = self.first_round_teams(Some(conference))?;
         let bracket_len = self.conference_brackets.get(&conference).map(|b| b.len()).unwrap_or(0);
@@ -1928,12 +2611,7 @@ impl LeagueSeasonPlayoffs {
                 // Get seeds of winners from previous round and ensure more than one
                 let round = match self.conference_brackets.get(&conference).and_then(|b| b.last()) {
                     Some(r) => r,
-                    None => return Err(
-                        format!(
-                            "Previous round not found for conference {}",
-                            conference
-                        )
-                    )
+                    None => return Err(LeagueError::PlayoffRoundNotFound { round: 0 })
                 };
                 let winner_seeds: Vec<usize> = round.matchups().iter().map(
                     |x| self.team_seed(x.winner().unwrap()).unwrap()
@@ -1945,9 +2623,12 @@ impl LeagueSeasonPlayoffs {
                             "Cannot generate next round for conference {}, only {} teams remain",
                             conference,
                             num_winners
-                        )
+                        ).into()
                     );
                 }
+                if self.reseed {
+                    return self.gen_reseeded_conference_round(conference, winner_seeds, rng);
+                }
                 let next_round_matchups = num_winners.checked_div(2).ok_or(
                     format!(
                         "Failed to calculate next round matchups for conference {}",
@@ -1961,11 +2642,11 @@ impl LeagueSeasonPlayoffs {
                     let t1_index = i * 2;
                     let t1_seed = match winner_seeds.get(t1_index) {
                         Some(s) => *s,
-                        None => return Err(format!("No winner found at index {}", t1_index))
+                        None => return Err(format!("No winner found at index {}", t1_index).into())
                     };
                     let t2_seed = match winner_seeds.get(t1_index + 1) {
                         Some(s) => *s,
-                        None => return Err(format!("No winner found at index {}", t1_index + 1))
+                        None => return Err(format!("No winner found at index {}", t1_index + 1).into())
                     };
 
                     // Get the home and away teams (lower seed gets home field)
@@ -1988,13 +2669,13 @@ impl LeagueSeasonPlayoffs {
                         ))?;
 
                     // Create the matchup and add to the week
-                    let matchup = LeagueSeasonMatchup::new(
-                        home_team_id,
-                        away_team_id,
-                        home_team.short_name(),
-                        away_team.short_name(),
-                        rng
-                    );
+                    let matchup = LeagueSeasonMatchupBuilder::new()
+                        .home_team(home_team_id)
+                        .away_team(away_team_id)
+                        .home_short_name(home_team.short_name())
+                        .away_short_name(away_team.short_name())
+                        .coin_toss(rng)
+                        .build()?;
                     week.matchups_mut().push(matchup);
                 }
                 self.conference_brackets.entry(conference).or_default().push(week);
@@ -2004,7 +2685,7 @@ impl LeagueSeasonPlayoffs {
     }
 
     /// Helper method for generating the next round for all conference brackets
-    fn gen_next_conference_rounds(&mut self, rng: &mut impl Rng) -> Result<(), String> {
+    fn gen_next_conference_rounds(&mut self, rng: &mut impl Rng) -> Result<(), LeagueError> {
         let conference_ids: Vec<usize> = self.teams.conferences().copied().collect();
         for conference in conference_ids {
             self.gen_next_conference_round(conference, rng)?;
@@ -2013,7 +2694,7 @@ impl LeagueSeasonPlayoffs {
     }
 
     /// Helper method for generating the next round of the winners bracket
-    fn gen_next_winners_round(&mut self, rng: &mut impl Rng) -> Result<(), String> {
+    fn gen_next_winners_round(&mut self, rng: &mut impl Rng) -> Result<(), LeagueError> {
         // Ensure there are enough conference champions
         let num_teams = self.teams.num_conferences();
         if num_teams < 2 {
@@ -2021,16 +2702,14 @@ impl LeagueSeasonPlayoffs {
                 format!(
                     "Winners bracket must contain at least 2 conference champions, got {}",
                     num_teams
-                )
+                ).into()
             )
         }
 
         // Ensure the conference playoffs are complete
         if !self.conference_brackets_complete() {
             return Err(
-                String::from(
-                    "Cannot generate winners bracket: Conference playoffs not complete"
-                )
+                LeagueError::PlayoffsNotComplete
             )
         }
 
@@ -2051,11 +2730,7 @@ impl LeagueSeasonPlayoffs {
                 // Get seeds of winners from previous round and ensure more than one
                 let round = match self.winners_bracket.last() {
                     Some(r) => r,
-                    None => return Err(
-                        String::from(
-                            "Previous winners bracket round not found"
-                        )
-                    )
+                    None => return Err(LeagueError::WinnersBracketRoundNotFound { round: 0 })
                 };
                 let winner_ids: Vec<usize> = round.matchups().iter().map(
                     |x| x.winner().unwrap()
@@ -2066,7 +2741,7 @@ impl LeagueSeasonPlayoffs {
                         format!(
                             "Cannot generate next round for winners bracket, only {} teams remain",
                             num_winners
-                        )
+                        ).into()
                     );
                 }
                 let next_round_matchups = num_winners.checked_div(2).ok_or(
@@ -2081,13 +2756,13 @@ impl LeagueSeasonPlayoffs {
                     let t1_index = i * 2;
                     let t1_id = match winner_ids.get(t1_index) {
                         Some(s) => *s,
-                        None => return Err(format!("No winner found at index {}", t1_index))
+                        None => return Err(format!("No winner found at index {}", t1_index).into())
                     };
                     let t1_seed = self.team_seed(t1_id)?;
                     let t2_index = t1_index + 1;
                     let t2_id = match winner_ids.get(t2_index) {
                         Some(s) => *s,
-                        None => return Err(format!("No winner found at index {}", t2_index))
+                        None => return Err(format!("No winner found at index {}", t2_index).into())
                     };
                     let t2_seed = self.team_seed(t2_id)?;
                     // Get the home and away teams (lower seed gets home field)
@@ -2100,13 +2775,13 @@ impl LeagueSeasonPlayoffs {
                     let away_team = self.teams.get(away_team_id).unwrap();
 
                     // Create the matchup and add to the week
-                    let matchup = LeagueSeasonMatchup::new(
-                        home_team_id,
-                        away_team_id,
-                        home_team.short_name(),
-                        away_team.short_name(),
-                        rng
-                    );
+                    let matchup = LeagueSeasonMatchupBuilder::new()
+                        .home_team(home_team_id)
+                        .away_team(away_team_id)
+                        .home_short_name(home_team.short_name())
+                        .away_short_name(away_team.short_name())
+                        .coin_toss(rng)
+                        .build()?;
                     week.matchups_mut().push(matchup);
                 }
                 self.winners_bracket.push(week);
@@ -2135,25 +2810,184 @@ impl LeagueSeasonPlayoffs {
     /// let res = my_playoffs.gen_next_playoff_round(&mut rng);
     /// assert!(res.is_ok());
     /// ```
-    pub fn gen_next_playoff_round(&mut self, rng: &mut impl Rng) -> Result<(), String> {
+    pub fn gen_next_playoff_round(&mut self, rng: &mut impl Rng) -> Result<(), LeagueError> {
         // Ensure playoffs are not already complete
         if self.complete() {
-            return Err(
-                String::from(
-                    "Cannot generate next playoff round: Playoffs complete"
-                )
-            )
+            return Err(LeagueError::PlayoffsAlreadyComplete)
         }
 
         // Generate the next round of the playoffs
         if self.is_conference_playoff() {
             if self.conference_brackets_complete() {
-                self.gen_next_winners_round(rng)
+                self.gen_next_winners_round(rng)?;
             } else {
-                self.gen_next_conference_rounds(rng)
+                self.gen_next_conference_rounds(rng)?;
             }
         } else {
-            self.gen_next_conference_rounds(rng)
+            self.gen_next_conference_rounds(rng)?;
+        }
+        self.record_rest_advantages();
+        Ok(())
+    }
+
+    /// Borrow the weeks of rest after each round of the playoffs
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::LeagueSeasonPlayoffs;
+    ///
+    /// let my_playoffs = LeagueSeasonPlayoffs::new();
+    /// assert!(my_playoffs.rest_weeks_between_rounds().is_empty());
+    /// ```
+    pub fn rest_weeks_between_rounds(&self) -> &Vec<usize> {
+        &self.rest_weeks_between_rounds
+    }
+
+    /// Get the amount added to each skill level of a team with more rest
+    /// than its opponent
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::LeagueSeasonPlayoffs;
+    ///
+    /// let my_playoffs = LeagueSeasonPlayoffs::new();
+    /// assert_eq!(my_playoffs.rest_advantage(), 0);
+    /// ```
+    pub fn rest_advantage(&self) -> u32 {
+        self.rest_advantage
+    }
+
+    /// Set the weeks of rest after each round and the rest advantage
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::LeagueSeasonPlayoffs;
+    ///
+    /// let mut my_playoffs = LeagueSeasonPlayoffs::new();
+    /// my_playoffs.set_rest(vec![0, 1], 2);
+    /// assert_eq!(my_playoffs.rest_weeks_between_rounds(), &vec![0, 1]);
+    /// assert_eq!(my_playoffs.rest_advantage(), 2);
+    /// ```
+    pub fn set_rest(&mut self, rest_weeks_between_rounds: Vec<usize>, rest_advantage: u32) {
+        self.rest_weeks_between_rounds = rest_weeks_between_rounds;
+        self.rest_advantage = rest_advantage;
+    }
+
+    /// Whether the surviving teams of each conference bracket are reseeded
+    /// after every round
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::LeagueSeasonPlayoffs;
+    ///
+    /// let my_playoffs = LeagueSeasonPlayoffs::new();
+    /// assert!(!my_playoffs.reseed());
+    /// ```
+    pub fn reseed(&self) -> bool {
+        self.reseed
+    }
+
+    /// Set whether the surviving teams of each conference bracket are
+    /// reseeded after every round, so that the best remaining seed hosts the
+    /// worst. Must be set before the playoffs have started.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::LeagueSeasonPlayoffs;
+    ///
+    /// let mut my_playoffs = LeagueSeasonPlayoffs::new();
+    /// assert!(my_playoffs.set_reseed(true).is_ok());
+    /// assert!(my_playoffs.reseed());
+    /// ```
+    pub fn set_reseed(&mut self, reseed: bool) -> Result<(), LeagueError> {
+        if self.started() {
+            return Err(LeagueError::PlayoffsAlreadyStarted);
+        }
+        self.reseed = reseed;
+        Ok(())
+    }
+
+    /// Get the number of weeks after the end of the regular season at which
+    /// a playoff round is played, counting the week right after the regular
+    /// season as 0. Winners bracket rounds follow the conference brackets.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::LeagueSeasonPlayoffs;
+    ///
+    /// let mut my_playoffs = LeagueSeasonPlayoffs::new();
+    /// assert_eq!(my_playoffs.round_week(false, 2), 2);
+    ///
+    /// // Two weeks off after the second round
+    /// my_playoffs.set_rest(vec![0, 1], 0);
+    /// assert_eq!(my_playoffs.round_week(false, 1), 1);
+    /// assert_eq!(my_playoffs.round_week(false, 2), 3);
+    /// ```
+    pub fn round_week(&self, winners_bracket: bool, round: usize) -> usize {
+        let index = if winners_bracket {
+            self.conference_brackets.values().map(|b| b.len()).max().unwrap_or(0) + round
+        } else {
+            round
+        };
+        index + self.rest_weeks_between_rounds.iter().take(index).sum::<usize>()
+    }
+
+    /// Record the rest advantage of every playoff matchup whose teams had
+    /// different amounts of rest since their previous games
+    fn record_rest_advantages(&mut self) {
+        if self.rest_advantage == 0 {
+            return;
+        }
+
+        // Collect the week each matchup is played in, and the weeks in which
+        // each team played, with the regular season ending in week -1
+        let mut matchup_weeks: Vec<(bool, usize, usize, usize, usize, i64)> = Vec::new();
+        let mut team_weeks: BTreeMap<usize, Vec<i64>> = BTreeMap::new();
+        let brackets = self.conference_brackets.iter()
+            .map(|(c, rounds)| (false, *c, rounds))
+            .chain(std::iter::once((true, 0, &self.winners_bracket)));
+        for (winners, conference, rounds) in brackets {
+            for (round, week) in rounds.iter().enumerate() {
+                let round_week = self.round_week(winners, round) as i64;
+                for (index, matchup) in week.matchups().iter().enumerate() {
+                    let (home, away) = (*matchup.home_team(), *matchup.away_team());
+                    team_weeks.entry(home).or_default().push(round_week);
+                    team_weeks.entry(away).or_default().push(round_week);
+                    matchup_weeks.push((winners, conference, round, index, home, round_week));
+                }
+            }
+        }
+        let rest = |team: usize, week: i64| -> i64 {
+            let previous = team_weeks.get(&team)
+                .and_then(|weeks| weeks.iter().filter(|w| **w < week).max().copied())
+                .unwrap_or(-1);
+            week - previous
+        };
+
+        // Give the advantage to the side with more rest
+        let mut advantages = Vec::new();
+        for (winners, conference, round, index, home, week) in matchup_weeks {
+            let rounds = if winners { &self.winners_bracket } else { &self.conference_brackets[&conference] };
+            let away = *rounds[round].matchups()[index].away_team();
+            let (home_rest, away_rest) = (rest(home, week), rest(away, week));
+            let advantage = match home_rest.cmp(&away_rest) {
+                std::cmp::Ordering::Greater => Some((home, home_rest - away_rest)),
+                std::cmp::Ordering::Less => Some((away, away_rest - home_rest)),
+                std::cmp::Ordering::Equal => None
+            }.map(|(team, weeks)| MatchupRestAdvantage {
+                team,
+                rest_weeks: weeks as usize,
+                bonus: self.rest_advantage
+            });
+            advantages.push((winners, conference, round, index, advantage));
+        }
+        for (winners, conference, round, index, advantage) in advantages {
+            let rounds = if winners {
+                &mut self.winners_bracket
+            } else {
+                self.conference_brackets.get_mut(&conference).unwrap()
+            };
+            *rounds[round].matchups_mut()[index].rest_advantage_mut() = advantage;
         }
     }
 
@@ -2169,36 +3003,24 @@ impl LeagueSeasonPlayoffs {
     /// assert!(championship.is_none());
     /// ```
     pub fn championship(&self) -> Option<&LeagueSeasonMatchup> {
-        if self.is_conference_playoff() {
-            let conferences = self.teams.num_conferences();
-            if !self.winners_bracket_started() || 
-                self.winners_bracket.is_empty() ||
-                (
-                    self.winners_bracket.len() == 1 &&
-                    conferences > 2
-                ) {
+        // The championship is the one matchup of the bracket's final round,
+        // once every round through it has been generated
+        let (bracket, rounds) = if self.is_conference_playoff() {
+            if !self.winners_bracket_started() {
                 return None;
             }
-            if let Some(final_round) = self.winners_bracket.last() {
-                if final_round.matchups().len() != 1 {
-                    return None;
-                }
-                return final_round.matchups().first();
-            }
-            None
+            (&self.winners_bracket, self.winners_bracket_rounds().ok()?)
         } else {
-            let bracket = self.conference_brackets.get(&0)?;
-            if bracket.is_empty() || (bracket.len() == 1 && self.teams.len() > 2) {
-                return None;
-            }
-            if let Some(final_round) = bracket.last() {
-                if final_round.matchups().len() != 1 {
-                    return None;
-                }
-                return final_round.matchups().first();
-            }
-            None
+            (self.conference_brackets.get(&0)?, self.conference_bracket_rounds(None).ok()?)
+        };
+        if bracket.is_empty() || bracket.len() != rounds {
+            return None;
+        }
+        let final_round = bracket.last()?;
+        if final_round.matchups().len() != 1 {
+            return None;
         }
+        final_round.matchups().first()
     }
 
     /// Check if a team made it to the championship
@@ -2216,10 +3038,10 @@ impl LeagueSeasonPlayoffs {
     /// assert!(in_championship.is_ok());
     /// assert!(!in_championship.unwrap());
     /// ```
-    pub fn in_championship(&self, team_id: usize) -> Result<bool, String> {
+    pub fn in_championship(&self, team_id: usize) -> Result<bool, LeagueError> {
         // Ensure the team ID exists in the playoffs
         if !self.team_in_playoffs(team_id) {
-            return Err(format!("Team {} not in playoffs", team_id));
+            return Err(LeagueError::TeamNotInPlayoffs { id: team_id });
         }
 
         // Check if the team appeared in the championship matchup
@@ -2261,10 +3083,10 @@ impl LeagueSeasonPlayoffs {
     /// assert!(record.is_ok());
     /// assert!(record.unwrap() == LeagueTeamRecord::new());
     /// ```
-    pub fn record(&self, team_id: usize) -> Result<LeagueTeamRecord, String> {
+    pub fn record(&self, team_id: usize) -> Result<LeagueTeamRecord, LeagueError> {
         // Ensure the team ID exists in the playoffs
         if !self.team_in_playoffs(team_id) {
-            return Err(format!("Team {} not in playoffs", team_id));
+            return Err(LeagueError::TeamNotInPlayoffs { id: team_id });
         }
         let mut record = LeagueTeamRecord::new();
 
@@ -2291,4 +3113,400 @@ impl LeagueSeasonPlayoffs {
         }
         Ok(record)
     }
+
+    /// Helper method which ends a matchup with the given winner
+    fn finish_matchup(matchup: &mut LeagueSeasonMatchup, home_wins: bool) -> Result<(), LeagueError> {
+        let context = GameContextBuilder::new()
+            .home_team_short(matchup.context().home_team_short())
+            .away_team_short(matchup.context().away_team_short())
+            .quarter(4)
+            .half_seconds(0)
+            .home_score(if home_wins { 1 } else { 0 })
+            .away_score(if home_wins { 0 } else { 1 })
+            .game_over(true)
+            .build()?;
+        *matchup.context_mut() = context;
+        Ok(())
+    }
+
+    /// Helper method which plays out the rest of the bracket with the given
+    /// team winning each of its games, and reads off the team's path
+    ///
+    /// Unfinished games the team is not in are decided by `home_wins`, which
+    /// receives the home and away seeds and returns whether the home team wins.
+    pub(crate) fn simulate_path<R: Rng>(
+        &self,
+        team_id: usize,
+        rng: &mut R,
+        home_wins: impl Fn(usize, usize, &mut R) -> bool,
+    ) -> Result<Vec<PlayoffPathRound>, LeagueError> {
+        // Ensure the team ID exists in the playoffs
+        if !self.team_in_playoffs(team_id) {
+            return Err(LeagueError::TeamNotInPlayoffs { id: team_id });
+        }
+        let conference = self.team_conference(team_id)?;
+
+        // Decide every unfinished game and generate rounds until complete
+        let projected = self.project(rng, |playoffs, home, away, rng| {
+            if home == team_id {
+                Ok(true)
+            } else if away == team_id {
+                Ok(false)
+            } else {
+                Ok(home_wins(playoffs.team_seed(home)?, playoffs.team_seed(away)?, rng))
+            }
+        })?;
+
+        // Read the team's opponent in each round of its brackets
+        let mut path = Vec::new();
+        let brackets = [
+            (false, projected.conference_brackets.get(&conference), self.conference_brackets.get(&conference)),
+            (true, Some(&projected.winners_bracket), Some(&self.winners_bracket)),
+        ];
+        for (winners_bracket, rounds, existing) in brackets {
+            let rounds = match rounds {
+                Some(r) => r,
+                None => continue,
+            };
+            let num_existing = existing.map(|b| b.len()).unwrap_or(0);
+            for (r, round) in rounds.iter().enumerate() {
+                let matchup = match round.matchups().iter().find(|m| m.participated(team_id)) {
+                    Some(m) => m,
+                    None => {
+                        path.push(PlayoffPathRound { winners_bracket, round: r, opponent: PlayoffPathOpponent::Bye });
+                        continue;
+                    }
+                };
+                let opponent_id = if *matchup.home_team() == team_id {
+                    *matchup.away_team()
+                } else {
+                    *matchup.home_team()
+                };
+                let seed = projected.team_seed(opponent_id)?;
+                let opponent = if r < num_existing {
+                    PlayoffPathOpponent::Known { team_id: opponent_id, seed }
+                } else {
+                    PlayoffPathOpponent::Projected { team_id: opponent_id, seed }
+                };
+                path.push(PlayoffPathRound { winners_bracket, round: r, opponent });
+
+                // The path ends where the team has already been eliminated
+                if matchup.winner() != Some(team_id) {
+                    return Ok(path);
+                }
+            }
+        }
+        Ok(path)
+    }
+
+    /// Project the playoffs to completion on a copy, deciding every
+    /// unfinished game with `home_wins`, which receives the home and away
+    /// team IDs and returns whether the home team wins, and generating each
+    /// remaining round
+    fn project<R: Rng>(
+        &self,
+        rng: &mut R,
+        home_wins: impl Fn(&Self, usize, usize, &mut R) -> Result<bool, LeagueError>,
+    ) -> Result<LeagueSeasonPlayoffs, LeagueError> {
+        let mut projected = self.clone();
+        while !projected.complete() {
+            let mut decisions: Vec<(Option<usize>, usize, usize, bool)> = Vec::new();
+            let brackets = projected.conference_brackets.iter()
+                .map(|(c, b)| (Some(*c), b))
+                .chain(std::iter::once((None, &projected.winners_bracket)));
+            for (bracket_id, bracket) in brackets {
+                for (r, round) in bracket.iter().enumerate() {
+                    for (m, matchup) in round.matchups().iter().enumerate() {
+                        if matchup.context().game_over() {
+                            continue;
+                        }
+                        let decision = home_wins(&projected, *matchup.home_team(), *matchup.away_team(), rng)?;
+                        decisions.push((bracket_id, r, m, decision));
+                    }
+                }
+            }
+            for (bracket_id, r, m, decision) in decisions {
+                let bracket = match bracket_id {
+                    Some(c) => projected.conference_brackets.get_mut(&c)
+                        .ok_or(format!("Conference bracket {} not found", c))?,
+                    None => &mut projected.winners_bracket,
+                };
+                Self::finish_matchup(&mut bracket[r].matchups_mut()[m], decision)?;
+            }
+            if !projected.complete() {
+                projected.gen_next_playoff_round(rng)?;
+            }
+        }
+        Ok(projected)
+    }
+
+    /// Get a team's path to the championship
+    ///
+    /// Returns the team's opponent in each round it has left to play, assuming
+    /// it keeps winning. Opponents in rounds which already exist are known,
+    /// byes are marked as such, and opponents in rounds not yet generated are
+    /// projected by assuming the better seed wins every other game. The path
+    /// ends early at the round in which the team was eliminated, if any.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::{LeagueSeasonPlayoffs, PlayoffPathOpponent};
+    ///
+    /// // Create a 6-team playoff, seeded by team ID
+    /// let mut my_playoffs = LeagueSeasonPlayoffs::new();
+    /// for (id, name) in ["A", "B", "C", "D", "E", "F"].iter().enumerate() {
+    ///     my_playoffs.add_team(id, name, None).unwrap();
+    /// }
+    ///
+    /// // The top seed has a bye, then likely faces the 4 and 2 seeds
+    /// let path = my_playoffs.path_to_championship(0).unwrap();
+    /// let opponents: Vec<&PlayoffPathOpponent> = path.iter().map(|r| r.opponent()).collect();
+    /// assert_eq!(opponents, vec![
+    ///     &PlayoffPathOpponent::Bye,
+    ///     &PlayoffPathOpponent::Projected { team_id: 3, seed: 4 },
+    ///     &PlayoffPathOpponent::Projected { team_id: 1, seed: 2 },
+    /// ]);
+    /// ```
+    pub fn path_to_championship(&self, team_id: usize) -> Result<Vec<PlayoffPathRound>, LeagueError> {
+        // Projected games are decided by seed alone, so a fixed RNG only
+        // feeds the placeholder matchups and keeps the path deterministic
+        let mut rng = SmallRng::seed_from_u64(0);
+        self.simulate_path(team_id, &mut rng, |home_seed, away_seed, _| home_seed <= away_seed)
+    }
+
+    /// Get the playoff bracket as a tree, for drawing it
+    ///
+    /// Every game of every round appears as a node, including rounds not
+    /// yet generated, whose games are pending. Each node lists the nodes
+    /// whose winners feed into it, and a team without a game in a round has
+    /// a bye node there. The feeds into rounds not yet generated follow the
+    /// bracket's pairing rules; when reseeding, pairings beyond the next
+    /// round depend on results, so they assume the better seed wins every
+    /// game still to be played.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::{LeagueSeasonPlayoffs, PlayoffBracketGame};
+    ///
+    /// // Create a 6-team playoff, seeded by team ID
+    /// let mut my_playoffs = LeagueSeasonPlayoffs::new();
+    /// for (id, name) in ["A", "B", "C", "D", "E", "F"].iter().enumerate() {
+    ///     my_playoffs.add_team(id, name, None).unwrap();
+    /// }
+    ///
+    /// // Two wild card games and two byes, two semifinals and a final
+    /// let tree = my_playoffs.bracket_tree().unwrap();
+    /// assert_eq!(tree.nodes.len(), 7);
+    /// assert_eq!(tree.round(Some(0), 0)[2].game, PlayoffBracketGame::Bye { team_id: 0, seed: 1 });
+    /// let root = &tree.nodes[tree.root.unwrap()];
+    /// assert_eq!(root.game, PlayoffBracketGame::Pending);
+    /// assert_eq!(root.children.len(), 2);
+    /// ```
+    pub fn bracket_tree(&self) -> Result<PlayoffBracketTree, LeagueError> {
+        if self.teams.is_empty() {
+            return Ok(PlayoffBracketTree::default());
+        }
+
+        // Project the remaining rounds with the better seed winning
+        let mut rng = SmallRng::seed_from_u64(0);
+        let projected = self.project(&mut rng, |playoffs, home, away, _| {
+            Ok(playoffs.team_seed(home)? <= playoffs.team_seed(away)?)
+        })?;
+
+        // Walk each bracket round by round, tracking the node each surviving
+        // team advanced from
+        let mut nodes: Vec<PlayoffBracketNode> = Vec::new();
+        let mut champions: Vec<(usize, Option<usize>)> = Vec::new();
+        let brackets = projected.conference_brackets.iter()
+            .map(|(c, b)| (Some(*c), b, self.conference_brackets.get(c)))
+            .chain(std::iter::once((None, &projected.winners_bracket, Some(&self.winners_bracket))));
+        for (conference, rounds, existing) in brackets {
+            let mut alive: Vec<(usize, Option<usize>)> = match conference {
+                Some(c) => self.conference_teams(c).iter().map(|(id, _)| (*id, None)).collect(),
+                None => std::mem::take(&mut champions),
+            };
+            for (r, round) in rounds.iter().enumerate() {
+                let generated = existing.and_then(|b| b.get(r));
+                let mut next: Vec<(usize, Option<usize>)> = Vec::new();
+                for (m, matchup) in round.matchups().iter().enumerate() {
+                    let children = [*matchup.home_team(), *matchup.away_team()].iter()
+                        .filter_map(|id| alive.iter().find(|(team, _)| team == id).and_then(|(_, node)| *node))
+                        .collect();
+                    let game = match generated.and_then(|w| w.matchups().get(m)) {
+                        Some(matchup) => PlayoffBracketGame::Matchup(Box::new(matchup.clone())),
+                        None => PlayoffBracketGame::Pending,
+                    };
+                    let winner = matchup.winner()
+                        .ok_or_else(|| format!("No winner projected for playoff round {} matchup {}", r, m))?;
+                    next.push((winner, Some(nodes.len())));
+                    nodes.push(PlayoffBracketNode { conference, round: r, index: Some(m), game, children });
+                }
+                for (team_id, node) in alive.iter() {
+                    if round.matchups().iter().any(|matchup| matchup.participated(*team_id)) {
+                        continue;
+                    }
+                    next.push((*team_id, Some(nodes.len())));
+                    nodes.push(PlayoffBracketNode {
+                        conference,
+                        round: r,
+                        index: None,
+                        game: PlayoffBracketGame::Bye { team_id: *team_id, seed: self.team_seed(*team_id)? },
+                        children: node.iter().copied().collect(),
+                    });
+                }
+                alive = next;
+            }
+            if conference.is_some() {
+                champions.extend(alive);
+            }
+        }
+        let root = nodes.len().checked_sub(1);
+        Ok(PlayoffBracketTree { nodes, root })
+    }
+
+    /// Preview the playoff bracket which would be generated from the
+    /// season's current standings, without modifying the season
+    ///
+    /// Uses the same seeding and first-round generation as
+    /// `LeagueSeason::generate_playoffs()`, so once the regular season is
+    /// complete the preview matches the generated bracket exactly. The
+    /// preview may be taken at any point in the regular season.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::{LeagueSeason, LeagueSeasonScheduleOptions, LeagueSeasonPlayoffOptions};
+    /// use fbsim_core::league::season::playoffs::LeagueSeasonPlayoffs;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..6 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    ///
+    /// // If the playoffs started today, the top 2 of 6 seeds would have byes
+    /// let options = LeagueSeasonPlayoffOptions::new().num_playoff_teams(6);
+    /// let view = LeagueSeasonPlayoffs::preview_from_standings(&my_league_season, options).unwrap();
+    /// assert_eq!(view.teams.len(), 6);
+    /// assert_eq!(view.byes().len(), 2);
+    /// assert_eq!(view.first_round.len(), 2);
+    /// ```
+    pub fn preview_from_standings(season: &LeagueSeason, options: LeagueSeasonPlayoffOptions) -> Result<PlayoffBracketView, LeagueError> {
+        if *season.championship_mode() == ChampionshipMode::BestRecord {
+            return Err(LeagueError::ChampionDecidedByBestRecord);
+        }
+
+        // Seed and generate the first round exactly as playoff generation
+        // would, using a fixed RNG since it only decides opening kickoffs
+        let mut playoffs = season.seed_playoffs(&options)?;
+        let mut rng = SmallRng::seed_from_u64(0);
+        playoffs.gen_next_playoff_round(&mut rng)?;
+
+        // Collect the first-round pairings for each conference bracket
+        let mut first_round = Vec::new();
+        let mut playing: HashSet<usize> = HashSet::new();
+        for (conference, bracket) in playoffs.conference_brackets().iter() {
+            let week = match bracket.first() {
+                Some(w) => w,
+                None => continue
+            };
+            for matchup in week.matchups().iter() {
+                let home_team = *matchup.home_team();
+                let away_team = *matchup.away_team();
+                playing.insert(home_team);
+                playing.insert(away_team);
+                first_round.push(
+                    PlayoffPreviewMatchup {
+                        conference: *conference,
+                        home_team,
+                        home_seed: playoffs.team_seed(home_team)?,
+                        away_team,
+                        away_seed: playoffs.team_seed(away_team)?,
+                    }
+                );
+            }
+        }
+
+        // Collect the seeded teams, marking those without a first-round game
+        let mut teams = Vec::new();
+        let conferences: Vec<usize> = playoffs.teams().conferences().copied().collect();
+        for conference in conferences {
+            for (team_id, team) in playoffs.teams().conference_teams_by_seed(conference) {
+                teams.push(
+                    PlayoffPreviewTeam {
+                        conference,
+                        seed: team.seed(),
+                        team_id,
+                        short_name: team.short_name().to_string(),
+                        bye: !playing.contains(&team_id),
+                    }
+                );
+            }
+        }
+        Ok(PlayoffBracketView { teams, first_round })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_champion_only_after_final_winners_bracket_game() {
+        let mut rng = SmallRng::seed_from_u64(1505);
+        for num_conferences in 2..=9 {
+            // Conferences of 2 to 4 teams, some with a wild card round
+            let mut playoffs = LeagueSeasonPlayoffs::new();
+            for conference in 0..num_conferences {
+                for seed in 0..(2 + num_conferences % 3) {
+                    playoffs.add_team(10 * conference + seed, "TM", Some(conference)).unwrap();
+                }
+            }
+            let rounds = num_conferences.next_power_of_two().trailing_zeros() as usize;
+            assert_eq!(playoffs.winners_bracket_rounds().unwrap(), rounds);
+
+            // Play every round, the home team winning each game. The final
+            // is only reached in the last winners bracket round.
+            let mut winners_games = 0;
+            while !playoffs.complete() {
+                assert!(playoffs.champion().is_none());
+                playoffs.gen_next_playoff_round(&mut rng).unwrap();
+                for conference in 0..num_conferences {
+                    let bracket = playoffs.conference_bracket_mut(conference).unwrap();
+                    for matchup in bracket.iter_mut().flat_map(|round| round.matchups_mut().iter_mut()) {
+                        if !matchup.context().game_over() {
+                            matchup.enter_score(21, 14).unwrap();
+                        }
+                    }
+                }
+                for matchup in playoffs.winners_bracket_mut().iter_mut().flat_map(|round| round.matchups_mut().iter_mut()) {
+                    if !matchup.context().game_over() {
+                        matchup.enter_score(21, 14).unwrap();
+                        winners_games += 1;
+                    }
+                }
+                let final_played = playoffs.winners_bracket().len() == rounds;
+                assert_eq!(playoffs.championship().is_some(), final_played, "{} conferences", num_conferences);
+                assert_eq!(playoffs.winners_bracket_complete(), final_played, "{} conferences", num_conferences);
+                assert_eq!(playoffs.complete(), final_played, "{} conferences", num_conferences);
+            }
+
+            // The champion is crowned by the last of the n - 1 winners
+            // bracket games, in the last of its rounds
+            assert_eq!(winners_games, num_conferences - 1);
+            assert_eq!(playoffs.winners_bracket().len(), rounds);
+            assert!(playoffs.winners_bracket_complete());
+            let championship = playoffs.championship().unwrap();
+            assert_eq!(playoffs.champion(), championship.winner());
+            assert!(playoffs.winners_bracket().last().unwrap().matchups().len() == 1);
+
+            // A saved winners bracket deeper than that is rejected
+            let json = serde_json::to_string(&playoffs).unwrap();
+            assert!(serde_json::from_str::<LeagueSeasonPlayoffs>(&json).is_ok());
+            playoffs.winners_bracket_mut().push(LeagueSeasonWeek::new());
+            let json = serde_json::to_string(&playoffs).unwrap();
+            assert!(serde_json::from_str::<LeagueSeasonPlayoffs>(&json).is_err());
+        }
+    }
 }
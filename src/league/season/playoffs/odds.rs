@@ -0,0 +1,574 @@
+#![doc = include_str!("../../../../docs/league/season/playoffs/odds.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use rand::Rng;
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashSet};
+
+use crate::league::error::LeagueError;
+use crate::game::score::{FinalScoreSimulator, PreparedMatchup};
+use crate::league::matchup::LeagueTeamRecord;
+use crate::league::season::{sort_standings, LeagueSeason};
+use crate::league::season::playoffs::picture::PlayoffPictureOptions;
+use crate::league::season::tiebreaker::Tiebreakers;
+use crate::team::DEFAULT_TEAM_NAME;
+
+/// # `PlayoffOddsEntry` struct
+///
+/// A team's probabilities of reaching the postseason, estimated by
+/// simulating the rest of the regular season
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct PlayoffOddsEntry {
+    team_id: usize,
+    team_name: String,
+    make_playoffs: f64,
+    win_division: f64,
+    top_seed: f64,
+}
+
+impl Default for PlayoffOddsEntry {
+    /// Default constructor for the `PlayoffOddsEntry` struct, a team with no
+    /// chance of anything
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::odds::PlayoffOddsEntry;
+    ///
+    /// let my_entry = PlayoffOddsEntry::default();
+    /// assert_eq!(my_entry.make_playoffs(), 0.0);
+    /// ```
+    fn default() -> Self {
+        PlayoffOddsEntry {
+            team_id: 0,
+            team_name: String::from(DEFAULT_TEAM_NAME),
+            make_playoffs: 0.0,
+            win_division: 0.0,
+            top_seed: 0.0,
+        }
+    }
+}
+
+impl PlayoffOddsEntry {
+    /// Constructor for the `PlayoffOddsEntry` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::odds::PlayoffOddsEntry;
+    ///
+    /// let my_entry = PlayoffOddsEntry::new();
+    /// ```
+    pub fn new() -> PlayoffOddsEntry {
+        PlayoffOddsEntry::default()
+    }
+
+    /// Get the team's ID
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::odds::PlayoffOddsEntry;
+    ///
+    /// let my_entry = PlayoffOddsEntry::new();
+    /// assert_eq!(my_entry.team_id(), 0);
+    /// ```
+    pub fn team_id(&self) -> usize {
+        self.team_id
+    }
+
+    /// Get the team's name
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::odds::PlayoffOddsEntry;
+    /// use fbsim_core::team::DEFAULT_TEAM_NAME;
+    ///
+    /// let my_entry = PlayoffOddsEntry::new();
+    /// assert_eq!(my_entry.team_name(), DEFAULT_TEAM_NAME);
+    /// ```
+    pub fn team_name(&self) -> &str {
+        &self.team_name
+    }
+
+    /// Get the probability that the team makes the playoffs
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::odds::PlayoffOddsEntry;
+    ///
+    /// let my_entry = PlayoffOddsEntry::new();
+    /// assert_eq!(my_entry.make_playoffs(), 0.0);
+    /// ```
+    pub fn make_playoffs(&self) -> f64 {
+        self.make_playoffs
+    }
+
+    /// Get the probability that the team wins its division
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::odds::PlayoffOddsEntry;
+    ///
+    /// let my_entry = PlayoffOddsEntry::new();
+    /// assert_eq!(my_entry.win_division(), 0.0);
+    /// ```
+    pub fn win_division(&self) -> f64 {
+        self.win_division
+    }
+
+    /// Get the probability that the team earns the top seed of its bracket
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::odds::PlayoffOddsEntry;
+    ///
+    /// let my_entry = PlayoffOddsEntry::new();
+    /// assert_eq!(my_entry.top_seed(), 0.0);
+    /// ```
+    pub fn top_seed(&self) -> f64 {
+        self.top_seed
+    }
+}
+
+/// # `PlayoffOdds` struct
+///
+/// Each team's probabilities of making the playoffs, winning its division,
+/// and earning the top seed, keyed by team ID
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct PlayoffOdds {
+    num_playoff_teams: usize,
+    iterations: usize,
+    entries: BTreeMap<usize, PlayoffOddsEntry>,
+}
+
+/// Internal helper struct for a remaining game prepared for repeated
+/// simulation
+struct RemainingGame {
+    home_team: usize,
+    away_team: usize,
+    /// Positions of the home and away teams in the base standings
+    home_index: usize,
+    away_index: usize,
+    matchup: PreparedMatchup,
+}
+
+/// Filter sorted standings to a group of teams and break ties again among
+/// the teams which remain
+fn filter_standings(standings: &[(usize, LeagueTeamRecord)], teams: &HashSet<usize>, tiebreakers: &Tiebreakers) -> Vec<(usize, LeagueTeamRecord)> {
+    let mut filtered: Vec<(usize, LeagueTeamRecord)> = standings
+        .iter()
+        .filter(|(id, _)| teams.contains(id))
+        .cloned()
+        .collect();
+    tiebreakers.break_ties(&mut filtered);
+    filtered
+}
+
+impl PlayoffOdds {
+    /// Simulate the remaining regular season games of a season many times
+    /// and count how often each team makes the playoffs, wins its division,
+    /// and earns the top seed. The season itself is not modified.
+    pub(crate) fn simulate(
+        season: &LeagueSeason,
+        num_playoff_teams: usize,
+        iterations: usize,
+        options: Option<PlayoffPictureOptions>,
+        rng: &mut impl Rng,
+    ) -> Result<PlayoffOdds, LeagueError> {
+        let opts = options.unwrap_or_default();
+        let use_conferences = match opts.by_conference {
+            Some(v) => v,
+            None => season.populated_conferences().len() > 1,
+        };
+
+        // Validate parameters the same way as the playoff picture
+        if season.weeks().is_empty() {
+            return Err(LeagueError::NoSchedule);
+        }
+        if iterations == 0 {
+            return Err(LeagueError::ZeroTrials);
+        }
+        let groups: Vec<HashSet<usize>> = if use_conferences {
+            let populated = season.populated_conferences();
+            if populated.is_empty() {
+                return Err(LeagueError::NoConferences);
+            }
+            let min_conference_size = populated
+                .iter()
+                .map(|c| season.conferences()[*c].num_teams())
+                .min()
+                .unwrap_or(0);
+            if num_playoff_teams == 0 || num_playoff_teams > min_conference_size {
+                return Err(LeagueError::PlayoffTeamsOutOfRange {
+                    num_playoff_teams,
+                    min: 1,
+                    max: min_conference_size
+                });
+            }
+            populated
+                .iter()
+                .map(|c| season.conferences()[*c].all_teams().into_iter().collect())
+                .collect()
+        } else {
+            if num_playoff_teams == 0 || num_playoff_teams > season.teams().len() {
+                return Err(LeagueError::PlayoffTeamsOutOfRange {
+                    num_playoff_teams,
+                    min: 1,
+                    max: season.teams().len()
+                });
+            }
+            vec![season.teams().keys().copied().collect()]
+        };
+        let divisions: Vec<HashSet<usize>> = season.conferences()
+            .iter()
+            .flat_map(|c| c.divisions().iter())
+            .map(|d| d.teams().iter().copied().collect::<HashSet<usize>>())
+            .filter(|d| !d.is_empty())
+            .collect();
+
+        // Compute the standings and tiebreakers of the games already played,
+        // and prepare each remaining game once so that simulating it only
+        // samples its score distributions
+        let base_standings = season.standings();
+        let base_tiebreakers = Tiebreakers::new(season);
+        let positions: BTreeMap<usize, usize> = base_standings
+            .iter()
+            .enumerate()
+            .map(|(i, (id, _))| (*id, i))
+            .collect();
+        let simulator = FinalScoreSimulator::new();
+        let mut remaining: Vec<RemainingGame> = Vec::new();
+        for week in season.weeks().iter() {
+            for matchup in week.matchups().iter() {
+                if matchup.context().game_over() {
+                    continue;
+                }
+                let (home_id, away_id) = (*matchup.home_team(), *matchup.away_team());
+                let (home, away, home_index, away_index) = match (
+                    season.team(home_id), season.team(away_id), positions.get(&home_id), positions.get(&away_id)
+                ) {
+                    (Some(home), Some(away), Some(h), Some(a)) => (home, away, *h, *a),
+                    (Some(_), _, Some(_), _) => return Err(LeagueError::TeamNotFound { id: away_id }),
+                    _ => return Err(LeagueError::TeamNotFound { id: home_id }),
+                };
+                remaining.push(RemainingGame {
+                    home_team: home_id,
+                    away_team: away_id,
+                    home_index,
+                    away_index,
+                    matchup: simulator.prepare(home, away)?,
+                });
+            }
+        }
+
+        // Play out the rest of the season once per iteration, seeding each
+        // group of teams as the playoff picture would
+        let mut playoffs: BTreeMap<usize, usize> = BTreeMap::new();
+        let mut division_titles: BTreeMap<usize, usize> = BTreeMap::new();
+        let mut top_seeds: BTreeMap<usize, usize> = BTreeMap::new();
+        let no_division_winners: Vec<usize> = Vec::new();
+        for _ in 0..iterations {
+            let mut standings = base_standings.clone();
+            let mut tiebreakers = base_tiebreakers.clone();
+            for game in remaining.iter() {
+                let (home_score, away_score) = game.matchup.fast_sim(rng);
+                match home_score.cmp(&away_score) {
+                    Ordering::Greater => {
+                        standings[game.home_index].1.increment_wins(1);
+                        standings[game.away_index].1.increment_losses(1);
+                    },
+                    Ordering::Less => {
+                        standings[game.home_index].1.increment_losses(1);
+                        standings[game.away_index].1.increment_wins(1);
+                    },
+                    Ordering::Equal => {
+                        standings[game.home_index].1.increment_ties(1);
+                        standings[game.away_index].1.increment_ties(1);
+                    },
+                }
+                tiebreakers.add_game(game.home_team, game.away_team, home_score as u32, away_score as u32);
+            }
+            sort_standings(&mut standings);
+            tiebreakers.summarize();
+
+            // Crown each division's winner
+            let mut division_winners: Vec<usize> = Vec::new();
+            for division in divisions.iter() {
+                let div_standings = filter_standings(&standings, division, &tiebreakers);
+                if let Some((winner_id, _)) = div_standings.first() {
+                    division_winners.push(*winner_id);
+                    *division_titles.entry(*winner_id).or_insert(0) += 1;
+                }
+            }
+
+            // Seed each conference, or the whole league
+            let guaranteed = if use_conferences && opts.division_winners_guaranteed {
+                &division_winners
+            } else {
+                &no_division_winners
+            };
+            for group in groups.iter() {
+                let group_standings = filter_standings(&standings, group, &tiebreakers);
+                let seeded = opts.seeding_rule.seed(&group_standings, guaranteed, num_playoff_teams);
+                for (i, team_id) in seeded.iter().enumerate() {
+                    *playoffs.entry(*team_id).or_insert(0) += 1;
+                    if i == 0 {
+                        *top_seeds.entry(*team_id).or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+
+        // Convert the counts to probabilities
+        let probability = |counts: &BTreeMap<usize, usize>, team_id: usize| {
+            *counts.get(&team_id).unwrap_or(&0) as f64 / iterations as f64
+        };
+        let entries = season.teams()
+            .iter()
+            .map(|(team_id, team)| {
+                (*team_id, PlayoffOddsEntry {
+                    team_id: *team_id,
+                    team_name: team.name().to_string(),
+                    make_playoffs: probability(&playoffs, *team_id),
+                    win_division: probability(&division_titles, *team_id),
+                    top_seed: probability(&top_seeds, *team_id),
+                })
+            })
+            .collect();
+        Ok(PlayoffOdds {
+            num_playoff_teams,
+            iterations,
+            entries,
+        })
+    }
+
+    /// Get the number of playoff spots, per conference in a conference-based
+    /// simulation
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPicture;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    ///
+    /// let odds = PlayoffPicture::simulate_odds(&my_league_season, 2, 10, None, &mut rng).unwrap();
+    /// assert_eq!(odds.num_playoff_teams(), 2);
+    /// ```
+    pub fn num_playoff_teams(&self) -> usize {
+        self.num_playoff_teams
+    }
+
+    /// Get the number of times the rest of the season was simulated
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPicture;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    ///
+    /// let odds = PlayoffPicture::simulate_odds(&my_league_season, 2, 10, None, &mut rng).unwrap();
+    /// assert_eq!(odds.iterations(), 10);
+    /// ```
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Borrow every team's odds, keyed by team ID
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPicture;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    ///
+    /// // Exactly two teams make the playoffs in every simulated season
+    /// let odds = PlayoffPicture::simulate_odds(&my_league_season, 2, 100, None, &mut rng).unwrap();
+    /// let total: f64 = odds.entries().values().map(|e| e.make_playoffs()).sum();
+    /// assert!((total - 2.0).abs() < 1e-9);
+    /// ```
+    pub fn entries(&self) -> &BTreeMap<usize, PlayoffOddsEntry> {
+        &self.entries
+    }
+
+    /// Borrow a team's odds
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPicture;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    ///
+    /// let odds = PlayoffPicture::simulate_odds(&my_league_season, 2, 10, None, &mut rng).unwrap();
+    /// assert!(odds.team_odds(3).is_some());
+    /// assert!(odds.team_odds(4).is_none());
+    /// ```
+    pub fn team_odds(&self, team_id: usize) -> Option<&PlayoffOddsEntry> {
+        self.entries.get(&team_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    use crate::league::season::LeagueSeasonScheduleOptions;
+    use crate::league::season::conference::{LeagueConference, LeagueDivision};
+    use crate::league::season::playoffs::picture::PlayoffPicture;
+    use crate::team::FootballTeam;
+
+    /// A season of 2 conferences of 2 divisions of 4 teams each, with one
+    /// much stronger team per division
+    fn conference_season(rng: &mut SmallRng) -> LeagueSeason {
+        let mut season = LeagueSeason::with_year(2026);
+        for (c, name) in ["AFC", "NFC"].iter().enumerate() {
+            let mut conference = LeagueConference::with_name(name);
+            for d in 0..2 {
+                let mut division = LeagueDivision::with_name(&format!("Div {}", d));
+                for t in 0..4 {
+                    let id = c * 8 + d * 4 + t;
+                    let overall = if t == 0 { 95 } else { 40 };
+                    let team = FootballTeam::from_overalls(&format!("Team {}", id), "TM", overall, overall).unwrap();
+                    season.add_team(id, team).unwrap();
+                    division.add_team(id).unwrap();
+                }
+                conference.add_division(division).unwrap();
+            }
+            season.add_conference(conference).unwrap();
+        }
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), rng).unwrap();
+        season
+    }
+
+    #[test]
+    fn test_simulate_odds_respects_conferences() {
+        let mut rng = SmallRng::seed_from_u64(1524);
+        let season = conference_season(&mut rng);
+        let before = season.clone();
+        let odds = PlayoffPicture::simulate_odds(&season, 3, 500, None, &mut rng).unwrap();
+
+        // The season is not modified
+        assert_eq!(season, before);
+
+        // Each conference fills 3 spots, wins 2 divisions, and has 1 top seed
+        // in every simulated season
+        for conference in 0..2 {
+            let teams: Vec<&PlayoffOddsEntry> = odds.entries().values()
+                .filter(|e| e.team_id() / 8 == conference)
+                .collect();
+            let sum = |f: fn(&PlayoffOddsEntry) -> f64| teams.iter().map(|e| f(e)).sum::<f64>();
+            assert!((sum(PlayoffOddsEntry::make_playoffs) - 3.0).abs() < 1e-9);
+            assert!((sum(PlayoffOddsEntry::win_division) - 2.0).abs() < 1e-9);
+            assert!((sum(PlayoffOddsEntry::top_seed) - 1.0).abs() < 1e-9);
+        }
+
+        // The strong teams are heavy favorites, and no team's top seed
+        // chance exceeds its playoff chance
+        for id in [0, 4, 8, 12] {
+            let entry = odds.team_odds(id).unwrap();
+            assert!(entry.make_playoffs() > 0.9, "team {}: {:?}", id, entry);
+            assert!(entry.win_division() > 0.8, "team {}: {:?}", id, entry);
+        }
+        for entry in odds.entries().values() {
+            assert!(entry.top_seed() <= entry.make_playoffs());
+        }
+    }
+
+    #[test]
+    fn test_simulate_odds_complete_season_is_certain() {
+        let mut rng = SmallRng::seed_from_u64(1524);
+        let mut season = conference_season(&mut rng);
+        season.sim_regular_season(&mut rng).unwrap();
+
+        // With nothing left to play, the odds match the playoff picture
+        let odds = PlayoffPicture::simulate_odds(&season, 3, 20, None, &mut rng).unwrap();
+        let picture = PlayoffPicture::from_season(&season, 3, None).unwrap();
+        for entry in picture.entries() {
+            let expected = if entry.is_clinched() { 1.0 } else { 0.0 };
+            assert_eq!(odds.team_odds(entry.team_id()).unwrap().make_playoffs(), expected);
+        }
+    }
+
+    #[test]
+    fn test_summarized_tiebreakers_match() {
+        let mut rng = SmallRng::seed_from_u64(1524);
+        let mut season = conference_season(&mut rng);
+        season.sim_regular_season(&mut rng).unwrap();
+
+        // Summarizing the results does not change how any group is ordered
+        let standings = season.standings();
+        let tiebreakers = Tiebreakers::new(&season);
+        let mut summarized = tiebreakers.clone();
+        summarized.summarize();
+        let mut groups: Vec<HashSet<usize>> = vec![season.teams().keys().copied().collect()];
+        for conference in season.conferences().iter() {
+            groups.push(conference.all_teams().into_iter().collect());
+            groups.extend(conference.divisions().iter().map(|d| d.teams().iter().copied().collect()));
+        }
+        for group in groups.iter() {
+            assert_eq!(
+                filter_standings(&standings, group, &tiebreakers),
+                filter_standings(&standings, group, &summarized)
+            );
+        }
+    }
+
+    #[test]
+    fn test_simulate_odds_invalid_parameters() {
+        let mut rng = SmallRng::seed_from_u64(1524);
+        let season = conference_season(&mut rng);
+        assert_eq!(
+            PlayoffPicture::simulate_odds(&season, 3, 0, None, &mut rng).unwrap_err(),
+            LeagueError::ZeroTrials
+        );
+        assert!(matches!(
+            PlayoffPicture::simulate_odds(&season, 9, 10, None, &mut rng).unwrap_err(),
+            LeagueError::PlayoffTeamsOutOfRange { num_playoff_teams: 9, .. }
+        ));
+        assert_eq!(
+            PlayoffPicture::simulate_odds(&LeagueSeason::with_year(2026), 2, 10, None, &mut rng).unwrap_err(),
+            LeagueError::NoSchedule
+        );
+    }
+}
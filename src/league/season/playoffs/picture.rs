@@ -1,14 +1,20 @@
 #![doc = include_str!("../../../../docs/league/season/playoffs/picture.md")]
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 
 #[cfg(feature = "rocket_okapi")]
 use rocket_okapi::okapi::schemars;
 #[cfg(feature = "rocket_okapi")]
 use rocket_okapi::okapi::schemars::JsonSchema;
-use serde::{Serialize, Deserialize};
+use rand::Rng;
+use serde::{Serialize, Deserialize, Deserializer};
 
+use crate::league::error::LeagueError;
+use crate::game::score::FinalScoreSimulator;
 use crate::league::matchup::LeagueTeamRecord;
 use crate::league::season::LeagueSeason;
+use crate::league::season::playoffs::odds::PlayoffOdds;
+use crate::league::season::playoffs::{LeagueSeasonPlayoffs, PlayoffPathOpponent, PlayoffTeam, SeedingRule};
+use crate::team::DEFAULT_TEAM_NAME;
 
 /// # `PlayoffStatus` enum
 ///
@@ -30,13 +36,50 @@ pub enum PlayoffStatus {
     Eliminated,
 }
 
+/// # `ProjectedRecord` struct
+///
+/// A team's projected final record: its current record plus its expected
+/// wins, losses, and ties in its remaining games
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct ProjectedRecord {
+    /// Projected number of wins
+    pub wins: f64,
+    /// Projected number of losses
+    pub losses: f64,
+    /// Projected number of ties
+    pub ties: f64,
+}
+
+impl ProjectedRecord {
+    /// Get the projected win percentage, treating ties as half a win
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::picture::ProjectedRecord;
+    ///
+    /// let my_record = ProjectedRecord { wins: 2.5, losses: 1.0, ties: 0.5 };
+    /// assert_eq!(my_record.win_pct(), 0.6875);
+    /// ```
+    pub fn win_pct(&self) -> f64 {
+        let games = self.wins + self.losses + self.ties;
+        if games > 0.0 {
+            (self.wins + 0.5 * self.ties) / games
+        } else {
+            0.0
+        }
+    }
+}
+
 /// # `PlayoffPictureEntry` struct
 ///
 /// Represents a single team's entry in the playoff picture
 #[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
 #[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
-#[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
-#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct PlayoffPictureEntry {
     team_id: usize,
     team_name: String,
@@ -45,6 +88,34 @@ pub struct PlayoffPictureEntry {
     games_back: f64,
     remaining_games: usize,
     magic_number: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    projected_record: Option<ProjectedRecord>,
+}
+
+impl Default for PlayoffPictureEntry {
+    /// Default constructor for the `PlayoffPictureEntry` struct, with the
+    /// team name defaulting to the default team name
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPictureEntry;
+    /// use fbsim_core::team::DEFAULT_TEAM_NAME;
+    ///
+    /// let my_playoff_picture_entry = PlayoffPictureEntry::default();
+    /// assert_eq!(my_playoff_picture_entry.team_name(), DEFAULT_TEAM_NAME);
+    /// ```
+    fn default() -> Self {
+        PlayoffPictureEntry {
+            team_id: 0,
+            team_name: String::from(DEFAULT_TEAM_NAME),
+            current_record: LeagueTeamRecord::new(),
+            status: PlayoffStatus::default(),
+            games_back: 0.0,
+            remaining_games: 0,
+            magic_number: None,
+            projected_record: None,
+        }
+    }
 }
 
 impl PlayoffPictureEntry {
@@ -79,8 +150,10 @@ impl PlayoffPictureEntry {
     /// ```
     /// use fbsim_core::league::season::playoffs::picture::PlayoffPictureEntry;
     ///
+    /// use fbsim_core::team::DEFAULT_TEAM_NAME;
+    ///
     /// let my_playoff_picture_entry = PlayoffPictureEntry::new();
-    /// assert!(my_playoff_picture_entry.team_name() == "".to_string());
+    /// assert!(my_playoff_picture_entry.team_name() == DEFAULT_TEAM_NAME);
     /// ```
     pub fn team_name(&self) -> &str {
         &self.team_name
@@ -153,6 +226,34 @@ impl PlayoffPictureEntry {
         self.magic_number
     }
 
+    /// Get the team's projected final record, if projections were requested
+    /// and could be computed
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPictureEntry;
+    ///
+    /// let my_playoff_picture_entry = PlayoffPictureEntry::new();
+    /// assert!(my_playoff_picture_entry.projected_record().is_none());
+    /// ```
+    pub fn projected_record(&self) -> Option<&ProjectedRecord> {
+        self.projected_record.as_ref()
+    }
+
+    /// Get the team's projected final win percentage, if projections were
+    /// requested and could be computed
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPictureEntry;
+    ///
+    /// let my_playoff_picture_entry = PlayoffPictureEntry::new();
+    /// assert!(my_playoff_picture_entry.projected_win_pct().is_none());
+    /// ```
+    pub fn projected_win_pct(&self) -> Option<f64> {
+        self.projected_record.as_ref().map(|r| r.win_pct())
+    }
+
     /// Check if the team has clinched a playoff spot
     ///
     /// ### Example
@@ -183,6 +284,36 @@ impl PlayoffPictureEntry {
     }
 }
 
+/// Internal helper struct for tracking the games left to be played, both
+/// per team and between each pair of teams
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RemainingGames {
+    /// Remaining games for each team
+    pub(crate) teams: BTreeMap<usize, usize>,
+    /// Remaining games between each pair of teams, keyed by the lower team ID
+    /// followed by the higher team ID
+    pub(crate) head_to_head: BTreeMap<(usize, usize), usize>,
+    /// Remaining games in the season overall
+    pub(crate) season: usize,
+}
+
+impl RemainingGames {
+    /// Record a remaining game between two teams
+    pub(crate) fn add(&mut self, home_team: usize, away_team: usize) {
+        self.season += 1;
+        *self.teams.entry(home_team).or_insert(0) += 1;
+        *self.teams.entry(away_team).or_insert(0) += 1;
+        let key = (home_team.min(away_team), home_team.max(away_team));
+        *self.head_to_head.entry(key).or_insert(0) += 1;
+    }
+
+    /// Get the number of remaining games between two teams
+    fn between(&self, team1: usize, team2: usize) -> usize {
+        let key = (team1.min(team2), team1.max(team2));
+        *self.head_to_head.get(&key).unwrap_or(&0)
+    }
+}
+
 /// Internal helper struct for tracking potential record ranges
 #[derive(Clone, Debug)]
 struct RecordBounds {
@@ -193,20 +324,44 @@ struct RecordBounds {
     #[allow(dead_code)]
     current_ties: usize,
     remaining_games: usize,
-    #[allow(dead_code)]
     total_games: usize,
     max_possible_wins: usize,
     max_possible_win_pct: f64,
     min_possible_wins: usize,
     min_possible_win_pct: f64,
+    /// The team's position in its group's standings, once the group has no
+    /// games left and the tiebreaker chain has settled every tie in record
+    final_position: Option<usize>,
 }
 
 impl RecordBounds {
-    fn from_record(team_id: usize, record: &LeagueTeamRecord, remaining_games: usize, total_games: usize) -> Self {
+    /// Compute the record bounds of a group of teams from its standings,
+    /// which are ordered by the tiebreaker chain
+    fn from_standings(standings: &[(usize, LeagueTeamRecord)], remaining_games: &RemainingGames) -> Vec<Self> {
+        let mut bounds: Vec<RecordBounds> = standings
+            .iter()
+            .map(|(team_id, record)| {
+                let remaining = *remaining_games.teams.get(team_id).unwrap_or(&0);
+                RecordBounds::from_record(*team_id, record, remaining)
+            })
+            .collect();
+        if bounds.iter().all(|b| b.remaining_games == 0) {
+            for (position, b) in bounds.iter_mut().enumerate() {
+                b.final_position = Some(position);
+            }
+        }
+        bounds
+    }
+
+    fn from_record(team_id: usize, record: &LeagueTeamRecord, remaining_games: usize) -> Self {
         let current_wins = *record.wins();
         let current_losses = *record.losses();
         let current_ties = *record.ties();
 
+        // A team's total games are those it has played plus those it has
+        // left, so bye weeks do not count against its win percentage
+        let total_games = current_wins + current_losses + current_ties + remaining_games;
+
         let max_possible_wins = current_wins + remaining_games;
         let min_possible_wins = current_wins;
 
@@ -233,6 +388,7 @@ impl RecordBounds {
             max_possible_win_pct,
             min_possible_wins,
             min_possible_win_pct,
+            final_position: None,
         }
     }
 }
@@ -244,6 +400,7 @@ impl RecordBounds {
 #[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
 #[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
 #[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PlayoffPictureOptions {
     /// If `Some(true)`, force conference-based playoff picture.
     /// If `Some(false)`, force flat (non-conference) playoff picture.
@@ -251,6 +408,428 @@ pub struct PlayoffPictureOptions {
     pub by_conference: Option<bool>,
     /// If true, division winners are guaranteed a playoff berth (conference mode only)
     pub division_winners_guaranteed: bool,
+    /// How division winners are seeded relative to the wild cards (only
+    /// used when `division_winners_guaranteed` is true)
+    pub seeding_rule: SeedingRule,
+    /// If true, each entry carries a projected final record
+    pub include_projections: bool,
+}
+
+impl PlayoffPictureOptions {
+    /// Constructor for the `PlayoffPictureOptions` struct, auto-detecting
+    /// conferences and guaranteeing no division winners
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPictureOptions;
+    ///
+    /// let my_options = PlayoffPictureOptions::new();
+    /// assert!(my_options.by_conference.is_none());
+    /// ```
+    pub fn new() -> PlayoffPictureOptions {
+        PlayoffPictureOptions::default()
+    }
+
+    /// Set the whether to force a conference-based playoff picture
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPictureOptions;
+    ///
+    /// let my_options = PlayoffPictureOptions::new()
+    ///     .by_conference(true);
+    /// assert!(my_options.by_conference == Some(true));
+    /// ```
+    pub fn by_conference(mut self, by_conference: bool) -> Self {
+        self.by_conference = Some(by_conference);
+        self
+    }
+
+    /// Set the whether division winners are guaranteed a playoff berth
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPictureOptions;
+    ///
+    /// let my_options = PlayoffPictureOptions::new()
+    ///     .division_winners_guaranteed(true);
+    /// assert!(my_options.division_winners_guaranteed);
+    /// ```
+    pub fn division_winners_guaranteed(mut self, division_winners_guaranteed: bool) -> Self {
+        self.division_winners_guaranteed = division_winners_guaranteed;
+        self
+    }
+
+    /// Set how division winners are seeded relative to the wild cards
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::SeedingRule;
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPictureOptions;
+    ///
+    /// let my_options = PlayoffPictureOptions::new()
+    ///     .seeding_rule(SeedingRule::ByRecord);
+    /// assert!(my_options.seeding_rule == SeedingRule::ByRecord);
+    /// ```
+    pub fn seeding_rule(mut self, seeding_rule: SeedingRule) -> Self {
+        self.seeding_rule = seeding_rule;
+        self
+    }
+
+    /// Set the whether to project each team's final record
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPictureOptions;
+    ///
+    /// let my_options = PlayoffPictureOptions::new()
+    ///     .include_projections(true);
+    /// assert!(my_options.include_projections);
+    /// ```
+    pub fn include_projections(mut self, include_projections: bool) -> Self {
+        self.include_projections = include_projections;
+        self
+    }
+}
+
+/// # `PlayoffPathCandidate` struct
+///
+/// A potential opponent in a round of a team's projected path to the
+/// championship, with the probability of facing them
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct PlayoffPathCandidate {
+    team_id: usize,
+    seed: usize,
+    probability: f64,
+}
+
+impl PlayoffPathCandidate {
+    /// Get the candidate's team ID
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPicture;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new());
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Project the path of the top seed in a 4-team playoff
+    /// let picture = PlayoffPicture::from_season(&my_league_season, 4, None).unwrap();
+    /// let team_id = picture.playoff_teams()[0].team_id();
+    /// let path = picture.projected_path_to_championship(&my_league_season, team_id, 10, &mut rng).unwrap();
+    /// assert!(path[0].candidates()[0].team_id() != team_id);
+    /// ```
+    pub fn team_id(&self) -> usize {
+        self.team_id
+    }
+
+    /// Get the candidate's seed
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPicture;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new());
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Project the path of the top seed in a 4-team playoff
+    /// let picture = PlayoffPicture::from_season(&my_league_season, 4, None).unwrap();
+    /// let team_id = picture.playoff_teams()[0].team_id();
+    /// let path = picture.projected_path_to_championship(&my_league_season, team_id, 10, &mut rng).unwrap();
+    /// assert!(path[0].candidates()[0].seed() == 4);
+    /// ```
+    pub fn seed(&self) -> usize {
+        self.seed
+    }
+
+    /// Get the probability of facing the candidate
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPicture;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new());
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Project the path of the top seed in a 4-team playoff
+    /// let picture = PlayoffPicture::from_season(&my_league_season, 4, None).unwrap();
+    /// let team_id = picture.playoff_teams()[0].team_id();
+    /// let path = picture.projected_path_to_championship(&my_league_season, team_id, 10, &mut rng).unwrap();
+    /// assert!(path[0].candidates()[0].probability() == 1.0);
+    /// ```
+    pub fn probability(&self) -> f64 {
+        self.probability
+    }
+}
+
+/// # `ProjectedPlayoffPathRound` struct
+///
+/// A single round of a team's projected path to the championship
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ProjectedPlayoffPathRound {
+    /// Whether the round belongs to the winners bracket rather than the
+    /// team's conference bracket
+    winners_bracket: bool,
+    /// Index of the round within its bracket
+    round: usize,
+    /// Probability that the team has a bye through the round
+    bye_probability: f64,
+    /// Potential opponents, most likely first
+    candidates: Vec<PlayoffPathCandidate>,
+}
+
+impl ProjectedPlayoffPathRound {
+    /// Whether the round belongs to the winners bracket
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPicture;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new());
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Project the path of the top seed in a 4-team playoff
+    /// let picture = PlayoffPicture::from_season(&my_league_season, 4, None).unwrap();
+    /// let team_id = picture.playoff_teams()[0].team_id();
+    /// let path = picture.projected_path_to_championship(&my_league_season, team_id, 10, &mut rng).unwrap();
+    /// assert!(!path[0].winners_bracket());
+    /// ```
+    pub fn winners_bracket(&self) -> bool {
+        self.winners_bracket
+    }
+
+    /// Index of the round within its bracket
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPicture;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new());
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Project the path of the top seed in a 4-team playoff
+    /// let picture = PlayoffPicture::from_season(&my_league_season, 4, None).unwrap();
+    /// let team_id = picture.playoff_teams()[0].team_id();
+    /// let path = picture.projected_path_to_championship(&my_league_season, team_id, 10, &mut rng).unwrap();
+    /// assert!(path[0].round() == 0);
+    /// ```
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    /// Probability that the team has a bye through the round
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPicture;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new());
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Project the path of the top seed in a 4-team playoff
+    /// let picture = PlayoffPicture::from_season(&my_league_season, 4, None).unwrap();
+    /// let team_id = picture.playoff_teams()[0].team_id();
+    /// let path = picture.projected_path_to_championship(&my_league_season, team_id, 10, &mut rng).unwrap();
+    /// assert!(path[0].bye_probability() == 0.0);
+    /// ```
+    pub fn bye_probability(&self) -> f64 {
+        self.bye_probability
+    }
+
+    /// Potential opponents in the round, most likely first
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPicture;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new());
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Project the path of the top seed in a 4-team playoff
+    /// let picture = PlayoffPicture::from_season(&my_league_season, 4, None).unwrap();
+    /// let team_id = picture.playoff_teams()[0].team_id();
+    /// let path = picture.projected_path_to_championship(&my_league_season, team_id, 10, &mut rng).unwrap();
+    /// assert!(path[0].candidates().len() == 1);
+    /// ```
+    pub fn candidates(&self) -> &Vec<PlayoffPathCandidate> {
+        &self.candidates
+    }
+}
+
+/// # `PlayoffPictureRaw` struct
+///
+/// A freshly deserialized `PlayoffPicture` prior to validation.
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct PlayoffPictureRaw {
+    pub num_playoff_teams: usize,
+    pub entries: Vec<PlayoffPictureEntry>,
+    pub games_remaining_in_season: usize,
+    /// Conflicts between an existing playoff bracket and the standings
+    /// (default: no conflicts)
+    #[serde(default)]
+    pub conflicts: Vec<String>,
+}
+
+impl PlayoffPictureRaw {
+    /// Validate the raw playoff picture
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPictureRaw;
+    ///
+    /// let mut raw = PlayoffPictureRaw::default();
+    /// assert!(raw.validate().is_err());
+    /// raw.num_playoff_teams = 2;
+    /// assert!(raw.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), LeagueError> {
+        // Ensure there is at least one playoff spot
+        if self.num_playoff_teams < 1 {
+            return Err(LeagueError::InvalidPlayoffPicture {
+                reason: String::from("must have at least 1 playoff team")
+            });
+        }
+
+        let mut team_ids: HashSet<usize> = HashSet::new();
+        let mut seeded: usize = 0;
+        for (index, entry) in self.entries.iter().enumerate() {
+            // Ensure each team appears once
+            if !team_ids.insert(entry.team_id) {
+                return Err(LeagueError::InvalidPlayoffPicture {
+                    reason: format!(
+                        "entry {} repeats team ID: {}",
+                        index, entry.team_id
+                    )
+                });
+            }
+
+            // Ensure games back is a non-negative number
+            if !entry.games_back.is_finite() || entry.games_back < 0.0 {
+                return Err(LeagueError::InvalidPlayoffPicture {
+                    reason: format!(
+                        "entry {} for team {} has invalid games back: {}",
+                        index, entry.team_id, entry.games_back
+                    )
+                });
+            }
+
+            // Ensure each seed is within the playoff spots
+            let seed = match entry.status {
+                PlayoffStatus::ClinchedTopSeed => Some(1),
+                PlayoffStatus::ClinchedPlayoffs { current_seed }
+                | PlayoffStatus::InPlayoffPosition { current_seed } => Some(current_seed),
+                PlayoffStatus::InTheHunt | PlayoffStatus::Eliminated => None,
+            };
+            if let Some(seed) = seed {
+                if !(1..=self.num_playoff_teams).contains(&seed) {
+                    return Err(LeagueError::InvalidPlayoffPicture {
+                        reason: format!(
+                            "entry {} for team {} has seed {} outside of the {} playoff spots",
+                            index, entry.team_id, seed, self.num_playoff_teams
+                        )
+                    });
+                }
+                seeded += 1;
+            }
+        }
+
+        // Ensure no more teams hold a seed than there are playoff spots
+        if seeded > self.num_playoff_teams {
+            return Err(LeagueError::InvalidPlayoffPicture {
+                reason: format!(
+                    "{} teams hold a seed but there are only {} playoff spots",
+                    seeded, self.num_playoff_teams
+                )
+            });
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<PlayoffPictureRaw> for PlayoffPicture {
+    type Error = LeagueError;
+
+    fn try_from(raw: PlayoffPictureRaw) -> Result<Self, Self::Error> {
+        raw.validate()?;
+        Ok(PlayoffPicture {
+            num_playoff_teams: raw.num_playoff_teams,
+            entries: raw.entries,
+            games_remaining_in_season: raw.games_remaining_in_season,
+            conflicts: raw.conflicts,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for PlayoffPicture {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = PlayoffPictureRaw::deserialize(deserializer)?;
+        PlayoffPicture::try_from(raw).map_err(serde::de::Error::custom)
+    }
 }
 
 /// # `PlayoffPicture` struct
@@ -259,31 +838,91 @@ pub struct PlayoffPictureOptions {
 /// qualification status of all teams
 #[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
 #[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
-#[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
-#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, PartialEq, Debug, Serialize)]
 pub struct PlayoffPicture {
     num_playoff_teams: usize,
     entries: Vec<PlayoffPictureEntry>,
     games_remaining_in_season: usize,
+    /// Conflicts between an existing playoff bracket and the standings
+    /// (default: no conflicts)
+    #[serde(default)]
+    conflicts: Vec<String>,
 }
 
-impl PlayoffPicture {
-    /// Create a playoff picture from a season
+impl PlayoffPicture {
+    /// Create a playoff picture from a season
+    ///
+    /// When the season has multiple conferences, the playoff picture is organized
+    /// by conference by default (with `num_playoff_teams` interpreted as the number
+    /// of playoff teams *per conference*). This behavior can be overridden via
+    /// `PlayoffPictureOptions`.
+    ///
+    /// ### Arguments
+    /// * `season` - The league season to analyze
+    /// * `num_playoff_teams` - Number of teams that make the playoffs. In conference
+    ///   mode this is the number of teams *per conference*.
+    /// * `options` - Optional configuration; pass `None` for defaults
+    ///
+    /// ### Returns
+    /// * `Ok(PlayoffPicture)` - The current playoff picture
+    /// * `Err(LeagueError)` - If the season hasn't started or parameters are invalid
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPicture;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Get the playoff picture for a 2-team playoff
+    /// let picture = PlayoffPicture::from_season(&my_league_season, 2, None);
+    /// assert!(picture.is_ok());
+    /// ```
+    pub fn from_season(
+        season: &LeagueSeason,
+        num_playoff_teams: usize,
+        options: Option<PlayoffPictureOptions>,
+    ) -> Result<Self, LeagueError> {
+        Self::from_standings(
+            season,
+            &season.standings(),
+            &Self::remaining_games(season),
+            num_playoff_teams,
+            options,
+        )
+    }
+
+    /// Estimate each team's odds of making the playoffs, winning its
+    /// division, and earning the top seed by simulating the season's
+    /// remaining games many times
     ///
-    /// When the season has multiple conferences, the playoff picture is organized
-    /// by conference by default (with `num_playoff_teams` interpreted as the number
-    /// of playoff teams *per conference*). This behavior can be overridden via
-    /// `PlayoffPictureOptions`.
+    /// Each remaining game is simulated by the `FinalScoreSimulator`, and
+    /// each simulated season is seeded exactly as `from_season` would seed
+    /// it once complete. The season itself is not modified.
     ///
     /// ### Arguments
-    /// * `season` - The league season to analyze
+    /// * `season` - The league season to simulate
     /// * `num_playoff_teams` - Number of teams that make the playoffs. In conference
     ///   mode this is the number of teams *per conference*.
+    /// * `iterations` - Number of times to simulate the rest of the season
     /// * `options` - Optional configuration; pass `None` for defaults
+    /// * `rng` - The random number generator to simulate with
     ///
     /// ### Returns
-    /// * `Ok(PlayoffPicture)` - The current playoff picture
-    /// * `Err(String)` - If the season hasn't started or parameters are invalid
+    /// * `Ok(PlayoffOdds)` - Each team's odds, keyed by team ID
+    /// * `Err(LeagueError)` - If the season has no schedule or parameters are invalid
     ///
     /// ### Example
     /// ```
@@ -303,79 +942,293 @@ impl PlayoffPicture {
     /// let mut rng = rand::thread_rng();
     /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
     ///
-    /// // Get the playoff picture for a 2-team playoff
-    /// let picture = PlayoffPicture::from_season(&my_league_season, 2, None);
-    /// assert!(picture.is_ok());
+    /// // Estimate the odds of making a 2-team playoff
+    /// let odds = PlayoffPicture::simulate_odds(&my_league_season, 2, 1000, None, &mut rng).unwrap();
+    /// let team_odds = odds.team_odds(0).unwrap();
+    /// assert!(team_odds.make_playoffs() > 0.0 && team_odds.make_playoffs() < 1.0);
     /// ```
-    pub fn from_season(
+    pub fn simulate_odds(
+        season: &LeagueSeason,
+        num_playoff_teams: usize,
+        iterations: usize,
+        options: Option<PlayoffPictureOptions>,
+        rng: &mut impl Rng,
+    ) -> Result<PlayoffOdds, LeagueError> {
+        PlayoffOdds::simulate(season, num_playoff_teams, iterations, options, rng)
+    }
+
+    /// Create a playoff picture from a season given its sorted standings and
+    /// remaining games, which are shared with other computations over the
+    /// same season
+    pub(crate) fn from_standings(
         season: &LeagueSeason,
+        standings: &[(usize, LeagueTeamRecord)],
+        remaining_games: &RemainingGames,
         num_playoff_teams: usize,
         options: Option<PlayoffPictureOptions>,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, LeagueError> {
         let opts = options.unwrap_or_default();
 
         let use_conferences = match opts.by_conference {
             Some(v) => v,
-            None => season.conferences().len() > 1,
+            None => season.populated_conferences().len() > 1,
         };
 
-        if use_conferences {
+        let picture = if use_conferences {
             Self::conference_playoff_picture(
                 season,
+                standings,
+                remaining_games,
                 num_playoff_teams,
                 opts.division_winners_guaranteed,
-            )
+                opts.seeding_rule,
+            )?
         } else {
-            Self::non_conference_playoff_picture(season, num_playoff_teams)
-        }
+            Self::non_conference_playoff_picture(season, standings, remaining_games, num_playoff_teams)?
+        };
+        Ok(picture.with_projections(season, opts.include_projections))
     }
 
-    /// Build a playoff picture using overall league standings (no conference separation)
-    fn non_conference_playoff_picture(season: &LeagueSeason, num_playoff_teams: usize) -> Result<Self, String> {
-        let total_teams = season.teams().len();
+    /// Create a playoff picture from a season whose playoff bracket has
+    /// already been partially or fully seeded
+    ///
+    /// Teams already in the bracket are treated as clinched with their
+    /// actual seeds, and the remaining spots are computed from the standings
+    /// of the teams not yet in the bracket. A bracketed team which the
+    /// standings say should be out, or which is not in the standings at all,
+    /// is recorded as a conflict rather than an error.
+    ///
+    /// ### Arguments
+    /// * `season` - The league season to analyze
+    /// * `playoffs` - The playoffs generated so far
+    /// * `num_playoff_teams` - Number of teams that make the playoffs. In conference
+    ///   mode this is the number of teams *per conference*.
+    /// * `options` - Optional configuration; pass `None` for defaults
+    ///
+    /// ### Returns
+    /// * `Ok(PlayoffPicture)` - The current playoff picture
+    /// * `Err(LeagueError)` - If the season hasn't started, parameters are invalid,
+    ///   or the bracket holds more teams than there are playoff spots
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::playoffs::LeagueSeasonPlayoffs;
+    /// use fbsim_core::league::season::playoffs::picture::{PlayoffPicture, PlayoffStatus};
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Pre-seed team 2 as the top seed of a 2-team playoff
+    /// let mut my_playoffs = LeagueSeasonPlayoffs::new();
+    /// my_playoffs.add_team(2, "TM", None).unwrap();
+    ///
+    /// // Get the playoff picture
+    /// let picture = PlayoffPicture::from_season_and_playoffs(
+    ///     &my_league_season,
+    ///     &my_playoffs,
+    ///     2,
+    ///     None
+    /// ).unwrap();
+    /// let entry = picture.team_status(2).unwrap();
+    /// assert!(*entry.status() == PlayoffStatus::ClinchedTopSeed);
+    /// ```
+    pub fn from_season_and_playoffs(
+        season: &LeagueSeason,
+        playoffs: &LeagueSeasonPlayoffs,
+        num_playoff_teams: usize,
+        options: Option<PlayoffPictureOptions>,
+    ) -> Result<Self, LeagueError> {
+        let opts = options.unwrap_or_default();
+
+        let use_conferences = match opts.by_conference {
+            Some(v) => v,
+            None => season.populated_conferences().len() > 1,
+        };
 
-        // Validate parameters
-        if num_playoff_teams == 0 {
-            return Err("Number of playoff teams must be at least 1".to_string());
-        }
-        if num_playoff_teams > total_teams {
-            return Err(format!(
-                "Number of playoff teams ({}) cannot exceed total teams ({})",
-                num_playoff_teams, total_teams
-            ));
-        }
         if season.weeks().is_empty() {
-            return Err("Season has no schedule".to_string());
+            return Err(LeagueError::NoSchedule);
         }
 
-        // Get current standings
-        let standings = season.standings();
+        // Compute remaining games for each team
+        let remaining_games = Self::remaining_games(season);
+        let games_remaining_in_season = remaining_games.season;
+        let mut entries = Vec::new();
+        let mut conflicts = Vec::new();
+
+        let total_playoff_teams = if use_conferences {
+            // Validate parameters, ignoring empty conferences
+            let populated = season.populated_conferences();
+            if populated.is_empty() {
+                return Err(LeagueError::NoConferences);
+            }
+            let min_conference_size = populated
+                .iter()
+                .map(|c| season.conferences()[*c].num_teams())
+                .min()
+                .unwrap_or(0);
+            if num_playoff_teams == 0 || num_playoff_teams > min_conference_size {
+                return Err(LeagueError::PlayoffTeamsOutOfRange {
+                    num_playoff_teams,
+                    min: 1,
+                    max: min_conference_size
+                });
+            }
+
+            // Each season conference maps to the bracket of the same index
+            let empty = BTreeMap::new();
+            for conf_index in populated.iter().copied() {
+                let conf_standings = season.conference_standings(conf_index)?;
+                let bracket = playoffs.teams().get_conference(conf_index).unwrap_or(&empty);
+                entries.extend(Self::bracketed_entries(
+                    season,
+                    &conf_standings,
+                    bracket,
+                    num_playoff_teams,
+                    &remaining_games,
+                    &mut conflicts,
+                )?);
+            }
 
-        // Calculate total games per team (assumes all teams play same number of games)
-        let total_games = season.weeks().len();
+            // Any bracket without a matching season conference is a conflict
+            for conf_index in playoffs.teams().conferences() {
+                if *conf_index >= season.conferences().len() {
+                    conflicts.push(format!(
+                        "Playoff conference {} does not exist in the season",
+                        conf_index
+                    ));
+                }
+            }
+            num_playoff_teams * populated.len()
+        } else {
+            // Validate parameters
+            let total_teams = season.teams().len();
+            if num_playoff_teams == 0 || num_playoff_teams > total_teams {
+                return Err(LeagueError::PlayoffTeamsOutOfRange {
+                    num_playoff_teams,
+                    min: 1,
+                    max: total_teams
+                });
+            }
+            if playoffs.is_conference_playoff() {
+                return Err(
+                    "Playoffs are seeded by conference but the playoff picture is not".to_string().into()
+                );
+            }
 
-        // Compute remaining games for each team
-        let mut team_remaining_games: BTreeMap<usize, usize> = BTreeMap::new();
-        let mut games_remaining_in_season = 0;
+            // Non-conference playoffs hold all teams in a single bracket
+            let standings = season.standings();
+            let bracket: BTreeMap<usize, PlayoffTeam> = playoffs
+                .teams()
+                .conferences()
+                .filter_map(|c| playoffs.teams().get_conference(*c))
+                .flat_map(|teams| teams.iter().map(|(id, team)| (*id, team.clone())))
+                .collect();
+            entries = Self::bracketed_entries(
+                season,
+                &standings,
+                &bracket,
+                num_playoff_teams,
+                &remaining_games,
+                &mut conflicts,
+            )?;
+            num_playoff_teams
+        };
+
+        let picture = PlayoffPicture {
+            num_playoff_teams: total_playoff_teams,
+            entries,
+            games_remaining_in_season,
+            conflicts,
+        };
+        Ok(picture.with_projections(season, opts.include_projections))
+    }
+
+    /// Attach a projected final record to each entry, if requested. Expected
+    /// results come from the final score model's outcome probabilities for
+    /// each remaining game, accumulated in a single pass over the schedule.
+    /// If any remaining game cannot be projected, no entry is given one.
+    fn with_projections(mut self, season: &LeagueSeason, include_projections: bool) -> Self {
+        if !include_projections {
+            return self;
+        }
 
+        // Accumulate each team's expected wins, losses, and ties
+        let simulator = FinalScoreSimulator::new();
+        let mut expected: BTreeMap<usize, ProjectedRecord> = BTreeMap::new();
         for week in season.weeks().iter() {
             for matchup in week.matchups().iter() {
-                if !matchup.context().game_over() {
-                    games_remaining_in_season += 1;
-                    *team_remaining_games.entry(*matchup.home_team()).or_insert(0) += 1;
-                    *team_remaining_games.entry(*matchup.away_team()).or_insert(0) += 1;
+                if matchup.context().game_over() {
+                    continue;
                 }
+                let (home_id, away_id) = (*matchup.home_team(), *matchup.away_team());
+                let probabilities = match (season.team(home_id), season.team(away_id)) {
+                    (Some(home), Some(away)) => simulator.outcome_probabilities(home, away),
+                    _ => Err(String::from("Matchup references a nonexistent team")),
+                };
+                let (p_home, p_away, p_tie) = match probabilities {
+                    Ok(p) => p,
+                    Err(_) => return self,
+                };
+                let home = expected.entry(home_id).or_default();
+                home.wins += p_home;
+                home.losses += p_away;
+                home.ties += p_tie;
+                let away = expected.entry(away_id).or_default();
+                away.wins += p_away;
+                away.losses += p_home;
+                away.ties += p_tie;
             }
         }
 
+        // Add the expected results to each team's current record
+        for entry in self.entries.iter_mut() {
+            let remaining = expected.remove(&entry.team_id).unwrap_or_default();
+            entry.projected_record = Some(ProjectedRecord {
+                wins: *entry.current_record.wins() as f64 + remaining.wins,
+                losses: *entry.current_record.losses() as f64 + remaining.losses,
+                ties: *entry.current_record.ties() as f64 + remaining.ties,
+            });
+        }
+        self
+    }
+
+    /// Build a playoff picture using overall league standings (no conference separation)
+    fn non_conference_playoff_picture(
+        season: &LeagueSeason,
+        standings: &[(usize, LeagueTeamRecord)],
+        remaining_games: &RemainingGames,
+        num_playoff_teams: usize,
+    ) -> Result<Self, LeagueError> {
+        let total_teams = season.teams().len();
+
+        // Validate parameters
+        if num_playoff_teams == 0 || num_playoff_teams > total_teams {
+            return Err(LeagueError::PlayoffTeamsOutOfRange {
+                num_playoff_teams,
+                min: 1,
+                max: total_teams
+            });
+        }
+        if season.weeks().is_empty() {
+            return Err(LeagueError::NoSchedule);
+        }
+
+        let team_remaining_games = &remaining_games.teams;
+        let games_remaining_in_season = remaining_games.season;
+
         // Compute record bounds for all teams
-        let bounds: Vec<RecordBounds> = standings
-            .iter()
-            .map(|(team_id, record)| {
-                let remaining = *team_remaining_games.get(team_id).unwrap_or(&0);
-                RecordBounds::from_record(*team_id, record, remaining, total_games)
-            })
-            .collect();
+        let bounds = RecordBounds::from_standings(standings, remaining_games);
 
         // Build entries for each team
         let mut entries = Vec::with_capacity(total_teams);
@@ -394,7 +1247,7 @@ impl PlayoffPicture {
             let games_back = if in_playoff_position {
                 0.0
             } else {
-                Self::compute_games_back(&standings, position, num_playoff_teams)
+                Self::compute_games_back(standings, position, num_playoff_teams)
             };
 
             // Determine status
@@ -402,6 +1255,7 @@ impl PlayoffPicture {
                 *team_id,
                 position,
                 &bounds,
+                remaining_games,
                 num_playoff_teams,
             );
 
@@ -409,7 +1263,7 @@ impl PlayoffPicture {
             let magic_number = if matches!(status, PlayoffStatus::Eliminated) {
                 None
             } else {
-                Self::compute_magic_number(*team_id, &bounds, num_playoff_teams)
+                Self::compute_magic_number(*team_id, &bounds, remaining_games, num_playoff_teams)
             };
 
             entries.push(PlayoffPictureEntry {
@@ -420,6 +1274,7 @@ impl PlayoffPicture {
                 games_back,
                 remaining_games: remaining,
                 magic_number,
+                projected_record: None,
             });
         }
 
@@ -427,111 +1282,95 @@ impl PlayoffPicture {
             num_playoff_teams,
             entries,
             games_remaining_in_season,
+            conflicts: Vec::new(),
         })
     }
 
     /// Build a playoff picture organized by conference
     fn conference_playoff_picture(
         season: &LeagueSeason,
+        standings: &[(usize, LeagueTeamRecord)],
+        remaining_games: &RemainingGames,
         playoff_teams_per_conference: usize,
         division_winners_guaranteed: bool,
-    ) -> Result<Self, String> {
-        // Validate that we have conferences
-        if season.conferences().is_empty() {
-            return Err("Season has no conferences defined".to_string());
+        seeding_rule: SeedingRule,
+    ) -> Result<Self, LeagueError> {
+        // Validate that we have conferences, ignoring empty conferences
+        let populated = season.populated_conferences();
+        if populated.is_empty() {
+            return Err(LeagueError::NoConferences);
         }
 
         if season.weeks().is_empty() {
-            return Err("Season has no schedule".to_string());
-        }
-
-        if playoff_teams_per_conference == 0 {
-            return Err("Number of playoff teams per conference must be at least 1".to_string());
+            return Err(LeagueError::NoSchedule);
         }
 
-        // Validate that playoff_teams_per_conference does not exceed the
-        // smallest conference size
-        let min_conference_size = season
-            .conferences()
+        // Validate that playoff_teams_per_conference is nonzero and does not
+        // exceed the smallest conference size
+        let min_conference_size = populated
             .iter()
-            .map(|c| c.num_teams())
+            .map(|c| season.conferences()[*c].num_teams())
             .min()
             .unwrap_or(0);
 
-        if playoff_teams_per_conference > min_conference_size {
-            return Err(format!(
-                "Playoff teams per conference ({}) exceeds the smallest conference size ({})",
-                playoff_teams_per_conference, min_conference_size
-            ));
+        if playoff_teams_per_conference == 0 || playoff_teams_per_conference > min_conference_size {
+            return Err(LeagueError::PlayoffTeamsOutOfRange {
+                num_playoff_teams: playoff_teams_per_conference,
+                min: 1,
+                max: min_conference_size
+            });
         }
 
-        let num_conferences = season.conferences().len();
-        let total_playoff_teams = playoff_teams_per_conference * num_conferences;
-
-        // Compute remaining games for each team
-        let mut team_remaining_games: BTreeMap<usize, usize> = BTreeMap::new();
-        let mut games_remaining_in_season = 0;
+        let total_playoff_teams = playoff_teams_per_conference * populated.len();
 
-        for week in season.weeks().iter() {
-            for matchup in week.matchups().iter() {
-                if !matchup.context().game_over() {
-                    games_remaining_in_season += 1;
-                    *team_remaining_games.entry(*matchup.home_team()).or_insert(0) += 1;
-                    *team_remaining_games.entry(*matchup.away_team()).or_insert(0) += 1;
-                }
-            }
-        }
+        let team_remaining_games = &remaining_games.teams;
+        let games_remaining_in_season = remaining_games.season;
 
-        let total_games = season.weeks().len();
         let mut all_entries = Vec::new();
 
         // Process each conference
-        for conf_index in 0..num_conferences {
+        for conf_index in populated.iter().copied() {
             let conference = season.conferences().get(conf_index)
                 .ok_or_else(|| format!("Conference {} not found", conf_index))?;
 
             // Get conference standings
-            let conf_standings = season.conference_standings(conf_index)?;
+            let conf_standings = season.filter_conference_standings(standings, conf_index)?;
 
-            // Determine division winners
+            // Determine division winners, and the division of each team
             let mut division_winners: Vec<usize> = Vec::new();
+            let mut division_leaders: Vec<LeagueTeamRecord> = Vec::new();
+            let mut team_divisions: BTreeMap<usize, usize> = BTreeMap::new();
             if division_winners_guaranteed {
                 for (div_id, _) in conference.divisions().iter().enumerate() {
-                    let div_standings = season.division_standings(conf_index, div_id)?;
-                    if let Some((winner_id, _)) = div_standings.first() {
+                    let div_standings = season.filter_division_standings(standings, conf_index, div_id)?;
+                    if let Some((winner_id, record)) = div_standings.first() {
                         division_winners.push(*winner_id);
+                        division_leaders.push(record.clone());
+                    }
+                    for (team_id, _) in div_standings.iter() {
+                        team_divisions.insert(*team_id, division_leaders.len() - 1);
                     }
                 }
             }
 
-            // Determine playoff teams for this conference
-            let mut conf_playoff_teams: Vec<usize> = Vec::new();
-
-            // First, add all division winners (if guaranteed)
-            for &winner in &division_winners {
-                if conf_playoff_teams.len() < playoff_teams_per_conference {
-                    conf_playoff_teams.push(winner);
-                }
-            }
-
-            // Fill remaining spots with wild cards (best records not already in)
-            for (team_id, _record) in conf_standings.iter() {
-                if conf_playoff_teams.len() >= playoff_teams_per_conference {
-                    break;
-                }
-                if !conf_playoff_teams.contains(team_id) {
-                    conf_playoff_teams.push(*team_id);
-                }
-            }
+            // Determine playoff teams for this conference, seeded as the
+            // playoffs would seed them
+            let conf_playoff_teams = seeding_rule.seed(
+                &conf_standings,
+                &division_winners,
+                playoff_teams_per_conference,
+            );
 
             // Compute record bounds for conference teams
-            let bounds: Vec<RecordBounds> = conf_standings
-                .iter()
-                .map(|(team_id, record)| {
-                    let remaining = *team_remaining_games.get(team_id).unwrap_or(&0);
-                    RecordBounds::from_record(*team_id, record, remaining, total_games)
-                })
-                .collect();
+            let bounds = RecordBounds::from_standings(&conf_standings, remaining_games);
+
+            // With guaranteed division winners, the last wild card sets the
+            // cutoff of the wild card race, if any wild cards qualify
+            let wild_card_cutoff = conf_playoff_teams.iter()
+                .rev()
+                .find(|id| !division_winners.contains(id))
+                .and_then(|id| conf_standings.iter().find(|(team_id, _)| team_id == id))
+                .map(|(_, record)| record);
 
             // Build entries for each team in the conference
             for (position, (team_id, record)) in conf_standings.iter().enumerate() {
@@ -542,11 +1381,26 @@ impl PlayoffPicture {
                     .unwrap_or_else(|| format!("Team {}", team_id));
 
                 let remaining = *team_remaining_games.get(team_id).unwrap_or(&0);
-                let in_playoff_position = position < playoff_teams_per_conference;
-                let is_division_winner = division_winners.contains(team_id);
-
-                // Calculate games back (within conference)
-                let games_back = if in_playoff_position {
+                let seed = conf_playoff_teams.iter()
+                    .position(|id| id == team_id)
+                    .map(|index| index + 1);
+
+                // Calculate games back (within conference). With guaranteed
+                // division winners a team outside the seeds trails in both
+                // its division race and the wild card race, whichever is
+                // closer
+                let games_back = if division_winners_guaranteed {
+                    let division_back = team_divisions.get(team_id)
+                        .map(|div| Self::games_behind(&division_leaders[*div], record));
+                    let wild_card_back = wild_card_cutoff
+                        .map(|cutoff| Self::games_behind(cutoff, record));
+                    match (seed, division_back, wild_card_back) {
+                        (Some(_), _, _) => 0.0,
+                        (None, Some(division), Some(wild_card)) => division.min(wild_card).max(0.0),
+                        (None, Some(back), None) | (None, None, Some(back)) => back.max(0.0),
+                        (None, None, None) => 0.0,
+                    }
+                } else if position < playoff_teams_per_conference {
                     0.0
                 } else {
                     Self::compute_games_back(&conf_standings, position, playoff_teams_per_conference)
@@ -557,6 +1411,7 @@ impl PlayoffPicture {
                     *team_id,
                     position,
                     &bounds,
+                    remaining_games,
                     playoff_teams_per_conference,
                 );
 
@@ -564,15 +1419,40 @@ impl PlayoffPicture {
                 let magic_number = if matches!(status, PlayoffStatus::Eliminated) {
                     None
                 } else {
-                    Self::compute_magic_number(*team_id, &bounds, playoff_teams_per_conference)
+                    Self::compute_magic_number(*team_id, &bounds, remaining_games, playoff_teams_per_conference)
                 };
 
-                // Adjust status based on division winner guarantee
-                let final_status = if is_division_winner && division_winners_guaranteed {
-                    // Division winners get special consideration
-                    match &status {
-                        PlayoffStatus::Eliminated => status, // Can't be eliminated if div winner guaranteed
-                        _ => status,
+                // With guaranteed division winners, the seeds come from the
+                // seeding rule rather than the conference standings, so a
+                // division leader holds a seed and a displaced wild card
+                // does not. A team which has clinched its division has
+                // clinched a berth, and a team which can still win its
+                // division is not eliminated
+                let final_status = if division_winners_guaranteed {
+                    let division_bounds: Vec<RecordBounds> = bounds.iter()
+                        .filter(|b| team_divisions.get(&b.team_id) == team_divisions.get(team_id))
+                        .cloned()
+                        .collect();
+                    let clinched_division = Self::has_clinched_playoffs(*team_id, &division_bounds, remaining_games, 1);
+                    let division_alive = !Self::is_eliminated(*team_id, &division_bounds, remaining_games, 1);
+                    let wild_card_alive = !Self::is_eliminated_from_wild_card(
+                        *team_id,
+                        &bounds,
+                        &team_divisions,
+                        playoff_teams_per_conference.saturating_sub(division_leaders.len()),
+                    );
+                    match (status, seed) {
+                        (PlayoffStatus::ClinchedTopSeed, Some(1)) => PlayoffStatus::ClinchedTopSeed,
+                        (PlayoffStatus::ClinchedTopSeed, Some(current_seed))
+                        | (PlayoffStatus::ClinchedPlayoffs { .. }, Some(current_seed)) => {
+                            PlayoffStatus::ClinchedPlayoffs { current_seed }
+                        }
+                        (_, Some(current_seed)) if clinched_division => PlayoffStatus::ClinchedPlayoffs { current_seed },
+                        (_, Some(current_seed)) => PlayoffStatus::InPlayoffPosition { current_seed },
+                        (_, None) if games_remaining_in_season == 0 => PlayoffStatus::Eliminated,
+                        (PlayoffStatus::Eliminated, None) if !division_alive => PlayoffStatus::Eliminated,
+                        (_, None) if !division_alive && !wild_card_alive => PlayoffStatus::Eliminated,
+                        (_, None) => PlayoffStatus::InTheHunt,
                     }
                 } else {
                     status
@@ -586,6 +1466,7 @@ impl PlayoffPicture {
                     games_back,
                     remaining_games: remaining,
                     magic_number,
+                    projected_record: None,
                 });
             }
         }
@@ -594,9 +1475,160 @@ impl PlayoffPicture {
             num_playoff_teams: total_playoff_teams,
             entries: all_entries,
             games_remaining_in_season,
+            conflicts: Vec::new(),
         })
     }
 
+    /// Count the remaining games for each team, and in the season overall
+    pub(crate) fn remaining_games(season: &LeagueSeason) -> RemainingGames {
+        let mut remaining_games = RemainingGames::default();
+        for week in season.weeks().iter() {
+            for matchup in week.matchups().iter() {
+                if !matchup.context().game_over() {
+                    remaining_games.add(*matchup.home_team(), *matchup.away_team());
+                }
+            }
+        }
+        remaining_games
+    }
+
+    /// Build the entries for a group of teams competing for the same playoff
+    /// spots, given the teams of that group which are already in the bracket
+    fn bracketed_entries(
+        season: &LeagueSeason,
+        standings: &[(usize, LeagueTeamRecord)],
+        bracket: &BTreeMap<usize, PlayoffTeam>,
+        num_playoff_teams: usize,
+        remaining_games: &RemainingGames,
+        conflicts: &mut Vec<String>,
+    ) -> Result<Vec<PlayoffPictureEntry>, LeagueError> {
+        let team_remaining_games = &remaining_games.teams;
+        // Ensure the bracket fits within the playoff spots
+        if bracket.len() > num_playoff_teams {
+            return Err(format!(
+                "Playoff bracket contains {} teams but only {} playoff spots exist",
+                bracket.len(), num_playoff_teams
+            ).into());
+        }
+        for (team_id, team) in bracket.iter() {
+            if team.seed() > num_playoff_teams {
+                return Err(format!(
+                    "Team {} is seeded {} but only {} playoff spots exist",
+                    team_id, team.seed(), num_playoff_teams
+                ).into());
+            }
+        }
+
+        // Compute record bounds for all teams in the group
+        let bounds = RecordBounds::from_standings(standings, remaining_games);
+
+        // Flag bracketed teams which the standings say should be out
+        for (team_id, team) in bracket.iter() {
+            if !standings.iter().any(|(id, _)| id == team_id) {
+                conflicts.push(format!(
+                    "Team {} is seeded {} in the playoffs but is not in the standings",
+                    team_id, team.seed()
+                ));
+            } else if Self::is_eliminated(*team_id, &bounds, remaining_games, num_playoff_teams) {
+                conflicts.push(format!(
+                    "Team {} is seeded {} in the playoffs but is eliminated in the standings",
+                    team_id, team.seed()
+                ));
+            }
+        }
+
+        // The remaining spots go to the teams not yet in the bracket, in the
+        // seeds the bracket has not used
+        let open_seeds: Vec<usize> = (1..=num_playoff_teams)
+            .filter(|seed| !bracket.values().any(|t| t.seed() == *seed))
+            .collect();
+        let open_spots = open_seeds.len();
+        let open_standings: Vec<(usize, LeagueTeamRecord)> = standings
+            .iter()
+            .filter(|(team_id, _)| !bracket.contains_key(team_id))
+            .cloned()
+            .collect();
+        let open_bounds: Vec<RecordBounds> = bounds
+            .iter()
+            .filter(|b| !bracket.contains_key(&b.team_id))
+            .cloned()
+            .collect();
+
+        // Build entries for each team in standings order
+        let mut entries = Vec::with_capacity(standings.len());
+        let mut open_position = 0;
+        for (team_id, record) in standings.iter() {
+            let team_name = season
+                .teams()
+                .get(team_id)
+                .map(|t| t.name().to_string())
+                .unwrap_or_else(|| format!("Team {}", team_id));
+            let remaining = *team_remaining_games.get(team_id).unwrap_or(&0);
+
+            // Bracketed teams have clinched their actual seed
+            if let Some(team) = bracket.get(team_id) {
+                let status = if team.seed() == 1 {
+                    PlayoffStatus::ClinchedTopSeed
+                } else {
+                    PlayoffStatus::ClinchedPlayoffs { current_seed: team.seed() }
+                };
+                entries.push(PlayoffPictureEntry {
+                    team_id: *team_id,
+                    team_name,
+                    current_record: record.clone(),
+                    status,
+                    games_back: 0.0,
+                    remaining_games: remaining,
+                    magic_number: Some(0),
+                    projected_record: None,
+                });
+                continue;
+            }
+
+            // Other teams compete for the open spots only
+            let position = open_position;
+            open_position += 1;
+            let status = if open_spots == 0 {
+                PlayoffStatus::Eliminated
+            } else {
+                match Self::compute_status(*team_id, position, &open_bounds, remaining_games, open_spots) {
+                    PlayoffStatus::ClinchedTopSeed if open_seeds[0] == 1 => PlayoffStatus::ClinchedTopSeed,
+                    PlayoffStatus::ClinchedTopSeed => PlayoffStatus::ClinchedPlayoffs {
+                        current_seed: open_seeds[0]
+                    },
+                    PlayoffStatus::ClinchedPlayoffs { current_seed } => PlayoffStatus::ClinchedPlayoffs {
+                        current_seed: open_seeds[current_seed - 1]
+                    },
+                    PlayoffStatus::InPlayoffPosition { current_seed } => PlayoffStatus::InPlayoffPosition {
+                        current_seed: open_seeds[current_seed - 1]
+                    },
+                    status => status,
+                }
+            };
+            let games_back = if position < open_spots {
+                0.0
+            } else {
+                Self::compute_games_back(&open_standings, position, open_spots)
+            };
+            let magic_number = if matches!(status, PlayoffStatus::Eliminated) {
+                None
+            } else {
+                Self::compute_magic_number(*team_id, &open_bounds, remaining_games, open_spots)
+            };
+            entries.push(PlayoffPictureEntry {
+                team_id: *team_id,
+                team_name,
+                current_record: record.clone(),
+                status,
+                games_back,
+                remaining_games: remaining,
+                magic_number,
+                projected_record: None,
+            });
+        }
+        Ok(entries)
+    }
+
     /// Compute how many games a team is behind the playoff cutoff
     fn compute_games_back(
         standings: &[(usize, LeagueTeamRecord)],
@@ -611,15 +1643,19 @@ impl PlayoffPicture {
         let cutoff_idx = num_playoff_teams - 1;
         let (_, cutoff_record) = &standings[cutoff_idx];
         let (_, team_record) = &standings[position];
+        Self::games_behind(cutoff_record, team_record)
+    }
 
-        // Games back = (cutoff_wins - team_wins + 0.5*(cutoff_ties - team_ties)) / 2
+    /// Compute how many games one team's record is behind another's
+    fn games_behind(leader: &LeagueTeamRecord, team: &LeagueTeamRecord) -> f64 {
+        // Games back = (leader_wins - team_wins + 0.5*(leader_ties - team_ties)) / 2
         // This follows standard "games back" calculation
-        let cutoff_win_value = *cutoff_record.wins() as f64 + 0.5 * *cutoff_record.ties() as f64;
-        let team_win_value = *team_record.wins() as f64 + 0.5 * *team_record.ties() as f64;
-        let cutoff_loss_value = *cutoff_record.losses() as f64 + 0.5 * *cutoff_record.ties() as f64;
-        let team_loss_value = *team_record.losses() as f64 + 0.5 * *team_record.ties() as f64;
+        let leader_win_value = *leader.wins() as f64 + 0.5 * *leader.ties() as f64;
+        let team_win_value = *team.wins() as f64 + 0.5 * *team.ties() as f64;
+        let leader_loss_value = *leader.losses() as f64 + 0.5 * *leader.ties() as f64;
+        let team_loss_value = *team.losses() as f64 + 0.5 * *team.ties() as f64;
 
-        ((cutoff_win_value - team_win_value) + (team_loss_value - cutoff_loss_value)) / 2.0
+        ((leader_win_value - team_win_value) + (team_loss_value - leader_loss_value)) / 2.0
     }
 
     /// Compute a team's playoff status
@@ -627,13 +1663,14 @@ impl PlayoffPicture {
         team_id: usize,
         position: usize,
         bounds: &[RecordBounds],
+        remaining_games: &RemainingGames,
         num_playoff_teams: usize,
     ) -> PlayoffStatus {
         let in_playoff_position = position < num_playoff_teams;
         let current_seed = position + 1;
 
         // Check if eliminated (best case can't make playoffs)
-        if Self::is_eliminated(team_id, bounds, num_playoff_teams) {
+        if Self::is_eliminated(team_id, bounds, remaining_games, num_playoff_teams) {
             return PlayoffStatus::Eliminated;
         }
 
@@ -643,7 +1680,7 @@ impl PlayoffPicture {
         }
 
         // Check if clinched playoffs
-        if Self::has_clinched_playoffs(team_id, bounds, num_playoff_teams) {
+        if Self::has_clinched_playoffs(team_id, bounds, remaining_games, num_playoff_teams) {
             return PlayoffStatus::ClinchedPlayoffs { current_seed };
         }
 
@@ -656,13 +1693,56 @@ impl PlayoffPicture {
         PlayoffStatus::InTheHunt
     }
 
+    /// Check if a team has been eliminated from the wild card race, when
+    /// each division winner is guaranteed a playoff spot
+    ///
+    /// Of the teams which will definitely finish ahead of us, at most one
+    /// per division can take that division's guaranteed spot, so the rest
+    /// are ahead of us for a wild card.
+    fn is_eliminated_from_wild_card(
+        team_id: usize,
+        bounds: &[RecordBounds],
+        team_divisions: &BTreeMap<usize, usize>,
+        num_wild_cards: usize,
+    ) -> bool {
+        let team_bounds = match bounds.iter().find(|b| b.team_id == team_id) {
+            Some(b) => b,
+            None => return true,
+        };
+
+        // Collect the divisions of the teams definitely ahead of us
+        let divisions_ahead: Vec<Option<&usize>> = bounds
+            .iter()
+            .filter(|other_bounds| {
+                other_bounds.team_id != team_id && Self::would_finish_ahead(
+                    other_bounds,
+                    other_bounds.min_possible_wins,
+                    other_bounds.min_possible_win_pct,
+                    team_bounds,
+                    team_bounds.max_possible_wins,
+                    team_bounds.max_possible_win_pct,
+                    false,
+                )
+            })
+            .map(|other_bounds| team_divisions.get(&other_bounds.team_id))
+            .collect();
+        let mut distinct_divisions = divisions_ahead.clone();
+        distinct_divisions.sort();
+        distinct_divisions.dedup();
+
+        divisions_ahead.len() - distinct_divisions.len() >= num_wild_cards
+    }
+
     /// Check if a team has clinched a playoff spot
     ///
     /// A team clinches if: even when they lose ALL remaining games AND
-    /// all other teams win ALL remaining games, they still finish in top N
+    /// all other teams win ALL remaining games, they still finish in top N.
+    /// Two teams which still play each other cannot both win out, so a pair
+    /// of such teams which cannot both pass us counts as at most one team.
     fn has_clinched_playoffs(
         team_id: usize,
         bounds: &[RecordBounds],
+        remaining_games: &RemainingGames,
         num_playoff_teams: usize,
     ) -> bool {
         let team_bounds = match bounds.iter().find(|b| b.team_id == team_id) {
@@ -670,29 +1750,30 @@ impl PlayoffPicture {
             None => return false,
         };
 
-        // Count how many teams could potentially finish ahead of us
-        let mut teams_that_could_pass = 0;
-
-        for other_bounds in bounds.iter() {
-            if other_bounds.team_id == team_id {
-                continue;
-            }
-
-            // Can this team pass us in our worst case vs their best case?
-            if Self::would_finish_ahead(
-                other_bounds.max_possible_wins,
-                other_bounds.max_possible_win_pct,
-                other_bounds.team_id,
-                team_bounds.min_possible_wins,
-                team_bounds.min_possible_win_pct,
-                team_id,
-            ) {
-                teams_that_could_pass += 1;
-            }
-        }
+        // Collect the teams that could potentially finish ahead of us
+        let could_pass: Vec<&RecordBounds> = bounds
+            .iter()
+            .filter(|other_bounds| {
+                // Can this team pass us in our worst case vs their best case?
+                other_bounds.team_id != team_id && Self::would_finish_ahead(
+                    other_bounds,
+                    other_bounds.max_possible_wins,
+                    other_bounds.max_possible_win_pct,
+                    team_bounds,
+                    team_bounds.min_possible_wins,
+                    team_bounds.min_possible_win_pct,
+                    true,
+                )
+            })
+            .collect();
+
+        // Discount pairs which cannot both pass us given their mutual games
+        let exclusive_pairs = Self::count_head_to_head_pairs(&could_pass, remaining_games, |a, b, games| {
+            !Self::can_both_pass(a, b, games, team_bounds)
+        });
 
         // If fewer than num_playoff_teams could pass us, we've clinched
-        teams_that_could_pass < num_playoff_teams
+        could_pass.len() - exclusive_pairs < num_playoff_teams
     }
 
     /// Check if a team has clinched the #1 seed
@@ -709,12 +1790,13 @@ impl PlayoffPicture {
             }
 
             if Self::would_finish_ahead(
+                other_bounds,
                 other_bounds.max_possible_wins,
                 other_bounds.max_possible_win_pct,
-                other_bounds.team_id,
+                team_bounds,
                 team_bounds.min_possible_wins,
                 team_bounds.min_possible_win_pct,
-                team_id,
+                true,
             ) {
                 return false; // Someone could pass us
             }
@@ -725,10 +1807,14 @@ impl PlayoffPicture {
     /// Check if a team has been eliminated from playoff contention
     ///
     /// A team is eliminated if: even when they win ALL remaining games AND
-    /// all teams above them lose ALL remaining games, they still can't finish in top N
+    /// all teams above them lose ALL remaining games, they still can't finish in top N.
+    /// Two teams which still play each other cannot both lose out, so a pair
+    /// of such teams which cannot both stay behind us counts as one more team
+    /// definitely ahead.
     fn is_eliminated(
         team_id: usize,
         bounds: &[RecordBounds],
+        remaining_games: &RemainingGames,
         num_playoff_teams: usize,
     ) -> bool {
         let team_bounds = match bounds.iter().find(|b| b.team_id == team_id) {
@@ -738,6 +1824,7 @@ impl PlayoffPicture {
 
         // Count how many teams will definitely finish ahead of us
         let mut teams_definitely_ahead = 0;
+        let mut others: Vec<&RecordBounds> = Vec::new();
 
         for other_bounds in bounds.iter() {
             if other_bounds.team_id == team_id {
@@ -746,29 +1833,134 @@ impl PlayoffPicture {
 
             // Will this team definitely be ahead even in their worst case vs our best case?
             if Self::would_finish_ahead(
+                other_bounds,
                 other_bounds.min_possible_wins,
                 other_bounds.min_possible_win_pct,
-                other_bounds.team_id,
+                team_bounds,
                 team_bounds.max_possible_wins,
                 team_bounds.max_possible_win_pct,
-                team_id,
+                false,
             ) {
                 teams_definitely_ahead += 1;
+            } else {
+                others.push(other_bounds);
             }
         }
 
+        // Count pairs of the remaining teams which cannot both stay behind us
+        teams_definitely_ahead += Self::count_head_to_head_pairs(&others, remaining_games, |a, b, games| {
+            !Self::can_both_stay_behind(a, b, games, team_bounds)
+        });
+
         // If num_playoff_teams or more will definitely be ahead, we're eliminated
         teams_definitely_ahead >= num_playoff_teams
     }
 
-    /// Determine if a team would finish ahead of another team given their records
+    /// Count disjoint pairs of teams which still play each other and satisfy
+    /// the given predicate, pairing teams greedily in order
+    fn count_head_to_head_pairs(
+        teams: &[&RecordBounds],
+        remaining_games: &RemainingGames,
+        predicate: impl Fn(&RecordBounds, &RecordBounds, usize) -> bool,
+    ) -> usize {
+        let mut paired = vec![false; teams.len()];
+        let mut pairs = 0;
+        for i in 0..teams.len() {
+            if paired[i] {
+                continue;
+            }
+            for j in (i + 1)..teams.len() {
+                if paired[j] {
+                    continue;
+                }
+                let games = remaining_games.between(teams[i].team_id, teams[j].team_id);
+                if games > 0 && predicate(teams[i], teams[j], games) {
+                    paired[i] = true;
+                    paired[j] = true;
+                    pairs += 1;
+                    break;
+                }
+            }
+        }
+        pairs
+    }
+
+    /// Enumerate the possible splits of the games between two teams as
+    /// (wins for the first team, ties, wins for the second team)
+    fn head_to_head_splits(games: usize) -> impl Iterator<Item = (usize, usize, usize)> {
+        (0..=games).flat_map(move |wins| {
+            (0..=(games - wins)).map(move |ties| (wins, ties, games - wins - ties))
+        })
+    }
+
+    /// Compute a team's win percentage given its final wins and ties
+    fn win_pct(bounds: &RecordBounds, wins: usize, ties: usize) -> f64 {
+        if bounds.total_games > 0 {
+            (wins as f64 + 0.5 * ties as f64) / bounds.total_games as f64
+        } else {
+            0.0
+        }
+    }
+
+    /// Determine whether two teams which play each other `games` more times
+    /// can both finish ahead of a team which loses all of its remaining games
+    fn can_both_pass(a: &RecordBounds, b: &RecordBounds, games: usize, team: &RecordBounds) -> bool {
+        Self::head_to_head_splits(games).any(|(a_wins, ties, b_wins)| {
+            [(a, a_wins), (b, b_wins)].iter().all(|(other, h2h_wins)| {
+                // The other team wins every game besides the head-to-head games
+                let wins = other.current_wins + other.remaining_games - games + h2h_wins;
+                let ties = other.current_ties + ties;
+                Self::would_finish_ahead(
+                    other,
+                    wins,
+                    Self::win_pct(other, wins, ties),
+                    team,
+                    team.min_possible_wins,
+                    team.min_possible_win_pct,
+                    true,
+                )
+            })
+        })
+    }
+
+    /// Determine whether two teams which play each other `games` more times
+    /// can both finish behind a team which wins all of its remaining games
+    fn can_both_stay_behind(a: &RecordBounds, b: &RecordBounds, games: usize, team: &RecordBounds) -> bool {
+        Self::head_to_head_splits(games).any(|(a_wins, ties, b_wins)| {
+            [(a, a_wins), (b, b_wins)].iter().all(|(other, h2h_wins)| {
+                // The other team loses every game besides the head-to-head games
+                let wins = other.current_wins + h2h_wins;
+                let ties = other.current_ties + ties;
+                !Self::would_finish_ahead(
+                    other,
+                    wins,
+                    Self::win_pct(other, wins, ties),
+                    team,
+                    team.max_possible_wins,
+                    team.max_possible_win_pct,
+                    false,
+                )
+            })
+        })
+    }
+
+    /// Determine if a team would finish ahead of another team given their
+    /// records
+    ///
+    /// A tie in record is broken by the rest of the tiebreaker chain, which
+    /// depends on games not yet played until the group has finished its
+    /// season. Until then the tie is unresolved, and `tie_ahead` says which
+    /// way it counts: as finishing ahead when asking whether a team could
+    /// pass another, and as finishing behind when asking whether a team
+    /// definitely finishes ahead, so that it never clinches or eliminates.
     fn would_finish_ahead(
+        team1: &RecordBounds,
         team1_wins: usize,
         team1_pct: f64,
-        team1_id: usize,
+        team2: &RecordBounds,
         team2_wins: usize,
         team2_pct: f64,
-        team2_id: usize,
+        tie_ahead: bool,
     ) -> bool {
         // Primary: win percentage (higher is better)
         if (team1_pct - team2_pct).abs() > 1e-9 {
@@ -780,25 +1972,30 @@ impl PlayoffPicture {
             return team1_wins > team2_wins;
         }
 
-        // Tertiary: team ID (lower is better)
-        team1_id < team2_id
+        // Otherwise: the settled standings position, if the season is over
+        match (team1.final_position, team2.final_position) {
+            (Some(position1), Some(position2)) => position1 < position2,
+            _ => tie_ahead,
+        }
     }
 
     /// Calculate magic number for clinching playoffs, if applicable
     fn compute_magic_number(
         team_id: usize,
         bounds: &[RecordBounds],
+        remaining_games: &RemainingGames,
         num_playoff_teams: usize,
     ) -> Option<usize> {
         let team_bounds = bounds.iter().find(|b| b.team_id == team_id)?;
 
-        // If no remaining games, magic number is 0 if clinched, None otherwise
+        // If clinched, the magic number is 0
+        if Self::has_clinched_playoffs(team_id, bounds, remaining_games, num_playoff_teams) {
+            return Some(0);
+        }
+
+        // If no remaining games and not clinched, there is no magic number
         if team_bounds.remaining_games == 0 {
-            if Self::has_clinched_playoffs(team_id, bounds, num_playoff_teams) {
-                return Some(0);
-            } else {
-                return None;
-            }
+            return None;
         }
 
         // Find the team that would be at the cutoff (first team out)
@@ -1102,6 +2299,171 @@ impl PlayoffPicture {
     pub fn team_status(&self, team_id: usize) -> Option<&PlayoffPictureEntry> {
         self.entries.iter().find(|e| e.team_id == team_id)
     }
+
+    /// Get the conflicts between the playoff bracket and the standings
+    ///
+    /// Only populated by `PlayoffPicture::from_season_and_playoffs`
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPicture;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Get the playoff picture for a 2-team playoff
+    /// let picture = PlayoffPicture::from_season(&my_league_season, 2, None).unwrap();
+    /// assert!(picture.conflicts().is_empty());
+    /// ```
+    pub fn conflicts(&self) -> &Vec<String> {
+        &self.conflicts
+    }
+
+    /// Project a team's path to the championship from the current seeds
+    ///
+    /// Seeds the teams currently in playoff position into a bracket, then
+    /// plays out the bracket `trials` times with the team winning each of its
+    /// games. Every other game is won by the home team with probability
+    /// `away_seed / (home_seed + away_seed)`, so better seeds are favored
+    /// without being certain to advance. Each round lists the potential
+    /// opponents alongside how often the team faced them.
+    ///
+    /// ### Arguments
+    /// * `season` - The league season the picture was generated from
+    /// * `team_id` - The team whose path to project
+    /// * `trials` - Number of times to play out the bracket
+    /// * `rng` - The random number generator
+    ///
+    /// ### Returns
+    /// * `Ok(Vec<ProjectedPlayoffPathRound>)` - The projected path
+    /// * `Err(LeagueError)` - If the team is not in playoff position or `trials` is zero
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::season::playoffs::picture::PlayoffPicture;
+    ///
+    /// // Create a new season with 4 teams
+    /// let mut my_league_season = LeagueSeason::new();
+    /// my_league_season.add_team(0, FootballTeam::new());
+    /// my_league_season.add_team(1, FootballTeam::new());
+    /// my_league_season.add_team(2, FootballTeam::new());
+    /// my_league_season.add_team(3, FootballTeam::new());
+    ///
+    /// // Generate the season schedule
+    /// let mut rng = rand::thread_rng();
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng);
+    ///
+    /// // Project the path of the team in the top seed of a 4-team playoff
+    /// let picture = PlayoffPicture::from_season(&my_league_season, 4, None).unwrap();
+    /// let team_id = picture.playoff_teams()[0].team_id();
+    /// let path = picture.projected_path_to_championship(
+    ///     &my_league_season,
+    ///     team_id,
+    ///     100,
+    ///     &mut rng
+    /// ).unwrap();
+    /// assert!(path.len() == 2);
+    /// ```
+    pub fn projected_path_to_championship(
+        &self,
+        season: &LeagueSeason,
+        team_id: usize,
+        trials: usize,
+        rng: &mut impl Rng,
+    ) -> Result<Vec<ProjectedPlayoffPathRound>, LeagueError> {
+        if trials == 0 {
+            return Err(LeagueError::ZeroTrials);
+        }
+
+        // Get the seeded teams, ordered by conference and then by seed
+        let playoff_teams = self.playoff_teams();
+        let by_conference = season.populated_conferences().len() > 1 &&
+            playoff_teams.len() > self.num_playoff_teams;
+        let mut seeded: Vec<(Option<usize>, usize, usize)> = Vec::new();
+        for entry in playoff_teams.iter() {
+            let seed = match entry.status {
+                PlayoffStatus::ClinchedTopSeed => 1,
+                PlayoffStatus::ClinchedPlayoffs { current_seed } |
+                PlayoffStatus::InPlayoffPosition { current_seed } => current_seed,
+                _ => continue,
+            };
+            let conference = if by_conference {
+                season.conferences().iter().position(|c| c.contains_team(entry.team_id))
+            } else {
+                None
+            };
+            seeded.push((conference, seed, entry.team_id));
+        }
+        if !seeded.iter().any(|(_, _, id)| *id == team_id) {
+            return Err(LeagueError::TeamNotInPlayoffs { id: team_id });
+        }
+        seeded.sort();
+
+        // Seed the hypothetical bracket
+        let mut playoffs = LeagueSeasonPlayoffs::new();
+        for (conference, _, id) in seeded.iter() {
+            let short_name = season.team(*id).map(|t| t.short_name()).unwrap_or_default();
+            playoffs.add_team(*id, short_name, *conference)?;
+        }
+
+        // Tally the team's opponents in each round across all trials
+        let mut byes: Vec<usize> = Vec::new();
+        let mut tallies: Vec<BTreeMap<usize, (usize, usize)>> = Vec::new();
+        let mut rounds: Vec<(bool, usize)> = Vec::new();
+        for _ in 0..trials {
+            let path = playoffs.simulate_path(team_id, rng, |home_seed, away_seed, rng| {
+                rng.gen_bool(away_seed as f64 / (home_seed + away_seed) as f64)
+            })?;
+            for (i, round) in path.iter().enumerate() {
+                if i == rounds.len() {
+                    rounds.push((round.winners_bracket(), round.round()));
+                    byes.push(0);
+                    tallies.push(BTreeMap::new());
+                }
+                match round.opponent() {
+                    PlayoffPathOpponent::Bye => byes[i] += 1,
+                    PlayoffPathOpponent::Known { team_id, seed } |
+                    PlayoffPathOpponent::Projected { team_id, seed } => {
+                        tallies[i].entry(*team_id).or_insert((*seed, 0)).1 += 1;
+                    }
+                }
+            }
+        }
+
+        // Convert the tallies to probabilities, most likely opponent first
+        let projected = rounds.into_iter().enumerate().map(|(i, (winners_bracket, round))| {
+            let mut candidates: Vec<PlayoffPathCandidate> = tallies[i].iter().map(
+                |(id, (seed, count))| PlayoffPathCandidate {
+                    team_id: *id,
+                    seed: *seed,
+                    probability: *count as f64 / trials as f64,
+                }
+            ).collect();
+            candidates.sort_by(|a, b| b.probability.total_cmp(&a.probability)
+                .then(a.seed.cmp(&b.seed)));
+            ProjectedPlayoffPathRound {
+                winners_bracket,
+                round,
+                bye_probability: byes[i] as f64 / trials as f64,
+                candidates,
+            }
+        }).collect();
+        Ok(projected)
+    }
 }
 
 #[cfg(test)]
@@ -1114,25 +2476,45 @@ mod tests {
         assert!(PlayoffStatus::ClinchedTopSeed < PlayoffStatus::ClinchedPlayoffs { current_seed: 1 });
     }
 
+    /// Build the record bounds of a team with no games left
+    fn settled_bounds(team_id: usize, final_position: Option<usize>) -> RecordBounds {
+        let mut bounds = RecordBounds::from_record(team_id, &LeagueTeamRecord::new(), 0);
+        bounds.final_position = final_position;
+        bounds
+    }
+
     #[test]
     fn test_would_finish_ahead_by_pct() {
         // Team with higher win percentage should finish ahead
-        assert!(PlayoffPicture::would_finish_ahead(8, 0.8, 1, 6, 0.6, 2));
-        assert!(!PlayoffPicture::would_finish_ahead(6, 0.6, 2, 8, 0.8, 1));
+        let (a, b) = (settled_bounds(1, None), settled_bounds(2, None));
+        assert!(PlayoffPicture::would_finish_ahead(&a, 8, 0.8, &b, 6, 0.6, false));
+        assert!(!PlayoffPicture::would_finish_ahead(&b, 6, 0.6, &a, 8, 0.8, true));
     }
 
     #[test]
     fn test_would_finish_ahead_by_wins_tiebreaker() {
         // Same percentage, more wins should finish ahead
-        assert!(PlayoffPicture::would_finish_ahead(8, 0.5, 1, 4, 0.5, 2));
-        assert!(!PlayoffPicture::would_finish_ahead(4, 0.5, 2, 8, 0.5, 1));
+        let (a, b) = (settled_bounds(1, None), settled_bounds(2, None));
+        assert!(PlayoffPicture::would_finish_ahead(&a, 8, 0.5, &b, 4, 0.5, false));
+        assert!(!PlayoffPicture::would_finish_ahead(&b, 4, 0.5, &a, 8, 0.5, true));
+    }
+
+    #[test]
+    fn test_would_finish_ahead_unresolved_tie() {
+        // Same percentage and wins before the season is over, the tie counts
+        // whichever way the caller asks, whatever the team IDs
+        let (a, b) = (settled_bounds(1, None), settled_bounds(2, None));
+        assert!(PlayoffPicture::would_finish_ahead(&b, 8, 0.5, &a, 8, 0.5, true));
+        assert!(!PlayoffPicture::would_finish_ahead(&a, 8, 0.5, &b, 8, 0.5, false));
     }
 
     #[test]
-    fn test_would_finish_ahead_by_team_id_tiebreaker() {
-        // Same percentage and wins, lower team ID wins
-        assert!(PlayoffPicture::would_finish_ahead(8, 0.5, 1, 8, 0.5, 2));
-        assert!(!PlayoffPicture::would_finish_ahead(8, 0.5, 2, 8, 0.5, 1));
+    fn test_would_finish_ahead_settled_tie() {
+        // Same percentage and wins once the season is over, the standings
+        // position from the tiebreaker chain decides
+        let (a, b) = (settled_bounds(1, Some(1)), settled_bounds(2, Some(0)));
+        assert!(PlayoffPicture::would_finish_ahead(&b, 8, 0.5, &a, 8, 0.5, false));
+        assert!(!PlayoffPicture::would_finish_ahead(&a, 8, 0.5, &b, 8, 0.5, true));
     }
 
     #[test]
@@ -1145,6 +2527,7 @@ mod tests {
             games_back: 0.0,
             remaining_games: 0,
             magic_number: Some(0),
+            projected_record: None,
         };
         assert!(entry.is_clinched());
         assert!(!entry.is_eliminated());
@@ -1160,6 +2543,7 @@ mod tests {
             games_back: 5.0,
             remaining_games: 0,
             magic_number: None,
+            projected_record: None,
         };
         assert!(!entry.is_clinched());
         assert!(entry.is_eliminated());
@@ -1171,7 +2555,7 @@ mod tests {
         use crate::league::season::{LeagueSeason, LeagueSeasonScheduleOptions};
 
         // Create a season with 4 teams
-        let mut season = LeagueSeason::new();
+        let mut season = LeagueSeason::with_year(2026);
         season.add_team(0, FootballTeam::new()).unwrap();
         season.add_team(1, FootballTeam::new()).unwrap();
         season.add_team(2, FootballTeam::new()).unwrap();
@@ -1207,7 +2591,7 @@ mod tests {
         use crate::league::season::{LeagueSeason, LeagueSeasonScheduleOptions};
 
         // Create a season with 4 teams
-        let mut season = LeagueSeason::new();
+        let mut season = LeagueSeason::with_year(2026);
         season.add_team(0, FootballTeam::new()).unwrap();
         season.add_team(1, FootballTeam::new()).unwrap();
         season.add_team(2, FootballTeam::new()).unwrap();
@@ -1246,7 +2630,7 @@ mod tests {
         use crate::league::season::{LeagueSeason, LeagueSeasonScheduleOptions};
 
         // Create a season with 4 teams
-        let mut season = LeagueSeason::new();
+        let mut season = LeagueSeason::with_year(2026);
         season.add_team(0, FootballTeam::new()).unwrap();
         season.add_team(1, FootballTeam::new()).unwrap();
         season.add_team(2, FootballTeam::new()).unwrap();
@@ -1281,7 +2665,7 @@ mod tests {
         use crate::league::season::{LeagueSeason, LeagueSeasonScheduleOptions};
 
         // Create a season with 4 teams
-        let mut season = LeagueSeason::new();
+        let mut season = LeagueSeason::with_year(2026);
         season.add_team(0, FootballTeam::new()).unwrap();
         season.add_team(1, FootballTeam::new()).unwrap();
         season.add_team(2, FootballTeam::new()).unwrap();
@@ -1298,6 +2682,10 @@ mod tests {
         // Test: num_playoff_teams > total teams should fail
         let result = PlayoffPicture::from_season(&season, 5, None);
         assert!(result.is_err());
+
+        // Test: a raw picture with no playoff spots fails validation
+        let result = PlayoffPictureRaw::default().validate();
+        assert!(matches!(result, Err(LeagueError::InvalidPlayoffPicture { .. })));
     }
 
     #[test]
@@ -1306,7 +2694,7 @@ mod tests {
         use crate::league::season::LeagueSeason;
 
         // Create a season with teams but no schedule
-        let mut season = LeagueSeason::new();
+        let mut season = LeagueSeason::with_year(2026);
         season.add_team(0, FootballTeam::new()).unwrap();
         season.add_team(1, FootballTeam::new()).unwrap();
 
@@ -1321,7 +2709,7 @@ mod tests {
         use crate::league::season::{LeagueSeason, LeagueSeasonScheduleOptions};
 
         // Create a season with 4 teams
-        let mut season = LeagueSeason::new();
+        let mut season = LeagueSeason::with_year(2026);
         season.add_team(0, FootballTeam::new()).unwrap();
         season.add_team(1, FootballTeam::new()).unwrap();
         season.add_team(2, FootballTeam::new()).unwrap();
@@ -1351,7 +2739,7 @@ mod tests {
         use crate::team::FootballTeam;
         use crate::league::season::{LeagueSeason, LeagueSeasonScheduleOptions};
 
-        let mut season = LeagueSeason::new();
+        let mut season = LeagueSeason::with_year(2026);
         season.add_team(0, FootballTeam::new()).unwrap();
         season.add_team(1, FootballTeam::new()).unwrap();
         season.add_team(2, FootballTeam::new()).unwrap();
@@ -1378,4 +2766,601 @@ mod tests {
             assert!(entry.games_back() >= 0.0);
         }
     }
+
+    #[test]
+    fn test_playoff_picture_pre_seeded_bracket() {
+        use crate::team::FootballTeam;
+        use crate::league::season::{LeagueSeason, LeagueSeasonScheduleOptions};
+        use crate::league::season::playoffs::LeagueSeasonPlayoffs;
+
+        let mut season = LeagueSeason::with_year(2026);
+        season.add_team(0, FootballTeam::new()).unwrap();
+        season.add_team(1, FootballTeam::new()).unwrap();
+        season.add_team(2, FootballTeam::new()).unwrap();
+        season.add_team(3, FootballTeam::new()).unwrap();
+
+        let mut rng = rand::thread_rng();
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+
+        // Simulate all but the last week
+        let total_weeks = season.weeks().len();
+        for week_idx in 0..(total_weeks - 1) {
+            season.sim_week(week_idx, &mut rng).unwrap();
+        }
+
+        // Pre-seed the standings leader, leaving one wild-card spot open
+        let leader = season.standings()[0].0;
+        let mut playoffs = LeagueSeasonPlayoffs::new();
+        playoffs.add_team(leader, "LEAD", None).unwrap();
+
+        let picture = PlayoffPicture::from_season_and_playoffs(&season, &playoffs, 2, None).unwrap();
+        assert_eq!(picture.entries().len(), 4);
+        assert_eq!(picture.num_playoff_teams(), 2);
+        assert!(picture.conflicts().is_empty());
+
+        // The bracketed team has clinched its actual seed
+        let entry = picture.team_status(leader).unwrap();
+        assert_eq!(*entry.status(), PlayoffStatus::ClinchedTopSeed);
+        assert_eq!(entry.magic_number(), Some(0));
+
+        // Exactly one other team holds the open second seed
+        assert_eq!(picture.playoff_teams().len(), 2);
+        for entry in picture.entries().iter().filter(|e| e.team_id() != leader) {
+            assert!(!matches!(
+                entry.status(),
+                PlayoffStatus::ClinchedTopSeed
+                    | PlayoffStatus::ClinchedPlayoffs { current_seed: 1 }
+                    | PlayoffStatus::InPlayoffPosition { current_seed: 1 }
+            ));
+        }
+    }
+
+    #[test]
+    fn test_playoff_picture_bracket_conflict() {
+        use crate::team::FootballTeam;
+        use crate::league::season::{LeagueSeason, LeagueSeasonScheduleOptions};
+        use crate::league::season::playoffs::LeagueSeasonPlayoffs;
+
+        let mut season = LeagueSeason::with_year(2026);
+        season.add_team(0, FootballTeam::new()).unwrap();
+        season.add_team(1, FootballTeam::new()).unwrap();
+        season.add_team(2, FootballTeam::new()).unwrap();
+        season.add_team(3, FootballTeam::new()).unwrap();
+
+        let mut rng = rand::thread_rng();
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        season.sim_regular_season(&mut rng).unwrap();
+
+        // Seed the last place team, which the standings say is out
+        let last = season.standings()[3].0;
+        let mut playoffs = LeagueSeasonPlayoffs::new();
+        playoffs.add_team(last, "LAST", None).unwrap();
+
+        let picture = PlayoffPicture::from_season_and_playoffs(&season, &playoffs, 2, None).unwrap();
+        assert_eq!(picture.conflicts().len(), 1);
+        assert_eq!(*picture.team_status(last).unwrap().status(), PlayoffStatus::ClinchedTopSeed);
+
+        // A bracket larger than the number of playoff spots is an error
+        let mut playoffs = LeagueSeasonPlayoffs::new();
+        playoffs.add_team(0, "A", None).unwrap();
+        playoffs.add_team(1, "B", None).unwrap();
+        playoffs.add_team(2, "C", None).unwrap();
+        assert!(PlayoffPicture::from_season_and_playoffs(&season, &playoffs, 2, None).is_err());
+    }
+
+    #[test]
+    fn test_path_to_championship_with_bye() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::game::context::GameContextBuilder;
+        use crate::league::season::playoffs::{LeagueSeasonPlayoffs, PlayoffPathOpponent};
+
+        // Seed a 6-team conference by team ID, giving the top 2 seeds byes
+        let mut playoffs = LeagueSeasonPlayoffs::new();
+        for (id, name) in ["A", "B", "C", "D", "E", "F"].iter().enumerate() {
+            playoffs.add_team(id, name, None).unwrap();
+        }
+        let mut rng = SmallRng::seed_from_u64(0);
+        playoffs.gen_next_playoff_round(&mut rng).unwrap();
+
+        // Play the wild card round with the 5 seed upsetting the 4 seed
+        for matchup in playoffs.conference_bracket_mut(0).unwrap()[0].matchups_mut() {
+            let home_wins = *matchup.home_team() == 2;
+            *matchup.context_mut() = GameContextBuilder::new()
+                .quarter(4)
+                .half_seconds(0)
+                .home_score(if home_wins { 1 } else { 0 })
+                .away_score(if home_wins { 0 } else { 1 })
+                .game_over(true)
+                .build()
+                .unwrap();
+        }
+
+        // The top seed has a bye, then faces the 5 seed and projects the 2 seed
+        let path = playoffs.path_to_championship(0).unwrap();
+        assert_eq!(path.len(), 3);
+        assert_eq!(*path[0].opponent(), PlayoffPathOpponent::Bye);
+        assert_eq!(*path[1].opponent(), PlayoffPathOpponent::Projected { team_id: 4, seed: 5 });
+        assert_eq!(*path[2].opponent(), PlayoffPathOpponent::Projected { team_id: 1, seed: 2 });
+        assert!(path.iter().all(|r| !r.winners_bracket()));
+
+        // Once the next round exists its opponent is known
+        playoffs.gen_next_playoff_round(&mut rng).unwrap();
+        let path = playoffs.path_to_championship(0).unwrap();
+        assert_eq!(*path[1].opponent(), PlayoffPathOpponent::Known { team_id: 4, seed: 5 });
+
+        // The eliminated 4 seed's path ends at its loss
+        let path = playoffs.path_to_championship(3).unwrap();
+        assert_eq!(path.len(), 1);
+        assert_eq!(*path[0].opponent(), PlayoffPathOpponent::Known { team_id: 4, seed: 5 });
+    }
+
+    #[test]
+    fn test_projected_path_to_championship_with_bye() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::team::FootballTeam;
+        use crate::league::season::{LeagueSeason, LeagueSeasonScheduleOptions};
+
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        let mut rng = SmallRng::seed_from_u64(0);
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+
+        // Every team is in a 6-team playoff, so get each team's seed
+        let picture = PlayoffPicture::from_season(&season, 6, None).unwrap();
+        let seed_of = |seed: usize| picture.playoff_teams().iter().find(|e| match e.status() {
+            PlayoffStatus::ClinchedTopSeed => seed == 1,
+            PlayoffStatus::ClinchedPlayoffs { current_seed } |
+            PlayoffStatus::InPlayoffPosition { current_seed } => *current_seed == seed,
+            _ => false,
+        }).unwrap().team_id();
+        let top_seed = seed_of(1);
+        let path = picture.projected_path_to_championship(&season, top_seed, 2000, &mut rng).unwrap();
+        assert_eq!(path.len(), 3);
+
+        // The top seed always has a first-round bye
+        assert_eq!(path[0].bye_probability(), 1.0);
+        assert!(path[0].candidates().is_empty());
+
+        // The second round opponent is the 4 or 5 seed, favoring the 4 seed
+        let candidates = path[1].candidates();
+        assert_eq!(path[1].bye_probability(), 0.0);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].team_id(), seed_of(4));
+        assert_eq!(candidates[1].team_id(), seed_of(5));
+        let total: f64 = candidates.iter().map(|c| c.probability()).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+
+        // The championship opponent comes from the other half of the bracket
+        let finalists: Vec<usize> = path[2].candidates().iter().map(|c| c.seed()).collect();
+        assert_eq!(finalists[0], 2);
+        assert!(finalists.iter().all(|s| [2, 3, 6].contains(s)));
+
+        // Teams outside the playoff picture have no path
+        assert!(picture.projected_path_to_championship(&season, 99, 10, &mut rng).is_err());
+        assert!(picture.projected_path_to_championship(&season, top_seed, 0, &mut rng).is_err());
+    }
+
+    /// Build a 4-team season from the scores of the played weeks, followed
+    /// by the matchups of the unplayed weeks
+    fn forced_season(
+        played: Vec<Vec<(usize, usize, u32, u32)>>,
+        unplayed: Vec<Vec<(usize, usize)>>,
+    ) -> crate::league::season::LeagueSeason {
+        forced_season_with_teams(4, played, unplayed)
+    }
+
+    /// Build a season of the given number of teams with forced results
+    fn forced_season_with_teams(
+        num_teams: usize,
+        played: Vec<Vec<(usize, usize, u32, u32)>>,
+        unplayed: Vec<Vec<(usize, usize)>>,
+    ) -> crate::league::season::LeagueSeason {
+        use crate::game::context::GameContextBuilder;
+        use crate::team::FootballTeam;
+        use crate::league::season::LeagueSeason;
+        use crate::league::season::matchup::LeagueSeasonMatchup;
+        use crate::league::season::week::LeagueSeasonWeek;
+
+        let mut rng = rand::thread_rng();
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..num_teams {
+            season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        for games in played {
+            let mut week = LeagueSeasonWeek::new();
+            for (home, away, home_score, away_score) in games {
+                let mut matchup = LeagueSeasonMatchup::new(home, away, "HOME", "AWAY", &mut rng);
+                *matchup.context_mut() = GameContextBuilder::new()
+                    .quarter(4)
+                    .half_seconds(0)
+                    .home_score(home_score)
+                    .away_score(away_score)
+                    .game_over(true)
+                    .build()
+                    .unwrap();
+                week.matchups_mut().push(matchup);
+            }
+            season.weeks_mut().push(week);
+        }
+        for games in unplayed {
+            let mut week = LeagueSeasonWeek::new();
+            for (home, away) in games {
+                week.matchups_mut().push(LeagueSeasonMatchup::new(home, away, "HOME", "AWAY", &mut rng));
+            }
+            season.weeks_mut().push(week);
+        }
+        season
+    }
+
+    /// Get a team's (wins, losses, ties) from a playoff picture
+    fn picture_record(picture: &PlayoffPicture, team_id: usize) -> (usize, usize, usize) {
+        let record = picture.team_status(team_id).unwrap().current_record();
+        (*record.wins(), *record.losses(), *record.ties())
+    }
+
+    /// Build a 16-week season in which teams 0 and 1 are 8-6 and play each
+    /// other in both remaining weeks, team 2 is 7-7, and team 3 is 5-9
+    fn head_to_head_race_season() -> crate::league::season::LeagueSeason {
+        // Alternate between two sets of pairings, with the number of games
+        // each home team wins out of the 7 it plays against its opponent
+        let pairings = [[(0, 2, 4), (1, 3, 5)], [(0, 3, 4), (1, 2, 3)]];
+        let played = (0..14)
+            .map(|week| {
+                pairings[week % 2]
+                    .iter()
+                    .map(|(home, away, home_wins)| {
+                        if week / 2 < *home_wins { (*home, *away, 1, 0) } else { (*home, *away, 0, 1) }
+                    })
+                    .collect()
+            })
+            .collect();
+        forced_season(played, vec![vec![(0, 1), (2, 3)]; 2])
+    }
+
+    #[test]
+    fn test_head_to_head_race_neither_clinched() {
+        let season = head_to_head_race_season();
+        let picture = PlayoffPicture::from_season(&season, 1, None).unwrap();
+        assert_eq!(picture_record(&picture, 0), (8, 6, 0));
+        assert_eq!(picture_record(&picture, 1), (8, 6, 0));
+        assert_eq!(picture_record(&picture, 2), (7, 7, 0));
+
+        // Either team in the race can still take the spot
+        assert_eq!(
+            *picture.team_status(0).unwrap().status(),
+            PlayoffStatus::InPlayoffPosition { current_seed: 1 }
+        );
+        assert_eq!(*picture.team_status(1).unwrap().status(), PlayoffStatus::InTheHunt);
+
+        // Team 2 stays alive only if both head-to-head games end in ties,
+        // leaving the race at 8-6-2 and team 2 ahead on wins at 9-7
+        assert_eq!(*picture.team_status(2).unwrap().status(), PlayoffStatus::InTheHunt);
+        assert_eq!(*picture.team_status(3).unwrap().status(), PlayoffStatus::Eliminated);
+    }
+
+    #[test]
+    fn test_head_to_head_eliminates_third_team() {
+        // Teams 0 and 1 are 9-5 and play each other twice more, while team 2
+        // is 7-6-1 with two games left against team 3
+        let mut played = vec![
+            vec![(0, 1, 1, 0), (2, 3, 0, 0)],
+            vec![(0, 1, 0, 1), (2, 3, 0, 1)],
+        ];
+        for week in 0..6 {
+            played.push(vec![(0, 2, if week < 3 { 1 } else { 0 }, if week < 3 { 0 } else { 1 }), (1, 3, 1, 0)]);
+            played.push(vec![(0, 3, if week < 5 { 1 } else { 0 }, if week < 5 { 0 } else { 1 }), (1, 2, if week < 2 { 1 } else { 0 }, if week < 2 { 0 } else { 1 })]);
+        }
+        let season = forced_season(played, vec![vec![(0, 1), (2, 3)]; 2]);
+        let picture = PlayoffPicture::from_season(&season, 1, None).unwrap();
+        assert_eq!(picture_record(&picture, 0), (9, 5, 0));
+        assert_eq!(picture_record(&picture, 1), (9, 5, 0));
+        assert_eq!(picture_record(&picture, 2), (7, 6, 1));
+
+        // Neither team in the race has clinched
+        assert_eq!(
+            *picture.team_status(0).unwrap().status(),
+            PlayoffStatus::InPlayoffPosition { current_seed: 1 }
+        );
+        assert_eq!(*picture.team_status(1).unwrap().status(), PlayoffStatus::InTheHunt);
+
+        // Team 2 can reach 9-6-1, beyond either team's current 9-5, but the
+        // race splits two games so one of its teams reaches at least 10 points
+        assert_eq!(*picture.team_status(2).unwrap().status(), PlayoffStatus::Eliminated);
+    }
+
+    #[test]
+    fn test_head_to_head_sweep_clinches() {
+        use crate::game::context::GameContextBuilder;
+
+        // Team 0 wins the first of its two remaining games against team 1
+        let mut season = head_to_head_race_season();
+        let win_week = |season: &mut crate::league::season::LeagueSeason, week: usize| {
+            for matchup in season.weeks_mut()[week].matchups_mut().iter_mut() {
+                *matchup.context_mut() = GameContextBuilder::new()
+                    .quarter(4)
+                    .half_seconds(0)
+                    .home_score(1)
+                    .away_score(0)
+                    .game_over(true)
+                    .build()
+                    .unwrap();
+            }
+        };
+        win_week(&mut season, 14);
+
+        // A split would tie the race at 9-7 and 1-1 head-to-head, leaving it
+        // to point differential, and team 2 can still reach 9-7 as well, so
+        // no team is decided yet
+        let picture = PlayoffPicture::from_season(&season, 1, None).unwrap();
+        assert_eq!(
+            *picture.team_status(0).unwrap().status(),
+            PlayoffStatus::InPlayoffPosition { current_seed: 1 }
+        );
+        assert_eq!(*picture.team_status(1).unwrap().status(), PlayoffStatus::InTheHunt);
+        assert_eq!(*picture.team_status(2).unwrap().status(), PlayoffStatus::InTheHunt);
+
+        // Winning the second game as well, team 0 can no longer be passed
+        win_week(&mut season, 15);
+        let picture = PlayoffPicture::from_season(&season, 1, None).unwrap();
+        assert_eq!(*picture.team_status(0).unwrap().status(), PlayoffStatus::ClinchedTopSeed);
+        assert_eq!(picture.team_status(0).unwrap().magic_number(), Some(0));
+        assert_eq!(*picture.team_status(1).unwrap().status(), PlayoffStatus::Eliminated);
+    }
+
+    #[test]
+    fn test_tie_at_cutoff_unresolved_until_settled() {
+        use crate::game::context::GameContextBuilder;
+
+        // Teams 0 and 1 finish 2-1 with team 1 winning the head-to-head game,
+        // while teams 2 and 3 are 1-2 with one game left against each other
+        let played = vec![
+            vec![(1, 0, 1, 0), (2, 3, 1, 0)],
+            vec![(0, 2, 1, 0), (3, 1, 1, 0)],
+            vec![(0, 3, 1, 0), (1, 2, 1, 0)],
+        ];
+        let mut season = forced_season(played, vec![vec![(2, 3)]]);
+        assert_eq!(season.standings()[0].0, 1);
+
+        // The tiebreaker chain may depend on games not yet played, so
+        // neither team tied for the spot has clinched or been eliminated
+        let picture = PlayoffPicture::from_season(&season, 1, None).unwrap();
+        assert_eq!(
+            *picture.team_status(1).unwrap().status(),
+            PlayoffStatus::InPlayoffPosition { current_seed: 1 }
+        );
+        assert_eq!(*picture.team_status(0).unwrap().status(), PlayoffStatus::InTheHunt);
+        assert_eq!(*picture.team_status(2).unwrap().status(), PlayoffStatus::Eliminated);
+        assert_eq!(*picture.team_status(3).unwrap().status(), PlayoffStatus::Eliminated);
+
+        // Once the season is over, the tie goes to team 1 on head-to-head
+        // rather than to the lower team ID
+        *season.weeks_mut()[3].matchups_mut()[0].context_mut() = GameContextBuilder::new()
+            .quarter(4)
+            .half_seconds(0)
+            .home_score(1)
+            .away_score(0)
+            .game_over(true)
+            .build()
+            .unwrap();
+        let picture = PlayoffPicture::from_season(&season, 1, None).unwrap();
+        assert_eq!(*picture.team_status(1).unwrap().status(), PlayoffStatus::ClinchedTopSeed);
+        assert_eq!(*picture.team_status(0).unwrap().status(), PlayoffStatus::Eliminated);
+    }
+
+    /// Build a 12-team season of two conferences, each with a division of
+    /// four and a division of two. In each conference the leader of the
+    /// four-team division is 7-0, its wild cards are 5-2 and 4-3, and the
+    /// other division is led by a 3-4 team over a 2-5 team, whom it plays
+    /// in both remaining weeks
+    fn weak_division_leader_season() -> crate::league::season::LeagueSeason {
+        use crate::league::season::conference::{LeagueConference, LeagueDivision};
+
+        // Each game is (home, away, whether the home team won)
+        let weeks = [
+            [(0, 1, true), (2, 4, true), (3, 5, false)],
+            [(0, 3, true), (1, 4, true), (2, 5, true)],
+            [(0, 5, true), (1, 2, true), (3, 4, false)],
+            [(0, 3, true), (1, 4, true), (2, 5, true)],
+            [(0, 3, true), (1, 4, false), (2, 5, true)],
+            [(0, 4, true), (1, 3, true), (2, 5, false)],
+            [(0, 3, true), (1, 2, true), (4, 5, true)],
+        ];
+        let played = weeks
+            .iter()
+            .map(|games| {
+                [0, 6]
+                    .iter()
+                    .flat_map(|offset| {
+                        games.iter().map(move |(home, away, home_won)| {
+                            let (home_score, away_score) = if *home_won { (1, 0) } else { (0, 1) };
+                            (home + offset, away + offset, home_score, away_score)
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+        let unplayed = vec![vec![(4, 5), (10, 11)], vec![(5, 4), (11, 10)]];
+        let mut season = forced_season_with_teams(12, played, unplayed);
+        for (name, offset) in [("AC", 0), ("BC", 6)] {
+            let mut conference = LeagueConference::with_name(name);
+            let mut large = LeagueDivision::with_name("Large");
+            for team_id in offset..offset + 4 {
+                large.add_team(team_id).unwrap();
+            }
+            let mut small = LeagueDivision::with_name("Small");
+            small.add_team(offset + 4).unwrap();
+            small.add_team(offset + 5).unwrap();
+            conference.add_division(large).unwrap();
+            conference.add_division(small).unwrap();
+            season.add_conference(conference).unwrap();
+        }
+        season
+    }
+
+    #[test]
+    fn test_guaranteed_division_winner_seeded_below_wild_cards() {
+        let season = weak_division_leader_season();
+        assert_eq!(season.standings().len(), 12);
+
+        // By record alone the 3-4 division leader sits fourth in its
+        // conference, outside a 3-team playoff
+        let picture = PlayoffPicture::from_season(&season, 3, None).unwrap();
+        assert_eq!(picture_record(&picture, 4), (3, 4, 0));
+        assert_eq!(*picture.team_status(4).unwrap().status(), PlayoffStatus::Eliminated);
+
+        // With division winners guaranteed it takes the second seed, and
+        // the 4-3 wild card is displaced
+        let options = PlayoffPictureOptions::new().division_winners_guaranteed(true);
+        let picture = PlayoffPicture::from_season(&season, 3, Some(options)).unwrap();
+        for offset in [0, 6] {
+            assert_eq!(
+                *picture.team_status(offset).unwrap().status(),
+                PlayoffStatus::ClinchedTopSeed
+            );
+            assert_eq!(
+                *picture.team_status(offset + 4).unwrap().status(),
+                PlayoffStatus::InPlayoffPosition { current_seed: 2 }
+            );
+            assert_eq!(picture.team_status(offset + 4).unwrap().games_back(), 0.0);
+            assert_eq!(
+                *picture.team_status(offset + 1).unwrap().status(),
+                PlayoffStatus::ClinchedPlayoffs { current_seed: 3 }
+            );
+
+            // The displaced wild card has finished its season a game behind
+            // the last wild card, which is closer than its division leader
+            let displaced = picture.team_status(offset + 2).unwrap();
+            assert_eq!(*displaced.status(), PlayoffStatus::Eliminated);
+            assert_eq!(displaced.games_back(), 1.0);
+
+            // The 2-5 team cannot catch the wild cards, but can still win
+            // its division by winning both games against the leader, so it
+            // is a game back of the division lead rather than eliminated
+            let challenger = picture.team_status(offset + 5).unwrap();
+            assert_eq!(*challenger.status(), PlayoffStatus::InTheHunt);
+            assert_eq!(challenger.games_back(), 1.0);
+
+            // The winless team is eliminated, and measured against the
+            // closer wild card race
+            let last = picture.team_status(offset + 3).unwrap();
+            assert_eq!(*last.status(), PlayoffStatus::Eliminated);
+            assert_eq!(last.games_back(), 5.0);
+        }
+    }
+
+    #[test]
+    fn test_projected_records_lie_within_bounds() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::team::FootballTeam;
+        use crate::league::season::{LeagueSeason, LeagueSeasonScheduleOptions};
+
+        // Create a season of 6 teams of increasing strength
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            let overall = 20 + 12 * id as u32;
+            let team = FootballTeam::from_overalls("Team", &format!("T{}", id), overall, overall).unwrap();
+            season.add_team(id, team).unwrap();
+        }
+        let mut rng = SmallRng::seed_from_u64(1472);
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        for week in 0..2 {
+            season.sim_week(week, &mut rng).unwrap();
+        }
+
+        // Projections are omitted unless requested
+        let picture = PlayoffPicture::from_season(&season, 2, None).unwrap();
+        assert!(picture.entries().iter().all(|e| e.projected_record().is_none()));
+
+        // Projected wins lie between the fewest and most possible wins
+        let options = PlayoffPictureOptions::new().include_projections(true);
+        let picture = PlayoffPicture::from_season(&season, 2, Some(options)).unwrap();
+        for entry in picture.entries() {
+            let projected = entry.projected_record().unwrap();
+            let wins = *entry.current_record().wins() as f64;
+            assert!(projected.wins >= wins);
+            assert!(projected.wins <= wins + entry.remaining_games() as f64);
+
+            // Every game is accounted for
+            let record = entry.current_record();
+            let played = (record.wins() + record.losses() + record.ties()) as f64;
+            let projected_games = projected.wins + projected.losses + projected.ties;
+            assert!((projected_games - played - entry.remaining_games() as f64).abs() < 1e-9);
+        }
+
+        // The strongest team is projected to finish with more wins than the weakest
+        let projected_wins = |id: usize| picture.team_status(id).unwrap().projected_record().unwrap().wins;
+        assert!(projected_wins(5) - *picture.team_status(5).unwrap().current_record().wins() as f64
+            > projected_wins(0) - *picture.team_status(0).unwrap().current_record().wins() as f64);
+    }
+
+    #[test]
+    fn test_playoff_picture_round_trip() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+        use crate::team::FootballTeam;
+        use crate::league::season::{LeagueSeason, LeagueSeasonScheduleOptions};
+
+        let mut season = LeagueSeason::with_year(2026);
+        for id in 0..6 {
+            season.add_team(id, FootballTeam::new()).unwrap();
+        }
+        let mut rng = SmallRng::seed_from_u64(1492);
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        for week in 0..3 {
+            season.sim_week(week, &mut rng).unwrap();
+        }
+
+        // A picture with projections survives a JSON round trip unchanged
+        let options = PlayoffPictureOptions::new().include_projections(true);
+        let picture = PlayoffPicture::from_season(&season, 2, Some(options)).unwrap();
+        let json = serde_json::to_string(&picture).unwrap();
+        let restored: PlayoffPicture = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, picture);
+        assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+
+        // Invalid pictures are rejected on deserialize
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["num_playoff_teams"] = serde_json::json!(0);
+        assert!(serde_json::from_value::<PlayoffPicture>(value).is_err());
+
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["entries"][3]["games_back"] = serde_json::json!(-1.5);
+        assert!(serde_json::from_value::<PlayoffPicture>(value).is_err());
+
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["entries"][1]["team_id"] = value["entries"][0]["team_id"].clone();
+        assert!(serde_json::from_value::<PlayoffPicture>(value).is_err());
+
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["entries"][4]["status"] = serde_json::json!({ "InPlayoffPosition": { "current_seed": 3 } });
+        assert!(serde_json::from_value::<PlayoffPicture>(value).is_err());
+
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        for index in 0..3 {
+            value["entries"][index]["status"] = serde_json::json!({ "InPlayoffPosition": { "current_seed": 2 } });
+        }
+        assert!(serde_json::from_value::<PlayoffPicture>(value).is_err());
+    }
+
+    #[cfg(feature = "rocket_okapi")]
+    #[test]
+    fn test_playoff_picture_schema() {
+        // The picture and its options generate JSON schemas with every field
+        let schema = serde_json::to_value(schemars::schema_for!(PlayoffPicture)).unwrap();
+        for field in ["num_playoff_teams", "entries", "games_remaining_in_season", "conflicts"] {
+            assert!(schema["properties"].get(field).is_some(), "{}", field);
+        }
+        for definition in ["PlayoffPictureEntry", "PlayoffStatus", "ProjectedRecord", "LeagueTeamRecord"] {
+            assert!(schema["definitions"].get(definition).is_some(), "{}", definition);
+        }
+        let schema = serde_json::to_value(schemars::schema_for!(PlayoffPictureOptions)).unwrap();
+        for field in ["by_conference", "division_winners_guaranteed", "seeding_rule", "include_projections"] {
+            assert!(schema["properties"].get(field).is_some(), "{}", field);
+        }
+    }
 }
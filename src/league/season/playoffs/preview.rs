@@ -0,0 +1,371 @@
+#![doc = include_str!("../../../../docs/league/season/playoffs/preview.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+use crate::league::error::LeagueError;
+use crate::game::score::FinalScoreSimulator;
+use crate::league::matchup::LeagueTeamRecord;
+use crate::league::season::LeagueSeason;
+pub use crate::league::season::head_to_head::HeadToHeadGame;
+use crate::league::season::head_to_head::HeadToHeadSeries;
+use crate::league::season::matchup::LeagueSeasonMatchup;
+use crate::league::season::playoffs::LeagueSeasonPlayoffs;
+use crate::league::season::week::LeagueSeasonWeek;
+
+/// # `PlayoffGameSummary` struct
+///
+/// A completed playoff game from one team's point of view
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct PlayoffGameSummary {
+    /// Whether the game was played in the winners bracket rather than the
+    /// team's conference bracket
+    pub winners_bracket: bool,
+    /// Index of the round within its bracket
+    pub round: usize,
+    /// The opponent's ID
+    pub opponent: usize,
+    /// The opponent's seed
+    pub opponent_seed: usize,
+    /// Whether the team was the home team
+    pub home: bool,
+    /// The points the team scored
+    pub points_for: u32,
+    /// The points the team allowed
+    pub points_against: u32,
+    /// Whether the team won
+    pub won: bool
+}
+
+/// # `PlayoffPreviewContender` struct
+///
+/// One side of a playoff matchup preview
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct PlayoffPreviewContender {
+    /// The team's ID
+    pub team_id: usize,
+    /// The team's short name
+    pub short_name: String,
+    /// The team's seed within its conference bracket
+    pub seed: usize,
+    /// The conference bracket the team was seeded into
+    pub conference: usize,
+    /// The team's regular season record
+    pub record: LeagueTeamRecord,
+    /// The team's completed playoff games before the previewed round, in
+    /// the order they were played
+    pub path: Vec<PlayoffGameSummary>,
+    /// The bonus to the team's skill levels from extra rest, if it is the
+    /// better rested team
+    pub rest_bonus: u32
+}
+
+/// # `PlayoffMatchupPreview` struct
+///
+/// A preview of a single playoff matchup
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct PlayoffMatchupPreview {
+    /// The conference bracket of the matchup, or `None` for the winners
+    /// bracket
+    pub bracket: Option<usize>,
+    /// Index of the round within its bracket
+    pub round: usize,
+    /// Index of the matchup within its round
+    pub matchup: usize,
+    /// The home team
+    pub home: PlayoffPreviewContender,
+    /// The away team
+    pub away: PlayoffPreviewContender,
+    /// The teams' completed regular season meetings, in week order
+    pub head_to_head: Vec<HeadToHeadGame>,
+    /// Whether the teams play in the same conference
+    pub same_conference: bool,
+    /// Whether the teams play in the same division
+    pub same_division: bool,
+    /// Whether the matchup is played at a neutral site
+    pub neutral_site: bool,
+    /// The probability that the home team wins
+    pub home_win_probability: f64,
+    /// The winner, if the matchup has been played
+    pub winner: Option<usize>
+}
+
+impl PlayoffMatchupPreview {
+    /// Get the home team's wins, losses, and ties against the away team in
+    /// their regular season meetings
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::{LeagueSeason, LeagueSeasonPlayoffOptions, LeagueSeasonScheduleOptions};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league_season.sim_regular_season(&mut rng).unwrap();
+    /// let options = LeagueSeasonPlayoffOptions::new().num_playoff_teams(4);
+    /// my_league_season.generate_playoffs(options, &mut rng).unwrap();
+    ///
+    /// // Each semifinal opponent was met twice in the regular season
+    /// let playoffs = my_league_season.playoffs();
+    /// let previews = playoffs.round_previews(Some(0), 0, &my_league_season).unwrap();
+    /// let record = previews[0].head_to_head_record();
+    /// assert_eq!(*record.wins() + *record.losses() + *record.ties(), 2);
+    /// ```
+    pub fn head_to_head_record(&self) -> LeagueTeamRecord {
+        HeadToHeadSeries::new(self.home.team_id, self.away.team_id, self.head_to_head.clone()).record
+    }
+}
+
+/// Get the probability of a win given the probabilities of a win and a loss,
+/// excluding ties
+fn decisive_probability(p_win: f64, p_loss: f64) -> f64 {
+    if p_win + p_loss > 0.0 {
+        p_win / (p_win + p_loss)
+    } else {
+        0.5
+    }
+}
+
+impl LeagueSeasonPlayoffs {
+    /// Summarize a team's completed games in the given bracket rounds
+    fn bracket_games(&self, rounds: &[LeagueSeasonWeek], winners_bracket: bool, team_id: usize) -> Result<Vec<PlayoffGameSummary>, LeagueError> {
+        let mut games = Vec::new();
+        for (round, week) in rounds.iter().enumerate() {
+            let matchup = match week.matchups().iter().find(|m| m.participated(team_id)) {
+                Some(m) if m.context().game_over() => m,
+                _ => continue
+            };
+            let home = matchup.is_home_team(team_id);
+            let (opponent, points_for, points_against) = if home {
+                (*matchup.away_team(), matchup.context().home_score(), matchup.context().away_score())
+            } else {
+                (*matchup.home_team(), matchup.context().away_score(), matchup.context().home_score())
+            };
+            games.push(PlayoffGameSummary {
+                winners_bracket,
+                round,
+                opponent,
+                opponent_seed: self.team_seed(opponent)?,
+                home,
+                points_for,
+                points_against,
+                won: matchup.winner() == Some(team_id)
+            });
+        }
+        Ok(games)
+    }
+
+    /// Describe one team of a previewed matchup
+    fn preview_contender(&self, season: &LeagueSeason, matchup: &LeagueSeasonMatchup, bracket: Option<usize>, round: usize, team_id: usize) -> Result<PlayoffPreviewContender, LeagueError> {
+        let conference = self.team_conference(team_id)?;
+
+        // Collect the team's games in the rounds before the previewed round
+        let conference_rounds = self.conference_brackets.get(&conference)
+            .map(|b| b.as_slice())
+            .unwrap_or(&[]);
+        let path = match bracket {
+            Some(_) => self.bracket_games(&conference_rounds[..round.min(conference_rounds.len())], false, team_id)?,
+            None => {
+                let mut path = self.bracket_games(conference_rounds, false, team_id)?;
+                path.extend(self.bracket_games(&self.winners_bracket[..round], true, team_id)?);
+                path
+            }
+        };
+        let short_name = match season.team(team_id) {
+            Some(team) => team.short_name().to_string(),
+            None => return Err(LeagueError::TeamNotFound { id: team_id })
+        };
+        let rest_bonus = match matchup.rest_advantage() {
+            Some(rest) if rest.team == team_id => rest.bonus,
+            _ => 0
+        };
+        Ok(PlayoffPreviewContender {
+            team_id,
+            short_name,
+            seed: self.team_seed(team_id)?,
+            conference,
+            record: season.team_matchups(team_id)?.record(),
+            path,
+            rest_bonus
+        })
+    }
+
+    /// Preview every matchup of a playoff round, with each team's seed,
+    /// regular season record, and results so far in the playoffs, the
+    /// teams' regular season meetings, and the home team's chance of
+    /// winning. The bracket is a conference index, or `None` for the winners
+    /// bracket of a conference playoff.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::{LeagueSeason, LeagueSeasonPlayoffOptions, LeagueSeasonScheduleOptions};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// my_league_season.sim_regular_season(&mut rng).unwrap();
+    /// let options = LeagueSeasonPlayoffOptions::new().num_playoff_teams(4);
+    /// my_league_season.generate_playoffs(options, &mut rng).unwrap();
+    ///
+    /// // Preview the semifinals
+    /// let playoffs = my_league_season.playoffs();
+    /// let previews = playoffs.round_previews(Some(0), 0, &my_league_season).unwrap();
+    /// assert_eq!(previews.len(), 2);
+    /// assert!(previews[0].home.path.is_empty());
+    /// assert_eq!(previews[0].head_to_head.len(), 2);
+    /// ```
+    pub fn round_previews(&self, bracket: Option<usize>, round: usize, season: &LeagueSeason) -> Result<Vec<PlayoffMatchupPreview>, LeagueError> {
+        let rounds = match bracket {
+            Some(c) => self.conference_brackets.get(&c)
+                .ok_or(format!("Conference bracket {} not found", c))?,
+            None => &self.winners_bracket
+        };
+        let week = rounds.get(round)
+            .ok_or(format!("Playoff round {} has not been generated", round))?;
+
+        let simulator = FinalScoreSimulator::new();
+        let mut previews = Vec::new();
+        for (index, matchup) in week.matchups().iter().enumerate() {
+            let home_id = *matchup.home_team();
+            let away_id = *matchup.away_team();
+            let home = self.preview_contender(season, matchup, bracket, round, home_id)?;
+            let away = self.preview_contender(season, matchup, bracket, round, away_id)?;
+
+            // Find the teams' regular season meetings
+            let head_to_head = season.head_to_head(home_id, away_id)?.games;
+
+            // Playoff games cannot end in a tie, so the home team's chance
+            // of winning excludes ties. At a neutral site neither team has
+            // home field advantage, so both orientations are averaged.
+            let (home_team, away_team) = match (season.team(home_id), season.team(away_id)) {
+                (Some(h), Some(a)) => (matchup.rested_team(home_id, h), matchup.rested_team(away_id, a)),
+                (None, _) => return Err(LeagueError::TeamNotFound { id: home_id }),
+                (_, None) => return Err(LeagueError::TeamNotFound { id: away_id })
+            };
+            let (p_home, p_away, _) = simulator.outcome_probabilities(home_team.as_ref(), away_team.as_ref())?;
+            let mut home_win_probability = decisive_probability(p_home, p_away);
+            let neutral_site = matchup.context().neutral_site();
+            if neutral_site {
+                let (p_away_host, p_home_visit, _) = simulator.outcome_probabilities(away_team.as_ref(), home_team.as_ref())?;
+                home_win_probability = (home_win_probability + decisive_probability(p_home_visit, p_away_host)) / 2.0;
+            }
+
+            previews.push(PlayoffMatchupPreview {
+                bracket,
+                round,
+                matchup: index,
+                home,
+                away,
+                head_to_head,
+                same_conference: season.same_conference(home_id, away_id),
+                same_division: season.same_division(home_id, away_id),
+                neutral_site,
+                home_win_probability,
+                winner: matchup.winner()
+            });
+        }
+        Ok(previews)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use crate::league::season::{LeagueSeasonPlayoffOptions, LeagueSeasonScheduleOptions};
+
+    #[test]
+    fn test_round_previews_first_round_and_championship() {
+        // Complete the regular season of a two-conference league and
+        // generate a first round of conference brackets
+        let mut rng = SmallRng::seed_from_u64(1487);
+        let mut season = LeagueSeason::with_year(2026);
+        season.import_teams(include_str!("../../../../docs/league/season/teams.json").as_bytes()).unwrap();
+        let schedule = LeagueSeasonScheduleOptions::new()
+            .conference_games(2)
+            .cross_conference_games(4);
+        season.generate_schedule(schedule, &mut rng).unwrap();
+        season.sim_regular_season(&mut rng).unwrap();
+        let options = LeagueSeasonPlayoffOptions::new()
+            .use_conference_brackets(true)
+            .playoff_teams_per_conference(4);
+        season.generate_playoffs(options, &mut rng).unwrap();
+
+        // First-round previews have no playoff history and use the
+        // regular season head-to-head games
+        let simulator = FinalScoreSimulator::new();
+        for conference in 0..2 {
+            let previews = season.playoffs().round_previews(Some(conference), 0, &season).unwrap();
+            assert_eq!(previews.len(), 2);
+            for preview in previews.iter() {
+                let (home, away) = (preview.home.team_id, preview.away.team_id);
+                assert_eq!(preview.bracket, Some(conference));
+                assert!(preview.home.path.is_empty() && preview.away.path.is_empty());
+                assert_eq!(preview.home.seed, season.playoffs().team_seed(home).unwrap());
+                assert!(preview.home.seed < preview.away.seed);
+                assert_eq!(preview.home.record, season.team_matchups(home).unwrap().record());
+                assert!(preview.same_conference);
+                assert_eq!(preview.same_division, season.same_division(home, away));
+                assert_eq!(preview.head_to_head.len(), season.opponent_meetings()[&home][&away]);
+                assert!(preview.head_to_head.iter().all(|g| {
+                    let teams = [g.home_team, g.away_team];
+                    teams.contains(&home) && teams.contains(&away)
+                }));
+                let record = preview.head_to_head_record();
+                assert_eq!(*record.wins() + *record.losses() + *record.ties(), preview.head_to_head.len());
+                assert!(preview.winner.is_none());
+
+                // The probability matches the score model without ties
+                let (p_home, p_away, _) = simulator.outcome_probabilities(
+                    season.team(home).unwrap(), season.team(away).unwrap()
+                ).unwrap();
+                assert!((preview.home_win_probability - p_home / (p_home + p_away)).abs() < 1e-12);
+            }
+        }
+
+        // Rounds which have not been generated are rejected
+        assert!(season.playoffs().round_previews(Some(0), 1, &season).is_err());
+        assert!(season.playoffs().round_previews(None, 0, &season).is_err());
+        assert!(season.playoffs().round_previews(Some(5), 0, &season).is_err());
+
+        // The championship is between conferences, and each team's path
+        // holds its conference bracket wins
+        season.sim_playoffs(&mut rng).unwrap();
+        let previews = season.playoffs().round_previews(None, 0, &season).unwrap();
+        assert_eq!(previews.len(), 1);
+        let championship = &previews[0];
+        assert_eq!(championship.bracket, None);
+        assert!(!championship.same_conference);
+        assert!(!championship.same_division);
+        assert_ne!(championship.home.conference, championship.away.conference);
+        for contender in [&championship.home, &championship.away] {
+            assert_eq!(contender.path.len(), 2);
+            assert!(contender.path.iter().all(|g| g.won && !g.winners_bracket));
+        }
+        assert_eq!(championship.winner, season.champion());
+
+        // Previews survive serialization
+        let json = serde_json::to_string(&previews).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<PlayoffMatchupPreview>>(&json).unwrap(), previews);
+    }
+}
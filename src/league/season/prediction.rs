@@ -0,0 +1,96 @@
+#![doc = include_str!("../../../docs/league/season/prediction.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+/// # `PredictedResult` struct
+///
+/// A `PredictedResult` is the hypothetical final score of a season matchup,
+/// simulated without being written into the season.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct PredictedResult {
+    /// Index of the week containing the matchup
+    pub week: usize,
+    /// Index of the matchup within its week
+    pub matchup: usize,
+    /// ID of the home team
+    pub home_team: usize,
+    /// ID of the away team
+    pub away_team: usize,
+    /// Predicted score of the home team
+    pub home_score: u32,
+    /// Predicted score of the away team
+    pub away_score: u32
+}
+
+impl PredictedResult {
+    /// Returns the predicted winner of the matchup, or `None` for a tie
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::prediction::PredictedResult;
+    ///
+    /// let my_prediction = PredictedResult {
+    ///     week: 0,
+    ///     matchup: 0,
+    ///     home_team: 0,
+    ///     away_team: 1,
+    ///     home_score: 17,
+    ///     away_score: 24
+    /// };
+    /// assert_eq!(my_prediction.winner(), Some(1));
+    /// ```
+    pub fn winner(&self) -> Option<usize> {
+        match self.home_score.cmp(&self.away_score) {
+            std::cmp::Ordering::Greater => Some(self.home_team),
+            std::cmp::Ordering::Less => Some(self.away_team),
+            std::cmp::Ordering::Equal => None
+        }
+    }
+}
+
+/// # `PredictionAccuracy` struct
+///
+/// The accuracy of a set of predictions against the completed matchups they
+/// predicted.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi))]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct PredictionAccuracy {
+    /// Number of predictions scored
+    pub predictions: usize,
+    /// Number of predictions with the correct winner, counting a predicted
+    /// tie as correct only if the matchup was tied
+    pub correct_winners: usize,
+    /// Mean absolute difference between each predicted and actual team score
+    pub average_score_error: f64
+}
+
+impl PredictionAccuracy {
+    /// Returns the fraction of predictions with the correct winner, or 0 if
+    /// there were no predictions
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::prediction::PredictionAccuracy;
+    ///
+    /// let my_accuracy = PredictionAccuracy {
+    ///     predictions: 4,
+    ///     correct_winners: 3,
+    ///     average_score_error: 7.5
+    /// };
+    /// assert_eq!(my_accuracy.winner_pct(), 0.75);
+    /// ```
+    pub fn winner_pct(&self) -> f64 {
+        if self.predictions == 0 {
+            return 0.0;
+        }
+        self.correct_winners as f64 / self.predictions as f64
+    }
+}
@@ -0,0 +1,218 @@
+#![doc = include_str!("../../../docs/league/season/proposal.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+
+use crate::league::season::{LeagueSeason, ScheduleFormat};
+use crate::league::season::conference::LeagueConference;
+use crate::league::season::week::LeagueSeasonWeek;
+
+/// # `ScheduleBalance` struct
+///
+/// A `ScheduleBalance` summarizes how evenly a schedule spreads each team's
+/// home and away games and its meetings with each opponent
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScheduleBalance {
+    /// The number of home games of each team, keyed by team ID
+    pub home_games: BTreeMap<usize, usize>,
+    /// The number of away games of each team, keyed by team ID
+    pub away_games: BTreeMap<usize, usize>,
+    /// The number of meetings between each pair of teams, by team ID and
+    /// then opponent ID
+    pub meetings: BTreeMap<usize, BTreeMap<usize, usize>>
+}
+
+impl ScheduleBalance {
+    /// Get the largest difference between any team's number of home games
+    /// and its number of away games
+    ///
+    /// ### Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use fbsim_core::league::season::proposal::ScheduleBalance;
+    ///
+    /// let my_balance = ScheduleBalance {
+    ///     home_games: BTreeMap::from([(0, 2), (1, 1)]),
+    ///     away_games: BTreeMap::from([(0, 1), (1, 4)]),
+    ///     meetings: BTreeMap::new()
+    /// };
+    /// assert_eq!(my_balance.max_home_away_imbalance(), 3);
+    /// ```
+    pub fn max_home_away_imbalance(&self) -> usize {
+        self.home_games.iter()
+            .map(|(id, home)| home.abs_diff(self.away_games.get(id).copied().unwrap_or(0)))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Get the fewest and most times any two teams meet, or `None` if there
+    /// are no pairs of teams
+    ///
+    /// ### Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use fbsim_core::league::season::proposal::ScheduleBalance;
+    ///
+    /// let my_balance = ScheduleBalance {
+    ///     home_games: BTreeMap::new(),
+    ///     away_games: BTreeMap::new(),
+    ///     meetings: BTreeMap::from([
+    ///         (0, BTreeMap::from([(1, 2), (2, 0)])),
+    ///         (1, BTreeMap::from([(0, 2), (2, 1)]))
+    ///     ])
+    /// };
+    /// assert_eq!(my_balance.meetings_range(), Some((0, 2)));
+    /// ```
+    pub fn meetings_range(&self) -> Option<(usize, usize)> {
+        let counts = self.meetings.values().flat_map(|opponents| opponents.values());
+        let min = counts.clone().min()?;
+        let max = counts.max()?;
+        Some((*min, *max))
+    }
+}
+
+/// # `ScheduleProposal` struct
+///
+/// A `ScheduleProposal` is a schedule generated by
+/// `LeagueSeason::preview_schedule` without being installed in the season.
+/// It records the teams and conferences it was generated for, so
+/// `LeagueSeason::apply_schedule` can refuse it if they have since changed.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct ScheduleProposal {
+    weeks: Vec<LeagueSeasonWeek>,
+    format: ScheduleFormat,
+    conferences: Vec<LeagueConference>,
+    teams: BTreeMap<usize, String>,
+    balance: ScheduleBalance
+}
+
+impl ScheduleProposal {
+    /// Capture the schedule of a season as a proposal
+    pub(crate) fn from_season(season: &LeagueSeason) -> ScheduleProposal {
+        ScheduleProposal {
+            weeks: season.weeks().clone(),
+            format: *season.schedule_format(),
+            conferences: season.conferences().clone(),
+            teams: season.teams().iter()
+                .map(|(id, team)| (*id, String::from(team.short_name())))
+                .collect(),
+            balance: season.schedule_balance()
+        }
+    }
+
+    /// Borrow the proposed weeks of the schedule
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// let proposal = my_league_season.preview_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// assert_eq!(proposal.weeks().len(), 6);
+    /// ```
+    pub fn weeks(&self) -> &Vec<LeagueSeasonWeek> {
+        &self.weeks
+    }
+
+    /// Borrow the format the schedule was generated with
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::{LeagueSeason, LeagueSeasonScheduleOptions, ScheduleFormat};
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// let proposal = my_league_season.preview_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// assert_eq!(*proposal.format(), ScheduleFormat::RoundRobin);
+    /// ```
+    pub fn format(&self) -> &ScheduleFormat {
+        &self.format
+    }
+
+    /// Borrow the conferences the schedule was generated for, including the
+    /// default conference created for a season without any
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// let proposal = my_league_season.preview_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// assert_eq!(proposal.conferences().len(), 1);
+    /// assert!(my_league_season.conferences().is_empty());
+    /// ```
+    pub fn conferences(&self) -> &Vec<LeagueConference> {
+        &self.conferences
+    }
+
+    /// Borrow the short name of each team the schedule was generated for,
+    /// keyed by team ID
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// let proposal = my_league_season.preview_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// assert_eq!(proposal.teams().len(), 4);
+    /// ```
+    pub fn teams(&self) -> &BTreeMap<usize, String> {
+        &self.teams
+    }
+
+    /// Borrow the home/away and opponent balance of the schedule
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::season::LeagueSeason;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    ///
+    /// let mut my_league_season = LeagueSeason::new();
+    /// for id in 0..4 {
+    ///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+    /// }
+    /// let mut rng = rand::thread_rng();
+    /// let proposal = my_league_season.preview_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    /// assert_eq!(proposal.balance().max_home_away_imbalance(), 0);
+    /// assert_eq!(proposal.balance().meetings_range(), Some((2, 2)));
+    /// ```
+    pub fn balance(&self) -> &ScheduleBalance {
+        &self.balance
+    }
+
+    /// Take the proposed weeks, format, and conferences
+    pub(crate) fn into_schedule(self) -> (Vec<LeagueSeasonWeek>, ScheduleFormat, Vec<LeagueConference>) {
+        (self.weeks, self.format, self.conferences)
+    }
+}
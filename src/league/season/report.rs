@@ -0,0 +1,494 @@
+#![doc = include_str!("../../../docs/league/season/report.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+use crate::league::season::LeagueSeason;
+use crate::league::season::week::LeagueSeasonWeek;
+
+/// # `SeasonReportFormat` enum
+///
+/// The output format of a season report
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub enum SeasonReportFormat {
+    /// A markdown document with headings and pipe tables
+    #[default] Markdown,
+    /// Plain text with underlined headings and space-aligned columns
+    PlainText
+}
+
+/// # `SeasonReportOptions` struct
+///
+/// Options for generating a season report, selecting which sections to
+/// include
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SeasonReportOptions {
+    /// Whether to include the champion
+    pub champion: bool,
+    /// Whether to include the final standings table
+    pub standings: bool,
+    /// Whether to include the playoff bracket results
+    pub playoffs: bool,
+    /// Whether to include the scores of each regular season week
+    pub weekly_scores: bool,
+    /// Whether to include the number of meetings between each pair of teams
+    pub meetings: bool,
+    /// Whether to include the notes attached to parts of the season
+    pub annotations: bool
+}
+
+impl Default for SeasonReportOptions {
+    /// Default constructor for the `SeasonReportOptions` struct, which
+    /// includes every section except the meetings between each pair of teams
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::report::SeasonReportOptions;
+    ///
+    /// let my_options = SeasonReportOptions::default();
+    /// assert!(my_options.weekly_scores);
+    /// ```
+    fn default() -> Self {
+        SeasonReportOptions {
+            champion: true,
+            standings: true,
+            playoffs: true,
+            weekly_scores: true,
+            meetings: false,
+            annotations: true
+        }
+    }
+}
+
+impl SeasonReportOptions {
+    /// Constructor for the `SeasonReportOptions` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::report::SeasonReportOptions;
+    ///
+    /// let my_options = SeasonReportOptions::new();
+    /// ```
+    pub fn new() -> SeasonReportOptions {
+        SeasonReportOptions::default()
+    }
+
+    /// Set whether to include the champion
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::report::SeasonReportOptions;
+    ///
+    /// let my_options = SeasonReportOptions::new().champion(false);
+    /// assert!(!my_options.champion);
+    /// ```
+    pub fn champion(mut self, champion: bool) -> Self {
+        self.champion = champion;
+        self
+    }
+
+    /// Set whether to include the final standings table
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::report::SeasonReportOptions;
+    ///
+    /// let my_options = SeasonReportOptions::new().standings(false);
+    /// assert!(!my_options.standings);
+    /// ```
+    pub fn standings(mut self, standings: bool) -> Self {
+        self.standings = standings;
+        self
+    }
+
+    /// Set whether to include the playoff bracket results
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::report::SeasonReportOptions;
+    ///
+    /// let my_options = SeasonReportOptions::new().playoffs(false);
+    /// assert!(!my_options.playoffs);
+    /// ```
+    pub fn playoffs(mut self, playoffs: bool) -> Self {
+        self.playoffs = playoffs;
+        self
+    }
+
+    /// Set whether to include the scores of each regular season week
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::report::SeasonReportOptions;
+    ///
+    /// let my_options = SeasonReportOptions::new().weekly_scores(false);
+    /// assert!(!my_options.weekly_scores);
+    /// ```
+    pub fn weekly_scores(mut self, weekly_scores: bool) -> Self {
+        self.weekly_scores = weekly_scores;
+        self
+    }
+
+    /// Set whether to include the number of meetings between each pair of
+    /// teams
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::report::SeasonReportOptions;
+    ///
+    /// let my_options = SeasonReportOptions::new().meetings(true);
+    /// assert!(my_options.meetings);
+    /// ```
+    pub fn meetings(mut self, meetings: bool) -> Self {
+        self.meetings = meetings;
+        self
+    }
+
+    /// Set whether to include the notes attached to parts of the season
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::report::SeasonReportOptions;
+    ///
+    /// let my_options = SeasonReportOptions::new().annotations(false);
+    /// assert!(!my_options.annotations);
+    /// ```
+    pub fn annotations(mut self, annotations: bool) -> Self {
+        self.annotations = annotations;
+        self
+    }
+}
+
+/// Column alignment within a report table
+#[derive(Clone, Copy, PartialEq)]
+enum Align {
+    Left,
+    Right
+}
+
+/// A table of cells rendered in either report format
+struct ReportTable {
+    columns: Vec<(String, Align)>,
+    rows: Vec<Vec<String>>
+}
+
+/// Builds a season report in a given format
+struct ReportWriter {
+    format: SeasonReportFormat,
+    out: String
+}
+
+impl ReportWriter {
+    /// Escape characters with special meaning inline or within a table cell
+    /// in markdown, leaving plain text unchanged
+    fn escape(&self, text: &str) -> String {
+        match self.format {
+            SeasonReportFormat::PlainText => text.to_string(),
+            SeasonReportFormat::Markdown => escape_markdown(text)
+        }
+    }
+
+    /// Start a new section, separated from the previous one by a blank line
+    fn heading(&mut self, level: usize, text: &str) {
+        if !self.out.is_empty() && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+        match self.format {
+            SeasonReportFormat::Markdown => {
+                self.out.push_str(&format!("{} {}\n\n", "#".repeat(level), self.escape(text)));
+            },
+            SeasonReportFormat::PlainText => {
+                let underline = if level == 1 { "=" } else { "-" };
+                let width = text.chars().count();
+                self.out.push_str(&format!("{}\n{}\n\n", text, underline.repeat(width)));
+            }
+        }
+    }
+
+    /// Write a line of text
+    fn line(&mut self, text: &str) {
+        self.out.push_str(text);
+        self.out.push('\n');
+    }
+
+    /// Write a table with each column padded to its widest cell
+    fn table(&mut self, table: &ReportTable) {
+        let markdown = self.format == SeasonReportFormat::Markdown;
+        let headers: Vec<String> = table.columns.iter().map(|(h, _)| self.escape(h)).collect();
+        let rows: Vec<Vec<String>> = table.rows.iter()
+            .map(|row| row.iter().map(|cell| self.escape(cell)).collect())
+            .collect();
+
+        // Compute the width of each column
+        let min_width = if markdown { 3 } else { 1 };
+        let widths: Vec<usize> = (0..table.columns.len())
+            .map(|i| {
+                rows.iter()
+                    .map(|row| row[i].chars().count())
+                    .chain(std::iter::once(headers[i].chars().count()))
+                    .max()
+                    .unwrap_or(0)
+                    .max(min_width)
+            })
+            .collect();
+        let pad = |cell: &str, i: usize| {
+            let fill = " ".repeat(widths[i] - cell.chars().count());
+            match table.columns[i].1 {
+                Align::Left => format!("{}{}", cell, fill),
+                Align::Right => format!("{}{}", fill, cell)
+            }
+        };
+        let render_row = |cells: &[String]| {
+            let padded: Vec<String> = cells.iter().enumerate().map(|(i, c)| pad(c, i)).collect();
+            if markdown {
+                format!("| {} |", padded.join(" | "))
+            } else {
+                padded.join("  ").trim_end().to_string()
+            }
+        };
+
+        // Write the header, the separator, then each row
+        self.line(&render_row(&headers));
+        let separator: Vec<String> = widths.iter().enumerate()
+            .map(|(i, w)| {
+                if !markdown {
+                    return "-".repeat(*w);
+                }
+                match table.columns[i].1 {
+                    Align::Left => format!(":{}", "-".repeat(w - 1)),
+                    Align::Right => format!("{}:", "-".repeat(w - 1))
+                }
+            })
+            .collect();
+        if markdown {
+            self.line(&format!("| {} |", separator.join(" | ")));
+        } else {
+            self.line(&separator.join("  "));
+        }
+        for row in rows.iter() {
+            self.line(&render_row(row));
+        }
+    }
+}
+
+/// Escape characters with special meaning inline or within a table cell in
+/// markdown
+pub(crate) fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '~') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Get a team's display name, falling back to its ID
+pub(crate) fn team_name(season: &LeagueSeason, team_id: usize) -> String {
+    match season.team(team_id) {
+        Some(team) => team.name().to_string(),
+        None => format!("Team {}", team_id)
+    }
+}
+
+/// Build a table of the scores of a week's matchups
+fn scores_table(season: &LeagueSeason, week: &LeagueSeasonWeek) -> ReportTable {
+    let rows = week.matchups().iter()
+        .map(|matchup| {
+            let context = matchup.context();
+            let (away_score, home_score) = if context.game_over() {
+                (context.away_score().to_string(), context.home_score().to_string())
+            } else {
+                (String::new(), String::new())
+            };
+            vec![
+                team_name(season, *matchup.away_team()),
+                away_score,
+                team_name(season, *matchup.home_team()),
+                home_score,
+                String::from(if context.game_over() { "Final" } else { "Scheduled" })
+            ]
+        })
+        .collect();
+    ReportTable {
+        columns: vec![
+            (String::from("Away"), Align::Left),
+            (String::from("Pts"), Align::Right),
+            (String::from("Home"), Align::Left),
+            (String::from("Pts"), Align::Right),
+            (String::from("Status"), Align::Left)
+        ],
+        rows
+    }
+}
+
+/// Name a round of a playoff bracket, prefixed by the bracket's title if it
+/// has one
+pub(crate) fn round_name(title: Option<&str>, bracket: &[LeagueSeasonWeek], round: usize) -> String {
+    let is_final = bracket.len() > 1 && round + 1 == bracket.len() &&
+        bracket.get(round).is_some_and(|week| week.matchups().len() == 1);
+    let round_name = if is_final {
+        String::from("Final")
+    } else {
+        format!("Round {}", round + 1)
+    };
+    match title {
+        Some(title) => format!("{}: {}", title, round_name),
+        None => round_name
+    }
+}
+
+/// Get the title of a conference bracket, or none if the playoffs are a
+/// single bracket
+pub(crate) fn bracket_title(season: &LeagueSeason, conf_index: usize) -> Option<String> {
+    if !season.playoffs().is_conference_playoff() {
+        return None;
+    }
+    match season.conference(conf_index) {
+        Some(conference) => Some(conference.name().to_string()),
+        None => Some(format!("Conference {}", conf_index))
+    }
+}
+
+/// Write each round of a playoff bracket
+fn bracket_section(writer: &mut ReportWriter, season: &LeagueSeason, title: Option<&str>, bracket: &[LeagueSeasonWeek], winners_bracket: bool) {
+    let show_weeks = !season.playoffs().rest_weeks_between_rounds().is_empty();
+    for (round, week) in bracket.iter().enumerate() {
+        let heading = round_name(title, bracket, round);
+        let heading = if show_weeks {
+            format!("{} (Week {})", heading, season.playoff_round_week(winners_bracket, round) + 1)
+        } else {
+            heading
+        };
+        writer.heading(3, &heading);
+        writer.table(&scores_table(season, week));
+    }
+}
+
+/// Generate a report of a season in the given format
+pub(crate) fn season_report(season: &LeagueSeason, options: &SeasonReportOptions, format: SeasonReportFormat) -> String {
+    let mut writer = ReportWriter {
+        format,
+        out: String::new()
+    };
+    writer.heading(1, &format!("{} Season Report", season.year()));
+
+    // Champion
+    if options.champion {
+        writer.heading(2, "Champion");
+        let champion = match season.champion() {
+            Some(id) => {
+                let name = writer.escape(&team_name(season, id));
+                match format {
+                    SeasonReportFormat::Markdown => format!("**{}**", name),
+                    SeasonReportFormat::PlainText => name
+                }
+            },
+            None => String::from("To be determined")
+        };
+        writer.line(&champion);
+    }
+
+    // Final standings
+    if options.standings {
+        writer.heading(2, "Standings");
+        let rows = season.standings().iter().enumerate()
+            .map(|(rank, (id, record))| {
+                vec![
+                    (rank + 1).to_string(),
+                    team_name(season, *id),
+                    record.wins().to_string(),
+                    record.losses().to_string(),
+                    record.ties().to_string(),
+                    record.win_pct_string(),
+                    match season.turnover_margin(*id) {
+                        Ok(Some(margin)) if margin > 0 => format!("+{}", margin),
+                        Ok(Some(margin)) => margin.to_string(),
+                        _ => String::from("n/a")
+                    }
+                ]
+            })
+            .collect();
+        writer.table(&ReportTable {
+            columns: vec![
+                (String::from("#"), Align::Right),
+                (String::from("Team"), Align::Left),
+                (String::from("W"), Align::Right),
+                (String::from("L"), Align::Right),
+                (String::from("T"), Align::Right),
+                (String::from("Pct"), Align::Right),
+                (String::from("TO"), Align::Right)
+            ],
+            rows
+        });
+    }
+
+    // Playoff bracket results
+    let playoffs = season.playoffs();
+    if options.playoffs && playoffs.started() {
+        writer.heading(2, "Playoffs");
+        for (conf_index, bracket) in playoffs.conference_brackets().iter() {
+            let title = bracket_title(season, *conf_index);
+            bracket_section(&mut writer, season, title.as_deref(), bracket, false);
+        }
+        bracket_section(&mut writer, season, Some("Championship"), playoffs.winners_bracket(), true);
+    }
+
+    // Meetings between each pair of teams
+    if options.meetings {
+        writer.heading(2, "Meetings");
+        let meetings = season.opponent_meetings();
+        let mut columns = vec![(String::from("Team"), Align::Left)];
+        columns.extend(
+            season.teams().values().map(|team| (team.short_name().to_string(), Align::Right))
+        );
+        let rows = meetings.iter()
+            .map(|(id, opponents)| {
+                let mut row = vec![team_name(season, *id)];
+                for opponent in season.teams().keys() {
+                    row.push(match opponents.get(opponent) {
+                        Some(count) => count.to_string(),
+                        None => String::from("-")
+                    });
+                }
+                row
+            })
+            .collect();
+        writer.table(&ReportTable { columns, rows });
+    }
+
+    // Weekly scores
+    if options.weekly_scores {
+        writer.heading(2, "Weekly Scores");
+        for (index, week) in season.weeks().iter().enumerate() {
+            writer.heading(3, &format!("Week {}", index + 1));
+            writer.table(&scores_table(season, week));
+        }
+    }
+
+    // Notes attached to parts of the season
+    if options.annotations && !season.annotations().is_empty() {
+        writer.heading(2, "Notes");
+        let rows = season.annotations().iter()
+            .map(|annotation| vec![annotation.target.describe(season), annotation.text.clone()])
+            .collect();
+        writer.table(&ReportTable {
+            columns: vec![
+                (String::from("Target"), Align::Left),
+                (String::from("Note"), Align::Left)
+            ],
+            rows
+        });
+    }
+    writer.out
+}
@@ -0,0 +1,324 @@
+#![doc = include_str!("../../../docs/league/season/scoreboard.md")]
+use crate::league::error::LeagueError;
+use crate::league::matchup::LeagueTeamRecord;
+use crate::league::season::LeagueSeason;
+use crate::league::season::matchup::{LeagueSeasonMatchup, LeagueSeasonMatchups};
+use crate::league::season::report::{bracket_title, escape_markdown, round_name, team_name};
+use crate::league::season::week::LeagueSeasonWeek;
+
+/// The maximum number of characters of a team name shown on a scoreboard
+/// card, beyond which the name is shortened with an ellipsis
+pub const SCOREBOARD_MAX_NAME_LEN: usize = 24;
+
+/// One team's row of a scoreboard card
+struct ScoreboardRow {
+    label: String,
+    period_points: Vec<u32>,
+    score: Option<u32>,
+    winner: bool
+}
+
+/// Label a period of a game: the quarters by number, then OT, 2OT, and so on
+fn period_label(period: usize, quarters: usize) -> String {
+    match period.checked_sub(quarters) {
+        None | Some(0) => period.to_string(),
+        Some(1) => String::from("OT"),
+        Some(overtime) => format!("{}OT", overtime)
+    }
+}
+
+/// Describe the status of a matchup, noting how many overtime periods a
+/// finished game went to
+fn status(matchup: &LeagueSeasonMatchup) -> String {
+    let context = matchup.context();
+    if context.game_over() {
+        let quarters = context.clock_rules().quarters;
+        if context.quarter() <= quarters {
+            String::from("Final")
+        } else {
+            format!("Final/{}", period_label(context.quarter() as usize, quarters as usize))
+        }
+    } else if context.started() {
+        String::from("In Progress")
+    } else {
+        String::from("Scheduled")
+    }
+}
+
+/// Shorten a team name to at most `SCOREBOARD_MAX_NAME_LEN` characters
+fn short_name(name: &str) -> String {
+    if name.chars().count() <= SCOREBOARD_MAX_NAME_LEN {
+        return name.to_string();
+    }
+    let mut shortened: String = name.chars().take(SCOREBOARD_MAX_NAME_LEN - 1).collect();
+    shortened.push('\u{2026}');
+    shortened
+}
+
+/// Find a matchup among some weeks, first by reference and then by value
+fn find_matchup(weeks: &[LeagueSeasonWeek], matchup: &LeagueSeasonMatchup) -> Option<(usize, usize)> {
+    let position = |same: &dyn Fn(&LeagueSeasonMatchup) -> bool| {
+        weeks.iter().enumerate().find_map(|(week, w)| {
+            w.matchups().iter().position(same).map(|index| (week, index))
+        })
+    };
+    position(&|m| std::ptr::eq(m, matchup)).or_else(|| position(&|m| m == matchup))
+}
+
+/// Compute a team's regular season record through a week, or through the
+/// whole regular season
+fn record_through(season: &LeagueSeason, team_id: usize, week: Option<usize>) -> LeagueTeamRecord {
+    let end = week.map(|w| w + 1).unwrap_or(season.weeks().len()).min(season.weeks().len());
+    let matchups = season.weeks()[..end].iter().map(|w| w.team_matchup(team_id)).collect();
+    LeagueSeasonMatchups::new(team_id, matchups).record()
+}
+
+/// Build the rows of a scoreboard card, the away team first
+fn rows(matchup: &LeagueSeasonMatchup, labels: (String, String)) -> Vec<ScoreboardRow> {
+    let context = matchup.context();
+    let started = context.game_over() || context.started();
+    let winner = if context.game_over() { matchup.winner() } else { None };
+    let (away_label, home_label) = labels;
+    vec![
+        ScoreboardRow {
+            label: away_label,
+            period_points: matchup.away_box_score().map(|s| s.period_points().clone()).unwrap_or_default(),
+            score: started.then_some(context.away_score()),
+            winner: winner == Some(*matchup.away_team())
+        },
+        ScoreboardRow {
+            label: home_label,
+            period_points: matchup.home_box_score().map(|s| s.period_points().clone()).unwrap_or_default(),
+            score: started.then_some(context.home_score()),
+            winner: winner == Some(*matchup.home_team())
+        }
+    ]
+}
+
+/// Render a scoreboard card as a bold heading above a markdown table, with
+/// each column padded to its widest cell, given the number of regulation
+/// quarters the game was played in
+fn render(heading: &str, rows: &[ScoreboardRow], quarters: usize) -> String {
+    // Show the scoring by period only when it is known for both teams
+    let periods = if rows.iter().all(|row| !row.period_points.is_empty()) {
+        rows.iter().map(|row| row.period_points.len()).max().unwrap_or(0)
+    } else {
+        0
+    };
+
+    // Build the header and each row's cells
+    let bold = |text: String, winner: bool| if winner { format!("**{}**", text) } else { text };
+    let mut header = vec![String::from("Team")];
+    header.extend((1..=periods).map(|period| period_label(period, quarters)));
+    header.push(String::from("T"));
+    let cells: Vec<Vec<String>> = rows.iter()
+        .map(|row| {
+            let mut cells = vec![bold(row.label.clone(), row.winner)];
+            cells.extend((0..periods).map(|p| {
+                row.period_points.get(p).map(|points| points.to_string()).unwrap_or_default()
+            }));
+            cells.push(match row.score {
+                Some(score) => bold(score.to_string(), row.winner),
+                None => String::from("-")
+            });
+            cells
+        })
+        .collect();
+
+    // Pad each column to its widest cell, the team names to the left and
+    // the points to the right
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            cells.iter()
+                .map(|row| row[i].chars().count())
+                .chain(std::iter::once(header[i].chars().count()))
+                .max()
+                .unwrap_or(0)
+                .max(3)
+        })
+        .collect();
+    let render_row = |row: &[String]| {
+        let padded: Vec<String> = row.iter().enumerate()
+            .map(|(i, cell)| {
+                let fill = " ".repeat(widths[i] - cell.chars().count());
+                if i == 0 { format!("{}{}", cell, fill) } else { format!("{}{}", fill, cell) }
+            })
+            .collect();
+        format!("| {} |", padded.join(" | "))
+    };
+    let separator: Vec<String> = widths.iter().enumerate()
+        .map(|(i, w)| if i == 0 { format!(":{}", "-".repeat(w - 1)) } else { format!("{}:", "-".repeat(w - 1)) })
+        .collect();
+
+    let mut out = format!("**{}**\n\n", escape_markdown(heading));
+    out.push_str(&render_row(&header));
+    out.push('\n');
+    out.push_str(&format!("| {} |\n", separator.join(" | ")));
+    for row in cells.iter() {
+        out.push_str(&render_row(row));
+        out.push('\n');
+    }
+    out
+}
+
+/// Format a regular season matchup as a compact markdown scoreboard card.
+///
+/// The card is headed by the matchup's week and status, and lists each team
+/// with its regular season record through that week, the points it scored
+/// in each period when the matchup has a box score, and its final score.
+/// The winner's name and score are bolded. A matchup which is not part of
+/// the season's regular season is shown with each team's full regular
+/// season record and no week.
+///
+/// ### Example
+/// ```
+/// use fbsim_core::team::FootballTeam;
+/// use fbsim_core::league::season::LeagueSeason;
+/// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+/// use fbsim_core::league::season::scoreboard::format_scoreboard;
+///
+/// let mut my_league_season = LeagueSeason::new();
+/// for id in 0..4 {
+///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+/// }
+/// let mut rng = rand::thread_rng();
+/// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+/// my_league_season.sim_week(0, &mut rng).unwrap();
+///
+/// let matchup = &my_league_season.weeks()[0].matchups()[0];
+/// let card = format_scoreboard(matchup, &my_league_season);
+/// assert!(card.starts_with("**Week 1 - Final"));
+/// ```
+pub fn format_scoreboard(matchup: &LeagueSeasonMatchup, season: &LeagueSeason) -> String {
+    let week = find_matchup(season.weeks(), matchup).map(|(week, _)| week);
+    let label = |team_id: usize| {
+        format!(
+            "{} ({})",
+            escape_markdown(&short_name(&team_name(season, team_id))),
+            record_through(season, team_id, week).record_string()
+        )
+    };
+    let labels = (label(*matchup.away_team()), label(*matchup.home_team()));
+    let heading = match week {
+        Some(week) => format!("Week {} - {}", week + 1, status(matchup)),
+        None => status(matchup)
+    };
+    render(&heading, &rows(matchup, labels), matchup.context().clock_rules().quarters as usize)
+}
+
+/// Format a playoff matchup as a compact markdown scoreboard card.
+///
+/// Like `format_scoreboard`, but headed by the name of the playoff round,
+/// or "Championship" for the championship game, and listing each team with
+/// its playoff seed and full regular season record. Returns an error if the
+/// matchup is not part of the season's playoffs.
+///
+/// ### Example
+/// ```
+/// use fbsim_core::team::FootballTeam;
+/// use fbsim_core::league::season::{LeagueSeason, LeagueSeasonPlayoffOptions};
+/// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+/// use fbsim_core::league::season::scoreboard::format_playoff_scoreboard;
+///
+/// let mut my_league_season = LeagueSeason::new();
+/// for id in 0..4 {
+///     my_league_season.add_team(id, FootballTeam::new()).unwrap();
+/// }
+/// let mut rng = rand::thread_rng();
+/// my_league_season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+/// my_league_season.sim_regular_season(&mut rng).unwrap();
+/// my_league_season.generate_playoffs(LeagueSeasonPlayoffOptions::new(), &mut rng).unwrap();
+/// my_league_season.sim_playoffs(&mut rng).unwrap();
+///
+/// let championship = my_league_season.playoffs().championship().unwrap();
+/// let card = format_playoff_scoreboard(championship, &my_league_season).unwrap();
+/// assert!(card.starts_with("**Championship - Final"));
+/// ```
+pub fn format_playoff_scoreboard(matchup: &LeagueSeasonMatchup, season: &LeagueSeason) -> Result<String, LeagueError> {
+    let playoffs = season.playoffs();
+
+    // Find the round the matchup was played in
+    let mut round_title = None;
+    for (conf_index, bracket) in playoffs.conference_brackets().iter() {
+        if let Some((round, _)) = find_matchup(bracket, matchup) {
+            round_title = Some(round_name(bracket_title(season, *conf_index).as_deref(), bracket, round));
+            break;
+        }
+    }
+    if round_title.is_none() {
+        if let Some((round, _)) = find_matchup(playoffs.winners_bracket(), matchup) {
+            round_title = Some(round_name(Some("Championship"), playoffs.winners_bracket(), round));
+        }
+    }
+    let round_title = round_title.ok_or_else(|| format!(
+        "Matchup {} at {} is not part of the {} playoffs",
+        matchup.away_team(), matchup.home_team(), season.year()
+    ))?;
+    let is_championship = playoffs.championship()
+        .is_some_and(|championship| std::ptr::eq(championship, matchup) || championship == matchup);
+    let round_title = if is_championship { String::from("Championship") } else { round_title };
+
+    // Label each team with its seed and regular season record
+    let label = |team_id: usize| -> Result<String, LeagueError> {
+        Ok(format!(
+            "#{} {} ({})",
+            playoffs.team_seed(team_id)?,
+            escape_markdown(&short_name(&team_name(season, team_id))),
+            record_through(season, team_id, None).record_string()
+        ))
+    };
+    let labels = (label(*matchup.away_team())?, label(*matchup.home_team())?);
+    let heading = format!("{} - {}", round_title, status(matchup));
+    Ok(render(&heading, &rows(matchup, labels), matchup.context().clock_rules().quarters as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use crate::league::season::LeagueSeasonPlayoffOptions;
+    use crate::league::season::LeagueSeasonScheduleOptions;
+    use crate::team::FootballTeam;
+
+    #[test]
+    fn test_scoreboard_cards_match_golden() {
+        // Simulate a season whose teams include a long and an unusual name
+        let mut rng = SmallRng::seed_from_u64(1506);
+        let mut season = LeagueSeason::with_year(2026);
+        let names = ["The Extraordinarily Long-Named Football Club", "Pipe|Dreams", "Ravens", "Hawks", "Bears", "Lions"];
+        for (id, name) in names.iter().enumerate() {
+            let team = FootballTeam::from_overalls(name, "TM", 55 + id as u32, 60).unwrap();
+            season.add_team(id, team).unwrap();
+        }
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        season.sim_regular_season(&mut rng).unwrap();
+        season.generate_playoffs(LeagueSeasonPlayoffOptions::new().num_playoff_teams(4), &mut rng).unwrap();
+        season.sim_playoffs(&mut rng).unwrap();
+
+        // The scoring by period adds up to each final score
+        for matchup in season.weeks().iter().flat_map(|week| week.matchups().iter()) {
+            let home: u32 = matchup.home_box_score().unwrap().period_points().iter().sum();
+            let away: u32 = matchup.away_box_score().unwrap().period_points().iter().sum();
+            assert_eq!((home, away), (matchup.context().home_score(), matchup.context().away_score()));
+        }
+
+        // Card a regular season game, the first overtime game, and the
+        // championship
+        let matchups: Vec<&LeagueSeasonMatchup> = season.weeks().iter().flat_map(|week| week.matchups().iter()).collect();
+        let overtime = matchups.iter().find(|m| m.context().quarter() > 4).unwrap();
+        let championship = season.playoffs().championship().unwrap();
+        let cards = [
+            format_scoreboard(matchups[0], &season),
+            format_scoreboard(overtime, &season),
+            format_playoff_scoreboard(championship, &season).unwrap()
+        ].join("\n");
+        if std::env::var("FBSIM_UPDATE_GOLDEN").is_ok() {
+            std::fs::write("tests/golden/scoreboard_cards.md", &cards).unwrap();
+        }
+        assert_eq!(cards, include_str!("../../../tests/golden/scoreboard_cards.md"));
+
+        // A regular season matchup is not a playoff matchup
+        assert!(format_playoff_scoreboard(matchups[0], &season).is_err());
+    }
+}
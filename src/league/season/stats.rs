@@ -0,0 +1,411 @@
+#![doc = include_str!("../../../docs/league/season/stats.md")]
+use std::fmt;
+
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+use crate::game::matchup::FootballMatchupResult;
+use crate::league::matchup::LeagueTeamRecord;
+use crate::league::season::matchup::LeagueSeasonMatchup;
+
+/// # `LeagueSeasonStreak` struct
+///
+/// A run of consecutive identical results, such as three straight wins
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct LeagueSeasonStreak {
+    /// The result repeated throughout the streak
+    pub result: FootballMatchupResult,
+    /// The number of games in the streak
+    pub games: usize
+}
+
+impl fmt::Display for LeagueSeasonStreak {
+    /// Format the streak as its result's initial followed by its length
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::matchup::FootballMatchupResult;
+    /// use fbsim_core::league::season::stats::LeagueSeasonStreak;
+    ///
+    /// let my_streak = LeagueSeasonStreak { result: FootballMatchupResult::Win, games: 3 };
+    /// assert_eq!(my_streak.to_string(), "W3");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let initial = match self.result {
+            FootballMatchupResult::Win => "W",
+            FootballMatchupResult::Loss => "L",
+            FootballMatchupResult::Tie => "T"
+        };
+        write!(f, "{}{}", initial, self.games)
+    }
+}
+
+/// # `LeagueSeasonTeamSplit` struct
+///
+/// A team's record and points over a subset of its completed games, such as
+/// its home games
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct LeagueSeasonTeamSplit {
+    record: LeagueTeamRecord,
+    points_for: u32,
+    points_against: u32
+}
+
+impl LeagueSeasonTeamSplit {
+    /// Constructor for the `LeagueSeasonTeamSplit` struct, with no games
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamSplit;
+    ///
+    /// let my_split = LeagueSeasonTeamSplit::new();
+    /// assert_eq!(my_split.games_played(), 0);
+    /// ```
+    pub fn new() -> LeagueSeasonTeamSplit {
+        LeagueSeasonTeamSplit::default()
+    }
+
+    /// Add a completed game to the split, given the points the team scored
+    /// and allowed
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamSplit;
+    ///
+    /// let mut my_split = LeagueSeasonTeamSplit::new();
+    /// my_split.add_game(24, 17);
+    /// my_split.add_game(10, 10);
+    /// assert_eq!(my_split.record().to_string(), "1-0-1");
+    /// assert_eq!((my_split.points_for(), my_split.points_against()), (34, 27));
+    /// ```
+    pub fn add_game(&mut self, scored: u32, allowed: u32) {
+        if scored > allowed {
+            self.record.increment_wins(1);
+        } else if scored < allowed {
+            self.record.increment_losses(1);
+        } else {
+            self.record.increment_ties(1);
+        }
+        self.points_for += scored;
+        self.points_against += allowed;
+    }
+
+    /// Get the team's record in the split
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamSplit;
+    ///
+    /// let my_split = LeagueSeasonTeamSplit::new();
+    /// assert_eq!(my_split.record().to_string(), "0-0-0");
+    /// ```
+    pub fn record(&self) -> &LeagueTeamRecord {
+        &self.record
+    }
+
+    /// Get the number of games in the split
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamSplit;
+    ///
+    /// let mut my_split = LeagueSeasonTeamSplit::new();
+    /// my_split.add_game(3, 7);
+    /// assert_eq!(my_split.games_played(), 1);
+    /// ```
+    pub fn games_played(&self) -> usize {
+        self.record.wins() + self.record.losses() + self.record.ties()
+    }
+
+    /// Get the points the team scored in the split
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamSplit;
+    ///
+    /// let my_split = LeagueSeasonTeamSplit::new();
+    /// assert_eq!(my_split.points_for(), 0);
+    /// ```
+    pub fn points_for(&self) -> u32 {
+        self.points_for
+    }
+
+    /// Get the points the team allowed in the split
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamSplit;
+    ///
+    /// let my_split = LeagueSeasonTeamSplit::new();
+    /// assert_eq!(my_split.points_against(), 0);
+    /// ```
+    pub fn points_against(&self) -> u32 {
+        self.points_against
+    }
+
+    /// The points the team scored minus the points it allowed
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamSplit;
+    ///
+    /// let mut my_split = LeagueSeasonTeamSplit::new();
+    /// my_split.add_game(10, 27);
+    /// assert_eq!(my_split.point_differential(), -17);
+    /// ```
+    pub fn point_differential(&self) -> i64 {
+        self.points_for as i64 - self.points_against as i64
+    }
+}
+
+/// # `LeagueSeasonTeamStats` struct
+///
+/// A `LeagueSeasonTeamStats` aggregates a team's completed regular season
+/// games into its points, home, away, and neutral site splits, margin of
+/// victory, and current streak
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct LeagueSeasonTeamStats {
+    team_id: usize,
+    overall: LeagueSeasonTeamSplit,
+    home: LeagueSeasonTeamSplit,
+    away: LeagueSeasonTeamSplit,
+    neutral: LeagueSeasonTeamSplit,
+    victory_margin: u32,
+    streak: Option<LeagueSeasonStreak>
+}
+
+impl LeagueSeasonTeamStats {
+    /// Constructor for the `LeagueSeasonTeamStats` struct, with no games
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamStats;
+    ///
+    /// let my_stats = LeagueSeasonTeamStats::new(3);
+    /// assert_eq!(my_stats.team_id(), 3);
+    /// assert!(my_stats.streak().is_none());
+    /// ```
+    pub fn new(team_id: usize) -> LeagueSeasonTeamStats {
+        LeagueSeasonTeamStats {
+            team_id,
+            ..Default::default()
+        }
+    }
+
+    /// Add a matchup to the stats. Matchups which are not complete, or which
+    /// the team did not play in, are ignored, so the matchups should be
+    /// added in week order for the streak to be current.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamStats;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut won = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// won.enter_score(24, 17).unwrap();
+    /// let unplayed = LeagueSeasonMatchup::new(1, 0, "HOME", "AWAY", &mut rng);
+    ///
+    /// let mut my_stats = LeagueSeasonTeamStats::new(0);
+    /// my_stats.add_matchup(&won);
+    /// my_stats.add_matchup(&unplayed);
+    /// assert_eq!(my_stats.home().record().to_string(), "1-0-0");
+    /// assert_eq!(my_stats.streak().unwrap().to_string(), "W1");
+    /// ```
+    pub fn add_matchup(&mut self, matchup: &LeagueSeasonMatchup) {
+        let result = match matchup.result(self.team_id) {
+            Some(result) => result,
+            None => return
+        };
+        let context = matchup.context();
+        let (scored, allowed) = if matchup.is_home_team(self.team_id) {
+            (context.home_score(), context.away_score())
+        } else {
+            (context.away_score(), context.home_score())
+        };
+
+        // Add the game to the overall split and its venue's split
+        self.overall.add_game(scored, allowed);
+        if context.neutral_site() {
+            self.neutral.add_game(scored, allowed);
+        } else if matchup.is_home_team(self.team_id) {
+            self.home.add_game(scored, allowed);
+        } else {
+            self.away.add_game(scored, allowed);
+        }
+        if result == FootballMatchupResult::Win {
+            self.victory_margin += scored - allowed;
+        }
+
+        // Extend the streak, or start a new one
+        self.streak = match self.streak {
+            Some(streak) if streak.result == result => Some(
+                LeagueSeasonStreak { result, games: streak.games + 1 }
+            ),
+            _ => Some(LeagueSeasonStreak { result, games: 1 })
+        };
+    }
+
+    /// Get the team's ID
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamStats;
+    ///
+    /// let my_stats = LeagueSeasonTeamStats::new(2);
+    /// assert_eq!(my_stats.team_id(), 2);
+    /// ```
+    pub fn team_id(&self) -> usize {
+        self.team_id
+    }
+
+    /// Get the team's record and points over all its completed games
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamStats;
+    ///
+    /// let my_stats = LeagueSeasonTeamStats::new(0);
+    /// assert_eq!(my_stats.overall().games_played(), 0);
+    /// ```
+    pub fn overall(&self) -> &LeagueSeasonTeamSplit {
+        &self.overall
+    }
+
+    /// Get the team's record and points in its home games
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamStats;
+    ///
+    /// let my_stats = LeagueSeasonTeamStats::new(0);
+    /// assert_eq!(my_stats.home().games_played(), 0);
+    /// ```
+    pub fn home(&self) -> &LeagueSeasonTeamSplit {
+        &self.home
+    }
+
+    /// Get the team's record and points in its away games
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamStats;
+    ///
+    /// let my_stats = LeagueSeasonTeamStats::new(0);
+    /// assert_eq!(my_stats.away().games_played(), 0);
+    /// ```
+    pub fn away(&self) -> &LeagueSeasonTeamSplit {
+        &self.away
+    }
+
+    /// Get the team's record and points in its neutral site games
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamStats;
+    ///
+    /// let my_stats = LeagueSeasonTeamStats::new(0);
+    /// assert_eq!(my_stats.neutral().games_played(), 0);
+    /// ```
+    pub fn neutral(&self) -> &LeagueSeasonTeamSplit {
+        &self.neutral
+    }
+
+    /// Get the points the team scored in its completed games
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamStats;
+    ///
+    /// let my_stats = LeagueSeasonTeamStats::new(0);
+    /// assert_eq!(my_stats.points_for(), 0);
+    /// ```
+    pub fn points_for(&self) -> u32 {
+        self.overall.points_for()
+    }
+
+    /// Get the points the team allowed in its completed games
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamStats;
+    ///
+    /// let my_stats = LeagueSeasonTeamStats::new(0);
+    /// assert_eq!(my_stats.points_against(), 0);
+    /// ```
+    pub fn points_against(&self) -> u32 {
+        self.overall.points_against()
+    }
+
+    /// The points the team scored minus the points it allowed
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamStats;
+    ///
+    /// let my_stats = LeagueSeasonTeamStats::new(0);
+    /// assert_eq!(my_stats.point_differential(), 0);
+    /// ```
+    pub fn point_differential(&self) -> i64 {
+        self.overall.point_differential()
+    }
+
+    /// The average number of points the team won its games by, or `None` if
+    /// it has not won a game
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamStats;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut my_stats = LeagueSeasonTeamStats::new(0);
+    /// assert!(my_stats.average_margin_of_victory().is_none());
+    /// for (home_score, away_score) in [(24, 17), (31, 10), (3, 20)] {
+    ///     let mut matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    ///     matchup.enter_score(home_score, away_score).unwrap();
+    ///     my_stats.add_matchup(&matchup);
+    /// }
+    /// assert_eq!(my_stats.average_margin_of_victory(), Some(14.0));
+    /// ```
+    pub fn average_margin_of_victory(&self) -> Option<f64> {
+        let wins = *self.overall.record().wins();
+        if wins == 0 {
+            return None;
+        }
+        Some(self.victory_margin as f64 / wins as f64)
+    }
+
+    /// Get the team's current streak, or `None` if it has not completed a
+    /// game
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    /// use fbsim_core::league::season::stats::LeagueSeasonTeamStats;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut my_stats = LeagueSeasonTeamStats::new(1);
+    /// for (home_score, away_score) in [(3, 20), (24, 17), (31, 10)] {
+    ///     let mut matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    ///     matchup.enter_score(home_score, away_score).unwrap();
+    ///     my_stats.add_matchup(&matchup);
+    /// }
+    /// assert_eq!(my_stats.streak().unwrap().to_string(), "L2");
+    /// ```
+    pub fn streak(&self) -> Option<&LeagueSeasonStreak> {
+        self.streak.as_ref()
+    }
+}
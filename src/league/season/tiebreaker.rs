@@ -0,0 +1,396 @@
+#![doc = include_str!("../../../docs/league/season/tiebreaker.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::league::matchup::LeagueTeamRecord;
+use crate::league::season::LeagueSeason;
+use crate::league::season::conference::LeagueConference;
+use crate::league::season::week::LeagueSeasonWeek;
+
+/// # `TiebreakerRule` enum
+///
+/// The rule which decided the order of two teams in the standings
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum TiebreakerRule {
+    /// The teams' records differ, so no tiebreaker was needed
+    Record,
+    /// Record in the games among the tied teams
+    HeadToHead,
+    /// Record against division opponents, when the tied teams share a
+    /// division
+    DivisionRecord,
+    /// Record against conference opponents, when the tied teams share a
+    /// conference
+    ConferenceRecord,
+    /// Points scored minus points allowed in all completed regular season
+    /// games
+    PointDifferential,
+    /// Lower team ID, as a last resort
+    TeamId
+}
+
+impl fmt::Display for TiebreakerRule {
+    /// Display a `TiebreakerRule` as a short phrase
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::tiebreaker::TiebreakerRule;
+    ///
+    /// assert_eq!(TiebreakerRule::HeadToHead.to_string(), "won head-to-head");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let phrase = match self {
+            TiebreakerRule::Record => "better record",
+            TiebreakerRule::HeadToHead => "won head-to-head",
+            TiebreakerRule::DivisionRecord => "better division record",
+            TiebreakerRule::ConferenceRecord => "better conference record",
+            TiebreakerRule::PointDifferential => "better point differential",
+            TiebreakerRule::TeamId => "lower team ID"
+        };
+        f.write_str(phrase)
+    }
+}
+
+/// # `TiebreakerExplanation` struct
+///
+/// Which of two teams ranks ahead in the standings, and the rule which
+/// decided it
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct TiebreakerExplanation {
+    /// ID of the team ranked ahead
+    pub winner: usize,
+    /// ID of the team ranked behind
+    pub loser: usize,
+    /// The rule which decided the order
+    pub rule: TiebreakerRule
+}
+
+impl fmt::Display for TiebreakerExplanation {
+    /// Display a `TiebreakerExplanation` as a sentence
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::tiebreaker::{TiebreakerExplanation, TiebreakerRule};
+    ///
+    /// let my_explanation = TiebreakerExplanation {
+    ///     winner: 2,
+    ///     loser: 1,
+    ///     rule: TiebreakerRule::HeadToHead
+    /// };
+    /// assert_eq!(my_explanation.to_string(), "Team 2 ranks ahead of team 1: won head-to-head");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Team {} ranks ahead of team {}: {}", self.winner, self.loser, self.rule)
+    }
+}
+
+/// Whether two records are tied in sorted standings, sharing a win
+/// percentage and number of wins
+pub(crate) fn records_tied(a: &LeagueTeamRecord, b: &LeagueTeamRecord) -> bool {
+    a.cmp_win_pct(b).is_eq() && a.wins() == b.wins()
+}
+
+/// The season results the tiebreaker chain is applied to
+#[derive(Clone)]
+pub(crate) struct Tiebreakers {
+    /// Each team's (conference index, division ID)
+    divisions: BTreeMap<usize, (usize, usize)>,
+    /// Each completed regular season game as (home, away, home score, away
+    /// score)
+    games: Vec<(usize, usize, u32, u32)>,
+    /// Each team's division record, conference record, and point
+    /// differential, if summarized since the last game was added
+    totals: Option<BTreeMap<usize, TeamTotals>>
+}
+
+/// A team's results under the tiebreaker rules which do not depend on the
+/// other tied teams
+#[derive(Clone, Default)]
+struct TeamTotals {
+    division: LeagueTeamRecord,
+    conference: LeagueTeamRecord,
+    differential: i64
+}
+
+impl Tiebreakers {
+    /// Collect the season's divisions and completed regular season games
+    pub fn new(season: &LeagueSeason) -> Tiebreakers {
+        Tiebreakers::through_week(season, season.weeks().len())
+    }
+
+    /// Collect the season's divisions and the completed games of its first
+    /// `num_weeks` regular season weeks
+    pub fn through_week(season: &LeagueSeason, num_weeks: usize) -> Tiebreakers {
+        Tiebreakers::from_parts(season.conferences(), season.weeks(), num_weeks)
+    }
+
+    /// Collect the divisions of a season's conferences and the completed
+    /// games of its first `num_weeks` regular season weeks
+    pub fn from_parts(conferences: &[LeagueConference], weeks: &[LeagueSeasonWeek], num_weeks: usize) -> Tiebreakers {
+        let mut divisions = BTreeMap::new();
+        for (conf_index, conference) in conferences.iter().enumerate() {
+            for (div_id, division) in conference.divisions().iter().enumerate() {
+                for team in division.teams().iter() {
+                    divisions.insert(*team, (conf_index, div_id));
+                }
+            }
+        }
+        let games = weeks.iter()
+            .take(num_weeks)
+            .flat_map(|week| week.matchups().iter())
+            .filter(|matchup| matchup.context().game_over())
+            .map(|matchup| {
+                let context = matchup.context();
+                (*matchup.home_team(), *matchup.away_team(), context.home_score(), context.away_score())
+            })
+            .collect();
+        Tiebreakers { divisions, games, totals: None }
+    }
+
+    /// Record another completed game, such as a simulated result of a game
+    /// not yet played
+    pub fn add_game(&mut self, home_team: usize, away_team: usize, home_score: u32, away_score: u32) {
+        self.games.push((home_team, away_team, home_score, away_score));
+        self.totals = None;
+    }
+
+    /// Summarize each team's division record, conference record, and point
+    /// differential in a single pass over the games, so that breaking many
+    /// ties among the same results need not scan the games for each rule
+    pub fn summarize(&mut self) {
+        let mut totals: BTreeMap<usize, TeamTotals> = BTreeMap::new();
+        for (home, away, home_score, away_score) in self.games.iter() {
+            let (home_key, away_key) = (self.divisions.get(home), self.divisions.get(away));
+            let same_conference = matches!((home_key, away_key), (Some(h), Some(a)) if h.0 == a.0);
+            let same_division = same_conference && home_key == away_key;
+            for (team, scored, allowed) in [(home, home_score, away_score), (away, away_score, home_score)] {
+                let team_totals = totals.entry(*team).or_default();
+                team_totals.differential += *scored as i64 - *allowed as i64;
+                let records = [(same_division, &mut team_totals.division), (same_conference, &mut team_totals.conference)];
+                for (applies, record) in records {
+                    if !applies {
+                        continue;
+                    }
+                    match scored.cmp(allowed) {
+                        Ordering::Greater => record.increment_wins(1),
+                        Ordering::Less => record.increment_losses(1),
+                        Ordering::Equal => record.increment_ties(1)
+                    }
+                }
+            }
+        }
+        self.totals = Some(totals);
+    }
+
+    /// Reorder each run of teams tied in sorted standings by the tiebreaker
+    /// chain
+    pub fn break_ties(&self, standings: &mut [(usize, LeagueTeamRecord)]) {
+        let mut start = 0;
+        while start < standings.len() {
+            let end = start + standings[start..].iter()
+                .take_while(|(_, record)| records_tied(record, &standings[start].1))
+                .count();
+            if end - start > 1 {
+                let group: Vec<usize> = standings[start..end].iter().map(|(id, _)| *id).collect();
+                let order = self.resolve(group, None, &mut None);
+                standings[start..end].sort_by_key(|(id, _)| order.iter().position(|o| o == id));
+            }
+            start = end;
+        }
+    }
+
+    /// The teams of a group tied in record which the tiebreaker chain cannot
+    /// separate from the given team without falling back on team ID,
+    /// including the team itself, in chain order
+    pub fn still_tied(&self, group: Vec<usize>, team: usize) -> Vec<usize> {
+        self.resolve(group.clone(), None, &mut None)
+            .into_iter()
+            .filter(|id| {
+                if *id == team {
+                    return true;
+                }
+                let mut rule = None;
+                self.resolve(group.clone(), Some((team, *id)), &mut rule);
+                rule == Some(TiebreakerRule::TeamId)
+            })
+            .collect()
+    }
+
+    /// Order a group of tied teams, recording in `rule` the rule which
+    /// separated the given pair of teams
+    pub fn resolve(&self, group: Vec<usize>, pair: Option<(usize, usize)>, rule: &mut Option<TiebreakerRule>) -> Vec<usize> {
+        if group.len() < 2 {
+            return group;
+        }
+        let chain = [
+            TiebreakerRule::HeadToHead,
+            TiebreakerRule::DivisionRecord,
+            TiebreakerRule::ConferenceRecord,
+            TiebreakerRule::PointDifferential,
+            TiebreakerRule::TeamId
+        ];
+        for step in chain {
+            let values = match self.values(step, &group) {
+                Some(values) => values,
+                None => continue
+            };
+
+            // Split the group into runs of teams the rule cannot separate
+            let mut sorted = group.clone();
+            sorted.sort_by(|a, b| values[b].cmp(&values[a]));
+            let mut runs: Vec<Vec<usize>> = Vec::new();
+            for id in sorted {
+                match runs.last_mut() {
+                    Some(run) if values[&run[0]] == values[&id] => run.push(id),
+                    _ => runs.push(vec![id])
+                }
+            }
+            if runs.len() < 2 {
+                continue;
+            }
+
+            // Restart the chain within each run which is still tied
+            if let Some((a, b)) = pair {
+                let run_of = |id: usize| runs.iter().position(|run| run.contains(&id));
+                if let (Some(run_a), Some(run_b)) = (run_of(a), run_of(b)) {
+                    if run_a != run_b {
+                        *rule = Some(step);
+                    }
+                }
+            }
+            return runs.into_iter()
+                .flat_map(|run| self.resolve(run, pair, rule))
+                .collect();
+        }
+        group
+    }
+
+    /// Rank each team of a group by a tiebreaker rule, higher first, or
+    /// `None` if the rule does not apply to the group
+    fn values(&self, rule: TiebreakerRule, group: &[usize]) -> Option<BTreeMap<usize, TiebreakerValue>> {
+        match rule {
+            TiebreakerRule::HeadToHead => {
+                // Only applies if every tied team played the same number of
+                // games against the others
+                let members: BTreeSet<usize> = group.iter().copied().collect();
+                let records = self.records(group, |home, away| members.contains(&home) && members.contains(&away));
+                let games = |record: &LeagueTeamRecord| record.wins() + record.losses() + record.ties();
+                let first = games(&records[&group[0]]);
+                if first == 0 || records.values().any(|record| games(record) != first) {
+                    return None;
+                }
+                Some(records.into_iter().map(|(id, r)| (id, TiebreakerValue::Record(r))).collect())
+            },
+            TiebreakerRule::DivisionRecord | TiebreakerRule::ConferenceRecord => {
+                // Only applies if every tied team shares the division or
+                // conference
+                let by_division = rule == TiebreakerRule::DivisionRecord;
+                let key = |id: &usize| self.divisions.get(id)
+                    .map(|(conf, div)| if by_division { (*conf, Some(*div)) } else { (*conf, None) });
+                let first = key(&group[0])?;
+                if group.iter().any(|id| key(id) != Some(first)) {
+                    return None;
+                }
+                if let Some(totals) = &self.totals {
+                    return Some(group.iter().map(|id| {
+                        let team_totals = totals.get(id).cloned().unwrap_or_default();
+                        let record = if by_division { team_totals.division } else { team_totals.conference };
+                        (*id, TiebreakerValue::Record(record))
+                    }).collect());
+                }
+                let records = self.records(group, |home, away| key(&home) == key(&away));
+                Some(records.into_iter().map(|(id, r)| (id, TiebreakerValue::Record(r))).collect())
+            },
+            TiebreakerRule::PointDifferential => {
+                if let Some(totals) = &self.totals {
+                    return Some(group.iter().map(|id| {
+                        let differential = totals.get(id).map(|t| t.differential).unwrap_or(0);
+                        (*id, TiebreakerValue::Number(differential))
+                    }).collect());
+                }
+                let mut differentials: BTreeMap<usize, i64> = group.iter().map(|id| (*id, 0)).collect();
+                for (home, away, home_score, away_score) in self.games.iter() {
+                    let (home_score, away_score) = (*home_score as i64, *away_score as i64);
+                    if let Some(differential) = differentials.get_mut(home) {
+                        *differential += home_score - away_score;
+                    }
+                    if let Some(differential) = differentials.get_mut(away) {
+                        *differential += away_score - home_score;
+                    }
+                }
+                Some(differentials.into_iter().map(|(id, d)| (id, TiebreakerValue::Number(d))).collect())
+            },
+            TiebreakerRule::TeamId => Some(
+                group.iter().map(|id| (*id, TiebreakerValue::Number(-(*id as i64)))).collect()
+            ),
+            TiebreakerRule::Record => None
+        }
+    }
+
+    /// Each group team's record in the completed games passing a filter
+    fn records(&self, group: &[usize], filter: impl Fn(usize, usize) -> bool) -> BTreeMap<usize, LeagueTeamRecord> {
+        let mut records: BTreeMap<usize, LeagueTeamRecord> = group.iter()
+            .map(|id| (*id, LeagueTeamRecord::new()))
+            .collect();
+        for (home, away, home_score, away_score) in self.games.iter() {
+            if !filter(*home, *away) {
+                continue;
+            }
+            for (team, scored, allowed) in [(home, home_score, away_score), (away, away_score, home_score)] {
+                if let Some(record) = records.get_mut(team) {
+                    match scored.cmp(allowed) {
+                        Ordering::Greater => record.increment_wins(1),
+                        Ordering::Less => record.increment_losses(1),
+                        Ordering::Equal => record.increment_ties(1)
+                    }
+                }
+            }
+        }
+        records
+    }
+}
+
+/// A team's standing under one tiebreaker rule, where records compare by
+/// win percentage alone
+#[derive(Clone)]
+enum TiebreakerValue {
+    Record(LeagueTeamRecord),
+    Number(i64)
+}
+
+impl PartialEq for TiebreakerValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for TiebreakerValue {}
+
+impl PartialOrd for TiebreakerValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TiebreakerValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (TiebreakerValue::Record(a), TiebreakerValue::Record(b)) => a.cmp_win_pct(b),
+            (TiebreakerValue::Number(a), TiebreakerValue::Number(b)) => a.cmp(b),
+            (TiebreakerValue::Record(_), TiebreakerValue::Number(_)) => Ordering::Greater,
+            (TiebreakerValue::Number(_), TiebreakerValue::Record(_)) => Ordering::Less
+        }
+    }
+}
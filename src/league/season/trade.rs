@@ -0,0 +1,128 @@
+#![doc = include_str!("../../../docs/league/season/trade.md")]
+use crate::league::error::LeagueError;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+/// # `LeagueSeasonTrade` struct
+///
+/// A `LeagueSeasonTrade` transfers skill between two teams in a season. The
+/// offense and defense deltas are added to each of the first team's offensive
+/// and defensive skills, and subtracted from each of the second team's.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct LeagueSeasonTrade {
+    /// ID of the team receiving the deltas
+    pub team_a: usize,
+    /// ID of the team giving up the deltas
+    pub team_b: usize,
+    /// Change to each of the first team's offensive skills
+    pub offense_delta: i32,
+    /// Change to each of the first team's defensive skills
+    pub defense_delta: i32
+}
+
+impl LeagueSeasonTrade {
+    /// Constructor for the `LeagueSeasonTrade` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::trade::LeagueSeasonTrade;
+    ///
+    /// let my_trade = LeagueSeasonTrade::new(0, 1, 5, -5);
+    /// assert!(my_trade.offense_delta == 5);
+    /// ```
+    pub fn new(team_a: usize, team_b: usize, offense_delta: i32, defense_delta: i32) -> LeagueSeasonTrade {
+        LeagueSeasonTrade {
+            team_a,
+            team_b,
+            offense_delta,
+            defense_delta
+        }
+    }
+
+    /// Validate the trade, ensuring it involves two distinct teams and that
+    /// each delta is within the range [-100, 100]
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::error::LeagueError;
+    /// use fbsim_core::league::season::trade::LeagueSeasonTrade;
+    ///
+    /// assert!(LeagueSeasonTrade::new(0, 1, 5, -5).validate().is_ok());
+    /// assert_eq!(LeagueSeasonTrade::new(0, 0, 5, -5).validate(), Err(LeagueError::SameTeam { id: 0 }));
+    /// assert!(LeagueSeasonTrade::new(0, 1, 101, 0).validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), LeagueError> {
+        if self.team_a == self.team_b {
+            return Err(LeagueError::SameTeam { id: self.team_a });
+        }
+        if !(-100..=100).contains(&self.offense_delta) {
+            return Err(format!("Trade offense delta is out of range [-100, 100]: {}", self.offense_delta).into());
+        }
+        if !(-100..=100).contains(&self.defense_delta) {
+            return Err(format!("Trade defense delta is out of range [-100, 100]: {}", self.defense_delta).into());
+        }
+        Ok(())
+    }
+}
+
+/// # `LeagueSeasonTradeRecord` struct
+///
+/// A `LeagueSeasonTradeRecord` is an entry in a season's trade ledger,
+/// recording a trade along with the week before which it took effect
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct LeagueSeasonTradeRecord {
+    week: usize,
+    trade: LeagueSeasonTrade
+}
+
+impl LeagueSeasonTradeRecord {
+    /// Constructor for the `LeagueSeasonTradeRecord` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::trade::{LeagueSeasonTrade, LeagueSeasonTradeRecord};
+    ///
+    /// let my_record = LeagueSeasonTradeRecord::new(3, LeagueSeasonTrade::new(0, 1, 5, -5));
+    /// ```
+    pub fn new(week: usize, trade: LeagueSeasonTrade) -> LeagueSeasonTradeRecord {
+        LeagueSeasonTradeRecord {
+            week,
+            trade
+        }
+    }
+
+    /// Get the week before which the trade took effect
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::trade::{LeagueSeasonTrade, LeagueSeasonTradeRecord};
+    ///
+    /// let my_record = LeagueSeasonTradeRecord::new(3, LeagueSeasonTrade::new(0, 1, 5, -5));
+    /// assert!(my_record.week() == 3);
+    /// ```
+    pub fn week(&self) -> usize {
+        self.week
+    }
+
+    /// Borrow the recorded trade
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::trade::{LeagueSeasonTrade, LeagueSeasonTradeRecord};
+    ///
+    /// let my_record = LeagueSeasonTradeRecord::new(3, LeagueSeasonTrade::new(0, 1, 5, -5));
+    /// assert!(my_record.trade().team_a == 0);
+    /// ```
+    pub fn trade(&self) -> &LeagueSeasonTrade {
+        &self.trade
+    }
+}
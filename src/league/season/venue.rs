@@ -0,0 +1,82 @@
+#![doc = include_str!("../../../docs/league/season/venue.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+use crate::league::season::proposal::ScheduleBalance;
+
+/// # `LeagueSeasonVenueSwap` struct
+///
+/// A `LeagueSeasonVenueSwap` is an entry in a season's venue swap ledger,
+/// recording a regular season matchup whose home and away teams were swapped
+/// and the teams' roles after the swap
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct LeagueSeasonVenueSwap {
+    /// Index of the week containing the matchup
+    pub week: usize,
+    /// Index of the matchup within its week
+    pub matchup: usize,
+    /// ID of the home team after the swap
+    pub home_team: usize,
+    /// ID of the away team after the swap
+    pub away_team: usize
+}
+
+impl LeagueSeasonVenueSwap {
+    /// Constructor for the `LeagueSeasonVenueSwap` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::venue::LeagueSeasonVenueSwap;
+    ///
+    /// let my_swap = LeagueSeasonVenueSwap::new(3, 1, 0, 2);
+    /// assert_eq!(my_swap.home_team, 0);
+    /// ```
+    pub fn new(week: usize, matchup: usize, home_team: usize, away_team: usize) -> LeagueSeasonVenueSwap {
+        LeagueSeasonVenueSwap {
+            week,
+            matchup,
+            home_team,
+            away_team
+        }
+    }
+}
+
+/// # `HomeAwayRebalance` struct
+///
+/// A `HomeAwayRebalance` reports the swaps made by
+/// `LeagueSeason::rebalance_home_away` along with the schedule's balance
+/// before and after them
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct HomeAwayRebalance {
+    /// The schedule's balance before any swaps
+    pub before: ScheduleBalance,
+    /// The schedule's balance after the swaps
+    pub after: ScheduleBalance,
+    /// The swaps made, in the order they were made
+    pub swaps: Vec<LeagueSeasonVenueSwap>
+}
+
+impl HomeAwayRebalance {
+    /// Whether every team's home and away games differ by at most one after
+    /// the swaps
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::venue::HomeAwayRebalance;
+    ///
+    /// let my_rebalance = HomeAwayRebalance::default();
+    /// assert!(my_rebalance.balanced());
+    /// ```
+    pub fn balanced(&self) -> bool {
+        self.after.max_home_away_imbalance() <= 1
+    }
+}
@@ -1,11 +1,16 @@
 #![doc = include_str!("../../../docs/league/season/week.md")]
+use std::collections::BTreeMap;
+
 #[cfg(feature = "rocket_okapi")]
 use rocket_okapi::okapi::schemars;
 #[cfg(feature = "rocket_okapi")]
 use rocket_okapi::okapi::schemars::JsonSchema;
 use serde::{Serialize, Deserialize};
 
+use crate::league::error::LeagueError;
+use crate::game::play::Game;
 use crate::league::season::matchup::LeagueSeasonMatchup;
+use crate::league::season::notification::{Notification, NotificationRules};
 
 /// # `LeagueSeasonWeek` struct
 ///
@@ -122,6 +127,75 @@ impl LeagueSeasonWeek {
         true
     }
 
+    /// Ensure each of the week's matchups agrees with itself after being
+    /// edited in place, so that the week's completion, its matchups' scores,
+    /// and their archived stats are consistent. Repairs what can be derived
+    /// from the game contexts and errors on the first conflicting matchup.
+    /// Returns whether any matchup was repaired.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::context::GameContextBuilder;
+    /// use fbsim_core::game::play::Game;
+    /// use fbsim_core::league::season::week::LeagueSeasonWeek;
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut my_week = LeagueSeasonWeek::new();
+    /// my_week.matchups_mut().push(LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng));
+    /// assert_eq!(my_week.reconcile(), Ok(false));
+    ///
+    /// // Manually end the matchup while its game is in progress
+    /// let my_matchup = &mut my_week.matchups_mut()[0];
+    /// *my_matchup.game_mut() = Some(Game::new());
+    /// *my_matchup.context_mut() = GameContextBuilder::new()
+    ///     .quarter(4)
+    ///     .half_seconds(0)
+    ///     .away_score(3)
+    ///     .game_over(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(my_week.reconcile(), Ok(true));
+    /// assert!(my_week.complete());
+    /// assert!(my_week.matchups()[0].game().is_none());
+    /// ```
+    pub fn reconcile(&mut self) -> Result<bool, LeagueError> {
+        let mut repaired = false;
+        for (index, matchup) in self.matchups.iter_mut().enumerate() {
+            repaired |= matchup.reconcile().map_err(
+                |e| format!("Matchup {} is inconsistent: {}", index, e)
+            )?;
+        }
+        Ok(repaired)
+    }
+
+    /// Sum the attendance recorded for the week's matchups, or `None` if no
+    /// attendance has been recorded for any of them
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::week::LeagueSeasonWeek;
+    /// use fbsim_core::league::season::matchup::LeagueSeasonMatchup;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut my_week = LeagueSeasonWeek::new();
+    /// assert!(my_week.total_attendance().is_none());
+    ///
+    /// let mut my_matchup = LeagueSeasonMatchup::new(0, 1, "HOME", "AWAY", &mut rng);
+    /// *my_matchup.attendance_mut() = Some(50000);
+    /// my_week.matchups_mut().push(my_matchup);
+    /// assert_eq!(my_week.total_attendance(), Some(50000));
+    /// ```
+    pub fn total_attendance(&self) -> Option<u64> {
+        let attendances: Vec<u32> = self.matchups.iter()
+            .filter_map(|matchup| matchup.attendance())
+            .collect();
+        if attendances.is_empty() {
+            return None;
+        }
+        Some(attendances.iter().map(|a| *a as u64).sum())
+    }
+
     /// Get a matchup involving a team
     pub fn team_matchup(&self, id: usize) -> Option<LeagueSeasonMatchup> {
         for matchup in self.matchups.iter() {
@@ -132,3 +206,243 @@ impl LeagueSeasonWeek {
         None
     }
 }
+
+/// # `WeekSimOptions` struct
+///
+/// Options for simulating a week of season matchups
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WeekSimOptions {
+    /// Whether to stop at the first matchup which fails to simulate rather
+    /// than attempting the rest of the week
+    pub abort_on_error: bool,
+    /// Rules for reporting notable events during the week, or `None` to
+    /// report none
+    pub notifications: Option<NotificationRules>,
+    /// Whether to return the play-by-play log of each completed matchup
+    pub play_logs: bool
+}
+
+impl WeekSimOptions {
+    /// Constructor for the `WeekSimOptions` struct, which attempts every
+    /// matchup of the week
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::week::WeekSimOptions;
+    ///
+    /// let my_options = WeekSimOptions::new();
+    /// assert!(!my_options.abort_on_error);
+    /// ```
+    pub fn new() -> WeekSimOptions {
+        WeekSimOptions::default()
+    }
+
+    /// Set whether to stop at the first matchup which fails to simulate
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::week::WeekSimOptions;
+    ///
+    /// let my_options = WeekSimOptions::new().abort_on_error(true);
+    /// assert!(my_options.abort_on_error);
+    /// ```
+    pub fn abort_on_error(mut self, abort_on_error: bool) -> Self {
+        self.abort_on_error = abort_on_error;
+        self
+    }
+
+    /// Set the rules for reporting notable events during the week
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::notification::NotificationRules;
+    /// use fbsim_core::league::season::week::WeekSimOptions;
+    ///
+    /// let rules = NotificationRules::new().shutouts(true);
+    /// let my_options = WeekSimOptions::new().notifications(rules);
+    /// assert!(my_options.notifications.is_some());
+    /// ```
+    pub fn notifications(mut self, notifications: NotificationRules) -> Self {
+        self.notifications = Some(notifications);
+        self
+    }
+
+    /// Set whether to return the play-by-play log of each completed matchup
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::week::WeekSimOptions;
+    ///
+    /// let my_options = WeekSimOptions::new().play_logs(true);
+    /// assert!(my_options.play_logs);
+    /// ```
+    pub fn play_logs(mut self, play_logs: bool) -> Self {
+        self.play_logs = play_logs;
+        self
+    }
+}
+
+/// # `MatchupSimOutcome` enum
+///
+/// The outcome of attempting to simulate a matchup during a week simulation
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum MatchupSimOutcome {
+    /// The matchup was simulated to completion with the given final score
+    Completed {
+        home_score: u32,
+        away_score: u32
+    },
+    /// The matchup failed to simulate and was left unplayed
+    Failed {
+        reason: String
+    }
+}
+
+/// # `MatchupSimReport` struct
+///
+/// The outcome of attempting to simulate one matchup of a week
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct MatchupSimReport {
+    /// Index of the matchup within its week
+    pub matchup: usize,
+    /// ID of the home team
+    pub home_team: usize,
+    /// ID of the away team
+    pub away_team: usize,
+    /// Whether the matchup completed, and its score or failure reason
+    pub outcome: MatchupSimOutcome
+}
+
+/// # `WeekSimReport` struct
+///
+/// A `WeekSimReport` lists the outcome of each matchup attempted during a
+/// week simulation. Matchups which were already complete are not attempted
+/// and are not listed.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct WeekSimReport {
+    /// Index of the simulated week
+    pub week: usize,
+    /// The outcome of each attempted matchup, in simulation order
+    pub matchups: Vec<MatchupSimReport>,
+    /// Notable events found by the week's notification rules
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notifications: Vec<Notification>,
+    /// The play-by-play log of each completed matchup by matchup index, if
+    /// requested by the week's options
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub play_logs: BTreeMap<usize, Game>
+}
+
+impl WeekSimReport {
+    /// Get the attempted matchups which failed to simulate
+    ///
+    /// ### Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use fbsim_core::league::season::week::{MatchupSimOutcome, MatchupSimReport, WeekSimReport};
+    ///
+    /// let my_report = WeekSimReport {
+    ///     week: 0,
+    ///     matchups: vec![
+    ///         MatchupSimReport {
+    ///             matchup: 0,
+    ///             home_team: 0,
+    ///             away_team: 1,
+    ///             outcome: MatchupSimOutcome::Completed { home_score: 21, away_score: 14 }
+    ///         },
+    ///         MatchupSimReport {
+    ///             matchup: 1,
+    ///             home_team: 2,
+    ///             away_team: 9,
+    ///             outcome: MatchupSimOutcome::Failed { reason: String::from("No team 9") }
+    ///         }
+    ///     ],
+    ///     notifications: Vec::new(),
+    ///     play_logs: BTreeMap::new()
+    /// };
+    /// assert_eq!(my_report.failures().len(), 1);
+    /// assert_eq!(my_report.failed_matchups(), vec![1]);
+    /// assert!(!my_report.succeeded());
+    /// ```
+    pub fn failures(&self) -> Vec<&MatchupSimReport> {
+        self.matchups.iter()
+            .filter(|m| matches!(m.outcome, MatchupSimOutcome::Failed { .. }))
+            .collect()
+    }
+
+    /// Get the indices of the matchups which failed to simulate, suitable
+    /// for retrying with `LeagueSeason::sim_matchups`
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::week::WeekSimReport;
+    ///
+    /// let my_report = WeekSimReport::default();
+    /// assert!(my_report.failed_matchups().is_empty());
+    /// ```
+    pub fn failed_matchups(&self) -> Vec<usize> {
+        self.failures().iter().map(|m| m.matchup).collect()
+    }
+
+    /// Summarize the reasons the failed matchups failed to simulate, or
+    /// `None` if every attempted matchup simulated to completion
+    ///
+    /// ### Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use fbsim_core::league::season::week::{MatchupSimOutcome, MatchupSimReport, WeekSimReport};
+    ///
+    /// let my_report = WeekSimReport {
+    ///     week: 0,
+    ///     matchups: vec![
+    ///         MatchupSimReport {
+    ///             matchup: 0,
+    ///             home_team: 2,
+    ///             away_team: 9,
+    ///             outcome: MatchupSimOutcome::Failed { reason: String::from("No team 9") }
+    ///         }
+    ///     ],
+    ///     notifications: Vec::new(),
+    ///     play_logs: BTreeMap::new()
+    /// };
+    /// assert_eq!(my_report.error(), Some(String::from("No team 9")));
+    /// ```
+    pub fn error(&self) -> Option<String> {
+        let reasons: Vec<&str> = self.matchups.iter()
+            .filter_map(|m| match &m.outcome {
+                MatchupSimOutcome::Failed { reason } => Some(reason.as_str()),
+                MatchupSimOutcome::Completed { .. } => None
+            })
+            .collect();
+        if reasons.is_empty() {
+            return None;
+        }
+        Some(reasons.join("; "))
+    }
+
+    /// Whether every attempted matchup simulated to completion
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::season::week::WeekSimReport;
+    ///
+    /// let my_report = WeekSimReport::default();
+    /// assert!(my_report.succeeded());
+    /// ```
+    pub fn succeeded(&self) -> bool {
+        self.failures().is_empty()
+    }
+}
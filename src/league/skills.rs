@@ -0,0 +1,263 @@
+#![doc = include_str!("../../docs/league/skills.md")]
+use std::collections::BTreeMap;
+
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+use crate::league::error::LeagueError;
+use crate::game::play::PlaySimulatable;
+use crate::league::season::LeagueSeason;
+use crate::team::FootballTeam;
+
+/// # `SkillStats` struct
+///
+/// The distribution of one skill across a season's teams
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct SkillStats {
+    /// Mean skill level
+    pub mean: f64,
+    /// Population standard deviation of the skill level
+    pub stddev: f64,
+    /// Lowest skill level
+    pub min: u32,
+    /// Highest skill level
+    pub max: u32
+}
+
+impl SkillStats {
+    /// Compute the distribution of a set of skill levels
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::skills::SkillStats;
+    ///
+    /// let stats = SkillStats::from_levels(&[40, 60]);
+    /// assert_eq!(stats.mean, 50.0);
+    /// assert_eq!(stats.stddev, 10.0);
+    /// assert_eq!((stats.min, stats.max), (40, 60));
+    /// ```
+    pub fn from_levels(levels: &[u32]) -> SkillStats {
+        if levels.is_empty() {
+            return SkillStats::default();
+        }
+        let n = levels.len() as f64;
+        let mean = levels.iter().map(|l| *l as f64).sum::<f64>() / n;
+        let variance = levels.iter().map(|l| (*l as f64 - mean).powi(2)).sum::<f64>() / n;
+        SkillStats {
+            mean,
+            stddev: variance.sqrt(),
+            min: *levels.iter().min().unwrap_or(&0),
+            max: *levels.iter().max().unwrap_or(&0)
+        }
+    }
+}
+
+/// # `SkillTransform` struct
+///
+/// The linear transform `level * scale + offset` applied to one skill by
+/// `normalize_skills`, rounded and clamped to the range [0, 100]
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct SkillTransform {
+    /// The skill's distribution before the transform
+    pub before: SkillStats,
+    /// The skill's distribution after the transform
+    pub after: SkillStats,
+    /// Factor each skill level was multiplied by
+    pub scale: f64,
+    /// Amount added to each scaled skill level
+    pub offset: f64,
+    /// Number of teams whose transformed level was clamped to [0, 100]
+    pub clamped: usize
+}
+
+impl SkillTransform {
+    /// Apply the transform to a skill level, returning the new level and
+    /// whether it was clamped
+    fn apply(&self, level: u32) -> (u32, bool) {
+        let value = (level as f64 * self.scale + self.offset).round();
+        (value.clamp(0.0, 100.0) as u32, !(0.0..=100.0).contains(&value))
+    }
+}
+
+/// Each skill level of a team, keyed by `offense.<skill>` or
+/// `defense.<skill>`
+fn team_skills(team: &FootballTeam) -> Vec<(String, u32)> {
+    let offense = team.offense().skills().into_iter()
+        .map(|(name, level)| (format!("offense.{}", name), level));
+    let defense = team.defense().skills().into_iter()
+        .map(|(name, level)| (format!("defense.{}", name), level));
+    offense.chain(defense).collect()
+}
+
+/// Get the distribution of each offensive and defensive skill, including
+/// special teams skills, across a season's teams. Skills are keyed by
+/// `offense.<skill>` or `defense.<skill>`.
+///
+/// ### Example
+/// ```
+/// use fbsim_core::league::season::LeagueSeason;
+/// use fbsim_core::league::skills::skill_distribution;
+/// use fbsim_core::team::FootballTeam;
+///
+/// let mut my_season = LeagueSeason::new();
+/// my_season.add_team(0, FootballTeam::new()).unwrap();
+/// my_season.add_team(1, FootballTeam::new()).unwrap();
+///
+/// let distribution = skill_distribution(&my_season);
+/// assert_eq!(distribution["offense.passing"].mean, 50.0);
+/// assert_eq!(distribution["defense.kick_returning"].stddev, 0.0);
+/// ```
+pub fn skill_distribution(season: &LeagueSeason) -> BTreeMap<String, SkillStats> {
+    let mut levels: BTreeMap<String, Vec<u32>> = BTreeMap::new();
+    for team in season.teams().values() {
+        for (skill, level) in team_skills(team) {
+            levels.entry(skill).or_default().push(level);
+        }
+    }
+    levels.into_iter()
+        .map(|(skill, levels)| (skill, SkillStats::from_levels(&levels)))
+        .collect()
+}
+
+/// Linearly rescale each offensive and defensive skill across a season's
+/// teams to the given mean and standard deviation, clamping each level to
+/// [0, 100]. Each team keeps its place relative to the others in every
+/// skill. A skill every team shares is set to the target mean. Returns the
+/// transform applied to each skill, keyed as in `skill_distribution`.
+///
+/// Refuses to run once the season has started unless `force` is set, since
+/// rescaling would change the teams partway through the season.
+///
+/// ### Example
+/// ```
+/// use fbsim_core::game::play::PlaySimulatable;
+/// use fbsim_core::league::season::LeagueSeason;
+/// use fbsim_core::league::skills::normalize_skills;
+/// use fbsim_core::team::FootballTeam;
+///
+/// let mut my_season = LeagueSeason::new();
+/// my_season.add_team(0, FootballTeam::from_overalls("A", "A", 90, 90).unwrap()).unwrap();
+/// my_season.add_team(1, FootballTeam::from_overalls("B", "B", 70, 70).unwrap()).unwrap();
+///
+/// let transforms = normalize_skills(&mut my_season, 50.0, 5.0, false).unwrap();
+/// assert_eq!(transforms["offense.passing"].scale, 0.5);
+/// assert_eq!(my_season.teams()[&0].offense().passing(), 55);
+/// assert_eq!(my_season.teams()[&1].offense().passing(), 45);
+/// ```
+pub fn normalize_skills(season: &mut LeagueSeason, target_mean: f64, target_stddev: f64, force: bool) -> Result<BTreeMap<String, SkillTransform>, LeagueError> {
+    if !(0.0..=100.0).contains(&target_mean) {
+        return Err(LeagueError::InvalidSkillTarget {
+            reason: format!("mean must be in range [0, 100]: {}", target_mean)
+        });
+    }
+    if !target_stddev.is_finite() || target_stddev < 0.0 {
+        return Err(LeagueError::InvalidSkillTarget {
+            reason: format!("standard deviation must be non-negative: {}", target_stddev)
+        });
+    }
+    if season.started() && !force {
+        return Err(LeagueError::SeasonAlreadyStarted);
+    }
+
+    // Fit a transform to each skill's current distribution
+    let mut transforms: BTreeMap<String, SkillTransform> = skill_distribution(season).into_iter()
+        .map(|(skill, before)| {
+            let scale = if before.stddev > 0.0 { target_stddev / before.stddev } else { 0.0 };
+            let transform = SkillTransform {
+                before,
+                scale,
+                offset: target_mean - before.mean * scale,
+                ..Default::default()
+            };
+            (skill, transform)
+        })
+        .collect();
+
+    // Apply the transforms to every team
+    for team in season.teams_mut().values_mut() {
+        let mut rescale = |side: &str, name: &'static str, level: u32| {
+            match transforms.get_mut(&format!("{}.{}", side, name)) {
+                Some(transform) => {
+                    let (level, clamped) = transform.apply(level);
+                    transform.clamped += clamped as usize;
+                    level
+                },
+                None => level
+            }
+        };
+        team.offense_mut().rescale(|name, level| rescale("offense", name, level));
+        team.defense_mut().rescale(|name, level| rescale("defense", name, level));
+    }
+
+    // Report the resulting distributions
+    for (skill, after) in skill_distribution(season) {
+        if let Some(transform) = transforms.get_mut(&skill) {
+            transform.after = after;
+        }
+    }
+    Ok(transforms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+    use crate::league::season::LeagueSeasonScheduleOptions;
+
+    #[test]
+    fn test_normalize_skewed_league() {
+        // Half the teams were imported on a 40-99 scale, half on 0-100
+        let mut season = LeagueSeason::with_year(2026);
+        let overalls = [99, 85, 70, 40, 100, 60, 25, 0];
+        for (id, overall) in overalls.iter().enumerate() {
+            let defense = 100 - overall;
+            season.add_team(id, FootballTeam::from_overalls("TM", "TM", *overall, defense).unwrap()).unwrap();
+        }
+        let overalls = |season: &LeagueSeason| -> Vec<u32> {
+            season.teams().values().map(|team| team.offense().overall()).collect()
+        };
+        let before = overalls(&season);
+        let keeps_order = |after: Vec<u32>| {
+            (0..before.len()).all(|i| (0..before.len()).all(|j| before[i] >= before[j] || after[i] <= after[j]))
+        };
+
+        // Every skill is rescaled to the target distribution, within
+        // rounding, and no team changes place
+        let transforms = normalize_skills(&mut season, 60.0, 12.0, false).unwrap();
+        assert_eq!(transforms.len(), 16);
+        for (skill, stats) in skill_distribution(&season) {
+            assert!((stats.mean - 60.0).abs() < 0.5, "{} mean {}", skill, stats.mean);
+            assert!((stats.stddev - 12.0).abs() < 0.5, "{} stddev {}", skill, stats.stddev);
+            assert_eq!(transforms[&skill].after, stats);
+            assert_eq!(transforms[&skill].clamped, 0);
+        }
+        assert!(keeps_order(overalls(&season)));
+
+        // A wide target distribution is clamped to [0, 100]
+        let transforms = normalize_skills(&mut season, 50.0, 60.0, false).unwrap();
+        assert!(transforms["offense.passing"].clamped > 0);
+        assert_eq!(skill_distribution(&season)["offense.passing"].max, 100);
+        assert!(keeps_order(overalls(&season)));
+
+        // Once the season starts, normalizing must be forced
+        let mut rng = SmallRng::seed_from_u64(1500);
+        season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        season.sim_week(0, &mut rng).unwrap();
+        assert!(normalize_skills(&mut season, 50.0, 10.0, false).is_err());
+        assert!(normalize_skills(&mut season, 50.0, 10.0, true).is_ok());
+        let err = normalize_skills(&mut season, 150.0, 10.0, true).unwrap_err();
+        assert!(matches!(err, LeagueError::InvalidSkillTarget { .. }), "{}", err);
+        let err = normalize_skills(&mut season, 50.0, -1.0, true).unwrap_err();
+        assert!(matches!(err, LeagueError::InvalidSkillTarget { .. }), "{}", err);
+    }
+}
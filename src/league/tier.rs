@@ -0,0 +1,290 @@
+#![doc = include_str!("../../docs/league/tier.md")]
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use crate::league::error::LeagueError;
+use crate::game::context::overtime::OvertimeRules;
+use crate::game::play::{Game, GameSimulator};
+use crate::league::season::LeagueSeason;
+use crate::league::season::matchup::LeagueSeasonMatchup;
+
+/// # `LeagueTierLink` struct
+///
+/// Options for exchanging teams between an upper and a lower tier at the
+/// end of a season
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LeagueTierLink {
+    /// Number of teams relegated from the bottom of the upper tier and
+    /// promoted from the top of the lower tier
+    pub num_teams: usize,
+    /// If true, the upper tier team just above the relegation places hosts
+    /// the lower tier team just below the promotion places, and the two
+    /// swap tiers if the lower tier team wins
+    pub promotion_playoff: bool
+}
+
+impl Default for LeagueTierLink {
+    /// Default constructor for the `LeagueTierLink` struct, an exchange of
+    /// 2 teams without a promotion playoff
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::tier::LeagueTierLink;
+    ///
+    /// let my_link = LeagueTierLink::default();
+    /// assert!(my_link.num_teams == 2);
+    /// assert!(!my_link.promotion_playoff);
+    /// ```
+    fn default() -> Self {
+        LeagueTierLink {
+            num_teams: 2,
+            promotion_playoff: false
+        }
+    }
+}
+
+impl LeagueTierLink {
+    /// Constructor for the `LeagueTierLink` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::tier::LeagueTierLink;
+    ///
+    /// let my_link = LeagueTierLink::new();
+    /// ```
+    pub fn new() -> LeagueTierLink {
+        LeagueTierLink::default()
+    }
+
+    /// Set the number of teams exchanged automatically
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::tier::LeagueTierLink;
+    ///
+    /// let my_link = LeagueTierLink::new().num_teams(3);
+    /// assert!(my_link.num_teams == 3);
+    /// ```
+    pub fn num_teams(mut self, num_teams: usize) -> Self {
+        self.num_teams = num_teams;
+        self
+    }
+
+    /// Set whether a promotion playoff decides one more exchange
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::tier::LeagueTierLink;
+    ///
+    /// let my_link = LeagueTierLink::new().promotion_playoff(true);
+    /// assert!(my_link.promotion_playoff);
+    /// ```
+    pub fn promotion_playoff(mut self, promotion_playoff: bool) -> Self {
+        self.promotion_playoff = promotion_playoff;
+        self
+    }
+
+    /// The number of teams each tier needs to take part in the exchange:
+    /// those exchanged automatically, plus one for the promotion playoff
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::tier::LeagueTierLink;
+    ///
+    /// let my_link = LeagueTierLink::new().num_teams(2).promotion_playoff(true);
+    /// assert_eq!(my_link.teams_involved(), 3);
+    /// ```
+    pub fn teams_involved(&self) -> usize {
+        self.num_teams + usize::from(self.promotion_playoff)
+    }
+}
+
+/// # `LeagueTier` enum
+///
+/// Which side of an exchange between tiers a league was on
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum LeagueTier {
+    /// The league relegated teams and received promoted teams
+    Upper,
+    /// The league promoted teams and received relegated teams
+    Lower
+}
+
+/// # `LeagueTierMove` enum
+///
+/// How a team moved between tiers
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub enum LeagueTierMove {
+    /// The team moved up from the lower tier
+    Promoted,
+    /// The team moved down from the upper tier
+    Relegated
+}
+
+/// # `LeagueTierExchange` struct
+///
+/// The teams exchanged between two tiers at the end of a season, as
+/// recorded on one of the two leagues
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[cfg_attr(feature = "wasm", derive(tsify_next::Tsify))]
+#[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize, Deserialize)]
+pub struct LeagueTierExchange {
+    /// The year of the season after which the teams moved
+    pub year: usize,
+    /// Which tier the league recording the exchange was
+    pub tier: LeagueTier,
+    /// IDs of the teams promoted from the lower tier, best finish first
+    pub promoted: Vec<usize>,
+    /// IDs of the teams relegated from the upper tier, worst finish last
+    pub relegated: Vec<usize>,
+    /// The promotion playoff, if one was played, hosted by the upper tier
+    /// team
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub playoff: Option<LeagueSeasonMatchup>
+}
+
+impl LeagueTierExchange {
+    /// Decide the teams exchanged between the complete seasons of two tiers,
+    /// simulating the promotion playoff if there is one
+    pub(crate) fn from_seasons(upper: &LeagueSeason, lower: &LeagueSeason, link: &LeagueTierLink, rng: &mut impl Rng) -> Result<LeagueTierExchange, LeagueError> {
+        // Ensure the seasons are complete, in the same year, and big enough
+        if upper.year() != lower.year() {
+            return Err(
+                format!(
+                    "Cannot exchange teams: upper tier season {} and lower tier season {} differ",
+                    upper.year(), lower.year()
+                ).into()
+            );
+        }
+        for (name, season) in [("upper", upper), ("lower", lower)] {
+            if !season.complete() {
+                return Err(
+                    format!(
+                        "Cannot exchange teams: {} tier season {} is not complete",
+                        name, season.year()
+                    ).into()
+                );
+            }
+            if season.teams().len() < link.teams_involved() {
+                return Err(
+                    format!(
+                        "Cannot exchange teams: {} tier season {} has {} teams, {} needed",
+                        name, season.year(), season.teams().len(), link.teams_involved()
+                    ).into()
+                );
+            }
+        }
+        if link.teams_involved() == 0 {
+            return Err(LeagueError::EmptyTierLink);
+        }
+        if let Some(id) = lower.teams().keys().find(|id| upper.team(**id).is_some()) {
+            return Err(
+                format!(
+                    "Cannot exchange teams: team {} plays in both tiers in {}",
+                    id, upper.year()
+                ).into()
+            );
+        }
+
+        // Relegate the bottom of the upper tier and promote the top of the
+        // lower tier
+        let upper_standings: Vec<usize> = upper.standings().into_iter().map(|(id, _)| id).collect();
+        let lower_standings: Vec<usize> = lower.standings().into_iter().map(|(id, _)| id).collect();
+        let cutoff = upper_standings.len() - link.num_teams;
+        let mut promoted: Vec<usize> = lower_standings[..link.num_teams].to_vec();
+        let mut relegated: Vec<usize> = upper_standings[cutoff..].to_vec();
+
+        // The promotion playoff is hosted by the upper tier team, which
+        // keeps its place unless the lower tier team wins
+        let playoff = if link.promotion_playoff {
+            let home_id = upper_standings[cutoff - 1];
+            let away_id = lower_standings[link.num_teams];
+            let home = upper.team(home_id).unwrap();
+            let away = lower.team(away_id).unwrap();
+            let mut matchup = LeagueSeasonMatchup::new(home_id, away_id, home.short_name(), away.short_name(), rng);
+            let mut game = Game::new();
+            let context = GameSimulator::new()
+                .sim_game(home, away, matchup.sim_context(OvertimeRules::playoffs()), &mut game, rng)
+                .map_err(|e| LeagueError::Simulation { reason: e.to_string() })?;
+            matchup.finalize(context, &game)?;
+            if matchup.winner() == Some(away_id) {
+                promoted.push(away_id);
+                relegated.insert(0, home_id);
+            }
+            Some(matchup)
+        } else {
+            None
+        };
+        Ok(LeagueTierExchange {
+            year: *upper.year(),
+            tier: LeagueTier::Upper,
+            promoted,
+            relegated,
+            playoff
+        })
+    }
+
+    /// How a team moved in the exchange, if it moved
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::tier::{LeagueTier, LeagueTierExchange, LeagueTierMove};
+    ///
+    /// let exchange = LeagueTierExchange {
+    ///     year: 2026,
+    ///     tier: LeagueTier::Upper,
+    ///     promoted: vec![4],
+    ///     relegated: vec![3],
+    ///     playoff: None
+    /// };
+    /// assert_eq!(exchange.moved(4), Some(LeagueTierMove::Promoted));
+    /// assert_eq!(exchange.moved(3), Some(LeagueTierMove::Relegated));
+    /// assert_eq!(exchange.moved(0), None);
+    /// ```
+    pub fn moved(&self, id: usize) -> Option<LeagueTierMove> {
+        if self.promoted.contains(&id) {
+            Some(LeagueTierMove::Promoted)
+        } else if self.relegated.contains(&id) {
+            Some(LeagueTierMove::Relegated)
+        } else {
+            None
+        }
+    }
+
+    /// The teams that join the league recording the exchange: the promoted
+    /// teams for the upper tier, and the relegated teams for the lower tier
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::tier::{LeagueTier, LeagueTierExchange};
+    ///
+    /// let exchange = LeagueTierExchange {
+    ///     year: 2026,
+    ///     tier: LeagueTier::Lower,
+    ///     promoted: vec![4],
+    ///     relegated: vec![3],
+    ///     playoff: None
+    /// };
+    /// assert_eq!(exchange.arriving(), &vec![3]);
+    /// ```
+    pub fn arriving(&self) -> &Vec<usize> {
+        match self.tier {
+            LeagueTier::Upper => &self.promoted,
+            LeagueTier::Lower => &self.relegated
+        }
+    }
+}
@@ -0,0 +1,593 @@
+#![doc = include_str!("../../docs/league/world.md")]
+use std::collections::BTreeMap;
+
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use rand::Rng;
+use serde::{Serialize, Deserialize, Deserializer};
+
+use crate::league::error::LeagueError;
+use crate::team::FootballTeam;
+use crate::league::League;
+use crate::league::season::LeagueSeasonScheduleOptions;
+
+/// # `WorldTeam` struct
+///
+/// A `WorldTeam` is a franchise in the shared team registry of a `World`.
+/// Its ID is stable across every league in the world, and its metadata
+/// identifies the franchise independently of its skills in any one season.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct WorldTeam {
+    name: String,
+    short_name: String
+}
+
+impl WorldTeam {
+    /// Constructor for the `WorldTeam` struct
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::world::WorldTeam;
+    ///
+    /// let my_team = WorldTeam::new("Bay City Bears", "BCB");
+    /// ```
+    pub fn new(name: &str, short_name: &str) -> WorldTeam {
+        WorldTeam {
+            name: String::from(name),
+            short_name: String::from(short_name)
+        }
+    }
+
+    /// Get the franchise name
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::world::WorldTeam;
+    ///
+    /// let my_team = WorldTeam::new("Bay City Bears", "BCB");
+    /// assert!(my_team.name() == "Bay City Bears");
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the franchise short name
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::world::WorldTeam;
+    ///
+    /// let my_team = WorldTeam::new("Bay City Bears", "BCB");
+    /// assert!(my_team.short_name() == "BCB");
+    /// ```
+    pub fn short_name(&self) -> &str {
+        &self.short_name
+    }
+}
+
+/// # `WorldRaw` struct
+///
+/// A `WorldRaw` represents a world that is freshly deserialized from JSON
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct WorldRaw {
+    pub teams: BTreeMap<usize, WorldTeam>,
+    pub leagues: BTreeMap<String, League>
+}
+
+impl WorldRaw {
+    pub fn validate(&self) -> Result<(), LeagueError> {
+        // Ensure every league team maps to a team in the registry
+        for (name, league) in self.leagues.iter() {
+            for id in league.teams().keys() {
+                if !self.teams.contains_key(id) {
+                    return Err(
+                        format!(
+                            "League {} contains team with nonexistent world team ID: {}",
+                            name, id
+                        ).into()
+                    )
+                }
+            }
+        }
+
+        // Ensure each team is active in at most one league per year
+        let mut active: BTreeMap<(usize, usize), &str> = BTreeMap::new();
+        for (name, league) in self.leagues.iter() {
+            let seasons = league.seasons().iter().chain(league.current_season().iter());
+            for season in seasons {
+                for id in season.teams().keys() {
+                    if let Some(other) = active.insert((*season.year(), *id), name) {
+                        return Err(
+                            format!(
+                                "Team {} is active in both league {} and league {} in {}",
+                                id, other, name, season.year()
+                            ).into()
+                        )
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// # `World` struct
+///
+/// A `World` is a group of independent leagues which draw from a shared
+/// registry of franchises. Each league's team IDs are IDs in the registry,
+/// and a team may be active in at most one league's season per year.
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize)]
+pub struct World {
+    teams: BTreeMap<usize, WorldTeam>,
+    leagues: BTreeMap<String, League>
+}
+
+impl TryFrom<WorldRaw> for World {
+    type Error = LeagueError;
+
+    fn try_from(item: WorldRaw) -> Result<Self, Self::Error> {
+        // Validate the raw world
+        item.validate()?;
+
+        // If valid, then convert
+        Ok(
+            World{
+                teams: item.teams,
+                leagues: item.leagues
+            }
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for World {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Only deserialize if the conversion from raw succeeds
+        let raw = WorldRaw::deserialize(deserializer)?;
+        World::try_from(raw).map_err(serde::de::Error::custom)
+    }
+}
+
+impl World {
+    /// Constructor for the `World` struct with an empty team registry and
+    /// no leagues
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::world::World;
+    ///
+    /// let my_world = World::new();
+    /// ```
+    pub fn new() -> World {
+        World::default()
+    }
+
+    /// Adds a team to the registry, returning its ID
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::world::{World, WorldTeam};
+    ///
+    /// let mut my_world = World::new();
+    /// let id = my_world.add_team(WorldTeam::new("Bay City Bears", "BCB"));
+    /// assert_eq!(id, 0);
+    /// ```
+    pub fn add_team(&mut self, team: WorldTeam) -> usize {
+        // Get the last item in the BTreeMap, which is auto-sorted by ID
+        let id = match self.teams.iter().next_back() {
+            Some((&max_id, _)) => max_id + 1,
+            None => 0
+        };
+        self.teams.insert(id, team);
+        id
+    }
+
+    /// Borrows the team registry immutably
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::world::{World, WorldTeam};
+    ///
+    /// let mut my_world = World::new();
+    /// my_world.add_team(WorldTeam::new("Bay City Bears", "BCB"));
+    /// assert_eq!(my_world.teams().len(), 1);
+    /// ```
+    pub fn teams(&self) -> &BTreeMap<usize, WorldTeam> {
+        &self.teams
+    }
+
+    /// Borrows a team from the registry given its ID
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::world::{World, WorldTeam};
+    ///
+    /// let mut my_world = World::new();
+    /// let id = my_world.add_team(WorldTeam::new("Bay City Bears", "BCB"));
+    /// assert!(my_world.team(id).unwrap().name() == "Bay City Bears");
+    /// ```
+    pub fn team(&self, id: usize) -> Option<&WorldTeam> {
+        self.teams.get(&id)
+    }
+
+    /// Adds a league to the world under the given name. The league's team
+    /// IDs must exist in the registry, and none of its teams may be active
+    /// in another league in the same year.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::world::World;
+    ///
+    /// let mut my_world = World::new();
+    /// assert!(my_world.add_league("Premier", League::new()).is_ok());
+    /// assert!(my_world.add_league("Premier", League::new()).is_err());
+    /// ```
+    pub fn add_league(&mut self, name: &str, league: League) -> Result<(), LeagueError> {
+        if self.leagues.contains_key(name) {
+            return Err(LeagueError::DuplicateLeague { name: name.to_string() });
+        }
+
+        // Validate the world with the league added before adding it
+        let mut leagues = self.leagues.clone();
+        leagues.insert(String::from(name), league);
+        let raw = WorldRaw {
+            teams: self.teams.clone(),
+            leagues
+        };
+        raw.validate()?;
+        self.leagues = raw.leagues;
+        Ok(())
+    }
+
+    /// Borrows the leagues of the world by name
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::world::World;
+    ///
+    /// let mut my_world = World::new();
+    /// let _ = my_world.add_league("Premier", League::new());
+    /// assert_eq!(my_world.leagues().len(), 1);
+    /// ```
+    pub fn leagues(&self) -> &BTreeMap<String, League> {
+        &self.leagues
+    }
+
+    /// Borrows a league from the world given its name
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::world::World;
+    ///
+    /// let mut my_world = World::new();
+    /// let _ = my_world.add_league("Premier", League::new());
+    /// assert!(my_world.league("Premier").is_some());
+    /// ```
+    pub fn league(&self, name: &str) -> Option<&League> {
+        self.leagues.get(name)
+    }
+
+    /// Mutably borrows a league given its name, returning an error if no
+    /// such league exists
+    fn league_mut(&mut self, name: &str) -> Result<&mut League, LeagueError> {
+        match self.leagues.get_mut(name) {
+            Some(league) => Ok(league),
+            None => Err(LeagueError::LeagueNotFound { name: name.to_string() })
+        }
+    }
+
+    /// Get the name of the league in which a team is active in a given year
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::world::{World, WorldTeam};
+    ///
+    /// let mut my_world = World::new();
+    /// let id = my_world.add_team(WorldTeam::new("Bay City Bears", "BCB"));
+    /// let _ = my_world.add_league("Premier", League::new());
+    /// let _ = my_world.add_league_team("Premier", id);
+    /// let _ = my_world.add_season("Premier");
+    /// let _ = my_world.add_season_team("Premier", id, FootballTeam::new());
+    ///
+    /// // The team is active in the league's current season
+    /// let year = *my_world.league("Premier").unwrap().current_season().as_ref().unwrap().year();
+    /// assert!(my_world.active_league(id, year) == Some("Premier"));
+    /// assert!(my_world.active_league(id, year + 1).is_none());
+    /// ```
+    pub fn active_league(&self, id: usize, year: usize) -> Option<&str> {
+        for (name, league) in self.leagues.iter() {
+            if let Some(season) = league.season(year) {
+                if season.team(id).is_some() {
+                    return Some(name);
+                }
+            }
+        }
+        None
+    }
+
+    /// Adds a team from the registry to a league
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::world::{World, WorldTeam};
+    ///
+    /// let mut my_world = World::new();
+    /// let id = my_world.add_team(WorldTeam::new("Bay City Bears", "BCB"));
+    /// let _ = my_world.add_league("Premier", League::new());
+    /// assert!(my_world.add_league_team("Premier", id).is_ok());
+    ///
+    /// // Teams must exist in the registry
+    /// assert!(my_world.add_league_team("Premier", id + 1).is_err());
+    /// ```
+    pub fn add_league_team(&mut self, name: &str, id: usize) -> Result<(), LeagueError> {
+        if !self.teams.contains_key(&id) {
+            return Err(LeagueError::TeamNotFound { id });
+        }
+        self.league_mut(name)?.add_team_with_id(id)
+    }
+
+    /// Creates a new season in a league, archiving its current season if
+    /// it is complete
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::world::World;
+    ///
+    /// let mut my_world = World::new();
+    /// let _ = my_world.add_league("Premier", League::new());
+    /// assert!(my_world.add_season("Premier").is_ok());
+    /// ```
+    pub fn add_season(&mut self, name: &str) -> Result<(), LeagueError> {
+        self.league_mut(name)?.add_season()
+    }
+
+    /// Creates a new season with the given year in a league, archiving its
+    /// current season if it is complete
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::world::World;
+    ///
+    /// let mut my_world = World::new();
+    /// let _ = my_world.add_league("Premier", League::new());
+    /// assert!(my_world.add_season_with_year("Premier", 2030).is_ok());
+    /// ```
+    pub fn add_season_with_year(&mut self, name: &str, year: usize) -> Result<(), LeagueError> {
+        self.league_mut(name)?.add_season_with_year(year)
+    }
+
+    /// Adds a team to the current season of a league. The team may not be
+    /// active in another league in the same year.
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::world::{World, WorldTeam};
+    ///
+    /// let mut my_world = World::new();
+    /// let id = my_world.add_team(WorldTeam::new("Bay City Bears", "BCB"));
+    /// for name in ["Premier", "Championship"] {
+    ///     let _ = my_world.add_league(name, League::new());
+    ///     let _ = my_world.add_league_team(name, id);
+    ///     let _ = my_world.add_season(name);
+    /// }
+    ///
+    /// // The team may only play in one league in the same year
+    /// assert!(my_world.add_season_team("Premier", id, FootballTeam::new()).is_ok());
+    /// assert!(my_world.add_season_team("Championship", id, FootballTeam::new()).is_err());
+    /// ```
+    pub fn add_season_team(&mut self, name: &str, id: usize, team: FootballTeam) -> Result<(), LeagueError> {
+        // Get the year of the league's current season
+        let year = match self.league(name) {
+            Some(league) => match league.current_season() {
+                Some(season) => *season.year(),
+                None => return Err(LeagueError::NoCurrentSeason)
+            },
+            None => return Err(LeagueError::LeagueNotFound { name: name.to_string() })
+        };
+
+        // Ensure the team is not active in another league that year
+        if let Some(other) = self.active_league(id, year) {
+            if other != name {
+                return Err(LeagueError::TeamAlreadyActive { id, league: other.to_string(), year })
+            }
+        }
+        self.league_mut(name)?.add_season_team(id, team)
+    }
+
+    /// Get the names of the leagues whose current season is in the given
+    /// year
+    fn leagues_in_year(&self, year: usize) -> Vec<String> {
+        self.leagues.iter()
+            .filter(|(_, league)| match league.current_season() {
+                Some(season) => *season.year() == year,
+                None => false
+            })
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Generate a schedule for every league whose current season is in the
+    /// given year
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::world::{World, WorldTeam};
+    ///
+    /// // Create a world with a 4 team league
+    /// let mut my_world = World::new();
+    /// let _ = my_world.add_league("Premier", League::new());
+    /// let _ = my_world.add_season("Premier");
+    /// for _ in 0..4 {
+    ///     let id = my_world.add_team(WorldTeam::new("Team", "TM"));
+    ///     let _ = my_world.add_league_team("Premier", id);
+    ///     let _ = my_world.add_season_team("Premier", id, FootballTeam::new());
+    /// }
+    ///
+    /// // Generate the schedules for the year
+    /// let year = *my_world.league("Premier").unwrap().current_season().as_ref().unwrap().year();
+    /// let mut rng = rand::thread_rng();
+    /// assert!(my_world.generate_schedules(year, LeagueSeasonScheduleOptions::new(), &mut rng).is_ok());
+    /// ```
+    pub fn generate_schedules(&mut self, year: usize, options: LeagueSeasonScheduleOptions, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        let names = self.leagues_in_year(year);
+        if names.is_empty() {
+            return Err(LeagueError::SeasonNotFound { year });
+        }
+        for name in names.iter() {
+            self.league_mut(name)?
+                .generate_schedule(options.clone(), rng)
+                .map_err(|e| format!("League {}: {}", name, e))?;
+        }
+        Ok(())
+    }
+
+    /// Simulate the current season of every league whose current season is
+    /// in the given year, in league name order
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    /// use fbsim_core::league::League;
+    /// use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+    /// use fbsim_core::league::world::{World, WorldTeam};
+    ///
+    /// // Create a world with a 4 team league
+    /// let mut my_world = World::new();
+    /// let _ = my_world.add_league("Premier", League::new());
+    /// let _ = my_world.add_season("Premier");
+    /// for _ in 0..4 {
+    ///     let id = my_world.add_team(WorldTeam::new("Team", "TM"));
+    ///     let _ = my_world.add_league_team("Premier", id);
+    ///     let _ = my_world.add_season_team("Premier", id, FootballTeam::new());
+    /// }
+    ///
+    /// // Generate the schedules and simulate the year
+    /// let year = *my_world.league("Premier").unwrap().current_season().as_ref().unwrap().year();
+    /// let mut rng = rand::thread_rng();
+    /// let _ = my_world.generate_schedules(year, LeagueSeasonScheduleOptions::new(), &mut rng);
+    /// assert!(my_world.sim_year(year, &mut rng).is_ok());
+    /// ```
+    pub fn sim_year(&mut self, year: usize, rng: &mut impl Rng) -> Result<(), LeagueError> {
+        let names = self.leagues_in_year(year);
+        if names.is_empty() {
+            return Err(LeagueError::SeasonNotFound { year });
+        }
+        for name in names.iter() {
+            self.league_mut(name)?
+                .sim(rng)
+                .map_err(|e| format!("League {}: {}", name, e))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    /// Create a world of two 4 team leagues drawing from an 8 team registry
+    fn two_league_world() -> World {
+        let mut world = World::new();
+        for i in 0..8 {
+            world.add_team(WorldTeam::new(&format!("Team {}", i), &format!("T{}", i)));
+        }
+        for (name, ids) in [("Premier", 0..4), ("Championship", 4..8)] {
+            world.add_league(name, League::new()).unwrap();
+            world.add_season_with_year(name, 2026).unwrap();
+            for id in ids {
+                world.add_league_team(name, id).unwrap();
+                let mut team = FootballTeam::new();
+                *team.name_mut() = String::from(world.team(id).unwrap().name());
+                world.add_season_team(name, id, team).unwrap();
+            }
+        }
+        world
+    }
+
+    #[test]
+    fn test_two_league_world_year() {
+        let mut world = two_league_world();
+        let year = *world.league("Premier").unwrap().current_season().as_ref().unwrap().year();
+
+        // Run a year in both leagues
+        let mut rng = SmallRng::seed_from_u64(1461);
+        world.generate_schedules(year, LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        world.sim_year(year, &mut rng).unwrap();
+        for league in world.leagues().values() {
+            assert!(league.current_season().as_ref().unwrap().regular_season_complete());
+        }
+
+        // The registry is unchanged and each team is active in one league
+        assert_eq!(world.teams().len(), 8);
+        for id in 0..8 {
+            let expected = if id < 4 { "Premier" } else { "Championship" };
+            assert_eq!(world.active_league(id, year), Some(expected));
+            assert_eq!(world.team(id).unwrap().name(), format!("Team {}", id));
+        }
+
+        // Running a year is deterministic under a seeded RNG
+        let mut scheduled = two_league_world();
+        scheduled.generate_schedules(year, LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        assert_deterministic!(scheduled, |w, rng| w.sim_year(year, &mut rng));
+        assert_deterministic!(two_league_world(), |w, rng| {
+            w.generate_schedules(year, LeagueSeasonScheduleOptions::new(), &mut rng)
+        });
+        let premier = scheduled.league("Premier").unwrap().clone();
+        assert_deterministic!(premier, |l, rng| l.sim(&mut rng));
+
+        // The whole world round trips through serialization
+        let json = serde_json::to_string(&world).unwrap();
+        let deserialized: World = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, world);
+    }
+
+    #[test]
+    fn test_world_rejects_team_in_two_leagues_per_year() {
+        let mut world = two_league_world();
+
+        // A team may belong to both leagues but play in only one per year
+        world.add_league_team("Championship", 0).unwrap();
+        assert!(world.add_season_team("Championship", 0, FootballTeam::new()).is_err());
+
+        // A league referencing a nonexistent team is rejected
+        let mut league = League::new();
+        league.add_team_with_id(100).unwrap();
+        assert!(world.add_league("Amateur", league).is_err());
+
+        // A league with a season overlapping another league is rejected
+        let premier = world.league("Premier").unwrap().clone();
+        assert!(world.add_league("Copy", premier).is_err());
+        assert!(world.league("Copy").is_none());
+
+        // Deserializing an inconsistent world fails
+        let mut raw = WorldRaw {
+            teams: world.teams().clone(),
+            leagues: world.leagues().clone()
+        };
+        raw.leagues.insert(String::from("Copy"), world.league("Premier").unwrap().clone());
+        let json = serde_json::to_string(&raw).unwrap();
+        assert!(serde_json::from_str::<World>(&json).is_err());
+    }
+}
@@ -1,6 +1,14 @@
 #![doc = include_str!("../README.md")]
+#[cfg(test)]
+#[macro_use]
+mod test_macros;
+
+#[cfg(feature = "binary")]
+pub mod binary;
+pub mod features;
 pub mod game;
 pub mod league;
+pub mod rng;
 pub mod team;
 
 #[cfg(feature = "wasm")]
@@ -0,0 +1,230 @@
+#![doc = include_str!("../docs/rng.md")]
+use std::cmp::Ordering;
+
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars;
+#[cfg(feature = "rocket_okapi")]
+use rocket_okapi::okapi::schemars::JsonSchema;
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+
+/// # `SeededRngRaw` struct
+///
+/// A `SeededRngRaw` is the serialized state of a `SeededRng`: the seed it was
+/// constructed from and the number of 32-bit words drawn from it since
+#[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize, Deserialize)]
+pub struct SeededRngRaw {
+    pub seed: u64,
+    /// Position in the generator's stream (default: the start of the stream)
+    #[serde(default)]
+    pub word_pos: u128
+}
+
+/// # `SeededRng` struct
+///
+/// A `SeededRng` is a deterministic random number generator constructed
+/// from a `u64` seed, whose state can be serialized to resume simulation
+/// exactly where it left off
+#[derive(Clone, Debug)]
+pub struct SeededRng {
+    seed: u64,
+    inner: ChaCha8Rng
+}
+
+impl From<SeededRngRaw> for SeededRng {
+    fn from(item: SeededRngRaw) -> Self {
+        let mut rng = SeededRng::from_seed(item.seed);
+        rng.inner.set_word_pos(item.word_pos);
+        rng
+    }
+}
+
+impl From<&SeededRng> for SeededRngRaw {
+    fn from(item: &SeededRng) -> Self {
+        SeededRngRaw {
+            seed: item.seed,
+            word_pos: item.inner.get_word_pos()
+        }
+    }
+}
+
+impl Serialize for SeededRng {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        SeededRngRaw::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SeededRng {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = SeededRngRaw::deserialize(deserializer)?;
+        Ok(SeededRng::from(raw))
+    }
+}
+
+#[cfg(feature = "rocket_okapi")]
+impl JsonSchema for SeededRng {
+    fn schema_name() -> String {
+        String::from("SeededRng")
+    }
+
+    fn json_schema(generator: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        SeededRngRaw::json_schema(generator)
+    }
+}
+
+impl PartialEq for SeededRng {
+    fn eq(&self, other: &Self) -> bool {
+        SeededRngRaw::from(self) == SeededRngRaw::from(other)
+    }
+}
+
+impl Eq for SeededRng {}
+
+impl PartialOrd for SeededRng {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SeededRng {
+    fn cmp(&self, other: &Self) -> Ordering {
+        SeededRngRaw::from(self).cmp(&SeededRngRaw::from(other))
+    }
+}
+
+impl SeededRng {
+    /// Construct a `SeededRng` from a seed, at the start of its stream
+    ///
+    /// ### Example
+    /// ```
+    /// use rand::Rng;
+    /// use fbsim_core::rng::SeededRng;
+    ///
+    /// let mut rng_a = SeededRng::from_seed(42);
+    /// let mut rng_b = SeededRng::from_seed(42);
+    /// assert_eq!(rng_a.gen::<u64>(), rng_b.gen::<u64>());
+    /// ```
+    pub fn from_seed(seed: u64) -> SeededRng {
+        SeededRng {
+            seed,
+            inner: ChaCha8Rng::seed_from_u64(seed)
+        }
+    }
+
+    /// Get the seed the `SeededRng` was constructed from
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::rng::SeededRng;
+    ///
+    /// let my_rng = SeededRng::from_seed(42);
+    /// assert_eq!(my_rng.seed(), 42);
+    /// ```
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Get the number of 32-bit words drawn from the `SeededRng` since it
+    /// was constructed
+    ///
+    /// ### Example
+    /// ```
+    /// use rand::Rng;
+    /// use fbsim_core::rng::SeededRng;
+    ///
+    /// let mut my_rng = SeededRng::from_seed(42);
+    /// let _ = my_rng.gen::<u64>();
+    /// assert_eq!(my_rng.word_pos(), 2);
+    /// ```
+    pub fn word_pos(&self) -> u128 {
+        self.inner.get_word_pos()
+    }
+}
+
+impl RngCore for SeededRng {
+    fn next_u32(&mut self) -> u32 {
+        self.inner.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.inner.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.inner.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.inner.try_fill_bytes(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+    use crate::league::League;
+    use crate::league::season::LeagueSeasonScheduleOptions;
+    use crate::team::FootballTeam;
+
+    #[test]
+    fn test_seeded_rng_resumes_from_saved_state() {
+        // The state round-trips mid-stream, including past a block boundary
+        let mut rng = SeededRng::from_seed(1503);
+        let _: Vec<u32> = (0..37).map(|_| rng.gen()).collect();
+        let mut resumed: SeededRng = serde_json::from_str(&serde_json::to_string(&rng).unwrap()).unwrap();
+        assert_eq!(resumed, rng);
+        let expected: Vec<u64> = (0..100).map(|_| rng.gen()).collect();
+        let actual: Vec<u64> = (0..100).map(|_| resumed.gen()).collect();
+        assert_eq!(actual, expected);
+
+        // Simulating a season twice from the same seed gives identical JSON
+        let new_league = || {
+            let mut league = League::new();
+            league.add_season_with_year(2026).unwrap();
+            for id in 0..6 {
+                league.add_team();
+                let team = FootballTeam::from_overalls("TM", "TM", 50 + 5 * id as u32, 60).unwrap();
+                league.add_season_team(id, team).unwrap();
+            }
+            league
+        };
+        let season_json = |seed: u64| {
+            let mut league = new_league();
+            let mut rng = SeededRng::from_seed(seed);
+            league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+            league.sim(&mut rng).unwrap();
+            serde_json::to_string(&league).unwrap()
+        };
+        assert_eq!(season_json(42), season_json(42));
+        assert_ne!(season_json(42), season_json(43));
+
+        // A league saved mid-season with its RNG resumes deterministically
+        let mut league = new_league();
+        let mut rng = SeededRng::from_seed(42);
+        league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+        league.sim_week(0, &mut rng).unwrap();
+        league.set_rng(Some(rng));
+        let saved = serde_json::to_string(&league).unwrap();
+
+        let mut uninterrupted = league.clone();
+        let mut rng = uninterrupted.take_rng().unwrap();
+        uninterrupted.sim(&mut rng).unwrap();
+        uninterrupted.set_rng(Some(rng));
+
+        let mut loaded: League = serde_json::from_str(&saved).unwrap();
+        assert_eq!(loaded, league);
+        let mut rng = loaded.take_rng().unwrap();
+        loaded.sim(&mut rng).unwrap();
+        loaded.set_rng(Some(rng));
+        assert_eq!(serde_json::to_string(&loaded).unwrap(), serde_json::to_string(&uninterrupted).unwrap());
+    }
+}
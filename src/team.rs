@@ -19,6 +19,13 @@ use crate::team::offense::FootballTeamOffense;
 
 pub const DEFAULT_TEAM_NAME: &str = "Null Island Defaults";
 pub const DEFAULT_TEAM_SHORT_NAME: &str = "NULL";
+pub const DEFAULT_STADIUM_CAPACITY: u32 = 65000;
+pub const MAX_STADIUM_CAPACITY: u32 = 250000;
+
+/// Provides the default stadium capacity for teams deserialized without one
+fn default_stadium_capacity() -> u32 {
+    DEFAULT_STADIUM_CAPACITY
+}
 
 /// # `FootballTeamRaw` struct
 ///
@@ -31,7 +38,9 @@ pub struct FootballTeamRaw {
     short_name: String,
     coach: FootballTeamCoach,
     defense: FootballTeamDefense,
-    offense: FootballTeamOffense
+    offense: FootballTeamOffense,
+    #[serde(default = "default_stadium_capacity")]
+    stadium_capacity: u32
 }
 
 impl FootballTeamRaw {
@@ -55,6 +64,17 @@ impl FootballTeamRaw {
                 )
             )
         }
+
+        // Ensure the stadium capacity is within range
+        if self.stadium_capacity > MAX_STADIUM_CAPACITY {
+            return Err(
+                format!(
+                    "Stadium capacity is greater than {}: {}",
+                    MAX_STADIUM_CAPACITY,
+                    self.stadium_capacity
+                )
+            )
+        }
         Ok(())
     }
 }
@@ -65,13 +85,14 @@ impl FootballTeamRaw {
 #[cfg_attr(feature = "rocket_okapi", derive(JsonSchema))]
 #[cfg_attr(feature = "wasm", derive(Tsify))]
 #[cfg_attr(feature = "wasm", tsify(into_wasm_abi, from_wasm_abi))]
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default, Serialize)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Serialize)]
 pub struct FootballTeam {
     name: String,
     short_name: String,
     coach: FootballTeamCoach,
     defense: FootballTeamDefense,
-    offense: FootballTeamOffense
+    offense: FootballTeamOffense,
+    stadium_capacity: u32
 }
 
 impl TryFrom<FootballTeamRaw> for FootballTeam {
@@ -91,7 +112,8 @@ impl TryFrom<FootballTeamRaw> for FootballTeam {
                 short_name: item.short_name,
                 coach: item.coach,
                 offense: item.offense,
-                defense: item.defense
+                defense: item.defense,
+                stadium_capacity: item.stadium_capacity
             }
         )
     }
@@ -197,25 +219,45 @@ impl ScoreSimulatable for FootballTeam {
     }
 }
 
-impl FootballTeam {
-    /// Constructor for the `FootballTeam` struct in which each
+impl Default for FootballTeam {
+    /// Default constructor for the `FootballTeam` struct in which each
     /// overall is defaulted to 50_i32, and the name is defaulted
     ///
     /// ### Example
     /// ```
     /// use fbsim_core::team::FootballTeam;
     ///
-    /// let my_team = FootballTeam::new();
+    /// let my_team = FootballTeam::default();
+    ///
+    /// // The default passes validation
+    /// let json = serde_json::to_string(&my_team).unwrap();
+    /// assert!(serde_json::from_str::<FootballTeam>(&json).is_ok());
     /// ```
-    pub fn new() -> FootballTeam {
+    fn default() -> Self {
         FootballTeam{
             name: String::from(DEFAULT_TEAM_NAME),
             short_name: String::from(DEFAULT_TEAM_SHORT_NAME),
             coach: FootballTeamCoach::new(),
             offense: FootballTeamOffense::new(),
-            defense: FootballTeamDefense::new()
+            defense: FootballTeamDefense::new(),
+            stadium_capacity: DEFAULT_STADIUM_CAPACITY
         }
     }
+}
+
+impl FootballTeam {
+    /// Constructor for the `FootballTeam` struct in which each
+    /// overall is defaulted to 50_i32, and the name is defaulted
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let my_team = FootballTeam::new();
+    /// ```
+    pub fn new() -> FootballTeam {
+        FootballTeam::default()
+    }
 
     /// Constructor for the `FootballTeam` struct in which an offense and defense
     /// are constructed given their overalls
@@ -235,7 +277,8 @@ impl FootballTeam {
                 short_name: String::from(short_name),
                 coach: FootballTeamCoach::new(),
                 offense,
-                defense
+                defense,
+                stadium_capacity: DEFAULT_STADIUM_CAPACITY
             }
         )
     }
@@ -261,7 +304,8 @@ impl FootballTeam {
             short_name: String::from(short_name),
             coach,
             offense,
-            defense
+            defense,
+            stadium_capacity: DEFAULT_STADIUM_CAPACITY
         }
     }
 
@@ -316,6 +360,76 @@ impl FootballTeam {
     pub fn short_name_mut(&mut self) -> &mut String {
         &mut self.short_name
     }
+
+    /// Borrow the football team's offense mutably
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::score::ScoreSimulatable;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let mut my_team = FootballTeam::new();
+    /// my_team.offense_mut().adjust(10);
+    /// assert!(my_team.offense_overall() == 60);
+    /// ```
+    pub fn offense_mut(&mut self) -> &mut FootballTeamOffense {
+        &mut self.offense
+    }
+
+    /// Borrow the football team's defense mutably
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::game::score::ScoreSimulatable;
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let mut my_team = FootballTeam::new();
+    /// my_team.defense_mut().adjust(-10);
+    /// assert!(my_team.defense_overall() == 40);
+    /// ```
+    pub fn defense_mut(&mut self) -> &mut FootballTeamDefense {
+        &mut self.defense
+    }
+
+    /// Get the capacity of the football team's home stadium
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::{FootballTeam, DEFAULT_STADIUM_CAPACITY};
+    ///
+    /// let my_team = FootballTeam::new();
+    /// assert_eq!(my_team.stadium_capacity(), DEFAULT_STADIUM_CAPACITY);
+    /// ```
+    pub fn stadium_capacity(&self) -> u32 {
+        self.stadium_capacity
+    }
+
+    /// Set the capacity of the football team's home stadium
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::FootballTeam;
+    ///
+    /// let mut my_team = FootballTeam::new();
+    /// assert!(my_team.set_stadium_capacity(80000).is_ok());
+    /// assert_eq!(my_team.stadium_capacity(), 80000);
+    ///
+    /// // Capacities beyond the maximum are rejected
+    /// assert!(my_team.set_stadium_capacity(1000000).is_err());
+    /// ```
+    pub fn set_stadium_capacity(&mut self, capacity: u32) -> Result<(), String> {
+        if capacity > MAX_STADIUM_CAPACITY {
+            return Err(
+                format!(
+                    "Stadium capacity is greater than {}: {}",
+                    MAX_STADIUM_CAPACITY,
+                    capacity
+                )
+            )
+        }
+        self.stadium_capacity = capacity;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -344,4 +458,17 @@ mod tests {
             expected_b
         );
     }
+
+    #[test]
+    fn test_football_team_stadium_capacity() {
+        // Teams without a stadium capacity receive the default
+        let mut value = serde_json::to_value(FootballTeam::new()).unwrap();
+        value.as_object_mut().unwrap().remove("stadium_capacity");
+        let team: FootballTeam = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(team.stadium_capacity(), DEFAULT_STADIUM_CAPACITY);
+
+        // Capacities beyond the maximum fail validation
+        value["stadium_capacity"] = serde_json::json!(MAX_STADIUM_CAPACITY + 1);
+        assert!(serde_json::from_value::<FootballTeam>(value).is_err());
+    }
 }
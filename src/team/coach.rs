@@ -16,7 +16,17 @@ use tsify_next::Tsify;
 pub struct FootballTeamCoachRaw {
     risk_taking: u32,
     run_pass: u32,
-    up_tempo: u32
+    up_tempo: u32,
+    /// How strongly the score and game clock shift the run-pass mix
+    /// (default: 50)
+    #[serde(default = "default_game_script")]
+    game_script: u32
+}
+
+/// The default game script attribute, for coaches serialized before it
+/// existed
+fn default_game_script() -> u32 {
+    50_u32
 }
 
 impl FootballTeamCoachRaw {
@@ -46,6 +56,14 @@ impl FootballTeamCoachRaw {
                 )
             )
         }
+        if self.game_script > 100 {
+            return Err(
+                format!(
+                    "Game script attribute is out of range [0, 100]: {}",
+                    self.game_script
+                )
+            )
+        }
         Ok(())
     }
 }
@@ -60,7 +78,8 @@ impl FootballTeamCoachRaw {
 pub struct FootballTeamCoach {
     risk_taking: u32,
     run_pass: u32,
-    up_tempo: u32
+    up_tempo: u32,
+    game_script: u32
 }
 
 impl TryFrom<FootballTeamCoachRaw> for FootballTeamCoach {
@@ -78,7 +97,8 @@ impl TryFrom<FootballTeamCoachRaw> for FootballTeamCoach {
             FootballTeamCoach{
                 risk_taking: item.risk_taking,
                 run_pass: item.run_pass,
-                up_tempo: item.up_tempo
+                up_tempo: item.up_tempo,
+                game_script: item.game_script
             }
         )
     }
@@ -103,12 +123,17 @@ impl Default for FootballTeamCoach {
     /// use fbsim_core::team::coach::FootballTeamCoach;
     /// 
     /// let my_coach_builder = FootballTeamCoach::default();
+    ///
+    /// // The default passes validation
+    /// let json = serde_json::to_string(&my_coach_builder).unwrap();
+    /// assert!(serde_json::from_str::<FootballTeamCoach>(&json).is_ok());
     /// ```
     fn default() -> Self {
         FootballTeamCoach{
             risk_taking: 50_u32,
             run_pass: 50_u32,
-            up_tempo: 50_u32
+            up_tempo: 50_u32,
+            game_script: 50_u32
         }
     }
 }
@@ -168,6 +193,20 @@ impl FootballTeamCoach {
     pub fn run_pass(&self) -> u32 {
         self.run_pass
     }
+
+    /// Get the coach's game script tendency
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::coach::FootballTeamCoach;
+    ///
+    /// let my_coach = FootballTeamCoach::new();
+    /// let game_script = my_coach.game_script();
+    /// assert!(game_script == 50_u32);
+    /// ```
+    pub fn game_script(&self) -> u32 {
+        self.game_script
+    }
 }
 
 /// # `FootballTeamCoachBuilder` struct
@@ -179,7 +218,8 @@ impl FootballTeamCoach {
 pub struct FootballTeamCoachBuilder {
     risk_taking: u32,
     run_pass: u32,
-    up_tempo: u32
+    up_tempo: u32,
+    game_script: u32
 }
 
 impl Default for FootballTeamCoachBuilder {
@@ -195,7 +235,8 @@ impl Default for FootballTeamCoachBuilder {
         FootballTeamCoachBuilder{
             risk_taking: 50_u32,
             run_pass: 50_u32,
-            up_tempo: 50_u32
+            up_tempo: 50_u32,
+            game_script: 50_u32
         }
     }
 }
@@ -264,6 +305,23 @@ impl FootballTeamCoachBuilder {
         self
     }
 
+    /// Set the game script property
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::coach::{FootballTeamCoach, FootballTeamCoachBuilder};
+    /// 
+    /// let my_coach = FootballTeamCoachBuilder::new()
+    ///     .game_script(60)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(my_coach.game_script() == 60);
+    /// ```
+    pub fn game_script(mut self, game_script: u32) -> Self {
+        self.game_script = game_script;
+        self
+    }
+
     /// Build the coach
     ///
     /// ### Example
@@ -281,7 +339,8 @@ impl FootballTeamCoachBuilder {
         let raw = FootballTeamCoachRaw{
             risk_taking: self.risk_taking,
             run_pass: self.run_pass,
-            up_tempo: self.up_tempo
+            up_tempo: self.up_tempo,
+            game_script: self.game_script
         };
         FootballTeamCoach::try_from(raw)
     }
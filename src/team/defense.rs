@@ -138,6 +138,10 @@ impl Default for FootballTeamDefense {
     /// use fbsim_core::team::defense::FootballTeamDefense;
     /// 
     /// let my_defense = FootballTeamDefense::default();
+    ///
+    /// // The default passes validation
+    /// let json = serde_json::to_string(&my_defense).unwrap();
+    /// assert!(serde_json::from_str::<FootballTeamDefense>(&json).is_ok());
     /// ```
     fn default() -> Self {
         FootballTeamDefense{
@@ -187,6 +191,70 @@ impl FootballTeamDefense {
         FootballTeamDefense::try_from(raw)
     }
 
+    /// Shift every skill level by the given delta, clamping each skill to
+    /// the range [0, 100]
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::defense::FootballTeamDefense;
+    ///
+    /// let mut my_defense = FootballTeamDefense::from_overall(95).unwrap();
+    /// my_defense.adjust(10);
+    /// assert!(my_defense.overall() == 100_u32);
+    /// my_defense.adjust(-30);
+    /// assert!(my_defense.overall() == 70_u32);
+    /// ```
+    pub fn adjust(&mut self, delta: i32) {
+        self.blitzing = (self.blitzing as i32 + delta).clamp(0, 100) as u32;
+        self.rush_defense = (self.rush_defense as i32 + delta).clamp(0, 100) as u32;
+        self.pass_defense = (self.pass_defense as i32 + delta).clamp(0, 100) as u32;
+        self.coverage = (self.coverage as i32 + delta).clamp(0, 100) as u32;
+        self.turnovers = (self.turnovers as i32 + delta).clamp(0, 100) as u32;
+        self.kick_returning = (self.kick_returning as i32 + delta).clamp(0, 100) as u32;
+    }
+
+    /// Get each skill level by name
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::defense::FootballTeamDefense;
+    ///
+    /// let my_defense = FootballTeamDefense::from_overall(70).unwrap();
+    /// let skills = my_defense.skills();
+    /// assert_eq!(skills.len(), 6);
+    /// assert_eq!(skills[0], ("blitzing", 70));
+    /// ```
+    pub fn skills(&self) -> Vec<(&'static str, u32)> {
+        vec![
+            ("blitzing", self.blitzing),
+            ("rush_defense", self.rush_defense),
+            ("pass_defense", self.pass_defense),
+            ("coverage", self.coverage),
+            ("turnovers", self.turnovers),
+            ("kick_returning", self.kick_returning),
+        ]
+    }
+
+    /// Replace each skill level with the result of the given function of
+    /// its name and current level, clamping each skill to at most 100
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::defense::FootballTeamDefense;
+    ///
+    /// let mut my_defense = FootballTeamDefense::from_overall(70).unwrap();
+    /// my_defense.rescale(|_, level| level / 2);
+    /// assert!(my_defense.overall() == 35_u32);
+    /// ```
+    pub fn rescale(&mut self, mut f: impl FnMut(&'static str, u32) -> u32) {
+        self.blitzing = f("blitzing", self.blitzing).min(100);
+        self.rush_defense = f("rush_defense", self.rush_defense).min(100);
+        self.pass_defense = f("pass_defense", self.pass_defense).min(100);
+        self.coverage = f("coverage", self.coverage).min(100);
+        self.turnovers = f("turnovers", self.turnovers).min(100);
+        self.kick_returning = f("kick_returning", self.kick_returning).min(100);
+    }
+
     /// Calculate the defense's overall rating
     ///
     /// ### Example
@@ -182,6 +182,10 @@ impl Default for FootballTeamOffense {
     /// use fbsim_core::team::offense::FootballTeamOffense;
     /// 
     /// let my_offense = FootballTeamOffense::default();
+    ///
+    /// // The default passes validation
+    /// let json = serde_json::to_string(&my_offense).unwrap();
+    /// assert!(serde_json::from_str::<FootballTeamOffense>(&json).is_ok());
     /// ```
     fn default() -> Self {
         FootballTeamOffense{
@@ -239,6 +243,82 @@ impl FootballTeamOffense {
         FootballTeamOffense::try_from(raw)
     }
 
+    /// Shift every skill level by the given delta, clamping each skill to
+    /// the range [0, 100]
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::offense::FootballTeamOffense;
+    ///
+    /// let mut my_offense = FootballTeamOffense::from_overall(95).unwrap();
+    /// my_offense.adjust(10);
+    /// assert!(my_offense.overall() == 100_u32);
+    /// my_offense.adjust(-30);
+    /// assert!(my_offense.overall() == 70_u32);
+    /// ```
+    pub fn adjust(&mut self, delta: i32) {
+        self.passing = (self.passing as i32 + delta).clamp(0, 100) as u32;
+        self.blocking = (self.blocking as i32 + delta).clamp(0, 100) as u32;
+        self.rushing = (self.rushing as i32 + delta).clamp(0, 100) as u32;
+        self.receiving = (self.receiving as i32 + delta).clamp(0, 100) as u32;
+        self.scrambling = (self.scrambling as i32 + delta).clamp(0, 100) as u32;
+        self.turnovers = (self.turnovers as i32 + delta).clamp(0, 100) as u32;
+        self.field_goals = (self.field_goals as i32 + delta).clamp(0, 100) as u32;
+        self.punting = (self.punting as i32 + delta).clamp(0, 100) as u32;
+        self.kickoffs = (self.kickoffs as i32 + delta).clamp(0, 100) as u32;
+        self.kick_return_defense = (self.kick_return_defense as i32 + delta).clamp(0, 100) as u32;
+    }
+
+    /// Get each skill level by name
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::offense::FootballTeamOffense;
+    ///
+    /// let my_offense = FootballTeamOffense::from_overall(70).unwrap();
+    /// let skills = my_offense.skills();
+    /// assert_eq!(skills.len(), 10);
+    /// assert_eq!(skills[0], ("passing", 70));
+    /// ```
+    pub fn skills(&self) -> Vec<(&'static str, u32)> {
+        vec![
+            ("passing", self.passing),
+            ("blocking", self.blocking),
+            ("rushing", self.rushing),
+            ("receiving", self.receiving),
+            ("scrambling", self.scrambling),
+            ("turnovers", self.turnovers),
+            ("field_goals", self.field_goals),
+            ("punting", self.punting),
+            ("kickoffs", self.kickoffs),
+            ("kick_return_defense", self.kick_return_defense),
+        ]
+    }
+
+    /// Replace each skill level with the result of the given function of
+    /// its name and current level, clamping each skill to at most 100
+    ///
+    /// ### Example
+    /// ```
+    /// use fbsim_core::team::offense::FootballTeamOffense;
+    ///
+    /// let mut my_offense = FootballTeamOffense::from_overall(70).unwrap();
+    /// my_offense.rescale(|_, level| level / 2);
+    /// assert!(my_offense.overall() == 35_u32);
+    /// ```
+    pub fn rescale(&mut self, mut f: impl FnMut(&'static str, u32) -> u32) {
+        self.passing = f("passing", self.passing).min(100);
+        self.blocking = f("blocking", self.blocking).min(100);
+        self.rushing = f("rushing", self.rushing).min(100);
+        self.receiving = f("receiving", self.receiving).min(100);
+        self.scrambling = f("scrambling", self.scrambling).min(100);
+        self.turnovers = f("turnovers", self.turnovers).min(100);
+        self.field_goals = f("field_goals", self.field_goals).min(100);
+        self.punting = f("punting", self.punting).min(100);
+        self.kickoffs = f("kickoffs", self.kickoffs).min(100);
+        self.kick_return_defense = f("kick_return_defense", self.kick_return_defense).min(100);
+    }
+
     /// Calculate the offense's overall rating
     ///
     /// ### Example
@@ -0,0 +1,28 @@
+//! Test helpers shared by the unit test modules
+
+/// Assert that a generation or simulation step is deterministic under a
+/// seeded RNG. Runs the step on two clones of the input, each with a
+/// freshly seeded `SmallRng`, and asserts the serialized results are equal.
+/// Every function taking an `&mut impl Rng` must pass this check, so any
+/// iteration over a hashed collection which feeds the RNG or the output
+/// order is caught here.
+///
+/// The body sees the clone as `$value` and the RNG as `$rng`, and must
+/// return a `Result` which is unwrapped.
+macro_rules! assert_deterministic {
+    ($input:expr, |$value:ident, $rng:ident| $body:expr) => {{
+        use rand::SeedableRng;
+
+        let run = || {
+            #[allow(unused_mut)]
+            let mut $value = $input.clone();
+            let mut $rng = rand::rngs::SmallRng::seed_from_u64(1462);
+            let output = $body.unwrap();
+            (
+                serde_json::to_string(&$value).unwrap(),
+                serde_json::to_string(&output).unwrap()
+            )
+        };
+        assert_eq!(run(), run(), "{} is not deterministic", stringify!($body));
+    }};
+}
@@ -9,6 +9,8 @@
 
 use wasm_bindgen::prelude::*;
 
+use crate::wasm::league_error;
+
 use crate::league::season::conference::{LeagueConference, LeagueDivision};
 
 /// A WASM-friendly wrapper around `LeagueDivision`.
@@ -52,7 +54,7 @@ impl WasmLeagueDivision {
     pub fn add_team(&mut self, team_id: usize) -> Result<(), JsError> {
         self.inner
             .add_team(team_id)
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 
     /// Returns the team IDs in this division.
@@ -146,7 +148,7 @@ impl WasmLeagueConference {
     pub fn add_division(&mut self, division: WasmLeagueDivision) -> Result<(), JsError> {
         self.inner
             .add_division(division.into_inner())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 
     /// Returns the divisions as a JSON array.
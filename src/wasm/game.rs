@@ -14,6 +14,7 @@ use crate::game::play::{Game as CoreGame, GameSimulator};
 use crate::wasm::play::{Drive, Play};
 use crate::wasm::rng::WasmRng;
 use crate::wasm::team::WasmFootballTeam;
+use crate::wasm::game_error;
 
 /// A WASM-friendly wrapper around the core `Game` type.
 ///
@@ -166,7 +167,7 @@ impl WasmGameSimulator {
                 &mut game.inner,
                 rng.inner_mut(),
             )
-            .map_err(|e| JsError::new(&e))
+            .map_err(game_error)
     }
 
     /// Simulates until the current drive is complete.
@@ -196,7 +197,7 @@ impl WasmGameSimulator {
                 &mut game.inner,
                 rng.inner_mut(),
             )
-            .map_err(|e| JsError::new(&e))
+            .map_err(game_error)
     }
 
     /// Simulates the rest of the game.
@@ -226,7 +227,48 @@ impl WasmGameSimulator {
                 &mut game.inner,
                 rng.inner_mut(),
             )
-            .map_err(|e| JsError::new(&e))
+            .map_err(game_error)
+    }
+
+    /// Simulates a new game play by play.
+    ///
+    /// Returns the final context, every play in order, the box score with
+    /// each team's points by period, and each team's offensive stats as a
+    /// JSON object.
+    ///
+    /// # Arguments
+    /// * `home` - The home team
+    /// * `away` - The away team
+    /// * `context` - The context to start the game from
+    /// * `rng` - The random number generator
+    #[wasm_bindgen(js_name = "simResult")]
+    pub fn sim_result(
+        &self,
+        home: &WasmFootballTeam,
+        away: &WasmFootballTeam,
+        context: GameContext,
+        rng: &mut WasmRng,
+    ) -> Result<JsValue, JsError> {
+        let result = self.inner
+            .sim_result(home.inner(), away.inner(), context, rng.inner_mut())
+            .map_err(game_error)?;
+        serde_wasm_bindgen::to_value(&result).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Creates a game simulator which fails a game once it has run for
+    /// more than `maxPlays` plays without ending.
+    #[wasm_bindgen(js_name = "withMaxPlays")]
+    pub fn with_max_plays(max_plays: usize) -> WasmGameSimulator {
+        WasmGameSimulator {
+            inner: GameSimulator::with_max_plays(max_plays),
+        }
+    }
+
+    /// Returns the number of plays after which a game which has not ended
+    /// fails.
+    #[wasm_bindgen(getter, js_name = "maxPlays")]
+    pub fn max_plays(&self) -> usize {
+        self.inner.max_plays()
     }
 }
 
@@ -244,3 +286,15 @@ impl Default for WasmGameSimulator {
 pub fn create_game_context() -> GameContext {
     GameContext::new()
 }
+
+/// Creates a `GameContext` at the opening kickoff between two teams, where
+/// a coin toss decides which team receives.
+#[wasm_bindgen(js_name = "createGameContextWithCoinToss")]
+pub fn create_game_context_with_coin_toss(
+    home_team_short: &str,
+    away_team_short: &str,
+    rng: &mut WasmRng,
+) -> Result<GameContext, JsError> {
+    GameContext::with_coin_toss(home_team_short, away_team_short, rng.inner_mut())
+        .map_err(|e| JsError::new(&e))
+}
@@ -9,7 +9,11 @@
 
 use wasm_bindgen::prelude::*;
 
+use crate::wasm::league_error;
+
 use crate::league::season::LeagueSeasonScheduleOptions;
+use crate::league::season::trade::LeagueSeasonTrade;
+use crate::league::tier::LeagueTierLink;
 use crate::league::League;
 use crate::wasm::conference::WasmLeagueConference;
 use crate::wasm::rng::WasmRng;
@@ -50,6 +54,82 @@ impl WasmLeague {
         Ok(WasmLeague { inner })
     }
 
+    /// Serializes the league to compact MessagePack bytes.
+    #[wasm_bindgen(js_name = "toBytes")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, JsError> {
+        self.inner.to_bytes().map_err(league_error)
+    }
+
+    /// Deserializes a league from the bytes produced by `toBytes`.
+    #[wasm_bindgen(js_name = "fromBytes")]
+    pub fn from_bytes(bytes: &[u8]) -> Result<WasmLeague, JsError> {
+        let inner = League::from_bytes(bytes).map_err(league_error)?;
+        Ok(WasmLeague { inner })
+    }
+
+    /// Serializes the league as JSON split into string chunks of at most
+    /// `maxChunkBytes` bytes, a manifest followed by checksummed data
+    /// chunks. Errors carry a stable code before the message.
+    #[wasm_bindgen(js_name = "saveChunks")]
+    pub fn save_chunks(&self, max_chunk_bytes: usize) -> Result<Vec<String>, JsError> {
+        self.inner
+            .save_chunks(max_chunk_bytes)
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Deserializes a league from the chunks produced by `saveChunks`,
+    /// given in any order. Errors carry a stable code, such as
+    /// `CHUNK_MISSING` or `CHUNK_CORRUPT`, before the message.
+    #[wasm_bindgen(js_name = "loadChunks")]
+    pub fn load_chunks(chunks: Vec<String>) -> Result<WasmLeague, JsError> {
+        let inner = League::load_chunks(&chunks).map_err(|e| JsError::new(&e.to_string()))?;
+        Ok(WasmLeague { inner })
+    }
+
+    /// Forks the league into an independent branch with its own seeded RNG.
+    pub fn fork(&self, label: &str, seed: u64) -> WasmLeague {
+        WasmLeague {
+            inner: self.inner.fork(label, seed),
+        }
+    }
+
+    /// Returns the lineage of a forked league as JSON, or `undefined` if the
+    /// league was never forked.
+    #[wasm_bindgen(getter)]
+    pub fn provenance(&self) -> Result<JsValue, JsError> {
+        match self.inner.provenance() {
+            Some(provenance) => serde_wasm_bindgen::to_value(provenance)
+                .map_err(|e| JsError::new(&e.to_string())),
+            None => Ok(JsValue::UNDEFINED),
+        }
+    }
+
+    /// Draws the next RNG from a forked league's seed, or `undefined` if the
+    /// league was never forked.
+    #[wasm_bindgen(js_name = "forkRng")]
+    pub fn fork_rng(&mut self) -> Option<WasmRng> {
+        self.inner.fork_rng().map(WasmRng::from_inner)
+    }
+
+    /// Returns a stable fingerprint of the league as a `bigint`, which
+    /// changes if and only if the league's serialized content changes.
+    pub fn fingerprint(&self) -> u64 {
+        self.inner.fingerprint()
+    }
+
+    /// Returns the broken invariants spanning the league's seasons, such as
+    /// duplicate season years or dangling team IDs, as a JSON array.
+    #[wasm_bindgen(js_name = "integrityCheck")]
+    pub fn integrity_check(&self) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(&self.inner.integrity_check()).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns the honors of each past season as a JSON object keyed by year.
+    #[wasm_bindgen(js_name = "honorsHistory")]
+    pub fn honors_history(&self) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(self.inner.honors_history()).map_err(|e| JsError::new(&e.to_string()))
+    }
+
     // ---------------------------------------------------------------
     // Team Management
     // ---------------------------------------------------------------
@@ -81,10 +161,26 @@ impl WasmLeague {
     // Season Management
     // ---------------------------------------------------------------
 
-    /// Creates a new season (archives the current one if complete).
+    /// Creates a new season (archives the current one if complete). The
+    /// season follows the most recent season's year unless a year is given,
+    /// and a league's first season defaults to the current year.
     #[wasm_bindgen(js_name = "addSeason")]
-    pub fn add_season(&mut self) -> Result<(), JsError> {
-        self.inner.add_season().map_err(|e| JsError::new(&e))
+    pub fn add_season(&mut self, year: Option<usize>) -> Result<(), JsError> {
+        let year = match year {
+            Some(year) => year,
+            None if self.inner.current_season().is_none() && self.inner.seasons().is_empty() => {
+                js_sys::Date::new_0().get_full_year() as usize
+            }
+            None => return self.inner.add_season().map_err(league_error),
+        };
+        self.inner.add_season_with_year(year).map_err(league_error)
+    }
+
+    /// Sets the year of the current season, failing if it has started or
+    /// the year is not later than every past season.
+    #[wasm_bindgen(js_name = "setCurrentSeasonYear")]
+    pub fn set_current_season_year(&mut self, year: usize) -> Result<(), JsError> {
+        self.inner.set_current_season_year(year).map_err(league_error)
     }
 
     /// Returns the current season as a JSON object, or `undefined` if none.
@@ -152,7 +248,27 @@ impl WasmLeague {
     ) -> Result<(), JsError> {
         self.inner
             .add_season_team(id, team.inner().clone())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
+    }
+
+    /// Imports a batch of teams from a JSON string of team definitions into
+    /// the current season, adding nothing if any team is invalid.
+    #[wasm_bindgen(js_name = "importTeams")]
+    pub fn import_teams(&mut self, json: &str) -> Result<(), JsError> {
+        self.inner
+            .import_teams(json.as_bytes())
+            .map_err(league_error)
+    }
+
+    /// Exports the current season's teams as a JSON string of team
+    /// definitions.
+    #[wasm_bindgen(js_name = "exportTeams")]
+    pub fn export_teams(&self) -> Result<String, JsError> {
+        let mut json: Vec<u8> = Vec::new();
+        self.inner
+            .export_teams(&mut json)
+            .map_err(league_error)?;
+        String::from_utf8(json).map_err(|e| JsError::new(&e.to_string()))
     }
 
     /// Adds a conference to the current season (takes ownership).
@@ -168,7 +284,7 @@ impl WasmLeague {
             .ok_or_else(|| JsError::new("No current season"))?;
         season
             .add_conference(conference.into_inner())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 
     // ---------------------------------------------------------------
@@ -184,7 +300,7 @@ impl WasmLeague {
     ) -> Result<(), JsError> {
         self.inner
             .generate_schedule(options, rng.inner_mut())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 
     /// Simulates the entire current season.
@@ -192,15 +308,19 @@ impl WasmLeague {
     pub fn sim(&mut self, rng: &mut WasmRng) -> Result<(), JsError> {
         self.inner
             .sim(rng.inner_mut())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 
-    /// Simulates a single week of the current season.
+    /// Simulates a single week of the current season, attempting every
+    /// matchup even if some fail. Returns the per-matchup outcomes as a JSON
+    /// object.
     #[wasm_bindgen(js_name = "simWeek")]
-    pub fn sim_week(&mut self, week: usize, rng: &mut WasmRng) -> Result<(), JsError> {
-        self.inner
+    pub fn sim_week(&mut self, week: usize, rng: &mut WasmRng) -> Result<JsValue, JsError> {
+        let report = self
+            .inner
             .sim_week(week, rng.inner_mut())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&report).map_err(|e| JsError::new(&e.to_string()))
     }
 
     /// Simulates a single matchup of the current season. Returns game log as JSON.
@@ -214,7 +334,7 @@ impl WasmLeague {
         let game = self
             .inner
             .sim_matchup(week, matchup, rng.inner_mut())
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
         serde_wasm_bindgen::to_value(&game).map_err(|e| JsError::new(&e.to_string()))
     }
 
@@ -230,7 +350,7 @@ impl WasmLeague {
         let result = self
             .inner
             .sim_play(week, matchup, rng.inner_mut())
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
         match result {
             Some(game) => {
                 serde_wasm_bindgen::to_value(&game).map_err(|e| JsError::new(&e.to_string()))
@@ -239,6 +359,35 @@ impl WasmLeague {
         }
     }
 
+    /// Executes a trade in the season with the given year, before its next
+    /// week to be played.
+    #[wasm_bindgen(js_name = "executeTrade")]
+    pub fn execute_trade(&mut self, year: usize, trade: LeagueSeasonTrade) -> Result<(), JsError> {
+        self.inner
+            .execute_trade(year, trade)
+            .map_err(league_error)
+    }
+
+    /// Exchanges teams with a lower tier league once the current seasons
+    /// of both are complete.
+    #[wasm_bindgen(js_name = "applyPromotionRelegation")]
+    pub fn apply_promotion_relegation(
+        &mut self,
+        lower: &mut WasmLeague,
+        link: LeagueTierLink,
+        rng: &mut WasmRng,
+    ) -> Result<(), JsError> {
+        self.inner
+            .apply_promotion_relegation(&mut lower.inner, link, rng.inner_mut())
+            .map_err(league_error)
+    }
+
+    /// Returns the teams exchanged with other tiers.
+    #[wasm_bindgen(js_name = "tierExchanges")]
+    pub fn tier_exchanges(&self) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(self.inner.tier_exchanges()).map_err(|e| JsError::new(&e.to_string()))
+    }
+
     // ---------------------------------------------------------------
     // Cross-Season Queries
     // ---------------------------------------------------------------
@@ -249,10 +398,72 @@ impl WasmLeague {
         let matchups = self
             .inner
             .team_matchups(id)
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
         serde_wasm_bindgen::to_value(&matchups).map_err(|e| JsError::new(&e.to_string()))
     }
 
+    /// Returns a team's season-by-season history, ordered by year, as a
+    /// JSON array.
+    #[wasm_bindgen(js_name = "franchiseTimeline")]
+    pub fn franchise_timeline(&self, id: usize) -> Result<JsValue, JsError> {
+        let timeline = self
+            .inner
+            .franchise_timeline(id)
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&timeline).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns a team's cumulative regular season record across every
+    /// complete season as JSON.
+    #[wasm_bindgen(js_name = "allTimeRecord")]
+    pub fn all_time_record(&self, id: usize) -> Result<JsValue, JsError> {
+        let record = self
+            .inner
+            .all_time_record(id)
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&record).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns the number of championships a team has won.
+    pub fn championships(&self, id: usize) -> Result<usize, JsError> {
+        self.inner
+            .championships(id)
+            .map_err(league_error)
+    }
+
+    /// Returns every completed meeting between two teams across all
+    /// seasons as JSON.
+    #[wasm_bindgen(js_name = "headToHead")]
+    pub fn head_to_head(&self, team: usize, opponent: usize) -> Result<JsValue, JsError> {
+        let series = self
+            .inner
+            .head_to_head(team, opponent)
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&series).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns a team's finish in each season it played, ordered by year,
+    /// as a JSON array.
+    #[wasm_bindgen(js_name = "seasonResults")]
+    pub fn season_results(&self, id: usize) -> Result<JsValue, JsError> {
+        let results = self
+            .inner
+            .season_results(id)
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&results).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns a summary of the complete seasons between two years,
+    /// inclusive, as JSON.
+    #[wasm_bindgen(js_name = "eraSummary")]
+    pub fn era_summary(&self, start_year: usize, end_year: usize) -> Result<JsValue, JsError> {
+        let summary = self
+            .inner
+            .era_summary(start_year, end_year)
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&summary).map_err(|e| JsError::new(&e.to_string()))
+    }
+
     /// Returns all matchups for a team in a specific season as JSON.
     #[wasm_bindgen(js_name = "teamSeasonMatchups")]
     pub fn team_season_matchups(
@@ -263,7 +474,7 @@ impl WasmLeague {
         let matchups = self
             .inner
             .team_season_matchups(id, year)
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
         serde_wasm_bindgen::to_value(&matchups).map_err(|e| JsError::new(&e.to_string()))
     }
 
@@ -273,7 +484,7 @@ impl WasmLeague {
         let record = self
             .inner
             .team_playoff_record(id)
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
         serde_wasm_bindgen::to_value(&record).map_err(|e| JsError::new(&e.to_string()))
     }
 
@@ -282,7 +493,7 @@ impl WasmLeague {
     pub fn team_championship_appearances(&self, id: usize) -> Result<usize, JsError> {
         self.inner
             .team_championship_appearances(id)
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 
     /// Returns a team's total championship wins.
@@ -290,7 +501,7 @@ impl WasmLeague {
     pub fn team_championship_wins(&self, id: usize) -> Result<usize, JsError> {
         self.inner
             .team_championship_wins(id)
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 }
 
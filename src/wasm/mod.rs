@@ -25,6 +25,9 @@ pub use team::*;
 
 use wasm_bindgen::prelude::*;
 
+use crate::game::error::GameError;
+use crate::league::error::LeagueError;
+
 /// Initialize the WASM module with better error handling.
 /// This is called automatically when the module is loaded.
 #[wasm_bindgen(start)]
@@ -37,3 +40,16 @@ pub fn init() {
 pub fn get_version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
+
+/// Convert a `LeagueError` into a `JsError` whose message carries the
+/// error's stable code, such as `LEAGUE_TEAM_NOT_FOUND`, before its
+/// description.
+pub(crate) fn league_error(error: LeagueError) -> JsError {
+    JsError::new(&format!("{}: {}", error.code(), error))
+}
+
+/// Convert a `GameError` into a `JsError` whose message carries the
+/// error's stable code, such as `GAME_ALREADY_OVER`, before its description.
+pub(crate) fn game_error(error: GameError) -> JsError {
+    JsError::new(&format!("{}: {}", error.code(), error))
+}
@@ -79,4 +79,9 @@ impl WasmRng {
     pub fn inner_mut(&mut self) -> &mut SmallRng {
         &mut self.inner
     }
+
+    /// Wraps an existing RNG.
+    pub fn from_inner(inner: SmallRng) -> Self {
+        WasmRng { inner }
+    }
 }
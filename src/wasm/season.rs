@@ -7,15 +7,48 @@
 //! Feature-gated behind the `wasm` Cargo feature. Compiled to WebAssembly
 //! via `wasm-pack`.
 
+use serde::Serialize;
+use tsify_next::Tsify;
 use wasm_bindgen::prelude::*;
 
+use crate::wasm::league_error;
+
+use crate::game::context::weather::WeatherDistribution;
+use crate::game::score::ScoreBounds;
+use crate::league::matchup::LeagueTeamRecord;
+use crate::league::season::annotation::AnnotationTarget;
+use crate::league::season::attendance::AttendanceModel;
+use crate::league::season::dashboard::SeasonDashboardOptions;
+use crate::league::season::history::{StandingsHistory, StandingsHistoryOptions};
+use crate::league::season::honors::SeasonHonors;
+use crate::league::season::playoffs::{LeagueSeasonPlayoffs, PlayoffBracketTree, PlayoffEntry};
+use crate::league::season::playoffs::picture::PlayoffPicture;
+use crate::league::season::prediction::PredictedResult;
+use crate::league::season::proposal::ScheduleProposal;
+use crate::league::season::report::SeasonReportOptions;
+use crate::league::season::scoreboard::{format_playoff_scoreboard, format_scoreboard};
+use crate::league::season::trade::LeagueSeasonTrade;
+use crate::league::season::week::{WeekSimOptions, WeekSimReport};
 use crate::league::season::{
-    LeagueSeason, LeagueSeasonPlayoffOptions, LeagueSeasonScheduleOptions,
+    ChampionshipMode, LeagueSeason, LeagueSeasonPlayoffOptions, LeagueSeasonScheduleOptions,
 };
 use crate::wasm::conference::WasmLeagueConference;
 use crate::wasm::rng::WasmRng;
 use crate::wasm::team::WasmFootballTeam;
 
+/// The progress of a regular season simulated in chunks.
+///
+/// Includes the report of each week simulated by the call, the index of the
+/// first incomplete week, or null once the regular season is complete, and
+/// the number of weeks in the regular season.
+#[derive(Clone, Debug, Serialize, Tsify)]
+#[tsify(into_wasm_abi)]
+pub struct SeasonSimProgress {
+    pub reports: Vec<WeekSimReport>,
+    pub next_week: Option<usize>,
+    pub total_weeks: usize,
+}
+
 /// A WASM-friendly wrapper around `LeagueSeason`.
 #[wasm_bindgen(js_name = "LeagueSeason")]
 pub struct WasmLeagueSeason {
@@ -28,11 +61,13 @@ impl WasmLeagueSeason {
     // Construction & Properties
     // ---------------------------------------------------------------
 
-    /// Creates a new empty season (defaults to the current year).
+    /// Creates a new empty season in the given year, defaulting to the
+    /// current year.
     #[wasm_bindgen(constructor)]
-    pub fn new() -> WasmLeagueSeason {
+    pub fn new(year: Option<usize>) -> WasmLeagueSeason {
+        let year = year.unwrap_or_else(|| js_sys::Date::new_0().get_full_year() as usize);
         WasmLeagueSeason {
-            inner: LeagueSeason::new(),
+            inner: LeagueSeason::with_year(year),
         }
     }
 
@@ -42,10 +77,10 @@ impl WasmLeagueSeason {
         *self.inner.year()
     }
 
-    /// Sets the season year.
+    /// Sets the season year, failing if the season has started.
     #[wasm_bindgen(setter)]
-    pub fn set_year(&mut self, year: usize) {
-        *self.inner.year_mut() = year;
+    pub fn set_year(&mut self, year: usize) -> Result<(), JsError> {
+        self.inner.set_year(year).map_err(league_error)
     }
 
     /// Returns true if the season has started (any game played).
@@ -66,6 +101,93 @@ impl WasmLeagueSeason {
         self.inner.complete()
     }
 
+    /// Gets how the season champion is decided.
+    #[wasm_bindgen(getter, js_name = "championshipMode")]
+    pub fn championship_mode(&self) -> ChampionshipMode {
+        *self.inner.championship_mode()
+    }
+
+    /// Sets how the season champion is decided.
+    #[wasm_bindgen(js_name = "setChampionshipMode")]
+    pub fn set_championship_mode(&mut self, mode: ChampionshipMode) -> Result<(), JsError> {
+        self.inner
+            .set_championship_mode(mode)
+            .map_err(league_error)
+    }
+
+    /// Gets the week before which trades must be applied, if any.
+    #[wasm_bindgen(getter, js_name = "tradeDeadline")]
+    pub fn trade_deadline(&self) -> Option<usize> {
+        self.inner.trade_deadline()
+    }
+
+    /// Sets the week before which trades must be applied.
+    #[wasm_bindgen(js_name = "setTradeDeadline")]
+    pub fn set_trade_deadline(&mut self, deadline: Option<usize>) -> Result<(), JsError> {
+        self.inner
+            .set_trade_deadline(deadline)
+            .map_err(league_error)
+    }
+
+    /// Applies a trade before the given week.
+    #[wasm_bindgen(js_name = "applyTrade")]
+    pub fn apply_trade(&mut self, week: usize, trade: LeagueSeasonTrade) -> Result<(), JsError> {
+        self.inner
+            .apply_trade(week, trade)
+            .map_err(league_error)
+    }
+
+    /// Returns the trade ledger as a JSON array.
+    #[wasm_bindgen(getter)]
+    pub fn trades(&self) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(self.inner.trades())
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns the model used to record per-game attendance, or null.
+    #[wasm_bindgen(getter, js_name = "attendanceModel")]
+    pub fn attendance_model(&self) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(self.inner.attendance_model())
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Sets the model used to record per-game attendance.
+    #[wasm_bindgen(js_name = "setAttendanceModel")]
+    pub fn set_attendance_model(&mut self, model: AttendanceModel) -> Result<(), JsError> {
+        self.inner
+            .set_attendance_model(Some(model))
+            .map_err(league_error)
+    }
+
+    /// Returns the distribution each game's weather is drawn from, or null.
+    #[wasm_bindgen(getter, js_name = "weatherDistribution")]
+    pub fn weather_distribution(&self) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(self.inner.weather_distribution())
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Sets the distribution each game's weather is drawn from.
+    #[wasm_bindgen(js_name = "setWeatherDistribution")]
+    pub fn set_weather_distribution(&mut self, distribution: WeatherDistribution) -> Result<(), JsError> {
+        self.inner
+            .set_weather_distribution(Some(distribution))
+            .map_err(league_error)
+    }
+
+    /// Stops recording per-game attendance.
+    #[wasm_bindgen(js_name = "clearAttendanceModel")]
+    pub fn clear_attendance_model(&mut self) -> Result<(), JsError> {
+        self.inner
+            .set_attendance_model(None)
+            .map_err(league_error)
+    }
+
+    /// Returns the season champion's team ID, if decided.
+    #[wasm_bindgen(getter)]
+    pub fn champion(&self) -> Option<usize> {
+        self.inner.champion()
+    }
+
     // ---------------------------------------------------------------
     // Team Management
     // ---------------------------------------------------------------
@@ -75,7 +197,26 @@ impl WasmLeagueSeason {
     pub fn add_team(&mut self, id: usize, team: &WasmFootballTeam) -> Result<(), JsError> {
         self.inner
             .add_team(id, team.inner().clone())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
+    }
+
+    /// Imports a batch of teams from a JSON string of team definitions,
+    /// adding nothing if any team is invalid.
+    #[wasm_bindgen(js_name = "importTeams")]
+    pub fn import_teams(&mut self, json: &str) -> Result<(), JsError> {
+        self.inner
+            .import_teams(json.as_bytes())
+            .map_err(league_error)
+    }
+
+    /// Exports the season's teams as a JSON string of team definitions.
+    #[wasm_bindgen(js_name = "exportTeams")]
+    pub fn export_teams(&self) -> Result<String, JsError> {
+        let mut json: Vec<u8> = Vec::new();
+        self.inner
+            .export_teams(&mut json)
+            .map_err(league_error)?;
+        String::from_utf8(json).map_err(|e| JsError::new(&e.to_string()))
     }
 
     /// Checks if a team exists in the season.
@@ -103,7 +244,7 @@ impl WasmLeagueSeason {
     ) -> Result<(), JsError> {
         self.inner
             .add_conference(conference.into_inner())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 
     /// Returns the conferences as a JSON array.
@@ -129,7 +270,202 @@ impl WasmLeagueSeason {
     ) -> Result<(), JsError> {
         self.inner
             .generate_schedule(options, rng.inner_mut())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
+    }
+
+    /// Generates a schedule without modifying the season. Returns the
+    /// proposed schedule and its balance report as a JSON object, which can
+    /// be passed back to `applySchedule`.
+    #[wasm_bindgen(js_name = "previewSchedule")]
+    pub fn preview_schedule(
+        &self,
+        options: LeagueSeasonScheduleOptions,
+        rng: &mut WasmRng,
+    ) -> Result<JsValue, JsError> {
+        let proposal = self
+            .inner
+            .preview_schedule(options, rng.inner_mut())
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&proposal).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Installs a schedule returned by `previewSchedule`, failing if the
+    /// season's teams or conferences have changed since the preview.
+    #[wasm_bindgen(js_name = "applySchedule")]
+    pub fn apply_schedule(&mut self, proposal: JsValue) -> Result<(), JsError> {
+        let proposal: ScheduleProposal =
+            serde_wasm_bindgen::from_value(proposal).map_err(|e| JsError::new(&e.to_string()))?;
+        self.inner
+            .apply_schedule(proposal)
+            .map_err(league_error)
+    }
+
+    /// Returns each team's home and away games and meetings with each
+    /// opponent as a JSON object.
+    #[wasm_bindgen(js_name = "scheduleBalance")]
+    pub fn schedule_balance(&self) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(&self.inner.schedule_balance())
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns the completed regular season series between two teams, from
+    /// the first team's point of view, as a JSON object.
+    #[wasm_bindgen(js_name = "headToHead")]
+    pub fn head_to_head(&self, team: usize, opponent: usize) -> Result<JsValue, JsError> {
+        let series = self.inner.head_to_head(team, opponent).map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&series).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns which of two teams ranks ahead in the standings and the
+    /// tiebreaker rule which decided it, as a JSON object.
+    #[wasm_bindgen(js_name = "tiebreakerExplanation")]
+    pub fn tiebreaker_explanation(&self, team_a: usize, team_b: usize) -> Result<JsValue, JsError> {
+        let explanation = self.inner.tiebreaker_explanation(team_a, team_b).map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&explanation).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Swaps the home and away teams of an unplayed regular season matchup.
+    #[wasm_bindgen(js_name = "swapHomeAway")]
+    pub fn swap_home_away(&mut self, week: usize, matchup: usize) -> Result<(), JsError> {
+        self.inner
+            .swap_home_away(week, matchup)
+            .map_err(league_error)
+    }
+
+    /// Swaps unplayed matchups to even out each team's home and away games.
+    /// Returns the swaps made and the balance before and after as a JSON
+    /// object.
+    #[wasm_bindgen(js_name = "rebalanceHomeAway")]
+    pub fn rebalance_home_away(&mut self) -> Result<JsValue, JsError> {
+        let rebalance = self
+            .inner
+            .rebalance_home_away()
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&rebalance).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns the venue swap ledger as a JSON array.
+    #[wasm_bindgen(getter, js_name = "venueSwaps")]
+    pub fn venue_swaps(&self) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(self.inner.venue_swaps())
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Whether completed matchups must be reopened before their scores can
+    /// be changed.
+    #[wasm_bindgen(getter)]
+    pub fn strict(&self) -> bool {
+        self.inner.strict()
+    }
+
+    /// Turns strict mode on or off.
+    #[wasm_bindgen(setter)]
+    pub fn set_strict(&mut self, strict: bool) {
+        self.inner.set_strict(strict);
+    }
+
+    /// The seed from which each regular season game's RNG is derived, or
+    /// `undefined` if games use the caller's RNG.
+    #[wasm_bindgen(getter, js_name = "simSeed")]
+    pub fn sim_seed(&self) -> Option<u64> {
+        self.inner.sim_seed()
+    }
+
+    /// Sets or clears the seed from which each regular season game's RNG is
+    /// derived.
+    #[wasm_bindgen(setter, js_name = "simSeed")]
+    pub fn set_sim_seed(&mut self, sim_seed: Option<u64>) {
+        self.inner.set_sim_seed(sim_seed);
+    }
+
+    /// Returns the seed of a regular season game's RNG.
+    #[wasm_bindgen(js_name = "matchupSeed")]
+    pub fn matchup_seed(&self, week: usize, matchup: usize) -> Result<u64, JsError> {
+        self.inner
+            .matchup_seed(week, matchup)
+            .map_err(league_error)
+    }
+
+    /// Replays a seeded regular season game from kickoff without modifying
+    /// the season. Returns the game log and final context as a JSON array.
+    #[wasm_bindgen(js_name = "resimulateMatchupReplay")]
+    pub fn resimulate_matchup_replay(&self, week: usize, matchup: usize) -> Result<JsValue, JsError> {
+        let replay = self
+            .inner
+            .resimulate_matchup_replay(week, matchup)
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&replay).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Enters the final score of a regular season matchup by hand.
+    #[wasm_bindgen(js_name = "setScore")]
+    pub fn set_score(&mut self, week: usize, matchup: usize, home_score: u32, away_score: u32) -> Result<(), JsError> {
+        self.inner
+            .set_score(week, matchup, home_score, away_score)
+            .map_err(league_error)
+    }
+
+    /// Clears the result of a completed regular season matchup.
+    #[wasm_bindgen(js_name = "reopenMatchup")]
+    pub fn reopen_matchup(&mut self, week: usize, matchup: usize) -> Result<(), JsError> {
+        self.inner
+            .reopen_matchup(week, matchup)
+            .map_err(league_error)
+    }
+
+    /// Corrects the final score of a completed regular season matchup.
+    #[wasm_bindgen(js_name = "overrideResult")]
+    pub fn override_result(&mut self, week: usize, matchup: usize, home_score: u32, away_score: u32) -> Result<(), JsError> {
+        self.inner
+            .override_result(week, matchup, home_score, away_score)
+            .map_err(league_error)
+    }
+
+    /// Clears the result of a completed regular season matchup so it can be
+    /// simulated again, as long as no later week has started.
+    #[wasm_bindgen(js_name = "invalidateResult")]
+    pub fn invalidate_result(&mut self, week: usize, matchup: usize) -> Result<(), JsError> {
+        self.inner
+            .invalidate_result(week, matchup)
+            .map_err(league_error)
+    }
+
+    /// Returns the result edit ledger as a JSON array.
+    #[wasm_bindgen(getter, js_name = "resultEdits")]
+    pub fn result_edits(&self) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(self.inner.result_edits())
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns the notes attached to parts of the season as a JSON array.
+    #[wasm_bindgen(getter)]
+    pub fn annotations(&self) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(self.inner.annotations())
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Attaches a note to part of the season, with an optional timestamp in
+    /// seconds since the Unix epoch.
+    pub fn annotate(&mut self, target: AnnotationTarget, text: &str, timestamp: Option<u64>) -> Result<(), JsError> {
+        self.inner
+            .annotate(target, text, timestamp)
+            .map_err(league_error)
+    }
+
+    /// Returns the notes attached to part of the season as a JSON array.
+    #[wasm_bindgen(js_name = "annotationsFor")]
+    pub fn annotations_for(&self, target: AnnotationTarget) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(&self.inner.annotations_for(&target))
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Validates schedule options against the season's teams without
+    /// generating a schedule.
+    #[wasm_bindgen(js_name = "validateScheduleOptions")]
+    pub fn validate_schedule_options(&self, options: LeagueSeasonScheduleOptions) -> Result<(), JsError> {
+        options
+            .validate(self.inner.teams().len())
+            .map_err(league_error)
     }
 
     // ---------------------------------------------------------------
@@ -141,7 +477,7 @@ impl WasmLeagueSeason {
     pub fn sim(&mut self, rng: &mut WasmRng) -> Result<(), JsError> {
         self.inner
             .sim(rng.inner_mut())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 
     /// Simulates all remaining regular season weeks.
@@ -149,15 +485,72 @@ impl WasmLeagueSeason {
     pub fn sim_regular_season(&mut self, rng: &mut WasmRng) -> Result<(), JsError> {
         self.inner
             .sim_regular_season(rng.inner_mut())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 
-    /// Simulates all matchups in a single week.
+    /// Simulates the regular season weeks from `from` up to but not
+    /// including `to`, skipping completed weeks. Every week is either
+    /// complete or untouched when this returns.
+    ///
+    /// This is the chunking API for long simulations: a host keeping the
+    /// page responsive simulates a few weeks per call, updates its progress
+    /// from the returned `next_week` and `total_weeks`, and awaits a macrotask
+    /// such as `setTimeout` before the next call so the browser can repaint.
+    /// Stopping between calls leaves the season consistent.
+    #[wasm_bindgen(js_name = "simWeeks")]
+    pub fn sim_weeks(&mut self, from: usize, to: usize, rng: &mut WasmRng) -> Result<SeasonSimProgress, JsError> {
+        let reports = self
+            .inner
+            .sim_weeks(from, to, rng.inner_mut())
+            .map_err(league_error)?;
+        Ok(self.sim_progress(reports))
+    }
+
+    /// Simulates all matchups in a single week, attempting every matchup
+    /// even if some fail. Returns the per-matchup outcomes as a JSON object.
     #[wasm_bindgen(js_name = "simWeek")]
-    pub fn sim_week(&mut self, week: usize, rng: &mut WasmRng) -> Result<(), JsError> {
-        self.inner
+    pub fn sim_week(&mut self, week: usize, rng: &mut WasmRng) -> Result<JsValue, JsError> {
+        let report = self
+            .inner
             .sim_week(week, rng.inner_mut())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&report).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Simulates all matchups in a single week with the given options.
+    /// Returns the per-matchup outcomes, and the play-by-play log of each
+    /// completed matchup if requested, as a JSON object.
+    #[wasm_bindgen(js_name = "simWeekWithOptions")]
+    pub fn sim_week_with_options(&mut self, week: usize, options: WeekSimOptions, rng: &mut WasmRng) -> Result<JsValue, JsError> {
+        let report = self
+            .inner
+            .sim_week_with_options(week, options, rng.inner_mut())
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&report).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Predicts the scores of a week's unplayed matchups without modifying
+    /// the season. Returns the predictions as a JSON array.
+    #[wasm_bindgen(js_name = "predictWeek")]
+    pub fn predict_week(&self, week: usize, force: bool, rng: &mut WasmRng) -> Result<JsValue, JsError> {
+        let predictions = self
+            .inner
+            .predict_week(week, force, rng.inner_mut())
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&predictions).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Scores a JSON array of predictions against a completed week. Returns
+    /// the accuracy as a JSON object.
+    #[wasm_bindgen(js_name = "scorePredictions")]
+    pub fn score_predictions(&self, week: usize, predictions: JsValue) -> Result<JsValue, JsError> {
+        let predictions: Vec<PredictedResult> =
+            serde_wasm_bindgen::from_value(predictions).map_err(|e| JsError::new(&e.to_string()))?;
+        let accuracy = self
+            .inner
+            .score_predictions(week, &predictions)
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&accuracy).map_err(|e| JsError::new(&e.to_string()))
     }
 
     /// Simulates a single matchup to completion. Returns the game log as JSON.
@@ -171,10 +564,27 @@ impl WasmLeagueSeason {
         let game = self
             .inner
             .sim_matchup(week, matchup, rng.inner_mut())
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
         serde_wasm_bindgen::to_value(&game).map_err(|e| JsError::new(&e.to_string()))
     }
 
+    /// Simulates the given matchups of a week in order. Returns an array
+    /// of game logs as JSON, with `null` for skipped complete matchups.
+    #[wasm_bindgen(js_name = "simMatchups")]
+    pub fn sim_matchups(
+        &mut self,
+        week: usize,
+        matchups: Vec<usize>,
+        skip_complete: bool,
+        rng: &mut WasmRng,
+    ) -> Result<JsValue, JsError> {
+        let games = self
+            .inner
+            .sim_matchups(week, &matchups, skip_complete, rng.inner_mut())
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&games).map_err(|e| JsError::new(&e.to_string()))
+    }
+
     /// Simulates a single play of a matchup. Returns the game log as JSON
     /// if the game finished on this play, or `undefined` if still in progress.
     #[wasm_bindgen(js_name = "simPlay")]
@@ -187,7 +597,7 @@ impl WasmLeagueSeason {
         let result = self
             .inner
             .sim_play(week, matchup, rng.inner_mut())
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
         match result {
             Some(game) => {
                 serde_wasm_bindgen::to_value(&game).map_err(|e| JsError::new(&e.to_string()))
@@ -211,7 +621,37 @@ impl WasmLeagueSeason {
     ) -> Result<(), JsError> {
         self.inner
             .generate_playoffs(options, rng.inner_mut())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
+    }
+
+    /// Adds a JSON array of `PlayoffEntry` objects to the playoffs. If any
+    /// entry is invalid, no team is added.
+    #[wasm_bindgen(js_name = "addPlayoffTeams")]
+    pub fn add_playoff_teams(&mut self, entries: JsValue) -> Result<(), JsError> {
+        let entries: Vec<PlayoffEntry> =
+            serde_wasm_bindgen::from_value(entries).map_err(|e| JsError::new(&e.to_string()))?;
+        self.inner
+            .playoffs_mut()
+            .add_teams(entries)
+            .map_err(league_error)
+    }
+
+    /// Previews the playoff bracket that would be generated from the
+    /// current standings, without modifying the season.
+    #[wasm_bindgen(js_name = "previewPlayoffs")]
+    pub fn preview_playoffs(&self, options: LeagueSeasonPlayoffOptions) -> Result<JsValue, JsError> {
+        let view = LeagueSeasonPlayoffs::preview_from_standings(&self.inner, options)
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&view)
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Generates a title playoff between the teams tied for the best record.
+    #[wasm_bindgen(js_name = "generateTitlePlayoff")]
+    pub fn generate_title_playoff(&mut self, rng: &mut WasmRng) -> Result<(), JsError> {
+        self.inner
+            .generate_title_playoff(rng.inner_mut())
+            .map_err(league_error)
     }
 
     /// Generates the next playoff round (used for multi-round brackets).
@@ -219,7 +659,7 @@ impl WasmLeagueSeason {
     pub fn generate_next_playoff_round(&mut self, rng: &mut WasmRng) -> Result<(), JsError> {
         self.inner
             .generate_next_playoff_round(rng.inner_mut())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 
     /// Simulates all remaining playoffs.
@@ -227,7 +667,7 @@ impl WasmLeagueSeason {
     pub fn sim_playoffs(&mut self, rng: &mut WasmRng) -> Result<(), JsError> {
         self.inner
             .sim_playoffs(rng.inner_mut())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 
     /// Simulates a full playoff round across all conference brackets.
@@ -239,7 +679,7 @@ impl WasmLeagueSeason {
     ) -> Result<(), JsError> {
         self.inner
             .sim_playoff_round(round, rng.inner_mut())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 
     /// Simulates a full round in a single conference bracket.
@@ -252,7 +692,7 @@ impl WasmLeagueSeason {
     ) -> Result<(), JsError> {
         self.inner
             .sim_playoff_conference_round(conference, round, rng.inner_mut())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 
     /// Simulates a single playoff matchup to completion. Returns game log as JSON.
@@ -267,7 +707,7 @@ impl WasmLeagueSeason {
         let game = self
             .inner
             .sim_playoff_matchup(conference, round, matchup, rng.inner_mut())
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
         serde_wasm_bindgen::to_value(&game).map_err(|e| JsError::new(&e.to_string()))
     }
 
@@ -284,7 +724,7 @@ impl WasmLeagueSeason {
         let result = self
             .inner
             .sim_playoff_play(conference, round, matchup, rng.inner_mut())
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
         match result {
             Some(game) => {
                 serde_wasm_bindgen::to_value(&game).map_err(|e| JsError::new(&e.to_string()))
@@ -302,7 +742,7 @@ impl WasmLeagueSeason {
     ) -> Result<(), JsError> {
         self.inner
             .sim_winners_bracket_round(round, rng.inner_mut())
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 
     /// Simulates a single winners bracket matchup to completion. Returns game log as JSON.
@@ -316,7 +756,7 @@ impl WasmLeagueSeason {
         let game = self
             .inner
             .sim_winners_bracket_matchup(round, matchup, rng.inner_mut())
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
         serde_wasm_bindgen::to_value(&game).map_err(|e| JsError::new(&e.to_string()))
     }
 
@@ -332,7 +772,7 @@ impl WasmLeagueSeason {
         let result = self
             .inner
             .sim_winners_bracket_play(round, matchup, rng.inner_mut())
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
         match result {
             Some(game) => {
                 serde_wasm_bindgen::to_value(&game).map_err(|e| JsError::new(&e.to_string()))
@@ -352,6 +792,24 @@ impl WasmLeagueSeason {
             .map_err(|e| JsError::new(&e.to_string()))
     }
 
+    /// Returns the total attendance recorded for a week, if any.
+    #[wasm_bindgen(js_name = "weekAttendance")]
+    pub fn week_attendance(&self, week: usize) -> Result<Option<u64>, JsError> {
+        self.inner
+            .weeks()
+            .get(week)
+            .map(|w| w.total_attendance())
+            .ok_or_else(|| JsError::new(&format!("No such week: {}", week)))
+    }
+
+    /// Returns a team's average regular season home attendance, if any.
+    #[wasm_bindgen(js_name = "averageAttendance")]
+    pub fn average_attendance(&self, team_id: usize) -> Result<Option<f64>, JsError> {
+        self.inner
+            .average_attendance(team_id)
+            .map_err(league_error)
+    }
+
     /// Returns the playoffs as a JSON object.
     #[wasm_bindgen(getter)]
     pub fn playoffs(&self) -> Result<JsValue, JsError> {
@@ -376,7 +834,7 @@ impl WasmLeagueSeason {
         let standings = self
             .inner
             .division_standings(conf_index, div_id)
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
         serde_wasm_bindgen::to_value(&standings).map_err(|e| JsError::new(&e.to_string()))
     }
 
@@ -386,7 +844,45 @@ impl WasmLeagueSeason {
         let standings = self
             .inner
             .conference_standings(conf_index)
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&standings).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns the overall standings after a week as a JSON array of
+    /// [teamId, record] pairs.
+    #[wasm_bindgen(js_name = "standingsThroughWeek")]
+    pub fn standings_through_week(&self, week: usize) -> Result<JsValue, JsError> {
+        let standings = self
+            .inner
+            .standings_through_week(week)
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&standings).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns the standings for a specific division after a week as a JSON
+    /// array.
+    #[wasm_bindgen(js_name = "divisionStandingsThroughWeek")]
+    pub fn division_standings_through_week(
+        &self,
+        conf_index: usize,
+        div_id: usize,
+        week: usize,
+    ) -> Result<JsValue, JsError> {
+        let standings = self
+            .inner
+            .division_standings_through_week(conf_index, div_id, week)
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&standings).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns the standings for a specific conference after a week as a
+    /// JSON array.
+    #[wasm_bindgen(js_name = "conferenceStandingsThroughWeek")]
+    pub fn conference_standings_through_week(&self, conf_index: usize, week: usize) -> Result<JsValue, JsError> {
+        let standings = self
+            .inner
+            .conference_standings_through_week(conf_index, week)
+            .map_err(league_error)?;
         serde_wasm_bindgen::to_value(&standings).map_err(|e| JsError::new(&e.to_string()))
     }
 
@@ -396,17 +892,65 @@ impl WasmLeagueSeason {
         let record = self
             .inner
             .division_record(team_id)
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
         serde_wasm_bindgen::to_value(&record).map_err(|e| JsError::new(&e.to_string()))
     }
 
+    /// Returns the completed regular season matchups whose scores fall
+    /// outside the given bounds as a JSON array.
+    pub fn anomalies(&self, bounds: ScoreBounds) -> Result<JsValue, JsError> {
+        let anomalies = self.inner.anomalies(&bounds);
+        serde_wasm_bindgen::to_value(&anomalies).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns the problems found in the season which are likely mistakes,
+    /// such as empty conferences, as a JSON array.
+    pub fn lint(&self) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(&self.inner.lint()).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns a stable fingerprint of the season as a `bigint`, which
+    /// changes if and only if the season's serialized content changes.
+    pub fn fingerprint(&self) -> u64 {
+        self.inner.fingerprint()
+    }
+
+    /// Returns a team's points, home, away, and neutral site splits, margin
+    /// of victory, and current streak as a JSON object.
+    #[wasm_bindgen(js_name = "teamStats")]
+    pub fn team_stats(&self, team_id: usize) -> Result<JsValue, JsError> {
+        let stats = self
+            .inner
+            .team_stats(team_id)
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&stats).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns every team's stats, keyed by team ID.
+    #[wasm_bindgen(js_name = "allTeamStats")]
+    pub fn all_team_stats(&self) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(&self.inner.all_team_stats())
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns a team's giveaways and takeaways as a JSON object, or `null`
+    /// if any of its games was completed without detailed stats.
+    #[wasm_bindgen(js_name = "teamTurnovers")]
+    pub fn team_turnovers(&self, team_id: usize) -> Result<JsValue, JsError> {
+        let turnovers = self
+            .inner
+            .team_turnovers(team_id)
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&turnovers).map_err(|e| JsError::new(&e.to_string()))
+    }
+
     /// Returns a team's conference record as a JSON object.
     #[wasm_bindgen(js_name = "conferenceRecord")]
     pub fn conference_record(&self, team_id: usize) -> Result<JsValue, JsError> {
         let record = self
             .inner
             .conference_record(team_id)
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
         serde_wasm_bindgen::to_value(&record).map_err(|e| JsError::new(&e.to_string()))
     }
 
@@ -416,7 +960,7 @@ impl WasmLeagueSeason {
         let matchups = self
             .inner
             .team_matchups(id)
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
         serde_wasm_bindgen::to_value(&matchups).map_err(|e| JsError::new(&e.to_string()))
     }
 
@@ -425,7 +969,7 @@ impl WasmLeagueSeason {
     pub fn team_in_playoffs(&self, team_id: usize) -> Result<bool, JsError> {
         self.inner
             .team_in_playoffs(team_id)
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 
     /// Returns a team's playoff record as a JSON object.
@@ -434,26 +978,175 @@ impl WasmLeagueSeason {
         let record = self
             .inner
             .playoff_record(team_id)
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
         serde_wasm_bindgen::to_value(&record).map_err(|e| JsError::new(&e.to_string()))
     }
 
+    /// Returns the IDs of the teams tied for the best record.
+    #[wasm_bindgen(js_name = "bestRecordTeams")]
+    pub fn best_record_teams(&self) -> Vec<usize> {
+        self.inner.best_record_teams()
+    }
+
+    /// Returns the zero-based season week in which a playoff round is
+    /// played, including any rest weeks between rounds.
+    #[wasm_bindgen(js_name = "playoffRoundWeek")]
+    pub fn playoff_round_week(&self, winners_bracket: bool, round: usize) -> usize {
+        self.inner.playoff_round_week(winners_bracket, round)
+    }
+
     /// Returns the playoff picture as a JSON object.
     #[wasm_bindgen(js_name = "playoffPicture")]
     pub fn playoff_picture(&self, num_teams: usize) -> Result<JsValue, JsError> {
         let picture = self
             .inner
             .playoff_picture(num_teams)
-            .map_err(|e| JsError::new(&e))?;
+            .map_err(league_error)?;
         serde_wasm_bindgen::to_value(&picture).map_err(|e| JsError::new(&e.to_string()))
     }
 
+    /// Returns each team's odds of making the playoffs, winning its
+    /// division, and earning the top seed, estimated by simulating the rest
+    /// of the season, as a JSON object keyed by team ID.
+    #[wasm_bindgen(js_name = "playoffOdds")]
+    pub fn playoff_odds(&self, num_teams: usize, iterations: usize, rng: &mut WasmRng) -> Result<JsValue, JsError> {
+        let odds = PlayoffPicture::simulate_odds(&self.inner, num_teams, iterations, None, rng.inner_mut())
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&odds).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns the season dashboard as a JSON object, computing the
+    /// requested sections in a single pass over the season.
+    pub fn dashboard(&self, options: SeasonDashboardOptions) -> Result<JsValue, JsError> {
+        let dashboard = self
+            .inner
+            .dashboard(options)
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&dashboard).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns every team's rank after each completed week as a JSON object
+    /// keyed by team ID.
+    #[wasm_bindgen(js_name = "rankHistory")]
+    pub fn rank_history(&self) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(&self.inner.rank_history())
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns the titles won during the season, such as its division and
+    /// conference champions.
+    #[wasm_bindgen(js_name = "honors")]
+    pub fn honors(&self) -> SeasonHonors {
+        self.inner.honors()
+    }
+
+    /// Returns the standings history of the season, every team's rank and
+    /// optionally its games back after each completed week.
+    #[wasm_bindgen(js_name = "standingsHistory")]
+    pub fn standings_history(&self, options: StandingsHistoryOptions) -> StandingsHistory {
+        self.inner.standings_history(options)
+    }
+
+    /// Returns a markdown report of the season.
+    #[wasm_bindgen(js_name = "reportMarkdown")]
+    pub fn report_markdown(&self, options: SeasonReportOptions) -> String {
+        self.inner.report_markdown(options)
+    }
+
+    /// Returns a plain-text report of the season.
+    #[wasm_bindgen(js_name = "reportText")]
+    pub fn report_text(&self, options: SeasonReportOptions) -> String {
+        self.inner.report_text(options)
+    }
+
+    /// Returns a markdown scoreboard card of a regular season matchup.
+    #[wasm_bindgen(js_name = "scoreboard")]
+    pub fn scoreboard(&self, week: usize, matchup: usize) -> Result<String, JsError> {
+        let matchup = self
+            .inner
+            .weeks()
+            .get(week)
+            .and_then(|w| w.matchups().get(matchup))
+            .ok_or_else(|| JsError::new(&format!("No matchup {} found in week {}", matchup, week)))?;
+        Ok(format_scoreboard(matchup, &self.inner))
+    }
+
+    /// Returns a markdown scoreboard card of a playoff matchup. The bracket
+    /// is a conference index, or omitted for the winners bracket.
+    #[wasm_bindgen(js_name = "playoffScoreboard")]
+    pub fn playoff_scoreboard(&self, bracket: Option<usize>, round: usize, matchup: usize) -> Result<String, JsError> {
+        let playoffs = self.inner.playoffs();
+        let rounds = match bracket {
+            Some(conference) => playoffs.conference_bracket(conference),
+            None => Some(playoffs.winners_bracket())
+        };
+        let matchup = rounds
+            .and_then(|r| r.get(round))
+            .and_then(|w| w.matchups().get(matchup))
+            .ok_or_else(|| JsError::new(&format!("No playoff matchup {} found in round {}", matchup, round)))?;
+        format_playoff_scoreboard(matchup, &self.inner).map_err(league_error)
+    }
+
+    /// Returns a preview of every matchup in a playoff round as a JSON
+    /// array. The bracket is a conference index, or omitted for the winners
+    /// bracket.
+    #[wasm_bindgen(js_name = "roundPreviews")]
+    pub fn round_previews(&self, bracket: Option<usize>, round: usize) -> Result<JsValue, JsError> {
+        let previews = self
+            .inner
+            .playoffs()
+            .round_previews(bracket, round, &self.inner)
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&previews).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns the playoff bracket as a tree of nodes, from the first round
+    /// of each conference bracket up to the championship.
+    #[wasm_bindgen(js_name = "bracketTree")]
+    pub fn bracket_tree(&self) -> Result<PlayoffBracketTree, JsError> {
+        self.inner
+            .playoffs()
+            .bracket_tree()
+            .map_err(league_error)
+    }
+
+    /// Returns a team's path to the championship as a JSON array of rounds.
+    #[wasm_bindgen(js_name = "pathToChampionship")]
+    pub fn path_to_championship(&self, team_id: usize) -> Result<JsValue, JsError> {
+        let path = self
+            .inner
+            .playoffs()
+            .path_to_championship(team_id)
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&path).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Returns a team's projected path to the championship from the current
+    /// playoff picture as a JSON array of rounds.
+    #[wasm_bindgen(js_name = "projectedPathToChampionship")]
+    pub fn projected_path_to_championship(
+        &self,
+        num_teams: usize,
+        team_id: usize,
+        trials: usize,
+        rng: &mut WasmRng,
+    ) -> Result<JsValue, JsError> {
+        let picture = self
+            .inner
+            .playoff_picture(num_teams)
+            .map_err(league_error)?;
+        let path = picture
+            .projected_path_to_championship(&self.inner, team_id, trials, rng.inner_mut())
+            .map_err(league_error)?;
+        serde_wasm_bindgen::to_value(&path).map_err(|e| JsError::new(&e.to_string()))
+    }
+
     /// Returns true if a team made it to the championship.
     #[wasm_bindgen(js_name = "teamInChampionship")]
     pub fn team_in_championship(&self, team_id: usize) -> Result<bool, JsError> {
         self.inner
             .team_in_championship(team_id)
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 
     /// Returns true if a team won the championship.
@@ -461,7 +1154,7 @@ impl WasmLeagueSeason {
     pub fn team_won_championship(&self, team_id: usize) -> Result<bool, JsError> {
         self.inner
             .team_won_championship(team_id)
-            .map_err(|e| JsError::new(&e))
+            .map_err(league_error)
     }
 
     /// Returns the season as a JSON-serializable object.
@@ -474,7 +1167,7 @@ impl WasmLeagueSeason {
 
 impl Default for WasmLeagueSeason {
     fn default() -> Self {
-        Self::new()
+        Self::new(None)
     }
 }
 
@@ -498,4 +1191,34 @@ impl WasmLeagueSeason {
     pub fn from_inner(inner: LeagueSeason) -> Self {
         WasmLeagueSeason { inner }
     }
+
+    /// Summarizes the regular season's progress after simulating the weeks
+    /// with the given reports.
+    fn sim_progress(&self, reports: Vec<WeekSimReport>) -> SeasonSimProgress {
+        SeasonSimProgress {
+            reports,
+            next_week: self.inner.weeks().iter().position(|week| !week.complete()),
+            total_weeks: self.inner.weeks().len(),
+        }
+    }
+}
+
+/// Formats a team record as wins-losses-ties, e.g. "10-5-1".
+#[wasm_bindgen(js_name = "formatRecord")]
+pub fn format_record(record: LeagueTeamRecord) -> String {
+    record.record_string()
+}
+
+/// Formats a team record's win percentage, e.g. ".667", counting each tie
+/// as half a win.
+#[wasm_bindgen(js_name = "formatWinPct")]
+pub fn format_win_pct(record: LeagueTeamRecord) -> String {
+    record.win_pct_string()
+}
+
+/// Formats the games a team record trails a leader's record by, e.g. "2.5",
+/// or an em dash for the leader.
+#[wasm_bindgen(js_name = "formatGamesBack")]
+pub fn format_games_back(record: LeagueTeamRecord, leader: LeagueTeamRecord) -> String {
+    record.games_back_string(&leader)
 }
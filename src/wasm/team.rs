@@ -84,6 +84,22 @@ impl WasmFootballTeam {
         self.inner.defense_overall()
     }
 
+    /// Gets the capacity of the team's home stadium.
+    #[wasm_bindgen(getter, js_name = "stadiumCapacity")]
+    pub fn stadium_capacity(&self) -> u32 {
+        self.inner.stadium_capacity()
+    }
+
+    /// Sets the capacity of the team's home stadium.
+    ///
+    /// # Errors
+    /// Returns an error if the capacity exceeds the maximum.
+    #[wasm_bindgen(js_name = "setStadiumCapacity")]
+    pub fn set_stadium_capacity(&mut self, capacity: u32) -> Result<(), JsError> {
+        self.inner.set_stadium_capacity(capacity)
+            .map_err(|e| JsError::new(&e))
+    }
+
     /// Returns the team as a JSON-serializable object.
     ///
     /// This allows full access to all team properties from JavaScript.
@@ -164,6 +180,13 @@ impl WasmFootballTeamCoach {
         self.inner.up_tempo()
     }
 
+    /// Gets the coach's game script tendency (0-100).
+    /// Higher values mean leading teams run more and trailing teams pass more.
+    #[wasm_bindgen(getter, js_name = "gameScript")]
+    pub fn game_script(&self) -> u32 {
+        self.inner.game_script()
+    }
+
     /// Returns the coach as a JSON-serializable object.
     #[wasm_bindgen(js_name = "toJSON")]
     pub fn to_json(&self) -> Result<JsValue, JsError> {
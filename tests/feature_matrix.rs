@@ -0,0 +1,145 @@
+//! Tests the library's core functionality under whichever combination of
+//! optional features it is built with. Run under every combination with
+//! `make test-features`.
+use std::fmt::Debug;
+
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use fbsim_core::features;
+use fbsim_core::game::context::{GameContext, GameContextUpdateOptions};
+use fbsim_core::game::matchup::{FootballMatchup, FootballMatchupResult};
+use fbsim_core::game::play::Game;
+use fbsim_core::game::play::call::PlayCall;
+use fbsim_core::game::score::FinalScore;
+use fbsim_core::league::League;
+use fbsim_core::league::season::{LeagueSeason, LeagueSeasonScheduleOptions};
+use fbsim_core::league::season::playoffs::LeagueSeasonPlayoffs;
+use fbsim_core::team::FootballTeam;
+
+/// Compiles only if a type has its core functionality without features
+fn assert_core<T: Clone + Debug + Serialize + DeserializeOwned>() {}
+
+#[test]
+fn test_core_traits_without_features() {
+    assert_core::<FootballTeam>();
+    assert_core::<FootballMatchup>();
+    assert_core::<FootballMatchupResult>();
+    assert_core::<FinalScore>();
+    assert_core::<GameContext>();
+    assert_core::<GameContextUpdateOptions>();
+    assert_core::<Game>();
+    assert_core::<PlayCall>();
+    assert_core::<LeagueSeason>();
+    assert_core::<LeagueSeasonPlayoffs>();
+    assert_core::<League>();
+}
+
+#[test]
+fn test_enabled_features() {
+    assert_eq!(features::BINARY, cfg!(feature = "binary"));
+    assert_eq!(features::CHRONO, cfg!(feature = "chrono"));
+    assert_eq!(features::EXPORT, cfg!(feature = "export"));
+    assert_eq!(features::ROCKET_OKAPI, cfg!(feature = "rocket_okapi"));
+    assert_eq!(features::WASM, cfg!(feature = "wasm"));
+    let expected = [
+        ("binary", cfg!(feature = "binary")),
+        ("chrono", cfg!(feature = "chrono")),
+        ("export", cfg!(feature = "export")),
+        ("rocket_okapi", cfg!(feature = "rocket_okapi")),
+        ("wasm", cfg!(feature = "wasm"))
+    ];
+    let expected: Vec<&str> = expected.iter().filter(|(_, on)| *on).map(|(name, _)| *name).collect();
+    assert_eq!(features::enabled(), expected);
+}
+
+#[test]
+fn test_season_round_trip() {
+    let mut rng = SmallRng::seed_from_u64(1502);
+    let mut season = LeagueSeason::with_year(2026);
+    for id in 0..4 {
+        season.add_team(id, FootballTeam::new()).unwrap();
+    }
+    season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    season.sim_regular_season(&mut rng).unwrap();
+
+    let json = serde_json::to_string(&season).unwrap();
+    let restored: LeagueSeason = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, season);
+    assert_eq!(restored.clone().standings(), season.standings());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_defaults_year() {
+    assert_eq!(LeagueSeason::new(), LeagueSeason::default());
+    let mut league = League::new();
+    assert!(league.add_season().is_ok());
+}
+
+#[cfg(not(feature = "chrono"))]
+#[test]
+fn test_without_chrono_needs_year() {
+    let mut league = League::new();
+    assert!(league.add_season().is_err());
+    league.add_season_with_year(2026).unwrap();
+    assert_eq!(*league.current_season().as_ref().unwrap().year(), 2026);
+}
+
+#[cfg(feature = "binary")]
+#[test]
+fn test_binary_round_trip() {
+    let mut rng = SmallRng::seed_from_u64(1515);
+    let mut league = League::new();
+    league.add_season_with_year(2026).unwrap();
+    for id in 0..4 {
+        league.add_team();
+        league.add_season_team(id, FootballTeam::new()).unwrap();
+    }
+    league.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    league.sim(&mut rng).unwrap();
+
+    let bytes = league.to_bytes().unwrap();
+    assert_eq!(League::from_bytes(&bytes).unwrap(), league);
+}
+
+#[cfg(feature = "export")]
+#[test]
+fn test_schedule_export() {
+    use fbsim_core::league::season::export::CalendarDate;
+
+    let mut rng = SmallRng::seed_from_u64(1522);
+    let mut season = LeagueSeason::with_year(2026);
+    for id in 0..4 {
+        season.add_team(id, FootballTeam::new()).unwrap();
+    }
+    season.generate_schedule(LeagueSeasonScheduleOptions::new(), &mut rng).unwrap();
+    season.sim_regular_season(&mut rng).unwrap();
+
+    let matchups: usize = season.weeks().iter().map(|w| w.matchups().len()).sum();
+    assert_eq!(season.to_csv_schedule().lines().count(), matchups + 1);
+    let ical = season.to_ical(CalendarDate::new(9, 10), 7).unwrap();
+    assert_eq!(ical.matches("BEGIN:VEVENT").count(), matchups);
+}
+
+#[cfg(feature = "rocket_okapi")]
+#[test]
+fn test_json_schemas() {
+    use rocket_okapi::okapi::schemars::schema_for;
+
+    let schema = serde_json::to_string(&schema_for!(LeagueSeason)).unwrap();
+    assert!(schema.contains("LeagueSeasonMatchup"));
+    let schema = serde_json::to_string(&schema_for!(PlayCall)).unwrap();
+    assert!(schema.contains("ExtraPoint"));
+}
+
+#[cfg(feature = "wasm")]
+#[test]
+fn test_typescript_declarations() {
+    use tsify_next::Tsify;
+
+    assert!(FootballTeam::DECL.contains("FootballTeam"));
+    assert!(FootballMatchupResult::DECL.contains("FootballMatchupResult"));
+}
@@ -0,0 +1,64 @@
+//! Tests the JavaScript bindings in a WebAssembly runtime. Run with
+//! `make test-wasm`.
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use fbsim_core::league::season::LeagueSeasonScheduleOptions;
+use fbsim_core::wasm::{WasmFootballTeam, WasmLeagueSeason, WasmRng};
+
+/// Build a 6-team season with its schedule generated
+fn scheduled_season(rng: &mut WasmRng) -> WasmLeagueSeason {
+    let mut season = WasmLeagueSeason::new(Some(2026));
+    for id in 0..6 {
+        season.add_team(id, &WasmFootballTeam::new()).unwrap();
+    }
+    season.generate_schedule(LeagueSeasonScheduleOptions::new(), rng).unwrap();
+    season
+}
+
+#[wasm_bindgen_test]
+fn test_sim_weeks_in_chunks() {
+    let mut rng = WasmRng::from_seed(1531);
+    let mut season = scheduled_season(&mut rng);
+    let total_weeks = season.inner().weeks().len();
+
+    // Each chunk completes its weeks and reports where the next begins
+    let mut from = 0;
+    while from < total_weeks {
+        let to = (from + 4).min(total_weeks);
+        let progress = season.sim_weeks(from, to, &mut rng).unwrap();
+        assert_eq!(progress.reports.len(), to - from);
+        assert_eq!(progress.total_weeks, total_weeks);
+        assert_eq!(progress.next_week, if to < total_weeks { Some(to) } else { None });
+        from = to;
+    }
+    assert!(season.regular_season_complete());
+
+    // Completed weeks are skipped
+    let progress = season.sim_weeks(0, total_weeks, &mut rng).unwrap();
+    assert!(progress.reports.is_empty());
+    assert_eq!(progress.next_week, None);
+}
+
+#[wasm_bindgen_test]
+fn test_sim_weeks_stopped_early_leaves_season_consistent() {
+    let mut rng = WasmRng::from_seed(1531);
+    let mut season = scheduled_season(&mut rng);
+
+    // A chunk past an incomplete week fails without simulating anything
+    assert!(season.sim_weeks(2, 3, &mut rng).is_err());
+    assert!(!season.started());
+
+    // Stopping after a chunk leaves its weeks complete and the rest unplayed
+    let progress = season.sim_weeks(0, 2, &mut rng).unwrap();
+    assert_eq!(progress.next_week, Some(2));
+    let weeks = season.inner().weeks();
+    assert!(weeks[..2].iter().all(|week| week.complete()));
+    assert!(weeks[2..].iter().all(|week| !week.started()));
+
+    // The season resumes where the caller stopped
+    let total_weeks = weeks.len();
+    let progress = season.sim_weeks(2, total_weeks, &mut rng).unwrap();
+    assert_eq!(progress.reports.len(), total_weeks - 2);
+    assert!(season.regular_season_complete());
+}